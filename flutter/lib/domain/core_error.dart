@@ -79,6 +79,28 @@ final class WatcherError extends CoreError {
     code: 'WATCHER_NOT_RUNNING',
   );
 
+  /// Ошибка с кодом из структурированной ошибки Rust (`LateraApiError`).
+  factory WatcherError.fromCode(
+    String code,
+    String message, {
+    Object? originalError,
+    StackTrace? stackTrace,
+  }) {
+    switch (code) {
+      case 'WATCHER_ALREADY_RUNNING':
+        return WatcherError.alreadyRunning(originalError);
+      case 'WATCHER_NOT_RUNNING':
+        return WatcherError.notRunning(originalError);
+      default:
+        return WatcherError(
+          message: message,
+          originalError: originalError,
+          stackTrace: stackTrace,
+          code: code,
+        );
+    }
+  }
+
   factory WatcherError.fromRust(Object error, [StackTrace? st]) {
     final errorStr = error.toString().toLowerCase();
     if (errorStr.contains('already running')) {
@@ -6,9 +6,29 @@ class FileAddedEvent {
   final String? fullPath;
   final DateTime occurredAt;
 
+  /// Время создания файла (birthtime), если платформа его сообщает.
+  final DateTime? createdAt;
+
+  /// Время последнего изменения файла (mtime).
+  final DateTime? modifiedAt;
+
+  /// Размер файла в байтах.
+  final int? sizeBytes;
+
+  /// Расширение в нижнем регистре, без точки.
+  final String? extension;
+
+  /// MIME-тип по содержимому файла.
+  final String? mimeType;
+
   const FileAddedEvent({
     required this.fileName,
     required this.occurredAt,
     this.fullPath,
+    this.createdAt,
+    this.modifiedAt,
+    this.sizeBytes,
+    this.extension,
+    this.mimeType,
   });
 }
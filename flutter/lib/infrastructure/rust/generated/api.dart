@@ -5,19 +5,111 @@
 
 import 'error.dart';
 import 'frb_generated.dart';
-import 'indexer/ocr.dart';
-import 'indexer/text_extractor.dart';
-import 'indexer/transcriber.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 
-// These functions are ignored because they are not marked as `pub`: `close_file_added_stream`, `close_file_removed_stream`, `with_index_db`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+part 'api.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `absolute_folder`, `api_config`, `append_to_wal`, `apply_core_config`, `apply_file_rules`, `apply_policy`, `apply_to`, `audit_quarantine`, `bind_sink`, `check_name_conflict`, `clear_event_debug_log`, `close`, `close`, `close_ackable_stream`, `close_archive_store`, `close_core_config`, `close_core_stores`, `close_dry_run_stream`, `close_event_wal`, `close_file_removed_stream`, `close_file_status_stream`, `close_folder_composition`, `close_index_db`, `close_onboarding`, `close_rule_applied_stream`, `close_screenshot_stream`, `close_settings_store`, `close_watch_status_stream`, `close_watch_streams`, `close_watcher_status_stream`, `coordinate_shared_file`, `current_core_config`, `db_path`, `detach_watcher`, `disable_ack_mode`, `emit_adoption_progress`, `emit_codes_detected`, `emit_dry_run_action`, `emit_duplicate_detected`, `emit_file_added`, `emit_file_batch`, `emit_file_chunks`, `emit_file_event`, `emit_file_hash`, `emit_file_op`, `emit_file_status_changed`, `emit_rule_applied`, `emit_screenshot_added`, `emit_watch_status`, `emit_watcher_status`, `enqueue_auto_index`, `enqueue_code_scan`, `enqueue_duplicate_check`, `enqueue_duplicate_job`, `enqueue_hash`, `enqueue_preview`, `enqueue_thumbnail`, `finish_wal_entry`, `for_each_live_scope`, `forget_known_file`, `handle_file_event`, `in_dir`, `inspect_onboarding`, `intake_arrival`, `intake_target`, `internal_watch_filter`, `is_ack_mode_enabled`, `is_watched`, `load_core_config`, `mark_event_delivered`, `new`, `new`, `note_onboarding_first_event`, `of`, `onboarding_folder`, `onboarding_path`, `onboarding_state`, `open_event_wal`, `policy_status`, `preview_cache_dir`, `record_archived`, `record_event_debug_info`, `record_journal_event`, `redeliver_pending_events`, `redeliver_unacknowledged`, `release_watcher_scope`, `repair_integrity_issue`, `report_file`, `send_ackable_file_added`, `send_file_added`, `send_file_batch`, `send_file_event`, `shutdown_core_within`, `spawn_watcher`, `start_archive_monitor`, `start_disk_space_monitor`, `start_folder_watcher`, `start_quota_monitor`, `stop`, `stop_adoptions`, `stop_auto_index_queue`, `stop_code_scan_queue`, `stop_duplicate_queue`, `stop_file_ops_pool`, `stop_hash_queue`, `stop_heartbeat`, `stop_preview_queue`, `stop_thumbnail_queue`, `stop_watcher`, `stop_watcher_and_streams`, `submit_file_op`, `take_slot`, `thumbnail`, `to_file_added_event`, `track_file_status`, `track_tags`, `tuned_watcher_options`, `update_folder_composition`, `watched_folders`, `watcher_scope`, `with_archive_store`, `with_event_journal`, `with_file_status`, `with_file_status_store`, `with_folder_indexes`, `with_index_db`, `with_journal`, `with_onboarding`, `with_settings_store`, `with_tag_store`, `with_tags`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `default`, `default`, `default`, `default`, `default`, `default`, `default`, `default`, `drop`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`, `from`
 
 /// Инициализация логирования в Rust.
 ///
 /// Можно вызвать из Flutter сразу после старта.
+/// Не заменяет [`init_core`] — остальные API требуют полной инициализации.
 Future<void> initLogging() => RustCore.instance.api.crateApiInitLogging();
 
+/// Изменить уровень логов на лету: `error`, `warn`, `info`, `debug`, `trace`
+/// или `off` (регистр не важен).
+///
+/// Действует на все приёмники (файл, stderr, буфер, [`on_log`]) до
+/// перезапуска и важнее `RUST_LOG` — поддержка может включить DEBUG в
+/// работающей сборке.
+Future<void> setLogLevel({required String level}) =>
+    RustCore.instance.api.crateApiSetLogLevel(level: level);
+
+/// Текущий уровень логов (`INFO`, `DEBUG`, …): самый подробный среди приёмников.
+Future<String> getLogLevel() => RustCore.instance.api.crateApiGetLogLevel();
+
+/// Stream логов Rust Core — для debug-консоли приложения.
+///
+/// Приходят записи не ниже уровня stream-приёмника (по умолчанию INFO).
+/// Один активный подписчик: повторный вызов заменяет предыдущий stream.
+/// Закрытый во Flutter stream отключается при следующей записи.
+Stream<LogRecord> onLog() => RustCore.instance.api.crateApiOnLog();
+
+/// Путь к основному лог-файлу (`{local data}/Latera/logs/latera.log`).
+///
+/// `None` — запись в файл отключена или папка логов недоступна. Путь можно
+/// показать пользователю или приложить к отчёту об ошибке.
+Future<String?> getLogFilePath() =>
+    RustCore.instance.api.crateApiGetLogFilePath();
+
+/// Текущие настройки ротации основного лог-файла.
+Future<LogFileOptions> getLogFileOptions() =>
+    RustCore.instance.api.crateApiGetLogFileOptions();
+
+/// Изменить ротацию и хранение основного лог-файла.
+///
+/// Новые лимиты применяются к следующей записи; лишние архивы удаляются
+/// при ближайшей ротации.
+Future<void> setLogFileOptions({required LogFileOptions options}) =>
+    RustCore.instance.api.crateApiSetLogFileOptions(options: options);
+
+/// Дублировать ERROR/WARN Rust Core в системный журнал ОС: Windows Event
+/// Log (источник `Latera`) или unified logging macOS (subsystem `Latera`).
+///
+/// Для режима агента/службы: администратор видит сбои там, куда и так
+/// смотрит. По умолчанию выключено; на других платформах включение
+/// возвращает ошибку.
+Future<void> setSystemLogMirroring({required bool enabled}) =>
+    RustCore.instance.api.crateApiSetSystemLogMirroring(enabled: enabled);
+
+/// Единая точка инициализации Rust Core.
+///
+/// Инициализирует логирование, папку данных, общий async runtime и
+/// (опционально) индекс. Возвращает новый [`CoreHandle`] — окну Flutter
+/// или тесту.
+/// Должна быть вызвана до остальных API — иначе они вернут
+/// `LateraError::CoreNotInitialized`.
+///
+/// Читает политику администратора (см. [`get_policy_status`]); если файл
+/// политики повреждён, ядро не инициализируется.
+///
+/// Безопасна для повторного вызова: общее состояние ядра инициализируется
+/// один раз, а каждый вызов создаёт новый handle. `data_dir` повторного
+/// вызова задаёт папку хранилищ только этого handle'а.
+Future<CoreHandle> initCore({required CoreConfig config}) =>
+    RustCore.instance.api.crateApiInitCore(config: config);
+
+/// Детерминированный teardown Rust Core при выходе из приложения.
+///
+/// Порядок:
+/// 1. останавливает все watcher'ы и закрывает streams,
+///    выключает режим подтверждения, закрывает WAL событий и heartbeat;
+/// 2. отменяет фоновые задачи (RAG-стрим, LLM-генерация) и очищает их очереди,
+///    сбрасывает агрегаты телеметрии на диск;
+/// 3. закрывает индексную БД (с checkpoint WAL);
+/// 4. выгружает модели (LLM, semantic);
+/// 5. сбрасывает состояние ядра и буферы логов.
+///
+/// Все шаги выполняются даже при ошибке на одном из них; возвращается
+/// первая ошибка. Безопасен для повторного вызова. После shutdown API снова
+/// требует [`init_core`].
+///
+/// Каждый watcher ждём не дольше 5 секунд; см. [`shutdown_all`].
+Future<void> shutdownCore() => RustCore.instance.api.crateApiShutdownCore();
+
+/// Teardown ядра при выходе из приложения с ограничением по времени.
+///
+/// То же, что [`shutdown_core`], но watcher'ы останавливаются параллельно
+/// и все вместе ждутся не дольше `timeout_ms`. Зависшие потоки
+/// отсоединяются — выход из приложения не блокируется; в этом случае
+/// возвращается `LateraError::ShutdownTimedOut` (остальной teardown всё
+/// равно выполняется).
+Future<void> shutdownAll({required int timeoutMs}) =>
+    RustCore.instance.api.crateApiShutdownAll(timeoutMs: timeoutMs);
+
 /// Stream событий добавления файла.
 ///
 /// В Dart это будет выглядеть как `Stream<FileAddedEvent> onFileAdded()`.
@@ -35,18 +127,307 @@ Stream<FileAddedEvent> onFileAdded() =>
 Stream<FileRemovedEvent> onFileRemoved() =>
     RustCore.instance.api.crateApiOnFileRemoved();
 
-/// Запуск мониторинга.
+/// Stream всех событий файлов: создание, изменение, удаление, переименование.
+///
+/// В Dart это будет выглядеть как `Stream<FileEvent> onFileEvent()`.
+/// Контракт как у [`on_file_added`]: один активный подписчик; stream
+/// закрывается при [`stop_watching`].
+Stream<FileEvent> onFileEvent() => RustCore.instance.api.crateApiOnFileEvent();
+
+/// Stream пачек появившихся файлов сверх rate-limit (см. [`FileBatchEvent`]).
+///
+/// Контракт как у [`on_file_added`]: один активный подписчик; stream
+/// закрывается при [`stop_watching`].
+Stream<FileBatchEvent> onFileBatch() =>
+    RustCore.instance.api.crateApiOnFileBatch();
+
+/// Stream чанков появившихся файлов (см. [`FileChunkEvent`]).
+///
+/// Контракт как у [`on_file_added`]: один активный подписчик; stream
+/// закрывается при [`stop_watching`]. Чанки приходят, только если
+/// передача чанками включена ([`set_chunked_transfer`]).
+Stream<FileChunkEvent> onFileChunks() =>
+    RustCore.instance.api.crateApiOnFileChunks();
+
+/// Передавать пачки появившихся файлов (сверка при запуске, перенос
+/// папки, поток сверх rate-limit) чанками по `events_per_chunk` событий
+/// в [`on_file_chunks`] вместо [`FileBatchEvent`].
+///
+/// Тысячи файлов пересекают FFI несколькими буферами, а не структурой на
+/// каждый файл; выигрыш виден в `latera_transfer_*` метриках
+/// ([`export_metrics_text`]). Пока к [`on_file_chunks`] никто не
+/// подписан, пачки идут как раньше. `None` — выключить.
+Future<void> setChunkedTransfer({int? eventsPerChunk}) =>
+    RustCore.instance.api.crateApiSetChunkedTransfer(
+      eventsPerChunk: eventsPerChunk,
+    );
+
+/// Запуск мониторинга папки.
 ///
 /// - Если `override_path` = `None` → используется дефолтный `Desktop/Latera`.
 /// - Если `Some` → должен быть абсолютный путь; директория будет создана при отсутствии.
 ///
-/// Возвращает фактический путь директории наблюдения (для отображения в UI).
-Future<String> startWatching({String? overridePath}) =>
-    RustCore.instance.api.crateApiStartWatching(overridePath: overridePath);
+/// Можно наблюдать несколько папок одновременно: каждый вызов запускает
+/// отдельный watcher. Повторный запуск для уже наблюдаемой папки возвращает
+/// `LateraError::WatcherAlreadyRunning`. Streams событий общие для всех
+/// watcher'ов; [`FileEvent::watcher_id`] указывает источник.
+///
+/// Настройки watcher'а ([`set_watch_filter`], [`set_watch_recursive`] и др.)
+/// фиксируются в момент запуска. `tuning` переопределяет сглаживание событий
+/// только для этого watcher'а; некорректное значение —
+/// `LateraError::InvalidArgument`.
+///
+/// Возвращает `watcher_id` — его принимают [`stop_watching`] и другие API
+/// конкретного watcher'а — и выбранный способ наблюдения (на сетевых дисках
+/// по умолчанию опрос, см. [`set_watch_backend`]). Путь папки для UI — в
+/// [`list_watchers`].
+Future<WatchStarted> startWatching({
+  String? overridePath,
+  WatchTuning? tuning,
+}) => RustCore.instance.api.crateApiStartWatching(
+  overridePath: overridePath,
+  tuning: tuning,
+);
+
+/// Наблюдать за папкой загрузок ОС (пресет).
+///
+/// Временные файлы незавершённых загрузок (`*.crdownload`, `*.part`…) и
+/// скрытые файлы не сообщаются; о загрузке сообщается, когда она перестала
+/// меняться. Остальные настройки — как у [`start_watching`], кроме фильтра
+/// и рекурсии.
+///
+/// `move_to` — абсолютный путь папки (обычно наблюдаемой папки Latera), куда
+/// переносить готовые загрузки; занятые имена получают суффикс ` (N)`.
+/// Перенесённый файл сообщает watcher папки `move_to`, а не этот. `None` —
+/// загрузки остаются на месте и сообщаются как обычно.
+///
+/// Возвращает `watcher_id`, как [`start_watching`].
+Future<String> startWatchingDownloads({String? moveTo}) =>
+    RustCore.instance.api.crateApiStartWatchingDownloads(moveTo: moveTo);
+
+/// Наблюдать за папкой скриншотов ОС (пресет).
+///
+/// Папка — Desktop на macOS, `Pictures/Screenshots` на Windows и Linux.
+/// Сообщаются только файлы с системными именами скриншотов
+/// (`Screenshot …`, `Screen Shot …`, `Снимок экрана …`) в форматах
+/// изображений; прочие файлы папки игнорируются.
+///
+/// `move_to` — абсолютный путь папки (обычно наблюдаемой папки Latera), куда
+/// переносить скриншоты; перенесённый файл сообщает watcher папки `move_to`.
+/// `None` — скриншоты остаются на месте и приходят в [`on_screenshot_added`]
+/// (и в [`on_file_event`]), но не в [`on_file_added`].
+///
+/// Возвращает `watcher_id`, как [`start_watching`].
+Future<String> startWatchingScreenshots({String? moveTo}) =>
+    RustCore.instance.api.crateApiStartWatchingScreenshots(moveTo: moveTo);
+
+/// Stream скриншотов (см. [`start_watching_screenshots`]).
+///
+/// Контракт как у [`on_file_added`]: один активный подписчик; stream
+/// закрывается при [`stop_watching`].
+Stream<ScreenshotAddedEvent> onScreenshotAdded() =>
+    RustCore.instance.api.crateApiOnScreenshotAdded();
+
+/// Запущенные watcher'ы (в порядке `watcher_id`).
+Future<List<WatcherInfo>> listWatchers() =>
+    RustCore.instance.api.crateApiListWatchers();
+
+/// Сверить папку watcher'а `watcher_id` с последним известным состоянием.
+///
+/// Запасной выход, если кажется, что watcher пропустил события (и
+/// единственный способ узнать об изменениях в [`ApiWatchMode::ManualRefresh`]):
+/// найденное приходит в [`on_file_batch`], как при сверке со снимком, без
+/// повторов уже сообщённого. Возвращает число найденных расхождений.
+Future<int> rescanNow({required String watcherId}) =>
+    RustCore.instance.api.crateApiRescanNow(watcherId: watcherId);
+
+/// Выбрать смысл `occurred_at_ms` в событиях добавления.
+///
+/// Применяется при следующем [`start_watching`]. Поля `detected_at_ms`
+/// и `monotonic_ms` всегда отражают момент обнаружения.
+Future<void> setEventTimestampSource({required EventTimestampSource source}) =>
+    RustCore.instance.api.crateApiSetEventTimestampSource(source: source);
+
+/// Включить режим «только наблюдение».
+///
+/// Ядро гарантирует, что ничего не пишет в watch dir: не создаёт её (папка
+/// должна существовать), не кладёт служебные файлы (`desktop.ini`), не
+/// удаляет файлы по квоте и не меняет атрибуты файлов. API, которым нужна
+/// запись, возвращают `LateraError::ReadOnlyMode`.
+///
+/// Если режим задан политикой администратора, другое значение —
+/// `LateraError::PolicyLocked`.
+Future<void> setReadOnlyMode({required bool enabled}) =>
+    RustCore.instance.api.crateApiSetReadOnlyMode(enabled: enabled);
+
+/// Включён ли режим «только наблюдение».
+Future<bool> isReadOnlyMode() => RustCore.instance.api.crateApiIsReadOnlyMode();
+
+/// Включить согласование общей (сетевой) папки между экземплярами Latera.
+///
+/// Для каждого появившегося файла ядро создаёт рядом служебный claim-файл;
+/// автоматические действия (перенос по пресету загрузок и т.п.) выполняет
+/// только экземпляр, забравший файл первым. Результат — в
+/// [`FileEvent::claim`]. Claim-файлы не попадают в streams и удаляются,
+/// когда файл уходит из папки. Действует сразу для всех watcher'ов.
+Future<void> setSharedFolderClaims({required bool enabled}) =>
+    RustCore.instance.api.crateApiSetSharedFolderClaims(enabled: enabled);
+
+/// Включено ли согласование общей папки.
+Future<bool> isSharedFolderClaimsEnabled() =>
+    RustCore.instance.api.crateApiIsSharedFolderClaimsEnabled();
+
+/// Задать префикс имён служебных файлов ядра (по умолчанию `.latera-`).
+///
+/// События файлов с этим префиксом (probe-файлы проверок и т.п.) и
+/// `desktop.ini` никогда не попадают в streams. Действует сразу для всех
+/// watcher'ов; файлы со старым префиксом перестают подавляться.
+Future<void> setInternalFilePrefix({required String prefix}) =>
+    RustCore.instance.api.crateApiSetInternalFilePrefix(prefix: prefix);
+
+/// Текущий префикс имён служебных файлов ядра.
+Future<String> getInternalFilePrefix() =>
+    RustCore.instance.api.crateApiGetInternalFilePrefix();
+
+/// Наблюдать и за вложенными папками watch dir.
+///
+/// Применяется при следующем [`start_watching`].
+Future<void> setWatchRecursive({required bool enabled}) =>
+    RustCore.instance.api.crateApiSetWatchRecursive(enabled: enabled);
+
+/// Выбрать поведение при пропаже папки наблюдения.
+///
+/// О пропаже приходит [`WatchStatusEvent::WatchDirLost`], о возобновлении
+/// наблюдения — [`WatchStatusEvent::WatchDirRestored`]; изменения, сделанные
+/// в папке за это время, сообщаются при возобновлении, если включена
+/// сверка со снимком. Применяется при следующем [`start_watching`].
+Future<void> setWatchDirRecovery({required WatchDirRecoveryMode mode}) =>
+    RustCore.instance.api.crateApiSetWatchDirRecovery(mode: mode);
+
+/// Настроить лестницу деградации watcher'а (по умолчанию включена:
+/// 3 сбоя, опрос раз в 5 с).
+///
+/// О каждом переходе приходит [`ApiWatcherState::Fallback`]; в режиме
+/// ручного обновления изменения находит [`rescan_now`]. `None` —
+/// только перезапуск нативного backend'а, затем [`ApiWatcherState::Error`].
+/// Применяется при следующем [`start_watching`].
+Future<void> setWatcherDegradation({WatcherDegradationPolicy? policy}) =>
+    RustCore.instance.api.crateApiSetWatcherDegradation(policy: policy);
+
+/// Выбрать способ наблюдения (по умолчанию [`ApiWatchBackend::Auto`]).
+///
+/// Сетевые папки часто не присылают уведомлений об изменениях: в режиме
+/// `Auto` watcher на сетевом диске сразу опрашивает папку. `poll_interval_ms`
+/// — период опроса (не меньше 100 мс); `None` — из
+/// [`set_watcher_degradation`], а без неё — по типу тома (на сети 30 с).
+/// Выбранный способ возвращает [`start_watching`].
+/// Применяется при следующем [`start_watching`].
+Future<void> setWatchBackend({
+  required ApiWatchBackend backend,
+  int? pollIntervalMs,
+}) => RustCore.instance.api.crateApiSetWatchBackend(
+  backend: backend,
+  pollIntervalMs: pollIntervalMs,
+);
+
+/// Сообщать только файлы, прошедшие фильтр (`None` — все файлы).
+///
+/// Отфильтрованные файлы не попадают ни в один stream — Dart не получает
+/// события, которые всё равно выбросил бы. Некорректный glob-шаблон —
+/// `LateraError::InvalidArgument`.
+/// Применяется при следующем [`start_watching`].
+Future<void> setWatchFilter({WatchFilter? filter}) =>
+    RustCore.instance.api.crateApiSetWatchFilter(filter: filter);
+
+/// Игнорировать появившиеся файлы, чей mtime старше `max_age_minutes` минут.
+///
+/// Такие файлы (восстановленные из бэкапа, заново скачанные облачным
+/// клиентом) для пользователя не «новые». `None` выключает фильтр.
+/// Применяется при следующем [`start_watching`].
+Future<void> setIgnoreFilesOlderThan({int? maxAgeMinutes}) =>
+    RustCore.instance.api.crateApiSetIgnoreFilesOlderThan(
+      maxAgeMinutes: maxAgeMinutes,
+    );
+
+/// Сообщать о появлении файла, только когда он «осел»: размер и mtime не
+/// менялись `quiet_period_ms` миллисекунд.
+///
+/// Большой файл, копируемый в папку, иначе приходит недописанным. Пока файл
+/// не осел, его изменения не сообщаются; исчезнувший до этого файл не
+/// сообщается вовсе. `None` — события отдаются сразу.
+/// Применяется при следующем [`start_watching`].
+Future<void> setSettleQuietPeriod({int? quietPeriodMs}) =>
+    RustCore.instance.api.crateApiSetSettleQuietPeriod(
+      quietPeriodMs: quietPeriodMs,
+    );
+
+/// Сообщать об изменениях, случившихся, пока приложение было закрыто.
+///
+/// Watcher сохраняет снимок папки (имена, mtime и размеры файлов) в
+/// `{data_dir}/snapshots` во время работы и при остановке. При следующем
+/// запуске он сверяет папку со снимком и отдаёт расхождения пачкой в
+/// [`on_file_batch`] / [`on_file_event`] с `reconciled = true`. Первый запуск
+/// с включённой сверкой только запоминает состояние.
+/// Применяется при следующем [`start_watching`].
+Future<void> setOfflineChangeDetection({required bool enabled}) =>
+    RustCore.instance.api.crateApiSetOfflineChangeDetection(enabled: enabled);
+
+/// macOS: при запуске брать пропущенные изменения из истории FSEvents, а не
+/// обходить папку целиком.
+///
+/// Номер последнего события хранится рядом со снимком папки, поэтому
+/// работает только с [`set_offline_change_detection`]. Если история
+/// недоступна (первый запуск, журнал тома сброшен, другая ОС), папка
+/// сверяется со снимком как обычно. Применяется при следующем
+/// [`start_watching`].
+Future<void> setFseventsReplay({required bool enabled}) =>
+    RustCore.instance.api.crateApiSetFseventsReplay(enabled: enabled);
+
+/// Статистика активности по подпапкам watcher'а `watcher_id`.
+///
+/// Полезна в рекурсивном режиме ([`set_watch_recursive`]): видно, какие
+/// подпапки активны, чтобы закрепить или исключить их.
+Future<List<WatchTreeStat>> getWatchTreeStats({required String watcherId}) =>
+    RustCore.instance.api.crateApiGetWatchTreeStats(watcherId: watcherId);
+
+/// Счётчики потока событий watcher'а `watcher_id`.
+///
+/// События ФС проходят через ограниченную очередь: во время шторма
+/// событий лишние отбрасываются (`events_dropped`), а watcher затем сверяет
+/// папку и сообщает о пропущенных файлах пачкой.
+Future<WatcherMetrics> getWatcherMetrics({required String watcherId}) =>
+    RustCore.instance.api.crateApiGetWatcherMetrics(watcherId: watcherId);
+
+/// Метрики ядра в текстовом формате Prometheus (exposition 0.0.4).
+///
+/// Гистограммы задержки события (от времени события ФС до выдачи) и
+/// длительности callback'а подписчика, счётчики и глубина очереди каждого
+/// запущенного watcher'а (метка `watcher_id`). Приложение может отдавать
+/// этот текст с локального debug-эндпоинта для Prometheus.
+Future<String> exportMetricsText() =>
+    RustCore.instance.api.crateApiExportMetricsText();
+
+/// Писать логи watcher'а в отдельный ротируемый файл.
+///
+/// Логи watcher'а идут под target `latera::watcher::{watcher_id}`
+/// (`watcher_id` возвращает [`start_watching`]). При `enabled = true`
+/// они дополнительно пишутся в `{data_dir}/logs/watcher-{watcher_id}.log`
+/// (ротация по 5 МБ, 3 архива в gzip, не более 20 МБ суммарно).
+///
+/// Возвращает путь к лог-файлу watcher'а.
+Future<String> setWatcherLogFile({
+  required String watcherId,
+  required bool enabled,
+}) => RustCore.instance.api.crateApiSetWatcherLogFile(
+  watcherId: watcherId,
+  enabled: enabled,
+);
 
 /// Получить дефолтный путь наблюдения (Desktop/Latera).
 ///
-/// Создаёт директорию, если она не существует.
+/// Создаёт директорию, если она не существует, и помечает её маркером
+/// `.latera-folder.json` (см. [`get_folder_marker`]). Занятое файлом или чужой папкой имя пропускается —
+/// возвращается фактический путь (`Desktop/Latera (2)`, …).
 /// Не запускает watcher — только возвращает путь.
 ///
 /// Используется для:
@@ -57,21 +438,503 @@ Future<String> getDefaultWatchPath() =>
 
 /// Получить дефолтный путь наблюдения (Desktop/Latera) **без** создания директории.
 ///
-/// Важно: функция не трогает файловую систему и не создаёт папку.
+/// Если `Desktop/Latera` — файл или чужая папка (без маркера Latera и не
+/// пустая), возвращается следующее свободное имя: `Latera (2)`, … — та же
+/// папка, которую создаст [`get_default_watch_path`] и будет наблюдать
+/// [`start_watching`] без пути.
+///
+/// Важно: функция не создаёт папку.
 /// Используется в онбординге для preview до явного согласия пользователя.
 Future<String> getDefaultWatchPathPreview() =>
     RustCore.instance.api.crateApiGetDefaultWatchPathPreview();
 
+/// Маркер папки `path` (`.latera-folder.json`).
+///
+/// По маркеру приложение узнаёт папку, созданную Latera до переустановки:
+/// `folder_id` тот же, `settings_match` показывает, совпадают ли настройки
+/// наблюдения с прежними.
+Future<FolderMarkerInfo> getFolderMarker({required String path}) =>
+    RustCore.instance.api.crateApiGetFolderMarker(path: path);
+
+/// Записать маркер в папку `path` или починить его.
+///
+/// Читаемый маркер сохраняет `folder_id` и время создания, отпечаток
+/// настроек обновляется до текущего. Повреждённый или отсутствующий маркер
+/// создаётся заново (с новым `folder_id`). В режиме «только наблюдение» —
+/// [`LateraError::ReadOnlyMode`].
+Future<FolderMarkerInfo> repairFolderMarker({required String path}) =>
+    RustCore.instance.api.crateApiRepairFolderMarker(path: path);
+
+/// Варианты папки наблюдения от лучшего к худшему: Desktop/Latera,
+/// Downloads, Documents/Latera; недоступные для записи и на томах с
+/// нехваткой места — в конце.
+///
+/// Как и [`get_default_watch_path_preview`], ничего не создаёт на диске.
+Future<List<WatchLocationSuggestion>> suggestWatchLocations() =>
+    RustCore.instance.api.crateApiSuggestWatchLocations();
+
+/// Есть ли у приложения доступ к «Рабочему столу».
+///
+/// На macOS доступ выдаёт пользователь (System Settings → Privacy &
+/// Security → Files and Folders); если он ещё не отвечал, проверка покажет
+/// системный запрос. Онбординг может опрашивать функцию, пока пользователь
+/// выдаёт разрешение.
+Future<DesktopAccessStatus> getDesktopAccessStatus() =>
+    RustCore.instance.api.crateApiGetDesktopAccessStatus();
+
+/// Контролируемый доступ к папкам Windows и как разрешить Latera запись.
+///
+/// Когда он включён, запуск watcher'а в защищённой папке возвращает
+/// `LateraError::ControlledFolderAccessBlocked`; UI показывает `steps` и
+/// кнопку, открывающую `settings_uri`.
+Future<ControlledFolderAccessInfo> getControlledFolderAccessInfo() =>
+    RustCore.instance.api.crateApiGetControlledFolderAccessInfo();
+
 /// Получить путь, где будет храниться индекс (локально на устройстве).
 ///
 /// Важно: функция **не** создаёт директорию.
 /// Нужна, чтобы прозрачно показать пользователю, где лежат служебные данные.
 ///
-/// Путь вычисляется через OS-provided local app data directory (MSIX/sandbox safe).
+/// Путь вычисляется от папки данных, выбранной в [`init_core`]
+/// (по умолчанию — OS-provided local app data directory, MSIX/sandbox safe).
 Future<String> getIndexPath() => RustCore.instance.api.crateApiGetIndexPath();
 
-/// Остановить мониторинг (graceful shutdown).
-Future<void> stopWatching() => RustCore.instance.api.crateApiStopWatching();
+/// Остановить watcher `watcher_id` (graceful shutdown).
+///
+/// Когда останавливается последний watcher, streams событий закрываются
+/// (onDone во Flutter).
+///
+/// Поток watcher'а ждём не дольше 5 секунд: если он завис, watcher всё
+/// равно снимается, а возвращается `LateraError::ShutdownTimedOut`.
+Future<void> stopWatching({required String watcherId}) =>
+    RustCore.instance.api.crateApiStopWatching(watcherId: watcherId);
+
+/// Stream событий добавления файла с подтверждением.
+///
+/// В Dart это будет выглядеть как `Stream<AckableFileEvent> onFileAddedAckable()`.
+/// События приходят только при включённом [`set_ack_mode`]; каждое нужно
+/// подтвердить через [`ack_event`] после обработки.
+Stream<AckableFileEvent> onFileAddedAckable() =>
+    RustCore.instance.api.crateApiOnFileAddedAckable();
+
+/// Включить/выключить режим подтверждаемой доставки.
+///
+/// - `enabled = true`: событие остаётся в WAL до [`ack_event`]; если
+///   подтверждение не пришло за `redelivery_timeout_ms`
+///   (по умолчанию 30 с), событие отправляется повторно.
+/// - `enabled = false`: неподтверждённые события остаются в WAL и будут
+///   доставлены обычным stream'ом при следующем [`start_watching`].
+///
+/// Dart должен быть готов к дубликатам (at-least-once). Без WAL
+/// подтверждать нечего — если его не удалось открыть, возвращается ошибка.
+Future<void> setAckMode({required bool enabled, int? redeliveryTimeoutMs}) =>
+    RustCore.instance.api.crateApiSetAckMode(
+      enabled: enabled,
+      redeliveryTimeoutMs: redeliveryTimeoutMs,
+    );
+
+/// Подтвердить обработку события из [`on_file_added_ackable`].
+///
+/// Повторное подтверждение (например, для дубликата) безопасно.
+Future<void> ackEvent({required int sequence}) =>
+    RustCore.instance.api.crateApiAckEvent(sequence: sequence);
+
+/// Включить отладочный контекст событий для разбора обращений в поддержку.
+///
+/// Watcher запоминает сырые события `notify`, решения дедупликации и
+/// разбивку по времени для каждого события [`on_file_event`]; контекст
+/// последних событий отдаёт [`get_event_debug_info`]. Стоит памяти и
+/// времени на каждое событие — по умолчанию выключено.
+/// Применяется при следующем [`start_watching`].
+Future<void> setEventDebugEnrichment({required bool enabled}) =>
+    RustCore.instance.api.crateApiSetEventDebugEnrichment(enabled: enabled);
+
+/// Отладочный контекст события [`FileEvent::sequence`].
+///
+/// `None` — контекст не собирался (см. [`set_event_debug_enrichment`]) или
+/// событие слишком старое: хранятся последние 1000.
+Future<EventDebugInfo?> getEventDebugInfo({required int sequence}) =>
+    RustCore.instance.api.crateApiGetEventDebugInfo(sequence: sequence);
+
+/// Stream пульса ядра.
+///
+/// В Dart это будет выглядеть как `Stream<HeartbeatEvent> onHeartbeat()`.
+/// Пульс приходит каждые `interval_ms` (по умолчанию 5 с). Если пульс не
+/// пришёл за несколько интервалов — ядро упало или зависло, и UI должен
+/// предложить перезапустить мониторинг.
+///
+/// Контракт как у [`on_file_added`]: один активный подписчик; stream
+/// закрывается при [`shutdown_core`].
+Stream<HeartbeatEvent> onHeartbeat({int? intervalMs}) =>
+    RustCore.instance.api.crateApiOnHeartbeat(intervalMs: intervalMs);
+
+/// Stream состояний watcher'ов.
+///
+/// В Dart это будет выглядеть как `Stream<WatcherStatusEvent> onWatcherStatus()`.
+/// Сразу после подписки приходит текущее состояние каждого watcher'а, затем —
+/// каждая смена состояния (`heartbeat == false`) и раз в `interval_ms`
+/// (по умолчанию 5 с) пульс с текущим состоянием (`heartbeat == true`).
+/// Если backend `notify` умер, приходит [`ApiWatcherState::Restarting`] на
+/// каждую попытку перезапуска, при переходе на опрос или ручное обновление —
+/// [`ApiWatcherState::Fallback`], а если перезапуск невозможен —
+/// [`ApiWatcherState::Error`]: UI не должен продолжать показывать
+/// «наблюдение идёт».
+///
+/// Контракт как у [`on_file_added`]: один активный подписчик; stream
+/// закрывается при [`shutdown_core`].
+Stream<WatcherStatusEvent> onWatcherStatus({int? intervalMs}) =>
+    RustCore.instance.api.crateApiOnWatcherStatus(intervalMs: intervalMs);
+
+/// Stream статусных событий наблюдаемой папки.
+///
+/// В Dart это будет выглядеть как `Stream<WatchStatusEvent> onWatchStatus()`.
+/// Контракт как у [`on_file_added`]: один активный подписчик; stream
+/// закрывается при [`stop_watching`] / [`shutdown_core`].
+Stream<WatchStatusEvent> onWatchStatus() =>
+    RustCore.instance.api.crateApiOnWatchStatus();
+
+/// Настроить порог свободного места на томе с watch dir.
+///
+/// - `threshold_bytes`: ниже этого значения отправляется
+///   [`WatchStatusEvent::LowDiskSpace`] и копирование в watch dir приостанавливается;
+/// - `check_interval_ms`: период проверки (по умолчанию 30 с, минимум 1 с).
+///
+/// Если watcher запущен, мониторинг перезапускается с новыми настройками.
+Future<void> setLowDiskSpaceThreshold({
+  required int thresholdBytes,
+  int? checkIntervalMs,
+}) => RustCore.instance.api.crateApiSetLowDiskSpaceThreshold(
+  thresholdBytes: thresholdBytes,
+  checkIntervalMs: checkIntervalMs,
+);
+
+/// Задать квоту наблюдаемой папки.
+///
+/// - `max_total_bytes` / `max_file_count`: лимиты (`None` — без ограничения;
+///   оба `None` — квота отключена);
+/// - `cleanup_oldest`: при превышении удалять самые старые файлы, пока папка
+///   не уложится в квоту (недоступно в режиме «только наблюдение»).
+///
+/// При превышении отправляется [`WatchStatusEvent::QuotaExceeded`]. Если
+/// watcher запущен, проверка перезапускается с новой квотой. Лимиты
+/// политики администратора действуют поверх заданных здесь: применяется
+/// меньший из двух.
+Future<void> setFolderQuota({
+  int? maxTotalBytes,
+  int? maxFileCount,
+  required bool cleanupOldest,
+}) => RustCore.instance.api.crateApiSetFolderQuota(
+  maxTotalBytes: maxTotalBytes,
+  maxFileCount: maxFileCount,
+  cleanupOldest: cleanupOldest,
+);
+
+/// Свободное место (в байтах) на томе с папкой watcher'а `watcher_id`.
+Future<int> getWatchVolumeFreeSpace({required String watcherId}) =>
+    RustCore.instance.api.crateApiGetWatchVolumeFreeSpace(watcherId: watcherId);
+
+/// Свободное место (в байтах) на томе, где находится `path`.
+///
+/// В отличие от [`get_watch_volume_free_space`], не требует запущенного
+/// watcher'а: например, чтобы проверить место перед выбором папки Latera.
+/// О нехватке места во время работы сообщает
+/// [`WatchStatusEvent::LowDiskSpace`] (см. [`set_low_disk_space_threshold`]).
+Future<int> getFreeSpace({required String path}) =>
+    RustCore.instance.api.crateApiGetFreeSpace(path: path);
+
+/// Размер папки `path` со всеми вложенными (обход в несколько потоков).
+///
+/// Вызывать не из UI-потока: большая папка считается секунды.
+Future<DirSizeInfo> getDirSize({required String path}) =>
+    RustCore.instance.api.crateApiGetDirSize(path: path);
+
+/// Том, на котором лежит папка `path`, и умолчания наблюдения для него.
+///
+/// Watcher применяет эти умолчания сам: на сетевом диске и в папке
+/// облачного клиента файлы сообщаются после стабилизации, опрос реже, а
+/// хэширование выключено (хэш файла «только в облаке» скачал бы его).
+/// Явно заданные [`set_settle_quiet_period`] и период опроса не меняются.
+Future<VolumeInfo> getVolumeInfo({required String path}) =>
+    RustCore.instance.api.crateApiGetVolumeInfo(path: path);
+
+/// Приостановлено ли копирование в watch dir из-за нехватки места.
+Future<bool> isCopyInPaused() => RustCore.instance.api.crateApiIsCopyInPaused();
+
+/// Статус обработки файла. `None` — файл неизвестен ядру.
+Future<FileProcessingStatus?> getFileStatus({required String path}) =>
+    RustCore.instance.api.crateApiGetFileStatus(path: path);
+
+/// Установить статус обработки файла.
+///
+/// Если статус изменился, отправляется [`FileStatusChangedEvent`].
+Future<void> setFileStatus({
+  required String path,
+  required FileProcessingStatus status,
+}) => RustCore.instance.api.crateApiSetFileStatus(path: path, status: status);
+
+/// Установить статусы нескольких файлов за один вызов.
+///
+/// Все обновления применяются одной транзакцией хранилища: либо все,
+/// либо (при ошибке) ни одного. По каждому изменившемуся статусу
+/// отправляется [`FileStatusChangedEvent`].
+Future<void> setFileStatuses({required List<FileStatusUpdate> updates}) =>
+    RustCore.instance.api.crateApiSetFileStatuses(updates: updates);
+
+/// Stream изменений статусов файлов.
+///
+/// В Dart это будет выглядеть как `Stream<FileStatusChangedEvent> onFileStatusChanged()`.
+/// Один активный подписчик; stream закрывается при [`shutdown_core`].
+Stream<FileStatusChangedEvent> onFileStatusChanged() =>
+    RustCore.instance.api.crateApiOnFileStatusChanged();
+
+/// Пометить файл тегом.
+///
+/// Теги привязаны к содержимому файла: переживают переименование и перенос
+/// и общие у копий с одинаковым содержимым.
+Future<void> addTag({required String path, required String tag}) =>
+    RustCore.instance.api.crateApiAddTag(path: path, tag: tag);
+
+/// Снять тег. `false` — файл не был помечен этим тегом.
+Future<bool> removeTag({required String path, required String tag}) =>
+    RustCore.instance.api.crateApiRemoveTag(path: path, tag: tag);
+
+/// Теги файла по алфавиту.
+Future<List<String>> listTags({required String path}) =>
+    RustCore.instance.api.crateApiListTags(path: path);
+
+/// Известные ядру файлы с тегом `tag`.
+Future<List<String>> queryByTag({required String tag}) =>
+    RustCore.instance.api.crateApiQueryByTag(tag: tag);
+
+/// Включить фоновую пре-генерацию превью новых файлов.
+///
+/// Каждый новый файл, как только перестанет меняться, получает текстовое
+/// превью (PDF, DOCX, текст) в кэше ядра — галерея читает его через
+/// [`get_text_preview`] без генерации на лету. Миниатюры изображений —
+/// [`set_thumbnail_pregeneration`]. По умолчанию выключено.
+Future<void> setPreviewPregeneration({required bool enabled}) =>
+    RustCore.instance.api.crateApiSetPreviewPregeneration(enabled: enabled);
+
+/// Включена ли пре-генерация превью.
+Future<bool> isPreviewPregenerationEnabled() =>
+    RustCore.instance.api.crateApiIsPreviewPregenerationEnabled();
+
+/// Текстовое превью файла из кэша. `None` — превью ещё не готово или
+/// формат не поддерживается.
+Future<String?> getTextPreview({required String path}) =>
+    RustCore.instance.api.crateApiGetTextPreview(path: path);
+
+/// Включить фоновую генерацию миниатюр новых изображений (PNG, JPEG).
+///
+/// Миниатюры размера по умолчанию (256 px по длинной стороне) кладутся в
+/// кэш приложения, и [`get_thumbnail`] отвечает без декодирования
+/// оригинала. По умолчанию выключено.
+Future<void> setThumbnailPregeneration({required bool enabled}) =>
+    RustCore.instance.api.crateApiSetThumbnailPregeneration(enabled: enabled);
+
+/// Включена ли генерация миниатюр.
+Future<bool> isThumbnailPregenerationEnabled() =>
+    RustCore.instance.api.crateApiIsThumbnailPregenerationEnabled();
+
+/// Миниатюра изображения (PNG, JPEG) с длинной стороной не больше
+/// `max_edge` (`None` — 256, максимум 2048): из кэша или построенная сейчас.
+///
+/// Построение декодирует оригинал — вызывать в background isolate.
+Future<ApiThumbnail> getThumbnail({required String path, int? maxEdge}) =>
+    RustCore.instance.api.crateApiGetThumbnail(path: path, maxEdge: maxEdge);
+
+/// Байты миниатюры (как [`get_thumbnail`]) — для `Image.memory`, когда
+/// у приложения нет доступа к кэшу ядра.
+Future<Uint8List> getThumbnailBytes({required String path, int? maxEdge}) =>
+    RustCore.instance.api.crateApiGetThumbnailBytes(
+      path: path,
+      maxEdge: maxEdge,
+    );
+
+/// Миниатюра (как [`get_thumbnail_bytes`]) без копирования через FFI.
+///
+/// Повторный запрос той же миниатюры, пока прежний [`ImageBuffer`] жив,
+/// возвращает тот же буфер.
+Future<ImageBuffer> getThumbnailBuffer({required String path, int? maxEdge}) =>
+    RustCore.instance.api.crateApiGetThumbnailBuffer(
+      path: path,
+      maxEdge: maxEdge,
+    );
+
+/// Хэш содержимого файла (hex, нижний регистр).
+///
+/// Читает файл целиком — для больших файлов вызывать в background isolate.
+Future<String> computeHash({
+  required String path,
+  required HashAlgorithm algorithm,
+}) =>
+    RustCore.instance.api.crateApiComputeHash(path: path, algorithm: algorithm);
+
+/// Считать хэши появившихся и изменённых файлов в фоне.
+///
+/// Хэш приходит в [`on_file_hash`] отдельно от события файла, когда файл
+/// перестанет меняться. `None` — выключить (по умолчанию выключено).
+Future<void> setEventHashing({HashAlgorithm? algorithm}) =>
+    RustCore.instance.api.crateApiSetEventHashing(algorithm: algorithm);
+
+/// Stream хэшей файлов (см. [`set_event_hashing`]).
+Stream<FileHashEvent> onFileHash() =>
+    RustCore.instance.api.crateApiOnFileHash();
+
+/// Группы дубликатов в папке `dir` (рекурсивно), от занимающих больше
+/// всего лишнего места. Пустые файлы не учитываются.
+///
+/// Хэшируются только файлы с совпавшим размером, но для большой папки
+/// вызывать в background isolate.
+Future<List<DuplicateGroup>> findDuplicates({required String dir}) =>
+    RustCore.instance.api.crateApiFindDuplicates(dir: dir);
+
+/// Проверять появившиеся в наблюдаемых папках файлы на дубликаты.
+///
+/// Совпадения (тот же размер и BLAKE3) приходят в [`on_duplicate_detected`],
+/// когда файл перестанет меняться. По умолчанию выключено.
+Future<void> setDuplicateDetection({required bool enabled}) =>
+    RustCore.instance.api.crateApiSetDuplicateDetection(enabled: enabled);
+
+/// Включена ли проверка дубликатов.
+Future<bool> isDuplicateDetectionEnabled() =>
+    RustCore.instance.api.crateApiIsDuplicateDetectionEnabled();
+
+/// Stream найденных дубликатов (см. [`set_duplicate_detection`]).
+Stream<DuplicateDetected> onDuplicateDetected() =>
+    RustCore.instance.api.crateApiOnDuplicateDetected();
+
+/// Перенести файл `src` в `dst` (полный путь) в фоне.
+///
+/// Проверки (пути абсолютные, файл есть, `dst` свободен) выполняются сразу;
+/// ход и результат приходят в [`on_file_op_progress`]. Между томами файл
+/// копируется и исходник удаляется. Возвращает идентификатор операции.
+Future<int> moveFile({required String src, required String dst}) =>
+    RustCore.instance.api.crateApiMoveFile(src: src, dst: dst);
+
+/// Скопировать файл `src` в `dst` (полный путь) в фоне.
+///
+/// `overwrite` — заменить существующий `dst`. Копия появляется под
+/// настоящим именем только целиком. Возвращает идентификатор операции.
+Future<int> copyFile({
+  required String src,
+  required String dst,
+  required bool overwrite,
+}) => RustCore.instance.api.crateApiCopyFile(
+  src: src,
+  dst: dst,
+  overwrite: overwrite,
+);
+
+/// Переименовать файл в пределах его папки (`new_name` — только имя).
+///
+/// Возвращает идентификатор операции; результат — в [`on_file_op_progress`].
+Future<int> renameFile({required String path, required String newName}) =>
+    RustCore.instance.api.crateApiRenameFile(path: path, newName: newName);
+
+/// Перенести файл или папку в корзину системы (не удаляя безвозвратно).
+///
+/// Возвращает путь в корзине, если платформа его сообщает (macOS, Linux);
+/// на Windows — `None`. Записывается в журнал аудита.
+Future<String?> deleteToTrash({required String path}) =>
+    RustCore.instance.api.crateApiDeleteToTrash(path: path);
+
+/// Stream хода файловых операций.
+///
+/// В Dart это будет выглядеть как `Stream<FileOpEvent> onFileOpProgress()`.
+/// Один активный подписчик; stream закрывается при [`shutdown_core`].
+Stream<FileOpEvent> onFileOpProgress() =>
+    RustCore.instance.api.crateApiOnFileOpProgress();
+
+/// Найти QR-коды и штрихкоды (EAN-13, UPC-A, EAN-8) в изображении PNG/JPEG.
+///
+/// Декодирует изображение целиком — вызывать в background isolate.
+Future<List<ApiDetectedCode>> scanImageCodes({required String path}) =>
+    RustCore.instance.api.crateApiScanImageCodes(path: path);
+
+/// Искать коды в появившихся изображениях в фоне (сценарий «QR со счёта»).
+///
+/// Найденные коды приходят в [`on_file_event`] событием `CodesDetected`
+/// того же watcher'а вслед за появлением файла; изображения без кодов
+/// событий не дают. По умолчанию выключено.
+Future<void> setCodeScanning({required bool enabled}) =>
+    RustCore.instance.api.crateApiSetCodeScanning(enabled: enabled);
+
+Future<bool> isCodeScanningEnabled() =>
+    RustCore.instance.api.crateApiIsCodeScanningEnabled();
+
+/// Получить владельца файла и права текущего пользователя.
+///
+/// Позволяет UI заранее объяснить, почему действие с файлом не удастся.
+/// То же приходит готовым в `access` событий [`FileEvent`] и
+/// [`FileAddedEvent`].
+Future<ApiFileAccessInfo> getFileAccessInfo({required String path}) =>
+    RustCore.instance.api.crateApiGetFileAccessInfo(path: path);
+
+/// Держит ли файл открытым другой процесс (например, Excel).
+///
+/// Windows: файл не открывается без общего доступа; Unix: на файле есть
+/// рекомендательная блокировка (`fcntl` или эксклюзивная `flock`) —
+/// открытый без блокировки файл занятым не считается. Вызывать перед
+/// переносом или удалением файла.
+Future<bool> isFileLocked({required String path}) =>
+    RustCore.instance.api.crateApiIsFileLocked(path: path);
+
+/// Ждёт, пока файл освободится ([`is_file_locked`]), не дольше
+/// `timeout_ms`. `true` — свободен, `false` — всё ещё занят.
+///
+/// Блокирует поток — вызывать в background isolate.
+Future<bool> waitUntilUnlocked({
+  required String path,
+  required int timeoutMs,
+}) => RustCore.instance.api.crateApiWaitUntilUnlocked(
+  path: path,
+  timeoutMs: timeoutMs,
+);
+
+/// Прочитать расширенные атрибуты файла.
+///
+/// Linux: только пространство `user.` (префикс снимается);
+/// Windows: атрибуты, записанные через [`set_xattr`] (NTFS alternate data stream).
+Future<List<ApiXattr>> getXattrs({required String path}) =>
+    RustCore.instance.api.crateApiGetXattrs(path: path);
+
+/// Записать расширенный атрибут файла (например, `latera.processed`).
+///
+/// В режиме «только наблюдение» возвращает `LateraError::ReadOnlyMode`.
+Future<void> setXattr({
+  required String path,
+  required String name,
+  required Uint8List value,
+}) => RustCore.instance.api.crateApiSetXattr(
+  path: path,
+  name: name,
+  value: value,
+);
+
+/// Число и суммарный размер файлов папки (рекурсивно) по типам: картинки,
+/// документы, архивы, остальное. Тип определяется по содержимому.
+///
+/// Первый вызов для папки обходит её целиком — вызывать в background
+/// isolate. Дальше ядро обновляет итоги по событиям watcher'а, и повторный
+/// вызов отвечает сразу.
+Future<List<CategoryStats>> getFolderComposition({required String path}) =>
+    RustCore.instance.api.crateApiGetFolderComposition(path: path);
+
+/// Не больше `limit` самых больших файлов в папках запущенных watcher'ов,
+/// по убыванию размера.
+///
+/// Строится по индексу файлов (см. [`get_folder_composition`]): обход папки
+/// нужен только при первом запросе.
+Future<List<ReportFile>> getLargestFiles({required int limit}) =>
+    RustCore.instance.api.crateApiGetLargestFiles(limit: limit);
+
+/// Файлы в папках запущенных watcher'ов, не менявшиеся дольше
+/// `older_than_days` дней, от самых старых.
+///
+/// Строится по индексу файлов, как [`get_largest_files`].
+Future<List<ReportFile>> getStaleFiles({required int olderThanDays}) =>
+    RustCore.instance.api.crateApiGetStaleFiles(olderThanDays: olderThanDays);
 
 /// Инициализировать индексную БД.
 ///
@@ -82,6 +945,19 @@ Future<void> stopWatching() => RustCore.instance.api.crateApiStopWatching();
 Future<void> initIndex({required String dbPath}) =>
     RustCore.instance.api.crateApiInitIndex(dbPath: dbPath);
 
+/// Включить автоматическую индексацию наблюдаемых папок.
+///
+/// Новые и изменённые файлы индексируются в фоне вместе с содержимым
+/// (PDF, DOCX, текстовые форматы), переименованные — переносятся, удалённые —
+/// убираются из индекса; описание пользователя сохраняется. Требует
+/// [`init_index`]. По умолчанию выключено.
+Future<void> setAutoIndexing({required bool enabled}) =>
+    RustCore.instance.api.crateApiSetAutoIndexing(enabled: enabled);
+
+/// Включена ли автоиндексация.
+Future<bool> isAutoIndexingEnabled() =>
+    RustCore.instance.api.crateApiIsAutoIndexingEnabled();
+
 /// Индексировать файл с описанием пользователя.
 ///
 /// Автоматически извлекает текстовое содержимое из поддерживаемых форматов
@@ -98,7 +974,18 @@ Future<void> indexFileWithDescription({
   description: description,
 );
 
-/// Поиск файлов по запросу.
+/// Поиск файлов по содержимому, имени и описанию.
+///
+/// Использует FTS5 полнотекстовый поиск; результаты упорядочены по BM25
+/// рангу (наиболее релевантные первыми), `snippet` — фрагмент текста с
+/// совпадениями в `<b>…</b>`. Файлы наблюдаемых папок попадают в индекс
+/// сами при включённой [`set_auto_indexing`].
+Future<List<SearchResultItem>> search({
+  required String query,
+  required int limit,
+}) => RustCore.instance.api.crateApiSearch(query: query, limit: limit);
+
+/// Поиск файлов по запросу (то же, что [`search`]).
 ///
 /// Использует FTS5 полнотекстовый поиск по имени, описанию и содержимому.
 /// Результаты упорядочены по BM25 рангу (наиболее релевантные первыми).
@@ -135,6 +1022,50 @@ Future<ExtractionResult> extractTextFromFile({
   options: options,
 );
 
+/// Извлечь текст из PDF (текстовый слой), DOCX или текстового файла с
+/// лимитами по умолчанию (100 страниц, 50 МБ).
+///
+/// Упрощённый вариант [`extract_text_from_file`] для превью и поиска:
+/// ошибка — `LateraError` вместо кода в результате. PDF больше лимита
+/// страниц не ошибка: возвращается текст первых страниц.
+Future<String> extractText({required String path}) =>
+    RustCore.instance.api.crateApiExtractText(path: path);
+
+/// Количество страниц PDF.
+Future<int> getPdfPageCount({required String path}) =>
+    RustCore.instance.api.crateApiGetPdfPageCount(path: path);
+
+/// PNG-схемы раскладки выбранных страниц PDF (contact sheet).
+///
+/// `pages` — номера страниц с 1; пустой список — первые несколько страниц.
+/// `dpi` — `1..=300`, 72 = пиксель на пункт. Это не рендер страницы:
+/// векторная графика рисуется как есть, а текст и изображения —
+/// плейсхолдерами на своих местах (см. [`pdf_layout`](crate::pdf_layout)).
+/// Текст на схеме не читается.
+///
+/// Разбирает PDF целиком — вызывать в background isolate.
+Future<List<PdfPagePreview>> renderPdfPages({
+  required String path,
+  required Uint32List pages,
+  required int dpi,
+}) => RustCore.instance.api.crateApiRenderPdfPages(
+  path: path,
+  pages: pages,
+  dpi: dpi,
+);
+
+/// Схемы страниц PDF (как [`render_pdf_pages`]) без копирования PNG через FFI —
+/// в том же порядке, что и результат [`render_pdf_pages`].
+Future<List<ImageBuffer>> renderPdfPageBuffers({
+  required String path,
+  required Uint32List pages,
+  required int dpi,
+}) => RustCore.instance.api.crateApiRenderPdfPageBuffers(
+  path: path,
+  pages: pages,
+  dpi: dpi,
+);
+
 /// Транскрибировать аудио/видео файл.
 ///
 /// Использует Rust-side транскрибацию (Whisper.cpp) с уважением лимитов.
@@ -261,6 +1192,8 @@ Future<void> clearAllEmbeddings() =>
 /// Инициализировать генеративную LLM (llama.cpp, GGUF-модель).
 ///
 /// Загружает модель из `{data_dir}/models/qwen2.5-3b-instruct-q4_k_m.gguf`.
+/// Безопасен для повторного вызова — если модель уже загружена, вернёт Ok.
+///
 /// Тяжёлая операция (~1.7 ГБ в RAM) — вызывать в background isolate.
 Future<void> initLlm({required String dataDir}) =>
     RustCore.instance.api.crateApiInitLlm(dataDir: dataDir);
@@ -306,256 +1239,3659 @@ Future<OcrResult> ocrExtractText({
 Future<bool> isOcrSupported({required String path}) =>
     RustCore.instance.api.crateApiIsOcrSupported(path: path);
 
-/// Результат вычисления эмбеддинга (FRB bridge type).
-class ApiEmbeddingVector {
-  /// Индекс чанка.
-  final int chunkIndex;
+/// Включить/выключить анонимную телеметрию (строго opt-in, по умолчанию выключена).
+///
+/// `upload_url` — HTTPS endpoint для периодической отправки агрегатов.
+/// `None` = агрегаты только копятся локально.
+///
+/// При выключении все накопленные данные удаляются. Если отправка запрещена
+/// политикой администратора, `upload_url` — `LateraError::PolicyLocked`.
+Future<void> setTelemetryEnabled({required bool enabled, String? uploadUrl}) =>
+    RustCore.instance.api.crateApiSetTelemetryEnabled(
+      enabled: enabled,
+      uploadUrl: uploadUrl,
+    );
 
-  /// Вектор эмбеддинга (f32).
-  final Float32List vector;
+/// Включена ли телеметрия.
+Future<bool> isTelemetryEnabled() =>
+    RustCore.instance.api.crateApiIsTelemetryEnabled();
 
-  const ApiEmbeddingVector({required this.chunkIndex, required this.vector});
+/// Показать данные телеметрии, ожидающие отправки.
+///
+/// Для прозрачности: UI может показать пользователю, что именно будет отправлено.
+Future<TelemetryPreview> getPendingTelemetry() =>
+    RustCore.instance.api.crateApiGetPendingTelemetry();
 
-  @override
-  int get hashCode => chunkIndex.hashCode ^ vector.hashCode;
+/// Значение настройки ядра. `None` — не задано.
+///
+/// Повреждённый файл настроек (обрыв питания посреди записи) при открытии
+/// восстанавливается из резервной копии — приходит
+/// [`WatchStatusEvent::SettingsRecovered`].
+///
+/// Значение, заданное политикой администратора, важнее сохранённого.
+Future<String?> getSetting({required String key}) =>
+    RustCore.instance.api.crateApiGetSetting(key: key);
 
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ApiEmbeddingVector &&
-          runtimeType == other.runtimeType &&
-          chunkIndex == other.chunkIndex &&
-          vector == other.vector;
-}
+/// Сохранить настройку ядра.
+///
+/// Запись атомарна; предыдущие версии файла хранятся как резервные копии.
+/// Настройка, заданная политикой администратора, — `LateraError::PolicyLocked`.
+Future<void> setSetting({required String key, required String value}) =>
+    RustCore.instance.api.crateApiSetSetting(key: key, value: value);
+
+/// Удалить настройку ядра. Возвращает `true`, если она была задана.
+Future<bool> removeSetting({required String key}) =>
+    RustCore.instance.api.crateApiRemoveSetting(key: key);
+
+/// Взять под наблюдение папку, которой пользователь уже пользуется.
+///
+/// В фоне: обходит папку (с фильтром и настройками следующего
+/// [`start_watching`]), сохраняет её снимок для сверки при запуске (если
+/// включена [`set_offline_change_detection`]), индексирует содержимое
+/// (если открыт индекс, см. [`init_index`]; при [`set_auto_indexing`]
+/// индексирует очередь автоиндексации) и отдаёт каждый файл в
+/// [`on_file_batch`] пачками с `reconciled = true` — превью, дубликаты и
+/// правила срабатывают как для новых файлов. В конце папка помечается
+/// маркером ([`get_folder_marker`]).
+///
+/// Ход — в [`on_adoption_progress`]. Сам watcher не запускается.
+/// Возвращает идентификатор переноса.
+Future<int> adoptFolder({required String path, required bool recursive}) =>
+    RustCore.instance.api.crateApiAdoptFolder(path: path, recursive: recursive);
+
+/// Stream хода переносов папок.
+///
+/// В Dart это будет выглядеть как
+/// `Stream<AdoptionProgressEvent> onAdoptionProgress()`.
+/// Один активный подписчик; stream закрывается при [`shutdown_core`].
+Stream<AdoptionProgressEvent> onAdoptionProgress() =>
+    RustCore.instance.api.crateApiOnAdoptionProgress();
+
+/// Состояние мастера первого запуска: пройденные шаги и то, что ядро видит
+/// сейчас — есть ли папка, читается ли она, было ли первое событие.
+///
+/// Папку и доступ ядро проверяет при каждом вызове, так что мастер может
+/// опрашивать эту функцию, пока пользователь выдаёт разрешение в системных
+/// настройках.
+Future<OnboardingState> getOnboardingState() =>
+    RustCore.instance.api.crateApiGetOnboardingState();
+
+/// Завершить шаг мастера первого запуска. Возвращает новое состояние.
+///
+/// Завершить можно только текущий шаг и только когда он действительно
+/// выполнен (например, [`OnboardingStep::FirstEvent`] — после первого
+/// события watcher'а); иначе — `LateraError::InvalidArgument`. Повторное
+/// завершение пройденного шага ничего не меняет. Прогресс хранится в папке
+/// данных и переживает перезапуск.
+Future<OnboardingState> completeOnboardingStep({
+  required OnboardingStep step,
+}) => RustCore.instance.api.crateApiCompleteOnboardingStep(step: step);
+
+/// Постоянные настройки ядра этой машины.
+///
+/// В отличие от `set_*` (действуют до перезапуска) эти значения хранятся в
+/// папке конфигурации приложения и применяются при каждом [`init_core`].
+Future<ApiConfig> getConfig() => RustCore.instance.api.crateApiGetConfig();
+
+/// Изменить постоянные настройки ядра: сохраняет их и применяет (уровень
+/// логов — сразу, сглаживание и фильтр — при следующем [`start_watching`]).
+///
+/// Некорректное значение — `LateraError::InvalidArgument`, настройки не
+/// меняются. Возвращает новые настройки.
+Future<ApiConfig> updateConfig({required ApiConfigPatch patch}) =>
+    RustCore.instance.api.crateApiUpdateConfig(patch: patch);
+
+/// Запустить watcher'ы для папок из настроек (`watch_paths`).
+///
+/// Уже наблюдаемые папки пропускаются. Возвращает `watcher_id` запущенных
+/// watcher'ов; первая ошибка запуска прерывает обход.
+Future<List<String>> startConfiguredWatchers() =>
+    RustCore.instance.api.crateApiStartConfiguredWatchers();
+
+/// Какие параметры заблокированы политикой администратора.
+///
+/// Политика — машинный файл, раскатываемый через MDM/GPO (см.
+/// [`policy`](crate::policy)). UI показывает заблокированные параметры
+/// недоступными для изменения; API, меняющие их, возвращают
+/// `LateraError::PolicyLocked`.
+Future<PolicyStatus> getPolicyStatus() =>
+    RustCore.instance.api.crateApiGetPolicyStatus();
+
+/// Перечитать файл политики (например, после обновления через MDM).
+///
+/// Новые ограничения действуют для следующих вызовов API; запущенные
+/// watcher'ы не перезапускаются. Запрет отправки (`disable_uploads`)
+/// сразу останавливает уже настроенную отправку телеметрии.
+Future<PolicyStatus> reloadPolicy() =>
+    RustCore.instance.api.crateApiReloadPolicy();
+
+/// Сохранить конфигурацию ядра в переносимый JSON-файл `path`.
+///
+/// В файл попадают запущенные watcher'ы с их фильтрами и настройками,
+/// настройки следующих запусков, расписания (архивирование, квота,
+/// порог свободного места), режим «только наблюдение», префикс служебных
+/// файлов и настройки приложения ([`set_setting`]). Папка по умолчанию и
+/// пресеты сохраняются без путей: на другой машине они определяются заново.
+Future<void> exportConfig({required String path}) =>
+    RustCore.instance.api.crateApiExportConfig(path: path);
+
+/// Применить конфигурацию из файла [`export_config`] и запустить её watcher'ы.
+///
+/// Файл целиком проверяется до применения: при ошибке формата, glob-шаблона
+/// или пути (`LateraError::InvalidArgument`) ничего не меняется. Настройки
+/// приложения из файла добавляются к текущим, расписания заменяются.
+/// Уже запущенные watcher'ы не останавливаются; watcher, который не удалось
+/// запустить, попадает в [`ConfigImportResult::skipped_watchers`].
+Future<ConfigImportResult> importConfig({required String path}) =>
+    RustCore.instance.api.crateApiImportConfig(path: path);
+
+/// Отсортировать имена файлов «как у человека» по правилам локали.
+///
+/// Числа сравниваются по значению (`file2` раньше `file10`), регистр и
+/// диакритика различают имена только при равенстве остального, локаль
+/// (`"ru"`, `"sv-SE"`; `None` — без локали) задаёт место национальных букв.
+/// UI сортирует этой функцией, чтобы порядок совпадал со списками ядра.
+Future<List<String>> sortFileNames({
+  required List<String> names,
+  String? locale,
+}) => RustCore.instance.api.crateApiSortFileNames(names: names, locale: locale);
+
+/// События из журнала, обнаруженные не раньше `since_ms` (Unix timestamp в
+/// миллисекундах), в порядке записи; не больше `limit`.
+///
+/// Журнал пишется в папку данных ядра и переживает перезапуск — по нему
+/// приложение восстанавливает историю.
+Future<List<ApiJournalEntry>> queryEvents({
+  required int sinceMs,
+  required int limit,
+}) => RustCore.instance.api.crateApiQueryEvents(sinceMs: sinceMs, limit: limit);
+
+/// Очистить журнал событий. Возвращает число удалённых записей.
+Future<int> clearJournal() => RustCore.instance.api.crateApiClearJournal();
+
+/// Активность по журналу событий в `[from_ms, to_ms)` для графика.
+///
+/// Корзины отсчитываются от `from_ms` — для суток по местному времени
+/// передайте местную полночь. Возвращаются все корзины, в том числе пустые;
+/// события, сделанные самим ядром, не учитываются.
+Future<List<ActivityBucket>> getActivityTimeline({
+  required int fromMs,
+  required int toMs,
+  required TimelineGranularity granularity,
+}) => RustCore.instance.api.crateApiGetActivityTimeline(
+  fromMs: fromMs,
+  toMs: toMs,
+  granularity: granularity,
+);
+
+/// Сверить поисковый индекс, журнал событий и содержимое папок запущенных
+/// watcher'ов.
+///
+/// Возвращает найденные расхождения. С `repair = true` ядро их исправляет:
+/// удаляет из индекса записи об исчезнувших файлах и сообщает недостающие
+/// события (удаление — для исчезнувших файлов, появление — для неизвестных)
+/// в обычные stream'ы с `reconciled = true`. Обходит папки целиком —
+/// вызывать в background isolate.
+Future<List<IntegrityIssue>> verifyFolderIntegrity({required bool repair}) =>
+    RustCore.instance.api.crateApiVerifyFolderIntegrity(repair: repair);
+
+/// Включить (или выключить — `None`) перенос старых файлов в архив
+/// `Archive/YYYY/MM/` по расписанию.
+///
+/// Год и месяц берутся из даты изменения файла. Каждый перенос
+/// записывается: найти файл по исходному пути — [`find_archived_file`].
+/// Недоступно в режиме «только наблюдение». Если watcher запущен,
+/// архивирование перезапускается с новыми настройками (первый проход — сразу).
+Future<void> setArchiveOptions({ArchiveOptions? options}) =>
+    RustCore.instance.api.crateApiSetArchiveOptions(options: options);
+
+/// Архивировать старые файлы папки watcher'а `watcher_id` сейчас, не дожидаясь
+/// расписания. Требует настроек [`set_archive_options`]; возвращает перенесённые файлы.
+Future<List<ApiArchivedFile>> archiveOldFilesNow({required String watcherId}) =>
+    RustCore.instance.api.crateApiArchiveOldFilesNow(watcherId: watcherId);
+
+/// Где теперь лежит файл, перенесённый из `original_path` (последний перенос).
+///
+/// `None` — файл не архивировался.
+Future<ApiArchivedFile?> findArchivedFile({required String originalPath}) =>
+    RustCore.instance.api.crateApiFindArchivedFile(originalPath: originalPath);
+
+/// Последние `limit` переносов в архив (от новых к старым).
+Future<List<ApiArchivedFile>> listArchivedFiles({required int limit}) =>
+    RustCore.instance.api.crateApiListArchivedFiles(limit: limit);
+
+/// Журнал аудита: что ядро само сделало с файлами пользователя (перенос,
+/// переименование, корзина, удаление, отправка, карантин), когда и по
+/// какому правилу.
+///
+/// Записи от новых к старым. Журнал хранится в папке данных ядра и только
+/// дописывается.
+Future<List<ApiAuditEntry>> getAuditLog({required AuditLogFilter filter}) =>
+    RustCore.instance.api.crateApiGetAuditLog(filter: filter);
+
+/// Включить пробный запуск: автоматические действия (перенос по пресету
+/// приёма, архивирование, очистка по квоте) не выполняются, а сообщаются в
+/// [`on_dry_run_action`] и в лог.
+///
+/// Позволяет проверить настройки до включения. Действует сразу для всех
+/// watcher'ов и расписаний; в журнал аудита пропущенные действия не попадают.
+Future<void> setDryRunMode({required bool enabled}) =>
+    RustCore.instance.api.crateApiSetDryRunMode(enabled: enabled);
+
+/// Включён ли пробный запуск.
+Future<bool> isDryRunMode() => RustCore.instance.api.crateApiIsDryRunMode();
+
+/// Stream действий, пропущенных из-за пробного запуска.
+///
+/// В Dart это будет выглядеть как `Stream<DryRunActionEvent> onDryRunAction()`.
+/// Один активный подписчик; stream закрывается при [`shutdown_core`].
+Stream<DryRunActionEvent> onDryRunAction() =>
+    RustCore.instance.api.crateApiOnDryRunAction();
+
+/// Добавить правило в конец списка или заменить правило с тем же `id`.
+///
+/// Для каждого появившегося файла выполняется первое подходящее правило.
+/// Перенос и переименование пишутся в журнал аудита как `rules.{id}`; при
+/// пробном запуске приходят в [`on_dry_run_action`], а файл не трогается.
+/// Действует сразу для всех watcher'ов.
+Future<void> setFileRule({required FileRule rule}) =>
+    RustCore.instance.api.crateApiSetFileRule(rule: rule);
+
+/// Удалить правило. `false` — правила с таким `id` не было.
+Future<bool> removeFileRule({required String ruleId}) =>
+    RustCore.instance.api.crateApiRemoveFileRule(ruleId: ruleId);
+
+/// Правила в порядке проверки.
+Future<List<FileRule>> listFileRules() =>
+    RustCore.instance.api.crateApiListFileRules();
+
+/// Stream выполненных правил.
+///
+/// В Dart это будет выглядеть как `Stream<RuleAppliedEvent> onRuleApplied()`.
+/// Один активный подписчик; stream закрывается вместе с остальными streams
+/// watcher'ов.
+Stream<RuleAppliedEvent> onRuleApplied() =>
+    RustCore.instance.api.crateApiOnRuleApplied();
+
+/// Является ли файл письмом (`.eml` или `.msg`).
+Future<bool> isEmailFile({required String path}) =>
+    RustCore.instance.api.crateApiIsEmailFile(path: path);
+
+/// Метаданные письма `.eml`/`.msg`: тема, отправитель, получатели, дата и
+/// список вложений.
+Future<ApiEmailMetadata> getEmailMetadata({required String path}) =>
+    RustCore.instance.api.crateApiGetEmailMetadata(path: path);
+
+/// Сохранить вложения письма в `target_dir` (по умолчанию — папка письма).
+/// Возвращает пути созданных файлов.
+///
+/// Созданные файлы не отмечаются как изменения ядра: в папке наблюдения
+/// они приходят обычными событиями `Created`.
+Future<List<String>> extractEmailAttachments({
+  required String path,
+  String? targetDir,
+}) => RustCore.instance.api.crateApiExtractEmailAttachments(
+  path: path,
+  targetDir: targetDir,
+);
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CoreHandle>>
+/// Экземпляр ядра для одного окна Flutter или теста (FRB opaque type).
+///
+/// Принадлежит handle'у:
+/// - watcher'ы: окно получает события только своих папок, а освобождённый
+///   handle останавливает свои watcher'ы, не трогая чужие;
+/// - streams событий файлов (`on_file_*`);
+/// - хранилища, в которые пишут его watcher'ы (статусы, теги, журнал), —
+///   свои соединения в папке данных handle'а;
+/// - настройки следующих watcher'ов: снимок настроек ядра на момент
+///   создания, дальше меняются только через handle.
+///
+/// Общее для процесса, как у функций без handle'а (они продолжают
+/// работать): индекс, настройки и WAL событий в папке данных ядра;
+/// остальные streams.
+abstract class CoreHandle implements RustOpaqueInterface {
+  /// Возможности ядра на момент создания handle'а; `data_dir` — папка
+  /// хранилищ handle'а.
+  Future<CoreCapabilities> capabilities();
+
+  /// Сглаживание событий для следующих watcher'ов этого handle'а;
+  /// запущенные не меняются. Некорректное значение —
+  /// `LateraError::InvalidArgument`, прежнее остаётся.
+  Future<void> setWatchTuning({required WatchTuning tuning});
+
+  /// Как [`set_watch_filter`], для следующих watcher'ов этого handle'а.
+  Future<void> setWatchFilter({WatchFilter? filter});
+
+  /// Как [`set_watch_recursive`], для следующих watcher'ов этого handle'а.
+  Future<void> setWatchRecursive({required bool enabled});
+
+  /// Как [`start_watching`], но с настройками этого handle'а, а события
+  /// приходят в его streams и хранилища. `tuning` переопределяет
+  /// [`CoreHandle::set_watch_tuning`] по полям.
+  ///
+  /// Папку, которую уже наблюдает любой watcher процесса, повторно не
+  /// наблюдают — `LateraError::WatcherAlreadyRunning`.
+  Future<WatchStarted> startWatching({
+    String? overridePath,
+    WatchTuning? tuning,
+  });
+
+  /// Как [`stop_watching`], только для watcher'ов этого handle'а: чужой
+  /// `watcher_id` — `LateraError::WatcherNotRunning`. Со своим последним
+  /// watcher'ом закрываются streams handle'а.
+  Future<void> stopWatching({required String watcherId});
+
+  /// Запущенные watcher'ы этого handle'а (как [`list_watchers`]).
+  Future<List<WatcherInfo>> listWatchers();
+
+  /// Как [`on_file_added`], для watcher'ов этого handle'а. Без подписчика
+  /// события не копятся: WAL и подтверждения — только у общего stream.
+  Stream<FileAddedEvent> onFileAdded();
+
+  /// Как [`on_file_event`], для watcher'ов этого handle'а.
+  Stream<FileEvent> onFileEvent();
+
+  /// Как [`on_file_batch`], для watcher'ов этого handle'а.
+  Stream<FileBatchEvent> onFileBatch();
+
+  /// Как [`on_file_chunks`], для watcher'ов этого handle'а.
+  Stream<FileChunkEvent> onFileChunks();
+
+  /// Как [`get_file_status`], из хранилища этого handle'а.
+  Future<FileProcessingStatus?> getFileStatus({required String path});
+
+  /// Как [`list_tags`], из хранилища этого handle'а.
+  Future<List<String>> listTags({required String path});
+
+  /// Как [`query_events`], из журнала этого handle'а.
+  Future<List<ApiJournalEntry>> queryEvents({
+    required int sinceMs,
+    required int limit,
+  });
+
+  /// Останавливает watcher'ы handle'а (ждёт каждый не дольше 5 секунд),
+  /// закрывает его streams и хранилища; handle можно использовать дальше.
+  Future<void> close();
+}
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ImageBuffer>>
+/// Изображение в памяти ядра (FRB opaque type).
+///
+/// Через FFI передаётся только handle; буфер освобождается финализатором
+/// Dart (или `dispose`) вместе с последним handle'ом. Байты копируются в
+/// Dart только по запросу ([`ImageBuffer::to_bytes`]), метаданные — без
+/// копирования изображения.
+abstract class ImageBuffer implements RustOpaqueInterface {
+  /// Длина в байтах.
+  Future<int> len();
+
+  Future<bool> isEmpty();
+
+  /// `image/png` или `image/jpeg`.
+  Future<String> mimeType();
+
+  Future<int> width();
+
+  Future<int> height();
+
+  /// Копия байтов — когда нужен собственный `Uint8List`.
+  Future<Uint8List> toBytes();
+}
+
+/// Событие добавления файла, ожидающее подтверждения через [`ack_event`].
+class AckableFileEvent {
+  /// Номер события в WAL — передаётся в [`ack_event`].
+  final int sequence;
+  final String fileName;
+  final String fullPath;
+  final int occurredAtMs;
+  final int detectedAtMs;
+  final int monotonicMs;
+  final int createdAtMs;
+  final int modifiedAtMs;
+
+  /// Размер файла в байтах (как в [`FileAddedEvent::size_bytes`]).
+  final int sizeBytes;
+
+  /// Расширение в нижнем регистре, без точки.
+  final String? extension;
+
+  /// MIME-тип по содержимому (`None` — файл пуст или не читается).
+  final String? mimeType;
+
+  /// Владелец и права текущего пользователя (`None` — не удалось прочитать).
+  final ApiFileAccessInfo? access;
+
+  const AckableFileEvent({
+    required this.sequence,
+    required this.fileName,
+    required this.fullPath,
+    required this.occurredAtMs,
+    required this.detectedAtMs,
+    required this.monotonicMs,
+    required this.createdAtMs,
+    required this.modifiedAtMs,
+    required this.sizeBytes,
+    this.extension,
+    this.mimeType,
+    this.access,
+  });
+
+  @override
+  int get hashCode =>
+      sequence.hashCode ^
+      fileName.hashCode ^
+      fullPath.hashCode ^
+      occurredAtMs.hashCode ^
+      detectedAtMs.hashCode ^
+      monotonicMs.hashCode ^
+      createdAtMs.hashCode ^
+      modifiedAtMs.hashCode ^
+      sizeBytes.hashCode ^
+      extension.hashCode ^
+      mimeType.hashCode ^
+      access.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AckableFileEvent &&
+          runtimeType == other.runtimeType &&
+          sequence == other.sequence &&
+          fileName == other.fileName &&
+          fullPath == other.fullPath &&
+          occurredAtMs == other.occurredAtMs &&
+          detectedAtMs == other.detectedAtMs &&
+          monotonicMs == other.monotonicMs &&
+          createdAtMs == other.createdAtMs &&
+          modifiedAtMs == other.modifiedAtMs &&
+          sizeBytes == other.sizeBytes &&
+          extension == other.extension &&
+          mimeType == other.mimeType &&
+          access == other.access;
+}
+
+/// Активность за интервал `[start_ms, end_ms)` (см. [`get_activity_timeline`]).
+class ActivityBucket {
+  final int startMs;
+  final int endMs;
+  final int eventCount;
+
+  /// Разные файлы, которых касались события.
+  final int fileCount;
+
+  /// Суммарный размер появившихся и изменённых файлов.
+  final int bytes;
+
+  /// Самые частые типы файлов, по убыванию.
+  final List<TypeCount> topTypes;
+
+  const ActivityBucket({
+    required this.startMs,
+    required this.endMs,
+    required this.eventCount,
+    required this.fileCount,
+    required this.bytes,
+    required this.topTypes,
+  });
+
+  @override
+  int get hashCode =>
+      startMs.hashCode ^
+      endMs.hashCode ^
+      eventCount.hashCode ^
+      fileCount.hashCode ^
+      bytes.hashCode ^
+      topTypes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ActivityBucket &&
+          runtimeType == other.runtimeType &&
+          startMs == other.startMs &&
+          endMs == other.endMs &&
+          eventCount == other.eventCount &&
+          fileCount == other.fileCount &&
+          bytes == other.bytes &&
+          topTypes == other.topTypes;
+}
+
+/// Стадия переноса папки.
+enum AdoptionPhase {
+  /// Папка обходится.
+  scanning,
+  /// Файлы индексируются и отдаются пачками.
+  backfilling,
+  completed,
+  /// Прервано остановкой ядра.
+  cancelled,
+  failed,
+}
+
+/// Ход переноса из [`adopt_folder`].
+class AdoptionProgressEvent {
+  /// Идентификатор, который вернул [`adopt_folder`].
+  final int adoptionId;
+  final String path;
+  final AdoptionPhase phase;
+  final int filesDone;
+
+  /// Известно после `Scanning`.
+  final int filesTotal;
+
+  /// Сколько файлов не удалось проиндексировать.
+  final int indexFailures;
+
+  /// Маркер папки (при `Completed`), см. [`get_folder_marker`].
+  final String? folderId;
+
+  /// Код [`LateraError`] при `Failed`.
+  final String? errorCode;
+  final String? errorMessage;
+
+  const AdoptionProgressEvent({
+    required this.adoptionId,
+    required this.path,
+    required this.phase,
+    required this.filesDone,
+    required this.filesTotal,
+    required this.indexFailures,
+    this.folderId,
+    this.errorCode,
+    this.errorMessage,
+  });
+
+  @override
+  int get hashCode =>
+      adoptionId.hashCode ^
+      path.hashCode ^
+      phase.hashCode ^
+      filesDone.hashCode ^
+      filesTotal.hashCode ^
+      indexFailures.hashCode ^
+      folderId.hashCode ^
+      errorCode.hashCode ^
+      errorMessage.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AdoptionProgressEvent &&
+          runtimeType == other.runtimeType &&
+          adoptionId == other.adoptionId &&
+          path == other.path &&
+          phase == other.phase &&
+          filesDone == other.filesDone &&
+          filesTotal == other.filesTotal &&
+          indexFailures == other.indexFailures &&
+          folderId == other.folderId &&
+          errorCode == other.errorCode &&
+          errorMessage == other.errorMessage;
+}
+
+/// Файл, перенесённый в архив.
+class ApiArchivedFile {
+  final String originalPath;
+  final String archivedPath;
+  final int archivedAtMs;
+  final int modifiedAtMs;
+  final int sizeBytes;
+
+  const ApiArchivedFile({
+    required this.originalPath,
+    required this.archivedPath,
+    required this.archivedAtMs,
+    required this.modifiedAtMs,
+    required this.sizeBytes,
+  });
+
+  @override
+  int get hashCode =>
+      originalPath.hashCode ^
+      archivedPath.hashCode ^
+      archivedAtMs.hashCode ^
+      modifiedAtMs.hashCode ^
+      sizeBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiArchivedFile &&
+          runtimeType == other.runtimeType &&
+          originalPath == other.originalPath &&
+          archivedPath == other.archivedPath &&
+          archivedAtMs == other.archivedAtMs &&
+          modifiedAtMs == other.modifiedAtMs &&
+          sizeBytes == other.sizeBytes;
+}
+
+/// Действие ядра над файлом пользователя (FRB bridge type).
+enum ApiAuditAction { move, rename, trash, delete, upload, quarantine }
+
+/// Запись журнала аудита.
+class ApiAuditEntry {
+  final int id;
+
+  /// Unix timestamp в миллисекундах.
+  final int atMs;
+  final ApiAuditAction action;
+  final String path;
+
+  /// Куда перенесён или отправлен файл.
+  final String? target;
+
+  /// Правило (`intake.downloads`, `archive.older_than_days`,
+  /// `quota.cleanup_oldest`, `rules.{id}`…); `None` — действие запросило
+  /// приложение.
+  final String? ruleId;
+
+  const ApiAuditEntry({
+    required this.id,
+    required this.atMs,
+    required this.action,
+    required this.path,
+    this.target,
+    this.ruleId,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      atMs.hashCode ^
+      action.hashCode ^
+      path.hashCode ^
+      target.hashCode ^
+      ruleId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiAuditEntry &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          atMs == other.atMs &&
+          action == other.action &&
+          path == other.path &&
+          target == other.target &&
+          ruleId == other.ruleId;
+}
+
+/// Кому достался появившийся файл общей папки (см. [`set_shared_folder_claims`]).
+@freezed
+sealed class ApiClaimStatus with _$ApiClaimStatus {
+  const ApiClaimStatus._();
+
+  /// Файл забран этим экземпляром: автоматические действия выполняет он.
+  const factory ApiClaimStatus.claimed() = ApiClaimStatus_Claimed;
+
+  /// Файл забран экземпляром `owner` (`пользователь@машина`).
+  const factory ApiClaimStatus.claimedByOther({
+    required String owner,
+    required int claimedAtMs,
+  }) = ApiClaimStatus_ClaimedByOther;
+
+  /// Claim-файл не создать (нет прав, режим «только наблюдение»).
+  const factory ApiClaimStatus.unavailable({
+    required String reason,
+  }) = ApiClaimStatus_Unavailable;
+}
+
+/// Формат найденного кода.
+enum ApiCodeFormat {
+  qrCode,
+  /// EAN-13; UPC-A приходит как EAN-13 с ведущим нулём.
+  ean13,
+  ean8,
+}
+
+/// Постоянные настройки ядра (см. [`config`](crate::config)).
+class ApiConfig {
+  /// Где хранятся настройки.
+  final String configPath;
+
+  /// Повтор того же события по тому же пути в пределах окна отбрасывается.
+  final int dedupWindowMs;
+
+  /// Сколько событий в секунду отдаётся сразу; остальные — пачками.
+  final int rateLimitPerSecond;
+
+  /// Папки для [`start_configured_watchers`].
+  final List<String> watchPaths;
+  final WatchFilter filter;
+
+  /// `None` — уровень по умолчанию.
+  final String? logLevel;
+
+  const ApiConfig({
+    required this.configPath,
+    required this.dedupWindowMs,
+    required this.rateLimitPerSecond,
+    required this.watchPaths,
+    required this.filter,
+    this.logLevel,
+  });
+
+  @override
+  int get hashCode =>
+      configPath.hashCode ^
+      dedupWindowMs.hashCode ^
+      rateLimitPerSecond.hashCode ^
+      watchPaths.hashCode ^
+      filter.hashCode ^
+      logLevel.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiConfig &&
+          runtimeType == other.runtimeType &&
+          configPath == other.configPath &&
+          dedupWindowMs == other.dedupWindowMs &&
+          rateLimitPerSecond == other.rateLimitPerSecond &&
+          watchPaths == other.watchPaths &&
+          filter == other.filter &&
+          logLevel == other.logLevel;
+}
+
+/// Изменение настроек для [`update_config`]: `None` — поле не меняется.
+class ApiConfigPatch {
+  final int? dedupWindowMs;
+  final int? rateLimitPerSecond;
+
+  /// Абсолютные пути.
+  final List<String>? watchPaths;
+
+  /// Заменяет фильтр целиком.
+  final WatchFilter? filter;
+
+  /// Как в [`set_log_level`]; пустая строка — уровень по умолчанию.
+  final String? logLevel;
+
+  const ApiConfigPatch({
+    this.dedupWindowMs,
+    this.rateLimitPerSecond,
+    this.watchPaths,
+    this.filter,
+    this.logLevel,
+  });
+
+  @override
+  int get hashCode =>
+      dedupWindowMs.hashCode ^
+      rateLimitPerSecond.hashCode ^
+      watchPaths.hashCode ^
+      filter.hashCode ^
+      logLevel.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiConfigPatch &&
+          runtimeType == other.runtimeType &&
+          dedupWindowMs == other.dedupWindowMs &&
+          rateLimitPerSecond == other.rateLimitPerSecond &&
+          watchPaths == other.watchPaths &&
+          filter == other.filter &&
+          logLevel == other.logLevel;
+}
+
+/// QR-код или штрихкод, найденный на изображении.
+class ApiDetectedCode {
+  final ApiCodeFormat format;
+
+  /// Текст QR или цифры штрихкода.
+  final String text;
+
+  const ApiDetectedCode({required this.format, required this.text});
+
+  @override
+  int get hashCode => format.hashCode ^ text.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiDetectedCode &&
+          runtimeType == other.runtimeType &&
+          format == other.format &&
+          text == other.text;
+}
+
+/// Вложение письма (без содержимого).
+class ApiEmailAttachment {
+  final String fileName;
+  final int sizeBytes;
+  final String? mimeType;
+
+  const ApiEmailAttachment({
+    required this.fileName,
+    required this.sizeBytes,
+    this.mimeType,
+  });
+
+  @override
+  int get hashCode =>
+      fileName.hashCode ^ sizeBytes.hashCode ^ mimeType.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiEmailAttachment &&
+          runtimeType == other.runtimeType &&
+          fileName == other.fileName &&
+          sizeBytes == other.sizeBytes &&
+          mimeType == other.mimeType;
+}
+
+/// Метаданные письма, перетащенного из почтового клиента.
+class ApiEmailMetadata {
+  final String? subject;
+
+  /// `Имя <адрес>` или только адрес.
+  final String? from;
+  final List<String> to;
+  final List<String> cc;
+
+  /// Дата отправки (Unix timestamp в миллисекундах).
+  final int? dateMs;
+  final String? messageId;
+  final List<ApiEmailAttachment> attachments;
+
+  const ApiEmailMetadata({
+    this.subject,
+    this.from,
+    required this.to,
+    required this.cc,
+    this.dateMs,
+    this.messageId,
+    required this.attachments,
+  });
+
+  @override
+  int get hashCode =>
+      subject.hashCode ^
+      from.hashCode ^
+      to.hashCode ^
+      cc.hashCode ^
+      dateMs.hashCode ^
+      messageId.hashCode ^
+      attachments.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiEmailMetadata &&
+          runtimeType == other.runtimeType &&
+          subject == other.subject &&
+          from == other.from &&
+          to == other.to &&
+          cc == other.cc &&
+          dateMs == other.dateMs &&
+          messageId == other.messageId &&
+          attachments == other.attachments;
+}
+
+/// Результат вычисления эмбеддинга (FRB bridge type).
+class ApiEmbeddingVector {
+  /// Индекс чанка.
+  final int chunkIndex;
+
+  /// Вектор эмбеддинга (f32).
+  final Float32List vector;
+
+  const ApiEmbeddingVector({required this.chunkIndex, required this.vector});
+
+  @override
+  int get hashCode => chunkIndex.hashCode ^ vector.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiEmbeddingVector &&
+          runtimeType == other.runtimeType &&
+          chunkIndex == other.chunkIndex &&
+          vector == other.vector;
+}
+
+/// Владелец файла и права текущего пользователя.
+class ApiFileAccessInfo {
+  /// Имя владельца (`user` на Unix, `DOMAIN\user` на Windows).
+  final String? ownerName;
+
+  /// Идентификатор владельца: uid на Unix, SID на Windows.
+  final String? ownerId;
+  final bool readable;
+  final bool writable;
+  final bool executable;
+
+  const ApiFileAccessInfo({
+    this.ownerName,
+    this.ownerId,
+    required this.readable,
+    required this.writable,
+    required this.executable,
+  });
+
+  @override
+  int get hashCode =>
+      ownerName.hashCode ^
+      ownerId.hashCode ^
+      readable.hashCode ^
+      writable.hashCode ^
+      executable.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiFileAccessInfo &&
+          runtimeType == other.runtimeType &&
+          ownerName == other.ownerName &&
+          ownerId == other.ownerId &&
+          readable == other.readable &&
+          writable == other.writable &&
+          executable == other.executable;
+}
+
+/// Запись журнала событий (см. [`query_events`]).
+class ApiJournalEntry {
+  /// Порядковый номер записи (растёт монотонно).
+  final int id;
+  final String watcherId;
+  final FileEventKind kind;
+  final String fullPath;
+  final String? previousPath;
+  final int occurredAtMs;
+  final int detectedAtMs;
+
+  /// Размер файла; `0` — неизвестен (например, для удаления).
+  final int sizeBytes;
+  final String? mimeType;
+  final bool selfGenerated;
+
+  const ApiJournalEntry({
+    required this.id,
+    required this.watcherId,
+    required this.kind,
+    required this.fullPath,
+    this.previousPath,
+    required this.occurredAtMs,
+    required this.detectedAtMs,
+    required this.sizeBytes,
+    this.mimeType,
+    required this.selfGenerated,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      watcherId.hashCode ^
+      kind.hashCode ^
+      fullPath.hashCode ^
+      previousPath.hashCode ^
+      occurredAtMs.hashCode ^
+      detectedAtMs.hashCode ^
+      sizeBytes.hashCode ^
+      mimeType.hashCode ^
+      selfGenerated.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiJournalEntry &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          watcherId == other.watcherId &&
+          kind == other.kind &&
+          fullPath == other.fullPath &&
+          previousPath == other.previousPath &&
+          occurredAtMs == other.occurredAtMs &&
+          detectedAtMs == other.detectedAtMs &&
+          sizeBytes == other.sizeBytes &&
+          mimeType == other.mimeType &&
+          selfGenerated == other.selfGenerated;
+}
+
+/// Параметр, заблокированный политикой администратора.
+class ApiLockedOption {
+  /// `watch_path`, `uploads`, `max_folder_bytes`, `max_folder_files`,
+  /// `read_only` или `settings.<key>` (настройка [`set_setting`]).
+  final String key;
+
+  /// Значение по политике.
+  final String value;
+
+  const ApiLockedOption({required this.key, required this.value});
+
+  @override
+  int get hashCode => key.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiLockedOption &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          value == other.value;
+}
 
 /// Источник ответа RAG (FRB bridge type).
 class ApiRagSource {
   /// Путь к файлу-источнику.
   final String filePath;
 
-  /// Фрагмент текста чанка (обрезанный сниппет).
-  final String chunkSnippet;
+  /// Фрагмент текста чанка (обрезанный сниппет).
+  final String chunkSnippet;
+
+  /// Смещение (байтовое) чанка в документе.
+  final int chunkOffset;
+
+  const ApiRagSource({
+    required this.filePath,
+    required this.chunkSnippet,
+    required this.chunkOffset,
+  });
+
+  @override
+  int get hashCode =>
+      filePath.hashCode ^ chunkSnippet.hashCode ^ chunkOffset.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiRagSource &&
+          runtimeType == other.runtimeType &&
+          filePath == other.filePath &&
+          chunkSnippet == other.chunkSnippet &&
+          chunkOffset == other.chunkOffset;
+}
+
+/// Результат similarity search (FRB bridge type).
+class ApiSimilarityResult {
+  /// Путь к файлу.
+  final String filePath;
+
+  /// Имя файла.
+  final String fileName;
+
+  /// Текст чанка, наиболее похожего на запрос.
+  final String chunkSnippet;
+
+  /// Смещение чанка в документе.
+  final int chunkOffset;
+
+  /// Косинусное сходство (0.0 – 1.0).
+  final double score;
+
+  const ApiSimilarityResult({
+    required this.filePath,
+    required this.fileName,
+    required this.chunkSnippet,
+    required this.chunkOffset,
+    required this.score,
+  });
+
+  @override
+  int get hashCode =>
+      filePath.hashCode ^
+      fileName.hashCode ^
+      chunkSnippet.hashCode ^
+      chunkOffset.hashCode ^
+      score.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiSimilarityResult &&
+          runtimeType == other.runtimeType &&
+          filePath == other.filePath &&
+          fileName == other.fileName &&
+          chunkSnippet == other.chunkSnippet &&
+          chunkOffset == other.chunkOffset &&
+          score == other.score;
+}
+
+/// Агрегированный счётчик телеметрии (FRB bridge type).
+class ApiTelemetryCounter {
+  /// День в формате `YYYY-MM-DD` (UTC).
+  final String day;
+
+  /// Категория: `"event"`, `"feature"`, `"error"`.
+  final String kind;
+
+  /// Имя счётчика (`"file_added"`, `"search"`, `"IO_ERROR"`, ...).
+  final String name;
+
+  /// Значение.
+  final int count;
+
+  const ApiTelemetryCounter({
+    required this.day,
+    required this.kind,
+    required this.name,
+    required this.count,
+  });
+
+  @override
+  int get hashCode =>
+      day.hashCode ^ kind.hashCode ^ name.hashCode ^ count.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiTelemetryCounter &&
+          runtimeType == other.runtimeType &&
+          day == other.day &&
+          kind == other.kind &&
+          name == other.name &&
+          count == other.count;
+}
+
+/// Текстовый чанк (FRB bridge type).
+class ApiTextChunk {
+  /// Текст чанка.
+  final String text;
+
+  /// Индекс чанка в документе (0-based).
+  final int chunkIndex;
+
+  /// Смещение (байтовое) от начала документа.
+  final int chunkOffset;
+
+  const ApiTextChunk({
+    required this.text,
+    required this.chunkIndex,
+    required this.chunkOffset,
+  });
+
+  @override
+  int get hashCode =>
+      text.hashCode ^ chunkIndex.hashCode ^ chunkOffset.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiTextChunk &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          chunkIndex == other.chunkIndex &&
+          chunkOffset == other.chunkOffset;
+}
+
+/// Миниатюра изображения в кэше ядра.
+class ApiThumbnail {
+  /// Путь к файлу миниатюры (можно показать через `Image.file`).
+  final String cachePath;
+
+  /// `image/png` или `image/jpeg`.
+  final String mimeType;
+  final int width;
+  final int height;
+
+  const ApiThumbnail({
+    required this.cachePath,
+    required this.mimeType,
+    required this.width,
+    required this.height,
+  });
+
+  @override
+  int get hashCode =>
+      cachePath.hashCode ^ mimeType.hashCode ^ width.hashCode ^ height.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiThumbnail &&
+          runtimeType == other.runtimeType &&
+          cachePath == other.cachePath &&
+          mimeType == other.mimeType &&
+          width == other.width &&
+          height == other.height;
+}
+
+/// Способ наблюдения при запуске watcher'а.
+enum ApiWatchBackend {
+  /// Опрос на сетевых дисках (SMB, NFS…), иначе нативный backend.
+  auto,
+  /// Всегда нативный backend.
+  native,
+  /// Всегда опрос папки.
+  polling,
+}
+
+/// Как watcher получает изменения (см. [`ApiWatcherState::Fallback`]).
+enum ApiWatchMode {
+  /// Нативный backend платформы.
+  native,
+  /// Периодический опрос папки.
+  polling,
+  /// Событий нет; изменения находит [`rescan_now`].
+  manualRefresh,
+}
+
+/// Почему watcher перестал работать (см. [`ApiWatcherState::Error`]).
+enum ApiWatcherErrorCode {
+  /// Не удалось создать backend `notify`.
+  backendInitFailed,
+  /// Backend не смог начать наблюдение за папкой.
+  watchFailed,
+  /// Backend перестал присылать события (канал закрыт).
+  channelDisconnected,
+  /// Наблюдаемая папка исчезла (удалена, переименована, диск отключён).
+  watchDirLost,
+  /// Backend сообщил о фатальной ошибке.
+  backendFailed,
+  /// Исчерпан лимит inotify (`fs.inotify.max_user_watches`), а переход на
+  /// опрос выключен.
+  watchLimitExceeded,
+}
+
+/// Состояние watcher'а.
+@freezed
+sealed class ApiWatcherState with _$ApiWatcherState {
+  const ApiWatcherState._();
+
+  /// Watcher запускается.
+  const factory ApiWatcherState.starting() = ApiWatcherState_Starting;
+
+  /// События поступают.
+  const factory ApiWatcherState.running() = ApiWatcherState_Running;
+
+  /// Backend сообщает об ошибках — часть событий может теряться.
+  const factory ApiWatcherState.degraded({
+    required String reason,
+  }) = ApiWatcherState_Degraded;
+
+  /// Остановлен по запросу.
+  const factory ApiWatcherState.stopped() = ApiWatcherState_Stopped;
+
+  /// Watcher завершился из-за ошибки; событий больше не будет.
+  const factory ApiWatcherState.error({
+    required ApiWatcherErrorCode code,
+    required String message,
+  }) = ApiWatcherState_Error;
+
+  /// Backend упал; попытка перезапуска `attempt` через `retry_in_ms`.
+  const factory ApiWatcherState.restarting({
+    required int attempt,
+    required int retryInMs,
+    required String reason,
+  }) = ApiWatcherState_Restarting;
+
+  /// Папка наблюдения пропала; watcher ждёт её возвращения
+  /// (см. [`set_watch_dir_recovery`]).
+  const factory ApiWatcherState.dirLost({
+    required String path,
+  }) = ApiWatcherState_DirLost;
+
+  /// Нативный backend не работает; watcher получает изменения способом
+  /// `mode` (см. [`set_watcher_degradation`]).
+  const factory ApiWatcherState.fallback({
+    required ApiWatchMode mode,
+    required String reason,
+  }) = ApiWatcherState_Fallback;
+}
+
+/// Расширенный атрибут файла.
+class ApiXattr {
+  final String name;
+  final Uint8List value;
+
+  const ApiXattr({required this.name, required this.value});
+
+  @override
+  int get hashCode => name.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ApiXattr &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          value == other.value;
+}
+
+/// Автоматическое архивирование старых файлов (FRB bridge type).
+class ArchiveOptions {
+  /// Файлы, не менявшиеся дольше стольких дней, переносятся в архив.
+  final int olderThanDays;
+
+  /// Корень архива (абсолютный путь); `None` — папка `Archive` внутри
+  /// папки наблюдения.
+  final String? archiveDir;
+
+  /// Период проверки в минутах (по умолчанию 60, минимум 1).
+  final int? checkIntervalMinutes;
+
+  const ArchiveOptions({
+    required this.olderThanDays,
+    this.archiveDir,
+    this.checkIntervalMinutes,
+  });
+
+  @override
+  int get hashCode =>
+      olderThanDays.hashCode ^
+      archiveDir.hashCode ^
+      checkIntervalMinutes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ArchiveOptions &&
+          runtimeType == other.runtimeType &&
+          olderThanDays == other.olderThanDays &&
+          archiveDir == other.archiveDir &&
+          checkIntervalMinutes == other.checkIntervalMinutes;
+}
+
+/// Условия выборки [`get_audit_log`]. Пустые поля не ограничивают выборку.
+class AuditLogFilter {
+  final ApiAuditAction? action;
+
+  /// Файл или папка: исходный путь или путь назначения под ней.
+  final String? path;
+  final String? ruleId;
+
+  /// Не раньше (включительно), Unix ms.
+  final int? sinceMs;
+
+  /// Раньше (не включительно), Unix ms.
+  final int? untilMs;
+
+  /// Не больше стольких записей (`None` — все).
+  final int? limit;
+
+  const AuditLogFilter({
+    this.action,
+    this.path,
+    this.ruleId,
+    this.sinceMs,
+    this.untilMs,
+    this.limit,
+  });
+
+  @override
+  int get hashCode =>
+      action.hashCode ^
+      path.hashCode ^
+      ruleId.hashCode ^
+      sinceMs.hashCode ^
+      untilMs.hashCode ^
+      limit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AuditLogFilter &&
+          runtimeType == other.runtimeType &&
+          action == other.action &&
+          path == other.path &&
+          ruleId == other.ruleId &&
+          sinceMs == other.sinceMs &&
+          untilMs == other.untilMs &&
+          limit == other.limit;
+}
+
+/// Файлы одной категории в папке.
+class CategoryStats {
+  final FileCategory category;
+  final int fileCount;
+  final int totalBytes;
+
+  const CategoryStats({
+    required this.category,
+    required this.fileCount,
+    required this.totalBytes,
+  });
+
+  @override
+  int get hashCode =>
+      category.hashCode ^ fileCount.hashCode ^ totalBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CategoryStats &&
+          runtimeType == other.runtimeType &&
+          category == other.category &&
+          fileCount == other.fileCount &&
+          totalBytes == other.totalBytes;
+}
+
+/// Итог [`import_config`].
+class ConfigImportResult {
+  /// Запущенные watcher'ы из файла.
+  final List<String> watcherIds;
+
+  /// Watcher'ы, которые не удалось запустить: `"<папка>: <причина>"`
+  /// (папка уже наблюдается, недоступна на этой машине и т.п.).
+  final List<String> skippedWatchers;
+
+  const ConfigImportResult({
+    required this.watcherIds,
+    required this.skippedWatchers,
+  });
+
+  @override
+  int get hashCode => watcherIds.hashCode ^ skippedWatchers.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConfigImportResult &&
+          runtimeType == other.runtimeType &&
+          watcherIds == other.watcherIds &&
+          skippedWatchers == other.skippedWatchers;
+}
+
+/// Состояние контролируемого доступа и инструкция для пользователя.
+class ControlledFolderAccessInfo {
+  final ControlledFolderAccessStatus status;
+
+  /// Исполняемый файл, который нужно разрешить.
+  final String appPath;
+
+  /// Открывает нужный раздел Windows Security (`launchUrl` во Flutter).
+  final String settingsUri;
+
+  /// Шаги по порядку.
+  final List<String> steps;
+
+  const ControlledFolderAccessInfo({
+    required this.status,
+    required this.appPath,
+    required this.settingsUri,
+    required this.steps,
+  });
+
+  @override
+  int get hashCode =>
+      status.hashCode ^
+      appPath.hashCode ^
+      settingsUri.hashCode ^
+      steps.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ControlledFolderAccessInfo &&
+          runtimeType == other.runtimeType &&
+          status == other.status &&
+          appPath == other.appPath &&
+          settingsUri == other.settingsUri &&
+          steps == other.steps;
+}
+
+/// Контролируемый доступ к папкам Windows (Microsoft Defender).
+enum ControlledFolderAccessStatus {
+  /// Запись в защищённые папки (в том числе `Desktop/Latera`) блокируется,
+  /// пока Latera не добавлена в разрешённые приложения.
+  enabled,
+  /// Запись разрешена, но попадает в журнал Defender.
+  auditOnly,
+  disabled,
+  /// Не Windows.
+  notSupported,
+}
+
+/// Возможности ядра на текущей машине (FRB bridge type).
+class CoreCapabilities {
+  /// Версия Rust Core.
+  final String coreVersion;
+
+  /// Целевая ОС: `"windows"`, `"macos"`, `"linux"`.
+  final String platform;
+
+  /// Фактическая папка данных приложения.
+  final String dataDir;
+
+  /// Открыт ли индекс.
+  final bool indexReady;
+
+  /// Доступен ли системный OCR.
+  final bool ocrAvailable;
+
+  /// Поддерживает ли процессор AVX2.
+  final bool hasAvx2;
+
+  /// Доступен ли Vulkan runtime.
+  final bool hasVulkan;
+
+  /// Объём физической памяти в мегабайтах.
+  final int totalRamMb;
+
+  const CoreCapabilities({
+    required this.coreVersion,
+    required this.platform,
+    required this.dataDir,
+    required this.indexReady,
+    required this.ocrAvailable,
+    required this.hasAvx2,
+    required this.hasVulkan,
+    required this.totalRamMb,
+  });
+
+  @override
+  int get hashCode =>
+      coreVersion.hashCode ^
+      platform.hashCode ^
+      dataDir.hashCode ^
+      indexReady.hashCode ^
+      ocrAvailable.hashCode ^
+      hasAvx2.hashCode ^
+      hasVulkan.hashCode ^
+      totalRamMb.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CoreCapabilities &&
+          runtimeType == other.runtimeType &&
+          coreVersion == other.coreVersion &&
+          platform == other.platform &&
+          dataDir == other.dataDir &&
+          indexReady == other.indexReady &&
+          ocrAvailable == other.ocrAvailable &&
+          hasAvx2 == other.hasAvx2 &&
+          hasVulkan == other.hasVulkan &&
+          totalRamMb == other.totalRamMb;
+}
+
+/// Конфигурация инициализации ядра (FRB bridge type).
+class CoreConfig {
+  /// Абсолютный путь к папке данных приложения.
+  /// `None` = `{local app data}/Latera`.
+  final String? dataDir;
+
+  /// Путь к файлу SQLite индекса. Если указан — индекс открывается
+  /// сразу (эквивалент [`init_index`]).
+  final String? indexDbPath;
+
+  const CoreConfig({this.dataDir, this.indexDbPath});
+
+  @override
+  int get hashCode => dataDir.hashCode ^ indexDbPath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CoreConfig &&
+          runtimeType == other.runtimeType &&
+          dataDir == other.dataDir &&
+          indexDbPath == other.indexDbPath;
+}
+
+/// Разрешение на доступ к «Рабочему столу» (macOS TCC).
+enum DesktopAccessStatus {
+  granted,
+  /// Пользователь не дал разрешения: запуск watcher'а в `Desktop/Latera`
+  /// вернёт `LateraError::PermissionDenied`.
+  denied,
+  /// На этой ОС разрешение не нужно.
+  notRequired,
+  /// Папки нет или её не удалось прочитать по другой причине.
+  unknown,
+}
+
+/// Размер папки (FRB bridge type).
+class DirSizeInfo {
+  final int totalBytes;
+  final int fileCount;
+
+  /// Вложенные папки (сама папка не считается).
+  final int dirCount;
+
+  const DirSizeInfo({
+    required this.totalBytes,
+    required this.fileCount,
+    required this.dirCount,
+  });
+
+  @override
+  int get hashCode =>
+      totalBytes.hashCode ^ fileCount.hashCode ^ dirCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DirSizeInfo &&
+          runtimeType == other.runtimeType &&
+          totalBytes == other.totalBytes &&
+          fileCount == other.fileCount &&
+          dirCount == other.dirCount;
+}
+
+/// Действие, пропущенное из-за пробного запуска (см. [`set_dry_run_mode`]).
+class DryRunActionEvent {
+  final ApiAuditAction action;
+  final String path;
+
+  /// Куда был бы перенесён файл.
+  final String? target;
+
+  /// Правило, по которому выполнилось бы действие.
+  final String ruleId;
+
+  /// Unix timestamp в миллисекундах.
+  final int plannedAtMs;
+
+  const DryRunActionEvent({
+    required this.action,
+    required this.path,
+    this.target,
+    required this.ruleId,
+    required this.plannedAtMs,
+  });
+
+  @override
+  int get hashCode =>
+      action.hashCode ^
+      path.hashCode ^
+      target.hashCode ^
+      ruleId.hashCode ^
+      plannedAtMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DryRunActionEvent &&
+          runtimeType == other.runtimeType &&
+          action == other.action &&
+          path == other.path &&
+          target == other.target &&
+          ruleId == other.ruleId &&
+          plannedAtMs == other.plannedAtMs;
+}
+
+/// Появившийся файл совпадает по содержимому с уже лежащим в папке.
+///
+/// UI может предложить «оставить оба», «заменить» (старый — в корзину через
+/// [`delete_to_trash`], новый — на его место через [`move_file`]) или
+/// «пропустить» (новый — в корзину).
+class DuplicateDetected {
+  final String watcherId;
+  final String newPath;
+  final String existingPath;
+
+  const DuplicateDetected({
+    required this.watcherId,
+    required this.newPath,
+    required this.existingPath,
+  });
+
+  @override
+  int get hashCode =>
+      watcherId.hashCode ^ newPath.hashCode ^ existingPath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DuplicateDetected &&
+          runtimeType == other.runtimeType &&
+          watcherId == other.watcherId &&
+          newPath == other.newPath &&
+          existingPath == other.existingPath;
+}
+
+/// Группа файлов с одинаковым содержимым.
+class DuplicateGroup {
+  /// Размер одного файла.
+  final int sizeBytes;
+
+  /// BLAKE3 содержимого (hex).
+  final String hash;
+  final List<String> paths;
+
+  const DuplicateGroup({
+    required this.sizeBytes,
+    required this.hash,
+    required this.paths,
+  });
+
+  @override
+  int get hashCode => sizeBytes.hashCode ^ hash.hashCode ^ paths.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DuplicateGroup &&
+          runtimeType == other.runtimeType &&
+          sizeBytes == other.sizeBytes &&
+          hash == other.hash &&
+          paths == other.paths;
+}
+
+/// Отладочный контекст события (см. [`set_event_debug_enrichment`]).
+class EventDebugInfo {
+  /// [`FileEvent::sequence`] события.
+  final int sequence;
+
+  /// События `notify` по пути с прошлого сообщённого события, по порядку
+  /// (например `Create(File)`, `Modify(Data(Content))`).
+  final List<String> notifyKinds;
+
+  /// Решения watcher'а по пути: отброшенные дубликаты, ожидание
+  /// стабилизации, откладывание в пачку.
+  final List<String> decisions;
+
+  /// Первое событие `notify` (монотонные мс, как [`FileEvent::monotonic_ms`]).
+  final int firstSeenMonotonicMs;
+
+  /// Сколько читались метаданные файла.
+  final int metadataMs;
+
+  /// Сколько событие ждало после сборки (стабилизация, пачка).
+  final int heldMs;
+
+  /// Когда watcher отдал событие (монотонные мс).
+  final int deliveredMonotonicMs;
+
+  const EventDebugInfo({
+    required this.sequence,
+    required this.notifyKinds,
+    required this.decisions,
+    required this.firstSeenMonotonicMs,
+    required this.metadataMs,
+    required this.heldMs,
+    required this.deliveredMonotonicMs,
+  });
+
+  @override
+  int get hashCode =>
+      sequence.hashCode ^
+      notifyKinds.hashCode ^
+      decisions.hashCode ^
+      firstSeenMonotonicMs.hashCode ^
+      metadataMs.hashCode ^
+      heldMs.hashCode ^
+      deliveredMonotonicMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EventDebugInfo &&
+          runtimeType == other.runtimeType &&
+          sequence == other.sequence &&
+          notifyKinds == other.notifyKinds &&
+          decisions == other.decisions &&
+          firstSeenMonotonicMs == other.firstSeenMonotonicMs &&
+          metadataMs == other.metadataMs &&
+          heldMs == other.heldMs &&
+          deliveredMonotonicMs == other.deliveredMonotonicMs;
+}
+
+/// Что означает `occurred_at_ms` в [`FileAddedEvent`].
+enum EventTimestampSource {
+  /// Момент, когда watcher обнаружил файл (по умолчанию).
+  detectedAt,
+  /// Время последнего изменения файла (mtime).
+  fileModified,
+}
+
+/// Опции извлечения текста (FRB bridge type).
+///
+/// Передаются из Flutter-стороны для уважения пользовательских лимитов
+/// (`AppConfig.effectiveLimits`).
+class ExtractionOptions {
+  /// Максимальное количество страниц PDF для обработки.
+  final int maxPagesPerPdf;
+
+  /// Максимальный размер файла в мегабайтах.
+  final int maxFileSizeMb;
+
+  const ExtractionOptions({
+    required this.maxPagesPerPdf,
+    required this.maxFileSizeMb,
+  });
+
+  @override
+  int get hashCode => maxPagesPerPdf.hashCode ^ maxFileSizeMb.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ExtractionOptions &&
+          runtimeType == other.runtimeType &&
+          maxPagesPerPdf == other.maxPagesPerPdf &&
+          maxFileSizeMb == other.maxFileSizeMb;
+}
+
+/// Результат извлечения текста (FRB bridge type).
+class ExtractionResult {
+  /// Извлечённый текст (может быть пуст при ошибке).
+  final String text;
+
+  /// Тип контента: `"pdf"`, `"docx"`, `"text"`, `"unsupported"`, `"unknown"`.
+  final String contentType;
+
+  /// Количество обработанных страниц (для PDF; для остальных — 0).
+  final int pagesExtracted;
+
+  /// Код ошибки (None = успех).
+  ///
+  /// Возможные значения:
+  /// - `"file_too_large"` — файл превышает лимит
+  /// - `"too_many_pages"` — PDF превышает лимит страниц (текст до лимита извлечён)
+  /// - `"unsupported_format"` — формат не поддерживается
+  /// - `"extraction_failed"` — внутренняя ошибка
+  /// - `"file_not_found"` — файл не найден
+  final String? errorCode;
+
+  const ExtractionResult({
+    required this.text,
+    required this.contentType,
+    required this.pagesExtracted,
+    this.errorCode,
+  });
+
+  @override
+  int get hashCode =>
+      text.hashCode ^
+      contentType.hashCode ^
+      pagesExtracted.hashCode ^
+      errorCode.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ExtractionResult &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          contentType == other.contentType &&
+          pagesExtracted == other.pagesExtracted &&
+          errorCode == other.errorCode;
+}
+
+/// Событие: добавлен новый файл.
+///
+/// Поля подобраны так, чтобы их было удобно бриджить во Flutter.
+class FileAddedEvent {
+  final String fileName;
+  final String fullPath;
+
+  /// Время события: момент обнаружения или mtime (см. [`set_event_timestamp_source`]).
+  final int occurredAtMs;
+
+  /// Момент обнаружения по UTC wall clock (Unix timestamp в миллисекундах).
+  final int detectedAtMs;
+
+  /// Монотонное время обнаружения (мс с запуска ядра) — для упорядочивания.
+  final int monotonicMs;
+
+  /// Время создания файла (birthtime). `0` = платформа/ФС не поддерживает.
+  final int createdAtMs;
+
+  /// Время последнего изменения файла (mtime). `0` = недоступно.
+  final int modifiedAtMs;
+
+  /// Размер файла в байтах; при стабилизации ([`set_settle_quiet_period`]) —
+  /// итоговый.
+  final int sizeBytes;
+
+  /// Расширение в нижнем регистре, без точки.
+  final String? extension;
+
+  /// MIME-тип по содержимому (`None` — файл пуст или не читается).
+  final String? mimeType;
+
+  /// Владелец и права текущего пользователя (`None` — не удалось прочитать).
+  final ApiFileAccessInfo? access;
+
+  const FileAddedEvent({
+    required this.fileName,
+    required this.fullPath,
+    required this.occurredAtMs,
+    required this.detectedAtMs,
+    required this.monotonicMs,
+    required this.createdAtMs,
+    required this.modifiedAtMs,
+    required this.sizeBytes,
+    this.extension,
+    this.mimeType,
+    this.access,
+  });
+
+  @override
+  int get hashCode =>
+      fileName.hashCode ^
+      fullPath.hashCode ^
+      occurredAtMs.hashCode ^
+      detectedAtMs.hashCode ^
+      monotonicMs.hashCode ^
+      createdAtMs.hashCode ^
+      modifiedAtMs.hashCode ^
+      sizeBytes.hashCode ^
+      extension.hashCode ^
+      mimeType.hashCode ^
+      access.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FileAddedEvent &&
+          runtimeType == other.runtimeType &&
+          fileName == other.fileName &&
+          fullPath == other.fullPath &&
+          occurredAtMs == other.occurredAtMs &&
+          detectedAtMs == other.detectedAtMs &&
+          monotonicMs == other.monotonicMs &&
+          createdAtMs == other.createdAtMs &&
+          modifiedAtMs == other.modifiedAtMs &&
+          sizeBytes == other.sizeBytes &&
+          extension == other.extension &&
+          mimeType == other.mimeType &&
+          access == other.access;
+}
+
+/// Пачка появившихся файлов, не прошедших rate-limit watcher'а
+/// (`rate_limit_per_second` в [`get_config`] или
+/// [`WatchTuning::max_events_per_second`]) — например, при массовом
+/// копировании.
+///
+/// Приходит раз в секунду в [`on_file_batch`] вместо отдельных
+/// [`FileAddedEvent`]. События пачки, как и отдельные, записываются в WAL:
+/// не принятая stream'ом пачка доставляется повторно по одному событию в
+/// [`on_file_added`] при следующем [`start_watching`], а в режиме
+/// подтверждения ([`set_ack_mode`]) каждое событие пачки приходит
+/// и в ackable stream и ждёт [`ack_event`].
+class FileBatchEvent {
+  /// Watcher, от которого пришла пачка; при переносе папки
+  /// ([`adopt_folder`]) — `adopt-{adoption_id}`.
+  final String watcherId;
+  final List<FileAddedEvent> events;
+
+  const FileBatchEvent({required this.watcherId, required this.events});
+
+  @override
+  int get hashCode => watcherId.hashCode ^ events.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FileBatchEvent &&
+          runtimeType == other.runtimeType &&
+          watcherId == other.watcherId &&
+          events == other.events;
+}
+
+/// Категория файлов в составе папки.
+enum FileCategory { images, documents, archives, other }
+
+/// Чанк появившихся файлов: вместо [`FileBatchEvent`] при включённой
+/// передаче чанками (см. [`set_chunked_transfer`]).
+///
+/// `payload` — до N событий в компактном двоичном формате `LEC2`
+/// (описан в `event_chunk.rs`): общие папки, расширения и MIME-типы
+/// хранятся один раз, времена — разностями. Поля событий — как у
+/// [`FileAddedEvent`].
+class FileChunkEvent {
+  /// Watcher, от которого пришёл чанк (как у [`FileBatchEvent`]).
+  final String watcherId;
+
+  /// Число событий в `payload`.
+  final int count;
+  final Uint8List payload;
+
+  const FileChunkEvent({
+    required this.watcherId,
+    required this.count,
+    required this.payload,
+  });
+
+  @override
+  int get hashCode => watcherId.hashCode ^ count.hashCode ^ payload.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FileChunkEvent &&
+          runtimeType == other.runtimeType &&
+          watcherId == other.watcherId &&
+          count == other.count &&
+          payload == other.payload;
+}
+
+/// Событие файла любого вида: создание, изменение, удаление, переименование.
+///
+/// Обобщение [`FileAddedEvent`] — по нему UI держит список файлов в
+/// актуальном состоянии. Для удалённых файлов и старого пути переименования
+/// `created_at_ms`/`modified_at_ms`/`size_bytes` = `0`.
+class FileEvent {
+  /// Номер события в процессе (по порядку отправки); по нему
+  /// [`get_event_debug_info`] отдаёт отладочный контекст.
+  final int sequence;
+
+  /// Watcher, от которого пришло событие (см. [`start_watching`]).
+  final String watcherId;
+  final FileEventKind kind;
+  final String fileName;
+  final String fullPath;
+
+  /// Прежний путь для `RenamedTo`, если платформа сообщила обе стороны.
+  final String? previousPath;
+  final int occurredAtMs;
+  final int detectedAtMs;
+  final int monotonicMs;
+  final int createdAtMs;
+  final int modifiedAtMs;
+
+  /// Размер файла; при стабилизации ([`set_settle_quiet_period`]) — итоговый.
+  final int sizeBytes;
+  final String? extension;
+  final String? mimeType;
+
+  /// Владелец и права текущего пользователя; для удалённых файлов и
+  /// старого пути переименования — `None`.
+  final ApiFileAccessInfo? access;
+
+  /// Изменение сделано самим ядром (очистка по квоте, запись атрибутов…).
+  ///
+  /// Такие появления файлов не дублируются в [`on_file_added`].
+  final bool selfGenerated;
+
+  /// Изменение произошло, пока приложение было закрыто: найдено сверкой
+  /// со снимком папки при запуске (см. [`set_offline_change_detection`]).
+  final bool reconciled;
+
+  /// Кому достался появившийся файл, если включено согласование общей
+  /// папки (см. [`set_shared_folder_claims`]); иначе `None`.
+  final ApiClaimStatus? claim;
+
+  /// QR-коды и штрихкоды изображения для `CodesDetected`; у остальных
+  /// событий пусто.
+  final List<ApiDetectedCode> codes;
+
+  const FileEvent({
+    required this.sequence,
+    required this.watcherId,
+    required this.kind,
+    required this.fileName,
+    required this.fullPath,
+    this.previousPath,
+    required this.occurredAtMs,
+    required this.detectedAtMs,
+    required this.monotonicMs,
+    required this.createdAtMs,
+    required this.modifiedAtMs,
+    required this.sizeBytes,
+    this.extension,
+    this.mimeType,
+    this.access,
+    required this.selfGenerated,
+    required this.reconciled,
+    this.claim,
+    required this.codes,
+  });
+
+  @override
+  int get hashCode =>
+      sequence.hashCode ^
+      watcherId.hashCode ^
+      kind.hashCode ^
+      fileName.hashCode ^
+      fullPath.hashCode ^
+      previousPath.hashCode ^
+      occurredAtMs.hashCode ^
+      detectedAtMs.hashCode ^
+      monotonicMs.hashCode ^
+      createdAtMs.hashCode ^
+      modifiedAtMs.hashCode ^
+      sizeBytes.hashCode ^
+      extension.hashCode ^
+      mimeType.hashCode ^
+      access.hashCode ^
+      selfGenerated.hashCode ^
+      reconciled.hashCode ^
+      claim.hashCode ^
+      codes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FileEvent &&
+          runtimeType == other.runtimeType &&
+          sequence == other.sequence &&
+          watcherId == other.watcherId &&
+          kind == other.kind &&
+          fileName == other.fileName &&
+          fullPath == other.fullPath &&
+          previousPath == other.previousPath &&
+          occurredAtMs == other.occurredAtMs &&
+          detectedAtMs == other.detectedAtMs &&
+          monotonicMs == other.monotonicMs &&
+          createdAtMs == other.createdAtMs &&
+          modifiedAtMs == other.modifiedAtMs &&
+          sizeBytes == other.sizeBytes &&
+          extension == other.extension &&
+          mimeType == other.mimeType &&
+          access == other.access &&
+          selfGenerated == other.selfGenerated &&
+          reconciled == other.reconciled &&
+          claim == other.claim &&
+          codes == other.codes;
+}
+
+/// Вид события файла (см. [`FileEvent`]).
+enum FileEventKind {
+  created,
+  modified,
+  removed,
+  /// Старый путь переименованного/перемещённого файла.
+  renamedFrom,
+  /// Новый путь переименованного/перемещённого файла.
+  renamedTo,
+  /// В появившемся изображении найдены коды ([`FileEvent::codes`]).
+  /// Приходит после события появления, если включён
+  /// [`set_code_scanning`].
+  codesDetected,
+}
+
+/// Хэш содержимого файла, посчитанный в фоне после события watcher'а.
+class FileHashEvent {
+  final String watcherId;
+  final String fullPath;
+  final HashAlgorithm algorithm;
+
+  /// Hex в нижнем регистре.
+  final String hash;
+
+  const FileHashEvent({
+    required this.watcherId,
+    required this.fullPath,
+    required this.algorithm,
+    required this.hash,
+  });
+
+  @override
+  int get hashCode =>
+      watcherId.hashCode ^
+      fullPath.hashCode ^
+      algorithm.hashCode ^
+      hash.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FileHashEvent &&
+          runtimeType == other.runtimeType &&
+          watcherId == other.watcherId &&
+          fullPath == other.fullPath &&
+          algorithm == other.algorithm &&
+          hash == other.hash;
+}
+
+/// Ход операции из [`move_file`], [`copy_file`] или [`rename_file`].
+class FileOpEvent {
+  /// Идентификатор, который вернула функция запуска.
+  final int operationId;
+  final FileOpKind kind;
+  final String src;
+  final String dst;
+  final FileOpStatus status;
+
+  /// Для переименования и переноса в пределах тома остаются 0.
+  final int bytesDone;
+  final int bytesTotal;
+
+  /// Код [`LateraError`] при `Failed`.
+  final String? errorCode;
+  final String? errorMessage;
+
+  const FileOpEvent({
+    required this.operationId,
+    required this.kind,
+    required this.src,
+    required this.dst,
+    required this.status,
+    required this.bytesDone,
+    required this.bytesTotal,
+    this.errorCode,
+    this.errorMessage,
+  });
+
+  @override
+  int get hashCode =>
+      operationId.hashCode ^
+      kind.hashCode ^
+      src.hashCode ^
+      dst.hashCode ^
+      status.hashCode ^
+      bytesDone.hashCode ^
+      bytesTotal.hashCode ^
+      errorCode.hashCode ^
+      errorMessage.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FileOpEvent &&
+          runtimeType == other.runtimeType &&
+          operationId == other.operationId &&
+          kind == other.kind &&
+          src == other.src &&
+          dst == other.dst &&
+          status == other.status &&
+          bytesDone == other.bytesDone &&
+          bytesTotal == other.bytesTotal &&
+          errorCode == other.errorCode &&
+          errorMessage == other.errorMessage;
+}
+
+/// Вид файловой операции.
+enum FileOpKind { move, copy, rename }
+
+/// Состояние файловой операции.
+enum FileOpStatus { running, completed, failed }
+
+/// Статус обработки файла (FRB bridge type).
+enum FileProcessingStatus { new_, processing, processed, failed, quarantined }
+
+/// Событие: файл удалён.
+class FileRemovedEvent {
+  final String fileName;
+  final String fullPath;
+  final int occurredAtMs;
+
+  const FileRemovedEvent({
+    required this.fileName,
+    required this.fullPath,
+    required this.occurredAtMs,
+  });
+
+  @override
+  int get hashCode =>
+      fileName.hashCode ^ fullPath.hashCode ^ occurredAtMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FileRemovedEvent &&
+          runtimeType == other.runtimeType &&
+          fileName == other.fileName &&
+          fullPath == other.fullPath &&
+          occurredAtMs == other.occurredAtMs;
+}
+
+/// Правило организации файлов (FRB bridge type). Пустые условия не
+/// ограничивают выбор.
+class FileRule {
+  final String id;
+
+  /// Шаблоны имени файла (`*.pdf`, `IMG_*`); хотя бы один должен совпасть.
+  final List<String> nameGlobs;
+
+  /// MIME-типы по содержимому: `application/pdf` или `image/*`.
+  final List<String> mimeTypes;
+  final int? minSizeBytes;
+  final int? maxSizeBytes;
+  final FileRuleAction action;
+
+  const FileRule({
+    required this.id,
+    required this.nameGlobs,
+    required this.mimeTypes,
+    this.minSizeBytes,
+    this.maxSizeBytes,
+    required this.action,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      nameGlobs.hashCode ^
+      mimeTypes.hashCode ^
+      minSizeBytes.hashCode ^
+      maxSizeBytes.hashCode ^
+      action.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FileRule &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          nameGlobs == other.nameGlobs &&
+          mimeTypes == other.mimeTypes &&
+          minSizeBytes == other.minSizeBytes &&
+          maxSizeBytes == other.maxSizeBytes &&
+          action == other.action;
+}
+
+/// Действие правила организации файлов (FRB bridge type).
+@freezed
+sealed class FileRuleAction with _$FileRuleAction {
+  const FileRuleAction._();
+
+  /// Перенести в подпапку (относительный путь) папки, где появился файл.
+  const factory FileRuleAction.moveToSubfolder({
+    required String subfolder,
+  }) = FileRuleAction_MoveToSubfolder;
+
+  /// Переименовать по шаблону: `{name}`, `{ext}` (с точкой), `{date}`
+  /// (`YYYY-MM-DD`, по mtime), `{year}`, `{month}`, `{day}`.
+  const factory FileRuleAction.rename({
+    required String template,
+  }) = FileRuleAction_Rename;
+
+  /// Пометить тегом (см. [`add_tag`]).
+  const factory FileRuleAction.tag({required String tag}) = FileRuleAction_Tag;
+
+  /// Не сообщать о файле и не обрабатывать его.
+  const factory FileRuleAction.ignore() = FileRuleAction_Ignore;
+}
+
+/// Событие: изменился статус файла.
+class FileStatusChangedEvent {
+  final String fullPath;
+
+  /// `None` — файл удалён и больше не отслеживается.
+  final FileProcessingStatus? status;
+  final int updatedAtMs;
+
+  const FileStatusChangedEvent({
+    required this.fullPath,
+    this.status,
+    required this.updatedAtMs,
+  });
+
+  @override
+  int get hashCode =>
+      fullPath.hashCode ^ status.hashCode ^ updatedAtMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FileStatusChangedEvent &&
+          runtimeType == other.runtimeType &&
+          fullPath == other.fullPath &&
+          status == other.status &&
+          updatedAtMs == other.updatedAtMs;
+}
+
+/// Обновление статуса одного файла для [`set_file_statuses`].
+class FileStatusUpdate {
+  final String fullPath;
+  final FileProcessingStatus status;
+
+  const FileStatusUpdate({required this.fullPath, required this.status});
+
+  @override
+  int get hashCode => fullPath.hashCode ^ status.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FileStatusUpdate &&
+          runtimeType == other.runtimeType &&
+          fullPath == other.fullPath &&
+          status == other.status;
+}
+
+/// Маркер папки Latera (FRB bridge type).
+class FolderMarkerInfo {
+  final FolderMarkerStatus status;
+
+  /// Идентификатор папки; сохраняется при переустановке приложения.
+  final String? folderId;
+  final int? createdAtMs;
+  final String? settingsHash;
+
+  /// Маркер записан с теми же настройками, что действуют сейчас.
+  final bool settingsMatch;
+
+  /// Почему маркер не читается (для `Corrupt`).
+  final String? error;
+
+  const FolderMarkerInfo({
+    required this.status,
+    this.folderId,
+    this.createdAtMs,
+    this.settingsHash,
+    required this.settingsMatch,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      status.hashCode ^
+      folderId.hashCode ^
+      createdAtMs.hashCode ^
+      settingsHash.hashCode ^
+      settingsMatch.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FolderMarkerInfo &&
+          runtimeType == other.runtimeType &&
+          status == other.status &&
+          folderId == other.folderId &&
+          createdAtMs == other.createdAtMs &&
+          settingsHash == other.settingsHash &&
+          settingsMatch == other.settingsMatch &&
+          error == other.error;
+}
+
+/// Состояние маркера папки Latera.
+enum FolderMarkerStatus {
+  /// Маркера нет: папку Latera не создавала (или маркер удалён).
+  missing,
+  /// Маркер прежних версий без данных; [`repair_folder_marker`] заменит его.
+  legacy,
+  /// Файл маркера повреждён.
+  corrupt,
+  valid,
+}
+
+/// Алгоритм хэширования содержимого.
+enum HashAlgorithm {
+  /// Быстрый; по умолчанию.
+  blake3,
+  /// Для сверки с внешними системами.
+  sha256,
+}
+
+/// Пульс ядра (см. [`on_heartbeat`]).
+class HeartbeatEvent {
+  /// Порядковый номер пульса (с 1, в пределах подписки).
+  final int sequence;
+
+  /// Время отправки (Unix timestamp в миллисекундах).
+  final int emittedAtMs;
+
+  /// Время с момента подписки (монотонное, не зависит от смены часов).
+  final int uptimeMs;
+
+  /// Запущен ли watcher.
+  final bool watcherRunning;
+
+  const HeartbeatEvent({
+    required this.sequence,
+    required this.emittedAtMs,
+    required this.uptimeMs,
+    required this.watcherRunning,
+  });
+
+  @override
+  int get hashCode =>
+      sequence.hashCode ^
+      emittedAtMs.hashCode ^
+      uptimeMs.hashCode ^
+      watcherRunning.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HeartbeatEvent &&
+          runtimeType == other.runtimeType &&
+          sequence == other.sequence &&
+          emittedAtMs == other.emittedAtMs &&
+          uptimeMs == other.uptimeMs &&
+          watcherRunning == other.watcherRunning;
+}
+
+/// Расхождение в папке watcher'а.
+class IntegrityIssue {
+  final String watcherId;
+  final IntegrityIssueKind kind;
+  final String fullPath;
+
+  const IntegrityIssue({
+    required this.watcherId,
+    required this.kind,
+    required this.fullPath,
+  });
+
+  @override
+  int get hashCode => watcherId.hashCode ^ kind.hashCode ^ fullPath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IntegrityIssue &&
+          runtimeType == other.runtimeType &&
+          watcherId == other.watcherId &&
+          kind == other.kind &&
+          fullPath == other.fullPath;
+}
+
+/// Вид расхождения (см. [`verify_folder_integrity`]).
+enum IntegrityIssueKind {
+  /// Файл есть в поисковом индексе, но не на диске.
+  indexedButMissing,
+  /// По журналу событий файл существует, но на диске его нет.
+  knownButMissing,
+  /// Файл есть на диске, но журнал событий о нём не знает.
+  presentButUnknown,
+}
+
+/// Ошибка API в структурированном виде.
+///
+/// В отличие от [`LateraError`] (непрозрачный тип для Dart, разбираемый по
+/// строке `Display`), поля доступны Dart напрямую. Функции API переводятся
+/// на этот тип постепенно; пока его возвращают [`start_watching`] и
+/// [`stop_watching`].
+class LateraApiError {
+  /// Машиночитаемый код (`WATCHER_ALREADY_RUNNING`, `INVALID_PATH`…),
+  /// см. [`LateraError::code`].
+  final String code;
+
+  /// Сообщение без префикса `LateraError::Variant:`.
+  final String message;
+
+  /// Можно продолжить работу (повторить после очевидного действия).
+  final bool recoverable;
+
+  /// Подробности для логов (вид ошибки ввода-вывода, путь).
+  final String? context;
+
+  const LateraApiError({
+    required this.code,
+    required this.message,
+    required this.recoverable,
+    this.context,
+  });
+
+  @override
+  int get hashCode =>
+      code.hashCode ^
+      message.hashCode ^
+      recoverable.hashCode ^
+      context.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LateraApiError &&
+          runtimeType == other.runtimeType &&
+          code == other.code &&
+          message == other.message &&
+          recoverable == other.recoverable &&
+          context == other.context;
+}
+
+/// Ротация и хранение основного лог-файла (FRB bridge type).
+class LogFileOptions {
+  /// Размер файла, после которого он ротируется.
+  final int maxFileBytes;
+
+  /// Сколько ротированных файлов хранить.
+  final int maxRotatedFiles;
+
+  /// Лимит суммарного размера текущего файла и архивов.
+  final int maxTotalBytes;
+
+  /// Сжимать ротированные файлы gzip'ом.
+  final bool compress;
+
+  const LogFileOptions({
+    required this.maxFileBytes,
+    required this.maxRotatedFiles,
+    required this.maxTotalBytes,
+    required this.compress,
+  });
+
+  @override
+  int get hashCode =>
+      maxFileBytes.hashCode ^
+      maxRotatedFiles.hashCode ^
+      maxTotalBytes.hashCode ^
+      compress.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LogFileOptions &&
+          runtimeType == other.runtimeType &&
+          maxFileBytes == other.maxFileBytes &&
+          maxRotatedFiles == other.maxRotatedFiles &&
+          maxTotalBytes == other.maxTotalBytes &&
+          compress == other.compress;
+}
+
+/// Запись лога Rust Core (FRB bridge type, см. [`on_log`]).
+class LogRecord {
+  /// `ERROR`, `WARN`, `INFO`, `DEBUG` или `TRACE`.
+  final String level;
+  final String target;
+  final String message;
+
+  /// ISO 8601 с миллисекундами и смещением.
+  final String timestamp;
+
+  /// `correlation_id` записи ([`logging::LogContext`]), если есть.
+  final String? correlationId;
+
+  const LogRecord({
+    required this.level,
+    required this.target,
+    required this.message,
+    required this.timestamp,
+    this.correlationId,
+  });
+
+  @override
+  int get hashCode =>
+      level.hashCode ^
+      target.hashCode ^
+      message.hashCode ^
+      timestamp.hashCode ^
+      correlationId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LogRecord &&
+          runtimeType == other.runtimeType &&
+          level == other.level &&
+          target == other.target &&
+          message == other.message &&
+          timestamp == other.timestamp &&
+          correlationId == other.correlationId;
+}
+
+/// Опции OCR (FRB bridge type).
+///
+/// Передаются из Flutter-стороны для уважения пользовательских лимитов
+/// (`AppConfig.effectiveLimits`).
+class OcrOptions {
+  /// Максимальное количество страниц скан-PDF для обработки.
+  final int maxPagesPerPdf;
+
+  /// Максимальный размер файла в мегабайтах.
+  final int maxFileSizeMb;
+
+  /// Язык OCR (ISO 639-1, например "rus", "eng").
+  /// `None` = автоопределение / eng по умолчанию.
+  final String? language;
+
+  const OcrOptions({
+    required this.maxPagesPerPdf,
+    required this.maxFileSizeMb,
+    this.language,
+  });
+
+  @override
+  int get hashCode =>
+      maxPagesPerPdf.hashCode ^ maxFileSizeMb.hashCode ^ language.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OcrOptions &&
+          runtimeType == other.runtimeType &&
+          maxPagesPerPdf == other.maxPagesPerPdf &&
+          maxFileSizeMb == other.maxFileSizeMb &&
+          language == other.language;
+}
+
+/// Результат OCR (FRB bridge type).
+class OcrResult {
+  /// Распознанный текст (может быть пуст при ошибке).
+  final String text;
+
+  /// Тип контента: `"image"`, `"scan_pdf"`, `"unsupported"`, `"unknown"`.
+  final String contentType;
+
+  /// Количество обработанных страниц.
+  final int pagesProcessed;
+
+  /// Уверенность распознавания (0.0 – 1.0), `None` при ошибке.
+  final double? confidence;
+
+  /// Код ошибки (None = успех).
+  ///
+  /// Возможные значения:
+  /// - `"file_too_large"` — файл превышает лимит
+  /// - `"too_many_pages"` — скан-PDF превышает лимит страниц
+  /// - `"unsupported_format"` — формат не поддерживается
+  /// - `"ocr_failed"` — внутренняя ошибка OCR-движка
+  /// - `"file_not_found"` — файл не найден
+  /// - `"not_implemented"` — OCR ещё не подключён (stub)
+  /// - `"empty_image"` — изображение не содержит текста
+  final String? errorCode;
+
+  const OcrResult({
+    required this.text,
+    required this.contentType,
+    required this.pagesProcessed,
+    this.confidence,
+    this.errorCode,
+  });
+
+  @override
+  int get hashCode =>
+      text.hashCode ^
+      contentType.hashCode ^
+      pagesProcessed.hashCode ^
+      confidence.hashCode ^
+      errorCode.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OcrResult &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          contentType == other.contentType &&
+          pagesProcessed == other.pagesProcessed &&
+          confidence == other.confidence &&
+          errorCode == other.errorCode;
+}
+
+/// Состояние мастера первого запуска.
+class OnboardingState {
+  /// Шаг, который показывает мастер; `None` — мастер пройден.
+  final OnboardingStep? currentStep;
+
+  /// Завершённые шаги по порядку.
+  final List<OnboardingStep> completedSteps;
+
+  /// Папка наблюдения: запущенного watcher'а, из настроек или по умолчанию.
+  final String folderPath;
+  final bool folderExists;
+
+  /// Папка (или, пока её нет, родительская) читается.
+  final bool accessGranted;
+
+  /// Когда через watcher прошло первое событие (Unix ms); `None` — ещё нет.
+  final int? firstEventAtMs;
+
+  const OnboardingState({
+    this.currentStep,
+    required this.completedSteps,
+    required this.folderPath,
+    required this.folderExists,
+    required this.accessGranted,
+    this.firstEventAtMs,
+  });
+
+  @override
+  int get hashCode =>
+      currentStep.hashCode ^
+      completedSteps.hashCode ^
+      folderPath.hashCode ^
+      folderExists.hashCode ^
+      accessGranted.hashCode ^
+      firstEventAtMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OnboardingState &&
+          runtimeType == other.runtimeType &&
+          currentStep == other.currentStep &&
+          completedSteps == other.completedSteps &&
+          folderPath == other.folderPath &&
+          folderExists == other.folderExists &&
+          accessGranted == other.accessGranted &&
+          firstEventAtMs == other.firstEventAtMs;
+}
+
+/// Шаг мастера первого запуска.
+enum OnboardingStep {
+  /// Папка наблюдения выбрана и существует.
+  chooseFolder,
+  /// Приложение может читать папку (на macOS — доступ к «Рабочему столу»).
+  grantAccess,
+  /// Через watcher прошло первое событие.
+  firstEvent,
+}
+
+/// Схема страницы PDF (FRB bridge type).
+class PdfPagePreview {
+  /// Номер страницы (с 1).
+  final int page;
+  final int width;
+  final int height;
+
+  /// PNG-изображение страницы.
+  final Uint8List png;
+
+  const PdfPagePreview({
+    required this.page,
+    required this.width,
+    required this.height,
+    required this.png,
+  });
+
+  @override
+  int get hashCode =>
+      page.hashCode ^ width.hashCode ^ height.hashCode ^ png.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PdfPagePreview &&
+          runtimeType == other.runtimeType &&
+          page == other.page &&
+          width == other.width &&
+          height == other.height &&
+          png == other.png;
+}
+
+/// Действующая политика администратора.
+class PolicyStatus {
+  /// Файл политики; `None` — установка не управляется.
+  final String? policyPath;
+  final List<ApiLockedOption> lockedOptions;
+
+  const PolicyStatus({this.policyPath, required this.lockedOptions});
+
+  @override
+  int get hashCode => policyPath.hashCode ^ lockedOptions.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PolicyStatus &&
+          runtimeType == other.runtimeType &&
+          policyPath == other.policyPath &&
+          lockedOptions == other.lockedOptions;
+}
+
+/// Результат RAG-запроса (FRB bridge type).
+class RagQueryResult {
+  /// Сгенерированный ответ.
+  final String answer;
+
+  /// Код ошибки (None = успех).
+  ///
+  /// Возможные значения:
+  /// - `"no_relevant_chunks"` — не найдено релевантных чанков
+  /// - `"empty_question"` — пустой вопрос
+  /// - `"query_failed"` — ошибка при выполнении запроса
+  final String? errorCode;
+
+  /// Источники (чанки, из которых сформирован ответ).
+  final List<ApiRagSource> sources;
+
+  const RagQueryResult({
+    required this.answer,
+    this.errorCode,
+    required this.sources,
+  });
+
+  @override
+  int get hashCode => answer.hashCode ^ errorCode.hashCode ^ sources.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RagQueryResult &&
+          runtimeType == other.runtimeType &&
+          answer == other.answer &&
+          errorCode == other.errorCode &&
+          sources == other.sources;
+}
+
+/// Файл в отчётах для очистки.
+class ReportFile {
+  final String fullPath;
+  final int sizeBytes;
+
+  /// Unix timestamp в миллисекундах, если ФС его сообщает.
+  final int? modifiedAtMs;
+  final FileCategory category;
+
+  const ReportFile({
+    required this.fullPath,
+    required this.sizeBytes,
+    this.modifiedAtMs,
+    required this.category,
+  });
+
+  @override
+  int get hashCode =>
+      fullPath.hashCode ^
+      sizeBytes.hashCode ^
+      modifiedAtMs.hashCode ^
+      category.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReportFile &&
+          runtimeType == other.runtimeType &&
+          fullPath == other.fullPath &&
+          sizeBytes == other.sizeBytes &&
+          modifiedAtMs == other.modifiedAtMs &&
+          category == other.category;
+}
+
+/// Правило, выполненное для появившегося файла (см. [`on_rule_applied`]).
+class RuleAppliedEvent {
+  final String watcherId;
+  final String ruleId;
+  final FileRuleAction action;
+
+  /// Путь, по которому файл появился.
+  final String path;
+
+  /// Куда файл перенесён или как переименован.
+  final String? newPath;
+
+  /// Unix timestamp в миллисекундах.
+  final int appliedAtMs;
+
+  const RuleAppliedEvent({
+    required this.watcherId,
+    required this.ruleId,
+    required this.action,
+    required this.path,
+    this.newPath,
+    required this.appliedAtMs,
+  });
+
+  @override
+  int get hashCode =>
+      watcherId.hashCode ^
+      ruleId.hashCode ^
+      action.hashCode ^
+      path.hashCode ^
+      newPath.hashCode ^
+      appliedAtMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RuleAppliedEvent &&
+          runtimeType == other.runtimeType &&
+          watcherId == other.watcherId &&
+          ruleId == other.ruleId &&
+          action == other.action &&
+          path == other.path &&
+          newPath == other.newPath &&
+          appliedAtMs == other.appliedAtMs;
+}
+
+/// Появившийся скриншот (см. [`start_watching_screenshots`]).
+class ScreenshotAddedEvent {
+  final String watcherId;
+  final String fileName;
+  final String fullPath;
+  final int occurredAtMs;
+  final int sizeBytes;
+  final String? mimeType;
+
+  const ScreenshotAddedEvent({
+    required this.watcherId,
+    required this.fileName,
+    required this.fullPath,
+    required this.occurredAtMs,
+    required this.sizeBytes,
+    this.mimeType,
+  });
+
+  @override
+  int get hashCode =>
+      watcherId.hashCode ^
+      fileName.hashCode ^
+      fullPath.hashCode ^
+      occurredAtMs.hashCode ^
+      sizeBytes.hashCode ^
+      mimeType.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ScreenshotAddedEvent &&
+          runtimeType == other.runtimeType &&
+          watcherId == other.watcherId &&
+          fileName == other.fileName &&
+          fullPath == other.fullPath &&
+          occurredAtMs == other.occurredAtMs &&
+          sizeBytes == other.sizeBytes &&
+          mimeType == other.mimeType;
+}
+
+/// Результат поиска, экспортируемый через FRB в Dart.
+class SearchResultItem {
+  final String filePath;
+  final String fileName;
+  final String description;
+  final String snippet;
+  final double rank;
+
+  const SearchResultItem({
+    required this.filePath,
+    required this.fileName,
+    required this.description,
+    required this.snippet,
+    required this.rank,
+  });
+
+  @override
+  int get hashCode =>
+      filePath.hashCode ^
+      fileName.hashCode ^
+      description.hashCode ^
+      snippet.hashCode ^
+      rank.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SearchResultItem &&
+          runtimeType == other.runtimeType &&
+          filePath == other.filePath &&
+          fileName == other.fileName &&
+          description == other.description &&
+          snippet == other.snippet &&
+          rank == other.rank;
+}
+
+/// Предпросмотр данных телеметрии, ожидающих отправки (FRB bridge type).
+class TelemetryPreview {
+  /// Включена ли телеметрия.
+  final bool enabled;
+
+  /// Накопленные счётчики.
+  final List<ApiTelemetryCounter> counters;
 
-  /// Смещение (байтовое) чанка в документе.
-  final int chunkOffset;
+  /// Ровно тот JSON, который будет отправлен на сервер.
+  final String payloadJson;
 
-  const ApiRagSource({
-    required this.filePath,
-    required this.chunkSnippet,
-    required this.chunkOffset,
+  const TelemetryPreview({
+    required this.enabled,
+    required this.counters,
+    required this.payloadJson,
   });
 
   @override
   int get hashCode =>
-      filePath.hashCode ^ chunkSnippet.hashCode ^ chunkOffset.hashCode;
+      enabled.hashCode ^ counters.hashCode ^ payloadJson.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is ApiRagSource &&
+      other is TelemetryPreview &&
           runtimeType == other.runtimeType &&
-          filePath == other.filePath &&
-          chunkSnippet == other.chunkSnippet &&
-          chunkOffset == other.chunkOffset;
+          enabled == other.enabled &&
+          counters == other.counters &&
+          payloadJson == other.payloadJson;
 }
 
-/// Результат similarity search (FRB bridge type).
-class ApiSimilarityResult {
-  /// Путь к файлу.
-  final String filePath;
-
-  /// Имя файла.
-  final String fileName;
+/// Ширина корзины временной шкалы активности.
+enum TimelineGranularity { hour, day, week }
 
-  /// Текст чанка, наиболее похожего на запрос.
-  final String chunkSnippet;
+/// Опции транскрибации (FRB bridge type).
+///
+/// Передаются из Flutter-стороны для уважения пользовательских лимитов
+/// (`AppConfig.effectiveLimits`).
+class TranscriptionOptions {
+  /// Максимальная длительность медиа для обработки (в минутах).
+  /// 0 = транскрибация отключена.
+  final int maxMediaMinutes;
 
-  /// Смещение чанка в документе.
-  final int chunkOffset;
+  /// Максимальный размер файла в мегабайтах.
+  final int maxFileSizeMb;
 
-  /// Косинусное сходство (0.0 – 1.0).
-  final double score;
+  /// Язык для транскрибации (ISO 639-1, например "ru", "en").
+  /// `None` = автоопределение.
+  final String? language;
 
-  const ApiSimilarityResult({
-    required this.filePath,
-    required this.fileName,
-    required this.chunkSnippet,
-    required this.chunkOffset,
-    required this.score,
+  const TranscriptionOptions({
+    required this.maxMediaMinutes,
+    required this.maxFileSizeMb,
+    this.language,
   });
 
   @override
   int get hashCode =>
-      filePath.hashCode ^
-      fileName.hashCode ^
-      chunkSnippet.hashCode ^
-      chunkOffset.hashCode ^
-      score.hashCode;
+      maxMediaMinutes.hashCode ^ maxFileSizeMb.hashCode ^ language.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is ApiSimilarityResult &&
+      other is TranscriptionOptions &&
           runtimeType == other.runtimeType &&
-          filePath == other.filePath &&
-          fileName == other.fileName &&
-          chunkSnippet == other.chunkSnippet &&
-          chunkOffset == other.chunkOffset &&
-          score == other.score;
+          maxMediaMinutes == other.maxMediaMinutes &&
+          maxFileSizeMb == other.maxFileSizeMb &&
+          language == other.language;
 }
 
-/// Текстовый чанк (FRB bridge type).
-class ApiTextChunk {
-  /// Текст чанка.
+/// Результат транскрибации (FRB bridge type).
+class TranscriptionResult {
+  /// Расшифрованный текст (может быть пуст при ошибке).
   final String text;
 
-  /// Индекс чанка в документе (0-based).
-  final int chunkIndex;
+  /// Тип контента: `"audio"`, `"video"`, `"unsupported"`, `"unknown"`.
+  final String contentType;
 
-  /// Смещение (байтовое) от начала документа.
-  final int chunkOffset;
+  /// Длительность обработанного медиа в секундах.
+  final int durationSeconds;
 
-  const ApiTextChunk({
+  /// Код ошибки (None = успех).
+  ///
+  /// Возможные значения:
+  /// - `"file_too_large"` — файл превышает лимит
+  /// - `"media_too_long"` — медиа превышает лимит длительности
+  /// - `"unsupported_format"` — формат не поддерживается
+  /// - `"transcription_disabled"` — транскрибация отключена (max_media_minutes=0)
+  /// - `"transcription_failed"` — внутренняя ошибка
+  /// - `"file_not_found"` — файл не найден
+  /// - `"not_implemented"` — Whisper ещё не подключён
+  final String? errorCode;
+
+  const TranscriptionResult({
     required this.text,
-    required this.chunkIndex,
-    required this.chunkOffset,
+    required this.contentType,
+    required this.durationSeconds,
+    this.errorCode,
   });
 
   @override
   int get hashCode =>
-      text.hashCode ^ chunkIndex.hashCode ^ chunkOffset.hashCode;
+      text.hashCode ^
+      contentType.hashCode ^
+      durationSeconds.hashCode ^
+      errorCode.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is ApiTextChunk &&
+      other is TranscriptionResult &&
           runtimeType == other.runtimeType &&
           text == other.text &&
-          chunkIndex == other.chunkIndex &&
-          chunkOffset == other.chunkOffset;
+          contentType == other.contentType &&
+          durationSeconds == other.durationSeconds &&
+          errorCode == other.errorCode;
 }
 
-/// Событие: добавлен новый файл.
+/// Число событий с файлами одного MIME-типа.
+class TypeCount {
+  final String mimeType;
+  final int count;
+
+  const TypeCount({required this.mimeType, required this.count});
+
+  @override
+  int get hashCode => mimeType.hashCode ^ count.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TypeCount &&
+          runtimeType == other.runtimeType &&
+          mimeType == other.mimeType &&
+          count == other.count;
+}
+
+/// Том папки и умолчания наблюдения для него (FRB bridge type).
+class VolumeInfo {
+  final VolumeKind kind;
+  final String? mountPoint;
+
+  /// `apfs`, `ntfs`, `smbfs`…; пусто, если неизвестна.
+  final String fileSystem;
+
+  /// `None` — тип накопителя неизвестен.
+  final bool? isSsd;
+  final String? cloudProvider;
+
+  /// Период опроса, если watcher перейдёт на опрос.
+  final int pollIntervalMs;
+
+  /// Ожидание стабилизации файла; `None` — события сразу.
+  final int? settleQuietPeriodMs;
+
+  /// Хэшируются ли появившиеся файлы (см. [`set_event_hashing`]).
+  final bool hashingEnabled;
+
+  const VolumeInfo({
+    required this.kind,
+    this.mountPoint,
+    required this.fileSystem,
+    this.isSsd,
+    this.cloudProvider,
+    required this.pollIntervalMs,
+    this.settleQuietPeriodMs,
+    required this.hashingEnabled,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      mountPoint.hashCode ^
+      fileSystem.hashCode ^
+      isSsd.hashCode ^
+      cloudProvider.hashCode ^
+      pollIntervalMs.hashCode ^
+      settleQuietPeriodMs.hashCode ^
+      hashingEnabled.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is VolumeInfo &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          mountPoint == other.mountPoint &&
+          fileSystem == other.fileSystem &&
+          isSsd == other.isSsd &&
+          cloudProvider == other.cloudProvider &&
+          pollIntervalMs == other.pollIntervalMs &&
+          settleQuietPeriodMs == other.settleQuietPeriodMs &&
+          hashingEnabled == other.hashingEnabled;
+}
+
+/// Тип тома (см. [`get_volume_info`]).
+enum VolumeKind {
+  internal,
+  /// Флешка, SD-карта, внешний USB-диск.
+  removable,
+  /// SMB, NFS, AFP, WebDAV.
+  network,
+  /// Папка облачного клиента (OneDrive, iCloud Drive, Dropbox, Google Drive).
+  cloudSynced,
+  unknown,
+}
+
+/// Что делать, если папка наблюдения пропала (удалена, переименована,
+/// отключён диск).
+enum WatchDirRecoveryMode {
+  /// Остановить watcher ([`ApiWatcherErrorCode::WatchDirLost`]).
+  stop,
+  /// Ждать, пока папка появится снова, и продолжить (по умолчанию).
+  waitForReturn,
+  /// Создать папку заново и продолжить; если нет и родительской папки
+  /// (отключён диск) — ждать её возвращения.
+  recreate,
+}
+
+/// Фильтр файлов watcher'а (FRB bridge type).
 ///
-/// Поля подобраны так, чтобы их было удобно бриджить во Flutter.
-class FileAddedEvent {
-  final String fileName;
-  final String fullPath;
-  final int occurredAtMs;
+/// Шаблон без `/` сравнивается с именем файла (`*.pdf`, `~$*.tmp`), с `/` —
+/// с путём относительно watch dir (`drafts/*.docx`). Регистр не учитывается.
+class WatchFilter {
+  /// Хотя бы один должен совпасть (пусто — любые файлы).
+  final List<String> includeGlobs;
 
-  const FileAddedEvent({
-    required this.fileName,
-    required this.fullPath,
-    required this.occurredAtMs,
+  /// Ни один не должен совпасть.
+  final List<String> excludeGlobs;
+
+  /// Допустимые расширения без точки, например `["pdf", "docx"]` (пусто — любые).
+  final List<String> extensions;
+
+  /// Сообщать ли скрытые файлы (имя начинается с `.`).
+  final bool includeHidden;
+
+  const WatchFilter({
+    required this.includeGlobs,
+    required this.excludeGlobs,
+    required this.extensions,
+    required this.includeHidden,
   });
 
   @override
   int get hashCode =>
-      fileName.hashCode ^ fullPath.hashCode ^ occurredAtMs.hashCode;
+      includeGlobs.hashCode ^
+      excludeGlobs.hashCode ^
+      extensions.hashCode ^
+      includeHidden.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is FileAddedEvent &&
+      other is WatchFilter &&
           runtimeType == other.runtimeType &&
-          fileName == other.fileName &&
-          fullPath == other.fullPath &&
-          occurredAtMs == other.occurredAtMs;
+          includeGlobs == other.includeGlobs &&
+          excludeGlobs == other.excludeGlobs &&
+          extensions == other.extensions &&
+          includeHidden == other.includeHidden;
 }
 
-/// Событие: файл удалён.
-class FileRemovedEvent {
-  final String fileName;
-  final String fullPath;
-  final int occurredAtMs;
+/// Откуда взят вариант папки наблюдения.
+enum WatchLocationKind {
+  /// `Desktop/Latera` — вариант по умолчанию.
+  desktopFolder,
+  downloads,
+  /// `Documents/Latera`.
+  documentsFolder,
+}
 
-  const FileRemovedEvent({
-    required this.fileName,
-    required this.fullPath,
-    required this.occurredAtMs,
+/// Вариант папки наблюдения для экрана онбординга.
+class WatchLocationSuggestion {
+  final WatchLocationKind kind;
+  final String path;
+
+  /// Папка уже существует (иначе будет создана при старте watcher'а).
+  final bool exists;
+
+  /// Текущий пользователь может писать в папку (или создать её).
+  final bool writable;
+
+  /// Свободное место на томе; `0` — не удалось определить.
+  final int availableBytes;
+
+  const WatchLocationSuggestion({
+    required this.kind,
+    required this.path,
+    required this.exists,
+    required this.writable,
+    required this.availableBytes,
   });
 
   @override
   int get hashCode =>
-      fileName.hashCode ^ fullPath.hashCode ^ occurredAtMs.hashCode;
+      kind.hashCode ^
+      path.hashCode ^
+      exists.hashCode ^
+      writable.hashCode ^
+      availableBytes.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is FileRemovedEvent &&
+      other is WatchLocationSuggestion &&
           runtimeType == other.runtimeType &&
-          fileName == other.fileName &&
-          fullPath == other.fullPath &&
-          occurredAtMs == other.occurredAtMs;
+          kind == other.kind &&
+          path == other.path &&
+          exists == other.exists &&
+          writable == other.writable &&
+          availableBytes == other.availableBytes;
 }
 
-/// Результат RAG-запроса (FRB bridge type).
-class RagQueryResult {
-  /// Сгенерированный ответ.
-  final String answer;
+/// Результат [`start_watching`] (FRB bridge type).
+class WatchStarted {
+  final String watcherId;
 
-  /// Код ошибки (None = успех).
-  ///
-  /// Возможные значения:
-  /// - `"no_relevant_chunks"` — не найдено релевантных чанков
-  /// - `"empty_question"` — пустой вопрос
-  /// - `"query_failed"` — ошибка при выполнении запроса
-  final String? errorCode;
+  /// Как watcher получает изменения сразу после запуска.
+  final ApiWatchMode backend;
 
-  /// Источники (чанки, из которых сформирован ответ).
-  final List<ApiRagSource> sources;
+  const WatchStarted({required this.watcherId, required this.backend});
 
-  const RagQueryResult({
-    required this.answer,
-    this.errorCode,
-    required this.sources,
+  @override
+  int get hashCode => watcherId.hashCode ^ backend.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WatchStarted &&
+          runtimeType == other.runtimeType &&
+          watcherId == other.watcherId &&
+          backend == other.backend;
+}
+
+/// Статусное событие наблюдаемой папки (см. [`on_watch_status`]).
+@freezed
+sealed class WatchStatusEvent with _$WatchStatusEvent {
+  const WatchStatusEvent._();
+
+  /// На томе с watch dir осталось меньше `threshold_bytes` свободного места.
+  /// Функции копирования в watch dir приостановлены.
+  const factory WatchStatusEvent.lowDiskSpace({
+    required int availableBytes,
+    required int thresholdBytes,
+  }) = WatchStatusEvent_LowDiskSpace;
+
+  /// Свободного места снова достаточно; копирование возобновлено.
+  const factory WatchStatusEvent.diskSpaceRecovered({
+    required int availableBytes,
+  }) = WatchStatusEvent_DiskSpaceRecovered;
+
+  /// Папка watcher'а `watcher_id` превысила квоту (см. [`set_folder_quota`]).
+  /// Лимит `0` — не задан. Если включена очистка, `removed_files`/`freed_bytes` —
+  /// её результат.
+  const factory WatchStatusEvent.quotaExceeded({
+    required String watcherId,
+    required int totalBytes,
+    required int fileCount,
+    required int maxTotalBytes,
+    required int maxFileCount,
+    required int removedFiles,
+    required int freedBytes,
+  }) = WatchStatusEvent_QuotaExceeded;
+
+  /// Появился файл с тем же именем (без учёта регистра), что и уже
+  /// известный, но с другим содержимым. Отпечатки — SHA-256 в hex;
+  /// по ним UI предлагает «оставить оба» / «заменить».
+  const factory WatchStatusEvent.nameConflict({
+    required String fileName,
+    required String newPath,
+    required String newFingerprint,
+    required String existingPath,
+    required String existingFingerprint,
+  }) = WatchStatusEvent_NameConflict;
+
+  /// Файл настроек ядра оказался повреждён и восстановлен из резервной
+  /// копии `backup_index` (`1` — самая свежая; `0` — целых копий нет,
+  /// настройки сброшены). Повреждённый файл отложен в `corrupt_path`.
+  const factory WatchStatusEvent.settingsRecovered({
+    required int backupIndex,
+    required String corruptPath,
+  }) = WatchStatusEvent_SettingsRecovered;
+
+  /// Папка watcher'а `watcher_id` пропала (удалена, переименована,
+  /// отключён диск). Дальше — по [`set_watch_dir_recovery`].
+  const factory WatchStatusEvent.watchDirLost({
+    required String watcherId,
+    required String path,
+  }) = WatchStatusEvent_WatchDirLost;
+
+  /// Папка снова доступна, наблюдение возобновлено.
+  const factory WatchStatusEvent.watchDirRestored({
+    required String watcherId,
+    required String path,
+  }) = WatchStatusEvent_WatchDirRestored;
+}
+
+/// Активность одной подпапки наблюдаемой директории.
+class WatchTreeStat {
+  /// Путь относительно watch dir (`""` — сама watch dir).
+  final String relativePath;
+
+  /// Сколько событий ФС пришло из подпапки с момента запуска watcher'а.
+  final int eventCount;
+
+  /// Время последнего события (Unix timestamp в миллисекундах).
+  final int lastActivityMs;
+
+  const WatchTreeStat({
+    required this.relativePath,
+    required this.eventCount,
+    required this.lastActivityMs,
   });
 
   @override
-  int get hashCode => answer.hashCode ^ errorCode.hashCode ^ sources.hashCode;
+  int get hashCode =>
+      relativePath.hashCode ^ eventCount.hashCode ^ lastActivityMs.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is RagQueryResult &&
+      other is WatchTreeStat &&
           runtimeType == other.runtimeType &&
-          answer == other.answer &&
-          errorCode == other.errorCode &&
-          sources == other.sources;
+          relativePath == other.relativePath &&
+          eventCount == other.eventCount &&
+          lastActivityMs == other.lastActivityMs;
 }
 
-/// Результат поиска, экспортируемый через FRB в Dart.
-class SearchResultItem {
-  final String filePath;
-  final String fileName;
-  final String description;
-  final String snippet;
-  final double rank;
+/// Сглаживание событий одного watcher'а (см. [`start_watching`]).
+///
+/// `None` — значение из настроек ядра ([`get_config`]). Большие пачки мелких
+/// файлов требуют короткого окна и высокого лимита.
+class WatchTuning {
+  /// Повтор того же события по тому же пути в пределах окна
+  /// отбрасывается; `0` — без дедупликации.
+  final int? dedupWindowMs;
 
-  const SearchResultItem({
-    required this.filePath,
-    required this.fileName,
-    required this.description,
-    required this.snippet,
-    required this.rank,
+  /// Сколько событий в секунду отдаётся сразу; остальные — пачками
+  /// ([`on_file_batch`]).
+  final int? maxEventsPerSecond;
+
+  const WatchTuning({this.dedupWindowMs, this.maxEventsPerSecond});
+
+  @override
+  int get hashCode => dedupWindowMs.hashCode ^ maxEventsPerSecond.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WatchTuning &&
+          runtimeType == other.runtimeType &&
+          dedupWindowMs == other.dedupWindowMs &&
+          maxEventsPerSecond == other.maxEventsPerSecond;
+}
+
+/// Когда watcher переходит с нативного backend'а на опрос папки, а с
+/// опроса — на ручное обновление (FRB bridge type).
+class WatcherDegradationPolicy {
+  /// Сбоев нативного backend'а подряд до перехода на опрос.
+  final int nativeFailures;
+
+  /// Сбоев опроса подряд до перехода на ручное обновление.
+  final int pollingFailures;
+
+  /// Период опроса папки.
+  final int pollIntervalMs;
+
+  const WatcherDegradationPolicy({
+    required this.nativeFailures,
+    required this.pollingFailures,
+    required this.pollIntervalMs,
   });
 
   @override
   int get hashCode =>
-      filePath.hashCode ^
-      fileName.hashCode ^
-      description.hashCode ^
-      snippet.hashCode ^
-      rank.hashCode;
+      nativeFailures.hashCode ^
+      pollingFailures.hashCode ^
+      pollIntervalMs.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is SearchResultItem &&
+      other is WatcherDegradationPolicy &&
           runtimeType == other.runtimeType &&
-          filePath == other.filePath &&
-          fileName == other.fileName &&
-          description == other.description &&
-          snippet == other.snippet &&
-          rank == other.rank;
+          nativeFailures == other.nativeFailures &&
+          pollingFailures == other.pollingFailures &&
+          pollIntervalMs == other.pollIntervalMs;
+}
+
+/// Запущенный watcher (FRB bridge type).
+class WatcherInfo {
+  final String watcherId;
+
+  /// Фактический путь наблюдаемой папки.
+  final String watchDir;
+  final bool recursive;
+
+  /// Момент запуска (Unix timestamp в миллисекундах).
+  final int startedAtMs;
+
+  const WatcherInfo({
+    required this.watcherId,
+    required this.watchDir,
+    required this.recursive,
+    required this.startedAtMs,
+  });
+
+  @override
+  int get hashCode =>
+      watcherId.hashCode ^
+      watchDir.hashCode ^
+      recursive.hashCode ^
+      startedAtMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WatcherInfo &&
+          runtimeType == other.runtimeType &&
+          watcherId == other.watcherId &&
+          watchDir == other.watchDir &&
+          recursive == other.recursive &&
+          startedAtMs == other.startedAtMs;
+}
+
+/// Счётчики потока событий watcher'а (FRB bridge type).
+class WatcherMetrics {
+  /// Сколько событий пришло от backend'а ФС (включая отброшенные).
+  final int eventsReceived;
+
+  /// Сколько событий отдано подписчику (по одному и пачками).
+  final int eventsEmitted;
+
+  /// Сколько повторов отброшено дедупликацией.
+  final int eventsDeduped;
+
+  /// Сколько событий отброшено из-за переполненной очереди.
+  final int eventsDropped;
+
+  /// Сколько событий сейчас ждёт обработки.
+  final int queueDepth;
+
+  /// Размер очереди: при `queue_depth == queue_capacity` новые события
+  /// отбрасываются.
+  final int queueCapacity;
+
+  const WatcherMetrics({
+    required this.eventsReceived,
+    required this.eventsEmitted,
+    required this.eventsDeduped,
+    required this.eventsDropped,
+    required this.queueDepth,
+    required this.queueCapacity,
+  });
+
+  @override
+  int get hashCode =>
+      eventsReceived.hashCode ^
+      eventsEmitted.hashCode ^
+      eventsDeduped.hashCode ^
+      eventsDropped.hashCode ^
+      queueDepth.hashCode ^
+      queueCapacity.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WatcherMetrics &&
+          runtimeType == other.runtimeType &&
+          eventsReceived == other.eventsReceived &&
+          eventsEmitted == other.eventsEmitted &&
+          eventsDeduped == other.eventsDeduped &&
+          eventsDropped == other.eventsDropped &&
+          queueDepth == other.queueDepth &&
+          queueCapacity == other.queueCapacity;
+}
+
+/// Состояние watcher'а `watcher_id` (см. [`on_watcher_status`]).
+class WatcherStatusEvent {
+  final String watcherId;
+  final ApiWatcherState state;
+
+  /// `true` — периодический пульс, `false` — смена состояния.
+  final bool heartbeat;
+
+  /// Время отправки (Unix timestamp в миллисекундах).
+  final int emittedAtMs;
+
+  const WatcherStatusEvent({
+    required this.watcherId,
+    required this.state,
+    required this.heartbeat,
+    required this.emittedAtMs,
+  });
+
+  @override
+  int get hashCode =>
+      watcherId.hashCode ^
+      state.hashCode ^
+      heartbeat.hashCode ^
+      emittedAtMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WatcherStatusEvent &&
+          runtimeType == other.runtimeType &&
+          watcherId == other.watcherId &&
+          state == other.state &&
+          heartbeat == other.heartbeat &&
+          emittedAtMs == other.emittedAtMs;
 }
@@ -0,0 +1,1456 @@
+// dart format width=80
+// coverage:ignore-file
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'api.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+/// @nodoc
+mixin _$ApiClaimStatus {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiClaimStatus);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ApiClaimStatus()';
+}
+
+
+}
+
+/// @nodoc
+class $ApiClaimStatusCopyWith<$Res>  {
+$ApiClaimStatusCopyWith(ApiClaimStatus _, $Res Function(ApiClaimStatus) __);
+}
+
+
+/// @nodoc
+
+
+class ApiClaimStatus_Claimed extends ApiClaimStatus {
+  const ApiClaimStatus_Claimed(): super._();
+
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiClaimStatus_Claimed);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ApiClaimStatus.claimed()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ApiClaimStatus_ClaimedByOther extends ApiClaimStatus {
+  const ApiClaimStatus_ClaimedByOther({required this.owner, required this.claimedAtMs}): super._();
+
+
+ final  String owner;
+ final  int claimedAtMs;
+
+/// Create a copy of ApiClaimStatus
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiClaimStatus_ClaimedByOtherCopyWith<ApiClaimStatus_ClaimedByOther> get copyWith => _$ApiClaimStatus_ClaimedByOtherCopyWithImpl<ApiClaimStatus_ClaimedByOther>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiClaimStatus_ClaimedByOther&&(identical(other.owner, owner) || other.owner == owner)&&(identical(other.claimedAtMs, claimedAtMs) || other.claimedAtMs == claimedAtMs));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,owner,claimedAtMs);
+
+@override
+String toString() {
+  return 'ApiClaimStatus.claimedByOther(owner: $owner, claimedAtMs: $claimedAtMs)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiClaimStatus_ClaimedByOtherCopyWith<$Res> implements $ApiClaimStatusCopyWith<$Res> {
+  factory $ApiClaimStatus_ClaimedByOtherCopyWith(ApiClaimStatus_ClaimedByOther value, $Res Function(ApiClaimStatus_ClaimedByOther) _then) = _$ApiClaimStatus_ClaimedByOtherCopyWithImpl;
+@useResult
+$Res call({
+ String owner, int claimedAtMs
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiClaimStatus_ClaimedByOtherCopyWithImpl<$Res>
+    implements $ApiClaimStatus_ClaimedByOtherCopyWith<$Res> {
+  _$ApiClaimStatus_ClaimedByOtherCopyWithImpl(this._self, this._then);
+
+  final ApiClaimStatus_ClaimedByOther _self;
+  final $Res Function(ApiClaimStatus_ClaimedByOther) _then;
+
+/// Create a copy of ApiClaimStatus
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? owner = null,Object? claimedAtMs = null,}) {
+  return _then(ApiClaimStatus_ClaimedByOther(
+owner: null == owner ? _self.owner : owner // ignore: cast_nullable_to_non_nullable
+as String,claimedAtMs: null == claimedAtMs ? _self.claimedAtMs : claimedAtMs // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ApiClaimStatus_Unavailable extends ApiClaimStatus {
+  const ApiClaimStatus_Unavailable({required this.reason}): super._();
+
+
+ final  String reason;
+
+/// Create a copy of ApiClaimStatus
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiClaimStatus_UnavailableCopyWith<ApiClaimStatus_Unavailable> get copyWith => _$ApiClaimStatus_UnavailableCopyWithImpl<ApiClaimStatus_Unavailable>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiClaimStatus_Unavailable&&(identical(other.reason, reason) || other.reason == reason));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,reason);
+
+@override
+String toString() {
+  return 'ApiClaimStatus.unavailable(reason: $reason)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiClaimStatus_UnavailableCopyWith<$Res> implements $ApiClaimStatusCopyWith<$Res> {
+  factory $ApiClaimStatus_UnavailableCopyWith(ApiClaimStatus_Unavailable value, $Res Function(ApiClaimStatus_Unavailable) _then) = _$ApiClaimStatus_UnavailableCopyWithImpl;
+@useResult
+$Res call({
+ String reason
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiClaimStatus_UnavailableCopyWithImpl<$Res>
+    implements $ApiClaimStatus_UnavailableCopyWith<$Res> {
+  _$ApiClaimStatus_UnavailableCopyWithImpl(this._self, this._then);
+
+  final ApiClaimStatus_Unavailable _self;
+  final $Res Function(ApiClaimStatus_Unavailable) _then;
+
+/// Create a copy of ApiClaimStatus
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? reason = null,}) {
+  return _then(ApiClaimStatus_Unavailable(
+reason: null == reason ? _self.reason : reason // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ApiWatcherState {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiWatcherState);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ApiWatcherState()';
+}
+
+
+}
+
+/// @nodoc
+class $ApiWatcherStateCopyWith<$Res>  {
+$ApiWatcherStateCopyWith(ApiWatcherState _, $Res Function(ApiWatcherState) __);
+}
+
+
+/// @nodoc
+
+
+class ApiWatcherState_Starting extends ApiWatcherState {
+  const ApiWatcherState_Starting(): super._();
+
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiWatcherState_Starting);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ApiWatcherState.starting()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ApiWatcherState_Running extends ApiWatcherState {
+  const ApiWatcherState_Running(): super._();
+
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiWatcherState_Running);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ApiWatcherState.running()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ApiWatcherState_Degraded extends ApiWatcherState {
+  const ApiWatcherState_Degraded({required this.reason}): super._();
+
+
+ final  String reason;
+
+/// Create a copy of ApiWatcherState
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiWatcherState_DegradedCopyWith<ApiWatcherState_Degraded> get copyWith => _$ApiWatcherState_DegradedCopyWithImpl<ApiWatcherState_Degraded>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiWatcherState_Degraded&&(identical(other.reason, reason) || other.reason == reason));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,reason);
+
+@override
+String toString() {
+  return 'ApiWatcherState.degraded(reason: $reason)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiWatcherState_DegradedCopyWith<$Res> implements $ApiWatcherStateCopyWith<$Res> {
+  factory $ApiWatcherState_DegradedCopyWith(ApiWatcherState_Degraded value, $Res Function(ApiWatcherState_Degraded) _then) = _$ApiWatcherState_DegradedCopyWithImpl;
+@useResult
+$Res call({
+ String reason
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiWatcherState_DegradedCopyWithImpl<$Res>
+    implements $ApiWatcherState_DegradedCopyWith<$Res> {
+  _$ApiWatcherState_DegradedCopyWithImpl(this._self, this._then);
+
+  final ApiWatcherState_Degraded _self;
+  final $Res Function(ApiWatcherState_Degraded) _then;
+
+/// Create a copy of ApiWatcherState
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? reason = null,}) {
+  return _then(ApiWatcherState_Degraded(
+reason: null == reason ? _self.reason : reason // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ApiWatcherState_Stopped extends ApiWatcherState {
+  const ApiWatcherState_Stopped(): super._();
+
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiWatcherState_Stopped);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ApiWatcherState.stopped()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ApiWatcherState_Error extends ApiWatcherState {
+  const ApiWatcherState_Error({required this.code, required this.message}): super._();
+
+
+ final  ApiWatcherErrorCode code;
+ final  String message;
+
+/// Create a copy of ApiWatcherState
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiWatcherState_ErrorCopyWith<ApiWatcherState_Error> get copyWith => _$ApiWatcherState_ErrorCopyWithImpl<ApiWatcherState_Error>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiWatcherState_Error&&(identical(other.code, code) || other.code == code)&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,code,message);
+
+@override
+String toString() {
+  return 'ApiWatcherState.error(code: $code, message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiWatcherState_ErrorCopyWith<$Res> implements $ApiWatcherStateCopyWith<$Res> {
+  factory $ApiWatcherState_ErrorCopyWith(ApiWatcherState_Error value, $Res Function(ApiWatcherState_Error) _then) = _$ApiWatcherState_ErrorCopyWithImpl;
+@useResult
+$Res call({
+ ApiWatcherErrorCode code, String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiWatcherState_ErrorCopyWithImpl<$Res>
+    implements $ApiWatcherState_ErrorCopyWith<$Res> {
+  _$ApiWatcherState_ErrorCopyWithImpl(this._self, this._then);
+
+  final ApiWatcherState_Error _self;
+  final $Res Function(ApiWatcherState_Error) _then;
+
+/// Create a copy of ApiWatcherState
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? code = null,Object? message = null,}) {
+  return _then(ApiWatcherState_Error(
+code: null == code ? _self.code : code // ignore: cast_nullable_to_non_nullable
+as ApiWatcherErrorCode,message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ApiWatcherState_Restarting extends ApiWatcherState {
+  const ApiWatcherState_Restarting({required this.attempt, required this.retryInMs, required this.reason}): super._();
+
+
+ final  int attempt;
+ final  int retryInMs;
+ final  String reason;
+
+/// Create a copy of ApiWatcherState
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiWatcherState_RestartingCopyWith<ApiWatcherState_Restarting> get copyWith => _$ApiWatcherState_RestartingCopyWithImpl<ApiWatcherState_Restarting>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiWatcherState_Restarting&&(identical(other.attempt, attempt) || other.attempt == attempt)&&(identical(other.retryInMs, retryInMs) || other.retryInMs == retryInMs)&&(identical(other.reason, reason) || other.reason == reason));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,attempt,retryInMs,reason);
+
+@override
+String toString() {
+  return 'ApiWatcherState.restarting(attempt: $attempt, retryInMs: $retryInMs, reason: $reason)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiWatcherState_RestartingCopyWith<$Res> implements $ApiWatcherStateCopyWith<$Res> {
+  factory $ApiWatcherState_RestartingCopyWith(ApiWatcherState_Restarting value, $Res Function(ApiWatcherState_Restarting) _then) = _$ApiWatcherState_RestartingCopyWithImpl;
+@useResult
+$Res call({
+ int attempt, int retryInMs, String reason
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiWatcherState_RestartingCopyWithImpl<$Res>
+    implements $ApiWatcherState_RestartingCopyWith<$Res> {
+  _$ApiWatcherState_RestartingCopyWithImpl(this._self, this._then);
+
+  final ApiWatcherState_Restarting _self;
+  final $Res Function(ApiWatcherState_Restarting) _then;
+
+/// Create a copy of ApiWatcherState
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? attempt = null,Object? retryInMs = null,Object? reason = null,}) {
+  return _then(ApiWatcherState_Restarting(
+attempt: null == attempt ? _self.attempt : attempt // ignore: cast_nullable_to_non_nullable
+as int,retryInMs: null == retryInMs ? _self.retryInMs : retryInMs // ignore: cast_nullable_to_non_nullable
+as int,reason: null == reason ? _self.reason : reason // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ApiWatcherState_DirLost extends ApiWatcherState {
+  const ApiWatcherState_DirLost({required this.path}): super._();
+
+
+ final  String path;
+
+/// Create a copy of ApiWatcherState
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiWatcherState_DirLostCopyWith<ApiWatcherState_DirLost> get copyWith => _$ApiWatcherState_DirLostCopyWithImpl<ApiWatcherState_DirLost>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiWatcherState_DirLost&&(identical(other.path, path) || other.path == path));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,path);
+
+@override
+String toString() {
+  return 'ApiWatcherState.dirLost(path: $path)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiWatcherState_DirLostCopyWith<$Res> implements $ApiWatcherStateCopyWith<$Res> {
+  factory $ApiWatcherState_DirLostCopyWith(ApiWatcherState_DirLost value, $Res Function(ApiWatcherState_DirLost) _then) = _$ApiWatcherState_DirLostCopyWithImpl;
+@useResult
+$Res call({
+ String path
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiWatcherState_DirLostCopyWithImpl<$Res>
+    implements $ApiWatcherState_DirLostCopyWith<$Res> {
+  _$ApiWatcherState_DirLostCopyWithImpl(this._self, this._then);
+
+  final ApiWatcherState_DirLost _self;
+  final $Res Function(ApiWatcherState_DirLost) _then;
+
+/// Create a copy of ApiWatcherState
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? path = null,}) {
+  return _then(ApiWatcherState_DirLost(
+path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ApiWatcherState_Fallback extends ApiWatcherState {
+  const ApiWatcherState_Fallback({required this.mode, required this.reason}): super._();
+
+
+ final  ApiWatchMode mode;
+ final  String reason;
+
+/// Create a copy of ApiWatcherState
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ApiWatcherState_FallbackCopyWith<ApiWatcherState_Fallback> get copyWith => _$ApiWatcherState_FallbackCopyWithImpl<ApiWatcherState_Fallback>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ApiWatcherState_Fallback&&(identical(other.mode, mode) || other.mode == mode)&&(identical(other.reason, reason) || other.reason == reason));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,mode,reason);
+
+@override
+String toString() {
+  return 'ApiWatcherState.fallback(mode: $mode, reason: $reason)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ApiWatcherState_FallbackCopyWith<$Res> implements $ApiWatcherStateCopyWith<$Res> {
+  factory $ApiWatcherState_FallbackCopyWith(ApiWatcherState_Fallback value, $Res Function(ApiWatcherState_Fallback) _then) = _$ApiWatcherState_FallbackCopyWithImpl;
+@useResult
+$Res call({
+ ApiWatchMode mode, String reason
+});
+
+
+
+
+}
+/// @nodoc
+class _$ApiWatcherState_FallbackCopyWithImpl<$Res>
+    implements $ApiWatcherState_FallbackCopyWith<$Res> {
+  _$ApiWatcherState_FallbackCopyWithImpl(this._self, this._then);
+
+  final ApiWatcherState_Fallback _self;
+  final $Res Function(ApiWatcherState_Fallback) _then;
+
+/// Create a copy of ApiWatcherState
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? mode = null,Object? reason = null,}) {
+  return _then(ApiWatcherState_Fallback(
+mode: null == mode ? _self.mode : mode // ignore: cast_nullable_to_non_nullable
+as ApiWatchMode,reason: null == reason ? _self.reason : reason // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$FileRuleAction {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is FileRuleAction);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'FileRuleAction()';
+}
+
+
+}
+
+/// @nodoc
+class $FileRuleActionCopyWith<$Res>  {
+$FileRuleActionCopyWith(FileRuleAction _, $Res Function(FileRuleAction) __);
+}
+
+
+/// @nodoc
+
+
+class FileRuleAction_MoveToSubfolder extends FileRuleAction {
+  const FileRuleAction_MoveToSubfolder({required this.subfolder}): super._();
+
+
+ final  String subfolder;
+
+/// Create a copy of FileRuleAction
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$FileRuleAction_MoveToSubfolderCopyWith<FileRuleAction_MoveToSubfolder> get copyWith => _$FileRuleAction_MoveToSubfolderCopyWithImpl<FileRuleAction_MoveToSubfolder>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is FileRuleAction_MoveToSubfolder&&(identical(other.subfolder, subfolder) || other.subfolder == subfolder));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,subfolder);
+
+@override
+String toString() {
+  return 'FileRuleAction.moveToSubfolder(subfolder: $subfolder)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $FileRuleAction_MoveToSubfolderCopyWith<$Res> implements $FileRuleActionCopyWith<$Res> {
+  factory $FileRuleAction_MoveToSubfolderCopyWith(FileRuleAction_MoveToSubfolder value, $Res Function(FileRuleAction_MoveToSubfolder) _then) = _$FileRuleAction_MoveToSubfolderCopyWithImpl;
+@useResult
+$Res call({
+ String subfolder
+});
+
+
+
+
+}
+/// @nodoc
+class _$FileRuleAction_MoveToSubfolderCopyWithImpl<$Res>
+    implements $FileRuleAction_MoveToSubfolderCopyWith<$Res> {
+  _$FileRuleAction_MoveToSubfolderCopyWithImpl(this._self, this._then);
+
+  final FileRuleAction_MoveToSubfolder _self;
+  final $Res Function(FileRuleAction_MoveToSubfolder) _then;
+
+/// Create a copy of FileRuleAction
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? subfolder = null,}) {
+  return _then(FileRuleAction_MoveToSubfolder(
+subfolder: null == subfolder ? _self.subfolder : subfolder // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class FileRuleAction_Rename extends FileRuleAction {
+  const FileRuleAction_Rename({required this.template}): super._();
+
+
+ final  String template;
+
+/// Create a copy of FileRuleAction
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$FileRuleAction_RenameCopyWith<FileRuleAction_Rename> get copyWith => _$FileRuleAction_RenameCopyWithImpl<FileRuleAction_Rename>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is FileRuleAction_Rename&&(identical(other.template, template) || other.template == template));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,template);
+
+@override
+String toString() {
+  return 'FileRuleAction.rename(template: $template)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $FileRuleAction_RenameCopyWith<$Res> implements $FileRuleActionCopyWith<$Res> {
+  factory $FileRuleAction_RenameCopyWith(FileRuleAction_Rename value, $Res Function(FileRuleAction_Rename) _then) = _$FileRuleAction_RenameCopyWithImpl;
+@useResult
+$Res call({
+ String template
+});
+
+
+
+
+}
+/// @nodoc
+class _$FileRuleAction_RenameCopyWithImpl<$Res>
+    implements $FileRuleAction_RenameCopyWith<$Res> {
+  _$FileRuleAction_RenameCopyWithImpl(this._self, this._then);
+
+  final FileRuleAction_Rename _self;
+  final $Res Function(FileRuleAction_Rename) _then;
+
+/// Create a copy of FileRuleAction
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? template = null,}) {
+  return _then(FileRuleAction_Rename(
+template: null == template ? _self.template : template // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class FileRuleAction_Tag extends FileRuleAction {
+  const FileRuleAction_Tag({required this.tag}): super._();
+
+
+ final  String tag;
+
+/// Create a copy of FileRuleAction
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$FileRuleAction_TagCopyWith<FileRuleAction_Tag> get copyWith => _$FileRuleAction_TagCopyWithImpl<FileRuleAction_Tag>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is FileRuleAction_Tag&&(identical(other.tag, tag) || other.tag == tag));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,tag);
+
+@override
+String toString() {
+  return 'FileRuleAction.tag(tag: $tag)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $FileRuleAction_TagCopyWith<$Res> implements $FileRuleActionCopyWith<$Res> {
+  factory $FileRuleAction_TagCopyWith(FileRuleAction_Tag value, $Res Function(FileRuleAction_Tag) _then) = _$FileRuleAction_TagCopyWithImpl;
+@useResult
+$Res call({
+ String tag
+});
+
+
+
+
+}
+/// @nodoc
+class _$FileRuleAction_TagCopyWithImpl<$Res>
+    implements $FileRuleAction_TagCopyWith<$Res> {
+  _$FileRuleAction_TagCopyWithImpl(this._self, this._then);
+
+  final FileRuleAction_Tag _self;
+  final $Res Function(FileRuleAction_Tag) _then;
+
+/// Create a copy of FileRuleAction
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? tag = null,}) {
+  return _then(FileRuleAction_Tag(
+tag: null == tag ? _self.tag : tag // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class FileRuleAction_Ignore extends FileRuleAction {
+  const FileRuleAction_Ignore(): super._();
+
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is FileRuleAction_Ignore);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'FileRuleAction.ignore()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+mixin _$WatchStatusEvent {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is WatchStatusEvent);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'WatchStatusEvent()';
+}
+
+
+}
+
+/// @nodoc
+class $WatchStatusEventCopyWith<$Res>  {
+$WatchStatusEventCopyWith(WatchStatusEvent _, $Res Function(WatchStatusEvent) __);
+}
+
+
+/// @nodoc
+
+
+class WatchStatusEvent_LowDiskSpace extends WatchStatusEvent {
+  const WatchStatusEvent_LowDiskSpace({required this.availableBytes, required this.thresholdBytes}): super._();
+
+
+ final  int availableBytes;
+ final  int thresholdBytes;
+
+/// Create a copy of WatchStatusEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$WatchStatusEvent_LowDiskSpaceCopyWith<WatchStatusEvent_LowDiskSpace> get copyWith => _$WatchStatusEvent_LowDiskSpaceCopyWithImpl<WatchStatusEvent_LowDiskSpace>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is WatchStatusEvent_LowDiskSpace&&(identical(other.availableBytes, availableBytes) || other.availableBytes == availableBytes)&&(identical(other.thresholdBytes, thresholdBytes) || other.thresholdBytes == thresholdBytes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,availableBytes,thresholdBytes);
+
+@override
+String toString() {
+  return 'WatchStatusEvent.lowDiskSpace(availableBytes: $availableBytes, thresholdBytes: $thresholdBytes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $WatchStatusEvent_LowDiskSpaceCopyWith<$Res> implements $WatchStatusEventCopyWith<$Res> {
+  factory $WatchStatusEvent_LowDiskSpaceCopyWith(WatchStatusEvent_LowDiskSpace value, $Res Function(WatchStatusEvent_LowDiskSpace) _then) = _$WatchStatusEvent_LowDiskSpaceCopyWithImpl;
+@useResult
+$Res call({
+ int availableBytes, int thresholdBytes
+});
+
+
+
+
+}
+/// @nodoc
+class _$WatchStatusEvent_LowDiskSpaceCopyWithImpl<$Res>
+    implements $WatchStatusEvent_LowDiskSpaceCopyWith<$Res> {
+  _$WatchStatusEvent_LowDiskSpaceCopyWithImpl(this._self, this._then);
+
+  final WatchStatusEvent_LowDiskSpace _self;
+  final $Res Function(WatchStatusEvent_LowDiskSpace) _then;
+
+/// Create a copy of WatchStatusEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? availableBytes = null,Object? thresholdBytes = null,}) {
+  return _then(WatchStatusEvent_LowDiskSpace(
+availableBytes: null == availableBytes ? _self.availableBytes : availableBytes // ignore: cast_nullable_to_non_nullable
+as int,thresholdBytes: null == thresholdBytes ? _self.thresholdBytes : thresholdBytes // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class WatchStatusEvent_DiskSpaceRecovered extends WatchStatusEvent {
+  const WatchStatusEvent_DiskSpaceRecovered({required this.availableBytes}): super._();
+
+
+ final  int availableBytes;
+
+/// Create a copy of WatchStatusEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$WatchStatusEvent_DiskSpaceRecoveredCopyWith<WatchStatusEvent_DiskSpaceRecovered> get copyWith => _$WatchStatusEvent_DiskSpaceRecoveredCopyWithImpl<WatchStatusEvent_DiskSpaceRecovered>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is WatchStatusEvent_DiskSpaceRecovered&&(identical(other.availableBytes, availableBytes) || other.availableBytes == availableBytes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,availableBytes);
+
+@override
+String toString() {
+  return 'WatchStatusEvent.diskSpaceRecovered(availableBytes: $availableBytes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $WatchStatusEvent_DiskSpaceRecoveredCopyWith<$Res> implements $WatchStatusEventCopyWith<$Res> {
+  factory $WatchStatusEvent_DiskSpaceRecoveredCopyWith(WatchStatusEvent_DiskSpaceRecovered value, $Res Function(WatchStatusEvent_DiskSpaceRecovered) _then) = _$WatchStatusEvent_DiskSpaceRecoveredCopyWithImpl;
+@useResult
+$Res call({
+ int availableBytes
+});
+
+
+
+
+}
+/// @nodoc
+class _$WatchStatusEvent_DiskSpaceRecoveredCopyWithImpl<$Res>
+    implements $WatchStatusEvent_DiskSpaceRecoveredCopyWith<$Res> {
+  _$WatchStatusEvent_DiskSpaceRecoveredCopyWithImpl(this._self, this._then);
+
+  final WatchStatusEvent_DiskSpaceRecovered _self;
+  final $Res Function(WatchStatusEvent_DiskSpaceRecovered) _then;
+
+/// Create a copy of WatchStatusEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? availableBytes = null,}) {
+  return _then(WatchStatusEvent_DiskSpaceRecovered(
+availableBytes: null == availableBytes ? _self.availableBytes : availableBytes // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class WatchStatusEvent_QuotaExceeded extends WatchStatusEvent {
+  const WatchStatusEvent_QuotaExceeded({required this.watcherId, required this.totalBytes, required this.fileCount, required this.maxTotalBytes, required this.maxFileCount, required this.removedFiles, required this.freedBytes}): super._();
+
+
+ final  String watcherId;
+ final  int totalBytes;
+ final  int fileCount;
+ final  int maxTotalBytes;
+ final  int maxFileCount;
+ final  int removedFiles;
+ final  int freedBytes;
+
+/// Create a copy of WatchStatusEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$WatchStatusEvent_QuotaExceededCopyWith<WatchStatusEvent_QuotaExceeded> get copyWith => _$WatchStatusEvent_QuotaExceededCopyWithImpl<WatchStatusEvent_QuotaExceeded>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is WatchStatusEvent_QuotaExceeded&&(identical(other.watcherId, watcherId) || other.watcherId == watcherId)&&(identical(other.totalBytes, totalBytes) || other.totalBytes == totalBytes)&&(identical(other.fileCount, fileCount) || other.fileCount == fileCount)&&(identical(other.maxTotalBytes, maxTotalBytes) || other.maxTotalBytes == maxTotalBytes)&&(identical(other.maxFileCount, maxFileCount) || other.maxFileCount == maxFileCount)&&(identical(other.removedFiles, removedFiles) || other.removedFiles == removedFiles)&&(identical(other.freedBytes, freedBytes) || other.freedBytes == freedBytes));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,watcherId,totalBytes,fileCount,maxTotalBytes,maxFileCount,removedFiles,freedBytes);
+
+@override
+String toString() {
+  return 'WatchStatusEvent.quotaExceeded(watcherId: $watcherId, totalBytes: $totalBytes, fileCount: $fileCount, maxTotalBytes: $maxTotalBytes, maxFileCount: $maxFileCount, removedFiles: $removedFiles, freedBytes: $freedBytes)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $WatchStatusEvent_QuotaExceededCopyWith<$Res> implements $WatchStatusEventCopyWith<$Res> {
+  factory $WatchStatusEvent_QuotaExceededCopyWith(WatchStatusEvent_QuotaExceeded value, $Res Function(WatchStatusEvent_QuotaExceeded) _then) = _$WatchStatusEvent_QuotaExceededCopyWithImpl;
+@useResult
+$Res call({
+ String watcherId, int totalBytes, int fileCount, int maxTotalBytes, int maxFileCount, int removedFiles, int freedBytes
+});
+
+
+
+
+}
+/// @nodoc
+class _$WatchStatusEvent_QuotaExceededCopyWithImpl<$Res>
+    implements $WatchStatusEvent_QuotaExceededCopyWith<$Res> {
+  _$WatchStatusEvent_QuotaExceededCopyWithImpl(this._self, this._then);
+
+  final WatchStatusEvent_QuotaExceeded _self;
+  final $Res Function(WatchStatusEvent_QuotaExceeded) _then;
+
+/// Create a copy of WatchStatusEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? watcherId = null,Object? totalBytes = null,Object? fileCount = null,Object? maxTotalBytes = null,Object? maxFileCount = null,Object? removedFiles = null,Object? freedBytes = null,}) {
+  return _then(WatchStatusEvent_QuotaExceeded(
+watcherId: null == watcherId ? _self.watcherId : watcherId // ignore: cast_nullable_to_non_nullable
+as String,totalBytes: null == totalBytes ? _self.totalBytes : totalBytes // ignore: cast_nullable_to_non_nullable
+as int,fileCount: null == fileCount ? _self.fileCount : fileCount // ignore: cast_nullable_to_non_nullable
+as int,maxTotalBytes: null == maxTotalBytes ? _self.maxTotalBytes : maxTotalBytes // ignore: cast_nullable_to_non_nullable
+as int,maxFileCount: null == maxFileCount ? _self.maxFileCount : maxFileCount // ignore: cast_nullable_to_non_nullable
+as int,removedFiles: null == removedFiles ? _self.removedFiles : removedFiles // ignore: cast_nullable_to_non_nullable
+as int,freedBytes: null == freedBytes ? _self.freedBytes : freedBytes // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class WatchStatusEvent_NameConflict extends WatchStatusEvent {
+  const WatchStatusEvent_NameConflict({required this.fileName, required this.newPath, required this.newFingerprint, required this.existingPath, required this.existingFingerprint}): super._();
+
+
+ final  String fileName;
+ final  String newPath;
+ final  String newFingerprint;
+ final  String existingPath;
+ final  String existingFingerprint;
+
+/// Create a copy of WatchStatusEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$WatchStatusEvent_NameConflictCopyWith<WatchStatusEvent_NameConflict> get copyWith => _$WatchStatusEvent_NameConflictCopyWithImpl<WatchStatusEvent_NameConflict>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is WatchStatusEvent_NameConflict&&(identical(other.fileName, fileName) || other.fileName == fileName)&&(identical(other.newPath, newPath) || other.newPath == newPath)&&(identical(other.newFingerprint, newFingerprint) || other.newFingerprint == newFingerprint)&&(identical(other.existingPath, existingPath) || other.existingPath == existingPath)&&(identical(other.existingFingerprint, existingFingerprint) || other.existingFingerprint == existingFingerprint));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,fileName,newPath,newFingerprint,existingPath,existingFingerprint);
+
+@override
+String toString() {
+  return 'WatchStatusEvent.nameConflict(fileName: $fileName, newPath: $newPath, newFingerprint: $newFingerprint, existingPath: $existingPath, existingFingerprint: $existingFingerprint)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $WatchStatusEvent_NameConflictCopyWith<$Res> implements $WatchStatusEventCopyWith<$Res> {
+  factory $WatchStatusEvent_NameConflictCopyWith(WatchStatusEvent_NameConflict value, $Res Function(WatchStatusEvent_NameConflict) _then) = _$WatchStatusEvent_NameConflictCopyWithImpl;
+@useResult
+$Res call({
+ String fileName, String newPath, String newFingerprint, String existingPath, String existingFingerprint
+});
+
+
+
+
+}
+/// @nodoc
+class _$WatchStatusEvent_NameConflictCopyWithImpl<$Res>
+    implements $WatchStatusEvent_NameConflictCopyWith<$Res> {
+  _$WatchStatusEvent_NameConflictCopyWithImpl(this._self, this._then);
+
+  final WatchStatusEvent_NameConflict _self;
+  final $Res Function(WatchStatusEvent_NameConflict) _then;
+
+/// Create a copy of WatchStatusEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? fileName = null,Object? newPath = null,Object? newFingerprint = null,Object? existingPath = null,Object? existingFingerprint = null,}) {
+  return _then(WatchStatusEvent_NameConflict(
+fileName: null == fileName ? _self.fileName : fileName // ignore: cast_nullable_to_non_nullable
+as String,newPath: null == newPath ? _self.newPath : newPath // ignore: cast_nullable_to_non_nullable
+as String,newFingerprint: null == newFingerprint ? _self.newFingerprint : newFingerprint // ignore: cast_nullable_to_non_nullable
+as String,existingPath: null == existingPath ? _self.existingPath : existingPath // ignore: cast_nullable_to_non_nullable
+as String,existingFingerprint: null == existingFingerprint ? _self.existingFingerprint : existingFingerprint // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class WatchStatusEvent_SettingsRecovered extends WatchStatusEvent {
+  const WatchStatusEvent_SettingsRecovered({required this.backupIndex, required this.corruptPath}): super._();
+
+
+ final  int backupIndex;
+ final  String corruptPath;
+
+/// Create a copy of WatchStatusEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$WatchStatusEvent_SettingsRecoveredCopyWith<WatchStatusEvent_SettingsRecovered> get copyWith => _$WatchStatusEvent_SettingsRecoveredCopyWithImpl<WatchStatusEvent_SettingsRecovered>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is WatchStatusEvent_SettingsRecovered&&(identical(other.backupIndex, backupIndex) || other.backupIndex == backupIndex)&&(identical(other.corruptPath, corruptPath) || other.corruptPath == corruptPath));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,backupIndex,corruptPath);
+
+@override
+String toString() {
+  return 'WatchStatusEvent.settingsRecovered(backupIndex: $backupIndex, corruptPath: $corruptPath)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $WatchStatusEvent_SettingsRecoveredCopyWith<$Res> implements $WatchStatusEventCopyWith<$Res> {
+  factory $WatchStatusEvent_SettingsRecoveredCopyWith(WatchStatusEvent_SettingsRecovered value, $Res Function(WatchStatusEvent_SettingsRecovered) _then) = _$WatchStatusEvent_SettingsRecoveredCopyWithImpl;
+@useResult
+$Res call({
+ int backupIndex, String corruptPath
+});
+
+
+
+
+}
+/// @nodoc
+class _$WatchStatusEvent_SettingsRecoveredCopyWithImpl<$Res>
+    implements $WatchStatusEvent_SettingsRecoveredCopyWith<$Res> {
+  _$WatchStatusEvent_SettingsRecoveredCopyWithImpl(this._self, this._then);
+
+  final WatchStatusEvent_SettingsRecovered _self;
+  final $Res Function(WatchStatusEvent_SettingsRecovered) _then;
+
+/// Create a copy of WatchStatusEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? backupIndex = null,Object? corruptPath = null,}) {
+  return _then(WatchStatusEvent_SettingsRecovered(
+backupIndex: null == backupIndex ? _self.backupIndex : backupIndex // ignore: cast_nullable_to_non_nullable
+as int,corruptPath: null == corruptPath ? _self.corruptPath : corruptPath // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class WatchStatusEvent_WatchDirLost extends WatchStatusEvent {
+  const WatchStatusEvent_WatchDirLost({required this.watcherId, required this.path}): super._();
+
+
+ final  String watcherId;
+ final  String path;
+
+/// Create a copy of WatchStatusEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$WatchStatusEvent_WatchDirLostCopyWith<WatchStatusEvent_WatchDirLost> get copyWith => _$WatchStatusEvent_WatchDirLostCopyWithImpl<WatchStatusEvent_WatchDirLost>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is WatchStatusEvent_WatchDirLost&&(identical(other.watcherId, watcherId) || other.watcherId == watcherId)&&(identical(other.path, path) || other.path == path));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,watcherId,path);
+
+@override
+String toString() {
+  return 'WatchStatusEvent.watchDirLost(watcherId: $watcherId, path: $path)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $WatchStatusEvent_WatchDirLostCopyWith<$Res> implements $WatchStatusEventCopyWith<$Res> {
+  factory $WatchStatusEvent_WatchDirLostCopyWith(WatchStatusEvent_WatchDirLost value, $Res Function(WatchStatusEvent_WatchDirLost) _then) = _$WatchStatusEvent_WatchDirLostCopyWithImpl;
+@useResult
+$Res call({
+ String watcherId, String path
+});
+
+
+
+
+}
+/// @nodoc
+class _$WatchStatusEvent_WatchDirLostCopyWithImpl<$Res>
+    implements $WatchStatusEvent_WatchDirLostCopyWith<$Res> {
+  _$WatchStatusEvent_WatchDirLostCopyWithImpl(this._self, this._then);
+
+  final WatchStatusEvent_WatchDirLost _self;
+  final $Res Function(WatchStatusEvent_WatchDirLost) _then;
+
+/// Create a copy of WatchStatusEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? watcherId = null,Object? path = null,}) {
+  return _then(WatchStatusEvent_WatchDirLost(
+watcherId: null == watcherId ? _self.watcherId : watcherId // ignore: cast_nullable_to_non_nullable
+as String,path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class WatchStatusEvent_WatchDirRestored extends WatchStatusEvent {
+  const WatchStatusEvent_WatchDirRestored({required this.watcherId, required this.path}): super._();
+
+
+ final  String watcherId;
+ final  String path;
+
+/// Create a copy of WatchStatusEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$WatchStatusEvent_WatchDirRestoredCopyWith<WatchStatusEvent_WatchDirRestored> get copyWith => _$WatchStatusEvent_WatchDirRestoredCopyWithImpl<WatchStatusEvent_WatchDirRestored>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is WatchStatusEvent_WatchDirRestored&&(identical(other.watcherId, watcherId) || other.watcherId == watcherId)&&(identical(other.path, path) || other.path == path));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,watcherId,path);
+
+@override
+String toString() {
+  return 'WatchStatusEvent.watchDirRestored(watcherId: $watcherId, path: $path)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $WatchStatusEvent_WatchDirRestoredCopyWith<$Res> implements $WatchStatusEventCopyWith<$Res> {
+  factory $WatchStatusEvent_WatchDirRestoredCopyWith(WatchStatusEvent_WatchDirRestored value, $Res Function(WatchStatusEvent_WatchDirRestored) _then) = _$WatchStatusEvent_WatchDirRestoredCopyWithImpl;
+@useResult
+$Res call({
+ String watcherId, String path
+});
+
+
+
+
+}
+/// @nodoc
+class _$WatchStatusEvent_WatchDirRestoredCopyWithImpl<$Res>
+    implements $WatchStatusEvent_WatchDirRestoredCopyWith<$Res> {
+  _$WatchStatusEvent_WatchDirRestoredCopyWithImpl(this._self, this._then);
+
+  final WatchStatusEvent_WatchDirRestored _self;
+  final $Res Function(WatchStatusEvent_WatchDirRestored) _then;
+
+/// Create a copy of WatchStatusEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? watcherId = null,Object? path = null,}) {
+  return _then(WatchStatusEvent_WatchDirRestored(
+watcherId: null == watcherId ? _self.watcherId : watcherId // ignore: cast_nullable_to_non_nullable
+as String,path: null == path ? _self.path : path // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+
+// dart format on
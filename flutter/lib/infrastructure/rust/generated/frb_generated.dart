@@ -10,9 +10,6 @@ import 'error.dart';
 import 'frb_generated.dart';
 import 'frb_generated.io.dart'
     if (dart.library.js_interop) 'frb_generated.web.dart';
-import 'indexer/ocr.dart';
-import 'indexer/text_extractor.dart';
-import 'indexer/transcriber.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
 /// Main entrypoint of the Rust API
@@ -69,7 +66,7 @@ class RustCore
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -829395274;
+  int get rustContentHash => 650023538;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -80,6 +77,97 @@ class RustCore
 }
 
 abstract class RustCoreApi extends BaseApi {
+  Future<CoreCapabilities> crateApiCoreHandleCapabilities({
+    required CoreHandle that,
+  });
+
+  Future<void> crateApiCoreHandleClose({required CoreHandle that});
+
+  Future<FileProcessingStatus?> crateApiCoreHandleGetFileStatus({
+    required CoreHandle that,
+    required String path,
+  });
+
+  Future<List<String>> crateApiCoreHandleListTags({
+    required CoreHandle that,
+    required String path,
+  });
+
+  Future<List<WatcherInfo>> crateApiCoreHandleListWatchers({
+    required CoreHandle that,
+  });
+
+  Stream<FileAddedEvent> crateApiCoreHandleOnFileAdded({
+    required CoreHandle that,
+  });
+
+  Stream<FileBatchEvent> crateApiCoreHandleOnFileBatch({
+    required CoreHandle that,
+  });
+
+  Stream<FileChunkEvent> crateApiCoreHandleOnFileChunks({
+    required CoreHandle that,
+  });
+
+  Stream<FileEvent> crateApiCoreHandleOnFileEvent({required CoreHandle that});
+
+  Future<List<ApiJournalEntry>> crateApiCoreHandleQueryEvents({
+    required CoreHandle that,
+    required int sinceMs,
+    required int limit,
+  });
+
+  Future<void> crateApiCoreHandleSetWatchFilter({
+    required CoreHandle that,
+    WatchFilter? filter,
+  });
+
+  Future<void> crateApiCoreHandleSetWatchRecursive({
+    required CoreHandle that,
+    required bool enabled,
+  });
+
+  Future<void> crateApiCoreHandleSetWatchTuning({
+    required CoreHandle that,
+    required WatchTuning tuning,
+  });
+
+  Future<WatchStarted> crateApiCoreHandleStartWatching({
+    required CoreHandle that,
+    String? overridePath,
+    WatchTuning? tuning,
+  });
+
+  Future<void> crateApiCoreHandleStopWatching({
+    required CoreHandle that,
+    required String watcherId,
+  });
+
+  Future<int> crateApiImageBufferHeight({required ImageBuffer that});
+
+  Future<bool> crateApiImageBufferIsEmpty({required ImageBuffer that});
+
+  Future<int> crateApiImageBufferLen({required ImageBuffer that});
+
+  Future<String> crateApiImageBufferMimeType({required ImageBuffer that});
+
+  Future<Uint8List> crateApiImageBufferToBytes({required ImageBuffer that});
+
+  Future<int> crateApiImageBufferWidth({required ImageBuffer that});
+
+  Future<void> crateApiAckEvent({required int sequence});
+
+  Future<void> crateApiAddTag({required String path, required String tag});
+
+  Future<int> crateApiAdoptFolder({
+    required String path,
+    required bool recursive,
+  });
+
+  Future<List<ApiArchivedFile>> crateApiArchiveOldFilesNow({
+    required String watcherId,
+  });
+
   Future<List<ApiTextChunk>> crateApiChunkText({
     required String text,
     required int chunkSize,
@@ -90,68 +178,306 @@ abstract class RustCoreApi extends BaseApi {
 
   Future<void> crateApiClearFileIndex();
 
+  Future<int> crateApiClearJournal();
+
+  Future<OnboardingState> crateApiCompleteOnboardingStep({
+    required OnboardingStep step,
+  });
+
   Future<List<ApiEmbeddingVector>> crateApiComputeEmbeddings({
     required List<ApiTextChunk> chunks,
   });
 
+  Future<String> crateApiComputeHash({
+    required String path,
+    required HashAlgorithm algorithm,
+  });
+
+  Future<int> crateApiCopyFile({
+    required String src,
+    required String dst,
+    required bool overwrite,
+  });
+
+  Future<String?> crateApiDeleteToTrash({required String path});
+
+  Future<void> crateApiExportConfig({required String path});
+
+  Future<String> crateApiExportMetricsText();
+
+  Future<List<String>> crateApiExtractEmailAttachments({
+    required String path,
+    String? targetDir,
+  });
+
+  Future<String> crateApiExtractText({required String path});
+
   Future<ExtractionResult> crateApiExtractTextFromFile({
     required String path,
     required ExtractionOptions options,
   });
 
+  Future<ApiArchivedFile?> crateApiFindArchivedFile({
+    required String originalPath,
+  });
+
+  Future<List<DuplicateGroup>> crateApiFindDuplicates({required String dir});
+
   Future<List<ApiSimilarityResult>> crateApiFindSimilarFiles({
     required String filePath,
     required int topK,
   });
 
+  Future<List<ActivityBucket>> crateApiGetActivityTimeline({
+    required int fromMs,
+    required int toMs,
+    required TimelineGranularity granularity,
+  });
+
+  Future<List<ApiAuditEntry>> crateApiGetAuditLog({
+    required AuditLogFilter filter,
+  });
+
+  Future<ApiConfig> crateApiGetConfig();
+
+  Future<ControlledFolderAccessInfo> crateApiGetControlledFolderAccessInfo();
+
   Future<String> crateApiGetDefaultWatchPath();
 
   Future<String> crateApiGetDefaultWatchPathPreview();
 
+  Future<DesktopAccessStatus> crateApiGetDesktopAccessStatus();
+
+  Future<DirSizeInfo> crateApiGetDirSize({required String path});
+
+  Future<ApiEmailMetadata> crateApiGetEmailMetadata({required String path});
+
   Future<int> crateApiGetEmbeddingCount();
 
   Future<int> crateApiGetEmbeddingDim();
 
+  Future<EventDebugInfo?> crateApiGetEventDebugInfo({required int sequence});
+
+  Future<ApiFileAccessInfo> crateApiGetFileAccessInfo({required String path});
+
+  Future<FileProcessingStatus?> crateApiGetFileStatus({required String path});
+
+  Future<List<CategoryStats>> crateApiGetFolderComposition({
+    required String path,
+  });
+
+  Future<FolderMarkerInfo> crateApiGetFolderMarker({required String path});
+
+  Future<int> crateApiGetFreeSpace({required String path});
+
   Future<String> crateApiGetIndexPath();
 
   Future<int> crateApiGetIndexedFileCount();
 
+  Future<String> crateApiGetInternalFilePrefix();
+
+  Future<List<ReportFile>> crateApiGetLargestFiles({required int limit});
+
+  Future<LogFileOptions> crateApiGetLogFileOptions();
+
+  Future<String?> crateApiGetLogFilePath();
+
+  Future<String> crateApiGetLogLevel();
+
+  Future<OnboardingState> crateApiGetOnboardingState();
+
+  Future<int> crateApiGetPdfPageCount({required String path});
+
+  Future<TelemetryPreview> crateApiGetPendingTelemetry();
+
+  Future<PolicyStatus> crateApiGetPolicyStatus();
+
+  Future<String?> crateApiGetSetting({required String key});
+
+  Future<List<ReportFile>> crateApiGetStaleFiles({required int olderThanDays});
+
+  Future<String?> crateApiGetTextPreview({required String path});
+
+  Future<ApiThumbnail> crateApiGetThumbnail({
+    required String path,
+    int? maxEdge,
+  });
+
+  Future<ImageBuffer> crateApiGetThumbnailBuffer({
+    required String path,
+    int? maxEdge,
+  });
+
+  Future<Uint8List> crateApiGetThumbnailBytes({
+    required String path,
+    int? maxEdge,
+  });
+
+  Future<VolumeInfo> crateApiGetVolumeInfo({required String path});
+
+  Future<List<WatchTreeStat>> crateApiGetWatchTreeStats({
+    required String watcherId,
+  });
+
+  Future<int> crateApiGetWatchVolumeFreeSpace({required String watcherId});
+
+  Future<WatcherMetrics> crateApiGetWatcherMetrics({required String watcherId});
+
+  Future<List<ApiXattr>> crateApiGetXattrs({required String path});
+
   Future<bool> crateApiHasEmbeddings({required String filePath});
 
+  Future<ConfigImportResult> crateApiImportConfig({required String path});
+
   Future<void> crateApiIndexFileWithDescription({
     required String filePath,
     required String fileName,
     required String description,
   });
 
+  Future<CoreHandle> crateApiInitCore({required CoreConfig config});
+
   Future<void> crateApiInitIndex({required String dbPath});
 
+  Future<void> crateApiInitLlm({required String dataDir});
+
   Future<void> crateApiInitLogging();
 
   Future<void> crateApiInitSemanticModel({required String dataDir});
 
+  Future<bool> crateApiIsAutoIndexingEnabled();
+
+  Future<bool> crateApiIsCodeScanningEnabled();
+
+  Future<bool> crateApiIsCopyInPaused();
+
+  Future<bool> crateApiIsDryRunMode();
+
+  Future<bool> crateApiIsDuplicateDetectionEnabled();
+
+  Future<bool> crateApiIsEmailFile({required String path});
+
   Future<bool> crateApiIsFileIndexed({required String filePath});
 
+  Future<bool> crateApiIsFileLocked({required String path});
+
+  Future<bool> crateApiIsLlmReady();
+
   Future<bool> crateApiIsOcrSupported({required String path});
 
+  Future<bool> crateApiIsPreviewPregenerationEnabled();
+
+  Future<bool> crateApiIsReadOnlyMode();
+
   Future<bool> crateApiIsSemanticModelReady();
 
+  Future<bool> crateApiIsSharedFolderClaimsEnabled();
+
+  Future<bool> crateApiIsTelemetryEnabled();
+
+  Future<bool> crateApiIsThumbnailPregenerationEnabled();
+
+  Future<List<ApiArchivedFile>> crateApiListArchivedFiles({required int limit});
+
+  Future<List<FileRule>> crateApiListFileRules();
+
+  Future<List<String>> crateApiListTags({required String path});
+
+  Future<List<WatcherInfo>> crateApiListWatchers();
+
+  Future<int> crateApiMoveFile({required String src, required String dst});
+
   Future<OcrResult> crateApiOcrExtractText({
     required String path,
     required OcrOptions options,
   });
 
+  Stream<AdoptionProgressEvent> crateApiOnAdoptionProgress();
+
+  Stream<DryRunActionEvent> crateApiOnDryRunAction();
+
+  Stream<DuplicateDetected> crateApiOnDuplicateDetected();
+
   Stream<FileAddedEvent> crateApiOnFileAdded();
 
+  Stream<AckableFileEvent> crateApiOnFileAddedAckable();
+
+  Stream<FileBatchEvent> crateApiOnFileBatch();
+
+  Stream<FileChunkEvent> crateApiOnFileChunks();
+
+  Stream<FileEvent> crateApiOnFileEvent();
+
+  Stream<FileHashEvent> crateApiOnFileHash();
+
+  Stream<FileOpEvent> crateApiOnFileOpProgress();
+
   Stream<FileRemovedEvent> crateApiOnFileRemoved();
 
+  Stream<FileStatusChangedEvent> crateApiOnFileStatusChanged();
+
+  Stream<HeartbeatEvent> crateApiOnHeartbeat({int? intervalMs});
+
+  Stream<LogRecord> crateApiOnLog();
+
+  Stream<RuleAppliedEvent> crateApiOnRuleApplied();
+
+  Stream<ScreenshotAddedEvent> crateApiOnScreenshotAdded();
+
+  Stream<WatchStatusEvent> crateApiOnWatchStatus();
+
+  Stream<WatcherStatusEvent> crateApiOnWatcherStatus({int? intervalMs});
+
+  Future<List<String>> crateApiQueryByTag({required String tag});
+
+  Future<List<ApiJournalEntry>> crateApiQueryEvents({
+    required int sinceMs,
+    required int limit,
+  });
+
   Future<RagQueryResult> crateApiRagQuery({
     required String question,
     required int topK,
   });
 
+  Future<PolicyStatus> crateApiReloadPolicy();
+
+  Future<bool> crateApiRemoveFileRule({required String ruleId});
+
   Future<bool> crateApiRemoveFromIndex({required String filePath});
 
+  Future<bool> crateApiRemoveSetting({required String key});
+
+  Future<bool> crateApiRemoveTag({required String path, required String tag});
+
+  Future<int> crateApiRenameFile({
+    required String path,
+    required String newName,
+  });
+
+  Future<List<ImageBuffer>> crateApiRenderPdfPageBuffers({
+    required String path,
+    required Uint32List pages,
+    required int dpi,
+  });
+
+  Future<List<PdfPagePreview>> crateApiRenderPdfPages({
+    required String path,
+    required Uint32List pages,
+    required int dpi,
+  });
+
+  Future<FolderMarkerInfo> crateApiRepairFolderMarker({required String path});
+
+  Future<int> crateApiRescanNow({required String watcherId});
+
+  Future<List<ApiDetectedCode>> crateApiScanImageCodes({required String path});
+
+  Future<List<SearchResultItem>> crateApiSearch({
+    required String query,
+    required int limit,
+  });
+
   Future<List<SearchResultItem>> crateApiSearchFiles({
     required String query,
     required int limit,
@@ -162,9 +488,133 @@ abstract class RustCoreApi extends BaseApi {
     required int topK,
   });
 
-  Future<String> crateApiStartWatching({String? overridePath});
+  Future<void> crateApiSetAckMode({
+    required bool enabled,
+    int? redeliveryTimeoutMs,
+  });
+
+  Future<void> crateApiSetArchiveOptions({ArchiveOptions? options});
+
+  Future<void> crateApiSetAutoIndexing({required bool enabled});
+
+  Future<void> crateApiSetChunkedTransfer({int? eventsPerChunk});
+
+  Future<void> crateApiSetCodeScanning({required bool enabled});
+
+  Future<void> crateApiSetDryRunMode({required bool enabled});
+
+  Future<void> crateApiSetDuplicateDetection({required bool enabled});
+
+  Future<void> crateApiSetEventDebugEnrichment({required bool enabled});
+
+  Future<void> crateApiSetEventHashing({HashAlgorithm? algorithm});
+
+  Future<void> crateApiSetEventTimestampSource({
+    required EventTimestampSource source,
+  });
+
+  Future<void> crateApiSetFileRule({required FileRule rule});
+
+  Future<void> crateApiSetFileStatus({
+    required String path,
+    required FileProcessingStatus status,
+  });
+
+  Future<void> crateApiSetFileStatuses({
+    required List<FileStatusUpdate> updates,
+  });
+
+  Future<void> crateApiSetFolderQuota({
+    int? maxTotalBytes,
+    int? maxFileCount,
+    required bool cleanupOldest,
+  });
+
+  Future<void> crateApiSetFseventsReplay({required bool enabled});
+
+  Future<void> crateApiSetIgnoreFilesOlderThan({int? maxAgeMinutes});
+
+  Future<void> crateApiSetInternalFilePrefix({required String prefix});
+
+  Future<void> crateApiSetLogFileOptions({required LogFileOptions options});
+
+  Future<void> crateApiSetLogLevel({required String level});
+
+  Future<void> crateApiSetLowDiskSpaceThreshold({
+    required int thresholdBytes,
+    int? checkIntervalMs,
+  });
+
+  Future<void> crateApiSetOfflineChangeDetection({required bool enabled});
+
+  Future<void> crateApiSetPreviewPregeneration({required bool enabled});
+
+  Future<void> crateApiSetReadOnlyMode({required bool enabled});
+
+  Future<void> crateApiSetSetting({required String key, required String value});
+
+  Future<void> crateApiSetSettleQuietPeriod({int? quietPeriodMs});
+
+  Future<void> crateApiSetSharedFolderClaims({required bool enabled});
+
+  Future<void> crateApiSetSystemLogMirroring({required bool enabled});
+
+  Future<void> crateApiSetTelemetryEnabled({
+    required bool enabled,
+    String? uploadUrl,
+  });
+
+  Future<void> crateApiSetThumbnailPregeneration({required bool enabled});
+
+  Future<void> crateApiSetWatchBackend({
+    required ApiWatchBackend backend,
+    int? pollIntervalMs,
+  });
+
+  Future<void> crateApiSetWatchDirRecovery({
+    required WatchDirRecoveryMode mode,
+  });
+
+  Future<void> crateApiSetWatchFilter({WatchFilter? filter});
+
+  Future<void> crateApiSetWatchRecursive({required bool enabled});
+
+  Future<void> crateApiSetWatcherDegradation({
+    WatcherDegradationPolicy? policy,
+  });
+
+  Future<String> crateApiSetWatcherLogFile({
+    required String watcherId,
+    required bool enabled,
+  });
+
+  Future<void> crateApiSetXattr({
+    required String path,
+    required String name,
+    required Uint8List value,
+  });
+
+  Future<void> crateApiShutdownAll({required int timeoutMs});
+
+  Future<void> crateApiShutdownCore();
+
+  Future<List<String>> crateApiSortFileNames({
+    required List<String> names,
+    String? locale,
+  });
+
+  Future<List<String>> crateApiStartConfiguredWatchers();
+
+  Future<WatchStarted> crateApiStartWatching({
+    String? overridePath,
+    WatchTuning? tuning,
+  });
+
+  Future<String> crateApiStartWatchingDownloads({String? moveTo});
 
-  Future<void> crateApiStopWatching();
+  Future<String> crateApiStartWatchingScreenshots({String? moveTo});
+
+  Future<void> crateApiStopWatching({required String watcherId});
 
   Future<void> crateApiStoreChunksAndEmbeddings({
     required String filePath,
@@ -172,23 +622,48 @@ abstract class RustCoreApi extends BaseApi {
     required List<ApiEmbeddingVector> embeddings,
   });
 
+  Future<List<WatchLocationSuggestion>> crateApiSuggestWatchLocations();
+
   Future<TranscriptionResult> crateApiTranscribeAudio({
     required String path,
     required TranscriptionOptions options,
   });
 
+  Future<void> crateApiUnloadLlm();
+
   Future<void> crateApiUnloadSemanticModel();
 
+  Future<ApiConfig> crateApiUpdateConfig({required ApiConfigPatch patch});
+
   Future<void> crateApiUpdateTranscript({
     required String filePath,
     required String transcript,
   });
 
-  Future<void> crateApiInitLlm({required String dataDir});
+  Future<List<IntegrityIssue>> crateApiVerifyFolderIntegrity({
+    required bool repair,
+  });
 
-  Future<void> crateApiUnloadLlm();
+  Future<bool> crateApiWaitUntilUnlocked({
+    required String path,
+    required int timeoutMs,
+  });
 
-  Future<bool> crateApiIsLlmReady();
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_CoreHandle;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_CoreHandle;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CoreHandlePtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_ImageBuffer;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ImageBuffer;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ImageBufferPtr;
 
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_LateraError;
@@ -208,18 +683,17 @@ class RustCoreApiImpl extends RustCoreApiImplPlatform implements RustCoreApi {
   });
 
   @override
-  Future<List<ApiTextChunk>> crateApiChunkText({
-    required String text,
-    required int chunkSize,
-    required int chunkOverlap,
+  Future<CoreCapabilities> crateApiCoreHandleCapabilities({
+    required CoreHandle that,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(text, serializer);
-          sse_encode_u_32(chunkSize, serializer);
-          sse_encode_u_32(chunkOverlap, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+            that,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -228,27 +702,32 @@ class RustCoreApiImpl extends RustCoreApiImplPlatform implements RustCoreApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_api_text_chunk,
+          decodeSuccessData: sse_decode_core_capabilities,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiChunkTextConstMeta,
-        argValues: [text, chunkSize, chunkOverlap],
+        constMeta: kCrateApiCoreHandleCapabilitiesConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiChunkTextConstMeta => const TaskConstMeta(
-    debugName: "chunk_text",
-    argNames: ["text", "chunkSize", "chunkOverlap"],
-  );
+  TaskConstMeta get kCrateApiCoreHandleCapabilitiesConstMeta =>
+      const TaskConstMeta(
+        debugName: "CoreHandle_capabilities",
+        argNames: ["that"],
+      );
 
   @override
-  Future<void> crateApiClearAllEmbeddings() {
+  Future<void> crateApiCoreHandleClose({required CoreHandle that}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+            that,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -261,22 +740,30 @@ class RustCoreApiImpl extends RustCoreApiImplPlatform implements RustCoreApi {
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiClearAllEmbeddingsConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCoreHandleCloseConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiClearAllEmbeddingsConstMeta =>
-      const TaskConstMeta(debugName: "clear_all_embeddings", argNames: []);
+  TaskConstMeta get kCrateApiCoreHandleCloseConstMeta =>
+      const TaskConstMeta(debugName: "CoreHandle_close", argNames: ["that"]);
 
   @override
-  Future<void> crateApiClearFileIndex() {
+  Future<FileProcessingStatus?> crateApiCoreHandleGetFileStatus({
+    required CoreHandle that,
+    required String path,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+            that,
+            serializer,
+          );
+          sse_encode_String(path, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -285,29 +772,37 @@ class RustCoreApiImpl extends RustCoreApiImplPlatform implements RustCoreApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_opt_box_autoadd_file_processing_status,
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiClearFileIndexConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCoreHandleGetFileStatusConstMeta,
+        argValues: [that, path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiClearFileIndexConstMeta =>
-      const TaskConstMeta(debugName: "clear_file_index", argNames: []);
+  TaskConstMeta get kCrateApiCoreHandleGetFileStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "CoreHandle_get_file_status",
+        argNames: ["that", "path"],
+      );
 
   @override
-  Future<List<ApiEmbeddingVector>> crateApiComputeEmbeddings({
-    required List<ApiTextChunk> chunks,
+  Future<List<String>> crateApiCoreHandleListTags({
+    required CoreHandle that,
+    required String path,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_api_text_chunk(chunks, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+            that,
+            serializer,
+          );
+          sse_encode_String(path, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -316,32 +811,34 @@ class RustCoreApiImpl extends RustCoreApiImplPlatform implements RustCoreApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_api_embedding_vector,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiComputeEmbeddingsConstMeta,
-        argValues: [chunks],
+        constMeta: kCrateApiCoreHandleListTagsConstMeta,
+        argValues: [that, path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiComputeEmbeddingsConstMeta => const TaskConstMeta(
-    debugName: "compute_embeddings",
-    argNames: ["chunks"],
+  TaskConstMeta get kCrateApiCoreHandleListTagsConstMeta => const TaskConstMeta(
+    debugName: "CoreHandle_list_tags",
+    argNames: ["that", "path"],
   );
 
   @override
-  Future<ExtractionResult> crateApiExtractTextFromFile({
-    required String path,
-    required ExtractionOptions options,
+  Future<List<WatcherInfo>> crateApiCoreHandleListWatchers({
+    required CoreHandle that,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
-          sse_encode_box_autoadd_extraction_options(options, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+            that,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -350,710 +847,941 @@ class RustCoreApiImpl extends RustCoreApiImplPlatform implements RustCoreApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_extraction_result,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_watcher_info,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiExtractTextFromFileConstMeta,
-        argValues: [path, options],
+        constMeta: kCrateApiCoreHandleListWatchersConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiExtractTextFromFileConstMeta =>
+  TaskConstMeta get kCrateApiCoreHandleListWatchersConstMeta =>
       const TaskConstMeta(
-        debugName: "extract_text_from_file",
-        argNames: ["path", "options"],
+        debugName: "CoreHandle_list_watchers",
+        argNames: ["that"],
       );
 
   @override
-  Future<List<ApiSimilarityResult>> crateApiFindSimilarFiles({
-    required String filePath,
-    required int topK,
+  Stream<FileAddedEvent> crateApiCoreHandleOnFileAdded({
+    required CoreHandle that,
+  }) {
+    final sink = RustStreamSink<FileAddedEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+              that,
+              serializer,
+            );
+            sse_encode_StreamSink_file_added_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 6,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiCoreHandleOnFileAddedConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCoreHandleOnFileAddedConstMeta =>
+      const TaskConstMeta(
+        debugName: "CoreHandle_on_file_added",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  Stream<FileBatchEvent> crateApiCoreHandleOnFileBatch({
+    required CoreHandle that,
+  }) {
+    final sink = RustStreamSink<FileBatchEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+              that,
+              serializer,
+            );
+            sse_encode_StreamSink_file_batch_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 7,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiCoreHandleOnFileBatchConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCoreHandleOnFileBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "CoreHandle_on_file_batch",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  Stream<FileChunkEvent> crateApiCoreHandleOnFileChunks({
+    required CoreHandle that,
+  }) {
+    final sink = RustStreamSink<FileChunkEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+              that,
+              serializer,
+            );
+            sse_encode_StreamSink_file_chunk_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 8,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiCoreHandleOnFileChunksConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCoreHandleOnFileChunksConstMeta =>
+      const TaskConstMeta(
+        debugName: "CoreHandle_on_file_chunks",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  Stream<FileEvent> crateApiCoreHandleOnFileEvent({required CoreHandle that}) {
+    final sink = RustStreamSink<FileEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+              that,
+              serializer,
+            );
+            sse_encode_StreamSink_file_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 9,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiCoreHandleOnFileEventConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiCoreHandleOnFileEventConstMeta =>
+      const TaskConstMeta(
+        debugName: "CoreHandle_on_file_event",
+        argNames: ["that", "sink"],
+      );
+
+  @override
+  Future<List<ApiJournalEntry>> crateApiCoreHandleQueryEvents({
+    required CoreHandle that,
+    required int sinceMs,
+    required int limit,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(filePath, serializer);
-          sse_encode_u_32(topK, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+            that,
+            serializer,
+          );
+          sse_encode_CastedPrimitive_i_64(sinceMs, serializer);
+          sse_encode_u_32(limit, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 6,
+            funcId: 10,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_api_similarity_result,
+          decodeSuccessData: sse_decode_list_api_journal_entry,
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiFindSimilarFilesConstMeta,
-        argValues: [filePath, topK],
+        constMeta: kCrateApiCoreHandleQueryEventsConstMeta,
+        argValues: [that, sinceMs, limit],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiFindSimilarFilesConstMeta => const TaskConstMeta(
-    debugName: "find_similar_files",
-    argNames: ["filePath", "topK"],
-  );
+  TaskConstMeta get kCrateApiCoreHandleQueryEventsConstMeta =>
+      const TaskConstMeta(
+        debugName: "CoreHandle_query_events",
+        argNames: ["that", "sinceMs", "limit"],
+      );
 
   @override
-  Future<String> crateApiGetDefaultWatchPath() {
+  Future<void> crateApiCoreHandleSetWatchFilter({
+    required CoreHandle that,
+    WatchFilter? filter,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+            that,
+            serializer,
+          );
+          sse_encode_opt_box_autoadd_watch_filter(filter, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 7,
+            funcId: 11,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiGetDefaultWatchPathConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCoreHandleSetWatchFilterConstMeta,
+        argValues: [that, filter],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGetDefaultWatchPathConstMeta =>
-      const TaskConstMeta(debugName: "get_default_watch_path", argNames: []);
+  TaskConstMeta get kCrateApiCoreHandleSetWatchFilterConstMeta =>
+      const TaskConstMeta(
+        debugName: "CoreHandle_set_watch_filter",
+        argNames: ["that", "filter"],
+      );
 
   @override
-  Future<String> crateApiGetDefaultWatchPathPreview() {
+  Future<void> crateApiCoreHandleSetWatchRecursive({
+    required CoreHandle that,
+    required bool enabled,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+            that,
+            serializer,
+          );
+          sse_encode_bool(enabled, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 8,
+            funcId: 12,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiGetDefaultWatchPathPreviewConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCoreHandleSetWatchRecursiveConstMeta,
+        argValues: [that, enabled],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGetDefaultWatchPathPreviewConstMeta =>
+  TaskConstMeta get kCrateApiCoreHandleSetWatchRecursiveConstMeta =>
       const TaskConstMeta(
-        debugName: "get_default_watch_path_preview",
-        argNames: [],
+        debugName: "CoreHandle_set_watch_recursive",
+        argNames: ["that", "enabled"],
       );
 
   @override
-  Future<int> crateApiGetEmbeddingCount() {
+  Future<void> crateApiCoreHandleSetWatchTuning({
+    required CoreHandle that,
+    required WatchTuning tuning,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+            that,
+            serializer,
+          );
+          sse_encode_box_autoadd_watch_tuning(tuning, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 9,
+            funcId: 13,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_CastedPrimitive_i_64,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiGetEmbeddingCountConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCoreHandleSetWatchTuningConstMeta,
+        argValues: [that, tuning],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGetEmbeddingCountConstMeta =>
-      const TaskConstMeta(debugName: "get_embedding_count", argNames: []);
+  TaskConstMeta get kCrateApiCoreHandleSetWatchTuningConstMeta =>
+      const TaskConstMeta(
+        debugName: "CoreHandle_set_watch_tuning",
+        argNames: ["that", "tuning"],
+      );
 
   @override
-  Future<int> crateApiGetEmbeddingDim() {
+  Future<WatchStarted> crateApiCoreHandleStartWatching({
+    required CoreHandle that,
+    String? overridePath,
+    WatchTuning? tuning,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+            that,
+            serializer,
+          );
+          sse_encode_opt_String(overridePath, serializer);
+          sse_encode_opt_box_autoadd_watch_tuning(tuning, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 10,
+            funcId: 14,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_u_32,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_watch_started,
+          decodeErrorData: sse_decode_latera_api_error,
         ),
-        constMeta: kCrateApiGetEmbeddingDimConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCoreHandleStartWatchingConstMeta,
+        argValues: [that, overridePath, tuning],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGetEmbeddingDimConstMeta =>
-      const TaskConstMeta(debugName: "get_embedding_dim", argNames: []);
+  TaskConstMeta get kCrateApiCoreHandleStartWatchingConstMeta =>
+      const TaskConstMeta(
+        debugName: "CoreHandle_start_watching",
+        argNames: ["that", "overridePath", "tuning"],
+      );
 
   @override
-  Future<String> crateApiGetIndexPath() {
+  Future<void> crateApiCoreHandleStopWatching({
+    required CoreHandle that,
+    required String watcherId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+            that,
+            serializer,
+          );
+          sse_encode_String(watcherId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 11,
+            funcId: 15,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_latera_api_error,
         ),
-        constMeta: kCrateApiGetIndexPathConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCoreHandleStopWatchingConstMeta,
+        argValues: [that, watcherId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGetIndexPathConstMeta =>
-      const TaskConstMeta(debugName: "get_index_path", argNames: []);
+  TaskConstMeta get kCrateApiCoreHandleStopWatchingConstMeta =>
+      const TaskConstMeta(
+        debugName: "CoreHandle_stop_watching",
+        argNames: ["that", "watcherId"],
+      );
 
   @override
-  Future<int> crateApiGetIndexedFileCount() {
+  Future<int> crateApiImageBufferHeight({required ImageBuffer that}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+            that,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 12,
+            funcId: 16,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_CastedPrimitive_i_64,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiGetIndexedFileCountConstMeta,
-        argValues: [],
+        constMeta: kCrateApiImageBufferHeightConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiGetIndexedFileCountConstMeta =>
-      const TaskConstMeta(debugName: "get_indexed_file_count", argNames: []);
+  TaskConstMeta get kCrateApiImageBufferHeightConstMeta =>
+      const TaskConstMeta(debugName: "ImageBuffer_height", argNames: ["that"]);
 
   @override
-  Future<bool> crateApiHasEmbeddings({required String filePath}) {
+  Future<bool> crateApiImageBufferIsEmpty({required ImageBuffer that}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(filePath, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+            that,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 13,
+            funcId: 17,
             port: port_,
           );
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_bool,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiHasEmbeddingsConstMeta,
-        argValues: [filePath],
+        constMeta: kCrateApiImageBufferIsEmptyConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiHasEmbeddingsConstMeta =>
-      const TaskConstMeta(debugName: "has_embeddings", argNames: ["filePath"]);
+  TaskConstMeta get kCrateApiImageBufferIsEmptyConstMeta => const TaskConstMeta(
+    debugName: "ImageBuffer_is_empty",
+    argNames: ["that"],
+  );
 
   @override
-  Future<void> crateApiIndexFileWithDescription({
-    required String filePath,
-    required String fileName,
-    required String description,
-  }) {
+  Future<int> crateApiImageBufferLen({required ImageBuffer that}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(filePath, serializer);
-          sse_encode_String(fileName, serializer);
-          sse_encode_String(description, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+            that,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 14,
+            funcId: 18,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+          decodeSuccessData: sse_decode_CastedPrimitive_usize,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiIndexFileWithDescriptionConstMeta,
-        argValues: [filePath, fileName, description],
+        constMeta: kCrateApiImageBufferLenConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiIndexFileWithDescriptionConstMeta =>
-      const TaskConstMeta(
-        debugName: "index_file_with_description",
-        argNames: ["filePath", "fileName", "description"],
-      );
+  TaskConstMeta get kCrateApiImageBufferLenConstMeta =>
+      const TaskConstMeta(debugName: "ImageBuffer_len", argNames: ["that"]);
 
   @override
-  Future<void> crateApiInitIndex({required String dbPath}) {
+  Future<String> crateApiImageBufferMimeType({required ImageBuffer that}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(dbPath, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+            that,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 15,
+            funcId: 19,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiInitIndexConstMeta,
-        argValues: [dbPath],
+        constMeta: kCrateApiImageBufferMimeTypeConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitIndexConstMeta =>
-      const TaskConstMeta(debugName: "init_index", argNames: ["dbPath"]);
+  TaskConstMeta get kCrateApiImageBufferMimeTypeConstMeta =>
+      const TaskConstMeta(
+        debugName: "ImageBuffer_mime_type",
+        argNames: ["that"],
+      );
 
   @override
-  Future<void> crateApiInitLogging() {
+  Future<Uint8List> crateApiImageBufferToBytes({required ImageBuffer that}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+            that,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 16,
+            funcId: 20,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiInitLoggingConstMeta,
-        argValues: [],
+        constMeta: kCrateApiImageBufferToBytesConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitLoggingConstMeta =>
-      const TaskConstMeta(debugName: "init_logging", argNames: []);
+  TaskConstMeta get kCrateApiImageBufferToBytesConstMeta => const TaskConstMeta(
+    debugName: "ImageBuffer_to_bytes",
+    argNames: ["that"],
+  );
 
   @override
-  Future<void> crateApiInitSemanticModel({required String dataDir}) {
+  Future<int> crateApiImageBufferWidth({required ImageBuffer that}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(dataDir, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+            that,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 17,
+            funcId: 21,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiInitSemanticModelConstMeta,
-        argValues: [dataDir],
+        constMeta: kCrateApiImageBufferWidthConstMeta,
+        argValues: [that],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitSemanticModelConstMeta => const TaskConstMeta(
-    debugName: "init_semantic_model",
-    argNames: ["dataDir"],
-  );
+  TaskConstMeta get kCrateApiImageBufferWidthConstMeta =>
+      const TaskConstMeta(debugName: "ImageBuffer_width", argNames: ["that"]);
 
   @override
-  Future<bool> crateApiIsFileIndexed({required String filePath}) {
+  Future<void> crateApiAckEvent({required int sequence}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(filePath, serializer);
+          sse_encode_CastedPrimitive_u_64(sequence, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 18,
+            funcId: 22,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiIsFileIndexedConstMeta,
-        argValues: [filePath],
+        constMeta: kCrateApiAckEventConstMeta,
+        argValues: [sequence],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiIsFileIndexedConstMeta =>
-      const TaskConstMeta(debugName: "is_file_indexed", argNames: ["filePath"]);
+  TaskConstMeta get kCrateApiAckEventConstMeta =>
+      const TaskConstMeta(debugName: "ack_event", argNames: ["sequence"]);
 
   @override
-  Future<bool> crateApiIsOcrSupported({required String path}) {
+  Future<void> crateApiAddTag({required String path, required String tag}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(path, serializer);
+          sse_encode_String(tag, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 19,
+            funcId: 23,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiIsOcrSupportedConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiAddTagConstMeta,
+        argValues: [path, tag],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiIsOcrSupportedConstMeta =>
-      const TaskConstMeta(debugName: "is_ocr_supported", argNames: ["path"]);
+  TaskConstMeta get kCrateApiAddTagConstMeta =>
+      const TaskConstMeta(debugName: "add_tag", argNames: ["path", "tag"]);
 
   @override
-  Future<bool> crateApiIsSemanticModelReady() {
+  Future<int> crateApiAdoptFolder({
+    required String path,
+    required bool recursive,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_bool(recursive, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 20,
+            funcId: 24,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_CastedPrimitive_u_64,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiIsSemanticModelReadyConstMeta,
-        argValues: [],
+        constMeta: kCrateApiAdoptFolderConstMeta,
+        argValues: [path, recursive],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiIsSemanticModelReadyConstMeta =>
-      const TaskConstMeta(debugName: "is_semantic_model_ready", argNames: []);
+  TaskConstMeta get kCrateApiAdoptFolderConstMeta => const TaskConstMeta(
+    debugName: "adopt_folder",
+    argNames: ["path", "recursive"],
+  );
 
   @override
-  Future<OcrResult> crateApiOcrExtractText({
-    required String path,
-    required OcrOptions options,
+  Future<List<ApiArchivedFile>> crateApiArchiveOldFilesNow({
+    required String watcherId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
-          sse_encode_box_autoadd_ocr_options(options, serializer);
+          sse_encode_String(watcherId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 21,
+            funcId: 25,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_ocr_result,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_list_api_archived_file,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiOcrExtractTextConstMeta,
-        argValues: [path, options],
+        constMeta: kCrateApiArchiveOldFilesNowConstMeta,
+        argValues: [watcherId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiOcrExtractTextConstMeta => const TaskConstMeta(
-    debugName: "ocr_extract_text",
-    argNames: ["path", "options"],
+  TaskConstMeta get kCrateApiArchiveOldFilesNowConstMeta => const TaskConstMeta(
+    debugName: "archive_old_files_now",
+    argNames: ["watcherId"],
   );
 
   @override
-  Stream<FileAddedEvent> crateApiOnFileAdded() {
-    final sink = RustStreamSink<FileAddedEvent>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_StreamSink_file_added_event_Sse(sink, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 22,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: null,
-          ),
-          constMeta: kCrateApiOnFileAddedConstMeta,
-          argValues: [sink],
-          apiImpl: this,
-        ),
-      ),
-    );
-    return sink.stream;
-  }
-
-  TaskConstMeta get kCrateApiOnFileAddedConstMeta =>
-      const TaskConstMeta(debugName: "on_file_added", argNames: ["sink"]);
-
-  @override
-  Stream<FileRemovedEvent> crateApiOnFileRemoved() {
-    final sink = RustStreamSink<FileRemovedEvent>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_StreamSink_file_removed_event_Sse(sink, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 23,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: null,
-          ),
-          constMeta: kCrateApiOnFileRemovedConstMeta,
-          argValues: [sink],
-          apiImpl: this,
-        ),
-      ),
-    );
-    return sink.stream;
-  }
-
-  TaskConstMeta get kCrateApiOnFileRemovedConstMeta =>
-      const TaskConstMeta(debugName: "on_file_removed", argNames: ["sink"]);
-
-  @override
-  Future<RagQueryResult> crateApiRagQuery({
-    required String question,
-    required int topK,
+  Future<List<ApiTextChunk>> crateApiChunkText({
+    required String text,
+    required int chunkSize,
+    required int chunkOverlap,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(question, serializer);
-          sse_encode_u_32(topK, serializer);
+          sse_encode_String(text, serializer);
+          sse_encode_u_32(chunkSize, serializer);
+          sse_encode_u_32(chunkOverlap, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 24,
+            funcId: 26,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_rag_query_result,
-          decodeErrorData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+          decodeSuccessData: sse_decode_list_api_text_chunk,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiRagQueryConstMeta,
-        argValues: [question, topK],
+        constMeta: kCrateApiChunkTextConstMeta,
+        argValues: [text, chunkSize, chunkOverlap],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRagQueryConstMeta => const TaskConstMeta(
-    debugName: "rag_query",
-    argNames: ["question", "topK"],
+  TaskConstMeta get kCrateApiChunkTextConstMeta => const TaskConstMeta(
+    debugName: "chunk_text",
+    argNames: ["text", "chunkSize", "chunkOverlap"],
   );
 
   @override
-  Future<bool> crateApiRemoveFromIndex({required String filePath}) {
+  Future<void> crateApiClearAllEmbeddings() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(filePath, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 25,
+            funcId: 27,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiRemoveFromIndexConstMeta,
-        argValues: [filePath],
+        constMeta: kCrateApiClearAllEmbeddingsConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiRemoveFromIndexConstMeta => const TaskConstMeta(
-    debugName: "remove_from_index",
-    argNames: ["filePath"],
-  );
+  TaskConstMeta get kCrateApiClearAllEmbeddingsConstMeta =>
+      const TaskConstMeta(debugName: "clear_all_embeddings", argNames: []);
 
   @override
-  Future<List<SearchResultItem>> crateApiSearchFiles({
-    required String query,
-    required int limit,
-  }) {
+  Future<void> crateApiClearFileIndex() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(query, serializer);
-          sse_encode_u_32(limit, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 26,
+            funcId: 28,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_search_result_item,
+          decodeSuccessData: sse_decode_unit,
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiSearchFilesConstMeta,
-        argValues: [query, limit],
+        constMeta: kCrateApiClearFileIndexConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSearchFilesConstMeta => const TaskConstMeta(
-    debugName: "search_files",
-    argNames: ["query", "limit"],
-  );
+  TaskConstMeta get kCrateApiClearFileIndexConstMeta =>
+      const TaskConstMeta(debugName: "clear_file_index", argNames: []);
 
   @override
-  Future<List<ApiSimilarityResult>> crateApiSemanticSearch({
-    required String query,
-    required int topK,
+  Future<int> crateApiClearJournal() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiClearJournalConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiClearJournalConstMeta =>
+      const TaskConstMeta(debugName: "clear_journal", argNames: []);
+
+  @override
+  Future<OnboardingState> crateApiCompleteOnboardingStep({
+    required OnboardingStep step,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(query, serializer);
-          sse_encode_u_32(topK, serializer);
+          sse_encode_onboarding_step(step, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 27,
+            funcId: 30,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_api_similarity_result,
+          decodeSuccessData: sse_decode_onboarding_state,
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiSemanticSearchConstMeta,
-        argValues: [query, topK],
+        constMeta: kCrateApiCompleteOnboardingStepConstMeta,
+        argValues: [step],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSemanticSearchConstMeta => const TaskConstMeta(
-    debugName: "semantic_search",
-    argNames: ["query", "topK"],
+  TaskConstMeta get kCrateApiCompleteOnboardingStepConstMeta =>
+      const TaskConstMeta(
+        debugName: "complete_onboarding_step",
+        argNames: ["step"],
+      );
+
+  @override
+  Future<List<ApiEmbeddingVector>> crateApiComputeEmbeddings({
+    required List<ApiTextChunk> chunks,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_api_text_chunk(chunks, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_api_embedding_vector,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiComputeEmbeddingsConstMeta,
+        argValues: [chunks],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiComputeEmbeddingsConstMeta => const TaskConstMeta(
+    debugName: "compute_embeddings",
+    argNames: ["chunks"],
   );
 
   @override
-  Future<String> crateApiStartWatching({String? overridePath}) {
+  Future<String> crateApiComputeHash({
+    required String path,
+    required HashAlgorithm algorithm,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_opt_String(overridePath, serializer);
+          sse_encode_String(path, serializer);
+          sse_encode_hash_algorithm(algorithm, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 28,
+            funcId: 32,
             port: port_,
           );
         },
@@ -1062,120 +1790,115 @@ class RustCoreApiImpl extends RustCoreApiImplPlatform implements RustCoreApi {
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiStartWatchingConstMeta,
-        argValues: [overridePath],
+        constMeta: kCrateApiComputeHashConstMeta,
+        argValues: [path, algorithm],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiStartWatchingConstMeta => const TaskConstMeta(
-    debugName: "start_watching",
-    argNames: ["overridePath"],
+  TaskConstMeta get kCrateApiComputeHashConstMeta => const TaskConstMeta(
+    debugName: "compute_hash",
+    argNames: ["path", "algorithm"],
   );
 
   @override
-  Future<void> crateApiStopWatching() {
+  Future<int> crateApiCopyFile({
+    required String src,
+    required String dst,
+    required bool overwrite,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(src, serializer);
+          sse_encode_String(dst, serializer);
+          sse_encode_bool(overwrite, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 29,
+            funcId: 33,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_CastedPrimitive_u_64,
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiStopWatchingConstMeta,
-        argValues: [],
+        constMeta: kCrateApiCopyFileConstMeta,
+        argValues: [src, dst, overwrite],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiStopWatchingConstMeta =>
-      const TaskConstMeta(debugName: "stop_watching", argNames: []);
+  TaskConstMeta get kCrateApiCopyFileConstMeta => const TaskConstMeta(
+    debugName: "copy_file",
+    argNames: ["src", "dst", "overwrite"],
+  );
 
   @override
-  Future<void> crateApiStoreChunksAndEmbeddings({
-    required String filePath,
-    required List<ApiTextChunk> chunks,
-    required List<ApiEmbeddingVector> embeddings,
-  }) {
+  Future<String?> crateApiDeleteToTrash({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(filePath, serializer);
-          sse_encode_list_api_text_chunk(chunks, serializer);
-          sse_encode_list_api_embedding_vector(embeddings, serializer);
+          sse_encode_String(path, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 30,
+            funcId: 34,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_opt_String,
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiStoreChunksAndEmbeddingsConstMeta,
-        argValues: [filePath, chunks, embeddings],
+        constMeta: kCrateApiDeleteToTrashConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiStoreChunksAndEmbeddingsConstMeta =>
-      const TaskConstMeta(
-        debugName: "store_chunks_and_embeddings",
-        argNames: ["filePath", "chunks", "embeddings"],
-      );
+  TaskConstMeta get kCrateApiDeleteToTrashConstMeta =>
+      const TaskConstMeta(debugName: "delete_to_trash", argNames: ["path"]);
 
   @override
-  Future<TranscriptionResult> crateApiTranscribeAudio({
-    required String path,
-    required TranscriptionOptions options,
-  }) {
+  Future<void> crateApiExportConfig({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(path, serializer);
-          sse_encode_box_autoadd_transcription_options(options, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 31,
+            funcId: 35,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_transcription_result,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiTranscribeAudioConstMeta,
-        argValues: [path, options],
+        constMeta: kCrateApiExportConfigConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiTranscribeAudioConstMeta => const TaskConstMeta(
-    debugName: "transcribe_audio",
-    argNames: ["path", "options"],
-  );
+  TaskConstMeta get kCrateApiExportConfigConstMeta =>
+      const TaskConstMeta(debugName: "export_config", argNames: ["path"]);
 
   @override
-  Future<void> crateApiUnloadSemanticModel() {
+  Future<String> crateApiExportMetricsText() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1183,1378 +1906,12466 @@ class RustCoreApiImpl extends RustCoreApiImplPlatform implements RustCoreApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 32,
+            funcId: 36,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiUnloadSemanticModelConstMeta,
+        constMeta: kCrateApiExportMetricsTextConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUnloadSemanticModelConstMeta =>
-      const TaskConstMeta(debugName: "unload_semantic_model", argNames: []);
+  TaskConstMeta get kCrateApiExportMetricsTextConstMeta =>
+      const TaskConstMeta(debugName: "export_metrics_text", argNames: []);
 
   @override
-  Future<void> crateApiUpdateTranscript({
-    required String filePath,
-    required String transcript,
+  Future<List<String>> crateApiExtractEmailAttachments({
+    required String path,
+    String? targetDir,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(filePath, serializer);
-          sse_encode_String(transcript, serializer);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_String(targetDir, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 33,
+            funcId: 37,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_String,
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiUpdateTranscriptConstMeta,
-        argValues: [filePath, transcript],
+        constMeta: kCrateApiExtractEmailAttachmentsConstMeta,
+        argValues: [path, targetDir],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUpdateTranscriptConstMeta => const TaskConstMeta(
-    debugName: "update_transcript",
-    argNames: ["filePath", "transcript"],
-  );
+  TaskConstMeta get kCrateApiExtractEmailAttachmentsConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_email_attachments",
+        argNames: ["path", "targetDir"],
+      );
 
   @override
-  Future<void> crateApiInitLlm({required String dataDir}) {
+  Future<String> crateApiExtractText({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(dataDir, serializer);
+          sse_encode_String(path, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 34,
+            funcId: 38,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData:
               sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiInitLlmConstMeta,
-        argValues: [dataDir],
+        constMeta: kCrateApiExtractTextConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiInitLlmConstMeta =>
-      const TaskConstMeta(debugName: "init_llm", argNames: ["dataDir"]);
+  TaskConstMeta get kCrateApiExtractTextConstMeta =>
+      const TaskConstMeta(debugName: "extract_text", argNames: ["path"]);
 
   @override
-  Future<void> crateApiUnloadLlm() {
+  Future<ExtractionResult> crateApiExtractTextFromFile({
+    required String path,
+    required ExtractionOptions options,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_box_autoadd_extraction_options(options, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 35,
+            funcId: 39,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_extraction_result,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiUnloadLlmConstMeta,
-        argValues: [],
+        constMeta: kCrateApiExtractTextFromFileConstMeta,
+        argValues: [path, options],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiUnloadLlmConstMeta =>
-      const TaskConstMeta(debugName: "unload_llm", argNames: []);
+  TaskConstMeta get kCrateApiExtractTextFromFileConstMeta =>
+      const TaskConstMeta(
+        debugName: "extract_text_from_file",
+        argNames: ["path", "options"],
+      );
 
   @override
-  Future<bool> crateApiIsLlmReady() {
+  Future<ApiArchivedFile?> crateApiFindArchivedFile({
+    required String originalPath,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(originalPath, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 36,
+            funcId: 40,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_opt_box_autoadd_api_archived_file,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
         ),
-        constMeta: kCrateApiIsLlmReadyConstMeta,
-        argValues: [],
+        constMeta: kCrateApiFindArchivedFileConstMeta,
+        argValues: [originalPath],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiIsLlmReadyConstMeta =>
-      const TaskConstMeta(debugName: "is_llm_ready", argNames: []);
+  TaskConstMeta get kCrateApiFindArchivedFileConstMeta => const TaskConstMeta(
+    debugName: "find_archived_file",
+    argNames: ["originalPath"],
+  );
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_LateraError => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError;
+  @override
+  Future<List<DuplicateGroup>> crateApiFindDuplicates({required String dir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_duplicate_group,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiFindDuplicatesConstMeta,
+        argValues: [dir],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_LateraError => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError;
+  TaskConstMeta get kCrateApiFindDuplicatesConstMeta =>
+      const TaskConstMeta(debugName: "find_duplicates", argNames: ["dir"]);
 
-  @protected
+  @override
+  Future<List<ApiSimilarityResult>> crateApiFindSimilarFiles({
+    required String filePath,
+    required int topK,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          sse_encode_u_32(topK, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_api_similarity_result,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiFindSimilarFilesConstMeta,
+        argValues: [filePath, topK],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFindSimilarFilesConstMeta => const TaskConstMeta(
+    debugName: "find_similar_files",
+    argNames: ["filePath", "topK"],
+  );
+
+  @override
+  Future<List<ActivityBucket>> crateApiGetActivityTimeline({
+    required int fromMs,
+    required int toMs,
+    required TimelineGranularity granularity,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_CastedPrimitive_i_64(fromMs, serializer);
+          sse_encode_CastedPrimitive_i_64(toMs, serializer);
+          sse_encode_timeline_granularity(granularity, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_activity_bucket,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetActivityTimelineConstMeta,
+        argValues: [fromMs, toMs, granularity],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetActivityTimelineConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_activity_timeline",
+        argNames: ["fromMs", "toMs", "granularity"],
+      );
+
+  @override
+  Future<List<ApiAuditEntry>> crateApiGetAuditLog({
+    required AuditLogFilter filter,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_audit_log_filter(filter, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_api_audit_entry,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetAuditLogConstMeta,
+        argValues: [filter],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetAuditLogConstMeta =>
+      const TaskConstMeta(debugName: "get_audit_log", argNames: ["filter"]);
+
+  @override
+  Future<ApiConfig> crateApiGetConfig() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_config,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetConfigConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetConfigConstMeta =>
+      const TaskConstMeta(debugName: "get_config", argNames: []);
+
+  @override
+  Future<ControlledFolderAccessInfo> crateApiGetControlledFolderAccessInfo() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_controlled_folder_access_info,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetControlledFolderAccessInfoConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetControlledFolderAccessInfoConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_controlled_folder_access_info",
+        argNames: [],
+      );
+
+  @override
+  Future<String> crateApiGetDefaultWatchPath() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetDefaultWatchPathConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetDefaultWatchPathConstMeta =>
+      const TaskConstMeta(debugName: "get_default_watch_path", argNames: []);
+
+  @override
+  Future<String> crateApiGetDefaultWatchPathPreview() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetDefaultWatchPathPreviewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetDefaultWatchPathPreviewConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_default_watch_path_preview",
+        argNames: [],
+      );
+
+  @override
+  Future<DesktopAccessStatus> crateApiGetDesktopAccessStatus() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_desktop_access_status,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetDesktopAccessStatusConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetDesktopAccessStatusConstMeta =>
+      const TaskConstMeta(debugName: "get_desktop_access_status", argNames: []);
+
+  @override
+  Future<DirSizeInfo> crateApiGetDirSize({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_dir_size_info,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetDirSizeConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetDirSizeConstMeta =>
+      const TaskConstMeta(debugName: "get_dir_size", argNames: ["path"]);
+
+  @override
+  Future<ApiEmailMetadata> crateApiGetEmailMetadata({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_email_metadata,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetEmailMetadataConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetEmailMetadataConstMeta =>
+      const TaskConstMeta(debugName: "get_email_metadata", argNames: ["path"]);
+
+  @override
+  Future<int> crateApiGetEmbeddingCount() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_CastedPrimitive_i_64,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetEmbeddingCountConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetEmbeddingCountConstMeta =>
+      const TaskConstMeta(debugName: "get_embedding_count", argNames: []);
+
+  @override
+  Future<int> crateApiGetEmbeddingDim() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetEmbeddingDimConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetEmbeddingDimConstMeta =>
+      const TaskConstMeta(debugName: "get_embedding_dim", argNames: []);
+
+  @override
+  Future<EventDebugInfo?> crateApiGetEventDebugInfo({required int sequence}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_CastedPrimitive_u_64(sequence, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_event_debug_info,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetEventDebugInfoConstMeta,
+        argValues: [sequence],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetEventDebugInfoConstMeta => const TaskConstMeta(
+    debugName: "get_event_debug_info",
+    argNames: ["sequence"],
+  );
+
+  @override
+  Future<ApiFileAccessInfo> crateApiGetFileAccessInfo({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_file_access_info,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetFileAccessInfoConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetFileAccessInfoConstMeta => const TaskConstMeta(
+    debugName: "get_file_access_info",
+    argNames: ["path"],
+  );
+
+  @override
+  Future<FileProcessingStatus?> crateApiGetFileStatus({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_file_processing_status,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetFileStatusConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetFileStatusConstMeta =>
+      const TaskConstMeta(debugName: "get_file_status", argNames: ["path"]);
+
+  @override
+  Future<List<CategoryStats>> crateApiGetFolderComposition({
+    required String path,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_category_stats,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetFolderCompositionConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetFolderCompositionConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_folder_composition",
+        argNames: ["path"],
+      );
+
+  @override
+  Future<FolderMarkerInfo> crateApiGetFolderMarker({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_folder_marker_info,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetFolderMarkerConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetFolderMarkerConstMeta =>
+      const TaskConstMeta(debugName: "get_folder_marker", argNames: ["path"]);
+
+  @override
+  Future<int> crateApiGetFreeSpace({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_CastedPrimitive_u_64,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetFreeSpaceConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetFreeSpaceConstMeta =>
+      const TaskConstMeta(debugName: "get_free_space", argNames: ["path"]);
+
+  @override
+  Future<String> crateApiGetIndexPath() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetIndexPathConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetIndexPathConstMeta =>
+      const TaskConstMeta(debugName: "get_index_path", argNames: []);
+
+  @override
+  Future<int> crateApiGetIndexedFileCount() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_CastedPrimitive_i_64,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetIndexedFileCountConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetIndexedFileCountConstMeta =>
+      const TaskConstMeta(debugName: "get_indexed_file_count", argNames: []);
+
+  @override
+  Future<String> crateApiGetInternalFilePrefix() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 62,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetInternalFilePrefixConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetInternalFilePrefixConstMeta =>
+      const TaskConstMeta(debugName: "get_internal_file_prefix", argNames: []);
+
+  @override
+  Future<List<ReportFile>> crateApiGetLargestFiles({required int limit}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_report_file,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetLargestFilesConstMeta,
+        argValues: [limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetLargestFilesConstMeta =>
+      const TaskConstMeta(debugName: "get_largest_files", argNames: ["limit"]);
+
+  @override
+  Future<LogFileOptions> crateApiGetLogFileOptions() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_log_file_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetLogFileOptionsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetLogFileOptionsConstMeta =>
+      const TaskConstMeta(debugName: "get_log_file_options", argNames: []);
+
+  @override
+  Future<String?> crateApiGetLogFilePath() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetLogFilePathConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetLogFilePathConstMeta =>
+      const TaskConstMeta(debugName: "get_log_file_path", argNames: []);
+
+  @override
+  Future<String> crateApiGetLogLevel() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiGetLogLevelConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetLogLevelConstMeta =>
+      const TaskConstMeta(debugName: "get_log_level", argNames: []);
+
+  @override
+  Future<OnboardingState> crateApiGetOnboardingState() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_onboarding_state,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetOnboardingStateConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetOnboardingStateConstMeta =>
+      const TaskConstMeta(debugName: "get_onboarding_state", argNames: []);
+
+  @override
+  Future<int> crateApiGetPdfPageCount({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 68,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetPdfPageCountConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetPdfPageCountConstMeta =>
+      const TaskConstMeta(debugName: "get_pdf_page_count", argNames: ["path"]);
+
+  @override
+  Future<TelemetryPreview> crateApiGetPendingTelemetry() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 69,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_telemetry_preview,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetPendingTelemetryConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetPendingTelemetryConstMeta =>
+      const TaskConstMeta(debugName: "get_pending_telemetry", argNames: []);
+
+  @override
+  Future<PolicyStatus> crateApiGetPolicyStatus() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_policy_status,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetPolicyStatusConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetPolicyStatusConstMeta =>
+      const TaskConstMeta(debugName: "get_policy_status", argNames: []);
+
+  @override
+  Future<String?> crateApiGetSetting({required String key}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetSettingConstMeta,
+        argValues: [key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetSettingConstMeta =>
+      const TaskConstMeta(debugName: "get_setting", argNames: ["key"]);
+
+  @override
+  Future<List<ReportFile>> crateApiGetStaleFiles({required int olderThanDays}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(olderThanDays, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_report_file,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetStaleFilesConstMeta,
+        argValues: [olderThanDays],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetStaleFilesConstMeta => const TaskConstMeta(
+    debugName: "get_stale_files",
+    argNames: ["olderThanDays"],
+  );
+
+  @override
+  Future<String?> crateApiGetTextPreview({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 73,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetTextPreviewConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetTextPreviewConstMeta =>
+      const TaskConstMeta(debugName: "get_text_preview", argNames: ["path"]);
+
+  @override
+  Future<ApiThumbnail> crateApiGetThumbnail({
+    required String path,
+    int? maxEdge,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_box_autoadd_u_32(maxEdge, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_thumbnail,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetThumbnailConstMeta,
+        argValues: [path, maxEdge],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetThumbnailConstMeta => const TaskConstMeta(
+    debugName: "get_thumbnail",
+    argNames: ["path", "maxEdge"],
+  );
+
+  @override
+  Future<ImageBuffer> crateApiGetThumbnailBuffer({
+    required String path,
+    int? maxEdge,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_box_autoadd_u_32(maxEdge, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetThumbnailBufferConstMeta,
+        argValues: [path, maxEdge],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetThumbnailBufferConstMeta => const TaskConstMeta(
+    debugName: "get_thumbnail_buffer",
+    argNames: ["path", "maxEdge"],
+  );
+
+  @override
+  Future<Uint8List> crateApiGetThumbnailBytes({
+    required String path,
+    int? maxEdge,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_box_autoadd_u_32(maxEdge, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetThumbnailBytesConstMeta,
+        argValues: [path, maxEdge],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetThumbnailBytesConstMeta => const TaskConstMeta(
+    debugName: "get_thumbnail_bytes",
+    argNames: ["path", "maxEdge"],
+  );
+
+  @override
+  Future<VolumeInfo> crateApiGetVolumeInfo({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_volume_info,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetVolumeInfoConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetVolumeInfoConstMeta =>
+      const TaskConstMeta(debugName: "get_volume_info", argNames: ["path"]);
+
+  @override
+  Future<List<WatchTreeStat>> crateApiGetWatchTreeStats({
+    required String watcherId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(watcherId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 78,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_watch_tree_stat,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetWatchTreeStatsConstMeta,
+        argValues: [watcherId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetWatchTreeStatsConstMeta => const TaskConstMeta(
+    debugName: "get_watch_tree_stats",
+    argNames: ["watcherId"],
+  );
+
+  @override
+  Future<int> crateApiGetWatchVolumeFreeSpace({required String watcherId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(watcherId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_CastedPrimitive_u_64,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetWatchVolumeFreeSpaceConstMeta,
+        argValues: [watcherId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetWatchVolumeFreeSpaceConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_watch_volume_free_space",
+        argNames: ["watcherId"],
+      );
+
+  @override
+  Future<WatcherMetrics> crateApiGetWatcherMetrics({
+    required String watcherId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(watcherId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 80,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_watcher_metrics,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetWatcherMetricsConstMeta,
+        argValues: [watcherId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetWatcherMetricsConstMeta => const TaskConstMeta(
+    debugName: "get_watcher_metrics",
+    argNames: ["watcherId"],
+  );
+
+  @override
+  Future<List<ApiXattr>> crateApiGetXattrs({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_api_xattr,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiGetXattrsConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiGetXattrsConstMeta =>
+      const TaskConstMeta(debugName: "get_xattrs", argNames: ["path"]);
+
+  @override
+  Future<bool> crateApiHasEmbeddings({required String filePath}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 82,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiHasEmbeddingsConstMeta,
+        argValues: [filePath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHasEmbeddingsConstMeta =>
+      const TaskConstMeta(debugName: "has_embeddings", argNames: ["filePath"]);
+
+  @override
+  Future<ConfigImportResult> crateApiImportConfig({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 83,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_config_import_result,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiImportConfigConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiImportConfigConstMeta =>
+      const TaskConstMeta(debugName: "import_config", argNames: ["path"]);
+
+  @override
+  Future<void> crateApiIndexFileWithDescription({
+    required String filePath,
+    required String fileName,
+    required String description,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          sse_encode_String(fileName, serializer);
+          sse_encode_String(description, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 84,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiIndexFileWithDescriptionConstMeta,
+        argValues: [filePath, fileName, description],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIndexFileWithDescriptionConstMeta =>
+      const TaskConstMeta(
+        debugName: "index_file_with_description",
+        argNames: ["filePath", "fileName", "description"],
+      );
+
+  @override
+  Future<CoreHandle> crateApiInitCore({required CoreConfig config}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_core_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 85,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiInitCoreConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitCoreConstMeta =>
+      const TaskConstMeta(debugName: "init_core", argNames: ["config"]);
+
+  @override
+  Future<void> crateApiInitIndex({required String dbPath}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dbPath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 86,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiInitIndexConstMeta,
+        argValues: [dbPath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitIndexConstMeta =>
+      const TaskConstMeta(debugName: "init_index", argNames: ["dbPath"]);
+
+  @override
+  Future<void> crateApiInitLlm({required String dataDir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 87,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiInitLlmConstMeta,
+        argValues: [dataDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitLlmConstMeta =>
+      const TaskConstMeta(debugName: "init_llm", argNames: ["dataDir"]);
+
+  @override
+  Future<void> crateApiInitLogging() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 88,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiInitLoggingConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitLoggingConstMeta =>
+      const TaskConstMeta(debugName: "init_logging", argNames: []);
+
+  @override
+  Future<void> crateApiInitSemanticModel({required String dataDir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataDir, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 89,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiInitSemanticModelConstMeta,
+        argValues: [dataDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiInitSemanticModelConstMeta => const TaskConstMeta(
+    debugName: "init_semantic_model",
+    argNames: ["dataDir"],
+  );
+
+  @override
+  Future<bool> crateApiIsAutoIndexingEnabled() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 90,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIsAutoIndexingEnabledConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsAutoIndexingEnabledConstMeta =>
+      const TaskConstMeta(debugName: "is_auto_indexing_enabled", argNames: []);
+
+  @override
+  Future<bool> crateApiIsCodeScanningEnabled() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 91,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIsCodeScanningEnabledConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsCodeScanningEnabledConstMeta =>
+      const TaskConstMeta(debugName: "is_code_scanning_enabled", argNames: []);
+
+  @override
+  Future<bool> crateApiIsCopyInPaused() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 92,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIsCopyInPausedConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsCopyInPausedConstMeta =>
+      const TaskConstMeta(debugName: "is_copy_in_paused", argNames: []);
+
+  @override
+  Future<bool> crateApiIsDryRunMode() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 93,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIsDryRunModeConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsDryRunModeConstMeta =>
+      const TaskConstMeta(debugName: "is_dry_run_mode", argNames: []);
+
+  @override
+  Future<bool> crateApiIsDuplicateDetectionEnabled() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 94,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIsDuplicateDetectionEnabledConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsDuplicateDetectionEnabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_duplicate_detection_enabled",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiIsEmailFile({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 95,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIsEmailFileConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsEmailFileConstMeta =>
+      const TaskConstMeta(debugName: "is_email_file", argNames: ["path"]);
+
+  @override
+  Future<bool> crateApiIsFileIndexed({required String filePath}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 96,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiIsFileIndexedConstMeta,
+        argValues: [filePath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsFileIndexedConstMeta =>
+      const TaskConstMeta(debugName: "is_file_indexed", argNames: ["filePath"]);
+
+  @override
+  Future<bool> crateApiIsFileLocked({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 97,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiIsFileLockedConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsFileLockedConstMeta =>
+      const TaskConstMeta(debugName: "is_file_locked", argNames: ["path"]);
+
+  @override
+  Future<bool> crateApiIsLlmReady() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 98,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIsLlmReadyConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsLlmReadyConstMeta =>
+      const TaskConstMeta(debugName: "is_llm_ready", argNames: []);
+
+  @override
+  Future<bool> crateApiIsOcrSupported({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 99,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIsOcrSupportedConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsOcrSupportedConstMeta =>
+      const TaskConstMeta(debugName: "is_ocr_supported", argNames: ["path"]);
+
+  @override
+  Future<bool> crateApiIsPreviewPregenerationEnabled() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 100,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIsPreviewPregenerationEnabledConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsPreviewPregenerationEnabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_preview_pregeneration_enabled",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiIsReadOnlyMode() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 101,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIsReadOnlyModeConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsReadOnlyModeConstMeta =>
+      const TaskConstMeta(debugName: "is_read_only_mode", argNames: []);
+
+  @override
+  Future<bool> crateApiIsSemanticModelReady() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 102,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIsSemanticModelReadyConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsSemanticModelReadyConstMeta =>
+      const TaskConstMeta(debugName: "is_semantic_model_ready", argNames: []);
+
+  @override
+  Future<bool> crateApiIsSharedFolderClaimsEnabled() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 103,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIsSharedFolderClaimsEnabledConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsSharedFolderClaimsEnabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_shared_folder_claims_enabled",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiIsTelemetryEnabled() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 104,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIsTelemetryEnabledConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsTelemetryEnabledConstMeta =>
+      const TaskConstMeta(debugName: "is_telemetry_enabled", argNames: []);
+
+  @override
+  Future<bool> crateApiIsThumbnailPregenerationEnabled() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 105,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiIsThumbnailPregenerationEnabledConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiIsThumbnailPregenerationEnabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "is_thumbnail_pregeneration_enabled",
+        argNames: [],
+      );
+
+  @override
+  Future<List<ApiArchivedFile>> crateApiListArchivedFiles({
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 106,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_api_archived_file,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiListArchivedFilesConstMeta,
+        argValues: [limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiListArchivedFilesConstMeta => const TaskConstMeta(
+    debugName: "list_archived_files",
+    argNames: ["limit"],
+  );
+
+  @override
+  Future<List<FileRule>> crateApiListFileRules() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 107,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_file_rule,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiListFileRulesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiListFileRulesConstMeta =>
+      const TaskConstMeta(debugName: "list_file_rules", argNames: []);
+
+  @override
+  Future<List<String>> crateApiListTags({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 108,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiListTagsConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiListTagsConstMeta =>
+      const TaskConstMeta(debugName: "list_tags", argNames: ["path"]);
+
+  @override
+  Future<List<WatcherInfo>> crateApiListWatchers() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 109,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_watcher_info,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiListWatchersConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiListWatchersConstMeta =>
+      const TaskConstMeta(debugName: "list_watchers", argNames: []);
+
+  @override
+  Future<int> crateApiMoveFile({required String src, required String dst}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(src, serializer);
+          sse_encode_String(dst, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 110,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_CastedPrimitive_u_64,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiMoveFileConstMeta,
+        argValues: [src, dst],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMoveFileConstMeta =>
+      const TaskConstMeta(debugName: "move_file", argNames: ["src", "dst"]);
+
+  @override
+  Future<OcrResult> crateApiOcrExtractText({
+    required String path,
+    required OcrOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_box_autoadd_ocr_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 111,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_ocr_result,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiOcrExtractTextConstMeta,
+        argValues: [path, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiOcrExtractTextConstMeta => const TaskConstMeta(
+    debugName: "ocr_extract_text",
+    argNames: ["path", "options"],
+  );
+
+  @override
+  Stream<AdoptionProgressEvent> crateApiOnAdoptionProgress() {
+    final sink = RustStreamSink<AdoptionProgressEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_adoption_progress_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 112,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiOnAdoptionProgressConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiOnAdoptionProgressConstMeta => const TaskConstMeta(
+    debugName: "on_adoption_progress",
+    argNames: ["sink"],
+  );
+
+  @override
+  Stream<DryRunActionEvent> crateApiOnDryRunAction() {
+    final sink = RustStreamSink<DryRunActionEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_dry_run_action_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 113,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiOnDryRunActionConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiOnDryRunActionConstMeta =>
+      const TaskConstMeta(debugName: "on_dry_run_action", argNames: ["sink"]);
+
+  @override
+  Stream<DuplicateDetected> crateApiOnDuplicateDetected() {
+    final sink = RustStreamSink<DuplicateDetected>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_duplicate_detected_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 114,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiOnDuplicateDetectedConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiOnDuplicateDetectedConstMeta =>
+      const TaskConstMeta(
+        debugName: "on_duplicate_detected",
+        argNames: ["sink"],
+      );
+
+  @override
+  Stream<FileAddedEvent> crateApiOnFileAdded() {
+    final sink = RustStreamSink<FileAddedEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_file_added_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 115,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiOnFileAddedConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiOnFileAddedConstMeta =>
+      const TaskConstMeta(debugName: "on_file_added", argNames: ["sink"]);
+
+  @override
+  Stream<AckableFileEvent> crateApiOnFileAddedAckable() {
+    final sink = RustStreamSink<AckableFileEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_ackable_file_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 116,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiOnFileAddedAckableConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiOnFileAddedAckableConstMeta => const TaskConstMeta(
+    debugName: "on_file_added_ackable",
+    argNames: ["sink"],
+  );
+
+  @override
+  Stream<FileBatchEvent> crateApiOnFileBatch() {
+    final sink = RustStreamSink<FileBatchEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_file_batch_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 117,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiOnFileBatchConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiOnFileBatchConstMeta =>
+      const TaskConstMeta(debugName: "on_file_batch", argNames: ["sink"]);
+
+  @override
+  Stream<FileChunkEvent> crateApiOnFileChunks() {
+    final sink = RustStreamSink<FileChunkEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_file_chunk_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 118,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiOnFileChunksConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiOnFileChunksConstMeta =>
+      const TaskConstMeta(debugName: "on_file_chunks", argNames: ["sink"]);
+
+  @override
+  Stream<FileEvent> crateApiOnFileEvent() {
+    final sink = RustStreamSink<FileEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_file_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 119,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiOnFileEventConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiOnFileEventConstMeta =>
+      const TaskConstMeta(debugName: "on_file_event", argNames: ["sink"]);
+
+  @override
+  Stream<FileHashEvent> crateApiOnFileHash() {
+    final sink = RustStreamSink<FileHashEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_file_hash_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 120,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiOnFileHashConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiOnFileHashConstMeta =>
+      const TaskConstMeta(debugName: "on_file_hash", argNames: ["sink"]);
+
+  @override
+  Stream<FileOpEvent> crateApiOnFileOpProgress() {
+    final sink = RustStreamSink<FileOpEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_file_op_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 121,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiOnFileOpProgressConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiOnFileOpProgressConstMeta =>
+      const TaskConstMeta(debugName: "on_file_op_progress", argNames: ["sink"]);
+
+  @override
+  Stream<FileRemovedEvent> crateApiOnFileRemoved() {
+    final sink = RustStreamSink<FileRemovedEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_file_removed_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 122,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiOnFileRemovedConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiOnFileRemovedConstMeta =>
+      const TaskConstMeta(debugName: "on_file_removed", argNames: ["sink"]);
+
+  @override
+  Stream<FileStatusChangedEvent> crateApiOnFileStatusChanged() {
+    final sink = RustStreamSink<FileStatusChangedEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_file_status_changed_event_Sse(
+              sink,
+              serializer,
+            );
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 123,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiOnFileStatusChangedConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiOnFileStatusChangedConstMeta =>
+      const TaskConstMeta(
+        debugName: "on_file_status_changed",
+        argNames: ["sink"],
+      );
+
+  @override
+  Stream<HeartbeatEvent> crateApiOnHeartbeat({int? intervalMs}) {
+    final sink = RustStreamSink<HeartbeatEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_heartbeat_event_Sse(sink, serializer);
+            sse_encode_opt_box_autoadd_u_32(intervalMs, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 124,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData:
+                sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+          ),
+          constMeta: kCrateApiOnHeartbeatConstMeta,
+          argValues: [sink, intervalMs],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiOnHeartbeatConstMeta => const TaskConstMeta(
+    debugName: "on_heartbeat",
+    argNames: ["sink", "intervalMs"],
+  );
+
+  @override
+  Stream<LogRecord> crateApiOnLog() {
+    final sink = RustStreamSink<LogRecord>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_log_record_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 125,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiOnLogConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiOnLogConstMeta =>
+      const TaskConstMeta(debugName: "on_log", argNames: ["sink"]);
+
+  @override
+  Stream<RuleAppliedEvent> crateApiOnRuleApplied() {
+    final sink = RustStreamSink<RuleAppliedEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_rule_applied_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 126,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiOnRuleAppliedConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiOnRuleAppliedConstMeta =>
+      const TaskConstMeta(debugName: "on_rule_applied", argNames: ["sink"]);
+
+  @override
+  Stream<ScreenshotAddedEvent> crateApiOnScreenshotAdded() {
+    final sink = RustStreamSink<ScreenshotAddedEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_screenshot_added_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 127,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiOnScreenshotAddedConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiOnScreenshotAddedConstMeta =>
+      const TaskConstMeta(debugName: "on_screenshot_added", argNames: ["sink"]);
+
+  @override
+  Stream<WatchStatusEvent> crateApiOnWatchStatus() {
+    final sink = RustStreamSink<WatchStatusEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_watch_status_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 128,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiOnWatchStatusConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiOnWatchStatusConstMeta =>
+      const TaskConstMeta(debugName: "on_watch_status", argNames: ["sink"]);
+
+  @override
+  Stream<WatcherStatusEvent> crateApiOnWatcherStatus({int? intervalMs}) {
+    final sink = RustStreamSink<WatcherStatusEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_watcher_status_event_Sse(sink, serializer);
+            sse_encode_opt_box_autoadd_u_32(intervalMs, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 129,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData:
+                sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+          ),
+          constMeta: kCrateApiOnWatcherStatusConstMeta,
+          argValues: [sink, intervalMs],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiOnWatcherStatusConstMeta => const TaskConstMeta(
+    debugName: "on_watcher_status",
+    argNames: ["sink", "intervalMs"],
+  );
+
+  @override
+  Future<List<String>> crateApiQueryByTag({required String tag}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(tag, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 130,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiQueryByTagConstMeta,
+        argValues: [tag],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiQueryByTagConstMeta =>
+      const TaskConstMeta(debugName: "query_by_tag", argNames: ["tag"]);
+
+  @override
+  Future<List<ApiJournalEntry>> crateApiQueryEvents({
+    required int sinceMs,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_CastedPrimitive_i_64(sinceMs, serializer);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 131,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_api_journal_entry,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiQueryEventsConstMeta,
+        argValues: [sinceMs, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiQueryEventsConstMeta => const TaskConstMeta(
+    debugName: "query_events",
+    argNames: ["sinceMs", "limit"],
+  );
+
+  @override
+  Future<RagQueryResult> crateApiRagQuery({
+    required String question,
+    required int topK,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(question, serializer);
+          sse_encode_u_32(topK, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 132,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_rag_query_result,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiRagQueryConstMeta,
+        argValues: [question, topK],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRagQueryConstMeta => const TaskConstMeta(
+    debugName: "rag_query",
+    argNames: ["question", "topK"],
+  );
+
+  @override
+  Future<PolicyStatus> crateApiReloadPolicy() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 133,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_policy_status,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiReloadPolicyConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReloadPolicyConstMeta =>
+      const TaskConstMeta(debugName: "reload_policy", argNames: []);
+
+  @override
+  Future<bool> crateApiRemoveFileRule({required String ruleId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(ruleId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 134,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiRemoveFileRuleConstMeta,
+        argValues: [ruleId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRemoveFileRuleConstMeta =>
+      const TaskConstMeta(debugName: "remove_file_rule", argNames: ["ruleId"]);
+
+  @override
+  Future<bool> crateApiRemoveFromIndex({required String filePath}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 135,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiRemoveFromIndexConstMeta,
+        argValues: [filePath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRemoveFromIndexConstMeta => const TaskConstMeta(
+    debugName: "remove_from_index",
+    argNames: ["filePath"],
+  );
+
+  @override
+  Future<bool> crateApiRemoveSetting({required String key}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 136,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiRemoveSettingConstMeta,
+        argValues: [key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRemoveSettingConstMeta =>
+      const TaskConstMeta(debugName: "remove_setting", argNames: ["key"]);
+
+  @override
+  Future<bool> crateApiRemoveTag({required String path, required String tag}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_String(tag, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 137,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiRemoveTagConstMeta,
+        argValues: [path, tag],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRemoveTagConstMeta =>
+      const TaskConstMeta(debugName: "remove_tag", argNames: ["path", "tag"]);
+
+  @override
+  Future<int> crateApiRenameFile({
+    required String path,
+    required String newName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_String(newName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 138,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_CastedPrimitive_u_64,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiRenameFileConstMeta,
+        argValues: [path, newName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRenameFileConstMeta => const TaskConstMeta(
+    debugName: "rename_file",
+    argNames: ["path", "newName"],
+  );
+
+  @override
+  Future<List<ImageBuffer>> crateApiRenderPdfPageBuffers({
+    required String path,
+    required Uint32List pages,
+    required int dpi,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_list_prim_u_32_strict(pages, serializer);
+          sse_encode_u_32(dpi, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 139,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiRenderPdfPageBuffersConstMeta,
+        argValues: [path, pages, dpi],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRenderPdfPageBuffersConstMeta =>
+      const TaskConstMeta(
+        debugName: "render_pdf_page_buffers",
+        argNames: ["path", "pages", "dpi"],
+      );
+
+  @override
+  Future<List<PdfPagePreview>> crateApiRenderPdfPages({
+    required String path,
+    required Uint32List pages,
+    required int dpi,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_list_prim_u_32_strict(pages, serializer);
+          sse_encode_u_32(dpi, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 140,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_pdf_page_preview,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiRenderPdfPagesConstMeta,
+        argValues: [path, pages, dpi],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRenderPdfPagesConstMeta => const TaskConstMeta(
+    debugName: "render_pdf_pages",
+    argNames: ["path", "pages", "dpi"],
+  );
+
+  @override
+  Future<FolderMarkerInfo> crateApiRepairFolderMarker({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 141,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_folder_marker_info,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiRepairFolderMarkerConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRepairFolderMarkerConstMeta => const TaskConstMeta(
+    debugName: "repair_folder_marker",
+    argNames: ["path"],
+  );
+
+  @override
+  Future<int> crateApiRescanNow({required String watcherId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(watcherId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 142,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiRescanNowConstMeta,
+        argValues: [watcherId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRescanNowConstMeta =>
+      const TaskConstMeta(debugName: "rescan_now", argNames: ["watcherId"]);
+
+  @override
+  Future<List<ApiDetectedCode>> crateApiScanImageCodes({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 143,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_api_detected_code,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiScanImageCodesConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiScanImageCodesConstMeta =>
+      const TaskConstMeta(debugName: "scan_image_codes", argNames: ["path"]);
+
+  @override
+  Future<List<SearchResultItem>> crateApiSearch({
+    required String query,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 144,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result_item,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSearchConstMeta,
+        argValues: [query, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSearchConstMeta =>
+      const TaskConstMeta(debugName: "search", argNames: ["query", "limit"]);
+
+  @override
+  Future<List<SearchResultItem>> crateApiSearchFiles({
+    required String query,
+    required int limit,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 145,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_result_item,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSearchFilesConstMeta,
+        argValues: [query, limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSearchFilesConstMeta => const TaskConstMeta(
+    debugName: "search_files",
+    argNames: ["query", "limit"],
+  );
+
+  @override
+  Future<List<ApiSimilarityResult>> crateApiSemanticSearch({
+    required String query,
+    required int topK,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          sse_encode_u_32(topK, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 146,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_api_similarity_result,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSemanticSearchConstMeta,
+        argValues: [query, topK],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSemanticSearchConstMeta => const TaskConstMeta(
+    debugName: "semantic_search",
+    argNames: ["query", "topK"],
+  );
+
+  @override
+  Future<void> crateApiSetAckMode({
+    required bool enabled,
+    int? redeliveryTimeoutMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          sse_encode_opt_CastedPrimitive_u_64(redeliveryTimeoutMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 147,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetAckModeConstMeta,
+        argValues: [enabled, redeliveryTimeoutMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetAckModeConstMeta => const TaskConstMeta(
+    debugName: "set_ack_mode",
+    argNames: ["enabled", "redeliveryTimeoutMs"],
+  );
+
+  @override
+  Future<void> crateApiSetArchiveOptions({ArchiveOptions? options}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_archive_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 148,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetArchiveOptionsConstMeta,
+        argValues: [options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetArchiveOptionsConstMeta => const TaskConstMeta(
+    debugName: "set_archive_options",
+    argNames: ["options"],
+  );
+
+  @override
+  Future<void> crateApiSetAutoIndexing({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 149,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetAutoIndexingConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetAutoIndexingConstMeta => const TaskConstMeta(
+    debugName: "set_auto_indexing",
+    argNames: ["enabled"],
+  );
+
+  @override
+  Future<void> crateApiSetChunkedTransfer({int? eventsPerChunk}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_u_32(eventsPerChunk, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 150,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetChunkedTransferConstMeta,
+        argValues: [eventsPerChunk],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetChunkedTransferConstMeta => const TaskConstMeta(
+    debugName: "set_chunked_transfer",
+    argNames: ["eventsPerChunk"],
+  );
+
+  @override
+  Future<void> crateApiSetCodeScanning({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 151,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetCodeScanningConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetCodeScanningConstMeta => const TaskConstMeta(
+    debugName: "set_code_scanning",
+    argNames: ["enabled"],
+  );
+
+  @override
+  Future<void> crateApiSetDryRunMode({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 152,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetDryRunModeConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetDryRunModeConstMeta =>
+      const TaskConstMeta(debugName: "set_dry_run_mode", argNames: ["enabled"]);
+
+  @override
+  Future<void> crateApiSetDuplicateDetection({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 153,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetDuplicateDetectionConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetDuplicateDetectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_duplicate_detection",
+        argNames: ["enabled"],
+      );
+
+  @override
+  Future<void> crateApiSetEventDebugEnrichment({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 154,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetEventDebugEnrichmentConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetEventDebugEnrichmentConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_event_debug_enrichment",
+        argNames: ["enabled"],
+      );
+
+  @override
+  Future<void> crateApiSetEventHashing({HashAlgorithm? algorithm}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_hash_algorithm(algorithm, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 155,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetEventHashingConstMeta,
+        argValues: [algorithm],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetEventHashingConstMeta => const TaskConstMeta(
+    debugName: "set_event_hashing",
+    argNames: ["algorithm"],
+  );
+
+  @override
+  Future<void> crateApiSetEventTimestampSource({
+    required EventTimestampSource source,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_event_timestamp_source(source, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 156,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetEventTimestampSourceConstMeta,
+        argValues: [source],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetEventTimestampSourceConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_event_timestamp_source",
+        argNames: ["source"],
+      );
+
+  @override
+  Future<void> crateApiSetFileRule({required FileRule rule}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_file_rule(rule, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 157,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetFileRuleConstMeta,
+        argValues: [rule],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetFileRuleConstMeta =>
+      const TaskConstMeta(debugName: "set_file_rule", argNames: ["rule"]);
+
+  @override
+  Future<void> crateApiSetFileStatus({
+    required String path,
+    required FileProcessingStatus status,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_file_processing_status(status, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 158,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetFileStatusConstMeta,
+        argValues: [path, status],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetFileStatusConstMeta => const TaskConstMeta(
+    debugName: "set_file_status",
+    argNames: ["path", "status"],
+  );
+
+  @override
+  Future<void> crateApiSetFileStatuses({
+    required List<FileStatusUpdate> updates,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_file_status_update(updates, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 159,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetFileStatusesConstMeta,
+        argValues: [updates],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetFileStatusesConstMeta => const TaskConstMeta(
+    debugName: "set_file_statuses",
+    argNames: ["updates"],
+  );
+
+  @override
+  Future<void> crateApiSetFolderQuota({
+    int? maxTotalBytes,
+    int? maxFileCount,
+    required bool cleanupOldest,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_CastedPrimitive_u_64(maxTotalBytes, serializer);
+          sse_encode_opt_CastedPrimitive_u_64(maxFileCount, serializer);
+          sse_encode_bool(cleanupOldest, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 160,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetFolderQuotaConstMeta,
+        argValues: [maxTotalBytes, maxFileCount, cleanupOldest],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetFolderQuotaConstMeta => const TaskConstMeta(
+    debugName: "set_folder_quota",
+    argNames: ["maxTotalBytes", "maxFileCount", "cleanupOldest"],
+  );
+
+  @override
+  Future<void> crateApiSetFseventsReplay({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 161,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetFseventsReplayConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetFseventsReplayConstMeta => const TaskConstMeta(
+    debugName: "set_fsevents_replay",
+    argNames: ["enabled"],
+  );
+
+  @override
+  Future<void> crateApiSetIgnoreFilesOlderThan({int? maxAgeMinutes}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_u_32(maxAgeMinutes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 162,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetIgnoreFilesOlderThanConstMeta,
+        argValues: [maxAgeMinutes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetIgnoreFilesOlderThanConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_ignore_files_older_than",
+        argNames: ["maxAgeMinutes"],
+      );
+
+  @override
+  Future<void> crateApiSetInternalFilePrefix({required String prefix}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(prefix, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 163,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetInternalFilePrefixConstMeta,
+        argValues: [prefix],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetInternalFilePrefixConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_internal_file_prefix",
+        argNames: ["prefix"],
+      );
+
+  @override
+  Future<void> crateApiSetLogFileOptions({required LogFileOptions options}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_log_file_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 164,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetLogFileOptionsConstMeta,
+        argValues: [options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetLogFileOptionsConstMeta => const TaskConstMeta(
+    debugName: "set_log_file_options",
+    argNames: ["options"],
+  );
+
+  @override
+  Future<void> crateApiSetLogLevel({required String level}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(level, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 165,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetLogLevelConstMeta,
+        argValues: [level],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetLogLevelConstMeta =>
+      const TaskConstMeta(debugName: "set_log_level", argNames: ["level"]);
+
+  @override
+  Future<void> crateApiSetLowDiskSpaceThreshold({
+    required int thresholdBytes,
+    int? checkIntervalMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_CastedPrimitive_u_64(thresholdBytes, serializer);
+          sse_encode_opt_box_autoadd_u_32(checkIntervalMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 166,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetLowDiskSpaceThresholdConstMeta,
+        argValues: [thresholdBytes, checkIntervalMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetLowDiskSpaceThresholdConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_low_disk_space_threshold",
+        argNames: ["thresholdBytes", "checkIntervalMs"],
+      );
+
+  @override
+  Future<void> crateApiSetOfflineChangeDetection({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 167,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetOfflineChangeDetectionConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetOfflineChangeDetectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_offline_change_detection",
+        argNames: ["enabled"],
+      );
+
+  @override
+  Future<void> crateApiSetPreviewPregeneration({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 168,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetPreviewPregenerationConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetPreviewPregenerationConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_preview_pregeneration",
+        argNames: ["enabled"],
+      );
+
+  @override
+  Future<void> crateApiSetReadOnlyMode({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 169,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetReadOnlyModeConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetReadOnlyModeConstMeta => const TaskConstMeta(
+    debugName: "set_read_only_mode",
+    argNames: ["enabled"],
+  );
+
+  @override
+  Future<void> crateApiSetSetting({
+    required String key,
+    required String value,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(key, serializer);
+          sse_encode_String(value, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 170,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetSettingConstMeta,
+        argValues: [key, value],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetSettingConstMeta =>
+      const TaskConstMeta(debugName: "set_setting", argNames: ["key", "value"]);
+
+  @override
+  Future<void> crateApiSetSettleQuietPeriod({int? quietPeriodMs}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_u_32(quietPeriodMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 171,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetSettleQuietPeriodConstMeta,
+        argValues: [quietPeriodMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetSettleQuietPeriodConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_settle_quiet_period",
+        argNames: ["quietPeriodMs"],
+      );
+
+  @override
+  Future<void> crateApiSetSharedFolderClaims({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 172,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetSharedFolderClaimsConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetSharedFolderClaimsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_shared_folder_claims",
+        argNames: ["enabled"],
+      );
+
+  @override
+  Future<void> crateApiSetSystemLogMirroring({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 173,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetSystemLogMirroringConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetSystemLogMirroringConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_system_log_mirroring",
+        argNames: ["enabled"],
+      );
+
+  @override
+  Future<void> crateApiSetTelemetryEnabled({
+    required bool enabled,
+    String? uploadUrl,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          sse_encode_opt_String(uploadUrl, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 174,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetTelemetryEnabledConstMeta,
+        argValues: [enabled, uploadUrl],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetTelemetryEnabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_telemetry_enabled",
+        argNames: ["enabled", "uploadUrl"],
+      );
+
+  @override
+  Future<void> crateApiSetThumbnailPregeneration({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 175,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetThumbnailPregenerationConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetThumbnailPregenerationConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_thumbnail_pregeneration",
+        argNames: ["enabled"],
+      );
+
+  @override
+  Future<void> crateApiSetWatchBackend({
+    required ApiWatchBackend backend,
+    int? pollIntervalMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_api_watch_backend(backend, serializer);
+          sse_encode_opt_box_autoadd_u_32(pollIntervalMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 176,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetWatchBackendConstMeta,
+        argValues: [backend, pollIntervalMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetWatchBackendConstMeta => const TaskConstMeta(
+    debugName: "set_watch_backend",
+    argNames: ["backend", "pollIntervalMs"],
+  );
+
+  @override
+  Future<void> crateApiSetWatchDirRecovery({
+    required WatchDirRecoveryMode mode,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_watch_dir_recovery_mode(mode, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 177,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetWatchDirRecoveryConstMeta,
+        argValues: [mode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetWatchDirRecoveryConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_watch_dir_recovery",
+        argNames: ["mode"],
+      );
+
+  @override
+  Future<void> crateApiSetWatchFilter({WatchFilter? filter}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_watch_filter(filter, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 178,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetWatchFilterConstMeta,
+        argValues: [filter],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetWatchFilterConstMeta =>
+      const TaskConstMeta(debugName: "set_watch_filter", argNames: ["filter"]);
+
+  @override
+  Future<void> crateApiSetWatchRecursive({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 179,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetWatchRecursiveConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetWatchRecursiveConstMeta => const TaskConstMeta(
+    debugName: "set_watch_recursive",
+    argNames: ["enabled"],
+  );
+
+  @override
+  Future<void> crateApiSetWatcherDegradation({
+    WatcherDegradationPolicy? policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_watcher_degradation_policy(
+            policy,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 180,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetWatcherDegradationConstMeta,
+        argValues: [policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetWatcherDegradationConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_watcher_degradation",
+        argNames: ["policy"],
+      );
+
+  @override
+  Future<String> crateApiSetWatcherLogFile({
+    required String watcherId,
+    required bool enabled,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(watcherId, serializer);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 181,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetWatcherLogFileConstMeta,
+        argValues: [watcherId, enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetWatcherLogFileConstMeta => const TaskConstMeta(
+    debugName: "set_watcher_log_file",
+    argNames: ["watcherId", "enabled"],
+  );
+
+  @override
+  Future<void> crateApiSetXattr({
+    required String path,
+    required String name,
+    required Uint8List value,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_String(name, serializer);
+          sse_encode_list_prim_u_8_strict(value, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 182,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSetXattrConstMeta,
+        argValues: [path, name, value],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSetXattrConstMeta => const TaskConstMeta(
+    debugName: "set_xattr",
+    argNames: ["path", "name", "value"],
+  );
+
+  @override
+  Future<void> crateApiShutdownAll({required int timeoutMs}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(timeoutMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 183,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiShutdownAllConstMeta,
+        argValues: [timeoutMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiShutdownAllConstMeta =>
+      const TaskConstMeta(debugName: "shutdown_all", argNames: ["timeoutMs"]);
+
+  @override
+  Future<void> crateApiShutdownCore() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 184,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiShutdownCoreConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiShutdownCoreConstMeta =>
+      const TaskConstMeta(debugName: "shutdown_core", argNames: []);
+
+  @override
+  Future<List<String>> crateApiSortFileNames({
+    required List<String> names,
+    String? locale,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(names, serializer);
+          sse_encode_opt_String(locale, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 185,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSortFileNamesConstMeta,
+        argValues: [names, locale],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSortFileNamesConstMeta => const TaskConstMeta(
+    debugName: "sort_file_names",
+    argNames: ["names", "locale"],
+  );
+
+  @override
+  Future<List<String>> crateApiStartConfiguredWatchers() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 186,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiStartConfiguredWatchersConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStartConfiguredWatchersConstMeta =>
+      const TaskConstMeta(debugName: "start_configured_watchers", argNames: []);
+
+  @override
+  Future<WatchStarted> crateApiStartWatching({
+    String? overridePath,
+    WatchTuning? tuning,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(overridePath, serializer);
+          sse_encode_opt_box_autoadd_watch_tuning(tuning, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 187,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_watch_started,
+          decodeErrorData: sse_decode_latera_api_error,
+        ),
+        constMeta: kCrateApiStartWatchingConstMeta,
+        argValues: [overridePath, tuning],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStartWatchingConstMeta => const TaskConstMeta(
+    debugName: "start_watching",
+    argNames: ["overridePath", "tuning"],
+  );
+
+  @override
+  Future<String> crateApiStartWatchingDownloads({String? moveTo}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(moveTo, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 188,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiStartWatchingDownloadsConstMeta,
+        argValues: [moveTo],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStartWatchingDownloadsConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_watching_downloads",
+        argNames: ["moveTo"],
+      );
+
+  @override
+  Future<String> crateApiStartWatchingScreenshots({String? moveTo}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(moveTo, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 189,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiStartWatchingScreenshotsConstMeta,
+        argValues: [moveTo],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStartWatchingScreenshotsConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_watching_screenshots",
+        argNames: ["moveTo"],
+      );
+
+  @override
+  Future<void> crateApiStopWatching({required String watcherId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(watcherId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 190,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_latera_api_error,
+        ),
+        constMeta: kCrateApiStopWatchingConstMeta,
+        argValues: [watcherId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStopWatchingConstMeta =>
+      const TaskConstMeta(debugName: "stop_watching", argNames: ["watcherId"]);
+
+  @override
+  Future<void> crateApiStoreChunksAndEmbeddings({
+    required String filePath,
+    required List<ApiTextChunk> chunks,
+    required List<ApiEmbeddingVector> embeddings,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          sse_encode_list_api_text_chunk(chunks, serializer);
+          sse_encode_list_api_embedding_vector(embeddings, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 191,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiStoreChunksAndEmbeddingsConstMeta,
+        argValues: [filePath, chunks, embeddings],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStoreChunksAndEmbeddingsConstMeta =>
+      const TaskConstMeta(
+        debugName: "store_chunks_and_embeddings",
+        argNames: ["filePath", "chunks", "embeddings"],
+      );
+
+  @override
+  Future<List<WatchLocationSuggestion>> crateApiSuggestWatchLocations() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 192,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_watch_location_suggestion,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiSuggestWatchLocationsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSuggestWatchLocationsConstMeta =>
+      const TaskConstMeta(debugName: "suggest_watch_locations", argNames: []);
+
+  @override
+  Future<TranscriptionResult> crateApiTranscribeAudio({
+    required String path,
+    required TranscriptionOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_box_autoadd_transcription_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 193,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_transcription_result,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTranscribeAudioConstMeta,
+        argValues: [path, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTranscribeAudioConstMeta => const TaskConstMeta(
+    debugName: "transcribe_audio",
+    argNames: ["path", "options"],
+  );
+
+  @override
+  Future<void> crateApiUnloadLlm() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 194,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiUnloadLlmConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUnloadLlmConstMeta =>
+      const TaskConstMeta(debugName: "unload_llm", argNames: []);
+
+  @override
+  Future<void> crateApiUnloadSemanticModel() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 195,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiUnloadSemanticModelConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUnloadSemanticModelConstMeta =>
+      const TaskConstMeta(debugName: "unload_semantic_model", argNames: []);
+
+  @override
+  Future<ApiConfig> crateApiUpdateConfig({required ApiConfigPatch patch}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_api_config_patch(patch, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 196,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_api_config,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiUpdateConfigConstMeta,
+        argValues: [patch],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUpdateConfigConstMeta =>
+      const TaskConstMeta(debugName: "update_config", argNames: ["patch"]);
+
+  @override
+  Future<void> crateApiUpdateTranscript({
+    required String filePath,
+    required String transcript,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filePath, serializer);
+          sse_encode_String(transcript, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 197,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiUpdateTranscriptConstMeta,
+        argValues: [filePath, transcript],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiUpdateTranscriptConstMeta => const TaskConstMeta(
+    debugName: "update_transcript",
+    argNames: ["filePath", "transcript"],
+  );
+
+  @override
+  Future<List<IntegrityIssue>> crateApiVerifyFolderIntegrity({
+    required bool repair,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(repair, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 198,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_integrity_issue,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiVerifyFolderIntegrityConstMeta,
+        argValues: [repair],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiVerifyFolderIntegrityConstMeta =>
+      const TaskConstMeta(
+        debugName: "verify_folder_integrity",
+        argNames: ["repair"],
+      );
+
+  @override
+  Future<bool> crateApiWaitUntilUnlocked({
+    required String path,
+    required int timeoutMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_u_32(timeoutMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 199,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError,
+        ),
+        constMeta: kCrateApiWaitUntilUnlockedConstMeta,
+        argValues: [path, timeoutMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWaitUntilUnlockedConstMeta => const TaskConstMeta(
+    debugName: "wait_until_unlocked",
+    argNames: ["path", "timeoutMs"],
+  );
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_CoreHandle => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_CoreHandle => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_ImageBuffer => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_ImageBuffer => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_LateraError => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_LateraError => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError;
+
+  @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  CoreHandle
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CoreHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ImageBuffer
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ImageBufferImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  LateraError
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LateraErrorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  CoreHandle
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CoreHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ImageBuffer
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ImageBufferImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  int dco_decode_CastedPrimitive_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError(
+      'Not implemented in this codec, please use the other one',
+    );
+  }
+
+  @protected
+  int dco_decode_CastedPrimitive_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError(
+      'Not implemented in this codec, please use the other one',
+    );
+  }
+
+  @protected
+  int dco_decode_CastedPrimitive_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError(
+      'Not implemented in this codec, please use the other one',
+    );
+  }
+
+  @protected
+  CoreHandle
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CoreHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  ImageBuffer
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ImageBufferImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  LateraError
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LateraErrorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RustStreamSink<AckableFileEvent> dco_decode_StreamSink_ackable_file_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<AdoptionProgressEvent>
+  dco_decode_StreamSink_adoption_progress_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<DryRunActionEvent>
+  dco_decode_StreamSink_dry_run_action_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<DuplicateDetected>
+  dco_decode_StreamSink_duplicate_detected_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<FileAddedEvent> dco_decode_StreamSink_file_added_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<FileBatchEvent> dco_decode_StreamSink_file_batch_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<FileChunkEvent> dco_decode_StreamSink_file_chunk_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<FileEvent> dco_decode_StreamSink_file_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<FileHashEvent> dco_decode_StreamSink_file_hash_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<FileOpEvent> dco_decode_StreamSink_file_op_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<FileRemovedEvent> dco_decode_StreamSink_file_removed_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<FileStatusChangedEvent>
+  dco_decode_StreamSink_file_status_changed_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<HeartbeatEvent> dco_decode_StreamSink_heartbeat_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<LogRecord> dco_decode_StreamSink_log_record_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<RuleAppliedEvent> dco_decode_StreamSink_rule_applied_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ScreenshotAddedEvent>
+  dco_decode_StreamSink_screenshot_added_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<WatchStatusEvent> dco_decode_StreamSink_watch_status_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<WatcherStatusEvent>
+  dco_decode_StreamSink_watcher_status_event_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AckableFileEvent dco_decode_ackable_file_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return AckableFileEvent(
+      sequence: dco_decode_CastedPrimitive_u_64(arr[0]),
+      fileName: dco_decode_String(arr[1]),
+      fullPath: dco_decode_String(arr[2]),
+      occurredAtMs: dco_decode_CastedPrimitive_i_64(arr[3]),
+      detectedAtMs: dco_decode_CastedPrimitive_i_64(arr[4]),
+      monotonicMs: dco_decode_CastedPrimitive_i_64(arr[5]),
+      createdAtMs: dco_decode_CastedPrimitive_i_64(arr[6]),
+      modifiedAtMs: dco_decode_CastedPrimitive_i_64(arr[7]),
+      sizeBytes: dco_decode_CastedPrimitive_u_64(arr[8]),
+      extension: dco_decode_opt_String(arr[9]),
+      mimeType: dco_decode_opt_String(arr[10]),
+      access: dco_decode_opt_box_autoadd_api_file_access_info(arr[11]),
+    );
+  }
+
+  @protected
+  ActivityBucket dco_decode_activity_bucket(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ActivityBucket(
+      startMs: dco_decode_CastedPrimitive_i_64(arr[0]),
+      endMs: dco_decode_CastedPrimitive_i_64(arr[1]),
+      eventCount: dco_decode_CastedPrimitive_u_64(arr[2]),
+      fileCount: dco_decode_CastedPrimitive_u_64(arr[3]),
+      bytes: dco_decode_CastedPrimitive_u_64(arr[4]),
+      topTypes: dco_decode_list_type_count(arr[5]),
+    );
+  }
+
+  @protected
+  AdoptionPhase dco_decode_adoption_phase(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AdoptionPhase.values[raw as int];
+  }
+
+  @protected
+  AdoptionProgressEvent dco_decode_adoption_progress_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return AdoptionProgressEvent(
+      adoptionId: dco_decode_CastedPrimitive_u_64(arr[0]),
+      path: dco_decode_String(arr[1]),
+      phase: dco_decode_adoption_phase(arr[2]),
+      filesDone: dco_decode_CastedPrimitive_u_64(arr[3]),
+      filesTotal: dco_decode_CastedPrimitive_u_64(arr[4]),
+      indexFailures: dco_decode_CastedPrimitive_u_64(arr[5]),
+      folderId: dco_decode_opt_String(arr[6]),
+      errorCode: dco_decode_opt_String(arr[7]),
+      errorMessage: dco_decode_opt_String(arr[8]),
+    );
+  }
+
+  @protected
+  ApiArchivedFile dco_decode_api_archived_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ApiArchivedFile(
+      originalPath: dco_decode_String(arr[0]),
+      archivedPath: dco_decode_String(arr[1]),
+      archivedAtMs: dco_decode_CastedPrimitive_i_64(arr[2]),
+      modifiedAtMs: dco_decode_CastedPrimitive_i_64(arr[3]),
+      sizeBytes: dco_decode_CastedPrimitive_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  ApiAuditAction dco_decode_api_audit_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ApiAuditAction.values[raw as int];
+  }
+
+  @protected
+  ApiAuditEntry dco_decode_api_audit_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ApiAuditEntry(
+      id: dco_decode_CastedPrimitive_i_64(arr[0]),
+      atMs: dco_decode_CastedPrimitive_i_64(arr[1]),
+      action: dco_decode_api_audit_action(arr[2]),
+      path: dco_decode_String(arr[3]),
+      target: dco_decode_opt_String(arr[4]),
+      ruleId: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  ApiClaimStatus dco_decode_api_claim_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ApiClaimStatus_Claimed();
+      case 1:
+        return ApiClaimStatus_ClaimedByOther(
+          owner: dco_decode_String(raw[1]),
+          claimedAtMs: dco_decode_CastedPrimitive_i_64(raw[2]),
+        );
+      case 2:
+        return ApiClaimStatus_Unavailable(reason: dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  ApiCodeFormat dco_decode_api_code_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ApiCodeFormat.values[raw as int];
+  }
+
+  @protected
+  ApiConfig dco_decode_api_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ApiConfig(
+      configPath: dco_decode_String(arr[0]),
+      dedupWindowMs: dco_decode_u_32(arr[1]),
+      rateLimitPerSecond: dco_decode_u_32(arr[2]),
+      watchPaths: dco_decode_list_String(arr[3]),
+      filter: dco_decode_watch_filter(arr[4]),
+      logLevel: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  ApiConfigPatch dco_decode_api_config_patch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ApiConfigPatch(
+      dedupWindowMs: dco_decode_opt_box_autoadd_u_32(arr[0]),
+      rateLimitPerSecond: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      watchPaths: dco_decode_opt_list_String(arr[2]),
+      filter: dco_decode_opt_box_autoadd_watch_filter(arr[3]),
+      logLevel: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  ApiDetectedCode dco_decode_api_detected_code(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ApiDetectedCode(
+      format: dco_decode_api_code_format(arr[0]),
+      text: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  ApiEmailAttachment dco_decode_api_email_attachment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ApiEmailAttachment(
+      fileName: dco_decode_String(arr[0]),
+      sizeBytes: dco_decode_CastedPrimitive_u_64(arr[1]),
+      mimeType: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  ApiEmailMetadata dco_decode_api_email_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ApiEmailMetadata(
+      subject: dco_decode_opt_String(arr[0]),
+      from: dco_decode_opt_String(arr[1]),
+      to: dco_decode_list_String(arr[2]),
+      cc: dco_decode_list_String(arr[3]),
+      dateMs: dco_decode_opt_CastedPrimitive_i_64(arr[4]),
+      messageId: dco_decode_opt_String(arr[5]),
+      attachments: dco_decode_list_api_email_attachment(arr[6]),
+    );
+  }
+
+  @protected
+  ApiEmbeddingVector dco_decode_api_embedding_vector(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ApiEmbeddingVector(
+      chunkIndex: dco_decode_u_32(arr[0]),
+      vector: dco_decode_list_prim_f_32_strict(arr[1]),
+    );
+  }
+
+  @protected
+  ApiFileAccessInfo dco_decode_api_file_access_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ApiFileAccessInfo(
+      ownerName: dco_decode_opt_String(arr[0]),
+      ownerId: dco_decode_opt_String(arr[1]),
+      readable: dco_decode_bool(arr[2]),
+      writable: dco_decode_bool(arr[3]),
+      executable: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  ApiJournalEntry dco_decode_api_journal_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return ApiJournalEntry(
+      id: dco_decode_CastedPrimitive_i_64(arr[0]),
+      watcherId: dco_decode_String(arr[1]),
+      kind: dco_decode_file_event_kind(arr[2]),
+      fullPath: dco_decode_String(arr[3]),
+      previousPath: dco_decode_opt_String(arr[4]),
+      occurredAtMs: dco_decode_CastedPrimitive_i_64(arr[5]),
+      detectedAtMs: dco_decode_CastedPrimitive_i_64(arr[6]),
+      sizeBytes: dco_decode_CastedPrimitive_u_64(arr[7]),
+      mimeType: dco_decode_opt_String(arr[8]),
+      selfGenerated: dco_decode_bool(arr[9]),
+    );
+  }
+
+  @protected
+  ApiLockedOption dco_decode_api_locked_option(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ApiLockedOption(
+      key: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  ApiRagSource dco_decode_api_rag_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ApiRagSource(
+      filePath: dco_decode_String(arr[0]),
+      chunkSnippet: dco_decode_String(arr[1]),
+      chunkOffset: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  ApiSimilarityResult dco_decode_api_similarity_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ApiSimilarityResult(
+      filePath: dco_decode_String(arr[0]),
+      fileName: dco_decode_String(arr[1]),
+      chunkSnippet: dco_decode_String(arr[2]),
+      chunkOffset: dco_decode_u_32(arr[3]),
+      score: dco_decode_f_64(arr[4]),
+    );
+  }
+
+  @protected
+  ApiTelemetryCounter dco_decode_api_telemetry_counter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ApiTelemetryCounter(
+      day: dco_decode_String(arr[0]),
+      kind: dco_decode_String(arr[1]),
+      name: dco_decode_String(arr[2]),
+      count: dco_decode_CastedPrimitive_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  ApiTextChunk dco_decode_api_text_chunk(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ApiTextChunk(
+      text: dco_decode_String(arr[0]),
+      chunkIndex: dco_decode_u_32(arr[1]),
+      chunkOffset: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  ApiThumbnail dco_decode_api_thumbnail(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ApiThumbnail(
+      cachePath: dco_decode_String(arr[0]),
+      mimeType: dco_decode_String(arr[1]),
+      width: dco_decode_u_32(arr[2]),
+      height: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  ApiWatchBackend dco_decode_api_watch_backend(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ApiWatchBackend.values[raw as int];
+  }
+
+  @protected
+  ApiWatchMode dco_decode_api_watch_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ApiWatchMode.values[raw as int];
+  }
+
+  @protected
+  ApiWatcherErrorCode dco_decode_api_watcher_error_code(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ApiWatcherErrorCode.values[raw as int];
+  }
+
+  @protected
+  ApiWatcherState dco_decode_api_watcher_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ApiWatcherState_Starting();
+      case 1:
+        return ApiWatcherState_Running();
+      case 2:
+        return ApiWatcherState_Degraded(reason: dco_decode_String(raw[1]));
+      case 3:
+        return ApiWatcherState_Stopped();
+      case 4:
+        return ApiWatcherState_Error(
+          code: dco_decode_api_watcher_error_code(raw[1]),
+          message: dco_decode_String(raw[2]),
+        );
+      case 5:
+        return ApiWatcherState_Restarting(
+          attempt: dco_decode_u_32(raw[1]),
+          retryInMs: dco_decode_CastedPrimitive_u_64(raw[2]),
+          reason: dco_decode_String(raw[3]),
+        );
+      case 6:
+        return ApiWatcherState_DirLost(path: dco_decode_String(raw[1]));
+      case 7:
+        return ApiWatcherState_Fallback(
+          mode: dco_decode_api_watch_mode(raw[1]),
+          reason: dco_decode_String(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  ApiXattr dco_decode_api_xattr(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ApiXattr(
+      name: dco_decode_String(arr[0]),
+      value: dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  ArchiveOptions dco_decode_archive_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ArchiveOptions(
+      olderThanDays: dco_decode_u_32(arr[0]),
+      archiveDir: dco_decode_opt_String(arr[1]),
+      checkIntervalMinutes: dco_decode_opt_box_autoadd_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  AuditLogFilter dco_decode_audit_log_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return AuditLogFilter(
+      action: dco_decode_opt_box_autoadd_api_audit_action(arr[0]),
+      path: dco_decode_opt_String(arr[1]),
+      ruleId: dco_decode_opt_String(arr[2]),
+      sinceMs: dco_decode_opt_CastedPrimitive_i_64(arr[3]),
+      untilMs: dco_decode_opt_CastedPrimitive_i_64(arr[4]),
+      limit: dco_decode_opt_box_autoadd_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  ApiArchivedFile dco_decode_box_autoadd_api_archived_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_api_archived_file(raw);
+  }
+
+  @protected
+  ApiAuditAction dco_decode_box_autoadd_api_audit_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_api_audit_action(raw);
+  }
+
+  @protected
+  ApiClaimStatus dco_decode_box_autoadd_api_claim_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_api_claim_status(raw);
+  }
+
+  @protected
+  ApiConfigPatch dco_decode_box_autoadd_api_config_patch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_api_config_patch(raw);
+  }
+
+  @protected
+  ApiFileAccessInfo dco_decode_box_autoadd_api_file_access_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_api_file_access_info(raw);
+  }
+
+  @protected
+  ArchiveOptions dco_decode_box_autoadd_archive_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_archive_options(raw);
+  }
+
+  @protected
+  AuditLogFilter dco_decode_box_autoadd_audit_log_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_audit_log_filter(raw);
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  CoreConfig dco_decode_box_autoadd_core_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_core_config(raw);
+  }
+
+  @protected
+  EventDebugInfo dco_decode_box_autoadd_event_debug_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_event_debug_info(raw);
+  }
+
+  @protected
+  ExtractionOptions dco_decode_box_autoadd_extraction_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_extraction_options(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FileProcessingStatus dco_decode_box_autoadd_file_processing_status(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_file_processing_status(raw);
+  }
+
+  @protected
+  FileRule dco_decode_box_autoadd_file_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_file_rule(raw);
+  }
+
+  @protected
+  HashAlgorithm dco_decode_box_autoadd_hash_algorithm(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_hash_algorithm(raw);
+  }
+
+  @protected
+  LogFileOptions dco_decode_box_autoadd_log_file_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_log_file_options(raw);
+  }
+
+  @protected
+  OcrOptions dco_decode_box_autoadd_ocr_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_ocr_options(raw);
+  }
+
+  @protected
+  OnboardingStep dco_decode_box_autoadd_onboarding_step(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_onboarding_step(raw);
+  }
+
+  @protected
+  TranscriptionOptions dco_decode_box_autoadd_transcription_options(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_transcription_options(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  WatchFilter dco_decode_box_autoadd_watch_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_watch_filter(raw);
+  }
+
+  @protected
+  WatchTuning dco_decode_box_autoadd_watch_tuning(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_watch_tuning(raw);
+  }
+
+  @protected
+  WatcherDegradationPolicy dco_decode_box_autoadd_watcher_degradation_policy(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_watcher_degradation_policy(raw);
+  }
+
+  @protected
+  CategoryStats dco_decode_category_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CategoryStats(
+      category: dco_decode_file_category(arr[0]),
+      fileCount: dco_decode_CastedPrimitive_u_64(arr[1]),
+      totalBytes: dco_decode_CastedPrimitive_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  ConfigImportResult dco_decode_config_import_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ConfigImportResult(
+      watcherIds: dco_decode_list_String(arr[0]),
+      skippedWatchers: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  ControlledFolderAccessInfo dco_decode_controlled_folder_access_info(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ControlledFolderAccessInfo(
+      status: dco_decode_controlled_folder_access_status(arr[0]),
+      appPath: dco_decode_String(arr[1]),
+      settingsUri: dco_decode_String(arr[2]),
+      steps: dco_decode_list_String(arr[3]),
+    );
+  }
+
+  @protected
+  ControlledFolderAccessStatus dco_decode_controlled_folder_access_status(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ControlledFolderAccessStatus.values[raw as int];
+  }
+
+  @protected
+  CoreCapabilities dco_decode_core_capabilities(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return CoreCapabilities(
+      coreVersion: dco_decode_String(arr[0]),
+      platform: dco_decode_String(arr[1]),
+      dataDir: dco_decode_String(arr[2]),
+      indexReady: dco_decode_bool(arr[3]),
+      ocrAvailable: dco_decode_bool(arr[4]),
+      hasAvx2: dco_decode_bool(arr[5]),
+      hasVulkan: dco_decode_bool(arr[6]),
+      totalRamMb: dco_decode_CastedPrimitive_u_64(arr[7]),
+    );
+  }
+
+  @protected
+  CoreConfig dco_decode_core_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CoreConfig(
+      dataDir: dco_decode_opt_String(arr[0]),
+      indexDbPath: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  DesktopAccessStatus dco_decode_desktop_access_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DesktopAccessStatus.values[raw as int];
+  }
+
+  @protected
+  DirSizeInfo dco_decode_dir_size_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DirSizeInfo(
+      totalBytes: dco_decode_CastedPrimitive_u_64(arr[0]),
+      fileCount: dco_decode_CastedPrimitive_u_64(arr[1]),
+      dirCount: dco_decode_CastedPrimitive_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  DryRunActionEvent dco_decode_dry_run_action_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DryRunActionEvent(
+      action: dco_decode_api_audit_action(arr[0]),
+      path: dco_decode_String(arr[1]),
+      target: dco_decode_opt_String(arr[2]),
+      ruleId: dco_decode_String(arr[3]),
+      plannedAtMs: dco_decode_CastedPrimitive_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  DuplicateDetected dco_decode_duplicate_detected(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DuplicateDetected(
+      watcherId: dco_decode_String(arr[0]),
+      newPath: dco_decode_String(arr[1]),
+      existingPath: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  DuplicateGroup dco_decode_duplicate_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return DuplicateGroup(
+      sizeBytes: dco_decode_CastedPrimitive_u_64(arr[0]),
+      hash: dco_decode_String(arr[1]),
+      paths: dco_decode_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  EventDebugInfo dco_decode_event_debug_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return EventDebugInfo(
+      sequence: dco_decode_CastedPrimitive_u_64(arr[0]),
+      notifyKinds: dco_decode_list_String(arr[1]),
+      decisions: dco_decode_list_String(arr[2]),
+      firstSeenMonotonicMs: dco_decode_CastedPrimitive_i_64(arr[3]),
+      metadataMs: dco_decode_CastedPrimitive_u_64(arr[4]),
+      heldMs: dco_decode_CastedPrimitive_u_64(arr[5]),
+      deliveredMonotonicMs: dco_decode_CastedPrimitive_i_64(arr[6]),
+    );
+  }
+
+  @protected
+  EventTimestampSource dco_decode_event_timestamp_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EventTimestampSource.values[raw as int];
+  }
+
+  @protected
+  ExtractionOptions dco_decode_extraction_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ExtractionOptions(
+      maxPagesPerPdf: dco_decode_u_32(arr[0]),
+      maxFileSizeMb: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  ExtractionResult dco_decode_extraction_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ExtractionResult(
+      text: dco_decode_String(arr[0]),
+      contentType: dco_decode_String(arr[1]),
+      pagesExtracted: dco_decode_u_32(arr[2]),
+      errorCode: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FileAddedEvent dco_decode_file_added_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11)
+      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return FileAddedEvent(
+      fileName: dco_decode_String(arr[0]),
+      fullPath: dco_decode_String(arr[1]),
+      occurredAtMs: dco_decode_CastedPrimitive_i_64(arr[2]),
+      detectedAtMs: dco_decode_CastedPrimitive_i_64(arr[3]),
+      monotonicMs: dco_decode_CastedPrimitive_i_64(arr[4]),
+      createdAtMs: dco_decode_CastedPrimitive_i_64(arr[5]),
+      modifiedAtMs: dco_decode_CastedPrimitive_i_64(arr[6]),
+      sizeBytes: dco_decode_CastedPrimitive_u_64(arr[7]),
+      extension: dco_decode_opt_String(arr[8]),
+      mimeType: dco_decode_opt_String(arr[9]),
+      access: dco_decode_opt_box_autoadd_api_file_access_info(arr[10]),
+    );
+  }
+
+  @protected
+  FileBatchEvent dco_decode_file_batch_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FileBatchEvent(
+      watcherId: dco_decode_String(arr[0]),
+      events: dco_decode_list_file_added_event(arr[1]),
+    );
+  }
+
+  @protected
+  FileCategory dco_decode_file_category(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FileCategory.values[raw as int];
+  }
+
+  @protected
+  FileChunkEvent dco_decode_file_chunk_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FileChunkEvent(
+      watcherId: dco_decode_String(arr[0]),
+      count: dco_decode_u_32(arr[1]),
+      payload: dco_decode_list_prim_u_8_strict(arr[2]),
+    );
+  }
+
+  @protected
+  FileEvent dco_decode_file_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 19)
+      throw Exception('unexpected arr length: expect 19 but see ${arr.length}');
+    return FileEvent(
+      sequence: dco_decode_CastedPrimitive_u_64(arr[0]),
+      watcherId: dco_decode_String(arr[1]),
+      kind: dco_decode_file_event_kind(arr[2]),
+      fileName: dco_decode_String(arr[3]),
+      fullPath: dco_decode_String(arr[4]),
+      previousPath: dco_decode_opt_String(arr[5]),
+      occurredAtMs: dco_decode_CastedPrimitive_i_64(arr[6]),
+      detectedAtMs: dco_decode_CastedPrimitive_i_64(arr[7]),
+      monotonicMs: dco_decode_CastedPrimitive_i_64(arr[8]),
+      createdAtMs: dco_decode_CastedPrimitive_i_64(arr[9]),
+      modifiedAtMs: dco_decode_CastedPrimitive_i_64(arr[10]),
+      sizeBytes: dco_decode_CastedPrimitive_u_64(arr[11]),
+      extension: dco_decode_opt_String(arr[12]),
+      mimeType: dco_decode_opt_String(arr[13]),
+      access: dco_decode_opt_box_autoadd_api_file_access_info(arr[14]),
+      selfGenerated: dco_decode_bool(arr[15]),
+      reconciled: dco_decode_bool(arr[16]),
+      claim: dco_decode_opt_box_autoadd_api_claim_status(arr[17]),
+      codes: dco_decode_list_api_detected_code(arr[18]),
+    );
+  }
+
+  @protected
+  FileEventKind dco_decode_file_event_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FileEventKind.values[raw as int];
+  }
+
+  @protected
+  FileHashEvent dco_decode_file_hash_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return FileHashEvent(
+      watcherId: dco_decode_String(arr[0]),
+      fullPath: dco_decode_String(arr[1]),
+      algorithm: dco_decode_hash_algorithm(arr[2]),
+      hash: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  FileOpEvent dco_decode_file_op_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return FileOpEvent(
+      operationId: dco_decode_CastedPrimitive_u_64(arr[0]),
+      kind: dco_decode_file_op_kind(arr[1]),
+      src: dco_decode_String(arr[2]),
+      dst: dco_decode_String(arr[3]),
+      status: dco_decode_file_op_status(arr[4]),
+      bytesDone: dco_decode_CastedPrimitive_u_64(arr[5]),
+      bytesTotal: dco_decode_CastedPrimitive_u_64(arr[6]),
+      errorCode: dco_decode_opt_String(arr[7]),
+      errorMessage: dco_decode_opt_String(arr[8]),
+    );
+  }
+
+  @protected
+  FileOpKind dco_decode_file_op_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FileOpKind.values[raw as int];
+  }
+
+  @protected
+  FileOpStatus dco_decode_file_op_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FileOpStatus.values[raw as int];
+  }
+
+  @protected
+  FileProcessingStatus dco_decode_file_processing_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FileProcessingStatus.values[raw as int];
+  }
+
+  @protected
+  FileRemovedEvent dco_decode_file_removed_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FileRemovedEvent(
+      fileName: dco_decode_String(arr[0]),
+      fullPath: dco_decode_String(arr[1]),
+      occurredAtMs: dco_decode_CastedPrimitive_i_64(arr[2]),
+    );
+  }
+
+  @protected
+  FileRule dco_decode_file_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return FileRule(
+      id: dco_decode_String(arr[0]),
+      nameGlobs: dco_decode_list_String(arr[1]),
+      mimeTypes: dco_decode_list_String(arr[2]),
+      minSizeBytes: dco_decode_opt_CastedPrimitive_u_64(arr[3]),
+      maxSizeBytes: dco_decode_opt_CastedPrimitive_u_64(arr[4]),
+      action: dco_decode_file_rule_action(arr[5]),
+    );
+  }
+
+  @protected
+  FileRuleAction dco_decode_file_rule_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return FileRuleAction_MoveToSubfolder(
+          subfolder: dco_decode_String(raw[1]),
+        );
+      case 1:
+        return FileRuleAction_Rename(template: dco_decode_String(raw[1]));
+      case 2:
+        return FileRuleAction_Tag(tag: dco_decode_String(raw[1]));
+      case 3:
+        return FileRuleAction_Ignore();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  FileStatusChangedEvent dco_decode_file_status_changed_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return FileStatusChangedEvent(
+      fullPath: dco_decode_String(arr[0]),
+      status: dco_decode_opt_box_autoadd_file_processing_status(arr[1]),
+      updatedAtMs: dco_decode_CastedPrimitive_i_64(arr[2]),
+    );
+  }
+
+  @protected
+  FileStatusUpdate dco_decode_file_status_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return FileStatusUpdate(
+      fullPath: dco_decode_String(arr[0]),
+      status: dco_decode_file_processing_status(arr[1]),
+    );
+  }
+
+  @protected
+  FolderMarkerInfo dco_decode_folder_marker_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return FolderMarkerInfo(
+      status: dco_decode_folder_marker_status(arr[0]),
+      folderId: dco_decode_opt_String(arr[1]),
+      createdAtMs: dco_decode_opt_CastedPrimitive_i_64(arr[2]),
+      settingsHash: dco_decode_opt_String(arr[3]),
+      settingsMatch: dco_decode_bool(arr[4]),
+      error: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  FolderMarkerStatus dco_decode_folder_marker_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FolderMarkerStatus.values[raw as int];
+  }
+
+  @protected
+  HashAlgorithm dco_decode_hash_algorithm(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return HashAlgorithm.values[raw as int];
+  }
+
+  @protected
+  HeartbeatEvent dco_decode_heartbeat_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return HeartbeatEvent(
+      sequence: dco_decode_CastedPrimitive_u_64(arr[0]),
+      emittedAtMs: dco_decode_CastedPrimitive_i_64(arr[1]),
+      uptimeMs: dco_decode_CastedPrimitive_u_64(arr[2]),
+      watcherRunning: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  IntegrityIssue dco_decode_integrity_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return IntegrityIssue(
+      watcherId: dco_decode_String(arr[0]),
+      kind: dco_decode_integrity_issue_kind(arr[1]),
+      fullPath: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  IntegrityIssueKind dco_decode_integrity_issue_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return IntegrityIssueKind.values[raw as int];
+  }
+
+  @protected
+  LateraApiError dco_decode_latera_api_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LateraApiError(
+      code: dco_decode_String(arr[0]),
+      message: dco_decode_String(arr[1]),
+      recoverable: dco_decode_bool(arr[2]),
+      context: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  List<ImageBuffer>
+  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer)
+        .toList();
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<ActivityBucket> dco_decode_list_activity_bucket(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_activity_bucket).toList();
+  }
+
+  @protected
+  List<ApiArchivedFile> dco_decode_list_api_archived_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_api_archived_file).toList();
+  }
+
+  @protected
+  List<ApiAuditEntry> dco_decode_list_api_audit_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_api_audit_entry).toList();
+  }
+
+  @protected
+  List<ApiDetectedCode> dco_decode_list_api_detected_code(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_api_detected_code).toList();
+  }
+
+  @protected
+  List<ApiEmailAttachment> dco_decode_list_api_email_attachment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_api_email_attachment).toList();
+  }
+
+  @protected
+  List<ApiEmbeddingVector> dco_decode_list_api_embedding_vector(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_api_embedding_vector).toList();
+  }
+
+  @protected
+  List<ApiJournalEntry> dco_decode_list_api_journal_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_api_journal_entry).toList();
+  }
+
+  @protected
+  List<ApiLockedOption> dco_decode_list_api_locked_option(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_api_locked_option).toList();
+  }
+
+  @protected
+  List<ApiRagSource> dco_decode_list_api_rag_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_api_rag_source).toList();
+  }
+
+  @protected
+  List<ApiSimilarityResult> dco_decode_list_api_similarity_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_api_similarity_result)
+        .toList();
+  }
+
+  @protected
+  List<ApiTelemetryCounter> dco_decode_list_api_telemetry_counter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_api_telemetry_counter)
+        .toList();
+  }
+
+  @protected
+  List<ApiTextChunk> dco_decode_list_api_text_chunk(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_api_text_chunk).toList();
+  }
+
+  @protected
+  List<ApiXattr> dco_decode_list_api_xattr(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_api_xattr).toList();
+  }
+
+  @protected
+  List<CategoryStats> dco_decode_list_category_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_category_stats).toList();
+  }
+
+  @protected
+  List<DuplicateGroup> dco_decode_list_duplicate_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_duplicate_group).toList();
+  }
+
+  @protected
+  List<FileAddedEvent> dco_decode_list_file_added_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_file_added_event).toList();
+  }
+
+  @protected
+  List<FileRule> dco_decode_list_file_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_file_rule).toList();
+  }
+
+  @protected
+  List<FileStatusUpdate> dco_decode_list_file_status_update(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_file_status_update).toList();
+  }
+
+  @protected
+  List<IntegrityIssue> dco_decode_list_integrity_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_integrity_issue).toList();
+  }
+
+  @protected
+  List<OnboardingStep> dco_decode_list_onboarding_step(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_onboarding_step).toList();
+  }
+
+  @protected
+  List<PdfPagePreview> dco_decode_list_pdf_page_preview(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_pdf_page_preview).toList();
+  }
+
+  @protected
+  Float32List dco_decode_list_prim_f_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Float32List;
+  }
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint32List;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<ReportFile> dco_decode_list_report_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_report_file).toList();
+  }
+
+  @protected
+  List<SearchResultItem> dco_decode_list_search_result_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_search_result_item).toList();
+  }
+
+  @protected
+  List<TypeCount> dco_decode_list_type_count(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_type_count).toList();
+  }
+
+  @protected
+  List<WatchLocationSuggestion> dco_decode_list_watch_location_suggestion(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_watch_location_suggestion)
+        .toList();
+  }
+
+  @protected
+  List<WatchTreeStat> dco_decode_list_watch_tree_stat(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_watch_tree_stat).toList();
+  }
+
+  @protected
+  List<WatcherInfo> dco_decode_list_watcher_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_watcher_info).toList();
+  }
+
+  @protected
+  LogFileOptions dco_decode_log_file_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LogFileOptions(
+      maxFileBytes: dco_decode_CastedPrimitive_u_64(arr[0]),
+      maxRotatedFiles: dco_decode_u_32(arr[1]),
+      maxTotalBytes: dco_decode_CastedPrimitive_u_64(arr[2]),
+      compress: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  LogRecord dco_decode_log_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return LogRecord(
+      level: dco_decode_String(arr[0]),
+      target: dco_decode_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      timestamp: dco_decode_String(arr[3]),
+      correlationId: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  OcrOptions dco_decode_ocr_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return OcrOptions(
+      maxPagesPerPdf: dco_decode_u_32(arr[0]),
+      maxFileSizeMb: dco_decode_u_32(arr[1]),
+      language: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  OcrResult dco_decode_ocr_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return OcrResult(
+      text: dco_decode_String(arr[0]),
+      contentType: dco_decode_String(arr[1]),
+      pagesProcessed: dco_decode_u_32(arr[2]),
+      confidence: dco_decode_opt_box_autoadd_f_64(arr[3]),
+      errorCode: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  OnboardingState dco_decode_onboarding_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return OnboardingState(
+      currentStep: dco_decode_opt_box_autoadd_onboarding_step(arr[0]),
+      completedSteps: dco_decode_list_onboarding_step(arr[1]),
+      folderPath: dco_decode_String(arr[2]),
+      folderExists: dco_decode_bool(arr[3]),
+      accessGranted: dco_decode_bool(arr[4]),
+      firstEventAtMs: dco_decode_opt_CastedPrimitive_i_64(arr[5]),
+    );
+  }
+
+  @protected
+  OnboardingStep dco_decode_onboarding_step(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return OnboardingStep.values[raw as int];
+  }
+
+  @protected
+  int? dco_decode_opt_CastedPrimitive_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_CastedPrimitive_i_64(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_CastedPrimitive_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_CastedPrimitive_u_64(raw);
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  ApiArchivedFile? dco_decode_opt_box_autoadd_api_archived_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_api_archived_file(raw);
+  }
+
+  @protected
+  ApiAuditAction? dco_decode_opt_box_autoadd_api_audit_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_api_audit_action(raw);
+  }
+
+  @protected
+  ApiClaimStatus? dco_decode_opt_box_autoadd_api_claim_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_api_claim_status(raw);
+  }
+
+  @protected
+  ApiFileAccessInfo? dco_decode_opt_box_autoadd_api_file_access_info(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_api_file_access_info(raw);
+  }
+
+  @protected
+  ArchiveOptions? dco_decode_opt_box_autoadd_archive_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_archive_options(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  EventDebugInfo? dco_decode_opt_box_autoadd_event_debug_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_event_debug_info(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  FileProcessingStatus? dco_decode_opt_box_autoadd_file_processing_status(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_file_processing_status(raw);
+  }
+
+  @protected
+  HashAlgorithm? dco_decode_opt_box_autoadd_hash_algorithm(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_hash_algorithm(raw);
+  }
+
+  @protected
+  OnboardingStep? dco_decode_opt_box_autoadd_onboarding_step(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_onboarding_step(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  WatchFilter? dco_decode_opt_box_autoadd_watch_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_watch_filter(raw);
+  }
+
+  @protected
+  WatchTuning? dco_decode_opt_box_autoadd_watch_tuning(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_watch_tuning(raw);
+  }
+
+  @protected
+  WatcherDegradationPolicy?
+  dco_decode_opt_box_autoadd_watcher_degradation_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_watcher_degradation_policy(raw);
+  }
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_String(raw);
+  }
+
+  @protected
+  PdfPagePreview dco_decode_pdf_page_preview(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PdfPagePreview(
+      page: dco_decode_u_32(arr[0]),
+      width: dco_decode_u_32(arr[1]),
+      height: dco_decode_u_32(arr[2]),
+      png: dco_decode_list_prim_u_8_strict(arr[3]),
+    );
+  }
+
+  @protected
+  PolicyStatus dco_decode_policy_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PolicyStatus(
+      policyPath: dco_decode_opt_String(arr[0]),
+      lockedOptions: dco_decode_list_api_locked_option(arr[1]),
+    );
+  }
+
+  @protected
+  RagQueryResult dco_decode_rag_query_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RagQueryResult(
+      answer: dco_decode_String(arr[0]),
+      errorCode: dco_decode_opt_String(arr[1]),
+      sources: dco_decode_list_api_rag_source(arr[2]),
+    );
+  }
+
+  @protected
+  ReportFile dco_decode_report_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ReportFile(
+      fullPath: dco_decode_String(arr[0]),
+      sizeBytes: dco_decode_CastedPrimitive_u_64(arr[1]),
+      modifiedAtMs: dco_decode_opt_CastedPrimitive_i_64(arr[2]),
+      category: dco_decode_file_category(arr[3]),
+    );
+  }
+
+  @protected
+  RuleAppliedEvent dco_decode_rule_applied_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return RuleAppliedEvent(
+      watcherId: dco_decode_String(arr[0]),
+      ruleId: dco_decode_String(arr[1]),
+      action: dco_decode_file_rule_action(arr[2]),
+      path: dco_decode_String(arr[3]),
+      newPath: dco_decode_opt_String(arr[4]),
+      appliedAtMs: dco_decode_CastedPrimitive_i_64(arr[5]),
+    );
+  }
+
+  @protected
+  ScreenshotAddedEvent dco_decode_screenshot_added_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ScreenshotAddedEvent(
+      watcherId: dco_decode_String(arr[0]),
+      fileName: dco_decode_String(arr[1]),
+      fullPath: dco_decode_String(arr[2]),
+      occurredAtMs: dco_decode_CastedPrimitive_i_64(arr[3]),
+      sizeBytes: dco_decode_CastedPrimitive_u_64(arr[4]),
+      mimeType: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  SearchResultItem dco_decode_search_result_item(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return SearchResultItem(
+      filePath: dco_decode_String(arr[0]),
+      fileName: dco_decode_String(arr[1]),
+      description: dco_decode_String(arr[2]),
+      snippet: dco_decode_String(arr[3]),
+      rank: dco_decode_f_64(arr[4]),
+    );
+  }
+
+  @protected
+  TelemetryPreview dco_decode_telemetry_preview(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TelemetryPreview(
+      enabled: dco_decode_bool(arr[0]),
+      counters: dco_decode_list_api_telemetry_counter(arr[1]),
+      payloadJson: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  TimelineGranularity dco_decode_timeline_granularity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TimelineGranularity.values[raw as int];
+  }
+
+  @protected
+  TranscriptionOptions dco_decode_transcription_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TranscriptionOptions(
+      maxMediaMinutes: dco_decode_u_32(arr[0]),
+      maxFileSizeMb: dco_decode_u_32(arr[1]),
+      language: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  TranscriptionResult dco_decode_transcription_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TranscriptionResult(
+      text: dco_decode_String(arr[0]),
+      contentType: dco_decode_String(arr[1]),
+      durationSeconds: dco_decode_u_32(arr[2]),
+      errorCode: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  TypeCount dco_decode_type_count(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return TypeCount(
+      mimeType: dco_decode_String(arr[0]),
+      count: dco_decode_CastedPrimitive_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  VolumeInfo dco_decode_volume_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return VolumeInfo(
+      kind: dco_decode_volume_kind(arr[0]),
+      mountPoint: dco_decode_opt_String(arr[1]),
+      fileSystem: dco_decode_String(arr[2]),
+      isSsd: dco_decode_opt_box_autoadd_bool(arr[3]),
+      cloudProvider: dco_decode_opt_String(arr[4]),
+      pollIntervalMs: dco_decode_CastedPrimitive_u_64(arr[5]),
+      settleQuietPeriodMs: dco_decode_opt_CastedPrimitive_u_64(arr[6]),
+      hashingEnabled: dco_decode_bool(arr[7]),
+    );
+  }
+
+  @protected
+  VolumeKind dco_decode_volume_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return VolumeKind.values[raw as int];
+  }
+
+  @protected
+  WatchDirRecoveryMode dco_decode_watch_dir_recovery_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WatchDirRecoveryMode.values[raw as int];
+  }
+
+  @protected
+  WatchFilter dco_decode_watch_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return WatchFilter(
+      includeGlobs: dco_decode_list_String(arr[0]),
+      excludeGlobs: dco_decode_list_String(arr[1]),
+      extensions: dco_decode_list_String(arr[2]),
+      includeHidden: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  WatchLocationKind dco_decode_watch_location_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WatchLocationKind.values[raw as int];
+  }
+
+  @protected
+  WatchLocationSuggestion dco_decode_watch_location_suggestion(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return WatchLocationSuggestion(
+      kind: dco_decode_watch_location_kind(arr[0]),
+      path: dco_decode_String(arr[1]),
+      exists: dco_decode_bool(arr[2]),
+      writable: dco_decode_bool(arr[3]),
+      availableBytes: dco_decode_CastedPrimitive_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  WatchStarted dco_decode_watch_started(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WatchStarted(
+      watcherId: dco_decode_String(arr[0]),
+      backend: dco_decode_api_watch_mode(arr[1]),
+    );
+  }
+
+  @protected
+  WatchStatusEvent dco_decode_watch_status_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return WatchStatusEvent_LowDiskSpace(
+          availableBytes: dco_decode_CastedPrimitive_u_64(raw[1]),
+          thresholdBytes: dco_decode_CastedPrimitive_u_64(raw[2]),
+        );
+      case 1:
+        return WatchStatusEvent_DiskSpaceRecovered(
+          availableBytes: dco_decode_CastedPrimitive_u_64(raw[1]),
+        );
+      case 2:
+        return WatchStatusEvent_QuotaExceeded(
+          watcherId: dco_decode_String(raw[1]),
+          totalBytes: dco_decode_CastedPrimitive_u_64(raw[2]),
+          fileCount: dco_decode_CastedPrimitive_u_64(raw[3]),
+          maxTotalBytes: dco_decode_CastedPrimitive_u_64(raw[4]),
+          maxFileCount: dco_decode_CastedPrimitive_u_64(raw[5]),
+          removedFiles: dco_decode_CastedPrimitive_u_64(raw[6]),
+          freedBytes: dco_decode_CastedPrimitive_u_64(raw[7]),
+        );
+      case 3:
+        return WatchStatusEvent_NameConflict(
+          fileName: dco_decode_String(raw[1]),
+          newPath: dco_decode_String(raw[2]),
+          newFingerprint: dco_decode_String(raw[3]),
+          existingPath: dco_decode_String(raw[4]),
+          existingFingerprint: dco_decode_String(raw[5]),
+        );
+      case 4:
+        return WatchStatusEvent_SettingsRecovered(
+          backupIndex: dco_decode_u_32(raw[1]),
+          corruptPath: dco_decode_String(raw[2]),
+        );
+      case 5:
+        return WatchStatusEvent_WatchDirLost(
+          watcherId: dco_decode_String(raw[1]),
+          path: dco_decode_String(raw[2]),
+        );
+      case 6:
+        return WatchStatusEvent_WatchDirRestored(
+          watcherId: dco_decode_String(raw[1]),
+          path: dco_decode_String(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  WatchTreeStat dco_decode_watch_tree_stat(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WatchTreeStat(
+      relativePath: dco_decode_String(arr[0]),
+      eventCount: dco_decode_CastedPrimitive_u_64(arr[1]),
+      lastActivityMs: dco_decode_CastedPrimitive_i_64(arr[2]),
+    );
+  }
+
+  @protected
+  WatchTuning dco_decode_watch_tuning(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WatchTuning(
+      dedupWindowMs: dco_decode_opt_box_autoadd_u_32(arr[0]),
+      maxEventsPerSecond: dco_decode_opt_box_autoadd_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  WatcherDegradationPolicy dco_decode_watcher_degradation_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WatcherDegradationPolicy(
+      nativeFailures: dco_decode_u_32(arr[0]),
+      pollingFailures: dco_decode_u_32(arr[1]),
+      pollIntervalMs: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  WatcherInfo dco_decode_watcher_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return WatcherInfo(
+      watcherId: dco_decode_String(arr[0]),
+      watchDir: dco_decode_String(arr[1]),
+      recursive: dco_decode_bool(arr[2]),
+      startedAtMs: dco_decode_CastedPrimitive_i_64(arr[3]),
+    );
+  }
+
+  @protected
+  WatcherMetrics dco_decode_watcher_metrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return WatcherMetrics(
+      eventsReceived: dco_decode_CastedPrimitive_u_64(arr[0]),
+      eventsEmitted: dco_decode_CastedPrimitive_u_64(arr[1]),
+      eventsDeduped: dco_decode_CastedPrimitive_u_64(arr[2]),
+      eventsDropped: dco_decode_CastedPrimitive_u_64(arr[3]),
+      queueDepth: dco_decode_CastedPrimitive_u_64(arr[4]),
+      queueCapacity: dco_decode_CastedPrimitive_u_64(arr[5]),
+    );
+  }
+
+  @protected
+  WatcherStatusEvent dco_decode_watcher_status_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return WatcherStatusEvent(
+      watcherId: dco_decode_String(arr[0]),
+      state: dco_decode_api_watcher_state(arr[1]),
+      heartbeat: dco_decode_bool(arr[2]),
+      emittedAtMs: dco_decode_CastedPrimitive_i_64(arr[3]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  CoreHandle
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return CoreHandleImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  ImageBuffer
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ImageBufferImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  LateraError
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return LateraErrorImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  CoreHandle
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return CoreHandleImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  ImageBuffer
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ImageBufferImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  int sse_decode_CastedPrimitive_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_64(deserializer);
+    return inner.toInt();
+  }
+
+  @protected
+  int sse_decode_CastedPrimitive_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_u_64(deserializer);
+    return inner.toInt();
+  }
+
+  @protected
+  int sse_decode_CastedPrimitive_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_usize(deserializer);
+    return inner.toInt();
+  }
+
+  @protected
+  CoreHandle
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return CoreHandleImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  ImageBuffer
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return ImageBufferImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  LateraError
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return LateraErrorImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RustStreamSink<AckableFileEvent> sse_decode_StreamSink_ackable_file_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<AdoptionProgressEvent>
+  sse_decode_StreamSink_adoption_progress_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<DryRunActionEvent>
+  sse_decode_StreamSink_dry_run_action_event_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<DuplicateDetected>
+  sse_decode_StreamSink_duplicate_detected_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<FileAddedEvent> sse_decode_StreamSink_file_added_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<FileBatchEvent> sse_decode_StreamSink_file_batch_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<FileChunkEvent> sse_decode_StreamSink_file_chunk_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<FileEvent> sse_decode_StreamSink_file_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<FileHashEvent> sse_decode_StreamSink_file_hash_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<FileOpEvent> sse_decode_StreamSink_file_op_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<FileRemovedEvent> sse_decode_StreamSink_file_removed_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<FileStatusChangedEvent>
+  sse_decode_StreamSink_file_status_changed_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<HeartbeatEvent> sse_decode_StreamSink_heartbeat_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<LogRecord> sse_decode_StreamSink_log_record_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<RuleAppliedEvent> sse_decode_StreamSink_rule_applied_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ScreenshotAddedEvent>
+  sse_decode_StreamSink_screenshot_added_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<WatchStatusEvent> sse_decode_StreamSink_watch_status_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<WatcherStatusEvent>
+  sse_decode_StreamSink_watcher_status_event_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AckableFileEvent sse_decode_ackable_file_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sequence = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_fileName = sse_decode_String(deserializer);
+    var var_fullPath = sse_decode_String(deserializer);
+    var var_occurredAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_detectedAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_monotonicMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_createdAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_modifiedAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_sizeBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_extension = sse_decode_opt_String(deserializer);
+    var var_mimeType = sse_decode_opt_String(deserializer);
+    var var_access = sse_decode_opt_box_autoadd_api_file_access_info(
+      deserializer,
+    );
+    return AckableFileEvent(
+      sequence: var_sequence,
+      fileName: var_fileName,
+      fullPath: var_fullPath,
+      occurredAtMs: var_occurredAtMs,
+      detectedAtMs: var_detectedAtMs,
+      monotonicMs: var_monotonicMs,
+      createdAtMs: var_createdAtMs,
+      modifiedAtMs: var_modifiedAtMs,
+      sizeBytes: var_sizeBytes,
+      extension: var_extension,
+      mimeType: var_mimeType,
+      access: var_access,
+    );
+  }
+
+  @protected
+  ActivityBucket sse_decode_activity_bucket(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_endMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_eventCount = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_fileCount = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_bytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_topTypes = sse_decode_list_type_count(deserializer);
+    return ActivityBucket(
+      startMs: var_startMs,
+      endMs: var_endMs,
+      eventCount: var_eventCount,
+      fileCount: var_fileCount,
+      bytes: var_bytes,
+      topTypes: var_topTypes,
+    );
+  }
+
+  @protected
+  AdoptionPhase sse_decode_adoption_phase(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AdoptionPhase.values[inner];
+  }
+
+  @protected
+  AdoptionProgressEvent sse_decode_adoption_progress_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_adoptionId = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_phase = sse_decode_adoption_phase(deserializer);
+    var var_filesDone = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_filesTotal = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_indexFailures = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_folderId = sse_decode_opt_String(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_errorMessage = sse_decode_opt_String(deserializer);
+    return AdoptionProgressEvent(
+      adoptionId: var_adoptionId,
+      path: var_path,
+      phase: var_phase,
+      filesDone: var_filesDone,
+      filesTotal: var_filesTotal,
+      indexFailures: var_indexFailures,
+      folderId: var_folderId,
+      errorCode: var_errorCode,
+      errorMessage: var_errorMessage,
+    );
+  }
+
+  @protected
+  ApiArchivedFile sse_decode_api_archived_file(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_originalPath = sse_decode_String(deserializer);
+    var var_archivedPath = sse_decode_String(deserializer);
+    var var_archivedAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_modifiedAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_sizeBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    return ApiArchivedFile(
+      originalPath: var_originalPath,
+      archivedPath: var_archivedPath,
+      archivedAtMs: var_archivedAtMs,
+      modifiedAtMs: var_modifiedAtMs,
+      sizeBytes: var_sizeBytes,
+    );
+  }
+
+  @protected
+  ApiAuditAction sse_decode_api_audit_action(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ApiAuditAction.values[inner];
+  }
+
+  @protected
+  ApiAuditEntry sse_decode_api_audit_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_atMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_action = sse_decode_api_audit_action(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_target = sse_decode_opt_String(deserializer);
+    var var_ruleId = sse_decode_opt_String(deserializer);
+    return ApiAuditEntry(
+      id: var_id,
+      atMs: var_atMs,
+      action: var_action,
+      path: var_path,
+      target: var_target,
+      ruleId: var_ruleId,
+    );
+  }
+
+  @protected
+  ApiClaimStatus sse_decode_api_claim_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return ApiClaimStatus_Claimed();
+      case 1:
+        var var_owner = sse_decode_String(deserializer);
+        var var_claimedAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+        return ApiClaimStatus_ClaimedByOther(
+          owner: var_owner,
+          claimedAtMs: var_claimedAtMs,
+        );
+      case 2:
+        var var_reason = sse_decode_String(deserializer);
+        return ApiClaimStatus_Unavailable(reason: var_reason);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  ApiCodeFormat sse_decode_api_code_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ApiCodeFormat.values[inner];
+  }
+
+  @protected
+  ApiConfig sse_decode_api_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_configPath = sse_decode_String(deserializer);
+    var var_dedupWindowMs = sse_decode_u_32(deserializer);
+    var var_rateLimitPerSecond = sse_decode_u_32(deserializer);
+    var var_watchPaths = sse_decode_list_String(deserializer);
+    var var_filter = sse_decode_watch_filter(deserializer);
+    var var_logLevel = sse_decode_opt_String(deserializer);
+    return ApiConfig(
+      configPath: var_configPath,
+      dedupWindowMs: var_dedupWindowMs,
+      rateLimitPerSecond: var_rateLimitPerSecond,
+      watchPaths: var_watchPaths,
+      filter: var_filter,
+      logLevel: var_logLevel,
+    );
+  }
+
+  @protected
+  ApiConfigPatch sse_decode_api_config_patch(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_dedupWindowMs = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_rateLimitPerSecond = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_watchPaths = sse_decode_opt_list_String(deserializer);
+    var var_filter = sse_decode_opt_box_autoadd_watch_filter(deserializer);
+    var var_logLevel = sse_decode_opt_String(deserializer);
+    return ApiConfigPatch(
+      dedupWindowMs: var_dedupWindowMs,
+      rateLimitPerSecond: var_rateLimitPerSecond,
+      watchPaths: var_watchPaths,
+      filter: var_filter,
+      logLevel: var_logLevel,
+    );
+  }
+
+  @protected
+  ApiDetectedCode sse_decode_api_detected_code(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_format = sse_decode_api_code_format(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    return ApiDetectedCode(format: var_format, text: var_text);
+  }
+
+  @protected
+  ApiEmailAttachment sse_decode_api_email_attachment(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fileName = sse_decode_String(deserializer);
+    var var_sizeBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_mimeType = sse_decode_opt_String(deserializer);
+    return ApiEmailAttachment(
+      fileName: var_fileName,
+      sizeBytes: var_sizeBytes,
+      mimeType: var_mimeType,
+    );
+  }
+
+  @protected
+  ApiEmailMetadata sse_decode_api_email_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_subject = sse_decode_opt_String(deserializer);
+    var var_from = sse_decode_opt_String(deserializer);
+    var var_to = sse_decode_list_String(deserializer);
+    var var_cc = sse_decode_list_String(deserializer);
+    var var_dateMs = sse_decode_opt_CastedPrimitive_i_64(deserializer);
+    var var_messageId = sse_decode_opt_String(deserializer);
+    var var_attachments = sse_decode_list_api_email_attachment(deserializer);
+    return ApiEmailMetadata(
+      subject: var_subject,
+      from: var_from,
+      to: var_to,
+      cc: var_cc,
+      dateMs: var_dateMs,
+      messageId: var_messageId,
+      attachments: var_attachments,
+    );
+  }
+
+  @protected
+  ApiEmbeddingVector sse_decode_api_embedding_vector(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_chunkIndex = sse_decode_u_32(deserializer);
+    var var_vector = sse_decode_list_prim_f_32_strict(deserializer);
+    return ApiEmbeddingVector(chunkIndex: var_chunkIndex, vector: var_vector);
+  }
+
+  @protected
+  ApiFileAccessInfo sse_decode_api_file_access_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ownerName = sse_decode_opt_String(deserializer);
+    var var_ownerId = sse_decode_opt_String(deserializer);
+    var var_readable = sse_decode_bool(deserializer);
+    var var_writable = sse_decode_bool(deserializer);
+    var var_executable = sse_decode_bool(deserializer);
+    return ApiFileAccessInfo(
+      ownerName: var_ownerName,
+      ownerId: var_ownerId,
+      readable: var_readable,
+      writable: var_writable,
+      executable: var_executable,
+    );
+  }
+
+  @protected
+  ApiJournalEntry sse_decode_api_journal_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_watcherId = sse_decode_String(deserializer);
+    var var_kind = sse_decode_file_event_kind(deserializer);
+    var var_fullPath = sse_decode_String(deserializer);
+    var var_previousPath = sse_decode_opt_String(deserializer);
+    var var_occurredAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_detectedAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_sizeBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_mimeType = sse_decode_opt_String(deserializer);
+    var var_selfGenerated = sse_decode_bool(deserializer);
+    return ApiJournalEntry(
+      id: var_id,
+      watcherId: var_watcherId,
+      kind: var_kind,
+      fullPath: var_fullPath,
+      previousPath: var_previousPath,
+      occurredAtMs: var_occurredAtMs,
+      detectedAtMs: var_detectedAtMs,
+      sizeBytes: var_sizeBytes,
+      mimeType: var_mimeType,
+      selfGenerated: var_selfGenerated,
+    );
+  }
+
+  @protected
+  ApiLockedOption sse_decode_api_locked_option(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return ApiLockedOption(key: var_key, value: var_value);
+  }
+
+  @protected
+  ApiRagSource sse_decode_api_rag_source(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_filePath = sse_decode_String(deserializer);
+    var var_chunkSnippet = sse_decode_String(deserializer);
+    var var_chunkOffset = sse_decode_u_32(deserializer);
+    return ApiRagSource(
+      filePath: var_filePath,
+      chunkSnippet: var_chunkSnippet,
+      chunkOffset: var_chunkOffset,
+    );
+  }
+
+  @protected
+  ApiSimilarityResult sse_decode_api_similarity_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_filePath = sse_decode_String(deserializer);
+    var var_fileName = sse_decode_String(deserializer);
+    var var_chunkSnippet = sse_decode_String(deserializer);
+    var var_chunkOffset = sse_decode_u_32(deserializer);
+    var var_score = sse_decode_f_64(deserializer);
+    return ApiSimilarityResult(
+      filePath: var_filePath,
+      fileName: var_fileName,
+      chunkSnippet: var_chunkSnippet,
+      chunkOffset: var_chunkOffset,
+      score: var_score,
+    );
+  }
+
+  @protected
+  ApiTelemetryCounter sse_decode_api_telemetry_counter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_day = sse_decode_String(deserializer);
+    var var_kind = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_count = sse_decode_CastedPrimitive_u_64(deserializer);
+    return ApiTelemetryCounter(
+      day: var_day,
+      kind: var_kind,
+      name: var_name,
+      count: var_count,
+    );
+  }
+
+  @protected
+  ApiTextChunk sse_decode_api_text_chunk(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_text = sse_decode_String(deserializer);
+    var var_chunkIndex = sse_decode_u_32(deserializer);
+    var var_chunkOffset = sse_decode_u_32(deserializer);
+    return ApiTextChunk(
+      text: var_text,
+      chunkIndex: var_chunkIndex,
+      chunkOffset: var_chunkOffset,
+    );
+  }
+
+  @protected
+  ApiThumbnail sse_decode_api_thumbnail(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_cachePath = sse_decode_String(deserializer);
+    var var_mimeType = sse_decode_String(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    return ApiThumbnail(
+      cachePath: var_cachePath,
+      mimeType: var_mimeType,
+      width: var_width,
+      height: var_height,
+    );
+  }
+
+  @protected
+  ApiWatchBackend sse_decode_api_watch_backend(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ApiWatchBackend.values[inner];
+  }
+
+  @protected
+  ApiWatchMode sse_decode_api_watch_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ApiWatchMode.values[inner];
+  }
+
+  @protected
+  ApiWatcherErrorCode sse_decode_api_watcher_error_code(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ApiWatcherErrorCode.values[inner];
+  }
+
+  @protected
+  ApiWatcherState sse_decode_api_watcher_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return ApiWatcherState_Starting();
+      case 1:
+        return ApiWatcherState_Running();
+      case 2:
+        var var_reason = sse_decode_String(deserializer);
+        return ApiWatcherState_Degraded(reason: var_reason);
+      case 3:
+        return ApiWatcherState_Stopped();
+      case 4:
+        var var_code = sse_decode_api_watcher_error_code(deserializer);
+        var var_message = sse_decode_String(deserializer);
+        return ApiWatcherState_Error(code: var_code, message: var_message);
+      case 5:
+        var var_attempt = sse_decode_u_32(deserializer);
+        var var_retryInMs = sse_decode_CastedPrimitive_u_64(deserializer);
+        var var_reason = sse_decode_String(deserializer);
+        return ApiWatcherState_Restarting(
+          attempt: var_attempt,
+          retryInMs: var_retryInMs,
+          reason: var_reason,
+        );
+      case 6:
+        var var_path = sse_decode_String(deserializer);
+        return ApiWatcherState_DirLost(path: var_path);
+      case 7:
+        var var_mode = sse_decode_api_watch_mode(deserializer);
+        var var_reason = sse_decode_String(deserializer);
+        return ApiWatcherState_Fallback(mode: var_mode, reason: var_reason);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  ApiXattr sse_decode_api_xattr(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_value = sse_decode_list_prim_u_8_strict(deserializer);
+    return ApiXattr(name: var_name, value: var_value);
+  }
+
+  @protected
+  ArchiveOptions sse_decode_archive_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_olderThanDays = sse_decode_u_32(deserializer);
+    var var_archiveDir = sse_decode_opt_String(deserializer);
+    var var_checkIntervalMinutes = sse_decode_opt_box_autoadd_u_32(
+      deserializer,
+    );
+    return ArchiveOptions(
+      olderThanDays: var_olderThanDays,
+      archiveDir: var_archiveDir,
+      checkIntervalMinutes: var_checkIntervalMinutes,
+    );
+  }
+
+  @protected
+  AuditLogFilter sse_decode_audit_log_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_action = sse_decode_opt_box_autoadd_api_audit_action(deserializer);
+    var var_path = sse_decode_opt_String(deserializer);
+    var var_ruleId = sse_decode_opt_String(deserializer);
+    var var_sinceMs = sse_decode_opt_CastedPrimitive_i_64(deserializer);
+    var var_untilMs = sse_decode_opt_CastedPrimitive_i_64(deserializer);
+    var var_limit = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return AuditLogFilter(
+      action: var_action,
+      path: var_path,
+      ruleId: var_ruleId,
+      sinceMs: var_sinceMs,
+      untilMs: var_untilMs,
+      limit: var_limit,
+    );
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  ApiArchivedFile sse_decode_box_autoadd_api_archived_file(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_api_archived_file(deserializer));
+  }
+
+  @protected
+  ApiAuditAction sse_decode_box_autoadd_api_audit_action(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_api_audit_action(deserializer));
+  }
+
+  @protected
+  ApiClaimStatus sse_decode_box_autoadd_api_claim_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_api_claim_status(deserializer));
+  }
+
+  @protected
+  ApiConfigPatch sse_decode_box_autoadd_api_config_patch(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_api_config_patch(deserializer));
+  }
+
+  @protected
+  ApiFileAccessInfo sse_decode_box_autoadd_api_file_access_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_api_file_access_info(deserializer));
+  }
+
+  @protected
+  ArchiveOptions sse_decode_box_autoadd_archive_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_archive_options(deserializer));
+  }
+
+  @protected
+  AuditLogFilter sse_decode_box_autoadd_audit_log_filter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_audit_log_filter(deserializer));
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  CoreConfig sse_decode_box_autoadd_core_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_core_config(deserializer));
+  }
+
+  @protected
+  EventDebugInfo sse_decode_box_autoadd_event_debug_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_event_debug_info(deserializer));
+  }
+
+  @protected
+  ExtractionOptions sse_decode_box_autoadd_extraction_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_extraction_options(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  FileProcessingStatus sse_decode_box_autoadd_file_processing_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_file_processing_status(deserializer));
+  }
+
+  @protected
+  FileRule sse_decode_box_autoadd_file_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_file_rule(deserializer));
+  }
+
+  @protected
+  HashAlgorithm sse_decode_box_autoadd_hash_algorithm(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_hash_algorithm(deserializer));
+  }
+
+  @protected
+  LogFileOptions sse_decode_box_autoadd_log_file_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_log_file_options(deserializer));
+  }
+
+  @protected
+  OcrOptions sse_decode_box_autoadd_ocr_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_ocr_options(deserializer));
+  }
+
+  @protected
+  OnboardingStep sse_decode_box_autoadd_onboarding_step(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_onboarding_step(deserializer));
+  }
+
+  @protected
+  TranscriptionOptions sse_decode_box_autoadd_transcription_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_transcription_options(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  WatchFilter sse_decode_box_autoadd_watch_filter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_watch_filter(deserializer));
+  }
+
+  @protected
+  WatchTuning sse_decode_box_autoadd_watch_tuning(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_watch_tuning(deserializer));
+  }
+
+  @protected
+  WatcherDegradationPolicy sse_decode_box_autoadd_watcher_degradation_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_watcher_degradation_policy(deserializer));
+  }
+
+  @protected
+  CategoryStats sse_decode_category_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_category = sse_decode_file_category(deserializer);
+    var var_fileCount = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_totalBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    return CategoryStats(
+      category: var_category,
+      fileCount: var_fileCount,
+      totalBytes: var_totalBytes,
+    );
+  }
+
+  @protected
+  ConfigImportResult sse_decode_config_import_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_watcherIds = sse_decode_list_String(deserializer);
+    var var_skippedWatchers = sse_decode_list_String(deserializer);
+    return ConfigImportResult(
+      watcherIds: var_watcherIds,
+      skippedWatchers: var_skippedWatchers,
+    );
+  }
+
+  @protected
+  ControlledFolderAccessInfo sse_decode_controlled_folder_access_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_status = sse_decode_controlled_folder_access_status(deserializer);
+    var var_appPath = sse_decode_String(deserializer);
+    var var_settingsUri = sse_decode_String(deserializer);
+    var var_steps = sse_decode_list_String(deserializer);
+    return ControlledFolderAccessInfo(
+      status: var_status,
+      appPath: var_appPath,
+      settingsUri: var_settingsUri,
+      steps: var_steps,
+    );
+  }
+
+  @protected
+  ControlledFolderAccessStatus sse_decode_controlled_folder_access_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ControlledFolderAccessStatus.values[inner];
+  }
+
+  @protected
+  CoreCapabilities sse_decode_core_capabilities(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_coreVersion = sse_decode_String(deserializer);
+    var var_platform = sse_decode_String(deserializer);
+    var var_dataDir = sse_decode_String(deserializer);
+    var var_indexReady = sse_decode_bool(deserializer);
+    var var_ocrAvailable = sse_decode_bool(deserializer);
+    var var_hasAvx2 = sse_decode_bool(deserializer);
+    var var_hasVulkan = sse_decode_bool(deserializer);
+    var var_totalRamMb = sse_decode_CastedPrimitive_u_64(deserializer);
+    return CoreCapabilities(
+      coreVersion: var_coreVersion,
+      platform: var_platform,
+      dataDir: var_dataDir,
+      indexReady: var_indexReady,
+      ocrAvailable: var_ocrAvailable,
+      hasAvx2: var_hasAvx2,
+      hasVulkan: var_hasVulkan,
+      totalRamMb: var_totalRamMb,
+    );
+  }
+
+  @protected
+  CoreConfig sse_decode_core_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_dataDir = sse_decode_opt_String(deserializer);
+    var var_indexDbPath = sse_decode_opt_String(deserializer);
+    return CoreConfig(dataDir: var_dataDir, indexDbPath: var_indexDbPath);
+  }
+
+  @protected
+  DesktopAccessStatus sse_decode_desktop_access_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DesktopAccessStatus.values[inner];
+  }
+
+  @protected
+  DirSizeInfo sse_decode_dir_size_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_totalBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_fileCount = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_dirCount = sse_decode_CastedPrimitive_u_64(deserializer);
+    return DirSizeInfo(
+      totalBytes: var_totalBytes,
+      fileCount: var_fileCount,
+      dirCount: var_dirCount,
+    );
+  }
+
+  @protected
+  DryRunActionEvent sse_decode_dry_run_action_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_action = sse_decode_api_audit_action(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_target = sse_decode_opt_String(deserializer);
+    var var_ruleId = sse_decode_String(deserializer);
+    var var_plannedAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    return DryRunActionEvent(
+      action: var_action,
+      path: var_path,
+      target: var_target,
+      ruleId: var_ruleId,
+      plannedAtMs: var_plannedAtMs,
+    );
+  }
+
+  @protected
+  DuplicateDetected sse_decode_duplicate_detected(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_watcherId = sse_decode_String(deserializer);
+    var var_newPath = sse_decode_String(deserializer);
+    var var_existingPath = sse_decode_String(deserializer);
+    return DuplicateDetected(
+      watcherId: var_watcherId,
+      newPath: var_newPath,
+      existingPath: var_existingPath,
+    );
+  }
+
+  @protected
+  DuplicateGroup sse_decode_duplicate_group(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sizeBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_hash = sse_decode_String(deserializer);
+    var var_paths = sse_decode_list_String(deserializer);
+    return DuplicateGroup(
+      sizeBytes: var_sizeBytes,
+      hash: var_hash,
+      paths: var_paths,
+    );
+  }
+
+  @protected
+  EventDebugInfo sse_decode_event_debug_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sequence = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_notifyKinds = sse_decode_list_String(deserializer);
+    var var_decisions = sse_decode_list_String(deserializer);
+    var var_firstSeenMonotonicMs = sse_decode_CastedPrimitive_i_64(
+      deserializer,
+    );
+    var var_metadataMs = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_heldMs = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_deliveredMonotonicMs = sse_decode_CastedPrimitive_i_64(
+      deserializer,
+    );
+    return EventDebugInfo(
+      sequence: var_sequence,
+      notifyKinds: var_notifyKinds,
+      decisions: var_decisions,
+      firstSeenMonotonicMs: var_firstSeenMonotonicMs,
+      metadataMs: var_metadataMs,
+      heldMs: var_heldMs,
+      deliveredMonotonicMs: var_deliveredMonotonicMs,
+    );
+  }
+
+  @protected
+  EventTimestampSource sse_decode_event_timestamp_source(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return EventTimestampSource.values[inner];
+  }
+
+  @protected
+  ExtractionOptions sse_decode_extraction_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxPagesPerPdf = sse_decode_u_32(deserializer);
+    var var_maxFileSizeMb = sse_decode_u_32(deserializer);
+    return ExtractionOptions(
+      maxPagesPerPdf: var_maxPagesPerPdf,
+      maxFileSizeMb: var_maxFileSizeMb,
+    );
+  }
+
+  @protected
+  ExtractionResult sse_decode_extraction_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_text = sse_decode_String(deserializer);
+    var var_contentType = sse_decode_String(deserializer);
+    var var_pagesExtracted = sse_decode_u_32(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    return ExtractionResult(
+      text: var_text,
+      contentType: var_contentType,
+      pagesExtracted: var_pagesExtracted,
+      errorCode: var_errorCode,
+    );
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FileAddedEvent sse_decode_file_added_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fileName = sse_decode_String(deserializer);
+    var var_fullPath = sse_decode_String(deserializer);
+    var var_occurredAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_detectedAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_monotonicMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_createdAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_modifiedAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_sizeBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_extension = sse_decode_opt_String(deserializer);
+    var var_mimeType = sse_decode_opt_String(deserializer);
+    var var_access = sse_decode_opt_box_autoadd_api_file_access_info(
+      deserializer,
+    );
+    return FileAddedEvent(
+      fileName: var_fileName,
+      fullPath: var_fullPath,
+      occurredAtMs: var_occurredAtMs,
+      detectedAtMs: var_detectedAtMs,
+      monotonicMs: var_monotonicMs,
+      createdAtMs: var_createdAtMs,
+      modifiedAtMs: var_modifiedAtMs,
+      sizeBytes: var_sizeBytes,
+      extension: var_extension,
+      mimeType: var_mimeType,
+      access: var_access,
+    );
+  }
+
+  @protected
+  FileBatchEvent sse_decode_file_batch_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_watcherId = sse_decode_String(deserializer);
+    var var_events = sse_decode_list_file_added_event(deserializer);
+    return FileBatchEvent(watcherId: var_watcherId, events: var_events);
+  }
+
+  @protected
+  FileCategory sse_decode_file_category(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FileCategory.values[inner];
+  }
+
+  @protected
+  FileChunkEvent sse_decode_file_chunk_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_watcherId = sse_decode_String(deserializer);
+    var var_count = sse_decode_u_32(deserializer);
+    var var_payload = sse_decode_list_prim_u_8_strict(deserializer);
+    return FileChunkEvent(
+      watcherId: var_watcherId,
+      count: var_count,
+      payload: var_payload,
+    );
+  }
+
+  @protected
+  FileEvent sse_decode_file_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sequence = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_watcherId = sse_decode_String(deserializer);
+    var var_kind = sse_decode_file_event_kind(deserializer);
+    var var_fileName = sse_decode_String(deserializer);
+    var var_fullPath = sse_decode_String(deserializer);
+    var var_previousPath = sse_decode_opt_String(deserializer);
+    var var_occurredAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_detectedAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_monotonicMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_createdAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_modifiedAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_sizeBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_extension = sse_decode_opt_String(deserializer);
+    var var_mimeType = sse_decode_opt_String(deserializer);
+    var var_access = sse_decode_opt_box_autoadd_api_file_access_info(
+      deserializer,
+    );
+    var var_selfGenerated = sse_decode_bool(deserializer);
+    var var_reconciled = sse_decode_bool(deserializer);
+    var var_claim = sse_decode_opt_box_autoadd_api_claim_status(deserializer);
+    var var_codes = sse_decode_list_api_detected_code(deserializer);
+    return FileEvent(
+      sequence: var_sequence,
+      watcherId: var_watcherId,
+      kind: var_kind,
+      fileName: var_fileName,
+      fullPath: var_fullPath,
+      previousPath: var_previousPath,
+      occurredAtMs: var_occurredAtMs,
+      detectedAtMs: var_detectedAtMs,
+      monotonicMs: var_monotonicMs,
+      createdAtMs: var_createdAtMs,
+      modifiedAtMs: var_modifiedAtMs,
+      sizeBytes: var_sizeBytes,
+      extension: var_extension,
+      mimeType: var_mimeType,
+      access: var_access,
+      selfGenerated: var_selfGenerated,
+      reconciled: var_reconciled,
+      claim: var_claim,
+      codes: var_codes,
+    );
+  }
+
+  @protected
+  FileEventKind sse_decode_file_event_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FileEventKind.values[inner];
+  }
+
+  @protected
+  FileHashEvent sse_decode_file_hash_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_watcherId = sse_decode_String(deserializer);
+    var var_fullPath = sse_decode_String(deserializer);
+    var var_algorithm = sse_decode_hash_algorithm(deserializer);
+    var var_hash = sse_decode_String(deserializer);
+    return FileHashEvent(
+      watcherId: var_watcherId,
+      fullPath: var_fullPath,
+      algorithm: var_algorithm,
+      hash: var_hash,
+    );
+  }
+
+  @protected
+  FileOpEvent sse_decode_file_op_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_operationId = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_kind = sse_decode_file_op_kind(deserializer);
+    var var_src = sse_decode_String(deserializer);
+    var var_dst = sse_decode_String(deserializer);
+    var var_status = sse_decode_file_op_status(deserializer);
+    var var_bytesDone = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_bytesTotal = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_errorMessage = sse_decode_opt_String(deserializer);
+    return FileOpEvent(
+      operationId: var_operationId,
+      kind: var_kind,
+      src: var_src,
+      dst: var_dst,
+      status: var_status,
+      bytesDone: var_bytesDone,
+      bytesTotal: var_bytesTotal,
+      errorCode: var_errorCode,
+      errorMessage: var_errorMessage,
+    );
+  }
+
+  @protected
+  FileOpKind sse_decode_file_op_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FileOpKind.values[inner];
+  }
+
+  @protected
+  FileOpStatus sse_decode_file_op_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FileOpStatus.values[inner];
+  }
+
+  @protected
+  FileProcessingStatus sse_decode_file_processing_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FileProcessingStatus.values[inner];
+  }
+
+  @protected
+  FileRemovedEvent sse_decode_file_removed_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fileName = sse_decode_String(deserializer);
+    var var_fullPath = sse_decode_String(deserializer);
+    var var_occurredAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    return FileRemovedEvent(
+      fileName: var_fileName,
+      fullPath: var_fullPath,
+      occurredAtMs: var_occurredAtMs,
+    );
+  }
+
+  @protected
+  FileRule sse_decode_file_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_nameGlobs = sse_decode_list_String(deserializer);
+    var var_mimeTypes = sse_decode_list_String(deserializer);
+    var var_minSizeBytes = sse_decode_opt_CastedPrimitive_u_64(deserializer);
+    var var_maxSizeBytes = sse_decode_opt_CastedPrimitive_u_64(deserializer);
+    var var_action = sse_decode_file_rule_action(deserializer);
+    return FileRule(
+      id: var_id,
+      nameGlobs: var_nameGlobs,
+      mimeTypes: var_mimeTypes,
+      minSizeBytes: var_minSizeBytes,
+      maxSizeBytes: var_maxSizeBytes,
+      action: var_action,
+    );
+  }
+
+  @protected
+  FileRuleAction sse_decode_file_rule_action(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_subfolder = sse_decode_String(deserializer);
+        return FileRuleAction_MoveToSubfolder(subfolder: var_subfolder);
+      case 1:
+        var var_template = sse_decode_String(deserializer);
+        return FileRuleAction_Rename(template: var_template);
+      case 2:
+        var var_tag = sse_decode_String(deserializer);
+        return FileRuleAction_Tag(tag: var_tag);
+      case 3:
+        return FileRuleAction_Ignore();
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  FileStatusChangedEvent sse_decode_file_status_changed_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fullPath = sse_decode_String(deserializer);
+    var var_status = sse_decode_opt_box_autoadd_file_processing_status(
+      deserializer,
+    );
+    var var_updatedAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    return FileStatusChangedEvent(
+      fullPath: var_fullPath,
+      status: var_status,
+      updatedAtMs: var_updatedAtMs,
+    );
+  }
+
+  @protected
+  FileStatusUpdate sse_decode_file_status_update(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fullPath = sse_decode_String(deserializer);
+    var var_status = sse_decode_file_processing_status(deserializer);
+    return FileStatusUpdate(fullPath: var_fullPath, status: var_status);
+  }
+
+  @protected
+  FolderMarkerInfo sse_decode_folder_marker_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_status = sse_decode_folder_marker_status(deserializer);
+    var var_folderId = sse_decode_opt_String(deserializer);
+    var var_createdAtMs = sse_decode_opt_CastedPrimitive_i_64(deserializer);
+    var var_settingsHash = sse_decode_opt_String(deserializer);
+    var var_settingsMatch = sse_decode_bool(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return FolderMarkerInfo(
+      status: var_status,
+      folderId: var_folderId,
+      createdAtMs: var_createdAtMs,
+      settingsHash: var_settingsHash,
+      settingsMatch: var_settingsMatch,
+      error: var_error,
+    );
+  }
+
+  @protected
+  FolderMarkerStatus sse_decode_folder_marker_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FolderMarkerStatus.values[inner];
+  }
+
+  @protected
+  HashAlgorithm sse_decode_hash_algorithm(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return HashAlgorithm.values[inner];
+  }
+
+  @protected
+  HeartbeatEvent sse_decode_heartbeat_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sequence = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_emittedAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_uptimeMs = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_watcherRunning = sse_decode_bool(deserializer);
+    return HeartbeatEvent(
+      sequence: var_sequence,
+      emittedAtMs: var_emittedAtMs,
+      uptimeMs: var_uptimeMs,
+      watcherRunning: var_watcherRunning,
+    );
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  IntegrityIssue sse_decode_integrity_issue(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_watcherId = sse_decode_String(deserializer);
+    var var_kind = sse_decode_integrity_issue_kind(deserializer);
+    var var_fullPath = sse_decode_String(deserializer);
+    return IntegrityIssue(
+      watcherId: var_watcherId,
+      kind: var_kind,
+      fullPath: var_fullPath,
+    );
+  }
+
+  @protected
+  IntegrityIssueKind sse_decode_integrity_issue_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return IntegrityIssueKind.values[inner];
+  }
+
+  @protected
+  LateraApiError sse_decode_latera_api_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_code = sse_decode_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_recoverable = sse_decode_bool(deserializer);
+    var var_context = sse_decode_opt_String(deserializer);
+    return LateraApiError(
+      code: var_code,
+      message: var_message,
+      recoverable: var_recoverable,
+      context: var_context,
+    );
+  }
+
+  @protected
+  List<ImageBuffer>
+  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ImageBuffer>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ActivityBucket> sse_decode_list_activity_bucket(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ActivityBucket>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_activity_bucket(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ApiArchivedFile> sse_decode_list_api_archived_file(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiArchivedFile>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_archived_file(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ApiAuditEntry> sse_decode_list_api_audit_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiAuditEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_audit_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ApiDetectedCode> sse_decode_list_api_detected_code(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiDetectedCode>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_detected_code(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ApiEmailAttachment> sse_decode_list_api_email_attachment(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiEmailAttachment>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_email_attachment(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ApiEmbeddingVector> sse_decode_list_api_embedding_vector(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiEmbeddingVector>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_embedding_vector(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ApiJournalEntry> sse_decode_list_api_journal_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiJournalEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_journal_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ApiLockedOption> sse_decode_list_api_locked_option(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiLockedOption>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_locked_option(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ApiRagSource> sse_decode_list_api_rag_source(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiRagSource>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_rag_source(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ApiSimilarityResult> sse_decode_list_api_similarity_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiSimilarityResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_similarity_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ApiTelemetryCounter> sse_decode_list_api_telemetry_counter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiTelemetryCounter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_telemetry_counter(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ApiTextChunk> sse_decode_list_api_text_chunk(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiTextChunk>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_text_chunk(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ApiXattr> sse_decode_list_api_xattr(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ApiXattr>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_api_xattr(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CategoryStats> sse_decode_list_category_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CategoryStats>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_category_stats(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DuplicateGroup> sse_decode_list_duplicate_group(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DuplicateGroup>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_duplicate_group(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FileAddedEvent> sse_decode_list_file_added_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FileAddedEvent>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_file_added_event(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FileRule> sse_decode_list_file_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FileRule>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_file_rule(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FileStatusUpdate> sse_decode_list_file_status_update(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FileStatusUpdate>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_file_status_update(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<IntegrityIssue> sse_decode_list_integrity_issue(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <IntegrityIssue>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_integrity_issue(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<OnboardingStep> sse_decode_list_onboarding_step(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <OnboardingStep>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_onboarding_step(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PdfPagePreview> sse_decode_list_pdf_page_preview(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PdfPagePreview>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_pdf_page_preview(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat32List(len_);
+  }
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint32List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<ReportFile> sse_decode_list_report_file(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ReportFile>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_report_file(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SearchResultItem> sse_decode_list_search_result_item(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SearchResultItem>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_search_result_item(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TypeCount> sse_decode_list_type_count(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TypeCount>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_type_count(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WatchLocationSuggestion> sse_decode_list_watch_location_suggestion(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WatchLocationSuggestion>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_watch_location_suggestion(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WatchTreeStat> sse_decode_list_watch_tree_stat(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WatchTreeStat>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_watch_tree_stat(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WatcherInfo> sse_decode_list_watcher_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WatcherInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_watcher_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LogFileOptions sse_decode_log_file_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxFileBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_maxRotatedFiles = sse_decode_u_32(deserializer);
+    var var_maxTotalBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_compress = sse_decode_bool(deserializer);
+    return LogFileOptions(
+      maxFileBytes: var_maxFileBytes,
+      maxRotatedFiles: var_maxRotatedFiles,
+      maxTotalBytes: var_maxTotalBytes,
+      compress: var_compress,
+    );
+  }
+
+  @protected
+  LogRecord sse_decode_log_record(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_level = sse_decode_String(deserializer);
+    var var_target = sse_decode_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_timestamp = sse_decode_String(deserializer);
+    var var_correlationId = sse_decode_opt_String(deserializer);
+    return LogRecord(
+      level: var_level,
+      target: var_target,
+      message: var_message,
+      timestamp: var_timestamp,
+      correlationId: var_correlationId,
+    );
+  }
+
+  @protected
+  OcrOptions sse_decode_ocr_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxPagesPerPdf = sse_decode_u_32(deserializer);
+    var var_maxFileSizeMb = sse_decode_u_32(deserializer);
+    var var_language = sse_decode_opt_String(deserializer);
+    return OcrOptions(
+      maxPagesPerPdf: var_maxPagesPerPdf,
+      maxFileSizeMb: var_maxFileSizeMb,
+      language: var_language,
+    );
+  }
+
+  @protected
+  OcrResult sse_decode_ocr_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_text = sse_decode_String(deserializer);
+    var var_contentType = sse_decode_String(deserializer);
+    var var_pagesProcessed = sse_decode_u_32(deserializer);
+    var var_confidence = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    return OcrResult(
+      text: var_text,
+      contentType: var_contentType,
+      pagesProcessed: var_pagesProcessed,
+      confidence: var_confidence,
+      errorCode: var_errorCode,
+    );
+  }
+
+  @protected
+  OnboardingState sse_decode_onboarding_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_currentStep = sse_decode_opt_box_autoadd_onboarding_step(
+      deserializer,
+    );
+    var var_completedSteps = sse_decode_list_onboarding_step(deserializer);
+    var var_folderPath = sse_decode_String(deserializer);
+    var var_folderExists = sse_decode_bool(deserializer);
+    var var_accessGranted = sse_decode_bool(deserializer);
+    var var_firstEventAtMs = sse_decode_opt_CastedPrimitive_i_64(deserializer);
+    return OnboardingState(
+      currentStep: var_currentStep,
+      completedSteps: var_completedSteps,
+      folderPath: var_folderPath,
+      folderExists: var_folderExists,
+      accessGranted: var_accessGranted,
+      firstEventAtMs: var_firstEventAtMs,
+    );
+  }
+
+  @protected
+  OnboardingStep sse_decode_onboarding_step(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return OnboardingStep.values[inner];
+  }
+
+  @protected
+  int? sse_decode_opt_CastedPrimitive_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_CastedPrimitive_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_CastedPrimitive_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_CastedPrimitive_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ApiArchivedFile? sse_decode_opt_box_autoadd_api_archived_file(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_api_archived_file(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ApiAuditAction? sse_decode_opt_box_autoadd_api_audit_action(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_api_audit_action(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ApiClaimStatus? sse_decode_opt_box_autoadd_api_claim_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_api_claim_status(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ApiFileAccessInfo? sse_decode_opt_box_autoadd_api_file_access_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_api_file_access_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ArchiveOptions? sse_decode_opt_box_autoadd_archive_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_archive_options(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  EventDebugInfo? sse_decode_opt_box_autoadd_event_debug_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_event_debug_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  FileProcessingStatus? sse_decode_opt_box_autoadd_file_processing_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_file_processing_status(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  HashAlgorithm? sse_decode_opt_box_autoadd_hash_algorithm(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_hash_algorithm(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OnboardingStep? sse_decode_opt_box_autoadd_onboarding_step(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_onboarding_step(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  WatchFilter? sse_decode_opt_box_autoadd_watch_filter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_watch_filter(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  WatchTuning? sse_decode_opt_box_autoadd_watch_tuning(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_watch_tuning(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  WatcherDegradationPolicy?
+  sse_decode_opt_box_autoadd_watcher_degradation_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_watcher_degradation_policy(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PdfPagePreview sse_decode_pdf_page_preview(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_page = sse_decode_u_32(deserializer);
+    var var_width = sse_decode_u_32(deserializer);
+    var var_height = sse_decode_u_32(deserializer);
+    var var_png = sse_decode_list_prim_u_8_strict(deserializer);
+    return PdfPagePreview(
+      page: var_page,
+      width: var_width,
+      height: var_height,
+      png: var_png,
+    );
+  }
+
+  @protected
+  PolicyStatus sse_decode_policy_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_policyPath = sse_decode_opt_String(deserializer);
+    var var_lockedOptions = sse_decode_list_api_locked_option(deserializer);
+    return PolicyStatus(
+      policyPath: var_policyPath,
+      lockedOptions: var_lockedOptions,
+    );
+  }
+
+  @protected
+  RagQueryResult sse_decode_rag_query_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_answer = sse_decode_String(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    var var_sources = sse_decode_list_api_rag_source(deserializer);
+    return RagQueryResult(
+      answer: var_answer,
+      errorCode: var_errorCode,
+      sources: var_sources,
+    );
+  }
+
+  @protected
+  ReportFile sse_decode_report_file(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fullPath = sse_decode_String(deserializer);
+    var var_sizeBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_modifiedAtMs = sse_decode_opt_CastedPrimitive_i_64(deserializer);
+    var var_category = sse_decode_file_category(deserializer);
+    return ReportFile(
+      fullPath: var_fullPath,
+      sizeBytes: var_sizeBytes,
+      modifiedAtMs: var_modifiedAtMs,
+      category: var_category,
+    );
+  }
+
+  @protected
+  RuleAppliedEvent sse_decode_rule_applied_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_watcherId = sse_decode_String(deserializer);
+    var var_ruleId = sse_decode_String(deserializer);
+    var var_action = sse_decode_file_rule_action(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_newPath = sse_decode_opt_String(deserializer);
+    var var_appliedAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    return RuleAppliedEvent(
+      watcherId: var_watcherId,
+      ruleId: var_ruleId,
+      action: var_action,
+      path: var_path,
+      newPath: var_newPath,
+      appliedAtMs: var_appliedAtMs,
+    );
+  }
+
+  @protected
+  ScreenshotAddedEvent sse_decode_screenshot_added_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_watcherId = sse_decode_String(deserializer);
+    var var_fileName = sse_decode_String(deserializer);
+    var var_fullPath = sse_decode_String(deserializer);
+    var var_occurredAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    var var_sizeBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_mimeType = sse_decode_opt_String(deserializer);
+    return ScreenshotAddedEvent(
+      watcherId: var_watcherId,
+      fileName: var_fileName,
+      fullPath: var_fullPath,
+      occurredAtMs: var_occurredAtMs,
+      sizeBytes: var_sizeBytes,
+      mimeType: var_mimeType,
+    );
+  }
+
+  @protected
+  SearchResultItem sse_decode_search_result_item(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_filePath = sse_decode_String(deserializer);
+    var var_fileName = sse_decode_String(deserializer);
+    var var_description = sse_decode_String(deserializer);
+    var var_snippet = sse_decode_String(deserializer);
+    var var_rank = sse_decode_f_64(deserializer);
+    return SearchResultItem(
+      filePath: var_filePath,
+      fileName: var_fileName,
+      description: var_description,
+      snippet: var_snippet,
+      rank: var_rank,
+    );
+  }
+
+  @protected
+  TelemetryPreview sse_decode_telemetry_preview(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_counters = sse_decode_list_api_telemetry_counter(deserializer);
+    var var_payloadJson = sse_decode_String(deserializer);
+    return TelemetryPreview(
+      enabled: var_enabled,
+      counters: var_counters,
+      payloadJson: var_payloadJson,
+    );
+  }
+
+  @protected
+  TimelineGranularity sse_decode_timeline_granularity(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TimelineGranularity.values[inner];
+  }
+
+  @protected
+  TranscriptionOptions sse_decode_transcription_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxMediaMinutes = sse_decode_u_32(deserializer);
+    var var_maxFileSizeMb = sse_decode_u_32(deserializer);
+    var var_language = sse_decode_opt_String(deserializer);
+    return TranscriptionOptions(
+      maxMediaMinutes: var_maxMediaMinutes,
+      maxFileSizeMb: var_maxFileSizeMb,
+      language: var_language,
+    );
+  }
+
+  @protected
+  TranscriptionResult sse_decode_transcription_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_text = sse_decode_String(deserializer);
+    var var_contentType = sse_decode_String(deserializer);
+    var var_durationSeconds = sse_decode_u_32(deserializer);
+    var var_errorCode = sse_decode_opt_String(deserializer);
+    return TranscriptionResult(
+      text: var_text,
+      contentType: var_contentType,
+      durationSeconds: var_durationSeconds,
+      errorCode: var_errorCode,
+    );
+  }
+
+  @protected
+  TypeCount sse_decode_type_count(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mimeType = sse_decode_String(deserializer);
+    var var_count = sse_decode_CastedPrimitive_u_64(deserializer);
+    return TypeCount(mimeType: var_mimeType, count: var_count);
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  VolumeInfo sse_decode_volume_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_volume_kind(deserializer);
+    var var_mountPoint = sse_decode_opt_String(deserializer);
+    var var_fileSystem = sse_decode_String(deserializer);
+    var var_isSsd = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_cloudProvider = sse_decode_opt_String(deserializer);
+    var var_pollIntervalMs = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_settleQuietPeriodMs = sse_decode_opt_CastedPrimitive_u_64(
+      deserializer,
+    );
+    var var_hashingEnabled = sse_decode_bool(deserializer);
+    return VolumeInfo(
+      kind: var_kind,
+      mountPoint: var_mountPoint,
+      fileSystem: var_fileSystem,
+      isSsd: var_isSsd,
+      cloudProvider: var_cloudProvider,
+      pollIntervalMs: var_pollIntervalMs,
+      settleQuietPeriodMs: var_settleQuietPeriodMs,
+      hashingEnabled: var_hashingEnabled,
+    );
+  }
+
+  @protected
+  VolumeKind sse_decode_volume_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return VolumeKind.values[inner];
+  }
+
+  @protected
+  WatchDirRecoveryMode sse_decode_watch_dir_recovery_mode(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return WatchDirRecoveryMode.values[inner];
+  }
+
+  @protected
+  WatchFilter sse_decode_watch_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_includeGlobs = sse_decode_list_String(deserializer);
+    var var_excludeGlobs = sse_decode_list_String(deserializer);
+    var var_extensions = sse_decode_list_String(deserializer);
+    var var_includeHidden = sse_decode_bool(deserializer);
+    return WatchFilter(
+      includeGlobs: var_includeGlobs,
+      excludeGlobs: var_excludeGlobs,
+      extensions: var_extensions,
+      includeHidden: var_includeHidden,
+    );
+  }
+
+  @protected
+  WatchLocationKind sse_decode_watch_location_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return WatchLocationKind.values[inner];
+  }
+
+  @protected
+  WatchLocationSuggestion sse_decode_watch_location_suggestion(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_watch_location_kind(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_exists = sse_decode_bool(deserializer);
+    var var_writable = sse_decode_bool(deserializer);
+    var var_availableBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+    return WatchLocationSuggestion(
+      kind: var_kind,
+      path: var_path,
+      exists: var_exists,
+      writable: var_writable,
+      availableBytes: var_availableBytes,
+    );
+  }
+
+  @protected
+  WatchStarted sse_decode_watch_started(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_watcherId = sse_decode_String(deserializer);
+    var var_backend = sse_decode_api_watch_mode(deserializer);
+    return WatchStarted(watcherId: var_watcherId, backend: var_backend);
+  }
+
+  @protected
+  WatchStatusEvent sse_decode_watch_status_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_availableBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+        var var_thresholdBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+        return WatchStatusEvent_LowDiskSpace(
+          availableBytes: var_availableBytes,
+          thresholdBytes: var_thresholdBytes,
+        );
+      case 1:
+        var var_availableBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+        return WatchStatusEvent_DiskSpaceRecovered(
+          availableBytes: var_availableBytes,
+        );
+      case 2:
+        var var_watcherId = sse_decode_String(deserializer);
+        var var_totalBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+        var var_fileCount = sse_decode_CastedPrimitive_u_64(deserializer);
+        var var_maxTotalBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+        var var_maxFileCount = sse_decode_CastedPrimitive_u_64(deserializer);
+        var var_removedFiles = sse_decode_CastedPrimitive_u_64(deserializer);
+        var var_freedBytes = sse_decode_CastedPrimitive_u_64(deserializer);
+        return WatchStatusEvent_QuotaExceeded(
+          watcherId: var_watcherId,
+          totalBytes: var_totalBytes,
+          fileCount: var_fileCount,
+          maxTotalBytes: var_maxTotalBytes,
+          maxFileCount: var_maxFileCount,
+          removedFiles: var_removedFiles,
+          freedBytes: var_freedBytes,
+        );
+      case 3:
+        var var_fileName = sse_decode_String(deserializer);
+        var var_newPath = sse_decode_String(deserializer);
+        var var_newFingerprint = sse_decode_String(deserializer);
+        var var_existingPath = sse_decode_String(deserializer);
+        var var_existingFingerprint = sse_decode_String(deserializer);
+        return WatchStatusEvent_NameConflict(
+          fileName: var_fileName,
+          newPath: var_newPath,
+          newFingerprint: var_newFingerprint,
+          existingPath: var_existingPath,
+          existingFingerprint: var_existingFingerprint,
+        );
+      case 4:
+        var var_backupIndex = sse_decode_u_32(deserializer);
+        var var_corruptPath = sse_decode_String(deserializer);
+        return WatchStatusEvent_SettingsRecovered(
+          backupIndex: var_backupIndex,
+          corruptPath: var_corruptPath,
+        );
+      case 5:
+        var var_watcherId = sse_decode_String(deserializer);
+        var var_path = sse_decode_String(deserializer);
+        return WatchStatusEvent_WatchDirLost(
+          watcherId: var_watcherId,
+          path: var_path,
+        );
+      case 6:
+        var var_watcherId = sse_decode_String(deserializer);
+        var var_path = sse_decode_String(deserializer);
+        return WatchStatusEvent_WatchDirRestored(
+          watcherId: var_watcherId,
+          path: var_path,
+        );
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  WatchTreeStat sse_decode_watch_tree_stat(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_relativePath = sse_decode_String(deserializer);
+    var var_eventCount = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_lastActivityMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    return WatchTreeStat(
+      relativePath: var_relativePath,
+      eventCount: var_eventCount,
+      lastActivityMs: var_lastActivityMs,
+    );
+  }
+
+  @protected
+  WatchTuning sse_decode_watch_tuning(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_dedupWindowMs = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_maxEventsPerSecond = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return WatchTuning(
+      dedupWindowMs: var_dedupWindowMs,
+      maxEventsPerSecond: var_maxEventsPerSecond,
+    );
+  }
+
+  @protected
+  WatcherDegradationPolicy sse_decode_watcher_degradation_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_nativeFailures = sse_decode_u_32(deserializer);
+    var var_pollingFailures = sse_decode_u_32(deserializer);
+    var var_pollIntervalMs = sse_decode_u_32(deserializer);
+    return WatcherDegradationPolicy(
+      nativeFailures: var_nativeFailures,
+      pollingFailures: var_pollingFailures,
+      pollIntervalMs: var_pollIntervalMs,
+    );
+  }
+
+  @protected
+  WatcherInfo sse_decode_watcher_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_watcherId = sse_decode_String(deserializer);
+    var var_watchDir = sse_decode_String(deserializer);
+    var var_recursive = sse_decode_bool(deserializer);
+    var var_startedAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    return WatcherInfo(
+      watcherId: var_watcherId,
+      watchDir: var_watchDir,
+      recursive: var_recursive,
+      startedAtMs: var_startedAtMs,
+    );
+  }
+
+  @protected
+  WatcherMetrics sse_decode_watcher_metrics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_eventsReceived = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_eventsEmitted = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_eventsDeduped = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_eventsDropped = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_queueDepth = sse_decode_CastedPrimitive_u_64(deserializer);
+    var var_queueCapacity = sse_decode_CastedPrimitive_u_64(deserializer);
+    return WatcherMetrics(
+      eventsReceived: var_eventsReceived,
+      eventsEmitted: var_eventsEmitted,
+      eventsDeduped: var_eventsDeduped,
+      eventsDropped: var_eventsDropped,
+      queueDepth: var_queueDepth,
+      queueCapacity: var_queueCapacity,
+    );
+  }
+
+  @protected
+  WatcherStatusEvent sse_decode_watcher_status_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_watcherId = sse_decode_String(deserializer);
+    var var_state = sse_decode_api_watcher_state(deserializer);
+    var var_heartbeat = sse_decode_bool(deserializer);
+    var var_emittedAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
+    return WatcherStatusEvent(
+      watcherId: var_watcherId,
+      state: var_state,
+      heartbeat: var_heartbeat,
+      emittedAtMs: var_emittedAtMs,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    CoreHandle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as CoreHandleImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    ImageBuffer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ImageBufferImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
+    LateraError self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as LateraErrorImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    CoreHandle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as CoreHandleImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    ImageBuffer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ImageBufferImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_CastedPrimitive_i_64(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(sseEncodeCastedPrimitiveI64(self), serializer);
+  }
+
+  @protected
+  void sse_encode_CastedPrimitive_u_64(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(sseEncodeCastedPrimitiveU64(self), serializer);
+  }
+
+  @protected
+  void sse_encode_CastedPrimitive_usize(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(sseEncodeCastedPrimitiveU64(self), serializer);
+  }
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    CoreHandle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as CoreHandleImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    ImageBuffer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as ImageBufferImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
+    LateraError self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as LateraErrorImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_ackable_file_event_Sse(
+    RustStreamSink<AckableFileEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_ackable_file_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_adoption_progress_event_Sse(
+    RustStreamSink<AdoptionProgressEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_adoption_progress_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_dry_run_action_event_Sse(
+    RustStreamSink<DryRunActionEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_dry_run_action_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_duplicate_detected_Sse(
+    RustStreamSink<DuplicateDetected> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_duplicate_detected,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_file_added_event_Sse(
+    RustStreamSink<FileAddedEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_file_added_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_file_batch_event_Sse(
+    RustStreamSink<FileBatchEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_file_batch_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_file_chunk_event_Sse(
+    RustStreamSink<FileChunkEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_file_chunk_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_file_event_Sse(
+    RustStreamSink<FileEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_file_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_file_hash_event_Sse(
+    RustStreamSink<FileHashEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_file_hash_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_file_op_event_Sse(
+    RustStreamSink<FileOpEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_file_op_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_file_removed_event_Sse(
+    RustStreamSink<FileRemovedEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_file_removed_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_file_status_changed_event_Sse(
+    RustStreamSink<FileStatusChangedEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_file_status_changed_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_heartbeat_event_Sse(
+    RustStreamSink<HeartbeatEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_heartbeat_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_log_record_Sse(
+    RustStreamSink<LogRecord> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_log_record,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_rule_applied_event_Sse(
+    RustStreamSink<RuleAppliedEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_rule_applied_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_screenshot_added_event_Sse(
+    RustStreamSink<ScreenshotAddedEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_screenshot_added_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_watch_status_event_Sse(
+    RustStreamSink<WatchStatusEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_watch_status_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_watcher_status_event_Sse(
+    RustStreamSink<WatcherStatusEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_watcher_status_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_ackable_file_event(
+    AckableFileEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CastedPrimitive_u_64(self.sequence, serializer);
+    sse_encode_String(self.fileName, serializer);
+    sse_encode_String(self.fullPath, serializer);
+    sse_encode_CastedPrimitive_i_64(self.occurredAtMs, serializer);
+    sse_encode_CastedPrimitive_i_64(self.detectedAtMs, serializer);
+    sse_encode_CastedPrimitive_i_64(self.monotonicMs, serializer);
+    sse_encode_CastedPrimitive_i_64(self.createdAtMs, serializer);
+    sse_encode_CastedPrimitive_i_64(self.modifiedAtMs, serializer);
+    sse_encode_CastedPrimitive_u_64(self.sizeBytes, serializer);
+    sse_encode_opt_String(self.extension, serializer);
+    sse_encode_opt_String(self.mimeType, serializer);
+    sse_encode_opt_box_autoadd_api_file_access_info(self.access, serializer);
+  }
+
+  @protected
+  void sse_encode_activity_bucket(
+    ActivityBucket self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CastedPrimitive_i_64(self.startMs, serializer);
+    sse_encode_CastedPrimitive_i_64(self.endMs, serializer);
+    sse_encode_CastedPrimitive_u_64(self.eventCount, serializer);
+    sse_encode_CastedPrimitive_u_64(self.fileCount, serializer);
+    sse_encode_CastedPrimitive_u_64(self.bytes, serializer);
+    sse_encode_list_type_count(self.topTypes, serializer);
+  }
+
+  @protected
+  void sse_encode_adoption_phase(AdoptionPhase self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_adoption_progress_event(
+    AdoptionProgressEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CastedPrimitive_u_64(self.adoptionId, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_adoption_phase(self.phase, serializer);
+    sse_encode_CastedPrimitive_u_64(self.filesDone, serializer);
+    sse_encode_CastedPrimitive_u_64(self.filesTotal, serializer);
+    sse_encode_CastedPrimitive_u_64(self.indexFailures, serializer);
+    sse_encode_opt_String(self.folderId, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_opt_String(self.errorMessage, serializer);
+  }
+
+  @protected
+  void sse_encode_api_archived_file(
+    ApiArchivedFile self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.originalPath, serializer);
+    sse_encode_String(self.archivedPath, serializer);
+    sse_encode_CastedPrimitive_i_64(self.archivedAtMs, serializer);
+    sse_encode_CastedPrimitive_i_64(self.modifiedAtMs, serializer);
+    sse_encode_CastedPrimitive_u_64(self.sizeBytes, serializer);
+  }
+
+  @protected
+  void sse_encode_api_audit_action(
+    ApiAuditAction self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_api_audit_entry(
+    ApiAuditEntry self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CastedPrimitive_i_64(self.id, serializer);
+    sse_encode_CastedPrimitive_i_64(self.atMs, serializer);
+    sse_encode_api_audit_action(self.action, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_opt_String(self.target, serializer);
+    sse_encode_opt_String(self.ruleId, serializer);
+  }
+
+  @protected
+  void sse_encode_api_claim_status(
+    ApiClaimStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case ApiClaimStatus_Claimed():
+        sse_encode_i_32(0, serializer);
+      case ApiClaimStatus_ClaimedByOther(
+        owner: final owner,
+        claimedAtMs: final claimedAtMs,
+      ):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(owner, serializer);
+        sse_encode_CastedPrimitive_i_64(claimedAtMs, serializer);
+      case ApiClaimStatus_Unavailable(reason: final reason):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(reason, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_api_code_format(
+    ApiCodeFormat self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_api_config(ApiConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.configPath, serializer);
+    sse_encode_u_32(self.dedupWindowMs, serializer);
+    sse_encode_u_32(self.rateLimitPerSecond, serializer);
+    sse_encode_list_String(self.watchPaths, serializer);
+    sse_encode_watch_filter(self.filter, serializer);
+    sse_encode_opt_String(self.logLevel, serializer);
+  }
+
+  @protected
+  void sse_encode_api_config_patch(
+    ApiConfigPatch self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_32(self.dedupWindowMs, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.rateLimitPerSecond, serializer);
+    sse_encode_opt_list_String(self.watchPaths, serializer);
+    sse_encode_opt_box_autoadd_watch_filter(self.filter, serializer);
+    sse_encode_opt_String(self.logLevel, serializer);
+  }
+
+  @protected
+  void sse_encode_api_detected_code(
+    ApiDetectedCode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_api_code_format(self.format, serializer);
+    sse_encode_String(self.text, serializer);
+  }
+
+  @protected
+  void sse_encode_api_email_attachment(
+    ApiEmailAttachment self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.fileName, serializer);
+    sse_encode_CastedPrimitive_u_64(self.sizeBytes, serializer);
+    sse_encode_opt_String(self.mimeType, serializer);
+  }
+
+  @protected
+  void sse_encode_api_email_metadata(
+    ApiEmailMetadata self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.subject, serializer);
+    sse_encode_opt_String(self.from, serializer);
+    sse_encode_list_String(self.to, serializer);
+    sse_encode_list_String(self.cc, serializer);
+    sse_encode_opt_CastedPrimitive_i_64(self.dateMs, serializer);
+    sse_encode_opt_String(self.messageId, serializer);
+    sse_encode_list_api_email_attachment(self.attachments, serializer);
+  }
+
+  @protected
+  void sse_encode_api_embedding_vector(
+    ApiEmbeddingVector self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.chunkIndex, serializer);
+    sse_encode_list_prim_f_32_strict(self.vector, serializer);
+  }
+
+  @protected
+  void sse_encode_api_file_access_info(
+    ApiFileAccessInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.ownerName, serializer);
+    sse_encode_opt_String(self.ownerId, serializer);
+    sse_encode_bool(self.readable, serializer);
+    sse_encode_bool(self.writable, serializer);
+    sse_encode_bool(self.executable, serializer);
+  }
+
+  @protected
+  void sse_encode_api_journal_entry(
+    ApiJournalEntry self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CastedPrimitive_i_64(self.id, serializer);
+    sse_encode_String(self.watcherId, serializer);
+    sse_encode_file_event_kind(self.kind, serializer);
+    sse_encode_String(self.fullPath, serializer);
+    sse_encode_opt_String(self.previousPath, serializer);
+    sse_encode_CastedPrimitive_i_64(self.occurredAtMs, serializer);
+    sse_encode_CastedPrimitive_i_64(self.detectedAtMs, serializer);
+    sse_encode_CastedPrimitive_u_64(self.sizeBytes, serializer);
+    sse_encode_opt_String(self.mimeType, serializer);
+    sse_encode_bool(self.selfGenerated, serializer);
+  }
+
+  @protected
+  void sse_encode_api_locked_option(
+    ApiLockedOption self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_api_rag_source(ApiRagSource self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.filePath, serializer);
+    sse_encode_String(self.chunkSnippet, serializer);
+    sse_encode_u_32(self.chunkOffset, serializer);
+  }
+
+  @protected
+  void sse_encode_api_similarity_result(
+    ApiSimilarityResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.filePath, serializer);
+    sse_encode_String(self.fileName, serializer);
+    sse_encode_String(self.chunkSnippet, serializer);
+    sse_encode_u_32(self.chunkOffset, serializer);
+    sse_encode_f_64(self.score, serializer);
+  }
+
+  @protected
+  void sse_encode_api_telemetry_counter(
+    ApiTelemetryCounter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.day, serializer);
+    sse_encode_String(self.kind, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_CastedPrimitive_u_64(self.count, serializer);
+  }
+
+  @protected
+  void sse_encode_api_text_chunk(ApiTextChunk self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.text, serializer);
+    sse_encode_u_32(self.chunkIndex, serializer);
+    sse_encode_u_32(self.chunkOffset, serializer);
+  }
+
+  @protected
+  void sse_encode_api_thumbnail(ApiThumbnail self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.cachePath, serializer);
+    sse_encode_String(self.mimeType, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+  }
+
+  @protected
+  void sse_encode_api_watch_backend(
+    ApiWatchBackend self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_api_watch_mode(ApiWatchMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_api_watcher_error_code(
+    ApiWatcherErrorCode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_api_watcher_state(
+    ApiWatcherState self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case ApiWatcherState_Starting():
+        sse_encode_i_32(0, serializer);
+      case ApiWatcherState_Running():
+        sse_encode_i_32(1, serializer);
+      case ApiWatcherState_Degraded(reason: final reason):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(reason, serializer);
+      case ApiWatcherState_Stopped():
+        sse_encode_i_32(3, serializer);
+      case ApiWatcherState_Error(code: final code, message: final message):
+        sse_encode_i_32(4, serializer);
+        sse_encode_api_watcher_error_code(code, serializer);
+        sse_encode_String(message, serializer);
+      case ApiWatcherState_Restarting(
+        attempt: final attempt,
+        retryInMs: final retryInMs,
+        reason: final reason,
+      ):
+        sse_encode_i_32(5, serializer);
+        sse_encode_u_32(attempt, serializer);
+        sse_encode_CastedPrimitive_u_64(retryInMs, serializer);
+        sse_encode_String(reason, serializer);
+      case ApiWatcherState_DirLost(path: final path):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(path, serializer);
+      case ApiWatcherState_Fallback(mode: final mode, reason: final reason):
+        sse_encode_i_32(7, serializer);
+        sse_encode_api_watch_mode(mode, serializer);
+        sse_encode_String(reason, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_api_xattr(ApiXattr self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_list_prim_u_8_strict(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_archive_options(
+    ArchiveOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.olderThanDays, serializer);
+    sse_encode_opt_String(self.archiveDir, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.checkIntervalMinutes, serializer);
+  }
+
+  @protected
+  void sse_encode_audit_log_filter(
+    AuditLogFilter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_api_audit_action(self.action, serializer);
+    sse_encode_opt_String(self.path, serializer);
+    sse_encode_opt_String(self.ruleId, serializer);
+    sse_encode_opt_CastedPrimitive_i_64(self.sinceMs, serializer);
+    sse_encode_opt_CastedPrimitive_i_64(self.untilMs, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.limit, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_api_archived_file(
+    ApiArchivedFile self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_api_archived_file(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_api_audit_action(
+    ApiAuditAction self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_api_audit_action(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_api_claim_status(
+    ApiClaimStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_api_claim_status(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_api_config_patch(
+    ApiConfigPatch self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_api_config_patch(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_api_file_access_info(
+    ApiFileAccessInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_api_file_access_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_archive_options(
+    ArchiveOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_archive_options(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_audit_log_filter(
+    AuditLogFilter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audit_log_filter(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
   }
 
   @protected
-  LateraError
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
-    dynamic raw,
+  void sse_encode_box_autoadd_core_config(
+    CoreConfig self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LateraErrorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_core_config(self, serializer);
   }
 
   @protected
-  int dco_decode_CastedPrimitive_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError(
-      'Not implemented in this codec, please use the other one',
-    );
+  void sse_encode_box_autoadd_event_debug_info(
+    EventDebugInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_event_debug_info(self, serializer);
   }
 
   @protected
-  LateraError
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
-    dynamic raw,
+  void sse_encode_box_autoadd_extraction_options(
+    ExtractionOptions self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LateraErrorImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_extraction_options(self, serializer);
   }
 
   @protected
-  RustStreamSink<FileAddedEvent> dco_decode_StreamSink_file_added_event_Sse(
-    dynamic raw,
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_file_processing_status(
+    FileProcessingStatus self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_file_processing_status(self, serializer);
   }
 
   @protected
-  RustStreamSink<FileRemovedEvent> dco_decode_StreamSink_file_removed_event_Sse(
-    dynamic raw,
+  void sse_encode_box_autoadd_file_rule(
+    FileRule self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_file_rule(self, serializer);
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  void sse_encode_box_autoadd_hash_algorithm(
+    HashAlgorithm self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_hash_algorithm(self, serializer);
   }
 
   @protected
-  ApiEmbeddingVector dco_decode_api_embedding_vector(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return ApiEmbeddingVector(
-      chunkIndex: dco_decode_u_32(arr[0]),
-      vector: dco_decode_list_prim_f_32_strict(arr[1]),
-    );
+  void sse_encode_box_autoadd_log_file_options(
+    LogFileOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_log_file_options(self, serializer);
   }
 
   @protected
-  ApiRagSource dco_decode_api_rag_source(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return ApiRagSource(
-      filePath: dco_decode_String(arr[0]),
-      chunkSnippet: dco_decode_String(arr[1]),
-      chunkOffset: dco_decode_u_32(arr[2]),
-    );
+  void sse_encode_box_autoadd_ocr_options(
+    OcrOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_ocr_options(self, serializer);
   }
 
   @protected
-  ApiSimilarityResult dco_decode_api_similarity_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return ApiSimilarityResult(
-      filePath: dco_decode_String(arr[0]),
-      fileName: dco_decode_String(arr[1]),
-      chunkSnippet: dco_decode_String(arr[2]),
-      chunkOffset: dco_decode_u_32(arr[3]),
-      score: dco_decode_f_64(arr[4]),
-    );
+  void sse_encode_box_autoadd_onboarding_step(
+    OnboardingStep self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_onboarding_step(self, serializer);
   }
 
   @protected
-  ApiTextChunk dco_decode_api_text_chunk(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return ApiTextChunk(
-      text: dco_decode_String(arr[0]),
-      chunkIndex: dco_decode_u_32(arr[1]),
-      chunkOffset: dco_decode_u_32(arr[2]),
-    );
+  void sse_encode_box_autoadd_transcription_options(
+    TranscriptionOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_transcription_options(self, serializer);
   }
 
   @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
   }
 
   @protected
-  ExtractionOptions dco_decode_box_autoadd_extraction_options(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_extraction_options(raw);
+  void sse_encode_box_autoadd_watch_filter(
+    WatchFilter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_watch_filter(self, serializer);
   }
 
   @protected
-  double dco_decode_box_autoadd_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  void sse_encode_box_autoadd_watch_tuning(
+    WatchTuning self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_watch_tuning(self, serializer);
   }
 
   @protected
-  OcrOptions dco_decode_box_autoadd_ocr_options(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_ocr_options(raw);
+  void sse_encode_box_autoadd_watcher_degradation_policy(
+    WatcherDegradationPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_watcher_degradation_policy(self, serializer);
   }
 
   @protected
-  TranscriptionOptions dco_decode_box_autoadd_transcription_options(
-    dynamic raw,
+  void sse_encode_category_stats(CategoryStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_file_category(self.category, serializer);
+    sse_encode_CastedPrimitive_u_64(self.fileCount, serializer);
+    sse_encode_CastedPrimitive_u_64(self.totalBytes, serializer);
+  }
+
+  @protected
+  void sse_encode_config_import_result(
+    ConfigImportResult self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_transcription_options(raw);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.watcherIds, serializer);
+    sse_encode_list_String(self.skippedWatchers, serializer);
   }
 
   @protected
-  ExtractionOptions dco_decode_extraction_options(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return ExtractionOptions(
-      maxPagesPerPdf: dco_decode_u_32(arr[0]),
-      maxFileSizeMb: dco_decode_u_32(arr[1]),
-    );
+  void sse_encode_controlled_folder_access_info(
+    ControlledFolderAccessInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_controlled_folder_access_status(self.status, serializer);
+    sse_encode_String(self.appPath, serializer);
+    sse_encode_String(self.settingsUri, serializer);
+    sse_encode_list_String(self.steps, serializer);
   }
 
   @protected
-  ExtractionResult dco_decode_extraction_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return ExtractionResult(
-      text: dco_decode_String(arr[0]),
-      contentType: dco_decode_String(arr[1]),
-      pagesExtracted: dco_decode_u_32(arr[2]),
-      errorCode: dco_decode_opt_String(arr[3]),
-    );
+  void sse_encode_controlled_folder_access_status(
+    ControlledFolderAccessStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  void sse_encode_core_capabilities(
+    CoreCapabilities self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.coreVersion, serializer);
+    sse_encode_String(self.platform, serializer);
+    sse_encode_String(self.dataDir, serializer);
+    sse_encode_bool(self.indexReady, serializer);
+    sse_encode_bool(self.ocrAvailable, serializer);
+    sse_encode_bool(self.hasAvx2, serializer);
+    sse_encode_bool(self.hasVulkan, serializer);
+    sse_encode_CastedPrimitive_u_64(self.totalRamMb, serializer);
   }
 
   @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  void sse_encode_core_config(CoreConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.dataDir, serializer);
+    sse_encode_opt_String(self.indexDbPath, serializer);
   }
 
   @protected
-  FileAddedEvent dco_decode_file_added_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return FileAddedEvent(
-      fileName: dco_decode_String(arr[0]),
-      fullPath: dco_decode_String(arr[1]),
-      occurredAtMs: dco_decode_CastedPrimitive_i_64(arr[2]),
-    );
+  void sse_encode_desktop_access_status(
+    DesktopAccessStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  FileRemovedEvent dco_decode_file_removed_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return FileRemovedEvent(
-      fileName: dco_decode_String(arr[0]),
-      fullPath: dco_decode_String(arr[1]),
-      occurredAtMs: dco_decode_CastedPrimitive_i_64(arr[2]),
-    );
+  void sse_encode_dir_size_info(DirSizeInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CastedPrimitive_u_64(self.totalBytes, serializer);
+    sse_encode_CastedPrimitive_u_64(self.fileCount, serializer);
+    sse_encode_CastedPrimitive_u_64(self.dirCount, serializer);
   }
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
+  void sse_encode_dry_run_action_event(
+    DryRunActionEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_api_audit_action(self.action, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_opt_String(self.target, serializer);
+    sse_encode_String(self.ruleId, serializer);
+    sse_encode_CastedPrimitive_i_64(self.plannedAtMs, serializer);
   }
 
   @protected
-  List<ApiEmbeddingVector> dco_decode_list_api_embedding_vector(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_api_embedding_vector).toList();
+  void sse_encode_duplicate_detected(
+    DuplicateDetected self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.watcherId, serializer);
+    sse_encode_String(self.newPath, serializer);
+    sse_encode_String(self.existingPath, serializer);
+  }
+
+  @protected
+  void sse_encode_duplicate_group(
+    DuplicateGroup self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CastedPrimitive_u_64(self.sizeBytes, serializer);
+    sse_encode_String(self.hash, serializer);
+    sse_encode_list_String(self.paths, serializer);
+  }
+
+  @protected
+  void sse_encode_event_debug_info(
+    EventDebugInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CastedPrimitive_u_64(self.sequence, serializer);
+    sse_encode_list_String(self.notifyKinds, serializer);
+    sse_encode_list_String(self.decisions, serializer);
+    sse_encode_CastedPrimitive_i_64(self.firstSeenMonotonicMs, serializer);
+    sse_encode_CastedPrimitive_u_64(self.metadataMs, serializer);
+    sse_encode_CastedPrimitive_u_64(self.heldMs, serializer);
+    sse_encode_CastedPrimitive_i_64(self.deliveredMonotonicMs, serializer);
+  }
+
+  @protected
+  void sse_encode_event_timestamp_source(
+    EventTimestampSource self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_extraction_options(
+    ExtractionOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.maxPagesPerPdf, serializer);
+    sse_encode_u_32(self.maxFileSizeMb, serializer);
+  }
+
+  @protected
+  void sse_encode_extraction_result(
+    ExtractionResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.text, serializer);
+    sse_encode_String(self.contentType, serializer);
+    sse_encode_u_32(self.pagesExtracted, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+  }
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat32(self);
   }
 
   @protected
-  List<ApiRagSource> dco_decode_list_api_rag_source(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_api_rag_source).toList();
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
   }
 
   @protected
-  List<ApiSimilarityResult> dco_decode_list_api_similarity_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_api_similarity_result)
-        .toList();
+  void sse_encode_file_added_event(
+    FileAddedEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.fileName, serializer);
+    sse_encode_String(self.fullPath, serializer);
+    sse_encode_CastedPrimitive_i_64(self.occurredAtMs, serializer);
+    sse_encode_CastedPrimitive_i_64(self.detectedAtMs, serializer);
+    sse_encode_CastedPrimitive_i_64(self.monotonicMs, serializer);
+    sse_encode_CastedPrimitive_i_64(self.createdAtMs, serializer);
+    sse_encode_CastedPrimitive_i_64(self.modifiedAtMs, serializer);
+    sse_encode_CastedPrimitive_u_64(self.sizeBytes, serializer);
+    sse_encode_opt_String(self.extension, serializer);
+    sse_encode_opt_String(self.mimeType, serializer);
+    sse_encode_opt_box_autoadd_api_file_access_info(self.access, serializer);
   }
 
   @protected
-  List<ApiTextChunk> dco_decode_list_api_text_chunk(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_api_text_chunk).toList();
+  void sse_encode_file_batch_event(
+    FileBatchEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.watcherId, serializer);
+    sse_encode_list_file_added_event(self.events, serializer);
   }
 
   @protected
-  Float32List dco_decode_list_prim_f_32_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Float32List;
+  void sse_encode_file_category(FileCategory self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_file_chunk_event(
+    FileChunkEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.watcherId, serializer);
+    sse_encode_u_32(self.count, serializer);
+    sse_encode_list_prim_u_8_strict(self.payload, serializer);
   }
 
   @protected
-  List<SearchResultItem> dco_decode_list_search_result_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_search_result_item).toList();
+  void sse_encode_file_event(FileEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CastedPrimitive_u_64(self.sequence, serializer);
+    sse_encode_String(self.watcherId, serializer);
+    sse_encode_file_event_kind(self.kind, serializer);
+    sse_encode_String(self.fileName, serializer);
+    sse_encode_String(self.fullPath, serializer);
+    sse_encode_opt_String(self.previousPath, serializer);
+    sse_encode_CastedPrimitive_i_64(self.occurredAtMs, serializer);
+    sse_encode_CastedPrimitive_i_64(self.detectedAtMs, serializer);
+    sse_encode_CastedPrimitive_i_64(self.monotonicMs, serializer);
+    sse_encode_CastedPrimitive_i_64(self.createdAtMs, serializer);
+    sse_encode_CastedPrimitive_i_64(self.modifiedAtMs, serializer);
+    sse_encode_CastedPrimitive_u_64(self.sizeBytes, serializer);
+    sse_encode_opt_String(self.extension, serializer);
+    sse_encode_opt_String(self.mimeType, serializer);
+    sse_encode_opt_box_autoadd_api_file_access_info(self.access, serializer);
+    sse_encode_bool(self.selfGenerated, serializer);
+    sse_encode_bool(self.reconciled, serializer);
+    sse_encode_opt_box_autoadd_api_claim_status(self.claim, serializer);
+    sse_encode_list_api_detected_code(self.codes, serializer);
+  }
+
+  @protected
+  void sse_encode_file_event_kind(
+    FileEventKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  OcrOptions dco_decode_ocr_options(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return OcrOptions(
-      maxPagesPerPdf: dco_decode_u_32(arr[0]),
-      maxFileSizeMb: dco_decode_u_32(arr[1]),
-      language: dco_decode_opt_String(arr[2]),
-    );
+  void sse_encode_file_hash_event(
+    FileHashEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.watcherId, serializer);
+    sse_encode_String(self.fullPath, serializer);
+    sse_encode_hash_algorithm(self.algorithm, serializer);
+    sse_encode_String(self.hash, serializer);
   }
 
   @protected
-  OcrResult dco_decode_ocr_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return OcrResult(
-      text: dco_decode_String(arr[0]),
-      contentType: dco_decode_String(arr[1]),
-      pagesProcessed: dco_decode_u_32(arr[2]),
-      confidence: dco_decode_opt_box_autoadd_f_64(arr[3]),
-      errorCode: dco_decode_opt_String(arr[4]),
-    );
+  void sse_encode_file_op_event(FileOpEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_CastedPrimitive_u_64(self.operationId, serializer);
+    sse_encode_file_op_kind(self.kind, serializer);
+    sse_encode_String(self.src, serializer);
+    sse_encode_String(self.dst, serializer);
+    sse_encode_file_op_status(self.status, serializer);
+    sse_encode_CastedPrimitive_u_64(self.bytesDone, serializer);
+    sse_encode_CastedPrimitive_u_64(self.bytesTotal, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_opt_String(self.errorMessage, serializer);
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_file_op_kind(FileOpKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  void sse_encode_file_op_status(FileOpStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  RagQueryResult dco_decode_rag_query_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return RagQueryResult(
-      answer: dco_decode_String(arr[0]),
-      errorCode: dco_decode_opt_String(arr[1]),
-      sources: dco_decode_list_api_rag_source(arr[2]),
-    );
+  void sse_encode_file_processing_status(
+    FileProcessingStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  SearchResultItem dco_decode_search_result_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return SearchResultItem(
-      filePath: dco_decode_String(arr[0]),
-      fileName: dco_decode_String(arr[1]),
-      description: dco_decode_String(arr[2]),
-      snippet: dco_decode_String(arr[3]),
-      rank: dco_decode_f_64(arr[4]),
-    );
+  void sse_encode_file_removed_event(
+    FileRemovedEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.fileName, serializer);
+    sse_encode_String(self.fullPath, serializer);
+    sse_encode_CastedPrimitive_i_64(self.occurredAtMs, serializer);
   }
 
   @protected
-  TranscriptionOptions dco_decode_transcription_options(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return TranscriptionOptions(
-      maxMediaMinutes: dco_decode_u_32(arr[0]),
-      maxFileSizeMb: dco_decode_u_32(arr[1]),
-      language: dco_decode_opt_String(arr[2]),
-    );
+  void sse_encode_file_rule(FileRule self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_list_String(self.nameGlobs, serializer);
+    sse_encode_list_String(self.mimeTypes, serializer);
+    sse_encode_opt_CastedPrimitive_u_64(self.minSizeBytes, serializer);
+    sse_encode_opt_CastedPrimitive_u_64(self.maxSizeBytes, serializer);
+    sse_encode_file_rule_action(self.action, serializer);
   }
 
   @protected
-  TranscriptionResult dco_decode_transcription_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return TranscriptionResult(
-      text: dco_decode_String(arr[0]),
-      contentType: dco_decode_String(arr[1]),
-      durationSeconds: dco_decode_u_32(arr[2]),
-      errorCode: dco_decode_opt_String(arr[3]),
-    );
+  void sse_encode_file_rule_action(
+    FileRuleAction self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case FileRuleAction_MoveToSubfolder(subfolder: final subfolder):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(subfolder, serializer);
+      case FileRuleAction_Rename(template: final template):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(template, serializer);
+      case FileRuleAction_Tag(tag: final tag):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(tag, serializer);
+      case FileRuleAction_Ignore():
+        sse_encode_i_32(3, serializer);
+    }
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_file_status_changed_event(
+    FileStatusChangedEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.fullPath, serializer);
+    sse_encode_opt_box_autoadd_file_processing_status(self.status, serializer);
+    sse_encode_CastedPrimitive_i_64(self.updatedAtMs, serializer);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_file_status_update(
+    FileStatusUpdate self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.fullPath, serializer);
+    sse_encode_file_processing_status(self.status, serializer);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_folder_marker_info(
+    FolderMarkerInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_folder_marker_status(self.status, serializer);
+    sse_encode_opt_String(self.folderId, serializer);
+    sse_encode_opt_CastedPrimitive_i_64(self.createdAtMs, serializer);
+    sse_encode_opt_String(self.settingsHash, serializer);
+    sse_encode_bool(self.settingsMatch, serializer);
+    sse_encode_opt_String(self.error, serializer);
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_folder_marker_status(
+    FolderMarkerStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_hash_algorithm(HashAlgorithm self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  LateraError
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
-    SseDeserializer deserializer,
+  void sse_encode_heartbeat_event(
+    HeartbeatEvent self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return LateraErrorImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_CastedPrimitive_u_64(self.sequence, serializer);
+    sse_encode_CastedPrimitive_i_64(self.emittedAtMs, serializer);
+    sse_encode_CastedPrimitive_u_64(self.uptimeMs, serializer);
+    sse_encode_bool(self.watcherRunning, serializer);
   }
 
   @protected
-  int sse_decode_CastedPrimitive_i_64(SseDeserializer deserializer) {
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_64(deserializer);
-    return inner.toInt();
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
-  LateraError
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
-    SseDeserializer deserializer,
+  void sse_encode_integrity_issue(
+    IntegrityIssue self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return LateraErrorImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
+    sse_encode_String(self.watcherId, serializer);
+    sse_encode_integrity_issue_kind(self.kind, serializer);
+    sse_encode_String(self.fullPath, serializer);
   }
 
   @protected
-  RustStreamSink<FileAddedEvent> sse_decode_StreamSink_file_added_event_Sse(
-    SseDeserializer deserializer,
+  void sse_encode_integrity_issue_kind(
+    IntegrityIssueKind self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  RustStreamSink<FileRemovedEvent> sse_decode_StreamSink_file_removed_event_Sse(
-    SseDeserializer deserializer,
+  void sse_encode_latera_api_error(
+    LateraApiError self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+    sse_encode_String(self.code, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_bool(self.recoverable, serializer);
+    sse_encode_opt_String(self.context, serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void
+  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    List<ImageBuffer> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(item, serializer);
+    }
   }
 
   @protected
-  ApiEmbeddingVector sse_decode_api_embedding_vector(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_chunkIndex = sse_decode_u_32(deserializer);
-    var var_vector = sse_decode_list_prim_f_32_strict(deserializer);
-    return ApiEmbeddingVector(chunkIndex: var_chunkIndex, vector: var_vector);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
   }
 
   @protected
-  ApiRagSource sse_decode_api_rag_source(SseDeserializer deserializer) {
+  void sse_encode_list_activity_bucket(
+    List<ActivityBucket> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_filePath = sse_decode_String(deserializer);
-    var var_chunkSnippet = sse_decode_String(deserializer);
-    var var_chunkOffset = sse_decode_u_32(deserializer);
-    return ApiRagSource(
-      filePath: var_filePath,
-      chunkSnippet: var_chunkSnippet,
-      chunkOffset: var_chunkOffset,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_activity_bucket(item, serializer);
+    }
   }
 
   @protected
-  ApiSimilarityResult sse_decode_api_similarity_result(
-    SseDeserializer deserializer,
+  void sse_encode_list_api_archived_file(
+    List<ApiArchivedFile> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_filePath = sse_decode_String(deserializer);
-    var var_fileName = sse_decode_String(deserializer);
-    var var_chunkSnippet = sse_decode_String(deserializer);
-    var var_chunkOffset = sse_decode_u_32(deserializer);
-    var var_score = sse_decode_f_64(deserializer);
-    return ApiSimilarityResult(
-      filePath: var_filePath,
-      fileName: var_fileName,
-      chunkSnippet: var_chunkSnippet,
-      chunkOffset: var_chunkOffset,
-      score: var_score,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_archived_file(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_api_audit_entry(
+    List<ApiAuditEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_audit_entry(item, serializer);
+    }
   }
 
   @protected
-  ApiTextChunk sse_decode_api_text_chunk(SseDeserializer deserializer) {
+  void sse_encode_list_api_detected_code(
+    List<ApiDetectedCode> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_text = sse_decode_String(deserializer);
-    var var_chunkIndex = sse_decode_u_32(deserializer);
-    var var_chunkOffset = sse_decode_u_32(deserializer);
-    return ApiTextChunk(
-      text: var_text,
-      chunkIndex: var_chunkIndex,
-      chunkOffset: var_chunkOffset,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_detected_code(item, serializer);
+    }
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_list_api_email_attachment(
+    List<ApiEmailAttachment> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_email_attachment(item, serializer);
+    }
   }
 
   @protected
-  ExtractionOptions sse_decode_box_autoadd_extraction_options(
-    SseDeserializer deserializer,
+  void sse_encode_list_api_embedding_vector(
+    List<ApiEmbeddingVector> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_extraction_options(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_embedding_vector(item, serializer);
+    }
   }
 
   @protected
-  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+  void sse_encode_list_api_journal_entry(
+    List<ApiJournalEntry> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_f_64(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_journal_entry(item, serializer);
+    }
   }
 
   @protected
-  OcrOptions sse_decode_box_autoadd_ocr_options(SseDeserializer deserializer) {
+  void sse_encode_list_api_locked_option(
+    List<ApiLockedOption> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_ocr_options(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_locked_option(item, serializer);
+    }
   }
 
   @protected
-  TranscriptionOptions sse_decode_box_autoadd_transcription_options(
-    SseDeserializer deserializer,
+  void sse_encode_list_api_rag_source(
+    List<ApiRagSource> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_transcription_options(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_rag_source(item, serializer);
+    }
   }
 
   @protected
-  ExtractionOptions sse_decode_extraction_options(
-    SseDeserializer deserializer,
+  void sse_encode_list_api_similarity_result(
+    List<ApiSimilarityResult> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_maxPagesPerPdf = sse_decode_u_32(deserializer);
-    var var_maxFileSizeMb = sse_decode_u_32(deserializer);
-    return ExtractionOptions(
-      maxPagesPerPdf: var_maxPagesPerPdf,
-      maxFileSizeMb: var_maxFileSizeMb,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_similarity_result(item, serializer);
+    }
   }
 
   @protected
-  ExtractionResult sse_decode_extraction_result(SseDeserializer deserializer) {
+  void sse_encode_list_api_telemetry_counter(
+    List<ApiTelemetryCounter> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_text = sse_decode_String(deserializer);
-    var var_contentType = sse_decode_String(deserializer);
-    var var_pagesExtracted = sse_decode_u_32(deserializer);
-    var var_errorCode = sse_decode_opt_String(deserializer);
-    return ExtractionResult(
-      text: var_text,
-      contentType: var_contentType,
-      pagesExtracted: var_pagesExtracted,
-      errorCode: var_errorCode,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_telemetry_counter(item, serializer);
+    }
   }
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
+  void sse_encode_list_api_text_chunk(
+    List<ApiTextChunk> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_text_chunk(item, serializer);
+    }
   }
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
+  void sse_encode_list_api_xattr(
+    List<ApiXattr> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_api_xattr(item, serializer);
+    }
   }
 
   @protected
-  FileAddedEvent sse_decode_file_added_event(SseDeserializer deserializer) {
+  void sse_encode_list_category_stats(
+    List<CategoryStats> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_fileName = sse_decode_String(deserializer);
-    var var_fullPath = sse_decode_String(deserializer);
-    var var_occurredAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
-    return FileAddedEvent(
-      fileName: var_fileName,
-      fullPath: var_fullPath,
-      occurredAtMs: var_occurredAtMs,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_category_stats(item, serializer);
+    }
   }
 
   @protected
-  FileRemovedEvent sse_decode_file_removed_event(SseDeserializer deserializer) {
+  void sse_encode_list_duplicate_group(
+    List<DuplicateGroup> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_fileName = sse_decode_String(deserializer);
-    var var_fullPath = sse_decode_String(deserializer);
-    var var_occurredAtMs = sse_decode_CastedPrimitive_i_64(deserializer);
-    return FileRemovedEvent(
-      fileName: var_fileName,
-      fullPath: var_fullPath,
-      occurredAtMs: var_occurredAtMs,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_duplicate_group(item, serializer);
+    }
   }
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+  void sse_encode_list_file_added_event(
+    List<FileAddedEvent> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_file_added_event(item, serializer);
+    }
   }
 
   @protected
-  List<ApiEmbeddingVector> sse_decode_list_api_embedding_vector(
-    SseDeserializer deserializer,
+  void sse_encode_list_file_rule(
+    List<FileRule> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ApiEmbeddingVector>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_api_embedding_vector(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_file_rule(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<ApiRagSource> sse_decode_list_api_rag_source(
-    SseDeserializer deserializer,
+  void sse_encode_list_file_status_update(
+    List<FileStatusUpdate> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ApiRagSource>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_api_rag_source(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_file_status_update(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<ApiSimilarityResult> sse_decode_list_api_similarity_result(
-    SseDeserializer deserializer,
+  void sse_encode_list_integrity_issue(
+    List<IntegrityIssue> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ApiSimilarityResult>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_api_similarity_result(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_integrity_issue(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<ApiTextChunk> sse_decode_list_api_text_chunk(
-    SseDeserializer deserializer,
+  void sse_encode_list_onboarding_step(
+    List<OnboardingStep> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ApiTextChunk>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_api_text_chunk(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_onboarding_step(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer) {
+  void sse_encode_list_pdf_page_preview(
+    List<PdfPagePreview> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getFloat32List(len_);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_pdf_page_preview(item, serializer);
+    }
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_list_prim_f_32_strict(
+    Float32List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putFloat32List(self);
   }
 
   @protected
-  List<SearchResultItem> sse_decode_list_search_result_item(
-    SseDeserializer deserializer,
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint32List(self);
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SearchResultItem>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_search_result_item(deserializer));
-    }
-    return ans_;
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  OcrOptions sse_decode_ocr_options(SseDeserializer deserializer) {
+  void sse_encode_list_report_file(
+    List<ReportFile> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_maxPagesPerPdf = sse_decode_u_32(deserializer);
-    var var_maxFileSizeMb = sse_decode_u_32(deserializer);
-    var var_language = sse_decode_opt_String(deserializer);
-    return OcrOptions(
-      maxPagesPerPdf: var_maxPagesPerPdf,
-      maxFileSizeMb: var_maxFileSizeMb,
-      language: var_language,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_report_file(item, serializer);
+    }
   }
 
   @protected
-  OcrResult sse_decode_ocr_result(SseDeserializer deserializer) {
+  void sse_encode_list_search_result_item(
+    List<SearchResultItem> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_text = sse_decode_String(deserializer);
-    var var_contentType = sse_decode_String(deserializer);
-    var var_pagesProcessed = sse_decode_u_32(deserializer);
-    var var_confidence = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_errorCode = sse_decode_opt_String(deserializer);
-    return OcrResult(
-      text: var_text,
-      contentType: var_contentType,
-      pagesProcessed: var_pagesProcessed,
-      confidence: var_confidence,
-      errorCode: var_errorCode,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_search_result_item(item, serializer);
+    }
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_list_type_count(
+    List<TypeCount> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_type_count(item, serializer);
+    }
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
+  @protected
+  void sse_encode_list_watch_location_suggestion(
+    List<WatchLocationSuggestion> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_watch_location_suggestion(item, serializer);
     }
   }
 
   @protected
-  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+  void sse_encode_list_watch_tree_stat(
+    List<WatchTreeStat> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_watch_tree_stat(item, serializer);
+    }
+  }
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_f_64(deserializer));
-    } else {
-      return null;
+  @protected
+  void sse_encode_list_watcher_info(
+    List<WatcherInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_watcher_info(item, serializer);
     }
   }
 
   @protected
-  RagQueryResult sse_decode_rag_query_result(SseDeserializer deserializer) {
+  void sse_encode_log_file_options(
+    LogFileOptions self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_answer = sse_decode_String(deserializer);
-    var var_errorCode = sse_decode_opt_String(deserializer);
-    var var_sources = sse_decode_list_api_rag_source(deserializer);
-    return RagQueryResult(
-      answer: var_answer,
-      errorCode: var_errorCode,
-      sources: var_sources,
-    );
+    sse_encode_CastedPrimitive_u_64(self.maxFileBytes, serializer);
+    sse_encode_u_32(self.maxRotatedFiles, serializer);
+    sse_encode_CastedPrimitive_u_64(self.maxTotalBytes, serializer);
+    sse_encode_bool(self.compress, serializer);
   }
 
   @protected
-  SearchResultItem sse_decode_search_result_item(SseDeserializer deserializer) {
+  void sse_encode_log_record(LogRecord self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_filePath = sse_decode_String(deserializer);
-    var var_fileName = sse_decode_String(deserializer);
-    var var_description = sse_decode_String(deserializer);
-    var var_snippet = sse_decode_String(deserializer);
-    var var_rank = sse_decode_f_64(deserializer);
-    return SearchResultItem(
-      filePath: var_filePath,
-      fileName: var_fileName,
-      description: var_description,
-      snippet: var_snippet,
-      rank: var_rank,
-    );
+    sse_encode_String(self.level, serializer);
+    sse_encode_String(self.target, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_String(self.timestamp, serializer);
+    sse_encode_opt_String(self.correlationId, serializer);
   }
 
   @protected
-  TranscriptionOptions sse_decode_transcription_options(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_ocr_options(OcrOptions self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_maxMediaMinutes = sse_decode_u_32(deserializer);
-    var var_maxFileSizeMb = sse_decode_u_32(deserializer);
-    var var_language = sse_decode_opt_String(deserializer);
-    return TranscriptionOptions(
-      maxMediaMinutes: var_maxMediaMinutes,
-      maxFileSizeMb: var_maxFileSizeMb,
-      language: var_language,
-    );
+    sse_encode_u_32(self.maxPagesPerPdf, serializer);
+    sse_encode_u_32(self.maxFileSizeMb, serializer);
+    sse_encode_opt_String(self.language, serializer);
   }
 
   @protected
-  TranscriptionResult sse_decode_transcription_result(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_ocr_result(OcrResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_text = sse_decode_String(deserializer);
-    var var_contentType = sse_decode_String(deserializer);
-    var var_durationSeconds = sse_decode_u_32(deserializer);
-    var var_errorCode = sse_decode_opt_String(deserializer);
-    return TranscriptionResult(
-      text: var_text,
-      contentType: var_contentType,
-      durationSeconds: var_durationSeconds,
-      errorCode: var_errorCode,
-    );
+    sse_encode_String(self.text, serializer);
+    sse_encode_String(self.contentType, serializer);
+    sse_encode_u_32(self.pagesProcessed, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.confidence, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_onboarding_state(
+    OnboardingState self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_opt_box_autoadd_onboarding_step(self.currentStep, serializer);
+    sse_encode_list_onboarding_step(self.completedSteps, serializer);
+    sse_encode_String(self.folderPath, serializer);
+    sse_encode_bool(self.folderExists, serializer);
+    sse_encode_bool(self.accessGranted, serializer);
+    sse_encode_opt_CastedPrimitive_i_64(self.firstEventAtMs, serializer);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_onboarding_step(
+    OnboardingStep self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_opt_CastedPrimitive_i_64(
+    int? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_CastedPrimitive_i_64(self, serializer);
+    }
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_opt_CastedPrimitive_u_64(
+    int? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_CastedPrimitive_u_64(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_opt_box_autoadd_api_archived_file(
+    ApiArchivedFile? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_api_archived_file(self, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
-    LateraError self,
+  void sse_encode_opt_box_autoadd_api_audit_action(
+    ApiAuditAction? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as LateraErrorImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_api_audit_action(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_CastedPrimitive_i_64(int self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_api_claim_status(
+    ApiClaimStatus? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_64(sseEncodeCastedPrimitiveI64(self), serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_api_claim_status(self, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
-    LateraError self,
+  void sse_encode_opt_box_autoadd_api_file_access_info(
+    ApiFileAccessInfo? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as LateraErrorImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_api_file_access_info(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_StreamSink_file_added_event_Sse(
-    RustStreamSink<FileAddedEvent> self,
+  void sse_encode_opt_box_autoadd_archive_options(
+    ArchiveOptions? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_file_added_event,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_archive_options(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_StreamSink_file_removed_event_Sse(
-    RustStreamSink<FileRemovedEvent> self,
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_event_debug_info(
+    EventDebugInfo? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_file_removed_event,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_event_debug_info(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_api_embedding_vector(
-    ApiEmbeddingVector self,
+  void sse_encode_opt_box_autoadd_file_processing_status(
+    FileProcessingStatus? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.chunkIndex, serializer);
-    sse_encode_list_prim_f_32_strict(self.vector, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_file_processing_status(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_api_rag_source(ApiRagSource self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_hash_algorithm(
+    HashAlgorithm? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.filePath, serializer);
-    sse_encode_String(self.chunkSnippet, serializer);
-    sse_encode_u_32(self.chunkOffset, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_hash_algorithm(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_api_similarity_result(
-    ApiSimilarityResult self,
+  void sse_encode_opt_box_autoadd_onboarding_step(
+    OnboardingStep? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.filePath, serializer);
-    sse_encode_String(self.fileName, serializer);
-    sse_encode_String(self.chunkSnippet, serializer);
-    sse_encode_u_32(self.chunkOffset, serializer);
-    sse_encode_f_64(self.score, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_onboarding_step(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_api_text_chunk(ApiTextChunk self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.text, serializer);
-    sse_encode_u_32(self.chunkIndex, serializer);
-    sse_encode_u_32(self.chunkOffset, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_watch_filter(
+    WatchFilter? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_watch_filter(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_extraction_options(
-    ExtractionOptions self,
+  void sse_encode_opt_box_autoadd_watch_tuning(
+    WatchTuning? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_extraction_options(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_watch_tuning(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_watcher_degradation_policy(
+    WatcherDegradationPolicy? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_watcher_degradation_policy(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_ocr_options(
-    OcrOptions self,
+  void sse_encode_opt_list_String(
+    List<String>? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_ocr_options(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_String(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_transcription_options(
-    TranscriptionOptions self,
+  void sse_encode_pdf_page_preview(
+    PdfPagePreview self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_transcription_options(self, serializer);
+    sse_encode_u_32(self.page, serializer);
+    sse_encode_u_32(self.width, serializer);
+    sse_encode_u_32(self.height, serializer);
+    sse_encode_list_prim_u_8_strict(self.png, serializer);
   }
 
   @protected
-  void sse_encode_extraction_options(
-    ExtractionOptions self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_policy_status(PolicyStatus self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.maxPagesPerPdf, serializer);
-    sse_encode_u_32(self.maxFileSizeMb, serializer);
+    sse_encode_opt_String(self.policyPath, serializer);
+    sse_encode_list_api_locked_option(self.lockedOptions, serializer);
   }
 
   @protected
-  void sse_encode_extraction_result(
-    ExtractionResult self,
+  void sse_encode_rag_query_result(
+    RagQueryResult self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.text, serializer);
-    sse_encode_String(self.contentType, serializer);
-    sse_encode_u_32(self.pagesExtracted, serializer);
+    sse_encode_String(self.answer, serializer);
     sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_list_api_rag_source(self.sources, serializer);
   }
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
+  void sse_encode_report_file(ReportFile self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
+    sse_encode_String(self.fullPath, serializer);
+    sse_encode_CastedPrimitive_u_64(self.sizeBytes, serializer);
+    sse_encode_opt_CastedPrimitive_i_64(self.modifiedAtMs, serializer);
+    sse_encode_file_category(self.category, serializer);
   }
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
+  void sse_encode_rule_applied_event(
+    RuleAppliedEvent self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
+    sse_encode_String(self.watcherId, serializer);
+    sse_encode_String(self.ruleId, serializer);
+    sse_encode_file_rule_action(self.action, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_opt_String(self.newPath, serializer);
+    sse_encode_CastedPrimitive_i_64(self.appliedAtMs, serializer);
   }
 
   @protected
-  void sse_encode_file_added_event(
-    FileAddedEvent self,
+  void sse_encode_screenshot_added_event(
+    ScreenshotAddedEvent self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.watcherId, serializer);
     sse_encode_String(self.fileName, serializer);
     sse_encode_String(self.fullPath, serializer);
     sse_encode_CastedPrimitive_i_64(self.occurredAtMs, serializer);
+    sse_encode_CastedPrimitive_u_64(self.sizeBytes, serializer);
+    sse_encode_opt_String(self.mimeType, serializer);
   }
 
   @protected
-  void sse_encode_file_removed_event(
-    FileRemovedEvent self,
+  void sse_encode_search_result_item(
+    SearchResultItem self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.filePath, serializer);
     sse_encode_String(self.fileName, serializer);
-    sse_encode_String(self.fullPath, serializer);
-    sse_encode_CastedPrimitive_i_64(self.occurredAtMs, serializer);
+    sse_encode_String(self.description, serializer);
+    sse_encode_String(self.snippet, serializer);
+    sse_encode_f_64(self.rank, serializer);
   }
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+  void sse_encode_telemetry_preview(
+    TelemetryPreview self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_list_api_telemetry_counter(self.counters, serializer);
+    sse_encode_String(self.payloadJson, serializer);
   }
 
   @protected
-  void sse_encode_list_api_embedding_vector(
-    List<ApiEmbeddingVector> self,
+  void sse_encode_timeline_granularity(
+    TimelineGranularity self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_api_embedding_vector(item, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_list_api_rag_source(
-    List<ApiRagSource> self,
+  void sse_encode_transcription_options(
+    TranscriptionOptions self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_api_rag_source(item, serializer);
-    }
+    sse_encode_u_32(self.maxMediaMinutes, serializer);
+    sse_encode_u_32(self.maxFileSizeMb, serializer);
+    sse_encode_opt_String(self.language, serializer);
   }
 
   @protected
-  void sse_encode_list_api_similarity_result(
-    List<ApiSimilarityResult> self,
+  void sse_encode_transcription_result(
+    TranscriptionResult self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_api_similarity_result(item, serializer);
-    }
+    sse_encode_String(self.text, serializer);
+    sse_encode_String(self.contentType, serializer);
+    sse_encode_u_32(self.durationSeconds, serializer);
+    sse_encode_opt_String(self.errorCode, serializer);
   }
 
   @protected
-  void sse_encode_list_api_text_chunk(
-    List<ApiTextChunk> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_type_count(TypeCount self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_api_text_chunk(item, serializer);
-    }
+    sse_encode_String(self.mimeType, serializer);
+    sse_encode_CastedPrimitive_u_64(self.count, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_f_32_strict(
-    Float32List self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putFloat32List(self);
+    serializer.buffer.putUint32(self);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    serializer.buffer.putBigUint64(self);
   }
 
   @protected
-  void sse_encode_list_search_result_item(
-    List<SearchResultItem> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_search_result_item(item, serializer);
-    }
+    serializer.buffer.putUint8(self);
   }
 
   @protected
-  void sse_encode_ocr_options(OcrOptions self, SseSerializer serializer) {
+  void sse_encode_unit(void self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.maxPagesPerPdf, serializer);
-    sse_encode_u_32(self.maxFileSizeMb, serializer);
-    sse_encode_opt_String(self.language, serializer);
   }
 
   @protected
-  void sse_encode_ocr_result(OcrResult self, SseSerializer serializer) {
+  void sse_encode_usize(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.text, serializer);
-    sse_encode_String(self.contentType, serializer);
-    sse_encode_u_32(self.pagesProcessed, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.confidence, serializer);
-    sse_encode_opt_String(self.errorCode, serializer);
+    serializer.buffer.putBigUint64(self);
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_volume_info(VolumeInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_volume_kind(self.kind, serializer);
+    sse_encode_opt_String(self.mountPoint, serializer);
+    sse_encode_String(self.fileSystem, serializer);
+    sse_encode_opt_box_autoadd_bool(self.isSsd, serializer);
+    sse_encode_opt_String(self.cloudProvider, serializer);
+    sse_encode_CastedPrimitive_u_64(self.pollIntervalMs, serializer);
+    sse_encode_opt_CastedPrimitive_u_64(self.settleQuietPeriodMs, serializer);
+    sse_encode_bool(self.hashingEnabled, serializer);
+  }
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
+  @protected
+  void sse_encode_volume_kind(VolumeKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
+  void sse_encode_watch_dir_recovery_mode(
+    WatchDirRecoveryMode self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_f_64(self, serializer);
-    }
+  @protected
+  void sse_encode_watch_filter(WatchFilter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.includeGlobs, serializer);
+    sse_encode_list_String(self.excludeGlobs, serializer);
+    sse_encode_list_String(self.extensions, serializer);
+    sse_encode_bool(self.includeHidden, serializer);
   }
 
   @protected
-  void sse_encode_rag_query_result(
-    RagQueryResult self,
+  void sse_encode_watch_location_kind(
+    WatchLocationKind self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.answer, serializer);
-    sse_encode_opt_String(self.errorCode, serializer);
-    sse_encode_list_api_rag_source(self.sources, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_search_result_item(
-    SearchResultItem self,
+  void sse_encode_watch_location_suggestion(
+    WatchLocationSuggestion self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.filePath, serializer);
-    sse_encode_String(self.fileName, serializer);
-    sse_encode_String(self.description, serializer);
-    sse_encode_String(self.snippet, serializer);
-    sse_encode_f_64(self.rank, serializer);
+    sse_encode_watch_location_kind(self.kind, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_bool(self.exists, serializer);
+    sse_encode_bool(self.writable, serializer);
+    sse_encode_CastedPrimitive_u_64(self.availableBytes, serializer);
   }
 
   @protected
-  void sse_encode_transcription_options(
-    TranscriptionOptions self,
+  void sse_encode_watch_started(WatchStarted self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.watcherId, serializer);
+    sse_encode_api_watch_mode(self.backend, serializer);
+  }
+
+  @protected
+  void sse_encode_watch_status_event(
+    WatchStatusEvent self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.maxMediaMinutes, serializer);
-    sse_encode_u_32(self.maxFileSizeMb, serializer);
-    sse_encode_opt_String(self.language, serializer);
+    switch (self) {
+      case WatchStatusEvent_LowDiskSpace(
+        availableBytes: final availableBytes,
+        thresholdBytes: final thresholdBytes,
+      ):
+        sse_encode_i_32(0, serializer);
+        sse_encode_CastedPrimitive_u_64(availableBytes, serializer);
+        sse_encode_CastedPrimitive_u_64(thresholdBytes, serializer);
+      case WatchStatusEvent_DiskSpaceRecovered(
+        availableBytes: final availableBytes,
+      ):
+        sse_encode_i_32(1, serializer);
+        sse_encode_CastedPrimitive_u_64(availableBytes, serializer);
+      case WatchStatusEvent_QuotaExceeded(
+        watcherId: final watcherId,
+        totalBytes: final totalBytes,
+        fileCount: final fileCount,
+        maxTotalBytes: final maxTotalBytes,
+        maxFileCount: final maxFileCount,
+        removedFiles: final removedFiles,
+        freedBytes: final freedBytes,
+      ):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(watcherId, serializer);
+        sse_encode_CastedPrimitive_u_64(totalBytes, serializer);
+        sse_encode_CastedPrimitive_u_64(fileCount, serializer);
+        sse_encode_CastedPrimitive_u_64(maxTotalBytes, serializer);
+        sse_encode_CastedPrimitive_u_64(maxFileCount, serializer);
+        sse_encode_CastedPrimitive_u_64(removedFiles, serializer);
+        sse_encode_CastedPrimitive_u_64(freedBytes, serializer);
+      case WatchStatusEvent_NameConflict(
+        fileName: final fileName,
+        newPath: final newPath,
+        newFingerprint: final newFingerprint,
+        existingPath: final existingPath,
+        existingFingerprint: final existingFingerprint,
+      ):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(fileName, serializer);
+        sse_encode_String(newPath, serializer);
+        sse_encode_String(newFingerprint, serializer);
+        sse_encode_String(existingPath, serializer);
+        sse_encode_String(existingFingerprint, serializer);
+      case WatchStatusEvent_SettingsRecovered(
+        backupIndex: final backupIndex,
+        corruptPath: final corruptPath,
+      ):
+        sse_encode_i_32(4, serializer);
+        sse_encode_u_32(backupIndex, serializer);
+        sse_encode_String(corruptPath, serializer);
+      case WatchStatusEvent_WatchDirLost(
+        watcherId: final watcherId,
+        path: final path,
+      ):
+        sse_encode_i_32(5, serializer);
+        sse_encode_String(watcherId, serializer);
+        sse_encode_String(path, serializer);
+      case WatchStatusEvent_WatchDirRestored(
+        watcherId: final watcherId,
+        path: final path,
+      ):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(watcherId, serializer);
+        sse_encode_String(path, serializer);
+    }
   }
 
   @protected
-  void sse_encode_transcription_result(
-    TranscriptionResult self,
+  void sse_encode_watch_tree_stat(
+    WatchTreeStat self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.text, serializer);
-    sse_encode_String(self.contentType, serializer);
-    sse_encode_u_32(self.durationSeconds, serializer);
-    sse_encode_opt_String(self.errorCode, serializer);
+    sse_encode_String(self.relativePath, serializer);
+    sse_encode_CastedPrimitive_u_64(self.eventCount, serializer);
+    sse_encode_CastedPrimitive_i_64(self.lastActivityMs, serializer);
   }
 
   @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
+  void sse_encode_watch_tuning(WatchTuning self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
+    sse_encode_opt_box_autoadd_u_32(self.dedupWindowMs, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.maxEventsPerSecond, serializer);
   }
 
   @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
+  void sse_encode_watcher_degradation_policy(
+    WatcherDegradationPolicy self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
+    sse_encode_u_32(self.nativeFailures, serializer);
+    sse_encode_u_32(self.pollingFailures, serializer);
+    sse_encode_u_32(self.pollIntervalMs, serializer);
   }
 
   @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
+  void sse_encode_watcher_info(WatcherInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.watcherId, serializer);
+    sse_encode_String(self.watchDir, serializer);
+    sse_encode_bool(self.recursive, serializer);
+    sse_encode_CastedPrimitive_i_64(self.startedAtMs, serializer);
   }
 
   @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
+  void sse_encode_watcher_metrics(
+    WatcherMetrics self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
+    sse_encode_CastedPrimitive_u_64(self.eventsReceived, serializer);
+    sse_encode_CastedPrimitive_u_64(self.eventsEmitted, serializer);
+    sse_encode_CastedPrimitive_u_64(self.eventsDeduped, serializer);
+    sse_encode_CastedPrimitive_u_64(self.eventsDropped, serializer);
+    sse_encode_CastedPrimitive_u_64(self.queueDepth, serializer);
+    sse_encode_CastedPrimitive_u_64(self.queueCapacity, serializer);
+  }
+
+  @protected
+  void sse_encode_watcher_status_event(
+    WatcherStatusEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.watcherId, serializer);
+    sse_encode_api_watcher_state(self.state, serializer);
+    sse_encode_bool(self.heartbeat, serializer);
+    sse_encode_CastedPrimitive_i_64(self.emittedAtMs, serializer);
   }
 
   @protected
@@ -2564,6 +14375,165 @@ class RustCoreApiImpl extends RustCoreApiImplPlatform implements RustCoreApi {
   }
 }
 
+@sealed
+class CoreHandleImpl extends RustOpaque implements CoreHandle {
+  // Not to be used by end users
+  CoreHandleImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  CoreHandleImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustCore.instance.api.rust_arc_increment_strong_count_CoreHandle,
+    rustArcDecrementStrongCount:
+        RustCore.instance.api.rust_arc_decrement_strong_count_CoreHandle,
+    rustArcDecrementStrongCountPtr:
+        RustCore.instance.api.rust_arc_decrement_strong_count_CoreHandlePtr,
+  );
+
+  /// Возможности ядра на момент создания handle'а; `data_dir` — папка
+  /// хранилищ handle'а.
+  Future<CoreCapabilities> capabilities() =>
+      RustCore.instance.api.crateApiCoreHandleCapabilities(that: this);
+
+  /// Сглаживание событий для следующих watcher'ов этого handle'а;
+  /// запущенные не меняются. Некорректное значение —
+  /// `LateraError::InvalidArgument`, прежнее остаётся.
+  Future<void> setWatchTuning({required WatchTuning tuning}) =>
+      RustCore.instance.api.crateApiCoreHandleSetWatchTuning(
+        that: this,
+        tuning: tuning,
+      );
+
+  /// Как [`set_watch_filter`], для следующих watcher'ов этого handle'а.
+  Future<void> setWatchFilter({WatchFilter? filter}) =>
+      RustCore.instance.api.crateApiCoreHandleSetWatchFilter(
+        that: this,
+        filter: filter,
+      );
+
+  /// Как [`set_watch_recursive`], для следующих watcher'ов этого handle'а.
+  Future<void> setWatchRecursive({required bool enabled}) =>
+      RustCore.instance.api.crateApiCoreHandleSetWatchRecursive(
+        that: this,
+        enabled: enabled,
+      );
+
+  /// Как [`start_watching`], но с настройками этого handle'а, а события
+  /// приходят в его streams и хранилища. `tuning` переопределяет
+  /// [`CoreHandle::set_watch_tuning`] по полям.
+  ///
+  /// Папку, которую уже наблюдает любой watcher процесса, повторно не
+  /// наблюдают — `LateraError::WatcherAlreadyRunning`.
+  Future<WatchStarted> startWatching({
+    String? overridePath,
+    WatchTuning? tuning,
+  }) => RustCore.instance.api.crateApiCoreHandleStartWatching(
+    that: this,
+    overridePath: overridePath,
+    tuning: tuning,
+  );
+
+  /// Как [`stop_watching`], только для watcher'ов этого handle'а: чужой
+  /// `watcher_id` — `LateraError::WatcherNotRunning`. Со своим последним
+  /// watcher'ом закрываются streams handle'а.
+  Future<void> stopWatching({required String watcherId}) =>
+      RustCore.instance.api.crateApiCoreHandleStopWatching(
+        that: this,
+        watcherId: watcherId,
+      );
+
+  /// Запущенные watcher'ы этого handle'а (как [`list_watchers`]).
+  Future<List<WatcherInfo>> listWatchers() =>
+      RustCore.instance.api.crateApiCoreHandleListWatchers(that: this);
+
+  /// Как [`on_file_added`], для watcher'ов этого handle'а. Без подписчика
+  /// события не копятся: WAL и подтверждения — только у общего stream.
+  Stream<FileAddedEvent> onFileAdded() =>
+      RustCore.instance.api.crateApiCoreHandleOnFileAdded(that: this);
+
+  /// Как [`on_file_event`], для watcher'ов этого handle'а.
+  Stream<FileEvent> onFileEvent() =>
+      RustCore.instance.api.crateApiCoreHandleOnFileEvent(that: this);
+
+  /// Как [`on_file_batch`], для watcher'ов этого handle'а.
+  Stream<FileBatchEvent> onFileBatch() =>
+      RustCore.instance.api.crateApiCoreHandleOnFileBatch(that: this);
+
+  /// Как [`on_file_chunks`], для watcher'ов этого handle'а.
+  Stream<FileChunkEvent> onFileChunks() =>
+      RustCore.instance.api.crateApiCoreHandleOnFileChunks(that: this);
+
+  /// Как [`get_file_status`], из хранилища этого handle'а.
+  Future<FileProcessingStatus?> getFileStatus({required String path}) =>
+      RustCore.instance.api.crateApiCoreHandleGetFileStatus(
+        that: this,
+        path: path,
+      );
+
+  /// Как [`list_tags`], из хранилища этого handle'а.
+  Future<List<String>> listTags({required String path}) =>
+      RustCore.instance.api.crateApiCoreHandleListTags(that: this, path: path);
+
+  /// Как [`query_events`], из журнала этого handle'а.
+  Future<List<ApiJournalEntry>> queryEvents({
+    required int sinceMs,
+    required int limit,
+  }) => RustCore.instance.api.crateApiCoreHandleQueryEvents(
+    that: this,
+    sinceMs: sinceMs,
+    limit: limit,
+  );
+
+  /// Останавливает watcher'ы handle'а (ждёт каждый не дольше 5 секунд),
+  /// закрывает его streams и хранилища; handle можно использовать дальше.
+  Future<void> close() =>
+      RustCore.instance.api.crateApiCoreHandleClose(that: this);
+}
+
+@sealed
+class ImageBufferImpl extends RustOpaque implements ImageBuffer {
+  // Not to be used by end users
+  ImageBufferImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  ImageBufferImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustCore.instance.api.rust_arc_increment_strong_count_ImageBuffer,
+    rustArcDecrementStrongCount:
+        RustCore.instance.api.rust_arc_decrement_strong_count_ImageBuffer,
+    rustArcDecrementStrongCountPtr:
+        RustCore.instance.api.rust_arc_decrement_strong_count_ImageBufferPtr,
+  );
+
+  /// Длина в байтах.
+  Future<int> len() => RustCore.instance.api.crateApiImageBufferLen(that: this);
+
+  Future<bool> isEmpty() =>
+      RustCore.instance.api.crateApiImageBufferIsEmpty(that: this);
+
+  /// `image/png` или `image/jpeg`.
+  Future<String> mimeType() =>
+      RustCore.instance.api.crateApiImageBufferMimeType(that: this);
+
+  Future<int> width() =>
+      RustCore.instance.api.crateApiImageBufferWidth(that: this);
+
+  Future<int> height() =>
+      RustCore.instance.api.crateApiImageBufferHeight(that: this);
+
+  /// Копия байтов — когда нужен собственный `Uint8List`.
+  Future<Uint8List> toBytes() =>
+      RustCore.instance.api.crateApiImageBufferToBytes(that: this);
+}
+
 @sealed
 class LateraErrorImpl extends RustOpaque implements LateraError {
   // Not to be used by end users
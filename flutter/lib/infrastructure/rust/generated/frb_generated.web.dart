@@ -21,159 +21,2629 @@ abstract class RustCoreApiImplPlatform extends BaseApiImpl<RustCoreWire> {
     required super.portManager,
   });
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_CoreHandlePtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_ImageBufferPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer;
+
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_LateraErrorPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError;
 
   @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+  @protected
+  CoreHandle
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    dynamic raw,
+  );
+
+  @protected
+  ImageBuffer
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    dynamic raw,
+  );
+
+  @protected
+  LateraError
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
+    dynamic raw,
+  );
+
+  @protected
+  CoreHandle
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    dynamic raw,
+  );
+
+  @protected
+  ImageBuffer
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    dynamic raw,
+  );
+
+  @protected
+  int dco_decode_CastedPrimitive_i_64(dynamic raw);
+
+  @protected
+  int dco_decode_CastedPrimitive_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_CastedPrimitive_usize(dynamic raw);
+
+  @protected
+  CoreHandle
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    dynamic raw,
+  );
+
+  @protected
+  ImageBuffer
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    dynamic raw,
+  );
+
+  @protected
+  LateraError
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<AckableFileEvent> dco_decode_StreamSink_ackable_file_event_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<AdoptionProgressEvent>
+  dco_decode_StreamSink_adoption_progress_event_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<DryRunActionEvent>
+  dco_decode_StreamSink_dry_run_action_event_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<DuplicateDetected>
+  dco_decode_StreamSink_duplicate_detected_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<FileAddedEvent> dco_decode_StreamSink_file_added_event_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<FileBatchEvent> dco_decode_StreamSink_file_batch_event_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<FileChunkEvent> dco_decode_StreamSink_file_chunk_event_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<FileEvent> dco_decode_StreamSink_file_event_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<FileHashEvent> dco_decode_StreamSink_file_hash_event_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<FileOpEvent> dco_decode_StreamSink_file_op_event_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<FileRemovedEvent> dco_decode_StreamSink_file_removed_event_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<FileStatusChangedEvent>
+  dco_decode_StreamSink_file_status_changed_event_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<HeartbeatEvent> dco_decode_StreamSink_heartbeat_event_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<LogRecord> dco_decode_StreamSink_log_record_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<RuleAppliedEvent> dco_decode_StreamSink_rule_applied_event_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<ScreenshotAddedEvent>
+  dco_decode_StreamSink_screenshot_added_event_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<WatchStatusEvent> dco_decode_StreamSink_watch_status_event_Sse(
+    dynamic raw,
+  );
+
+  @protected
+  RustStreamSink<WatcherStatusEvent>
+  dco_decode_StreamSink_watcher_status_event_Sse(dynamic raw);
+
+  @protected
+  String dco_decode_String(dynamic raw);
+
+  @protected
+  AckableFileEvent dco_decode_ackable_file_event(dynamic raw);
+
+  @protected
+  ActivityBucket dco_decode_activity_bucket(dynamic raw);
+
+  @protected
+  AdoptionPhase dco_decode_adoption_phase(dynamic raw);
+
+  @protected
+  AdoptionProgressEvent dco_decode_adoption_progress_event(dynamic raw);
+
+  @protected
+  ApiArchivedFile dco_decode_api_archived_file(dynamic raw);
+
+  @protected
+  ApiAuditAction dco_decode_api_audit_action(dynamic raw);
+
+  @protected
+  ApiAuditEntry dco_decode_api_audit_entry(dynamic raw);
+
+  @protected
+  ApiClaimStatus dco_decode_api_claim_status(dynamic raw);
+
+  @protected
+  ApiCodeFormat dco_decode_api_code_format(dynamic raw);
+
+  @protected
+  ApiConfig dco_decode_api_config(dynamic raw);
+
+  @protected
+  ApiConfigPatch dco_decode_api_config_patch(dynamic raw);
+
+  @protected
+  ApiDetectedCode dco_decode_api_detected_code(dynamic raw);
+
+  @protected
+  ApiEmailAttachment dco_decode_api_email_attachment(dynamic raw);
+
+  @protected
+  ApiEmailMetadata dco_decode_api_email_metadata(dynamic raw);
+
+  @protected
+  ApiEmbeddingVector dco_decode_api_embedding_vector(dynamic raw);
+
+  @protected
+  ApiFileAccessInfo dco_decode_api_file_access_info(dynamic raw);
+
+  @protected
+  ApiJournalEntry dco_decode_api_journal_entry(dynamic raw);
+
+  @protected
+  ApiLockedOption dco_decode_api_locked_option(dynamic raw);
+
+  @protected
+  ApiRagSource dco_decode_api_rag_source(dynamic raw);
+
+  @protected
+  ApiSimilarityResult dco_decode_api_similarity_result(dynamic raw);
+
+  @protected
+  ApiTelemetryCounter dco_decode_api_telemetry_counter(dynamic raw);
+
+  @protected
+  ApiTextChunk dco_decode_api_text_chunk(dynamic raw);
+
+  @protected
+  ApiThumbnail dco_decode_api_thumbnail(dynamic raw);
+
+  @protected
+  ApiWatchBackend dco_decode_api_watch_backend(dynamic raw);
+
+  @protected
+  ApiWatchMode dco_decode_api_watch_mode(dynamic raw);
+
+  @protected
+  ApiWatcherErrorCode dco_decode_api_watcher_error_code(dynamic raw);
+
+  @protected
+  ApiWatcherState dco_decode_api_watcher_state(dynamic raw);
+
+  @protected
+  ApiXattr dco_decode_api_xattr(dynamic raw);
+
+  @protected
+  ArchiveOptions dco_decode_archive_options(dynamic raw);
+
+  @protected
+  AuditLogFilter dco_decode_audit_log_filter(dynamic raw);
+
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  ApiArchivedFile dco_decode_box_autoadd_api_archived_file(dynamic raw);
+
+  @protected
+  ApiAuditAction dco_decode_box_autoadd_api_audit_action(dynamic raw);
+
+  @protected
+  ApiClaimStatus dco_decode_box_autoadd_api_claim_status(dynamic raw);
+
+  @protected
+  ApiConfigPatch dco_decode_box_autoadd_api_config_patch(dynamic raw);
+
+  @protected
+  ApiFileAccessInfo dco_decode_box_autoadd_api_file_access_info(dynamic raw);
+
+  @protected
+  ArchiveOptions dco_decode_box_autoadd_archive_options(dynamic raw);
+
+  @protected
+  AuditLogFilter dco_decode_box_autoadd_audit_log_filter(dynamic raw);
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
+  @protected
+  CoreConfig dco_decode_box_autoadd_core_config(dynamic raw);
+
+  @protected
+  EventDebugInfo dco_decode_box_autoadd_event_debug_info(dynamic raw);
+
+  @protected
+  ExtractionOptions dco_decode_box_autoadd_extraction_options(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  FileProcessingStatus dco_decode_box_autoadd_file_processing_status(
+    dynamic raw,
+  );
+
+  @protected
+  FileRule dco_decode_box_autoadd_file_rule(dynamic raw);
+
+  @protected
+  HashAlgorithm dco_decode_box_autoadd_hash_algorithm(dynamic raw);
+
+  @protected
+  LogFileOptions dco_decode_box_autoadd_log_file_options(dynamic raw);
+
+  @protected
+  OcrOptions dco_decode_box_autoadd_ocr_options(dynamic raw);
+
+  @protected
+  OnboardingStep dco_decode_box_autoadd_onboarding_step(dynamic raw);
+
+  @protected
+  TranscriptionOptions dco_decode_box_autoadd_transcription_options(
+    dynamic raw,
+  );
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  WatchFilter dco_decode_box_autoadd_watch_filter(dynamic raw);
+
+  @protected
+  WatchTuning dco_decode_box_autoadd_watch_tuning(dynamic raw);
+
+  @protected
+  WatcherDegradationPolicy dco_decode_box_autoadd_watcher_degradation_policy(
+    dynamic raw,
+  );
+
+  @protected
+  CategoryStats dco_decode_category_stats(dynamic raw);
+
+  @protected
+  ConfigImportResult dco_decode_config_import_result(dynamic raw);
+
+  @protected
+  ControlledFolderAccessInfo dco_decode_controlled_folder_access_info(
+    dynamic raw,
+  );
+
+  @protected
+  ControlledFolderAccessStatus dco_decode_controlled_folder_access_status(
+    dynamic raw,
+  );
+
+  @protected
+  CoreCapabilities dco_decode_core_capabilities(dynamic raw);
+
+  @protected
+  CoreConfig dco_decode_core_config(dynamic raw);
+
+  @protected
+  DesktopAccessStatus dco_decode_desktop_access_status(dynamic raw);
+
+  @protected
+  DirSizeInfo dco_decode_dir_size_info(dynamic raw);
+
+  @protected
+  DryRunActionEvent dco_decode_dry_run_action_event(dynamic raw);
+
+  @protected
+  DuplicateDetected dco_decode_duplicate_detected(dynamic raw);
+
+  @protected
+  DuplicateGroup dco_decode_duplicate_group(dynamic raw);
+
+  @protected
+  EventDebugInfo dco_decode_event_debug_info(dynamic raw);
+
+  @protected
+  EventTimestampSource dco_decode_event_timestamp_source(dynamic raw);
+
+  @protected
+  ExtractionOptions dco_decode_extraction_options(dynamic raw);
+
+  @protected
+  ExtractionResult dco_decode_extraction_result(dynamic raw);
+
+  @protected
+  double dco_decode_f_32(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  FileAddedEvent dco_decode_file_added_event(dynamic raw);
+
+  @protected
+  FileBatchEvent dco_decode_file_batch_event(dynamic raw);
+
+  @protected
+  FileCategory dco_decode_file_category(dynamic raw);
+
+  @protected
+  FileChunkEvent dco_decode_file_chunk_event(dynamic raw);
+
+  @protected
+  FileEvent dco_decode_file_event(dynamic raw);
+
+  @protected
+  FileEventKind dco_decode_file_event_kind(dynamic raw);
+
+  @protected
+  FileHashEvent dco_decode_file_hash_event(dynamic raw);
+
+  @protected
+  FileOpEvent dco_decode_file_op_event(dynamic raw);
+
+  @protected
+  FileOpKind dco_decode_file_op_kind(dynamic raw);
+
+  @protected
+  FileOpStatus dco_decode_file_op_status(dynamic raw);
+
+  @protected
+  FileProcessingStatus dco_decode_file_processing_status(dynamic raw);
+
+  @protected
+  FileRemovedEvent dco_decode_file_removed_event(dynamic raw);
+
+  @protected
+  FileRule dco_decode_file_rule(dynamic raw);
+
+  @protected
+  FileRuleAction dco_decode_file_rule_action(dynamic raw);
+
+  @protected
+  FileStatusChangedEvent dco_decode_file_status_changed_event(dynamic raw);
+
+  @protected
+  FileStatusUpdate dco_decode_file_status_update(dynamic raw);
+
+  @protected
+  FolderMarkerInfo dco_decode_folder_marker_info(dynamic raw);
+
+  @protected
+  FolderMarkerStatus dco_decode_folder_marker_status(dynamic raw);
+
+  @protected
+  HashAlgorithm dco_decode_hash_algorithm(dynamic raw);
+
+  @protected
+  HeartbeatEvent dco_decode_heartbeat_event(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
+  @protected
+  IntegrityIssue dco_decode_integrity_issue(dynamic raw);
+
+  @protected
+  IntegrityIssueKind dco_decode_integrity_issue_kind(dynamic raw);
+
+  @protected
+  LateraApiError dco_decode_latera_api_error(dynamic raw);
+
+  @protected
+  List<ImageBuffer>
+  dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    dynamic raw,
+  );
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<ActivityBucket> dco_decode_list_activity_bucket(dynamic raw);
+
+  @protected
+  List<ApiArchivedFile> dco_decode_list_api_archived_file(dynamic raw);
+
+  @protected
+  List<ApiAuditEntry> dco_decode_list_api_audit_entry(dynamic raw);
+
+  @protected
+  List<ApiDetectedCode> dco_decode_list_api_detected_code(dynamic raw);
+
+  @protected
+  List<ApiEmailAttachment> dco_decode_list_api_email_attachment(dynamic raw);
+
+  @protected
+  List<ApiEmbeddingVector> dco_decode_list_api_embedding_vector(dynamic raw);
+
+  @protected
+  List<ApiJournalEntry> dco_decode_list_api_journal_entry(dynamic raw);
+
+  @protected
+  List<ApiLockedOption> dco_decode_list_api_locked_option(dynamic raw);
+
+  @protected
+  List<ApiRagSource> dco_decode_list_api_rag_source(dynamic raw);
+
+  @protected
+  List<ApiSimilarityResult> dco_decode_list_api_similarity_result(dynamic raw);
+
+  @protected
+  List<ApiTelemetryCounter> dco_decode_list_api_telemetry_counter(dynamic raw);
+
+  @protected
+  List<ApiTextChunk> dco_decode_list_api_text_chunk(dynamic raw);
+
+  @protected
+  List<ApiXattr> dco_decode_list_api_xattr(dynamic raw);
+
+  @protected
+  List<CategoryStats> dco_decode_list_category_stats(dynamic raw);
+
+  @protected
+  List<DuplicateGroup> dco_decode_list_duplicate_group(dynamic raw);
+
+  @protected
+  List<FileAddedEvent> dco_decode_list_file_added_event(dynamic raw);
+
+  @protected
+  List<FileRule> dco_decode_list_file_rule(dynamic raw);
+
+  @protected
+  List<FileStatusUpdate> dco_decode_list_file_status_update(dynamic raw);
+
+  @protected
+  List<IntegrityIssue> dco_decode_list_integrity_issue(dynamic raw);
+
+  @protected
+  List<OnboardingStep> dco_decode_list_onboarding_step(dynamic raw);
+
+  @protected
+  List<PdfPagePreview> dco_decode_list_pdf_page_preview(dynamic raw);
+
+  @protected
+  Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
+
+  @protected
+  Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<ReportFile> dco_decode_list_report_file(dynamic raw);
+
+  @protected
+  List<SearchResultItem> dco_decode_list_search_result_item(dynamic raw);
+
+  @protected
+  List<TypeCount> dco_decode_list_type_count(dynamic raw);
+
+  @protected
+  List<WatchLocationSuggestion> dco_decode_list_watch_location_suggestion(
+    dynamic raw,
+  );
+
+  @protected
+  List<WatchTreeStat> dco_decode_list_watch_tree_stat(dynamic raw);
+
+  @protected
+  List<WatcherInfo> dco_decode_list_watcher_info(dynamic raw);
+
+  @protected
+  LogFileOptions dco_decode_log_file_options(dynamic raw);
+
+  @protected
+  LogRecord dco_decode_log_record(dynamic raw);
+
+  @protected
+  OcrOptions dco_decode_ocr_options(dynamic raw);
+
+  @protected
+  OcrResult dco_decode_ocr_result(dynamic raw);
+
+  @protected
+  OnboardingState dco_decode_onboarding_state(dynamic raw);
+
+  @protected
+  OnboardingStep dco_decode_onboarding_step(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_CastedPrimitive_i_64(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_CastedPrimitive_u_64(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  ApiArchivedFile? dco_decode_opt_box_autoadd_api_archived_file(dynamic raw);
+
+  @protected
+  ApiAuditAction? dco_decode_opt_box_autoadd_api_audit_action(dynamic raw);
+
+  @protected
+  ApiClaimStatus? dco_decode_opt_box_autoadd_api_claim_status(dynamic raw);
+
+  @protected
+  ApiFileAccessInfo? dco_decode_opt_box_autoadd_api_file_access_info(
+    dynamic raw,
+  );
+
+  @protected
+  ArchiveOptions? dco_decode_opt_box_autoadd_archive_options(dynamic raw);
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+  @protected
+  EventDebugInfo? dco_decode_opt_box_autoadd_event_debug_info(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  FileProcessingStatus? dco_decode_opt_box_autoadd_file_processing_status(
+    dynamic raw,
+  );
+
+  @protected
+  HashAlgorithm? dco_decode_opt_box_autoadd_hash_algorithm(dynamic raw);
+
+  @protected
+  OnboardingStep? dco_decode_opt_box_autoadd_onboarding_step(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  WatchFilter? dco_decode_opt_box_autoadd_watch_filter(dynamic raw);
+
+  @protected
+  WatchTuning? dco_decode_opt_box_autoadd_watch_tuning(dynamic raw);
+
+  @protected
+  WatcherDegradationPolicy?
+  dco_decode_opt_box_autoadd_watcher_degradation_policy(dynamic raw);
+
+  @protected
+  List<String>? dco_decode_opt_list_String(dynamic raw);
+
+  @protected
+  PdfPagePreview dco_decode_pdf_page_preview(dynamic raw);
+
+  @protected
+  PolicyStatus dco_decode_policy_status(dynamic raw);
+
+  @protected
+  RagQueryResult dco_decode_rag_query_result(dynamic raw);
+
+  @protected
+  ReportFile dco_decode_report_file(dynamic raw);
+
+  @protected
+  RuleAppliedEvent dco_decode_rule_applied_event(dynamic raw);
+
+  @protected
+  ScreenshotAddedEvent dco_decode_screenshot_added_event(dynamic raw);
+
+  @protected
+  SearchResultItem dco_decode_search_result_item(dynamic raw);
+
+  @protected
+  TelemetryPreview dco_decode_telemetry_preview(dynamic raw);
+
+  @protected
+  TimelineGranularity dco_decode_timeline_granularity(dynamic raw);
+
+  @protected
+  TranscriptionOptions dco_decode_transcription_options(dynamic raw);
+
+  @protected
+  TranscriptionResult dco_decode_transcription_result(dynamic raw);
+
+  @protected
+  TypeCount dco_decode_type_count(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  VolumeInfo dco_decode_volume_info(dynamic raw);
+
+  @protected
+  VolumeKind dco_decode_volume_kind(dynamic raw);
+
+  @protected
+  WatchDirRecoveryMode dco_decode_watch_dir_recovery_mode(dynamic raw);
+
+  @protected
+  WatchFilter dco_decode_watch_filter(dynamic raw);
+
+  @protected
+  WatchLocationKind dco_decode_watch_location_kind(dynamic raw);
+
+  @protected
+  WatchLocationSuggestion dco_decode_watch_location_suggestion(dynamic raw);
+
+  @protected
+  WatchStarted dco_decode_watch_started(dynamic raw);
+
+  @protected
+  WatchStatusEvent dco_decode_watch_status_event(dynamic raw);
+
+  @protected
+  WatchTreeStat dco_decode_watch_tree_stat(dynamic raw);
+
+  @protected
+  WatchTuning dco_decode_watch_tuning(dynamic raw);
+
+  @protected
+  WatcherDegradationPolicy dco_decode_watcher_degradation_policy(dynamic raw);
+
+  @protected
+  WatcherInfo dco_decode_watcher_info(dynamic raw);
+
+  @protected
+  WatcherMetrics dco_decode_watcher_metrics(dynamic raw);
+
+  @protected
+  WatcherStatusEvent dco_decode_watcher_status_event(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  CoreHandle
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ImageBuffer
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LateraError
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CoreHandle
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ImageBuffer
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_CastedPrimitive_i_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_CastedPrimitive_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_CastedPrimitive_usize(SseDeserializer deserializer);
+
+  @protected
+  CoreHandle
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ImageBuffer
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LateraError
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<AckableFileEvent> sse_decode_StreamSink_ackable_file_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<AdoptionProgressEvent>
+  sse_decode_StreamSink_adoption_progress_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<DryRunActionEvent>
+  sse_decode_StreamSink_dry_run_action_event_Sse(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<DuplicateDetected>
+  sse_decode_StreamSink_duplicate_detected_Sse(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<FileAddedEvent> sse_decode_StreamSink_file_added_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<FileBatchEvent> sse_decode_StreamSink_file_batch_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<FileChunkEvent> sse_decode_StreamSink_file_chunk_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<FileEvent> sse_decode_StreamSink_file_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<FileHashEvent> sse_decode_StreamSink_file_hash_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<FileOpEvent> sse_decode_StreamSink_file_op_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<FileRemovedEvent> sse_decode_StreamSink_file_removed_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<FileStatusChangedEvent>
+  sse_decode_StreamSink_file_status_changed_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<HeartbeatEvent> sse_decode_StreamSink_heartbeat_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<LogRecord> sse_decode_StreamSink_log_record_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<RuleAppliedEvent> sse_decode_StreamSink_rule_applied_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<ScreenshotAddedEvent>
+  sse_decode_StreamSink_screenshot_added_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<WatchStatusEvent> sse_decode_StreamSink_watch_status_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<WatcherStatusEvent>
+  sse_decode_StreamSink_watcher_status_event_Sse(SseDeserializer deserializer);
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  AckableFileEvent sse_decode_ackable_file_event(SseDeserializer deserializer);
+
+  @protected
+  ActivityBucket sse_decode_activity_bucket(SseDeserializer deserializer);
+
+  @protected
+  AdoptionPhase sse_decode_adoption_phase(SseDeserializer deserializer);
+
+  @protected
+  AdoptionProgressEvent sse_decode_adoption_progress_event(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiArchivedFile sse_decode_api_archived_file(SseDeserializer deserializer);
+
+  @protected
+  ApiAuditAction sse_decode_api_audit_action(SseDeserializer deserializer);
+
+  @protected
+  ApiAuditEntry sse_decode_api_audit_entry(SseDeserializer deserializer);
+
+  @protected
+  ApiClaimStatus sse_decode_api_claim_status(SseDeserializer deserializer);
+
+  @protected
+  ApiCodeFormat sse_decode_api_code_format(SseDeserializer deserializer);
+
+  @protected
+  ApiConfig sse_decode_api_config(SseDeserializer deserializer);
+
+  @protected
+  ApiConfigPatch sse_decode_api_config_patch(SseDeserializer deserializer);
+
+  @protected
+  ApiDetectedCode sse_decode_api_detected_code(SseDeserializer deserializer);
+
+  @protected
+  ApiEmailAttachment sse_decode_api_email_attachment(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiEmailMetadata sse_decode_api_email_metadata(SseDeserializer deserializer);
+
+  @protected
+  ApiEmbeddingVector sse_decode_api_embedding_vector(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiFileAccessInfo sse_decode_api_file_access_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiJournalEntry sse_decode_api_journal_entry(SseDeserializer deserializer);
+
+  @protected
+  ApiLockedOption sse_decode_api_locked_option(SseDeserializer deserializer);
+
+  @protected
+  ApiRagSource sse_decode_api_rag_source(SseDeserializer deserializer);
+
+  @protected
+  ApiSimilarityResult sse_decode_api_similarity_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiTelemetryCounter sse_decode_api_telemetry_counter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiTextChunk sse_decode_api_text_chunk(SseDeserializer deserializer);
+
+  @protected
+  ApiThumbnail sse_decode_api_thumbnail(SseDeserializer deserializer);
+
+  @protected
+  ApiWatchBackend sse_decode_api_watch_backend(SseDeserializer deserializer);
+
+  @protected
+  ApiWatchMode sse_decode_api_watch_mode(SseDeserializer deserializer);
+
+  @protected
+  ApiWatcherErrorCode sse_decode_api_watcher_error_code(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiWatcherState sse_decode_api_watcher_state(SseDeserializer deserializer);
+
+  @protected
+  ApiXattr sse_decode_api_xattr(SseDeserializer deserializer);
+
+  @protected
+  ArchiveOptions sse_decode_archive_options(SseDeserializer deserializer);
+
+  @protected
+  AuditLogFilter sse_decode_audit_log_filter(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  ApiArchivedFile sse_decode_box_autoadd_api_archived_file(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiAuditAction sse_decode_box_autoadd_api_audit_action(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiClaimStatus sse_decode_box_autoadd_api_claim_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiConfigPatch sse_decode_box_autoadd_api_config_patch(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiFileAccessInfo sse_decode_box_autoadd_api_file_access_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ArchiveOptions sse_decode_box_autoadd_archive_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AuditLogFilter sse_decode_box_autoadd_audit_log_filter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  CoreConfig sse_decode_box_autoadd_core_config(SseDeserializer deserializer);
+
+  @protected
+  EventDebugInfo sse_decode_box_autoadd_event_debug_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ExtractionOptions sse_decode_box_autoadd_extraction_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  FileProcessingStatus sse_decode_box_autoadd_file_processing_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FileRule sse_decode_box_autoadd_file_rule(SseDeserializer deserializer);
+
+  @protected
+  HashAlgorithm sse_decode_box_autoadd_hash_algorithm(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LogFileOptions sse_decode_box_autoadd_log_file_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OcrOptions sse_decode_box_autoadd_ocr_options(SseDeserializer deserializer);
+
+  @protected
+  OnboardingStep sse_decode_box_autoadd_onboarding_step(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TranscriptionOptions sse_decode_box_autoadd_transcription_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  WatchFilter sse_decode_box_autoadd_watch_filter(SseDeserializer deserializer);
+
+  @protected
+  WatchTuning sse_decode_box_autoadd_watch_tuning(SseDeserializer deserializer);
+
+  @protected
+  WatcherDegradationPolicy sse_decode_box_autoadd_watcher_degradation_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CategoryStats sse_decode_category_stats(SseDeserializer deserializer);
+
+  @protected
+  ConfigImportResult sse_decode_config_import_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ControlledFolderAccessInfo sse_decode_controlled_folder_access_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ControlledFolderAccessStatus sse_decode_controlled_folder_access_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CoreCapabilities sse_decode_core_capabilities(SseDeserializer deserializer);
+
+  @protected
+  CoreConfig sse_decode_core_config(SseDeserializer deserializer);
+
+  @protected
+  DesktopAccessStatus sse_decode_desktop_access_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DirSizeInfo sse_decode_dir_size_info(SseDeserializer deserializer);
+
+  @protected
+  DryRunActionEvent sse_decode_dry_run_action_event(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DuplicateDetected sse_decode_duplicate_detected(SseDeserializer deserializer);
+
+  @protected
+  DuplicateGroup sse_decode_duplicate_group(SseDeserializer deserializer);
+
+  @protected
+  EventDebugInfo sse_decode_event_debug_info(SseDeserializer deserializer);
+
+  @protected
+  EventTimestampSource sse_decode_event_timestamp_source(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ExtractionOptions sse_decode_extraction_options(SseDeserializer deserializer);
+
+  @protected
+  ExtractionResult sse_decode_extraction_result(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  FileAddedEvent sse_decode_file_added_event(SseDeserializer deserializer);
+
+  @protected
+  FileBatchEvent sse_decode_file_batch_event(SseDeserializer deserializer);
+
+  @protected
+  FileCategory sse_decode_file_category(SseDeserializer deserializer);
+
+  @protected
+  FileChunkEvent sse_decode_file_chunk_event(SseDeserializer deserializer);
+
+  @protected
+  FileEvent sse_decode_file_event(SseDeserializer deserializer);
+
+  @protected
+  FileEventKind sse_decode_file_event_kind(SseDeserializer deserializer);
+
+  @protected
+  FileHashEvent sse_decode_file_hash_event(SseDeserializer deserializer);
+
+  @protected
+  FileOpEvent sse_decode_file_op_event(SseDeserializer deserializer);
+
+  @protected
+  FileOpKind sse_decode_file_op_kind(SseDeserializer deserializer);
+
+  @protected
+  FileOpStatus sse_decode_file_op_status(SseDeserializer deserializer);
+
+  @protected
+  FileProcessingStatus sse_decode_file_processing_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FileRemovedEvent sse_decode_file_removed_event(SseDeserializer deserializer);
+
+  @protected
+  FileRule sse_decode_file_rule(SseDeserializer deserializer);
+
+  @protected
+  FileRuleAction sse_decode_file_rule_action(SseDeserializer deserializer);
+
+  @protected
+  FileStatusChangedEvent sse_decode_file_status_changed_event(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  FileStatusUpdate sse_decode_file_status_update(SseDeserializer deserializer);
+
+  @protected
+  FolderMarkerInfo sse_decode_folder_marker_info(SseDeserializer deserializer);
+
+  @protected
+  FolderMarkerStatus sse_decode_folder_marker_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  HashAlgorithm sse_decode_hash_algorithm(SseDeserializer deserializer);
+
+  @protected
+  HeartbeatEvent sse_decode_heartbeat_event(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  IntegrityIssue sse_decode_integrity_issue(SseDeserializer deserializer);
+
+  @protected
+  IntegrityIssueKind sse_decode_integrity_issue_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LateraApiError sse_decode_latera_api_error(SseDeserializer deserializer);
+
+  @protected
+  List<ImageBuffer>
+  sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<ActivityBucket> sse_decode_list_activity_bucket(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ApiArchivedFile> sse_decode_list_api_archived_file(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ApiAuditEntry> sse_decode_list_api_audit_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ApiDetectedCode> sse_decode_list_api_detected_code(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ApiEmailAttachment> sse_decode_list_api_email_attachment(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ApiEmbeddingVector> sse_decode_list_api_embedding_vector(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ApiJournalEntry> sse_decode_list_api_journal_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ApiLockedOption> sse_decode_list_api_locked_option(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ApiRagSource> sse_decode_list_api_rag_source(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ApiSimilarityResult> sse_decode_list_api_similarity_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ApiTelemetryCounter> sse_decode_list_api_telemetry_counter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ApiTextChunk> sse_decode_list_api_text_chunk(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ApiXattr> sse_decode_list_api_xattr(SseDeserializer deserializer);
+
+  @protected
+  List<CategoryStats> sse_decode_list_category_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DuplicateGroup> sse_decode_list_duplicate_group(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FileAddedEvent> sse_decode_list_file_added_event(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FileRule> sse_decode_list_file_rule(SseDeserializer deserializer);
+
+  @protected
+  List<FileStatusUpdate> sse_decode_list_file_status_update(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<IntegrityIssue> sse_decode_list_integrity_issue(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<OnboardingStep> sse_decode_list_onboarding_step(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PdfPagePreview> sse_decode_list_pdf_page_preview(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<ReportFile> sse_decode_list_report_file(SseDeserializer deserializer);
+
+  @protected
+  List<SearchResultItem> sse_decode_list_search_result_item(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TypeCount> sse_decode_list_type_count(SseDeserializer deserializer);
+
+  @protected
+  List<WatchLocationSuggestion> sse_decode_list_watch_location_suggestion(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<WatchTreeStat> sse_decode_list_watch_tree_stat(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<WatcherInfo> sse_decode_list_watcher_info(SseDeserializer deserializer);
+
+  @protected
+  LogFileOptions sse_decode_log_file_options(SseDeserializer deserializer);
+
+  @protected
+  LogRecord sse_decode_log_record(SseDeserializer deserializer);
+
+  @protected
+  OcrOptions sse_decode_ocr_options(SseDeserializer deserializer);
+
+  @protected
+  OcrResult sse_decode_ocr_result(SseDeserializer deserializer);
+
+  @protected
+  OnboardingState sse_decode_onboarding_state(SseDeserializer deserializer);
+
+  @protected
+  OnboardingStep sse_decode_onboarding_step(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_CastedPrimitive_i_64(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_CastedPrimitive_u_64(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  ApiArchivedFile? sse_decode_opt_box_autoadd_api_archived_file(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiAuditAction? sse_decode_opt_box_autoadd_api_audit_action(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiClaimStatus? sse_decode_opt_box_autoadd_api_claim_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ApiFileAccessInfo? sse_decode_opt_box_autoadd_api_file_access_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ArchiveOptions? sse_decode_opt_box_autoadd_archive_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  EventDebugInfo? sse_decode_opt_box_autoadd_event_debug_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  FileProcessingStatus? sse_decode_opt_box_autoadd_file_processing_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  HashAlgorithm? sse_decode_opt_box_autoadd_hash_algorithm(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  OnboardingStep? sse_decode_opt_box_autoadd_onboarding_step(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  WatchFilter? sse_decode_opt_box_autoadd_watch_filter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WatchTuning? sse_decode_opt_box_autoadd_watch_tuning(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WatcherDegradationPolicy?
+  sse_decode_opt_box_autoadd_watcher_degradation_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+  @protected
+  PdfPagePreview sse_decode_pdf_page_preview(SseDeserializer deserializer);
+
+  @protected
+  PolicyStatus sse_decode_policy_status(SseDeserializer deserializer);
+
+  @protected
+  RagQueryResult sse_decode_rag_query_result(SseDeserializer deserializer);
+
+  @protected
+  ReportFile sse_decode_report_file(SseDeserializer deserializer);
+
+  @protected
+  RuleAppliedEvent sse_decode_rule_applied_event(SseDeserializer deserializer);
+
+  @protected
+  ScreenshotAddedEvent sse_decode_screenshot_added_event(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SearchResultItem sse_decode_search_result_item(SseDeserializer deserializer);
+
+  @protected
+  TelemetryPreview sse_decode_telemetry_preview(SseDeserializer deserializer);
+
+  @protected
+  TimelineGranularity sse_decode_timeline_granularity(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TranscriptionOptions sse_decode_transcription_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TranscriptionResult sse_decode_transcription_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TypeCount sse_decode_type_count(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  VolumeInfo sse_decode_volume_info(SseDeserializer deserializer);
+
+  @protected
+  VolumeKind sse_decode_volume_kind(SseDeserializer deserializer);
+
+  @protected
+  WatchDirRecoveryMode sse_decode_watch_dir_recovery_mode(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WatchFilter sse_decode_watch_filter(SseDeserializer deserializer);
+
+  @protected
+  WatchLocationKind sse_decode_watch_location_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WatchLocationSuggestion sse_decode_watch_location_suggestion(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WatchStarted sse_decode_watch_started(SseDeserializer deserializer);
+
+  @protected
+  WatchStatusEvent sse_decode_watch_status_event(SseDeserializer deserializer);
+
+  @protected
+  WatchTreeStat sse_decode_watch_tree_stat(SseDeserializer deserializer);
+
+  @protected
+  WatchTuning sse_decode_watch_tuning(SseDeserializer deserializer);
+
+  @protected
+  WatcherDegradationPolicy sse_decode_watcher_degradation_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WatcherInfo sse_decode_watcher_info(SseDeserializer deserializer);
+
+  @protected
+  WatcherMetrics sse_decode_watcher_metrics(SseDeserializer deserializer);
+
+  @protected
+  WatcherStatusEvent sse_decode_watcher_status_event(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    CoreHandle self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    ImageBuffer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
+    LateraError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    CoreHandle self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    ImageBuffer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_CastedPrimitive_i_64(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_CastedPrimitive_u_64(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_CastedPrimitive_usize(int self, SseSerializer serializer);
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    CoreHandle self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    ImageBuffer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
+    LateraError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_ackable_file_event_Sse(
+    RustStreamSink<AckableFileEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_adoption_progress_event_Sse(
+    RustStreamSink<AdoptionProgressEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_dry_run_action_event_Sse(
+    RustStreamSink<DryRunActionEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_duplicate_detected_Sse(
+    RustStreamSink<DuplicateDetected> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_file_added_event_Sse(
+    RustStreamSink<FileAddedEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_file_batch_event_Sse(
+    RustStreamSink<FileBatchEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_file_chunk_event_Sse(
+    RustStreamSink<FileChunkEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_file_event_Sse(
+    RustStreamSink<FileEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_file_hash_event_Sse(
+    RustStreamSink<FileHashEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_file_op_event_Sse(
+    RustStreamSink<FileOpEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_file_removed_event_Sse(
+    RustStreamSink<FileRemovedEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_file_status_changed_event_Sse(
+    RustStreamSink<FileStatusChangedEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_heartbeat_event_Sse(
+    RustStreamSink<HeartbeatEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_log_record_Sse(
+    RustStreamSink<LogRecord> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_rule_applied_event_Sse(
+    RustStreamSink<RuleAppliedEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_screenshot_added_event_Sse(
+    RustStreamSink<ScreenshotAddedEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_watch_status_event_Sse(
+    RustStreamSink<WatchStatusEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_watcher_status_event_Sse(
+    RustStreamSink<WatcherStatusEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_ackable_file_event(
+    AckableFileEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_activity_bucket(
+    ActivityBucket self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_adoption_phase(AdoptionPhase self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_adoption_progress_event(
+    AdoptionProgressEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_archived_file(
+    ApiArchivedFile self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_audit_action(
+    ApiAuditAction self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_audit_entry(ApiAuditEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_api_claim_status(
+    ApiClaimStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_code_format(ApiCodeFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_api_config(ApiConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_api_config_patch(
+    ApiConfigPatch self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_detected_code(
+    ApiDetectedCode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_email_attachment(
+    ApiEmailAttachment self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_email_metadata(
+    ApiEmailMetadata self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_embedding_vector(
+    ApiEmbeddingVector self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_file_access_info(
+    ApiFileAccessInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_journal_entry(
+    ApiJournalEntry self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_locked_option(
+    ApiLockedOption self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_rag_source(ApiRagSource self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_api_similarity_result(
+    ApiSimilarityResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_telemetry_counter(
+    ApiTelemetryCounter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_text_chunk(ApiTextChunk self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_api_thumbnail(ApiThumbnail self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_api_watch_backend(
+    ApiWatchBackend self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_watch_mode(ApiWatchMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_api_watcher_error_code(
+    ApiWatcherErrorCode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_watcher_state(
+    ApiWatcherState self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_api_xattr(ApiXattr self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_archive_options(
+    ArchiveOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_audit_log_filter(
+    AuditLogFilter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_api_archived_file(
+    ApiArchivedFile self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_api_audit_action(
+    ApiAuditAction self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_api_claim_status(
+    ApiClaimStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_api_config_patch(
+    ApiConfigPatch self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_api_file_access_info(
+    ApiFileAccessInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_archive_options(
+    ArchiveOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_audit_log_filter(
+    AuditLogFilter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_core_config(
+    CoreConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_event_debug_info(
+    EventDebugInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_extraction_options(
+    ExtractionOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_file_processing_status(
+    FileProcessingStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_file_rule(
+    FileRule self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_hash_algorithm(
+    HashAlgorithm self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_log_file_options(
+    LogFileOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_ocr_options(
+    OcrOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_onboarding_step(
+    OnboardingStep self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_transcription_options(
+    TranscriptionOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_watch_filter(
+    WatchFilter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_watch_tuning(
+    WatchTuning self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_watcher_degradation_policy(
+    WatcherDegradationPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_category_stats(CategoryStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_config_import_result(
+    ConfigImportResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_controlled_folder_access_info(
+    ControlledFolderAccessInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_controlled_folder_access_status(
+    ControlledFolderAccessStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_core_capabilities(
+    CoreCapabilities self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_core_config(CoreConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_desktop_access_status(
+    DesktopAccessStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_dir_size_info(DirSizeInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_dry_run_action_event(
+    DryRunActionEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_duplicate_detected(
+    DuplicateDetected self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_duplicate_group(
+    DuplicateGroup self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_event_debug_info(
+    EventDebugInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_event_timestamp_source(
+    EventTimestampSource self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_extraction_options(
+    ExtractionOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_extraction_result(
+    ExtractionResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_file_added_event(
+    FileAddedEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_file_batch_event(
+    FileBatchEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_file_category(FileCategory self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_file_chunk_event(
+    FileChunkEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_file_event(FileEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_file_event_kind(FileEventKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_file_hash_event(FileHashEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_file_op_event(FileOpEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_file_op_kind(FileOpKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_file_op_status(FileOpStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_file_processing_status(
+    FileProcessingStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_file_removed_event(
+    FileRemovedEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_file_rule(FileRule self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_file_rule_action(
+    FileRuleAction self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_file_status_changed_event(
+    FileStatusChangedEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_file_status_update(
+    FileStatusUpdate self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_folder_marker_info(
+    FolderMarkerInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_folder_marker_status(
+    FolderMarkerStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_hash_algorithm(HashAlgorithm self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_heartbeat_event(
+    HeartbeatEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_integrity_issue(
+    IntegrityIssue self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_integrity_issue_kind(
+    IntegrityIssueKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_latera_api_error(
+    LateraApiError self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    List<ImageBuffer> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_activity_bucket(
+    List<ActivityBucket> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_api_archived_file(
+    List<ApiArchivedFile> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_api_audit_entry(
+    List<ApiAuditEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_api_detected_code(
+    List<ApiDetectedCode> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_api_email_attachment(
+    List<ApiEmailAttachment> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_api_embedding_vector(
+    List<ApiEmbeddingVector> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_api_journal_entry(
+    List<ApiJournalEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_api_locked_option(
+    List<ApiLockedOption> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_api_rag_source(
+    List<ApiRagSource> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_api_similarity_result(
+    List<ApiSimilarityResult> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  LateraError
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
-    dynamic raw,
+  void sse_encode_list_api_telemetry_counter(
+    List<ApiTelemetryCounter> self,
+    SseSerializer serializer,
   );
 
   @protected
-  int dco_decode_CastedPrimitive_i_64(dynamic raw);
+  void sse_encode_list_api_text_chunk(
+    List<ApiTextChunk> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  LateraError
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
-    dynamic raw,
+  void sse_encode_list_api_xattr(List<ApiXattr> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_category_stats(
+    List<CategoryStats> self,
+    SseSerializer serializer,
   );
 
   @protected
-  RustStreamSink<FileAddedEvent> dco_decode_StreamSink_file_added_event_Sse(
-    dynamic raw,
+  void sse_encode_list_duplicate_group(
+    List<DuplicateGroup> self,
+    SseSerializer serializer,
   );
 
   @protected
-  String dco_decode_String(dynamic raw);
+  void sse_encode_list_file_added_event(
+    List<FileAddedEvent> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  FileAddedEvent dco_decode_file_added_event(dynamic raw);
+  void sse_encode_list_file_rule(List<FileRule> self, SseSerializer serializer);
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw);
+  void sse_encode_list_file_status_update(
+    List<FileStatusUpdate> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  void sse_encode_list_integrity_issue(
+    List<IntegrityIssue> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  void sse_encode_list_onboarding_step(
+    List<OnboardingStep> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  void sse_encode_list_pdf_page_preview(
+    List<PdfPagePreview> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  void sse_encode_list_prim_f_32_strict(
+    Float32List self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt dco_decode_usize(dynamic raw);
+  void sse_encode_list_prim_u_32_strict(
+    Uint32List self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  );
 
   @protected
-  LateraError
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
-    SseDeserializer deserializer,
+  void sse_encode_list_report_file(
+    List<ReportFile> self,
+    SseSerializer serializer,
   );
 
   @protected
-  int sse_decode_CastedPrimitive_i_64(SseDeserializer deserializer);
+  void sse_encode_list_search_result_item(
+    List<SearchResultItem> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  LateraError
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
-    SseDeserializer deserializer,
+  void sse_encode_list_type_count(
+    List<TypeCount> self,
+    SseSerializer serializer,
   );
 
   @protected
-  RustStreamSink<FileAddedEvent> sse_decode_StreamSink_file_added_event_Sse(
-    SseDeserializer deserializer,
+  void sse_encode_list_watch_location_suggestion(
+    List<WatchLocationSuggestion> self,
+    SseSerializer serializer,
   );
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  void sse_encode_list_watch_tree_stat(
+    List<WatchTreeStat> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  FileAddedEvent sse_decode_file_added_event(SseDeserializer deserializer);
+  void sse_encode_list_watcher_info(
+    List<WatcherInfo> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+  void sse_encode_log_file_options(
+    LogFileOptions self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  void sse_encode_log_record(LogRecord self, SseSerializer serializer);
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  void sse_encode_ocr_options(OcrOptions self, SseSerializer serializer);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_ocr_result(OcrResult self, SseSerializer serializer);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_encode_onboarding_state(
+    OnboardingState self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  void sse_encode_onboarding_step(
+    OnboardingStep self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_opt_CastedPrimitive_i_64(int? self, SseSerializer serializer);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_opt_CastedPrimitive_u_64(int? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_api_archived_file(
+    ApiArchivedFile? self,
     SseSerializer serializer,
   );
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
-    LateraError self,
+  void sse_encode_opt_box_autoadd_api_audit_action(
+    ApiAuditAction? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_CastedPrimitive_i_64(int self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_api_claim_status(
+    ApiClaimStatus? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
-    LateraError self,
+  void sse_encode_opt_box_autoadd_api_file_access_info(
+    ApiFileAccessInfo? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_StreamSink_file_added_event_Sse(
-    RustStreamSink<FileAddedEvent> self,
+  void sse_encode_opt_box_autoadd_archive_options(
+    ArchiveOptions? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_file_added_event(
-    FileAddedEvent self,
+  void sse_encode_opt_box_autoadd_event_debug_info(
+    EventDebugInfo? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_opt_box_autoadd_file_processing_status(
+    FileProcessingStatus? self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_hash_algorithm(
+    HashAlgorithm? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_onboarding_step(
+    OnboardingStep? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_watch_filter(
+    WatchFilter? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_watch_tuning(
+    WatchTuning? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_watcher_degradation_policy(
+    WatcherDegradationPolicy? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_pdf_page_preview(
+    PdfPagePreview self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_policy_status(PolicyStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_rag_query_result(
+    RagQueryResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_report_file(ReportFile self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_rule_applied_event(
+    RuleAppliedEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_screenshot_added_event(
+    ScreenshotAddedEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_search_result_item(
+    SearchResultItem self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_telemetry_preview(
+    TelemetryPreview self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_timeline_granularity(
+    TimelineGranularity self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_transcription_options(
+    TranscriptionOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_transcription_result(
+    TranscriptionResult self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_type_count(TypeCount self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
 
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
@@ -185,10 +2655,70 @@ abstract class RustCoreApiImplPlatform extends BaseApiImpl<RustCoreWire> {
   void sse_encode_usize(BigInt self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_volume_info(VolumeInfo self, SseSerializer serializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_volume_kind(VolumeKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_watch_dir_recovery_mode(
+    WatchDirRecoveryMode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_watch_filter(WatchFilter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_watch_location_kind(
+    WatchLocationKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_watch_location_suggestion(
+    WatchLocationSuggestion self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_watch_started(WatchStarted self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_watch_status_event(
+    WatchStatusEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_watch_tree_stat(WatchTreeStat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_watch_tuning(WatchTuning self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_watcher_degradation_policy(
+    WatcherDegradationPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_watcher_info(WatcherInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_watcher_metrics(
+    WatcherMetrics self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_watcher_status_event(
+    WatcherStatusEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
 }
 
 // Section: wire_class
@@ -196,6 +2726,38 @@ abstract class RustCoreApiImplPlatform extends BaseApiImpl<RustCoreWire> {
 class RustCoreWire implements BaseWire {
   RustCoreWire.fromExternalLibrary(ExternalLibrary lib);
 
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+        ptr,
+      );
+
   void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
     int ptr,
@@ -219,6 +2781,26 @@ external RustCoreWasmModule get wasmModule;
 @JS()
 @anonymous
 extension type RustCoreWasmModule._(JSObject _) implements JSObject {
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCoreHandle(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerImageBuffer(
+    int ptr,
+  );
+
   external void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLateraError(
     int ptr,
@@ -1,97 +0,0 @@
-// This file is automatically generated, so please do not edit it.
-// @generated by `flutter_rust_bridge`@ 2.11.1.
-
-// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
-
-import '../frb_generated.dart';
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
-
-/// Опции OCR.
-///
-/// Передаются из Flutter-стороны для уважения пользовательских лимитов
-/// (`AppConfig.effectiveLimits`).
-class OcrOptions {
-  /// Максимальное количество страниц скан-PDF для обработки.
-  final int maxPagesPerPdf;
-
-  /// Максимальный размер файла в мегабайтах.
-  final int maxFileSizeMb;
-
-  /// Язык OCR (BCP-47 language tag, например "en", "ru", "de").
-  /// `None` = автоопределение из профиля пользователя.
-  final String? language;
-
-  const OcrOptions({
-    required this.maxPagesPerPdf,
-    required this.maxFileSizeMb,
-    this.language,
-  });
-
-  @override
-  int get hashCode =>
-      maxPagesPerPdf.hashCode ^ maxFileSizeMb.hashCode ^ language.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is OcrOptions &&
-          runtimeType == other.runtimeType &&
-          maxPagesPerPdf == other.maxPagesPerPdf &&
-          maxFileSizeMb == other.maxFileSizeMb &&
-          language == other.language;
-}
-
-/// Результат OCR.
-///
-/// Всегда возвращается (не `Result`), ошибки кодируются в `error_code`.
-class OcrResult {
-  /// Распознанный текст (может быть пуст при ошибке).
-  final String text;
-
-  /// Тип контента: `"image"`, `"scan_pdf"`, `"unsupported"`, `"unknown"`.
-  final String contentType;
-
-  /// Количество обработанных страниц (для скан-PDF; для изображений — 1 при успехе, 0 при ошибке).
-  final int pagesProcessed;
-
-  /// Уверенность распознавания (0.0 – 1.0), `None` при ошибке.
-  final double? confidence;
-
-  /// Код ошибки, если была:
-  /// - `None` — успех
-  /// - `"file_too_large"` — файл превышает лимит `max_file_size_mb`
-  /// - `"too_many_pages"` — скан-PDF превышает лимит (текст до лимита извлечён)
-  /// - `"unsupported_format"` — формат не поддерживается
-  /// - `"ocr_failed"` — внутренняя ошибка OCR-движка
-  /// - `"file_not_found"` — файл не найден / недоступен
-  /// - `"empty_image"` — изображение не содержит распознаваемого текста
-  /// - `"no_ocr_language"` — нет установленных языков OCR в системе
-  final String? errorCode;
-
-  const OcrResult({
-    required this.text,
-    required this.contentType,
-    required this.pagesProcessed,
-    this.confidence,
-    this.errorCode,
-  });
-
-  @override
-  int get hashCode =>
-      text.hashCode ^
-      contentType.hashCode ^
-      pagesProcessed.hashCode ^
-      confidence.hashCode ^
-      errorCode.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is OcrResult &&
-          runtimeType == other.runtimeType &&
-          text == other.text &&
-          contentType == other.contentType &&
-          pagesProcessed == other.pagesProcessed &&
-          confidence == other.confidence &&
-          errorCode == other.errorCode;
-}
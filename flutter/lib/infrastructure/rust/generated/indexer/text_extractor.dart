@@ -1,82 +0,0 @@
-// This file is automatically generated, so please do not edit it.
-// @generated by `flutter_rust_bridge`@ 2.11.1.
-
-// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
-
-import '../frb_generated.dart';
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
-
-/// Опции извлечения текста.
-///
-/// Передаются из Flutter-стороны для уважения пользовательских лимитов
-/// (`AppConfig.effectiveLimits`).
-class ExtractionOptions {
-  /// Максимальное количество страниц PDF для обработки.
-  final int maxPagesPerPdf;
-
-  /// Максимальный размер файла в мегабайтах.
-  final int maxFileSizeMb;
-
-  const ExtractionOptions({
-    required this.maxPagesPerPdf,
-    required this.maxFileSizeMb,
-  });
-
-  @override
-  int get hashCode => maxPagesPerPdf.hashCode ^ maxFileSizeMb.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ExtractionOptions &&
-          runtimeType == other.runtimeType &&
-          maxPagesPerPdf == other.maxPagesPerPdf &&
-          maxFileSizeMb == other.maxFileSizeMb;
-}
-
-/// Результат извлечения текста.
-///
-/// Всегда возвращается (не `Result`), ошибки кодируются в `error_code`.
-class ExtractionResult {
-  /// Извлечённый текст (может быть пуст при ошибке).
-  final String text;
-
-  /// Тип контента: `"pdf"`, `"docx"`, `"text"`, `"unsupported"`, `"unknown"`.
-  final String contentType;
-
-  /// Количество обработанных страниц (для PDF; для остальных — 0).
-  final int pagesExtracted;
-
-  /// Код ошибки, если была:
-  /// - `None` — успех
-  /// - `"file_too_large"` — файл превышает лимит `max_file_size_mb`
-  /// - `"too_many_pages"` — PDF превышает лимит (текст до лимита извлечён)
-  /// - `"unsupported_format"` — формат не поддерживается
-  /// - `"extraction_failed"` — внутренняя ошибка при извлечении
-  /// - `"file_not_found"` — файл не найден / недоступен
-  final String? errorCode;
-
-  const ExtractionResult({
-    required this.text,
-    required this.contentType,
-    required this.pagesExtracted,
-    this.errorCode,
-  });
-
-  @override
-  int get hashCode =>
-      text.hashCode ^
-      contentType.hashCode ^
-      pagesExtracted.hashCode ^
-      errorCode.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ExtractionResult &&
-          runtimeType == other.runtimeType &&
-          text == other.text &&
-          contentType == other.contentType &&
-          pagesExtracted == other.pagesExtracted &&
-          errorCode == other.errorCode;
-}
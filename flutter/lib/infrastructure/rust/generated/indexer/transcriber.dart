@@ -1,92 +0,0 @@
-// This file is automatically generated, so please do not edit it.
-// @generated by `flutter_rust_bridge`@ 2.11.1.
-
-// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
-
-import '../frb_generated.dart';
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
-
-/// Опции транскрибации.
-///
-/// Передаются из Flutter-стороны для уважения пользовательских лимитов
-/// (`AppConfig.effectiveLimits`).
-class TranscriptionOptions {
-  /// Максимальная длительность медиа для обработки (в минутах).
-  /// 0 = транскрибация отключена.
-  final int maxMediaMinutes;
-
-  /// Максимальный размер файла в мегабайтах.
-  final int maxFileSizeMb;
-
-  /// Язык для транскрибации (ISO 639-1, например "ru", "en").
-  /// `None` = автоопределение.
-  final String? language;
-
-  const TranscriptionOptions({
-    required this.maxMediaMinutes,
-    required this.maxFileSizeMb,
-    this.language,
-  });
-
-  @override
-  int get hashCode =>
-      maxMediaMinutes.hashCode ^ maxFileSizeMb.hashCode ^ language.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is TranscriptionOptions &&
-          runtimeType == other.runtimeType &&
-          maxMediaMinutes == other.maxMediaMinutes &&
-          maxFileSizeMb == other.maxFileSizeMb &&
-          language == other.language;
-}
-
-/// Результат транскрибации.
-///
-/// Всегда возвращается (не `Result`), ошибки кодируются в `error_code`.
-class TranscriptionResult {
-  /// Расшифрованный текст (может быть пуст при ошибке).
-  final String text;
-
-  /// Тип контента: `"audio"`, `"video"`, `"unsupported"`, `"unknown"`.
-  final String contentType;
-
-  /// Длительность обработанного медиа в секундах.
-  final int durationSeconds;
-
-  /// Код ошибки, если была:
-  /// - `None` — успех
-  /// - `"file_too_large"` — файл превышает лимит `max_file_size_mb`
-  /// - `"media_too_long"` — медиа превышает лимит `max_media_minutes`
-  /// - `"unsupported_format"` — формат не поддерживается
-  /// - `"transcription_disabled"` — транскрибация отключена (`max_media_minutes == 0`)
-  /// - `"transcription_failed"` — внутренняя ошибка
-  /// - `"file_not_found"` — файл не найден / недоступен
-  /// - `"not_implemented"` — Whisper ещё не подключён
-  final String? errorCode;
-
-  const TranscriptionResult({
-    required this.text,
-    required this.contentType,
-    required this.durationSeconds,
-    this.errorCode,
-  });
-
-  @override
-  int get hashCode =>
-      text.hashCode ^
-      contentType.hashCode ^
-      durationSeconds.hashCode ^
-      errorCode.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is TranscriptionResult &&
-          runtimeType == other.runtimeType &&
-          text == other.text &&
-          contentType == other.contentType &&
-          durationSeconds == other.durationSeconds &&
-          errorCode == other.errorCode;
-}
@@ -3,6 +3,7 @@ import 'dart:io';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 import 'package:logger/logger.dart';
 import 'package:path/path.dart' as p;
+import 'generated/api.dart' as rust_api;
 import 'generated/frb_generated.dart';
 import '../logging/app_logger.dart';
 
@@ -17,11 +18,18 @@ import '../logging/app_logger.dart';
 class RustCoreBootstrap {
   static bool _initialized = false;
   static Future<void>? _initFuture;
+  static rust_api.CoreHandle? _handle;
   static final Logger _log = AppLogger.create();
 
   /// Возвращает true, если Rust Core успешно инициализирован.
   static bool get isInitialized => _initialized;
 
+  /// Handle ядра главного окна; `null` до инициализации.
+  ///
+  /// Хранится всё время работы приложения: освобождённый handle
+  /// останавливает свои watcher'ы.
+  static rust_api.CoreHandle? get handle => _handle;
+
   /// Инициализирует Rust Core, гарантируя единственный вызов.
   ///
   /// Использует кешированный Future для защиты от гонок при параллельных
@@ -42,6 +50,17 @@ class RustCoreBootstrap {
       final externalLibrary = _resolveExternalLibrary();
 
       await RustCore.init(externalLibrary: externalLibrary);
+      // Без init_core остальные API ядра возвращают CoreNotInitialized.
+      // Папка данных — по умолчанию ядра, индекс открывается отдельно
+      // (initIndex в composition root).
+      final handle = await rust_api.initCore(
+        config: const rust_api.CoreConfig(),
+      );
+      final caps = await handle.capabilities();
+      _handle = handle;
+      _log.i(
+        'Rust Core ${caps.coreVersion} initialized (data dir: ${caps.dataDir})',
+      );
       _initialized = true;
     } catch (e) {
       // Сбрасываем Future чтобы позволить повторную попытку инициализации
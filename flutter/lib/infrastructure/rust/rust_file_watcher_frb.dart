@@ -20,6 +20,9 @@ class RustFileWatcherFrb implements FileWatcher {
   StreamSubscription<FileSystemEvent>? _fsSub;
   bool _isWatching = false;
 
+  /// `watcher_id` запущенного Rust watcher'а (для [stopWatching]).
+  String? _watcherId;
+
   StreamController<FileAddedEvent> get _eventsController =>
       _controller ??= StreamController<FileAddedEvent>.broadcast();
 
@@ -75,6 +78,16 @@ class RustFileWatcherFrb implements FileWatcher {
             fileName: e.fileName,
             fullPath: e.fullPath,
             occurredAt: DateTime.fromMillisecondsSinceEpoch(e.occurredAtMs),
+            // 0 = birthtime недоступен на этой платформе/ФС.
+            createdAt: e.createdAtMs > 0
+                ? DateTime.fromMillisecondsSinceEpoch(e.createdAtMs)
+                : null,
+            modifiedAt: e.modifiedAtMs > 0
+                ? DateTime.fromMillisecondsSinceEpoch(e.modifiedAtMs)
+                : null,
+            sizeBytes: e.sizeBytes,
+            extension: e.extension,
+            mimeType: e.mimeType,
           ),
         );
       },
@@ -93,14 +106,34 @@ class RustFileWatcherFrb implements FileWatcher {
 
     // Запуск watcher'а
     try {
-      final watchDir = await rust_api.startWatching(overridePath: overridePath);
+      final started = await rust_api.startWatching(
+        overridePath: overridePath,
+      );
+      _watcherId = started.watcherId;
+      final watchDir =
+          overridePath ?? await rust_api.getDefaultWatchPathPreview();
       _isWatching = true;
-      _log.i('Rust watcher started. dir=$watchDir');
+      _log.i(
+        'Rust watcher started. id=${started.watcherId} dir=$watchDir '
+        'backend=${started.backend.name}',
+      );
 
       // Запускаем Dart-сторонний мониторинг удалений файлов
       _startDartDeleteWatcher(watchDir);
 
       return WatchSuccess(watchDir);
+    } on rust_api.LateraApiError catch (e, st) {
+      _log.e('Failed to start Rust watcher', error: e, stackTrace: st);
+      await _sub?.cancel();
+      _sub = null;
+      return WatchFailure(
+        WatcherError.fromCode(
+          e.code,
+          e.message,
+          originalError: e,
+          stackTrace: st,
+        ),
+      );
     } catch (e, st) {
       _log.e('Failed to start Rust watcher', error: e, stackTrace: st);
       // При ошибке запуска отменяем подписку, чтобы избежать утечки
@@ -117,8 +150,20 @@ class RustFileWatcherFrb implements FileWatcher {
     CoreError? error;
 
     // Сначала останавливаем Rust watcher
+    final watcherId = _watcherId;
+    _watcherId = null;
     try {
-      await rust_api.stopWatching();
+      if (watcherId != null) {
+        await rust_api.stopWatching(watcherId: watcherId);
+      }
+    } on rust_api.LateraApiError catch (e, st) {
+      _log.w('stopWatching failed in Rust', error: e, stackTrace: st);
+      error = WatcherError.fromCode(
+        e.code,
+        e.message,
+        originalError: e,
+        stackTrace: st,
+      );
     } catch (e, st) {
       _log.w('stopWatching failed in Rust', error: e, stackTrace: st);
       error = WatcherError.fromRust(e, st);
@@ -4,23 +4,116 @@
 //! Должен оставаться тонким слоем: только типы/функции, которые экспортируются
 //! через bridge. Вся логика — в `src/*` модулях.
 //!
-//! NOTE: Новая версия API (FileEvent, ApiVersion, LateraApiError) временно
+//! NOTE: Новая версия API (FileEvent, ApiVersion) временно
 //! отключена до ручной генерации FRB (codegen падает на Windows с prefix not found).
 //! См. планы в `plans/runbook.md`.
 
 use once_cell::sync::Lazy;
-use std::path::Path;
-use std::sync::Mutex;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 
+use crate::adoption;
+use crate::archive;
+use crate::audit_log;
+use crate::claims;
+use crate::codes;
+use crate::composition;
+use crate::config;
+use crate::desktop_access;
+use crate::disk_space;
+use crate::dry_run;
+use crate::duplicates;
+use crate::email;
 use crate::error::LateraError;
+use crate::event_ack;
+use crate::event_chunk;
+use crate::event_wal;
+use crate::file_lock;
+use crate::file_metadata;
+use crate::file_ops;
+use crate::file_status;
+use crate::file_type;
 use crate::file_watcher;
 use crate::frb_generated;
+use crate::hashing;
+use crate::heartbeat;
 use crate::indexer;
+use crate::intake;
+use crate::integrity;
+use crate::internal_files;
+use crate::journal;
+use crate::lifecycle;
 use crate::logging;
+use crate::metrics;
+use crate::name_conflict;
+use crate::natural_sort;
+use crate::onboarding;
+use crate::path_utils;
+use crate::pdf_layout;
+use crate::policy;
+use crate::portable_config::{self, PortableConfig};
+use crate::preview;
+use crate::quota;
+use crate::read_only;
+use crate::rules;
+use crate::settings;
+use crate::shared_bytes;
+use crate::tags;
+use crate::telemetry::{self, CounterKind};
+use crate::thumbnails;
+use crate::trash;
+use crate::volume;
+use crate::xattr;
+use flutter_rust_bridge::frb;
 use log::warn;
 
 use rusqlite::Connection;
 
+/// Ошибка API в структурированном виде.
+///
+/// В отличие от [`LateraError`] (непрозрачный тип для Dart, разбираемый по
+/// строке `Display`), поля доступны Dart напрямую. Функции API переводятся
+/// на этот тип постепенно; пока его возвращают [`start_watching`] и
+/// [`stop_watching`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LateraApiError {
+    /// Машиночитаемый код (`WATCHER_ALREADY_RUNNING`, `INVALID_PATH`…),
+    /// см. [`LateraError::code`].
+    pub code: String,
+    /// Сообщение без префикса `LateraError::Variant:`.
+    pub message: String,
+    /// Можно продолжить работу (повторить после очевидного действия).
+    pub recoverable: bool,
+    /// Подробности для логов (вид ошибки ввода-вывода, путь).
+    pub context: Option<String>,
+}
+
+impl std::fmt::Display for LateraApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.code, self.message)
+    }
+}
+
+impl std::error::Error for LateraApiError {}
+
+impl From<LateraError> for LateraApiError {
+    fn from(error: LateraError) -> Self {
+        let display = error.to_string();
+        let message = display
+            .split_once(": ")
+            .map_or(display.as_str(), |(_, message)| message)
+            .to_string();
+        Self {
+            code: error.code().to_string(),
+            message,
+            recoverable: error.is_recoverable(),
+            context: error.context(),
+        }
+    }
+}
+
 /// Событие: добавлен новый файл.
 ///
 /// Поля подобраны так, чтобы их было удобно бриджить во Flutter.
@@ -28,7 +121,159 @@ use rusqlite::Connection;
 pub struct FileAddedEvent {
     pub file_name: String,
     pub full_path: String,
+    /// Время события: момент обнаружения или mtime (см. [`set_event_timestamp_source`]).
+    pub occurred_at_ms: i64,
+    /// Момент обнаружения по UTC wall clock (Unix timestamp в миллисекундах).
+    pub detected_at_ms: i64,
+    /// Монотонное время обнаружения (мс с запуска ядра) — для упорядочивания.
+    pub monotonic_ms: i64,
+    /// Время создания файла (birthtime). `0` = платформа/ФС не поддерживает.
+    pub created_at_ms: i64,
+    /// Время последнего изменения файла (mtime). `0` = недоступно.
+    pub modified_at_ms: i64,
+    /// Размер файла в байтах; при стабилизации ([`set_settle_quiet_period`]) —
+    /// итоговый.
+    pub size_bytes: u64,
+    /// Расширение в нижнем регистре, без точки.
+    pub extension: Option<String>,
+    /// MIME-тип по содержимому (`None` — файл пуст или не читается).
+    pub mime_type: Option<String>,
+    /// Владелец и права текущего пользователя (`None` — не удалось прочитать).
+    pub access: Option<ApiFileAccessInfo>,
+}
+
+/// Вид события файла (см. [`FileEvent`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileEventKind {
+    Created,
+    Modified,
+    Removed,
+    /// Старый путь переименованного/перемещённого файла.
+    RenamedFrom,
+    /// Новый путь переименованного/перемещённого файла.
+    RenamedTo,
+    /// В появившемся изображении найдены коды ([`FileEvent::codes`]).
+    /// Приходит после события появления, если включён
+    /// [`set_code_scanning`].
+    CodesDetected,
+}
+
+impl From<file_watcher::FileEventKind> for FileEventKind {
+    fn from(kind: file_watcher::FileEventKind) -> Self {
+        match kind {
+            file_watcher::FileEventKind::Created => Self::Created,
+            file_watcher::FileEventKind::Modified => Self::Modified,
+            file_watcher::FileEventKind::Removed => Self::Removed,
+            file_watcher::FileEventKind::RenamedFrom => Self::RenamedFrom,
+            file_watcher::FileEventKind::RenamedTo => Self::RenamedTo,
+        }
+    }
+}
+
+/// Событие файла любого вида: создание, изменение, удаление, переименование.
+///
+/// Обобщение [`FileAddedEvent`] — по нему UI держит список файлов в
+/// актуальном состоянии. Для удалённых файлов и старого пути переименования
+/// `created_at_ms`/`modified_at_ms`/`size_bytes` = `0`.
+#[derive(Clone, Debug)]
+pub struct FileEvent {
+    /// Номер события в процессе (по порядку отправки); по нему
+    /// [`get_event_debug_info`] отдаёт отладочный контекст.
+    pub sequence: u64,
+    /// Watcher, от которого пришло событие (см. [`start_watching`]).
+    pub watcher_id: String,
+    pub kind: FileEventKind,
+    pub file_name: String,
+    pub full_path: String,
+    /// Прежний путь для `RenamedTo`, если платформа сообщила обе стороны.
+    pub previous_path: Option<String>,
     pub occurred_at_ms: i64,
+    pub detected_at_ms: i64,
+    pub monotonic_ms: i64,
+    pub created_at_ms: i64,
+    pub modified_at_ms: i64,
+    /// Размер файла; при стабилизации ([`set_settle_quiet_period`]) — итоговый.
+    pub size_bytes: u64,
+    pub extension: Option<String>,
+    pub mime_type: Option<String>,
+    /// Владелец и права текущего пользователя; для удалённых файлов и
+    /// старого пути переименования — `None`.
+    pub access: Option<ApiFileAccessInfo>,
+    /// Изменение сделано самим ядром (очистка по квоте, запись атрибутов…).
+    ///
+    /// Такие появления файлов не дублируются в [`on_file_added`].
+    pub self_generated: bool,
+    /// Изменение произошло, пока приложение было закрыто: найдено сверкой
+    /// со снимком папки при запуске (см. [`set_offline_change_detection`]).
+    pub reconciled: bool,
+    /// Кому достался появившийся файл, если включено согласование общей
+    /// папки (см. [`set_shared_folder_claims`]); иначе `None`.
+    pub claim: Option<ApiClaimStatus>,
+    /// QR-коды и штрихкоды изображения для `CodesDetected`; у остальных
+    /// событий пусто.
+    pub codes: Vec<ApiDetectedCode>,
+}
+
+/// Кому достался появившийся файл общей папки (см. [`set_shared_folder_claims`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ApiClaimStatus {
+    /// Файл забран этим экземпляром: автоматические действия выполняет он.
+    Claimed,
+    /// Файл забран экземпляром `owner` (`пользователь@машина`).
+    ClaimedByOther { owner: String, claimed_at_ms: i64 },
+    /// Claim-файл не создать (нет прав, режим «только наблюдение»).
+    Unavailable { reason: String },
+}
+
+impl From<claims::ClaimStatus> for ApiClaimStatus {
+    fn from(status: claims::ClaimStatus) -> Self {
+        match status {
+            claims::ClaimStatus::Claimed => Self::Claimed,
+            claims::ClaimStatus::ClaimedByOther {
+                owner,
+                claimed_at_ms,
+            } => Self::ClaimedByOther {
+                owner,
+                claimed_at_ms,
+            },
+            claims::ClaimStatus::Unavailable { reason } => Self::Unavailable { reason },
+        }
+    }
+}
+
+/// Пачка появившихся файлов, не прошедших rate-limit watcher'а
+/// (`rate_limit_per_second` в [`get_config`] или
+/// [`WatchTuning::max_events_per_second`]) — например, при массовом
+/// копировании.
+///
+/// Приходит раз в секунду в [`on_file_batch`] вместо отдельных
+/// [`FileAddedEvent`]. События пачки, как и отдельные, записываются в WAL:
+/// не принятая stream'ом пачка доставляется повторно по одному событию в
+/// [`on_file_added`] при следующем [`start_watching`], а в режиме
+/// подтверждения ([`set_ack_mode`]) каждое событие пачки приходит
+/// и в ackable stream и ждёт [`ack_event`].
+#[derive(Clone, Debug)]
+pub struct FileBatchEvent {
+    /// Watcher, от которого пришла пачка; при переносе папки
+    /// ([`adopt_folder`]) — `adopt-{adoption_id}`.
+    pub watcher_id: String,
+    pub events: Vec<FileAddedEvent>,
+}
+
+/// Чанк появившихся файлов: вместо [`FileBatchEvent`] при включённой
+/// передаче чанками (см. [`set_chunked_transfer`]).
+///
+/// `payload` — до N событий в компактном двоичном формате `LEC2`
+/// (описан в `event_chunk.rs`): общие папки, расширения и MIME-типы
+/// хранятся один раз, времена — разностями. Поля событий — как у
+/// [`FileAddedEvent`].
+#[derive(Clone, Debug)]
+pub struct FileChunkEvent {
+    /// Watcher, от которого пришёл чанк (как у [`FileBatchEvent`]).
+    pub watcher_id: String,
+    /// Число событий в `payload`.
+    pub count: u32,
+    pub payload: Vec<u8>,
 }
 
 /// Событие: файл удалён.
@@ -39,27 +284,232 @@ pub struct FileRemovedEvent {
     pub occurred_at_ms: i64,
 }
 
-static FILE_ADDED_SINK: Lazy<Mutex<Option<frb_generated::StreamSink<FileAddedEvent>>>> =
-    Lazy::new(|| Mutex::new(None));
+/// Streams событий файлов: общие (`on_file_*`) или своего [`CoreHandle`].
+#[derive(Default)]
+struct EventSinks {
+    added: Mutex<Option<frb_generated::StreamSink<FileAddedEvent>>>,
+    event: Mutex<Option<frb_generated::StreamSink<FileEvent>>>,
+    batch: Mutex<Option<frb_generated::StreamSink<FileBatchEvent>>>,
+    chunk: Mutex<Option<frb_generated::StreamSink<FileChunkEvent>>>,
+}
 
-static FILE_REMOVED_SINK: Lazy<Mutex<Option<frb_generated::StreamSink<FileRemovedEvent>>>> =
-    Lazy::new(|| Mutex::new(None));
+impl EventSinks {
+    /// Закрывает streams (onDone во Flutter): FRB stream закрывается при
+    /// Drop последнего `StreamSink`.
+    fn close(&self) {
+        let _dropped = (
+            take_slot(&self.added),
+            take_slot(&self.event),
+            take_slot(&self.batch),
+            take_slot(&self.chunk),
+        );
+    }
+}
 
-static WATCHER: Lazy<Mutex<Option<file_watcher::WatcherHandle>>> = Lazy::new(|| Mutex::new(None));
+fn take_slot<T>(slot: &Mutex<Option<T>>) -> Option<T> {
+    slot.lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take()
+}
 
-fn close_file_added_stream() {
-    // FRB stream закрывается при Drop последнего `StreamSink` (см. StreamSinkCloser).
-    // Поэтому достаточно вынуть sink из глобального хранилища и дать ему дропнуться.
-    // Примечание: recover from poisoned mutex - если предыдущий поток паниковал,
-    // мы всё равно можем безопасно извлечь данные.
-    let _dropped = FILE_ADDED_SINK
+/// Заменяет sink в `slot`; прежний stream закрывается.
+fn bind_sink<T>(slot: &Mutex<Option<T>>, sink: T, name: &str) {
+    let mut guard = slot
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_some() {
+        warn!("{name} called while previous stream is still bound; closing previous stream");
+    }
+    *guard = Some(sink);
+}
+
+/// Хранилища, в которые пишут события watcher'ов: статусы, теги, журнал.
+///
+/// Каждое открывается при первом обращении в `data_dir`.
+#[derive(Default)]
+struct CoreStores {
+    /// `None` — папка данных ядра ([`lifecycle::data_dir`]).
+    data_dir: Option<PathBuf>,
+    file_status: Mutex<Option<file_status::FileStatusStore>>,
+    tags: Mutex<Option<tags::TagStore>>,
+    journal: Mutex<Option<journal::EventJournal>>,
+}
+
+impl CoreStores {
+    fn in_dir(data_dir: PathBuf) -> Self {
+        Self {
+            data_dir: Some(data_dir),
+            ..Self::default()
+        }
+    }
+
+    fn db_path(&self, file_name: &str) -> Result<PathBuf, LateraError> {
+        let dir = match &self.data_dir {
+            Some(dir) => dir.clone(),
+            None => lifecycle::data_dir()?,
+        };
+        Ok(dir.join(file_name))
+    }
+
+    fn with_file_status<F, T>(&self, f: F) -> Result<T, LateraError>
+    where
+        F: FnOnce(&mut file_status::FileStatusStore) -> Result<T, LateraError>,
+    {
+        let mut guard = self
+            .file_status
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if guard.is_none() {
+            let db_path = self.db_path(file_status::FILE_STATUS_DB_FILE)?;
+            *guard = Some(file_status::FileStatusStore::open(&db_path)?);
+        }
+        match guard.as_mut() {
+            Some(store) => f(store),
+            None => Err(LateraError::CoreNotInitialized),
+        }
+    }
+
+    fn with_tags<F, T>(&self, f: F) -> Result<T, LateraError>
+    where
+        F: FnOnce(&tags::TagStore) -> Result<T, LateraError>,
+    {
+        let mut guard = self
+            .tags
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if guard.is_none() {
+            let db_path = self.db_path(tags::TAGS_DB_FILE)?;
+            *guard = Some(tags::TagStore::open(&db_path)?);
+        }
+        match guard.as_ref() {
+            Some(store) => f(store),
+            None => Err(LateraError::CoreNotInitialized),
+        }
+    }
+
+    fn with_journal<F, T>(&self, f: F) -> Result<T, LateraError>
+    where
+        F: FnOnce(&journal::EventJournal) -> Result<T, LateraError>,
+    {
+        let mut guard = self
+            .journal
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if guard.is_none() {
+            let db_path = self.db_path(journal::JOURNAL_DB_FILE)?;
+            *guard = Some(journal::EventJournal::open(&db_path)?);
+        }
+        match guard.as_ref() {
+            Some(journal) => f(journal),
+            None => Err(LateraError::CoreNotInitialized),
+        }
+    }
+
+    /// Закрывает соединения; следующее обращение откроет их заново.
+    fn close(&self) {
+        let _dropped = (
+            take_slot(&self.file_status),
+            take_slot(&self.tags),
+            take_slot(&self.journal),
+        );
+    }
+}
+
+/// Куда пишут watcher'ы: общие streams и хранилища или своего [`CoreHandle`].
+#[derive(Default)]
+struct CoreScope {
+    sinks: EventSinks,
+    stores: CoreStores,
+}
+
+/// Streams и хранилища функций без handle'а.
+static SHARED_SCOPE: Lazy<Arc<CoreScope>> = Lazy::new(Arc::default);
+
+/// Scope watcher'ов, запущенных через [`CoreHandle`], по `watcher_id`;
+/// остальные пишут в [`SHARED_SCOPE`]. Запись убирается, только когда
+/// watcher остановлен: его последние события не уходят в общие streams.
+static HANDLE_SCOPES: Lazy<Mutex<HashMap<String, Arc<CoreScope>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Scope живых [`CoreHandle`] — чтобы [`shutdown_core`] закрыл и их.
+static LIVE_SCOPES: Lazy<Mutex<Vec<std::sync::Weak<CoreScope>>>> =
+    Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Куда пишет watcher `watcher_id`.
+fn watcher_scope(watcher_id: &str) -> Arc<CoreScope> {
+    HANDLE_SCOPES
         .lock()
         .unwrap_or_else(std::sync::PoisonError::into_inner)
-        .take();
-    // _dropped будет дропнут здесь, закрывая stream
-    log::debug!("File added stream closed");
+        .get(watcher_id)
+        .map_or_else(|| Arc::clone(&SHARED_SCOPE), Arc::clone)
+}
+
+/// Вызывает `f` для scope каждого живого [`CoreHandle`].
+fn for_each_live_scope(f: impl Fn(&CoreScope)) {
+    LIVE_SCOPES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .retain(|scope| match scope.upgrade() {
+            Some(scope) => {
+                f(&scope);
+                true
+            }
+            None => false,
+        });
+}
+
+/// Закрывает хранилища общего scope и всех живых handle'ов.
+fn close_core_stores() {
+    SHARED_SCOPE.stores.close();
+    for_each_live_scope(|scope| scope.stores.close());
+}
+
+static FILE_REMOVED_SINK: Lazy<Mutex<Option<frb_generated::StreamSink<FileRemovedEvent>>>> =
+    Lazy::new(|| Mutex::new(None));
+
+/// Событий в чанке; `None` — пачки отдаются как [`FileBatchEvent`].
+static FILE_CHUNK_SIZE: Lazy<Mutex<Option<usize>>> = Lazy::new(|| Mutex::new(None));
+
+/// Запущенный watcher и привязанные к его папке мониторы.
+struct ActiveWatcher {
+    handle: file_watcher::WatcherHandle,
+    recursive: bool,
+    /// Фильтр файлов — для [`verify_folder_integrity`].
+    filter: file_watcher::WatchFilter,
+    /// Как запущен — для [`export_config`].
+    launch: portable_config::WatcherEntry,
+    started_at_ms: i64,
+    disk_space_monitor: Option<disk_space::DiskSpaceMonitorHandle>,
+    quota_monitor: Option<quota::QuotaMonitorHandle>,
+    archive_monitor: Option<archive::ArchiveMonitorHandle>,
 }
 
+impl ActiveWatcher {
+    /// Останавливает мониторы, затем сам watcher (ждёт его не дольше `timeout`).
+    fn stop(self, timeout: std::time::Duration) -> Result<(), LateraError> {
+        if let Some(monitor) = self.disk_space_monitor {
+            monitor.stop();
+        }
+        if let Some(monitor) = self.quota_monitor {
+            monitor.stop();
+        }
+        if let Some(monitor) = self.archive_monitor {
+            monitor.stop();
+        }
+        self.handle.stop_with_timeout(timeout)
+    }
+}
+
+/// Запущенные watcher'ы по `watcher_id`.
+static WATCHERS: Lazy<Mutex<BTreeMap<String, ActiveWatcher>>> =
+    Lazy::new(|| Mutex::new(BTreeMap::new()));
+
+/// Счётчик для генерации `watcher_id`.
+static NEXT_WATCHER_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Счётчик [`FileEvent::sequence`].
+static NEXT_EVENT_SEQUENCE: AtomicU64 = AtomicU64::new(1);
+
 fn close_file_removed_stream() {
     let _dropped = FILE_REMOVED_SINK
         .lock()
@@ -68,822 +518,7962 @@ fn close_file_removed_stream() {
     log::debug!("File removed stream closed");
 }
 
-/// Инициализация логирования в Rust.
-///
-/// Можно вызвать из Flutter сразу после старта.
-pub fn init_logging() {
-    logging::init_logging();
+/// Записать событие в журнал и отправить в [`on_file_event`].
+fn emit_file_event(watcher_id: &str, event: &file_watcher::InternalFileEvent) {
+    note_onboarding_first_event(event.detected_at_ms);
+    record_journal_event(watcher_id, event);
+    update_folder_composition(event);
+    send_file_event(watcher_id, event, event.kind.into(), Vec::new());
 }
 
-/// Stream событий добавления файла.
-///
-/// В Dart это будет выглядеть как `Stream<FileAddedEvent> onFileAdded()`.
-///
-/// Контракт:
-/// - один активный подписчик;
-/// - при вызове [`stop_watching`](crate::api::stop_watching) стрим закрывается (onDone во Flutter);
-/// - при повторном старте подписка создаётся заново.
-pub fn on_file_added(sink: frb_generated::StreamSink<FileAddedEvent>) {
-    logging::init_logging();
-
-    // Контракт: один активный подписчик. Если подписчик уже есть — закрываем
-    // старый stream и заменяем sink новым.
-    // Примечание: recover from poisoned mutex - если предыдущий поток паниковал,
-    // мы всё равно можем безопасно продолжить работу.
-    let mut guard = FILE_ADDED_SINK
+/// Отправить событие в [`on_file_event`] под очередным номером.
+fn send_file_event(
+    watcher_id: &str,
+    event: &file_watcher::InternalFileEvent,
+    kind: FileEventKind,
+    codes: Vec<ApiDetectedCode>,
+) {
+    let sequence = NEXT_EVENT_SEQUENCE.fetch_add(1, Ordering::Relaxed);
+    record_event_debug_info(sequence, event);
+    if let Some(sink) = watcher_scope(watcher_id)
+        .sinks
+        .event
         .lock()
-        .unwrap_or_else(std::sync::PoisonError::into_inner);
-    if guard.is_some() {
-        warn!("on_file_added called while previous stream is still bound; closing previous stream");
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+    {
+        let result = sink.add(FileEvent {
+            sequence,
+            watcher_id: watcher_id.to_string(),
+            kind,
+            file_name: event.file_name.clone(),
+            full_path: event.full_path.to_string_lossy().to_string(),
+            previous_path: event
+                .previous_path
+                .as_ref()
+                .map(|p| p.to_string_lossy().to_string()),
+            occurred_at_ms: event.occurred_at_ms,
+            detected_at_ms: event.detected_at_ms,
+            monotonic_ms: event.monotonic_ms,
+            created_at_ms: event.created_at_ms.unwrap_or(0),
+            modified_at_ms: event.modified_at_ms.unwrap_or(0),
+            size_bytes: event.size_bytes.unwrap_or(0),
+            extension: event.extension.clone(),
+            mime_type: event.mime_type.clone(),
+            access: event.access.clone().map(Into::into),
+            self_generated: event.self_generated,
+            reconciled: event.reconciled,
+            claim: event.claim.clone().map(Into::into),
+            codes,
+        });
+        if let Err(e) = result {
+            log::warn!("Failed to emit file event (stream closed): {e}");
+        }
     }
-    *guard = Some(sink);
 }
 
-/// Stream событий удаления файла.
+/// Write-ahead log событий добавления файла.
 ///
-/// В Dart это будет выглядеть как `Stream<FileRemovedEvent> onFileRemoved()`.
-pub fn on_file_removed(sink: frb_generated::StreamSink<FileRemovedEvent>) {
-    logging::init_logging();
+/// Открывается при первом [`start_watching`] или [`set_ack_mode`] в папке
+/// данных ядра.
+/// `None` = WAL не открыт (события отправляются без гарантии доставки).
+static EVENT_WAL: Lazy<Mutex<Option<event_wal::EventWal>>> = Lazy::new(|| Mutex::new(None));
 
-    let mut guard = FILE_REMOVED_SINK
+fn open_event_wal() {
+    let mut guard = EVENT_WAL
         .lock()
         .unwrap_or_else(std::sync::PoisonError::into_inner);
     if guard.is_some() {
-        warn!(
-            "on_file_removed called while previous stream is still bound; closing previous stream"
-        );
+        return;
+    }
+
+    let wal = lifecycle::data_dir()
+        .and_then(|dir| event_wal::EventWal::open(&dir.join(event_wal::EVENT_WAL_FILE)));
+    match wal {
+        Ok(wal) => *guard = Some(wal),
+        // Без WAL watcher продолжает работать — лучше доставлять события
+        // без гарантии, чем не доставлять вовсе.
+        Err(e) => log::error!("Failed to open event WAL, delivery is not guaranteed: {e}"),
     }
-    *guard = Some(sink);
 }
 
-/// Запуск мониторинга.
-///
-/// - Если `override_path` = `None` → используется дефолтный `Desktop/Latera`.
-/// - Если `Some` → должен быть абсолютный путь; директория будет создана при отсутствии.
-///
-/// Возвращает фактический путь директории наблюдения (для отображения в UI).
-pub fn start_watching(override_path: Option<String>) -> Result<String, LateraError> {
-    logging::init_logging();
+fn close_event_wal() {
+    let _dropped = EVENT_WAL
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+}
 
-    // Примечание: recover from poisoned mutex - если предыдущий поток паниковал,
-    // мы всё равно можем безопасно продолжить работу.
-    let mut guard = WATCHER
+fn to_file_added_event(event: &file_watcher::InternalFileEvent) -> FileAddedEvent {
+    FileAddedEvent {
+        file_name: event.file_name.clone(),
+        full_path: event.full_path.to_string_lossy().to_string(),
+        occurred_at_ms: event.occurred_at_ms,
+        detected_at_ms: event.detected_at_ms,
+        monotonic_ms: event.monotonic_ms,
+        created_at_ms: event.created_at_ms.unwrap_or(0),
+        modified_at_ms: event.modified_at_ms.unwrap_or(0),
+        size_bytes: event.size_bytes.unwrap_or(0),
+        extension: event.extension.clone(),
+        mime_type: event.mime_type.clone(),
+        access: event.access.clone().map(Into::into),
+    }
+}
+
+/// Отправить событие в stream.
+///
+/// Возвращает `true`, если sink принял событие.
+fn send_file_added(sinks: &EventSinks, event: &file_watcher::InternalFileEvent) -> bool {
+    // Emit события в stream. Если stream закрыт — логируем и продолжаем.
+    if let Some(sink) = sinks
+        .added
         .lock()
-        .unwrap_or_else(std::sync::PoisonError::into_inner);
-    if guard.is_some() {
-        return Err(LateraError::WatcherAlreadyRunning);
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+    {
+        match sink.add(to_file_added_event(event)) {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!("Failed to emit file added event (stream closed): {e}");
+                false
+            }
+        }
+    } else {
+        log::debug!("File added event kept pending (no active stream subscriber)");
+        false
     }
+}
 
-    let handle = file_watcher::start_watcher(
-        override_path,
-        |event| {
-            // Emit события в stream. Если stream закрыт — логируем и продолжаем.
-            if let Some(sink) = FILE_ADDED_SINK
-                .lock()
-                .unwrap_or_else(std::sync::PoisonError::into_inner)
-                .as_ref()
-            {
-                if let Err(e) = sink.add(FileAddedEvent {
-                    file_name: event.file_name,
-                    full_path: event.full_path.to_string_lossy().to_string(),
-                    occurred_at_ms: event.occurred_at_ms,
-                }) {
-                    log::warn!("Failed to emit file added event (stream closed): {e}");
-                }
-            } else {
-                log::debug!("File added event dropped (no active stream subscriber)");
+/// Записать событие в WAL, затем отправить в stream.
+///
+/// Событие помечается доставленным только после того, как sink его принял.
+/// WAL и подтверждения — только у общего stream: события watcher'а
+/// [`CoreHandle`] уходят в его stream сразу.
+fn emit_file_added(watcher_id: &str, event: &file_watcher::InternalFileEvent) {
+    let scope = watcher_scope(watcher_id);
+    if !Arc::ptr_eq(&scope, &SHARED_SCOPE) {
+        send_file_added(&scope.sinks, event);
+        return;
+    }
+    let sequence = append_to_wal(event);
+    let delivered = send_file_added(&scope.sinks, event);
+    if let Some(seq) = sequence {
+        finish_wal_entry(seq, event, delivered);
+    }
+}
+
+/// Записать событие в WAL. `None` — WAL не открыт или запись не удалась.
+fn append_to_wal(event: &file_watcher::InternalFileEvent) -> Option<u64> {
+    EVENT_WAL
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_mut()
+        .and_then(|wal| match wal.append(event) {
+            Ok(seq) => Some(seq),
+            Err(e) => {
+                log::warn!("Failed to append event to WAL: {e}");
+                None
             }
-        },
-        |event| {
-            // Emit события удаления в stream.
-            // NOTE: Временно отключено — FRB codegen не генерирует SseEncode
-            // для FileRemovedEvent. Будет включено после пересборки bindings.
-            log::debug!(
-                "File removed event: {} (stream emit disabled pending FRB codegen fix)",
-                event.file_name
-            );
-        },
-    )?;
+        })
+}
+
+/// Запись WAL после отправки события в stream.
+fn finish_wal_entry(sequence: u64, event: &file_watcher::InternalFileEvent, delivered: bool) {
+    // В режиме подтверждения событие остаётся в WAL до `ack_event`.
+    if is_ack_mode_enabled() {
+        send_ackable_file_added(sequence, event);
+    } else if delivered {
+        mark_event_delivered(sequence);
+    }
+}
 
-    let watch_dir = handle.watch_dir().to_string_lossy().to_string();
-    *guard = Some(handle);
-    Ok(watch_dir)
+fn mark_event_delivered(sequence: u64) {
+    if let Some(wal) = EVENT_WAL
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_mut()
+    {
+        if let Err(e) = wal.mark_delivered(sequence) {
+            log::warn!("Failed to mark event {sequence} as delivered: {e}");
+        }
+    }
 }
 
-/// Получить дефолтный путь наблюдения (Desktop/Latera).
+/// Повторно доставить события, не подтверждённые ранее (в порядке записи).
 ///
-/// Создаёт директорию, если она не существует.
-/// Не запускает watcher — только возвращает путь.
+/// Останавливается на первом событии, которое sink не принял, — оставшиеся
+/// будут доставлены при следующем запуске.
+fn redeliver_pending_events() {
+    // В режиме подтверждения повторной доставкой занимается тред redelivery.
+    if is_ack_mode_enabled() {
+        return;
+    }
+
+    let pending = EVENT_WAL
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+        .map(event_wal::EventWal::pending)
+        .unwrap_or_default();
+    if pending.is_empty() {
+        return;
+    }
+
+    let mut redelivered = 0usize;
+    for entry in &pending {
+        let Some(event) = entry.to_event() else {
+            // Путь без имени файла доставить нельзя — не держим его вечно.
+            mark_event_delivered(entry.sequence);
+            continue;
+        };
+        if !send_file_added(&SHARED_SCOPE.sinks, &event) {
+            break;
+        }
+        mark_event_delivered(entry.sequence);
+        redelivered += 1;
+    }
+    log::info!(
+        "Redelivered {redelivered} of {} undelivered file events",
+        pending.len()
+    );
+}
+
+/// Инициализация логирования в Rust.
 ///
-/// Используется для:
-/// - Показа пути в UI до запуска watcher'а
-/// - Сохранения пути при первом запуске (onboarding)
-pub fn get_default_watch_path() -> Result<String, LateraError> {
+/// Можно вызвать из Flutter сразу после старта.
+/// Не заменяет [`init_core`] — остальные API требуют полной инициализации.
+pub fn init_logging() {
     logging::init_logging();
-
-    let watch_dir = file_watcher::ensure_default_watch_dir()?;
-    Ok(watch_dir.to_string_lossy().to_string())
 }
 
-/// Получить дефолтный путь наблюдения (Desktop/Latera) **без** создания директории.
+/// Изменить уровень логов на лету: `error`, `warn`, `info`, `debug`, `trace`
+/// или `off` (регистр не важен).
 ///
-/// Важно: функция не трогает файловую систему и не создаёт папку.
-/// Используется в онбординге для preview до явного согласия пользователя.
-pub fn get_default_watch_path_preview() -> Result<String, LateraError> {
+/// Действует на все приёмники (файл, stderr, буфер, [`on_log`]) до
+/// перезапуска и важнее `RUST_LOG` — поддержка может включить DEBUG в
+/// работающей сборке.
+pub fn set_log_level(level: String) -> Result<(), LateraError> {
+    let filter = level
+        .trim()
+        .parse::<log::LevelFilter>()
+        .map_err(|_| LateraError::InvalidArgument(format!("unknown log level: {level}")))?;
     logging::init_logging();
+    // До смены: при понижении уровня запись иначе потерялась бы
+    log::info!("Log level changed to {filter}");
+    logging::set_log_level(filter);
+    Ok(())
+}
 
-    let watch_dir = file_watcher::default_watch_dir_preview()?;
-    Ok(watch_dir.to_string_lossy().to_string())
+/// Текущий уровень логов (`INFO`, `DEBUG`, …): самый подробный среди приёмников.
+pub fn get_log_level() -> String {
+    logging::log_level().to_string()
 }
 
-/// Получить путь, где будет храниться индекс (локально на устройстве).
-///
-/// Важно: функция **не** создаёт директорию.
-/// Нужна, чтобы прозрачно показать пользователю, где лежат служебные данные.
+/// Запись лога Rust Core (FRB bridge type, см. [`on_log`]).
+#[derive(Clone, Debug)]
+pub struct LogRecord {
+    /// `ERROR`, `WARN`, `INFO`, `DEBUG` или `TRACE`.
+    pub level: String,
+    pub target: String,
+    pub message: String,
+    /// ISO 8601 с миллисекундами и смещением.
+    pub timestamp: String,
+    /// `correlation_id` записи ([`logging::LogContext`]), если есть.
+    pub correlation_id: Option<String>,
+}
+
+impl From<&logging::LogEntry> for LogRecord {
+    fn from(entry: &logging::LogEntry) -> Self {
+        Self {
+            level: entry.level.to_string(),
+            target: entry.target.clone(),
+            message: entry.message.clone(),
+            timestamp: entry.timestamp.clone(),
+            correlation_id: entry
+                .fields
+                .iter()
+                .find(|(key, _)| key == "correlation_id")
+                .map(|(_, value)| value.clone()),
+        }
+    }
+}
+
+/// Stream логов Rust Core — для debug-консоли приложения.
 ///
-/// Путь вычисляется через OS-provided local app data directory (MSIX/sandbox safe).
-pub fn get_index_path() -> Result<String, LateraError> {
+/// Приходят записи не ниже уровня stream-приёмника (по умолчанию INFO).
+/// Один активный подписчик: повторный вызов заменяет предыдущий stream.
+/// Закрытый во Flutter stream отключается при следующей записи.
+pub fn on_log(sink: frb_generated::StreamSink<LogRecord>) {
     logging::init_logging();
+    logging::set_log_stream(Some(Box::new(move |entry: &logging::LogEntry| {
+        sink.add(LogRecord::from(entry)).is_ok()
+    })));
+}
 
-    let local_data = dirs::data_local_dir().ok_or(LateraError::DataLocalDirNotFound)?;
-    let index_dir = local_data
-        .join(file_watcher::DEFAULT_WATCH_FOLDER_NAME)
-        .join("index");
-    Ok(index_dir.to_string_lossy().to_string())
+/// Ротация и хранение основного лог-файла (FRB bridge type).
+#[derive(Clone, Debug)]
+pub struct LogFileOptions {
+    /// Размер файла, после которого он ротируется.
+    pub max_file_bytes: u64,
+    /// Сколько ротированных файлов хранить.
+    pub max_rotated_files: u32,
+    /// Лимит суммарного размера текущего файла и архивов.
+    pub max_total_bytes: u64,
+    /// Сжимать ротированные файлы gzip'ом.
+    pub compress: bool,
 }
 
-/// Остановить мониторинг (graceful shutdown).
-pub fn stop_watching() -> Result<(), LateraError> {
+impl From<logging::RotationPolicy> for LogFileOptions {
+    fn from(policy: logging::RotationPolicy) -> Self {
+        Self {
+            max_file_bytes: policy.max_file_bytes,
+            max_rotated_files: u32::try_from(policy.max_rotated_files).unwrap_or(u32::MAX),
+            max_total_bytes: policy.max_total_bytes,
+            compress: policy.compress,
+        }
+    }
+}
+
+/// Путь к основному лог-файлу (`{local data}/Latera/logs/latera.log`).
+///
+/// `None` — запись в файл отключена или папка логов недоступна. Путь можно
+/// показать пользователю или приложить к отчёту об ошибке.
+pub fn get_log_file_path() -> Option<String> {
     logging::init_logging();
+    logging::log_file_path().map(|p| p.to_string_lossy().to_string())
+}
 
-    // 1) Сначала останавливаем watcher (и ждём завершения треда), чтобы он больше
-    // не мог эмитить события.
-    // Примечание: recover from poisoned mutex - если предыдущий поток паниковал,
-    // мы всё равно можем безопасно продолжить работу.
-    let handle = {
-        let mut guard = WATCHER
-            .lock()
-            .unwrap_or_else(std::sync::PoisonError::into_inner);
-        guard.take()
-    };
+/// Текущие настройки ротации основного лог-файла.
+pub fn get_log_file_options() -> LogFileOptions {
+    logging::log_file_policy().into()
+}
 
-    if let Some(h) = handle {
-        h.stop()?;
+/// Изменить ротацию и хранение основного лог-файла.
+///
+/// Новые лимиты применяются к следующей записи; лишние архивы удаляются
+/// при ближайшей ротации.
+pub fn set_log_file_options(options: LogFileOptions) -> Result<(), LateraError> {
+    if options.max_file_bytes == 0 || options.max_total_bytes < options.max_file_bytes {
+        return Err(LateraError::InvalidArgument(format!(
+            "invalid log file limits: max_file_bytes={}, max_total_bytes={}",
+            options.max_file_bytes, options.max_total_bytes
+        )));
     }
+    logging::init_logging();
+    logging::set_log_file_policy(logging::RotationPolicy {
+        max_file_bytes: options.max_file_bytes,
+        max_rotated_files: options.max_rotated_files as usize,
+        compress: options.compress,
+        max_total_bytes: options.max_total_bytes,
+    })?;
+    Ok(())
+}
 
-    // 2) Затем закрываем stream (onDone во Flutter) и очищаем sink.
-    close_file_added_stream();
-    close_file_removed_stream();
+/// Дублировать ERROR/WARN Rust Core в системный журнал ОС: Windows Event
+/// Log (источник `Latera`) или unified logging macOS (subsystem `Latera`).
+///
+/// Для режима агента/службы: администратор видит сбои там, куда и так
+/// смотрит. По умолчанию выключено; на других платформах включение
+/// возвращает ошибку.
+pub fn set_system_log_mirroring(enabled: bool) -> Result<(), LateraError> {
+    logging::init_logging();
+    if !logging::set_system_log_mirroring(enabled) {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "system log is not supported on this platform",
+        )
+        .into());
+    }
+    log::info!(
+        "System log mirroring {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
     Ok(())
 }
 
 // ============================================================================
-// Index API
+// Lifecycle API
 // ============================================================================
 
-/// Глобальное соединение с БД индекса.
-///
-/// Инициализируется единожды при вызове [`init_index`].
-/// Защищён мьютексом для потокобезопасного доступа.
-static INDEX_DB: Lazy<Mutex<Option<Connection>>> = Lazy::new(|| Mutex::new(None));
+/// Конфигурация инициализации ядра (FRB bridge type).
+#[derive(Clone, Debug)]
+pub struct CoreConfig {
+    /// Абсолютный путь к папке данных приложения.
+    /// `None` = `{local app data}/Latera`.
+    pub data_dir: Option<String>,
+    /// Путь к файлу SQLite индекса. Если указан — индекс открывается
+    /// сразу (эквивалент [`init_index`]).
+    pub index_db_path: Option<String>,
+}
 
-/// Получить ссылку на подключение к БД.
-/// Если БД не инициализирована — возвращает ошибку.
-pub(crate) fn with_index_db<F, T>(f: F) -> Result<T, LateraError>
-where
-    F: FnOnce(&Connection) -> Result<T, LateraError>,
+/// Возможности ядра на текущей машине (FRB bridge type).
+#[derive(Clone, Debug)]
+pub struct CoreCapabilities {
+    /// Версия Rust Core.
+    pub core_version: String,
+    /// Целевая ОС: `"windows"`, `"macos"`, `"linux"`.
+    pub platform: String,
+    /// Фактическая папка данных приложения.
+    pub data_dir: String,
+    /// Открыт ли индекс.
+    pub index_ready: bool,
+    /// Доступен ли системный OCR.
+    pub ocr_available: bool,
+    /// Поддерживает ли процессор AVX2.
+    pub has_avx2: bool,
+    /// Доступен ли Vulkan runtime.
+    pub has_vulkan: bool,
+    /// Объём физической памяти в мегабайтах.
+    pub total_ram_mb: u64,
+}
+
+/// Единая точка инициализации Rust Core.
+///
+/// Инициализирует логирование, папку данных, общий async runtime и
+/// (опционально) индекс. Возвращает новый [`CoreHandle`] — окну Flutter
+/// или тесту.
+/// Должна быть вызвана до остальных API — иначе они вернут
+/// `LateraError::CoreNotInitialized`.
+///
+/// Читает политику администратора (см. [`get_policy_status`]); если файл
+/// политики повреждён, ядро не инициализируется.
+///
+/// Безопасна для повторного вызова: общее состояние ядра инициализируется
+/// один раз, а каждый вызов создаёт новый handle. `data_dir` повторного
+/// вызова задаёт папку хранилищ только этого handle'а.
+pub fn init_core(config: CoreConfig) -> Result<CoreHandle, LateraError> {
+    let handle_dir = config.data_dir.map(PathBuf::from);
+    let internal_config = lifecycle::CoreConfig {
+        data_dir: handle_dir.clone(),
+    };
+    logging::init_logging();
+    let machine_policy = policy::reload()?;
+    let caps = lifecycle::init_core(&internal_config)?;
+    apply_policy(&machine_policy.unwrap_or_default());
+    load_core_config();
+
+    if let Some(db_path) = config.index_db_path {
+        init_index(db_path)?;
+    }
+
+    let index_ready = with_index_db(|_| Ok(())).is_ok();
+    let data_dir = match handle_dir {
+        Some(dir) => lifecycle::prepare_data_dir(&dir)?,
+        None => caps.data_dir,
+    };
+
+    let capabilities = CoreCapabilities {
+        core_version: caps.core_version,
+        platform: caps.platform,
+        data_dir: data_dir.to_string_lossy().to_string(),
+        index_ready,
+        ocr_available: caps.ocr_available,
+        has_avx2: caps.has_avx2,
+        has_vulkan: caps.has_vulkan,
+        total_ram_mb: caps.total_ram_mb,
+    };
+    Ok(CoreHandle::new(capabilities, data_dir))
+}
+
+/// Детерминированный teardown Rust Core при выходе из приложения.
+///
+/// Порядок:
+/// 1. останавливает все watcher'ы и закрывает streams,
+///    выключает режим подтверждения, закрывает WAL событий и heartbeat;
+/// 2. отменяет фоновые задачи (RAG-стрим, LLM-генерация) и очищает их очереди,
+///    сбрасывает агрегаты телеметрии на диск;
+/// 3. закрывает индексную БД (с checkpoint WAL);
+/// 4. выгружает модели (LLM, semantic);
+/// 5. сбрасывает состояние ядра и буферы логов.
+///
+/// Все шаги выполняются даже при ошибке на одном из них; возвращается
+/// первая ошибка. Безопасен для повторного вызова. После shutdown API снова
+/// требует [`init_core`].
+///
+/// Каждый watcher ждём не дольше 5 секунд; см. [`shutdown_all`].
+pub fn shutdown_core() -> Result<(), LateraError> {
+    shutdown_core_within(file_watcher::STOP_TIMEOUT)
+}
+
+/// Teardown ядра при выходе из приложения с ограничением по времени.
+///
+/// То же, что [`shutdown_core`], но watcher'ы останавливаются параллельно
+/// и все вместе ждутся не дольше `timeout_ms`. Зависшие потоки
+/// отсоединяются — выход из приложения не блокируется; в этом случае
+/// возвращается `LateraError::ShutdownTimedOut` (остальной teardown всё
+/// равно выполняется).
+pub fn shutdown_all(timeout_ms: u32) -> Result<(), LateraError> {
+    shutdown_core_within(std::time::Duration::from_millis(u64::from(timeout_ms)))
+}
+
+fn shutdown_core_within(watcher_timeout: std::time::Duration) -> Result<(), LateraError> {
+    logging::init_logging();
+    log::info!("Core shutdown requested");
+
+    let watcher_result = stop_watcher_and_streams(watcher_timeout);
+    if let Err(e) = &watcher_result {
+        log::error!("Failed to stop watcher during shutdown: {e}");
+    }
+
+    stop_adoptions();
+    disable_ack_mode();
+    close_event_wal();
+    clear_event_debug_log();
+    metrics::reset();
+    close_file_status_stream();
+    close_core_stores();
+    close_settings_store();
+    close_onboarding();
+    close_folder_composition();
+    close_archive_store();
+    audit_log::close();
+    close_dry_run_stream();
+    close_core_config();
+    stop_preview_queue();
+    stop_thumbnail_queue();
+    stop_hash_queue();
+    stop_file_ops_pool();
+    stop_code_scan_queue();
+    stop_auto_index_queue();
+    stop_duplicate_queue();
+    stop_heartbeat();
+    close_watcher_status_stream();
+    indexer::rag::shutdown_streaming();
+    telemetry::shutdown();
+
+    let index_result = close_index_db();
+    if let Err(e) = &index_result {
+        log::error!("Failed to close index DB during shutdown: {e}");
+    }
+
+    indexer::llm_engine::unload_llm();
+    indexer::unload_semantic_model();
+
+    policy::clear();
+    lifecycle::reset();
+    log::info!("Core shutdown complete");
+    logging::flush_logging();
+
+    watcher_result.and(index_result)
+}
+
+// ============================================================================
+// Core handle API
+// ============================================================================
+
+/// Экземпляр ядра для одного окна Flutter или теста (FRB opaque type).
+///
+/// Принадлежит handle'у:
+/// - watcher'ы: окно получает события только своих папок, а освобождённый
+///   handle останавливает свои watcher'ы, не трогая чужие;
+/// - streams событий файлов (`on_file_*`);
+/// - хранилища, в которые пишут его watcher'ы (статусы, теги, журнал), —
+///   свои соединения в папке данных handle'а;
+/// - настройки следующих watcher'ов: снимок настроек ядра на момент
+///   создания, дальше меняются только через handle.
+///
+/// Общее для процесса, как у функций без handle'а (они продолжают
+/// работать): индекс, настройки и WAL событий в папке данных ядра;
+/// остальные streams.
+#[frb(opaque)]
+pub struct CoreHandle {
+    capabilities: CoreCapabilities,
+    scope: Arc<CoreScope>,
+    watcher_ids: Mutex<BTreeSet<String>>,
+    options: Mutex<file_watcher::WatcherOptions>,
+}
+
+impl CoreHandle {
+    fn new(capabilities: CoreCapabilities, data_dir: PathBuf) -> Self {
+        let scope = Arc::new(CoreScope {
+            sinks: EventSinks::default(),
+            stores: CoreStores::in_dir(data_dir),
+        });
+        LIVE_SCOPES
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(Arc::downgrade(&scope));
+        let options = WATCHER_OPTIONS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone();
+        Self {
+            capabilities,
+            scope,
+            watcher_ids: Mutex::default(),
+            options: Mutex::new(options),
+        }
+    }
+
+    /// Возможности ядра на момент создания handle'а; `data_dir` — папка
+    /// хранилищ handle'а.
+    pub fn capabilities(&self) -> CoreCapabilities {
+        self.capabilities.clone()
+    }
+
+    /// Сглаживание событий для следующих watcher'ов этого handle'а;
+    /// запущенные не меняются. Некорректное значение —
+    /// `LateraError::InvalidArgument`, прежнее остаётся.
+    pub fn set_watch_tuning(&self, tuning: WatchTuning) -> Result<(), LateraError> {
+        let mut options = self
+            .options
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let mut tuned = options.clone();
+        tuning.apply_to(&mut tuned)?;
+        *options = tuned;
+        Ok(())
+    }
+
+    /// Как [`set_watch_filter`], для следующих watcher'ов этого handle'а.
+    pub fn set_watch_filter(&self, filter: Option<WatchFilter>) -> Result<(), LateraError> {
+        let filter = internal_watch_filter(filter)?;
+        self.options
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .filter = filter;
+        Ok(())
+    }
+
+    /// Как [`set_watch_recursive`], для следующих watcher'ов этого handle'а.
+    pub fn set_watch_recursive(&self, enabled: bool) {
+        self.options
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .recursive = enabled;
+    }
+
+    /// Как [`start_watching`], но с настройками этого handle'а, а события
+    /// приходят в его streams и хранилища. `tuning` переопределяет
+    /// [`CoreHandle::set_watch_tuning`] по полям.
+    ///
+    /// Папку, которую уже наблюдает любой watcher процесса, повторно не
+    /// наблюдают — `LateraError::WatcherAlreadyRunning`.
+    pub fn start_watching(
+        &self,
+        override_path: Option<String>,
+        tuning: Option<WatchTuning>,
+    ) -> Result<WatchStarted, LateraApiError> {
+        lifecycle::ensure_initialized()?;
+
+        let mut options = self
+            .options
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone();
+        if let Some(tuning) = tuning {
+            tuning.apply_to(&mut options)?;
+        }
+        let launch = portable_config::WatcherEntry::Folder {
+            path: override_path.clone(),
+            settings: portable_config::WatcherSettings::from_options(&options),
+        };
+        let watcher_id = spawn_watcher(
+            override_path,
+            options,
+            ArrivalAction::Report,
+            launch,
+            Some(Arc::clone(&self.scope)),
+        )?;
+        self.watcher_ids
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .insert(watcher_id.clone());
+        Ok(WatchStarted::of(watcher_id))
+    }
+
+    /// Как [`stop_watching`], только для watcher'ов этого handle'а: чужой
+    /// `watcher_id` — `LateraError::WatcherNotRunning`. Со своим последним
+    /// watcher'ом закрываются streams handle'а.
+    pub fn stop_watching(&self, watcher_id: String) -> Result<(), LateraApiError> {
+        lifecycle::ensure_initialized()?;
+
+        let owned = self
+            .watcher_ids
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .remove(&watcher_id);
+        if !owned {
+            return Err(LateraError::WatcherNotRunning.into());
+        }
+        Ok(stop_watcher(watcher_id)?)
+    }
+
+    /// Запущенные watcher'ы этого handle'а (как [`list_watchers`]).
+    pub fn list_watchers(&self) -> Result<Vec<WatcherInfo>, LateraError> {
+        let owned = self
+            .watcher_ids
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone();
+        Ok(list_watchers()?
+            .into_iter()
+            .filter(|w| owned.contains(&w.watcher_id))
+            .collect())
+    }
+
+    /// Как [`on_file_added`], для watcher'ов этого handle'а. Без подписчика
+    /// события не копятся: WAL и подтверждения — только у общего stream.
+    pub fn on_file_added(&self, sink: frb_generated::StreamSink<FileAddedEvent>) {
+        bind_sink(&self.scope.sinks.added, sink, "CoreHandle::on_file_added");
+    }
+
+    /// Как [`on_file_event`], для watcher'ов этого handle'а.
+    pub fn on_file_event(&self, sink: frb_generated::StreamSink<FileEvent>) {
+        bind_sink(&self.scope.sinks.event, sink, "CoreHandle::on_file_event");
+    }
+
+    /// Как [`on_file_batch`], для watcher'ов этого handle'а.
+    pub fn on_file_batch(&self, sink: frb_generated::StreamSink<FileBatchEvent>) {
+        bind_sink(&self.scope.sinks.batch, sink, "CoreHandle::on_file_batch");
+    }
+
+    /// Как [`on_file_chunks`], для watcher'ов этого handle'а.
+    pub fn on_file_chunks(&self, sink: frb_generated::StreamSink<FileChunkEvent>) {
+        bind_sink(&self.scope.sinks.chunk, sink, "CoreHandle::on_file_chunks");
+    }
+
+    /// Как [`get_file_status`], из хранилища этого handle'а.
+    pub fn get_file_status(
+        &self,
+        path: String,
+    ) -> Result<Option<FileProcessingStatus>, LateraError> {
+        lifecycle::ensure_initialized()?;
+
+        let record = self
+            .scope
+            .stores
+            .with_file_status(|store| store.get(Path::new(&path)))?;
+        Ok(record.map(|r| r.status.into()))
+    }
+
+    /// Как [`list_tags`], из хранилища этого handle'а.
+    pub fn list_tags(&self, path: String) -> Result<Vec<String>, LateraError> {
+        lifecycle::ensure_initialized()?;
+
+        self.scope
+            .stores
+            .with_tags(|store| store.list(Path::new(&path)))
+    }
+
+    /// Как [`query_events`], из журнала этого handle'а.
+    pub fn query_events(
+        &self,
+        since_ms: i64,
+        limit: u32,
+    ) -> Result<Vec<ApiJournalEntry>, LateraError> {
+        lifecycle::ensure_initialized()?;
+
+        let entries = self
+            .scope
+            .stores
+            .with_journal(|journal| journal.query(since_ms, limit as usize))?;
+        Ok(entries.into_iter().map(ApiJournalEntry::from).collect())
+    }
+
+    /// Останавливает watcher'ы handle'а (ждёт каждый не дольше 5 секунд),
+    /// закрывает его streams и хранилища; handle можно использовать дальше.
+    pub fn close(&self) -> Result<(), LateraError> {
+        let owned = std::mem::take(
+            &mut *self
+                .watcher_ids
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+        );
+        let mut result = Ok(());
+        for watcher_id in owned {
+            match stop_watcher(watcher_id) {
+                // Уже остановлен: stop_watching без handle'а или shutdown_core
+                Ok(()) | Err(LateraError::WatcherNotRunning) => {}
+                Err(e) => {
+                    if result.is_ok() {
+                        result = Err(e);
+                    }
+                }
+            }
+        }
+        self.scope.sinks.close();
+        self.scope.stores.close();
+        result
+    }
+}
+
+impl Drop for CoreHandle {
+    /// Освобождение handle'а не ждёт потоки watcher'ов (Drop вызывает
+    /// финализатор Dart): они сразу снимаются с учёта — папку можно снова
+    /// наблюдать, — а останавливаются в фоне. Хранилища закрываются с
+    /// последней ссылкой на scope.
+    fn drop(&mut self) {
+        let owned = std::mem::take(
+            self.watcher_ids
+                .get_mut()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+        );
+        self.scope.sinks.close();
+        let detached: Vec<(String, ActiveWatcher)> = owned
+            .into_iter()
+            .filter_map(|id| detach_watcher(&id).ok().map(|(watcher, _)| (id, watcher)))
+            .collect();
+        if detached.is_empty() {
+            return;
+        }
+        let stop_detached = move || {
+            for (watcher_id, watcher) in detached {
+                if let Err(e) = watcher.stop(file_watcher::STOP_TIMEOUT) {
+                    log::warn!("Failed to stop watcher {watcher_id} of dropped core handle: {e}");
+                }
+                release_watcher_scope(&watcher_id, false);
+            }
+        };
+        let spawned = std::thread::Builder::new()
+            .name("latera-handle-drop".to_string())
+            .spawn(stop_detached);
+        if let Err(e) = spawned {
+            // Watcher'ы отменяются при Drop своих handle'ов, без ожидания
+            log::warn!("Failed to spawn core handle cleanup thread: {e}");
+        }
+    }
+}
+
+/// Stream событий добавления файла.
+///
+/// В Dart это будет выглядеть как `Stream<FileAddedEvent> onFileAdded()`.
+///
+/// Контракт:
+/// - один активный подписчик;
+/// - при вызове [`stop_watching`](crate::api::stop_watching) стрим закрывается (onDone во Flutter);
+/// - при повторном старте подписка создаётся заново.
+pub fn on_file_added(sink: frb_generated::StreamSink<FileAddedEvent>) {
+    bind_sink(&SHARED_SCOPE.sinks.added, sink, "on_file_added");
+}
+
+/// Stream событий удаления файла.
+///
+/// В Dart это будет выглядеть как `Stream<FileRemovedEvent> onFileRemoved()`.
+pub fn on_file_removed(sink: frb_generated::StreamSink<FileRemovedEvent>) {
+    let mut guard = FILE_REMOVED_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_some() {
+        warn!(
+            "on_file_removed called while previous stream is still bound; closing previous stream"
+        );
+    }
+    *guard = Some(sink);
+}
+
+/// Stream всех событий файлов: создание, изменение, удаление, переименование.
+///
+/// В Dart это будет выглядеть как `Stream<FileEvent> onFileEvent()`.
+/// Контракт как у [`on_file_added`]: один активный подписчик; stream
+/// закрывается при [`stop_watching`].
+pub fn on_file_event(sink: frb_generated::StreamSink<FileEvent>) {
+    bind_sink(&SHARED_SCOPE.sinks.event, sink, "on_file_event");
+}
+
+/// Stream пачек появившихся файлов сверх rate-limit (см. [`FileBatchEvent`]).
+///
+/// Контракт как у [`on_file_added`]: один активный подписчик; stream
+/// закрывается при [`stop_watching`].
+pub fn on_file_batch(sink: frb_generated::StreamSink<FileBatchEvent>) {
+    bind_sink(&SHARED_SCOPE.sinks.batch, sink, "on_file_batch");
+}
+
+/// Stream чанков появившихся файлов (см. [`FileChunkEvent`]).
+///
+/// Контракт как у [`on_file_added`]: один активный подписчик; stream
+/// закрывается при [`stop_watching`]. Чанки приходят, только если
+/// передача чанками включена ([`set_chunked_transfer`]).
+pub fn on_file_chunks(sink: frb_generated::StreamSink<FileChunkEvent>) {
+    bind_sink(&SHARED_SCOPE.sinks.chunk, sink, "on_file_chunks");
+}
+
+/// Наибольший размер чанка.
+const MAX_FILE_CHUNK_SIZE: u32 = 10_000;
+
+/// Передавать пачки появившихся файлов (сверка при запуске, перенос
+/// папки, поток сверх rate-limit) чанками по `events_per_chunk` событий
+/// в [`on_file_chunks`] вместо [`FileBatchEvent`].
+///
+/// Тысячи файлов пересекают FFI несколькими буферами, а не структурой на
+/// каждый файл; выигрыш виден в `latera_transfer_*` метриках
+/// ([`export_metrics_text`]). Пока к [`on_file_chunks`] никто не
+/// подписан, пачки идут как раньше. `None` — выключить.
+pub fn set_chunked_transfer(events_per_chunk: Option<u32>) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    if let Some(size) = events_per_chunk {
+        if size == 0 || size > MAX_FILE_CHUNK_SIZE {
+            return Err(LateraError::InvalidArgument(format!(
+                "events_per_chunk must be in 1..={MAX_FILE_CHUNK_SIZE}"
+            )));
+        }
+    }
+    *FILE_CHUNK_SIZE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) =
+        events_per_chunk.map(|size| size as usize);
+    Ok(())
+}
+
+/// Запуск мониторинга папки.
+///
+/// - Если `override_path` = `None` → используется дефолтный `Desktop/Latera`.
+/// - Если `Some` → должен быть абсолютный путь; директория будет создана при отсутствии.
+///
+/// Можно наблюдать несколько папок одновременно: каждый вызов запускает
+/// отдельный watcher. Повторный запуск для уже наблюдаемой папки возвращает
+/// `LateraError::WatcherAlreadyRunning`. Streams событий общие для всех
+/// watcher'ов; [`FileEvent::watcher_id`] указывает источник.
+///
+/// Настройки watcher'а ([`set_watch_filter`], [`set_watch_recursive`] и др.)
+/// фиксируются в момент запуска. `tuning` переопределяет сглаживание событий
+/// только для этого watcher'а; некорректное значение —
+/// `LateraError::InvalidArgument`.
+///
+/// Возвращает `watcher_id` — его принимают [`stop_watching`] и другие API
+/// конкретного watcher'а — и выбранный способ наблюдения (на сетевых дисках
+/// по умолчанию опрос, см. [`set_watch_backend`]). Путь папки для UI — в
+/// [`list_watchers`].
+pub fn start_watching(
+    override_path: Option<String>,
+    tuning: Option<WatchTuning>,
+) -> Result<WatchStarted, LateraApiError> {
+    lifecycle::ensure_initialized()?;
+
+    let watcher_id = start_folder_watcher(override_path, tuned_watcher_options(tuning)?)?;
+    Ok(WatchStarted::of(watcher_id))
+}
+
+/// Текущие настройки watcher'а с поправками `tuning`.
+fn tuned_watcher_options(
+    tuning: Option<WatchTuning>,
+) -> Result<file_watcher::WatcherOptions, LateraError> {
+    let mut options = WATCHER_OPTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone();
+    if let Some(tuning) = tuning {
+        tuning.apply_to(&mut options)?;
+    }
+    Ok(options)
+}
+
+/// Результат [`start_watching`] (FRB bridge type).
+#[derive(Clone, Debug)]
+pub struct WatchStarted {
+    pub watcher_id: String,
+    /// Как watcher получает изменения сразу после запуска.
+    pub backend: ApiWatchMode,
+}
+
+impl WatchStarted {
+    fn of(watcher_id: String) -> Self {
+        let backend = WATCHERS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .get(&watcher_id)
+            .map_or(ApiWatchMode::Native, |w| w.handle.backend().into());
+        Self {
+            watcher_id,
+            backend,
+        }
+    }
+}
+
+/// Сглаживание событий одного watcher'а (см. [`start_watching`]).
+///
+/// `None` — значение из настроек ядра ([`get_config`]). Большие пачки мелких
+/// файлов требуют короткого окна и высокого лимита.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WatchTuning {
+    /// Повтор того же события по тому же пути в пределах окна
+    /// отбрасывается; `0` — без дедупликации.
+    pub dedup_window_ms: Option<u32>,
+    /// Сколько событий в секунду отдаётся сразу; остальные — пачками
+    /// ([`on_file_batch`]).
+    pub max_events_per_second: Option<u32>,
+}
+
+impl WatchTuning {
+    fn apply_to(self, options: &mut file_watcher::WatcherOptions) -> Result<(), LateraError> {
+        let dedup_window = self.dedup_window_ms.map_or(options.dedup_window, |ms| {
+            std::time::Duration::from_millis(u64::from(ms))
+        });
+        let rate_limit = self
+            .max_events_per_second
+            .unwrap_or(options.rate_limit_per_second);
+        config::check_event_tuning(dedup_window, rate_limit)
+            .map_err(LateraError::InvalidArgument)?;
+        options.dedup_window = dedup_window;
+        options.rate_limit_per_second = rate_limit;
+        Ok(())
+    }
+}
+
+/// Наблюдать за папкой загрузок ОС (пресет).
+///
+/// Временные файлы незавершённых загрузок (`*.crdownload`, `*.part`…) и
+/// скрытые файлы не сообщаются; о загрузке сообщается, когда она перестала
+/// меняться. Остальные настройки — как у [`start_watching`], кроме фильтра
+/// и рекурсии.
+///
+/// `move_to` — абсолютный путь папки (обычно наблюдаемой папки Latera), куда
+/// переносить готовые загрузки; занятые имена получают суффикс ` (N)`.
+/// Перенесённый файл сообщает watcher папки `move_to`, а не этот. `None` —
+/// загрузки остаются на месте и сообщаются как обычно.
+///
+/// Возвращает `watcher_id`, как [`start_watching`].
+pub fn start_watching_downloads(move_to: Option<String>) -> Result<String, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let downloads_dir = intake::downloads::downloads_dir()?;
+    let launch = portable_config::WatcherEntry::Downloads {
+        move_to: move_to.clone(),
+    };
+    let action = match intake_target(&downloads_dir, move_to)? {
+        Some(target) => ArrivalAction::MoveTo {
+            target,
+            rule_id: intake::downloads::RULE_ID,
+        },
+        None => ArrivalAction::Report,
+    };
+    let options = intake::downloads::preset_options(
+        &WATCHER_OPTIONS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner),
+    )?;
+    spawn_watcher(
+        Some(downloads_dir.to_string_lossy().to_string()),
+        options,
+        action,
+        launch,
+        None,
+    )
+}
+
+/// Появившийся скриншот (см. [`start_watching_screenshots`]).
+#[derive(Clone, Debug)]
+pub struct ScreenshotAddedEvent {
+    pub watcher_id: String,
+    pub file_name: String,
+    pub full_path: String,
+    pub occurred_at_ms: i64,
+    pub size_bytes: u64,
+    pub mime_type: Option<String>,
+}
+
+static SCREENSHOT_SINK: Lazy<Mutex<Option<frb_generated::StreamSink<ScreenshotAddedEvent>>>> =
+    Lazy::new(|| Mutex::new(None));
+
+/// Наблюдать за папкой скриншотов ОС (пресет).
+///
+/// Папка — Desktop на macOS, `Pictures/Screenshots` на Windows и Linux.
+/// Сообщаются только файлы с системными именами скриншотов
+/// (`Screenshot …`, `Screen Shot …`, `Снимок экрана …`) в форматах
+/// изображений; прочие файлы папки игнорируются.
+///
+/// `move_to` — абсолютный путь папки (обычно наблюдаемой папки Latera), куда
+/// переносить скриншоты; перенесённый файл сообщает watcher папки `move_to`.
+/// `None` — скриншоты остаются на месте и приходят в [`on_screenshot_added`]
+/// (и в [`on_file_event`]), но не в [`on_file_added`].
+///
+/// Возвращает `watcher_id`, как [`start_watching`].
+pub fn start_watching_screenshots(move_to: Option<String>) -> Result<String, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let screenshots_dir = intake::screenshots::screenshots_dir()?;
+    let launch = portable_config::WatcherEntry::Screenshots {
+        move_to: move_to.clone(),
+    };
+    let action = match intake_target(&screenshots_dir, move_to)? {
+        Some(target) => ArrivalAction::MoveTo {
+            target,
+            rule_id: intake::screenshots::RULE_ID,
+        },
+        None => ArrivalAction::ReportScreenshot,
+    };
+    let options = intake::screenshots::preset_options(
+        &WATCHER_OPTIONS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner),
+    )?;
+    spawn_watcher(
+        Some(screenshots_dir.to_string_lossy().to_string()),
+        options,
+        action,
+        launch,
+        None,
+    )
+}
+
+/// Stream скриншотов (см. [`start_watching_screenshots`]).
+///
+/// Контракт как у [`on_file_added`]: один активный подписчик; stream
+/// закрывается при [`stop_watching`].
+pub fn on_screenshot_added(sink: frb_generated::StreamSink<ScreenshotAddedEvent>) {
+    let mut guard = SCREENSHOT_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_some() {
+        warn!("on_screenshot_added called while previous stream is still bound; closing previous stream");
+    }
+    *guard = Some(sink);
+}
+
+fn close_screenshot_stream() {
+    let _dropped = SCREENSHOT_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    log::debug!("Screenshot stream closed");
+}
+
+fn emit_screenshot_added(watcher_id: &str, event: &file_watcher::InternalFileEvent) {
+    if let Some(sink) = SCREENSHOT_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+    {
+        let result = sink.add(ScreenshotAddedEvent {
+            watcher_id: watcher_id.to_string(),
+            file_name: event.file_name.clone(),
+            full_path: event.full_path.to_string_lossy().to_string(),
+            occurred_at_ms: event.occurred_at_ms,
+            size_bytes: event.size_bytes.unwrap_or(0),
+            mime_type: event.mime_type.clone(),
+        });
+        if let Err(e) = result {
+            log::warn!("Failed to emit screenshot event (stream closed): {e}");
+        }
+    }
+}
+
+/// Что делать с появившимися файлами watcher'а.
+#[derive(Clone, Debug)]
+enum ArrivalAction {
+    /// Сообщать как обычно ([`on_file_added`]).
+    Report,
+    /// Переносить в папку `target` по правилу `rule_id` (пресеты приёма,
+    /// см. [`intake::move_into`]).
+    MoveTo {
+        target: PathBuf,
+        rule_id: &'static str,
+    },
+    /// Сообщать в [`on_screenshot_added`] вместо [`on_file_added`].
+    ReportScreenshot,
+}
+
+/// Проверяет папку, куда пресет переносит файлы из `source_dir`.
+fn intake_target(
+    source_dir: &Path,
+    move_to: Option<String>,
+) -> Result<Option<PathBuf>, LateraError> {
+    let Some(path) = move_to else {
+        return Ok(None);
+    };
+    let path = PathBuf::from(path);
+    if !path.is_absolute() {
+        return Err(LateraError::InvalidPath(
+            "move_to must be an absolute path".to_string(),
+        ));
+    }
+    // Watcher пресета не рекурсивный: вложенная папка (Desktop/Latera) допустима.
+    if path_utils::paths_equal(&path, source_dir) {
+        return Err(LateraError::InvalidPath(
+            "move_to must differ from the watched directory".to_string(),
+        ));
+    }
+    read_only::ensure_writable("moving files into watch dir")?;
+    Ok(Some(path))
+}
+
+/// Выполняет [`ArrivalAction`] для события. `true` — событие обработано
+/// и обычным путём не сообщается.
+fn intake_arrival(
+    action: &ArrivalAction,
+    watcher_id: &str,
+    event: &file_watcher::InternalFileEvent,
+) -> bool {
+    if !event.kind.is_arrival() || event.self_generated {
+        return false;
+    }
+    // Файлом общей папки занимается экземпляр, который его забрал.
+    if let Some(claims::ClaimStatus::ClaimedByOther { owner, .. }) = &event.claim {
+        log::info!(
+            "{} is claimed by {owner}; skipping",
+            event.full_path.display()
+        );
+        return false;
+    }
+    match action {
+        ArrivalAction::Report => false,
+        ArrivalAction::MoveTo { target, rule_id } if dry_run::is_enabled() => {
+            let Some(file_name) = event.full_path.file_name() else {
+                return false;
+            };
+            let would_move_to = intake::free_path(&target.join(file_name));
+            dry_run::report(
+                audit_log::AuditAction::Move,
+                &event.full_path,
+                Some(&would_move_to.to_string_lossy()),
+                rule_id,
+            );
+            false
+        }
+        ArrivalAction::MoveTo { target, rule_id } => {
+            match intake::move_into(&event.full_path, target, rule_id) {
+                Ok(moved) => {
+                    log::info!("Moved {} -> {}", event.full_path.display(), moved.display());
+                    true
+                }
+                Err(e) => {
+                    warn!("Failed to move {}: {e}", event.full_path.display());
+                    false
+                }
+            }
+        }
+        ArrivalAction::ReportScreenshot => {
+            emit_file_event(watcher_id, event);
+            emit_screenshot_added(watcher_id, event);
+            handle_file_event(watcher_id, event);
+            true
+        }
+    }
+}
+
+/// Забирает появившийся файл общей папки и отпускает ушедший
+/// (см. [`set_shared_folder_claims`]).
+fn coordinate_shared_file(event: &mut file_watcher::InternalFileEvent) {
+    if !claims::is_enabled() || event.self_generated {
+        return;
+    }
+    if event.kind.is_arrival() {
+        event.claim = Some(claims::try_claim(&event.full_path));
+    } else if event.kind.is_departure() {
+        if let Err(e) = claims::release(&event.full_path) {
+            warn!(
+                "Failed to release claim of {}: {e}",
+                event.full_path.display()
+            );
+        }
+    }
+}
+
+/// Запускает watcher обычной папки с заданными настройками.
+fn start_folder_watcher(
+    override_path: Option<String>,
+    options: file_watcher::WatcherOptions,
+) -> Result<String, LateraError> {
+    let launch = portable_config::WatcherEntry::Folder {
+        path: override_path.clone(),
+        settings: portable_config::WatcherSettings::from_options(&options),
+    };
+    spawn_watcher(override_path, options, ArrivalAction::Report, launch, None)
+}
+
+/// Запускает watcher и регистрирует его в [`WATCHERS`].
+///
+/// `scope` — streams и хранилища [`CoreHandle`]; `None` — общие.
+fn spawn_watcher(
+    override_path: Option<String>,
+    mut options: file_watcher::WatcherOptions,
+    action: ArrivalAction,
+    launch: portable_config::WatcherEntry,
+    scope: Option<Arc<CoreScope>>,
+) -> Result<String, LateraError> {
+    let override_path = policy::current().resolve_watch_path(override_path)?;
+
+    // Примечание: recover from poisoned mutex - если предыдущий поток паниковал,
+    // мы всё равно можем безопасно продолжить работу.
+    let mut watchers = WATCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    // До старта: второй watcher той же папки успел бы сверить и сохранить
+    // общий снимок, отдать события и пометить папку.
+    let watch_dir = file_watcher::resolve_watch_dir(override_path.as_deref())?;
+    if is_watched(&watchers, &watch_dir) {
+        return Err(LateraError::WatcherAlreadyRunning);
+    }
+
+    // До старта watcher'а доставляем события, не подтверждённые в прошлой сессии.
+    open_event_wal();
+    redeliver_pending_events();
+
+    let watcher_id = format!("w{}", NEXT_WATCHER_ID.fetch_add(1, Ordering::Relaxed));
+    options.id.clone_from(&watcher_id);
+    // До старта: сверка со снимком уже отдаёт события
+    if let Some(scope) = scope {
+        HANDLE_SCOPES
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .insert(watcher_id.clone(), scope);
+    }
+    let forget_scope = || release_watcher_scope(&watcher_id, false);
+    let recursive = options.recursive;
+    let filter = options.filter.clone();
+    let id_for_events = watcher_id.clone();
+    let id_for_batches = watcher_id.clone();
+    let id_for_status = watcher_id.clone();
+    let lost_dir: Mutex<Option<PathBuf>> = Mutex::new(None);
+    options.status_listener = Some(file_watcher::StatusListener::new(move |state| {
+        emit_watcher_status(&id_for_status, state, false);
+        let mut lost_dir = lost_dir
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        match state {
+            file_watcher::WatcherState::DirLost { path } => {
+                *lost_dir = Some(path.clone());
+                emit_watch_status(WatchStatusEvent::WatchDirLost {
+                    watcher_id: id_for_status.clone(),
+                    path: path.to_string_lossy().to_string(),
+                });
+            }
+            file_watcher::WatcherState::Running | file_watcher::WatcherState::Fallback { .. } => {
+                if let Some(path) = lost_dir.take() {
+                    emit_watch_status(WatchStatusEvent::WatchDirRestored {
+                        watcher_id: id_for_status.clone(),
+                        path: path.to_string_lossy().to_string(),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }));
+    let action_for_batches = action.clone();
+    let handle = file_watcher::start_watcher_with_batches(
+        override_path,
+        options,
+        move |mut event| {
+            coordinate_shared_file(&mut event);
+            if intake_arrival(&action, &id_for_events, &event)
+                || apply_file_rules(&id_for_events, &mut event)
+            {
+                return;
+            }
+            emit_file_event(&id_for_events, &event);
+            if event.kind.is_arrival() && !event.self_generated {
+                emit_file_added(&id_for_events, &event);
+            }
+            handle_file_event(&id_for_events, &event);
+        },
+        move |mut events| {
+            events.iter_mut().for_each(coordinate_shared_file);
+            events.retain_mut(|e| {
+                !intake_arrival(&action_for_batches, &id_for_batches, e)
+                    && !apply_file_rules(&id_for_batches, e)
+            });
+            emit_file_batch(&id_for_batches, &events);
+        },
+    )
+    .inspect_err(|_| forget_scope())?;
+
+    // Папка по умолчанию могла смениться между проверкой и стартом
+    if is_watched(&watchers, handle.watch_dir()) {
+        if let Err(e) = handle.stop() {
+            warn!("Failed to stop duplicate watcher: {e}");
+        }
+        forget_scope();
+        return Err(LateraError::WatcherAlreadyRunning);
+    }
+
+    let watch_dir = handle.watch_dir().to_path_buf();
+    if !handle.volume().kind.strategy().hashing {
+        UNHASHED_WATCHERS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .insert(watcher_id.clone());
+    }
+    KNOWN_FILES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .insert(
+            watcher_id.clone(),
+            name_conflict::KnownFiles::scan(&watch_dir, recursive),
+        );
+    enqueue_duplicate_job(duplicates::DuplicateJob::Scan {
+        watcher_id: watcher_id.clone(),
+        dir: watch_dir.clone(),
+        recursive,
+    });
+
+    // Нехватка места не должна мешать наблюдению — только логируем.
+    let disk_space_monitor = match start_disk_space_monitor(watch_dir.clone()) {
+        Ok(monitor) => Some(monitor),
+        Err(e) => {
+            warn!("Failed to start disk space monitor: {e}");
+            None
+        }
+    };
+    let quota_monitor = match start_quota_monitor(&watcher_id, watch_dir.clone()) {
+        Ok(monitor) => monitor,
+        Err(e) => {
+            warn!("Failed to start quota monitor: {e}");
+            None
+        }
+    };
+    let archive_monitor = match start_archive_monitor(watch_dir) {
+        Ok(monitor) => monitor,
+        Err(e) => {
+            warn!("Failed to start archive monitor: {e}");
+            None
+        }
+    };
+
+    watchers.insert(
+        watcher_id.clone(),
+        ActiveWatcher {
+            handle,
+            recursive,
+            filter,
+            launch,
+            started_at_ms: file_watcher::now_ms(),
+            disk_space_monitor,
+            quota_monitor,
+            archive_monitor,
+        },
+    );
+    Ok(watcher_id)
+}
+
+/// Наблюдает ли уже какой-нибудь watcher папку `dir`.
+fn is_watched(watchers: &BTreeMap<String, ActiveWatcher>, dir: &Path) -> bool {
+    watchers
+        .values()
+        .any(|w| path_utils::paths_equal(w.handle.watch_dir(), dir))
+}
+
+/// Побочные эффекты события для ядра: статусы, конфликты имён, превью.
+fn handle_file_event(watcher_id: &str, event: &file_watcher::InternalFileEvent) {
+    let _span = tracing::info_span!(
+        "handle_file_event",
+        watcher_id,
+        kind = ?event.kind,
+        path = %event.full_path.display(),
+    )
+    .entered();
+    if event.self_generated && !event.kind.is_departure() {
+        // Файлы, записанные самим ядром, уже учтены операцией
+        return;
+    }
+    if event.kind.is_arrival() {
+        telemetry::record(CounterKind::Event, "file_added");
+        check_name_conflict(watcher_id, &event.full_path);
+        track_file_status(
+            watcher_id,
+            &event.full_path,
+            Some(file_status::FileStatus::New),
+        );
+        enqueue_preview(&event.full_path);
+        enqueue_thumbnail(&event.full_path);
+        enqueue_hash(watcher_id, &event.full_path);
+        enqueue_code_scan(watcher_id, &event.full_path);
+        enqueue_auto_index(event);
+        enqueue_duplicate_check(watcher_id, event);
+        track_tags(watcher_id, event);
+    } else if event.kind == file_watcher::FileEventKind::Modified {
+        enqueue_hash(watcher_id, &event.full_path);
+        enqueue_auto_index(event);
+        enqueue_duplicate_check(watcher_id, event);
+    } else if event.kind.is_departure() {
+        telemetry::record(CounterKind::Event, "file_removed");
+        enqueue_auto_index(event);
+        enqueue_duplicate_check(watcher_id, event);
+        track_tags(watcher_id, event);
+        forget_known_file(watcher_id, &event.full_path);
+        track_file_status(watcher_id, &event.full_path, None);
+
+        // Emit события удаления в stream.
+        // NOTE: Временно отключено — FRB codegen не генерирует SseEncode
+        // для FileRemovedEvent. Будет включено после пересборки bindings.
+        log::debug!(
+            "File removed event: {} (stream emit disabled pending FRB codegen fix)",
+            event.file_name
+        );
+    }
+}
+
+/// Пачка событий сверх rate-limit: появившиеся файлы — одним
+/// [`FileBatchEvent`], остальные (уже схлопнутые по пути) — в [`on_file_event`].
+///
+/// Появившиеся файлы общего stream проходят через WAL и режим
+/// подтверждения, как и в [`emit_file_added`].
+fn emit_file_batch(watcher_id: &str, events: &[file_watcher::InternalFileEvent]) {
+    let mut added = Vec::new();
+    for event in events {
+        if event.kind.is_arrival() {
+            if !event.self_generated {
+                added.push(event);
+            }
+        } else {
+            emit_file_event(watcher_id, event);
+        }
+        handle_file_event(watcher_id, event);
+    }
+    if added.is_empty() {
+        return;
+    }
+    let scope = watcher_scope(watcher_id);
+    let sequences: Vec<Option<u64>> = if Arc::ptr_eq(&scope, &SHARED_SCOPE) {
+        added.iter().map(|e| append_to_wal(e)).collect()
+    } else {
+        Vec::new()
+    };
+    let delivered = emit_file_chunks(&scope.sinks, watcher_id, &added)
+        .unwrap_or_else(|| vec![send_file_batch(&scope.sinks, watcher_id, &added); added.len()]);
+    for ((sequence, event), delivered) in sequences.into_iter().zip(&added).zip(delivered) {
+        if let Some(seq) = sequence {
+            finish_wal_entry(seq, event, delivered);
+        }
+    }
+}
+
+/// Отдать появившиеся файлы одним [`FileBatchEvent`]. `true` — sink принял
+/// пачку.
+fn send_file_batch(
+    sinks: &EventSinks,
+    watcher_id: &str,
+    added: &[&file_watcher::InternalFileEvent],
+) -> bool {
+    let guard = sinks
+        .batch
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let Some(sink) = guard.as_ref() else {
+        log::debug!("File batch kept pending (no active stream subscriber)");
+        return false;
+    };
+    let added: Vec<FileAddedEvent> = added.iter().map(|e| to_file_added_event(e)).collect();
+    let count = added.len();
+    let bytes = added
+        .iter()
+        .map(|e| {
+            event_chunk::sse_size(
+                &e.file_name,
+                &e.full_path,
+                e.extension.as_deref(),
+                e.mime_type.as_deref(),
+            )
+        })
+        .sum();
+    match sink.add(FileBatchEvent {
+        watcher_id: watcher_id.to_string(),
+        events: added,
+    }) {
+        Ok(()) => {
+            metrics::observe_transfer(metrics::TransferMode::Batch, count, bytes);
+            true
+        }
+        Err(e) => {
+            log::warn!("Failed to emit file batch of {count} events (stream closed): {e}");
+            false
+        }
+    }
+}
+
+/// Отдать появившиеся файлы чанками в [`on_file_chunks`]; для каждого
+/// файла — принял ли sink его чанк.
+///
+/// `None` — передача чанками выключена или подписчика нет: файлы нужно
+/// отдать обычной пачкой.
+fn emit_file_chunks(
+    sinks: &EventSinks,
+    watcher_id: &str,
+    added: &[&file_watcher::InternalFileEvent],
+) -> Option<Vec<bool>> {
+    let chunk_size = (*FILE_CHUNK_SIZE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner))?;
+    let guard = sinks
+        .chunk
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let sink = guard.as_ref()?;
+    let mut delivered = Vec::with_capacity(added.len());
+    for chunk in added.chunks(chunk_size) {
+        let entries: Vec<event_chunk::ChunkEntry> = chunk
+            .iter()
+            .map(|e| event_chunk::ChunkEntry::from(*e))
+            .collect();
+        let payload = event_chunk::encode(&entries);
+        let bytes = payload.len();
+        let count = u32::try_from(chunk.len()).unwrap_or(u32::MAX);
+        let accepted = match sink.add(FileChunkEvent {
+            watcher_id: watcher_id.to_string(),
+            count,
+            payload,
+        }) {
+            Ok(()) => {
+                metrics::observe_transfer(metrics::TransferMode::Chunk, chunk.len(), bytes);
+                true
+            }
+            Err(e) => {
+                log::warn!("Failed to emit file chunk of {count} events (stream closed): {e}");
+                false
+            }
+        };
+        delivered.resize(delivered.len() + chunk.len(), accepted);
+    }
+    Some(delivered)
+}
+
+/// Запущенный watcher (FRB bridge type).
+#[derive(Clone, Debug)]
+pub struct WatcherInfo {
+    pub watcher_id: String,
+    /// Фактический путь наблюдаемой папки.
+    pub watch_dir: String,
+    pub recursive: bool,
+    /// Момент запуска (Unix timestamp в миллисекундах).
+    pub started_at_ms: i64,
+}
+
+/// Запущенные watcher'ы (в порядке `watcher_id`).
+pub fn list_watchers() -> Result<Vec<WatcherInfo>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    Ok(WATCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .iter()
+        .map(|(id, w)| WatcherInfo {
+            watcher_id: id.clone(),
+            watch_dir: w.handle.watch_dir().to_string_lossy().to_string(),
+            recursive: w.recursive,
+            started_at_ms: w.started_at_ms,
+        })
+        .collect())
+}
+
+/// Дольше ответа [`rescan_now`] не ждём: сверка большой папки может идти
+/// долго, а watcher, ждущий пропавшую папку, не отвечает вовсе.
+const RESCAN_TIMEOUT: std::time::Duration = std::time::Duration::from_mins(2);
+
+/// Сверить папку watcher'а `watcher_id` с последним известным состоянием.
+///
+/// Запасной выход, если кажется, что watcher пропустил события (и
+/// единственный способ узнать об изменениях в [`ApiWatchMode::ManualRefresh`]):
+/// найденное приходит в [`on_file_batch`], как при сверке со снимком, без
+/// повторов уже сообщённого. Возвращает число найденных расхождений.
+pub fn rescan_now(watcher_id: String) -> Result<u32, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let reply = WATCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .get(&watcher_id)
+        .map(|w| w.handle.rescan())
+        .ok_or(LateraError::WatcherNotRunning)?;
+    match reply.recv_timeout(RESCAN_TIMEOUT) {
+        Ok(Some(found)) => Ok(u32::try_from(found).unwrap_or(u32::MAX)),
+        Ok(None) => Err(LateraError::InvalidPath(format!(
+            "cannot scan watch directory of {watcher_id}"
+        ))),
+        Err(std::sync::mpsc::RecvTimeoutError::Timeout) => Err(std::io::Error::new(
+            std::io::ErrorKind::TimedOut,
+            "rescan did not finish in time",
+        )
+        .into()),
+        Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => Err(LateraError::WatcherNotRunning),
+    }
+}
+
+/// Что означает `occurred_at_ms` в [`FileAddedEvent`].
+#[derive(Clone, Copy, Debug)]
+pub enum EventTimestampSource {
+    /// Момент, когда watcher обнаружил файл (по умолчанию).
+    DetectedAt,
+    /// Время последнего изменения файла (mtime).
+    FileModified,
+}
+
+/// Настройки, с которыми запускается следующий watcher.
+static WATCHER_OPTIONS: Lazy<Mutex<file_watcher::WatcherOptions>> =
+    Lazy::new(|| Mutex::new(file_watcher::WatcherOptions::default()));
+
+/// Выбрать смысл `occurred_at_ms` в событиях добавления.
+///
+/// Применяется при следующем [`start_watching`]. Поля `detected_at_ms`
+/// и `monotonic_ms` всегда отражают момент обнаружения.
+pub fn set_event_timestamp_source(source: EventTimestampSource) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    WATCHER_OPTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .timestamp_source = match source {
+        EventTimestampSource::DetectedAt => file_watcher::TimestampSource::Detected,
+        EventTimestampSource::FileModified => file_watcher::TimestampSource::FileModified,
+    };
+    Ok(())
+}
+
+/// Включить режим «только наблюдение».
+///
+/// Ядро гарантирует, что ничего не пишет в watch dir: не создаёт её (папка
+/// должна существовать), не кладёт служебные файлы (`desktop.ini`), не
+/// удаляет файлы по квоте и не меняет атрибуты файлов. API, которым нужна
+/// запись, возвращают `LateraError::ReadOnlyMode`.
+///
+/// Если режим задан политикой администратора, другое значение —
+/// `LateraError::PolicyLocked`.
+pub fn set_read_only_mode(enabled: bool) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+    if policy::current()
+        .read_only
+        .is_some_and(|forced| forced != enabled)
+    {
+        return Err(LateraError::PolicyLocked(policy::READ_ONLY_KEY.to_string()));
+    }
+
+    read_only::set_enabled(enabled);
+    log::info!("Read-only observation mode: {enabled}");
+    Ok(())
+}
+
+/// Включён ли режим «только наблюдение».
+pub fn is_read_only_mode() -> bool {
+    read_only::is_enabled()
+}
+
+/// Включить согласование общей (сетевой) папки между экземплярами Latera.
+///
+/// Для каждого появившегося файла ядро создаёт рядом служебный claim-файл;
+/// автоматические действия (перенос по пресету загрузок и т.п.) выполняет
+/// только экземпляр, забравший файл первым. Результат — в
+/// [`FileEvent::claim`]. Claim-файлы не попадают в streams и удаляются,
+/// когда файл уходит из папки. Действует сразу для всех watcher'ов.
+pub fn set_shared_folder_claims(enabled: bool) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    claims::set_enabled(enabled);
+    log::info!(
+        "Shared folder claims: {enabled} (instance {})",
+        claims::instance_id()
+    );
+    Ok(())
+}
+
+/// Включено ли согласование общей папки.
+pub fn is_shared_folder_claims_enabled() -> bool {
+    claims::is_enabled()
+}
+
+/// Задать префикс имён служебных файлов ядра (по умолчанию `.latera-`).
+///
+/// События файлов с этим префиксом (probe-файлы проверок и т.п.) и
+/// `desktop.ini` никогда не попадают в streams. Действует сразу для всех
+/// watcher'ов; файлы со старым префиксом перестают подавляться.
+pub fn set_internal_file_prefix(prefix: String) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    internal_files::set_prefix(&prefix)?;
+    log::info!("Internal file prefix: {prefix:?}");
+    Ok(())
+}
+
+/// Текущий префикс имён служебных файлов ядра.
+pub fn get_internal_file_prefix() -> String {
+    internal_files::prefix()
+}
+
+/// Наблюдать и за вложенными папками watch dir.
+///
+/// Применяется при следующем [`start_watching`].
+pub fn set_watch_recursive(enabled: bool) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    WATCHER_OPTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .recursive = enabled;
+    Ok(())
+}
+
+/// Что делать, если папка наблюдения пропала (удалена, переименована,
+/// отключён диск).
+#[derive(Clone, Copy, Debug)]
+pub enum WatchDirRecoveryMode {
+    /// Остановить watcher ([`ApiWatcherErrorCode::WatchDirLost`]).
+    Stop,
+    /// Ждать, пока папка появится снова, и продолжить (по умолчанию).
+    WaitForReturn,
+    /// Создать папку заново и продолжить; если нет и родительской папки
+    /// (отключён диск) — ждать её возвращения.
+    Recreate,
+}
+
+/// Выбрать поведение при пропаже папки наблюдения.
+///
+/// О пропаже приходит [`WatchStatusEvent::WatchDirLost`], о возобновлении
+/// наблюдения — [`WatchStatusEvent::WatchDirRestored`]; изменения, сделанные
+/// в папке за это время, сообщаются при возобновлении, если включена
+/// сверка со снимком. Применяется при следующем [`start_watching`].
+pub fn set_watch_dir_recovery(mode: WatchDirRecoveryMode) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    WATCHER_OPTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .dir_recovery = match mode {
+        WatchDirRecoveryMode::Stop => file_watcher::WatchDirRecovery::Stop,
+        WatchDirRecoveryMode::WaitForReturn => file_watcher::WatchDirRecovery::WaitForReturn,
+        WatchDirRecoveryMode::Recreate => file_watcher::WatchDirRecovery::Recreate,
+    };
+    Ok(())
+}
+
+/// Когда watcher переходит с нативного backend'а на опрос папки, а с
+/// опроса — на ручное обновление (FRB bridge type).
+#[derive(Clone, Copy, Debug)]
+pub struct WatcherDegradationPolicy {
+    /// Сбоев нативного backend'а подряд до перехода на опрос.
+    pub native_failures: u32,
+    /// Сбоев опроса подряд до перехода на ручное обновление.
+    pub polling_failures: u32,
+    /// Период опроса папки.
+    pub poll_interval_ms: u32,
+}
+
+/// Настроить лестницу деградации watcher'а (по умолчанию включена:
+/// 3 сбоя, опрос раз в 5 с).
+///
+/// О каждом переходе приходит [`ApiWatcherState::Fallback`]; в режиме
+/// ручного обновления изменения находит [`rescan_now`]. `None` —
+/// только перезапуск нативного backend'а, затем [`ApiWatcherState::Error`].
+/// Применяется при следующем [`start_watching`].
+pub fn set_watcher_degradation(
+    policy: Option<WatcherDegradationPolicy>,
+) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    WATCHER_OPTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .degradation = policy.map(|p| file_watcher::DegradationPolicy {
+        native_failures: p.native_failures.max(1),
+        polling_failures: p.polling_failures.max(1),
+        poll_interval: std::time::Duration::from_millis(u64::from(p.poll_interval_ms.max(100))),
+    });
+    Ok(())
+}
+
+/// Способ наблюдения при запуске watcher'а.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ApiWatchBackend {
+    /// Опрос на сетевых дисках (SMB, NFS…), иначе нативный backend.
+    #[default]
+    Auto,
+    /// Всегда нативный backend.
+    Native,
+    /// Всегда опрос папки.
+    Polling,
+}
+
+/// Выбрать способ наблюдения (по умолчанию [`ApiWatchBackend::Auto`]).
+///
+/// Сетевые папки часто не присылают уведомлений об изменениях: в режиме
+/// `Auto` watcher на сетевом диске сразу опрашивает папку. `poll_interval_ms`
+/// — период опроса (не меньше 100 мс); `None` — из
+/// [`set_watcher_degradation`], а без неё — по типу тома (на сети 30 с).
+/// Выбранный способ возвращает [`start_watching`].
+/// Применяется при следующем [`start_watching`].
+pub fn set_watch_backend(
+    backend: ApiWatchBackend,
+    poll_interval_ms: Option<u32>,
+) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let mut options = WATCHER_OPTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    options.backend = match backend {
+        ApiWatchBackend::Auto => file_watcher::WatchBackend::Auto,
+        ApiWatchBackend::Native => file_watcher::WatchBackend::Native,
+        ApiWatchBackend::Polling => file_watcher::WatchBackend::Polling,
+    };
+    options.poll_interval =
+        poll_interval_ms.map(|ms| std::time::Duration::from_millis(u64::from(ms.max(100))));
+    Ok(())
+}
+
+/// Фильтр файлов watcher'а (FRB bridge type).
+///
+/// Шаблон без `/` сравнивается с именем файла (`*.pdf`, `~$*.tmp`), с `/` —
+/// с путём относительно watch dir (`drafts/*.docx`). Регистр не учитывается.
+#[derive(Clone, Debug, Default)]
+pub struct WatchFilter {
+    /// Хотя бы один должен совпасть (пусто — любые файлы).
+    pub include_globs: Vec<String>,
+    /// Ни один не должен совпасть.
+    pub exclude_globs: Vec<String>,
+    /// Допустимые расширения без точки, например `["pdf", "docx"]` (пусто — любые).
+    pub extensions: Vec<String>,
+    /// Сообщать ли скрытые файлы (имя начинается с `.`).
+    pub include_hidden: bool,
+}
+
+/// Сообщать только файлы, прошедшие фильтр (`None` — все файлы).
+///
+/// Отфильтрованные файлы не попадают ни в один stream — Dart не получает
+/// события, которые всё равно выбросил бы. Некорректный glob-шаблон —
+/// `LateraError::InvalidArgument`.
+/// Применяется при следующем [`start_watching`].
+pub fn set_watch_filter(filter: Option<WatchFilter>) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    WATCHER_OPTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .filter = internal_watch_filter(filter)?;
+    Ok(())
+}
+
+fn internal_watch_filter(
+    filter: Option<WatchFilter>,
+) -> Result<file_watcher::WatchFilter, LateraError> {
+    match filter {
+        Some(f) => file_watcher::WatchFilter::new(
+            &f.include_globs,
+            &f.exclude_globs,
+            &f.extensions,
+            f.include_hidden,
+        ),
+        None => Ok(file_watcher::WatchFilter::default()),
+    }
+}
+
+/// Игнорировать появившиеся файлы, чей mtime старше `max_age_minutes` минут.
+///
+/// Такие файлы (восстановленные из бэкапа, заново скачанные облачным
+/// клиентом) для пользователя не «новые». `None` выключает фильтр.
+/// Применяется при следующем [`start_watching`].
+pub fn set_ignore_files_older_than(max_age_minutes: Option<u32>) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    if max_age_minutes == Some(0) {
+        return Err(LateraError::InvalidArgument(
+            "max_age_minutes must be positive".to_string(),
+        ));
+    }
+    WATCHER_OPTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .max_file_age = max_age_minutes.map(|m| std::time::Duration::from_mins(u64::from(m)));
+    Ok(())
+}
+
+/// Сообщать о появлении файла, только когда он «осел»: размер и mtime не
+/// менялись `quiet_period_ms` миллисекунд.
+///
+/// Большой файл, копируемый в папку, иначе приходит недописанным. Пока файл
+/// не осел, его изменения не сообщаются; исчезнувший до этого файл не
+/// сообщается вовсе. `None` — события отдаются сразу.
+/// Применяется при следующем [`start_watching`].
+pub fn set_settle_quiet_period(quiet_period_ms: Option<u32>) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    if quiet_period_ms == Some(0) {
+        return Err(LateraError::InvalidArgument(
+            "quiet_period_ms must be positive".to_string(),
+        ));
+    }
+    WATCHER_OPTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .settle_quiet_period =
+        quiet_period_ms.map(|ms| std::time::Duration::from_millis(u64::from(ms)));
+    Ok(())
+}
+
+/// Сообщать об изменениях, случившихся, пока приложение было закрыто.
+///
+/// Watcher сохраняет снимок папки (имена, mtime и размеры файлов) в
+/// `{data_dir}/snapshots` во время работы и при остановке. При следующем
+/// запуске он сверяет папку со снимком и отдаёт расхождения пачкой в
+/// [`on_file_batch`] / [`on_file_event`] с `reconciled = true`. Первый запуск
+/// с включённой сверкой только запоминает состояние.
+/// Применяется при следующем [`start_watching`].
+pub fn set_offline_change_detection(enabled: bool) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let snapshot_dir = if enabled {
+        Some(lifecycle::data_dir()?.join(crate::dir_snapshot::SNAPSHOT_DIR_NAME))
+    } else {
+        None
+    };
+    WATCHER_OPTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .snapshot_dir = snapshot_dir;
+    Ok(())
+}
+
+/// macOS: при запуске брать пропущенные изменения из истории FSEvents, а не
+/// обходить папку целиком.
+///
+/// Номер последнего события хранится рядом со снимком папки, поэтому
+/// работает только с [`set_offline_change_detection`]. Если история
+/// недоступна (первый запуск, журнал тома сброшен, другая ОС), папка
+/// сверяется со снимком как обычно. Применяется при следующем
+/// [`start_watching`].
+pub fn set_fsevents_replay(enabled: bool) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    WATCHER_OPTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .fsevents_replay = enabled;
+    Ok(())
+}
+
+/// Активность одной подпапки наблюдаемой директории.
+#[derive(Clone, Debug)]
+pub struct WatchTreeStat {
+    /// Путь относительно watch dir (`""` — сама watch dir).
+    pub relative_path: String,
+    /// Сколько событий ФС пришло из подпапки с момента запуска watcher'а.
+    pub event_count: u64,
+    /// Время последнего события (Unix timestamp в миллисекундах).
+    pub last_activity_ms: i64,
+}
+
+/// Статистика активности по подпапкам watcher'а `watcher_id`.
+///
+/// Полезна в рекурсивном режиме ([`set_watch_recursive`]): видно, какие
+/// подпапки активны, чтобы закрепить или исключить их.
+pub fn get_watch_tree_stats(watcher_id: String) -> Result<Vec<WatchTreeStat>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let watchers = WATCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let watcher = watchers
+        .get(&watcher_id)
+        .ok_or(LateraError::WatcherNotRunning)?;
+    Ok(watcher
+        .handle
+        .tree_stats()
+        .into_iter()
+        .map(|s| WatchTreeStat {
+            relative_path: s.relative_path.to_string_lossy().to_string(),
+            event_count: s.event_count,
+            last_activity_ms: s.last_activity_ms,
+        })
+        .collect())
+}
+
+/// Счётчики потока событий watcher'а (FRB bridge type).
+#[derive(Clone, Debug)]
+pub struct WatcherMetrics {
+    /// Сколько событий пришло от backend'а ФС (включая отброшенные).
+    pub events_received: u64,
+    /// Сколько событий отдано подписчику (по одному и пачками).
+    pub events_emitted: u64,
+    /// Сколько повторов отброшено дедупликацией.
+    pub events_deduped: u64,
+    /// Сколько событий отброшено из-за переполненной очереди.
+    pub events_dropped: u64,
+    /// Сколько событий сейчас ждёт обработки.
+    pub queue_depth: u64,
+    /// Размер очереди: при `queue_depth == queue_capacity` новые события
+    /// отбрасываются.
+    pub queue_capacity: u64,
+}
+
+/// Счётчики потока событий watcher'а `watcher_id`.
+///
+/// События ФС проходят через ограниченную очередь: во время шторма
+/// событий лишние отбрасываются (`events_dropped`), а watcher затем сверяет
+/// папку и сообщает о пропущенных файлах пачкой.
+pub fn get_watcher_metrics(watcher_id: String) -> Result<WatcherMetrics, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let watchers = WATCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let watcher = watchers
+        .get(&watcher_id)
+        .ok_or(LateraError::WatcherNotRunning)?;
+    let metrics = watcher.handle.metrics();
+    Ok(WatcherMetrics {
+        events_received: metrics.events_received,
+        events_emitted: metrics.events_emitted,
+        events_deduped: metrics.events_deduped,
+        events_dropped: metrics.events_dropped,
+        queue_depth: metrics.queue_depth,
+        queue_capacity: file_watcher::EVENT_QUEUE_CAPACITY as u64,
+    })
+}
+
+/// Метрики ядра в текстовом формате Prometheus (exposition 0.0.4).
+///
+/// Гистограммы задержки события (от времени события ФС до выдачи) и
+/// длительности callback'а подписчика, счётчики и глубина очереди каждого
+/// запущенного watcher'а (метка `watcher_id`). Приложение может отдавать
+/// этот текст с локального debug-эндпоинта для Prometheus.
+pub fn export_metrics_text() -> Result<String, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let watchers: Vec<(String, file_watcher::WatcherMetrics)> = WATCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .iter()
+        .map(|(id, w)| (id.clone(), w.handle.metrics()))
+        .collect();
+    let per_watcher = |value: fn(&file_watcher::WatcherMetrics) -> u64| {
+        watchers
+            .iter()
+            .map(|(id, m)| (vec![("watcher_id", id.as_str())], value(m)))
+            .collect::<Vec<_>>()
+    };
+    Ok(metrics::export_text(|out| {
+        out.metric(
+            "latera_watcher_events_received_total",
+            "Events received from the file system backend.",
+            "counter",
+            &per_watcher(|m| m.events_received),
+        );
+        out.metric(
+            "latera_watcher_events_emitted_total",
+            "Events delivered to subscribers.",
+            "counter",
+            &per_watcher(|m| m.events_emitted),
+        );
+        out.metric(
+            "latera_watcher_events_deduped_total",
+            "Duplicate events dropped by deduplication.",
+            "counter",
+            &per_watcher(|m| m.events_deduped),
+        );
+        out.metric(
+            "latera_watcher_events_dropped_total",
+            "Events dropped because the event queue was full.",
+            "counter",
+            &per_watcher(|m| m.events_dropped),
+        );
+        out.metric(
+            "latera_watcher_queue_depth",
+            "Events waiting in the event queue.",
+            "gauge",
+            &per_watcher(|m| m.queue_depth),
+        );
+    }))
+}
+
+/// Писать логи watcher'а в отдельный ротируемый файл.
+///
+/// Логи watcher'а идут под target `latera::watcher::{watcher_id}`
+/// (`watcher_id` возвращает [`start_watching`]). При `enabled = true`
+/// они дополнительно пишутся в `{data_dir}/logs/watcher-{watcher_id}.log`
+/// (ротация по 5 МБ, 3 архива в gzip, не более 20 МБ суммарно).
+///
+/// Возвращает путь к лог-файлу watcher'а.
+pub fn set_watcher_log_file(watcher_id: String, enabled: bool) -> Result<String, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    if watcher_id.is_empty()
+        || !watcher_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(LateraError::InvalidArgument(format!(
+            "invalid watcher id: {watcher_id:?}"
+        )));
+    }
+
+    let path = lifecycle::data_dir()?
+        .join("logs")
+        .join(format!("watcher-{watcher_id}.log"));
+    let target = logging::watcher_target(&watcher_id);
+    if enabled {
+        logging::route_target_to_file(&target, &path)?;
+    } else {
+        logging::unroute_target(&target);
+    }
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// Получить дефолтный путь наблюдения (Desktop/Latera).
+///
+/// Создаёт директорию, если она не существует, и помечает её маркером
+/// `.latera-folder.json` (см. [`get_folder_marker`]). Занятое файлом или чужой папкой имя пропускается —
+/// возвращается фактический путь (`Desktop/Latera (2)`, …).
+/// Не запускает watcher — только возвращает путь.
+///
+/// Используется для:
+/// - Показа пути в UI до запуска watcher'а
+/// - Сохранения пути при первом запуске (onboarding)
+pub fn get_default_watch_path() -> Result<String, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let watch_dir = file_watcher::ensure_default_watch_dir()?;
+    Ok(watch_dir.to_string_lossy().to_string())
+}
+
+/// Получить дефолтный путь наблюдения (Desktop/Latera) **без** создания директории.
+///
+/// Если `Desktop/Latera` — файл или чужая папка (без маркера Latera и не
+/// пустая), возвращается следующее свободное имя: `Latera (2)`, … — та же
+/// папка, которую создаст [`get_default_watch_path`] и будет наблюдать
+/// [`start_watching`] без пути.
+///
+/// Важно: функция не создаёт папку.
+/// Используется в онбординге для preview до явного согласия пользователя.
+pub fn get_default_watch_path_preview() -> Result<String, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let watch_dir = file_watcher::default_watch_dir_preview()?;
+    Ok(watch_dir.to_string_lossy().to_string())
+}
+
+/// Состояние маркера папки Latera.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FolderMarkerStatus {
+    /// Маркера нет: папку Latera не создавала (или маркер удалён).
+    Missing,
+    /// Маркер прежних версий без данных; [`repair_folder_marker`] заменит его.
+    Legacy,
+    /// Файл маркера повреждён.
+    Corrupt,
+    Valid,
+}
+
+/// Маркер папки Latera (FRB bridge type).
+#[derive(Clone, Debug)]
+pub struct FolderMarkerInfo {
+    pub status: FolderMarkerStatus,
+    /// Идентификатор папки; сохраняется при переустановке приложения.
+    pub folder_id: Option<String>,
+    pub created_at_ms: Option<i64>,
+    pub settings_hash: Option<String>,
+    /// Маркер записан с теми же настройками, что действуют сейчас.
+    pub settings_match: bool,
+    /// Почему маркер не читается (для `Corrupt`).
+    pub error: Option<String>,
+}
+
+impl From<file_watcher::MarkerState> for FolderMarkerInfo {
+    fn from(state: file_watcher::MarkerState) -> Self {
+        let status = match &state {
+            file_watcher::MarkerState::Missing => FolderMarkerStatus::Missing,
+            file_watcher::MarkerState::Legacy => FolderMarkerStatus::Legacy,
+            file_watcher::MarkerState::Corrupt(_) => FolderMarkerStatus::Corrupt,
+            file_watcher::MarkerState::Valid(_) => FolderMarkerStatus::Valid,
+        };
+        let mut info = Self {
+            status,
+            folder_id: None,
+            created_at_ms: None,
+            settings_hash: None,
+            settings_match: false,
+            error: None,
+        };
+        match state {
+            file_watcher::MarkerState::Valid(marker) => {
+                info.settings_match = marker.settings_hash == file_watcher::folder_settings_hash();
+                info.folder_id = Some(marker.folder_id);
+                info.created_at_ms = Some(marker.created_at_ms);
+                info.settings_hash = Some(marker.settings_hash);
+            }
+            file_watcher::MarkerState::Corrupt(error) => info.error = Some(error),
+            file_watcher::MarkerState::Missing | file_watcher::MarkerState::Legacy => {}
+        }
+        info
+    }
+}
+
+/// Маркер папки `path` (`.latera-folder.json`).
+///
+/// По маркеру приложение узнаёт папку, созданную Latera до переустановки:
+/// `folder_id` тот же, `settings_match` показывает, совпадают ли настройки
+/// наблюдения с прежними.
+pub fn get_folder_marker(path: String) -> Result<FolderMarkerInfo, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    Ok(file_watcher::read_folder_marker(Path::new(&path)).into())
+}
+
+/// Записать маркер в папку `path` или починить его.
+///
+/// Читаемый маркер сохраняет `folder_id` и время создания, отпечаток
+/// настроек обновляется до текущего. Повреждённый или отсутствующий маркер
+/// создаётся заново (с новым `folder_id`). В режиме «только наблюдение» —
+/// [`LateraError::ReadOnlyMode`].
+pub fn repair_folder_marker(path: String) -> Result<FolderMarkerInfo, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let marker = file_watcher::repair_folder_marker(Path::new(&path))?;
+    Ok(file_watcher::MarkerState::Valid(marker).into())
+}
+
+/// Откуда взят вариант папки наблюдения.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WatchLocationKind {
+    /// `Desktop/Latera` — вариант по умолчанию.
+    DesktopFolder,
+    Downloads,
+    /// `Documents/Latera`.
+    DocumentsFolder,
+}
+
+impl From<file_watcher::WatchLocationKind> for WatchLocationKind {
+    fn from(kind: file_watcher::WatchLocationKind) -> Self {
+        match kind {
+            file_watcher::WatchLocationKind::DesktopFolder => Self::DesktopFolder,
+            file_watcher::WatchLocationKind::Downloads => Self::Downloads,
+            file_watcher::WatchLocationKind::DocumentsFolder => Self::DocumentsFolder,
+        }
+    }
+}
+
+/// Вариант папки наблюдения для экрана онбординга.
+#[derive(Clone, Debug)]
+pub struct WatchLocationSuggestion {
+    pub kind: WatchLocationKind,
+    pub path: String,
+    /// Папка уже существует (иначе будет создана при старте watcher'а).
+    pub exists: bool,
+    /// Текущий пользователь может писать в папку (или создать её).
+    pub writable: bool,
+    /// Свободное место на томе; `0` — не удалось определить.
+    pub available_bytes: u64,
+}
+
+/// Варианты папки наблюдения от лучшего к худшему: Desktop/Latera,
+/// Downloads, Documents/Latera; недоступные для записи и на томах с
+/// нехваткой места — в конце.
+///
+/// Как и [`get_default_watch_path_preview`], ничего не создаёт на диске.
+pub fn suggest_watch_locations() -> Result<Vec<WatchLocationSuggestion>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    Ok(file_watcher::suggest_watch_locations()
+        .into_iter()
+        .map(|location| WatchLocationSuggestion {
+            kind: location.kind.into(),
+            path: location.path.to_string_lossy().to_string(),
+            exists: location.exists,
+            writable: location.writable,
+            available_bytes: location.available_bytes.unwrap_or(0),
+        })
+        .collect())
+}
+
+/// Разрешение на доступ к «Рабочему столу» (macOS TCC).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DesktopAccessStatus {
+    Granted,
+    /// Пользователь не дал разрешения: запуск watcher'а в `Desktop/Latera`
+    /// вернёт `LateraError::PermissionDenied`.
+    Denied,
+    /// На этой ОС разрешение не нужно.
+    NotRequired,
+    /// Папки нет или её не удалось прочитать по другой причине.
+    Unknown,
+}
+
+impl From<desktop_access::DesktopAccessStatus> for DesktopAccessStatus {
+    fn from(status: desktop_access::DesktopAccessStatus) -> Self {
+        match status {
+            desktop_access::DesktopAccessStatus::Granted => Self::Granted,
+            desktop_access::DesktopAccessStatus::Denied => Self::Denied,
+            desktop_access::DesktopAccessStatus::NotRequired => Self::NotRequired,
+            desktop_access::DesktopAccessStatus::Unknown => Self::Unknown,
+        }
+    }
+}
+
+/// Есть ли у приложения доступ к «Рабочему столу».
+///
+/// На macOS доступ выдаёт пользователь (System Settings → Privacy &
+/// Security → Files and Folders); если он ещё не отвечал, проверка покажет
+/// системный запрос. Онбординг может опрашивать функцию, пока пользователь
+/// выдаёт разрешение.
+pub fn get_desktop_access_status() -> Result<DesktopAccessStatus, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    Ok(desktop_access::desktop_access_status().into())
+}
+
+/// Контролируемый доступ к папкам Windows (Microsoft Defender).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ControlledFolderAccessStatus {
+    /// Запись в защищённые папки (в том числе `Desktop/Latera`) блокируется,
+    /// пока Latera не добавлена в разрешённые приложения.
+    Enabled,
+    /// Запись разрешена, но попадает в журнал Defender.
+    AuditOnly,
+    Disabled,
+    /// Не Windows.
+    NotSupported,
+}
+
+impl From<desktop_access::ControlledFolderAccess> for ControlledFolderAccessStatus {
+    fn from(status: desktop_access::ControlledFolderAccess) -> Self {
+        match status {
+            desktop_access::ControlledFolderAccess::Enabled => Self::Enabled,
+            desktop_access::ControlledFolderAccess::AuditOnly => Self::AuditOnly,
+            desktop_access::ControlledFolderAccess::Disabled => Self::Disabled,
+            desktop_access::ControlledFolderAccess::NotSupported => Self::NotSupported,
+        }
+    }
+}
+
+/// Состояние контролируемого доступа и инструкция для пользователя.
+#[derive(Clone, Debug)]
+pub struct ControlledFolderAccessInfo {
+    pub status: ControlledFolderAccessStatus,
+    /// Исполняемый файл, который нужно разрешить.
+    pub app_path: String,
+    /// Открывает нужный раздел Windows Security (`launchUrl` во Flutter).
+    pub settings_uri: String,
+    /// Шаги по порядку.
+    pub steps: Vec<String>,
+}
+
+/// Контролируемый доступ к папкам Windows и как разрешить Latera запись.
+///
+/// Когда он включён, запуск watcher'а в защищённой папке возвращает
+/// `LateraError::ControlledFolderAccessBlocked`; UI показывает `steps` и
+/// кнопку, открывающую `settings_uri`.
+pub fn get_controlled_folder_access_info() -> Result<ControlledFolderAccessInfo, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let app_path = std::env::current_exe()?;
+    Ok(ControlledFolderAccessInfo {
+        status: desktop_access::controlled_folder_access().into(),
+        app_path: app_path.to_string_lossy().to_string(),
+        settings_uri: desktop_access::CONTROLLED_FOLDER_ACCESS_SETTINGS_URI.to_string(),
+        steps: desktop_access::controlled_folder_access_steps(&app_path),
+    })
+}
+
+/// Получить путь, где будет храниться индекс (локально на устройстве).
+///
+/// Важно: функция **не** создаёт директорию.
+/// Нужна, чтобы прозрачно показать пользователю, где лежат служебные данные.
+///
+/// Путь вычисляется от папки данных, выбранной в [`init_core`]
+/// (по умолчанию — OS-provided local app data directory, MSIX/sandbox safe).
+pub fn get_index_path() -> Result<String, LateraError> {
+    let index_dir = lifecycle::data_dir()?.join("index");
+    Ok(index_dir.to_string_lossy().to_string())
+}
+
+/// Остановить watcher `watcher_id` (graceful shutdown).
+///
+/// Когда останавливается последний watcher, streams событий закрываются
+/// (onDone во Flutter).
+///
+/// Поток watcher'а ждём не дольше 5 секунд: если он завис, watcher всё
+/// равно снимается, а возвращается `LateraError::ShutdownTimedOut`.
+pub fn stop_watching(watcher_id: String) -> Result<(), LateraApiError> {
+    lifecycle::ensure_initialized()?;
+
+    Ok(stop_watcher(watcher_id)?)
+}
+
+/// Останавливает watcher; когда его streams больше никто не использует —
+/// закрывает их.
+fn stop_watcher(watcher_id: String) -> Result<(), LateraError> {
+    let (watcher, last_in_scope) = detach_watcher(&watcher_id)?;
+    let result = watcher.stop(file_watcher::STOP_TIMEOUT);
+    release_watcher_scope(&watcher_id, last_in_scope);
+    result
+}
+
+/// Снимает watcher с учёта, не останавливая его: это делает вызывающий,
+/// а затем — [`release_watcher_scope`]. Второе значение — у scope watcher'а
+/// не осталось других watcher'ов.
+fn detach_watcher(watcher_id: &str) -> Result<(ActiveWatcher, bool), LateraError> {
+    let (watcher, last_in_scope) = {
+        let mut watchers = WATCHERS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let watcher = watchers
+            .remove(watcher_id)
+            .ok_or(LateraError::WatcherNotRunning)?;
+        let scopes = HANDLE_SCOPES
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let owner = |id: &str| scopes.get(id).map(Arc::as_ptr);
+        let last_in_scope = watchers.keys().all(|id| owner(id) != owner(watcher_id));
+        (watcher, last_in_scope)
+    };
+    KNOWN_FILES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .remove(watcher_id);
+    UNHASHED_WATCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .remove(watcher_id);
+    enqueue_duplicate_job(duplicates::DuplicateJob::Forget {
+        watcher_id: watcher_id.to_string(),
+    });
+    Ok((watcher, last_in_scope))
+}
+
+/// Забывает scope остановленного watcher'а; `close_streams` — закрыть
+/// streams этого scope.
+fn release_watcher_scope(watcher_id: &str, close_streams: bool) {
+    let scope = HANDLE_SCOPES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .remove(watcher_id);
+    if close_streams {
+        match scope {
+            Some(scope) => scope.sinks.close(),
+            None => close_watch_streams(),
+        }
+    }
+}
+
+/// Остановить все watcher'ы и закрыть streams (путь [`shutdown_core`]).
+///
+/// Watcher'ы останавливаются параллельно, так что зависшие потоки задерживают
+/// выход не больше чем на `timeout` в сумме. Streams закрываются, даже если
+/// какой-то watcher не остановился; возвращается первая ошибка.
+fn stop_watcher_and_streams(timeout: std::time::Duration) -> Result<(), LateraError> {
+    // 1) Сначала останавливаем watcher'ы (и ждём завершения тредов), чтобы они
+    // больше не могли эмитить события.
+    // Примечание: recover from poisoned mutex - если предыдущий поток паниковал,
+    // мы всё равно можем безопасно продолжить работу.
+    let watchers = std::mem::take(
+        &mut *WATCHERS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner),
+    );
+    let handle_scopes = std::mem::take(
+        &mut *HANDLE_SCOPES
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner),
+    );
+    KNOWN_FILES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clear();
+    UNHASHED_WATCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clear();
+
+    for watcher_id in watchers.keys() {
+        enqueue_duplicate_job(duplicates::DuplicateJob::Forget {
+            watcher_id: watcher_id.clone(),
+        });
+    }
+    let results: Vec<_> = std::thread::scope(|scope| {
+        let stops: Vec<_> = watchers
+            .into_iter()
+            .map(|(watcher_id, watcher)| scope.spawn(move || (watcher_id, watcher.stop(timeout))))
+            .collect();
+        stops
+            .into_iter()
+            .filter_map(|stop| stop.join().ok())
+            .collect()
+    });
+    let mut result = Ok(());
+    for (watcher_id, stopped) in results {
+        if let Err(e) = stopped {
+            log::error!("Failed to stop watcher {watcher_id}: {e}");
+            if result.is_ok() {
+                result = Err(e);
+            }
+        }
+    }
+
+    // 2) Затем закрываем streams.
+    close_watch_streams();
+    for scope in handle_scopes.values() {
+        scope.sinks.close();
+    }
+    for_each_live_scope(|scope| scope.sinks.close());
+    result
+}
+
+/// Закрыть streams событий (onDone во Flutter) и очистить sinks.
+fn close_watch_streams() {
+    SHARED_SCOPE.sinks.close();
+    log::debug!("File event streams closed");
+    close_file_removed_stream();
+    close_screenshot_stream();
+    close_ackable_stream();
+    close_watch_status_stream();
+    close_rule_applied_stream();
+}
+
+// ============================================================================
+// Acknowledged delivery API (at-least-once)
+// ============================================================================
+
+/// Событие добавления файла, ожидающее подтверждения через [`ack_event`].
+#[derive(Clone, Debug)]
+pub struct AckableFileEvent {
+    /// Номер события в WAL — передаётся в [`ack_event`].
+    pub sequence: u64,
+    pub file_name: String,
+    pub full_path: String,
+    pub occurred_at_ms: i64,
+    pub detected_at_ms: i64,
+    pub monotonic_ms: i64,
+    pub created_at_ms: i64,
+    pub modified_at_ms: i64,
+    /// Размер файла в байтах (как в [`FileAddedEvent::size_bytes`]).
+    pub size_bytes: u64,
+    /// Расширение в нижнем регистре, без точки.
+    pub extension: Option<String>,
+    /// MIME-тип по содержимому (`None` — файл пуст или не читается).
+    pub mime_type: Option<String>,
+    /// Владелец и права текущего пользователя (`None` — не удалось прочитать).
+    pub access: Option<ApiFileAccessInfo>,
+}
+
+impl AckableFileEvent {
+    fn new(sequence: u64, event: FileAddedEvent) -> Self {
+        Self {
+            sequence,
+            file_name: event.file_name,
+            full_path: event.full_path,
+            occurred_at_ms: event.occurred_at_ms,
+            detected_at_ms: event.detected_at_ms,
+            monotonic_ms: event.monotonic_ms,
+            created_at_ms: event.created_at_ms,
+            modified_at_ms: event.modified_at_ms,
+            size_bytes: event.size_bytes,
+            extension: event.extension,
+            mime_type: event.mime_type,
+            access: event.access,
+        }
+    }
+}
+
+static ACKABLE_SINK: Lazy<Mutex<Option<frb_generated::StreamSink<AckableFileEvent>>>> =
+    Lazy::new(|| Mutex::new(None));
+
+/// `Some` = режим подтверждения включён.
+static ACK_TRACKER: Lazy<Mutex<Option<event_ack::AckTracker>>> = Lazy::new(|| Mutex::new(None));
+
+static ACK_REDELIVERY: Lazy<Mutex<Option<event_ack::RedeliveryHandle>>> =
+    Lazy::new(|| Mutex::new(None));
+
+fn is_ack_mode_enabled() -> bool {
+    ACK_TRACKER
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .is_some()
+}
+
+fn close_ackable_stream() {
+    let _dropped = ACKABLE_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    log::debug!("Ackable file stream closed");
+}
+
+/// Отправить событие в ackable stream и запомнить момент отправки.
+fn send_ackable_file_added(sequence: u64, event: &file_watcher::InternalFileEvent) {
+    let sent = match ACKABLE_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+    {
+        Some(sink) => match sink.add(AckableFileEvent::new(sequence, to_file_added_event(event))) {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!("Failed to emit ackable event {sequence} (stream closed): {e}");
+                false
+            }
+        },
+        None => false,
+    };
+
+    if sent {
+        if let Some(tracker) = ACK_TRACKER
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .as_mut()
+        {
+            tracker.record_sent(sequence, std::time::Instant::now());
+        }
+    }
+}
+
+/// Один проход повторной доставки: отправляет события, которые ещё не
+/// отправлялись или не подтверждены дольше таймаута.
+fn redeliver_unacknowledged() {
+    let pending = EVENT_WAL
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+        .map(event_wal::EventWal::pending)
+        .unwrap_or_default();
+    if pending.is_empty() {
+        return;
+    }
+
+    let due = match ACK_TRACKER
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+    {
+        Some(tracker) => tracker.due(&pending, std::time::Instant::now()),
+        None => return,
+    };
+
+    for entry in &due {
+        match entry.to_event() {
+            Some(event) => send_ackable_file_added(entry.sequence, &event),
+            // Путь без имени файла доставить нельзя — не держим его вечно.
+            None => mark_event_delivered(entry.sequence),
+        }
+    }
+    if !due.is_empty() {
+        log::debug!("Ack redelivery pass: {} events sent", due.len());
+    }
+}
+
+/// Stream событий добавления файла с подтверждением.
+///
+/// В Dart это будет выглядеть как `Stream<AckableFileEvent> onFileAddedAckable()`.
+/// События приходят только при включённом [`set_ack_mode`]; каждое нужно
+/// подтвердить через [`ack_event`] после обработки.
+pub fn on_file_added_ackable(sink: frb_generated::StreamSink<AckableFileEvent>) {
+    let mut guard = ACKABLE_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_some() {
+        warn!("on_file_added_ackable called while previous stream is still bound; closing previous stream");
+    }
+    *guard = Some(sink);
+}
+
+/// Включить/выключить режим подтверждаемой доставки.
+///
+/// - `enabled = true`: событие остаётся в WAL до [`ack_event`]; если
+///   подтверждение не пришло за `redelivery_timeout_ms`
+///   (по умолчанию 30 с), событие отправляется повторно.
+/// - `enabled = false`: неподтверждённые события остаются в WAL и будут
+///   доставлены обычным stream'ом при следующем [`start_watching`].
+///
+/// Dart должен быть готов к дубликатам (at-least-once). Без WAL
+/// подтверждать нечего — если его не удалось открыть, возвращается ошибка.
+pub fn set_ack_mode(enabled: bool, redelivery_timeout_ms: Option<u64>) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    if !enabled {
+        disable_ack_mode();
+        return Ok(());
+    }
+
+    open_event_wal();
+    if EVENT_WAL
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .is_none()
+    {
+        return Err(LateraError::InvalidArgument(
+            "ack mode requires the event WAL, which is not open".to_string(),
+        ));
+    }
+
+    let timeout = redelivery_timeout_ms.map_or(
+        event_ack::DEFAULT_REDELIVERY_TIMEOUT,
+        std::time::Duration::from_millis,
+    );
+
+    // Повторное включение меняет только таймаут, учёт отправок сохраняется.
+    {
+        let mut guard = ACK_TRACKER
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        match guard.as_mut() {
+            Some(tracker) => tracker.set_redelivery_timeout(timeout)?,
+            None => *guard = Some(event_ack::AckTracker::new(timeout)?),
+        }
+    }
+
+    let mut redelivery = ACK_REDELIVERY
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if redelivery.is_none() {
+        *redelivery = Some(event_ack::spawn_redelivery(redeliver_unacknowledged)?);
+    }
+    log::info!(
+        "Ack mode enabled (redelivery timeout {} ms)",
+        timeout.as_millis()
+    );
+    Ok(())
+}
+
+fn disable_ack_mode() {
+    let handle = ACK_REDELIVERY
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    if let Some(handle) = handle {
+        handle.stop();
+    }
+    if ACK_TRACKER
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take()
+        .is_some()
+    {
+        log::info!("Ack mode disabled");
+    }
+}
+
+/// Подтвердить обработку события из [`on_file_added_ackable`].
+///
+/// Повторное подтверждение (например, для дубликата) безопасно.
+pub fn ack_event(sequence: u64) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    {
+        let mut guard = ACK_TRACKER
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let Some(tracker) = guard.as_mut() else {
+            return Err(LateraError::InvalidArgument(
+                "ack mode is disabled".to_string(),
+            ));
+        };
+        tracker.acknowledge(sequence);
+    }
+
+    mark_event_delivered(sequence);
+    Ok(())
+}
+
+// ============================================================================
+// Event debug info API
+// ============================================================================
+
+/// Сколько последних отладочных контекстов хранить.
+const EVENT_DEBUG_LOG_CAPACITY: usize = 1000;
+
+/// Отладочный контекст события (см. [`set_event_debug_enrichment`]).
+#[derive(Clone, Debug)]
+pub struct EventDebugInfo {
+    /// [`FileEvent::sequence`] события.
+    pub sequence: u64,
+    /// События `notify` по пути с прошлого сообщённого события, по порядку
+    /// (например `Create(File)`, `Modify(Data(Content))`).
+    pub notify_kinds: Vec<String>,
+    /// Решения watcher'а по пути: отброшенные дубликаты, ожидание
+    /// стабилизации, откладывание в пачку.
+    pub decisions: Vec<String>,
+    /// Первое событие `notify` (монотонные мс, как [`FileEvent::monotonic_ms`]).
+    pub first_seen_monotonic_ms: i64,
+    /// Сколько читались метаданные файла.
+    pub metadata_ms: u64,
+    /// Сколько событие ждало после сборки (стабилизация, пачка).
+    pub held_ms: u64,
+    /// Когда watcher отдал событие (монотонные мс).
+    pub delivered_monotonic_ms: i64,
+}
+
+/// Последние отладочные контексты, от старых к новым.
+static EVENT_DEBUG_LOG: Lazy<Mutex<VecDeque<EventDebugInfo>>> =
+    Lazy::new(|| Mutex::new(VecDeque::new()));
+
+fn record_event_debug_info(sequence: u64, event: &file_watcher::InternalFileEvent) {
+    let Some(debug) = event.debug.as_deref() else {
+        return;
+    };
+    let mut log = EVENT_DEBUG_LOG
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if log.len() >= EVENT_DEBUG_LOG_CAPACITY {
+        log.pop_front();
+    }
+    log.push_back(EventDebugInfo {
+        sequence,
+        notify_kinds: debug.notify_kinds.clone(),
+        decisions: debug.decisions.clone(),
+        first_seen_monotonic_ms: debug.first_seen_monotonic_ms,
+        metadata_ms: debug.metadata_ms,
+        held_ms: debug.held_ms,
+        delivered_monotonic_ms: debug.delivered_monotonic_ms,
+    });
+}
+
+fn clear_event_debug_log() {
+    EVENT_DEBUG_LOG
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clear();
+}
+
+/// Включить отладочный контекст событий для разбора обращений в поддержку.
+///
+/// Watcher запоминает сырые события `notify`, решения дедупликации и
+/// разбивку по времени для каждого события [`on_file_event`]; контекст
+/// последних событий отдаёт [`get_event_debug_info`]. Стоит памяти и
+/// времени на каждое событие — по умолчанию выключено.
+/// Применяется при следующем [`start_watching`].
+pub fn set_event_debug_enrichment(enabled: bool) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    WATCHER_OPTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .debug_enrichment = enabled;
+    Ok(())
+}
+
+/// Отладочный контекст события [`FileEvent::sequence`].
+///
+/// `None` — контекст не собирался (см. [`set_event_debug_enrichment`]) или
+/// событие слишком старое: хранятся последние 1000.
+pub fn get_event_debug_info(sequence: u64) -> Result<Option<EventDebugInfo>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    Ok(EVENT_DEBUG_LOG
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .iter()
+        .rev()
+        .find(|info| info.sequence == sequence)
+        .cloned())
+}
+
+// ============================================================================
+// Heartbeat API
+// ============================================================================
+
+/// Пульс ядра (см. [`on_heartbeat`]).
+#[derive(Clone, Debug)]
+pub struct HeartbeatEvent {
+    /// Порядковый номер пульса (с 1, в пределах подписки).
+    pub sequence: u64,
+    /// Время отправки (Unix timestamp в миллисекундах).
+    pub emitted_at_ms: i64,
+    /// Время с момента подписки (монотонное, не зависит от смены часов).
+    pub uptime_ms: u64,
+    /// Запущен ли watcher.
+    pub watcher_running: bool,
+}
+
+static HEARTBEAT: Lazy<Mutex<Option<heartbeat::HeartbeatHandle>>> = Lazy::new(|| Mutex::new(None));
+
+fn stop_heartbeat() {
+    let handle = HEARTBEAT
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    if let Some(handle) = handle {
+        handle.stop();
+    }
+}
+
+/// Stream пульса ядра.
+///
+/// В Dart это будет выглядеть как `Stream<HeartbeatEvent> onHeartbeat()`.
+/// Пульс приходит каждые `interval_ms` (по умолчанию 5 с). Если пульс не
+/// пришёл за несколько интервалов — ядро упало или зависло, и UI должен
+/// предложить перезапустить мониторинг.
+///
+/// Контракт как у [`on_file_added`]: один активный подписчик; stream
+/// закрывается при [`shutdown_core`].
+pub fn on_heartbeat(
+    sink: frb_generated::StreamSink<HeartbeatEvent>,
+    interval_ms: Option<u32>,
+) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let interval = interval_ms.map_or(heartbeat::DEFAULT_HEARTBEAT_INTERVAL, |ms| {
+        std::time::Duration::from_millis(u64::from(ms))
+    });
+
+    let mut guard = HEARTBEAT
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if let Some(previous) = guard.take() {
+        warn!("on_heartbeat called while previous stream is still bound; closing previous stream");
+        previous.stop();
+    }
+
+    *guard = Some(heartbeat::spawn(interval, move |beat| {
+        // Если WATCHERS заблокирован зависшим потоком, пульс тоже остановится —
+        // это и есть сигнал для Dart.
+        let watcher_running = !WATCHERS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .is_empty();
+        match sink.add(HeartbeatEvent {
+            sequence: beat.sequence,
+            emitted_at_ms: beat.emitted_at_ms,
+            uptime_ms: beat.uptime_ms,
+            watcher_running,
+        }) {
+            Ok(()) => true,
+            Err(e) => {
+                log::debug!("Heartbeat stream closed: {e}");
+                false
+            }
+        }
+    })?);
+    Ok(())
+}
+
+// ============================================================================
+// Watcher status API
+// ============================================================================
+
+/// Почему watcher перестал работать (см. [`ApiWatcherState::Error`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ApiWatcherErrorCode {
+    /// Не удалось создать backend `notify`.
+    BackendInitFailed,
+    /// Backend не смог начать наблюдение за папкой.
+    WatchFailed,
+    /// Backend перестал присылать события (канал закрыт).
+    ChannelDisconnected,
+    /// Наблюдаемая папка исчезла (удалена, переименована, диск отключён).
+    WatchDirLost,
+    /// Backend сообщил о фатальной ошибке.
+    BackendFailed,
+    /// Исчерпан лимит inotify (`fs.inotify.max_user_watches`), а переход на
+    /// опрос выключен.
+    WatchLimitExceeded,
+}
+
+impl From<file_watcher::WatcherErrorCode> for ApiWatcherErrorCode {
+    fn from(code: file_watcher::WatcherErrorCode) -> Self {
+        match code {
+            file_watcher::WatcherErrorCode::BackendInitFailed => Self::BackendInitFailed,
+            file_watcher::WatcherErrorCode::WatchFailed => Self::WatchFailed,
+            file_watcher::WatcherErrorCode::ChannelDisconnected => Self::ChannelDisconnected,
+            file_watcher::WatcherErrorCode::WatchDirLost => Self::WatchDirLost,
+            file_watcher::WatcherErrorCode::BackendFailed => Self::BackendFailed,
+            file_watcher::WatcherErrorCode::WatchLimitExceeded => Self::WatchLimitExceeded,
+        }
+    }
+}
+
+/// Как watcher получает изменения (см. [`ApiWatcherState::Fallback`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ApiWatchMode {
+    /// Нативный backend платформы.
+    Native,
+    /// Периодический опрос папки.
+    Polling,
+    /// Событий нет; изменения находит [`rescan_now`].
+    ManualRefresh,
+}
+
+impl From<file_watcher::WatchMode> for ApiWatchMode {
+    fn from(mode: file_watcher::WatchMode) -> Self {
+        match mode {
+            file_watcher::WatchMode::Native => Self::Native,
+            file_watcher::WatchMode::Polling => Self::Polling,
+            file_watcher::WatchMode::ManualRefresh => Self::ManualRefresh,
+        }
+    }
+}
+
+/// Состояние watcher'а.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ApiWatcherState {
+    /// Watcher запускается.
+    Starting,
+    /// События поступают.
+    Running,
+    /// Backend сообщает об ошибках — часть событий может теряться.
+    Degraded { reason: String },
+    /// Остановлен по запросу.
+    Stopped,
+    /// Watcher завершился из-за ошибки; событий больше не будет.
+    Error {
+        code: ApiWatcherErrorCode,
+        message: String,
+    },
+    /// Backend упал; попытка перезапуска `attempt` через `retry_in_ms`.
+    Restarting {
+        attempt: u32,
+        retry_in_ms: u64,
+        reason: String,
+    },
+    /// Папка наблюдения пропала; watcher ждёт её возвращения
+    /// (см. [`set_watch_dir_recovery`]).
+    DirLost { path: String },
+    /// Нативный backend не работает; watcher получает изменения способом
+    /// `mode` (см. [`set_watcher_degradation`]).
+    Fallback { mode: ApiWatchMode, reason: String },
+}
+
+impl From<&file_watcher::WatcherState> for ApiWatcherState {
+    fn from(state: &file_watcher::WatcherState) -> Self {
+        match state {
+            file_watcher::WatcherState::Starting => Self::Starting,
+            file_watcher::WatcherState::Running => Self::Running,
+            file_watcher::WatcherState::Degraded { reason } => Self::Degraded {
+                reason: reason.clone(),
+            },
+            file_watcher::WatcherState::Stopped => Self::Stopped,
+            file_watcher::WatcherState::Error { code, message } => Self::Error {
+                code: (*code).into(),
+                message: message.clone(),
+            },
+            file_watcher::WatcherState::Restarting {
+                attempt,
+                retry_in,
+                reason,
+            } => Self::Restarting {
+                attempt: *attempt,
+                retry_in_ms: u64::try_from(retry_in.as_millis()).unwrap_or(u64::MAX),
+                reason: reason.clone(),
+            },
+            file_watcher::WatcherState::DirLost { path } => Self::DirLost {
+                path: path.to_string_lossy().to_string(),
+            },
+            file_watcher::WatcherState::Fallback { mode, reason } => Self::Fallback {
+                mode: (*mode).into(),
+                reason: reason.clone(),
+            },
+        }
+    }
+}
+
+/// Состояние watcher'а `watcher_id` (см. [`on_watcher_status`]).
+#[derive(Clone, Debug)]
+pub struct WatcherStatusEvent {
+    pub watcher_id: String,
+    pub state: ApiWatcherState,
+    /// `true` — периодический пульс, `false` — смена состояния.
+    pub heartbeat: bool,
+    /// Время отправки (Unix timestamp в миллисекундах).
+    pub emitted_at_ms: i64,
+}
+
+static WATCHER_STATUS_SINK: Lazy<Mutex<Option<frb_generated::StreamSink<WatcherStatusEvent>>>> =
+    Lazy::new(|| Mutex::new(None));
+
+static WATCHER_STATUS_HEARTBEAT: Lazy<Mutex<Option<heartbeat::HeartbeatHandle>>> =
+    Lazy::new(|| Mutex::new(None));
+
+fn close_watcher_status_stream() {
+    let handle = WATCHER_STATUS_HEARTBEAT
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    if let Some(handle) = handle {
+        handle.stop();
+    }
+    let _dropped = WATCHER_STATUS_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    log::debug!("Watcher status stream closed");
+}
+
+/// Отправить состояние watcher'а. Возвращает `false`, если stream закрыт.
+///
+/// Вызывается с треда watcher'а — [`WATCHERS`] здесь не блокируется.
+fn emit_watcher_status(
+    watcher_id: &str,
+    state: &file_watcher::WatcherState,
+    heartbeat: bool,
+) -> bool {
+    let guard = WATCHER_STATUS_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let Some(sink) = guard.as_ref() else {
+        return false;
+    };
+    match sink.add(WatcherStatusEvent {
+        watcher_id: watcher_id.to_string(),
+        state: state.into(),
+        heartbeat,
+        emitted_at_ms: file_watcher::now_ms(),
+    }) {
+        Ok(()) => true,
+        Err(e) => {
+            log::debug!("Failed to emit watcher status event: {e}");
+            false
+        }
+    }
+}
+
+/// Stream состояний watcher'ов.
+///
+/// В Dart это будет выглядеть как `Stream<WatcherStatusEvent> onWatcherStatus()`.
+/// Сразу после подписки приходит текущее состояние каждого watcher'а, затем —
+/// каждая смена состояния (`heartbeat == false`) и раз в `interval_ms`
+/// (по умолчанию 5 с) пульс с текущим состоянием (`heartbeat == true`).
+/// Если backend `notify` умер, приходит [`ApiWatcherState::Restarting`] на
+/// каждую попытку перезапуска, при переходе на опрос или ручное обновление —
+/// [`ApiWatcherState::Fallback`], а если перезапуск невозможен —
+/// [`ApiWatcherState::Error`]: UI не должен продолжать показывать
+/// «наблюдение идёт».
+///
+/// Контракт как у [`on_file_added`]: один активный подписчик; stream
+/// закрывается при [`shutdown_core`].
+pub fn on_watcher_status(
+    sink: frb_generated::StreamSink<WatcherStatusEvent>,
+    interval_ms: Option<u32>,
+) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let interval = interval_ms.map_or(heartbeat::DEFAULT_HEARTBEAT_INTERVAL, |ms| {
+        std::time::Duration::from_millis(u64::from(ms))
+    });
+
+    let mut heartbeat_guard = WATCHER_STATUS_HEARTBEAT
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if let Some(previous) = heartbeat_guard.take() {
+        warn!("on_watcher_status called while previous stream is still bound; closing previous stream");
+        previous.stop();
+    }
+    *WATCHER_STATUS_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(sink);
+
+    let emit_all = |heartbeat: bool| {
+        let states: Vec<(String, file_watcher::WatcherState)> = WATCHERS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .iter()
+            .map(|(id, watcher)| (id.clone(), watcher.handle.state()))
+            .collect();
+        states
+            .iter()
+            .all(|(id, state)| emit_watcher_status(id, state, heartbeat))
+    };
+    emit_all(false);
+
+    *heartbeat_guard = Some(heartbeat::spawn(interval, move |_beat| {
+        let sink_open = WATCHER_STATUS_SINK
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .is_some();
+        sink_open && emit_all(true)
+    })?);
+    Ok(())
+}
+
+// ============================================================================
+// Watch status API
+// ============================================================================
+
+/// Статусное событие наблюдаемой папки (см. [`on_watch_status`]).
+#[derive(Clone, Debug)]
+pub enum WatchStatusEvent {
+    /// На томе с watch dir осталось меньше `threshold_bytes` свободного места.
+    /// Функции копирования в watch dir приостановлены.
+    LowDiskSpace {
+        available_bytes: u64,
+        threshold_bytes: u64,
+    },
+    /// Свободного места снова достаточно; копирование возобновлено.
+    DiskSpaceRecovered { available_bytes: u64 },
+    /// Папка watcher'а `watcher_id` превысила квоту (см. [`set_folder_quota`]).
+    /// Лимит `0` — не задан. Если включена очистка, `removed_files`/`freed_bytes` —
+    /// её результат.
+    QuotaExceeded {
+        watcher_id: String,
+        total_bytes: u64,
+        file_count: u64,
+        max_total_bytes: u64,
+        max_file_count: u64,
+        removed_files: u64,
+        freed_bytes: u64,
+    },
+    /// Появился файл с тем же именем (без учёта регистра), что и уже
+    /// известный, но с другим содержимым. Отпечатки — SHA-256 в hex;
+    /// по ним UI предлагает «оставить оба» / «заменить».
+    NameConflict {
+        file_name: String,
+        new_path: String,
+        new_fingerprint: String,
+        existing_path: String,
+        existing_fingerprint: String,
+    },
+    /// Файл настроек ядра оказался повреждён и восстановлен из резервной
+    /// копии `backup_index` (`1` — самая свежая; `0` — целых копий нет,
+    /// настройки сброшены). Повреждённый файл отложен в `corrupt_path`.
+    SettingsRecovered {
+        backup_index: u32,
+        corrupt_path: String,
+    },
+    /// Папка watcher'а `watcher_id` пропала (удалена, переименована,
+    /// отключён диск). Дальше — по [`set_watch_dir_recovery`].
+    WatchDirLost { watcher_id: String, path: String },
+    /// Папка снова доступна, наблюдение возобновлено.
+    WatchDirRestored { watcher_id: String, path: String },
+}
+
+static WATCH_STATUS_SINK: Lazy<Mutex<Option<frb_generated::StreamSink<WatchStatusEvent>>>> =
+    Lazy::new(|| Mutex::new(None));
+
+static DISK_SPACE_SETTINGS: Lazy<Mutex<disk_space::DiskSpaceSettings>> =
+    Lazy::new(|| Mutex::new(disk_space::DiskSpaceSettings::default()));
+
+fn close_watch_status_stream() {
+    let _dropped = WATCH_STATUS_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    log::debug!("Watch status stream closed");
+}
+
+fn emit_watch_status(event: WatchStatusEvent) {
+    if let Some(sink) = WATCH_STATUS_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+    {
+        if let Err(e) = sink.add(event) {
+            log::debug!("Failed to emit watch status event: {e}");
+        }
+    }
+}
+
+/// Известные файлы каждой watch dir (по `watcher_id`) — для обнаружения
+/// конфликтов имён. Заполняется при [`start_watching`].
+/// Watcher'ы, чьи файлы не хэшируются из-за тома (см. [`get_volume_info`]).
+static UNHASHED_WATCHERS: Lazy<Mutex<std::collections::HashSet<String>>> =
+    Lazy::new(|| Mutex::new(std::collections::HashSet::new()));
+
+static KNOWN_FILES: Lazy<Mutex<HashMap<String, name_conflict::KnownFiles>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn check_name_conflict(watcher_id: &str, path: &Path) {
+    let conflict = KNOWN_FILES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .get_mut(watcher_id)
+        .and_then(|known| known.observe_added(path));
+    if let Some(conflict) = conflict {
+        log::info!(
+            "Name conflict: {} vs {}",
+            conflict.new_path.display(),
+            conflict.existing_path.display()
+        );
+        emit_watch_status(WatchStatusEvent::NameConflict {
+            file_name: conflict.file_name,
+            new_path: conflict.new_path.to_string_lossy().to_string(),
+            new_fingerprint: conflict.new_fingerprint,
+            existing_path: conflict.existing_path.to_string_lossy().to_string(),
+            existing_fingerprint: conflict.existing_fingerprint,
+        });
+    }
+}
+
+fn forget_known_file(watcher_id: &str, path: &Path) {
+    if let Some(known) = KNOWN_FILES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .get_mut(watcher_id)
+    {
+        known.forget(path);
+    }
+}
+
+/// Запустить мониторинг свободного места для папки watcher'а.
+fn start_disk_space_monitor(
+    watch_dir: PathBuf,
+) -> Result<disk_space::DiskSpaceMonitorHandle, LateraError> {
+    let settings = *DISK_SPACE_SETTINGS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    disk_space::spawn_monitor(watch_dir, settings, |status| {
+        emit_watch_status(match status {
+            disk_space::DiskSpaceStatus::Low {
+                available_bytes,
+                threshold_bytes,
+            } => WatchStatusEvent::LowDiskSpace {
+                available_bytes,
+                threshold_bytes,
+            },
+            disk_space::DiskSpaceStatus::Recovered { available_bytes } => {
+                WatchStatusEvent::DiskSpaceRecovered { available_bytes }
+            }
+        });
+    })
+}
+
+static FOLDER_QUOTA: Lazy<Mutex<quota::FolderQuota>> =
+    Lazy::new(|| Mutex::new(quota::FolderQuota::default()));
+
+/// Запустить проверку квоты для папки watcher'а. `None` — квота не задана.
+fn start_quota_monitor(
+    watcher_id: &str,
+    watch_dir: PathBuf,
+) -> Result<Option<quota::QuotaMonitorHandle>, LateraError> {
+    let folder_quota = policy::current().cap_quota(
+        *FOLDER_QUOTA
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner),
+    );
+    if !folder_quota.is_enabled() {
+        return Ok(None);
+    }
+    let watcher_id = watcher_id.to_string();
+    let handle = quota::spawn_monitor(
+        watch_dir,
+        folder_quota,
+        quota::DEFAULT_QUOTA_CHECK_INTERVAL,
+        move |event| {
+            let cleanup = event.cleanup.unwrap_or_default();
+            emit_watch_status(WatchStatusEvent::QuotaExceeded {
+                watcher_id: watcher_id.clone(),
+                total_bytes: event.usage.total_bytes,
+                file_count: event.usage.file_count,
+                max_total_bytes: event.quota.max_total_bytes.unwrap_or(0),
+                max_file_count: event.quota.max_file_count.unwrap_or(0),
+                removed_files: cleanup.removed_files,
+                freed_bytes: cleanup.freed_bytes,
+            });
+        },
+    )?;
+    Ok(Some(handle))
+}
+
+/// Stream статусных событий наблюдаемой папки.
+///
+/// В Dart это будет выглядеть как `Stream<WatchStatusEvent> onWatchStatus()`.
+/// Контракт как у [`on_file_added`]: один активный подписчик; stream
+/// закрывается при [`stop_watching`] / [`shutdown_core`].
+pub fn on_watch_status(sink: frb_generated::StreamSink<WatchStatusEvent>) {
+    let mut guard = WATCH_STATUS_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_some() {
+        warn!("on_watch_status called while previous stream is still bound; replacing sink");
+    }
+    *guard = Some(sink);
+}
+
+/// Настроить порог свободного места на томе с watch dir.
+///
+/// - `threshold_bytes`: ниже этого значения отправляется
+///   [`WatchStatusEvent::LowDiskSpace`] и копирование в watch dir приостанавливается;
+/// - `check_interval_ms`: период проверки (по умолчанию 30 с, минимум 1 с).
+///
+/// Если watcher запущен, мониторинг перезапускается с новыми настройками.
+pub fn set_low_disk_space_threshold(
+    threshold_bytes: u64,
+    check_interval_ms: Option<u32>,
+) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let check_interval = check_interval_ms.map_or(disk_space::DEFAULT_CHECK_INTERVAL, |ms| {
+        std::time::Duration::from_millis(u64::from(ms))
+    });
+    if check_interval < disk_space::MIN_CHECK_INTERVAL {
+        return Err(LateraError::InvalidArgument(format!(
+            "disk space check interval must be at least {} ms",
+            disk_space::MIN_CHECK_INTERVAL.as_millis()
+        )));
+    }
+    *DISK_SPACE_SETTINGS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = disk_space::DiskSpaceSettings {
+        threshold_bytes,
+        check_interval,
+    };
+
+    let mut watchers = WATCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    for watcher in watchers.values_mut() {
+        if let Some(previous) = watcher.disk_space_monitor.take() {
+            previous.stop();
+        }
+        watcher.disk_space_monitor = Some(start_disk_space_monitor(
+            watcher.handle.watch_dir().to_path_buf(),
+        )?);
+    }
+    Ok(())
+}
+
+/// Задать квоту наблюдаемой папки.
+///
+/// - `max_total_bytes` / `max_file_count`: лимиты (`None` — без ограничения;
+///   оба `None` — квота отключена);
+/// - `cleanup_oldest`: при превышении удалять самые старые файлы, пока папка
+///   не уложится в квоту (недоступно в режиме «только наблюдение»).
+///
+/// При превышении отправляется [`WatchStatusEvent::QuotaExceeded`]. Если
+/// watcher запущен, проверка перезапускается с новой квотой. Лимиты
+/// политики администратора действуют поверх заданных здесь: применяется
+/// меньший из двух.
+pub fn set_folder_quota(
+    max_total_bytes: Option<u64>,
+    max_file_count: Option<u64>,
+    cleanup_oldest: bool,
+) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+    if cleanup_oldest {
+        read_only::ensure_writable("quota cleanup")?;
+    }
+
+    *FOLDER_QUOTA
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = quota::FolderQuota {
+        max_total_bytes,
+        max_file_count,
+        cleanup_oldest,
+    };
+
+    let mut watchers = WATCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    for (watcher_id, watcher) in watchers.iter_mut() {
+        if let Some(previous) = watcher.quota_monitor.take() {
+            previous.stop();
+        }
+        watcher.quota_monitor =
+            start_quota_monitor(watcher_id, watcher.handle.watch_dir().to_path_buf())?;
+    }
+    Ok(())
+}
+
+/// Свободное место (в байтах) на томе с папкой watcher'а `watcher_id`.
+pub fn get_watch_volume_free_space(watcher_id: String) -> Result<u64, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let watch_dir = WATCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .get(&watcher_id)
+        .map(|watcher| watcher.handle.watch_dir().to_path_buf())
+        .ok_or(LateraError::WatcherNotRunning)?;
+    disk_space::available_space(&watch_dir)
+}
+
+/// Свободное место (в байтах) на томе, где находится `path`.
+///
+/// В отличие от [`get_watch_volume_free_space`], не требует запущенного
+/// watcher'а: например, чтобы проверить место перед выбором папки Latera.
+/// О нехватке места во время работы сообщает
+/// [`WatchStatusEvent::LowDiskSpace`] (см. [`set_low_disk_space_threshold`]).
+pub fn get_free_space(path: String) -> Result<u64, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    disk_space::available_space(Path::new(&path))
+}
+
+/// Размер папки (FRB bridge type).
+#[derive(Clone, Debug)]
+pub struct DirSizeInfo {
+    pub total_bytes: u64,
+    pub file_count: u64,
+    /// Вложенные папки (сама папка не считается).
+    pub dir_count: u64,
+}
+
+/// Размер папки `path` со всеми вложенными (обход в несколько потоков).
+///
+/// Вызывать не из UI-потока: большая папка считается секунды.
+pub fn get_dir_size(path: String) -> Result<DirSizeInfo, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let size = disk_space::dir_size(Path::new(&path))?;
+    Ok(DirSizeInfo {
+        total_bytes: size.total_bytes,
+        file_count: size.file_count,
+        dir_count: size.dir_count,
+    })
+}
+
+/// Тип тома (см. [`get_volume_info`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VolumeKind {
+    Internal,
+    /// Флешка, SD-карта, внешний USB-диск.
+    Removable,
+    /// SMB, NFS, AFP, WebDAV.
+    Network,
+    /// Папка облачного клиента (OneDrive, iCloud Drive, Dropbox, Google Drive).
+    CloudSynced,
+    Unknown,
+}
+
+impl From<volume::VolumeKind> for VolumeKind {
+    fn from(kind: volume::VolumeKind) -> Self {
+        match kind {
+            volume::VolumeKind::Internal => Self::Internal,
+            volume::VolumeKind::Removable => Self::Removable,
+            volume::VolumeKind::Network => Self::Network,
+            volume::VolumeKind::CloudSynced => Self::CloudSynced,
+            volume::VolumeKind::Unknown => Self::Unknown,
+        }
+    }
+}
+
+/// Том папки и умолчания наблюдения для него (FRB bridge type).
+#[derive(Clone, Debug)]
+pub struct VolumeInfo {
+    pub kind: VolumeKind,
+    pub mount_point: Option<String>,
+    /// `apfs`, `ntfs`, `smbfs`…; пусто, если неизвестна.
+    pub file_system: String,
+    /// `None` — тип накопителя неизвестен.
+    pub is_ssd: Option<bool>,
+    pub cloud_provider: Option<String>,
+    /// Период опроса, если watcher перейдёт на опрос.
+    pub poll_interval_ms: u64,
+    /// Ожидание стабилизации файла; `None` — события сразу.
+    pub settle_quiet_period_ms: Option<u64>,
+    /// Хэшируются ли появившиеся файлы (см. [`set_event_hashing`]).
+    pub hashing_enabled: bool,
+}
+
+/// Том, на котором лежит папка `path`, и умолчания наблюдения для него.
+///
+/// Watcher применяет эти умолчания сам: на сетевом диске и в папке
+/// облачного клиента файлы сообщаются после стабилизации, опрос реже, а
+/// хэширование выключено (хэш файла «только в облаке» скачал бы его).
+/// Явно заданные [`set_settle_quiet_period`] и период опроса не меняются.
+pub fn get_volume_info(path: String) -> Result<VolumeInfo, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let info = volume::volume_info(Path::new(&path))?;
+    let strategy = info.kind.strategy();
+    let millis = |d: std::time::Duration| u64::try_from(d.as_millis()).unwrap_or(u64::MAX);
+    Ok(VolumeInfo {
+        kind: info.kind.into(),
+        mount_point: info
+            .mount_point
+            .map(|mount_point| mount_point.to_string_lossy().to_string()),
+        file_system: info.file_system,
+        is_ssd: match info.storage {
+            volume::StorageKind::Ssd => Some(true),
+            volume::StorageKind::Hdd => Some(false),
+            volume::StorageKind::Unknown => None,
+        },
+        cloud_provider: info.cloud_provider,
+        poll_interval_ms: millis(strategy.poll_interval),
+        settle_quiet_period_ms: strategy.settle_quiet_period.map(millis),
+        hashing_enabled: strategy.hashing,
+    })
+}
+
+/// Приостановлено ли копирование в watch dir из-за нехватки места.
+pub fn is_copy_in_paused() -> bool {
+    disk_space::copy_in_paused()
+}
+
+// ============================================================================
+// File status API
+// ============================================================================
+
+/// Статус обработки файла (FRB bridge type).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileProcessingStatus {
+    New,
+    Processing,
+    Processed,
+    Failed,
+    Quarantined,
+}
+
+impl From<file_status::FileStatus> for FileProcessingStatus {
+    fn from(status: file_status::FileStatus) -> Self {
+        match status {
+            file_status::FileStatus::New => Self::New,
+            file_status::FileStatus::Processing => Self::Processing,
+            file_status::FileStatus::Processed => Self::Processed,
+            file_status::FileStatus::Failed => Self::Failed,
+            file_status::FileStatus::Quarantined => Self::Quarantined,
+        }
+    }
+}
+
+impl From<FileProcessingStatus> for file_status::FileStatus {
+    fn from(status: FileProcessingStatus) -> Self {
+        match status {
+            FileProcessingStatus::New => Self::New,
+            FileProcessingStatus::Processing => Self::Processing,
+            FileProcessingStatus::Processed => Self::Processed,
+            FileProcessingStatus::Failed => Self::Failed,
+            FileProcessingStatus::Quarantined => Self::Quarantined,
+        }
+    }
+}
+
+/// Событие: изменился статус файла.
+#[derive(Clone, Debug)]
+pub struct FileStatusChangedEvent {
+    pub full_path: String,
+    /// `None` — файл удалён и больше не отслеживается.
+    pub status: Option<FileProcessingStatus>,
+    pub updated_at_ms: i64,
+}
+
+static FILE_STATUS_SINK: Lazy<Mutex<Option<frb_generated::StreamSink<FileStatusChangedEvent>>>> =
+    Lazy::new(|| Mutex::new(None));
+
+fn with_file_status_store<F, T>(f: F) -> Result<T, LateraError>
+where
+    F: FnOnce(&mut file_status::FileStatusStore) -> Result<T, LateraError>,
+{
+    SHARED_SCOPE.stores.with_file_status(f)
+}
+
+fn close_file_status_stream() {
+    let _dropped = FILE_STATUS_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+}
+
+fn emit_file_status_changed(event: FileStatusChangedEvent) {
+    if let Some(sink) = FILE_STATUS_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+    {
+        if let Err(e) = sink.add(event) {
+            log::debug!("Failed to emit file status event: {e}");
+        }
+    }
+}
+
+/// Обновить статус по событию watcher'а: `Some` — файл появился, `None` — удалён.
+fn track_file_status(watcher_id: &str, path: &Path, status: Option<file_status::FileStatus>) {
+    let updated_at_ms = file_watcher::now_ms();
+    let result = watcher_scope(watcher_id)
+        .stores
+        .with_file_status(|store| match status {
+            Some(status) => store.set(path, status, updated_at_ms),
+            None => store.remove(path),
+        });
+    match result {
+        Ok(true) => emit_file_status_changed(FileStatusChangedEvent {
+            full_path: path.to_string_lossy().to_string(),
+            status: status.map(Into::into),
+            updated_at_ms,
+        }),
+        Ok(false) => {}
+        Err(e) => log::warn!("Failed to update file status: {e}"),
+    }
+}
+
+/// Статус обработки файла. `None` — файл неизвестен ядру.
+pub fn get_file_status(path: String) -> Result<Option<FileProcessingStatus>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let record = with_file_status_store(|store| store.get(Path::new(&path)))?;
+    Ok(record.map(|r| r.status.into()))
+}
+
+/// Установить статус обработки файла.
+///
+/// Если статус изменился, отправляется [`FileStatusChangedEvent`].
+pub fn set_file_status(path: String, status: FileProcessingStatus) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let updated_at_ms = file_watcher::now_ms();
+    let changed =
+        with_file_status_store(|store| store.set(Path::new(&path), status.into(), updated_at_ms))?;
+    if changed {
+        audit_quarantine(&path, status);
+        emit_file_status_changed(FileStatusChangedEvent {
+            full_path: path,
+            status: Some(status),
+            updated_at_ms,
+        });
+    }
+    Ok(())
+}
+
+/// Обновление статуса одного файла для [`set_file_statuses`].
+#[derive(Clone, Debug)]
+pub struct FileStatusUpdate {
+    pub full_path: String,
+    pub status: FileProcessingStatus,
+}
+
+/// Установить статусы нескольких файлов за один вызов.
+///
+/// Все обновления применяются одной транзакцией хранилища: либо все,
+/// либо (при ошибке) ни одного. По каждому изменившемуся статусу
+/// отправляется [`FileStatusChangedEvent`].
+pub fn set_file_statuses(updates: Vec<FileStatusUpdate>) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let updated_at_ms = file_watcher::now_ms();
+    let internal: Vec<(PathBuf, file_status::FileStatus)> = updates
+        .iter()
+        .map(|u| (PathBuf::from(&u.full_path), u.status.into()))
+        .collect();
+    let changed = with_file_status_store(|store| store.set_many(&internal, updated_at_ms))?;
+    for index in changed {
+        let update = &updates[index];
+        audit_quarantine(&update.full_path, update.status);
+        emit_file_status_changed(FileStatusChangedEvent {
+            full_path: update.full_path.clone(),
+            status: Some(update.status),
+            updated_at_ms,
+        });
+    }
+    Ok(())
+}
+
+/// Карантин — действие над файлом: записывается в журнал аудита
+/// (правила нет — статус задало приложение).
+fn audit_quarantine(path: &str, status: FileProcessingStatus) {
+    if status == FileProcessingStatus::Quarantined {
+        audit_log::record(
+            audit_log::AuditAction::Quarantine,
+            Path::new(path),
+            None,
+            None,
+        );
+    }
+}
+
+/// Stream изменений статусов файлов.
+///
+/// В Dart это будет выглядеть как `Stream<FileStatusChangedEvent> onFileStatusChanged()`.
+/// Один активный подписчик; stream закрывается при [`shutdown_core`].
+pub fn on_file_status_changed(sink: frb_generated::StreamSink<FileStatusChangedEvent>) {
+    let mut guard = FILE_STATUS_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_some() {
+        warn!("on_file_status_changed called while previous stream is still bound; replacing sink");
+    }
+    *guard = Some(sink);
+}
+
+// ============================================================================
+// Tags API
+// ============================================================================
+
+fn with_tag_store<F, T>(f: F) -> Result<T, LateraError>
+where
+    F: FnOnce(&tags::TagStore) -> Result<T, LateraError>,
+{
+    SHARED_SCOPE.stores.with_tags(f)
+}
+
+/// Перенести теги вслед за файлом по событию watcher'а.
+fn track_tags(watcher_id: &str, event: &file_watcher::InternalFileEvent) {
+    let path = &event.full_path;
+    let result = watcher_scope(watcher_id).stores.with_tags(|store| {
+        match (event.kind, &event.previous_path) {
+            (file_watcher::FileEventKind::RenamedTo, Some(from)) => store.rename(from, path),
+            (kind, _) if kind.is_departure() => store.forget(path),
+            _ => store.observe(path),
+        }
+    });
+    if let Err(e) = result {
+        log::warn!("Failed to update tags of {}: {e}", path.display());
+    }
+}
+
+/// Пометить файл тегом.
+///
+/// Теги привязаны к содержимому файла: переживают переименование и перенос
+/// и общие у копий с одинаковым содержимым.
+pub fn add_tag(path: String, tag: String) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    with_tag_store(|store| store.add(Path::new(&path), &tag, file_watcher::now_ms()))?;
+    Ok(())
+}
+
+/// Снять тег. `false` — файл не был помечен этим тегом.
+pub fn remove_tag(path: String, tag: String) -> Result<bool, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    with_tag_store(|store| store.remove(Path::new(&path), &tag))
+}
+
+/// Теги файла по алфавиту.
+pub fn list_tags(path: String) -> Result<Vec<String>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    with_tag_store(|store| store.list(Path::new(&path)))
+}
+
+/// Известные ядру файлы с тегом `tag`.
+pub fn query_by_tag(tag: String) -> Result<Vec<String>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let paths = with_tag_store(|store| store.query(&tag))?;
+    Ok(paths
+        .into_iter()
+        .map(|p| p.to_string_lossy().to_string())
+        .collect())
+}
+
+// ============================================================================
+// Preview API
+// ============================================================================
+
+/// Очередь пре-генерации превью. `None` — режим выключен.
+static PREVIEW_QUEUE: Lazy<Mutex<Option<preview::PreviewQueue>>> = Lazy::new(|| Mutex::new(None));
+
+fn preview_cache_dir() -> Result<PathBuf, LateraError> {
+    Ok(lifecycle::data_dir()?.join(preview::PREVIEW_DIR))
+}
+
+fn enqueue_preview(path: &Path) {
+    if let Some(queue) = PREVIEW_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+    {
+        queue.enqueue(path.to_path_buf());
+    }
+}
+
+fn stop_preview_queue() {
+    let queue = PREVIEW_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    if let Some(queue) = queue {
+        queue.stop();
+    }
+}
+
+/// Включить фоновую пре-генерацию превью новых файлов.
+///
+/// Каждый новый файл, как только перестанет меняться, получает текстовое
+/// превью (PDF, DOCX, текст) в кэше ядра — галерея читает его через
+/// [`get_text_preview`] без генерации на лету. Миниатюры изображений —
+/// [`set_thumbnail_pregeneration`]. По умолчанию выключено.
+pub fn set_preview_pregeneration(enabled: bool) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    if !enabled {
+        stop_preview_queue();
+        return Ok(());
+    }
+    let mut guard = PREVIEW_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_none() {
+        *guard = Some(preview::PreviewQueue::spawn(preview_cache_dir()?)?);
+        log::info!("Preview pre-generation enabled");
+    }
+    Ok(())
+}
+
+/// Включена ли пре-генерация превью.
+pub fn is_preview_pregeneration_enabled() -> bool {
+    PREVIEW_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .is_some()
+}
+
+/// Текстовое превью файла из кэша. `None` — превью ещё не готово или
+/// формат не поддерживается.
+pub fn get_text_preview(path: String) -> Result<Option<String>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    preview::cached_text_preview(&preview_cache_dir()?, Path::new(&path))
+}
+
+// ============================================================================
+// Thumbnails API
+// ============================================================================
+
+/// Миниатюра изображения в кэше ядра.
+#[derive(Clone, Debug)]
+pub struct ApiThumbnail {
+    /// Путь к файлу миниатюры (можно показать через `Image.file`).
+    pub cache_path: String,
+    /// `image/png` или `image/jpeg`.
+    pub mime_type: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Очередь пре-генерации миниатюр. `None` — режим выключен.
+static THUMBNAIL_QUEUE: Lazy<Mutex<Option<thumbnails::ThumbnailQueue>>> =
+    Lazy::new(|| Mutex::new(None));
+
+fn enqueue_thumbnail(path: &Path) {
+    if let Some(queue) = THUMBNAIL_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+    {
+        queue.enqueue(path.to_path_buf());
+    }
+}
+
+fn stop_thumbnail_queue() {
+    let queue = THUMBNAIL_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    if let Some(queue) = queue {
+        queue.stop();
+    }
+}
+
+fn thumbnail(path: &str, max_edge: Option<u32>) -> Result<thumbnails::Thumbnail, LateraError> {
+    thumbnails::get_or_create(
+        &thumbnails::default_cache_dir()?,
+        Path::new(path),
+        max_edge.unwrap_or(thumbnails::DEFAULT_MAX_EDGE),
+    )
+}
+
+/// Включить фоновую генерацию миниатюр новых изображений (PNG, JPEG).
+///
+/// Миниатюры размера по умолчанию (256 px по длинной стороне) кладутся в
+/// кэш приложения, и [`get_thumbnail`] отвечает без декодирования
+/// оригинала. По умолчанию выключено.
+pub fn set_thumbnail_pregeneration(enabled: bool) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    if !enabled {
+        stop_thumbnail_queue();
+        return Ok(());
+    }
+    let mut guard = THUMBNAIL_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_none() {
+        *guard = Some(thumbnails::ThumbnailQueue::spawn(
+            thumbnails::default_cache_dir()?,
+            thumbnails::DEFAULT_MAX_EDGE,
+        )?);
+        log::info!("Thumbnail pre-generation enabled");
+    }
+    Ok(())
+}
+
+/// Включена ли генерация миниатюр.
+pub fn is_thumbnail_pregeneration_enabled() -> bool {
+    THUMBNAIL_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .is_some()
+}
+
+/// Миниатюра изображения (PNG, JPEG) с длинной стороной не больше
+/// `max_edge` (`None` — 256, максимум 2048): из кэша или построенная сейчас.
+///
+/// Построение декодирует оригинал — вызывать в background isolate.
+pub fn get_thumbnail(path: String, max_edge: Option<u32>) -> Result<ApiThumbnail, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let thumbnail = thumbnail(&path, max_edge)?;
+    Ok(ApiThumbnail {
+        cache_path: thumbnail.path.to_string_lossy().to_string(),
+        mime_type: thumbnail.format.mime_type().to_string(),
+        width: thumbnail.width,
+        height: thumbnail.height,
+    })
+}
+
+/// Байты миниатюры (как [`get_thumbnail`]) — для `Image.memory`, когда
+/// у приложения нет доступа к кэшу ядра.
+pub fn get_thumbnail_bytes(path: String, max_edge: Option<u32>) -> Result<Vec<u8>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    Ok(std::fs::read(thumbnail(&path, max_edge)?.path)?)
+}
+
+/// Буферы миниатюр, выданные Dart: одна миниатюра — одна копия в памяти.
+static THUMBNAIL_BUFFERS: Lazy<Mutex<shared_bytes::SharedBytesCache>> =
+    Lazy::new(|| Mutex::new(shared_bytes::SharedBytesCache::default()));
+
+/// Изображение в памяти ядра (FRB opaque type).
+///
+/// Через FFI передаётся только handle; буфер освобождается финализатором
+/// Dart (или `dispose`) вместе с последним handle'ом. Байты копируются в
+/// Dart только по запросу ([`ImageBuffer::to_bytes`]), метаданные — без
+/// копирования изображения.
+#[frb(opaque)]
+#[derive(Clone, Debug)]
+pub struct ImageBuffer {
+    bytes: shared_bytes::SharedBytes,
+    mime_type: String,
+    width: u32,
+    height: u32,
+}
+
+impl ImageBuffer {
+    /// Длина в байтах.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// `image/png` или `image/jpeg`.
+    pub fn mime_type(&self) -> String {
+        self.mime_type.clone()
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Копия байтов — когда нужен собственный `Uint8List`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.bytes.as_slice().to_vec()
+    }
+}
+
+/// Миниатюра (как [`get_thumbnail_bytes`]) без копирования через FFI.
+///
+/// Повторный запрос той же миниатюры, пока прежний [`ImageBuffer`] жив,
+/// возвращает тот же буфер.
+pub fn get_thumbnail_buffer(
+    path: String,
+    max_edge: Option<u32>,
+) -> Result<ImageBuffer, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let thumbnail = thumbnail(&path, max_edge)?;
+    let bytes = THUMBNAIL_BUFFERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .get_or_read(&thumbnail.path)?;
+    Ok(ImageBuffer {
+        bytes,
+        mime_type: thumbnail.format.mime_type().to_string(),
+        width: thumbnail.width,
+        height: thumbnail.height,
+    })
+}
+
+// ============================================================================
+// Hashing API
+// ============================================================================
+
+/// Алгоритм хэширования содержимого.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    /// Быстрый; по умолчанию.
+    Blake3,
+    /// Для сверки с внешними системами.
+    Sha256,
+}
+
+impl From<HashAlgorithm> for hashing::HashAlgorithm {
+    fn from(algorithm: HashAlgorithm) -> Self {
+        match algorithm {
+            HashAlgorithm::Blake3 => Self::Blake3,
+            HashAlgorithm::Sha256 => Self::Sha256,
+        }
+    }
+}
+
+impl From<hashing::HashAlgorithm> for HashAlgorithm {
+    fn from(algorithm: hashing::HashAlgorithm) -> Self {
+        match algorithm {
+            hashing::HashAlgorithm::Blake3 => Self::Blake3,
+            hashing::HashAlgorithm::Sha256 => Self::Sha256,
+        }
+    }
+}
+
+/// Хэш содержимого файла, посчитанный в фоне после события watcher'а.
+#[derive(Clone, Debug)]
+pub struct FileHashEvent {
+    pub watcher_id: String,
+    pub full_path: String,
+    pub algorithm: HashAlgorithm,
+    /// Hex в нижнем регистре.
+    pub hash: String,
+}
+
+/// Очередь хэширования файлов из событий. `None` — выключено.
+static HASH_QUEUE: Lazy<Mutex<Option<hashing::HashQueue>>> = Lazy::new(|| Mutex::new(None));
+
+static FILE_HASH_SINK: Lazy<Mutex<Option<frb_generated::StreamSink<FileHashEvent>>>> =
+    Lazy::new(|| Mutex::new(None));
+
+fn enqueue_hash(watcher_id: &str, path: &Path) {
+    if UNHASHED_WATCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .contains(watcher_id)
+    {
+        return;
+    }
+    if let Some(queue) = HASH_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+    {
+        queue.enqueue(hashing::HashJob {
+            watcher_id: watcher_id.to_string(),
+            path: path.to_path_buf(),
+        });
+    }
+}
+
+fn stop_hash_queue() {
+    let queue = HASH_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    if let Some(queue) = queue {
+        queue.stop();
+    }
+}
+
+fn emit_file_hash(algorithm: hashing::HashAlgorithm, job: hashing::HashJob, hash: String) {
+    if let Some(sink) = FILE_HASH_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+    {
+        let result = sink.add(FileHashEvent {
+            watcher_id: job.watcher_id,
+            full_path: job.path.to_string_lossy().to_string(),
+            algorithm: algorithm.into(),
+            hash,
+        });
+        if let Err(e) = result {
+            log::warn!("Failed to emit file hash (stream closed): {e}");
+        }
+    }
+}
+
+/// Хэш содержимого файла (hex, нижний регистр).
+///
+/// Читает файл целиком — для больших файлов вызывать в background isolate.
+pub fn compute_hash(path: String, algorithm: HashAlgorithm) -> Result<String, LateraError> {
+    hashing::compute_hash(Path::new(&path), algorithm.into())
+}
+
+/// Считать хэши появившихся и изменённых файлов в фоне.
+///
+/// Хэш приходит в [`on_file_hash`] отдельно от события файла, когда файл
+/// перестанет меняться. `None` — выключить (по умолчанию выключено).
+pub fn set_event_hashing(algorithm: Option<HashAlgorithm>) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let mut guard = HASH_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let algorithm = algorithm.map(hashing::HashAlgorithm::from);
+    if guard.as_ref().map(hashing::HashQueue::algorithm) == algorithm {
+        return Ok(());
+    }
+    if let Some(queue) = guard.take() {
+        queue.stop();
+    }
+    if let Some(algorithm) = algorithm {
+        *guard = Some(hashing::HashQueue::spawn(algorithm, move |job, hash| {
+            emit_file_hash(algorithm, job, hash);
+        })?);
+        log::info!("Event hashing enabled: {algorithm:?}");
+    }
+    Ok(())
+}
+
+/// Stream хэшей файлов (см. [`set_event_hashing`]).
+pub fn on_file_hash(sink: frb_generated::StreamSink<FileHashEvent>) {
+    let mut guard = FILE_HASH_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_some() {
+        warn!("on_file_hash called while previous stream is still bound; replacing sink");
+    }
+    *guard = Some(sink);
+}
+
+// ============================================================================
+// Duplicates API
+// ============================================================================
+
+/// Группа файлов с одинаковым содержимым.
+#[derive(Clone, Debug)]
+pub struct DuplicateGroup {
+    /// Размер одного файла.
+    pub size_bytes: u64,
+    /// BLAKE3 содержимого (hex).
+    pub hash: String,
+    pub paths: Vec<String>,
+}
+
+/// Появившийся файл совпадает по содержимому с уже лежащим в папке.
+///
+/// UI может предложить «оставить оба», «заменить» (старый — в корзину через
+/// [`delete_to_trash`], новый — на его место через [`move_file`]) или
+/// «пропустить» (новый — в корзину).
+#[derive(Clone, Debug)]
+pub struct DuplicateDetected {
+    pub watcher_id: String,
+    pub new_path: String,
+    pub existing_path: String,
+}
+
+/// Очередь проверки дубликатов. `None` — режим выключен.
+static DUPLICATE_QUEUE: Lazy<Mutex<Option<duplicates::DuplicateQueue>>> =
+    Lazy::new(|| Mutex::new(None));
+
+static DUPLICATE_SINK: Lazy<Mutex<Option<frb_generated::StreamSink<DuplicateDetected>>>> =
+    Lazy::new(|| Mutex::new(None));
+
+fn enqueue_duplicate_job(job: duplicates::DuplicateJob) {
+    if let Some(queue) = DUPLICATE_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+    {
+        queue.enqueue(job);
+    }
+}
+
+/// Задание проверки дубликатов по событию watcher'а.
+fn enqueue_duplicate_check(watcher_id: &str, event: &file_watcher::InternalFileEvent) {
+    use duplicates::DuplicateJob;
+
+    let watcher_id = watcher_id.to_string();
+    let path = event.full_path.clone();
+    match (event.kind, &event.previous_path) {
+        // Переименование внутри папки — не новый файл
+        (file_watcher::FileEventKind::RenamedTo, Some(from)) => {
+            enqueue_duplicate_job(DuplicateJob::Removed {
+                watcher_id: watcher_id.clone(),
+                path: from.clone(),
+            });
+            enqueue_duplicate_job(DuplicateJob::Changed { watcher_id, path });
+        }
+        (kind, _) if kind.is_arrival() => {
+            enqueue_duplicate_job(DuplicateJob::Added { watcher_id, path });
+        }
+        (kind, _) if kind.is_departure() => {
+            enqueue_duplicate_job(DuplicateJob::Removed { watcher_id, path });
+        }
+        _ => enqueue_duplicate_job(DuplicateJob::Changed { watcher_id, path }),
+    }
+}
+
+fn stop_duplicate_queue() {
+    let queue = DUPLICATE_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    if let Some(queue) = queue {
+        queue.stop();
+    }
+}
+
+fn emit_duplicate_detected(found: duplicates::DuplicateFound) {
+    log::info!(
+        "Duplicate detected: {} == {}",
+        found.new_path.display(),
+        found.existing_path.display()
+    );
+    if let Some(sink) = DUPLICATE_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+    {
+        let result = sink.add(DuplicateDetected {
+            watcher_id: found.watcher_id,
+            new_path: found.new_path.to_string_lossy().to_string(),
+            existing_path: found.existing_path.to_string_lossy().to_string(),
+        });
+        if let Err(e) = result {
+            log::warn!("Failed to emit duplicate (stream closed): {e}");
+        }
+    }
+}
+
+/// Группы дубликатов в папке `dir` (рекурсивно), от занимающих больше
+/// всего лишнего места. Пустые файлы не учитываются.
+///
+/// Хэшируются только файлы с совпавшим размером, но для большой папки
+/// вызывать в background isolate.
+pub fn find_duplicates(dir: String) -> Result<Vec<DuplicateGroup>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    Ok(duplicates::find_duplicates(Path::new(&dir), true)?
+        .into_iter()
+        .map(|group| DuplicateGroup {
+            size_bytes: group.size,
+            hash: group.hash,
+            paths: group
+                .paths
+                .iter()
+                .map(|p| p.to_string_lossy().to_string())
+                .collect(),
+        })
+        .collect())
+}
+
+/// Проверять появившиеся в наблюдаемых папках файлы на дубликаты.
+///
+/// Совпадения (тот же размер и BLAKE3) приходят в [`on_duplicate_detected`],
+/// когда файл перестанет меняться. По умолчанию выключено.
+pub fn set_duplicate_detection(enabled: bool) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    if !enabled {
+        stop_duplicate_queue();
+        return Ok(());
+    }
+    let mut guard = DUPLICATE_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_some() {
+        return Ok(());
+    }
+    let queue = duplicates::DuplicateQueue::spawn(emit_duplicate_detected)?;
+    for (watcher_id, watcher) in WATCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .iter()
+    {
+        queue.enqueue(duplicates::DuplicateJob::Scan {
+            watcher_id: watcher_id.clone(),
+            dir: watcher.handle.watch_dir().to_path_buf(),
+            recursive: watcher.recursive,
+        });
+    }
+    *guard = Some(queue);
+    log::info!("Duplicate detection enabled");
+    Ok(())
+}
+
+/// Включена ли проверка дубликатов.
+pub fn is_duplicate_detection_enabled() -> bool {
+    DUPLICATE_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .is_some()
+}
+
+/// Stream найденных дубликатов (см. [`set_duplicate_detection`]).
+pub fn on_duplicate_detected(sink: frb_generated::StreamSink<DuplicateDetected>) {
+    let mut guard = DUPLICATE_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_some() {
+        warn!("on_duplicate_detected called while previous stream is still bound; replacing sink");
+    }
+    *guard = Some(sink);
+}
+
+// ============================================================================
+// File operations API
+// ============================================================================
+
+/// Вид файловой операции.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileOpKind {
+    Move,
+    Copy,
+    Rename,
+}
+
+impl From<file_ops::FileOpKind> for FileOpKind {
+    fn from(kind: file_ops::FileOpKind) -> Self {
+        match kind {
+            file_ops::FileOpKind::Move => Self::Move,
+            file_ops::FileOpKind::Copy => Self::Copy,
+            file_ops::FileOpKind::Rename => Self::Rename,
+        }
+    }
+}
+
+/// Состояние файловой операции.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileOpStatus {
+    Running,
+    Completed,
+    Failed,
+}
+
+/// Ход операции из [`move_file`], [`copy_file`] или [`rename_file`].
+#[derive(Clone, Debug)]
+pub struct FileOpEvent {
+    /// Идентификатор, который вернула функция запуска.
+    pub operation_id: u64,
+    pub kind: FileOpKind,
+    pub src: String,
+    pub dst: String,
+    pub status: FileOpStatus,
+    /// Для переименования и переноса в пределах тома остаются 0.
+    pub bytes_done: u64,
+    pub bytes_total: u64,
+    /// Код [`LateraError`] при `Failed`.
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+static NEXT_FILE_OP_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Пул файловых операций; создаётся при первой операции.
+static FILE_OPS_POOL: Lazy<Mutex<Option<file_ops::FileOpsPool>>> = Lazy::new(|| Mutex::new(None));
+
+static FILE_OP_SINK: Lazy<Mutex<Option<frb_generated::StreamSink<FileOpEvent>>>> =
+    Lazy::new(|| Mutex::new(None));
+
+fn submit_file_op(
+    kind: file_ops::FileOpKind,
+    src: PathBuf,
+    dst: PathBuf,
+    overwrite: bool,
+) -> Result<u64, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let op = file_ops::FileOp {
+        id: NEXT_FILE_OP_ID.fetch_add(1, Ordering::Relaxed),
+        kind,
+        src,
+        dst,
+        overwrite,
+    };
+    op.validate()?;
+    read_only::ensure_writable("file operations")?;
+
+    let mut guard = FILE_OPS_POOL
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_none() {
+        let workers = std::thread::available_parallelism()
+            .map_or(1, std::num::NonZeroUsize::get)
+            .min(file_ops::MAX_WORKERS);
+        *guard = Some(file_ops::FileOpsPool::spawn(workers, emit_file_op)?);
+    }
+    let id = op.id;
+    if let Some(pool) = guard.as_ref() {
+        pool.submit(op);
+    }
+    Ok(id)
+}
+
+fn stop_file_ops_pool() {
+    let pool = FILE_OPS_POOL
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    if let Some(pool) = pool {
+        pool.stop();
+    }
+    let _dropped = FILE_OP_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+}
+
+fn emit_file_op(op: &file_ops::FileOp, update: file_ops::FileOpUpdate) {
+    if let Some(sink) = FILE_OP_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+    {
+        let mut event = FileOpEvent {
+            operation_id: op.id,
+            kind: op.kind.into(),
+            src: op.src.to_string_lossy().to_string(),
+            dst: op.dst.to_string_lossy().to_string(),
+            status: FileOpStatus::Running,
+            bytes_done: 0,
+            bytes_total: 0,
+            error_code: None,
+            error_message: None,
+        };
+        match update {
+            file_ops::FileOpUpdate::Progress {
+                bytes_done,
+                bytes_total,
+            } => {
+                event.bytes_done = bytes_done;
+                event.bytes_total = bytes_total;
+            }
+            file_ops::FileOpUpdate::Completed => event.status = FileOpStatus::Completed,
+            file_ops::FileOpUpdate::Failed(error) => {
+                event.status = FileOpStatus::Failed;
+                event.error_code = Some(error.code().to_string());
+                event.error_message = Some(error.to_string());
+            }
+        }
+        if let Err(e) = sink.add(event) {
+            log::warn!("Failed to emit file operation progress (stream closed): {e}");
+        }
+    }
+}
+
+/// Перенести файл `src` в `dst` (полный путь) в фоне.
+///
+/// Проверки (пути абсолютные, файл есть, `dst` свободен) выполняются сразу;
+/// ход и результат приходят в [`on_file_op_progress`]. Между томами файл
+/// копируется и исходник удаляется. Возвращает идентификатор операции.
+pub fn move_file(src: String, dst: String) -> Result<u64, LateraError> {
+    submit_file_op(
+        file_ops::FileOpKind::Move,
+        PathBuf::from(src),
+        PathBuf::from(dst),
+        false,
+    )
+}
+
+/// Скопировать файл `src` в `dst` (полный путь) в фоне.
+///
+/// `overwrite` — заменить существующий `dst`. Копия появляется под
+/// настоящим именем только целиком. Возвращает идентификатор операции.
+pub fn copy_file(src: String, dst: String, overwrite: bool) -> Result<u64, LateraError> {
+    submit_file_op(
+        file_ops::FileOpKind::Copy,
+        PathBuf::from(src),
+        PathBuf::from(dst),
+        overwrite,
+    )
+}
+
+/// Переименовать файл в пределах его папки (`new_name` — только имя).
+///
+/// Возвращает идентификатор операции; результат — в [`on_file_op_progress`].
+pub fn rename_file(path: String, new_name: String) -> Result<u64, LateraError> {
+    let path = PathBuf::from(path);
+    let dst = file_ops::renamed_path(&path, &new_name)?;
+    submit_file_op(file_ops::FileOpKind::Rename, path, dst, false)
+}
+
+/// Перенести файл или папку в корзину системы (не удаляя безвозвратно).
+///
+/// Возвращает путь в корзине, если платформа его сообщает (macOS, Linux);
+/// на Windows — `None`. Записывается в журнал аудита.
+pub fn delete_to_trash(path: String) -> Result<Option<String>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let trashed = trash::delete_to_trash(Path::new(&path))?;
+    Ok(trashed.map(|location| location.to_string_lossy().to_string()))
+}
+
+/// Stream хода файловых операций.
+///
+/// В Dart это будет выглядеть как `Stream<FileOpEvent> onFileOpProgress()`.
+/// Один активный подписчик; stream закрывается при [`shutdown_core`].
+pub fn on_file_op_progress(sink: frb_generated::StreamSink<FileOpEvent>) {
+    let mut guard = FILE_OP_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_some() {
+        warn!("on_file_op_progress called while previous stream is still bound; closing previous stream");
+    }
+    *guard = Some(sink);
+}
+
+// ============================================================================
+// QR / barcode API
+// ============================================================================
+
+/// Формат найденного кода.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ApiCodeFormat {
+    QrCode,
+    /// EAN-13; UPC-A приходит как EAN-13 с ведущим нулём.
+    Ean13,
+    Ean8,
+}
+
+impl From<codes::CodeFormat> for ApiCodeFormat {
+    fn from(format: codes::CodeFormat) -> Self {
+        match format {
+            codes::CodeFormat::QrCode => Self::QrCode,
+            codes::CodeFormat::Ean13 => Self::Ean13,
+            codes::CodeFormat::Ean8 => Self::Ean8,
+        }
+    }
+}
+
+/// QR-код или штрихкод, найденный на изображении.
+#[derive(Clone, Debug)]
+pub struct ApiDetectedCode {
+    pub format: ApiCodeFormat,
+    /// Текст QR или цифры штрихкода.
+    pub text: String,
+}
+
+impl From<codes::DetectedCode> for ApiDetectedCode {
+    fn from(code: codes::DetectedCode) -> Self {
+        Self {
+            format: code.format.into(),
+            text: code.text,
+        }
+    }
+}
+
+/// Очередь поиска кодов в новых изображениях. `None` — выключено.
+static CODE_SCAN_QUEUE: Lazy<Mutex<Option<codes::CodeScanQueue>>> = Lazy::new(|| Mutex::new(None));
+
+fn enqueue_code_scan(watcher_id: &str, path: &Path) {
+    if let Some(queue) = CODE_SCAN_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+    {
+        queue.enqueue(codes::CodeScanJob {
+            watcher_id: watcher_id.to_string(),
+            path: path.to_path_buf(),
+        });
+    }
+}
+
+fn stop_code_scan_queue() {
+    let queue = CODE_SCAN_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    if let Some(queue) = queue {
+        queue.stop();
+    }
+}
+
+/// Коды изображения — событием `CodesDetected` в stream watcher'а файла.
+fn emit_codes_detected(job: codes::CodeScanJob, detected: Vec<codes::DetectedCode>) {
+    let timestamp_source = WATCHER_OPTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .timestamp_source;
+    let event = match file_watcher::make_internal_file_event(
+        &job.path,
+        timestamp_source,
+        file_watcher::FileEventKind::Modified,
+    ) {
+        Ok(event) => event,
+        Err(e) => {
+            log::debug!("Detected codes dropped, file is gone: {e}");
+            return;
+        }
+    };
+    send_file_event(
+        &job.watcher_id,
+        &event,
+        FileEventKind::CodesDetected,
+        detected.into_iter().map(ApiDetectedCode::from).collect(),
+    );
+}
+
+/// Найти QR-коды и штрихкоды (EAN-13, UPC-A, EAN-8) в изображении PNG/JPEG.
+///
+/// Декодирует изображение целиком — вызывать в background isolate.
+pub fn scan_image_codes(path: String) -> Result<Vec<ApiDetectedCode>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let path = Path::new(&path);
+    if !codes::is_scannable(path) {
+        return Err(LateraError::InvalidArgument(format!(
+            "unsupported image format: {}",
+            path.display()
+        )));
+    }
+    Ok(codes::scan_file(path)?
+        .into_iter()
+        .map(ApiDetectedCode::from)
+        .collect())
+}
+
+/// Искать коды в появившихся изображениях в фоне (сценарий «QR со счёта»).
+///
+/// Найденные коды приходят в [`on_file_event`] событием `CodesDetected`
+/// того же watcher'а вслед за появлением файла; изображения без кодов
+/// событий не дают. По умолчанию выключено.
+pub fn set_code_scanning(enabled: bool) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let mut guard = CODE_SCAN_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_some() == enabled {
+        return Ok(());
+    }
+    if let Some(queue) = guard.take() {
+        queue.stop();
+        log::info!("Code scanning disabled");
+    }
+    if enabled {
+        *guard = Some(codes::CodeScanQueue::spawn(emit_codes_detected)?);
+        log::info!("Code scanning enabled");
+    }
+    Ok(())
+}
+
+pub fn is_code_scanning_enabled() -> bool {
+    CODE_SCAN_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .is_some()
+}
+
+// ============================================================================
+// File metadata API
+// ============================================================================
+
+/// Владелец файла и права текущего пользователя.
+#[derive(Clone, Debug)]
+pub struct ApiFileAccessInfo {
+    /// Имя владельца (`user` на Unix, `DOMAIN\user` на Windows).
+    pub owner_name: Option<String>,
+    /// Идентификатор владельца: uid на Unix, SID на Windows.
+    pub owner_id: Option<String>,
+    pub readable: bool,
+    pub writable: bool,
+    pub executable: bool,
+}
+
+impl From<file_metadata::FileAccessInfo> for ApiFileAccessInfo {
+    fn from(info: file_metadata::FileAccessInfo) -> Self {
+        Self {
+            owner_name: info.owner_name,
+            owner_id: info.owner_id,
+            readable: info.readable,
+            writable: info.writable,
+            executable: info.executable,
+        }
+    }
+}
+
+/// Получить владельца файла и права текущего пользователя.
+///
+/// Позволяет UI заранее объяснить, почему действие с файлом не удастся.
+/// То же приходит готовым в `access` событий [`FileEvent`] и
+/// [`FileAddedEvent`].
+pub fn get_file_access_info(path: String) -> Result<ApiFileAccessInfo, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    Ok(file_metadata::get_file_access_info(Path::new(&path))?.into())
+}
+
+/// Держит ли файл открытым другой процесс (например, Excel).
+///
+/// Windows: файл не открывается без общего доступа; Unix: на файле есть
+/// рекомендательная блокировка (`fcntl` или эксклюзивная `flock`) —
+/// открытый без блокировки файл занятым не считается. Вызывать перед
+/// переносом или удалением файла.
+pub fn is_file_locked(path: String) -> Result<bool, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    file_lock::is_locked(Path::new(&path))
+}
+
+/// Ждёт, пока файл освободится ([`is_file_locked`]), не дольше
+/// `timeout_ms`. `true` — свободен, `false` — всё ещё занят.
+///
+/// Блокирует поток — вызывать в background isolate.
+pub fn wait_until_unlocked(path: String, timeout_ms: u32) -> Result<bool, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    file_lock::wait_until_unlocked(
+        Path::new(&path),
+        std::time::Duration::from_millis(u64::from(timeout_ms)),
+        file_lock::POLL_INTERVAL,
+    )
+}
+
+/// Расширенный атрибут файла.
+#[derive(Clone, Debug)]
+pub struct ApiXattr {
+    pub name: String,
+    pub value: Vec<u8>,
+}
+
+/// Прочитать расширенные атрибуты файла.
+///
+/// Linux: только пространство `user.` (префикс снимается);
+/// Windows: атрибуты, записанные через [`set_xattr`] (NTFS alternate data stream).
+pub fn get_xattrs(path: String) -> Result<Vec<ApiXattr>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    Ok(xattr::get_xattrs(Path::new(&path))?
+        .into_iter()
+        .map(|a| ApiXattr {
+            name: a.name,
+            value: a.value,
+        })
+        .collect())
+}
+
+/// Записать расширенный атрибут файла (например, `latera.processed`).
+///
+/// В режиме «только наблюдение» возвращает `LateraError::ReadOnlyMode`.
+pub fn set_xattr(path: String, name: String, value: Vec<u8>) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+    read_only::ensure_writable("set_xattr")?;
+    xattr::set_xattr(Path::new(&path), &name, &value)
+}
+
+// ============================================================================
+// Folder composition API
+// ============================================================================
+
+/// Категория файлов в составе папки.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileCategory {
+    Images,
+    Documents,
+    Archives,
+    Other,
+}
+
+impl From<composition::FileCategory> for FileCategory {
+    fn from(category: composition::FileCategory) -> Self {
+        match category {
+            composition::FileCategory::Image => Self::Images,
+            composition::FileCategory::Document => Self::Documents,
+            composition::FileCategory::Archive => Self::Archives,
+            composition::FileCategory::Other => Self::Other,
+        }
+    }
+}
+
+/// Файлы одной категории в папке.
+#[derive(Clone, Debug)]
+pub struct CategoryStats {
+    pub category: FileCategory,
+    pub file_count: u64,
+    pub total_bytes: u64,
+}
+
+/// Индексы состава папок, уже запрошенных через [`get_folder_composition`].
+static FOLDER_COMPOSITION: Lazy<Mutex<Vec<composition::CompositionIndex>>> =
+    Lazy::new(|| Mutex::new(Vec::new()));
+
+fn update_folder_composition(event: &file_watcher::InternalFileEvent) {
+    for index in FOLDER_COMPOSITION
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .iter_mut()
+    {
+        index.apply(event);
+    }
+}
+
+fn close_folder_composition() {
+    FOLDER_COMPOSITION
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clear();
+}
+
+/// Строит индексы папок `roots`, которых ещё нет, затем вызывает `f` с
+/// индексами `roots` (в том же порядке).
+fn with_folder_indexes<T>(
+    roots: &[PathBuf],
+    f: impl FnOnce(&[&composition::CompositionIndex]) -> T,
+) -> Result<T, LateraError> {
+    let missing: Vec<_> = {
+        let indexes = FOLDER_COMPOSITION
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        roots
+            .iter()
+            .filter(|root| !indexes.iter().any(|index| index.root() == root.as_path()))
+            .cloned()
+            .collect()
+    };
+    // Обход без блокировки: события других папок не ждут
+    let scanned = missing
+        .iter()
+        .map(|root| composition::CompositionIndex::scan(root))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut indexes = FOLDER_COMPOSITION
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    for index in scanned {
+        if !indexes
+            .iter()
+            .any(|existing| existing.root() == index.root())
+        {
+            indexes.push(index);
+        }
+    }
+    let selected: Vec<_> = roots
+        .iter()
+        .filter_map(|root| indexes.iter().find(|index| index.root() == root.as_path()))
+        .collect();
+    Ok(f(&selected))
+}
+
+fn absolute_folder(path: String) -> Result<PathBuf, LateraError> {
+    let root = PathBuf::from(path);
+    if !root.is_absolute() {
+        return Err(LateraError::InvalidPath(format!(
+            "path must be absolute: {}",
+            root.display()
+        )));
+    }
+    Ok(root)
+}
+
+/// Число и суммарный размер файлов папки (рекурсивно) по типам: картинки,
+/// документы, архивы, остальное. Тип определяется по содержимому.
+///
+/// Первый вызов для папки обходит её целиком — вызывать в background
+/// isolate. Дальше ядро обновляет итоги по событиям watcher'а, и повторный
+/// вызов отвечает сразу.
+pub fn get_folder_composition(path: String) -> Result<Vec<CategoryStats>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let root = absolute_folder(path)?;
+    with_folder_indexes(&[root], |indexes| {
+        indexes
+            .iter()
+            .flat_map(|index| index.composition())
+            .map(|(category, totals)| CategoryStats {
+                category: category.into(),
+                file_count: totals.file_count,
+                total_bytes: totals.total_bytes,
+            })
+            .collect()
+    })
+}
+
+/// Файл в отчётах для очистки.
+#[derive(Clone, Debug)]
+pub struct ReportFile {
+    pub full_path: String,
+    pub size_bytes: u64,
+    /// Unix timestamp в миллисекундах, если ФС его сообщает.
+    pub modified_at_ms: Option<i64>,
+    pub category: FileCategory,
+}
+
+fn report_file(path: &Path, file: composition::IndexedFile) -> ReportFile {
+    ReportFile {
+        full_path: path.to_string_lossy().to_string(),
+        size_bytes: file.size,
+        modified_at_ms: file.modified_at_ms,
+        category: file.category.into(),
+    }
+}
+
+/// Папки запущенных watcher'ов (для отчётов).
+fn watched_folders() -> Vec<PathBuf> {
+    let mut folders: Vec<PathBuf> = WATCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .values()
+        .map(|watcher| watcher.handle.watch_dir().to_path_buf())
+        .collect();
+    folders.sort();
+    folders.dedup();
+    folders
+}
+
+/// Не больше `limit` самых больших файлов в папках запущенных watcher'ов,
+/// по убыванию размера.
+///
+/// Строится по индексу файлов (см. [`get_folder_composition`]): обход папки
+/// нужен только при первом запросе.
+pub fn get_largest_files(limit: u32) -> Result<Vec<ReportFile>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let limit = limit as usize;
+    with_folder_indexes(&watched_folders(), |indexes| {
+        let mut files: BTreeMap<PathBuf, composition::IndexedFile> = BTreeMap::new();
+        for index in indexes {
+            files.extend(index.largest(limit));
+        }
+        let mut files: Vec<_> = files.into_iter().collect();
+        files.sort_by(|a, b| b.1.size.cmp(&a.1.size).then_with(|| a.0.cmp(&b.0)));
+        files
+            .iter()
+            .take(limit)
+            .map(|(path, file)| report_file(path, *file))
+            .collect()
+    })
+}
+
+/// Файлы в папках запущенных watcher'ов, не менявшиеся дольше
+/// `older_than_days` дней, от самых старых.
+///
+/// Строится по индексу файлов, как [`get_largest_files`].
+pub fn get_stale_files(older_than_days: u32) -> Result<Vec<ReportFile>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let cutoff_ms = file_watcher::now_ms() - i64::from(older_than_days) * 24 * 60 * 60 * 1000;
+    with_folder_indexes(&watched_folders(), |indexes| {
+        let mut files: BTreeMap<PathBuf, composition::IndexedFile> = BTreeMap::new();
+        for index in indexes {
+            files.extend(index.stale(cutoff_ms));
+        }
+        let mut files: Vec<_> = files.into_iter().collect();
+        files.sort_by(|a, b| {
+            a.1.modified_at_ms
+                .cmp(&b.1.modified_at_ms)
+                .then_with(|| a.0.cmp(&b.0))
+        });
+        files
+            .iter()
+            .map(|(path, file)| report_file(path, *file))
+            .collect()
+    })
+}
+
+// ============================================================================
+// Index API
+// ============================================================================
+
+/// Глобальное соединение с БД индекса.
+///
+/// Инициализируется единожды при вызове [`init_index`].
+/// Защищён мьютексом для потокобезопасного доступа.
+static INDEX_DB: Lazy<Mutex<Option<Connection>>> = Lazy::new(|| Mutex::new(None));
+
+/// Получить ссылку на подключение к БД.
+/// Если БД не инициализирована — возвращает ошибку.
+pub(crate) fn with_index_db<F, T>(f: F) -> Result<T, LateraError>
+where
+    F: FnOnce(&Connection) -> Result<T, LateraError>,
+{
+    let guard = INDEX_DB
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let conn = guard.as_ref().ok_or(LateraError::IndexNotInitialized)?;
+    f(conn)
+}
+
+/// Закрыть индексную БД.
+///
+/// Перед закрытием переносит WAL в основной файл, чтобы на диске не
+/// оставалось незафиксированного журнала. Если БД не открыта — no-op.
+fn close_index_db() -> Result<(), LateraError> {
+    let conn = INDEX_DB
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    let Some(conn) = conn else {
+        return Ok(());
+    };
+
+    conn.execute_batch("PRAGMA wal_checkpoint(TRUNCATE);")?;
+    conn.close().map_err(|(_, e)| LateraError::Sqlite(e))?;
+    log::info!("Index DB closed");
+    Ok(())
+}
+
+/// Инициализировать индексную БД.
+///
+/// Вызывается один раз при старте приложения.
+/// `db_path` — путь к файлу SQLite (будет создан вместе с директорией).
+///
+/// Безопасен для повторного вызова — если БД уже открыта, вернёт Ok.
+pub fn init_index(db_path: String) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let mut guard = INDEX_DB
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    if guard.is_some() {
+        log::info!("Index DB already initialized, skipping");
+        return Ok(());
+    }
+
+    let conn = indexer::init_db(&db_path)?;
+    *guard = Some(conn);
+    if let Some(dir) = Path::new(&db_path).parent() {
+        file_watcher::register_core_dir(dir);
+    }
+    Ok(())
+}
+
+/// Результат поиска, экспортируемый через FRB в Dart.
+#[derive(Clone, Debug)]
+pub struct SearchResultItem {
+    pub file_path: String,
+    pub file_name: String,
+    pub description: String,
+    pub snippet: String,
+    pub rank: f64,
+}
+
+/// Очередь автоиндексации наблюдаемых папок. `None` — режим выключен.
+static AUTO_INDEX_QUEUE: Lazy<Mutex<Option<indexer::auto_index::AutoIndexQueue>>> =
+    Lazy::new(|| Mutex::new(None));
+
+/// Задание автоиндексации по событию watcher'а.
+fn enqueue_auto_index(event: &file_watcher::InternalFileEvent) {
+    use indexer::auto_index::IndexJob;
+
+    let guard = AUTO_INDEX_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let Some(queue) = guard.as_ref() else {
+        return;
+    };
+    let path = event.full_path.clone();
+    let job = match (event.kind, &event.previous_path) {
+        (file_watcher::FileEventKind::RenamedTo, Some(from)) => IndexJob::Rename {
+            from: from.clone(),
+            to: path,
+        },
+        (kind, _) if kind.is_departure() => IndexJob::Remove(path),
+        _ => IndexJob::Upsert(path),
+    };
+    queue.enqueue(job);
+}
+
+fn stop_auto_index_queue() {
+    let queue = AUTO_INDEX_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    if let Some(queue) = queue {
+        queue.stop();
+    }
+}
+
+/// Включить автоматическую индексацию наблюдаемых папок.
+///
+/// Новые и изменённые файлы индексируются в фоне вместе с содержимым
+/// (PDF, DOCX, текстовые форматы), переименованные — переносятся, удалённые —
+/// убираются из индекса; описание пользователя сохраняется. Требует
+/// [`init_index`]. По умолчанию выключено.
+pub fn set_auto_indexing(enabled: bool) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    if !enabled {
+        stop_auto_index_queue();
+        return Ok(());
+    }
+    with_index_db(|_| Ok(()))?;
+    let mut guard = AUTO_INDEX_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_none() {
+        *guard = Some(indexer::auto_index::AutoIndexQueue::spawn(Box::new(|f| {
+            with_index_db(|conn| f(conn))
+        }))?);
+        log::info!("Auto-indexing enabled");
+    }
+    Ok(())
+}
+
+/// Включена ли автоиндексация.
+pub fn is_auto_indexing_enabled() -> bool {
+    AUTO_INDEX_QUEUE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .is_some()
+}
+
+/// Индексировать файл с описанием пользователя.
+///
+/// Автоматически извлекает текстовое содержимое из поддерживаемых форматов
+/// (.txt, .md и др.) и добавляет его в FTS5 индекс.
+///
+/// Если файл уже есть в индексе — обновляет описание и содержимое.
+pub fn index_file_with_description(
+    file_path: String,
+    file_name: String,
+    description: String,
+) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    // Извлекаем текстовое содержимое, если файл текстовый
+    let text_content = indexer::extract_text(Path::new(&file_path));
+
+    with_index_db(|conn| {
+        indexer::index_file(
+            conn,
+            &file_path,
+            &file_name,
+            &description,
+            text_content.as_deref(),
+        )?;
+        Ok(())
+    })
+}
+
+/// Поиск файлов по содержимому, имени и описанию.
+///
+/// Использует FTS5 полнотекстовый поиск; результаты упорядочены по BM25
+/// рангу (наиболее релевантные первыми), `snippet` — фрагмент текста с
+/// совпадениями в `<b>…</b>`. Файлы наблюдаемых папок попадают в индекс
+/// сами при включённой [`set_auto_indexing`].
+pub fn search(query: String, limit: u32) -> Result<Vec<SearchResultItem>, LateraError> {
+    search_files(query, limit)
+}
+
+/// Поиск файлов по запросу (то же, что [`search`]).
+///
+/// Использует FTS5 полнотекстовый поиск по имени, описанию и содержимому.
+/// Результаты упорядочены по BM25 рангу (наиболее релевантные первыми).
+pub fn search_files(query: String, limit: u32) -> Result<Vec<SearchResultItem>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    telemetry::track(
+        "search",
+        with_index_db(|conn| {
+            let results = indexer::search(conn, &query, limit as usize)?;
+            Ok(results
+                .into_iter()
+                .map(|r| SearchResultItem {
+                    file_path: r.file_path,
+                    file_name: r.file_name,
+                    description: r.description,
+                    snippet: r.snippet,
+                    rank: r.rank,
+                })
+                .collect())
+        }),
+    )
+}
+
+/// Удалить файл из индекса.
+pub fn remove_from_index(file_path: String) -> Result<bool, LateraError> {
+    lifecycle::ensure_initialized()?;
+    with_index_db(|conn| indexer::remove_file(conn, &file_path))
+}
+
+/// Проверить, проиндексирован ли файл.
+pub fn is_file_indexed(file_path: String) -> Result<bool, LateraError> {
+    lifecycle::ensure_initialized()?;
+    with_index_db(|conn| indexer::is_indexed(conn, &file_path))
+}
+
+/// Получить количество проиндексированных файлов.
+pub fn get_indexed_file_count() -> Result<i64, LateraError> {
+    lifecycle::ensure_initialized()?;
+    with_index_db(|conn| indexer::get_indexed_count(conn))
+}
+
+/// Очистить весь индекс.
+pub fn clear_file_index() -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+    with_index_db(|conn| indexer::clear_index(conn))
+}
+
+// ============================================================================
+// Text Extraction API (Phase 1: PDF/DOCX)
+// ============================================================================
+
+/// Опции извлечения текста (FRB bridge type).
+///
+/// Передаются из Flutter-стороны для уважения пользовательских лимитов
+/// (`AppConfig.effectiveLimits`).
+#[derive(Clone, Debug)]
+pub struct ExtractionOptions {
+    /// Максимальное количество страниц PDF для обработки.
+    pub max_pages_per_pdf: u32,
+    /// Максимальный размер файла в мегабайтах.
+    pub max_file_size_mb: u32,
+}
+
+/// Результат извлечения текста (FRB bridge type).
+#[derive(Clone, Debug)]
+pub struct ExtractionResult {
+    /// Извлечённый текст (может быть пуст при ошибке).
+    pub text: String,
+    /// Тип контента: `"pdf"`, `"docx"`, `"text"`, `"unsupported"`, `"unknown"`.
+    pub content_type: String,
+    /// Количество обработанных страниц (для PDF; для остальных — 0).
+    pub pages_extracted: u32,
+    /// Код ошибки (None = успех).
+    ///
+    /// Возможные значения:
+    /// - `"file_too_large"` — файл превышает лимит
+    /// - `"too_many_pages"` — PDF превышает лимит страниц (текст до лимита извлечён)
+    /// - `"unsupported_format"` — формат не поддерживается
+    /// - `"extraction_failed"` — внутренняя ошибка
+    /// - `"file_not_found"` — файл не найден
+    pub error_code: Option<String>,
+}
+
+/// Извлечь текст из файла (PDF text layer, DOCX, plain-text).
+///
+/// Использует Rust-side extraction с уважением лимитов из `options`.
+///
+/// В Dart: `ExtractionResult extractTextFromFile(String path, ExtractionOptions options)`.
+pub fn extract_text_from_file(path: String, options: ExtractionOptions) -> ExtractionResult {
+    telemetry::record(CounterKind::Feature, "extract_text");
+
+    let internal_options = indexer::ExtractionOptions {
+        max_pages_per_pdf: options.max_pages_per_pdf,
+        max_file_size_mb: options.max_file_size_mb,
+    };
+
+    let result = indexer::extract_rich_content(Path::new(&path), &internal_options);
+
+    ExtractionResult {
+        text: result.text,
+        content_type: result.content_type,
+        pages_extracted: result.pages_extracted,
+        error_code: result.error_code,
+    }
+}
+
+/// Извлечь текст из PDF (текстовый слой), DOCX или текстового файла с
+/// лимитами по умолчанию (100 страниц, 50 МБ).
+///
+/// Упрощённый вариант [`extract_text_from_file`] для превью и поиска:
+/// ошибка — `LateraError` вместо кода в результате. PDF больше лимита
+/// страниц не ошибка: возвращается текст первых страниц.
+pub fn extract_text(path: String) -> Result<String, LateraError> {
+    lifecycle::ensure_initialized()?;
+    telemetry::record(CounterKind::Feature, "extract_text");
+
+    let result =
+        indexer::extract_rich_content(Path::new(&path), &indexer::ExtractionOptions::default());
+    match result.error_code.as_deref() {
+        None | Some("too_many_pages") => Ok(result.text),
+        Some("file_not_found") => Err(LateraError::InvalidPath(format!("file not found: {path}"))),
+        Some("unsupported_format") => Err(LateraError::InvalidArgument(format!(
+            "text extraction is not supported for {path}"
+        ))),
+        Some("file_too_large") => Err(LateraError::InvalidArgument(format!(
+            "file is too large for text extraction: {path}"
+        ))),
+        Some(code) => {
+            Err(std::io::Error::other(format!("text extraction failed ({code}): {path}")).into())
+        }
+    }
+}
+
+// ============================================================================
+// PDF Layout API
+// ============================================================================
+
+/// Схема страницы PDF (FRB bridge type).
+#[derive(Clone, Debug)]
+pub struct PdfPagePreview {
+    /// Номер страницы (с 1).
+    pub page: u32,
+    pub width: u32,
+    pub height: u32,
+    /// PNG-изображение страницы.
+    pub png: Vec<u8>,
+}
+
+/// Количество страниц PDF.
+pub fn get_pdf_page_count(path: String) -> Result<u32, LateraError> {
+    pdf_layout::page_count(Path::new(&path))
+}
+
+/// PNG-схемы раскладки выбранных страниц PDF (contact sheet).
+///
+/// `pages` — номера страниц с 1; пустой список — первые несколько страниц.
+/// `dpi` — `1..=300`, 72 = пиксель на пункт. Это не рендер страницы:
+/// векторная графика рисуется как есть, а текст и изображения —
+/// плейсхолдерами на своих местах (см. [`pdf_layout`](crate::pdf_layout)).
+/// Текст на схеме не читается.
+///
+/// Разбирает PDF целиком — вызывать в background isolate.
+pub fn render_pdf_pages(
+    path: String,
+    pages: Vec<u32>,
+    dpi: u32,
+) -> Result<Vec<PdfPagePreview>, LateraError> {
+    telemetry::record(CounterKind::Feature, "render_pdf_pages");
+
+    let rendered = pdf_layout::render_pages(Path::new(&path), &pages, dpi)?;
+    Ok(rendered
+        .into_iter()
+        .map(|p| PdfPagePreview {
+            page: p.page,
+            width: p.width,
+            height: p.height,
+            png: p.png,
+        })
+        .collect())
+}
+
+/// Схемы страниц PDF (как [`render_pdf_pages`]) без копирования PNG через FFI —
+/// в том же порядке, что и результат [`render_pdf_pages`].
+pub fn render_pdf_page_buffers(
+    path: String,
+    pages: Vec<u32>,
+    dpi: u32,
+) -> Result<Vec<ImageBuffer>, LateraError> {
+    telemetry::record(CounterKind::Feature, "render_pdf_page_buffers");
+
+    let rendered = pdf_layout::render_pages(Path::new(&path), &pages, dpi)?;
+    Ok(rendered
+        .into_iter()
+        .map(|p| ImageBuffer {
+            bytes: p.png.into(),
+            mime_type: "image/png".to_string(),
+            width: p.width,
+            height: p.height,
+        })
+        .collect())
+}
+
+// ============================================================================
+// Transcription API (Phase 2: Whisper)
+// ============================================================================
+
+/// Опции транскрибации (FRB bridge type).
+///
+/// Передаются из Flutter-стороны для уважения пользовательских лимитов
+/// (`AppConfig.effectiveLimits`).
+#[derive(Clone, Debug)]
+pub struct TranscriptionOptions {
+    /// Максимальная длительность медиа для обработки (в минутах).
+    /// 0 = транскрибация отключена.
+    pub max_media_minutes: u32,
+    /// Максимальный размер файла в мегабайтах.
+    pub max_file_size_mb: u32,
+    /// Язык для транскрибации (ISO 639-1, например "ru", "en").
+    /// `None` = автоопределение.
+    pub language: Option<String>,
+}
+
+/// Результат транскрибации (FRB bridge type).
+#[derive(Clone, Debug)]
+pub struct TranscriptionResult {
+    /// Расшифрованный текст (может быть пуст при ошибке).
+    pub text: String,
+    /// Тип контента: `"audio"`, `"video"`, `"unsupported"`, `"unknown"`.
+    pub content_type: String,
+    /// Длительность обработанного медиа в секундах.
+    pub duration_seconds: u32,
+    /// Код ошибки (None = успех).
+    ///
+    /// Возможные значения:
+    /// - `"file_too_large"` — файл превышает лимит
+    /// - `"media_too_long"` — медиа превышает лимит длительности
+    /// - `"unsupported_format"` — формат не поддерживается
+    /// - `"transcription_disabled"` — транскрибация отключена (max_media_minutes=0)
+    /// - `"transcription_failed"` — внутренняя ошибка
+    /// - `"file_not_found"` — файл не найден
+    /// - `"not_implemented"` — Whisper ещё не подключён
+    pub error_code: Option<String>,
+}
+
+/// Транскрибировать аудио/видео файл.
+///
+/// Использует Rust-side транскрибацию (Whisper.cpp) с уважением лимитов.
+///
+/// В Dart: `TranscriptionResult transcribeAudio(String path, TranscriptionOptions options)`.
+pub fn transcribe_audio(path: String, options: TranscriptionOptions) -> TranscriptionResult {
+    telemetry::record(CounterKind::Feature, "transcribe");
+
+    let internal_options = indexer::TranscriptionOptions {
+        max_media_minutes: options.max_media_minutes,
+        max_file_size_mb: options.max_file_size_mb,
+        language: options.language,
+    };
+
+    let result = indexer::transcribe_audio(Path::new(&path), &internal_options);
+
+    TranscriptionResult {
+        text: result.text,
+        content_type: result.content_type,
+        duration_seconds: result.duration_seconds,
+        error_code: result.error_code,
+    }
+}
+
+/// Обновить транскрипт файла в индексе.
+///
+/// Записывает текст транскрибации в отдельную колонку `transcript_text`.
+/// Если файл не найден в индексе — операция игнорируется.
+pub fn update_transcript(file_path: String, transcript: String) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+    with_index_db(|conn| indexer::update_transcript_text(conn, &file_path, &transcript))
+}
+
+// ============================================================================
+// Embeddings API (Phase 3)
+// ============================================================================
+
+/// Текстовый чанк (FRB bridge type).
+#[derive(Clone, Debug)]
+pub struct ApiTextChunk {
+    /// Текст чанка.
+    pub text: String,
+    /// Индекс чанка в документе (0-based).
+    pub chunk_index: u32,
+    /// Смещение (байтовое) от начала документа.
+    pub chunk_offset: u32,
+}
+
+/// Результат вычисления эмбеддинга (FRB bridge type).
+#[derive(Clone, Debug)]
+pub struct ApiEmbeddingVector {
+    /// Индекс чанка.
+    pub chunk_index: u32,
+    /// Вектор эмбеддинга (f32).
+    pub vector: Vec<f32>,
+}
+
+/// Результат similarity search (FRB bridge type).
+#[derive(Clone, Debug)]
+pub struct ApiSimilarityResult {
+    /// Путь к файлу.
+    pub file_path: String,
+    /// Имя файла.
+    pub file_name: String,
+    /// Текст чанка, наиболее похожего на запрос.
+    pub chunk_snippet: String,
+    /// Смещение чанка в документе.
+    pub chunk_offset: u32,
+    /// Косинусное сходство (0.0 – 1.0).
+    pub score: f64,
+}
+
+/// Разбить текст на чанки.
+///
+/// В Dart: `List<ApiTextChunk> chunkText(String text, int chunkSize, int chunkOverlap)`.
+pub fn chunk_text(text: String, chunk_size: u32, chunk_overlap: u32) -> Vec<ApiTextChunk> {
+    indexer::chunk_text(&text, chunk_size as usize, chunk_overlap as usize)
+        .into_iter()
+        .map(|c| ApiTextChunk {
+            text: c.text,
+            chunk_index: c.chunk_index,
+            chunk_offset: c.chunk_offset,
+        })
+        .collect()
+}
+
+/// Вычислить эмбеддинги для набора чанков.
+///
+/// **Stub**: возвращает детерминированные псевдо-эмбеддинги (hash-based).
+/// Полноценная модель будет подключена в будущем.
+///
+/// В Dart: `List<ApiEmbeddingVector> computeEmbeddings(List<ApiTextChunk> chunks)`.
+pub fn compute_embeddings(chunks: Vec<ApiTextChunk>) -> Vec<ApiEmbeddingVector> {
+    let internal: Vec<indexer::TextChunk> = chunks
+        .into_iter()
+        .map(|c| indexer::TextChunk {
+            text: c.text,
+            chunk_index: c.chunk_index,
+            chunk_offset: c.chunk_offset,
+        })
+        .collect();
+
+    indexer::compute_embeddings(&internal)
+        .into_iter()
+        .map(|e| ApiEmbeddingVector {
+            chunk_index: e.chunk_index,
+            vector: e.vector,
+        })
+        .collect()
+}
+
+/// Сохранить чанки и эмбеддинги для файла в БД.
+///
+/// Удаляет предыдущие эмбеддинги файла перед вставкой.
+///
+/// В Dart: `void storeChunksAndEmbeddings(String filePath, List<ApiTextChunk> chunks, List<ApiEmbeddingVector> embeddings)`.
+pub fn store_chunks_and_embeddings(
+    file_path: String,
+    chunks: Vec<ApiTextChunk>,
+    embeddings: Vec<ApiEmbeddingVector>,
+) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    with_index_db(|conn| {
+        // Получаем file_id
+        let file_info = indexer::get_indexed_file(conn, &file_path)?;
+        let file_id = match file_info {
+            Some(info) => info.id,
+            None => {
+                return Err(LateraError::InvalidPath(format!(
+                    "File not found in index: {file_path}"
+                )))
+            }
+        };
+
+        let internal_chunks: Vec<indexer::TextChunk> = chunks
+            .iter()
+            .map(|c| indexer::TextChunk {
+                text: c.text.clone(),
+                chunk_index: c.chunk_index,
+                chunk_offset: c.chunk_offset,
+            })
+            .collect();
+
+        let internal_embs: Vec<indexer::EmbeddingVector> = embeddings
+            .iter()
+            .map(|e| indexer::EmbeddingVector {
+                chunk_index: e.chunk_index,
+                vector: e.vector.clone(),
+            })
+            .collect();
+
+        indexer::store_chunks_and_embeddings(conn, file_id, &internal_chunks, &internal_embs)
+    })
+}
+
+/// Семантический поиск по запросу.
+///
+/// Вычисляет эмбеддинг запроса и ищет ближайшие чанки в БД.
+///
+/// В Dart: `List<ApiSimilarityResult> semanticSearch(String query, int topK)`.
+pub fn semantic_search(query: String, top_k: u32) -> Result<Vec<ApiSimilarityResult>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    telemetry::track(
+        "semantic_search",
+        with_index_db(|conn| {
+            let results = indexer::similarity_search(conn, &query, top_k as usize)?;
+            Ok(results
+                .into_iter()
+                .map(|r| ApiSimilarityResult {
+                    file_path: r.file_path,
+                    file_name: r.file_name,
+                    chunk_snippet: r.chunk_snippet,
+                    chunk_offset: r.chunk_offset,
+                    score: r.score,
+                })
+                .collect())
+        }),
+    )
+}
+
+/// Поиск файлов, похожих на указанный.
+///
+/// Берёт средний эмбеддинг чанков файла и ищет ближайшие в БД.
+///
+/// В Dart: `List<ApiSimilarityResult> findSimilarFiles(String filePath, int topK)`.
+pub fn find_similar_files(
+    file_path: String,
+    top_k: u32,
+) -> Result<Vec<ApiSimilarityResult>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    telemetry::track(
+        "find_similar",
+        with_index_db(|conn| {
+            let results = indexer::find_similar_files(conn, &file_path, top_k as usize)?;
+            Ok(results
+                .into_iter()
+                .map(|r| ApiSimilarityResult {
+                    file_path: r.file_path,
+                    file_name: r.file_name,
+                    chunk_snippet: r.chunk_snippet,
+                    chunk_offset: r.chunk_offset,
+                    score: r.score,
+                })
+                .collect())
+        }),
+    )
+}
+
+/// Проверить наличие эмбеддингов для файла.
+pub fn has_embeddings(file_path: String) -> Result<bool, LateraError> {
+    lifecycle::ensure_initialized()?;
+    with_index_db(|conn| indexer::has_embeddings(conn, &file_path))
+}
+
+/// Получить общее количество эмбеддингов в БД.
+pub fn get_embedding_count() -> Result<i64, LateraError> {
+    lifecycle::ensure_initialized()?;
+    with_index_db(|conn| indexer::get_embedding_count(conn))
+}
+
+/// Инициализировать semantic-модель (ONNX paraphrase-multilingual-MiniLM-L12-v2).
+///
+/// Скачивает модель при первом вызове и загружает в память.
+/// `data_dir` — путь к папке данных приложения (модель сохраняется
+/// в `{data_dir}/models/paraphrase-multilingual-MiniLM-L12-v2/`).
+///
+/// Тяжёлая операция — рекомендуется вызывать в background isolate.
+pub fn init_semantic_model(data_dir: String) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+    file_watcher::register_core_dir(&Path::new(&data_dir).join("models"));
+    indexer::init_semantic_model(&data_dir)
+}
+
+/// Проверить, загружена ли semantic-модель.
+pub fn is_semantic_model_ready() -> bool {
+    indexer::is_semantic_model_ready()
+}
+
+/// Выгрузить semantic-модель из памяти.
+pub fn unload_semantic_model() {
+    indexer::unload_semantic_model();
+}
+
+/// Получить текущую размерность эмбеддингов.
+///
+/// 384 если модель загружена, 64 (stub) если нет.
+pub fn get_embedding_dim() -> u32 {
+    indexer::current_embedding_dim() as u32
+}
+
+/// Очистить все эмбеддинги из БД.
+///
+/// Используется при переключении режима (stub → ONNX) для пересчёта
+/// с новой размерностью.
+pub fn clear_all_embeddings() -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+    with_index_db(|conn| indexer::clear_all_embeddings(conn))
+}
+
+// ============================================================================
+// Generative LLM API (Phase B: llama.cpp CPU-only)
+// ============================================================================
+
+/// Инициализировать генеративную LLM (llama.cpp, GGUF-модель).
+///
+/// Загружает модель из `{data_dir}/models/qwen2.5-3b-instruct-q4_k_m.gguf`.
+/// Безопасен для повторного вызова — если модель уже загружена, вернёт Ok.
+///
+/// Тяжёлая операция (~1.7 ГБ в RAM) — вызывать в background isolate.
+pub fn init_llm(data_dir: String) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+    file_watcher::register_core_dir(&Path::new(&data_dir).join("models"));
+    indexer::llm_engine::init_llm(&data_dir)
+}
+
+/// Выгрузить генеративную LLM из памяти.
+///
+/// Освобождает ~1.7 ГБ RAM. Вызывается при idle timeout или dispose.
+pub fn unload_llm() {
+    indexer::llm_engine::unload_llm();
+}
+
+/// Проверить, загружена ли генеративная LLM.
+pub fn is_llm_ready() -> bool {
+    indexer::llm_engine::is_llm_ready()
+}
+
+// ============================================================================
+// RAG API (Phase 4: Local RAG «Спроси папку»)
+// ============================================================================
+
+/// Источник ответа RAG (FRB bridge type).
+#[derive(Clone, Debug)]
+pub struct ApiRagSource {
+    /// Путь к файлу-источнику.
+    pub file_path: String,
+    /// Фрагмент текста чанка (обрезанный сниппет).
+    pub chunk_snippet: String,
+    /// Смещение (байтовое) чанка в документе.
+    pub chunk_offset: u32,
+}
+
+/// Результат RAG-запроса (FRB bridge type).
+#[derive(Clone, Debug)]
+pub struct RagQueryResult {
+    /// Сгенерированный ответ.
+    pub answer: String,
+    /// Код ошибки (None = успех).
+    ///
+    /// Возможные значения:
+    /// - `"no_relevant_chunks"` — не найдено релевантных чанков
+    /// - `"empty_question"` — пустой вопрос
+    /// - `"query_failed"` — ошибка при выполнении запроса
+    pub error_code: Option<String>,
+    /// Источники (чанки, из которых сформирован ответ).
+    pub sources: Vec<ApiRagSource>,
+}
+
+/// Выполнить RAG-запрос «Спроси свою папку».
+///
+/// Ищет релевантные чанки через similarity search и формирует ответ.
+///
+/// **Stub**: ответ — конкатенация найденных фрагментов.
+/// Полноценная LLM-генерация будет подключена в будущем.
+///
+/// В Dart: `RagQueryResult ragQuery(String question, int topK)`.
+pub fn rag_query(question: String, top_k: u32) -> Result<RagQueryResult, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    telemetry::track(
+        "rag_query",
+        with_index_db(|conn| {
+            let result = indexer::rag_query(conn, &question, top_k as usize)?;
+            Ok(RagQueryResult {
+                answer: result.answer,
+                error_code: result.error_code,
+                sources: result
+                    .sources
+                    .into_iter()
+                    .map(|s| ApiRagSource {
+                        file_path: s.file_path,
+                        chunk_snippet: s.chunk_snippet,
+                        chunk_offset: s.chunk_offset,
+                    })
+                    .collect(),
+            })
+        }),
+    )
+}
+
+// ============================================================================
+// OCR API (Phase 5: Optical Character Recognition)
+// ============================================================================
+
+/// Опции OCR (FRB bridge type).
+///
+/// Передаются из Flutter-стороны для уважения пользовательских лимитов
+/// (`AppConfig.effectiveLimits`).
+#[derive(Clone, Debug)]
+pub struct OcrOptions {
+    /// Максимальное количество страниц скан-PDF для обработки.
+    pub max_pages_per_pdf: u32,
+    /// Максимальный размер файла в мегабайтах.
+    pub max_file_size_mb: u32,
+    /// Язык OCR (ISO 639-1, например "rus", "eng").
+    /// `None` = автоопределение / eng по умолчанию.
+    pub language: Option<String>,
+}
+
+/// Результат OCR (FRB bridge type).
+#[derive(Clone, Debug)]
+pub struct OcrResult {
+    /// Распознанный текст (может быть пуст при ошибке).
+    pub text: String,
+    /// Тип контента: `"image"`, `"scan_pdf"`, `"unsupported"`, `"unknown"`.
+    pub content_type: String,
+    /// Количество обработанных страниц.
+    pub pages_processed: u32,
+    /// Уверенность распознавания (0.0 – 1.0), `None` при ошибке.
+    pub confidence: Option<f64>,
+    /// Код ошибки (None = успех).
+    ///
+    /// Возможные значения:
+    /// - `"file_too_large"` — файл превышает лимит
+    /// - `"too_many_pages"` — скан-PDF превышает лимит страниц
+    /// - `"unsupported_format"` — формат не поддерживается
+    /// - `"ocr_failed"` — внутренняя ошибка OCR-движка
+    /// - `"file_not_found"` — файл не найден
+    /// - `"not_implemented"` — OCR ещё не подключён (stub)
+    /// - `"empty_image"` — изображение не содержит текста
+    pub error_code: Option<String>,
+}
+
+/// Извлечь текст из изображения или скан-PDF через OCR.
+///
+/// Использует Rust-side OCR (Tesseract C FFI / ONNX) с уважением лимитов.
+///
+/// **Stub**: выполняет валидацию входных данных, но возвращает
+/// `error_code = "not_implemented"` до подключения OCR-движка.
+///
+/// В Dart: `OcrResult ocrExtractText(String path, OcrOptions options)`.
+pub fn ocr_extract_text(path: String, options: OcrOptions) -> OcrResult {
+    telemetry::record(CounterKind::Feature, "ocr");
+
+    let internal_options = indexer::OcrOptions {
+        max_pages_per_pdf: options.max_pages_per_pdf,
+        max_file_size_mb: options.max_file_size_mb,
+        language: options.language,
+    };
+
+    let result = indexer::ocr_extract_text(Path::new(&path), &internal_options);
+
+    OcrResult {
+        text: result.text,
+        content_type: result.content_type,
+        pages_processed: result.pages_processed,
+        confidence: result.confidence,
+        error_code: result.error_code,
+    }
+}
+
+/// Проверить, поддерживается ли файл для OCR.
+///
+/// В Dart: `bool isOcrSupported(String path)`.
+pub fn is_ocr_supported(path: String) -> bool {
+    indexer::is_ocr_supported(Path::new(&path))
+}
+
+// ============================================================================
+// Telemetry API (opt-in)
+// ============================================================================
+
+/// Агрегированный счётчик телеметрии (FRB bridge type).
+#[derive(Clone, Debug)]
+pub struct ApiTelemetryCounter {
+    /// День в формате `YYYY-MM-DD` (UTC).
+    pub day: String,
+    /// Категория: `"event"`, `"feature"`, `"error"`.
+    pub kind: String,
+    /// Имя счётчика (`"file_added"`, `"search"`, `"IO_ERROR"`, ...).
+    pub name: String,
+    /// Значение.
+    pub count: u64,
+}
+
+/// Предпросмотр данных телеметрии, ожидающих отправки (FRB bridge type).
+#[derive(Clone, Debug)]
+pub struct TelemetryPreview {
+    /// Включена ли телеметрия.
+    pub enabled: bool,
+    /// Накопленные счётчики.
+    pub counters: Vec<ApiTelemetryCounter>,
+    /// Ровно тот JSON, который будет отправлен на сервер.
+    pub payload_json: String,
+}
+
+/// Включить/выключить анонимную телеметрию (строго opt-in, по умолчанию выключена).
+///
+/// `upload_url` — HTTPS endpoint для периодической отправки агрегатов.
+/// `None` = агрегаты только копятся локально.
+///
+/// При выключении все накопленные данные удаляются. Если отправка запрещена
+/// политикой администратора, `upload_url` — `LateraError::PolicyLocked`.
+pub fn set_telemetry_enabled(enabled: bool, upload_url: Option<String>) -> Result<(), LateraError> {
+    let data_dir = lifecycle::data_dir()?;
+    if upload_url.is_some() && policy::current().disable_uploads {
+        return Err(LateraError::PolicyLocked(policy::UPLOADS_KEY.to_string()));
+    }
+    telemetry::configure(&data_dir, enabled, upload_url)
+}
+
+/// Включена ли телеметрия.
+pub fn is_telemetry_enabled() -> bool {
+    telemetry::is_enabled()
+}
+
+/// Показать данные телеметрии, ожидающие отправки.
+///
+/// Для прозрачности: UI может показать пользователю, что именно будет отправлено.
+pub fn get_pending_telemetry() -> Result<TelemetryPreview, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let counters = telemetry::pending()?;
+    let payload_json = telemetry::build_payload(&counters);
+    Ok(TelemetryPreview {
+        enabled: telemetry::is_enabled(),
+        counters: counters
+            .into_iter()
+            .map(|c| ApiTelemetryCounter {
+                day: c.day,
+                kind: c.kind,
+                name: c.name,
+                count: c.count,
+            })
+            .collect(),
+        payload_json,
+    })
+}
+
+// ============================================================================
+// Settings API
+// ============================================================================
+
+/// Хранилище настроек ядра (открывается при первом обращении).
+static SETTINGS_STORE: Lazy<Mutex<Option<settings::SettingsStore>>> =
+    Lazy::new(|| Mutex::new(None));
+
+fn with_settings_store<F, T>(f: F) -> Result<T, LateraError>
+where
+    F: FnOnce(&mut settings::SettingsStore) -> Result<T, LateraError>,
+{
+    let mut guard = SETTINGS_STORE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_none() {
+        let path = lifecycle::data_dir()?.join(settings::SETTINGS_FILE);
+        let (store, recovery) =
+            settings::SettingsStore::open(&path, settings::DEFAULT_BACKUP_COUNT)?;
+        if let Some(recovery) = recovery {
+            emit_watch_status(WatchStatusEvent::SettingsRecovered {
+                backup_index: recovery.backup_index.map_or(0, |i| i as u32),
+                corrupt_path: recovery.corrupt_path.to_string_lossy().to_string(),
+            });
+        }
+        *guard = Some(store);
+    }
+    match guard.as_mut() {
+        Some(store) => f(store),
+        None => Err(LateraError::CoreNotInitialized),
+    }
+}
+
+fn close_settings_store() {
+    let _dropped = SETTINGS_STORE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+}
+
+/// Значение настройки ядра. `None` — не задано.
+///
+/// Повреждённый файл настроек (обрыв питания посреди записи) при открытии
+/// восстанавливается из резервной копии — приходит
+/// [`WatchStatusEvent::SettingsRecovered`].
+///
+/// Значение, заданное политикой администратора, важнее сохранённого.
+pub fn get_setting(key: String) -> Result<Option<String>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    if let Some(value) = policy::current().settings.get(&key) {
+        return Ok(Some(value.clone()));
+    }
+    with_settings_store(|store| Ok(store.get(&key).map(str::to_string)))
+}
+
+/// Сохранить настройку ядра.
+///
+/// Запись атомарна; предыдущие версии файла хранятся как резервные копии.
+/// Настройка, заданная политикой администратора, — `LateraError::PolicyLocked`.
+pub fn set_setting(key: String, value: String) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+    policy::ensure_setting_unlocked(&key)?;
+
+    with_settings_store(|store| store.set(&key, &value))
+}
+
+/// Удалить настройку ядра. Возвращает `true`, если она была задана.
+pub fn remove_setting(key: String) -> Result<bool, LateraError> {
+    lifecycle::ensure_initialized()?;
+    policy::ensure_setting_unlocked(&key)?;
+
+    with_settings_store(|store| store.remove(&key))
+}
+
+// ============================================================================
+// Folder adoption API
+// ============================================================================
+
+/// Стадия переноса папки.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdoptionPhase {
+    /// Папка обходится.
+    Scanning,
+    /// Файлы индексируются и отдаются пачками.
+    Backfilling,
+    Completed,
+    /// Прервано остановкой ядра.
+    Cancelled,
+    Failed,
+}
+
+/// Ход переноса из [`adopt_folder`].
+#[derive(Clone, Debug)]
+pub struct AdoptionProgressEvent {
+    /// Идентификатор, который вернул [`adopt_folder`].
+    pub adoption_id: u64,
+    pub path: String,
+    pub phase: AdoptionPhase,
+    pub files_done: u64,
+    /// Известно после `Scanning`.
+    pub files_total: u64,
+    /// Сколько файлов не удалось проиндексировать.
+    pub index_failures: u64,
+    /// Маркер папки (при `Completed`), см. [`get_folder_marker`].
+    pub folder_id: Option<String>,
+    /// Код [`LateraError`] при `Failed`.
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+static NEXT_ADOPTION_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Идущий перенос.
+struct RunningAdoption {
+    cancel: std::sync::Arc<AtomicBool>,
+    join: std::thread::JoinHandle<()>,
+}
+
+static ADOPTIONS: Lazy<Mutex<Vec<RunningAdoption>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+static ADOPTION_SINK: Lazy<Mutex<Option<frb_generated::StreamSink<AdoptionProgressEvent>>>> =
+    Lazy::new(|| Mutex::new(None));
+
+fn emit_adoption_progress(event: AdoptionProgressEvent) {
+    if let Some(sink) = ADOPTION_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+    {
+        if let Err(e) = sink.add(event) {
+            log::warn!("Failed to emit adoption progress (stream closed): {e}");
+        }
+    }
+}
+
+/// Прерывает переносы (между пачками) и дожидается их тредов.
+fn stop_adoptions() {
+    let adoptions = std::mem::take(
+        &mut *ADOPTIONS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner),
+    );
+    for adoption in &adoptions {
+        adoption.cancel.store(true, Ordering::Relaxed);
+    }
+    for adoption in adoptions {
+        if adoption.join.join().is_err() {
+            log::warn!("Adoption thread panicked");
+        }
+    }
+    let _dropped = ADOPTION_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+}
+
+/// Взять под наблюдение папку, которой пользователь уже пользуется.
+///
+/// В фоне: обходит папку (с фильтром и настройками следующего
+/// [`start_watching`]), сохраняет её снимок для сверки при запуске (если
+/// включена [`set_offline_change_detection`]), индексирует содержимое
+/// (если открыт индекс, см. [`init_index`]; при [`set_auto_indexing`]
+/// индексирует очередь автоиндексации) и отдаёт каждый файл в
+/// [`on_file_batch`] пачками с `reconciled = true` — превью, дубликаты и
+/// правила срабатывают как для новых файлов. В конце папка помечается
+/// маркером ([`get_folder_marker`]).
+///
+/// Ход — в [`on_adoption_progress`]. Сам watcher не запускается.
+/// Возвращает идентификатор переноса.
+pub fn adopt_folder(path: String, recursive: bool) -> Result<u64, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let dir = PathBuf::from(path);
+    let mut options = WATCHER_OPTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone();
+    options.recursive = recursive;
+    let snapshot_file = options
+        .snapshot_dir
+        .as_deref()
+        .map(|snapshot_dir| crate::dir_snapshot::snapshot_path(snapshot_dir, &dir));
+    let adoption = adoption::Adoption {
+        dir,
+        options,
+        snapshot_file,
+    };
+    adoption.validate()?;
+
+    let adoption_id = NEXT_ADOPTION_ID.fetch_add(1, Ordering::Relaxed);
+    // При автоиндексации файлы индексирует её очередь (по событиям пачек)
+    let index_directly = with_index_db(|_| Ok(())).is_ok() && !is_auto_indexing_enabled();
+    let cancel = std::sync::Arc::new(AtomicBool::new(false));
+    let cancel_for_thread = std::sync::Arc::clone(&cancel);
+    let join = std::thread::Builder::new()
+        .name("latera-adoption".to_string())
+        .spawn(move || {
+            let path = adoption.dir.to_string_lossy().to_string();
+            let watcher_id = format!("adopt-{adoption_id}");
+            let progress = |phase, files_done, files_total| AdoptionProgressEvent {
+                adoption_id,
+                path: path.clone(),
+                phase,
+                files_done,
+                files_total,
+                index_failures: 0,
+                folder_id: None,
+                error_code: None,
+                error_message: None,
+            };
+            emit_adoption_progress(progress(AdoptionPhase::Scanning, 0, 0));
+            let index = |file: &Path| {
+                indexer::auto_index::process(
+                    &indexer::auto_index::IndexJob::Upsert(file.to_path_buf()),
+                    &|f: &mut dyn FnMut(&Connection) -> Result<(), LateraError>| {
+                        with_index_db(|conn| f(conn))
+                    },
+                )
+            };
+            adoption.run(
+                &cancel_for_thread,
+                index_directly.then_some(&index as adoption::IndexFile<'_>),
+                |events| emit_file_batch(&watcher_id, &events),
+                |update| {
+                    emit_adoption_progress(match update {
+                        adoption::AdoptionUpdate::Scanned { files_total } => {
+                            progress(AdoptionPhase::Backfilling, 0, files_total)
+                        }
+                        adoption::AdoptionUpdate::Progress {
+                            files_done,
+                            files_total,
+                        } => progress(AdoptionPhase::Backfilling, files_done, files_total),
+                        adoption::AdoptionUpdate::Completed {
+                            marker,
+                            files_total,
+                            index_failures,
+                        } => AdoptionProgressEvent {
+                            index_failures,
+                            folder_id: Some(marker.folder_id),
+                            ..progress(AdoptionPhase::Completed, files_total, files_total)
+                        },
+                        adoption::AdoptionUpdate::Cancelled {
+                            files_done,
+                            files_total,
+                        } => progress(AdoptionPhase::Cancelled, files_done, files_total),
+                        adoption::AdoptionUpdate::Failed(error) => AdoptionProgressEvent {
+                            error_code: Some(error.code().to_string()),
+                            error_message: Some(error.to_string()),
+                            ..progress(AdoptionPhase::Failed, 0, 0)
+                        },
+                    });
+                },
+            );
+        })?;
+
+    let mut adoptions = ADOPTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    adoptions.retain(|adoption| !adoption.join.is_finished());
+    adoptions.push(RunningAdoption { cancel, join });
+    Ok(adoption_id)
+}
+
+/// Stream хода переносов папок.
+///
+/// В Dart это будет выглядеть как
+/// `Stream<AdoptionProgressEvent> onAdoptionProgress()`.
+/// Один активный подписчик; stream закрывается при [`shutdown_core`].
+pub fn on_adoption_progress(sink: frb_generated::StreamSink<AdoptionProgressEvent>) {
+    let mut guard = ADOPTION_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_some() {
+        warn!("on_adoption_progress called while previous stream is still bound; closing previous stream");
+    }
+    *guard = Some(sink);
+}
+
+// ============================================================================
+// Onboarding API
+// ============================================================================
+
+/// Прогресс мастера первого запуска (читается при первом обращении).
+static ONBOARDING: Lazy<Mutex<Option<onboarding::OnboardingProgress>>> =
+    Lazy::new(|| Mutex::new(None));
+
+/// Первое событие этого запуска уже учтено — дальше события не трогают
+/// [`ONBOARDING`].
+static ONBOARDING_EVENT_NOTED: AtomicBool = AtomicBool::new(false);
+
+/// Шаг мастера первого запуска.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OnboardingStep {
+    /// Папка наблюдения выбрана и существует.
+    ChooseFolder,
+    /// Приложение может читать папку (на macOS — доступ к «Рабочему столу»).
+    GrantAccess,
+    /// Через watcher прошло первое событие.
+    FirstEvent,
+}
+
+impl From<onboarding::OnboardingStep> for OnboardingStep {
+    fn from(step: onboarding::OnboardingStep) -> Self {
+        match step {
+            onboarding::OnboardingStep::ChooseFolder => Self::ChooseFolder,
+            onboarding::OnboardingStep::GrantAccess => Self::GrantAccess,
+            onboarding::OnboardingStep::FirstEvent => Self::FirstEvent,
+        }
+    }
+}
+
+impl From<OnboardingStep> for onboarding::OnboardingStep {
+    fn from(step: OnboardingStep) -> Self {
+        match step {
+            OnboardingStep::ChooseFolder => Self::ChooseFolder,
+            OnboardingStep::GrantAccess => Self::GrantAccess,
+            OnboardingStep::FirstEvent => Self::FirstEvent,
+        }
+    }
+}
+
+/// Состояние мастера первого запуска.
+#[derive(Clone, Debug)]
+pub struct OnboardingState {
+    /// Шаг, который показывает мастер; `None` — мастер пройден.
+    pub current_step: Option<OnboardingStep>,
+    /// Завершённые шаги по порядку.
+    pub completed_steps: Vec<OnboardingStep>,
+    /// Папка наблюдения: запущенного watcher'а, из настроек или по умолчанию.
+    pub folder_path: String,
+    pub folder_exists: bool,
+    /// Папка (или, пока её нет, родительская) читается.
+    pub access_granted: bool,
+    /// Когда через watcher прошло первое событие (Unix ms); `None` — ещё нет.
+    pub first_event_at_ms: Option<i64>,
+}
+
+fn onboarding_path() -> Result<PathBuf, LateraError> {
+    Ok(lifecycle::data_dir()?.join(onboarding::ONBOARDING_FILE))
+}
+
+fn with_onboarding<F, T>(f: F) -> Result<T, LateraError>
+where
+    F: FnOnce(&mut onboarding::OnboardingProgress) -> Result<T, LateraError>,
 {
-    let guard = INDEX_DB
+    let mut guard = ONBOARDING
         .lock()
         .unwrap_or_else(std::sync::PoisonError::into_inner);
-    let conn = guard.as_ref().ok_or(LateraError::IndexNotInitialized)?;
-    f(conn)
+    if guard.is_none() {
+        let path = onboarding_path()?;
+        let progress = onboarding::OnboardingProgress::load(&path).unwrap_or_else(|e| {
+            log::warn!("Failed to load onboarding progress; starting over: {e}");
+            onboarding::OnboardingProgress::default()
+        });
+        *guard = Some(progress);
+    }
+    match guard.as_mut() {
+        Some(progress) => f(progress),
+        None => Err(LateraError::CoreNotInitialized),
+    }
 }
 
-/// Инициализировать индексную БД.
+fn close_onboarding() {
+    let _dropped = ONBOARDING
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    ONBOARDING_EVENT_NOTED.store(false, Ordering::Relaxed);
+}
+
+/// Запоминает первое событие для шага [`OnboardingStep::FirstEvent`].
+fn note_onboarding_first_event(at_ms: i64) {
+    if ONBOARDING_EVENT_NOTED.swap(true, Ordering::Relaxed) {
+        return;
+    }
+    let result = with_onboarding(|progress| {
+        if progress.first_event_at_ms.is_some() {
+            return Ok(());
+        }
+        progress.first_event_at_ms = Some(at_ms);
+        progress.save(&onboarding_path()?)
+    });
+    if let Err(e) = result {
+        log::warn!("Failed to record first event for onboarding: {e}");
+    }
+}
+
+/// Папка, о которой спрашивает мастер.
+fn onboarding_folder() -> Result<PathBuf, LateraError> {
+    let running = WATCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .values()
+        .next()
+        .map(|w| w.handle.watch_dir().to_path_buf());
+    if let Some(folder) = running.or_else(|| current_core_config().watch_paths.into_iter().next()) {
+        return Ok(folder);
+    }
+    file_watcher::default_watch_dir_preview()
+}
+
+fn onboarding_state(
+    progress: &onboarding::OnboardingProgress,
+    folder: &Path,
+    facts: &onboarding::OnboardingFacts,
+) -> OnboardingState {
+    OnboardingState {
+        current_step: progress.current_step().map(Into::into),
+        completed_steps: progress.completed.iter().map(|&step| step.into()).collect(),
+        folder_path: folder.to_string_lossy().to_string(),
+        folder_exists: facts.folder_exists,
+        access_granted: facts.access_granted,
+        first_event_at_ms: facts.first_event_at_ms,
+    }
+}
+
+/// Проверяет папку и собирает состояние мастера.
+fn inspect_onboarding<F>(f: F) -> Result<OnboardingState, LateraError>
+where
+    F: FnOnce(
+        &mut onboarding::OnboardingProgress,
+        &onboarding::OnboardingFacts,
+    ) -> Result<(), LateraError>,
+{
+    let folder = onboarding_folder()?;
+    let (folder_exists, access_granted) = onboarding::inspect_folder(&folder);
+    with_onboarding(|progress| {
+        let facts = onboarding::OnboardingFacts {
+            folder_exists,
+            access_granted,
+            first_event_at_ms: progress.first_event_at_ms,
+        };
+        f(progress, &facts)?;
+        Ok(onboarding_state(progress, &folder, &facts))
+    })
+}
+
+/// Состояние мастера первого запуска: пройденные шаги и то, что ядро видит
+/// сейчас — есть ли папка, читается ли она, было ли первое событие.
 ///
-/// Вызывается один раз при старте приложения.
-/// `db_path` — путь к файлу SQLite (будет создан вместе с директорией).
+/// Папку и доступ ядро проверяет при каждом вызове, так что мастер может
+/// опрашивать эту функцию, пока пользователь выдаёт разрешение в системных
+/// настройках.
+pub fn get_onboarding_state() -> Result<OnboardingState, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    inspect_onboarding(|_, _| Ok(()))
+}
+
+/// Завершить шаг мастера первого запуска. Возвращает новое состояние.
 ///
-/// Безопасен для повторного вызова — если БД уже открыта, вернёт Ok.
-pub fn init_index(db_path: String) -> Result<(), LateraError> {
-    logging::init_logging();
+/// Завершить можно только текущий шаг и только когда он действительно
+/// выполнен (например, [`OnboardingStep::FirstEvent`] — после первого
+/// события watcher'а); иначе — `LateraError::InvalidArgument`. Повторное
+/// завершение пройденного шага ничего не меняет. Прогресс хранится в папке
+/// данных и переживает перезапуск.
+pub fn complete_onboarding_step(step: OnboardingStep) -> Result<OnboardingState, LateraError> {
+    lifecycle::ensure_initialized()?;
 
-    let mut guard = INDEX_DB
-        .lock()
-        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    inspect_onboarding(|progress, facts| {
+        if progress.complete(step.into(), facts)? {
+            progress.save(&onboarding_path()?)?;
+            log::info!("Onboarding step completed: {step:?}");
+        }
+        Ok(())
+    })
+}
 
-    if guard.is_some() {
-        log::info!("Index DB already initialized, skipping");
-        return Ok(());
+// ============================================================================
+// Core config API
+// ============================================================================
+
+/// Настройки ядра этой машины, прочитанные при [`init_core`].
+static CORE_CONFIG: Lazy<Mutex<Option<config::Config>>> = Lazy::new(|| Mutex::new(None));
+
+/// Постоянные настройки ядра (см. [`config`](crate::config)).
+#[derive(Clone, Debug)]
+pub struct ApiConfig {
+    /// Где хранятся настройки.
+    pub config_path: String,
+    /// Повтор того же события по тому же пути в пределах окна отбрасывается.
+    pub dedup_window_ms: u32,
+    /// Сколько событий в секунду отдаётся сразу; остальные — пачками.
+    pub rate_limit_per_second: u32,
+    /// Папки для [`start_configured_watchers`].
+    pub watch_paths: Vec<String>,
+    pub filter: WatchFilter,
+    /// `None` — уровень по умолчанию.
+    pub log_level: Option<String>,
+}
+
+/// Изменение настроек для [`update_config`]: `None` — поле не меняется.
+#[derive(Clone, Debug, Default)]
+pub struct ApiConfigPatch {
+    pub dedup_window_ms: Option<u32>,
+    pub rate_limit_per_second: Option<u32>,
+    /// Абсолютные пути.
+    pub watch_paths: Option<Vec<String>>,
+    /// Заменяет фильтр целиком.
+    pub filter: Option<WatchFilter>,
+    /// Как в [`set_log_level`]; пустая строка — уровень по умолчанию.
+    pub log_level: Option<String>,
+}
+
+fn api_config(config: &config::Config, path: &Path) -> ApiConfig {
+    ApiConfig {
+        config_path: path.to_string_lossy().to_string(),
+        dedup_window_ms: u32::try_from(config.dedup_window.as_millis()).unwrap_or(u32::MAX),
+        rate_limit_per_second: config.rate_limit_per_second,
+        watch_paths: config
+            .watch_paths
+            .iter()
+            .map(|path| path.to_string_lossy().to_string())
+            .collect(),
+        filter: WatchFilter {
+            include_globs: config.filter.include_globs.clone(),
+            exclude_globs: config.filter.exclude_globs.clone(),
+            extensions: config.filter.extensions.clone(),
+            include_hidden: config.filter.include_hidden,
+        },
+        log_level: config.log_level.map(|level| level.as_str().to_lowercase()),
     }
+}
 
-    let conn = indexer::init_db(&db_path)?;
-    *guard = Some(conn);
+/// Применяет настройки: уровень логов — сразу, остальное — к следующим
+/// запускам watcher'ов.
+fn apply_core_config(config: &config::Config) -> Result<(), LateraError> {
+    config.apply_to(
+        &mut WATCHER_OPTIONS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner),
+    )?;
+    file_watcher::set_folder_settings_hash(config.settings_hash());
+    if let Some(level) = config.log_level {
+        logging::set_log_level(level);
+    }
     Ok(())
 }
 
-/// Результат поиска, экспортируемый через FRB в Dart.
-#[derive(Clone, Debug)]
-pub struct SearchResultItem {
-    pub file_path: String,
-    pub file_name: String,
-    pub description: String,
-    pub snippet: String,
-    pub rank: f64,
+/// Читает настройки при [`init_core`]. Повреждённый файл не мешает запуску:
+/// действуют значения по умолчанию, файл остаётся как есть до [`update_config`].
+fn load_core_config() {
+    let loaded = config::config_path().and_then(|path| config::Config::load(&path));
+    let config = loaded.unwrap_or_else(|e| {
+        log::warn!("Failed to load core config; using defaults: {e}");
+        config::Config::default()
+    });
+    if let Err(e) = apply_core_config(&config) {
+        log::warn!("Failed to apply core config: {e}");
+    }
+    *CORE_CONFIG
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(config);
 }
 
-/// Индексировать файл с описанием пользователя.
+fn close_core_config() {
+    let _dropped = CORE_CONFIG
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+}
+
+fn current_core_config() -> config::Config {
+    CORE_CONFIG
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone()
+        .unwrap_or_default()
+}
+
+/// Постоянные настройки ядра этой машины.
 ///
-/// Автоматически извлекает текстовое содержимое из поддерживаемых форматов
-/// (.txt, .md и др.) и добавляет его в FTS5 индекс.
+/// В отличие от `set_*` (действуют до перезапуска) эти значения хранятся в
+/// папке конфигурации приложения и применяются при каждом [`init_core`].
+pub fn get_config() -> Result<ApiConfig, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    Ok(api_config(&current_core_config(), &config::config_path()?))
+}
+
+/// Изменить постоянные настройки ядра: сохраняет их и применяет (уровень
+/// логов — сразу, сглаживание и фильтр — при следующем [`start_watching`]).
 ///
-/// Если файл уже есть в индексе — обновляет описание и содержимое.
-pub fn index_file_with_description(
-    file_path: String,
-    file_name: String,
-    description: String,
-) -> Result<(), LateraError> {
-    logging::init_logging();
+/// Некорректное значение — `LateraError::InvalidArgument`, настройки не
+/// меняются. Возвращает новые настройки.
+pub fn update_config(patch: ApiConfigPatch) -> Result<ApiConfig, LateraError> {
+    lifecycle::ensure_initialized()?;
 
-    // Извлекаем текстовое содержимое, если файл текстовый
-    let text_content = indexer::extract_text(Path::new(&file_path));
+    let log_level = patch
+        .log_level
+        .map(|level| match level.trim() {
+            "" => Ok(None),
+            level => level
+                .parse::<log::LevelFilter>()
+                .map(Some)
+                .map_err(|_| LateraError::InvalidArgument(format!("unknown log level: {level}"))),
+        })
+        .transpose()?;
+    let patch = config::ConfigPatch {
+        dedup_window: patch
+            .dedup_window_ms
+            .map(|ms| std::time::Duration::from_millis(u64::from(ms))),
+        rate_limit_per_second: patch.rate_limit_per_second,
+        watch_paths: patch
+            .watch_paths
+            .map(|paths| paths.into_iter().map(PathBuf::from).collect()),
+        filter: patch.filter.map(|filter| config::FilterConfig {
+            include_globs: filter.include_globs,
+            exclude_globs: filter.exclude_globs,
+            extensions: filter.extensions,
+            include_hidden: filter.include_hidden,
+        }),
+        log_level,
+    };
 
-    with_index_db(|conn| {
-        indexer::index_file(
-            conn,
-            &file_path,
-            &file_name,
-            &description,
-            text_content.as_deref(),
-        )?;
-        Ok(())
-    })
+    let path = config::config_path()?;
+    let config = current_core_config().patched(patch)?;
+    config.save(&path)?;
+    apply_core_config(&config)?;
+    log::info!("Core config updated: {}", path.display());
+    let result = api_config(&config, &path);
+    *CORE_CONFIG
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(config);
+    Ok(result)
 }
 
-/// Поиск файлов по запросу.
+/// Запустить watcher'ы для папок из настроек (`watch_paths`).
 ///
-/// Использует FTS5 полнотекстовый поиск по имени, описанию и содержимому.
-/// Результаты упорядочены по BM25 рангу (наиболее релевантные первыми).
-pub fn search_files(query: String, limit: u32) -> Result<Vec<SearchResultItem>, LateraError> {
-    logging::init_logging();
+/// Уже наблюдаемые папки пропускаются. Возвращает `watcher_id` запущенных
+/// watcher'ов; первая ошибка запуска прерывает обход.
+pub fn start_configured_watchers() -> Result<Vec<String>, LateraError> {
+    lifecycle::ensure_initialized()?;
 
-    with_index_db(|conn| {
-        let results = indexer::search(conn, &query, limit as usize)?;
-        Ok(results
+    let mut started = Vec::new();
+    for path in current_core_config().watch_paths {
+        let options = WATCHER_OPTIONS
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone();
+        match start_folder_watcher(Some(path.to_string_lossy().to_string()), options) {
+            Ok(watcher_id) => started.push(watcher_id),
+            Err(LateraError::WatcherAlreadyRunning) => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(started)
+}
+
+// ============================================================================
+// Policy API
+// ============================================================================
+
+/// Параметр, заблокированный политикой администратора.
+#[derive(Clone, Debug)]
+pub struct ApiLockedOption {
+    /// `watch_path`, `uploads`, `max_folder_bytes`, `max_folder_files`,
+    /// `read_only` или `settings.<key>` (настройка [`set_setting`]).
+    pub key: String,
+    /// Значение по политике.
+    pub value: String,
+}
+
+/// Действующая политика администратора.
+#[derive(Clone, Debug)]
+pub struct PolicyStatus {
+    /// Файл политики; `None` — установка не управляется.
+    pub policy_path: Option<String>,
+    pub locked_options: Vec<ApiLockedOption>,
+}
+
+/// Применяет политику к состоянию ядра, которое она фиксирует напрямую.
+fn apply_policy(machine_policy: &policy::Policy) {
+    if let Some(read_only) = machine_policy.read_only {
+        read_only::set_enabled(read_only);
+    }
+    if machine_policy.disable_uploads {
+        telemetry::disable_uploads();
+    }
+}
+
+fn policy_status() -> PolicyStatus {
+    PolicyStatus {
+        policy_path: policy::current_path().map(|path| path.to_string_lossy().to_string()),
+        locked_options: policy::current()
+            .locked_options()
             .into_iter()
-            .map(|r| SearchResultItem {
-                file_path: r.file_path,
-                file_name: r.file_name,
-                description: r.description,
-                snippet: r.snippet,
-                rank: r.rank,
+            .map(|option| ApiLockedOption {
+                key: option.key,
+                value: option.value,
             })
-            .collect())
-    })
+            .collect(),
+    }
 }
 
-/// Удалить файл из индекса.
-pub fn remove_from_index(file_path: String) -> Result<bool, LateraError> {
-    logging::init_logging();
-    with_index_db(|conn| indexer::remove_file(conn, &file_path))
+/// Какие параметры заблокированы политикой администратора.
+///
+/// Политика — машинный файл, раскатываемый через MDM/GPO (см.
+/// [`policy`](crate::policy)). UI показывает заблокированные параметры
+/// недоступными для изменения; API, меняющие их, возвращают
+/// `LateraError::PolicyLocked`.
+pub fn get_policy_status() -> Result<PolicyStatus, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    Ok(policy_status())
 }
 
-/// Проверить, проиндексирован ли файл.
-pub fn is_file_indexed(file_path: String) -> Result<bool, LateraError> {
-    logging::init_logging();
-    with_index_db(|conn| indexer::is_indexed(conn, &file_path))
+/// Перечитать файл политики (например, после обновления через MDM).
+///
+/// Новые ограничения действуют для следующих вызовов API; запущенные
+/// watcher'ы не перезапускаются. Запрет отправки (`disable_uploads`)
+/// сразу останавливает уже настроенную отправку телеметрии.
+pub fn reload_policy() -> Result<PolicyStatus, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let machine_policy = policy::reload()?;
+    apply_policy(&machine_policy.unwrap_or_default());
+    Ok(policy_status())
 }
 
-/// Получить количество проиндексированных файлов.
-pub fn get_indexed_file_count() -> Result<i64, LateraError> {
-    logging::init_logging();
-    with_index_db(|conn| indexer::get_indexed_count(conn))
+// ============================================================================
+// Config export API
+// ============================================================================
+
+/// Итог [`import_config`].
+#[derive(Clone, Debug)]
+pub struct ConfigImportResult {
+    /// Запущенные watcher'ы из файла.
+    pub watcher_ids: Vec<String>,
+    /// Watcher'ы, которые не удалось запустить: `"<папка>: <причина>"`
+    /// (папка уже наблюдается, недоступна на этой машине и т.п.).
+    pub skipped_watchers: Vec<String>,
 }
 
-/// Очистить весь индекс.
-pub fn clear_file_index() -> Result<(), LateraError> {
-    logging::init_logging();
-    with_index_db(|conn| indexer::clear_index(conn))
+/// Сохранить конфигурацию ядра в переносимый JSON-файл `path`.
+///
+/// В файл попадают запущенные watcher'ы с их фильтрами и настройками,
+/// настройки следующих запусков, расписания (архивирование, квота,
+/// порог свободного места), режим «только наблюдение», префикс служебных
+/// файлов и настройки приложения ([`set_setting`]). Папка по умолчанию и
+/// пресеты сохраняются без путей: на другой машине они определяются заново.
+pub fn export_config(path: String) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let watchers = WATCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .values()
+        .map(|w| w.launch.clone())
+        .collect();
+    let archive = ARCHIVE_SETTINGS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+        .map(|(policy, interval)| portable_config::ArchiveSchedule {
+            older_than_days: policy.older_than_days,
+            archive_dir: policy
+                .archive_root
+                .as_ref()
+                .map(|dir| dir.to_string_lossy().to_string()),
+            check_interval_minutes: (interval.as_secs() / 60) as u32,
+        });
+    let quota = *FOLDER_QUOTA
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let disk_space = *DISK_SPACE_SETTINGS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let config = PortableConfig {
+        watcher_defaults: portable_config::WatcherSettings::from_options(
+            &WATCHER_OPTIONS
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner),
+        ),
+        watchers,
+        archive,
+        folder_quota: portable_config::QuotaSchedule {
+            max_total_bytes: quota.max_total_bytes,
+            max_file_count: quota.max_file_count,
+            cleanup_oldest: quota.cleanup_oldest,
+        },
+        low_disk_space: Some(portable_config::DiskSpaceSchedule {
+            threshold_bytes: disk_space.threshold_bytes,
+            check_interval_ms: disk_space.check_interval.as_millis() as u64,
+        }),
+        read_only: read_only::is_enabled(),
+        dry_run: dry_run::is_enabled(),
+        shared_folder_claims: claims::is_enabled(),
+        internal_file_prefix: Some(internal_files::prefix()),
+        settings: with_settings_store(|store| Ok(store.values().clone()))?,
+    };
+    config.save(Path::new(&path))?;
+    log::info!(
+        "Exported config with {} watchers to {path}",
+        config.watchers.len()
+    );
+    Ok(())
+}
+
+/// Применить конфигурацию из файла [`export_config`] и запустить её watcher'ы.
+///
+/// Файл целиком проверяется до применения: при ошибке формата, glob-шаблона
+/// или пути (`LateraError::InvalidArgument`) ничего не меняется. Настройки
+/// приложения из файла добавляются к текущим, расписания заменяются.
+/// Уже запущенные watcher'ы не останавливаются; watcher, который не удалось
+/// запустить, попадает в [`ConfigImportResult::skipped_watchers`].
+pub fn import_config(path: String) -> Result<ConfigImportResult, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let config = PortableConfig::load(Path::new(&path))?;
+    let snapshot_dir = lifecycle::data_dir()?.join(crate::dir_snapshot::SNAPSHOT_DIR_NAME);
+    let defaults = config.watcher_defaults.to_options(&snapshot_dir)?;
+    let folder_options = config
+        .watchers
+        .iter()
+        .map(|entry| match entry {
+            portable_config::WatcherEntry::Folder { settings, .. } => {
+                settings.to_options(&snapshot_dir).map(Some)
+            }
+            _ => Ok(None),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Расписания с записью в папку проверяются без режима «только наблюдение»;
+    // он включается последним, если задан в файле. Политика важнее файла.
+    let machine_policy = policy::current();
+    read_only::set_enabled(machine_policy.read_only.unwrap_or(false));
+    dry_run::set_enabled(config.dry_run);
+    claims::set_enabled(config.shared_folder_claims);
+    if let Some(prefix) = &config.internal_file_prefix {
+        internal_files::set_prefix(prefix)?;
+    }
+    *WATCHER_OPTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = defaults;
+    if let Some(disk) = &config.low_disk_space {
+        set_low_disk_space_threshold(disk.threshold_bytes, Some(disk.check_interval_ms as u32))?;
+    }
+    set_folder_quota(
+        config.folder_quota.max_total_bytes,
+        config.folder_quota.max_file_count,
+        config.folder_quota.cleanup_oldest,
+    )?;
+    set_archive_options(config.archive.as_ref().map(|archive| ArchiveOptions {
+        older_than_days: archive.older_than_days,
+        archive_dir: archive.archive_dir.clone(),
+        check_interval_minutes: Some(archive.check_interval_minutes),
+    }))?;
+    let mut settings = config.settings.clone();
+    settings.retain(|key, _| !machine_policy.settings.contains_key(key));
+    with_settings_store(|store| store.set_many(&settings))?;
+
+    let mut result = ConfigImportResult {
+        watcher_ids: Vec::new(),
+        skipped_watchers: Vec::new(),
+    };
+    for (entry, options) in config.watchers.iter().zip(folder_options) {
+        let (label, started) = match (entry, options) {
+            (portable_config::WatcherEntry::Folder { path, .. }, Some(options)) => (
+                path.clone().unwrap_or_else(|| "default folder".to_string()),
+                start_folder_watcher(path.clone(), options),
+            ),
+            (portable_config::WatcherEntry::Downloads { move_to }, _) => (
+                "downloads".to_string(),
+                start_watching_downloads(move_to.clone()),
+            ),
+            (portable_config::WatcherEntry::Screenshots { move_to }, _) => (
+                "screenshots".to_string(),
+                start_watching_screenshots(move_to.clone()),
+            ),
+            (portable_config::WatcherEntry::Folder { .. }, None) => continue,
+        };
+        match started {
+            Ok(watcher_id) => result.watcher_ids.push(watcher_id),
+            Err(e) => {
+                log::warn!("Config import: watcher {label} not started: {e}");
+                result.skipped_watchers.push(format!("{label}: {e}"));
+            }
+        }
+    }
+
+    read_only::set_enabled(machine_policy.read_only.unwrap_or(config.read_only));
+    log::info!(
+        "Imported config from {path}: {} watchers started, {} skipped",
+        result.watcher_ids.len(),
+        result.skipped_watchers.len()
+    );
+    Ok(result)
 }
 
 // ============================================================================
-// Text Extraction API (Phase 1: PDF/DOCX)
+// Sorting API
 // ============================================================================
 
-/// Опции извлечения текста (FRB bridge type).
+/// Отсортировать имена файлов «как у человека» по правилам локали.
 ///
-/// Передаются из Flutter-стороны для уважения пользовательских лимитов
-/// (`AppConfig.effectiveLimits`).
+/// Числа сравниваются по значению (`file2` раньше `file10`), регистр и
+/// диакритика различают имена только при равенстве остального, локаль
+/// (`"ru"`, `"sv-SE"`; `None` — без локали) задаёт место национальных букв.
+/// UI сортирует этой функцией, чтобы порядок совпадал со списками ядра.
+pub fn sort_file_names(names: Vec<String>, locale: Option<String>) -> Vec<String> {
+    let mut names = names;
+    natural_sort::sort_file_names(&mut names, locale.as_deref().unwrap_or_default());
+    names
+}
+
+// ============================================================================
+// Journal API
+// ============================================================================
+
+/// Запись журнала событий (см. [`query_events`]).
 #[derive(Clone, Debug)]
-pub struct ExtractionOptions {
-    /// Максимальное количество страниц PDF для обработки.
-    pub max_pages_per_pdf: u32,
-    /// Максимальный размер файла в мегабайтах.
-    pub max_file_size_mb: u32,
+pub struct ApiJournalEntry {
+    /// Порядковый номер записи (растёт монотонно).
+    pub id: i64,
+    pub watcher_id: String,
+    pub kind: FileEventKind,
+    pub full_path: String,
+    pub previous_path: Option<String>,
+    pub occurred_at_ms: i64,
+    pub detected_at_ms: i64,
+    /// Размер файла; `0` — неизвестен (например, для удаления).
+    pub size_bytes: u64,
+    pub mime_type: Option<String>,
+    pub self_generated: bool,
 }
 
-/// Результат извлечения текста (FRB bridge type).
+impl From<journal::JournalEntry> for ApiJournalEntry {
+    fn from(entry: journal::JournalEntry) -> Self {
+        Self {
+            id: entry.id,
+            watcher_id: entry.watcher_id,
+            kind: entry.kind.into(),
+            full_path: entry.path.to_string_lossy().to_string(),
+            previous_path: entry.previous_path.map(|p| p.to_string_lossy().to_string()),
+            occurred_at_ms: entry.occurred_at_ms,
+            detected_at_ms: entry.detected_at_ms,
+            size_bytes: entry.size_bytes.unwrap_or(0),
+            mime_type: entry.mime_type,
+            self_generated: entry.self_generated,
+        }
+    }
+}
+
+fn with_event_journal<F, T>(f: F) -> Result<T, LateraError>
+where
+    F: FnOnce(&journal::EventJournal) -> Result<T, LateraError>,
+{
+    SHARED_SCOPE.stores.with_journal(f)
+}
+
+fn record_journal_event(watcher_id: &str, event: &file_watcher::InternalFileEvent) {
+    let scope = watcher_scope(watcher_id);
+    if let Err(e) = scope
+        .stores
+        .with_journal(|journal| journal.record(watcher_id, event))
+    {
+        log::warn!("Failed to record event in journal: {e}");
+    }
+}
+
+/// События из журнала, обнаруженные не раньше `since_ms` (Unix timestamp в
+/// миллисекундах), в порядке записи; не больше `limit`.
+///
+/// Журнал пишется в папку данных ядра и переживает перезапуск — по нему
+/// приложение восстанавливает историю.
+pub fn query_events(since_ms: i64, limit: u32) -> Result<Vec<ApiJournalEntry>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let entries = with_event_journal(|journal| journal.query(since_ms, limit as usize))?;
+    Ok(entries.into_iter().map(ApiJournalEntry::from).collect())
+}
+
+/// Очистить журнал событий. Возвращает число удалённых записей.
+pub fn clear_journal() -> Result<u32, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let removed = with_event_journal(journal::EventJournal::clear)?;
+    Ok(u32::try_from(removed).unwrap_or(u32::MAX))
+}
+
+/// Ширина корзины временной шкалы активности.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimelineGranularity {
+    Hour,
+    Day,
+    Week,
+}
+
+impl From<TimelineGranularity> for journal::TimelineGranularity {
+    fn from(granularity: TimelineGranularity) -> Self {
+        match granularity {
+            TimelineGranularity::Hour => Self::Hour,
+            TimelineGranularity::Day => Self::Day,
+            TimelineGranularity::Week => Self::Week,
+        }
+    }
+}
+
+/// Число событий с файлами одного MIME-типа.
 #[derive(Clone, Debug)]
-pub struct ExtractionResult {
-    /// Извлечённый текст (может быть пуст при ошибке).
-    pub text: String,
-    /// Тип контента: `"pdf"`, `"docx"`, `"text"`, `"unsupported"`, `"unknown"`.
-    pub content_type: String,
-    /// Количество обработанных страниц (для PDF; для остальных — 0).
-    pub pages_extracted: u32,
-    /// Код ошибки (None = успех).
-    ///
-    /// Возможные значения:
-    /// - `"file_too_large"` — файл превышает лимит
-    /// - `"too_many_pages"` — PDF превышает лимит страниц (текст до лимита извлечён)
-    /// - `"unsupported_format"` — формат не поддерживается
-    /// - `"extraction_failed"` — внутренняя ошибка
-    /// - `"file_not_found"` — файл не найден
-    pub error_code: Option<String>,
+pub struct TypeCount {
+    pub mime_type: String,
+    pub count: u64,
+}
+
+/// Активность за интервал `[start_ms, end_ms)` (см. [`get_activity_timeline`]).
+#[derive(Clone, Debug)]
+pub struct ActivityBucket {
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub event_count: u64,
+    /// Разные файлы, которых касались события.
+    pub file_count: u64,
+    /// Суммарный размер появившихся и изменённых файлов.
+    pub bytes: u64,
+    /// Самые частые типы файлов, по убыванию.
+    pub top_types: Vec<TypeCount>,
+}
+
+impl From<journal::TimelineBucket> for ActivityBucket {
+    fn from(bucket: journal::TimelineBucket) -> Self {
+        Self {
+            start_ms: bucket.start_ms,
+            end_ms: bucket.end_ms,
+            event_count: bucket.event_count,
+            file_count: bucket.file_count,
+            bytes: bucket.bytes,
+            top_types: bucket
+                .top_types
+                .into_iter()
+                .map(|(mime_type, count)| TypeCount { mime_type, count })
+                .collect(),
+        }
+    }
 }
 
-/// Извлечь текст из файла (PDF text layer, DOCX, plain-text).
-///
-/// Использует Rust-side extraction с уважением лимитов из `options`.
+/// Активность по журналу событий в `[from_ms, to_ms)` для графика.
 ///
-/// В Dart: `ExtractionResult extractTextFromFile(String path, ExtractionOptions options)`.
-pub fn extract_text_from_file(path: String, options: ExtractionOptions) -> ExtractionResult {
-    logging::init_logging();
-
-    let internal_options = indexer::ExtractionOptions {
-        max_pages_per_pdf: options.max_pages_per_pdf,
-        max_file_size_mb: options.max_file_size_mb,
-    };
-
-    let result = indexer::extract_rich_content(Path::new(&path), &internal_options);
+/// Корзины отсчитываются от `from_ms` — для суток по местному времени
+/// передайте местную полночь. Возвращаются все корзины, в том числе пустые;
+/// события, сделанные самим ядром, не учитываются.
+pub fn get_activity_timeline(
+    from_ms: i64,
+    to_ms: i64,
+    granularity: TimelineGranularity,
+) -> Result<Vec<ActivityBucket>, LateraError> {
+    lifecycle::ensure_initialized()?;
 
-    ExtractionResult {
-        text: result.text,
-        content_type: result.content_type,
-        pages_extracted: result.pages_extracted,
-        error_code: result.error_code,
-    }
+    let buckets =
+        with_event_journal(|journal| journal.timeline(from_ms, to_ms, granularity.into()))?;
+    Ok(buckets.into_iter().map(ActivityBucket::from).collect())
 }
 
 // ============================================================================
-// Transcription API (Phase 2: Whisper)
+// Integrity check API
 // ============================================================================
 
-/// Опции транскрибации (FRB bridge type).
-///
-/// Передаются из Flutter-стороны для уважения пользовательских лимитов
-/// (`AppConfig.effectiveLimits`).
-#[derive(Clone, Debug)]
-pub struct TranscriptionOptions {
-    /// Максимальная длительность медиа для обработки (в минутах).
-    /// 0 = транскрибация отключена.
-    pub max_media_minutes: u32,
-    /// Максимальный размер файла в мегабайтах.
-    pub max_file_size_mb: u32,
-    /// Язык для транскрибации (ISO 639-1, например "ru", "en").
-    /// `None` = автоопределение.
-    pub language: Option<String>,
+/// Вид расхождения (см. [`verify_folder_integrity`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntegrityIssueKind {
+    /// Файл есть в поисковом индексе, но не на диске.
+    IndexedButMissing,
+    /// По журналу событий файл существует, но на диске его нет.
+    KnownButMissing,
+    /// Файл есть на диске, но журнал событий о нём не знает.
+    PresentButUnknown,
 }
 
-/// Результат транскрибации (FRB bridge type).
-#[derive(Clone, Debug)]
-pub struct TranscriptionResult {
-    /// Расшифрованный текст (может быть пуст при ошибке).
-    pub text: String,
-    /// Тип контента: `"audio"`, `"video"`, `"unsupported"`, `"unknown"`.
-    pub content_type: String,
-    /// Длительность обработанного медиа в секундах.
-    pub duration_seconds: u32,
-    /// Код ошибки (None = успех).
-    ///
-    /// Возможные значения:
-    /// - `"file_too_large"` — файл превышает лимит
-    /// - `"media_too_long"` — медиа превышает лимит длительности
-    /// - `"unsupported_format"` — формат не поддерживается
-    /// - `"transcription_disabled"` — транскрибация отключена (max_media_minutes=0)
-    /// - `"transcription_failed"` — внутренняя ошибка
-    /// - `"file_not_found"` — файл не найден
-    /// - `"not_implemented"` — Whisper ещё не подключён
-    pub error_code: Option<String>,
+impl From<integrity::IssueKind> for IntegrityIssueKind {
+    fn from(kind: integrity::IssueKind) -> Self {
+        match kind {
+            integrity::IssueKind::IndexedButMissing => Self::IndexedButMissing,
+            integrity::IssueKind::KnownButMissing => Self::KnownButMissing,
+            integrity::IssueKind::PresentButUnknown => Self::PresentButUnknown,
+        }
+    }
 }
 
-/// Транскрибировать аудио/видео файл.
-///
-/// Использует Rust-side транскрибацию (Whisper.cpp) с уважением лимитов.
-///
-/// В Dart: `TranscriptionResult transcribeAudio(String path, TranscriptionOptions options)`.
-pub fn transcribe_audio(path: String, options: TranscriptionOptions) -> TranscriptionResult {
-    logging::init_logging();
+/// Расхождение в папке watcher'а.
+#[derive(Clone, Debug)]
+pub struct IntegrityIssue {
+    pub watcher_id: String,
+    pub kind: IntegrityIssueKind,
+    pub full_path: String,
+}
 
-    let internal_options = indexer::TranscriptionOptions {
-        max_media_minutes: options.max_media_minutes,
-        max_file_size_mb: options.max_file_size_mb,
-        language: options.language,
+/// Исправляет расхождение: убирает запись из индекса или сообщает
+/// недостающее событие (с `reconciled = true`).
+fn repair_integrity_issue(
+    watcher_id: &str,
+    issue: &integrity::IntegrityIssue,
+) -> Result<(), LateraError> {
+    let (kind, event) = match issue.kind {
+        integrity::IssueKind::IndexedButMissing => {
+            return with_index_db(|conn| {
+                indexer::remove_file(conn, &issue.path.to_string_lossy()).map(|_| ())
+            });
+        }
+        integrity::IssueKind::KnownButMissing => {
+            let kind = file_watcher::FileEventKind::Removed;
+            (kind, file_watcher::make_file_gone_event(&issue.path, kind)?)
+        }
+        integrity::IssueKind::PresentButUnknown => {
+            let kind = file_watcher::FileEventKind::Created;
+            let event = file_watcher::make_internal_file_event(
+                &issue.path,
+                file_watcher::TimestampSource::default(),
+                kind,
+            )?;
+            (kind, event)
+        }
     };
-
-    let result = indexer::transcribe_audio(Path::new(&path), &internal_options);
-
-    TranscriptionResult {
-        text: result.text,
-        content_type: result.content_type,
-        duration_seconds: result.duration_seconds,
-        error_code: result.error_code,
+    let event = file_watcher::InternalFileEvent {
+        reconciled: true,
+        ..event
+    };
+    emit_file_event(watcher_id, &event);
+    if kind.is_arrival() {
+        emit_file_added(watcher_id, &event);
     }
+    handle_file_event(watcher_id, &event);
+    Ok(())
 }
 
-/// Обновить транскрипт файла в индексе.
+/// Сверить поисковый индекс, журнал событий и содержимое папок запущенных
+/// watcher'ов.
 ///
-/// Записывает текст транскрибации в отдельную колонку `transcript_text`.
-/// Если файл не найден в индексе — операция игнорируется.
-pub fn update_transcript(file_path: String, transcript: String) -> Result<(), LateraError> {
-    logging::init_logging();
-    with_index_db(|conn| indexer::update_transcript_text(conn, &file_path, &transcript))
+/// Возвращает найденные расхождения. С `repair = true` ядро их исправляет:
+/// удаляет из индекса записи об исчезнувших файлах и сообщает недостающие
+/// события (удаление — для исчезнувших файлов, появление — для неизвестных)
+/// в обычные stream'ы с `reconciled = true`. Обходит папки целиком —
+/// вызывать в background isolate.
+pub fn verify_folder_integrity(repair: bool) -> Result<Vec<IntegrityIssue>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let folders: Vec<_> = WATCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .iter()
+        .map(|(id, watcher)| {
+            (
+                id.clone(),
+                watcher.handle.watch_dir().to_path_buf(),
+                watcher.recursive,
+                watcher.filter.clone(),
+            )
+        })
+        .collect();
+    let known = with_event_journal(journal::EventJournal::known_files)?;
+    let indexed: Vec<PathBuf> = match with_index_db(indexer::indexed_paths) {
+        Ok(paths) => paths.into_iter().map(PathBuf::from).collect(),
+        Err(LateraError::IndexNotInitialized) => Vec::new(),
+        Err(e) => return Err(e),
+    };
+
+    let mut result = Vec::new();
+    for (watcher_id, root, recursive, filter) in folders {
+        let scope = integrity::FolderScope {
+            root: &root,
+            recursive,
+            filter: &filter,
+        };
+        let issues = integrity::find_issues(&scope, &indexed, &known)?;
+        if repair {
+            // Один файл может быть и в индексе, и в журнале — событие одно
+            let mut reported = std::collections::HashSet::new();
+            for issue in &issues {
+                let is_event = issue.kind != integrity::IssueKind::IndexedButMissing;
+                if is_event && !reported.insert(issue.path.clone()) {
+                    continue;
+                }
+                if let Err(e) = repair_integrity_issue(&watcher_id, issue) {
+                    log::warn!("Failed to repair {}: {e}", issue.path.display());
+                }
+            }
+        }
+        result.extend(issues.into_iter().map(|issue| IntegrityIssue {
+            watcher_id: watcher_id.clone(),
+            kind: issue.kind.into(),
+            full_path: issue.path.to_string_lossy().to_string(),
+        }));
+    }
+    if !result.is_empty() {
+        log::info!(
+            "Folder integrity check: {} issue(s){}",
+            result.len(),
+            if repair { ", repaired" } else { "" }
+        );
+    }
+    Ok(result)
 }
 
 // ============================================================================
-// Embeddings API (Phase 3)
+// Archive API
 // ============================================================================
 
-/// Текстовый чанк (FRB bridge type).
+/// Автоматическое архивирование старых файлов (FRB bridge type).
 #[derive(Clone, Debug)]
-pub struct ApiTextChunk {
-    /// Текст чанка.
-    pub text: String,
-    /// Индекс чанка в документе (0-based).
-    pub chunk_index: u32,
-    /// Смещение (байтовое) от начала документа.
-    pub chunk_offset: u32,
+pub struct ArchiveOptions {
+    /// Файлы, не менявшиеся дольше стольких дней, переносятся в архив.
+    pub older_than_days: u32,
+    /// Корень архива (абсолютный путь); `None` — папка `Archive` внутри
+    /// папки наблюдения.
+    pub archive_dir: Option<String>,
+    /// Период проверки в минутах (по умолчанию 60, минимум 1).
+    pub check_interval_minutes: Option<u32>,
 }
 
-/// Результат вычисления эмбеддинга (FRB bridge type).
+/// Файл, перенесённый в архив.
 #[derive(Clone, Debug)]
-pub struct ApiEmbeddingVector {
-    /// Индекс чанка.
-    pub chunk_index: u32,
-    /// Вектор эмбеддинга (f32).
-    pub vector: Vec<f32>,
+pub struct ApiArchivedFile {
+    pub original_path: String,
+    pub archived_path: String,
+    pub archived_at_ms: i64,
+    pub modified_at_ms: i64,
+    pub size_bytes: u64,
 }
 
-/// Результат similarity search (FRB bridge type).
-#[derive(Clone, Debug)]
-pub struct ApiSimilarityResult {
-    /// Путь к файлу.
-    pub file_path: String,
-    /// Имя файла.
-    pub file_name: String,
-    /// Текст чанка, наиболее похожего на запрос.
-    pub chunk_snippet: String,
-    /// Смещение чанка в документе.
-    pub chunk_offset: u32,
-    /// Косинусное сходство (0.0 – 1.0).
-    pub score: f64,
+impl From<archive::ArchivedFile> for ApiArchivedFile {
+    fn from(file: archive::ArchivedFile) -> Self {
+        Self {
+            original_path: file.original_path.to_string_lossy().to_string(),
+            archived_path: file.archived_path.to_string_lossy().to_string(),
+            archived_at_ms: file.archived_at_ms,
+            modified_at_ms: file.modified_at_ms,
+            size_bytes: file.size_bytes,
+        }
+    }
 }
 
-/// Разбить текст на чанки.
-///
-/// В Dart: `List<ApiTextChunk> chunkText(String text, int chunkSize, int chunkOverlap)`.
-pub fn chunk_text(text: String, chunk_size: u32, chunk_overlap: u32) -> Vec<ApiTextChunk> {
-    indexer::chunk_text(&text, chunk_size as usize, chunk_overlap as usize)
-        .into_iter()
-        .map(|c| ApiTextChunk {
-            text: c.text,
-            chunk_index: c.chunk_index,
-            chunk_offset: c.chunk_offset,
-        })
-        .collect()
+/// Политика архивирования и период проверки. `None` — выключено.
+static ARCHIVE_SETTINGS: Lazy<Mutex<Option<(archive::ArchivePolicy, std::time::Duration)>>> =
+    Lazy::new(|| Mutex::new(None));
+
+/// Хранилище переносов. Открывается при первой записи в папке данных ядра.
+static ARCHIVE_STORE: Lazy<Mutex<Option<archive::ArchiveStore>>> = Lazy::new(|| Mutex::new(None));
+
+fn with_archive_store<F, T>(f: F) -> Result<T, LateraError>
+where
+    F: FnOnce(&mut archive::ArchiveStore) -> Result<T, LateraError>,
+{
+    let mut guard = ARCHIVE_STORE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_none() {
+        let db_path = lifecycle::data_dir()?.join(archive::ARCHIVE_DB_FILE);
+        *guard = Some(archive::ArchiveStore::open(&db_path)?);
+    }
+    match guard.as_mut() {
+        Some(store) => f(store),
+        None => Err(LateraError::CoreNotInitialized),
+    }
 }
 
-/// Вычислить эмбеддинги для набора чанков.
-///
-/// **Stub**: возвращает детерминированные псевдо-эмбеддинги (hash-based).
-/// Полноценная модель будет подключена в будущем.
-///
-/// В Dart: `List<ApiEmbeddingVector> computeEmbeddings(List<ApiTextChunk> chunks)`.
-pub fn compute_embeddings(chunks: Vec<ApiTextChunk>) -> Vec<ApiEmbeddingVector> {
-    let internal: Vec<indexer::TextChunk> = chunks
-        .into_iter()
-        .map(|c| indexer::TextChunk {
-            text: c.text,
-            chunk_index: c.chunk_index,
-            chunk_offset: c.chunk_offset,
-        })
-        .collect();
+fn close_archive_store() {
+    let _dropped = ARCHIVE_STORE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+}
 
-    indexer::compute_embeddings(&internal)
-        .into_iter()
-        .map(|e| ApiEmbeddingVector {
-            chunk_index: e.chunk_index,
-            vector: e.vector,
-        })
-        .collect()
+fn record_archived(files: &[archive::ArchivedFile]) {
+    if let Err(e) = with_archive_store(|store| store.record(files)) {
+        log::warn!("Failed to record archived files: {e}");
+    }
 }
 
-/// Сохранить чанки и эмбеддинги для файла в БД.
-///
-/// Удаляет предыдущие эмбеддинги файла перед вставкой.
+/// Запустить архивирование папки watcher'а. `None` — архивирование выключено.
+fn start_archive_monitor(
+    watch_dir: PathBuf,
+) -> Result<Option<archive::ArchiveMonitorHandle>, LateraError> {
+    let settings = ARCHIVE_SETTINGS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone();
+    let Some((policy, interval)) = settings else {
+        return Ok(None);
+    };
+    let handle = archive::spawn_monitor(watch_dir, policy, interval, |files| {
+        record_archived(&files);
+    })?;
+    Ok(Some(handle))
+}
+
+/// Включить (или выключить — `None`) перенос старых файлов в архив
+/// `Archive/YYYY/MM/` по расписанию.
 ///
-/// В Dart: `void storeChunksAndEmbeddings(String filePath, List<ApiTextChunk> chunks, List<ApiEmbeddingVector> embeddings)`.
-pub fn store_chunks_and_embeddings(
-    file_path: String,
-    chunks: Vec<ApiTextChunk>,
-    embeddings: Vec<ApiEmbeddingVector>,
-) -> Result<(), LateraError> {
-    logging::init_logging();
+/// Год и месяц берутся из даты изменения файла. Каждый перенос
+/// записывается: найти файл по исходному пути — [`find_archived_file`].
+/// Недоступно в режиме «только наблюдение». Если watcher запущен,
+/// архивирование перезапускается с новыми настройками (первый проход — сразу).
+pub fn set_archive_options(options: Option<ArchiveOptions>) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
 
-    with_index_db(|conn| {
-        // Получаем file_id
-        let file_info = indexer::get_indexed_file(conn, &file_path)?;
-        let file_id = match file_info {
-            Some(info) => info.id,
-            None => {
-                return Err(LateraError::InvalidPath(format!(
-                    "File not found in index: {file_path}"
-                )))
+    let settings = match options {
+        Some(options) => {
+            read_only::ensure_writable("archiving old files")?;
+            if options.older_than_days == 0 {
+                return Err(LateraError::InvalidArgument(
+                    "older_than_days must be at least 1".to_string(),
+                ));
             }
-        };
+            let archive_root = options.archive_dir.map(PathBuf::from);
+            if archive_root.as_ref().is_some_and(|dir| !dir.is_absolute()) {
+                return Err(LateraError::InvalidArgument(
+                    "archive_dir must be an absolute path".to_string(),
+                ));
+            }
+            let interval = options
+                .check_interval_minutes
+                .map_or(archive::DEFAULT_ARCHIVE_CHECK_INTERVAL, |minutes| {
+                    std::time::Duration::from_secs(u64::from(minutes) * 60)
+                });
+            if interval < archive::MIN_ARCHIVE_CHECK_INTERVAL {
+                return Err(LateraError::InvalidArgument(format!(
+                    "archive check interval must be at least {} s",
+                    archive::MIN_ARCHIVE_CHECK_INTERVAL.as_secs()
+                )));
+            }
+            Some((
+                archive::ArchivePolicy {
+                    older_than_days: options.older_than_days,
+                    archive_root,
+                },
+                interval,
+            ))
+        }
+        None => None,
+    };
+    *ARCHIVE_SETTINGS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = settings;
 
-        let internal_chunks: Vec<indexer::TextChunk> = chunks
-            .iter()
-            .map(|c| indexer::TextChunk {
-                text: c.text.clone(),
-                chunk_index: c.chunk_index,
-                chunk_offset: c.chunk_offset,
-            })
-            .collect();
+    let mut watchers = WATCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    for watcher in watchers.values_mut() {
+        if let Some(previous) = watcher.archive_monitor.take() {
+            previous.stop();
+        }
+        watcher.archive_monitor = start_archive_monitor(watcher.handle.watch_dir().to_path_buf())?;
+    }
+    Ok(())
+}
 
-        let internal_embs: Vec<indexer::EmbeddingVector> = embeddings
-            .iter()
-            .map(|e| indexer::EmbeddingVector {
-                chunk_index: e.chunk_index,
-                vector: e.vector.clone(),
-            })
-            .collect();
+/// Архивировать старые файлы папки watcher'а `watcher_id` сейчас, не дожидаясь
+/// расписания. Требует настроек [`set_archive_options`]; возвращает перенесённые файлы.
+pub fn archive_old_files_now(watcher_id: String) -> Result<Vec<ApiArchivedFile>, LateraError> {
+    lifecycle::ensure_initialized()?;
 
-        indexer::store_chunks_and_embeddings(conn, file_id, &internal_chunks, &internal_embs)
-    })
+    let Some((policy, _)) = ARCHIVE_SETTINGS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone()
+    else {
+        return Err(LateraError::InvalidArgument(
+            "archiving is not configured".to_string(),
+        ));
+    };
+    let watch_dir = WATCHERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .get(&watcher_id)
+        .map(|w| w.handle.watch_dir().to_path_buf())
+        .ok_or(LateraError::WatcherNotRunning)?;
+
+    let files = archive::archive_old_files(&watch_dir, &policy, std::time::SystemTime::now())?;
+    record_archived(&files);
+    Ok(files.into_iter().map(ApiArchivedFile::from).collect())
 }
 
-/// Семантический поиск по запросу.
-///
-/// Вычисляет эмбеддинг запроса и ищет ближайшие чанки в БД.
+/// Где теперь лежит файл, перенесённый из `original_path` (последний перенос).
 ///
-/// В Dart: `List<ApiSimilarityResult> semanticSearch(String query, int topK)`.
-pub fn semantic_search(query: String, top_k: u32) -> Result<Vec<ApiSimilarityResult>, LateraError> {
-    logging::init_logging();
+/// `None` — файл не архивировался.
+pub fn find_archived_file(original_path: String) -> Result<Option<ApiArchivedFile>, LateraError> {
+    lifecycle::ensure_initialized()?;
 
-    with_index_db(|conn| {
-        let results = indexer::similarity_search(conn, &query, top_k as usize)?;
-        Ok(results
-            .into_iter()
-            .map(|r| ApiSimilarityResult {
-                file_path: r.file_path,
-                file_name: r.file_name,
-                chunk_snippet: r.chunk_snippet,
-                chunk_offset: r.chunk_offset,
-                score: r.score,
-            })
-            .collect())
-    })
+    let file = with_archive_store(|store| store.find(Path::new(&original_path)))?;
+    Ok(file.map(ApiArchivedFile::from))
 }
 
-/// Поиск файлов, похожих на указанный.
-///
-/// Берёт средний эмбеддинг чанков файла и ищет ближайшие в БД.
-///
-/// В Dart: `List<ApiSimilarityResult> findSimilarFiles(String filePath, int topK)`.
-pub fn find_similar_files(
-    file_path: String,
-    top_k: u32,
-) -> Result<Vec<ApiSimilarityResult>, LateraError> {
-    logging::init_logging();
-
-    with_index_db(|conn| {
-        let results = indexer::find_similar_files(conn, &file_path, top_k as usize)?;
-        Ok(results
-            .into_iter()
-            .map(|r| ApiSimilarityResult {
-                file_path: r.file_path,
-                file_name: r.file_name,
-                chunk_snippet: r.chunk_snippet,
-                chunk_offset: r.chunk_offset,
-                score: r.score,
-            })
-            .collect())
-    })
+/// Последние `limit` переносов в архив (от новых к старым).
+pub fn list_archived_files(limit: u32) -> Result<Vec<ApiArchivedFile>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let files = with_archive_store(|store| store.list(limit as usize))?;
+    Ok(files.into_iter().map(ApiArchivedFile::from).collect())
 }
 
-/// Проверить наличие эмбеддингов для файла.
-pub fn has_embeddings(file_path: String) -> Result<bool, LateraError> {
-    logging::init_logging();
-    with_index_db(|conn| indexer::has_embeddings(conn, &file_path))
+// ============================================================================
+// Audit log API
+// ============================================================================
+
+/// Действие ядра над файлом пользователя (FRB bridge type).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ApiAuditAction {
+    Move,
+    Rename,
+    Trash,
+    Delete,
+    Upload,
+    Quarantine,
 }
 
-/// Получить общее количество эмбеддингов в БД.
-pub fn get_embedding_count() -> Result<i64, LateraError> {
-    logging::init_logging();
-    with_index_db(|conn| indexer::get_embedding_count(conn))
+impl From<audit_log::AuditAction> for ApiAuditAction {
+    fn from(action: audit_log::AuditAction) -> Self {
+        match action {
+            audit_log::AuditAction::Move => Self::Move,
+            audit_log::AuditAction::Rename => Self::Rename,
+            audit_log::AuditAction::Trash => Self::Trash,
+            audit_log::AuditAction::Delete => Self::Delete,
+            audit_log::AuditAction::Upload => Self::Upload,
+            audit_log::AuditAction::Quarantine => Self::Quarantine,
+        }
+    }
 }
 
-/// Инициализировать semantic-модель (ONNX paraphrase-multilingual-MiniLM-L12-v2).
-///
-/// Скачивает модель при первом вызове и загружает в память.
-/// `data_dir` — путь к папке данных приложения (модель сохраняется
-/// в `{data_dir}/models/paraphrase-multilingual-MiniLM-L12-v2/`).
-///
-/// Тяжёлая операция — рекомендуется вызывать в background isolate.
-pub fn init_semantic_model(data_dir: String) -> Result<(), LateraError> {
-    logging::init_logging();
-    indexer::init_semantic_model(&data_dir)
+impl From<ApiAuditAction> for audit_log::AuditAction {
+    fn from(action: ApiAuditAction) -> Self {
+        match action {
+            ApiAuditAction::Move => Self::Move,
+            ApiAuditAction::Rename => Self::Rename,
+            ApiAuditAction::Trash => Self::Trash,
+            ApiAuditAction::Delete => Self::Delete,
+            ApiAuditAction::Upload => Self::Upload,
+            ApiAuditAction::Quarantine => Self::Quarantine,
+        }
+    }
 }
 
-/// Проверить, загружена ли semantic-модель.
-pub fn is_semantic_model_ready() -> bool {
-    indexer::is_semantic_model_ready()
+/// Запись журнала аудита.
+#[derive(Clone, Debug)]
+pub struct ApiAuditEntry {
+    pub id: i64,
+    /// Unix timestamp в миллисекундах.
+    pub at_ms: i64,
+    pub action: ApiAuditAction,
+    pub path: String,
+    /// Куда перенесён или отправлен файл.
+    pub target: Option<String>,
+    /// Правило (`intake.downloads`, `archive.older_than_days`,
+    /// `quota.cleanup_oldest`, `rules.{id}`…); `None` — действие запросило
+    /// приложение.
+    pub rule_id: Option<String>,
 }
 
-/// Выгрузить semantic-модель из памяти.
-pub fn unload_semantic_model() {
-    indexer::unload_semantic_model();
+impl From<audit_log::AuditEntry> for ApiAuditEntry {
+    fn from(entry: audit_log::AuditEntry) -> Self {
+        Self {
+            id: entry.id,
+            at_ms: entry.at_ms,
+            action: entry.action.into(),
+            path: entry.path.to_string_lossy().to_string(),
+            target: entry.target,
+            rule_id: entry.rule_id,
+        }
+    }
 }
 
-/// Получить текущую размерность эмбеддингов.
-///
-/// 384 если модель загружена, 64 (stub) если нет.
-pub fn get_embedding_dim() -> u32 {
-    indexer::current_embedding_dim() as u32
+/// Условия выборки [`get_audit_log`]. Пустые поля не ограничивают выборку.
+#[derive(Clone, Debug, Default)]
+pub struct AuditLogFilter {
+    pub action: Option<ApiAuditAction>,
+    /// Файл или папка: исходный путь или путь назначения под ней.
+    pub path: Option<String>,
+    pub rule_id: Option<String>,
+    /// Не раньше (включительно), Unix ms.
+    pub since_ms: Option<i64>,
+    /// Раньше (не включительно), Unix ms.
+    pub until_ms: Option<i64>,
+    /// Не больше стольких записей (`None` — все).
+    pub limit: Option<u32>,
 }
 
-/// Очистить все эмбеддинги из БД.
+/// Журнал аудита: что ядро само сделало с файлами пользователя (перенос,
+/// переименование, корзина, удаление, отправка, карантин), когда и по
+/// какому правилу.
 ///
-/// Используется при переключении режима (stub → ONNX) для пересчёта
-/// с новой размерностью.
-pub fn clear_all_embeddings() -> Result<(), LateraError> {
-    logging::init_logging();
-    with_index_db(|conn| indexer::clear_all_embeddings(conn))
+/// Записи от новых к старым. Журнал хранится в папке данных ядра и только
+/// дописывается.
+pub fn get_audit_log(filter: AuditLogFilter) -> Result<Vec<ApiAuditEntry>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let entries = audit_log::query(&audit_log::AuditFilter {
+        action: filter.action.map(Into::into),
+        path: filter.path.map(PathBuf::from),
+        rule_id: filter.rule_id,
+        since_ms: filter.since_ms,
+        until_ms: filter.until_ms,
+        limit: filter.limit.map(|limit| limit as usize),
+    })?;
+    Ok(entries.into_iter().map(ApiAuditEntry::from).collect())
 }
 
 // ============================================================================
-// Generative LLM API (Phase B: llama.cpp CPU-only)
+// Dry run API
 // ============================================================================
 
-/// Инициализировать генеративную LLM (llama.cpp, GGUF-модель).
-///
-/// Загружает модель из `{data_dir}/models/qwen2.5-3b-instruct-q4_k_m.gguf`.
-/// Безопасен для повторного вызова — если модель уже загружена, вернёт Ok.
+/// Действие, пропущенное из-за пробного запуска (см. [`set_dry_run_mode`]).
+#[derive(Clone, Debug)]
+pub struct DryRunActionEvent {
+    pub action: ApiAuditAction,
+    pub path: String,
+    /// Куда был бы перенесён файл.
+    pub target: Option<String>,
+    /// Правило, по которому выполнилось бы действие.
+    pub rule_id: String,
+    /// Unix timestamp в миллисекундах.
+    pub planned_at_ms: i64,
+}
+
+static DRY_RUN_SINK: Lazy<Mutex<Option<frb_generated::StreamSink<DryRunActionEvent>>>> =
+    Lazy::new(|| Mutex::new(None));
+
+/// Включить пробный запуск: автоматические действия (перенос по пресету
+/// приёма, архивирование, очистка по квоте) не выполняются, а сообщаются в
+/// [`on_dry_run_action`] и в лог.
 ///
-/// Тяжёлая операция (~1.7 ГБ в RAM) — вызывать в background isolate.
-pub fn init_llm(data_dir: String) -> Result<(), LateraError> {
-    logging::init_logging();
-    indexer::llm_engine::init_llm(&data_dir)
+/// Позволяет проверить настройки до включения. Действует сразу для всех
+/// watcher'ов и расписаний; в журнал аудита пропущенные действия не попадают.
+pub fn set_dry_run_mode(enabled: bool) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    dry_run::set_enabled(enabled);
+    log::info!("Dry run mode: {enabled}");
+    Ok(())
 }
 
-/// Выгрузить генеративную LLM из памяти.
+/// Включён ли пробный запуск.
+pub fn is_dry_run_mode() -> bool {
+    dry_run::is_enabled()
+}
+
+/// Stream действий, пропущенных из-за пробного запуска.
 ///
-/// Освобождает ~1.7 ГБ RAM. Вызывается при idle timeout или dispose.
-pub fn unload_llm() {
-    indexer::llm_engine::unload_llm();
+/// В Dart это будет выглядеть как `Stream<DryRunActionEvent> onDryRunAction()`.
+/// Один активный подписчик; stream закрывается при [`shutdown_core`].
+pub fn on_dry_run_action(sink: frb_generated::StreamSink<DryRunActionEvent>) {
+    let mut guard = DRY_RUN_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_some() {
+        warn!("on_dry_run_action called while previous stream is still bound; closing previous stream");
+    }
+    *guard = Some(sink);
+    dry_run::set_listener(Some(std::sync::Arc::new(emit_dry_run_action)));
 }
 
-/// Проверить, загружена ли генеративная LLM.
-pub fn is_llm_ready() -> bool {
-    indexer::llm_engine::is_llm_ready()
+fn close_dry_run_stream() {
+    dry_run::set_listener(None);
+    let _dropped = DRY_RUN_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+}
+
+fn emit_dry_run_action(planned: &dry_run::PlannedAction) {
+    if let Some(sink) = DRY_RUN_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+    {
+        let result = sink.add(DryRunActionEvent {
+            action: planned.action.into(),
+            path: planned.path.to_string_lossy().to_string(),
+            target: planned.target.clone(),
+            rule_id: planned.rule_id.clone(),
+            planned_at_ms: planned.planned_at_ms,
+        });
+        if let Err(e) = result {
+            log::warn!("Failed to emit dry run action (stream closed): {e}");
+        }
+    }
 }
 
 // ============================================================================
-// RAG API (Phase 4: Local RAG «Спроси папку»)
+// File rules API
 // ============================================================================
 
-/// Источник ответа RAG (FRB bridge type).
+/// Действие правила организации файлов (FRB bridge type).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FileRuleAction {
+    /// Перенести в подпапку (относительный путь) папки, где появился файл.
+    MoveToSubfolder { subfolder: String },
+    /// Переименовать по шаблону: `{name}`, `{ext}` (с точкой), `{date}`
+    /// (`YYYY-MM-DD`, по mtime), `{year}`, `{month}`, `{day}`.
+    Rename { template: String },
+    /// Пометить тегом (см. [`add_tag`]).
+    Tag { tag: String },
+    /// Не сообщать о файле и не обрабатывать его.
+    Ignore,
+}
+
+impl From<&rules::RuleAction> for FileRuleAction {
+    fn from(action: &rules::RuleAction) -> Self {
+        match action {
+            rules::RuleAction::MoveToSubfolder(subfolder) => Self::MoveToSubfolder {
+                subfolder: subfolder.to_string_lossy().to_string(),
+            },
+            rules::RuleAction::Rename(template) => Self::Rename {
+                template: template.clone(),
+            },
+            rules::RuleAction::Tag(tag) => Self::Tag { tag: tag.clone() },
+            rules::RuleAction::Ignore => Self::Ignore,
+        }
+    }
+}
+
+impl From<FileRuleAction> for rules::RuleAction {
+    fn from(action: FileRuleAction) -> Self {
+        match action {
+            FileRuleAction::MoveToSubfolder { subfolder } => {
+                Self::MoveToSubfolder(PathBuf::from(subfolder))
+            }
+            FileRuleAction::Rename { template } => Self::Rename(template),
+            FileRuleAction::Tag { tag } => Self::Tag(tag),
+            FileRuleAction::Ignore => Self::Ignore,
+        }
+    }
+}
+
+/// Правило организации файлов (FRB bridge type). Пустые условия не
+/// ограничивают выбор.
 #[derive(Clone, Debug)]
-pub struct ApiRagSource {
-    /// Путь к файлу-источнику.
-    pub file_path: String,
-    /// Фрагмент текста чанка (обрезанный сниппет).
-    pub chunk_snippet: String,
-    /// Смещение (байтовое) чанка в документе.
-    pub chunk_offset: u32,
+pub struct FileRule {
+    pub id: String,
+    /// Шаблоны имени файла (`*.pdf`, `IMG_*`); хотя бы один должен совпасть.
+    pub name_globs: Vec<String>,
+    /// MIME-типы по содержимому: `application/pdf` или `image/*`.
+    pub mime_types: Vec<String>,
+    pub min_size_bytes: Option<u64>,
+    pub max_size_bytes: Option<u64>,
+    pub action: FileRuleAction,
 }
 
-/// Результат RAG-запроса (FRB bridge type).
+impl From<&rules::FileRule> for FileRule {
+    fn from(rule: &rules::FileRule) -> Self {
+        Self {
+            id: rule.id.clone(),
+            name_globs: rule
+                .condition
+                .name_globs
+                .iter()
+                .map(|p| p.as_str().to_string())
+                .collect(),
+            mime_types: rule.condition.mime_types.clone(),
+            min_size_bytes: rule.condition.min_size,
+            max_size_bytes: rule.condition.max_size,
+            action: (&rule.action).into(),
+        }
+    }
+}
+
+/// Правило, выполненное для появившегося файла (см. [`on_rule_applied`]).
 #[derive(Clone, Debug)]
-pub struct RagQueryResult {
-    /// Сгенерированный ответ.
-    pub answer: String,
-    /// Код ошибки (None = успех).
-    ///
-    /// Возможные значения:
-    /// - `"no_relevant_chunks"` — не найдено релевантных чанков
-    /// - `"empty_question"` — пустой вопрос
-    /// - `"query_failed"` — ошибка при выполнении запроса
-    pub error_code: Option<String>,
-    /// Источники (чанки, из которых сформирован ответ).
-    pub sources: Vec<ApiRagSource>,
+pub struct RuleAppliedEvent {
+    pub watcher_id: String,
+    pub rule_id: String,
+    pub action: FileRuleAction,
+    /// Путь, по которому файл появился.
+    pub path: String,
+    /// Куда файл перенесён или как переименован.
+    pub new_path: Option<String>,
+    /// Unix timestamp в миллисекундах.
+    pub applied_at_ms: i64,
 }
 
-/// Выполнить RAG-запрос «Спроси свою папку».
-///
-/// Ищет релевантные чанки через similarity search и формирует ответ.
+/// Правила в порядке проверки.
+static FILE_RULES: Lazy<Mutex<rules::RuleSet>> =
+    Lazy::new(|| Mutex::new(rules::RuleSet::default()));
+
+static RULE_APPLIED_SINK: Lazy<Mutex<Option<frb_generated::StreamSink<RuleAppliedEvent>>>> =
+    Lazy::new(|| Mutex::new(None));
+
+/// Добавить правило в конец списка или заменить правило с тем же `id`.
 ///
-/// **Stub**: ответ — конкатенация найденных фрагментов.
-/// Полноценная LLM-генерация будет подключена в будущем.
+/// Для каждого появившегося файла выполняется первое подходящее правило.
+/// Перенос и переименование пишутся в журнал аудита как `rules.{id}`; при
+/// пробном запуске приходят в [`on_dry_run_action`], а файл не трогается.
+/// Действует сразу для всех watcher'ов.
+pub fn set_file_rule(rule: FileRule) -> Result<(), LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    let rule = rules::FileRule {
+        id: rule.id,
+        condition: rules::RuleCondition::new(
+            &rule.name_globs,
+            &rule.mime_types,
+            rule.min_size_bytes,
+            rule.max_size_bytes,
+        )?,
+        action: rule.action.into(),
+    };
+    rule.validate()?;
+    log::info!("File rule set: {} -> {:?}", rule.id, rule.action);
+    FILE_RULES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .upsert(rule);
+    Ok(())
+}
+
+/// Удалить правило. `false` — правила с таким `id` не было.
+pub fn remove_file_rule(rule_id: String) -> Result<bool, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    Ok(FILE_RULES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .remove(&rule_id))
+}
+
+/// Правила в порядке проверки.
+pub fn list_file_rules() -> Result<Vec<FileRule>, LateraError> {
+    lifecycle::ensure_initialized()?;
+
+    Ok(FILE_RULES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .rules()
+        .iter()
+        .map(FileRule::from)
+        .collect())
+}
+
+/// Stream выполненных правил.
 ///
-/// В Dart: `RagQueryResult ragQuery(String question, int topK)`.
-pub fn rag_query(question: String, top_k: u32) -> Result<RagQueryResult, LateraError> {
-    logging::init_logging();
+/// В Dart это будет выглядеть как `Stream<RuleAppliedEvent> onRuleApplied()`.
+/// Один активный подписчик; stream закрывается вместе с остальными streams
+/// watcher'ов.
+pub fn on_rule_applied(sink: frb_generated::StreamSink<RuleAppliedEvent>) {
+    let mut guard = RULE_APPLIED_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_some() {
+        warn!(
+            "on_rule_applied called while previous stream is still bound; closing previous stream"
+        );
+    }
+    *guard = Some(sink);
+}
 
-    with_index_db(|conn| {
-        let result = indexer::rag_query(conn, &question, top_k as usize)?;
-        Ok(RagQueryResult {
-            answer: result.answer,
-            error_code: result.error_code,
-            sources: result
-                .sources
-                .into_iter()
-                .map(|s| ApiRagSource {
-                    file_path: s.file_path,
-                    chunk_snippet: s.chunk_snippet,
-                    chunk_offset: s.chunk_offset,
-                })
-                .collect(),
-        })
-    })
+fn close_rule_applied_stream() {
+    let _dropped = RULE_APPLIED_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+}
+
+fn emit_rule_applied(event: RuleAppliedEvent) {
+    if let Some(sink) = RULE_APPLIED_SINK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+    {
+        if let Err(e) = sink.add(event) {
+            log::warn!("Failed to emit rule applied event (stream closed): {e}");
+        }
+    }
+}
+
+/// Выполняет первое подходящее правило для появившегося файла; если файл
+/// перенесён или переименован, событие дальше идёт с новым путём.
+/// `true` — файл игнорируется правилом и не сообщается.
+fn apply_file_rules(watcher_id: &str, event: &mut file_watcher::InternalFileEvent) -> bool {
+    if !event.kind.is_arrival() || event.self_generated {
+        return false;
+    }
+    // Файлом общей папки занимается экземпляр, который его забрал.
+    if let Some(claims::ClaimStatus::ClaimedByOther { .. }) = &event.claim {
+        return false;
+    }
+    let size = event
+        .size_bytes
+        .or_else(|| std::fs::metadata(&event.full_path).ok().map(|m| m.len()))
+        .unwrap_or(0);
+    let Some(rule) = FILE_RULES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .first_match(&event.full_path, size)
+        .cloned()
+    else {
+        return false;
+    };
+
+    let new_path = match rules::apply(&rule, &event.full_path) {
+        Ok(rules::RuleOutcome::Moved(new_path)) => Some(new_path),
+        Ok(rules::RuleOutcome::Unchanged)
+            if matches!(
+                rule.action,
+                rules::RuleAction::Tag(_) | rules::RuleAction::Ignore
+            ) =>
+        {
+            None
+        }
+        Ok(_) => return false,
+        Err(e) => {
+            warn!(
+                "Rule {} failed for {}: {e}",
+                rule.id,
+                event.full_path.display()
+            );
+            return false;
+        }
+    };
+    if let rules::RuleAction::Tag(tag) = &rule.action {
+        let tagged = watcher_scope(watcher_id)
+            .stores
+            .with_tags(|store| store.add(&event.full_path, tag, file_watcher::now_ms()));
+        if let Err(e) = tagged {
+            warn!(
+                "Rule {} failed to tag {}: {e}",
+                rule.id,
+                event.full_path.display()
+            );
+            return false;
+        }
+    }
+    log::info!("Rule {} applied to {}", rule.id, event.full_path.display());
+    emit_rule_applied(RuleAppliedEvent {
+        watcher_id: watcher_id.to_string(),
+        rule_id: rule.id.clone(),
+        action: (&rule.action).into(),
+        path: event.full_path.to_string_lossy().to_string(),
+        new_path: new_path.as_ref().map(|p| p.to_string_lossy().to_string()),
+        applied_at_ms: file_watcher::now_ms(),
+    });
+    if let Some(new_path) = new_path {
+        event.file_name = new_path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        event.extension = file_type::extension_of(&new_path);
+        event.full_path = new_path;
+    }
+    rule.action == rules::RuleAction::Ignore
 }
 
 // ============================================================================
-// OCR API (Phase 5: Optical Character Recognition)
+// Email API (.eml / .msg)
 // ============================================================================
 
-/// Опции OCR (FRB bridge type).
-///
-/// Передаются из Flutter-стороны для уважения пользовательских лимитов
-/// (`AppConfig.effectiveLimits`).
+/// Вложение письма (без содержимого).
 #[derive(Clone, Debug)]
-pub struct OcrOptions {
-    /// Максимальное количество страниц скан-PDF для обработки.
-    pub max_pages_per_pdf: u32,
-    /// Максимальный размер файла в мегабайтах.
-    pub max_file_size_mb: u32,
-    /// Язык OCR (ISO 639-1, например "rus", "eng").
-    /// `None` = автоопределение / eng по умолчанию.
-    pub language: Option<String>,
+pub struct ApiEmailAttachment {
+    pub file_name: String,
+    pub size_bytes: u64,
+    pub mime_type: Option<String>,
 }
 
-/// Результат OCR (FRB bridge type).
+/// Метаданные письма, перетащенного из почтового клиента.
 #[derive(Clone, Debug)]
-pub struct OcrResult {
-    /// Распознанный текст (может быть пуст при ошибке).
-    pub text: String,
-    /// Тип контента: `"image"`, `"scan_pdf"`, `"unsupported"`, `"unknown"`.
-    pub content_type: String,
-    /// Количество обработанных страниц.
-    pub pages_processed: u32,
-    /// Уверенность распознавания (0.0 – 1.0), `None` при ошибке.
-    pub confidence: Option<f64>,
-    /// Код ошибки (None = успех).
-    ///
-    /// Возможные значения:
-    /// - `"file_too_large"` — файл превышает лимит
-    /// - `"too_many_pages"` — скан-PDF превышает лимит страниц
-    /// - `"unsupported_format"` — формат не поддерживается
-    /// - `"ocr_failed"` — внутренняя ошибка OCR-движка
-    /// - `"file_not_found"` — файл не найден
-    /// - `"not_implemented"` — OCR ещё не подключён (stub)
-    /// - `"empty_image"` — изображение не содержит текста
-    pub error_code: Option<String>,
+pub struct ApiEmailMetadata {
+    pub subject: Option<String>,
+    /// `Имя <адрес>` или только адрес.
+    pub from: Option<String>,
+    pub to: Vec<String>,
+    pub cc: Vec<String>,
+    /// Дата отправки (Unix timestamp в миллисекундах).
+    pub date_ms: Option<i64>,
+    pub message_id: Option<String>,
+    pub attachments: Vec<ApiEmailAttachment>,
 }
 
-/// Извлечь текст из изображения или скан-PDF через OCR.
-///
-/// Использует Rust-side OCR (Tesseract C FFI / ONNX) с уважением лимитов.
-///
-/// **Stub**: выполняет валидацию входных данных, но возвращает
-/// `error_code = "not_implemented"` до подключения OCR-движка.
-///
-/// В Dart: `OcrResult ocrExtractText(String path, OcrOptions options)`.
-pub fn ocr_extract_text(path: String, options: OcrOptions) -> OcrResult {
-    logging::init_logging();
+impl From<email::EmailMessage> for ApiEmailMetadata {
+    fn from(message: email::EmailMessage) -> Self {
+        Self {
+            subject: message.subject,
+            from: message.from,
+            to: message.to,
+            cc: message.cc,
+            date_ms: message.date_ms,
+            message_id: message.message_id,
+            attachments: message
+                .attachments
+                .into_iter()
+                .map(|a| ApiEmailAttachment {
+                    size_bytes: a.data.len() as u64,
+                    file_name: a.file_name,
+                    mime_type: a.mime_type,
+                })
+                .collect(),
+        }
+    }
+}
 
-    let internal_options = indexer::OcrOptions {
-        max_pages_per_pdf: options.max_pages_per_pdf,
-        max_file_size_mb: options.max_file_size_mb,
-        language: options.language,
-    };
+/// Является ли файл письмом (`.eml` или `.msg`).
+pub fn is_email_file(path: String) -> bool {
+    email::is_email(Path::new(&path))
+}
 
-    let result = indexer::ocr_extract_text(Path::new(&path), &internal_options);
+/// Метаданные письма `.eml`/`.msg`: тема, отправитель, получатели, дата и
+/// список вложений.
+pub fn get_email_metadata(path: String) -> Result<ApiEmailMetadata, LateraError> {
+    telemetry::record(CounterKind::Feature, "email_metadata");
 
-    OcrResult {
-        text: result.text,
-        content_type: result.content_type,
-        pages_processed: result.pages_processed,
-        confidence: result.confidence,
-        error_code: result.error_code,
-    }
+    email::parse(Path::new(&path)).map(ApiEmailMetadata::from)
 }
 
-/// Проверить, поддерживается ли файл для OCR.
+/// Сохранить вложения письма в `target_dir` (по умолчанию — папка письма).
+/// Возвращает пути созданных файлов.
 ///
-/// В Dart: `bool isOcrSupported(String path)`.
-pub fn is_ocr_supported(path: String) -> bool {
-    indexer::is_ocr_supported(Path::new(&path))
+/// Созданные файлы не отмечаются как изменения ядра: в папке наблюдения
+/// они приходят обычными событиями `Created`.
+pub fn extract_email_attachments(
+    path: String,
+    target_dir: Option<String>,
+) -> Result<Vec<String>, LateraError> {
+    telemetry::record(CounterKind::Feature, "email_extract_attachments");
+
+    let path = PathBuf::from(path);
+    let target_dir = match target_dir {
+        Some(dir) => PathBuf::from(dir),
+        None => path
+            .parent()
+            .map(Path::to_path_buf)
+            .ok_or_else(|| LateraError::InvalidPath(path.to_string_lossy().to_string()))?,
+    };
+    let extracted = email::extract_attachments(&path, &target_dir)?;
+    Ok(extracted
+        .into_iter()
+        .map(|p| p.to_string_lossy().to_string())
+        .collect())
 }
@@ -0,0 +1,230 @@
+//! Перенос под наблюдение уже существующей папки.
+//!
+//! Пользователь может указать папку, которой давно пользуется. Её файлы
+//! watcher не видел: без подготовки поиск по ним пуст, а автоматика
+//! (превью, дубликаты, правила) о них не знает. Перенос обходит папку,
+//! снимает снимок (mtime и размеры — по нему следующий запуск не сочтёт
+//! файлы новыми), индексирует содержимое и отдаёт каждый файл событием
+//! `Created` с `reconciled = true` пачками по [`MAX_BATCH_SIZE`]. В конце
+//! папка помечается маркером (см. [`file_watcher::FolderMarker`]).
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::dir_snapshot::DirSnapshot;
+use crate::error::LateraError;
+use crate::file_watcher::{
+    self, FileEventKind, FolderMarker, InternalFileEvent, WatcherOptions, MAX_BATCH_SIZE,
+};
+use crate::internal_files;
+use crate::read_only;
+
+/// Папка для переноса.
+pub struct Adoption {
+    pub dir: PathBuf,
+    /// Обход, фильтр и смысл `occurred_at_ms` — как у будущего watcher'а.
+    pub options: WatcherOptions,
+    /// Куда сохранить снимок папки; `None` — сверка при запуске выключена.
+    pub snapshot_file: Option<PathBuf>,
+}
+
+/// Ход переноса.
+#[derive(Debug)]
+pub enum AdoptionUpdate {
+    /// Папка обойдена: столько файлов будет перенесено.
+    Scanned {
+        files_total: u64,
+    },
+    /// Очередная пачка отдана.
+    Progress {
+        files_done: u64,
+        files_total: u64,
+    },
+    Completed {
+        marker: FolderMarker,
+        files_total: u64,
+        /// Сколько файлов не удалось проиндексировать.
+        index_failures: u64,
+    },
+    /// Прервано остановкой ядра.
+    Cancelled {
+        files_done: u64,
+        files_total: u64,
+    },
+    Failed(LateraError),
+}
+
+/// Индексирование одного файла (см. [`crate::indexer::auto_index`]).
+pub type IndexFile<'a> = &'a dyn Fn(&Path) -> Result<(), LateraError>;
+
+impl Adoption {
+    /// Проверки, которые стоит сделать до запуска в фоне.
+    pub fn validate(&self) -> Result<(), LateraError> {
+        if !self.dir.is_absolute() {
+            return Err(LateraError::InvalidPath(format!(
+                "path must be absolute: {}",
+                self.dir.display()
+            )));
+        }
+        if !self.dir.is_dir() {
+            return Err(LateraError::InvalidPath(format!(
+                "not a directory: {}",
+                self.dir.display()
+            )));
+        }
+        // Маркер пишется в саму папку
+        read_only::ensure_writable("adopt folder")
+    }
+
+    /// Переносит папку; ход и результат — в `on_update`, события — в
+    /// `on_batch`. `cancel` проверяется между пачками.
+    pub fn run(
+        &self,
+        cancel: &AtomicBool,
+        index: Option<IndexFile<'_>>,
+        on_batch: impl Fn(Vec<InternalFileEvent>),
+        on_update: impl Fn(AdoptionUpdate),
+    ) {
+        match self.adopt(cancel, index, on_batch, &on_update) {
+            Ok(update) => on_update(update),
+            Err(e) => on_update(AdoptionUpdate::Failed(e)),
+        }
+    }
+
+    fn adopt(
+        &self,
+        cancel: &AtomicBool,
+        index: Option<IndexFile<'_>>,
+        on_batch: impl Fn(Vec<InternalFileEvent>),
+        on_update: &impl Fn(AdoptionUpdate),
+    ) -> Result<AdoptionUpdate, LateraError> {
+        self.validate()?;
+        let dir = &self.dir;
+        let snapshot = DirSnapshot::scan(dir, self.options.recursive, |path| {
+            !internal_files::is_internal(path) && self.options.filter.matches(dir, path)
+        })?;
+        let files_total = snapshot.len() as u64;
+        on_update(AdoptionUpdate::Scanned { files_total });
+
+        let paths: Vec<PathBuf> = snapshot.paths().map(|p| dir.join(p)).collect();
+        let mut files_done = 0;
+        let mut index_failures = 0;
+        for chunk in paths.chunks(MAX_BATCH_SIZE) {
+            if cancel.load(Ordering::Relaxed) {
+                return Ok(AdoptionUpdate::Cancelled {
+                    files_done,
+                    files_total,
+                });
+            }
+            let mut events = Vec::with_capacity(chunk.len());
+            for path in chunk {
+                if let Some(index) = index {
+                    if let Err(e) = index(path) {
+                        log::warn!("Adoption: cannot index {}: {e}", path.display());
+                        index_failures += 1;
+                    }
+                }
+                match file_watcher::make_internal_file_event(
+                    path,
+                    self.options.timestamp_source,
+                    FileEventKind::Created,
+                ) {
+                    Ok(mut event) => {
+                        event.reconciled = true;
+                        events.push(event);
+                    }
+                    // Файл исчез посреди переноса
+                    Err(e) => log::debug!("Adoption: skipping {}: {e}", path.display()),
+                }
+            }
+            files_done += chunk.len() as u64;
+            if !events.is_empty() {
+                on_batch(events);
+            }
+            on_update(AdoptionUpdate::Progress {
+                files_done,
+                files_total,
+            });
+        }
+
+        if let Some(snapshot_file) = &self.snapshot_file {
+            snapshot.save(snapshot_file)?;
+        }
+        let marker = file_watcher::ensure_folder_marker(dir)?;
+        log::info!(
+            "Adopted folder {} ({files_total} files, id {})",
+            dir.display(),
+            marker.folder_id
+        );
+        Ok(AdoptionUpdate::Completed {
+            marker,
+            files_total,
+            index_failures,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    #[test]
+    fn test_adoption_backfills_in_batches_and_marks_folder() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dir = temp_dir.path().join("Documents");
+        std::fs::create_dir_all(dir.join("2023")).unwrap();
+        for i in 0..(MAX_BATCH_SIZE + 5) {
+            std::fs::write(dir.join(format!("note-{i}.txt")), b"x").unwrap();
+        }
+        std::fs::write(dir.join("2023").join("report.pdf"), b"pdf").unwrap();
+        let snapshot_file = temp_dir.path().join("snapshot");
+
+        let adoption = Adoption {
+            dir: dir.clone(),
+            options: WatcherOptions {
+                recursive: true,
+                ..WatcherOptions::default()
+            },
+            snapshot_file: Some(snapshot_file.clone()),
+        };
+        let batches = Mutex::new(Vec::new());
+        let updates = Mutex::new(Vec::new());
+        let indexed = Mutex::new(0);
+        adoption.run(
+            &AtomicBool::new(false),
+            Some(&|_| {
+                *indexed.lock().unwrap() += 1;
+                Ok(())
+            }),
+            |events| batches.lock().unwrap().push(events),
+            |update| updates.lock().unwrap().push(update),
+        );
+
+        let total = MAX_BATCH_SIZE + 6;
+        let batches = batches.into_inner().unwrap();
+        assert_eq!(batches.len(), 2);
+        assert!(batches
+            .iter()
+            .flatten()
+            .all(|e| e.reconciled && e.kind == FileEventKind::Created));
+        assert_eq!(batches.iter().map(Vec::len).sum::<usize>(), total);
+        assert_eq!(indexed.into_inner().unwrap(), total);
+        let updates = updates.into_inner().unwrap();
+        assert!(matches!(
+            updates.last(),
+            Some(AdoptionUpdate::Completed { files_total, index_failures: 0, .. })
+                if *files_total == total as u64
+        ));
+        assert_eq!(
+            DirSnapshot::load(&snapshot_file).unwrap().unwrap().len(),
+            total
+        );
+        // Папка помечена как своя
+        assert!(matches!(
+            file_watcher::read_folder_marker(&dir),
+            file_watcher::MarkerState::Valid(_)
+        ));
+    }
+}
@@ -0,0 +1,434 @@
+//! Архивирование старых файлов по дате.
+//!
+//! Фоновый тред периодически переносит файлы старше N дней (по mtime) из
+//! наблюдаемой папки в иерархию `Archive/YYYY/MM/` — внутри папки наблюдения
+//! или в отдельной папке. Каждый перенос записывается в
+//! `{data_dir}/archive.db`, чтобы приложение находило файл по исходному пути,
+//! и в журнал аудита ([`crate::audit_log`]).
+//!
+//! В режиме «только наблюдение» ([`crate::read_only`]) файлы не переносятся;
+//! при пробном запуске ([`crate::dry_run`]) переносы только сообщаются.
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, Datelike, Local};
+use log::{debug, info, warn};
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::audit_log::{self, AuditAction};
+use crate::dry_run;
+use crate::error::LateraError;
+use crate::expected_changes;
+use crate::intake;
+use crate::internal_files;
+use crate::path_utils;
+use crate::read_only;
+
+/// Имя хранилища переносов в папке данных.
+pub const ARCHIVE_DB_FILE: &str = "archive.db";
+
+/// Правило архивирования в журнале аудита.
+pub const RULE_ID: &str = "archive.older_than_days";
+
+/// Папка архива внутри папки наблюдения (если другая не задана).
+pub const DEFAULT_ARCHIVE_DIR_NAME: &str = "Archive";
+
+/// Интервал проверки по умолчанию.
+pub const DEFAULT_ARCHIVE_CHECK_INTERVAL: Duration = Duration::from_hours(1);
+
+/// Минимально допустимый интервал проверки.
+pub const MIN_ARCHIVE_CHECK_INTERVAL: Duration = Duration::from_mins(1);
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Что и куда архивировать.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArchivePolicy {
+    /// Файлы, не менявшиеся дольше стольких дней, переносятся в архив.
+    pub older_than_days: u32,
+    /// Корень архива; `None` — [`DEFAULT_ARCHIVE_DIR_NAME`] внутри папки наблюдения.
+    pub archive_root: Option<PathBuf>,
+}
+
+impl ArchivePolicy {
+    /// Корень архива для папки `watch_dir`.
+    pub fn root_for(&self, watch_dir: &Path) -> PathBuf {
+        self.archive_root
+            .clone()
+            .unwrap_or_else(|| watch_dir.join(DEFAULT_ARCHIVE_DIR_NAME))
+    }
+}
+
+/// Перенесённый в архив файл.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArchivedFile {
+    pub original_path: PathBuf,
+    pub archived_path: PathBuf,
+    /// Unix timestamp в миллисекундах.
+    pub archived_at_ms: i64,
+    /// mtime файла (по нему выбраны год и месяц).
+    pub modified_at_ms: i64,
+    pub size_bytes: u64,
+}
+
+fn to_ms(time: SystemTime) -> i64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|d| i64::try_from(d.as_millis()).unwrap_or(i64::MAX))
+        .unwrap_or(0)
+}
+
+/// Скрытые и служебные файлы ([`internal_files`]) не архивируются.
+fn is_protected(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|name| name.starts_with('.') || internal_files::is_internal_name(name))
+}
+
+/// Файлы `watch_dir` (рекурсивно, без symlink'ов), кроме самого архива.
+fn list_candidates(
+    watch_dir: &Path,
+    archive_root: &Path,
+) -> Result<Vec<(PathBuf, std::fs::Metadata)>, LateraError> {
+    let mut files = Vec::new();
+    let mut pending = vec![watch_dir.to_path_buf()];
+    while let Some(current) = pending.pop() {
+        for entry in std::fs::read_dir(&current)? {
+            let Ok(entry) = entry else { continue };
+            let path = entry.path();
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if is_protected(&path) {
+                continue;
+            }
+            if file_type.is_dir() {
+                if !path_utils::paths_equal(&path, archive_root) {
+                    pending.push(path);
+                }
+            } else if file_type.is_file() {
+                if let Ok(metadata) = entry.metadata() {
+                    files.push((path, metadata));
+                }
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// Переносит файлы старше `policy.older_than_days` в `{root}/YYYY/MM/`
+/// (год и месяц — по mtime, в локальном времени).
+///
+/// Занятое имя не перезаписывается (` (1)`, ` (2)`…). Исчезновение файла
+/// отмечается как изменение ядра; появление в архиве — тоже, если архив
+/// внутри папки наблюдения. Файлы, которые не удалось перенести,
+/// пропускаются. При пробном запуске переносы только сообщаются
+/// ([`dry_run::report`]), а результат пуст.
+pub fn archive_old_files(
+    watch_dir: &Path,
+    policy: &ArchivePolicy,
+    now: SystemTime,
+) -> Result<Vec<ArchivedFile>, LateraError> {
+    read_only::ensure_writable("archiving old files")?;
+
+    let root = policy.root_for(watch_dir);
+    let inside_watch_dir = path_utils::is_within(watch_dir, &root);
+    let max_age = Duration::from_secs(u64::from(policy.older_than_days) * SECONDS_PER_DAY);
+    let cutoff = now.checked_sub(max_age).unwrap_or(UNIX_EPOCH);
+
+    let mut archived = Vec::new();
+    for (path, metadata) in list_candidates(watch_dir, &root)? {
+        let modified = metadata.modified().unwrap_or(now);
+        if modified >= cutoff {
+            continue;
+        }
+        let Some(file_name) = path.file_name() else {
+            continue;
+        };
+        let date = DateTime::<Local>::from(modified);
+        let target_dir = root
+            .join(format!("{:04}", date.year()))
+            .join(format!("{:02}", date.month()));
+        if dry_run::is_enabled() {
+            let target = intake::free_path(&target_dir.join(file_name));
+            dry_run::report(
+                AuditAction::Move,
+                &path,
+                Some(&target.to_string_lossy()),
+                RULE_ID,
+            );
+            continue;
+        }
+        match move_file(&path, &target_dir, file_name, inside_watch_dir) {
+            Ok(target) => {
+                info!("Archived {} -> {}", path.display(), target.display());
+                audit_log::record(
+                    AuditAction::Move,
+                    &path,
+                    Some(&target.to_string_lossy()),
+                    Some(RULE_ID),
+                );
+                archived.push(ArchivedFile {
+                    original_path: path,
+                    archived_path: target,
+                    archived_at_ms: to_ms(now),
+                    modified_at_ms: to_ms(modified),
+                    size_bytes: metadata.len(),
+                });
+            }
+            Err(e) => warn!("Failed to archive {}: {e}", path.display()),
+        }
+    }
+    Ok(archived)
+}
+
+/// Переносит `path` в `target_dir` под именем `file_name` (занятое имя не
+/// перезаписывается); `expect_target` — новый путь тоже изменение ядра.
+pub(crate) fn move_file(
+    path: &Path,
+    target_dir: &Path,
+    file_name: &std::ffi::OsStr,
+    expect_target: bool,
+) -> Result<PathBuf, LateraError> {
+    std::fs::create_dir_all(target_dir)?;
+    let target = intake::free_path(&target_dir.join(file_name));
+
+    expected_changes::expect(path);
+    if expect_target {
+        expected_changes::expect(&target);
+    }
+    let result = std::fs::rename(path, &target).or_else(|_| {
+        // Другой том: копируем и удаляем исходник
+        std::fs::copy(path, &target)?;
+        std::fs::remove_file(path)
+    });
+    if let Err(e) = result {
+        expected_changes::forget(path);
+        expected_changes::forget(&target);
+        return Err(e.into());
+    }
+    Ok(target)
+}
+
+/// Handle фонового треда архивирования.
+pub struct ArchiveMonitorHandle {
+    stop_tx: mpsc::Sender<()>,
+    join: thread::JoinHandle<()>,
+}
+
+impl ArchiveMonitorHandle {
+    /// Останавливает тред и дожидается его завершения.
+    pub fn stop(self) {
+        let _ = self.stop_tx.send(());
+        if self.join.join().is_err() {
+            warn!("Archive monitor thread panicked");
+        }
+    }
+}
+
+/// Запускает архивирование `watch_dir` сразу и затем раз в `interval`.
+///
+/// `on_archived` получает файлы, перенесённые за один проход (если есть).
+pub fn spawn_monitor<F>(
+    watch_dir: PathBuf,
+    policy: ArchivePolicy,
+    interval: Duration,
+    on_archived: F,
+) -> Result<ArchiveMonitorHandle, LateraError>
+where
+    F: Fn(Vec<ArchivedFile>) + Send + 'static,
+{
+    if interval < MIN_ARCHIVE_CHECK_INTERVAL {
+        return Err(LateraError::InvalidArgument(format!(
+            "archive check interval must be at least {} s",
+            MIN_ARCHIVE_CHECK_INTERVAL.as_secs()
+        )));
+    }
+
+    let (stop_tx, stop_rx) = mpsc::channel::<()>();
+    let join = thread::Builder::new()
+        .name("latera-archive".to_string())
+        .spawn(move || {
+            loop {
+                // Режим «только наблюдение» мог включиться после запуска
+                if !read_only::is_enabled() {
+                    match archive_old_files(&watch_dir, &policy, SystemTime::now()) {
+                        Ok(files) if files.is_empty() => {}
+                        Ok(files) => on_archived(files),
+                        Err(e) => debug!("Archiving failed: {e}"),
+                    }
+                }
+                match stop_rx.recv_timeout(interval) {
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Ok(()) | Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+            debug!("Archive monitor stopped");
+        })?;
+    Ok(ArchiveMonitorHandle { stop_tx, join })
+}
+
+/// Хранилище переносов: где теперь лежит файл.
+pub struct ArchiveStore {
+    conn: Connection,
+}
+
+impl ArchiveStore {
+    /// Открывает (или создаёт) хранилище.
+    pub fn open(db_path: &Path) -> Result<Self, LateraError> {
+        if let Some(parent) = db_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let conn = Connection::open(db_path)?;
+        conn.execute_batch(
+            "PRAGMA journal_mode=WAL;
+            CREATE TABLE IF NOT EXISTS archived_files (
+                archived_path TEXT PRIMARY KEY,
+                original_path TEXT NOT NULL,
+                archived_at_ms INTEGER NOT NULL,
+                modified_at_ms INTEGER NOT NULL,
+                size_bytes INTEGER NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS archived_files_original
+                ON archived_files (original_path);",
+        )?;
+        Ok(Self { conn })
+    }
+
+    /// Записывает переносы одной транзакцией.
+    pub fn record(&mut self, files: &[ArchivedFile]) -> Result<(), LateraError> {
+        let tx = self.conn.transaction()?;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT OR REPLACE INTO archived_files
+                    (archived_path, original_path, archived_at_ms, modified_at_ms, size_bytes)
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+            )?;
+            for file in files {
+                stmt.execute(params![
+                    file.archived_path.to_string_lossy(),
+                    file.original_path.to_string_lossy(),
+                    file.archived_at_ms,
+                    file.modified_at_ms,
+                    i64::try_from(file.size_bytes).unwrap_or(i64::MAX),
+                ])?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Последний перенос файла с исходным путём `original_path`.
+    pub fn find(&self, original_path: &Path) -> Result<Option<ArchivedFile>, LateraError> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT archived_path, original_path, archived_at_ms, modified_at_ms, size_bytes
+                 FROM archived_files WHERE original_path = ?1
+                 ORDER BY archived_at_ms DESC LIMIT 1",
+                params![original_path.to_string_lossy()],
+                row_to_file,
+            )
+            .optional()?)
+    }
+
+    /// Последние `limit` переносов (от новых к старым).
+    pub fn list(&self, limit: usize) -> Result<Vec<ArchivedFile>, LateraError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT archived_path, original_path, archived_at_ms, modified_at_ms, size_bytes
+             FROM archived_files ORDER BY archived_at_ms DESC, rowid DESC LIMIT ?1",
+        )?;
+        let files = stmt
+            .query_map(
+                params![i64::try_from(limit).unwrap_or(i64::MAX)],
+                row_to_file,
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(files)
+    }
+}
+
+fn row_to_file(row: &rusqlite::Row<'_>) -> rusqlite::Result<ArchivedFile> {
+    Ok(ArchivedFile {
+        archived_path: PathBuf::from(row.get::<_, String>(0)?),
+        original_path: PathBuf::from(row.get::<_, String>(1)?),
+        archived_at_ms: row.get(2)?,
+        modified_at_ms: row.get(3)?,
+        size_bytes: u64::try_from(row.get::<_, i64>(4)?).unwrap_or(0),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_file(path: &Path, modified: SystemTime) {
+        std::fs::write(path, b"data").unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(path)
+            .and_then(|f| f.set_modified(modified))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_archives_old_files_by_month() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let watch_dir = temp_dir.path();
+        std::fs::create_dir(watch_dir.join("sub")).unwrap();
+        let now = SystemTime::now();
+        let old = now - Duration::from_secs(40 * SECONDS_PER_DAY);
+        write_file(&watch_dir.join("old.pdf"), old);
+        write_file(&watch_dir.join("sub").join("old.txt"), old);
+        write_file(&watch_dir.join("new.pdf"), now);
+        write_file(&watch_dir.join(".hidden"), old);
+
+        let policy = ArchivePolicy {
+            older_than_days: 30,
+            archive_root: None,
+        };
+        let mut archived = archive_old_files(watch_dir, &policy, now).unwrap();
+        archived.sort_by(|a, b| a.original_path.cmp(&b.original_path));
+        assert_eq!(archived.len(), 2);
+
+        let date = DateTime::<Local>::from(old);
+        let month_dir = watch_dir
+            .join(DEFAULT_ARCHIVE_DIR_NAME)
+            .join(format!("{:04}", date.year()))
+            .join(format!("{:02}", date.month()));
+        assert_eq!(archived[0].archived_path, month_dir.join("old.pdf"));
+        assert_eq!(archived[1].archived_path, month_dir.join("old.txt"));
+        assert!(month_dir.join("old.pdf").exists());
+        assert!(!watch_dir.join("old.pdf").exists());
+        assert!(watch_dir.join("new.pdf").exists());
+        assert!(watch_dir.join(".hidden").exists());
+
+        // Архив внутри папки наблюдения повторно не архивируется
+        assert!(archive_old_files(watch_dir, &policy, now)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_store_finds_latest_location() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut store = ArchiveStore::open(&temp_dir.path().join(ARCHIVE_DB_FILE)).unwrap();
+        let file = |archived: &str, at: i64| ArchivedFile {
+            original_path: PathBuf::from("/w/a.pdf"),
+            archived_path: PathBuf::from(archived),
+            archived_at_ms: at,
+            modified_at_ms: 1,
+            size_bytes: 4,
+        };
+        store
+            .record(&[file("/a/2023/01/a.pdf", 10), file("/a/2024/05/a.pdf", 20)])
+            .unwrap();
+
+        let found = store.find(Path::new("/w/a.pdf")).unwrap().unwrap();
+        assert_eq!(found.archived_path, PathBuf::from("/a/2024/05/a.pdf"));
+        assert_eq!(store.find(Path::new("/w/b.pdf")).unwrap(), None);
+        assert_eq!(store.list(1).unwrap(), vec![found]);
+    }
+}
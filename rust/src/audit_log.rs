@@ -0,0 +1,336 @@
+//! Журнал аудита действий ядра над файлами пользователя.
+//!
+//! Каждый перенос, переименование, удаление, отправка и помещение в
+//! карантин, которые ядро выполняет само (по пресету, расписанию, квоте,
+//! правилу), записывается с моментом и идентификатором правила в
+//! `{data_dir}/audit.db`. Журнал только дописывается: изменить или удалить
+//! запись не даёт сама база (триггеры).
+//!
+//! Запись не должна мешать действию: ошибка журнала только логируется.
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use rusqlite::{params, Connection};
+
+use crate::error::LateraError;
+use crate::file_watcher;
+use crate::lifecycle;
+
+/// Имя журнала аудита в папке данных.
+pub const AUDIT_DB_FILE: &str = "audit.db";
+
+/// Журнал текущего запуска. Открывается при первой записи.
+static LOG: Lazy<Mutex<Option<AuditLog>>> = Lazy::new(|| Mutex::new(None));
+
+/// Что ядро сделало с файлом.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AuditAction {
+    /// Перенесён в другую папку.
+    Move,
+    /// Переименован в той же папке.
+    Rename,
+    /// Перемещён в корзину ОС.
+    Trash,
+    /// Удалён безвозвратно.
+    Delete,
+    /// Отправлен во внешний сервис.
+    Upload,
+    /// Помещён в карантин.
+    Quarantine,
+}
+
+impl AuditAction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AuditAction::Move => "move",
+            AuditAction::Rename => "rename",
+            AuditAction::Trash => "trash",
+            AuditAction::Delete => "delete",
+            AuditAction::Upload => "upload",
+            AuditAction::Quarantine => "quarantine",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "move" => Some(AuditAction::Move),
+            "rename" => Some(AuditAction::Rename),
+            "trash" => Some(AuditAction::Trash),
+            "delete" => Some(AuditAction::Delete),
+            "upload" => Some(AuditAction::Upload),
+            "quarantine" => Some(AuditAction::Quarantine),
+            _ => None,
+        }
+    }
+}
+
+/// Запись журнала.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditEntry {
+    /// Порядковый номер записи (растёт монотонно).
+    pub id: i64,
+    /// Unix timestamp в миллисекундах.
+    pub at_ms: i64,
+    pub action: AuditAction,
+    pub path: PathBuf,
+    /// Куда перенесён или отправлен файл (для [`AuditAction::Move`],
+    /// [`AuditAction::Rename`], [`AuditAction::Upload`]).
+    pub target: Option<String>,
+    /// Правило, по которому выполнено действие; `None` — по прямому
+    /// запросу приложения.
+    pub rule_id: Option<String>,
+}
+
+/// Условия выборки. Пустые поля не ограничивают выборку.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AuditFilter {
+    pub action: Option<AuditAction>,
+    /// Файл или папка: запись подходит, если под ней лежит исходный путь
+    /// или путь назначения.
+    pub path: Option<PathBuf>,
+    pub rule_id: Option<String>,
+    /// Не раньше (включительно), Unix ms.
+    pub since_ms: Option<i64>,
+    /// Раньше (не включительно), Unix ms.
+    pub until_ms: Option<i64>,
+    /// Не больше стольких записей (`None` — все).
+    pub limit: Option<usize>,
+}
+
+/// Записывает действие в журнал текущего запуска.
+pub fn record(action: AuditAction, path: &Path, target: Option<&str>, rule_id: Option<&str>) {
+    let result = with_log(|log| log.append(action, path, target, rule_id, file_watcher::now_ms()));
+    if let Err(e) = result {
+        log::warn!(
+            "Failed to audit {} of {}: {e}",
+            action.as_str(),
+            path.display()
+        );
+    }
+}
+
+/// Записи журнала по фильтру (от новых к старым).
+pub fn query(filter: &AuditFilter) -> Result<Vec<AuditEntry>, LateraError> {
+    with_log(|log| log.query(filter))
+}
+
+/// Закрывает журнал (вызывается из `shutdown_core`).
+pub fn close() {
+    let _dropped = LOG
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+}
+
+fn with_log<F, T>(f: F) -> Result<T, LateraError>
+where
+    F: FnOnce(&AuditLog) -> Result<T, LateraError>,
+{
+    let mut guard = LOG
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if guard.is_none() {
+        let db_path = lifecycle::data_dir()?.join(AUDIT_DB_FILE);
+        *guard = Some(AuditLog::open(&db_path)?);
+    }
+    match guard.as_ref() {
+        Some(log) => f(log),
+        None => Err(LateraError::CoreNotInitialized),
+    }
+}
+
+/// Хранилище журнала.
+pub struct AuditLog {
+    conn: Connection,
+}
+
+impl AuditLog {
+    /// Открывает (или создаёт) журнал.
+    pub fn open(db_path: &Path) -> Result<Self, LateraError> {
+        if let Some(parent) = db_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let conn = Connection::open(db_path)?;
+        conn.execute_batch(
+            "PRAGMA journal_mode=WAL;
+            CREATE TABLE IF NOT EXISTS audit_log (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                at_ms INTEGER NOT NULL,
+                action TEXT NOT NULL,
+                path TEXT NOT NULL,
+                target TEXT,
+                rule_id TEXT
+            );
+            CREATE INDEX IF NOT EXISTS audit_log_at ON audit_log (at_ms);
+            CREATE TRIGGER IF NOT EXISTS audit_log_no_update
+                BEFORE UPDATE ON audit_log
+                BEGIN SELECT RAISE(ABORT, 'audit log is append-only'); END;
+            CREATE TRIGGER IF NOT EXISTS audit_log_no_delete
+                BEFORE DELETE ON audit_log
+                BEGIN SELECT RAISE(ABORT, 'audit log is append-only'); END;",
+        )?;
+        Ok(Self { conn })
+    }
+
+    /// Дописывает запись; возвращает её номер.
+    pub fn append(
+        &self,
+        action: AuditAction,
+        path: &Path,
+        target: Option<&str>,
+        rule_id: Option<&str>,
+        at_ms: i64,
+    ) -> Result<i64, LateraError> {
+        self.conn.execute(
+            "INSERT INTO audit_log (at_ms, action, path, target, rule_id)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                at_ms,
+                action.as_str(),
+                path.to_string_lossy(),
+                target,
+                rule_id
+            ],
+        )?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    /// Записи по фильтру (от новых к старым).
+    pub fn query(&self, filter: &AuditFilter) -> Result<Vec<AuditEntry>, LateraError> {
+        let path = filter
+            .path
+            .as_ref()
+            .map(|p| p.to_string_lossy().to_string());
+        // Папка: всё, что под ней (`/a/b` не должно задевать `/a/bc`).
+        let under_path = path.as_ref().map(|p| {
+            format!(
+                "{}{}",
+                p.trim_end_matches(['/', '\\']),
+                std::path::MAIN_SEPARATOR
+            )
+        });
+        let mut stmt = self.conn.prepare(
+            "SELECT id, at_ms, action, path, target, rule_id FROM audit_log
+             WHERE (?1 IS NULL OR action = ?1)
+               AND (?2 IS NULL OR path = ?2 OR target = ?2
+                    OR substr(path, 1, length(?3)) = ?3
+                    OR substr(target, 1, length(?3)) = ?3)
+               AND (?4 IS NULL OR rule_id = ?4)
+               AND (?5 IS NULL OR at_ms >= ?5)
+               AND (?6 IS NULL OR at_ms < ?6)
+             ORDER BY id DESC LIMIT ?7",
+        )?;
+        let entries = stmt
+            .query_map(
+                params![
+                    filter.action.map(AuditAction::as_str),
+                    path,
+                    under_path,
+                    filter.rule_id,
+                    filter.since_ms,
+                    filter.until_ms,
+                    filter
+                        .limit
+                        .map_or(-1, |limit| i64::try_from(limit).unwrap_or(i64::MAX)),
+                ],
+                |row| {
+                    Ok((
+                        row.get::<_, i64>(0)?,
+                        row.get::<_, i64>(1)?,
+                        row.get::<_, String>(2)?,
+                        row.get::<_, String>(3)?,
+                        row.get::<_, Option<String>>(4)?,
+                        row.get::<_, Option<String>>(5)?,
+                    ))
+                },
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(entries
+            .into_iter()
+            .filter_map(|(id, at_ms, action, path, target, rule_id)| {
+                Some(AuditEntry {
+                    id,
+                    at_ms,
+                    action: AuditAction::parse(&action)?,
+                    path: PathBuf::from(path),
+                    target,
+                    rule_id,
+                })
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_is_append_only_and_filterable() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let log = AuditLog::open(&temp_dir.path().join(AUDIT_DB_FILE)).unwrap();
+        let dir = temp_dir.path().join("w");
+        let other = temp_dir.path().join("w2");
+        let moved = dir.join("a.pdf").to_string_lossy().to_string();
+        log.append(
+            AuditAction::Move,
+            &temp_dir.path().join("Downloads").join("a.pdf"),
+            Some(&moved),
+            Some("intake.downloads"),
+            10,
+        )
+        .unwrap();
+        log.append(
+            AuditAction::Delete,
+            &other.join("b.pdf"),
+            None,
+            Some("quota.cleanup_oldest"),
+            20,
+        )
+        .unwrap();
+        log.append(AuditAction::Quarantine, &dir.join("c.exe"), None, None, 30)
+            .unwrap();
+
+        let all = log.query(&AuditFilter::default()).unwrap();
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[0].action, AuditAction::Quarantine);
+
+        // Папка `w` — по исходному пути или по пути назначения, но не `w2`.
+        let in_dir = log
+            .query(&AuditFilter {
+                path: Some(dir.clone()),
+                ..AuditFilter::default()
+            })
+            .unwrap();
+        let actions: Vec<_> = in_dir.iter().map(|e| e.action).collect();
+        assert_eq!(actions, vec![AuditAction::Quarantine, AuditAction::Move]);
+
+        let by_rule = log
+            .query(&AuditFilter {
+                rule_id: Some("quota.cleanup_oldest".to_string()),
+                since_ms: Some(15),
+                until_ms: Some(25),
+                ..AuditFilter::default()
+            })
+            .unwrap();
+        assert_eq!(by_rule.len(), 1);
+        assert_eq!(by_rule[0].path, other.join("b.pdf"));
+
+        let latest = log
+            .query(&AuditFilter {
+                limit: Some(1),
+                ..AuditFilter::default()
+            })
+            .unwrap();
+        assert_eq!(latest.len(), 1);
+
+        assert!(log.conn.execute("DELETE FROM audit_log", []).is_err());
+        assert!(log
+            .conn
+            .execute("UPDATE audit_log SET rule_id = NULL", [])
+            .is_err());
+        assert_eq!(log.query(&AuditFilter::default()).unwrap().len(), 3);
+    }
+}
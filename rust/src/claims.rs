@@ -0,0 +1,223 @@
+//! Согласование нескольких экземпляров Latera в общей (сетевой) папке.
+//!
+//! Когда одну папку наблюдают несколько пользователей, появившийся файл
+//! «забирает» тот экземпляр, который первым создал рядом с ним claim-файл
+//! `.latera-claim-<hash имени>`. Создание атомарно (`create_new`, в том числе
+//! на SMB/NFS), поэтому автоматические действия над файлом (перенос по
+//! пресету и т.п.) выполняет только один экземпляр; остальные видят
+//! [`ClaimStatus::ClaimedByOther`].
+//!
+//! Блокировка рекомендательная: claim-файлы видны только экземплярам Latera.
+//! Claim старше [`CLAIM_TTL`] считается брошенным (экземпляр упал) и может
+//! быть перехвачен. Claim удаляется владельцем, когда файл уходит из папки.
+
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use serde_json::{json, Value};
+
+use crate::error::LateraError;
+use crate::file_watcher;
+use crate::internal_files;
+use crate::read_only;
+
+/// Через сколько claim без владельца можно перехватить.
+pub const CLAIM_TTL: Duration = Duration::from_mins(10);
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Кто забирает файлы: `пользователь@машина` (одинаково между запусками).
+static INSTANCE_ID: Lazy<String> = Lazy::new(|| {
+    let user = std::env::var("USERNAME")
+        .or_else(|_| std::env::var("USER"))
+        .unwrap_or_else(|_| "unknown".to_string());
+    let host = sysinfo::System::host_name().unwrap_or_else(|| "unknown".to_string());
+    format!("{user}@{host}")
+});
+
+/// Кому достался файл.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClaimStatus {
+    /// Файл забран этим экземпляром — его можно обрабатывать.
+    Claimed,
+    /// Файл уже забран другим экземпляром.
+    ClaimedByOther { owner: String, claimed_at_ms: i64 },
+    /// Claim-файл не создать (нет прав на запись, режим «только
+    /// наблюдение»): согласование для этого файла не работает.
+    Unavailable { reason: String },
+}
+
+/// Включить или выключить claim-файлы для появившихся файлов.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Включены ли claim-файлы.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Идентификатор этого экземпляра в claim-файлах.
+pub fn instance_id() -> &'static str {
+    &INSTANCE_ID
+}
+
+/// Путь claim-файла для `file` (рядом с ним).
+pub fn claim_path(file: &Path) -> Option<PathBuf> {
+    let name = file.file_name()?;
+    let hash = blake3::hash(name.as_encoded_bytes()).to_hex();
+    let claim_name = internal_files::internal_file_name(&format!("claim-{}", &hash[..16]));
+    Some(file.with_file_name(claim_name))
+}
+
+/// Забрать `file`, если его ещё не забрал другой экземпляр.
+pub fn try_claim(file: &Path) -> ClaimStatus {
+    if read_only::is_enabled() {
+        return ClaimStatus::Unavailable {
+            reason: "read-only mode".to_string(),
+        };
+    }
+    let Some(path) = claim_path(file) else {
+        return ClaimStatus::Unavailable {
+            reason: format!("no file name: {}", file.display()),
+        };
+    };
+
+    // Вторая попытка — после перехвата брошенного claim'а.
+    for _ in 0..2 {
+        match create_claim(&path, file) {
+            Ok(()) => return ClaimStatus::Claimed,
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {}
+            Err(e) => {
+                return ClaimStatus::Unavailable {
+                    reason: e.to_string(),
+                }
+            }
+        }
+        let (owner, claimed_at_ms) = read_claim(&path);
+        if owner == instance_id() {
+            return ClaimStatus::Claimed;
+        }
+        let age_ms = file_watcher::now_ms().saturating_sub(claimed_at_ms);
+        if u128::try_from(age_ms).unwrap_or(0) < CLAIM_TTL.as_millis() || !take_over(&path) {
+            return ClaimStatus::ClaimedByOther {
+                owner,
+                claimed_at_ms,
+            };
+        }
+        log::info!("Took over stale claim of {owner} for {}", file.display());
+    }
+    let (owner, claimed_at_ms) = read_claim(&path);
+    ClaimStatus::ClaimedByOther {
+        owner,
+        claimed_at_ms,
+    }
+}
+
+/// Удалить claim `file`, если он принадлежит этому экземпляру.
+/// Возвращает `true`, если claim был удалён.
+pub fn release(file: &Path) -> Result<bool, LateraError> {
+    let Some(path) = claim_path(file) else {
+        return Ok(false);
+    };
+    if !path.exists() || read_claim(&path).0 != instance_id() {
+        return Ok(false);
+    }
+    match std::fs::remove_file(&path) {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn create_claim(path: &Path, file: &Path) -> std::io::Result<()> {
+    let mut claim = OpenOptions::new().write(true).create_new(true).open(path)?;
+    let record = json!({
+        "owner": instance_id(),
+        "claimed_at_ms": file_watcher::now_ms(),
+        "file": file.file_name().map(|name| name.to_string_lossy()),
+    });
+    writeln!(claim, "{record}")?;
+    claim.sync_all()
+}
+
+/// Владелец и время claim'а. Claim, который ещё дописывается или
+/// повреждён, датируется по mtime.
+fn read_claim(path: &Path) -> (String, i64) {
+    let record = std::fs::read_to_string(path)
+        .ok()
+        .and_then(|text| serde_json::from_str::<Value>(&text).ok());
+    let owner = record
+        .as_ref()
+        .and_then(|r| r["owner"].as_str())
+        .unwrap_or("unknown")
+        .to_string();
+    let claimed_at_ms = record
+        .as_ref()
+        .and_then(|r| r["claimed_at_ms"].as_i64())
+        .or_else(|| file_watcher::file_times_ms(path).1)
+        .unwrap_or_else(file_watcher::now_ms);
+    (owner, claimed_at_ms)
+}
+
+/// Убирает брошенный claim. Переименование атомарно: из нескольких
+/// экземпляров, решивших перехватить claim, это удаётся только одному.
+fn take_over(path: &Path) -> bool {
+    let mut stale = path.as_os_str().to_os_string();
+    stale.push(format!(".stale-{}", std::process::id()));
+    if std::fs::rename(path, &stale).is_err() {
+        return false;
+    }
+    if let Err(e) = std::fs::remove_file(&stale) {
+        log::warn!(
+            "Failed to remove stale claim {}: {e}",
+            Path::new(&stale).display()
+        );
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_claim_is_exclusive_and_released_by_owner() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let file = dir.path().join("report.pdf");
+        std::fs::write(&file, b"pdf").unwrap();
+        let claim = claim_path(&file).unwrap();
+        assert!(internal_files::is_internal(&claim));
+
+        assert_eq!(try_claim(&file), ClaimStatus::Claimed);
+        // Повторное событие того же файла — claim уже наш.
+        assert_eq!(try_claim(&file), ClaimStatus::Claimed);
+
+        // Свежий claim другого экземпляра.
+        std::fs::write(
+            &claim,
+            json!({ "owner": "bob@pc-2", "claimed_at_ms": file_watcher::now_ms() }).to_string(),
+        )
+        .unwrap();
+        assert!(matches!(
+            try_claim(&file),
+            ClaimStatus::ClaimedByOther { owner, .. } if owner == "bob@pc-2"
+        ));
+        assert!(!release(&file).unwrap());
+        assert!(claim.exists());
+
+        // Брошенный claim перехватывается.
+        std::fs::write(
+            &claim,
+            json!({ "owner": "bob@pc-2", "claimed_at_ms": 0 }).to_string(),
+        )
+        .unwrap();
+        assert_eq!(try_claim(&file), ClaimStatus::Claimed);
+        assert!(release(&file).unwrap());
+        assert!(!claim.exists());
+    }
+}
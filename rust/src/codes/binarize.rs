@@ -0,0 +1,172 @@
+//! Бинаризация изображения.
+//!
+//! Порог считается по блокам 8×8 и сглаживается по окрестности 5×5 блоков
+//! (как `HybridBinarizer` в ZXing): неравномерное освещение фотографий не
+//! мешает, а однотонные области (центр крупного finder pattern) не
+//! «проваливаются» в шум.
+
+/// Сторона блока.
+const BLOCK_SIZE: usize = 8;
+
+/// Блоки с меньшим разбросом яркости считаются однотонными.
+const MIN_DYNAMIC_RANGE: u32 = 24;
+
+/// Изображения меньше этого размера бинаризуются глобальным порогом.
+const MIN_LOCAL_SIZE: usize = BLOCK_SIZE * 5;
+
+/// Бинарное изображение: `true` — тёмный пиксель.
+#[derive(Clone, Debug)]
+pub struct BitImage {
+    width: usize,
+    height: usize,
+    bits: Vec<bool>,
+}
+
+impl BitImage {
+    /// Бинаризует изображение в оттенках серого (`luma` — строки подряд).
+    pub fn from_luma(width: usize, height: usize, luma: &[u8]) -> Self {
+        debug_assert_eq!(luma.len(), width * height);
+        let bits = if width < MIN_LOCAL_SIZE || height < MIN_LOCAL_SIZE {
+            global_threshold(luma)
+        } else {
+            local_threshold(width, height, luma)
+        };
+        Self {
+            width,
+            height,
+            bits,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn from_bits(width: usize, height: usize, bits: Vec<bool>) -> Self {
+        Self {
+            width,
+            height,
+            bits,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.bits[y * self.width + x]
+    }
+
+    /// Пиксель по вещественным координатам; `None` — за пределами изображения.
+    #[allow(clippy::cast_sign_loss)]
+    pub fn sample(&self, x: f32, y: f32) -> Option<bool> {
+        // Центр модуля на самой границе допускается: округление вниз
+        // оставляет его внутри.
+        if !(x >= -0.5 && y >= -0.5) {
+            return None;
+        }
+        let (x, y) = (x.max(0.0) as usize, y.max(0.0) as usize);
+        (x < self.width && y < self.height).then(|| self.get(x, y))
+    }
+}
+
+fn global_threshold(luma: &[u8]) -> Vec<bool> {
+    if luma.is_empty() {
+        return Vec::new();
+    }
+    let sum: u64 = luma.iter().map(|&v| u64::from(v)).sum();
+    let mean = sum / luma.len() as u64;
+    luma.iter().map(|&v| u64::from(v) < mean).collect()
+}
+
+fn local_threshold(width: usize, height: usize, luma: &[u8]) -> Vec<bool> {
+    let blocks_x = width.div_ceil(BLOCK_SIZE);
+    let blocks_y = height.div_ceil(BLOCK_SIZE);
+
+    // «Чёрная точка» каждого блока
+    let mut black_points = vec![0u32; blocks_x * blocks_y];
+    for by in 0..blocks_y {
+        // Неполный последний блок сдвигается внутрь изображения
+        let y0 = (by * BLOCK_SIZE).min(height - BLOCK_SIZE);
+        for bx in 0..blocks_x {
+            let x0 = (bx * BLOCK_SIZE).min(width - BLOCK_SIZE);
+            let (mut sum, mut min, mut max) = (0u32, u32::MAX, 0u32);
+            for y in y0..y0 + BLOCK_SIZE {
+                for &v in &luma[y * width + x0..y * width + x0 + BLOCK_SIZE] {
+                    let v = u32::from(v);
+                    sum += v;
+                    min = min.min(v);
+                    max = max.max(v);
+                }
+            }
+            let mut average = sum / (BLOCK_SIZE * BLOCK_SIZE) as u32;
+            if max - min <= MIN_DYNAMIC_RANGE {
+                // Однотонный блок: считаем светлым, если соседи не темнее
+                average = min / 2;
+                if by > 0 && bx > 0 {
+                    let neighbors = (black_points[(by - 1) * blocks_x + bx]
+                        + 2 * black_points[by * blocks_x + bx - 1]
+                        + black_points[(by - 1) * blocks_x + bx - 1])
+                        / 4;
+                    if min < neighbors {
+                        average = neighbors;
+                    }
+                }
+            }
+            black_points[by * blocks_x + bx] = average;
+        }
+    }
+
+    let mut bits = vec![false; width * height];
+    for by in 0..blocks_y {
+        let y0 = (by * BLOCK_SIZE).min(height - BLOCK_SIZE);
+        let cy = by.clamp(2, blocks_y - 3);
+        for bx in 0..blocks_x {
+            let x0 = (bx * BLOCK_SIZE).min(width - BLOCK_SIZE);
+            let cx = bx.clamp(2, blocks_x - 3);
+            let mut sum = 0;
+            for ny in cy - 2..=cy + 2 {
+                for nx in cx - 2..=cx + 2 {
+                    sum += black_points[ny * blocks_x + nx];
+                }
+            }
+            let threshold = sum / 25;
+            for y in y0..y0 + BLOCK_SIZE {
+                for x in x0..x0 + BLOCK_SIZE {
+                    bits[y * width + x] = u32::from(luma[y * width + x]) <= threshold;
+                }
+            }
+        }
+    }
+    bits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uneven_lighting_keeps_dark_squares() {
+        // Градиент фона слева направо, тёмные квадраты 12×12 на нём
+        let (width, height) = (96, 64);
+        let mut luma = vec![0u8; width * height];
+        for y in 0..height {
+            for x in 0..width {
+                let background = 120 + (x * 130 / width) as u8;
+                let dark = (x / 12) % 2 == 0 && (y / 12) % 2 == 0;
+                luma[y * width + x] = if dark { background / 4 } else { background };
+            }
+        }
+        let image = BitImage::from_luma(width, height, &luma);
+        for y in 0..height {
+            for x in 0..width {
+                let dark = (x / 12) % 2 == 0 && (y / 12) % 2 == 0;
+                assert_eq!(image.get(x, y), dark, "({x}, {y})");
+            }
+        }
+        assert_eq!(image.sample(-0.2, 3.0), Some(true));
+        assert_eq!(image.sample(96.0, 3.0), None);
+    }
+}
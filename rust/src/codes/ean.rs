@@ -0,0 +1,341 @@
+//! Линейные штрихкоды EAN-13 (включая UPC-A) и EAN-8.
+//!
+//! Изображение читается по нескольким строкам и столбцам в обе стороны,
+//! поэтому код может быть повёрнут на 90° или 180°. Каждая цифра — четыре
+//! участка общей шириной 7 модулей; результат принимается только при
+//! верной контрольной цифре.
+
+use super::binarize::BitImage;
+use super::CodeFormat;
+
+/// Ширины участков цифр набора L (светлый, тёмный, светлый, тёмный).
+/// Набор R — те же ширины, начиная с тёмного; набор G — в обратном порядке.
+const L_PATTERNS: [[u8; 4]; 10] = [
+    [3, 2, 1, 1],
+    [2, 2, 2, 1],
+    [2, 1, 2, 2],
+    [1, 4, 1, 1],
+    [1, 1, 3, 2],
+    [1, 2, 3, 1],
+    [1, 1, 1, 4],
+    [1, 3, 1, 2],
+    [1, 2, 1, 3],
+    [3, 1, 1, 2],
+];
+
+/// Наборы левой половины EAN-13 (бит 1 — G) по первой цифре; старший бит — первая цифра половины.
+const FIRST_DIGIT_PARITY: [u8; 10] = [
+    0b00_0000, 0b00_1011, 0b00_1101, 0b00_1110, 0b01_0011, 0b01_1001, 0b01_1100, 0b01_0101,
+    0b01_0110, 0b01_1010,
+];
+
+/// Средняя допустимая погрешность ширины цифры (в модулях на модуль).
+const MAX_AVG_VARIANCE: f32 = 0.48;
+
+/// Погрешность одного участка, в модулях.
+const MAX_INDIVIDUAL_VARIANCE: f32 = 0.7;
+
+/// Сколько строк (и столбцов) изображения просматривать.
+const SCAN_LINES: usize = 15;
+
+/// Минимальная светлая зона вокруг кода, в модулях.
+const MIN_QUIET_ZONE: f32 = 3.0;
+
+/// Находит коды на изображении; результаты без повторов.
+pub fn detect(image: &BitImage) -> Vec<(CodeFormat, String)> {
+    let (width, height) = (image.width(), image.height());
+    let mut found: Vec<(CodeFormat, String)> = Vec::new();
+    let mut scan = |line: Vec<bool>| {
+        let reversed: Vec<bool> = line.iter().rev().copied().collect();
+        for line in [line, reversed] {
+            for result in decode_line(&line) {
+                if !found.contains(&result) {
+                    found.push(result);
+                }
+            }
+        }
+    };
+    // От середины к краям: центральные линии чаще проходят через код
+    for i in 0..SCAN_LINES {
+        let offset = (i + 1) / 2;
+        let fraction = if i % 2 == 0 {
+            SCAN_LINES / 2 + offset
+        } else {
+            SCAN_LINES / 2 - offset
+        };
+        let y = height * (2 * fraction + 1) / (2 * SCAN_LINES);
+        scan((0..width).map(|x| image.get(x, y)).collect());
+        let x = width * (2 * fraction + 1) / (2 * SCAN_LINES);
+        scan((0..height).map(|y| image.get(x, y)).collect());
+    }
+    found
+}
+
+/// Декодирует все коды на одной линии пикселей (слева направо).
+fn decode_line(line: &[bool]) -> Vec<(CodeFormat, String)> {
+    // Участки одного цвета; первый — светлый (возможно, нулевой длины)
+    let mut runs: Vec<usize> = vec![0];
+    let mut dark = false;
+    for &pixel in line {
+        if pixel != dark {
+            runs.push(0);
+            dark = pixel;
+        }
+        if let Some(last) = runs.last_mut() {
+            *last += 1;
+        }
+    }
+
+    let mut results = Vec::new();
+    let mut start = 1;
+    while start + 2 < runs.len() {
+        let decoded = decode_at(&runs, start, CodeFormat::Ean13)
+            .or_else(|| decode_at(&runs, start, CodeFormat::Ean8));
+        match decoded {
+            Some((result, consumed)) => {
+                results.push(result);
+                // Следующий тёмный участок после светлой зоны
+                start += consumed + 1;
+            }
+            None => start += 2,
+        }
+    }
+    results
+}
+
+/// Пробует прочитать код, начинающийся с тёмного участка `runs[start]`.
+/// Возвращает результат и число пройденных участков.
+fn decode_at(
+    runs: &[usize],
+    start: usize,
+    format: CodeFormat,
+) -> Option<((CodeFormat, String), usize)> {
+    let (digits_per_half, modules) = match format {
+        CodeFormat::Ean13 => (6, 95),
+        _ => (4, 67),
+    };
+    // Охранные знаки 3 + 5 + 3 участка, цифры по 4
+    let run_count = 11 + 8 * digits_per_half;
+    let code = runs.get(start..start + run_count)?;
+    let total: usize = code.iter().sum();
+    let module = total as f32 / modules as f32;
+    let quiet = module * MIN_QUIET_ZONE;
+    // Код, упирающийся в край изображения, не читается: светлой зоны нет
+    if (runs[start - 1] as f32) < quiet || (*runs.get(start + run_count)? as f32) < quiet {
+        return None;
+    }
+
+    let is_guard = |widths: &[usize]| {
+        widths
+            .iter()
+            .all(|&w| (w as f32 - module).abs() <= MAX_INDIVIDUAL_VARIANCE * module)
+    };
+    let middle = 3 + 4 * digits_per_half;
+    if !is_guard(&code[..3])
+        || !is_guard(&code[middle..middle + 5])
+        || !is_guard(&code[run_count - 3..])
+    {
+        return None;
+    }
+
+    let mut digits = Vec::with_capacity(13);
+    let mut parity = 0u8;
+    for i in 0..digits_per_half {
+        let (digit, is_g) = match_digit(&code[3 + 4 * i..7 + 4 * i], true)?;
+        if is_g && format == CodeFormat::Ean8 {
+            return None;
+        }
+        parity = (parity << 1) | u8::from(is_g);
+        digits.push(digit);
+    }
+    for i in 0..digits_per_half {
+        let offset = middle + 5 + 4 * i;
+        let (digit, _) = match_digit(&code[offset..offset + 4], false)?;
+        digits.push(digit);
+    }
+    if format == CodeFormat::Ean13 {
+        let first = FIRST_DIGIT_PARITY.iter().position(|&p| p == parity)?;
+        digits.insert(0, first as u8);
+    }
+    if !is_valid_check_digit(&digits) {
+        return None;
+    }
+    let text = digits.iter().map(|d| char::from(b'0' + d)).collect();
+    Some(((format, text), run_count))
+}
+
+/// Цифра по ширинам четырёх участков и признак набора G (только для левой половины).
+fn match_digit(widths: &[usize], left: bool) -> Option<(u8, bool)> {
+    let total: usize = widths.iter().sum();
+    if total == 0 {
+        return None;
+    }
+    let scale = 7.0 / total as f32;
+    let variance = |pattern: [u8; 4]| -> Option<f32> {
+        let mut sum = 0.0;
+        for (&width, &expected) in widths.iter().zip(pattern.iter()) {
+            let difference = (width as f32 * scale - f32::from(expected)).abs();
+            if difference > MAX_INDIVIDUAL_VARIANCE {
+                return None;
+            }
+            sum += difference;
+        }
+        Some(sum / 7.0)
+    };
+
+    let mut best: Option<(f32, u8, bool)> = None;
+    for (digit, &pattern) in L_PATTERNS.iter().enumerate() {
+        let mut options = vec![(pattern, false)];
+        if left {
+            let mut reversed = pattern;
+            reversed.reverse();
+            options.push((reversed, true));
+        }
+        for (pattern, is_g) in options {
+            if let Some(v) = variance(pattern) {
+                if v < MAX_AVG_VARIANCE && best.is_none_or(|(b, _, _)| v < b) {
+                    best = Some((v, digit as u8, is_g));
+                }
+            }
+        }
+    }
+    best.map(|(_, digit, is_g)| (digit, is_g))
+}
+
+/// Последняя цифра — контрольная: веса 3 и 1, начиная с цифры перед ней.
+fn is_valid_check_digit(digits: &[u8]) -> bool {
+    let Some((&check, data)) = digits.split_last() else {
+        return false;
+    };
+    let sum: u32 = data
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| u32::from(d) * if i % 2 == 0 { 3 } else { 1 })
+        .sum();
+    (10 - sum % 10) % 10 == u32::from(check)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Кодировки цифр набора L по GS1 General Specifications (5.2.1.2);
+    /// таблицы декодера намеренно не используются.
+    const L_CODES: [&str; 10] = [
+        "0001101", "0011001", "0010011", "0111101", "0100011", "0110001", "0101111", "0111011",
+        "0110111", "0001011",
+    ];
+
+    /// Наборы левой половины EAN-13 по первой цифре (GS1, 5.2.1.2).
+    const LEFT_SETS: [&str; 10] = [
+        "LLLLLL", "LLGLGG", "LLGGLG", "LLGGGL", "LGLLGG", "LGGLLG", "LGGGLL", "LGLGLG", "LGLGGL",
+        "LGGLGL",
+    ];
+
+    /// Модули кода (`true` — тёмный) без светлой зоны.
+    fn encode(digits: &str) -> Vec<bool> {
+        let digits: Vec<usize> = digits.bytes().map(|b| usize::from(b - b'0')).collect();
+        let (sets, left, right) = if digits.len() == 13 {
+            (LEFT_SETS[digits[0]], &digits[1..7], &digits[7..])
+        } else {
+            ("LLLL", &digits[..4], &digits[4..])
+        };
+        // R — инверсия L, G — R в обратном порядке
+        let r_code = |d: usize| -> String {
+            L_CODES[d]
+                .chars()
+                .map(|c| if c == '0' { '1' } else { '0' })
+                .collect()
+        };
+        let mut code = String::from("101");
+        for (&d, set) in left.iter().zip(sets.chars()) {
+            match set {
+                'L' => code.push_str(L_CODES[d]),
+                _ => code.extend(r_code(d).chars().rev()),
+            }
+        }
+        code.push_str("01010");
+        for &d in right {
+            code.push_str(&r_code(d));
+        }
+        code.push_str("101");
+        code.chars().map(|c| c == '1').collect()
+    }
+
+    /// Добавляет контрольную цифру (GS1, 7.9.1).
+    fn with_check_digit(digits: &str) -> String {
+        let sum: u32 = digits
+            .bytes()
+            .rev()
+            .enumerate()
+            .map(|(i, b)| u32::from(b - b'0') * if i % 2 == 0 { 3 } else { 1 })
+            .sum();
+        format!("{digits}{}", (10 - sum % 10) % 10)
+    }
+
+    /// Код из `modules`, растянутый в `scale` раз, со светлой зоной, высотой 40 пикселей.
+    fn render(modules: &[bool], scale: usize) -> BitImage {
+        let mut row = vec![false; 10 * scale];
+        for &m in modules {
+            row.extend(std::iter::repeat_n(m, scale));
+        }
+        row.extend(std::iter::repeat_n(false, 10 * scale));
+        let width = row.len();
+        BitImage::from_bits(width, 40, row.repeat(40))
+    }
+
+    #[test]
+    fn test_reads_ean13_and_ean8() {
+        let ean13 = encode("4006381333931");
+        assert_eq!(ean13.len(), 95);
+        assert_eq!(
+            detect(&render(&ean13, 3)),
+            vec![(CodeFormat::Ean13, "4006381333931".to_string())]
+        );
+
+        let ean8 = encode("96385074");
+        assert_eq!(ean8.len(), 67);
+        assert_eq!(
+            detect(&render(&ean8, 2)),
+            vec![(CodeFormat::Ean8, "96385074".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_reads_upside_down_and_rejects_bad_check_digit() {
+        let mut upside_down = encode("5901234123457");
+        upside_down.reverse();
+        assert_eq!(
+            detect(&render(&upside_down, 2)),
+            vec![(CodeFormat::Ean13, "5901234123457".to_string())]
+        );
+
+        assert!(detect(&render(&encode("5901234123458"), 2)).is_empty());
+    }
+
+    #[test]
+    fn test_round_trips_generated_numbers() {
+        // Все первые цифры EAN-13 (все наборы левой половины) и разные цифры EAN-8
+        for first in 0..10u64 {
+            let number = with_check_digit(&format!(
+                "{first}{:011}",
+                first * 7_919_113_457 % 100_000_000_000
+            ));
+            for scale in [1, 2, 3] {
+                assert_eq!(
+                    detect(&render(&encode(&number), scale)),
+                    vec![(CodeFormat::Ean13, number.clone())],
+                    "scale {scale}"
+                );
+            }
+        }
+        for seed in 0..10u64 {
+            let number = with_check_digit(&format!("{:07}", seed * 1_234_567 % 10_000_000));
+            assert_eq!(
+                detect(&render(&encode(&number), 2)),
+                vec![(CodeFormat::Ean8, number.clone())]
+            );
+        }
+    }
+}
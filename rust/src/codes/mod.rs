@@ -0,0 +1,332 @@
+//! QR-коды и штрихкоды на входящих изображениях.
+//!
+//! Сценарий — «отсканировать QR со счёта прямо из папки»: новые PNG/JPEG
+//! просматриваются в фоне ([`CodeScanQueue`]), найденные коды приходят
+//! событием `CodesDetected` того же файла в общем stream событий.
+//!
+//! Поддерживаются QR (модель 2, версии 1–40; Micro QR — нет) и EAN-13 /
+//! UPC-A / EAN-8. Декодер рассчитан на сканы и снимки экрана: сильно
+//! размытые или искажённые фотографии могут не читаться.
+
+mod binarize;
+mod ean;
+mod qr;
+mod qr_decode;
+mod reed_solomon;
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+
+use log::{debug, warn};
+
+use crate::error::LateraError;
+use crate::preview;
+use binarize::BitImage;
+
+/// Расширения изображений, которые просматриваются.
+pub const SCANNABLE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg"];
+
+/// Изображения больше этого числа пикселей пропускаются (память и время).
+pub const MAX_SCAN_PIXELS: u64 = 40_000_000;
+
+/// Как часто проверять, что файл перестал меняться.
+const SETTLE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Дольше не ждём: файл, меняющийся дольше, не просматривается.
+const SETTLE_MAX_WAIT: Duration = Duration::from_secs(30);
+
+/// Формат найденного кода.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CodeFormat {
+    QrCode,
+    /// EAN-13; UPC-A читается как EAN-13 с ведущим нулём.
+    Ean13,
+    Ean8,
+}
+
+/// Найденный код.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DetectedCode {
+    pub format: CodeFormat,
+    /// Содержимое: текст QR или цифры штрихкода.
+    pub text: String,
+}
+
+/// Изображение поддерживаемого формата (по расширению).
+pub fn is_scannable(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| SCANNABLE_EXTENSIONS.contains(&e.to_ascii_lowercase().as_str()))
+}
+
+/// Ищет коды в файле изображения.
+pub fn scan_file(path: &Path) -> Result<Vec<DetectedCode>, LateraError> {
+    let invalid = |e: image::ImageError| {
+        LateraError::InvalidArgument(format!("cannot decode image {}: {e}", path.display()))
+    };
+    let (width, height) = image::image_dimensions(path).map_err(invalid)?;
+    if u64::from(width) * u64::from(height) > MAX_SCAN_PIXELS {
+        return Err(LateraError::InvalidArgument(format!(
+            "image too large for code scanning: {width}x{height}"
+        )));
+    }
+    let luma = image::ImageReader::open(path)?
+        .with_guessed_format()?
+        .decode()
+        .map_err(invalid)?
+        .to_luma8();
+    Ok(scan_luma(
+        luma.width() as usize,
+        luma.height() as usize,
+        luma.as_raw(),
+    ))
+}
+
+/// Ищет коды в изображении в оттенках серого (`luma` — строки подряд).
+pub fn scan_luma(width: usize, height: usize, luma: &[u8]) -> Vec<DetectedCode> {
+    if width == 0 || height == 0 || luma.len() != width * height {
+        return Vec::new();
+    }
+    let image = BitImage::from_luma(width, height, luma);
+    let mut codes: Vec<DetectedCode> = qr::detect(&image)
+        .into_iter()
+        .map(|text| DetectedCode {
+            format: CodeFormat::QrCode,
+            text,
+        })
+        .collect();
+    codes.extend(
+        ean::detect(&image)
+            .into_iter()
+            .map(|(format, text)| DetectedCode { format, text }),
+    );
+    codes
+}
+
+/// Изображение, ждущее просмотра.
+#[derive(Clone, Debug)]
+pub struct CodeScanJob {
+    /// Watcher, сообщивший о файле.
+    pub watcher_id: String,
+    pub path: PathBuf,
+}
+
+/// Очередь фонового поиска кодов.
+pub struct CodeScanQueue {
+    job_tx: mpsc::Sender<CodeScanJob>,
+    stop: Arc<AtomicBool>,
+    join: thread::JoinHandle<()>,
+}
+
+impl CodeScanQueue {
+    /// Запускает фоновый тред; `on_codes` вызывается, только если коды найдены.
+    ///
+    /// Файлы не поддерживаемых форматов, исчезнувшие или не переставшие
+    /// меняться, пропускаются.
+    pub fn spawn(
+        on_codes: impl Fn(CodeScanJob, Vec<DetectedCode>) + Send + 'static,
+    ) -> Result<Self, LateraError> {
+        let (job_tx, job_rx) = mpsc::channel::<CodeScanJob>();
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_for_thread = Arc::clone(&stop);
+        let join = thread::Builder::new()
+            .name("latera-codes".to_string())
+            .spawn(move || {
+                while let Ok(job) = job_rx.recv() {
+                    if stop_for_thread.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    if !preview::wait_until_settled(&job.path, SETTLE_INTERVAL, SETTLE_MAX_WAIT) {
+                        debug!(
+                            "Code scan skipped, file did not settle: {}",
+                            job.path.display()
+                        );
+                        continue;
+                    }
+                    match scan_file(&job.path) {
+                        Ok(codes) if codes.is_empty() => {}
+                        Ok(codes) => on_codes(job, codes),
+                        Err(e) => debug!("Code scan failed for {}: {e}", job.path.display()),
+                    }
+                }
+                debug!("Code scan queue stopped");
+            })?;
+        Ok(Self { job_tx, stop, join })
+    }
+
+    /// Поставить файл в очередь; файлы не поддерживаемых форматов игнорируются.
+    pub fn enqueue(&self, job: CodeScanJob) {
+        if !is_scannable(&job.path) {
+            return;
+        }
+        if self.job_tx.send(job).is_err() {
+            debug!("Code scan queue is closed");
+        }
+    }
+
+    /// Останавливает тред; необработанные файлы отбрасываются.
+    pub fn stop(self) {
+        self.stop.store(true, Ordering::Relaxed);
+        drop(self.job_tx);
+        if self.join.join().is_err() {
+            warn!("Code scan thread panicked");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use qrcode::bits::Bits;
+    use qrcode::types::Mode;
+    use qrcode::{Color, EcLevel, QrCode, Version};
+
+    use super::*;
+
+    /// Рендер QR уровня M: `scale` пикселей на модуль, светлая зона 4 модуля.
+    fn render_qr(data: &str, version: i16, scale: usize) -> (usize, Vec<u8>) {
+        render_qr_code(
+            &QrCode::with_version(data, Version::Normal(version), EcLevel::M).unwrap(),
+            scale,
+        )
+    }
+
+    fn render_qr_code(code: &QrCode, scale: usize) -> (usize, Vec<u8>) {
+        let modules: Vec<bool> = code.to_colors().iter().map(|&c| c == Color::Dark).collect();
+        let width = code.width();
+        let side = (width + 8) * scale;
+        let mut luma = vec![255u8; side * side];
+        for y in 0..side {
+            for x in 0..side {
+                let (mx, my) = ((x / scale).wrapping_sub(4), (y / scale).wrapping_sub(4));
+                if mx < width && my < width && modules[my * width + mx] {
+                    luma[y * side + x] = 0;
+                }
+            }
+        }
+        (side, luma)
+    }
+
+    fn qr_texts(codes: &[DetectedCode]) -> Vec<&str> {
+        codes
+            .iter()
+            .filter(|c| c.format == CodeFormat::QrCode)
+            .map(|c| c.text.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_scans_qr_codes_of_several_versions() {
+        for (data, version, scale) in [
+            ("https://pay.example.com/inv/2024-0042", 3, 4),
+            ("ST00012|Name=ООО Ромашка|PersonalAcc=40702810", 7, 3),
+            (&"0123456789".repeat(20) as &str, 10, 3),
+        ] {
+            let (side, luma) = render_qr(data, version, scale);
+            let codes = scan_luma(side, side, &luma);
+            assert_eq!(qr_texts(&codes), vec![data], "version {version}");
+        }
+    }
+
+    /// Код версии `version`, заполненный до предела: короткий байтовый сегмент
+    /// и сегмент режима `fill` на всё оставшееся место.
+    fn fill_capacity(version: i16, level: EcLevel, fill: Mode) -> (String, QrCode) {
+        let version = Version::Normal(version);
+        let prefix = "№";
+        let mut bits = Bits::new(version);
+        bits.push_byte_data(prefix.as_bytes()).unwrap();
+        let free = bits.max_len(level).unwrap() - bits.len() - 4 - fill.length_bits_count(version);
+        let (alphabet, len): (&[u8], usize) = match fill {
+            Mode::Numeric => (
+                b"0123456789",
+                free / 10 * 3 + [0, 0, 0, 0, 1, 1, 1, 2, 2, 2][free % 10],
+            ),
+            Mode::Alphanumeric => (
+                b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:",
+                free / 11 * 2 + usize::from(free % 11 >= 6),
+            ),
+            _ => (b"Latera qr-round/trip?&=#", free / 8),
+        };
+        let tail: Vec<u8> = (0..len)
+            .map(|i| alphabet[(i * 7 + i / 3) % alphabet.len()])
+            .collect();
+        match fill {
+            Mode::Numeric => bits.push_numeric_data(&tail),
+            Mode::Alphanumeric => bits.push_alphanumeric_data(&tail),
+            _ => bits.push_byte_data(&tail),
+        }
+        .unwrap();
+        bits.push_terminator(level).unwrap();
+        let data = format!("{prefix}{}", String::from_utf8(tail).unwrap());
+        (data, QrCode::with_bits(bits, level).unwrap())
+    }
+
+    #[test]
+    fn test_round_trips_all_versions_and_ec_levels() {
+        for version in 1..=40i16 {
+            for level in [EcLevel::L, EcLevel::M, EcLevel::Q, EcLevel::H] {
+                let fill = [Mode::Numeric, Mode::Alphanumeric, Mode::Byte][version as usize % 3];
+                let (data, code) = fill_capacity(version, level, fill);
+                let (side, luma) = render_qr_code(&code, 2);
+                let codes = scan_luma(side, side, &luma);
+                assert_eq!(
+                    qr_texts(&codes),
+                    vec![data.as_str()],
+                    "version {version}, level {level:?}, fill {fill:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_scans_rotated_qr_on_gray_background() {
+        let data = "SPD*1.0*ACC:CZ5855000000001265098001*AM:480.50";
+        let (side, luma) = render_qr(data, 4, 4);
+        // Поворот на 90° и серый фон вместо белого
+        let mut rotated = vec![0u8; side * side];
+        for y in 0..side {
+            for x in 0..side {
+                let value = luma[(side - 1 - x) * side + y];
+                rotated[y * side + x] = if value == 255 { 170 } else { 40 };
+            }
+        }
+        let codes = scan_luma(side, side, &rotated);
+        assert_eq!(qr_texts(&codes), vec![data]);
+    }
+
+    #[test]
+    fn test_scan_file_and_queue() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let data = "WIFI:T:WPA;S:office;P:secret;;";
+        let (side, luma) = render_qr(data, 3, 5);
+        let path = temp_dir.path().join("invoice.png");
+        image::GrayImage::from_raw(side as u32, side as u32, luma)
+            .unwrap()
+            .save(&path)
+            .unwrap();
+        let text_path = temp_dir.path().join("notes.txt");
+        std::fs::write(&text_path, "no codes here").unwrap();
+
+        assert!(is_scannable(Path::new("a/B.JPG")));
+        assert!(!is_scannable(&text_path));
+        assert_eq!(qr_texts(&scan_file(&path).unwrap()), vec![data]);
+
+        let (tx, rx) = mpsc::channel();
+        let queue = CodeScanQueue::spawn(move |job, codes| {
+            let _ = tx.send((job.path, codes));
+        })
+        .unwrap();
+        for path in [&text_path, &path] {
+            queue.enqueue(CodeScanJob {
+                watcher_id: "w1".to_string(),
+                path: path.clone(),
+            });
+        }
+        let (scanned, codes) = rx.recv_timeout(Duration::from_secs(10)).unwrap();
+        assert_eq!(scanned, path);
+        assert_eq!(qr_texts(&codes), vec![data]);
+        queue.stop();
+    }
+}
@@ -0,0 +1,535 @@
+//! Поиск QR-кодов на бинарном изображении.
+//!
+//! Ищутся три finder pattern (квадраты 1:1:3:1:1 в углах), по ним —
+//! размер кода и перспектива (четвёртая опорная точка — нижний правый
+//! alignment pattern), затем снимается сетка модулей и декодируется
+//! [`super::qr_decode`].
+
+use super::binarize::BitImage;
+use super::qr_decode::{self, ModuleGrid};
+
+/// Сколько кандидатов finder pattern перебирать (самые частые).
+const MAX_FINDER_CANDIDATES: usize = 10;
+
+/// Допустимое отношение размеров модуля у трёх finder patterns.
+const MAX_MODULE_RATIO: f32 = 1.4;
+
+/// Радиус поиска alignment pattern, в модулях.
+const ALIGNMENT_SEARCH_MODULES: f32 = 6.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+impl Point {
+    fn distance(self, other: Self) -> f32 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+}
+
+/// Кандидат в finder pattern.
+#[derive(Clone, Copy, Debug)]
+struct Candidate {
+    center: Point,
+    module: f32,
+    /// Сколько строк сканирования его подтвердили.
+    count: u32,
+}
+
+/// Находит и декодирует QR-коды; тексты без повторов.
+pub fn detect(image: &BitImage) -> Vec<String> {
+    let mut candidates = find_candidates(image);
+    candidates.sort_by_key(|c| std::cmp::Reverse(c.count));
+    candidates.truncate(MAX_FINDER_CANDIDATES);
+
+    let mut texts: Vec<String> = Vec::new();
+    let mut used = vec![false; candidates.len()];
+    for i in 0..candidates.len() {
+        for j in i + 1..candidates.len() {
+            for k in j + 1..candidates.len() {
+                if used[i] || used[j] || used[k] {
+                    continue;
+                }
+                let triple = [candidates[i], candidates[j], candidates[k]];
+                if let Some(text) = decode_triple(image, triple) {
+                    used[i] = true;
+                    used[j] = true;
+                    used[k] = true;
+                    if !texts.contains(&text) {
+                        texts.push(text);
+                    }
+                }
+            }
+        }
+    }
+    texts
+}
+
+/// Длины пяти чередующихся участков соответствуют 1:1:3:1:1.
+fn is_finder_ratio(counts: [usize; 5]) -> bool {
+    let total: usize = counts.iter().sum();
+    if total < 7 {
+        return false;
+    }
+    let module = total as f32 / 7.0;
+    let tolerance = module / 2.0;
+    let near =
+        |count: usize, modules: f32| (count as f32 - module * modules).abs() < tolerance * modules;
+    near(counts[0], 1.0)
+        && near(counts[1], 1.0)
+        && near(counts[2], 3.0)
+        && near(counts[3], 1.0)
+        && near(counts[4], 1.0)
+}
+
+/// Участки тёмный–светлый–тёмный–светлый–тёмный через точку `(x, y)`
+/// по вертикали или горизонтали и центр среднего участка на этой оси.
+///
+/// Внешние участки длиннее `limit` отвергаются.
+fn cross_check(
+    image: &BitImage,
+    x: usize,
+    y: usize,
+    vertical: bool,
+    limit: usize,
+) -> Option<([usize; 5], f32)> {
+    let len = if vertical {
+        image.height()
+    } else {
+        image.width()
+    };
+    let at = |i: usize| {
+        if vertical {
+            image.get(x, i)
+        } else {
+            image.get(i, y)
+        }
+    };
+    let start = if vertical { y } else { x };
+    if !at(start) {
+        return None;
+    }
+
+    let mut counts = [0usize; 5];
+    // Назад от центра
+    let mut i = start;
+    loop {
+        counts[2] += 1;
+        if i == 0 || !at(i - 1) {
+            break;
+        }
+        i -= 1;
+    }
+    let mut i = i.checked_sub(1)?;
+    while !at(i) {
+        counts[1] += 1;
+        if counts[1] > limit {
+            return None;
+        }
+        i = i.checked_sub(1)?;
+    }
+    loop {
+        counts[0] += 1;
+        if counts[0] > limit || i == 0 || !at(i - 1) {
+            break;
+        }
+        i -= 1;
+    }
+    if counts[0] > limit {
+        return None;
+    }
+
+    // Вперёд от центра
+    let mut i = start + 1;
+    while i < len && at(i) {
+        counts[2] += 1;
+        i += 1;
+    }
+    while i < len && !at(i) {
+        counts[3] += 1;
+        if counts[3] > limit {
+            return None;
+        }
+        i += 1;
+    }
+    if i == len {
+        return None;
+    }
+    while i < len && at(i) {
+        counts[4] += 1;
+        if counts[4] > limit {
+            return None;
+        }
+        i += 1;
+    }
+
+    is_finder_ratio(counts).then(|| {
+        let center_end = (i - counts[4] - counts[3]) as f32;
+        (counts, center_end - counts[2] as f32 / 2.0)
+    })
+}
+
+/// Кандидаты finder pattern: строки сканируются на 1:1:3:1:1, совпадения
+/// подтверждаются по вертикали и повторно по горизонтали.
+#[allow(clippy::cast_sign_loss)]
+fn find_candidates(image: &BitImage) -> Vec<Candidate> {
+    let (width, height) = (image.width(), image.height());
+    let step = (height / 400).max(1);
+    let mut candidates: Vec<Candidate> = Vec::new();
+    let mut runs: Vec<(usize, usize)> = Vec::new();
+    for y in (0..height).step_by(step) {
+        // Участки строки: (начало, длина); чётные индексы — цвет первого пикселя
+        runs.clear();
+        let first_dark = image.get(0, y);
+        let mut run_start = 0;
+        for x in 1..=width {
+            if x == width || image.get(x, y) != image.get(run_start, y) {
+                runs.push((run_start, x - run_start));
+                run_start = x;
+            }
+        }
+        let first = usize::from(!first_dark);
+        for k in (first..runs.len().saturating_sub(4)).step_by(2) {
+            let counts = [
+                runs[k].1,
+                runs[k + 1].1,
+                runs[k + 2].1,
+                runs[k + 3].1,
+                runs[k + 4].1,
+            ];
+            if !is_finder_ratio(counts) {
+                continue;
+            }
+            let horizontal_total: usize = counts.iter().sum();
+            let center_x = runs[k + 2].0 + runs[k + 2].1 / 2;
+            let Some((vertical, center_y)) = cross_check(image, center_x, y, true, counts[2])
+            else {
+                continue;
+            };
+            let vertical_total: usize = vertical.iter().sum();
+            if 5 * vertical_total.abs_diff(horizontal_total) >= 2 * horizontal_total {
+                continue;
+            }
+            let Some((horizontal, center_x)) =
+                cross_check(image, center_x, center_y as usize, false, counts[2])
+            else {
+                continue;
+            };
+            let total: usize = horizontal.iter().sum::<usize>() + vertical_total;
+            add_candidate(
+                &mut candidates,
+                Point {
+                    x: center_x,
+                    y: center_y,
+                },
+                total as f32 / 14.0,
+            );
+        }
+    }
+    candidates
+}
+
+/// Добавляет кандидата или усредняет его с уже найденным рядом.
+fn add_candidate(candidates: &mut Vec<Candidate>, center: Point, module: f32) {
+    let existing = candidates.iter_mut().find(|c| {
+        (c.center.x - center.x).abs() <= c.module
+            && (c.center.y - center.y).abs() <= c.module
+            && (c.module - module).abs() <= 1.0f32.max(c.module / 2.0)
+    });
+    match existing {
+        Some(c) => {
+            let weight = c.count as f32;
+            let average = |old: f32, new: f32| (old * weight + new) / (weight + 1.0);
+            c.center = Point {
+                x: average(c.center.x, center.x),
+                y: average(c.center.y, center.y),
+            };
+            c.module = average(c.module, module);
+            c.count += 1;
+        }
+        None => candidates.push(Candidate {
+            center,
+            module,
+            count: 1,
+        }),
+    }
+}
+
+/// Пробует декодировать код по трём finder patterns.
+#[allow(clippy::cast_sign_loss)]
+fn decode_triple(image: &BitImage, triple: [Candidate; 3]) -> Option<String> {
+    let modules = triple.map(|c| c.module);
+    let (min, max) = modules
+        .iter()
+        .fold((f32::MAX, 0.0f32), |(lo, hi), &m| (lo.min(m), hi.max(m)));
+    if max / min > MAX_MODULE_RATIO {
+        return None;
+    }
+    let module = modules.iter().sum::<f32>() / 3.0;
+
+    // Верхний левый — вершина напротив самой длинной стороны
+    let [a, b, c] = triple.map(|c| c.center);
+    let (bc, ac, ab) = (b.distance(c), a.distance(c), a.distance(b));
+    let (top_left, mut top_right, mut bottom_left) = if bc >= ac && bc >= ab {
+        (a, b, c)
+    } else if ac >= ab {
+        (b, a, c)
+    } else {
+        (c, a, b)
+    };
+    // Ось y направлена вниз: у правильной тройки векторное произведение положительно
+    let cross = (top_right.x - top_left.x) * (bottom_left.y - top_left.y)
+        - (top_right.y - top_left.y) * (bottom_left.x - top_left.x);
+    if cross < 0.0 {
+        std::mem::swap(&mut top_right, &mut bottom_left);
+    }
+
+    let (right, down) = (top_left.distance(top_right), top_left.distance(bottom_left));
+    if right.max(down) / right.min(down) > MAX_MODULE_RATIO {
+        return None;
+    }
+    let estimated = (right.midpoint(down) / module + 7.0 - 17.0) / 4.0;
+    if !(0.5..=40.5).contains(&estimated) {
+        return None;
+    }
+    let version = estimated.round() as usize;
+    for version in [version, version - 1, version + 1] {
+        if !(1..=40).contains(&version) {
+            continue;
+        }
+        let grid = sample_grid(image, version, module, top_left, top_right, bottom_left);
+        if let Some(text) =
+            qr_decode::decode(&grid).or_else(|| qr_decode::decode(&grid.transposed()))
+        {
+            return Some(text);
+        }
+    }
+    None
+}
+
+/// Снимает сетку модулей версии `version`.
+fn sample_grid(
+    image: &BitImage,
+    version: usize,
+    module: f32,
+    top_left: Point,
+    top_right: Point,
+    bottom_left: Point,
+) -> ModuleGrid {
+    let size = version * 4 + 17;
+    let far = size as f64 - 3.5;
+    let parallelogram = Point {
+        x: top_right.x + bottom_left.x - top_left.x,
+        y: top_right.y + bottom_left.y - top_left.y,
+    };
+    // Четвёртая точка: центр нижнего правого alignment pattern, если он есть
+    let alignment = (version >= 2)
+        .then(|| {
+            let ratio = 1.0 - 3.0 / (size as f32 - 7.0);
+            let estimate = Point {
+                x: top_left.x + (parallelogram.x - top_left.x) * ratio,
+                y: top_left.y + (parallelogram.y - top_left.y) * ratio,
+            };
+            find_alignment(image, estimate, module)
+        })
+        .flatten();
+    let (corner, corner_module) = match alignment {
+        Some(point) => (point, size as f64 - 6.5),
+        None => (parallelogram, far),
+    };
+
+    let transform = Perspective::quad_to_quad(
+        [
+            (3.5, 3.5),
+            (far, 3.5),
+            (corner_module, corner_module),
+            (3.5, far),
+        ],
+        [top_left, top_right, corner, bottom_left].map(|p| (f64::from(p.x), f64::from(p.y))),
+    );
+    let mut modules = Vec::with_capacity(size * size);
+    for row in 0..size {
+        for column in 0..size {
+            let (x, y) = transform.apply(column as f64 + 0.5, row as f64 + 0.5);
+            modules.push(image.sample(x as f32, y as f32).unwrap_or(false));
+        }
+    }
+    ModuleGrid::new(size, modules)
+}
+
+/// Ищет alignment pattern (тёмный центр, светлое и тёмное кольцо) рядом
+/// с ожидаемой точкой; берётся ближайший к ней.
+#[allow(clippy::cast_sign_loss)]
+fn find_alignment(image: &BitImage, estimate: Point, module: f32) -> Option<Point> {
+    let radius = module * ALIGNMENT_SEARCH_MODULES;
+    let clamp = |value: f32, len: usize| value.clamp(0.0, (len - 1) as f32) as usize;
+    let (x0, x1) = (
+        clamp(estimate.x - radius, image.width()),
+        clamp(estimate.x + radius, image.width()),
+    );
+    let (y0, y1) = (
+        clamp(estimate.y - radius, image.height()),
+        clamp(estimate.y + radius, image.height()),
+    );
+
+    let mut best: Option<(f32, Point)> = None;
+    for y in y0..=y1 {
+        let mut x = x0;
+        while x <= x1 {
+            // Центр — первый тёмный пиксель тёмного участка
+            if !image.get(x, y) || (x > 0 && image.get(x - 1, y)) {
+                x += 1;
+                continue;
+            }
+            if let Some(center) = check_alignment(image, x, y, module) {
+                let distance = center.distance(estimate);
+                if best.is_none_or(|(d, _)| distance < d) {
+                    best = Some((distance, center));
+                }
+            }
+            x += 1;
+        }
+    }
+    best.map(|(_, point)| point)
+}
+
+/// Проверяет, что через тёмный участок, начинающийся в `(x, y)`, проходит
+/// alignment pattern по обеим осям; возвращает его центр.
+#[allow(clippy::cast_sign_loss)]
+fn check_alignment(image: &BitImage, x: usize, y: usize, module: f32) -> Option<Point> {
+    let mut end = x;
+    while end < image.width() && image.get(end, y) {
+        end += 1;
+    }
+    let center_x = x.midpoint(end);
+    let cx = alignment_center(image, center_x, y, false, module)?;
+    let cy = alignment_center(image, cx as usize, y, true, module)?;
+    Some(Point { x: cx, y: cy })
+}
+
+/// Центр тёмного участка на оси, если вокруг него светлые участки и тёмное
+/// кольцо, а все три участка ≈ модулю.
+fn alignment_center(
+    image: &BitImage,
+    x: usize,
+    y: usize,
+    vertical: bool,
+    module: f32,
+) -> Option<f32> {
+    let len = if vertical {
+        image.height()
+    } else {
+        image.width()
+    };
+    let at = |i: usize| {
+        if vertical {
+            image.get(x, i)
+        } else {
+            image.get(i, y)
+        }
+    };
+    let start = if vertical { y } else { x };
+    if !at(start) {
+        return None;
+    }
+    let limit = (module * 2.0).ceil() as usize;
+    let near = |count: usize| (count as f32 - module).abs() <= (module / 2.0).max(1.0);
+
+    let mut begin = start;
+    while begin > 0 && at(begin - 1) {
+        begin -= 1;
+    }
+    let mut end = start + 1;
+    while end < len && at(end) {
+        end += 1;
+    }
+    let center = end - begin;
+
+    // Светлые участки по сторонам, за ними — тёмное кольцо
+    let mut before = 0;
+    let mut i = begin;
+    while i > 0 && !at(i - 1) && before <= limit {
+        before += 1;
+        i -= 1;
+    }
+    if i == 0 || before > limit {
+        return None;
+    }
+    let mut after = 0;
+    let mut i = end;
+    while i < len && !at(i) && after <= limit {
+        after += 1;
+        i += 1;
+    }
+    if i == len || after > limit {
+        return None;
+    }
+
+    (near(before) && near(center) && near(after)).then(|| begin as f32 + center as f32 / 2.0)
+}
+
+/// Перспективное преобразование: `[x y w] = [u v 1] · m`.
+struct Perspective {
+    m: [[f64; 3]; 3],
+}
+
+impl Perspective {
+    /// Единичный квадрат (0,0), (1,0), (1,1), (0,1) → четырёхугольник.
+    fn square_to_quad(quad: [(f64, f64); 4]) -> [[f64; 3]; 3] {
+        let [(x0, y0), (x1, y1), (x2, y2), (x3, y3)] = quad;
+        let dx3 = x0 - x1 + x2 - x3;
+        let dy3 = y0 - y1 + y2 - y3;
+        if dx3.abs() < 1e-9 && dy3.abs() < 1e-9 {
+            return [
+                [x1 - x0, y1 - y0, 0.0],
+                [x2 - x1, y2 - y1, 0.0],
+                [x0, y0, 1.0],
+            ];
+        }
+        let (dx1, dx2) = (x1 - x2, x3 - x2);
+        let (dy1, dy2) = (y1 - y2, y3 - y2);
+        let denominator = dx1 * dy2 - dx2 * dy1;
+        let a13 = (dx3 * dy2 - dx2 * dy3) / denominator;
+        let a23 = (dx1 * dy3 - dx3 * dy1) / denominator;
+        [
+            [x1 - x0 + a13 * x1, y1 - y0 + a13 * y1, a13],
+            [x3 - x0 + a23 * x3, y3 - y0 + a23 * y3, a23],
+            [x0, y0, 1.0],
+        ]
+    }
+
+    /// Присоединённая матрица — обратная с точностью до множителя.
+    fn adjugate(m: [[f64; 3]; 3]) -> [[f64; 3]; 3] {
+        let minor = |r0: usize, r1: usize, c0: usize, c1: usize| {
+            m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+        };
+        [
+            [minor(1, 2, 1, 2), -minor(0, 2, 1, 2), minor(0, 1, 1, 2)],
+            [-minor(1, 2, 0, 2), minor(0, 2, 0, 2), -minor(0, 1, 0, 2)],
+            [minor(1, 2, 0, 1), -minor(0, 2, 0, 1), minor(0, 1, 0, 1)],
+        ]
+    }
+
+    fn quad_to_quad(from: [(f64, f64); 4], to: [(f64, f64); 4]) -> Self {
+        let a = Self::adjugate(Self::square_to_quad(from));
+        let b = Self::square_to_quad(to);
+        let mut m = [[0.0; 3]; 3];
+        for (i, row) in m.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+            }
+        }
+        Self { m }
+    }
+
+    fn apply(&self, u: f64, v: f64) -> (f64, f64) {
+        let m = &self.m;
+        let w = m[0][2] * u + m[1][2] * v + m[2][2];
+        (
+            (m[0][0] * u + m[1][0] * v + m[2][0]) / w,
+            (m[0][1] * u + m[1][1] * v + m[2][1]) / w,
+        )
+    }
+}
@@ -0,0 +1,498 @@
+//! Декодирование QR-кода из сетки модулей (ISO/IEC 18004).
+//!
+//! Сетка уже снята с изображения ([`super::qr`]): здесь читаются формат
+//! (уровень коррекции и маска), кодовые слова, исправляются ошибки и
+//! разбираются сегменты данных.
+
+use super::reed_solomon;
+
+/// Байты коррекции на блок: `[уровень][версия]` (L, M, Q, H; версия с 1).
+const ECC_CODEWORDS_PER_BLOCK: [[u8; 41]; 4] = [
+    [
+        0, 7, 10, 15, 20, 26, 18, 20, 24, 30, 18, 20, 24, 26, 30, 22, 24, 28, 30, 28, 28, 28, 28,
+        30, 30, 26, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        0, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26, 26, 28,
+        28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+    ],
+    [
+        0, 13, 22, 18, 26, 18, 24, 18, 22, 20, 24, 28, 26, 24, 20, 30, 24, 28, 28, 26, 30, 28, 30,
+        30, 30, 30, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        0, 17, 28, 22, 16, 22, 28, 26, 26, 24, 28, 24, 28, 22, 24, 24, 30, 28, 28, 26, 28, 30, 24,
+        30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+];
+
+/// Число блоков коррекции: `[уровень][версия]`.
+const NUM_ERROR_CORRECTION_BLOCKS: [[u8; 41]; 4] = [
+    [
+        0, 1, 1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4, 4, 4, 6, 6, 6, 6, 7, 8, 8, 9, 9, 10, 12, 12, 12, 13,
+        14, 15, 16, 17, 18, 19, 19, 20, 21, 22, 24, 25,
+    ],
+    [
+        0, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20, 21,
+        23, 25, 26, 28, 29, 31, 33, 35, 37, 38, 40, 43, 45, 47, 49,
+    ],
+    [
+        0, 1, 1, 2, 2, 4, 4, 6, 6, 8, 8, 8, 10, 12, 16, 12, 17, 16, 18, 21, 20, 23, 23, 25, 27, 29,
+        34, 34, 35, 38, 40, 43, 45, 48, 51, 53, 56, 59, 62, 65, 68,
+    ],
+    [
+        0, 1, 1, 2, 4, 4, 4, 5, 6, 8, 8, 11, 11, 16, 16, 18, 16, 19, 21, 25, 25, 25, 34, 30, 32,
+        35, 37, 40, 42, 45, 48, 51, 54, 57, 60, 63, 66, 70, 74, 77, 81,
+    ],
+];
+
+/// Маска, которой XOR-ится информация о формате.
+const FORMAT_MASK: u32 = 0x5412;
+
+/// Допустимое расстояние Хэмминга при чтении формата (код исправляет 3 ошибки).
+const MAX_FORMAT_ERRORS: u32 = 3;
+
+/// Символы режима alphanumeric.
+const ALPHANUMERIC: &[u8; 45] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+/// Сетка модулей QR-кода: `true` — тёмный модуль.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModuleGrid {
+    size: usize,
+    modules: Vec<bool>,
+}
+
+impl ModuleGrid {
+    pub fn new(size: usize, modules: Vec<bool>) -> Self {
+        debug_assert_eq!(modules.len(), size * size);
+        Self { size, modules }
+    }
+
+    fn get(&self, x: usize, y: usize) -> bool {
+        self.modules[y * self.size + x]
+    }
+
+    /// Зеркальная сетка (код, снятый с обратной стороны плёнки или экрана).
+    pub fn transposed(&self) -> Self {
+        let size = self.size;
+        let modules = (0..size * size)
+            .map(|i| self.get(i / size, i % size))
+            .collect();
+        Self { size, modules }
+    }
+}
+
+/// Версия по стороне сетки.
+pub fn version_for_size(size: usize) -> Option<usize> {
+    ((21..=177).contains(&size) && size % 4 == 1).then(|| (size - 17) / 4)
+}
+
+/// Позиции центров alignment patterns по каждой оси.
+pub fn alignment_positions(version: usize) -> Vec<usize> {
+    if version == 1 {
+        return Vec::new();
+    }
+    let size = version * 4 + 17;
+    let count = version / 7 + 2;
+    let step = if version == 32 {
+        26
+    } else {
+        (version * 4 + count * 2 + 1) / (count * 2 - 2) * 2
+    };
+    let mut positions = vec![6];
+    positions.extend((0..count - 1).rev().map(|i| size - 7 - i * step));
+    positions
+}
+
+/// Число модулей под данные и коррекцию (без служебных областей).
+fn num_raw_data_modules(version: usize) -> usize {
+    let mut result = (16 * version + 128) * version + 64;
+    if version >= 2 {
+        let count = version / 7 + 2;
+        result -= (25 * count - 10) * count - 55;
+        if version >= 7 {
+            result -= 36;
+        }
+    }
+    result
+}
+
+/// Декодирует сетку; `None` — формат или данные не читаются.
+pub fn decode(grid: &ModuleGrid) -> Option<String> {
+    let version = version_for_size(grid.size)?;
+    let (ecc_level, mask) = read_format(grid)?;
+    let codewords = read_codewords(grid, version, mask);
+    let data = correct_blocks(&codewords, version, ecc_level)?;
+    parse_segments(&data, version)
+}
+
+/// Уровень коррекции (индекс в таблицах: L, M, Q, H) и номер маски.
+fn read_format(grid: &ModuleGrid) -> Option<(usize, usize)> {
+    let size = grid.size;
+    let bit = |x: usize, y: usize, i: usize| u32::from(grid.get(x, y)) << i;
+
+    // Первая копия — вокруг левого верхнего finder pattern
+    let mut first = 0;
+    for i in 0..=5 {
+        first |= bit(8, i, i);
+    }
+    first |= bit(8, 7, 6) | bit(8, 8, 7) | bit(7, 8, 8);
+    for i in 9..15 {
+        first |= bit(14 - i, 8, i);
+    }
+    // Вторая — у правого верхнего и левого нижнего
+    let mut second = 0;
+    for i in 0..8 {
+        second |= bit(size - 1 - i, 8, i);
+    }
+    for i in 8..15 {
+        second |= bit(8, size - 15 + i, i);
+    }
+
+    (0..32u32)
+        .map(|data| {
+            let mut remainder = data;
+            for _ in 0..10 {
+                remainder = (remainder << 1) ^ ((remainder >> 9) * 0x537);
+            }
+            let expected = ((data << 10) | remainder) ^ FORMAT_MASK;
+            let distance = (first ^ expected)
+                .count_ones()
+                .min((second ^ expected).count_ones());
+            (distance, data)
+        })
+        .min_by_key(|(distance, _)| *distance)
+        .filter(|(distance, _)| *distance <= MAX_FORMAT_ERRORS)
+        .map(|(_, data)| {
+            // Биты уровня: L = 01, M = 00, Q = 11, H = 10
+            let level = match data >> 3 {
+                1 => 0,
+                0 => 1,
+                3 => 2,
+                _ => 3,
+            };
+            (level, (data & 7) as usize)
+        })
+}
+
+fn mask_bit(mask: usize, x: usize, y: usize) -> bool {
+    match mask {
+        0 => (x + y).is_multiple_of(2),
+        1 => y.is_multiple_of(2),
+        2 => x.is_multiple_of(3),
+        3 => (x + y).is_multiple_of(3),
+        4 => (x / 3 + y / 2).is_multiple_of(2),
+        5 => x * y % 2 + x * y % 3 == 0,
+        6 => (x * y % 2 + x * y % 3).is_multiple_of(2),
+        _ => ((x + y) % 2 + x * y % 3).is_multiple_of(2),
+    }
+}
+
+/// Служебные модули: finder patterns с разделителями и форматом, timing,
+/// alignment patterns, информация о версии.
+fn function_modules(version: usize) -> Vec<bool> {
+    let size = version * 4 + 17;
+    let mut function = vec![false; size * size];
+    let mut mark = |x: usize, y: usize| function[y * size + x] = true;
+    for y in 0..size {
+        for x in 0..size {
+            let finder = (y < 9 && (x < 9 || x >= size - 8)) || (x < 9 && y >= size - 8);
+            if finder || x == 6 || y == 6 {
+                mark(x, y);
+            }
+        }
+    }
+    let positions = alignment_positions(version);
+    let last = positions.len().saturating_sub(1);
+    for (i, &cy) in positions.iter().enumerate() {
+        for (j, &cx) in positions.iter().enumerate() {
+            // Пересечения с finder patterns
+            if (i == 0 && (j == 0 || j == last)) || (i == last && j == 0) {
+                continue;
+            }
+            for y in cy - 2..=cy + 2 {
+                for x in cx - 2..=cx + 2 {
+                    mark(x, y);
+                }
+            }
+        }
+    }
+    if version >= 7 {
+        for i in 0..18 {
+            let (a, b) = (size - 11 + i % 3, i / 3);
+            mark(a, b);
+            mark(b, a);
+        }
+    }
+    function
+}
+
+/// Кодовые слова в порядке размещения (зигзагом по парам столбцов справа).
+fn read_codewords(grid: &ModuleGrid, version: usize, mask: usize) -> Vec<u8> {
+    let size = grid.size;
+    let function = function_modules(version);
+    let total = num_raw_data_modules(version) / 8;
+    let mut codewords = vec![0u8; total];
+    let mut bit_index = 0;
+    let mut right = size - 1;
+    loop {
+        if right == 6 {
+            right = 5;
+        }
+        let upward = (right + 1) & 2 == 0;
+        for vertical in 0..size {
+            let y = if upward {
+                size - 1 - vertical
+            } else {
+                vertical
+            };
+            for x in [right, right - 1] {
+                if function[y * size + x] || bit_index >= total * 8 {
+                    continue;
+                }
+                if grid.get(x, y) ^ mask_bit(mask, x, y) {
+                    codewords[bit_index / 8] |= 0x80 >> (bit_index % 8);
+                }
+                bit_index += 1;
+            }
+        }
+        if right < 2 {
+            break;
+        }
+        right -= 2;
+    }
+    codewords
+}
+
+/// Разводит чередующиеся блоки, исправляет ошибки и склеивает данные.
+fn correct_blocks(codewords: &[u8], version: usize, ecc_level: usize) -> Option<Vec<u8>> {
+    let num_blocks = NUM_ERROR_CORRECTION_BLOCKS[ecc_level][version] as usize;
+    let ec_len = ECC_CODEWORDS_PER_BLOCK[ecc_level][version] as usize;
+    let total = codewords.len();
+    let num_short = num_blocks - total % num_blocks;
+    let short_len = total / num_blocks;
+    if short_len <= ec_len {
+        return None;
+    }
+
+    // Порядок как при записи: короткие блоки пропускают последнюю позицию данных
+    let mut blocks: Vec<Vec<u8>> = vec![Vec::with_capacity(short_len + 1); num_blocks];
+    let mut source = codewords.iter();
+    for i in 0..=short_len {
+        for (j, block) in blocks.iter_mut().enumerate() {
+            if i != short_len - ec_len || j >= num_short {
+                block.push(*source.next()?);
+            }
+        }
+    }
+
+    let mut data = Vec::with_capacity(total - num_blocks * ec_len);
+    for mut block in blocks {
+        reed_solomon::correct(&mut block, ec_len)?;
+        data.extend_from_slice(&block[..block.len() - ec_len]);
+    }
+    Some(data)
+}
+
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl BitReader<'_> {
+    fn remaining(&self) -> usize {
+        self.data.len() * 8 - self.position
+    }
+
+    fn read(&mut self, bits: usize) -> Option<u32> {
+        if bits > self.remaining() {
+            return None;
+        }
+        let mut value = 0;
+        for _ in 0..bits {
+            let byte = self.data[self.position / 8];
+            value = (value << 1) | u32::from((byte >> (7 - self.position % 8)) & 1);
+            self.position += 1;
+        }
+        Some(value)
+    }
+}
+
+/// Кодировка байтового режима, заданная ECI.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Charset {
+    /// Не задана: UTF-8, если байты корректны, иначе ISO-8859-1.
+    Auto,
+    Utf8,
+    Latin1,
+}
+
+fn parse_segments(data: &[u8], version: usize) -> Option<String> {
+    let group = match version {
+        1..=9 => 0,
+        10..=26 => 1,
+        _ => 2,
+    };
+    let mut reader = BitReader { data, position: 0 };
+    let mut bytes = Vec::new();
+    let mut charset = Charset::Auto;
+    while reader.remaining() >= 4 {
+        match reader.read(4)? {
+            0b0000 => break,
+            // Numeric
+            0b0001 => {
+                let mut count = reader.read([10, 12, 14][group])? as usize;
+                while count > 0 {
+                    let (digits, bits) = match count {
+                        1 => (1, 4),
+                        2 => (2, 7),
+                        _ => (3, 10),
+                    };
+                    let value = reader.read(bits)?;
+                    if value >= 10u32.pow(digits) {
+                        return None;
+                    }
+                    bytes.extend(format!("{value:0width$}", width = digits as usize).bytes());
+                    count -= digits as usize;
+                }
+            }
+            // Alphanumeric
+            0b0010 => {
+                let mut count = reader.read([9, 11, 13][group])? as usize;
+                while count >= 2 {
+                    let value = reader.read(11)? as usize;
+                    bytes.push(*ALPHANUMERIC.get(value / 45)?);
+                    bytes.push(*ALPHANUMERIC.get(value % 45)?);
+                    count -= 2;
+                }
+                if count == 1 {
+                    bytes.push(*ALPHANUMERIC.get(reader.read(6)? as usize)?);
+                }
+            }
+            // Byte
+            0b0100 => {
+                let count = reader.read([8, 16, 16][group])?;
+                for _ in 0..count {
+                    bytes.push(reader.read(8)? as u8);
+                }
+            }
+            // Kanji: Shift_JIS не декодируется — символ замены на каждый знак
+            0b1000 => {
+                let count = reader.read([8, 10, 12][group])?;
+                for _ in 0..count {
+                    reader.read(13)?;
+                    bytes.extend_from_slice("\u{FFFD}".as_bytes());
+                }
+            }
+            // ECI
+            0b0111 => {
+                let first = reader.read(8)?;
+                let designator = if first & 0x80 == 0 {
+                    first
+                } else if first & 0xC0 == 0x80 {
+                    ((first & 0x3F) << 8) | reader.read(8)?
+                } else {
+                    ((first & 0x1F) << 16) | reader.read(16)?
+                };
+                charset = match designator {
+                    26 => Charset::Utf8,
+                    1 | 3 => Charset::Latin1,
+                    _ => Charset::Auto,
+                };
+            }
+            // Structured append: номер части и чётность
+            0b0011 => {
+                reader.read(16)?;
+            }
+            // FNC1 в первой позиции
+            0b0101 => {}
+            // FNC1 во второй позиции: идентификатор приложения
+            0b1001 => {
+                reader.read(8)?;
+            }
+            _ => return None,
+        }
+    }
+
+    let text = match (charset, String::from_utf8(bytes)) {
+        (Charset::Latin1, Err(e)) => e.into_bytes().iter().map(|&b| char::from(b)).collect(),
+        (Charset::Latin1, Ok(text)) if !text.is_ascii() => text.bytes().map(char::from).collect(),
+        (_, Ok(text)) => text,
+        (_, Err(e)) => e.into_bytes().iter().map(|&b| char::from(b)).collect(),
+    };
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use qrcode::{Color, EcLevel, QrCode, Version};
+
+    use super::*;
+
+    fn grid_of(code: &QrCode) -> ModuleGrid {
+        ModuleGrid::new(code.width(), dark_modules(code))
+    }
+
+    fn dark_modules(code: &QrCode) -> Vec<bool> {
+        code.to_colors().iter().map(|&c| c == Color::Dark).collect()
+    }
+
+    #[test]
+    fn test_decodes_reference_codes() {
+        let cases: [(&[u8], i16, EcLevel); 6] = [
+            (b"https://example.com/invoice/42", 3, EcLevel::M),
+            (b"0123456789012345", 1, EcLevel::H),
+            (b"HELLO WORLD $%*+-./:", 2, EcLevel::Q),
+            ("Счёт №17 — 1 250,00 ₽".as_bytes(), 5, EcLevel::L),
+            (&[b'x'; 300], 13, EcLevel::M),
+            (&[b'7'; 900], 27, EcLevel::H),
+        ];
+        for (data, version, level) in cases {
+            let code = QrCode::with_version(data, Version::Normal(version), level).unwrap();
+            let text = decode(&grid_of(&code));
+            assert_eq!(
+                text.as_deref().map(str::as_bytes),
+                Some(data),
+                "version {version}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_corrects_damaged_and_mirrored_codes() {
+        let code =
+            QrCode::with_version(b"ST00012|Name=OOO Romashka", Version::Normal(4), EcLevel::M)
+                .unwrap();
+        let grid = grid_of(&code);
+        let size = code.width();
+
+        // Пятно в области данных
+        let mut modules = dark_modules(&code);
+        for y in 20..24 {
+            for x in 20..24 {
+                modules[y * size + x] = !modules[y * size + x];
+            }
+        }
+        let damaged = ModuleGrid::new(size, modules);
+        assert_eq!(
+            decode(&damaged).as_deref(),
+            Some("ST00012|Name=OOO Romashka")
+        );
+
+        let mirrored = grid.transposed();
+        assert_eq!(decode(&mirrored), None);
+        assert_eq!(
+            decode(&mirrored.transposed()).as_deref(),
+            Some("ST00012|Name=OOO Romashka")
+        );
+    }
+
+    #[test]
+    fn test_alignment_positions() {
+        assert!(alignment_positions(1).is_empty());
+        assert_eq!(alignment_positions(2), vec![6, 18]);
+        assert_eq!(alignment_positions(7), vec![6, 22, 38]);
+        assert_eq!(alignment_positions(32), vec![6, 34, 60, 86, 112, 138]);
+        assert_eq!(alignment_positions(40), vec![6, 30, 58, 86, 114, 142, 170]);
+    }
+}
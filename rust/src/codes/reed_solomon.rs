@@ -0,0 +1,203 @@
+//! Исправление ошибок Рида–Соломона над GF(256) для QR-кодов.
+//!
+//! Поле — по модулю x⁸ + x⁴ + x³ + x² + 1 (0x11D), корни порождающего
+//! многочлена — α⁰…α^(n−1). Ошибки находятся алгоритмом
+//! Берлекэмпа–Месси, их значения — по формуле Форни.
+
+/// Таблицы степеней и логарифмов α; `EXP` продублирована, чтобы сумма двух
+/// логарифмов не требовала взятия по модулю.
+const TABLES: ([u8; 512], [u8; 256]) = build_tables();
+const EXP: [u8; 512] = TABLES.0;
+const LOG: [u8; 256] = TABLES.1;
+
+const fn build_tables() -> ([u8; 512], [u8; 256]) {
+    let mut exp = [0u8; 512];
+    let mut log = [0u8; 256];
+    let mut x: u16 = 1;
+    let mut i = 0;
+    while i < 255 {
+        exp[i] = x as u8;
+        log[x as usize] = i as u8;
+        x <<= 1;
+        if x & 0x100 != 0 {
+            x ^= 0x11D;
+        }
+        i += 1;
+    }
+    while i < 512 {
+        exp[i] = exp[i - 255];
+        i += 1;
+    }
+    (exp, log)
+}
+
+fn mul(a: u8, b: u8) -> u8 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        EXP[LOG[a as usize] as usize + LOG[b as usize] as usize]
+    }
+}
+
+/// `a / b`; `b` не равно нулю.
+fn div(a: u8, b: u8) -> u8 {
+    if a == 0 {
+        0
+    } else {
+        EXP[LOG[a as usize] as usize + 255 - LOG[b as usize] as usize]
+    }
+}
+
+/// α^power.
+fn alpha(power: usize) -> u8 {
+    EXP[power % 255]
+}
+
+/// Значение многочлена с коэффициентами по возрастанию степеней.
+fn eval(poly: &[u8], x: u8) -> u8 {
+    poly.iter().rev().fold(0, |acc, &c| mul(acc, x) ^ c)
+}
+
+/// Исправляет блок (данные + `ec_len` байт коррекции, старшая степень
+/// первой). Возвращает число исправленных байт; `None` — ошибок больше,
+/// чем позволяет коррекция.
+pub fn correct(block: &mut [u8], ec_len: usize) -> Option<usize> {
+    let n = block.len();
+    if ec_len == 0 || ec_len >= n || n > 255 {
+        return None;
+    }
+    // Синдромы S_i = c(α^i); c записан старшей степенью вперёд
+    let syndromes: Vec<u8> = (0..ec_len)
+        .map(|i| block.iter().fold(0, |acc, &c| mul(acc, alpha(i)) ^ c))
+        .collect();
+    if syndromes.iter().all(|&s| s == 0) {
+        return Some(0);
+    }
+
+    // Берлекэмп–Месси: многочлен локаторов ошибок Λ(x)
+    let mut lambda = vec![1u8];
+    let mut previous = vec![1u8];
+    let mut errors = 0usize;
+    let mut shift = 1usize;
+    let mut previous_discrepancy = 1u8;
+    for k in 0..ec_len {
+        let mut discrepancy = syndromes[k];
+        for i in 1..lambda.len().min(k + 1) {
+            discrepancy ^= mul(lambda[i], syndromes[k - i]);
+        }
+        if discrepancy == 0 {
+            shift += 1;
+            continue;
+        }
+        let coefficient = div(discrepancy, previous_discrepancy);
+        let mut next = lambda.clone();
+        if next.len() < previous.len() + shift {
+            next.resize(previous.len() + shift, 0);
+        }
+        for (i, &p) in previous.iter().enumerate() {
+            next[i + shift] ^= mul(coefficient, p);
+        }
+        if 2 * errors <= k {
+            previous = std::mem::replace(&mut lambda, next);
+            errors = k + 1 - errors;
+            previous_discrepancy = discrepancy;
+            shift = 1;
+        } else {
+            lambda = next;
+            shift += 1;
+        }
+    }
+    while lambda.last() == Some(&0) {
+        lambda.pop();
+    }
+    if lambda.len() != errors + 1 || 2 * errors > ec_len {
+        return None;
+    }
+
+    // Поиск Ченя: байт с индексом k — позиция X = α^(n−1−k), Λ(X⁻¹) = 0
+    let positions: Vec<usize> = (0..n)
+        .filter(|&k| eval(&lambda, alpha(255 - (n - 1 - k) % 255)) == 0)
+        .collect();
+    if positions.len() != errors {
+        return None;
+    }
+
+    // Форни: Ω(x) = S(x)·Λ(x) mod x^ec_len; e = X·Ω(X⁻¹) / Λ'(X⁻¹)
+    let mut omega = vec![0u8; ec_len];
+    for (i, &s) in syndromes.iter().enumerate() {
+        for (j, &l) in lambda.iter().enumerate().take(ec_len - i) {
+            omega[i + j] ^= mul(s, l);
+        }
+    }
+    for &k in &positions {
+        let x = alpha(n - 1 - k);
+        let x_inv = div(1, x);
+        // Формальная производная: в характеристике 2 остаются нечётные степени
+        let derivative: Vec<u8> = lambda
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, &c)| if i % 2 == 1 { c } else { 0 })
+            .collect();
+        let denominator = eval(&derivative, x_inv);
+        if denominator == 0 {
+            return None;
+        }
+        block[k] ^= mul(x, div(eval(&omega, x_inv), denominator));
+    }
+    Some(errors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Кодирует данные систематическим кодом с `ec_len` байтами коррекции.
+    fn encode(data: &[u8], ec_len: usize) -> Vec<u8> {
+        // g(x) = Π (x − α^i), коэффициенты старшей степенью вперёд
+        let mut generator = vec![1u8];
+        for i in 0..ec_len {
+            let mut next = vec![0u8; generator.len() + 1];
+            for (j, &g) in generator.iter().enumerate() {
+                next[j] ^= g;
+                next[j + 1] ^= mul(g, alpha(i));
+            }
+            generator = next;
+        }
+        let mut remainder = data.to_vec();
+        remainder.resize(data.len() + ec_len, 0);
+        for i in 0..data.len() {
+            let factor = remainder[i];
+            if factor != 0 {
+                for (j, &g) in generator.iter().enumerate() {
+                    remainder[i + j] ^= mul(g, factor);
+                }
+            }
+        }
+        let mut block = data.to_vec();
+        block.extend_from_slice(&remainder[data.len()..]);
+        block
+    }
+
+    #[test]
+    fn test_corrects_up_to_half_of_ec_bytes() {
+        let data: Vec<u8> = (0..40u8).map(|i| i.wrapping_mul(37) ^ 0x5A).collect();
+        let block = encode(&data, 10);
+
+        let mut clean = block.clone();
+        assert_eq!(correct(&mut clean, 10), Some(0));
+
+        let mut damaged = block.clone();
+        for (i, position) in [0, 7, 19, 33, 49].into_iter().enumerate() {
+            damaged[position] ^= 0x11 + i as u8;
+        }
+        assert_eq!(correct(&mut damaged, 10), Some(5));
+        assert_eq!(damaged, block);
+
+        let mut hopeless = block.clone();
+        for position in [1, 2, 3, 4, 5, 6, 8] {
+            hopeless[position] ^= 0xFF;
+        }
+        assert_ne!(correct(&mut hopeless, 10).map(|_| hopeless), Some(block));
+    }
+}
@@ -0,0 +1,408 @@
+//! Индекс файлов наблюдаемой папки: тип, размер и mtime каждого файла.
+//!
+//! Первый запрос обходит папку и определяет тип каждого файла по
+//! содержимому ([`file_type::sniff_mime_type`]); дальше индекс обновляется
+//! событиями watcher'а. Состав папки по категориям и отчёты для очистки
+//! (самые большие и давно не менявшиеся файлы) строятся без повторного
+//! обхода.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use crate::error::LateraError;
+use crate::file_type;
+use crate::file_watcher::{FileEventKind, InternalFileEvent};
+use crate::internal_files;
+
+/// Категория файла для статистики.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FileCategory {
+    Image,
+    Document,
+    Archive,
+    Other,
+}
+
+impl FileCategory {
+    /// Все категории в порядке вывода.
+    pub const ALL: [Self; 4] = [Self::Image, Self::Document, Self::Archive, Self::Other];
+
+    /// Категория по MIME-типу (`None` — тип неизвестен).
+    pub fn from_mime(mime_type: Option<&str>) -> Self {
+        let Some(mime_type) = mime_type else {
+            return Self::Other;
+        };
+        if mime_type.starts_with("image/") {
+            return Self::Image;
+        }
+        if mime_type.starts_with("text/") {
+            return Self::Document;
+        }
+        match mime_type {
+            "application/pdf"
+            | "application/rtf"
+            | "application/json"
+            | "application/xml"
+            | "application/epub+zip"
+            | "application/msword"
+            | "application/vnd.ms-excel"
+            | "application/vnd.ms-powerpoint"
+            | "application/vnd.ms-outlook" => Self::Document,
+            "application/zip"
+            | "application/vnd.rar"
+            | "application/x-7z-compressed"
+            | "application/gzip" => Self::Archive,
+            _ if mime_type.starts_with("application/vnd.openxmlformats-officedocument.")
+                || mime_type.starts_with("application/vnd.oasis.opendocument.") =>
+            {
+                Self::Document
+            }
+            _ => Self::Other,
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            Self::Image => 0,
+            Self::Document => 1,
+            Self::Archive => 2,
+            Self::Other => 3,
+        }
+    }
+}
+
+/// Число файлов и их суммарный размер.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CategoryTotals {
+    pub file_count: u64,
+    pub total_bytes: u64,
+}
+
+/// Файл в индексе.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IndexedFile {
+    pub category: FileCategory,
+    pub size: u64,
+    /// mtime (Unix timestamp в миллисекундах), если ФС его сообщает.
+    pub modified_at_ms: Option<i64>,
+}
+
+/// Индекс файлов одной папки.
+#[derive(Debug)]
+pub struct CompositionIndex {
+    root: PathBuf,
+    files: HashMap<PathBuf, IndexedFile>,
+    totals: [CategoryTotals; 4],
+}
+
+impl CompositionIndex {
+    /// Обходит `root` (рекурсивно) и строит индекс. Служебные файлы ядра
+    /// и недоступные записи пропускаются.
+    pub fn scan(root: &Path) -> Result<Self, LateraError> {
+        if !root.is_dir() {
+            return Err(LateraError::InvalidPath(format!(
+                "not a directory: {}",
+                root.display()
+            )));
+        }
+        let mut index = Self {
+            root: root.to_path_buf(),
+            files: HashMap::new(),
+            totals: [CategoryTotals::default(); 4],
+        };
+        index.add_tree(root);
+        Ok(index)
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Итоги по всем категориям (в порядке [`FileCategory::ALL`]).
+    pub fn composition(&self) -> Vec<(FileCategory, CategoryTotals)> {
+        FileCategory::ALL
+            .iter()
+            .map(|category| (*category, self.totals[category.index()]))
+            .collect()
+    }
+
+    /// Не больше `limit` самых больших файлов, по убыванию размера.
+    pub fn largest(&self, limit: usize) -> Vec<(PathBuf, IndexedFile)> {
+        let mut files: Vec<_> = self
+            .files
+            .iter()
+            .map(|(path, file)| (path.clone(), *file))
+            .collect();
+        files.sort_by(|a, b| b.1.size.cmp(&a.1.size).then_with(|| a.0.cmp(&b.0)));
+        files.truncate(limit);
+        files
+    }
+
+    /// Файлы, не менявшиеся с `cutoff_ms`, от самых старых. Файлы без
+    /// mtime не попадают в отчёт.
+    pub fn stale(&self, cutoff_ms: i64) -> Vec<(PathBuf, IndexedFile)> {
+        let mut files: Vec<_> = self
+            .files
+            .iter()
+            .filter(|(_, file)| file.modified_at_ms.is_some_and(|mtime| mtime < cutoff_ms))
+            .map(|(path, file)| (path.clone(), *file))
+            .collect();
+        files.sort_by(|a, b| {
+            a.1.modified_at_ms
+                .cmp(&b.1.modified_at_ms)
+                .then_with(|| a.0.cmp(&b.0))
+        });
+        files
+    }
+
+    /// Учитывает событие watcher'а. События вне папки игнорируются.
+    pub fn apply(&mut self, event: &InternalFileEvent) {
+        if !event.full_path.starts_with(&self.root) {
+            return;
+        }
+        match event.kind {
+            FileEventKind::Removed | FileEventKind::RenamedFrom => {
+                self.remove_tree(&event.full_path);
+            }
+            FileEventKind::Created | FileEventKind::Modified | FileEventKind::RenamedTo => {
+                if event.full_path.is_dir() {
+                    self.add_tree(&event.full_path);
+                } else if event.full_path.is_file() {
+                    let mime_type = event
+                        .mime_type
+                        .clone()
+                        .or_else(|| file_type::sniff_mime_type(&event.full_path));
+                    let metadata = std::fs::metadata(&event.full_path).ok();
+                    let size = event
+                        .size_bytes
+                        .or_else(|| metadata.as_ref().map(std::fs::Metadata::len));
+                    let modified_at_ms = event
+                        .modified_at_ms
+                        .or_else(|| metadata.as_ref().and_then(modified_ms));
+                    self.insert(
+                        event.full_path.clone(),
+                        IndexedFile {
+                            category: FileCategory::from_mime(mime_type.as_deref()),
+                            size: size.unwrap_or(0),
+                            modified_at_ms,
+                        },
+                    );
+                }
+            }
+        }
+        if let Some(previous) = &event.previous_path {
+            if event.kind == FileEventKind::RenamedTo && previous.starts_with(&self.root) {
+                self.remove_tree(previous);
+            }
+        }
+    }
+
+    fn add_tree(&mut self, dir: &Path) {
+        let mut pending = vec![dir.to_path_buf()];
+        while let Some(current) = pending.pop() {
+            let Ok(entries) = std::fs::read_dir(&current) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let Ok(file_type) = entry.file_type() else {
+                    continue;
+                };
+                let path = entry.path();
+                if file_type.is_dir() {
+                    pending.push(path);
+                    continue;
+                }
+                if !file_type.is_file() || internal_files::is_internal(&path) {
+                    continue;
+                }
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+                let file = IndexedFile {
+                    category: FileCategory::from_mime(file_type::sniff_mime_type(&path).as_deref()),
+                    size: metadata.len(),
+                    modified_at_ms: modified_ms(&metadata),
+                };
+                self.insert(path, file);
+            }
+        }
+    }
+
+    fn insert(&mut self, path: PathBuf, file: IndexedFile) {
+        if internal_files::is_internal(&path) {
+            return;
+        }
+        if let Some(previous) = self.files.insert(path, file) {
+            self.subtract(previous);
+        }
+        let totals = &mut self.totals[file.category.index()];
+        totals.file_count += 1;
+        totals.total_bytes += file.size;
+    }
+
+    /// Убирает файл или все файлы внутри папки `path`.
+    fn remove_tree(&mut self, path: &Path) {
+        if let Some(previous) = self.files.remove(path) {
+            self.subtract(previous);
+            return;
+        }
+        let nested: Vec<_> = self
+            .files
+            .keys()
+            .filter(|file| file.starts_with(path))
+            .cloned()
+            .collect();
+        for file in nested {
+            if let Some(previous) = self.files.remove(&file) {
+                self.subtract(previous);
+            }
+        }
+    }
+
+    fn subtract(&mut self, file: IndexedFile) {
+        let totals = &mut self.totals[file.category.index()];
+        totals.file_count = totals.file_count.saturating_sub(1);
+        totals.total_bytes = totals.total_bytes.saturating_sub(file.size);
+    }
+}
+
+fn modified_ms(metadata: &std::fs::Metadata) -> Option<i64> {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .and_then(|d| i64::try_from(d.as_millis()).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(kind: FileEventKind, path: &Path) -> InternalFileEvent {
+        InternalFileEvent {
+            kind,
+            file_name: path.file_name().unwrap().to_string_lossy().to_string(),
+            full_path: path.to_path_buf(),
+            previous_path: None,
+            occurred_at_ms: 0,
+            detected_at_ms: 0,
+            monotonic_ms: 0,
+            created_at_ms: None,
+            modified_at_ms: None,
+            size_bytes: None,
+            extension: None,
+            mime_type: None,
+            access: None,
+            self_generated: false,
+            reconciled: false,
+            claim: None,
+            debug: None,
+        }
+    }
+
+    fn totals(index: &CompositionIndex, category: FileCategory) -> (u64, u64) {
+        let totals = index.totals[category.index()];
+        (totals.file_count, totals.total_bytes)
+    }
+
+    #[test]
+    fn test_scan_and_incremental_updates() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::write(root.join("a.png"), b"\x89PNG\r\n\x1a\n1234").unwrap();
+        std::fs::write(root.join("notes.txt"), b"hello").unwrap();
+        std::fs::create_dir(root.join("sub")).unwrap();
+        std::fs::write(root.join("sub").join("b.zip"), b"PK\x03\x04zz").unwrap();
+        std::fs::write(root.join("sub").join("blob.bin"), b"\x00\x01\x02").unwrap();
+
+        let mut index = CompositionIndex::scan(root).unwrap();
+        assert_eq!(totals(&index, FileCategory::Image), (1, 12));
+        assert_eq!(totals(&index, FileCategory::Document), (1, 5));
+        assert_eq!(totals(&index, FileCategory::Archive), (1, 6));
+        assert_eq!(totals(&index, FileCategory::Other), (1, 3));
+
+        let pdf = root.join("c.pdf");
+        std::fs::write(&pdf, b"%PDF-1.7").unwrap();
+        index.apply(&event(FileEventKind::Created, &pdf));
+        assert_eq!(totals(&index, FileCategory::Document), (2, 13));
+
+        // Изменение не удваивает счёт
+        std::fs::write(&pdf, b"%PDF-1.7 more").unwrap();
+        index.apply(&event(FileEventKind::Modified, &pdf));
+        assert_eq!(totals(&index, FileCategory::Document), (2, 18));
+
+        index.apply(&event(FileEventKind::Removed, &root.join("sub")));
+        assert_eq!(totals(&index, FileCategory::Archive), (0, 0));
+        assert_eq!(totals(&index, FileCategory::Other), (0, 0));
+
+        // Вне папки — игнорируется
+        index.apply(&event(
+            FileEventKind::Removed,
+            Path::new("/elsewhere/a.png"),
+        ));
+        assert_eq!(totals(&index, FileCategory::Image), (1, 12));
+    }
+
+    #[test]
+    fn test_largest_and_stale_reports() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let root = temp_dir.path();
+        let now = std::time::SystemTime::now();
+        for (name, size, age_days) in [
+            ("small.txt", 10, 400),
+            ("big.bin", 300, 10),
+            ("mid.txt", 50, 90),
+        ] {
+            let path = root.join(name);
+            std::fs::write(&path, vec![b'a'; size]).unwrap();
+            std::fs::File::options()
+                .write(true)
+                .open(&path)
+                .and_then(|f| f.set_modified(now - std::time::Duration::from_hours(24 * age_days)))
+                .unwrap();
+        }
+        let index = CompositionIndex::scan(root).unwrap();
+
+        let largest: Vec<_> = index
+            .largest(2)
+            .into_iter()
+            .map(|(path, file)| (path.file_name().unwrap().to_owned(), file.size))
+            .collect();
+        assert_eq!(
+            largest,
+            vec![("big.bin".into(), 300), ("mid.txt".into(), 50)]
+        );
+
+        let cutoff = modified_ms(&std::fs::metadata(root.join("big.bin")).unwrap()).unwrap() - 1;
+        let stale: Vec<_> = index
+            .stale(cutoff)
+            .into_iter()
+            .map(|(path, _)| path.file_name().unwrap().to_owned())
+            .collect();
+        assert_eq!(stale, vec!["small.txt", "mid.txt"]);
+    }
+
+    #[test]
+    fn test_category_from_mime() {
+        assert_eq!(
+            FileCategory::from_mime(Some("image/heic")),
+            FileCategory::Image
+        );
+        assert_eq!(
+            FileCategory::from_mime(Some(
+                "application/vnd.openxmlformats-officedocument.wordprocessingml.document"
+            )),
+            FileCategory::Document
+        );
+        assert_eq!(
+            FileCategory::from_mime(Some("application/x-7z-compressed")),
+            FileCategory::Archive
+        );
+        assert_eq!(
+            FileCategory::from_mime(Some("video/mp4")),
+            FileCategory::Other
+        );
+        assert_eq!(FileCategory::from_mime(None), FileCategory::Other);
+    }
+}
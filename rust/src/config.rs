@@ -0,0 +1,350 @@
+//! Постоянные настройки ядра на этой машине: `{config_dir}/Latera/config.json`.
+//!
+//! В отличие от [`crate::portable_config`] (снимок для развёртывания на
+//! многих машинах) это настройки одной установки, которые раньше были
+//! зашиты в код: сглаживание событий watcher'а, папки наблюдения, фильтр
+//! файлов и уровень логов. Файл читается при `init_core` и переписывается
+//! при `update_config`.
+//!
+//! ```json
+//! {"dedup_window_ms":300,"rate_limit_per_second":200,
+//!  "watch_paths":["/Users/a/Desktop/Latera"],
+//!  "filter":{"include_globs":[],"exclude_globs":["*.tmp"],"extensions":[],
+//!            "include_hidden":false},
+//!  "log_level":"debug"}
+//! ```
+//!
+//! Отсутствующие поля принимают значения по умолчанию.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use log::LevelFilter;
+use serde_json::{json, Value};
+
+use crate::error::LateraError;
+use crate::file_watcher::{self, WatchFilter, WatcherOptions};
+use crate::portable_config::{invalid, tmp_path, Object};
+
+/// Переменная окружения с путём файла настроек (вместо пути по умолчанию).
+pub const CONFIG_FILE_ENV: &str = "LATERA_CONFIG_FILE";
+
+/// Имя файла настроек в папке конфигурации приложения.
+pub const CONFIG_FILE_NAME: &str = "config.json";
+
+/// Наибольшее окно дедупликации: дольше — уже не «дребезг», а новые события.
+pub const MAX_DEDUP_WINDOW: Duration = Duration::from_secs(10);
+
+/// Фильтр файлов (см. [`WatchFilter`]).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FilterConfig {
+    pub include_globs: Vec<String>,
+    pub exclude_globs: Vec<String>,
+    pub extensions: Vec<String>,
+    pub include_hidden: bool,
+}
+
+impl FilterConfig {
+    /// Фильтр watcher'а; некорректный glob — ошибка.
+    pub fn to_filter(&self) -> Result<WatchFilter, LateraError> {
+        WatchFilter::new(
+            &self.include_globs,
+            &self.exclude_globs,
+            &self.extensions,
+            self.include_hidden,
+        )
+    }
+}
+
+/// Настройки ядра.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// См. [`WatcherOptions::dedup_window`].
+    pub dedup_window: Duration,
+    /// См. [`WatcherOptions::rate_limit_per_second`].
+    pub rate_limit_per_second: u32,
+    /// Папки, за которыми наблюдать (абсолютные пути).
+    pub watch_paths: Vec<PathBuf>,
+    pub filter: FilterConfig,
+    /// `None` — уровень по умолчанию (`RUST_LOG` или `info`).
+    pub log_level: Option<LevelFilter>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            dedup_window: file_watcher::DEFAULT_DEDUP_WINDOW,
+            rate_limit_per_second: file_watcher::DEFAULT_RATE_LIMIT_PER_SECOND,
+            watch_paths: Vec::new(),
+            filter: FilterConfig::default(),
+            log_level: None,
+        }
+    }
+}
+
+/// Изменение настроек: `None` — поле не меняется.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfigPatch {
+    pub dedup_window: Option<Duration>,
+    pub rate_limit_per_second: Option<u32>,
+    pub watch_paths: Option<Vec<PathBuf>>,
+    /// Заменяет фильтр целиком.
+    pub filter: Option<FilterConfig>,
+    /// `Some(None)` — вернуть уровень по умолчанию.
+    pub log_level: Option<Option<LevelFilter>>,
+}
+
+impl Config {
+    /// Настройки с применённым `patch`; некорректное значение — ошибка.
+    pub fn patched(&self, patch: ConfigPatch) -> Result<Self, LateraError> {
+        let config = Self {
+            dedup_window: patch.dedup_window.unwrap_or(self.dedup_window),
+            rate_limit_per_second: patch
+                .rate_limit_per_second
+                .unwrap_or(self.rate_limit_per_second),
+            watch_paths: patch
+                .watch_paths
+                .unwrap_or_else(|| self.watch_paths.clone()),
+            filter: patch.filter.unwrap_or_else(|| self.filter.clone()),
+            log_level: patch.log_level.unwrap_or(self.log_level),
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<(), LateraError> {
+        check_event_tuning(self.dedup_window, self.rate_limit_per_second).map_err(invalid)?;
+        if let Some(path) = self.watch_paths.iter().find(|path| !path.is_absolute()) {
+            return Err(invalid(format!(
+                "watch path must be absolute: {}",
+                path.display()
+            )));
+        }
+        self.filter.to_filter()?;
+        Ok(())
+    }
+
+    /// Переносит сглаживание и фильтр в настройки watcher'а.
+    pub fn apply_to(&self, options: &mut WatcherOptions) -> Result<(), LateraError> {
+        options.filter = self.filter.to_filter()?;
+        options.dedup_window = self.dedup_window;
+        options.rate_limit_per_second = self.rate_limit_per_second;
+        Ok(())
+    }
+
+    /// Отпечаток настроек, влияющих на то, какие события папки видит
+    /// подписчик: сглаживание и фильтр (без списка папок и уровня логов).
+    pub fn settings_hash(&self) -> String {
+        let mut value = self.to_json();
+        if let Some(map) = value.as_object_mut() {
+            map.remove("watch_paths");
+            map.remove("log_level");
+        }
+        blake3::hash(value.to_string().as_bytes()).to_hex()[..16].to_string()
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "dedup_window_ms": u64::try_from(self.dedup_window.as_millis()).unwrap_or(u64::MAX),
+            "rate_limit_per_second": self.rate_limit_per_second,
+            "watch_paths": self
+                .watch_paths
+                .iter()
+                .map(|path| path.to_string_lossy())
+                .collect::<Vec<_>>(),
+            "filter": {
+                "include_globs": self.filter.include_globs,
+                "exclude_globs": self.filter.exclude_globs,
+                "extensions": self.filter.extensions,
+                "include_hidden": self.filter.include_hidden,
+            },
+            "log_level": self.log_level.map(|level| level.as_str().to_lowercase()),
+        })
+    }
+
+    pub fn from_json(value: &Value) -> Result<Self, LateraError> {
+        let root = Object::new(value, "config")?;
+        let defaults = Self::default();
+        let filter = match root.get("filter") {
+            Some(value) => {
+                let filter = Object::new(value, "filter")?;
+                FilterConfig {
+                    include_globs: filter.strings("include_globs")?,
+                    exclude_globs: filter.strings("exclude_globs")?,
+                    extensions: filter.strings("extensions")?,
+                    include_hidden: filter.bool("include_hidden")?.unwrap_or(false),
+                }
+            }
+            None => FilterConfig::default(),
+        };
+        let log_level = root
+            .str("log_level")?
+            .map(|level| {
+                level
+                    .parse::<LevelFilter>()
+                    .map_err(|_| invalid(format!("unknown log_level {level:?}")))
+            })
+            .transpose()?;
+        let rate_limit_per_second = match root.u64("rate_limit_per_second")? {
+            Some(rate) => u32::try_from(rate)
+                .map_err(|_| root.wrong_type("rate_limit_per_second", "a 32-bit integer"))?,
+            None => defaults.rate_limit_per_second,
+        };
+        let config = Self {
+            dedup_window: root
+                .u64("dedup_window_ms")?
+                .map_or(defaults.dedup_window, Duration::from_millis),
+            rate_limit_per_second,
+            watch_paths: root
+                .strings("watch_paths")?
+                .into_iter()
+                .map(PathBuf::from)
+                .collect(),
+            filter,
+            log_level,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Читает настройки из `path`; нет файла — настройки по умолчанию.
+    pub fn load(path: &Path) -> Result<Self, LateraError> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e.into()),
+        };
+        let value: Value = serde_json::from_str(&text)
+            .map_err(|e| invalid(format!("config is not valid JSON: {e}")))?;
+        Self::from_json(&value)
+    }
+
+    /// Записывает настройки в `path` (через временный файл).
+    pub fn save(&self, path: &Path) -> Result<(), LateraError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp_path = tmp_path(path);
+        {
+            let mut file = std::fs::File::create(&tmp_path)?;
+            let text =
+                serde_json::to_string_pretty(&self.to_json()).map_err(std::io::Error::other)?;
+            file.write_all(text.as_bytes())?;
+            file.write_all(b"\n")?;
+            file.sync_all()?;
+        }
+        std::fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+}
+
+/// Проверяет сглаживание событий watcher'а; `Err` — описание ошибки.
+pub fn check_event_tuning(
+    dedup_window: Duration,
+    rate_limit_per_second: u32,
+) -> Result<(), String> {
+    if dedup_window > MAX_DEDUP_WINDOW {
+        return Err(format!(
+            "dedup_window_ms must not exceed {}",
+            MAX_DEDUP_WINDOW.as_millis()
+        ));
+    }
+    if rate_limit_per_second == 0 {
+        return Err("rate limit must be positive".to_string());
+    }
+    Ok(())
+}
+
+/// Путь файла настроек: [`CONFIG_FILE_ENV`] или
+/// `{config_dir}/Latera/config.json`.
+pub fn config_path() -> Result<PathBuf, LateraError> {
+    if let Some(path) = std::env::var_os(CONFIG_FILE_ENV).filter(|p| !p.is_empty()) {
+        return Ok(PathBuf::from(path));
+    }
+    dirs::config_dir()
+        .map(|dir| dir.join("Latera").join(CONFIG_FILE_NAME))
+        .ok_or_else(|| {
+            LateraError::InvalidPath(
+                "Config directory is not available on this OS/user".to_string(),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_patch_round_trips_through_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("Latera").join(CONFIG_FILE_NAME);
+        assert_eq!(Config::load(&path).unwrap(), Config::default());
+
+        let watch_path = temp_dir.path().join("Inbox");
+        let config = Config::default()
+            .patched(ConfigPatch {
+                dedup_window: Some(Duration::from_millis(50)),
+                watch_paths: Some(vec![watch_path.clone()]),
+                filter: Some(FilterConfig {
+                    exclude_globs: vec!["*.tmp".to_string()],
+                    ..FilterConfig::default()
+                }),
+                log_level: Some(Some(LevelFilter::Debug)),
+                ..ConfigPatch::default()
+            })
+            .unwrap();
+        assert_eq!(
+            config.rate_limit_per_second,
+            file_watcher::DEFAULT_RATE_LIMIT_PER_SECOND
+        );
+        config.save(&path).unwrap();
+        let loaded = Config::load(&path).unwrap();
+        assert_eq!(loaded, config);
+        assert_eq!(loaded.watch_paths, vec![watch_path]);
+
+        let mut options = WatcherOptions::default();
+        loaded.apply_to(&mut options).unwrap();
+        assert_eq!(options.dedup_window, Duration::from_millis(50));
+
+        let reset = loaded
+            .patched(ConfigPatch {
+                log_level: Some(None),
+                ..ConfigPatch::default()
+            })
+            .unwrap();
+        assert_eq!(reset.log_level, None);
+        assert_eq!(reset.dedup_window, Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_invalid_values_are_rejected() {
+        let config = Config::default();
+        for patch in [
+            ConfigPatch {
+                rate_limit_per_second: Some(0),
+                ..ConfigPatch::default()
+            },
+            ConfigPatch {
+                dedup_window: Some(MAX_DEDUP_WINDOW * 2),
+                ..ConfigPatch::default()
+            },
+            ConfigPatch {
+                watch_paths: Some(vec![PathBuf::from("relative/dir")]),
+                ..ConfigPatch::default()
+            },
+            ConfigPatch {
+                filter: Some(FilterConfig {
+                    include_globs: vec!["[".to_string()],
+                    ..FilterConfig::default()
+                }),
+                ..ConfigPatch::default()
+            },
+        ] {
+            assert!(config.patched(patch).is_err());
+        }
+
+        let value = json!({ "log_level": "loud" });
+        assert!(Config::from_json(&value).is_err());
+    }
+}
@@ -0,0 +1,227 @@
+//! Доступ к защищённым папкам пользователя (macOS TCC, Windows Controlled
+//! Folder Access).
+//!
+//! На macOS «Рабочий стол», «Документы» и «Загрузки» защищены TCC: пока
+//! пользователь не разрешил доступ, чтение и создание файлов в них
+//! завершаются `EPERM`, что выглядит как обычная ошибка ввода-вывода.
+//! Здесь такие отказы распознаются и превращаются в
+//! [`LateraError::PermissionDenied`] с подсказкой, где выдать разрешение.
+//!
+//! На Windows похожим образом работает контролируемый доступ к папкам
+//! (Microsoft Defender): запись в «Рабочий стол», «Документы» и другие
+//! защищённые папки разрешена только доверенным приложениям, остальные
+//! получают `ERROR_ACCESS_DENIED`. Такой отказ —
+//! [`LateraError::ControlledFolderAccessBlocked`].
+
+use std::path::Path;
+
+use crate::error::LateraError;
+use crate::path_utils;
+
+/// Разрешение на доступ к «Рабочему столу».
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DesktopAccessStatus {
+    /// Папка читается.
+    Granted,
+    /// Система отказала в доступе (пользователь не дал разрешения).
+    Denied,
+    /// На этой ОС разрешение не нужно.
+    NotRequired,
+    /// Папки нет или её не удалось прочитать по другой причине.
+    Unknown,
+}
+
+/// Текущее состояние доступа к «Рабочему столу».
+///
+/// Проверка — чтение содержимого папки; если пользователь ещё не отвечал
+/// на запрос, macOS покажет его.
+pub fn desktop_access_status() -> DesktopAccessStatus {
+    if !cfg!(target_os = "macos") {
+        return DesktopAccessStatus::NotRequired;
+    }
+    let Some(desktop) = dirs::desktop_dir() else {
+        return DesktopAccessStatus::Unknown;
+    };
+    match std::fs::read_dir(&desktop) {
+        Ok(_) => DesktopAccessStatus::Granted,
+        Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => DesktopAccessStatus::Denied,
+        Err(_) => DesktopAccessStatus::Unknown,
+    }
+}
+
+/// Режим контролируемого доступа к папкам (Windows).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ControlledFolderAccess {
+    /// Запись в защищённые папки блокируется.
+    Enabled,
+    /// Запись разрешена, но попадает в журнал Defender.
+    AuditOnly,
+    Disabled,
+    /// Не Windows.
+    NotSupported,
+}
+
+/// Где в Windows Security разрешают приложению запись в защищённые папки.
+pub const CONTROLLED_FOLDER_ACCESS_SETTINGS_URI: &str = "windowsdefender://RansomwareProtection";
+
+/// Текущий режим контролируемого доступа к папкам.
+///
+/// Читается из реестра: сначала групповая политика, затем настройка
+/// пользователя. Нет значения — режим выключен (так по умолчанию).
+pub fn controlled_folder_access() -> ControlledFolderAccess {
+    if !cfg!(target_os = "windows") {
+        return ControlledFolderAccess::NotSupported;
+    }
+    // 1 — блокировка; 2 — аудит; 3 и 4 — только защита дисков, не папок.
+    match read_controlled_folder_access_setting() {
+        Some(1) => ControlledFolderAccess::Enabled,
+        Some(2) => ControlledFolderAccess::AuditOnly,
+        _ => ControlledFolderAccess::Disabled,
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn read_controlled_folder_access_setting() -> Option<u32> {
+    use windows::core::{w, PCWSTR};
+    use windows::Win32::Foundation::ERROR_SUCCESS;
+    use windows::Win32::System::Registry::{RegGetValueW, HKEY_LOCAL_MACHINE, RRF_RT_REG_DWORD};
+
+    const KEYS: [PCWSTR; 2] = [
+        w!(
+            r"SOFTWARE\Policies\Microsoft\Windows Defender\Windows Defender Exploit Guard\Controlled Folder Access"
+        ),
+        w!(
+            r"SOFTWARE\Microsoft\Windows Defender\Windows Defender Exploit Guard\Controlled Folder Access"
+        ),
+    ];
+    KEYS.into_iter().find_map(|key| {
+        let mut value: u32 = 0;
+        let mut size = std::mem::size_of::<u32>() as u32;
+        // SAFETY: буфер — u32 на стеке, его размер передан в `size`.
+        let status = unsafe {
+            RegGetValueW(
+                HKEY_LOCAL_MACHINE,
+                key,
+                w!("EnableControlledFolderAccess"),
+                RRF_RT_REG_DWORD,
+                None,
+                Some(std::ptr::from_mut(&mut value).cast()),
+                Some(std::ptr::from_mut(&mut size)),
+            )
+        };
+        (status == ERROR_SUCCESS).then_some(value)
+    })
+}
+
+#[cfg(not(target_os = "windows"))]
+fn read_controlled_folder_access_setting() -> Option<u32> {
+    None
+}
+
+/// Защищена ли папка контролируемым доступом (стандартный набор Windows).
+fn is_controlled_folder(path: &Path) -> bool {
+    cfg!(target_os = "windows")
+        && [
+            dirs::desktop_dir(),
+            dirs::document_dir(),
+            dirs::picture_dir(),
+            dirs::video_dir(),
+            dirs::audio_dir(),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|dir| path_utils::is_within(&dir, path))
+}
+
+/// Что сделать пользователю, чтобы Latera могла писать в защищённые папки.
+pub fn controlled_folder_access_steps(app_path: &Path) -> Vec<String> {
+    vec![
+        "Open Windows Security > Virus & threat protection.".to_string(),
+        "Under Ransomware protection, select Manage ransomware protection.".to_string(),
+        "Select Allow an app through Controlled folder access, then Add an allowed app."
+            .to_string(),
+        format!("Choose Browse all apps and select {}.", app_path.display()),
+    ]
+}
+
+/// Лежит ли `path` в папке, доступ к которой на macOS выдаёт пользователь.
+fn is_tcc_protected(path: &Path) -> bool {
+    cfg!(target_os = "macos")
+        && [
+            dirs::desktop_dir(),
+            dirs::document_dir(),
+            dirs::download_dir(),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|dir| path_utils::is_within(&dir, path))
+}
+
+/// Ошибка доступа к `path`: отказ в правах — [`LateraError::PermissionDenied`]
+/// с подсказкой, остальное — как есть.
+pub fn map_access_error(path: &Path, err: std::io::Error) -> LateraError {
+    if err.kind() != std::io::ErrorKind::PermissionDenied {
+        return err.into();
+    }
+    if is_controlled_folder(path) && controlled_folder_access() == ControlledFolderAccess::Enabled {
+        return LateraError::ControlledFolderAccessBlocked(format!(
+            "writing to {} was blocked by Controlled folder access; allow Latera in Windows \
+             Security > Ransomware protection",
+            path.display()
+        ));
+    }
+    LateraError::PermissionDenied(guidance(path, is_tcc_protected(path)))
+}
+
+fn guidance(path: &Path, tcc_protected: bool) -> String {
+    if tcc_protected {
+        format!(
+            "access to {} was denied; allow Latera in System Settings > Privacy & Security > \
+             Files and Folders, then try again",
+            path.display()
+        )
+    } else {
+        format!(
+            "access to {} was denied; check the folder permissions",
+            path.display()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_permission_errors_are_mapped() {
+        let path = Path::new("/Users/me/Desktop/Latera");
+        let denied = map_access_error(
+            path,
+            std::io::Error::from(std::io::ErrorKind::PermissionDenied),
+        );
+        assert!(matches!(denied, LateraError::PermissionDenied(_)));
+        assert_eq!(denied.code(), "PERMISSION_DENIED");
+
+        let missing = map_access_error(path, std::io::Error::from(std::io::ErrorKind::NotFound));
+        assert!(matches!(missing, LateraError::Io(_)));
+
+        assert!(guidance(path, true).contains("Privacy & Security"));
+        assert!(!guidance(path, false).contains("Privacy & Security"));
+    }
+
+    #[test]
+    fn test_controlled_folder_access_steps_name_the_app() {
+        let steps =
+            controlled_folder_access_steps(Path::new(r"C:\Program Files\Latera\latera.exe"));
+        assert!(steps
+            .last()
+            .unwrap()
+            .contains(r"C:\Program Files\Latera\latera.exe"));
+        if !cfg!(target_os = "windows") {
+            assert_eq!(
+                controlled_folder_access(),
+                ControlledFolderAccess::NotSupported
+            );
+        }
+    }
+}
@@ -0,0 +1,363 @@
+//! Снимок состояния наблюдаемой папки на диске.
+//!
+//! Пока приложение закрыто, watcher не видит изменений. Снимок (имена,
+//! mtime и размеры файлов) сохраняется во время работы и при остановке;
+//! при следующем запуске watcher сравнивает с ним текущее содержимое папки
+//! и сообщает о файлах, появившихся, изменившихся или исчезнувших за время
+//! простоя.
+//!
+//! ## Формат
+//! Текстовый: строка заголовка [`SNAPSHOT_HEADER`], затем по строке на файл
+//! `<mtime_ms>\t<size>\t<escaped_relative_path>` (экранирование как в
+//! [`crate::event_wal`]). Файл переписывается атомарно (tmp + fsync +
+//! rename), поэтому падение посреди записи оставляет прежний снимок.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use sha2::{Digest, Sha256};
+
+use crate::error::LateraError;
+use crate::event_wal;
+use crate::path_utils;
+
+/// Папка снимков внутри папки данных.
+pub const SNAPSHOT_DIR_NAME: &str = "snapshots";
+
+/// Первая строка файла снимка (версия формата).
+pub const SNAPSHOT_HEADER: &str = "latera-dir-snapshot 1";
+
+/// Состояние файла в снимке.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SnapshotEntry {
+    /// mtime (Unix timestamp в миллисекундах); `0`, если ФС его не сообщает.
+    pub mtime_ms: i64,
+    pub size: u64,
+}
+
+/// Расхождения между двумя снимками (пути относительно папки).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SnapshotDiff {
+    pub added: Vec<PathBuf>,
+    pub modified: Vec<PathBuf>,
+    pub removed: Vec<PathBuf>,
+}
+
+impl SnapshotEntry {
+    fn of(metadata: &std::fs::Metadata) -> Self {
+        let mtime_ms = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .and_then(|d| i64::try_from(d.as_millis()).ok())
+            .unwrap_or(0);
+        Self {
+            mtime_ms,
+            size: metadata.len(),
+        }
+    }
+}
+
+impl SnapshotDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.modified.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Снимок папки: относительный путь файла → его состояние.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DirSnapshot {
+    entries: BTreeMap<PathBuf, SnapshotEntry>,
+}
+
+impl DirSnapshot {
+    /// Снимает текущее состояние папки.
+    ///
+    /// В снимок попадают только обычные файлы, для которых `include`
+    /// (получает полный путь) вернул `true`. Недоступные записи пропускаются.
+    pub fn scan(
+        dir: &Path,
+        recursive: bool,
+        include: impl Fn(&Path) -> bool,
+    ) -> Result<Self, LateraError> {
+        let mut entries = BTreeMap::new();
+        let mut pending = vec![dir.to_path_buf()];
+        while let Some(current) = pending.pop() {
+            for entry in std::fs::read_dir(&current)? {
+                let Ok(entry) = entry else { continue };
+                let Ok(file_type) = entry.file_type() else {
+                    continue;
+                };
+                let path = entry.path();
+                if file_type.is_dir() {
+                    if recursive {
+                        pending.push(path);
+                    }
+                    continue;
+                }
+                if !file_type.is_file() || !include(&path) {
+                    continue;
+                }
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+                let Ok(relative) = path.strip_prefix(dir) else {
+                    continue;
+                };
+                entries.insert(relative.to_path_buf(), SnapshotEntry::of(&metadata));
+            }
+        }
+        Ok(Self { entries })
+    }
+
+    /// Загружает снимок. `Ok(None)` — снимка ещё нет.
+    ///
+    /// Повреждённые строки пропускаются; файл с чужим заголовком считается
+    /// отсутствующим.
+    pub fn load(path: &Path) -> Result<Option<Self>, LateraError> {
+        let file = match File::open(path) {
+            Ok(f) => f,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let mut lines = BufReader::new(file).lines();
+        match lines.next() {
+            Some(Ok(header)) if header == SNAPSHOT_HEADER => {}
+            _ => return Ok(None),
+        }
+        let mut entries = BTreeMap::new();
+        for line in lines {
+            if let Some((relative, entry)) = parse_line(&line?) {
+                entries.insert(relative, entry);
+            }
+        }
+        Ok(Some(Self { entries }))
+    }
+
+    /// Атомарно сохраняет снимок.
+    pub fn save(&self, path: &Path) -> Result<(), LateraError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp_path = path.with_extension("tmp");
+        {
+            let mut tmp = std::io::BufWriter::new(File::create(&tmp_path)?);
+            writeln!(tmp, "{SNAPSHOT_HEADER}")?;
+            for (relative, entry) in &self.entries {
+                writeln!(
+                    tmp,
+                    "{}\t{}\t{}",
+                    entry.mtime_ms,
+                    entry.size,
+                    event_wal::escape(&relative.to_string_lossy())
+                )?;
+            }
+            tmp.into_inner().map_err(|e| e.into_error())?.sync_all()?;
+        }
+        std::fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    /// Чем `current` отличается от этого (более старого) снимка.
+    ///
+    /// Файл считается изменённым, если у него другой размер или mtime.
+    pub fn diff(&self, current: &Self) -> SnapshotDiff {
+        let mut diff = SnapshotDiff::default();
+        for (relative, entry) in &current.entries {
+            match self.entries.get(relative) {
+                None => diff.added.push(relative.clone()),
+                Some(previous) if previous != entry => diff.modified.push(relative.clone()),
+                Some(_) => {}
+            }
+        }
+        diff.removed = self
+            .entries
+            .keys()
+            .filter(|relative| !current.entries.contains_key(*relative))
+            .cloned()
+            .collect();
+        diff
+    }
+
+    /// Убирает файл из снимка: о нём ещё не сообщено, и при следующем
+    /// запуске он должен считаться новым.
+    pub fn remove(&mut self, relative: &Path) {
+        self.entries.remove(relative);
+    }
+
+    /// Перечитывает с диска `relative` (файл или папку целиком) внутри
+    /// `dir`, не обходя остальную папку. Исчезнувшее убирается из снимка.
+    ///
+    /// `include` — как у [`Self::scan`].
+    pub fn refresh(
+        &mut self,
+        dir: &Path,
+        relative: &Path,
+        recursive: bool,
+        include: impl Fn(&Path) -> bool,
+    ) -> Result<(), LateraError> {
+        let stale: Vec<PathBuf> = self
+            .entries
+            .range(relative.to_path_buf()..)
+            .map(|(p, _)| p)
+            .take_while(|p| p.starts_with(relative))
+            .cloned()
+            .collect();
+        for p in &stale {
+            self.entries.remove(p);
+        }
+
+        let path = dir.join(relative);
+        let metadata = match std::fs::symlink_metadata(&path) {
+            Ok(m) => m,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        if metadata.is_file() {
+            if include(&path) {
+                self.entries
+                    .insert(relative.to_path_buf(), SnapshotEntry::of(&metadata));
+            }
+        } else if metadata.is_dir() && recursive {
+            for (nested, entry) in Self::scan(&path, recursive, include)?.entries {
+                self.entries.insert(relative.join(nested), entry);
+            }
+        }
+        Ok(())
+    }
+
+    /// Относительные пути файлов снимка (по возрастанию).
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.entries.keys().map(PathBuf::as_path)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Файл снимка для папки `watch_dir` внутри `snapshot_dir`.
+///
+/// Имя — SHA-256 канонического пути: одна и та же папка, указанная
+/// по-разному (регистр, симлинк, хвостовой разделитель), даёт один снимок.
+pub fn snapshot_path(snapshot_dir: &Path, watch_dir: &Path) -> PathBuf {
+    let key = path_utils::comparison_key(watch_dir);
+    let digest = Sha256::digest(key.to_string_lossy().as_bytes());
+    let name = digest.iter().fold(String::with_capacity(64), |mut hex, b| {
+        let _ = write!(hex, "{b:02x}");
+        hex
+    });
+    snapshot_dir.join(format!("{name}.snapshot"))
+}
+
+fn parse_line(line: &str) -> Option<(PathBuf, SnapshotEntry)> {
+    let mut parts = line.splitn(3, '\t');
+    let mtime_ms = parts.next()?.parse().ok()?;
+    let size = parts.next()?.parse().ok()?;
+    let relative = PathBuf::from(event_wal::unescape(parts.next()?)?);
+    Some((relative, SnapshotEntry { mtime_ms, size }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_load_roundtrip_and_diff() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let watch_dir = temp_dir.path().join("watch");
+        std::fs::create_dir_all(watch_dir.join("sub")).unwrap();
+        std::fs::write(watch_dir.join("keep.txt"), b"1").unwrap();
+        std::fs::write(watch_dir.join("gone.txt"), b"1").unwrap();
+        std::fs::write(watch_dir.join("edit.txt"), b"1").unwrap();
+        std::fs::write(watch_dir.join("sub/tab\tname.txt"), b"1").unwrap();
+
+        let snapshot_file = snapshot_path(&temp_dir.path().join("snapshots"), &watch_dir);
+        assert!(DirSnapshot::load(&snapshot_file).unwrap().is_none());
+
+        let before = DirSnapshot::scan(&watch_dir, true, |_| true).unwrap();
+        assert_eq!(before.len(), 4);
+        before.save(&snapshot_file).unwrap();
+        assert_eq!(DirSnapshot::load(&snapshot_file).unwrap(), Some(before));
+
+        // Изменения «пока приложение закрыто»
+        std::fs::remove_file(watch_dir.join("gone.txt")).unwrap();
+        std::fs::write(watch_dir.join("edit.txt"), b"longer").unwrap();
+        std::fs::write(watch_dir.join("new.txt"), b"1").unwrap();
+
+        let previous = DirSnapshot::load(&snapshot_file).unwrap().unwrap();
+        let current = DirSnapshot::scan(&watch_dir, true, |_| true).unwrap();
+        let diff = previous.diff(&current);
+        assert_eq!(diff.added, vec![PathBuf::from("new.txt")]);
+        assert_eq!(diff.modified, vec![PathBuf::from("edit.txt")]);
+        assert_eq!(diff.removed, vec![PathBuf::from("gone.txt")]);
+        assert!(current.diff(&current).is_empty());
+    }
+
+    #[test]
+    fn test_scan_respects_recursion_and_filter() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(temp_dir.path().join("sub")).unwrap();
+        std::fs::write(temp_dir.path().join("a.txt"), b"1").unwrap();
+        std::fs::write(temp_dir.path().join("b.tmp"), b"1").unwrap();
+        std::fs::write(temp_dir.path().join("sub/c.txt"), b"1").unwrap();
+
+        let is_txt = |p: &Path| p.extension().is_some_and(|e| e == "txt");
+        assert_eq!(
+            DirSnapshot::scan(temp_dir.path(), false, is_txt)
+                .unwrap()
+                .len(),
+            1
+        );
+        assert_eq!(
+            DirSnapshot::scan(temp_dir.path(), true, is_txt)
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_refresh_rereads_only_given_paths() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        std::fs::create_dir_all(dir.join("sub/deep")).unwrap();
+        std::fs::write(dir.join("a.txt"), b"1").unwrap();
+        std::fs::write(dir.join("sub/b.txt"), b"1").unwrap();
+        std::fs::write(dir.join("sub/deep/c.txt"), b"1").unwrap();
+        let before = DirSnapshot::scan(dir, true, |_| true).unwrap();
+
+        std::fs::write(dir.join("a.txt"), b"longer").unwrap();
+        std::fs::remove_dir_all(dir.join("sub/deep")).unwrap();
+        std::fs::write(dir.join("sub/new.txt"), b"1").unwrap();
+        std::fs::write(dir.join("untouched.txt"), b"1").unwrap();
+
+        let mut after = before.clone();
+        after
+            .refresh(dir, Path::new("a.txt"), true, |_| true)
+            .unwrap();
+        after
+            .refresh(dir, Path::new("sub"), true, |_| true)
+            .unwrap();
+        let diff = before.diff(&after);
+        assert_eq!(diff.added, vec![PathBuf::from("sub/new.txt")]);
+        assert_eq!(diff.modified, vec![PathBuf::from("a.txt")]);
+        assert_eq!(diff.removed, vec![PathBuf::from("sub/deep/c.txt")]);
+    }
+
+    #[test]
+    fn test_foreign_header_ignored() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("x.snapshot");
+        std::fs::write(&path, "something else\n1\t2\ta.txt\n").unwrap();
+        assert!(DirSnapshot::load(&path).unwrap().is_none());
+    }
+}
@@ -0,0 +1,356 @@
+//! Мониторинг свободного места на томе с watch dir.
+//!
+//! Фоновый тред периодически проверяет свободное место. Когда оно опускается
+//! ниже порога, вызывается callback с [`DiskSpaceStatus::Low`] и взводится
+//! флаг [`copy_in_paused`] — функции, копирующие файлы в watch dir, должны
+//! проверять его и приостанавливаться, пока место не освободится. При
+//! нескольких мониторах (по одному на watcher) пауза действует, пока хотя
+//! бы один из них видит нехватку места.
+//!
+//! Размер папки считает [`dir_size`] — обходом в несколько потоков, чтобы
+//! большая папка на SSD не считалась минутами.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use log::{debug, info, warn};
+use sysinfo::Disks;
+
+use crate::error::LateraError;
+
+/// Порог свободного места по умолчанию (1 ГиБ).
+pub const DEFAULT_LOW_SPACE_THRESHOLD_BYTES: u64 = 1024 * 1024 * 1024;
+
+/// Интервал проверки по умолчанию.
+pub const DEFAULT_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Минимально допустимый интервал проверки.
+pub const MIN_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Сколько мониторов сейчас видят нехватку места.
+static LOW_SPACE_MONITORS: AtomicUsize = AtomicUsize::new(0);
+
+/// Приостановлены ли функции, копирующие файлы в watch dir.
+pub fn copy_in_paused() -> bool {
+    LOW_SPACE_MONITORS.load(Ordering::Relaxed) > 0
+}
+
+/// Переход состояния свободного места.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiskSpaceStatus {
+    /// Свободного места меньше порога.
+    Low {
+        available_bytes: u64,
+        threshold_bytes: u64,
+    },
+    /// Места снова достаточно.
+    Recovered { available_bytes: u64 },
+}
+
+/// Настройки мониторинга.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DiskSpaceSettings {
+    pub threshold_bytes: u64,
+    pub check_interval: Duration,
+}
+
+impl Default for DiskSpaceSettings {
+    fn default() -> Self {
+        Self {
+            threshold_bytes: DEFAULT_LOW_SPACE_THRESHOLD_BYTES,
+            check_interval: DEFAULT_CHECK_INTERVAL,
+        }
+    }
+}
+
+/// Свободное место (в байтах) на томе, где находится `path`.
+///
+/// Том определяется по самой длинной точке монтирования, содержащей `path`.
+pub fn available_space(path: &Path) -> Result<u64, LateraError> {
+    let path = std::fs::canonicalize(path)?;
+    let disks = Disks::new_with_refreshed_list();
+    disks
+        .list()
+        .iter()
+        .filter(|disk| path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())
+        .map(sysinfo::Disk::available_space)
+        .ok_or_else(|| LateraError::InvalidPath(format!("no volume found for {}", path.display())))
+}
+
+/// Сколько потоков обходят папку в [`dir_size`].
+const MAX_WALK_THREADS: usize = 8;
+
+/// Размер папки.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DirSize {
+    pub total_bytes: u64,
+    pub file_count: u64,
+    /// Вложенные папки (сама папка не считается).
+    pub dir_count: u64,
+}
+
+/// Папки, ждущие обхода, и сколько потоков сейчас обходят свою.
+struct WalkQueue {
+    pending: Vec<PathBuf>,
+    busy: usize,
+}
+
+/// Размер папки `dir` со всеми вложенными (символические ссылки не
+/// раскрываются). Папки, которые не удалось прочитать, пропускаются;
+/// ошибка — только если не читается сама `dir`.
+pub fn dir_size(dir: &Path) -> Result<DirSize, LateraError> {
+    // Сама папка должна читаться — иначе «0 байт» вводил бы в заблуждение.
+    std::fs::read_dir(dir)?;
+
+    let queue = Mutex::new(WalkQueue {
+        pending: vec![dir.to_path_buf()],
+        busy: 0,
+    });
+    let changed = Condvar::new();
+    let total_bytes = AtomicU64::new(0);
+    let file_count = AtomicU64::new(0);
+    let dir_count = AtomicU64::new(0);
+    let threads = thread::available_parallelism()
+        .map_or(1, std::num::NonZeroUsize::get)
+        .min(MAX_WALK_THREADS);
+
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| loop {
+                let current = {
+                    let mut queue = queue
+                        .lock()
+                        .unwrap_or_else(std::sync::PoisonError::into_inner);
+                    loop {
+                        if let Some(next) = queue.pending.pop() {
+                            queue.busy += 1;
+                            break next;
+                        }
+                        if queue.busy == 0 {
+                            return;
+                        }
+                        queue = changed
+                            .wait(queue)
+                            .unwrap_or_else(std::sync::PoisonError::into_inner);
+                    }
+                };
+
+                let mut subdirs = Vec::new();
+                if let Ok(entries) = std::fs::read_dir(&current) {
+                    for entry in entries.flatten() {
+                        let Ok(file_type) = entry.file_type() else {
+                            continue;
+                        };
+                        if file_type.is_dir() {
+                            subdirs.push(entry.path());
+                        } else if file_type.is_file() {
+                            if let Ok(metadata) = entry.metadata() {
+                                total_bytes.fetch_add(metadata.len(), Ordering::Relaxed);
+                                file_count.fetch_add(1, Ordering::Relaxed);
+                            }
+                        }
+                    }
+                } else {
+                    debug!("Cannot read {} while measuring size", current.display());
+                }
+
+                dir_count.fetch_add(subdirs.len() as u64, Ordering::Relaxed);
+                let mut queue = queue
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner);
+                queue.pending.extend(subdirs);
+                queue.busy -= 1;
+                changed.notify_all();
+            });
+        }
+    });
+
+    Ok(DirSize {
+        total_bytes: total_bytes.into_inner(),
+        file_count: file_count.into_inner(),
+        dir_count: dir_count.into_inner(),
+    })
+}
+
+/// Отслеживает переходы между «мало места» и «места достаточно».
+#[derive(Debug, Default)]
+pub struct LowSpaceDetector {
+    low: bool,
+}
+
+impl LowSpaceDetector {
+    /// Учесть очередное измерение. Возвращает статус только при смене состояния.
+    pub fn observe(
+        &mut self,
+        available_bytes: u64,
+        threshold_bytes: u64,
+    ) -> Option<DiskSpaceStatus> {
+        let low = available_bytes < threshold_bytes;
+        if low == self.low {
+            return None;
+        }
+        self.low = low;
+        Some(if low {
+            DiskSpaceStatus::Low {
+                available_bytes,
+                threshold_bytes,
+            }
+        } else {
+            DiskSpaceStatus::Recovered { available_bytes }
+        })
+    }
+}
+
+/// Handle фонового треда мониторинга.
+pub struct DiskSpaceMonitorHandle {
+    stop_tx: mpsc::Sender<()>,
+    join: thread::JoinHandle<()>,
+    low: Arc<AtomicBool>,
+}
+
+impl DiskSpaceMonitorHandle {
+    /// Останавливает тред, дожидается его завершения и снимает свою паузу копирования.
+    pub fn stop(self) {
+        let _ = self.stop_tx.send(());
+        if self.join.join().is_err() {
+            warn!("Disk space monitor thread panicked");
+        }
+        if self.low.swap(false, Ordering::Relaxed) {
+            LOW_SPACE_MONITORS.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Запускает мониторинг свободного места на томе с `watch_dir`.
+///
+/// Первая проверка выполняется сразу; `on_change` вызывается только при
+/// переходе между состояниями.
+pub fn spawn_monitor<F>(
+    watch_dir: PathBuf,
+    settings: DiskSpaceSettings,
+    on_change: F,
+) -> Result<DiskSpaceMonitorHandle, LateraError>
+where
+    F: Fn(DiskSpaceStatus) + Send + 'static,
+{
+    if settings.check_interval < MIN_CHECK_INTERVAL {
+        return Err(LateraError::InvalidArgument(format!(
+            "disk space check interval must be at least {} ms",
+            MIN_CHECK_INTERVAL.as_millis()
+        )));
+    }
+
+    let (stop_tx, stop_rx) = mpsc::channel::<()>();
+    let low_flag = Arc::new(AtomicBool::new(false));
+    let low_flag_for_thread = Arc::clone(&low_flag);
+    let join = thread::Builder::new()
+        .name("latera-disk-space".to_string())
+        .spawn(move || {
+            let mut detector = LowSpaceDetector::default();
+            loop {
+                match available_space(&watch_dir) {
+                    Ok(available) => {
+                        if let Some(status) = detector.observe(available, settings.threshold_bytes)
+                        {
+                            let low = matches!(status, DiskSpaceStatus::Low { .. });
+                            if low_flag_for_thread.swap(low, Ordering::Relaxed) != low {
+                                if low {
+                                    LOW_SPACE_MONITORS.fetch_add(1, Ordering::Relaxed);
+                                } else {
+                                    LOW_SPACE_MONITORS.fetch_sub(1, Ordering::Relaxed);
+                                }
+                            }
+                            if low {
+                                warn!(
+                                    "Low disk space on watch volume: {available} bytes free (threshold {})",
+                                    settings.threshold_bytes
+                                );
+                            } else {
+                                info!("Disk space recovered: {available} bytes free");
+                            }
+                            on_change(status);
+                        }
+                    }
+                    Err(e) => debug!("Disk space check failed: {e}"),
+                }
+                match stop_rx.recv_timeout(settings.check_interval) {
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Ok(()) | Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+            debug!("Disk space monitor stopped");
+        })?;
+    Ok(DiskSpaceMonitorHandle {
+        stop_tx,
+        join,
+        low: low_flag,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detector_reports_only_transitions() {
+        let mut detector = LowSpaceDetector::default();
+        assert_eq!(detector.observe(500, 100), None);
+        assert_eq!(
+            detector.observe(50, 100),
+            Some(DiskSpaceStatus::Low {
+                available_bytes: 50,
+                threshold_bytes: 100
+            })
+        );
+        assert_eq!(detector.observe(40, 100), None);
+        assert_eq!(
+            detector.observe(200, 100),
+            Some(DiskSpaceStatus::Recovered {
+                available_bytes: 200
+            })
+        );
+    }
+
+    #[test]
+    fn test_available_space_for_temp_dir() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        // В контейнерах список томов может быть пустым — тогда ошибка, а не паника.
+        if let Ok(available) = available_space(temp_dir.path()) {
+            assert!(available > 0);
+        }
+    }
+
+    #[test]
+    fn test_dir_size_counts_nested_files() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let nested = temp_dir.path().join("a").join("b");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(temp_dir.path().join("root.bin"), [0u8; 10]).unwrap();
+        std::fs::write(temp_dir.path().join("a").join("one.bin"), [0u8; 20]).unwrap();
+        std::fs::write(nested.join("two.bin"), [0u8; 30]).unwrap();
+
+        assert_eq!(
+            dir_size(temp_dir.path()).unwrap(),
+            DirSize {
+                total_bytes: 60,
+                file_count: 3,
+                dir_count: 2,
+            }
+        );
+        assert!(dir_size(&temp_dir.path().join("missing")).is_err());
+    }
+
+    #[test]
+    fn test_monitor_rejects_short_interval() {
+        let settings = DiskSpaceSettings {
+            threshold_bytes: 1,
+            check_interval: Duration::from_millis(10),
+        };
+        assert!(spawn_monitor(PathBuf::from("/"), settings, |_| {}).is_err());
+    }
+}
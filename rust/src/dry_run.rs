@@ -0,0 +1,76 @@
+//! Режим «пробного запуска».
+//!
+//! Чтобы проверить настройки до включения, ядро выполняет все проверки
+//! автоматических действий (пресеты приёма, архивирование, очистка по
+//! квоте), но файлы не трогает: каждое действие, которое было бы
+//! выполнено, сообщается через [`report`] — в лог и подписчику.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use once_cell::sync::Lazy;
+
+use crate::audit_log::AuditAction;
+use crate::file_watcher;
+
+static DRY_RUN: AtomicBool = AtomicBool::new(false);
+
+/// Подписчик на запланированные действия. Вызывается на треде, который
+/// принял решение (watcher, архивирование, квота).
+pub type Listener = Arc<dyn Fn(&PlannedAction) + Send + Sync>;
+
+static LISTENER: Lazy<Mutex<Option<Listener>>> = Lazy::new(|| Mutex::new(None));
+
+/// Действие, которое ядро выполнило бы вне пробного запуска.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlannedAction {
+    pub action: AuditAction,
+    pub path: PathBuf,
+    /// Куда был бы перенесён файл.
+    pub target: Option<String>,
+    /// Правило, по которому выполнилось бы действие.
+    pub rule_id: String,
+    /// Unix timestamp в миллисекундах.
+    pub planned_at_ms: i64,
+}
+
+/// Включить или выключить пробный запуск.
+pub fn set_enabled(enabled: bool) {
+    DRY_RUN.store(enabled, Ordering::Relaxed);
+}
+
+/// Включён ли пробный запуск.
+pub fn is_enabled() -> bool {
+    DRY_RUN.load(Ordering::Relaxed)
+}
+
+/// Задать подписчика (`None` — только лог).
+pub fn set_listener(listener: Option<Listener>) {
+    *LISTENER
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = listener;
+}
+
+/// Сообщает действие, пропущенное из-за пробного запуска.
+pub fn report(action: AuditAction, path: &Path, target: Option<&str>, rule_id: &str) {
+    log::info!(
+        "Dry run: would {} {}{} ({rule_id})",
+        action.as_str(),
+        path.display(),
+        target.map(|t| format!(" -> {t}")).unwrap_or_default()
+    );
+    let listener = LISTENER
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone();
+    if let Some(listener) = listener {
+        listener(&PlannedAction {
+            action,
+            path: path.to_path_buf(),
+            target: target.map(str::to_string),
+            rule_id: rule_id.to_string(),
+            planned_at_ms: file_watcher::now_ms(),
+        });
+    }
+}
@@ -0,0 +1,335 @@
+//! Дубликаты: файлы с одинаковым содержимым.
+//!
+//! Кандидаты сначала отбираются по размеру, и только файлы с совпавшим
+//! размером хэшируются (BLAKE3), поэтому обход большой папки не читает
+//! каждый файл.
+//!
+//! В режиме наблюдения ([`DuplicateQueue`]) для каждого watcher'а ведётся
+//! реестр размеров ([`SizeIndex`]); появившийся файл сравнивается с уже
+//! известными файлами того же размера, и UI получает пару «новый —
+//! существующий», чтобы предложить «оставить / заменить / пропустить».
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+
+use log::{debug, warn};
+
+use crate::error::LateraError;
+use crate::hashing::{self, HashAlgorithm};
+use crate::internal_files;
+use crate::preview;
+
+/// Как часто проверять, что файл перестал меняться.
+const SETTLE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Дольше не ждём: файл, меняющийся дольше, не проверяется.
+const SETTLE_MAX_WAIT: Duration = Duration::from_secs(30);
+
+/// Группа файлов с одинаковым содержимым.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DuplicateGroup {
+    pub size: u64,
+    /// BLAKE3 содержимого (hex).
+    pub hash: String,
+    /// Пути, отсортированные по возрастанию.
+    pub paths: Vec<PathBuf>,
+}
+
+/// Реестр файлов папки по размеру.
+#[derive(Debug, Default)]
+pub struct SizeIndex {
+    by_size: HashMap<u64, Vec<PathBuf>>,
+    sizes: HashMap<PathBuf, u64>,
+}
+
+impl SizeIndex {
+    /// Реестр файлов, которые уже лежат в `dir`. Пустые и служебные файлы
+    /// не учитываются.
+    pub fn scan(dir: &Path, recursive: bool) -> Self {
+        let mut index = Self::default();
+        let mut pending = vec![dir.to_path_buf()];
+        while let Some(current) = pending.pop() {
+            let Ok(entries) = std::fs::read_dir(&current) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let Ok(file_type) = entry.file_type() else {
+                    continue;
+                };
+                if file_type.is_dir() && recursive {
+                    pending.push(entry.path());
+                } else if file_type.is_file() {
+                    if let Ok(metadata) = entry.metadata() {
+                        index.remember(entry.path(), metadata.len());
+                    }
+                }
+            }
+        }
+        index
+    }
+
+    fn remember(&mut self, path: PathBuf, size: u64) {
+        if size == 0 || internal_files::is_internal(&path) {
+            return;
+        }
+        self.forget(&path);
+        self.by_size.entry(size).or_default().push(path.clone());
+        self.sizes.insert(path, size);
+    }
+
+    /// Учесть удаление файла.
+    pub fn forget(&mut self, path: &Path) {
+        let Some(size) = self.sizes.remove(path) else {
+            return;
+        };
+        if let Some(paths) = self.by_size.get_mut(&size) {
+            paths.retain(|p| p != path);
+            if paths.is_empty() {
+                self.by_size.remove(&size);
+            }
+        }
+    }
+
+    /// Учесть изменённый файл без проверки на дубликат.
+    pub fn update(&mut self, path: &Path) {
+        match std::fs::metadata(path) {
+            Ok(metadata) if metadata.is_file() => self.remember(path.to_path_buf(), metadata.len()),
+            _ => self.forget(path),
+        }
+    }
+
+    /// Учесть появившийся файл. Возвращает путь уже известного файла с тем
+    /// же содержимым, если такой есть.
+    pub fn observe_added(&mut self, path: &Path) -> Option<PathBuf> {
+        let size = std::fs::metadata(path).ok().filter(|m| m.is_file())?.len();
+        self.remember(path.to_path_buf(), size);
+        let candidates: Vec<PathBuf> = self
+            .by_size
+            .get(&size)?
+            .iter()
+            .filter(|p| *p != path)
+            .cloned()
+            .collect();
+        if candidates.is_empty() {
+            return None;
+        }
+
+        let hash = match hashing::compute_hash(path, HashAlgorithm::Blake3) {
+            Ok(hash) => hash,
+            Err(e) => {
+                debug!("Cannot hash {}: {e}", path.display());
+                return None;
+            }
+        };
+        candidates.into_iter().find(|candidate| {
+            hashing::compute_hash(candidate, HashAlgorithm::Blake3).is_ok_and(|h| h == hash)
+        })
+    }
+}
+
+/// Все группы дубликатов в `dir`, от занимающих больше всего лишнего места.
+pub fn find_duplicates(dir: &Path, recursive: bool) -> Result<Vec<DuplicateGroup>, LateraError> {
+    if !dir.is_dir() {
+        return Err(LateraError::InvalidPath(format!(
+            "not a directory: {}",
+            dir.display()
+        )));
+    }
+    let index = SizeIndex::scan(dir, recursive);
+    let mut groups = Vec::new();
+    for (size, paths) in index.by_size {
+        if paths.len() < 2 {
+            continue;
+        }
+        let mut by_hash: HashMap<String, Vec<PathBuf>> = HashMap::new();
+        for path in paths {
+            match hashing::compute_hash(&path, HashAlgorithm::Blake3) {
+                Ok(hash) => by_hash.entry(hash).or_default().push(path),
+                Err(e) => debug!("Cannot hash {}: {e}", path.display()),
+            }
+        }
+        for (hash, mut paths) in by_hash {
+            if paths.len() < 2 {
+                continue;
+            }
+            paths.sort();
+            groups.push(DuplicateGroup { size, hash, paths });
+        }
+    }
+    groups.sort_by(|a, b| {
+        let wasted = |g: &DuplicateGroup| g.size * (g.paths.len() as u64 - 1);
+        wasted(b)
+            .cmp(&wasted(a))
+            .then_with(|| a.paths.cmp(&b.paths))
+    });
+    Ok(groups)
+}
+
+/// Задание очереди проверки дубликатов.
+#[derive(Clone, Debug)]
+pub enum DuplicateJob {
+    /// Watcher запущен — построить реестр его папки.
+    Scan {
+        watcher_id: String,
+        dir: PathBuf,
+        recursive: bool,
+    },
+    /// Появился файл — проверить на дубликат.
+    Added { watcher_id: String, path: PathBuf },
+    /// Файл изменён или переименован — обновить реестр без проверки.
+    Changed { watcher_id: String, path: PathBuf },
+    /// Файла больше нет.
+    Removed { watcher_id: String, path: PathBuf },
+    /// Watcher остановлен — забыть реестр.
+    Forget { watcher_id: String },
+}
+
+/// Найденный дубликат.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DuplicateFound {
+    pub watcher_id: String,
+    pub new_path: PathBuf,
+    pub existing_path: PathBuf,
+}
+
+/// Очередь проверки появившихся файлов на дубликаты.
+pub struct DuplicateQueue {
+    job_tx: mpsc::Sender<DuplicateJob>,
+    stop: Arc<AtomicBool>,
+    join: thread::JoinHandle<()>,
+}
+
+impl DuplicateQueue {
+    /// Запускает фоновый тред; `on_duplicate` получает каждый найденный дубликат.
+    pub fn spawn(
+        on_duplicate: impl Fn(DuplicateFound) + Send + 'static,
+    ) -> Result<Self, LateraError> {
+        let (job_tx, job_rx) = mpsc::channel::<DuplicateJob>();
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_for_thread = Arc::clone(&stop);
+        let join = thread::Builder::new()
+            .name("latera-duplicates".to_string())
+            .spawn(move || {
+                let mut indexes: HashMap<String, SizeIndex> = HashMap::new();
+                while let Ok(job) = job_rx.recv() {
+                    if stop_for_thread.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    match job {
+                        DuplicateJob::Scan {
+                            watcher_id,
+                            dir,
+                            recursive,
+                        } => {
+                            indexes.insert(watcher_id, SizeIndex::scan(&dir, recursive));
+                        }
+                        DuplicateJob::Added { watcher_id, path } => {
+                            let Some(index) = indexes.get_mut(&watcher_id) else {
+                                continue;
+                            };
+                            if !preview::wait_until_settled(&path, SETTLE_INTERVAL, SETTLE_MAX_WAIT)
+                            {
+                                debug!(
+                                    "Duplicate check skipped, file did not settle: {}",
+                                    path.display()
+                                );
+                                continue;
+                            }
+                            if let Some(existing_path) = index.observe_added(&path) {
+                                on_duplicate(DuplicateFound {
+                                    watcher_id,
+                                    new_path: path,
+                                    existing_path,
+                                });
+                            }
+                        }
+                        DuplicateJob::Changed { watcher_id, path } => {
+                            if let Some(index) = indexes.get_mut(&watcher_id) {
+                                index.update(&path);
+                            }
+                        }
+                        DuplicateJob::Removed { watcher_id, path } => {
+                            if let Some(index) = indexes.get_mut(&watcher_id) {
+                                index.forget(&path);
+                            }
+                        }
+                        DuplicateJob::Forget { watcher_id } => {
+                            indexes.remove(&watcher_id);
+                        }
+                    }
+                }
+                debug!("Duplicate queue stopped");
+            })?;
+        Ok(Self { job_tx, stop, join })
+    }
+
+    /// Поставить задание в очередь.
+    pub fn enqueue(&self, job: DuplicateJob) {
+        if self.job_tx.send(job).is_err() {
+            debug!("Duplicate queue is closed");
+        }
+    }
+
+    /// Останавливает тред; необработанные задания отбрасываются.
+    pub fn stop(self) {
+        self.stop.store(true, Ordering::Relaxed);
+        drop(self.job_tx);
+        if self.join.join().is_err() {
+            warn!("Duplicate thread panicked");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_duplicates_groups_by_content() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let sub = temp_dir.path().join("sub");
+        std::fs::create_dir(&sub).unwrap();
+        std::fs::write(temp_dir.path().join("a.pdf"), b"same").unwrap();
+        std::fs::write(sub.join("b.pdf"), b"same").unwrap();
+        // Тот же размер, другое содержимое
+        std::fs::write(temp_dir.path().join("c.pdf"), b"diff").unwrap();
+        std::fs::write(temp_dir.path().join("empty1"), b"").unwrap();
+        std::fs::write(temp_dir.path().join("empty2"), b"").unwrap();
+
+        let groups = find_duplicates(temp_dir.path(), true).unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].size, 4);
+        assert_eq!(
+            groups[0].paths,
+            vec![temp_dir.path().join("a.pdf"), sub.join("b.pdf")]
+        );
+        assert!(find_duplicates(temp_dir.path(), false).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_incoming_file_matched_against_known_files() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let existing = temp_dir.path().join("invoice.pdf");
+        std::fs::write(&existing, b"invoice-v1").unwrap();
+        let mut index = SizeIndex::scan(temp_dir.path(), false);
+
+        let copy = temp_dir.path().join("invoice (1).pdf");
+        std::fs::write(&copy, b"invoice-v1").unwrap();
+        assert_eq!(index.observe_added(&copy), Some(existing.clone()));
+
+        let other = temp_dir.path().join("other.pdf");
+        std::fs::write(&other, b"invoice-v2").unwrap();
+        assert_eq!(index.observe_added(&other), None);
+
+        // Удалённый файл больше не считается оригиналом
+        index.forget(&existing);
+        index.forget(&copy);
+        let again = temp_dir.path().join("again.pdf");
+        std::fs::write(&again, b"invoice-v1").unwrap();
+        assert_eq!(index.observe_added(&again), None);
+    }
+}
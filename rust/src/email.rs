@@ -0,0 +1,416 @@
+//! Письма, перетащенные из почтового клиента: `.eml` (RFC 5322) и `.msg`
+//! (Outlook, Compound File Binary).
+//!
+//! Модуль читает метаданные письма (тема, отправитель, получатели, дата) и
+//! по запросу сохраняет вложения рядом с письмом — пользователю обычно
+//! нужны именно они, а не само письмо.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use mail_parser::{MessageParser, MimeHeaders};
+
+use crate::error::LateraError;
+use crate::file_type;
+use crate::intake;
+use crate::read_only;
+
+/// FILETIME (100 нс с 1601-01-01) ↔ Unix epoch.
+const FILETIME_UNIX_EPOCH_OFFSET_MS: i64 = 11_644_473_600_000;
+
+/// Вложение письма.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EmailAttachment {
+    /// Имя файла вложения (без каталогов).
+    pub file_name: String,
+    pub mime_type: Option<String>,
+    pub data: Vec<u8>,
+}
+
+/// Разобранное письмо.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EmailMessage {
+    pub subject: Option<String>,
+    /// Отправитель: `Имя <адрес>` или только адрес.
+    pub from: Option<String>,
+    pub to: Vec<String>,
+    pub cc: Vec<String>,
+    /// Дата отправки (Unix timestamp в миллисекундах).
+    pub date_ms: Option<i64>,
+    pub message_id: Option<String>,
+    pub attachments: Vec<EmailAttachment>,
+}
+
+/// Похож ли файл на письмо (по расширению).
+pub fn is_email(path: &Path) -> bool {
+    matches!(
+        file_type::extension_of(path).as_deref(),
+        Some("eml" | "msg")
+    )
+}
+
+/// Разбирает письмо; формат определяется по расширению.
+pub fn parse(path: &Path) -> Result<EmailMessage, LateraError> {
+    match file_type::extension_of(path).as_deref() {
+        Some("eml") => parse_eml(&std::fs::read(path)?).ok_or_else(|| not_an_email(path)),
+        Some("msg") => parse_msg(path),
+        _ => Err(LateraError::InvalidArgument(format!(
+            "unsupported email format: {}",
+            path.display()
+        ))),
+    }
+}
+
+/// Сохраняет вложения письма в `target_dir`; возвращает пути созданных файлов.
+///
+/// Занятые имена не перезаписываются: к ним добавляется ` (N)`.
+pub fn extract_attachments(path: &Path, target_dir: &Path) -> Result<Vec<PathBuf>, LateraError> {
+    read_only::ensure_writable("extracting email attachments")?;
+
+    let message = parse(path)?;
+    std::fs::create_dir_all(target_dir)?;
+    let mut extracted = Vec::with_capacity(message.attachments.len());
+    for attachment in message.attachments {
+        let target = intake::free_path(&target_dir.join(&attachment.file_name));
+        std::fs::write(&target, &attachment.data)?;
+        extracted.push(target);
+    }
+    Ok(extracted)
+}
+
+fn not_an_email(path: &Path) -> LateraError {
+    LateraError::InvalidArgument(format!("not an email message: {}", path.display()))
+}
+
+fn parse_eml(raw: &[u8]) -> Option<EmailMessage> {
+    let message = MessageParser::default().parse(raw)?;
+    let addresses = |address: Option<&mail_parser::Address>| -> Vec<String> {
+        address
+            .map(|a| {
+                a.iter()
+                    .filter_map(|addr| format_address(addr.name(), addr.address()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let attachments = message
+        .attachments()
+        .enumerate()
+        .map(|(index, part)| {
+            let mime_type = part.content_type().map(|ct| match ct.subtype() {
+                Some(subtype) => format!("{}/{subtype}", ct.ctype()),
+                None => ct.ctype().to_string(),
+            });
+            let fallback = if part.is_message() {
+                format!("attachment-{}.eml", index + 1)
+            } else {
+                format!("attachment-{}", index + 1)
+            };
+            EmailAttachment {
+                file_name: safe_file_name(part.attachment_name(), &fallback),
+                mime_type,
+                data: part.contents().to_vec(),
+            }
+        })
+        .collect();
+
+    Some(EmailMessage {
+        subject: message.subject().map(str::to_string),
+        from: addresses(message.from()).into_iter().next(),
+        to: addresses(message.to()),
+        cc: addresses(message.cc()),
+        date_ms: message
+            .date()
+            .map(|d| d.to_timestamp().saturating_mul(1000)),
+        message_id: message.message_id().map(str::to_string),
+        attachments,
+    })
+}
+
+fn format_address(name: Option<&str>, address: Option<&str>) -> Option<String> {
+    match (name, address) {
+        (Some(name), Some(address)) if !name.is_empty() => Some(format!("{name} <{address}>")),
+        (_, Some(address)) => Some(address.to_string()),
+        (Some(name), None) => Some(name.to_string()),
+        (None, None) => None,
+    }
+}
+
+/// Имя вложения без каталогов и недопустимых символов.
+fn safe_file_name(name: Option<&str>, fallback: &str) -> String {
+    let cleaned: String = name
+        .and_then(|n| n.rsplit(['/', '\\']).next())
+        .unwrap_or_default()
+        .chars()
+        .map(|c| {
+            if c.is_control() || matches!(c, ':' | '*' | '?' | '"' | '<' | '>' | '|') {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect();
+    let cleaned = cleaned.trim().trim_start_matches('.');
+    if cleaned.is_empty() {
+        fallback.to_string()
+    } else {
+        cleaned.to_string()
+    }
+}
+
+// --- Outlook .msg ([MS-OXMSG]) ---
+
+/// Свойства MAPI, которые читаются из `.msg`.
+const PR_SUBJECT: u16 = 0x0037;
+const PR_SENDER_NAME: u16 = 0x0C1A;
+const PR_SENDER_EMAIL_ADDRESS: u16 = 0x0C1F;
+const PR_DISPLAY_CC: u16 = 0x0E03;
+const PR_DISPLAY_TO: u16 = 0x0E04;
+const PR_INTERNET_MESSAGE_ID: u16 = 0x1035;
+const PR_CLIENT_SUBMIT_TIME: u16 = 0x0039;
+const PR_MESSAGE_DELIVERY_TIME: u16 = 0x0E06;
+const PR_ATTACH_DATA_BIN: u16 = 0x3701;
+const PR_ATTACH_FILENAME: u16 = 0x3704;
+const PR_ATTACH_LONG_FILENAME: u16 = 0x3707;
+const PR_ATTACH_MIME_TAG: u16 = 0x370E;
+
+/// Типы свойств MAPI.
+const PT_UNICODE: u16 = 0x001F;
+const PT_STRING8: u16 = 0x001E;
+const PT_BINARY: u16 = 0x0102;
+const PT_SYSTIME: u16 = 0x0040;
+
+/// Размер заголовка потока свойств корня письма (у вложений — 8 байт).
+const ROOT_PROPERTIES_HEADER: usize = 32;
+const PROPERTIES_STREAM: &str = "__properties_version1.0";
+const ATTACHMENT_STORAGE_PREFIX: &str = "__attach_version1.0_#";
+
+type MsgFile = cfb::CompoundFile<std::fs::File>;
+
+fn parse_msg(path: &Path) -> Result<EmailMessage, LateraError> {
+    let mut msg = cfb::open(path).map_err(|_| not_an_email(path))?;
+    let root = Path::new("/");
+
+    let sender_name = read_string(&mut msg, root, PR_SENDER_NAME);
+    let sender_email = read_string(&mut msg, root, PR_SENDER_EMAIL_ADDRESS);
+    let recipients = |value: Option<String>| -> Vec<String> {
+        value
+            .map(|v| {
+                v.split(';')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let to = recipients(read_string(&mut msg, root, PR_DISPLAY_TO));
+    let cc = recipients(read_string(&mut msg, root, PR_DISPLAY_CC));
+    let date_ms = read_properties(&mut msg, root, ROOT_PROPERTIES_HEADER).and_then(|props| {
+        [PR_CLIENT_SUBMIT_TIME, PR_MESSAGE_DELIVERY_TIME]
+            .iter()
+            .find_map(|id| props.get(&(*id, PT_SYSTIME)).copied())
+            .map(filetime_to_ms)
+    });
+
+    let mut attachment_storages: Vec<PathBuf> = msg
+        .read_root_storage()
+        .filter(|e| e.is_storage() && e.name().starts_with(ATTACHMENT_STORAGE_PREFIX))
+        .map(|e| e.path().to_path_buf())
+        .collect();
+    attachment_storages.sort();
+    let mut attachments = Vec::new();
+    for (index, storage) in attachment_storages.iter().enumerate() {
+        // Вложенные письма (PT_OBJECT) и OLE-объекты не извлекаются
+        let Some(data) = read_stream(
+            &mut msg,
+            &stream_path(storage, PR_ATTACH_DATA_BIN, PT_BINARY),
+        ) else {
+            continue;
+        };
+        let name = read_string(&mut msg, storage, PR_ATTACH_LONG_FILENAME)
+            .or_else(|| read_string(&mut msg, storage, PR_ATTACH_FILENAME));
+        attachments.push(EmailAttachment {
+            file_name: safe_file_name(name.as_deref(), &format!("attachment-{}", index + 1)),
+            mime_type: read_string(&mut msg, storage, PR_ATTACH_MIME_TAG),
+            data,
+        });
+    }
+
+    Ok(EmailMessage {
+        subject: read_string(&mut msg, root, PR_SUBJECT),
+        from: format_address(sender_name.as_deref(), sender_email.as_deref()),
+        to,
+        cc,
+        date_ms,
+        message_id: read_string(&mut msg, root, PR_INTERNET_MESSAGE_ID),
+        attachments,
+    })
+}
+
+fn stream_path(storage: &Path, id: u16, kind: u16) -> PathBuf {
+    storage.join(format!("__substg1.0_{id:04X}{kind:04X}"))
+}
+
+fn read_stream(msg: &mut MsgFile, path: &Path) -> Option<Vec<u8>> {
+    let mut stream = msg.open_stream(path).ok()?;
+    let mut data = Vec::new();
+    stream.read_to_end(&mut data).ok()?;
+    Some(data)
+}
+
+/// Строковое свойство: Unicode (UTF-16LE) или 8-битное.
+fn read_string(msg: &mut MsgFile, storage: &Path, id: u16) -> Option<String> {
+    let value = if let Some(data) = read_stream(msg, &stream_path(storage, id, PT_UNICODE)) {
+        let units: Vec<u16> = data
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .collect();
+        String::from_utf16_lossy(&units)
+    } else {
+        let data = read_stream(msg, &stream_path(storage, id, PT_STRING8))?;
+        String::from_utf8_lossy(&data).into_owned()
+    };
+    let value = value.trim_end_matches('\0').trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// Свойства фиксированной длины: `(id, тип) → 8 байт значения`.
+fn read_properties(
+    msg: &mut MsgFile,
+    storage: &Path,
+    header_len: usize,
+) -> Option<std::collections::HashMap<(u16, u16), u64>> {
+    let data = read_stream(msg, &storage.join(PROPERTIES_STREAM))?;
+    let entries = data.get(header_len..)?;
+    Some(
+        entries
+            .chunks_exact(16)
+            .map(|entry| {
+                let tag = u32::from_le_bytes([entry[0], entry[1], entry[2], entry[3]]);
+                let mut value = [0u8; 8];
+                value.copy_from_slice(&entry[8..16]);
+                (((tag >> 16) as u16, tag as u16), u64::from_le_bytes(value))
+            })
+            .collect(),
+    )
+}
+
+fn filetime_to_ms(filetime: u64) -> i64 {
+    i64::try_from(filetime / 10_000).unwrap_or(i64::MAX) - FILETIME_UNIX_EPOCH_OFFSET_MS
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    const EML: &str = "From: Alice Example <alice@example.com>\r\n\
+To: bob@example.com, Carol <carol@example.com>\r\n\
+Subject: Quarterly report\r\n\
+Date: Tue, 1 Oct 2024 10:00:00 +0000\r\n\
+Message-ID: <report-1@example.com>\r\n\
+MIME-Version: 1.0\r\n\
+Content-Type: multipart/mixed; boundary=\"b1\"\r\n\
+\r\n\
+--b1\r\n\
+Content-Type: text/plain\r\n\
+\r\n\
+See attached.\r\n\
+--b1\r\n\
+Content-Type: application/pdf; name=\"../report.pdf\"\r\n\
+Content-Disposition: attachment; filename=\"../report.pdf\"\r\n\
+Content-Transfer-Encoding: base64\r\n\
+\r\n\
+JVBERi0xLjQK\r\n\
+--b1--\r\n";
+
+    #[test]
+    fn test_eml_metadata_and_attachments() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("mail.eml");
+        std::fs::write(&path, EML).unwrap();
+
+        let message = parse(&path).unwrap();
+        assert_eq!(message.subject.as_deref(), Some("Quarterly report"));
+        assert_eq!(
+            message.from.as_deref(),
+            Some("Alice Example <alice@example.com>")
+        );
+        assert_eq!(
+            message.to,
+            vec!["bob@example.com", "Carol <carol@example.com>"]
+        );
+        assert_eq!(message.date_ms, Some(1_727_776_800_000));
+        assert_eq!(message.message_id.as_deref(), Some("report-1@example.com"));
+        assert_eq!(message.attachments.len(), 1);
+        assert_eq!(message.attachments[0].file_name, "report.pdf");
+        assert_eq!(message.attachments[0].data, b"%PDF-1.4\n");
+
+        // Повторное извлечение не перезаписывает файлы
+        let out = temp_dir.path().join("out");
+        extract_attachments(&path, &out).unwrap();
+        let second = extract_attachments(&path, &out).unwrap();
+        assert_eq!(second, vec![out.join("report (1).pdf")]);
+    }
+
+    #[test]
+    fn test_msg_metadata_and_attachments() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("mail.msg");
+        let utf16 = |s: &str| -> Vec<u8> { s.encode_utf16().flat_map(u16::to_le_bytes).collect() };
+        // 2024-10-01T10:00:00Z в FILETIME
+        let filetime = (1_727_776_800_000u64 + 11_644_473_600_000) * 10_000;
+        let mut props = vec![0u8; ROOT_PROPERTIES_HEADER];
+        props.extend_from_slice(&0x0039_0040u32.to_le_bytes());
+        props.extend_from_slice(&0u32.to_le_bytes());
+        props.extend_from_slice(&filetime.to_le_bytes());
+        let attachment = "/__attach_version1.0_#00000000";
+
+        let mut msg = cfb::create(&path).unwrap();
+        msg.create_storage(attachment).unwrap();
+        for (stream, data) in [
+            ("/__substg1.0_0037001F".to_string(), utf16("Отчёт")),
+            ("/__substg1.0_0C1A001F".to_string(), utf16("Alice")),
+            (
+                "/__substg1.0_0C1F001F".to_string(),
+                utf16("alice@example.com"),
+            ),
+            ("/__substg1.0_0E04001F".to_string(), utf16("Bob; Carol")),
+            ("/__properties_version1.0".to_string(), props),
+            (
+                format!("{attachment}/__substg1.0_3707001F"),
+                utf16("notes.txt"),
+            ),
+            (
+                format!("{attachment}/__substg1.0_37010102"),
+                b"hello".to_vec(),
+            ),
+        ] {
+            msg.create_stream(&stream)
+                .unwrap()
+                .write_all(&data)
+                .unwrap();
+        }
+        msg.flush().unwrap();
+        drop(msg);
+
+        let message = parse(&path).unwrap();
+        assert_eq!(message.subject.as_deref(), Some("Отчёт"));
+        assert_eq!(message.from.as_deref(), Some("Alice <alice@example.com>"));
+        assert_eq!(message.to, vec!["Bob", "Carol"]);
+        assert_eq!(message.date_ms, Some(1_727_776_800_000));
+        assert_eq!(message.attachments.len(), 1);
+        assert_eq!(message.attachments[0].file_name, "notes.txt");
+        assert_eq!(message.attachments[0].data, b"hello");
+    }
+
+    #[test]
+    fn test_safe_file_name() {
+        assert_eq!(safe_file_name(Some("..\\..\\evil.exe"), "x"), "evil.exe");
+        assert_eq!(safe_file_name(Some("a:b?.txt"), "x"), "a_b_.txt");
+        assert_eq!(safe_file_name(Some(".."), "attachment-1"), "attachment-1");
+        assert_eq!(safe_file_name(None, "attachment-1"), "attachment-1");
+    }
+}
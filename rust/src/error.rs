@@ -25,7 +25,10 @@ pub enum LateraError {
     Io(#[from] std::io::Error),
 
     #[error("LateraError::Notify: {0}")]
-    Notify(#[from] notify::Error),
+    Notify(#[source] notify::Error),
+
+    #[error("LateraError::InotifyLimitExceeded: inotify watch limit reached (fs.inotify.max_user_watches = {limit})")]
+    InotifyLimitExceeded { limit: u64 },
 
     #[error("LateraError::FileNameMissing: Cannot determine file name for path {0:?}")]
     FileNameMissing(PathBuf),
@@ -62,6 +65,30 @@ pub enum LateraError {
 
     #[error("LateraError::LlmGenerationFailed: {0}")]
     LlmGenerationFailed(String),
+
+    #[error("LateraError::CoreNotInitialized: Core is not initialized. Call init_core() first.")]
+    CoreNotInitialized,
+
+    #[error("LateraError::InvalidArgument: {0}")]
+    InvalidArgument(String),
+
+    #[error("LateraError::TelemetryUploadFailed: {0}")]
+    TelemetryUploadFailed(String),
+
+    #[error("LateraError::ReadOnlyMode: {0} is not allowed in read-only mode")]
+    ReadOnlyMode(String),
+
+    #[error("LateraError::PolicyLocked: {0} is locked by administrator policy")]
+    PolicyLocked(String),
+
+    #[error("LateraError::ShutdownTimedOut: {0}")]
+    ShutdownTimedOut(String),
+
+    #[error("LateraError::PermissionDenied: {0}")]
+    PermissionDenied(String),
+
+    #[error("LateraError::ControlledFolderAccessBlocked: {0}")]
+    ControlledFolderAccessBlocked(String),
 }
 
 impl LateraError {
@@ -75,6 +102,7 @@ impl LateraError {
             LateraError::WatcherNotRunning => "WATCHER_NOT_RUNNING",
             LateraError::Io(_) => "IO_ERROR",
             LateraError::Notify(_) => "NOTIFY_ERROR",
+            LateraError::InotifyLimitExceeded { .. } => "INOTIFY_LIMIT_EXCEEDED",
             LateraError::FileNameMissing(_) => "FILE_NAME_MISSING",
             LateraError::StreamClosed => "STREAM_CLOSED",
             LateraError::InitializationFailed(_) => "INITIALIZATION_FAILED",
@@ -87,6 +115,31 @@ impl LateraError {
             LateraError::LlmLoadFailed(_) => "LLM_LOAD_FAILED",
             LateraError::LlmNotLoaded => "LLM_NOT_LOADED",
             LateraError::LlmGenerationFailed(_) => "LLM_GENERATION_FAILED",
+            LateraError::CoreNotInitialized => "CORE_NOT_INITIALIZED",
+            LateraError::InvalidArgument(_) => "INVALID_ARGUMENT",
+            LateraError::TelemetryUploadFailed(_) => "TELEMETRY_UPLOAD_FAILED",
+            LateraError::ReadOnlyMode(_) => "READ_ONLY_MODE",
+            LateraError::PolicyLocked(_) => "POLICY_LOCKED",
+            LateraError::ShutdownTimedOut(_) => "SHUTDOWN_TIMED_OUT",
+            LateraError::PermissionDenied(_) => "PERMISSION_DENIED",
+            LateraError::ControlledFolderAccessBlocked(_) => "CONTROLLED_FOLDER_ACCESS_BLOCKED",
+        }
+    }
+
+    /// Подробности для логов и отладки, которых нет в сообщении:
+    /// вид ошибки ввода-вывода, пути из ошибки notify, путь без имени файла.
+    pub fn context(&self) -> Option<String> {
+        match self {
+            LateraError::Io(e) => Some(format!("{:?}", e.kind())),
+            LateraError::Notify(e) if !e.paths.is_empty() => Some(
+                e.paths
+                    .iter()
+                    .map(|p| p.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+            LateraError::FileNameMissing(path) => Some(path.display().to_string()),
+            _ => None,
         }
     }
 
@@ -95,7 +148,15 @@ impl LateraError {
         match self {
             LateraError::WatcherAlreadyRunning
             | LateraError::WatcherNotRunning
-            | LateraError::StreamClosed => true,
+            | LateraError::StreamClosed
+            | LateraError::InvalidArgument(_)
+            | LateraError::TelemetryUploadFailed(_)
+            | LateraError::ReadOnlyMode(_)
+            | LateraError::PolicyLocked(_)
+            | LateraError::ShutdownTimedOut(_)
+            | LateraError::PermissionDenied(_)
+            | LateraError::ControlledFolderAccessBlocked(_)
+            | LateraError::InotifyLimitExceeded { .. } => true,
             LateraError::DesktopDirNotFound
             | LateraError::DataLocalDirNotFound
             | LateraError::InvalidPath(_)
@@ -111,7 +172,21 @@ impl LateraError {
             | LateraError::EmbeddingComputeFailed(_)
             | LateraError::LlmLoadFailed(_)
             | LateraError::LlmNotLoaded
-            | LateraError::LlmGenerationFailed(_) => false,
+            | LateraError::LlmGenerationFailed(_)
+            | LateraError::CoreNotInitialized => false,
+        }
+    }
+}
+
+impl From<notify::Error> for LateraError {
+    /// Исчерпанный лимит inotify — отдельная ошибка с текущим лимитом
+    /// (`0`, если его не прочитать): её лечит `sysctl`, а не повтор.
+    fn from(err: notify::Error) -> Self {
+        if matches!(err.kind, notify::ErrorKind::MaxFilesWatch) {
+            return LateraError::InotifyLimitExceeded {
+                limit: crate::file_watcher::inotify_watch_limit().unwrap_or(0),
+            };
         }
+        LateraError::Notify(err)
     }
 }
@@ -0,0 +1,177 @@
+//! Режим подтверждаемой доставки (at-least-once).
+//!
+//! В этом режиме событие остаётся в WAL ([`crate::event_wal`]) до явного
+//! подтверждения из Dart (`ack_event(sequence)`). Если подтверждение не
+//! пришло за `redelivery_timeout`, событие отправляется повторно.
+//!
+//! Модуль хранит только учёт отправок и фоновый тред проверки; сами
+//! события и sink'и живут в API-слое.
+
+use std::collections::HashMap;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use log::{debug, warn};
+
+use crate::error::LateraError;
+use crate::event_wal::WalEntry;
+
+/// Таймаут повторной доставки по умолчанию.
+pub const DEFAULT_REDELIVERY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Минимально допустимый таймаут повторной доставки.
+pub const MIN_REDELIVERY_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Как часто фоновый тред проверяет неподтверждённые события.
+const REDELIVERY_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Учёт отправленных, но ещё не подтверждённых событий.
+#[derive(Debug)]
+pub struct AckTracker {
+    redelivery_timeout: Duration,
+    sent_at: HashMap<u64, Instant>,
+}
+
+impl AckTracker {
+    /// Создаёт трекер с указанным таймаутом повторной доставки.
+    pub fn new(redelivery_timeout: Duration) -> Result<Self, LateraError> {
+        validate_timeout(redelivery_timeout)?;
+        Ok(Self {
+            redelivery_timeout,
+            sent_at: HashMap::new(),
+        })
+    }
+
+    /// Таймаут повторной доставки.
+    pub fn redelivery_timeout(&self) -> Duration {
+        self.redelivery_timeout
+    }
+
+    /// Меняет таймаут, сохраняя учёт уже отправленных событий.
+    pub fn set_redelivery_timeout(&mut self, timeout: Duration) -> Result<(), LateraError> {
+        validate_timeout(timeout)?;
+        self.redelivery_timeout = timeout;
+        Ok(())
+    }
+
+    /// Запоминает момент отправки события.
+    pub fn record_sent(&mut self, sequence: u64, now: Instant) {
+        self.sent_at.insert(sequence, now);
+    }
+
+    /// Снимает событие с учёта. Возвращает `false`, если оно не ожидало подтверждения.
+    pub fn acknowledge(&mut self, sequence: u64) -> bool {
+        self.sent_at.remove(&sequence).is_some()
+    }
+
+    /// Записи WAL, которые нужно (пере)отправить: ещё не отправлялись
+    /// или не подтверждены дольше таймаута.
+    pub fn due(&self, pending: &[WalEntry], now: Instant) -> Vec<WalEntry> {
+        pending
+            .iter()
+            .filter(|entry| {
+                self.sent_at
+                    .get(&entry.sequence)
+                    .is_none_or(|sent| now.duration_since(*sent) >= self.redelivery_timeout)
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+fn validate_timeout(timeout: Duration) -> Result<(), LateraError> {
+    if timeout < MIN_REDELIVERY_TIMEOUT {
+        return Err(LateraError::InvalidArgument(format!(
+            "redelivery timeout must be at least {} ms",
+            MIN_REDELIVERY_TIMEOUT.as_millis()
+        )));
+    }
+    Ok(())
+}
+
+/// Handle фонового треда повторной доставки.
+pub struct RedeliveryHandle {
+    stop_tx: mpsc::Sender<()>,
+    join: thread::JoinHandle<()>,
+}
+
+impl RedeliveryHandle {
+    /// Останавливает тред и дожидается его завершения.
+    pub fn stop(self) {
+        let _ = self.stop_tx.send(());
+        if self.join.join().is_err() {
+            warn!("Ack redelivery thread panicked");
+        }
+    }
+}
+
+/// Запускает фоновый тред, вызывающий `tick` раз в [`REDELIVERY_CHECK_INTERVAL`].
+pub fn spawn_redelivery<F>(tick: F) -> Result<RedeliveryHandle, LateraError>
+where
+    F: Fn() + Send + 'static,
+{
+    let (stop_tx, stop_rx) = mpsc::channel::<()>();
+    let join = thread::Builder::new()
+        .name("latera-ack-redelivery".to_string())
+        .spawn(move || loop {
+            match stop_rx.recv_timeout(REDELIVERY_CHECK_INTERVAL) {
+                Err(RecvTimeoutError::Timeout) => tick(),
+                Ok(()) | Err(RecvTimeoutError::Disconnected) => {
+                    debug!("Ack redelivery thread stopped");
+                    break;
+                }
+            }
+        })?;
+    Ok(RedeliveryHandle { stop_tx, join })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn entry(sequence: u64) -> WalEntry {
+        WalEntry {
+            sequence,
+            occurred_at_ms: 0,
+            detected_at_ms: 0,
+            full_path: PathBuf::from(format!("/w/{sequence}.txt")),
+        }
+    }
+
+    #[test]
+    fn test_rejects_too_short_timeout() {
+        assert!(AckTracker::new(Duration::from_millis(10)).is_err());
+        assert!(AckTracker::new(DEFAULT_REDELIVERY_TIMEOUT).is_ok());
+    }
+
+    #[test]
+    fn test_due_respects_timeout_and_acks() {
+        let mut tracker = AckTracker::new(Duration::from_secs(5)).unwrap();
+        let start = Instant::now();
+        let pending = vec![entry(1), entry(2), entry(3)];
+
+        // Ничего ещё не отправлялось — всё к отправке
+        assert_eq!(tracker.due(&pending, start).len(), 3);
+
+        tracker.record_sent(1, start);
+        tracker.record_sent(2, start);
+        let due: Vec<u64> = tracker
+            .due(&pending, start + Duration::from_secs(1))
+            .iter()
+            .map(|e| e.sequence)
+            .collect();
+        assert_eq!(due, vec![3]);
+
+        // После таймаута неподтверждённые уходят повторно
+        assert!(tracker.acknowledge(1));
+        assert!(!tracker.acknowledge(1));
+        let due: Vec<u64> = tracker
+            .due(&pending[1..], start + Duration::from_secs(6))
+            .iter()
+            .map(|e| e.sequence)
+            .collect();
+        assert_eq!(due, vec![2, 3]);
+    }
+}
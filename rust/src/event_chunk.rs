@@ -0,0 +1,335 @@
+//! Сжатая передача пачек событий через FFI.
+//!
+//! При переносе папки или первой сверке ядро отдаёт тысячи событий
+//! подряд. В обычной пачке ([`crate::api::FileBatchEvent`]) каждое событие —
+//! отдельная структура: полный путь, шесть `i64`, расширение и MIME-тип
+//! кодируются и декодируются по полю. Чанк — один байтовый буфер на N
+//! событий: общие папки, расширения и MIME-типы хранятся один раз, а
+//! времена — разностями с предыдущим событием.
+//!
+//! ## Формат (версия 2)
+//! Все числа — LEB128 (`uvarint`); знаковые — zigzag + LEB128 (`svarint`);
+//! строка — `uvarint` длины и UTF-8.
+//!
+//! ```text
+//! "LEC2"
+//! uvarint  n_strings, n_strings × string   — папки, расширения, MIME-типы
+//! uvarint  n_events, n_events × {
+//!     uvarint dir          — индекс папки (с разделителем на конце)
+//!     string  file_name    — full_path = dir + file_name
+//!     svarint occurred_at_ms, detected_at_ms, monotonic_ms,
+//!             created_at_ms, modified_at_ms  — разность с прошлым событием
+//!     uvarint size_bytes
+//!     uvarint extension, mime_type  — 0 = нет, иначе индекс + 1
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::error::LateraError;
+use crate::file_watcher::InternalFileEvent;
+
+/// Заголовок чанка (формат и версия).
+pub const CHUNK_MAGIC: &[u8; 4] = b"LEC2";
+
+/// Появившийся файл в чанке (поля как у `FileAddedEvent`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChunkEntry {
+    pub full_path: String,
+    pub occurred_at_ms: i64,
+    pub detected_at_ms: i64,
+    pub monotonic_ms: i64,
+    /// `0` — недоступно.
+    pub created_at_ms: i64,
+    /// `0` — недоступно.
+    pub modified_at_ms: i64,
+    /// `0` — недоступно.
+    pub size_bytes: u64,
+    pub extension: Option<String>,
+    pub mime_type: Option<String>,
+}
+
+impl ChunkEntry {
+    /// Имя файла — хвост пути после последнего разделителя.
+    pub fn file_name(&self) -> &str {
+        split_path(&self.full_path).1
+    }
+}
+
+impl From<&InternalFileEvent> for ChunkEntry {
+    fn from(event: &InternalFileEvent) -> Self {
+        Self {
+            full_path: event.full_path.to_string_lossy().to_string(),
+            occurred_at_ms: event.occurred_at_ms,
+            detected_at_ms: event.detected_at_ms,
+            monotonic_ms: event.monotonic_ms,
+            created_at_ms: event.created_at_ms.unwrap_or(0),
+            modified_at_ms: event.modified_at_ms.unwrap_or(0),
+            size_bytes: event.size_bytes.unwrap_or(0),
+            extension: event.extension.clone(),
+            mime_type: event.mime_type.clone(),
+        }
+    }
+}
+
+/// Папка (с разделителем) и имя файла.
+fn split_path(full_path: &str) -> (&str, &str) {
+    match full_path.rfind(std::path::is_separator) {
+        Some(i) => full_path.split_at(i + 1),
+        None => ("", full_path),
+    }
+}
+
+/// Строки чанка без повторов.
+#[derive(Default)]
+struct StringTable<'a> {
+    strings: Vec<&'a str>,
+    index: HashMap<&'a str, u64>,
+}
+
+impl<'a> StringTable<'a> {
+    fn intern(&mut self, s: &'a str) -> u64 {
+        *self.index.entry(s).or_insert_with(|| {
+            self.strings.push(s);
+            self.strings.len() as u64 - 1
+        })
+    }
+}
+
+/// Времена события в порядке формата.
+fn times(entry: &ChunkEntry) -> [i64; 5] {
+    [
+        entry.occurred_at_ms,
+        entry.detected_at_ms,
+        entry.monotonic_ms,
+        entry.created_at_ms,
+        entry.modified_at_ms,
+    ]
+}
+
+/// Кодирует события в чанк.
+pub fn encode(entries: &[ChunkEntry]) -> Vec<u8> {
+    let mut table = StringTable::default();
+    let refs: Vec<(u64, Option<u64>, Option<u64>)> = entries
+        .iter()
+        .map(|e| {
+            (
+                table.intern(split_path(&e.full_path).0),
+                e.extension.as_deref().map(|s| table.intern(s)),
+                e.mime_type.as_deref().map(|s| table.intern(s)),
+            )
+        })
+        .collect();
+
+    let mut out = Vec::with_capacity(16 + entries.len() * 24);
+    out.extend_from_slice(CHUNK_MAGIC);
+    put_uvarint(&mut out, table.strings.len() as u64);
+    for s in &table.strings {
+        put_str(&mut out, s);
+    }
+    put_uvarint(&mut out, entries.len() as u64);
+    let mut prev = [0i64; 5];
+    for (entry, (dir, extension, mime_type)) in entries.iter().zip(refs) {
+        put_uvarint(&mut out, dir);
+        put_str(&mut out, entry.file_name());
+        for (prev, value) in prev.iter_mut().zip(times(entry)) {
+            put_svarint(&mut out, value.wrapping_sub(*prev));
+            *prev = value;
+        }
+        put_uvarint(&mut out, entry.size_bytes);
+        put_uvarint(&mut out, extension.map_or(0, |i| i + 1));
+        put_uvarint(&mut out, mime_type.map_or(0, |i| i + 1));
+    }
+    out
+}
+
+/// Декодирует чанк (для проверок и клиентов на Rust).
+pub fn decode(bytes: &[u8]) -> Result<Vec<ChunkEntry>, LateraError> {
+    let mut reader = Reader { bytes, pos: 0 };
+    if reader.take(CHUNK_MAGIC.len())? != CHUNK_MAGIC {
+        return Err(corrupt("unknown header"));
+    }
+    let n_strings = reader.uvarint()?;
+    let strings = (0..n_strings)
+        .map(|_| reader.string())
+        .collect::<Result<Vec<_>, _>>()?;
+    let lookup = |i: u64| {
+        usize::try_from(i)
+            .ok()
+            .and_then(|i| strings.get(i))
+            .ok_or_else(|| corrupt("string index out of range"))
+    };
+    let optional = |i: u64| -> Result<Option<String>, LateraError> {
+        match i {
+            0 => Ok(None),
+            i => lookup(i - 1).map(|s| Some(s.clone())),
+        }
+    };
+
+    let n_events = reader.uvarint()?;
+    let mut entries = Vec::new();
+    let mut prev = [0i64; 5];
+    for _ in 0..n_events {
+        let dir = lookup(reader.uvarint()?)?;
+        let name = reader.string()?;
+        for prev in &mut prev {
+            *prev = prev.wrapping_add(reader.svarint()?);
+        }
+        let [occurred_at_ms, detected_at_ms, monotonic_ms, created_at_ms, modified_at_ms] = prev;
+        entries.push(ChunkEntry {
+            full_path: format!("{dir}{name}"),
+            occurred_at_ms,
+            detected_at_ms,
+            monotonic_ms,
+            created_at_ms,
+            modified_at_ms,
+            size_bytes: reader.uvarint()?,
+            extension: optional(reader.uvarint()?)?,
+            mime_type: optional(reader.uvarint()?)?,
+        });
+    }
+    if reader.pos != bytes.len() {
+        return Err(corrupt("trailing bytes"));
+    }
+    Ok(entries)
+}
+
+/// Сколько байт событие занимает в обычной пачке (кодек SSE: длина
+/// строки `i32`, `i64` по 8 байт, флаг `Option`).
+pub fn sse_size(
+    file_name: &str,
+    full_path: &str,
+    extension: Option<&str>,
+    mime_type: Option<&str>,
+) -> usize {
+    let string = |s: &str| 4 + s.len();
+    let optional = |s: Option<&str>| 1 + s.map_or(0, string);
+    string(file_name) + string(full_path) + 6 * 8 + optional(extension) + optional(mime_type)
+}
+
+/// Сколько байт те же события заняли бы в обычной пачке.
+pub fn unchunked_size(entries: &[ChunkEntry]) -> usize {
+    4 + entries
+        .iter()
+        .map(|e| {
+            sse_size(
+                e.file_name(),
+                &e.full_path,
+                e.extension.as_deref(),
+                e.mime_type.as_deref(),
+            )
+        })
+        .sum::<usize>()
+}
+
+fn corrupt(reason: &str) -> LateraError {
+    LateraError::InvalidArgument(format!("corrupt event chunk: {reason}"))
+}
+
+fn put_uvarint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn put_svarint(out: &mut Vec<u8>, value: i64) {
+    put_uvarint(out, ((value << 1) ^ (value >> 63)) as u64);
+}
+
+fn put_str(out: &mut Vec<u8>, s: &str) {
+    put_uvarint(out, s.len() as u64);
+    out.extend_from_slice(s.as_bytes());
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn take(&mut self, len: usize) -> Result<&[u8], LateraError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| corrupt("unexpected end"))?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn uvarint(&mut self) -> Result<u64, LateraError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.take(1)?[0];
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(corrupt("varint too long"))
+    }
+
+    fn svarint(&mut self) -> Result<i64, LateraError> {
+        let value = self.uvarint()?;
+        Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+    }
+
+    fn string(&mut self) -> Result<String, LateraError> {
+        let len = usize::try_from(self.uvarint()?).map_err(|_| corrupt("string too long"))?;
+        String::from_utf8(self.take(len)?.to_vec()).map_err(|_| corrupt("invalid UTF-8"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(i: i64, dir: &str) -> ChunkEntry {
+        ChunkEntry {
+            full_path: format!("{dir}{}photo-{i}.jpg", std::path::MAIN_SEPARATOR),
+            occurred_at_ms: 1_760_000_000_000 + i * 3,
+            detected_at_ms: 1_760_000_000_500 + i * 3,
+            monotonic_ms: 10_000 + i,
+            created_at_ms: if i % 7 == 0 { 0 } else { 1_700_000_000_000 - i },
+            modified_at_ms: 1_700_000_000_000 + i,
+            size_bytes: u64::try_from(2_000_000 + i * 1_000).unwrap(),
+            extension: Some("jpg".to_string()),
+            mime_type: (i % 5 != 0).then(|| "image/jpeg".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_and_size() {
+        let sep = std::path::MAIN_SEPARATOR;
+        let home = format!("{sep}Users{sep}ann{sep}Pictures");
+        let entries: Vec<ChunkEntry> = (0..1000)
+            .map(|i| entry(i, &format!("{home}{sep}{}", 2020 + i % 4)))
+            .chain([ChunkEntry {
+                full_path: "no-dir".to_string(),
+                // Размер за пределами i64 не обрезается
+                size_bytes: u64::MAX,
+                extension: None,
+                mime_type: None,
+                ..entry(-5, "")
+            }])
+            .collect();
+
+        let chunk = encode(&entries);
+        assert_eq!(decode(&chunk).unwrap(), entries);
+        assert_eq!(entries[1].file_name(), "photo-1.jpg");
+        // Общие папки и типы хранятся один раз, времена — разностями
+        assert!(
+            chunk.len() * 3 < unchunked_size(&entries),
+            "{} vs {}",
+            chunk.len(),
+            unchunked_size(&entries)
+        );
+
+        assert!(decode(&chunk[..chunk.len() - 1]).is_err());
+        assert!(decode(b"LEC2").is_err());
+        assert_eq!(decode(&encode(&[])).unwrap(), Vec::new());
+    }
+}
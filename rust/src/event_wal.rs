@@ -0,0 +1,425 @@
+//! Write-ahead log событий для гарантированной доставки во Flutter.
+//!
+//! Каждое событие сначала дописывается в WAL (`{data_dir}/events.wal`)
+//! и сбрасывается на диск (fsync), и только затем отправляется в stream.
+//! После того как sink принял событие, в WAL дописывается отметка
+//! о доставке. Файл переписывается только при открытии и после превышения
+//! [`COMPACT_THRESHOLD_BYTES`], а не на каждое событие. При следующем запуске
+//! неподтверждённые события доставляются повторно — падение процесса
+//! посреди emit не теряет добавленные файлы.
+//!
+//! ## Формат
+//! Текстовый, одна запись на строку:
+//! - `H\t<seq>` — наибольший выданный номер; первая строка после компактирования,
+//!   чтобы нумерация не начиналась заново, когда pending-записей не осталось
+//!   (иначе запоздалый `ack_event` подтвердил бы чужое событие)
+//! - `A\t<seq>\t<occurred_at_ms>\t<detected_at_ms>\t<escaped_path>` — событие добавлено
+//! - `D\t<seq>` — событие доставлено
+//!
+//! В пути экранируются `\\`, `\t`, `\n`, `\r`. Повреждённые строки
+//! (например, недописанная последняя строка после краха) пропускаются.
+
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use log::{debug, info, warn};
+
+use crate::error::LateraError;
+use crate::file_metadata;
+use crate::file_type;
+use crate::file_watcher::{self, FileEventKind, InternalFileEvent};
+
+/// Имя файла WAL в папке данных.
+pub const EVENT_WAL_FILE: &str = "events.wal";
+
+/// Порог размера файла, после которого WAL переписывается только
+/// с неподтверждёнными записями.
+pub const COMPACT_THRESHOLD_BYTES: u64 = 1024 * 1024;
+
+/// Неподтверждённая запись WAL.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WalEntry {
+    /// Порядковый номер события (монотонный в пределах файла WAL,
+    /// в том числе между запусками).
+    pub sequence: u64,
+    /// Время события (Unix timestamp в миллисекундах).
+    pub occurred_at_ms: i64,
+    /// Момент обнаружения (Unix timestamp в миллисекундах).
+    pub detected_at_ms: i64,
+    /// Полный путь к файлу.
+    pub full_path: PathBuf,
+}
+
+impl WalEntry {
+    /// Восстанавливает внутреннее событие для повторной доставки.
+    ///
+    /// Монотонное время прошлого запуска не переносится между процессами,
+    /// поэтому `monotonic_ms` = момент восстановления. Время создания,
+    /// изменения и размер файла читаются с диска заново.
+    pub fn to_event(&self) -> Option<InternalFileEvent> {
+        let file_name = self.full_path.file_name()?.to_str()?.to_string();
+        let (created_at_ms, modified_at_ms) = file_watcher::file_times_ms(&self.full_path);
+        Some(InternalFileEvent {
+            kind: FileEventKind::Created,
+            file_name,
+            full_path: self.full_path.clone(),
+            previous_path: None,
+            occurred_at_ms: self.occurred_at_ms,
+            detected_at_ms: self.detected_at_ms,
+            monotonic_ms: file_watcher::monotonic_ms(),
+            created_at_ms,
+            modified_at_ms,
+            size_bytes: std::fs::metadata(&self.full_path).ok().map(|m| m.len()),
+            extension: file_type::extension_of(&self.full_path),
+            mime_type: file_type::sniff_mime_type(&self.full_path),
+            access: file_metadata::get_file_access_info(&self.full_path).ok(),
+            self_generated: false,
+            reconciled: false,
+            claim: None,
+            debug: None,
+        })
+    }
+}
+
+/// Write-ahead log событий.
+pub struct EventWal {
+    path: PathBuf,
+    file: File,
+    /// Размер файла, после которого он компактируется.
+    compact_threshold: u64,
+    next_sequence: u64,
+    pending: BTreeMap<u64, WalEntry>,
+}
+
+impl EventWal {
+    /// Открывает WAL, восстанавливая неподтверждённые записи.
+    ///
+    /// Файл сразу компактируется: на диске остаются только номер и
+    /// pending-записи.
+    pub fn open(path: &Path) -> Result<Self, LateraError> {
+        Self::open_with_threshold(path, COMPACT_THRESHOLD_BYTES)
+    }
+
+    fn open_with_threshold(path: &Path, compact_threshold: u64) -> Result<Self, LateraError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let mut pending = BTreeMap::new();
+        let mut max_sequence = 0u64;
+
+        if path.exists() {
+            let reader = BufReader::new(File::open(path)?);
+            for line in reader.lines() {
+                let line = line?;
+                match parse_line(&line) {
+                    Some(WalRecord::HighWater(seq)) => {
+                        max_sequence = max_sequence.max(seq);
+                    }
+                    Some(WalRecord::Appended(entry)) => {
+                        max_sequence = max_sequence.max(entry.sequence);
+                        pending.insert(entry.sequence, entry);
+                    }
+                    Some(WalRecord::Delivered(seq)) => {
+                        max_sequence = max_sequence.max(seq);
+                        pending.remove(&seq);
+                    }
+                    None => {
+                        if !line.is_empty() {
+                            warn!("Event WAL: skipping corrupted line");
+                        }
+                    }
+                }
+            }
+        }
+
+        let file = rewrite(path, max_sequence, pending.values())?;
+        if !pending.is_empty() {
+            info!(
+                "Event WAL: recovered {} undelivered events from {}",
+                pending.len(),
+                path.display()
+            );
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            file,
+            compact_threshold,
+            next_sequence: max_sequence + 1,
+            pending,
+        })
+    }
+
+    /// Записывает событие в WAL до отправки. Возвращает его номер.
+    pub fn append(&mut self, event: &InternalFileEvent) -> Result<u64, LateraError> {
+        let entry = WalEntry {
+            sequence: self.next_sequence,
+            occurred_at_ms: event.occurred_at_ms,
+            detected_at_ms: event.detected_at_ms,
+            full_path: event.full_path.clone(),
+        };
+        write_entry(&mut self.file, &entry)?;
+        // Событие уходит в stream только после того, как запись на диске:
+        // без fsync сбой питания терял бы его вместе с кэшем ОС
+        self.file.sync_data()?;
+
+        self.next_sequence += 1;
+        let sequence = entry.sequence;
+        self.pending.insert(sequence, entry);
+        Ok(sequence)
+    }
+
+    /// Отмечает событие как доставленное.
+    ///
+    /// Отметка дописывается без fsync: потерянная при сбое отметка
+    /// означает лишь повторную доставку (at-least-once).
+    pub fn mark_delivered(&mut self, sequence: u64) -> Result<(), LateraError> {
+        if self.pending.remove(&sequence).is_none() {
+            debug!("Event WAL: sequence {sequence} is not pending");
+            return Ok(());
+        }
+
+        writeln!(self.file, "D\t{sequence}")?;
+        self.file.flush()?;
+
+        if self.file.metadata()?.len() > self.compact_threshold {
+            self.file = rewrite(&self.path, self.next_sequence - 1, self.pending.values())?;
+            debug!("Event WAL compacted ({} pending)", self.pending.len());
+        }
+        Ok(())
+    }
+
+    /// Неподтверждённые записи в порядке возрастания номера.
+    pub fn pending(&self) -> Vec<WalEntry> {
+        self.pending.values().cloned().collect()
+    }
+
+    /// Количество неподтверждённых записей.
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Путь к файлу WAL.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+enum WalRecord {
+    HighWater(u64),
+    Appended(WalEntry),
+    Delivered(u64),
+}
+
+fn parse_line(line: &str) -> Option<WalRecord> {
+    let mut parts = line.splitn(5, '\t');
+    match parts.next()? {
+        "A" => {
+            let sequence = parts.next()?.parse().ok()?;
+            let occurred_at_ms = parts.next()?.parse().ok()?;
+            let detected_at_ms = parts.next()?.parse().ok()?;
+            let full_path = PathBuf::from(unescape(parts.next()?)?);
+            Some(WalRecord::Appended(WalEntry {
+                sequence,
+                occurred_at_ms,
+                detected_at_ms,
+                full_path,
+            }))
+        }
+        "D" => Some(WalRecord::Delivered(parts.next()?.parse().ok()?)),
+        "H" => Some(WalRecord::HighWater(parts.next()?.parse().ok()?)),
+        _ => None,
+    }
+}
+
+/// Атомарно переписывает WAL наибольшим выданным номером и указанными
+/// записями и открывает его на дозапись.
+fn rewrite<'a>(
+    path: &Path,
+    high_water: u64,
+    entries: impl Iterator<Item = &'a WalEntry>,
+) -> Result<File, LateraError> {
+    let tmp_path = path.with_extension("wal.tmp");
+    {
+        let mut tmp = File::create(&tmp_path)?;
+        writeln!(tmp, "H\t{high_water}")?;
+        for e in entries {
+            write_entry(&mut tmp, e)?;
+        }
+        tmp.sync_all()?;
+    }
+    std::fs::rename(&tmp_path, path)?;
+    Ok(OpenOptions::new().append(true).open(path)?)
+}
+
+fn write_entry(out: &mut impl Write, entry: &WalEntry) -> std::io::Result<()> {
+    writeln!(
+        out,
+        "A\t{}\t{}\t{}\t{}",
+        entry.sequence,
+        entry.occurred_at_ms,
+        entry.detected_at_ms,
+        escape(&entry.full_path.to_string_lossy())
+    )
+}
+
+pub(crate) fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+pub(crate) fn unescape(s: &str) -> Option<String> {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next()? {
+                '\\' => out.push('\\'),
+                't' => out.push('\t'),
+                'n' => out.push('\n'),
+                'r' => out.push('\r'),
+                _ => return None,
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(path: &str, at: i64) -> InternalFileEvent {
+        let full_path = PathBuf::from(path);
+        InternalFileEvent {
+            kind: FileEventKind::Created,
+            file_name: full_path.file_name().unwrap().to_string_lossy().to_string(),
+            full_path,
+            previous_path: None,
+            occurred_at_ms: at,
+            detected_at_ms: at,
+            monotonic_ms: 0,
+            created_at_ms: None,
+            modified_at_ms: None,
+            size_bytes: None,
+            extension: None,
+            mime_type: None,
+            access: None,
+            self_generated: false,
+            reconciled: false,
+            claim: None,
+            debug: None,
+        }
+    }
+
+    #[test]
+    fn test_escape_roundtrip() {
+        let raw = "/tmp/we\\ird\tna\nme\r.txt";
+        assert_eq!(unescape(&escape(raw)).as_deref(), Some(raw));
+        assert!(!escape(raw).contains('\n'));
+    }
+
+    #[test]
+    fn test_undelivered_events_survive_reopen() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let wal_path = temp_dir.path().join(EVENT_WAL_FILE);
+
+        {
+            let mut wal = EventWal::open(&wal_path).unwrap();
+            let a = wal.append(&event("/w/a.txt", 1)).unwrap();
+            let _b = wal.append(&event("/w/b.txt", 2)).unwrap();
+            let c = wal.append(&event("/w/c.txt", 3)).unwrap();
+            wal.mark_delivered(a).unwrap();
+            wal.mark_delivered(c).unwrap();
+            // «Краш»: WAL дропается без подтверждения b
+        }
+
+        let mut wal = EventWal::open(&wal_path).unwrap();
+        let pending = wal.pending();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].full_path, PathBuf::from("/w/b.txt"));
+        assert_eq!(pending[0].to_event().unwrap().file_name, "b.txt");
+
+        // Нумерация продолжается после восстановленных записей
+        let next = wal.append(&event("/w/d.txt", 4)).unwrap();
+        assert!(next > pending[0].sequence);
+    }
+
+    #[test]
+    fn test_wal_compacted_past_threshold() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let wal_path = temp_dir.path().join(EVENT_WAL_FILE);
+
+        // Порог 0: компактирование после каждой отметки
+        let mut wal = EventWal::open_with_threshold(&wal_path, 0).unwrap();
+        let a = wal.append(&event("/w/a.txt", 1)).unwrap();
+        let _b = wal.append(&event("/w/b.txt", 2)).unwrap();
+        wal.mark_delivered(a).unwrap();
+
+        let text = std::fs::read_to_string(&wal_path).unwrap();
+        assert_eq!(text.lines().count(), 2, "{text}");
+        assert!(text.starts_with("H\t2\nA\t2\t"), "{text}");
+    }
+
+    #[test]
+    fn test_sequence_survives_compaction_when_all_delivered() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let wal_path = temp_dir.path().join(EVENT_WAL_FILE);
+
+        // Порог 0: компактирование после каждой отметки
+        let mut wal = EventWal::open_with_threshold(&wal_path, 0).unwrap();
+        let first = wal.append(&event("/w/a.txt", 1)).unwrap();
+        wal.mark_delivered(first).unwrap();
+        assert_eq!(wal.pending_count(), 0);
+        assert_eq!(
+            std::fs::read_to_string(&wal_path).unwrap(),
+            format!("H\t{first}\n")
+        );
+        drop(wal);
+
+        // Пустой журнал не сбрасывает нумерацию: запоздалое подтверждение
+        // `first` не заденет новое событие
+        let mut wal = EventWal::open(&wal_path).unwrap();
+        let second = wal.append(&event("/w/b.txt", 2)).unwrap();
+        assert!(second > first);
+        wal.mark_delivered(first).unwrap();
+        assert_eq!(wal.pending_count(), 1);
+    }
+
+    #[test]
+    fn test_delivery_appends_record_below_threshold() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let wal_path = temp_dir.path().join(EVENT_WAL_FILE);
+
+        let mut wal = EventWal::open(&wal_path).unwrap();
+        let seq = wal.append(&event("/w/a.txt", 1)).unwrap();
+        wal.mark_delivered(seq).unwrap();
+
+        let text = std::fs::read_to_string(&wal_path).unwrap();
+        assert!(text.ends_with(&format!("D\t{seq}\n")), "{text}");
+    }
+
+    #[test]
+    fn test_corrupted_tail_is_skipped() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let wal_path = temp_dir.path().join(EVENT_WAL_FILE);
+        std::fs::write(&wal_path, "A\t1\t10\t10\t/w/a.txt\nA\t2\t2").unwrap();
+
+        let wal = EventWal::open(&wal_path).unwrap();
+        assert_eq!(wal.pending_count(), 1);
+    }
+}
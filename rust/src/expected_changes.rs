@@ -0,0 +1,83 @@
+//! Ожидаемые изменения: файлы, которые меняет само ядро.
+//!
+//! Операции ядра в папке наблюдения (очистка по квоте, запись атрибутов,
+//! раскладка файлов) отмечают свои пути перед изменением. Watcher помечает
+//! события по отмеченным путям как `self_generated`, и приложение не
+//! принимает собственные действия ядра за действия пользователя.
+//!
+//! Отметка живёт [`DEFAULT_EXPECTATION_TTL`]: одна операция может дать
+//! несколько событий (Create + Modify), поэтому совпадение её не снимает.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+
+use crate::path_utils;
+
+/// Сколько ждать событий от операции ядра.
+pub const DEFAULT_EXPECTATION_TTL: Duration = Duration::from_secs(10);
+
+/// Нормализованный путь → момент, до которого изменение ожидается.
+static EXPECTED: Lazy<Mutex<HashMap<PathBuf, Instant>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Отметить, что ядро сейчас изменит `path` (на [`DEFAULT_EXPECTATION_TTL`]).
+pub fn expect(path: &Path) {
+    expect_for(path, DEFAULT_EXPECTATION_TTL);
+}
+
+/// Отметить, что ядро изменит `path`; отметка живёт `ttl`.
+pub fn expect_for(path: &Path, ttl: Duration) {
+    let key = path_utils::comparison_key(path);
+    let deadline = Instant::now() + ttl;
+    let mut expected = EXPECTED
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    expected.retain(|_, until| *until > Instant::now());
+    expected.insert(key, deadline);
+}
+
+/// Снять отметку досрочно (операция не состоялась).
+pub fn forget(path: &Path) {
+    EXPECTED
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .remove(&path_utils::comparison_key(path));
+}
+
+/// Вызвано ли изменение `path` самим ядром.
+pub fn is_expected(path: &Path) -> bool {
+    let mut expected = EXPECTED
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if expected.is_empty() {
+        return false;
+    }
+    let now = Instant::now();
+    expected.retain(|_, until| *until > now);
+    expected.contains_key(&path_utils::comparison_key(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expectation_matches_until_expired() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("out.txt");
+        assert!(!is_expected(&path));
+
+        expect(&path);
+        assert!(is_expected(&path));
+        // Повторные события той же операции тоже совпадают
+        assert!(is_expected(&temp_dir.path().join(".").join("out.txt")));
+        forget(&path);
+        assert!(!is_expected(&path));
+
+        expect_for(&path, Duration::ZERO);
+        assert!(!is_expected(&path));
+    }
+}
@@ -0,0 +1,182 @@
+//! Занят ли файл другим процессом.
+//!
+//! Перенос файла, который ещё открыт в Excel или Word, на Windows падает с
+//! sharing violation, а на Unix переносит файл из-под приложения, и оно
+//! сохраняет его по старому пути. Перед переносом приложение спрашивает
+//! [`is_locked`] или ждёт освобождения ([`wait_until_unlocked`]).
+//!
+//! - Windows: файл открывается без общего доступа (share mode `0`) —
+//!   не получится, пока его держит открытым кто-то ещё;
+//! - Unix: проверяются рекомендательные блокировки — `fcntl` (любая) и
+//!   `flock` (эксклюзивная). Файл, открытый без блокировки, занятым не
+//!   считается: на Unix узнать об этом без обхода чужих процессов нельзя.
+
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::error::LateraError;
+
+/// Как часто [`wait_until_unlocked`] проверяет файл.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Держит ли файл другой процесс.
+pub fn is_locked(path: &Path) -> Result<bool, LateraError> {
+    if !path.is_file() {
+        return Err(LateraError::InvalidPath(format!(
+            "file not found: {}",
+            path.display()
+        )));
+    }
+    platform::is_locked(path)
+}
+
+/// Ждёт, пока файл освободится, не дольше `timeout`, проверяя раз в
+/// `poll_interval`. `false` — файл всё ещё занят.
+pub fn wait_until_unlocked(
+    path: &Path,
+    timeout: Duration,
+    poll_interval: Duration,
+) -> Result<bool, LateraError> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if !is_locked(path)? {
+            return Ok(true);
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return Ok(false);
+        }
+        thread::sleep(poll_interval.min(deadline - now));
+    }
+}
+
+#[cfg(unix)]
+mod platform {
+    use std::fs::File;
+    use std::io;
+    use std::os::unix::io::AsRawFd;
+    use std::path::Path;
+
+    use crate::error::LateraError;
+
+    pub(super) fn is_locked(path: &Path) -> Result<bool, LateraError> {
+        let file = File::open(path)?;
+        let fd = file.as_raw_fd();
+
+        // SAFETY: `flock` — POD-структура C, нули — допустимое значение
+        let mut lock: libc::flock = unsafe { std::mem::zeroed() };
+        lock.l_type = libc::F_WRLCK as libc::c_short;
+        lock.l_whence = libc::SEEK_SET as libc::c_short;
+        // F_GETLK не берёт блокировку: только сообщает о конфликтующей
+        // SAFETY: `fd` открыт, пока жив `file`; `lock` — указатель на
+        // инициализированную структуру, которую fcntl заполняет
+        if unsafe { libc::fcntl(fd, libc::F_GETLK, &raw mut lock) } != 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+        if lock.l_type != libc::F_UNLCK as libc::c_short {
+            return Ok(true);
+        }
+
+        // Общая flock конфликтует только с эксклюзивной (запись); своя
+        // снимается сразу и никому не мешает
+        // SAFETY: `fd` открыт, пока жив `file`; flock не трогает память
+        if unsafe { libc::flock(fd, libc::LOCK_SH | libc::LOCK_NB) } != 0 {
+            let error = io::Error::last_os_error();
+            return match error.raw_os_error() {
+                Some(libc::EWOULDBLOCK) => Ok(true),
+                _ => Err(error.into()),
+            };
+        }
+        // SAFETY: как выше; снимаем только что взятую блокировку
+        if unsafe { libc::flock(fd, libc::LOCK_UN) } != 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+        Ok(false)
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::fs::OpenOptions;
+    use std::os::windows::fs::OpenOptionsExt;
+    use std::path::Path;
+
+    use crate::error::LateraError;
+
+    /// ERROR_SHARING_VIOLATION, ERROR_LOCK_VIOLATION.
+    const IN_USE_ERRORS: [i32; 2] = [32, 33];
+
+    pub(super) fn is_locked(path: &Path) -> Result<bool, LateraError> {
+        match OpenOptions::new().read(true).share_mode(0).open(path) {
+            Ok(_) => Ok(false),
+            Err(e)
+                if e.raw_os_error()
+                    .is_some_and(|code| IN_USE_ERRORS.contains(&code)) =>
+            {
+                Ok(true)
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod platform {
+    use std::path::Path;
+
+    use crate::error::LateraError;
+
+    pub(super) fn is_locked(_path: &Path) -> Result<bool, LateraError> {
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlocked_file_and_missing_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("report.xlsx");
+        std::fs::write(&path, b"data").unwrap();
+
+        assert!(!is_locked(&path).unwrap());
+        assert!(wait_until_unlocked(&path, Duration::ZERO, POLL_INTERVAL).unwrap());
+        assert!(is_locked(&temp_dir.path().join("missing.xlsx")).is_err());
+        assert!(is_locked(temp_dir.path()).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_exclusive_flock_is_reported_until_released() {
+        use std::os::unix::io::AsRawFd;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("report.xlsx");
+        std::fs::write(&path, b"data").unwrap();
+
+        // flock принадлежит открытому файлу, так что мешает и своему процессу
+        let holder = std::fs::File::open(&path).unwrap();
+        // SAFETY: дескриптор `holder` открыт
+        assert_eq!(
+            unsafe { libc::flock(holder.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) },
+            0
+        );
+        assert!(is_locked(&path).unwrap());
+        assert!(
+            !wait_until_unlocked(&path, Duration::from_millis(30), Duration::from_millis(10))
+                .unwrap()
+        );
+
+        let release = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            drop(holder);
+        });
+        assert!(
+            wait_until_unlocked(&path, Duration::from_secs(5), Duration::from_millis(10)).unwrap()
+        );
+        release.join().unwrap();
+    }
+}
@@ -0,0 +1,228 @@
+//! Метаданные файла: владелец и права текущего пользователя.
+//!
+//! Нужны UI, чтобы заранее объяснить, почему действие с файлом не удастся
+//! (нет прав на запись, файл принадлежит другому пользователю и т.п.).
+
+use std::path::Path;
+
+use crate::error::LateraError;
+
+/// Владелец файла и упрощённая сводка прав.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FileAccessInfo {
+    /// Имя владельца (`user` на Unix, `DOMAIN\user` на Windows).
+    pub owner_name: Option<String>,
+    /// Идентификатор владельца: uid на Unix, SID на Windows.
+    pub owner_id: Option<String>,
+    /// Текущий пользователь может читать файл.
+    pub readable: bool,
+    /// Текущий пользователь может изменять файл.
+    pub writable: bool,
+    /// Текущий пользователь может запускать файл.
+    pub executable: bool,
+}
+
+/// Получить владельца файла и права текущего пользователя.
+pub fn get_file_access_info(path: &Path) -> Result<FileAccessInfo, LateraError> {
+    let metadata = std::fs::metadata(path)?;
+    Ok(access_info_from(path, &metadata))
+}
+
+/// То же по уже прочитанным метаданным (watcher обогащает ими события).
+pub fn access_info_from(path: &Path, metadata: &std::fs::Metadata) -> FileAccessInfo {
+    let (owner_name, owner_id) = owner(path, metadata);
+    let (readable, writable, executable) = permissions(path, metadata);
+    FileAccessInfo {
+        owner_name,
+        owner_id,
+        readable,
+        writable,
+        executable,
+    }
+}
+
+#[cfg(unix)]
+fn owner(_path: &Path, metadata: &std::fs::Metadata) -> (Option<String>, Option<String>) {
+    use std::os::unix::fs::MetadataExt;
+
+    let uid = metadata.uid();
+    (user_name(uid), Some(uid.to_string()))
+}
+
+/// Имя пользователя по uid (`getpwuid_r`).
+#[cfg(unix)]
+fn user_name(uid: u32) -> Option<String> {
+    use std::ffi::CStr;
+
+    let mut buf = vec![0 as libc::c_char; 4096];
+    let mut pwd: libc::passwd = unsafe { std::mem::zeroed() };
+    let mut result: *mut libc::passwd = std::ptr::null_mut();
+    let rc = unsafe {
+        libc::getpwuid_r(
+            uid,
+            &raw mut pwd,
+            buf.as_mut_ptr(),
+            buf.len(),
+            &raw mut result,
+        )
+    };
+    if rc != 0 || result.is_null() || pwd.pw_name.is_null() {
+        return None;
+    }
+    let name = unsafe { CStr::from_ptr(pwd.pw_name) };
+    Some(name.to_string_lossy().into_owned())
+}
+
+/// Права текущего пользователя через `access(2)` — учитывает uid/gid и ACL.
+#[cfg(unix)]
+fn permissions(path: &Path, _metadata: &std::fs::Metadata) -> (bool, bool, bool) {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let Ok(c_path) = CString::new(path.as_os_str().as_bytes()) else {
+        return (false, false, false);
+    };
+    let check = |mode| unsafe { libc::access(c_path.as_ptr(), mode) == 0 };
+    (check(libc::R_OK), check(libc::W_OK), check(libc::X_OK))
+}
+
+#[cfg(target_os = "windows")]
+fn owner(path: &Path, _metadata: &std::fs::Metadata) -> (Option<String>, Option<String>) {
+    use windows::core::{HSTRING, PWSTR};
+    use windows::Win32::Foundation::{LocalFree, ERROR_SUCCESS, HLOCAL};
+    use windows::Win32::Security::Authorization::{
+        ConvertSidToStringSidW, GetNamedSecurityInfoW, SE_FILE_OBJECT,
+    };
+    use windows::Win32::Security::{
+        LookupAccountSidW, OWNER_SECURITY_INFORMATION, PSECURITY_DESCRIPTOR, PSID, SID_NAME_USE,
+    };
+
+    let mut owner = PSID::default();
+    let mut descriptor = PSECURITY_DESCRIPTOR::default();
+    let err = unsafe {
+        GetNamedSecurityInfoW(
+            &HSTRING::from(path.as_os_str()),
+            SE_FILE_OBJECT,
+            OWNER_SECURITY_INFORMATION,
+            Some(&raw mut owner),
+            None,
+            None,
+            None,
+            &raw mut descriptor,
+        )
+    };
+    if err != ERROR_SUCCESS {
+        log::debug!(
+            "GetNamedSecurityInfoW failed for {}: {err:?}",
+            path.display()
+        );
+        return (None, None);
+    }
+
+    // owner указывает внутрь descriptor — читаем всё до LocalFree.
+    let sid = unsafe {
+        let mut raw = PWSTR::null();
+        match ConvertSidToStringSidW(owner, &raw mut raw) {
+            Ok(()) => {
+                let sid = raw.to_string().ok();
+                let _ = LocalFree(HLOCAL(raw.0.cast()));
+                sid
+            }
+            Err(_) => None,
+        }
+    };
+
+    let name = unsafe {
+        let mut name = [0u16; 256];
+        let mut domain = [0u16; 256];
+        let mut name_len = name.len() as u32;
+        let mut domain_len = domain.len() as u32;
+        let mut sid_use = SID_NAME_USE::default();
+        LookupAccountSidW(
+            None,
+            owner,
+            PWSTR(name.as_mut_ptr()),
+            &raw mut name_len,
+            PWSTR(domain.as_mut_ptr()),
+            &raw mut domain_len,
+            &raw mut sid_use,
+        )
+        .ok()
+        .map(|()| {
+            let name = String::from_utf16_lossy(&name[..name_len as usize]);
+            let domain = String::from_utf16_lossy(&domain[..domain_len as usize]);
+            if domain.is_empty() {
+                name
+            } else {
+                format!("{domain}\\{name}")
+            }
+        })
+    };
+
+    unsafe {
+        let _ = LocalFree(HLOCAL(descriptor.0));
+    }
+    (name, sid)
+}
+
+/// Права текущего пользователя на Windows.
+///
+/// Проверяются фактическим открытием файла (ACL учитываются ОС), без
+/// изменения содержимого. «Запускаемость» определяется по расширению.
+#[cfg(target_os = "windows")]
+fn permissions(path: &Path, metadata: &std::fs::Metadata) -> (bool, bool, bool) {
+    const EXECUTABLE_EXTENSIONS: &[&str] = &["exe", "bat", "cmd", "com", "msi", "ps1"];
+
+    let readable = std::fs::File::open(path).is_ok();
+    let writable = !metadata.permissions().readonly()
+        && std::fs::OpenOptions::new().write(true).open(path).is_ok();
+    let executable = readable
+        && path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| EXECUTABLE_EXTENSIONS.contains(&e.to_ascii_lowercase().as_str()));
+    (readable, writable, executable)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_access_info_for_own_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("doc.txt");
+        std::fs::write(&path, b"hello").unwrap();
+
+        let info = get_file_access_info(&path).unwrap();
+        assert!(info.readable);
+        assert!(info.writable);
+        assert!(!info.executable);
+        assert!(info.owner_id.is_some());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_read_only_file_is_not_writable() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // root обходит права доступа — проверка бессмысленна
+        if unsafe { libc::geteuid() } == 0 {
+            return;
+        }
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("locked.txt");
+        std::fs::write(&path, b"x").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o444)).unwrap();
+
+        let info = get_file_access_info(&path).unwrap();
+        assert!(info.readable);
+        assert!(!info.writable);
+    }
+
+    #[test]
+    fn test_missing_file_is_error() {
+        assert!(get_file_access_info(Path::new("/definitely/missing/file.txt")).is_err());
+    }
+}
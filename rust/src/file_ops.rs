@@ -0,0 +1,431 @@
+//! Перенос, копирование и переименование файлов по запросу приложения.
+//!
+//! Операции выполняются пулом фоновых тредов и сообщают прогресс
+//! ([`FileOpUpdate`]), чтобы большой файл не блокировал UI. Копия пишется
+//! во временный служебный файл ([`internal_files`]) рядом с целью и
+//! переименовывается в конце: watcher не видит недописанный файл, а
+//! прерванная копия не оставляет обрывок под настоящим именем.
+//!
+//! Изменения отмечаются как сделанные ядром ([`expected_changes`]); перенос
+//! и переименование записываются в журнал аудита ([`crate::audit_log`]).
+
+use std::fs::File;
+use std::io::{ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use log::{debug, warn};
+
+use crate::audit_log::{self, AuditAction};
+use crate::error::LateraError;
+use crate::expected_changes;
+use crate::internal_files;
+use crate::read_only;
+
+/// Сколько операций выполняется одновременно (не больше числа ядер).
+pub const MAX_WORKERS: usize = 4;
+
+/// Размер блока копирования.
+const COPY_CHUNK_SIZE: usize = 1 << 20;
+
+/// Как часто сообщать прогресс одной операции.
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Вид операции.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileOpKind {
+    Move,
+    Copy,
+    Rename,
+}
+
+/// Операция над файлом.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileOp {
+    pub id: u64,
+    pub kind: FileOpKind,
+    pub src: PathBuf,
+    /// Полный путь результата.
+    pub dst: PathBuf,
+    /// Заменить существующий `dst` (иначе это ошибка).
+    pub overwrite: bool,
+}
+
+impl FileOp {
+    /// Проверяет операцию до постановки в очередь: исходный файл есть,
+    /// пути абсолютные, цель свободна (или разрешена замена).
+    pub fn validate(&self) -> Result<(), LateraError> {
+        for path in [&self.src, &self.dst] {
+            if !path.is_absolute() {
+                return Err(LateraError::InvalidPath(format!(
+                    "path must be absolute: {}",
+                    path.display()
+                )));
+            }
+        }
+        if !self.src.is_file() {
+            return Err(LateraError::InvalidPath(format!(
+                "not a file: {}",
+                self.src.display()
+            )));
+        }
+        if self.dst.is_dir() {
+            return Err(LateraError::InvalidPath(format!(
+                "destination is a directory: {}",
+                self.dst.display()
+            )));
+        }
+        if self.dst.exists() && !self.overwrite {
+            return Err(already_exists(&self.dst).into());
+        }
+        Ok(())
+    }
+}
+
+/// Путь для переименования `path` в `new_name` (в той же папке).
+pub fn renamed_path(path: &Path, new_name: &str) -> Result<PathBuf, LateraError> {
+    let is_plain_name = !new_name.is_empty()
+        && Path::new(new_name).file_name() == Some(std::ffi::OsStr::new(new_name))
+        && !new_name.contains(['/', '\\']);
+    if !is_plain_name {
+        return Err(LateraError::InvalidArgument(format!(
+            "new name must be a file name without directories: {new_name:?}"
+        )));
+    }
+    if path.file_name().is_none() {
+        return Err(LateraError::FileNameMissing(path.to_path_buf()));
+    }
+    Ok(path.with_file_name(new_name))
+}
+
+/// Ход операции.
+#[derive(Debug)]
+pub enum FileOpUpdate {
+    /// Скопировано `bytes_done` из `bytes_total`.
+    Progress {
+        bytes_done: u64,
+        bytes_total: u64,
+    },
+    Completed,
+    Failed(LateraError),
+}
+
+/// Пул тредов файловых операций.
+pub struct FileOpsPool {
+    job_tx: mpsc::Sender<FileOp>,
+    stop: Arc<AtomicBool>,
+    workers: Vec<thread::JoinHandle<()>>,
+}
+
+impl FileOpsPool {
+    /// Запускает `workers` тредов; `on_update` вызывается на них.
+    pub fn spawn(
+        workers: usize,
+        on_update: impl Fn(&FileOp, FileOpUpdate) + Send + Sync + 'static,
+    ) -> Result<Self, LateraError> {
+        let (job_tx, job_rx) = mpsc::channel::<FileOp>();
+        let job_rx = Arc::new(Mutex::new(job_rx));
+        let stop = Arc::new(AtomicBool::new(false));
+        let on_update = Arc::new(on_update);
+        let workers = (0..workers.max(1))
+            .map(|index| {
+                let job_rx = Arc::clone(&job_rx);
+                let stop = Arc::clone(&stop);
+                let on_update = Arc::clone(&on_update);
+                thread::Builder::new()
+                    .name(format!("latera-file-ops-{index}"))
+                    .spawn(move || loop {
+                        let job = job_rx
+                            .lock()
+                            .unwrap_or_else(std::sync::PoisonError::into_inner)
+                            .recv();
+                        let Ok(job) = job else { break };
+                        let result = run(&job, &stop, &|bytes_done, bytes_total| {
+                            on_update(
+                                &job,
+                                FileOpUpdate::Progress {
+                                    bytes_done,
+                                    bytes_total,
+                                },
+                            );
+                        });
+                        let update = match result {
+                            Ok(()) => FileOpUpdate::Completed,
+                            Err(error) => {
+                                debug!("File operation {} failed: {error}", job.id);
+                                FileOpUpdate::Failed(error)
+                            }
+                        };
+                        on_update(&job, update);
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            job_tx,
+            stop,
+            workers,
+        })
+    }
+
+    /// Поставить операцию в очередь.
+    pub fn submit(&self, op: FileOp) {
+        if self.job_tx.send(op).is_err() {
+            debug!("File ops pool is closed");
+        }
+    }
+
+    /// Останавливает треды. Идущие копирования прерываются (временные
+    /// файлы удаляются), операции из очереди отбрасываются.
+    pub fn stop(self) {
+        self.stop.store(true, Ordering::Relaxed);
+        drop(self.job_tx);
+        for worker in self.workers {
+            if worker.join().is_err() {
+                warn!("File ops thread panicked");
+            }
+        }
+    }
+}
+
+/// Выполняет операцию на текущем треде.
+///
+/// `progress(bytes_done, bytes_total)` вызывается при копировании; `stop`
+/// прерывает копирование.
+pub fn run(op: &FileOp, stop: &AtomicBool, progress: &dyn Fn(u64, u64)) -> Result<(), LateraError> {
+    if stop.load(Ordering::Relaxed) {
+        return Err(interrupted().into());
+    }
+    op.validate()?;
+    read_only::ensure_writable(match op.kind {
+        FileOpKind::Move => "moving files",
+        FileOpKind::Copy => "copying files",
+        FileOpKind::Rename => "renaming files",
+    })?;
+    match op.kind {
+        FileOpKind::Copy => copy(&op.src, &op.dst, stop, progress)?,
+        FileOpKind::Move | FileOpKind::Rename => {
+            expected_changes::expect(&op.src);
+            expected_changes::expect(&op.dst);
+            if let Err(e) = rename(&op.src, &op.dst, op.overwrite) {
+                if op.kind == FileOpKind::Rename || e.kind() == ErrorKind::AlreadyExists {
+                    expected_changes::forget(&op.src);
+                    expected_changes::forget(&op.dst);
+                    return Err(e.into());
+                }
+                // Другой том: копируем и удаляем исходник
+                expected_changes::forget(&op.dst);
+                copy(&op.src, &op.dst, stop, progress)?;
+                std::fs::remove_file(&op.src)?;
+            }
+            audit_log::record(
+                AuditAction::Move,
+                &op.src,
+                Some(&op.dst.to_string_lossy()),
+                None,
+            );
+        }
+    }
+    Ok(())
+}
+
+fn rename(src: &Path, dst: &Path, overwrite: bool) -> std::io::Result<()> {
+    // `rename` молча заменяет цель на Unix — проверяем сами.
+    if !overwrite && dst.exists() {
+        return Err(already_exists(dst));
+    }
+    std::fs::rename(src, dst)
+}
+
+/// Копирует блоками во временный файл рядом с `dst` и переименовывает его.
+fn copy(
+    src: &Path,
+    dst: &Path,
+    stop: &AtomicBool,
+    progress: &dyn Fn(u64, u64),
+) -> Result<(), LateraError> {
+    let parent = dst
+        .parent()
+        .ok_or_else(|| LateraError::FileNameMissing(dst.to_path_buf()))?;
+    std::fs::create_dir_all(parent)?;
+    let partial = parent.join(internal_files::internal_file_name(&format!(
+        "partial-{}",
+        blake3::hash(dst.as_os_str().as_encoded_bytes()).to_hex()[..16].to_owned()
+    )));
+
+    let result = copy_chunks(src, &partial, stop, progress);
+    let result = result.and_then(|()| {
+        expected_changes::expect(dst);
+        std::fs::rename(&partial, dst).map_err(|e| {
+            expected_changes::forget(dst);
+            e.into()
+        })
+    });
+    if result.is_err() {
+        let _ = std::fs::remove_file(&partial);
+    }
+    result
+}
+
+fn copy_chunks(
+    src: &Path,
+    partial: &Path,
+    stop: &AtomicBool,
+    progress: &dyn Fn(u64, u64),
+) -> Result<(), LateraError> {
+    let mut reader = File::open(src)?;
+    let bytes_total = reader.metadata()?.len();
+    let mut writer = File::create(partial)?;
+    let mut buffer = vec![0u8; COPY_CHUNK_SIZE];
+    let mut bytes_done = 0u64;
+    let mut reported_at = Instant::now();
+    progress(0, bytes_total);
+    loop {
+        if stop.load(Ordering::Relaxed) {
+            return Err(interrupted().into());
+        }
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+        writer.write_all(&buffer[..read])?;
+        bytes_done += read as u64;
+        if reported_at.elapsed() >= PROGRESS_INTERVAL {
+            progress(bytes_done, bytes_total);
+            reported_at = Instant::now();
+        }
+    }
+    writer.sync_all()?;
+    if let Ok(modified) = reader.metadata().and_then(|m| m.modified()) {
+        let _ = writer.set_modified(modified);
+    }
+    progress(bytes_done, bytes_total);
+    Ok(())
+}
+
+fn already_exists(path: &Path) -> std::io::Error {
+    std::io::Error::new(
+        ErrorKind::AlreadyExists,
+        format!("destination already exists: {}", path.display()),
+    )
+}
+
+fn interrupted() -> std::io::Error {
+    std::io::Error::new(ErrorKind::Interrupted, "file operation was cancelled")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn op(id: u64, kind: FileOpKind, src: &Path, dst: &Path, overwrite: bool) -> FileOp {
+        FileOp {
+            id,
+            kind,
+            src: src.to_path_buf(),
+            dst: dst.to_path_buf(),
+            overwrite,
+        }
+    }
+
+    #[test]
+    fn test_copy_move_and_rename() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let src = temp_dir.path().join("a.bin");
+        let data = vec![7u8; COPY_CHUNK_SIZE * 2 + 10];
+        std::fs::write(&src, &data).unwrap();
+        let stop = AtomicBool::new(false);
+        let last = Mutex::new((0, 0));
+        let progress = |done, total| *last.lock().unwrap() = (done, total);
+
+        let copy_dst = temp_dir.path().join("sub").join("b.bin");
+        run(
+            &op(1, FileOpKind::Copy, &src, &copy_dst, false),
+            &stop,
+            &progress,
+        )
+        .unwrap();
+        assert_eq!(std::fs::read(&copy_dst).unwrap(), data);
+        assert_eq!(
+            *last.lock().unwrap(),
+            (data.len() as u64, data.len() as u64)
+        );
+        // Временный файл не остался
+        assert_eq!(
+            std::fs::read_dir(copy_dst.parent().unwrap())
+                .unwrap()
+                .count(),
+            1
+        );
+
+        // Занятая цель без разрешения замены — ошибка, файлы не тронуты
+        let err = run(
+            &op(2, FileOpKind::Move, &src, &copy_dst, false),
+            &stop,
+            &progress,
+        );
+        assert!(matches!(err, Err(LateraError::Io(e)) if e.kind() == ErrorKind::AlreadyExists));
+        assert!(src.exists());
+
+        let moved = temp_dir.path().join("c.bin");
+        run(
+            &op(3, FileOpKind::Move, &src, &moved, false),
+            &stop,
+            &progress,
+        )
+        .unwrap();
+        assert!(!src.exists());
+
+        let renamed = renamed_path(&moved, "d.bin").unwrap();
+        run(
+            &op(4, FileOpKind::Rename, &moved, &renamed, false),
+            &stop,
+            &progress,
+        )
+        .unwrap();
+        assert_eq!(std::fs::read(&renamed).unwrap(), data);
+        assert!(renamed_path(&moved, "../x.bin").is_err());
+        assert!(renamed_path(&moved, "").is_err());
+    }
+
+    #[test]
+    fn test_pool_reports_completion() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let src = temp_dir.path().join("a.txt");
+        std::fs::write(&src, b"abc").unwrap();
+
+        let (tx, rx) = mpsc::channel();
+        let tx = Mutex::new(tx);
+        let pool = FileOpsPool::spawn(2, move |op, update| {
+            if !matches!(update, FileOpUpdate::Progress { .. }) {
+                let _ = tx.lock().unwrap().send((op.id, update));
+            }
+        })
+        .unwrap();
+        pool.submit(op(
+            1,
+            FileOpKind::Copy,
+            &src,
+            &temp_dir.path().join("b.txt"),
+            false,
+        ));
+        pool.submit(op(
+            2,
+            FileOpKind::Copy,
+            &temp_dir.path().join("missing.txt"),
+            &temp_dir.path().join("c.txt"),
+            false,
+        ));
+        let mut updates: Vec<_> = (0..2)
+            .map(|_| rx.recv_timeout(Duration::from_secs(5)).unwrap())
+            .collect();
+        updates.sort_by_key(|(id, _)| *id);
+        assert!(matches!(updates[0], (1, FileOpUpdate::Completed)));
+        assert!(matches!(updates[1], (2, FileOpUpdate::Failed(_))));
+        pool.stop();
+    }
+}
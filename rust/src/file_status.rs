@@ -0,0 +1,217 @@
+//! Статус обработки известных файлов.
+//!
+//! Единый источник правды для бейджей в UI (и будущей интеграции с
+//! оболочкой ОС): каждому известному файлу сопоставлен статус
+//! [`FileStatus`]. Хранится локально в `{data_dir}/file_status.db`.
+//!
+//! Watcher ставит новым файлам [`FileStatus::New`] и удаляет запись при
+//! удалении файла; остальные переходы задаёт Dart.
+
+use std::path::{Path, PathBuf};
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::error::LateraError;
+
+/// Имя файла хранилища статусов в папке данных.
+pub const FILE_STATUS_DB_FILE: &str = "file_status.db";
+
+/// Статус обработки файла.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FileStatus {
+    New,
+    Processing,
+    Processed,
+    Failed,
+    Quarantined,
+}
+
+impl FileStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FileStatus::New => "new",
+            FileStatus::Processing => "processing",
+            FileStatus::Processed => "processed",
+            FileStatus::Failed => "failed",
+            FileStatus::Quarantined => "quarantined",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "new" => Some(FileStatus::New),
+            "processing" => Some(FileStatus::Processing),
+            "processed" => Some(FileStatus::Processed),
+            "failed" => Some(FileStatus::Failed),
+            "quarantined" => Some(FileStatus::Quarantined),
+            _ => None,
+        }
+    }
+}
+
+/// Статус файла с моментом последнего изменения.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileStatusRecord {
+    pub path: PathBuf,
+    pub status: FileStatus,
+    /// Unix timestamp в миллисекундах.
+    pub updated_at_ms: i64,
+}
+
+/// Локальное хранилище статусов.
+pub struct FileStatusStore {
+    conn: Connection,
+}
+
+impl FileStatusStore {
+    /// Открывает (или создаёт) хранилище.
+    pub fn open(db_path: &Path) -> Result<Self, LateraError> {
+        if let Some(parent) = db_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let conn = Connection::open(db_path)?;
+        conn.execute_batch(
+            "PRAGMA journal_mode=WAL;
+            CREATE TABLE IF NOT EXISTS file_status (
+                path TEXT PRIMARY KEY,
+                status TEXT NOT NULL,
+                updated_at_ms INTEGER NOT NULL
+            );",
+        )?;
+        Ok(Self { conn })
+    }
+
+    /// Статус файла; `None` — файл неизвестен.
+    pub fn get(&self, path: &Path) -> Result<Option<FileStatusRecord>, LateraError> {
+        let row = self
+            .conn
+            .query_row(
+                "SELECT status, updated_at_ms FROM file_status WHERE path = ?1",
+                params![path.to_string_lossy()],
+                |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?)),
+            )
+            .optional()?;
+        Ok(row.and_then(|(status, updated_at_ms)| {
+            Some(FileStatusRecord {
+                path: path.to_path_buf(),
+                status: FileStatus::parse(&status)?,
+                updated_at_ms,
+            })
+        }))
+    }
+
+    /// Устанавливает статус файла. Возвращает `true`, если статус изменился.
+    pub fn set(
+        &self,
+        path: &Path,
+        status: FileStatus,
+        updated_at_ms: i64,
+    ) -> Result<bool, LateraError> {
+        let changed = self.conn.execute(
+            "INSERT INTO file_status (path, status, updated_at_ms) VALUES (?1, ?2, ?3)
+             ON CONFLICT(path) DO UPDATE SET
+                status = excluded.status, updated_at_ms = excluded.updated_at_ms
+             WHERE status != excluded.status",
+            params![path.to_string_lossy(), status.as_str(), updated_at_ms],
+        )?;
+        Ok(changed > 0)
+    }
+
+    /// Устанавливает статусы нескольких файлов одной транзакцией.
+    ///
+    /// Возвращает индексы обновлений, которые изменили статус.
+    pub fn set_many(
+        &mut self,
+        updates: &[(PathBuf, FileStatus)],
+        updated_at_ms: i64,
+    ) -> Result<Vec<usize>, LateraError> {
+        let tx = self.conn.transaction()?;
+        let mut changed = Vec::new();
+        {
+            let mut stmt = tx.prepare(
+                "INSERT INTO file_status (path, status, updated_at_ms) VALUES (?1, ?2, ?3)
+                 ON CONFLICT(path) DO UPDATE SET
+                    status = excluded.status, updated_at_ms = excluded.updated_at_ms
+                 WHERE status != excluded.status",
+            )?;
+            for (index, (path, status)) in updates.iter().enumerate() {
+                let rows = stmt.execute(params![
+                    path.to_string_lossy(),
+                    status.as_str(),
+                    updated_at_ms
+                ])?;
+                if rows > 0 {
+                    changed.push(index);
+                }
+            }
+        }
+        tx.commit()?;
+        Ok(changed)
+    }
+
+    /// Забывает файл. Возвращает `true`, если запись была.
+    pub fn remove(&self, path: &Path) -> Result<bool, LateraError> {
+        let removed = self.conn.execute(
+            "DELETE FROM file_status WHERE path = ?1",
+            params![path.to_string_lossy()],
+        )?;
+        Ok(removed > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_get_remove() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let store = FileStatusStore::open(&temp_dir.path().join(FILE_STATUS_DB_FILE)).unwrap();
+        let path = Path::new("/w/a.pdf");
+
+        assert_eq!(store.get(path).unwrap(), None);
+        assert!(store.set(path, FileStatus::New, 10).unwrap());
+        // Тот же статус — не изменение
+        assert!(!store.set(path, FileStatus::New, 20).unwrap());
+        assert!(store.set(path, FileStatus::Processed, 30).unwrap());
+
+        let record = store.get(path).unwrap().unwrap();
+        assert_eq!(record.status, FileStatus::Processed);
+        assert_eq!(record.updated_at_ms, 30);
+
+        assert!(store.remove(path).unwrap());
+        assert_eq!(store.get(path).unwrap(), None);
+    }
+
+    #[test]
+    fn test_set_many_reports_changed() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut store = FileStatusStore::open(&temp_dir.path().join(FILE_STATUS_DB_FILE)).unwrap();
+        store
+            .set(Path::new("/w/a.pdf"), FileStatus::Processed, 10)
+            .unwrap();
+
+        let updates = vec![
+            (PathBuf::from("/w/a.pdf"), FileStatus::Processed),
+            (PathBuf::from("/w/b.pdf"), FileStatus::Failed),
+        ];
+        assert_eq!(store.set_many(&updates, 20).unwrap(), vec![1]);
+        assert_eq!(
+            store.get(Path::new("/w/b.pdf")).unwrap().unwrap().status,
+            FileStatus::Failed
+        );
+    }
+
+    #[test]
+    fn test_status_round_trip() {
+        for status in [
+            FileStatus::New,
+            FileStatus::Processing,
+            FileStatus::Processed,
+            FileStatus::Failed,
+            FileStatus::Quarantined,
+        ] {
+            assert_eq!(FileStatus::parse(status.as_str()), Some(status));
+        }
+    }
+}
@@ -0,0 +1,194 @@
+//! Тип файла: расширение и MIME-тип по содержимому.
+//!
+//! MIME определяется по сигнатуре первых байт файла, а не по расширению:
+//! `scan.pdf`, на деле оказавшийся JPEG, получит `image/jpeg`. Расширение
+//! используется только для уточнения контейнеров (ZIP → DOCX/XLSX/…) и
+//! текстовых форматов, у которых сигнатуры нет.
+
+use std::io::Read;
+use std::path::Path;
+
+/// Сколько байт читать для определения типа.
+const SNIFF_LEN: usize = 512;
+
+/// Сигнатуры: смещение, байты, MIME-тип.
+const SIGNATURES: &[(usize, &[u8], &str)] = &[
+    (0, b"%PDF-", "application/pdf"),
+    (0, b"\x89PNG\r\n\x1a\n", "image/png"),
+    (0, b"\xff\xd8\xff", "image/jpeg"),
+    (0, b"GIF87a", "image/gif"),
+    (0, b"GIF89a", "image/gif"),
+    (0, b"BM", "image/bmp"),
+    (0, b"II*\0", "image/tiff"),
+    (0, b"MM\0*", "image/tiff"),
+    (8, b"WEBP", "image/webp"),
+    (4, b"ftypheic", "image/heic"),
+    (4, b"ftypheix", "image/heic"),
+    (4, b"ftypavif", "image/avif"),
+    (4, b"ftypqt", "video/quicktime"),
+    (4, b"ftyp", "video/mp4"),
+    (8, b"WAVE", "audio/wav"),
+    (8, b"AVI ", "video/x-msvideo"),
+    (0, b"ID3", "audio/mpeg"),
+    (0, b"fLaC", "audio/flac"),
+    (0, b"OggS", "audio/ogg"),
+    (0, b"\x1a\x45\xdf\xa3", "video/x-matroska"),
+    (0, b"PK\x03\x04", "application/zip"),
+    (0, b"Rar!\x1a\x07", "application/vnd.rar"),
+    (0, b"7z\xbc\xaf\x27\x1c", "application/x-7z-compressed"),
+    (0, b"\x1f\x8b", "application/gzip"),
+    (
+        0,
+        b"\xd0\xcf\x11\xe0\xa1\xb1\x1a\xe1",
+        "application/x-ole-storage",
+    ),
+    (0, b"SQLite format 3\0", "application/vnd.sqlite3"),
+    (0, b"{\\rtf", "application/rtf"),
+];
+
+/// Расширение файла в нижнем регистре, без точки.
+pub fn extension_of(path: &Path) -> Option<String> {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .filter(|e| !e.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// MIME-тип файла по содержимому.
+///
+/// `None` — файл пуст или недоступен для чтения. Неопознанные двоичные
+/// данные — `application/octet-stream`.
+pub fn sniff_mime_type(path: &Path) -> Option<String> {
+    let mut head = Vec::with_capacity(SNIFF_LEN);
+    std::fs::File::open(path)
+        .ok()?
+        .take(SNIFF_LEN as u64)
+        .read_to_end(&mut head)
+        .ok()?;
+    if head.is_empty() {
+        return None;
+    }
+    let extension = extension_of(path);
+    Some(mime_from_head(&head, extension.as_deref()).to_string())
+}
+
+fn mime_from_head(head: &[u8], extension: Option<&str>) -> &'static str {
+    let signature = SIGNATURES
+        .iter()
+        .find(|(offset, magic, _)| head.get(*offset..offset + magic.len()) == Some(*magic))
+        .map(|(_, _, mime)| *mime);
+    match signature {
+        Some("application/zip") => zip_container(extension),
+        Some("application/x-ole-storage") => ole_container(extension),
+        Some(mime) => mime,
+        None if looks_like_text(head) => text_type(extension),
+        None => "application/octet-stream",
+    }
+}
+
+/// ZIP-контейнеры Office/OpenDocument/EPUB различаются только расширением
+/// (без распаковки).
+fn zip_container(extension: Option<&str>) -> &'static str {
+    match extension {
+        Some("docx") => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        Some("xlsx") => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        Some("pptx") => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        Some("odt") => "application/vnd.oasis.opendocument.text",
+        Some("ods") => "application/vnd.oasis.opendocument.spreadsheet",
+        Some("epub") => "application/epub+zip",
+        _ => "application/zip",
+    }
+}
+
+/// Старые форматы Office (OLE Compound File).
+fn ole_container(extension: Option<&str>) -> &'static str {
+    match extension {
+        Some("doc") => "application/msword",
+        Some("xls") => "application/vnd.ms-excel",
+        Some("ppt") => "application/vnd.ms-powerpoint",
+        Some("msg") => "application/vnd.ms-outlook",
+        _ => "application/x-ole-storage",
+    }
+}
+
+fn text_type(extension: Option<&str>) -> &'static str {
+    match extension {
+        Some("md" | "markdown") => "text/markdown",
+        Some("csv") => "text/csv",
+        Some("json") => "application/json",
+        Some("xml") => "application/xml",
+        Some("html" | "htm") => "text/html",
+        Some("svg") => "image/svg+xml",
+        _ => "text/plain",
+    }
+}
+
+/// UTF-8 (или UTF-16 с BOM) без управляющих символов, кроме пробельных.
+///
+/// Последний символ мог быть обрезан на границе [`SNIFF_LEN`].
+fn looks_like_text(head: &[u8]) -> bool {
+    if head.starts_with(b"\xff\xfe") || head.starts_with(b"\xfe\xff") {
+        return true;
+    }
+    let text = match std::str::from_utf8(head) {
+        Ok(text) => text,
+        Err(e) if e.error_len().is_none() => {
+            std::str::from_utf8(&head[..e.valid_up_to()]).unwrap_or_default()
+        }
+        Err(_) => return false,
+    };
+    !text
+        .chars()
+        .any(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t' | '\x0c'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signature_wins_over_extension() {
+        assert_eq!(
+            mime_from_head(b"\xff\xd8\xff\xe0rest", Some("pdf")),
+            "image/jpeg"
+        );
+        assert_eq!(mime_from_head(b"%PDF-1.7\n", None), "application/pdf");
+        assert_eq!(
+            mime_from_head(b"PK\x03\x04....", Some("docx")),
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.document"
+        );
+        assert_eq!(
+            mime_from_head(b"PK\x03\x04....", Some("pdf")),
+            "application/zip"
+        );
+    }
+
+    #[test]
+    fn test_text_and_binary() {
+        assert_eq!(
+            mime_from_head("Привет\n".as_bytes(), Some("txt")),
+            "text/plain"
+        );
+        assert_eq!(mime_from_head(b"# Title\n", Some("md")), "text/markdown");
+        assert_eq!(
+            mime_from_head(b"\x00\x01\x02\x03", None),
+            "application/octet-stream"
+        );
+        // Многобайтовый символ, обрезанный на границе чтения
+        assert_eq!(mime_from_head(&"ж".as_bytes()[..1], None), "text/plain");
+    }
+
+    #[test]
+    fn test_sniff_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("Photo.PNG");
+        std::fs::write(&path, b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR").unwrap();
+        assert_eq!(sniff_mime_type(&path).as_deref(), Some("image/png"));
+        assert_eq!(extension_of(&path).as_deref(), Some("png"));
+
+        let empty = temp_dir.path().join("empty");
+        std::fs::write(&empty, b"").unwrap();
+        assert_eq!(sniff_mime_type(&empty), None);
+        assert_eq!(extension_of(&empty), None);
+    }
+}
@@ -0,0 +1,112 @@
+//! Накопление событий сверх rate-limit.
+//!
+//! Вместо того чтобы терять события при burst-копировании, watcher
+//! складывает избыток в пачку и отдаёт её целиком — раз в
+//! [`BATCH_FLUSH_INTERVAL`] или при [`MAX_BATCH_SIZE`] событиях.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use super::events::{FileEventKind, InternalFileEvent};
+
+/// Как долго копится пачка.
+pub const BATCH_FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Пачка отдаётся досрочно, набрав столько событий.
+pub const MAX_BATCH_SIZE: usize = 1000;
+
+/// Пачка событий, не прошедших rate-limit.
+///
+/// Повторные события того же вида для того же пути схлопываются: в пачке
+/// остаётся последнее.
+#[derive(Default)]
+pub(crate) struct EventBatch {
+    events: Vec<InternalFileEvent>,
+    index: HashMap<(FileEventKind, PathBuf), usize>,
+    started_at: Option<Instant>,
+}
+
+impl EventBatch {
+    pub(crate) fn push(&mut self, event: InternalFileEvent, now: Instant) {
+        self.started_at.get_or_insert(now);
+        let key = (event.kind, event.full_path.clone());
+        if let Some(&i) = self.index.get(&key) {
+            self.events[i] = event;
+        } else {
+            self.index.insert(key, self.events.len());
+            self.events.push(event);
+        }
+    }
+
+    /// Пора ли отдавать пачку.
+    pub(crate) fn is_due(&self, now: Instant) -> bool {
+        self.events.len() >= MAX_BATCH_SIZE
+            || self
+                .started_at
+                .is_some_and(|started| now.duration_since(started) >= BATCH_FLUSH_INTERVAL)
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Ждёт ли в пачке событие по `path`.
+    pub(crate) fn contains(&self, path: &Path) -> bool {
+        self.events.iter().any(|e| e.full_path == path)
+    }
+
+    /// Забирает накопленные события (в порядке поступления).
+    pub(crate) fn take(&mut self) -> Vec<InternalFileEvent> {
+        self.index.clear();
+        self.started_at = None;
+        std::mem::take(&mut self.events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(kind: FileEventKind, path: &str, at: i64) -> InternalFileEvent {
+        InternalFileEvent {
+            kind,
+            file_name: path.to_string(),
+            full_path: PathBuf::from(path),
+            previous_path: None,
+            occurred_at_ms: at,
+            detected_at_ms: at,
+            monotonic_ms: at,
+            created_at_ms: None,
+            modified_at_ms: None,
+            size_bytes: None,
+            extension: None,
+            mime_type: None,
+            access: None,
+            self_generated: false,
+            reconciled: false,
+            claim: None,
+            debug: None,
+        }
+    }
+
+    #[test]
+    fn test_batch_coalesces_and_flushes_on_interval() {
+        let start = Instant::now();
+        let mut batch = EventBatch::default();
+        assert!(!batch.is_due(start));
+
+        batch.push(event(FileEventKind::Created, "/w/a", 1), start);
+        batch.push(event(FileEventKind::Modified, "/w/a", 2), start);
+        batch.push(event(FileEventKind::Modified, "/w/a", 3), start);
+        batch.push(event(FileEventKind::Created, "/w/b", 4), start);
+        assert!(!batch.is_due(start + BATCH_FLUSH_INTERVAL / 2));
+        assert!(batch.is_due(start + BATCH_FLUSH_INTERVAL));
+
+        let events = batch.take();
+        let at: Vec<i64> = events.iter().map(|e| e.occurred_at_ms).collect();
+        assert_eq!(at, vec![1, 3, 4]);
+        assert!(batch.is_empty());
+        assert!(!batch.is_due(start + BATCH_FLUSH_INTERVAL * 2));
+    }
+}
@@ -0,0 +1,176 @@
+//! Отладочный контекст событий (режим [`WatcherOptions::debug_enrichment`]).
+//!
+//! Для каждого пути копится «след»: какие события `notify` пришли, какие
+//! из них отброшены дедупликацией или придержаны, сколько читались
+//! метаданные. След прикрепляется к событию, которое в итоге отдано
+//! подписчику, и сбрасывается — по нему служба поддержки видит, почему
+//! событие выглядит именно так.
+//!
+//! [`WatcherOptions::debug_enrichment`]: super::WatcherOptions::debug_enrichment
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::{monotonic_ms, InternalFileEvent};
+
+/// Сколько событий `notify` и решений хранить на путь (самые старые
+/// отбрасываются).
+const MAX_TRAIL_ENTRIES: usize = 32;
+
+/// Для скольких путей одновременно копить след; при переполнении следы
+/// сбрасываются целиком — лучше потерять контекст, чем память.
+const MAX_TRAILS: usize = 1000;
+
+/// Отладочный контекст события.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EventDebugInfo {
+    /// События `notify` по этому пути с прошлого сообщённого события
+    /// (`EventKind` в виде `Debug`), по порядку.
+    pub notify_kinds: Vec<String>,
+    /// Решения watcher'а по пути: отброшенные дубликаты, ожидание
+    /// стабилизации, откладывание в пачку.
+    pub decisions: Vec<String>,
+    /// Первое событие `notify` следа (монотонные мс, как
+    /// [`InternalFileEvent::monotonic_ms`]).
+    pub first_seen_monotonic_ms: i64,
+    /// Сколько читались метаданные файла.
+    pub metadata_ms: u64,
+    /// Сколько событие ждало после сборки (стабилизация, пачка).
+    pub held_ms: u64,
+    /// Когда событие отдано подписчику (монотонные мс).
+    pub delivered_monotonic_ms: i64,
+}
+
+#[derive(Debug)]
+struct Trail {
+    notify_kinds: Vec<String>,
+    decisions: Vec<String>,
+    first_seen_monotonic_ms: i64,
+    metadata_ms: u64,
+}
+
+fn push_bounded(entries: &mut Vec<String>, entry: String) {
+    if entries.len() >= MAX_TRAIL_ENTRIES {
+        entries.remove(0);
+    }
+    entries.push(entry);
+}
+
+/// Следы событий по путям.
+#[derive(Debug, Default)]
+pub(super) struct DebugTrails {
+    trails: HashMap<PathBuf, Trail>,
+}
+
+impl DebugTrails {
+    fn trail(&mut self, path: &Path) -> &mut Trail {
+        if self.trails.len() >= MAX_TRAILS && !self.trails.contains_key(path) {
+            self.trails.clear();
+        }
+        self.trails
+            .entry(path.to_path_buf())
+            .or_insert_with(|| Trail {
+                notify_kinds: Vec::new(),
+                decisions: Vec::new(),
+                first_seen_monotonic_ms: monotonic_ms(),
+                metadata_ms: 0,
+            })
+    }
+
+    /// Пришло событие `notify` по путям `paths`.
+    pub fn observe(&mut self, kind: &notify::EventKind, paths: &[PathBuf]) {
+        for path in paths {
+            push_bounded(&mut self.trail(path).notify_kinds, format!("{kind:?}"));
+        }
+    }
+
+    /// Watcher принял решение по пути.
+    pub fn decide(&mut self, path: &Path, decision: impl Into<String>) {
+        push_bounded(&mut self.trail(path).decisions, decision.into());
+    }
+
+    /// Метаданные пути читались `took`.
+    pub fn metadata_took(&mut self, path: &Path, took: Duration) {
+        let trail = self.trail(path);
+        trail.metadata_ms = trail
+            .metadata_ms
+            .saturating_add(u64::try_from(took.as_millis()).unwrap_or(u64::MAX));
+    }
+
+    /// Событие отдаётся подписчику: прикрепить след и сбросить его.
+    pub fn attach(&mut self, event: &mut InternalFileEvent) {
+        let delivered = monotonic_ms();
+        let trail = self.trails.remove(&event.full_path);
+        let (notify_kinds, decisions, first_seen, metadata_ms) = match trail {
+            Some(t) => (
+                t.notify_kinds,
+                t.decisions,
+                t.first_seen_monotonic_ms,
+                t.metadata_ms,
+            ),
+            None => (Vec::new(), Vec::new(), event.monotonic_ms, 0),
+        };
+        event.debug = Some(Box::new(EventDebugInfo {
+            notify_kinds,
+            decisions,
+            first_seen_monotonic_ms: first_seen,
+            metadata_ms,
+            held_ms: u64::try_from(delivered.saturating_sub(event.monotonic_ms)).unwrap_or(0),
+            delivered_monotonic_ms: delivered,
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file_watcher::FileEventKind;
+    use notify::event::{CreateKind, ModifyKind};
+    use notify::EventKind;
+
+    #[test]
+    fn test_trail_attached_to_delivered_event() {
+        let path = PathBuf::from("/watched/report.pdf");
+        let mut trails = DebugTrails::default();
+        trails.observe(
+            &EventKind::Create(CreateKind::File),
+            std::slice::from_ref(&path),
+        );
+        trails.observe(
+            &EventKind::Modify(ModifyKind::Any),
+            std::slice::from_ref(&path),
+        );
+        trails.decide(&path, "dedup: duplicate Modified");
+        trails.metadata_took(&path, Duration::from_millis(3));
+
+        let mut event = InternalFileEvent {
+            kind: FileEventKind::Created,
+            file_name: "report.pdf".to_string(),
+            full_path: path.clone(),
+            previous_path: None,
+            occurred_at_ms: 0,
+            detected_at_ms: 0,
+            monotonic_ms: monotonic_ms(),
+            created_at_ms: None,
+            modified_at_ms: None,
+            size_bytes: None,
+            extension: Some("pdf".to_string()),
+            mime_type: None,
+            access: None,
+            self_generated: false,
+            reconciled: false,
+            claim: None,
+            debug: None,
+        };
+        trails.attach(&mut event);
+        let info = event.debug.take().unwrap();
+        assert_eq!(info.notify_kinds, vec!["Create(File)", "Modify(Any)"]);
+        assert_eq!(info.decisions, vec!["dedup: duplicate Modified"]);
+        assert_eq!(info.metadata_ms, 3);
+
+        // След сброшен: следующее событие начинает новый
+        trails.attach(&mut event);
+        assert!(event.debug.unwrap().notify_kinds.is_empty());
+    }
+}
@@ -0,0 +1,125 @@
+//! Выбор папки наблюдения по умолчанию без конфликтов.
+//!
+//! По умолчанию наблюдается `Desktop/Latera`. Если под этим именем уже
+//! лежит файл или чужая папка, ядро не использует её и не падает, а берёт
+//! следующее свободное имя: `Latera (2)`, `Latera (3)`, …
+//!
+//! Своя папка помечается маркером (см. [`super::folder_marker`]).
+//! Пустая папка без маркера (или только со служебными файлами) считается
+//! своей и получает маркер; непустая папка без маркера — чужая.
+
+use std::path::{Path, PathBuf};
+
+use super::{folder_marker, DEFAULT_WATCH_FOLDER_NAME};
+use crate::error::LateraError;
+use crate::internal_files;
+
+/// Сколько имён перебирать: `Latera`, `Latera (2)` … `Latera (99)`.
+const MAX_CANDIDATES: u32 = 99;
+
+/// Почему имя пропущено.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Occupant {
+    /// Под этим именем лежит файл.
+    File,
+    /// Папка другого приложения (или пользователя).
+    ForeignFolder,
+}
+
+/// Что лежит под именем-кандидатом.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Slot {
+    Free,
+    Ours,
+    Taken(Occupant),
+}
+
+/// Выбранная папка и пропущенные перед ней имена.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DefaultWatchDir {
+    pub path: PathBuf,
+    pub skipped: Vec<(PathBuf, Occupant)>,
+}
+
+fn candidate_name(index: u32) -> String {
+    if index == 1 {
+        DEFAULT_WATCH_FOLDER_NAME.to_string()
+    } else {
+        format!("{DEFAULT_WATCH_FOLDER_NAME} ({index})")
+    }
+}
+
+fn inspect(path: &Path) -> Slot {
+    if std::fs::symlink_metadata(path).is_err() {
+        return Slot::Free;
+    }
+    if !path.is_dir() {
+        return Slot::Taken(Occupant::File);
+    }
+    if folder_marker::is_marked(path) {
+        return Slot::Ours;
+    }
+    let only_internal = std::fs::read_dir(path).is_ok_and(|entries| {
+        entries
+            .flatten()
+            .all(|entry| internal_files::is_internal(&entry.path()))
+    });
+    if only_internal {
+        Slot::Ours
+    } else {
+        Slot::Taken(Occupant::ForeignFolder)
+    }
+}
+
+/// Первое свободное или своё имя в `parent`. Ничего не создаёт.
+pub fn resolve(parent: &Path) -> Result<DefaultWatchDir, LateraError> {
+    let mut skipped = Vec::new();
+    for index in 1..=MAX_CANDIDATES {
+        let path = parent.join(candidate_name(index));
+        match inspect(&path) {
+            Slot::Free | Slot::Ours => return Ok(DefaultWatchDir { path, skipped }),
+            Slot::Taken(occupant) => skipped.push((path, occupant)),
+        }
+    }
+    Err(LateraError::InvalidPath(format!(
+        "no free name for the default watch folder in {}",
+        parent.display()
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_skips_files_and_foreign_folders() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let parent = temp_dir.path();
+
+        // Свободно — первое имя
+        let resolved = resolve(parent).unwrap();
+        assert_eq!(resolved.path, parent.join("Latera"));
+        assert!(resolved.skipped.is_empty());
+
+        // Файл и чужая папка пропускаются
+        std::fs::write(parent.join("Latera"), b"not a folder").unwrap();
+        std::fs::create_dir(parent.join("Latera (2)")).unwrap();
+        std::fs::write(parent.join("Latera (2)").join("notes.txt"), b"x").unwrap();
+        let resolved = resolve(parent).unwrap();
+        assert_eq!(resolved.path, parent.join("Latera (3)"));
+        assert_eq!(
+            resolved.skipped,
+            vec![
+                (parent.join("Latera"), Occupant::File),
+                (parent.join("Latera (2)"), Occupant::ForeignFolder),
+            ]
+        );
+
+        // Своя папка с файлами пользователя остаётся своей
+        let ours = parent.join("Latera (3)");
+        std::fs::create_dir(&ours).unwrap();
+        folder_marker::ensure(&ours).unwrap();
+        std::fs::write(ours.join("report.pdf"), b"pdf").unwrap();
+        assert_eq!(resolve(parent).unwrap().path, ours);
+    }
+}
@@ -0,0 +1,175 @@
+//! Лестница деградации watcher'а: нативный backend → опрос папки →
+//! только ручное обновление.
+//!
+//! Если нативный backend (`inotify`, FSEvents, `ReadDirectoryChangesW`)
+//! раз за разом падает или не запускается, watcher не сдаётся, а переходит
+//! на ступень ниже. Опрос (`PollWatcher`) медленнее и дороже, но работает
+//! почти везде (сетевые диски, FUSE); в режиме ручного обновления событий
+//! нет вовсе, изменения находятся по
+//! [`WatcherHandle::rescan`](super::WatcherHandle::rescan).
+
+use std::time::Duration;
+
+use crate::volume::VolumeKind;
+
+/// Способ получения изменений.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WatchMode {
+    /// Нативный backend `notify` для платформы.
+    Native,
+    /// Периодический опрос папки.
+    Polling,
+    /// Событий нет; папка сверяется по запросу.
+    ManualRefresh,
+}
+
+impl WatchMode {
+    /// Следующая ступень; `None` — ниже некуда.
+    pub fn next(self) -> Option<Self> {
+        match self {
+            Self::Native => Some(Self::Polling),
+            Self::Polling => Some(Self::ManualRefresh),
+            Self::ManualRefresh => None,
+        }
+    }
+}
+
+/// Каким способом начинать наблюдение.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WatchBackend {
+    /// По тому папки: на сетевом диске — опрос (SMB, NFS и WebDAV часто
+    /// не доставляют уведомления), иначе — нативный backend.
+    #[default]
+    Auto,
+    Native,
+    Polling,
+}
+
+impl WatchBackend {
+    /// Первая ступень для папки на томе `volume`.
+    pub fn initial_mode(self, volume: VolumeKind) -> WatchMode {
+        match self {
+            Self::Auto if volume == VolumeKind::Network => WatchMode::Polling,
+            Self::Auto | Self::Native => WatchMode::Native,
+            Self::Polling => WatchMode::Polling,
+        }
+    }
+}
+
+/// Когда переходить на ступень ниже.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DegradationPolicy {
+    /// Сколько сбоев нативного backend'а подряд, прежде чем перейти на опрос.
+    pub native_failures: u32,
+    /// Сколько сбоев опроса подряд, прежде чем перейти на ручное обновление.
+    pub polling_failures: u32,
+    /// Период опроса папки.
+    pub poll_interval: Duration,
+}
+
+impl Default for DegradationPolicy {
+    fn default() -> Self {
+        Self {
+            native_failures: 3,
+            polling_failures: 3,
+            poll_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Текущая ступень и сбои на ней.
+#[derive(Debug)]
+pub(super) struct Ladder {
+    policy: DegradationPolicy,
+    mode: WatchMode,
+    failures: u32,
+}
+
+impl Ladder {
+    /// Лестница, начинающаяся со ступени `mode` (например, с опроса на
+    /// сетевом диске).
+    pub fn starting_at(policy: DegradationPolicy, mode: WatchMode) -> Self {
+        Self {
+            policy,
+            mode,
+            failures: 0,
+        }
+    }
+
+    pub fn mode(&self) -> WatchMode {
+        self.mode
+    }
+
+    /// Учитывает сбой; `true` — на этой ступени сбоев уже слишком много.
+    pub fn record_failure(&mut self) -> bool {
+        self.failures = self.failures.saturating_add(1);
+        let limit = match self.mode {
+            WatchMode::Native => self.policy.native_failures,
+            WatchMode::Polling => self.policy.polling_failures,
+            WatchMode::ManualRefresh => return false,
+        };
+        self.failures >= limit
+    }
+
+    /// Переходит на ступень ниже; `None` — ниже некуда.
+    pub fn step_down(&mut self) -> Option<WatchMode> {
+        let next = self.mode.next()?;
+        self.mode = next;
+        self.failures = 0;
+        Some(next)
+    }
+
+    /// Backend работает стабильно — сбои забываются.
+    pub fn reset_failures(&mut self) {
+        self.failures = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ladder_steps_down_after_repeated_failures() {
+        let mut ladder = Ladder::starting_at(
+            DegradationPolicy {
+                native_failures: 2,
+                polling_failures: 1,
+                poll_interval: Duration::from_secs(1),
+            },
+            WatchMode::Native,
+        );
+        assert!(!ladder.record_failure());
+        ladder.reset_failures();
+        assert!(!ladder.record_failure());
+        assert!(ladder.record_failure());
+        assert_eq!(ladder.step_down(), Some(WatchMode::Polling));
+
+        assert!(ladder.record_failure());
+        assert_eq!(ladder.step_down(), Some(WatchMode::ManualRefresh));
+
+        // Ручное обновление сломаться не может — ниже некуда
+        assert!(!ladder.record_failure());
+        assert_eq!(ladder.step_down(), None);
+        assert_eq!(ladder.mode(), WatchMode::ManualRefresh);
+    }
+
+    #[test]
+    fn test_auto_backend_polls_network_volumes() {
+        assert_eq!(
+            WatchBackend::Auto.initial_mode(VolumeKind::Network),
+            WatchMode::Polling
+        );
+        assert_eq!(
+            WatchBackend::Auto.initial_mode(VolumeKind::Internal),
+            WatchMode::Native
+        );
+        assert_eq!(
+            WatchBackend::Native.initial_mode(VolumeKind::Network),
+            WatchMode::Native
+        );
+
+        let mut ladder = Ladder::starting_at(DegradationPolicy::default(), WatchMode::Polling);
+        assert_eq!(ladder.step_down(), Some(WatchMode::ManualRefresh));
+    }
+}
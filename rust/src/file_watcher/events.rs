@@ -5,15 +5,103 @@
 
 use std::path::PathBuf;
 
-/// Внутреннее событие: добавлен новый файл.
+use super::EventDebugInfo;
+use crate::claims::ClaimStatus;
+use crate::file_metadata::FileAccessInfo;
+
+/// Вид события файла.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FileEventKind {
+    /// Файл создан (или скопирован в наблюдаемую папку).
+    Created,
+    /// Содержимое файла изменилось.
+    Modified,
+    /// Файл удалён.
+    Removed,
+    /// Файл переименован или перемещён — старый путь (файла по нему больше нет).
+    RenamedFrom,
+    /// Файл переименован или перемещён — новый путь.
+    ///
+    /// Сюда же попадает файл, перемещённый в наблюдаемую папку с того же диска.
+    RenamedTo,
+}
+
+impl FileEventKind {
+    /// Событие о том, что файл появился по этому пути.
+    pub fn is_arrival(self) -> bool {
+        matches!(self, FileEventKind::Created | FileEventKind::RenamedTo)
+    }
+
+    /// Событие о том, что файла по этому пути больше нет.
+    pub fn is_departure(self) -> bool {
+        matches!(self, FileEventKind::Removed | FileEventKind::RenamedFrom)
+    }
+}
+
+/// Внутреннее событие файла.
 #[derive(Clone, Debug)]
 pub struct InternalFileEvent {
+    /// Вид события.
+    pub kind: FileEventKind,
     /// Имя файла.
     pub file_name: String,
     /// Полный путь к файлу.
     pub full_path: PathBuf,
+    /// Прежний путь для [`FileEventKind::RenamedTo`], если платформа сообщила
+    /// обе стороны переименования.
+    pub previous_path: Option<PathBuf>,
     /// Время события (Unix timestamp в миллисекундах).
+    ///
+    /// Смысл задаётся [`TimestampSource`]: момент обнаружения или mtime файла.
     pub occurred_at_ms: i64,
+    /// Момент обнаружения по UTC wall clock (Unix timestamp в миллисекундах).
+    pub detected_at_ms: i64,
+    /// Монотонное время обнаружения: миллисекунды с запуска процесса.
+    ///
+    /// Не скачет при смене часов (NTP, часовой пояс) — по нему надёжно
+    /// упорядочивать события в пределах одного запуска.
+    pub monotonic_ms: i64,
+    /// Время создания файла (birthtime), если платформа его поддерживает.
+    ///
+    /// Для удалённых файлов — `None`.
+    pub created_at_ms: Option<i64>,
+    /// Время последнего изменения файла (mtime). Для удалённых файлов — `None`.
+    pub modified_at_ms: Option<i64>,
+    /// Размер файла в байтах. Для удалённых файлов — `None`.
+    ///
+    /// При включённой стабилизации — итоговый размер «осевшего» файла.
+    pub size_bytes: Option<u64>,
+    /// Расширение в нижнем регистре, без точки.
+    pub extension: Option<String>,
+    /// MIME-тип по содержимому файла (см. [`crate::file_type`]).
+    /// Для удалённых и пустых файлов — `None`.
+    pub mime_type: Option<String>,
+    /// Владелец файла и права текущего пользователя. Для удалённых
+    /// файлов — `None`.
+    pub access: Option<FileAccessInfo>,
+    /// Изменение сделано самим ядром (см. [`crate::expected_changes`]).
+    pub self_generated: bool,
+    /// Изменение произошло, пока watcher не работал: найдено сверкой
+    /// со снимком папки при запуске (см. [`crate::dir_snapshot`]).
+    pub reconciled: bool,
+    /// Кому достался появившийся файл, если включены claim-файлы общей
+    /// папки (см. [`crate::claims`]); выставляется получателем событий.
+    pub claim: Option<ClaimStatus>,
+    /// Отладочный контекст (только при
+    /// [`WatcherOptions::debug_enrichment`](super::WatcherOptions::debug_enrichment)).
+    pub debug: Option<Box<EventDebugInfo>>,
+}
+
+/// Что означает `occurred_at_ms` в событии добавления файла.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimestampSource {
+    /// Момент, когда watcher обнаружил файл.
+    #[default]
+    Detected,
+    /// Время последнего изменения файла (mtime).
+    ///
+    /// Если mtime недоступен — используется момент обнаружения.
+    FileModified,
 }
 
 /// Внутреннее событие: файл удалён.
@@ -0,0 +1,166 @@
+//! Фильтр файлов watcher'а: glob-шаблоны, расширения, скрытые файлы.
+//!
+//! Отброшенные файлы не доходят до callback'ов — Flutter не получает
+//! события, которые всё равно выбросил бы.
+
+use std::path::Path;
+
+use glob::{MatchOptions, Pattern};
+
+use crate::error::LateraError;
+
+/// Шаблоны сравниваются без учёта регистра; `*` не пересекает `/`.
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: false,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Какие файлы watcher сообщает.
+///
+/// Шаблон без `/` сравнивается с именем файла (`*.pdf`, `~$*`), с `/` —
+/// с путём относительно watch dir (`drafts/*.docx`).
+#[derive(Clone, Debug)]
+pub struct WatchFilter {
+    include: Vec<Pattern>,
+    exclude: Vec<Pattern>,
+    extensions: Vec<String>,
+    include_hidden: bool,
+}
+
+impl Default for WatchFilter {
+    /// Пропускает всё.
+    fn default() -> Self {
+        Self {
+            include: Vec::new(),
+            exclude: Vec::new(),
+            extensions: Vec::new(),
+            include_hidden: true,
+        }
+    }
+}
+
+impl WatchFilter {
+    /// Собирает фильтр.
+    ///
+    /// - `include` — хотя бы один должен совпасть (пусто — любые файлы);
+    /// - `exclude` — ни один не должен совпасть;
+    /// - `extensions` — допустимые расширения без точки (пусто — любые);
+    /// - `include_hidden` — сообщать ли файлы, чьё имя начинается с `.`.
+    pub fn new(
+        include: &[String],
+        exclude: &[String],
+        extensions: &[String],
+        include_hidden: bool,
+    ) -> Result<Self, LateraError> {
+        Ok(Self {
+            include: compile(include)?,
+            exclude: compile(exclude)?,
+            extensions: extensions
+                .iter()
+                .map(|e| e.trim_start_matches('.').to_lowercase())
+                .filter(|e| !e.is_empty())
+                .collect(),
+            include_hidden,
+        })
+    }
+
+    /// Шаблоны, из которых собран фильтр: include, exclude, расширения
+    /// (без точки, в нижнем регистре) — для экспорта настроек.
+    pub fn patterns(&self) -> (Vec<String>, Vec<String>, Vec<String>) {
+        let strings =
+            |patterns: &[Pattern]| patterns.iter().map(|p| p.as_str().to_string()).collect();
+        (
+            strings(&self.include),
+            strings(&self.exclude),
+            self.extensions.clone(),
+        )
+    }
+
+    /// Сообщаются ли скрытые файлы.
+    pub fn include_hidden(&self) -> bool {
+        self.include_hidden
+    }
+
+    /// Пропускает ли фильтр файл `path` из папки `watch_dir`.
+    pub fn matches(&self, watch_dir: &Path, path: &Path) -> bool {
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            return false;
+        };
+        if !self.include_hidden && name.starts_with('.') {
+            return false;
+        }
+        if !self.extensions.is_empty() {
+            let extension = path
+                .extension()
+                .and_then(|e| e.to_str())
+                .map(str::to_lowercase);
+            if !extension.is_some_and(|e| self.extensions.contains(&e)) {
+                return false;
+            }
+        }
+        let relative = path
+            .strip_prefix(watch_dir)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let hit = |pattern: &Pattern| {
+            let subject = if pattern.as_str().contains('/') {
+                relative.as_str()
+            } else {
+                name
+            };
+            pattern.matches_with(subject, MATCH_OPTIONS)
+        };
+        (self.include.is_empty() || self.include.iter().any(hit)) && !self.exclude.iter().any(hit)
+    }
+}
+
+fn compile(patterns: &[String]) -> Result<Vec<Pattern>, LateraError> {
+    patterns
+        .iter()
+        .map(|p| {
+            Pattern::new(p).map_err(|e| {
+                LateraError::InvalidArgument(format!("invalid glob pattern {p:?}: {e}"))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| (*s).to_string()).collect()
+    }
+
+    #[test]
+    fn test_extensions_and_excludes() {
+        let filter =
+            WatchFilter::new(&[], &strings(&["~$*"]), &strings(&["pdf", ".DOCX"]), false).unwrap();
+        let dir = Path::new("/w");
+
+        assert!(filter.matches(dir, Path::new("/w/Report.PDF")));
+        assert!(filter.matches(dir, Path::new("/w/letter.docx")));
+        assert!(!filter.matches(dir, Path::new("/w/~$letter.docx")));
+        assert!(!filter.matches(dir, Path::new("/w/notes.txt")));
+        assert!(!filter.matches(dir, Path::new("/w/.draft.pdf")));
+    }
+
+    #[test]
+    fn test_path_patterns_match_relative_path() {
+        let filter = WatchFilter::new(&strings(&["drafts/*.md"]), &[], &[], true).unwrap();
+        let dir = Path::new("/w");
+
+        assert!(filter.matches(dir, Path::new("/w/drafts/a.md")));
+        assert!(!filter.matches(dir, Path::new("/w/a.md")));
+        assert!(!filter.matches(dir, Path::new("/w/drafts/deep/a.md")));
+    }
+
+    #[test]
+    fn test_default_passes_everything_and_bad_pattern_rejected() {
+        assert!(WatchFilter::default().matches(Path::new("/w"), Path::new("/w/.x")));
+        assert!(WatchFilter::new(&strings(&["[a-"]), &[], &[], true).is_err());
+    }
+}
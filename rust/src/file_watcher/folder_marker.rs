@@ -0,0 +1,265 @@
+//! Маркер папки, которой управляет Latera (`.latera-folder.json`).
+//!
+//! Маркер хранит идентификатор папки, время её создания и отпечаток
+//! настроек, с которыми за ней наблюдали. По нему папка узнаётся после
+//! переустановки приложения (настройки и индекс потеряны, папка — нет).
+//!
+//! ```json
+//! {"version":1,"folder_id":"fld_3f2a9c0d1e4b5a6f",
+//!  "created_at_ms":1760000000000,"settings_hash":"9b1c…"}
+//! ```
+//!
+//! Прежние версии писали маркер `.latera-folder` без содержимого: такая
+//! папка тоже своя, а [`repair`] заменяет его на JSON-маркер.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde_json::{json, Value};
+
+use super::now_ms;
+use crate::error::LateraError;
+use crate::internal_files;
+use crate::portable_config::tmp_path;
+use crate::read_only;
+
+/// Назначение файла маркера (`{prefix}folder.json`).
+pub const MARKER_PURPOSE: &str = "folder.json";
+
+/// Назначение маркера прежних версий (`{prefix}folder`).
+const LEGACY_MARKER_PURPOSE: &str = "folder";
+
+/// Версия формата маркера.
+const MARKER_VERSION: u64 = 1;
+
+/// Отпечаток текущих настроек (см. [`set_settings_hash`]).
+static SETTINGS_HASH: Lazy<Mutex<String>> =
+    Lazy::new(|| Mutex::new(crate::config::Config::default().settings_hash()));
+
+/// Счётчик для уникальных идентификаторов папок.
+static NEXT_FOLDER_SEED: AtomicU64 = AtomicU64::new(0);
+
+/// Содержимое маркера.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FolderMarker {
+    /// Идентификатор папки (`fld_…`), не меняется при починке.
+    pub folder_id: String,
+    /// Когда папка помечена (Unix ms).
+    pub created_at_ms: i64,
+    /// Отпечаток настроек на момент записи маркера.
+    pub settings_hash: String,
+}
+
+/// Что найдено в папке.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MarkerState {
+    /// Маркера нет.
+    Missing,
+    /// Маркер прежних версий без содержимого.
+    Legacy,
+    /// Файл маркера есть, но не читается.
+    Corrupt(String),
+    Valid(FolderMarker),
+}
+
+impl FolderMarker {
+    /// Новый маркер с текущими настройками.
+    pub fn new(dir: &Path) -> Self {
+        let created_at_ms = now_ms();
+        let seed = format!(
+            "{}:{}:{created_at_ms}:{}",
+            dir.display(),
+            std::process::id(),
+            NEXT_FOLDER_SEED.fetch_add(1, Ordering::Relaxed)
+        );
+        Self {
+            folder_id: format!("fld_{}", &blake3::hash(seed.as_bytes()).to_hex()[..16]),
+            created_at_ms,
+            settings_hash: current_settings_hash(),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "version": MARKER_VERSION,
+            "folder_id": self.folder_id,
+            "created_at_ms": self.created_at_ms,
+            "settings_hash": self.settings_hash,
+        })
+    }
+
+    fn from_json(text: &str) -> Result<Self, String> {
+        let value: Value =
+            serde_json::from_str(text).map_err(|e| format!("not valid JSON: {e}"))?;
+        let version = value["version"].as_u64().ok_or("missing version")?;
+        if version > MARKER_VERSION {
+            return Err(format!("unsupported version {version}"));
+        }
+        let folder_id = value["folder_id"]
+            .as_str()
+            .filter(|id| !id.is_empty())
+            .ok_or("missing folder_id")?;
+        Ok(Self {
+            folder_id: folder_id.to_string(),
+            created_at_ms: value["created_at_ms"]
+                .as_i64()
+                .ok_or("missing created_at_ms")?,
+            settings_hash: value["settings_hash"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+        })
+    }
+}
+
+/// Задать отпечаток текущих настроек (при загрузке и изменении настроек).
+pub fn set_settings_hash(hash: String) {
+    *SETTINGS_HASH
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = hash;
+}
+
+/// Отпечаток текущих настроек.
+pub fn current_settings_hash() -> String {
+    SETTINGS_HASH
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone()
+}
+
+/// Путь маркера в папке `dir` (с текущим префиксом служебных файлов).
+pub fn marker_path(dir: &Path) -> PathBuf {
+    dir.join(internal_files::internal_file_name(MARKER_PURPOSE))
+}
+
+/// Возможные имена файла `purpose`: с текущим и со стандартным префиксом
+/// (префикс настраивается, маркер мог быть создан со стандартным).
+fn candidates(dir: &Path, purpose: &str) -> [PathBuf; 2] {
+    [
+        dir.join(internal_files::internal_file_name(purpose)),
+        dir.join(format!(
+            "{}{purpose}",
+            internal_files::DEFAULT_INTERNAL_PREFIX
+        )),
+    ]
+}
+
+/// Есть ли в папке маркер (любой версии, даже повреждённый).
+pub fn is_marked(dir: &Path) -> bool {
+    candidates(dir, MARKER_PURPOSE)
+        .iter()
+        .chain(&candidates(dir, LEGACY_MARKER_PURPOSE))
+        .any(|path| path.exists())
+}
+
+/// Читает маркер папки `dir`.
+pub fn read(dir: &Path) -> MarkerState {
+    if let Some(path) = candidates(dir, MARKER_PURPOSE)
+        .into_iter()
+        .find(|path| path.exists())
+    {
+        return match std::fs::read_to_string(&path) {
+            Ok(text) => match FolderMarker::from_json(&text) {
+                Ok(marker) => MarkerState::Valid(marker),
+                Err(e) => MarkerState::Corrupt(format!("{}: {e}", path.display())),
+            },
+            Err(e) => MarkerState::Corrupt(format!("{}: {e}", path.display())),
+        };
+    }
+    if is_marked(dir) {
+        MarkerState::Legacy
+    } else {
+        MarkerState::Missing
+    }
+}
+
+/// Записывает маркер в `dir` (через временный файл).
+fn write(dir: &Path, marker: &FolderMarker) -> Result<(), LateraError> {
+    read_only::ensure_writable("write folder marker")?;
+    let path = marker_path(dir);
+    let tmp_path = tmp_path(&path);
+    {
+        let mut file = std::fs::File::create(&tmp_path)?;
+        let text = serde_json::to_string(&marker.to_json()).map_err(std::io::Error::other)?;
+        file.write_all(text.as_bytes())?;
+        file.sync_all()?;
+    }
+    std::fs::rename(&tmp_path, &path)?;
+    Ok(())
+}
+
+/// Помечает папку, если в ней ещё нет читаемого маркера.
+pub fn ensure(dir: &Path) -> Result<FolderMarker, LateraError> {
+    match read(dir) {
+        MarkerState::Valid(marker) => Ok(marker),
+        MarkerState::Missing | MarkerState::Legacy | MarkerState::Corrupt(_) => repair(dir),
+    }
+}
+
+/// Переписывает маркер: идентификатор и время создания сохраняются (если
+/// маркер читается), отпечаток настроек обновляется до текущего. Маркер
+/// прежних версий удаляется.
+pub fn repair(dir: &Path) -> Result<FolderMarker, LateraError> {
+    if !dir.is_dir() {
+        return Err(LateraError::InvalidPath(format!(
+            "not a directory: {}",
+            dir.display()
+        )));
+    }
+    let marker = match read(dir) {
+        MarkerState::Valid(marker) => FolderMarker {
+            settings_hash: current_settings_hash(),
+            ..marker
+        },
+        MarkerState::Missing | MarkerState::Legacy | MarkerState::Corrupt(_) => {
+            FolderMarker::new(dir)
+        }
+    };
+    write(dir, &marker)?;
+    for legacy in candidates(dir, LEGACY_MARKER_PURPOSE) {
+        if legacy.is_file() {
+            std::fs::remove_file(&legacy)?;
+        }
+    }
+    Ok(marker)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repair_keeps_identity_and_replaces_legacy_marker() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dir = temp_dir.path();
+        assert_eq!(read(dir), MarkerState::Missing);
+        assert!(!is_marked(dir));
+
+        let legacy = dir.join(internal_files::internal_file_name(LEGACY_MARKER_PURPOSE));
+        std::fs::write(&legacy, b"Latera watch folder\n").unwrap();
+        assert_eq!(read(dir), MarkerState::Legacy);
+        let marker = ensure(dir).unwrap();
+        assert!(marker.folder_id.starts_with("fld_"));
+        assert!(!legacy.exists());
+        assert_eq!(read(dir), MarkerState::Valid(marker.clone()));
+
+        // Повреждённый маркер: папка остаётся своей, починка выдаёт новый id
+        std::fs::write(marker_path(dir), b"{oops").unwrap();
+        assert!(is_marked(dir));
+        assert!(matches!(read(dir), MarkerState::Corrupt(_)));
+        let repaired = repair(dir).unwrap();
+        assert_ne!(repaired.folder_id, marker.folder_id);
+
+        // Читаемый маркер: id и время создания сохраняются
+        let mut stale = repaired.clone();
+        stale.settings_hash = "stale".to_string();
+        write(dir, &stale).unwrap();
+        let again = repair(dir).unwrap();
+        assert_eq!(again.folder_id, repaired.folder_id);
+        assert_eq!(again.created_at_ms, repaired.created_at_ms);
+        assert_eq!(again.settings_hash, current_settings_hash());
+    }
+}
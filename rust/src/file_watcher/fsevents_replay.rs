@@ -0,0 +1,231 @@
+//! Воспроизведение истории FSEvents (macOS) вместо полного обхода папки.
+//!
+//! FSEvents хранит журнал изменений тома. Watcher запоминает номер
+//! последнего события (рядом со снимком папки, файл `*.fsevents`), а при
+//! следующем запуске просит у системы всё, что случилось после него:
+//! перечитываются только изменённые пути, а не вся папка.
+//!
+//! Журнал может не помочь: номера событий переполнились, система потеряла
+//! события или просит пересканировать папку. Тогда [`replay`] сообщает
+//! [`Replayed::must_rescan`], и watcher сверяет папку со снимком целиком.
+//! На других платформах истории нет — [`replay`] возвращает `None`.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::error::LateraError;
+
+/// Расширение файла с номером события рядом со снимком.
+const EVENT_ID_EXTENSION: &str = "fsevents";
+
+/// Сколько ждать, пока система отдаст историю.
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+const REPLAY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Изменения из журнала.
+#[derive(Debug, Default)]
+pub(crate) struct Replayed {
+    /// Изменённые пути (файлы и папки, канонические).
+    pub paths: Vec<PathBuf>,
+    /// Журналу нельзя верить: папку нужно сверить целиком.
+    pub must_rescan: bool,
+}
+
+/// Файл с номером события для снимка `snapshot_file`.
+pub(crate) fn event_id_path(snapshot_file: &Path) -> PathBuf {
+    snapshot_file.with_extension(EVENT_ID_EXTENSION)
+}
+
+/// Номер события, сохранённый для снимка. `Ok(None)` — его ещё нет.
+pub(crate) fn load_event_id(snapshot_file: &Path) -> Result<Option<u64>, LateraError> {
+    match std::fs::read_to_string(event_id_path(snapshot_file)) {
+        Ok(text) => Ok(text.trim().parse().ok()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Сохраняет номер события для снимка (через временный файл).
+pub(crate) fn save_event_id(snapshot_file: &Path, event_id: u64) -> Result<(), LateraError> {
+    let path = event_id_path(snapshot_file);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp_path = path.with_extension("fsevents.tmp");
+    std::fs::write(&tmp_path, format!("{event_id}\n"))?;
+    std::fs::rename(&tmp_path, &path)?;
+    Ok(())
+}
+
+/// Номер последнего события FSEvents; `None` вне macOS.
+pub(crate) fn current_event_id() -> Option<u64> {
+    #[cfg(target_os = "macos")]
+    {
+        // SAFETY: функция без аргументов и побочных эффектов
+        Some(unsafe { fsevent_sys::FSEventsGetCurrentEventId() })
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        None
+    }
+}
+
+/// Изменения в `dir` после события `since_event_id`.
+///
+/// `None` — историю получить не удалось (не macOS, поток не создан, система
+/// не ответила за [`REPLAY_TIMEOUT`]).
+pub(crate) fn replay(dir: &Path, since_event_id: u64) -> Option<Replayed> {
+    #[cfg(target_os = "macos")]
+    {
+        macos::replay(dir, since_event_id)
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = (dir, since_event_id);
+        None
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod macos {
+    use std::ffi::{CStr, CString, OsStr};
+    use std::os::raw::{c_char, c_void};
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::{Path, PathBuf};
+    use std::time::Instant;
+
+    use fsevent_sys as fse;
+    use fsevent_sys::core_foundation as cf;
+
+    use super::{Replayed, REPLAY_TIMEOUT};
+
+    #[link(name = "CoreFoundation", kind = "framework")]
+    extern "C" {
+        fn CFRunLoopRunInMode(
+            mode: cf::CFStringRef,
+            seconds: cf::CFTimeInterval,
+            return_after_source_handled: cf::Boolean,
+        ) -> i32;
+    }
+
+    /// Флаги, после которых журналу нельзя верить.
+    const RESCAN_FLAGS: fse::FSEventStreamEventFlags = fse::kFSEventStreamEventFlagMustScanSubDirs
+        | fse::kFSEventStreamEventFlagUserDropped
+        | fse::kFSEventStreamEventFlagKernelDropped
+        | fse::kFSEventStreamEventFlagEventIdsWrapped
+        | fse::kFSEventStreamEventFlagRootChanged;
+
+    #[derive(Default)]
+    struct Collector {
+        replayed: Replayed,
+        history_done: bool,
+    }
+
+    extern "C" fn callback(
+        _stream: fse::FSEventStreamRef,
+        info: *mut c_void,
+        count: usize,
+        paths: *mut c_void,
+        flags: *const fse::FSEventStreamEventFlags,
+        _ids: *const fse::FSEventStreamEventId,
+    ) {
+        // SAFETY: `info` — Collector из `replay`, живущий дольше потока;
+        // без kFSEventStreamCreateFlagUseCFTypes `paths` — массив C-строк
+        // длины `count`, как и `flags`.
+        let collector = unsafe { &mut *info.cast::<Collector>() };
+        let paths = paths.cast::<*const c_char>();
+        for i in 0..count {
+            let (path, flag) = unsafe { (CStr::from_ptr(*paths.add(i)), *flags.add(i)) };
+            if flag & fse::kFSEventStreamEventFlagHistoryDone != 0 {
+                collector.history_done = true;
+                continue;
+            }
+            if flag & RESCAN_FLAGS != 0 {
+                collector.replayed.must_rescan = true;
+            }
+            collector
+                .replayed
+                .paths
+                .push(PathBuf::from(OsStr::from_bytes(path.to_bytes())));
+        }
+    }
+
+    pub(super) fn replay(dir: &Path, since_event_id: u64) -> Option<Replayed> {
+        let dir = CString::new(dir.as_os_str().as_bytes()).ok()?;
+        let collector = Box::into_raw(Box::<Collector>::default());
+        let context = fse::FSEventStreamContext {
+            version: 0,
+            info: collector.cast(),
+            retain: None,
+            release: None,
+            copy_description: None,
+        };
+
+        // SAFETY: стандартная последовательность CoreFoundation/FSEvents;
+        // созданные объекты освобождаются ниже, `collector` — после потока.
+        unsafe {
+            let paths =
+                cf::CFArrayCreateMutable(cf::kCFAllocatorDefault, 1, &cf::kCFTypeArrayCallBacks);
+            let cf_dir = cf::CFStringCreateWithCString(
+                cf::kCFAllocatorDefault,
+                dir.as_ptr(),
+                cf::kCFStringEncodingUTF8,
+            );
+            cf::CFArrayAppendValue(paths, cf_dir);
+            cf::CFRelease(cf_dir);
+
+            let stream = fse::FSEventStreamCreate(
+                cf::kCFAllocatorDefault,
+                callback,
+                &context,
+                paths,
+                since_event_id,
+                0.0,
+                fse::kFSEventStreamCreateFlagFileEvents | fse::kFSEventStreamCreateFlagNoDefer,
+            );
+            cf::CFRelease(paths);
+            if stream.is_null() {
+                drop(Box::from_raw(collector));
+                return None;
+            }
+
+            let run_loop = cf::CFRunLoopGetCurrent();
+            fse::FSEventStreamScheduleWithRunLoop(stream, run_loop, cf::kCFRunLoopDefaultMode);
+            let started = fse::FSEventStreamStart(stream) != 0;
+            let deadline = Instant::now() + REPLAY_TIMEOUT;
+            while started && !(*collector).history_done && Instant::now() < deadline {
+                CFRunLoopRunInMode(cf::kCFRunLoopDefaultMode, 0.25, 0);
+            }
+            if started {
+                fse::FSEventStreamStop(stream);
+            }
+            fse::FSEventStreamInvalidate(stream);
+            fse::FSEventStreamRelease(stream);
+
+            let collector = *Box::from_raw(collector);
+            collector.history_done.then_some(collector.replayed)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_id_roundtrip() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let snapshot_file = temp_dir.path().join("snapshots").join("abc.snapshot");
+        assert_eq!(load_event_id(&snapshot_file).unwrap(), None);
+
+        save_event_id(&snapshot_file, 42).unwrap();
+        assert_eq!(load_event_id(&snapshot_file).unwrap(), Some(42));
+        assert!(event_id_path(&snapshot_file)
+            .to_string_lossy()
+            .ends_with("abc.fsevents"));
+
+        // Испорченный файл — как отсутствующий
+        std::fs::write(event_id_path(&snapshot_file), b"garbage").unwrap();
+        assert_eq!(load_event_id(&snapshot_file).unwrap(), None);
+    }
+}
@@ -0,0 +1,141 @@
+//! Ступени, через которые проходит событие перед выдачей подписчику:
+//! дедупликация ([`Dedup`]) и ограничение частоты ([`RateLimit`]).
+//!
+//! Каждая ступень — отдельное состояние с одним решением на событие, так
+//! что их можно переставлять и проверять по отдельности.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use super::FileEventKind;
+
+/// Максимальный размер таблицы дедупликации.
+const DEDUP_MAP_MAX_SIZE: usize = 1000;
+
+/// Через сколько событий чистить таблицу дедупликации от устаревших записей.
+const DEDUP_CLEANUP_EVERY: u32 = 100;
+
+/// Отбрасывает повтор того же события по тому же пути в пределах окна.
+pub(super) struct Dedup {
+    window: Duration,
+    last_seen: HashMap<(FileEventKind, PathBuf), Instant>,
+    cleanup_counter: u32,
+}
+
+impl Dedup {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            last_seen: HashMap::new(),
+            cleanup_counter: 0,
+        }
+    }
+
+    /// `Err(since)` — повтор: такое же событие было `since` назад.
+    pub fn admit(
+        &mut self,
+        kind: FileEventKind,
+        path: &Path,
+        now: Instant,
+    ) -> Result<(), Duration> {
+        let key = (kind, path.to_path_buf());
+        if let Some(prev) = self.last_seen.get(&key) {
+            let since = now.duration_since(*prev);
+            if since < self.window {
+                return Err(since);
+            }
+        }
+        self.last_seen.insert(key, now);
+
+        // Периодическая очистка: каждые 100 событий или при превышении лимита.
+        self.cleanup_counter = self.cleanup_counter.saturating_add(1);
+        if self.last_seen.len() > DEDUP_MAP_MAX_SIZE || self.cleanup_counter >= DEDUP_CLEANUP_EVERY
+        {
+            let window = self.window;
+            self.last_seen
+                .retain(|_, &mut instant| now.duration_since(instant) < window * 10);
+            self.cleanup_counter = 0;
+        }
+        Ok(())
+    }
+}
+
+/// Пропускает не больше `per_second` событий за секунду.
+pub(super) struct RateLimit {
+    per_second: u32,
+    window_started_at: Instant,
+    count: u32,
+}
+
+impl RateLimit {
+    pub fn new(per_second: u32) -> Self {
+        Self {
+            per_second,
+            window_started_at: Instant::now(),
+            count: 0,
+        }
+    }
+
+    /// `false` — лимит текущей секунды исчерпан.
+    pub fn admit(&mut self, now: Instant) -> bool {
+        if now.duration_since(self.window_started_at) >= Duration::from_secs(1) {
+            self.window_started_at = now;
+            self.count = 0;
+        }
+        self.count = self.count.saturating_add(1);
+        self.count <= self.per_second
+    }
+
+    /// Сколько событий пришло за текущую секунду.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dedup_drops_repeats_within_window() {
+        let mut dedup = Dedup::new(Duration::from_millis(300));
+        let path = PathBuf::from("/watched/a.txt");
+        let start = Instant::now();
+        assert!(dedup.admit(FileEventKind::Created, &path, start).is_ok());
+        assert_eq!(
+            dedup.admit(
+                FileEventKind::Created,
+                &path,
+                start + Duration::from_millis(100)
+            ),
+            Err(Duration::from_millis(100))
+        );
+        // Другой вид события — не повтор
+        assert!(dedup
+            .admit(
+                FileEventKind::Modified,
+                &path,
+                start + Duration::from_millis(100)
+            )
+            .is_ok());
+        assert!(dedup
+            .admit(
+                FileEventKind::Created,
+                &path,
+                start + Duration::from_millis(400)
+            )
+            .is_ok());
+    }
+
+    #[test]
+    fn test_rate_limit_resets_every_second() {
+        let mut limit = RateLimit::new(2);
+        let start = Instant::now();
+        assert!(limit.admit(start));
+        assert!(limit.admit(start));
+        assert!(!limit.admit(start));
+        assert_eq!(limit.count(), 3);
+        assert!(limit.admit(start + Duration::from_secs(1)));
+    }
+}
@@ -0,0 +1,136 @@
+//! Варианты папки наблюдения для онбординга.
+//!
+//! Экран выбора папки показывает реальные данные ОС: существует ли папка,
+//! можно ли в неё писать и сколько места на томе. Функции модуля ничего не
+//! создают и не пишут на диск.
+
+use std::path::PathBuf;
+
+use crate::disk_space;
+use crate::file_metadata;
+
+use super::DEFAULT_WATCH_FOLDER_NAME;
+
+/// Откуда взят вариант.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WatchLocationKind {
+    /// `Desktop/Latera` — вариант по умолчанию (`Latera (2)`, … если имя
+    /// занято файлом или чужой папкой).
+    DesktopFolder,
+    /// Папка загрузок целиком.
+    Downloads,
+    /// `Documents/Latera`.
+    DocumentsFolder,
+}
+
+/// Вариант папки наблюдения.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WatchLocation {
+    pub kind: WatchLocationKind,
+    pub path: PathBuf,
+    /// Папка уже существует (иначе будет создана при старте watcher'а).
+    pub exists: bool,
+    /// Текущий пользователь может писать в папку (или создать её).
+    pub writable: bool,
+    /// Свободное место на томе; `None` — не удалось определить.
+    pub available_bytes: Option<u64>,
+}
+
+impl WatchLocation {
+    fn inspect(kind: WatchLocationKind, path: PathBuf) -> Self {
+        let exists = path.is_dir();
+        // Для ещё не созданной папки важны права на ближайшего существующего предка
+        let existing = path.ancestors().find(|p| p.is_dir());
+        let writable = existing
+            .and_then(|p| file_metadata::get_file_access_info(p).ok())
+            .is_some_and(|info| info.writable);
+        let available_bytes = existing.and_then(|p| disk_space::available_space(p).ok());
+        Self {
+            kind,
+            path,
+            exists,
+            writable,
+            available_bytes,
+        }
+    }
+
+    /// Подходит ли вариант без оговорок.
+    fn is_usable(&self) -> bool {
+        self.writable
+            && self
+                .available_bytes
+                .is_none_or(|available| available >= disk_space::DEFAULT_LOW_SPACE_THRESHOLD_BYTES)
+    }
+}
+
+/// Варианты папки наблюдения, от лучшего к худшему.
+///
+/// Порядок по умолчанию: Desktop/Latera, Downloads, Documents/Latera;
+/// варианты без права записи или с нехваткой места опускаются в конец.
+/// Каталоги, которые ОС не сообщает, пропускаются.
+pub fn suggest_watch_locations() -> Vec<WatchLocation> {
+    let candidates = [
+        (
+            WatchLocationKind::DesktopFolder,
+            super::resolve_default_watch_dir().ok().map(|d| d.path),
+        ),
+        (WatchLocationKind::Downloads, dirs::download_dir()),
+        (
+            WatchLocationKind::DocumentsFolder,
+            dirs::document_dir().map(|d| d.join(DEFAULT_WATCH_FOLDER_NAME)),
+        ),
+    ];
+    rank(
+        candidates
+            .into_iter()
+            .filter_map(|(kind, path)| Some(WatchLocation::inspect(kind, path?)))
+            .collect(),
+    )
+}
+
+fn rank(mut locations: Vec<WatchLocation>) -> Vec<WatchLocation> {
+    // Сортировка стабильна: среди равных сохраняется порядок по умолчанию
+    locations.sort_by_key(|location| !location.is_usable());
+    locations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inspect_missing_folder_uses_parent() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let location = WatchLocation::inspect(
+            WatchLocationKind::DocumentsFolder,
+            temp_dir.path().join("Latera"),
+        );
+        assert!(!location.exists);
+        assert!(location.writable);
+    }
+
+    #[test]
+    fn test_unusable_locations_ranked_last() {
+        let location = |kind, writable| WatchLocation {
+            kind,
+            path: PathBuf::from("/x"),
+            exists: true,
+            writable,
+            available_bytes: None,
+        };
+        let ranked = rank(vec![
+            location(WatchLocationKind::DesktopFolder, false),
+            location(WatchLocationKind::Downloads, true),
+            location(WatchLocationKind::DocumentsFolder, true),
+        ]);
+        let kinds: Vec<_> = ranked.iter().map(|l| l.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                WatchLocationKind::Downloads,
+                WatchLocationKind::DocumentsFolder,
+                WatchLocationKind::DesktopFolder
+            ]
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Счётчики потока событий watcher'а.
+//!
+//! События backend'а попадают в ограниченную очередь
+//! ([`EVENT_QUEUE_CAPACITY`]): шторм событий ФС не раздувает память, а
+//! лишние события отбрасываются и считаются. После потерь watcher сверяет
+//! папку, так что пропущенные файлы всё равно приходят подписчику (пачкой).
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Сколько событий backend'а ждёт обработки, прежде чем новые отбрасываются.
+pub const EVENT_QUEUE_CAPACITY: usize = 4096;
+
+/// Снимок счётчиков.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WatcherMetrics {
+    /// Сколько событий пришло от backend'а (включая отброшенные).
+    pub events_received: u64,
+    /// Сколько событий отдано подписчику (по одному и пачками).
+    pub events_emitted: u64,
+    /// Сколько повторов отброшено дедупликацией.
+    pub events_deduped: u64,
+    /// Сколько событий отброшено из-за переполненной очереди.
+    pub events_dropped: u64,
+    /// Сколько событий сейчас ждёт в очереди.
+    pub queue_depth: u64,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    received: AtomicU64,
+    emitted: AtomicU64,
+    deduped: AtomicU64,
+    dropped: AtomicU64,
+    queued: AtomicU64,
+    /// С прошлой сверки были потери.
+    lagged: AtomicBool,
+}
+
+/// Общие (между backend'ом, тредом watcher'а и API) счётчики.
+#[derive(Clone, Debug, Default)]
+pub struct EventMetrics {
+    inner: Arc<Counters>,
+}
+
+impl EventMetrics {
+    /// Событие backend'а пришло и ставится в очередь (до отправки: иначе
+    /// обработчик мог бы взять его раньше, чем оно учтено).
+    pub fn record_received(&self) {
+        self.inner.received.fetch_add(1, Ordering::Relaxed);
+        self.inner.queued.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Событие не попало в очередь: она полна (`lagged`) или закрыта.
+    pub fn record_not_queued(&self, lagged: bool) {
+        self.record_dequeued();
+        if lagged {
+            self.inner.dropped.fetch_add(1, Ordering::Relaxed);
+            self.inner.lagged.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Событие взято из очереди.
+    pub fn record_dequeued(&self) {
+        let _ = self
+            .inner
+            .queued
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| {
+                Some(n.saturating_sub(1))
+            });
+    }
+
+    /// Очередь пересоздана вместе с backend'ом.
+    pub fn reset_queue(&self) {
+        self.inner.queued.store(0, Ordering::Relaxed);
+    }
+
+    pub fn record_deduped(&self) {
+        self.inner.deduped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_emitted(&self, count: usize) {
+        self.inner
+            .emitted
+            .fetch_add(u64::try_from(count).unwrap_or(u64::MAX), Ordering::Relaxed);
+    }
+
+    /// Были ли потери с прошлого вызова.
+    pub fn take_lagged(&self) -> bool {
+        self.inner.lagged.swap(false, Ordering::Relaxed)
+    }
+
+    pub fn snapshot(&self) -> WatcherMetrics {
+        WatcherMetrics {
+            events_received: self.inner.received.load(Ordering::Relaxed),
+            events_emitted: self.inner.emitted.load(Ordering::Relaxed),
+            events_deduped: self.inner.deduped.load(Ordering::Relaxed),
+            events_dropped: self.inner.dropped.load(Ordering::Relaxed),
+            queue_depth: self.inner.queued.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drops_are_counted_and_flag_lag_once() {
+        let metrics = EventMetrics::default();
+        metrics.record_received();
+        metrics.record_received();
+        metrics.record_received();
+        metrics.record_not_queued(true);
+        metrics.record_dequeued();
+        metrics.record_deduped();
+        metrics.record_emitted(2);
+
+        assert_eq!(
+            metrics.snapshot(),
+            WatcherMetrics {
+                events_received: 3,
+                events_emitted: 2,
+                events_deduped: 1,
+                events_dropped: 1,
+                queue_depth: 1,
+            }
+        );
+        assert!(metrics.take_lagged());
+        assert!(!metrics.take_lagged());
+    }
+}
@@ -5,63 +5,250 @@
 //! - создание дефолтной директории `Desktop/Latera`
 //! - запуск `notify` watcher
 //! - graceful shutdown
-//! - дедупликацию и rate-limiting событий
+//! - фильтрацию файлов (glob-шаблоны, расширения, скрытые файлы)
+//! - дедупликацию и rate-limiting событий (избыток отдаётся пачками)
+//! - ожидание, пока новый файл «осядет» (докопируется)
+//! - подавление событий служебных файлов ядра ([`internal_files`])
+//! - запрет наблюдения за папками, куда пишет само ядро ([`register_core_dir`])
+//! - сверку с прошлым запуском по снимку папки ([`crate::dir_snapshot`])
+//! - отслеживание состояния watcher'а ([`WatcherState`])
+//! - перезапуск упавшего backend'а `notify` с backoff ([`RestartPolicy`])
+//! - возобновление наблюдения за пропавшей папкой ([`WatchDirRecovery`])
+//! - переход на опрос и ручное обновление, если нативный backend не
+//!   работает ([`DegradationPolicy`])
+//! - сверку папки по запросу ([`WatcherHandle::rescan`])
+//! - отладочный контекст событий ([`EventDebugInfo`])
+//! - повтор чтения метаданных файла, занятого антивирусом ([`AccessFailure`])
 
+mod batch;
+mod debug_trail;
+mod default_dir;
+mod degrade;
 mod events;
+mod filter;
+mod folder_marker;
+mod fsevents_replay;
+mod gate;
+mod locations;
+mod metrics;
+mod pipeline;
+mod restart;
+mod settle;
+mod status;
+mod transient;
+mod tree_stats;
 
-use std::collections::HashMap;
+use std::collections::BTreeSet;
 use std::path::{Path, PathBuf};
-use std::sync::mpsc;
+use std::sync::{mpsc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
-use log::{debug, error, info, warn};
 use notify::{
-    event::{CreateKind, ModifyKind, RemoveKind, RenameMode},
-    EventKind, RecommendedWatcher, RecursiveMode, Watcher,
+    event::{ModifyKind, RemoveKind, RenameMode},
+    EventKind, PollWatcher, RecursiveMode, Watcher,
 };
+use once_cell::sync::Lazy;
+use tokio::sync::mpsc as async_mpsc;
+use tokio_util::sync::{CancellationToken, DropGuard};
 
+pub use batch::{BATCH_FLUSH_INTERVAL, MAX_BATCH_SIZE};
+pub use debug_trail::EventDebugInfo;
+pub use default_dir::{DefaultWatchDir, Occupant};
+use degrade::Ladder;
+pub use degrade::{DegradationPolicy, WatchBackend, WatchMode};
+pub use events::FileEventKind;
 pub use events::InternalFileEvent;
 pub use events::InternalFileRemovedEvent;
+pub use events::TimestampSource;
+pub use filter::WatchFilter;
+pub use folder_marker::{FolderMarker, MarkerState};
+pub use locations::{suggest_watch_locations, WatchLocation, WatchLocationKind};
+use metrics::EventMetrics;
+pub use metrics::{WatcherMetrics, EVENT_QUEUE_CAPACITY};
+use pipeline::EventPipeline;
+use restart::Backoff;
+pub use restart::{RestartPolicy, WatchDirRecovery, RESTART_RESET_PERIOD};
+use settle::SettleQueue;
+use status::StatusCell;
+pub use status::{StatusListener, WatcherErrorCode, WatcherState, DEGRADED_RECOVERY_PERIOD};
+pub use transient::AccessFailure;
+pub use tree_stats::{SubdirStats, TreeStats};
 
+use crate::desktop_access;
+use crate::dir_snapshot::{self, DirSnapshot};
 use crate::error::LateraError;
+use crate::expected_changes;
+use crate::file_metadata;
+use crate::file_type;
+use crate::internal_files;
+use crate::lifecycle;
+use crate::log_event;
+use crate::logging;
+use crate::path_utils;
+use crate::read_only;
+use crate::volume::{self, VolumeInfo, VolumeStrategy};
 
 /// Десктоп-папка для наблюдения по умолчанию (внутри Desktop).
 pub const DEFAULT_WATCH_FOLDER_NAME: &str = "Latera";
 
 /// Получить дефолтную директорию наблюдения: `Desktop/Latera` (preview).
 ///
+/// Если имя занято файлом или чужой папкой — следующее свободное
+/// (`Latera (2)`, …), как в [`ensure_default_watch_dir`].
+///
 /// Важно: **НЕ** создаёт директорию на диске.
 /// Используется в UI на первом запуске, до явного согласия пользователя.
 pub fn default_watch_dir_preview() -> Result<PathBuf, LateraError> {
+    Ok(resolve_default_watch_dir()?.path)
+}
+
+/// Папка по умолчанию на «Рабочем столе» и пропущенные занятые имена.
+/// Ничего не создаёт.
+pub fn resolve_default_watch_dir() -> Result<DefaultWatchDir, LateraError> {
     let desktop = dirs::desktop_dir().ok_or(LateraError::DesktopDirNotFound)?;
-    Ok(desktop.join(DEFAULT_WATCH_FOLDER_NAME))
+    default_dir::resolve(&desktop)
 }
 
-/// Политика сглаживания и backpressure.
+/// Политика сглаживания и backpressure по умолчанию
+/// ([`WatcherOptions::dedup_window`], [`WatcherOptions::rate_limit_per_second`]).
 ///
 /// Значения подобраны под desktop сценарий: достаточно отзывчиво для UI,
 /// но защищает от burst-событий файловой системы.
-const DEDUP_WINDOW: Duration = Duration::from_millis(300);
-const RATE_LIMIT_PER_SECOND: u32 = 200;
+pub const DEFAULT_DEDUP_WINDOW: Duration = Duration::from_millis(300);
+pub const DEFAULT_RATE_LIMIT_PER_SECOND: u32 = 200;
 
-/// Максимальный размер HashMap для дедупликации.
-/// При превышении очищаются устаревшие записи.
-const DEDUP_MAP_MAX_SIZE: usize = 1000;
-
-/// Timeout для ожидания завершения watcher-потока.
+/// Timeout для ожидания завершения watcher-потока в [`WatcherHandle::stop`].
 /// Защищает от "вечного ожидания" при зависании потока.
-const STOP_TIMEOUT: Duration = Duration::from_secs(5);
+pub const STOP_TIMEOUT: Duration = Duration::from_secs(5);
 
-/// Интервал проверки существования watched-директории.
+/// Интервал проверки существования watched-директории. Заодно это самый
+/// долгий сон цикла watcher'а, когда нет ни событий, ни ждущих файлов.
 const DIR_CHECK_INTERVAL: Duration = Duration::from_secs(1);
 
+/// Интервал проверки, «осели» ли придержанные файлы.
+const SETTLE_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Как часто сохранять снимок папки, если в ней что-то менялось.
+const SNAPSHOT_SAVE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Ключи повторяющихся предупреждений для [`logging::LogThrottle`].
+const RATE_LIMIT_WARNING: &str = "rate limit exceeded";
+const NOTIFY_ERROR_WARNING: &str = "notify error";
+
+/// Точка отсчёта монотонного времени событий (первое обращение в процессе).
+static MONOTONIC_EPOCH: Lazy<Instant> = Lazy::new(Instant::now);
+
+/// Папки, куда пишет ядро, помимо папки данных и папки логов.
+static CORE_DIRS: Lazy<Mutex<Vec<PathBuf>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Идентификатор watcher'а по умолчанию.
+pub const DEFAULT_WATCHER_ID: &str = "default";
+
+/// Настройки watcher'а.
+#[derive(Clone, Debug)]
+pub struct WatcherOptions {
+    /// Идентификатор watcher'а; логи пишутся под target `latera::watcher::{id}`.
+    pub id: String,
+    /// Что означает `occurred_at_ms` в событиях добавления.
+    pub timestamp_source: TimestampSource,
+    /// Наблюдать и за вложенными папками.
+    pub recursive: bool,
+    /// Игнорировать появившиеся файлы, чей mtime старше этого возраста
+    /// (восстановлены из бэкапа, заново синхронизированы облачным клиентом).
+    ///
+    /// `None` — фильтр выключен. Файлы без mtime не отбрасываются.
+    pub max_file_age: Option<Duration>,
+    /// Сообщать о появлении файла, только когда его размер и mtime не
+    /// менялись этот период (файл докопирован).
+    ///
+    /// `None` — события отдаются сразу. Файлы, не «осевшие» к остановке
+    /// watcher'а, не сообщаются.
+    pub settle_quiet_period: Option<Duration>,
+    /// Какие файлы сообщать (по умолчанию — все).
+    pub filter: WatchFilter,
+    /// Папка снимков ([`crate::dir_snapshot`]): при запуске watcher сообщает
+    /// об изменениях, случившихся, пока он не работал.
+    ///
+    /// `None` — сверка выключена, снимок не ведётся.
+    pub snapshot_dir: Option<PathBuf>,
+    /// macOS: при запуске брать изменения из истории FSEvents (с номера
+    /// события, сохранённого рядом со снимком), а не обходить папку целиком.
+    ///
+    /// Работает только вместе с [`Self::snapshot_dir`]; если история
+    /// недоступна или неполна, папка сверяется со снимком как обычно.
+    pub fsevents_replay: bool,
+    /// Кому сообщать о смене [`WatcherState`].
+    pub status_listener: Option<StatusListener>,
+    /// Перезапуск backend'а после фатальной ошибки.
+    ///
+    /// `None` — без повторных попыток: сразу следующая ступень
+    /// [`Self::degradation`] или [`WatcherState::Error`].
+    pub restart: Option<RestartPolicy>,
+    /// Переход на опрос и ручное обновление при повторяющихся сбоях
+    /// нативного backend'а.
+    ///
+    /// `None` — только перезапуск по [`Self::restart`].
+    pub degradation: Option<DegradationPolicy>,
+    /// Что делать, если папка наблюдения пропала.
+    pub dir_recovery: WatchDirRecovery,
+    /// Повтор того же события по тому же пути в пределах окна отбрасывается.
+    pub dedup_window: Duration,
+    /// Сколько событий в секунду отдаётся сразу; остальные — пачками.
+    pub rate_limit_per_second: u32,
+    /// Прикреплять к событиям [`EventDebugInfo`]: сырые события `notify`,
+    /// решения дедупликации, разбивку по времени. Для разбора обращений в
+    /// поддержку; стоит памяти и времени на каждое событие.
+    pub debug_enrichment: bool,
+    /// Подбирать умолчания под том папки ([`crate::volume`]): период
+    /// опроса и ожидание стабилизации, если они не заданы явно.
+    pub adapt_to_volume: bool,
+    /// Каким способом начинать наблюдение (по умолчанию — по тому папки).
+    pub backend: WatchBackend,
+    /// Период опроса; `None` — из [`Self::degradation`], а без неё — по
+    /// тому папки (или как в [`DegradationPolicy::default`]).
+    pub poll_interval: Option<Duration>,
+}
+
+impl Default for WatcherOptions {
+    fn default() -> Self {
+        Self {
+            id: DEFAULT_WATCHER_ID.to_string(),
+            timestamp_source: TimestampSource::default(),
+            recursive: false,
+            max_file_age: None,
+            settle_quiet_period: None,
+            filter: WatchFilter::default(),
+            snapshot_dir: None,
+            fsevents_replay: false,
+            status_listener: None,
+            restart: Some(RestartPolicy::default()),
+            degradation: Some(DegradationPolicy::default()),
+            dir_recovery: WatchDirRecovery::default(),
+            dedup_window: DEFAULT_DEDUP_WINDOW,
+            rate_limit_per_second: DEFAULT_RATE_LIMIT_PER_SECOND,
+            debug_enrichment: false,
+            adapt_to_volume: true,
+            backend: WatchBackend::default(),
+            poll_interval: None,
+        }
+    }
+}
+
 /// Handle запущенного watcher'а.
 pub struct WatcherHandle {
-    stop_tx: mpsc::Sender<()>,
+    cancel: CancellationToken,
+    /// Handle уничтожен без [`Self::stop`] — watcher тоже останавливается.
+    _cancel_on_drop: DropGuard,
+    rescan_tx: async_mpsc::UnboundedSender<mpsc::Sender<Option<usize>>>,
     done_rx: Option<mpsc::Receiver<()>>,
     join: Option<thread::JoinHandle<()>>,
     watch_dir: PathBuf,
+    volume: VolumeInfo,
+    backend: WatchMode,
+    tree_stats: TreeStats,
+    metrics: EventMetrics,
+    status: StatusCell,
 }
 
 impl WatcherHandle {
@@ -69,13 +256,60 @@ impl WatcherHandle {
         &self.watch_dir
     }
 
-    pub fn stop(mut self) -> Result<(), LateraError> {
-        // Отправляем сигнал остановки. Если receiver уже мёртв — это не ошибка,
-        // поток уже завершился.
-        if let Err(e) = self.stop_tx.send(()) {
-            log::warn!("Failed to send stop signal (channel closed): {e}");
-            // Не возвращаем ошибку — поток уже не работает
-        }
+    /// Том папки наблюдения (определён при запуске).
+    pub fn volume(&self) -> &VolumeInfo {
+        &self.volume
+    }
+
+    /// Способ получения изменений, выбранный при запуске (дальше может
+    /// смениться — см. [`WatcherState::Fallback`]).
+    pub fn backend(&self) -> WatchMode {
+        self.backend
+    }
+
+    /// Текущее состояние; после выхода треда из-за ошибки — [`WatcherState::Error`].
+    pub fn state(&self) -> WatcherState {
+        self.status.get()
+    }
+
+    /// Сверить папку с последним известным состоянием и сообщить о том,
+    /// что watcher пропустил (в режиме [`WatchMode::ManualRefresh`] — о
+    /// всех изменениях).
+    ///
+    /// Найденное приходит пачками, как при сверке со снимком; файлы, о
+    /// которых уже пришли события, повторно не сообщаются. В канал придёт
+    /// число найденных расхождений (`None` — папку не удалось прочитать);
+    /// канал закроется без ответа, если watcher остановлен.
+    pub fn rescan(&self) -> mpsc::Receiver<Option<usize>> {
+        let (reply_tx, reply_rx) = mpsc::channel();
+        let _ = self.rescan_tx.send(reply_tx);
+        reply_rx
+    }
+
+    /// Статистика активности по подпапкам (в нерекурсивном режиме — только корень).
+    pub fn tree_stats(&self) -> Vec<SubdirStats> {
+        self.tree_stats.snapshot()
+    }
+
+    /// Счётчики потока событий: получено, отдано, отброшено.
+    pub fn metrics(&self) -> WatcherMetrics {
+        self.metrics.snapshot()
+    }
+
+    /// Остановить watcher, ожидая поток не дольше [`STOP_TIMEOUT`].
+    pub fn stop(self) -> Result<(), LateraError> {
+        self.stop_with_timeout(STOP_TIMEOUT)
+    }
+
+    /// Остановить watcher, ожидая поток не дольше `timeout`.
+    ///
+    /// Если поток завис (например, внутри backend'а `notify`), он
+    /// отсоединяется и продолжает работать сам по себе, а вызов возвращает
+    /// [`LateraError::ShutdownTimedOut`]: события от него уже никуда не
+    /// попадут, но ресурсы освободятся только с выходом потока.
+    pub fn stop_with_timeout(mut self, timeout: Duration) -> Result<(), LateraError> {
+        // Сигнал остановки; если поток уже завершился, отмена ни на что не влияет.
+        self.cancel.cancel();
 
         // Ждём завершения потока с timeout.
         // Сначала пробуем дождаться сигнала через done_rx.
@@ -84,21 +318,25 @@ impl WatcherHandle {
         // перестаёт работать (join может повиснуть навсегда).
         let mut can_join = true;
         if let Some(done_rx) = self.done_rx.take() {
-            match done_rx.recv_timeout(STOP_TIMEOUT) {
+            match done_rx.recv_timeout(timeout) {
                 Ok(()) => {
-                    log::debug!("Watcher thread signaled completion");
+                    log_event!(debug, "Watcher thread signaled completion");
                 }
                 Err(mpsc::RecvTimeoutError::Timeout) => {
-                    log::error!(
-                        "Watcher thread did not stop within {:?}, proceeding with forced shutdown",
-                        STOP_TIMEOUT
+                    log_event!(
+                        error,
+                        timeout_ms = timeout.as_millis(),
+                        "Watcher thread did not stop in time, proceeding with forced shutdown"
                     );
                     // Поток может продолжать работать, но мы не будем ждать вечно.
                     // Не делаем join() в текущем потоке.
                     can_join = false;
                 }
                 Err(mpsc::RecvTimeoutError::Disconnected) => {
-                    log::debug!("Watcher thread already terminated (channel disconnected)");
+                    log_event!(
+                        debug,
+                        "Watcher thread already terminated (channel disconnected)"
+                    );
                 }
             }
         }
@@ -110,7 +348,7 @@ impl WatcherHandle {
                 match join.join() {
                     Ok(()) => {}
                     Err(panic_payload) => {
-                        log::error!("Watcher thread panicked: {:?}", panic_payload);
+                        log_event!(error, panic:? = panic_payload, "Watcher thread panicked");
                         // Восстанавливаемся после паники, не возвращаем ошибку
                         // так как остановка всё равно произошла
                     }
@@ -120,6 +358,11 @@ impl WatcherHandle {
             // Не блокируем вызывающий поток. JoinHandle будет дропнут без join.
             // Это сознательный trade-off: избегаем зависаний при проблемах notify/FS.
             self.join.take();
+            return Err(LateraError::ShutdownTimedOut(format!(
+                "watcher thread for {} did not stop within {} ms",
+                self.watch_dir.display(),
+                timeout.as_millis()
+            )));
         }
         Ok(())
     }
@@ -149,9 +392,9 @@ fn set_folder_icon(folder: &Path) -> Result<(), LateraError> {
             if existing.contains(&*exe_str) {
                 return Ok(()); // Путь актуален — не трогаем
             }
-            info!("desktop.ini has stale exe path, updating");
+            log_event!(info, "desktop.ini has stale exe path, updating");
         } else {
-            warn!("Failed to read existing desktop.ini, will overwrite");
+            log_event!(warn, "Failed to read existing desktop.ini, will overwrite");
         }
     }
 
@@ -185,7 +428,7 @@ fn set_folder_icon(folder: &Path) -> Result<(), LateraError> {
         );
     }
 
-    info!("Folder icon set via desktop.ini in {}", folder.display());
+    log_event!(info, folder:% = folder.display(), "Folder icon set via desktop.ini");
     Ok(())
 }
 
@@ -195,21 +438,103 @@ fn set_folder_icon(_folder: &Path) -> Result<(), LateraError> {
 }
 
 /// Определить дефолтную директорию наблюдения: `Desktop/Latera`.
-/// Если директории нет — создать.
+/// Если директории нет — создать и пометить маркером.
+///
+/// Файл или чужая папка с тем же именем не используются: берётся
+/// следующее свободное имя (`Latera (2)`, …).
 pub fn ensure_default_watch_dir() -> Result<PathBuf, LateraError> {
-    let desktop = dirs::desktop_dir().ok_or(LateraError::DesktopDirNotFound)?;
-    let watch_dir = desktop.join(DEFAULT_WATCH_FOLDER_NAME);
-    std::fs::create_dir_all(&watch_dir)?;
+    let resolved = resolve_default_watch_dir()?;
+    for (path, occupant) in &resolved.skipped {
+        log_event!(info,
+            path:% = path.display(),
+            occupant:? = occupant,
+            "Default watch folder name is taken, trying next"
+        );
+    }
+    let watch_dir = resolved.path;
+    prepare_watch_dir(&watch_dir)?;
+    if !read_only::is_enabled() {
+        if let Err(e) = folder_marker::ensure(&watch_dir) {
+            log_event!(warn, error:% = e, "Failed to mark default watch folder");
+        }
+    }
+    Ok(watch_dir)
+}
+
+/// Маркер папки `dir` (см. [`FolderMarker`]).
+pub fn read_folder_marker(dir: &Path) -> MarkerState {
+    folder_marker::read(dir)
+}
+
+/// Пометить папку `dir`, если в ней ещё нет читаемого маркера.
+pub fn ensure_folder_marker(dir: &Path) -> Result<FolderMarker, LateraError> {
+    folder_marker::ensure(dir)
+}
+
+/// Пометить папку `dir` или переписать её маркер (id сохраняется).
+pub fn repair_folder_marker(dir: &Path) -> Result<FolderMarker, LateraError> {
+    folder_marker::repair(dir)
+}
+
+/// Отпечаток текущих настроек для новых маркеров (см. [`FolderMarker`]).
+pub fn set_folder_settings_hash(hash: String) {
+    folder_marker::set_settings_hash(hash);
+}
+
+/// Отпечаток настроек, с которыми сейчас пишутся маркеры.
+pub fn folder_settings_hash() -> String {
+    folder_marker::current_settings_hash()
+}
+
+/// Создать директорию наблюдения (если её нет) и установить иконку папки.
+///
+/// В режиме «только наблюдение» директория должна уже существовать и не изменяется.
+/// Отказ в доступе (на macOS — нет разрешения на «Рабочий стол») —
+/// [`LateraError::PermissionDenied`]: иначе watcher запустился бы, но
+/// событий бы не было. Запрет контролируемого доступа к папкам Windows —
+/// [`LateraError::ControlledFolderAccessBlocked`].
+fn prepare_watch_dir(dir: &Path) -> Result<(), LateraError> {
+    if read_only::is_enabled() {
+        if !dir.is_dir() {
+            return Err(LateraError::ReadOnlyMode(format!(
+                "creating watch directory {}",
+                dir.display()
+            )));
+        }
+        std::fs::read_dir(dir).map_err(|e| desktop_access::map_access_error(dir, e))?;
+        return Ok(());
+    }
+
+    std::fs::create_dir_all(dir).map_err(|e| desktop_access::map_access_error(dir, e))?;
+    std::fs::read_dir(dir).map_err(|e| desktop_access::map_access_error(dir, e))?;
 
     // Установить иконку папки (тихо игнорируем ошибку)
-    if let Err(e) = set_folder_icon(&watch_dir) {
-        warn!("Failed to set folder icon: {e}");
+    if let Err(e) = set_folder_icon(dir) {
+        log_event!(warn, error:% = e, "Failed to set folder icon");
     }
+    Ok(())
+}
 
-    Ok(watch_dir)
+/// Папка, которую наблюдал бы [`start_watcher`] с `override_path`.
+///
+/// Ничего не создаёт и не пишет: по ней проверяют, не наблюдается ли
+/// папка уже, до запуска watcher'а.
+pub fn resolve_watch_dir(override_path: Option<&str>) -> Result<PathBuf, LateraError> {
+    match override_path {
+        Some(p) => check_override_dir(p),
+        None => Ok(resolve_default_watch_dir()?.path),
+    }
 }
 
 fn ensure_override_dir(override_path: &str) -> Result<PathBuf, LateraError> {
+    let p = check_override_dir(override_path)?;
+    prepare_watch_dir(&p)?;
+    Ok(p)
+}
+
+/// Проверяет override-путь и приводит его к форме, в которой его откроет
+/// backend.
+fn check_override_dir(override_path: &str) -> Result<PathBuf, LateraError> {
     if override_path.trim().is_empty() {
         return Err(LateraError::InvalidPath("empty override_path".to_string()));
     }
@@ -219,14 +544,42 @@ fn ensure_override_dir(override_path: &str) -> Result<PathBuf, LateraError> {
             "override_path must be absolute: {override_path}"
         )));
     }
-    std::fs::create_dir_all(&p)?;
+    // Наблюдение за папками ядра ловило бы собственные записи ядра
+    if let Some(dir) = core_dirs()
+        .into_iter()
+        .find(|dir| path_utils::is_within(&p, dir) || path_utils::is_within(dir, &p))
+    {
+        return Err(LateraError::InvalidPath(format!(
+            "override_path overlaps core directory {}: {override_path}",
+            dir.display()
+        )));
+    }
+    // Длинный путь Windows backend откроет только в форме `\\?\`
+    Ok(path_utils::to_extended_length(&p))
+}
 
-    // Установить иконку папки (тихо игнорируем ошибку)
-    if let Err(e) = set_folder_icon(&p) {
-        warn!("Failed to set folder icon: {e}");
+/// Запомнить папку, куда пишет ядро (индекс, модели и т.п.).
+///
+/// Override-путь не может ни лежать внутри такой папки, ни содержать её:
+/// события от записей самого ядра зациклились бы. Папка данных
+/// ([`lifecycle::data_dir`]) и папка логов учитываются всегда.
+pub fn register_core_dir(dir: &Path) {
+    let mut dirs = CORE_DIRS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if !dirs.iter().any(|known| path_utils::paths_equal(known, dir)) {
+        dirs.push(dir.to_path_buf());
     }
+}
 
-    Ok(p)
+fn core_dirs() -> Vec<PathBuf> {
+    let mut dirs = CORE_DIRS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone();
+    dirs.extend(lifecycle::data_dir().ok());
+    dirs.extend(logging::log_file_path().and_then(|p| p.parent().map(Path::to_path_buf)));
+    dirs
 }
 
 /// Запустить watcher.
@@ -239,216 +592,1241 @@ pub fn start_watcher(
     on_added: impl Fn(InternalFileEvent) + Send + Sync + 'static,
     on_removed: impl Fn(InternalFileRemovedEvent) + Send + Sync + 'static,
 ) -> Result<WatcherHandle, LateraError> {
+    start_watcher_with_options(
+        override_path,
+        WatcherOptions::default(),
+        on_added,
+        on_removed,
+    )
+}
+
+/// Запустить watcher с явными настройками (см. [`start_watcher`]).
+///
+/// `on_added` получает созданные и перемещённые в папку файлы,
+/// `on_removed` — удалённые. Изменения и переименования (старый путь)
+/// доступны только через [`start_watcher_with_events`].
+pub fn start_watcher_with_options(
+    override_path: Option<String>,
+    options: WatcherOptions,
+    on_added: impl Fn(InternalFileEvent) + Send + Sync + 'static,
+    on_removed: impl Fn(InternalFileRemovedEvent) + Send + Sync + 'static,
+) -> Result<WatcherHandle, LateraError> {
+    start_watcher_with_events(override_path, options, move |event| match event.kind {
+        FileEventKind::Created | FileEventKind::RenamedTo => on_added(event),
+        FileEventKind::Removed => on_removed(InternalFileRemovedEvent {
+            file_name: event.file_name,
+            full_path: event.full_path,
+            occurred_at_ms: event.occurred_at_ms,
+        }),
+        FileEventKind::Modified | FileEventKind::RenamedFrom => {}
+    })
+}
+
+/// Запустить watcher, получающий все виды событий файлов ([`FileEventKind`]).
+///
+/// События сверх rate-limit приходят в `on_event` по одному, но с
+/// задержкой до [`BATCH_FLUSH_INTERVAL`] (см. [`start_watcher_with_batches`]).
+pub fn start_watcher_with_events(
+    override_path: Option<String>,
+    options: WatcherOptions,
+    on_event: impl Fn(InternalFileEvent) + Send + Sync + 'static,
+) -> Result<WatcherHandle, LateraError> {
+    let on_event = std::sync::Arc::new(on_event);
+    let on_event_for_batch = std::sync::Arc::clone(&on_event);
+    start_watcher_with_batches(
+        override_path,
+        options,
+        move |event| on_event(event),
+        move |events| events.into_iter().for_each(|e| on_event_for_batch(e)),
+    )
+}
+
+/// Запустить watcher, отдающий события сверх rate-limit пачками.
+///
+/// Не более [`WatcherOptions::rate_limit_per_second`] событий в секунду
+/// приходят в `on_event` (настройка ядра `rate_limit_per_second`, см.
+/// [`crate::config::Config`]); избыток накапливается (повторы по тому же пути
+/// схлопываются) и приходит в `on_batch` раз в [`BATCH_FLUSH_INTERVAL`] —
+/// события не теряются.
+pub fn start_watcher_with_batches(
+    override_path: Option<String>,
+    mut options: WatcherOptions,
+    on_event: impl Fn(InternalFileEvent) + Send + Sync + 'static,
+    on_batch: impl Fn(Vec<InternalFileEvent>) + Send + Sync + 'static,
+) -> Result<WatcherHandle, LateraError> {
+    // Все записи watcher'а (и его треда) несут watcher_id и correlation_id.
+    let context = logging::LogContext::with_operation("start_watcher");
+    let span = tracing::info_span!(
+        "start_watcher",
+        watcher_id = %options.id,
+        correlation_id = %context.correlation_id,
+    );
+    let _entered = span.enter();
+
     let watch_dir = match override_path {
         Some(p) => ensure_override_dir(&p)?,
         None => ensure_default_watch_dir()?,
     };
 
-    info!("Starting watcher for: {}", watch_dir.display());
+    let log_target = logging::watcher_target(&options.id);
+    log_event!(info, target: &log_target, path:% = watch_dir.display(), "Starting watcher");
+
+    let volume = volume::volume_info(&watch_dir).unwrap_or_else(|e| {
+        log_event!(warn, target: &log_target, error:% = e, "Cannot detect watch dir volume");
+        VolumeInfo::unknown()
+    });
+    if options.adapt_to_volume {
+        apply_volume_strategy(&mut options, volume.kind.strategy());
+    }
+    let backend = options.backend.initial_mode(volume.kind);
+    let poll_interval = options
+        .poll_interval
+        .or(options.degradation.map(|policy| policy.poll_interval))
+        .unwrap_or_else(|| {
+            if options.adapt_to_volume {
+                volume.kind.strategy().poll_interval
+            } else {
+                DegradationPolicy::default().poll_interval
+            }
+        });
+    log_event!(info, target: &log_target,
+        volume:? = volume.kind,
+        file_system:% = volume.file_system,
+        backend:? = backend,
+        "Watch dir volume detected"
+    );
 
-    let (stop_tx, stop_rx) = mpsc::channel::<()>();
+    let cancel = CancellationToken::new();
+    let cancel_for_thread = cancel.clone();
     let (done_tx, done_rx) = mpsc::channel::<()>();
-    let (event_tx, event_rx) = mpsc::channel::<Result<notify::Event, notify::Error>>();
+    let (rescan_tx, mut rescan_rx) = async_mpsc::unbounded_channel::<mpsc::Sender<Option<usize>>>();
+
+    // Цикл watcher'а ждёт событий notify, запросов и таймеров на runtime
+    // ядра: без событий тред просыпается раз в DIR_CHECK_INTERVAL, а не
+    // опрашивает канал.
+    let runtime = LoopRuntime::new()?;
+
+    let tree_stats = TreeStats::default();
+    let tree_stats_for_thread = tree_stats.clone();
+    let metrics = EventMetrics::default();
+    let metrics_for_thread = metrics.clone();
+    let recursive_mode = if options.recursive {
+        RecursiveMode::Recursive
+    } else {
+        RecursiveMode::NonRecursive
+    };
 
+    let status = StatusCell::new(options.status_listener.clone());
+    let status_for_thread = status.clone();
     let watch_dir_clone = watch_dir.clone();
+    let span_for_thread = span.clone();
     let join = thread::spawn(move || {
-        // Клонируем sender для использования внутри closure watcher'а
-        let event_tx_for_watcher = event_tx.clone();
-        let mut watcher: RecommendedWatcher = match notify::recommended_watcher(move |res| {
-            // Отправляем событие в канал. Если receiver закрыт — логируем и продолжаем.
-            if let Err(e) = event_tx_for_watcher.send(res) {
-                debug!("Failed to send notify event (channel closed): {e}");
+        let _entered = span_for_thread.enter();
+        let cancel = cancel_for_thread;
+        let metrics = metrics_for_thread;
+        let on_event = {
+            let metrics = metrics.clone();
+            move |e: InternalFileEvent| {
+                metrics.record_emitted(1);
+                observe_latency(std::slice::from_ref(&e));
+                let started = Instant::now();
+                on_event(e);
+                crate::metrics::observe_callback_duration(started.elapsed());
             }
-        }) {
-            Ok(w) => w,
-            Err(e) => {
-                error!("Failed to create watcher: {e}");
-                // Сигнализируем о завершении даже при ошибке
-                let _ = done_tx.send(());
-                return;
+        };
+        let on_batch = {
+            let metrics = metrics.clone();
+            move |events: Vec<InternalFileEvent>| {
+                metrics.record_emitted(events.len());
+                observe_latency(&events);
+                let started = Instant::now();
+                on_batch(events);
+                crate::metrics::observe_callback_duration(started.elapsed());
             }
         };
+        runtime.block_on(async move {
+            let mut backoff = options.restart.map(Backoff::new);
+            let mut supervisor = Supervisor {
+                watch_dir: &watch_dir_clone,
+                recursive_mode,
+                dir_recovery: options.dir_recovery,
+                initial_mode: backend,
+                ladder: options.degradation.map(|policy| Ladder::starting_at(policy, backend)),
+                poll_interval,
+                fallback_reason: String::new(),
+                log_target: &log_target,
+                status: &status_for_thread,
+                cancel: &cancel,
+                metrics: &metrics,
+            };
+            // Когда backend последний раз перезапускался (для сброса backoff).
+            let mut restarted_at: Option<Instant> = None;
+            let mut backend = match supervisor.start_backend() {
+                Ok(backend) => backend,
+                Err(failure) => {
+                    let Some(backend) = supervisor.restart(failure, backoff.as_mut()).await else {
+                        // Сигнализируем о завершении даже при ошибке
+                        let _ = done_tx.send(());
+                        return;
+                    };
+                    restarted_at = Some(Instant::now());
+                    backend
+                }
+            };
 
-        if let Err(e) = watcher.watch(&watch_dir_clone, RecursiveMode::NonRecursive) {
-            error!(
-                "Failed to watch directory {}: {e}",
-                watch_dir_clone.display()
+            let mut pipeline = EventPipeline::new(
+                &watch_dir_clone,
+                &options,
+                &log_target,
+                &metrics,
+                &tree_stats_for_thread,
+                on_event,
+                on_batch,
             );
-            // Сигнализируем о завершении даже при ошибке
-            let _ = done_tx.send(());
-            return;
-        }
 
-        // Burst/дедуп состояние.
-        let mut last_seen: HashMap<String, Instant> = HashMap::new();
-        let mut second_window_started_at = Instant::now();
-        let mut second_event_count: u32 = 0;
-        let mut cleanup_counter: u32 = 0;
+            // Таймер для периодической проверки существования директории
+            let mut last_dir_check = Instant::now();
 
-        // Таймер для периодической проверки существования директории
-        let mut last_dir_check = Instant::now();
+            // Последняя ошибка notify: пока она свежая, watcher в Degraded.
+            let mut last_notify_error: Option<Instant> = None;
 
-        loop {
-            // 1) graceful shutdown
-            if stop_rx.try_recv().is_ok() {
-                info!("Watcher shutdown requested");
-                break;
+            // Снимок папки: сверка с прошлым запуском, затем сохранение по изменениям.
+            let snapshot_file = options
+                .snapshot_dir
+                .as_deref()
+                .map(|dir| dir_snapshot::snapshot_path(dir, &watch_dir_clone));
+            if let Some(file) = &snapshot_file {
+                // Номер события — до сверки: случившееся во время неё
+                // попадёт в следующее воспроизведение.
+                let event_id = options
+                    .fsevents_replay
+                    .then(fsevents_replay::current_event_id)
+                    .flatten();
+                let replayed = options.fsevents_replay
+                    && replay_with_snapshot(
+                        file,
+                        &watch_dir_clone,
+                        &options,
+                        &log_target,
+                        pipeline.settle.as_mut(),
+                        &pipeline.on_batch,
+                    );
+                if !replayed {
+                    reconcile_with_snapshot(
+                        file,
+                        &watch_dir_clone,
+                        &options,
+                        &log_target,
+                        pipeline.settle.as_mut(),
+                        &pipeline.on_batch,
+                        |_| false,
+                    );
+                }
+                save_fsevents_id(file, event_id, &log_target);
             }
+            let mut last_snapshot_save = Instant::now();
 
-            // 2) проверка существования watched-директории
-            if last_dir_check.elapsed() >= DIR_CHECK_INTERVAL {
-                last_dir_check = Instant::now();
-                if !watch_dir_clone.exists() {
-                    warn!(
-                        "Watch directory no longer exists: {}",
-                        watch_dir_clone.display()
-                    );
-                    // Директория удалена или переименована — завершаем работу
+            // Состояние папки для сверки по запросу, если снимок не ведётся.
+            let mut memory_baseline = if snapshot_file.is_none() {
+                scan_snapshot(&watch_dir_clone, &options, &log_target, |_| false)
+            } else {
+                None
+            };
+            // Запросы сверки, пришедшие, пока цикл ждал событий.
+            let mut rescan_requests: Vec<mpsc::Sender<Option<usize>>> = Vec::new();
+            status_for_thread.set(supervisor.running_state());
+
+            loop {
+                // 1) graceful shutdown
+                if cancel.is_cancelled() {
+                    log_event!(info, target: &log_target, "Watcher shutdown requested");
+                    status_for_thread.set(WatcherState::Stopped);
                     break;
                 }
-            }
 
-            // 3) обработка событий notify
-            match event_rx.recv_timeout(Duration::from_millis(50)) {
-                Ok(Ok(event)) => {
-                    debug!("notify event: {:?}", event.kind);
-
-                    // Обработка событий удаления файлов
-                    if is_remove_file_event(&event.kind) {
-                        for path in &event.paths {
-                            match make_internal_file_removed_event(path) {
-                                Ok(e) => {
-                                    info!("File removed: {}", e.full_path.display());
-                                    on_removed(e);
-                                }
-                                Err(err) => warn!("Cannot build InternalFileRemovedEvent: {err}"),
-                            }
-                        }
-                        continue;
+                // 1.1) сводки по подавленным предупреждениям
+                log_suppressed(&log_target, pipeline.log_throttle.due_summaries(Instant::now()));
+
+                // 1.2) выход из Degraded, когда ошибки прекратились
+                if last_notify_error.is_some_and(|at| at.elapsed() >= DEGRADED_RECOVERY_PERIOD) {
+                    last_notify_error = None;
+                    log_event!(info, target: &log_target, "notify errors stopped, watcher recovered");
+                    status_for_thread.set(supervisor.running_state());
+                }
+
+                // 1.3) перезапущенный backend работает стабильно — backoff заново
+                if restarted_at.is_some_and(|at| at.elapsed() >= RESTART_RESET_PERIOD) {
+                    restarted_at = None;
+                    if let Some(backoff) = backoff.as_mut() {
+                        backoff.reset();
                     }
+                    supervisor.reset_failures();
+                }
 
-                    if !is_create_file_event(&event.kind) {
-                        continue;
+                // 1.4) сверка по запросу (rescan) или после потерь в очереди событий
+                while let Ok(reply) = rescan_rx.try_recv() {
+                    rescan_requests.push(reply);
+                }
+                let lagged = metrics.take_lagged();
+                if !rescan_requests.is_empty() || lagged {
+                    let replies = std::mem::take(&mut rescan_requests);
+                    if lagged {
+                        log_event!(warn, target: &log_target,
+                            dropped = metrics.snapshot().events_dropped,
+                            "Event queue overflowed, rescanning watch dir"
+                        );
+                    } else {
+                        log_event!(info, target: &log_target, "Rescan requested");
+                    }
+                    let known =
+                        |p: &Path| pipeline.touched.contains(p) || pipeline.overflow.contains(p);
+                    let found = if let Some(file) = &snapshot_file {
+                        reconcile_with_snapshot(
+                            file,
+                            &watch_dir_clone,
+                            &options,
+                            &log_target,
+                            pipeline.settle.as_mut(),
+                            &pipeline.on_batch,
+                            known,
+                        )
+                    } else {
+                        reconcile_dir(
+                            memory_baseline.as_ref(),
+                            &watch_dir_clone,
+                            &options,
+                            &log_target,
+                            pipeline.settle.as_mut(),
+                            &pipeline.on_batch,
+                            known,
+                        )
+                        .map(|(current, found)| {
+                            memory_baseline = Some(current);
+                            found
+                        })
+                    };
+                    if found.is_some() {
+                        let overflow = &pipeline.overflow;
+                        pipeline.touched.retain(|p| overflow.contains(p));
                     }
+                    for reply in replies {
+                        let _ = reply.send(found);
+                    }
+                }
 
-                    for path in event.paths {
-                        if !is_regular_file(&path) {
-                            continue;
-                        }
+                // 2) проверка существования watched-директории (удалена,
+                // переименована, диск отключён) — обрабатывается в 4)
+                let mut dir_lost = false;
+                if last_dir_check.elapsed() >= DIR_CHECK_INTERVAL {
+                    last_dir_check = Instant::now();
+                    dir_lost = !watch_dir_clone.exists();
+                }
+
+                // 2.1) выпуск «осевших» файлов
+                pipeline.release_settled();
+
+                // 2.2) выдача накопленной пачки
+                pipeline.flush_due_batch();
 
-                        match make_internal_file_event(&path) {
-                            Ok(e) => {
-                                // 3.1) дедуп по полному пути (окно 300мс)
-                                let key = e.full_path.to_string_lossy().to_string();
-                                let now = Instant::now();
-                                if let Some(prev) = last_seen.get(&key) {
-                                    if now.duration_since(*prev) < DEDUP_WINDOW {
-                                        debug!(
-                                            "dedup: skipping duplicate event for {}",
-                                            e.full_path.display()
-                                        );
-                                        continue;
-                                    }
-                                }
-                                last_seen.insert(key, now);
-
-                                // 3.1.1) Периодическая очистка устаревших записей
-                                // Выполняется каждые 100 событий или при превышении лимита
-                                cleanup_counter = cleanup_counter.saturating_add(1);
-                                if last_seen.len() > DEDUP_MAP_MAX_SIZE || cleanup_counter >= 100 {
-                                    let before = last_seen.len();
-                                    last_seen.retain(|_, &mut instant| {
-                                        now.duration_since(instant) < DEDUP_WINDOW * 10
-                                    });
-                                    if before != last_seen.len() {
-                                        debug!(
-                                            "Dedup map cleaned: {} -> {} entries",
-                                            before,
-                                            last_seen.len()
-                                        );
-                                    }
-                                    cleanup_counter = 0;
-                                }
-
-                                // 3.2) rate-limit: не более 200 событий/сек
-                                if second_window_started_at.elapsed() >= Duration::from_secs(1) {
-                                    second_window_started_at = Instant::now();
-                                    second_event_count = 0;
-                                }
-                                second_event_count = second_event_count.saturating_add(1);
-
-                                if second_event_count <= RATE_LIMIT_PER_SECOND {
-                                    on_added(e);
-                                } else {
-                                    // При превышении лимита — логируем и пропускаем.
-                                    // В будущей версии здесь будет batch.
-                                    warn!(
-                                        "rate limit exceeded ({} events/sec), dropping event for {}",
-                                        second_event_count,
-                                        e.full_path.display()
-                                    );
-                                }
-                            }
-                            Err(err) => warn!("Cannot build InternalFileEvent: {err}"),
+                // 2.3) сохранение снимка папки (ещё не сообщённые файлы не входят)
+                if pipeline.snapshot_dirty && last_snapshot_save.elapsed() >= SNAPSHOT_SAVE_INTERVAL {
+                    last_snapshot_save = Instant::now();
+                    pipeline.snapshot_dirty = false;
+                    let pending = |p: &Path| pipeline.is_pending(p);
+                    let saved = if let Some(file) = &snapshot_file {
+                        save_snapshot(file, &watch_dir_clone, &options, &log_target, pending)
+                    } else {
+                        let current =
+                            scan_snapshot(&watch_dir_clone, &options, &log_target, pending);
+                        let scanned = current.is_some();
+                        if scanned {
+                            memory_baseline = current;
                         }
+                        scanned
+                    };
+                    if saved {
+                        pipeline.retain_pending_touched();
                     }
                 }
-                Ok(Err(err)) => {
-                    warn!("notify error: {err}");
+
+                // 3) ожидание: событие notify, остановка, запрос сверки или таймер
+                let mut wake_at = last_dir_check + DIR_CHECK_INTERVAL;
+                if pipeline.has_pending() {
+                    wake_at = wake_at.min(Instant::now() + SETTLE_CHECK_INTERVAL);
                 }
-                Err(mpsc::RecvTimeoutError::Timeout) => {
+                let received = tokio::select! {
+                    () = cancel.cancelled() => continue,
+                    Some(reply) = rescan_rx.recv() => {
+                        rescan_requests.push(reply);
+                        continue;
+                    }
+                    received = backend.events.recv() => {
+                        if received.is_some() {
+                            metrics.record_dequeued();
+                        }
+                        Some(received)
+                    }
+                    () = tokio::time::sleep_until(wake_at.into()) => None,
+                };
+
+                // 3.0) обработка событий notify
+                let failure = match received {
+                    Some(Some(Ok(event))) => {
+                        let _event_span =
+                            tracing::info_span!("file_event", kind = ?event.kind).entered();
+                        log_event!(debug, target: &log_target, kind:? = event.kind, "notify event");
+                        pipeline.process(event);
+                        None
+                    }
+                    Some(Some(Err(err))) if is_watch_limit(&err) => {
+                        log_event!(warn, target: &log_target,
+                            limit:? = inotify_watch_limit(),
+                            "inotify watch limit reached"
+                        );
+                        Some(watch_limit_failure(err))
+                    }
+                    Some(Some(Err(err))) if is_backend_failure(&err) => {
+                        log_event!(error, target: &log_target, error:% = err, "notify backend failed");
+                        Some(BackendFailure {
+                            code: WatcherErrorCode::BackendFailed,
+                            message: err.to_string(),
+                        })
+                    }
+                    Some(Some(Err(err))) => {
+                        if pipeline.log_throttle.allow(NOTIFY_ERROR_WARNING, Instant::now()) {
+                            log_event!(warn, target: &log_target, error:% = err, "notify error");
+                        }
+                        last_notify_error = Some(Instant::now());
+                        status_for_thread.set(WatcherState::Degraded {
+                            reason: err.to_string(),
+                        });
+                        None
+                    }
                     // тик
+                    None => None,
+                    Some(None) => {
+                        log_event!(warn, target: &log_target, "notify channel disconnected");
+                        Some(BackendFailure {
+                            code: WatcherErrorCode::ChannelDisconnected,
+                            message: "notify channel disconnected".to_string(),
+                        })
+                    }
+                };
+
+                // 4) перезапуск упавшего backend'а / ожидание пропавшей папки
+                let failure = if dir_lost {
+                    Some(BackendFailure {
+                        code: WatcherErrorCode::WatchDirLost,
+                        message: format!("{} no longer exists", watch_dir_clone.display()),
+                    })
+                } else {
+                    failure
+                };
+                if let Some(failure) = failure {
+                    // Всё, что известно к этому моменту, — в снимок: после
+                    // перезапуска сверка сообщит только о пропущенном.
+                    if let Some(file) = &snapshot_file {
+                        let saved = !dir_lost
+                            && save_snapshot(file, &watch_dir_clone, &options, &log_target, |_| {
+                                false
+                            });
+                        if saved {
+                            pipeline.touched.clear();
+                        }
+                    }
+                    let Some(restarted) = supervisor.restart(failure, backoff.as_mut()).await else {
+                        break;
+                    };
+                    backend = restarted;
+                    restarted_at = Some(Instant::now());
+                    last_notify_error = None;
+                    if let Some(file) = &snapshot_file {
+                        reconcile_with_snapshot(
+                            file,
+                            &watch_dir_clone,
+                            &options,
+                            &log_target,
+                            pipeline.settle.as_mut(),
+                            &pipeline.on_batch,
+                            |p| pipeline.overflow.contains(p),
+                        );
+                        pipeline.snapshot_dirty = true;
+                    }
+                    status_for_thread.set(supervisor.running_state());
                 }
-                Err(mpsc::RecvTimeoutError::Disconnected) => {
-                    warn!("notify channel disconnected");
-                    break;
+            }
+
+            // Накопленное не теряем и при остановке.
+            pipeline.flush_batch();
+
+            // Не «осевшие» файлы при следующем запуске должны оказаться новыми.
+            if let Some(file) = &snapshot_file {
+                if watch_dir_clone.exists() {
+                    // Не «осевших» файлов нет в снимке, а их события — до
+                    // этого номера: тогда остаётся номер с запуска.
+                    let event_id = (options.fsevents_replay
+                        && pipeline.settle.as_ref().is_none_or(SettleQueue::is_empty))
+                    .then(fsevents_replay::current_event_id)
+                    .flatten();
+                    let saved = save_snapshot(file, &watch_dir_clone, &options, &log_target, |p| {
+                        pipeline.settle.as_ref().is_some_and(|q| q.contains(p))
+                    });
+                    if saved {
+                        save_fsevents_id(file, event_id, &log_target);
+                    }
                 }
             }
-        }
 
-        info!("Watcher thread finished");
-        // Сигнализируем о завершении потока
-        let _ = done_tx.send(());
+            log_suppressed(&log_target, pipeline.log_throttle.take_summaries());
+            log_event!(info, target: &log_target, "Watcher thread finished");
+            // Сигнализируем о завершении потока
+            let _ = done_tx.send(());
+        });
     });
 
     Ok(WatcherHandle {
-        stop_tx,
+        _cancel_on_drop: cancel.clone().drop_guard(),
+        cancel,
+        rescan_tx,
         done_rx: Some(done_rx),
         join: Some(join),
         watch_dir,
+        volume,
+        backend,
+        tree_stats,
+        metrics,
+        status,
+    })
+}
+
+/// Умолчания тома для настроек, оставленных по умолчанию.
+fn apply_volume_strategy(options: &mut WatcherOptions, strategy: VolumeStrategy) {
+    if options.settle_quiet_period.is_none() {
+        options.settle_quiet_period = strategy.settle_quiet_period;
+    }
+    if let Some(degradation) = options.degradation.as_mut() {
+        if degradation.poll_interval == DegradationPolicy::default().poll_interval {
+            degradation.poll_interval = strategy.poll_interval;
+        }
+    }
+}
+
+/// Runtime цикла watcher'а.
+enum LoopRuntime {
+    /// Общий runtime ядра ([`lifecycle::runtime`]).
+    Core(tokio::runtime::Handle),
+    /// Свой однопоточный: ядро не инициализировано (тесты, утилиты).
+    Own(tokio::runtime::Runtime),
+}
+
+impl LoopRuntime {
+    fn new() -> Result<Self, LateraError> {
+        match lifecycle::runtime() {
+            Some(handle) => Ok(Self::Core(handle)),
+            None => Ok(Self::Own(
+                tokio::runtime::Builder::new_current_thread()
+                    .enable_time()
+                    .build()?,
+            )),
+        }
+    }
+
+    fn block_on<F: std::future::Future>(&self, future: F) -> F::Output {
+        match self {
+            Self::Core(handle) => handle.block_on(future),
+            Self::Own(runtime) => runtime.block_on(future),
+        }
+    }
+}
+
+/// Работающий backend `notify` и канал его событий.
+struct Backend {
+    /// `None` в режиме ручного обновления.
+    _watcher: Option<Box<dyn Watcher>>,
+    /// В режиме ручного обновления канал держится открытым: закрытый канал
+    /// означал бы падение backend'а.
+    _idle_tx: Option<async_mpsc::Sender<Result<notify::Event, notify::Error>>>,
+    events: async_mpsc::Receiver<Result<notify::Event, notify::Error>>,
+}
+
+/// Почему backend перестал работать.
+struct BackendFailure {
+    code: WatcherErrorCode,
+    message: String,
+}
+
+/// Запуск и перезапуск backend'а на треде watcher'а.
+struct Supervisor<'a> {
+    watch_dir: &'a Path,
+    recursive_mode: RecursiveMode,
+    dir_recovery: WatchDirRecovery,
+    /// Ступень, выбранная при запуске ([`WatcherOptions::backend`]).
+    initial_mode: WatchMode,
+    /// `None` — лестница деградации выключена, всегда `initial_mode`.
+    ladder: Option<Ladder>,
+    poll_interval: Duration,
+    /// Почему watcher ушёл с нативного backend'а.
+    fallback_reason: String,
+    log_target: &'a str,
+    status: &'a StatusCell,
+    cancel: &'a CancellationToken,
+    metrics: &'a EventMetrics,
+}
+
+impl Supervisor<'_> {
+    fn mode(&self) -> WatchMode {
+        self.ladder.as_ref().map_or(self.initial_mode, Ladder::mode)
+    }
+
+    /// Состояние работающего watcher'а на текущей ступени.
+    fn running_state(&self) -> WatcherState {
+        match self.mode() {
+            mode if mode == self.initial_mode => WatcherState::Running,
+            mode => WatcherState::Fallback {
+                mode,
+                reason: self.fallback_reason.clone(),
+            },
+        }
+    }
+
+    /// Backend стабильно работает — сбои на текущей ступени забываются.
+    fn reset_failures(&mut self) {
+        if let Some(ladder) = self.ladder.as_mut() {
+            ladder.reset_failures();
+        }
+    }
+
+    /// Переходит на ступень ниже по [`DegradationPolicy`] и сообщает об этом.
+    ///
+    /// `false` — лестница выключена или ниже некуда.
+    fn fall_back(&mut self, failure: &BackendFailure) -> bool {
+        let Some(mode) = self.ladder.as_mut().and_then(Ladder::step_down) else {
+            return false;
+        };
+        log_event!(warn, target: self.log_target,
+            mode:? = mode,
+            reason:% = failure.message,
+            "Watcher falling back"
+        );
+        self.fallback_reason.clone_from(&failure.message);
+        self.status.set(self.running_state());
+        true
+    }
+
+    /// Создаёт backend текущей ступени и начинает наблюдение за папкой.
+    fn start_backend(&self) -> Result<Backend, BackendFailure> {
+        let (event_tx, event_rx) =
+            async_mpsc::channel::<Result<notify::Event, notify::Error>>(EVENT_QUEUE_CAPACITY);
+        self.metrics.reset_queue();
+        let mode = self.mode();
+        if mode == WatchMode::ManualRefresh {
+            return Ok(Backend {
+                _watcher: None,
+                _idle_tx: Some(event_tx),
+                events: event_rx,
+            });
+        }
+        let log_target_for_watcher = self.log_target.to_string();
+        let metrics = self.metrics.clone();
+        let handler = move |res| {
+            // Очередь ограничена: при переполнении событие отбрасывается, а
+            // watcher потом сверяет папку. Если receiver закрыт — логируем и продолжаем.
+            metrics.record_received();
+            match event_tx.try_send(res) {
+                Ok(()) => {}
+                Err(async_mpsc::error::TrySendError::Full(_)) => metrics.record_not_queued(true),
+                Err(e @ async_mpsc::error::TrySendError::Closed(_)) => {
+                    metrics.record_not_queued(false);
+                    log_event!(debug, target: &log_target_for_watcher,
+                        error:% = e,
+                        "Failed to send notify event (channel closed)"
+                    );
+                }
+            }
+        };
+        let created: notify::Result<Box<dyn Watcher>> = match mode {
+            WatchMode::Polling => PollWatcher::new(
+                handler,
+                notify::Config::default().with_poll_interval(self.poll_interval),
+            )
+            .map(|w| Box::new(w) as Box<dyn Watcher>),
+            _ => notify::recommended_watcher(handler).map(|w| Box::new(w) as Box<dyn Watcher>),
+        };
+        let mut watcher = created.map_err(|e| {
+            log_event!(error, target: self.log_target, error:% = e, "Failed to create watcher");
+            BackendFailure {
+                code: WatcherErrorCode::BackendInitFailed,
+                message: e.to_string(),
+            }
+        })?;
+
+        watcher
+            .watch(self.watch_dir, self.recursive_mode)
+            .map_err(|e| {
+                if is_watch_limit(&e) {
+                    log_event!(warn, target: self.log_target,
+                        path:% = self.watch_dir.display(),
+                        limit:? = inotify_watch_limit(),
+                        "inotify watch limit reached"
+                    );
+                    return watch_limit_failure(e);
+                }
+                log_event!(error, target: self.log_target,
+                    path:% = self.watch_dir.display(),
+                    error:% = e,
+                    "Failed to watch directory"
+                );
+                BackendFailure {
+                    code: WatcherErrorCode::WatchFailed,
+                    message: e.to_string(),
+                }
+            })?;
+
+        Ok(Backend {
+            _watcher: Some(watcher),
+            _idle_tx: None,
+            events: event_rx,
+        })
+    }
+
+    /// Перезапускает backend по `backoff`, сообщая о попытках через `status`.
+    /// Пропавшую папку сначала дожидается (см. [`WatchDirRecovery`]).
+    /// Повторяющиеся сбои и исчерпанные попытки переводят watcher на
+    /// ступень ниже ([`DegradationPolicy`]).
+    ///
+    /// `None` — watcher должен завершиться: остановка запрошена, папка
+    /// потеряна или попытки исчерпаны (состояние уже выставлено).
+    async fn restart(
+        &mut self,
+        mut failure: BackendFailure,
+        mut backoff: Option<&mut Backoff>,
+    ) -> Option<Backend> {
+        loop {
+            if !self.watch_dir.exists() {
+                if !self.wait_for_watch_dir().await {
+                    return None;
+                }
+                match self.start_backend() {
+                    Ok(backend) => return Some(backend),
+                    Err(next) => failure = next,
+                }
+            }
+
+            // Лимит inotify перезапуском не исправить: сразу на опрос
+            if failure.code == WatcherErrorCode::WatchLimitExceeded {
+                if !self.fall_back(&failure) {
+                    self.status.set(WatcherState::Error {
+                        code: failure.code,
+                        message: failure.message,
+                    });
+                    return None;
+                }
+                if let Some(backoff) = backoff.as_deref_mut() {
+                    backoff.reset();
+                }
+                match self.start_backend() {
+                    Ok(backend) => return Some(backend),
+                    Err(next) => {
+                        failure = next;
+                        continue;
+                    }
+                }
+            }
+
+            let next = backoff.as_deref_mut().and_then(Backoff::next_attempt);
+            let too_many = self.ladder.as_mut().is_some_and(Ladder::record_failure);
+            if (too_many || next.is_none()) && self.fall_back(&failure) {
+                if let Some(backoff) = backoff.as_deref_mut() {
+                    backoff.reset();
+                }
+                match self.start_backend() {
+                    Ok(backend) => return Some(backend),
+                    Err(next) => {
+                        failure = next;
+                        continue;
+                    }
+                }
+            }
+            let Some((attempt, delay)) = next else {
+                self.status.set(WatcherState::Error {
+                    code: failure.code,
+                    message: failure.message,
+                });
+                return None;
+            };
+            log_event!(warn, target: self.log_target,
+                attempt,
+                retry_in_ms = delay.as_millis(),
+                reason:% = failure.message,
+                "Restarting watcher backend"
+            );
+            self.status.set(WatcherState::Restarting {
+                attempt,
+                retry_in: delay,
+                reason: failure.message.clone(),
+            });
+            if self.stop_requested(delay).await {
+                return None;
+            }
+
+            match self.start_backend() {
+                Ok(backend) => {
+                    log_event!(info, target: self.log_target, attempt, "Watcher backend restarted");
+                    return Some(backend);
+                }
+                Err(next) => failure = next,
+            }
+        }
+    }
+
+    /// Ждёт, пока пропавшая папка появится снова (или создаёт её заново).
+    ///
+    /// `false` — ждать не нужно или остановка запрошена (состояние уже выставлено).
+    async fn wait_for_watch_dir(&self) -> bool {
+        log_event!(warn, target: self.log_target,
+            path:% = self.watch_dir.display(),
+            "Watch directory no longer exists"
+        );
+        if self.dir_recovery == WatchDirRecovery::Stop {
+            self.status.set(WatcherState::Error {
+                code: WatcherErrorCode::WatchDirLost,
+                message: format!("{} no longer exists", self.watch_dir.display()),
+            });
+            return false;
+        }
+
+        self.status.set(WatcherState::DirLost {
+            path: self.watch_dir.to_path_buf(),
+        });
+        loop {
+            if self.dir_recovery == WatchDirRecovery::Recreate && !read_only::is_enabled() {
+                match recreate_watch_dir(self.watch_dir) {
+                    Ok(()) => {
+                        log_event!(info, target: self.log_target,
+                            path:% = self.watch_dir.display(),
+                            "Watch directory recreated"
+                        );
+                    }
+                    Err(e) => {
+                        log_event!(debug, target: self.log_target, error:% = e, "Cannot recreate watch directory yet");
+                    }
+                }
+            }
+            if self.watch_dir.is_dir() {
+                log_event!(info, target: self.log_target,
+                    path:% = self.watch_dir.display(),
+                    "Watch directory is available again"
+                );
+                return true;
+            }
+            if self.stop_requested(DIR_CHECK_INTERVAL).await {
+                return false;
+            }
+        }
+    }
+
+    /// Ждёт сигнала остановки не дольше `timeout`; при остановке выставляет
+    /// [`WatcherState::Stopped`].
+    async fn stop_requested(&self, timeout: Duration) -> bool {
+        tokio::select! {
+            () = self.cancel.cancelled() => {
+                log_event!(info, target: self.log_target, "Watcher shutdown requested");
+                self.status.set(WatcherState::Stopped);
+                true
+            }
+            () = tokio::time::sleep(timeout) => false,
+        }
+    }
+}
+
+/// Создаёт пропавшую папку наблюдения заново.
+///
+/// Только сам последний компонент пути: если пропал и родитель (отключён
+/// диск, размонтирован том), создавать на его месте ничего нельзя.
+fn recreate_watch_dir(dir: &Path) -> std::io::Result<()> {
+    if dir.is_dir() {
+        return Ok(());
+    }
+    std::fs::create_dir(dir)?;
+    if let Err(e) = set_folder_icon(dir) {
+        log_event!(warn, error:% = e, "Failed to set folder icon");
+    }
+    Ok(())
+}
+
+/// Файл лимита inotify на Linux.
+#[cfg(target_os = "linux")]
+const INOTIFY_WATCH_LIMIT_FILE: &str = "/proc/sys/fs/inotify/max_user_watches";
+
+/// Лимит наблюдений inotify (`fs.inotify.max_user_watches`); `None` вне
+/// Linux или если его не прочитать.
+pub fn inotify_watch_limit() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        std::fs::read_to_string(INOTIFY_WATCH_LIMIT_FILE)
+            .ok()?
+            .trim()
+            .parse()
+            .ok()
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+/// Backend упёрся в лимит inotify: перезапуск не поможет, нужен опрос.
+fn is_watch_limit(err: &notify::Error) -> bool {
+    matches!(err.kind, notify::ErrorKind::MaxFilesWatch)
+}
+
+/// Сбой из-за лимита inotify: сообщение — [`LateraError::InotifyLimitExceeded`].
+fn watch_limit_failure(err: notify::Error) -> BackendFailure {
+    BackendFailure {
+        code: WatcherErrorCode::WatchLimitExceeded,
+        message: LateraError::from(err).to_string(),
+    }
+}
+
+/// Ошибка самого backend'а (не связанная с конкретными путями): события
+/// перестают поступать, нужен перезапуск.
+fn is_backend_failure(err: &notify::Error) -> bool {
+    err.paths.is_empty() && matches!(err.kind, notify::ErrorKind::Io(_))
+}
+
+/// Входит ли файл в снимок папки: те же правила, что для событий.
+fn snapshot_includes(options: &WatcherOptions, watch_dir: &Path, path: &Path) -> bool {
+    !internal_files::is_internal(path) && options.filter.matches(watch_dir, path)
+}
+
+/// Сверяет папку со снимком прошлого запуска и сообщает о расхождениях.
+///
+/// Появившиеся файлы проходят фильтр по возрасту и стабилизацию, как
+/// обычные события. Всё найденное отдаётся в `on_batch` пачками по
+/// [`MAX_BATCH_SIZE`], и только после этого снимок перезаписывается —
+/// падение посреди сверки приведёт к повтору, а не к потере событий.
+/// Пути, для которых `known` вернул `true`, уже сообщены и пропускаются.
+///
+/// Возвращает число найденных расхождений; `None` — папку не удалось прочитать.
+fn reconcile_with_snapshot(
+    snapshot_file: &Path,
+    watch_dir: &Path,
+    options: &WatcherOptions,
+    log_target: &str,
+    settle: Option<&mut SettleQueue>,
+    on_batch: &impl Fn(Vec<InternalFileEvent>),
+    known: impl Fn(&Path) -> bool,
+) -> Option<usize> {
+    let previous = DirSnapshot::load(snapshot_file).unwrap_or_else(|e| {
+        log_event!(warn, target: log_target, error:% = e, "Cannot read directory snapshot");
+        None
+    });
+    let (current, found) = reconcile_dir(
+        previous.as_ref(),
+        watch_dir,
+        options,
+        log_target,
+        settle,
+        on_batch,
+        known,
+    )?;
+    if let Err(e) = current.save(snapshot_file) {
+        log_event!(warn, target: log_target, error:% = e, "Cannot save directory snapshot");
+    }
+    Some(found)
+}
+
+/// Сверка при запуске по истории FSEvents ([`WatcherOptions::fsevents_replay`]):
+/// снимок прошлого запуска перечитывается только по путям из журнала.
+///
+/// `false` — номера события или снимка нет, история недоступна или
+/// неполна: нужна полная сверка [`reconcile_with_snapshot`].
+fn replay_with_snapshot(
+    snapshot_file: &Path,
+    watch_dir: &Path,
+    options: &WatcherOptions,
+    log_target: &str,
+    settle: Option<&mut SettleQueue>,
+    on_batch: &impl Fn(Vec<InternalFileEvent>),
+) -> bool {
+    let Ok(Some(since)) = fsevents_replay::load_event_id(snapshot_file) else {
+        return false;
+    };
+    let Ok(Some(previous)) = DirSnapshot::load(snapshot_file) else {
+        return false;
+    };
+    // FSEvents сообщает канонические пути (`/private/var/…`)
+    let Ok(root) = std::fs::canonicalize(watch_dir) else {
+        return false;
+    };
+    let Some(replayed) = fsevents_replay::replay(&root, since) else {
+        log_event!(warn, target: log_target, "FSEvents history unavailable, rescanning watch dir");
+        return false;
+    };
+    if replayed.must_rescan {
+        log_event!(info, target: log_target, "FSEvents history incomplete, rescanning watch dir");
+        return false;
+    }
+
+    let mut relatives = BTreeSet::new();
+    for path in &replayed.paths {
+        let Ok(relative) = path.strip_prefix(&root) else {
+            continue;
+        };
+        // Изменилась сама папка (переименована, пересоздана)
+        if relative.as_os_str().is_empty() {
+            return false;
+        }
+        if options.recursive || relative.components().count() == 1 {
+            relatives.insert(relative);
+        }
+    }
+    let mut current = previous.clone();
+    for relative in relatives {
+        let refreshed = current.refresh(watch_dir, relative, options.recursive, |p| {
+            snapshot_includes(options, watch_dir, p)
+        });
+        if let Err(e) = refreshed {
+            log_event!(warn, target: log_target, error:% = e, "Cannot read replayed path");
+            return false;
+        }
+    }
+    log_event!(info, target: log_target,
+        events = replayed.paths.len(),
+        "Replayed FSEvents history"
+    );
+    report_diff(
+        &previous,
+        &mut current,
+        watch_dir,
+        options,
+        log_target,
+        settle,
+        on_batch,
+        |_| false,
+    );
+    if let Err(e) = current.save(snapshot_file) {
+        log_event!(warn, target: log_target, error:% = e, "Cannot save directory snapshot");
+    }
+    true
+}
+
+/// Запоминает номер события FSEvents для следующего запуска.
+fn save_fsevents_id(snapshot_file: &Path, event_id: Option<u64>, log_target: &str) {
+    let Some(event_id) = event_id else {
+        return;
+    };
+    if let Err(e) = fsevents_replay::save_event_id(snapshot_file, event_id) {
+        log_event!(warn, target: log_target, error:% = e, "Cannot save FSEvents event id");
+    }
+}
+
+/// Сверяет папку с прошлым состоянием `previous` (как
+/// [`reconcile_with_snapshot`]) и возвращает текущее — без файлов, ждущих
+/// стабилизации, — и число найденных расхождений. `None` — папку не
+/// удалось прочитать.
+fn reconcile_dir(
+    previous: Option<&DirSnapshot>,
+    watch_dir: &Path,
+    options: &WatcherOptions,
+    log_target: &str,
+    settle: Option<&mut SettleQueue>,
+    on_batch: &impl Fn(Vec<InternalFileEvent>),
+    known: impl Fn(&Path) -> bool,
+) -> Option<(DirSnapshot, usize)> {
+    let mut current = scan_snapshot(watch_dir, options, log_target, |_| false)?;
+    // Первый запуск: сообщать не о чем, просто запоминаем состояние.
+    let found = previous.map_or(0, |previous| {
+        report_diff(
+            previous,
+            &mut current,
+            watch_dir,
+            options,
+            log_target,
+            settle,
+            on_batch,
+            known,
+        )
+    });
+    Some((current, found))
+}
+
+/// Сообщает, чем `current` отличается от `previous` (см.
+/// [`reconcile_with_snapshot`]); файлы, ушедшие ждать стабилизации,
+/// убираются из `current`. Возвращает число расхождений.
+#[allow(clippy::too_many_arguments)]
+fn report_diff(
+    previous: &DirSnapshot,
+    current: &mut DirSnapshot,
+    watch_dir: &Path,
+    options: &WatcherOptions,
+    log_target: &str,
+    mut settle: Option<&mut SettleQueue>,
+    on_batch: &impl Fn(Vec<InternalFileEvent>),
+    known: impl Fn(&Path) -> bool,
+) -> usize {
+    let mut found = 0;
+    let diff = previous.diff(current);
+    log_event!(info, target: log_target,
+        added = diff.added.len(),
+        modified = diff.modified.len(),
+        removed = diff.removed.len(),
+        "Reconciled watch directory with snapshot"
+    );
+
+    let mut events = Vec::new();
+    let changed = diff
+        .added
+        .iter()
+        .map(|p| (p, FileEventKind::Created))
+        .chain(diff.modified.iter().map(|p| (p, FileEventKind::Modified)));
+    for (relative, kind) in changed {
+        let path = watch_dir.join(relative);
+        if known(&path) || settle.as_deref().is_some_and(|q| q.contains(&path)) {
+            continue;
+        }
+        let mut e = match make_internal_file_event(&path, options.timestamp_source, kind) {
+            Ok(e) => e,
+            Err(err) => {
+                log_event!(warn, target: log_target, error:% = err, "Cannot build InternalFileEvent");
+                continue;
+            }
+        };
+        e.reconciled = true;
+        if kind.is_arrival() {
+            if options
+                .max_file_age
+                .is_some_and(|age| is_older_than(&e, age))
+            {
+                continue;
+            }
+            if let Some(queue) = settle.as_deref_mut() {
+                queue.track(e, Instant::now());
+                current.remove(relative);
+                found += 1;
+                continue;
+            }
+        }
+        events.push(e);
+    }
+    for relative in &diff.removed {
+        let path = watch_dir.join(relative);
+        if known(&path) {
+            continue;
+        }
+        match make_file_gone_event(&path, FileEventKind::Removed) {
+            Ok(mut e) => {
+                e.reconciled = true;
+                events.push(e);
+            }
+            Err(err) => {
+                log_event!(warn, target: log_target, error:% = err, "Cannot build InternalFileEvent");
+            }
+        }
+    }
+
+    found += events.len();
+    while !events.is_empty() {
+        let rest = events.split_off(events.len().min(MAX_BATCH_SIZE));
+        on_batch(std::mem::replace(&mut events, rest));
+    }
+    found
+}
+
+/// Снимает текущее состояние папки; файлы, для которых `pending` вернул
+/// `true` (о них ещё не сообщено), в снимок не входят. `None` — папку не
+/// удалось прочитать.
+fn scan_snapshot(
+    watch_dir: &Path,
+    options: &WatcherOptions,
+    log_target: &str,
+    pending: impl Fn(&Path) -> bool,
+) -> Option<DirSnapshot> {
+    DirSnapshot::scan(watch_dir, options.recursive, |p| {
+        snapshot_includes(options, watch_dir, p) && !pending(p)
     })
+    .inspect_err(|e| {
+        log_event!(warn, target: log_target, error:% = e, "Cannot scan watch directory");
+    })
+    .ok()
+}
+
+/// Снимает и сохраняет текущее состояние папки (см. [`scan_snapshot`]).
+/// `false` — снимок не сохранён.
+fn save_snapshot(
+    snapshot_file: &Path,
+    watch_dir: &Path,
+    options: &WatcherOptions,
+    log_target: &str,
+    pending: impl Fn(&Path) -> bool,
+) -> bool {
+    let Some(snapshot) = scan_snapshot(watch_dir, options, log_target, pending) else {
+        return false;
+    };
+    if let Err(e) = snapshot.save(snapshot_file) {
+        log_event!(warn, target: log_target, error:% = e, "Cannot save directory snapshot");
+        return false;
+    }
+    true
 }
 
-fn is_create_file_event(kind: &EventKind) -> bool {
+/// Пишет сводки по подавленным предупреждениям.
+fn log_suppressed(log_target: &str, summaries: Vec<(&'static str, u64)>) {
+    for (key, suppressed) in summaries {
+        log_event!(warn, target: log_target, suppressed, key, "suppressed similar messages");
+    }
+}
+
+/// Вид события файла по событию notify. `None` — событие не интересно.
+///
+/// `last_path` нужен для `Name(Any)` (FSEvents на macOS): направление
+/// переименования определяется по тому, существует ли путь.
+fn classify_event(kind: &EventKind, last_path: Option<&PathBuf>) -> Option<FileEventKind> {
     match kind {
-        EventKind::Create(CreateKind::File) => true,
-        // Некоторые FS/драйверы могут отдавать CreateKind::Any.
-        EventKind::Create(CreateKind::Any) => true,
-        EventKind::Create(_) => true,
+        // Некоторые FS/драйверы отдают CreateKind::Any; папки отсеиваются позже.
+        EventKind::Create(_) => Some(FileEventKind::Created),
+        EventKind::Modify(ModifyKind::Data(_) | ModifyKind::Any) => Some(FileEventKind::Modified),
+        EventKind::Modify(ModifyKind::Name(RenameMode::From)) => Some(FileEventKind::RenamedFrom),
         // Файл, перемещённый в наблюдаемую папку с того же диска,
         // генерирует Modify(Name(To)) вместо Create на Windows.
-        EventKind::Modify(ModifyKind::Name(RenameMode::To)) => true,
-        EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => true,
-        _ => false,
+        EventKind::Modify(ModifyKind::Name(RenameMode::To | RenameMode::Both)) => {
+            Some(FileEventKind::RenamedTo)
+        }
+        EventKind::Modify(ModifyKind::Name(_)) => Some(if last_path.is_some_and(|p| p.exists()) {
+            FileEventKind::RenamedTo
+        } else {
+            FileEventKind::RenamedFrom
+        }),
+        EventKind::Remove(RemoveKind::File | RemoveKind::Any) => Some(FileEventKind::Removed),
+        _ => None,
     }
 }
 
-fn is_remove_file_event(kind: &EventKind) -> bool {
-    match kind {
-        EventKind::Remove(RemoveKind::File) => true,
-        EventKind::Remove(RemoveKind::Any) => true,
-        _ => false,
+/// Путь — обычный файл. Занятый антивирусом файл ждём, а если блокировка
+/// не снялась — всё равно считаем файлом: папки так не блокируются, а
+/// потерять событие хуже, чем сообщить его без размера.
+/// Задержка от события ФС до выдачи (см. [`crate::metrics`]). Найденное
+/// сверкой не учитывается: время события там — время изменения файла,
+/// возможно, давнее.
+fn observe_latency(events: &[InternalFileEvent]) {
+    let now = now_ms();
+    for e in events.iter().filter(|e| !e.reconciled) {
+        crate::metrics::observe_event_latency(now - e.occurred_at_ms);
     }
 }
 
 fn is_regular_file(path: &Path) -> bool {
-    match std::fs::metadata(path) {
+    match transient::metadata_with_retry(path) {
         Ok(m) => m.is_file(),
-        Err(_) => false,
+        Err(e) => AccessFailure::classify(&e) == AccessFailure::Locked,
     }
 }
 
-fn make_internal_file_event(path: &Path) -> Result<InternalFileEvent, LateraError> {
+pub(crate) fn make_internal_file_event(
+    path: &Path,
+    timestamp_source: TimestampSource,
+    kind: FileEventKind,
+) -> Result<InternalFileEvent, LateraError> {
     let file_name = path
         .file_name()
         .and_then(|s| s.to_str())
@@ -456,35 +1834,247 @@ fn make_internal_file_event(path: &Path) -> Result<InternalFileEvent, LateraErro
         .to_string();
 
     let full_path = path.to_path_buf();
-    let occurred_at_ms = now_ms();
+    let detected_at_ms = now_ms();
+    let metadata = transient::metadata_with_retry(path).ok();
+    let created_at_ms = metadata
+        .as_ref()
+        .and_then(|m| m.created().ok())
+        .and_then(system_time_ms);
+    let modified_at_ms = metadata
+        .as_ref()
+        .and_then(|m| m.modified().ok())
+        .and_then(system_time_ms);
+    let occurred_at_ms = match timestamp_source {
+        TimestampSource::Detected => detected_at_ms,
+        TimestampSource::FileModified => modified_at_ms.unwrap_or(detected_at_ms),
+    };
 
     Ok(InternalFileEvent {
+        kind,
         file_name,
         full_path,
+        previous_path: None,
         occurred_at_ms,
+        detected_at_ms,
+        monotonic_ms: monotonic_ms(),
+        created_at_ms,
+        modified_at_ms,
+        size_bytes: metadata.as_ref().map(std::fs::Metadata::len),
+        extension: file_type::extension_of(path),
+        mime_type: file_type::sniff_mime_type(path),
+        access: metadata
+            .as_ref()
+            .map(|m| file_metadata::access_info_from(path, m)),
+        self_generated: expected_changes::is_expected(path),
+        reconciled: false,
+        claim: None,
+        debug: None,
     })
 }
 
-fn make_internal_file_removed_event(path: &Path) -> Result<InternalFileRemovedEvent, LateraError> {
+/// mtime файла старше `max_age` относительно момента обнаружения.
+fn is_older_than(event: &InternalFileEvent, max_age: Duration) -> bool {
+    let max_age_ms = i64::try_from(max_age.as_millis()).unwrap_or(i64::MAX);
+    event
+        .modified_at_ms
+        .is_some_and(|modified| event.detected_at_ms.saturating_sub(modified) > max_age_ms)
+}
+
+/// Событие для файла, которого по пути уже нет (удалён или переименован).
+pub(crate) fn make_file_gone_event(
+    path: &Path,
+    kind: FileEventKind,
+) -> Result<InternalFileEvent, LateraError> {
     let file_name = path
         .file_name()
         .and_then(|s| s.to_str())
         .ok_or_else(|| LateraError::FileNameMissing(path.to_path_buf()))?
         .to_string();
 
-    let full_path = path.to_path_buf();
-    let occurred_at_ms = now_ms();
-
-    Ok(InternalFileRemovedEvent {
+    let detected_at_ms = now_ms();
+    Ok(InternalFileEvent {
+        kind,
         file_name,
-        full_path,
-        occurred_at_ms,
+        full_path: path.to_path_buf(),
+        previous_path: None,
+        occurred_at_ms: detected_at_ms,
+        detected_at_ms,
+        monotonic_ms: monotonic_ms(),
+        created_at_ms: None,
+        modified_at_ms: None,
+        size_bytes: None,
+        extension: file_type::extension_of(path),
+        mime_type: None,
+        access: None,
+        self_generated: expected_changes::is_expected(path),
+        reconciled: false,
+        claim: None,
+        debug: None,
     })
 }
 
-fn now_ms() -> i64 {
+/// Монотонное время с запуска процесса в миллисекундах.
+pub fn monotonic_ms() -> i64 {
+    i64::try_from(MONOTONIC_EPOCH.elapsed().as_millis()).unwrap_or(i64::MAX)
+}
+
+/// Время создания (birthtime) и mtime файла (Unix timestamp в миллисекундах).
+///
+/// birthtime доступен на Windows, macOS и Linux с `statx` (ядро 4.11+);
+/// на остальных платформах/ФС — `None`.
+pub fn file_times_ms(path: &Path) -> (Option<i64>, Option<i64>) {
+    let Ok(metadata) = std::fs::metadata(path) else {
+        return (None, None);
+    };
+    (
+        metadata.created().ok().and_then(system_time_ms),
+        metadata.modified().ok().and_then(system_time_ms),
+    )
+}
+
+fn system_time_ms(time: SystemTime) -> Option<i64> {
+    let since_epoch = time.duration_since(UNIX_EPOCH).ok()?;
+    i64::try_from(since_epoch.as_millis()).ok()
+}
+
+/// Текущее время UTC (Unix timestamp в миллисекундах).
+pub fn now_ms() -> i64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap_or(Duration::from_millis(0))
         .as_millis() as i64
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event_with_mtime(modified_at_ms: Option<i64>) -> InternalFileEvent {
+        InternalFileEvent {
+            kind: FileEventKind::Created,
+            file_name: "a.txt".to_string(),
+            full_path: PathBuf::from("/w/a.txt"),
+            previous_path: None,
+            occurred_at_ms: 1_000_000,
+            detected_at_ms: 1_000_000,
+            monotonic_ms: 0,
+            created_at_ms: None,
+            modified_at_ms,
+            size_bytes: None,
+            extension: None,
+            mime_type: None,
+            access: None,
+            self_generated: false,
+            reconciled: false,
+            claim: None,
+            debug: None,
+        }
+    }
+
+    #[test]
+    fn test_override_dir_must_not_overlap_core_dirs() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let core = temp_dir.path().join("core");
+        std::fs::create_dir(&core).unwrap();
+        register_core_dir(&core);
+
+        let as_arg = |p: &Path| p.to_string_lossy().to_string();
+        assert!(ensure_override_dir(&as_arg(temp_dir.path())).is_err());
+        assert!(ensure_override_dir(&as_arg(&core.join("sub"))).is_err());
+        assert!(ensure_override_dir(&as_arg(&temp_dir.path().join("inbox"))).is_ok());
+    }
+
+    #[test]
+    fn test_resolve_watch_dir_has_no_side_effects() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let inbox = temp_dir.path().join("inbox");
+
+        let resolved = resolve_watch_dir(Some(&inbox.to_string_lossy())).unwrap();
+        assert_eq!(resolved, inbox);
+        assert!(!inbox.exists());
+        assert!(resolve_watch_dir(Some("relative/inbox")).is_err());
+        assert!(resolve_watch_dir(Some("  ")).is_err());
+    }
+
+    #[test]
+    fn test_watch_limit_error_is_typed() {
+        let err = notify::Error::new(notify::ErrorKind::MaxFilesWatch);
+        assert!(is_watch_limit(&err));
+        let failure = watch_limit_failure(err);
+        assert_eq!(failure.code, WatcherErrorCode::WatchLimitExceeded);
+        assert!(failure
+            .message
+            .starts_with("LateraError::InotifyLimitExceeded"));
+
+        let other = LateraError::from(notify::Error::generic("boom"));
+        assert_eq!(other.code(), "NOTIFY_ERROR");
+        #[cfg(target_os = "linux")]
+        assert!(inotify_watch_limit().is_some_and(|limit| limit > 0));
+    }
+
+    #[test]
+    fn test_arrival_event_carries_access_info() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("scan.pdf");
+        std::fs::write(&path, b"%PDF").unwrap();
+
+        let event =
+            make_internal_file_event(&path, TimestampSource::default(), FileEventKind::Created)
+                .unwrap();
+        let access = event.access.unwrap();
+        assert!(access.readable && access.writable);
+        assert!(access.owner_id.is_some());
+
+        let gone = make_file_gone_event(&path, FileEventKind::Removed).unwrap();
+        assert!(gone.access.is_none());
+    }
+
+    #[test]
+    fn test_is_older_than_uses_mtime() {
+        let max_age = Duration::from_mins(1);
+        assert!(is_older_than(&event_with_mtime(Some(900_000)), max_age));
+        assert!(!is_older_than(&event_with_mtime(Some(950_000)), max_age));
+        // mtime в будущем (сбитые часы) и отсутствующий mtime — не старые
+        assert!(!is_older_than(&event_with_mtime(Some(2_000_000)), max_age));
+        assert!(!is_older_than(&event_with_mtime(None), max_age));
+    }
+
+    #[test]
+    fn test_classify_event_kinds() {
+        let path = PathBuf::from("/definitely/missing/file.txt");
+        assert_eq!(
+            classify_event(&EventKind::Create(notify::event::CreateKind::File), None),
+            Some(FileEventKind::Created)
+        );
+        assert_eq!(
+            classify_event(
+                &EventKind::Modify(ModifyKind::Data(notify::event::DataChange::Content)),
+                None
+            ),
+            Some(FileEventKind::Modified)
+        );
+        assert_eq!(
+            classify_event(&EventKind::Modify(ModifyKind::Name(RenameMode::Both)), None),
+            Some(FileEventKind::RenamedTo)
+        );
+        // Name(Any) по несуществующему пути — файл ушёл
+        assert_eq!(
+            classify_event(
+                &EventKind::Modify(ModifyKind::Name(RenameMode::Any)),
+                Some(&path)
+            ),
+            Some(FileEventKind::RenamedFrom)
+        );
+        assert_eq!(
+            classify_event(&EventKind::Remove(RemoveKind::Any), None),
+            Some(FileEventKind::Removed)
+        );
+        assert_eq!(
+            classify_event(
+                &EventKind::Modify(ModifyKind::Metadata(notify::event::MetadataKind::Any)),
+                None
+            ),
+            None
+        );
+    }
+}
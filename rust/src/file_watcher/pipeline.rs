@@ -0,0 +1,410 @@
+//! Путь события `notify` от backend'а до подписчика, по стадиям:
+//! фильтр → стабилизация → дедупликация и rate-limit → выдача.
+//!
+//! Цикл watcher'а ([`super::start_watcher_with_batches`]) ждёт событий и
+//! таймеров и управляет backend'ом, а что и когда сообщить подписчику,
+//! решает [`EventPipeline`]. Каждая стадия — отдельный метод с одним
+//! решением на событие.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use notify::event::{ModifyKind, RenameMode};
+use notify::EventKind;
+
+use super::batch::EventBatch;
+use super::debug_trail::DebugTrails;
+use super::gate::{Dedup, RateLimit};
+use super::metrics::EventMetrics;
+use super::settle::SettleQueue;
+use super::tree_stats::TreeStats;
+use super::{
+    classify_event, is_older_than, is_regular_file, make_file_gone_event, make_internal_file_event,
+    now_ms, FileEventKind, InternalFileEvent, WatcherOptions, RATE_LIMIT_WARNING,
+    SETTLE_CHECK_INTERVAL,
+};
+use crate::internal_files;
+use crate::log_event;
+use crate::logging::LogThrottle;
+
+/// Состояние стадий и получатели событий одного watcher'а.
+pub(super) struct EventPipeline<'a, E, B> {
+    watch_dir: &'a Path,
+    options: &'a WatcherOptions,
+    log_target: &'a str,
+    metrics: &'a EventMetrics,
+    tree_stats: &'a TreeStats,
+    on_event: E,
+    pub(super) on_batch: B,
+    dedup: Dedup,
+    rate_limit: RateLimit,
+    /// Ограничение повторяющихся предупреждений (bulk copy не должен заливать лог).
+    pub(super) log_throttle: LogThrottle,
+    /// События сверх rate-limit.
+    pub(super) overflow: EventBatch,
+    /// Появившиеся файлы, ждущие стабилизации.
+    pub(super) settle: Option<SettleQueue>,
+    last_settle_check: Instant,
+    debug_trails: Option<DebugTrails>,
+    /// Пути, о которых сообщено после снимка папки (или состояния в
+    /// памяти): сверка по запросу о них не сообщает.
+    pub(super) touched: HashSet<PathBuf>,
+    /// Папка менялась после последнего сохранения снимка.
+    pub(super) snapshot_dirty: bool,
+}
+
+impl<'a, E, B> EventPipeline<'a, E, B>
+where
+    E: Fn(InternalFileEvent),
+    B: Fn(Vec<InternalFileEvent>),
+{
+    pub(super) fn new(
+        watch_dir: &'a Path,
+        options: &'a WatcherOptions,
+        log_target: &'a str,
+        metrics: &'a EventMetrics,
+        tree_stats: &'a TreeStats,
+        on_event: E,
+        on_batch: B,
+    ) -> Self {
+        Self {
+            watch_dir,
+            options,
+            log_target,
+            metrics,
+            tree_stats,
+            on_event,
+            on_batch,
+            dedup: Dedup::new(options.dedup_window),
+            rate_limit: RateLimit::new(options.rate_limit_per_second),
+            log_throttle: LogThrottle::default(),
+            overflow: EventBatch::default(),
+            settle: options.settle_quiet_period.map(SettleQueue::new),
+            last_settle_check: Instant::now(),
+            debug_trails: options.debug_enrichment.then(DebugTrails::default),
+            touched: HashSet::new(),
+            snapshot_dirty: false,
+        }
+    }
+
+    /// Есть ли события, выдачи которых цикл должен дождаться по таймеру.
+    pub(super) fn has_pending(&self) -> bool {
+        self.settle.as_ref().is_some_and(|q| !q.is_empty()) || !self.overflow.is_empty()
+    }
+
+    /// Ещё не сообщён: ждёт стабилизации или лежит в пачке.
+    pub(super) fn is_pending(&self, path: &Path) -> bool {
+        self.settle.as_ref().is_some_and(|q| q.contains(path)) || self.overflow.contains(path)
+    }
+
+    /// Снимок сохранён: из сообщённых путей остаются только ещё не выданные.
+    pub(super) fn retain_pending_touched(&mut self) {
+        let mut touched = std::mem::take(&mut self.touched);
+        touched.retain(|p| self.is_pending(p));
+        self.touched = touched;
+    }
+
+    /// Провести событие `notify` через все стадии.
+    pub(super) fn process(&mut self, event: notify::Event) {
+        let Some(event) = self.filter(event) else {
+            return;
+        };
+        let Some(kind) = classify_event(&event.kind, event.paths.last()) else {
+            return;
+        };
+
+        // Исчезновение файла: метаданных уже нет, дедуп и rate-limit не нужны.
+        if kind.is_departure() {
+            for path in &event.paths {
+                self.depart(path, kind);
+            }
+            return;
+        }
+
+        // Переименование внутри папки: notify отдаёт [from, to] одним событием.
+        let (paths, previous_path) = match (&event.kind, event.paths.as_slice()) {
+            (EventKind::Modify(ModifyKind::Name(RenameMode::Both)), [from, to]) => {
+                (vec![to.clone()], Some(from.clone()))
+            }
+            _ => (event.paths, None),
+        };
+        for path in paths {
+            let Some(e) = self.build(&path, kind, previous_path.as_ref()) else {
+                continue;
+            };
+            if let Some(e) = self.settle(e, kind) {
+                self.admit(e, kind);
+            }
+        }
+    }
+
+    /// Стадия фильтра: служебные файлы ядра и отфильтрованные файлы
+    /// подписчику не показываются. `None` — не осталось ни одного пути.
+    fn filter(&mut self, mut event: notify::Event) -> Option<notify::Event> {
+        let (watch_dir, options) = (self.watch_dir, self.options);
+        let reported =
+            |p: &PathBuf| !internal_files::is_internal(p) && options.filter.matches(watch_dir, p);
+        // Переименование в отфильтрованное имя — для подписчика файл ушёл.
+        if let (EventKind::Modify(ModifyKind::Name(RenameMode::Both)), [from, to]) =
+            (&event.kind, event.paths.as_slice())
+        {
+            if reported(from) && !reported(to) {
+                event.kind = EventKind::Modify(ModifyKind::Name(RenameMode::From));
+            }
+        }
+        event.paths.retain(reported);
+        if event.paths.is_empty() {
+            return None;
+        }
+        if let Some(trails) = self.debug_trails.as_mut() {
+            trails.observe(&event.kind, &event.paths);
+        }
+        self.snapshot_dirty = true;
+        self.touched.extend(event.paths.iter().cloned());
+
+        let event_ms = now_ms();
+        for path in &event.paths {
+            self.tree_stats.record(watch_dir, path, event_ms);
+        }
+        Some(event)
+    }
+
+    /// Исчезновение файла. Исчез, не успев «осесть», — о появлении не
+    /// сообщали, и об исчезновении тоже не сообщаем.
+    fn depart(&mut self, path: &Path, kind: FileEventKind) {
+        if self.settle.as_mut().is_some_and(|q| q.forget(path)) {
+            log_event!(debug, target: self.log_target,
+                path:% = path.display(),
+                "File gone before settling"
+            );
+            if let Some(trails) = self.debug_trails.as_mut() {
+                trails.decide(path, "gone before settling, not reported");
+            }
+            return;
+        }
+        match make_file_gone_event(path, kind) {
+            Ok(e) => {
+                log_event!(info, target: self.log_target,
+                    kind:? = e.kind,
+                    path:% = e.full_path.display(),
+                    "File gone"
+                );
+                self.emit(e);
+            }
+            Err(err) => {
+                log_event!(warn, target: self.log_target, error:% = err, "Cannot build InternalFileEvent");
+            }
+        }
+    }
+
+    /// Событие с метаданными файла; появившиеся файлы старше
+    /// [`WatcherOptions::max_file_age`] отбрасываются.
+    fn build(
+        &mut self,
+        path: &Path,
+        kind: FileEventKind,
+        previous_path: Option<&PathBuf>,
+    ) -> Option<InternalFileEvent> {
+        if !is_regular_file(path) {
+            return None;
+        }
+        let built_at = Instant::now();
+        let mut e = match make_internal_file_event(path, self.options.timestamp_source, kind) {
+            Ok(e) => e,
+            Err(err) => {
+                log_event!(warn, target: self.log_target, error:% = err, "Cannot build InternalFileEvent");
+                return None;
+            }
+        };
+        e.previous_path = previous_path.cloned();
+        if let Some(trails) = self.debug_trails.as_mut() {
+            trails.metadata_took(path, built_at.elapsed());
+        }
+
+        if let Some(max_age) = self.options.max_file_age {
+            if kind.is_arrival() && is_older_than(&e, max_age) {
+                log_event!(debug, target: self.log_target,
+                    path:% = e.full_path.display(),
+                    "skipping file older than max age"
+                );
+                if let Some(trails) = self.debug_trails.as_mut() {
+                    trails.decide(path, "older than max age, skipped");
+                }
+                return None;
+            }
+        }
+        Some(e)
+    }
+
+    /// Стадия стабилизации: появление придерживается до «оседания»,
+    /// изменения недописанного файла не сообщаются. `None` — событие
+    /// осталось в очереди или отброшено.
+    fn settle(&mut self, e: InternalFileEvent, kind: FileEventKind) -> Option<InternalFileEvent> {
+        let Some(queue) = self.settle.as_mut() else {
+            return Some(e);
+        };
+        if kind.is_arrival() {
+            if let Some(trails) = self.debug_trails.as_mut() {
+                trails.decide(&e.full_path, "held until settled");
+            }
+            queue.track(e, Instant::now());
+            return None;
+        }
+        if queue.contains(&e.full_path) {
+            if let Some(trails) = self.debug_trails.as_mut() {
+                trails.decide(
+                    &e.full_path,
+                    format!("{kind:?} suppressed, file not settled"),
+                );
+            }
+            return None;
+        }
+        Some(e)
+    }
+
+    /// Стадия дедупликации (по виду события и полному пути, окно
+    /// `dedup_window`) и rate-limit: избыток откладывается в пачку.
+    fn admit(&mut self, e: InternalFileEvent, kind: FileEventKind) {
+        let now = Instant::now();
+        if let Err(since) = self.dedup.admit(kind, &e.full_path, now) {
+            self.metrics.record_deduped();
+            log_event!(debug, target: self.log_target,
+                path:% = e.full_path.display(),
+                "dedup: skipping duplicate event"
+            );
+            if let Some(trails) = self.debug_trails.as_mut() {
+                trails.decide(
+                    &e.full_path,
+                    format!(
+                        "dedup: {kind:?} dropped, {} ms after previous",
+                        since.as_millis()
+                    ),
+                );
+            }
+            return;
+        }
+
+        if self.rate_limit.admit(now) {
+            self.emit(e);
+            return;
+        }
+        if let Some(trails) = self.debug_trails.as_mut() {
+            trails.decide(&e.full_path, "rate limit: deferred to batch");
+        }
+        if self.log_throttle.allow(RATE_LIMIT_WARNING, now) {
+            log_event!(warn, target: self.log_target,
+                events_per_sec = self.rate_limit.count(),
+                path:% = e.full_path.display(),
+                "rate limit exceeded, batching events"
+            );
+        }
+        self.overflow.push(e, now);
+    }
+
+    /// Стадия выдачи: событие с отладочным контекстом — подписчику.
+    fn emit(&mut self, mut e: InternalFileEvent) {
+        if let Some(trails) = self.debug_trails.as_mut() {
+            trails.attach(&mut e);
+        }
+        (self.on_event)(e);
+    }
+
+    /// Провести «осевшие» файлы дальше, через дедупликацию и rate-limit
+    /// (проверка не чаще [`SETTLE_CHECK_INTERVAL`]).
+    pub(super) fn release_settled(&mut self) {
+        let Some(queue) = self.settle.as_mut() else {
+            return;
+        };
+        if queue.is_empty() || self.last_settle_check.elapsed() < SETTLE_CHECK_INTERVAL {
+            return;
+        }
+        self.last_settle_check = Instant::now();
+        for e in queue.take_settled(self.last_settle_check) {
+            log_event!(debug, target: self.log_target,
+                path:% = e.full_path.display(),
+                size_bytes = e.size_bytes.unwrap_or(0),
+                "File settled"
+            );
+            self.touched.insert(e.full_path.clone());
+            self.snapshot_dirty = true;
+            let kind = e.kind;
+            self.admit(e, kind);
+        }
+    }
+
+    /// Выдать пачку, если подошёл срок.
+    pub(super) fn flush_due_batch(&mut self) {
+        if self.overflow.is_due(Instant::now()) {
+            let events = self.overflow.take();
+            log_event!(info, target: self.log_target, count = events.len(), "Delivering event batch");
+            self.deliver_batch(events);
+        }
+    }
+
+    /// Выдать всё накопленное в пачке (при остановке — чтобы не потерять).
+    pub(super) fn flush_batch(&mut self) {
+        if !self.overflow.is_empty() {
+            let events = self.overflow.take();
+            self.deliver_batch(events);
+        }
+    }
+
+    fn deliver_batch(&mut self, mut events: Vec<InternalFileEvent>) {
+        if let Some(trails) = self.debug_trails.as_mut() {
+            for e in &mut events {
+                trails.attach(e);
+            }
+        }
+        (self.on_batch)(events);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::time::Duration;
+
+    use notify::event::CreateKind;
+
+    use super::*;
+
+    #[test]
+    fn test_settled_files_are_rate_limited() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let options = WatcherOptions {
+            settle_quiet_period: Some(Duration::from_millis(20)),
+            rate_limit_per_second: 2,
+            ..WatcherOptions::default()
+        };
+        let (metrics, tree_stats) = (EventMetrics::default(), TreeStats::default());
+        let emitted = RefCell::new(Vec::new());
+        let batched = RefCell::new(Vec::new());
+        let mut pipeline = EventPipeline::new(
+            temp_dir.path(),
+            &options,
+            "test",
+            &metrics,
+            &tree_stats,
+            |e| emitted.borrow_mut().push(e),
+            |events| batched.borrow_mut().extend(events),
+        );
+
+        for i in 0..5 {
+            let path = temp_dir.path().join(format!("scan{i}.pdf"));
+            std::fs::write(&path, b"%PDF").unwrap();
+            pipeline
+                .process(notify::Event::new(EventKind::Create(CreateKind::File)).add_path(path));
+        }
+        assert!(emitted.borrow().is_empty(), "arrivals must wait to settle");
+
+        std::thread::sleep(SETTLE_CHECK_INTERVAL + Duration::from_millis(50));
+        pipeline.release_settled();
+        assert_eq!(emitted.borrow().len(), 2);
+        assert!(pipeline.has_pending());
+
+        pipeline.flush_batch();
+        assert_eq!(batched.borrow().len(), 3);
+        assert!(!pipeline.has_pending());
+    }
+}
@@ -0,0 +1,114 @@
+//! Перезапуск backend'а `notify` после фатальной ошибки и возврат
+//! пропавшей папки наблюдения.
+//!
+//! Задержка между попытками растёт экспоненциально: `initial_delay`,
+//! `2 × initial_delay`, ... до `max_delay`. Счётчик попыток сбрасывается,
+//! когда перезапущенный backend проработал [`RESTART_RESET_PERIOD`].
+
+use std::time::Duration;
+
+/// Сколько backend должен проработать без сбоев, чтобы backoff начался заново.
+pub const RESTART_RESET_PERIOD: Duration = Duration::from_mins(1);
+
+/// Как перезапускать watcher после фатальной ошибки backend'а.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RestartPolicy {
+    /// Задержка перед первой попыткой.
+    pub initial_delay: Duration,
+    /// Верхняя граница задержки.
+    pub max_delay: Duration,
+    /// Сколько попыток подряд, прежде чем сдаться (`None` — без ограничения).
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_mins(1),
+            max_attempts: None,
+        }
+    }
+}
+
+/// Что делать, если папка наблюдения пропала (удалена, переименована,
+/// отключён диск).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WatchDirRecovery {
+    /// Завершиться с [`WatcherErrorCode::WatchDirLost`](super::WatcherErrorCode::WatchDirLost).
+    Stop,
+    /// Ждать, пока папка появится снова (диск подключат обратно), и
+    /// продолжить наблюдение.
+    #[default]
+    WaitForReturn,
+    /// Создать папку заново и продолжить; пока это невозможно (нет и
+    /// родительской папки) — ждать, как [`Self::WaitForReturn`]. В режиме
+    /// «только наблюдение» папка не создаётся.
+    Recreate,
+}
+
+/// Счётчик попыток перезапуска по [`RestartPolicy`].
+#[derive(Debug)]
+pub(super) struct Backoff {
+    policy: RestartPolicy,
+    attempt: u32,
+}
+
+impl Backoff {
+    pub fn new(policy: RestartPolicy) -> Self {
+        Self { policy, attempt: 0 }
+    }
+
+    /// Номер следующей попытки и задержка перед ней; `None` — попытки исчерпаны.
+    pub fn next_attempt(&mut self) -> Option<(u32, Duration)> {
+        if self
+            .policy
+            .max_attempts
+            .is_some_and(|max| self.attempt >= max)
+        {
+            return None;
+        }
+        let delay = self
+            .policy
+            .initial_delay
+            .saturating_mul(2_u32.saturating_pow(self.attempt.min(31)))
+            .min(self.policy.max_delay);
+        self.attempt += 1;
+        Some((self.attempt, delay))
+    }
+
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_up_to_limit() {
+        let mut backoff = Backoff::new(RestartPolicy {
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(500),
+            max_attempts: Some(5),
+        });
+        let delays: Vec<_> = std::iter::from_fn(|| backoff.next_attempt()).collect();
+        assert_eq!(
+            delays,
+            vec![
+                (1, Duration::from_millis(100)),
+                (2, Duration::from_millis(200)),
+                (3, Duration::from_millis(400)),
+                (4, Duration::from_millis(500)),
+                (5, Duration::from_millis(500)),
+            ]
+        );
+
+        backoff.reset();
+        assert_eq!(
+            backoff.next_attempt(),
+            Some((1, Duration::from_millis(100)))
+        );
+    }
+}
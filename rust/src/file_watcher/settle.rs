@@ -0,0 +1,176 @@
+//! Ожидание, пока появившийся файл «осядет».
+//!
+//! Большой файл, копируемый в папку, даёт Create ещё недописанным. Пока
+//! включена стабилизация, события появления придерживаются и отдаются
+//! только когда размер и mtime файла не менялись `quiet_period`.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use super::events::InternalFileEvent;
+use super::system_time_ms;
+use super::transient::AccessFailure;
+use crate::file_type;
+
+/// Придержанное событие и последнее наблюдавшееся состояние файла.
+struct Pending {
+    event: InternalFileEvent,
+    state: (u64, Option<SystemTime>),
+    changed_at: Instant,
+}
+
+/// Очередь событий появления, ждущих стабилизации файла.
+pub(crate) struct SettleQueue {
+    quiet_period: Duration,
+    pending: HashMap<PathBuf, Pending>,
+}
+
+/// Размер и mtime файла. `Err` — прочитать не удалось: файл исчез или
+/// временно занят (см. [`AccessFailure`]).
+fn file_state(path: &Path) -> Result<(u64, Option<SystemTime>), AccessFailure> {
+    let metadata = std::fs::metadata(path).map_err(|e| AccessFailure::classify(&e))?;
+    if metadata.is_file() {
+        Ok((metadata.len(), metadata.modified().ok()))
+    } else {
+        Err(AccessFailure::Missing)
+    }
+}
+
+impl SettleQueue {
+    pub(crate) fn new(quiet_period: Duration) -> Self {
+        Self {
+            quiet_period,
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Придержать событие появления файла до его стабилизации.
+    ///
+    /// Повторное появление того же пути заменяет событие и сбрасывает ожидание.
+    pub(crate) fn track(&mut self, event: InternalFileEvent, now: Instant) {
+        let state = file_state(&event.full_path).unwrap_or((0, None));
+        self.pending.insert(
+            event.full_path.clone(),
+            Pending {
+                event,
+                state,
+                changed_at: now,
+            },
+        );
+    }
+
+    /// Файл ещё ждёт стабилизации (его изменения отдельно не сообщаются).
+    pub(crate) fn contains(&self, path: &Path) -> bool {
+        self.pending.contains_key(path)
+    }
+
+    /// Файл исчез до стабилизации. Возвращает `true`, если он ждал.
+    pub(crate) fn forget(&mut self, path: &Path) -> bool {
+        self.pending.remove(path).is_some()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Забирает события файлов, которые не менялись `quiet_period`.
+    ///
+    /// Размер и mtime в событиях — итоговые. Исчезнувшие файлы
+    /// отбрасываются молча, занятые (антивирусом) — ждут дальше.
+    pub(crate) fn take_settled(&mut self, now: Instant) -> Vec<InternalFileEvent> {
+        let quiet_period = self.quiet_period;
+        let mut settled = Vec::new();
+        self.pending.retain(|path, pending| {
+            let state = match file_state(path) {
+                Ok(state) => state,
+                // Антивирус проверяет файл — ждём следующей проверки
+                Err(AccessFailure::Locked) => return true,
+                Err(_) => return false,
+            };
+            if state != pending.state {
+                pending.state = state;
+                pending.changed_at = now;
+                return true;
+            }
+            if now.duration_since(pending.changed_at) < quiet_period {
+                return true;
+            }
+            let mut event = pending.event.clone();
+            event.size_bytes = Some(state.0);
+            event.modified_at_ms = state.1.and_then(system_time_ms);
+            // При появлении файл мог быть ещё недописан
+            event.mime_type = file_type::sniff_mime_type(path);
+            settled.push(event);
+            false
+        });
+        settled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file_watcher::FileEventKind;
+
+    fn arrival(path: &Path) -> InternalFileEvent {
+        InternalFileEvent {
+            kind: FileEventKind::Created,
+            file_name: "a.bin".to_string(),
+            full_path: path.to_path_buf(),
+            previous_path: None,
+            occurred_at_ms: 0,
+            detected_at_ms: 0,
+            monotonic_ms: 0,
+            created_at_ms: None,
+            modified_at_ms: None,
+            size_bytes: Some(0),
+            extension: None,
+            mime_type: None,
+            access: None,
+            self_generated: false,
+            reconciled: false,
+            claim: None,
+            debug: None,
+        }
+    }
+
+    #[test]
+    fn test_event_released_after_quiet_period_with_final_size() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("a.bin");
+        std::fs::write(&path, b"ab").unwrap();
+
+        let quiet = Duration::from_secs(1);
+        let start = Instant::now();
+        let mut queue = SettleQueue::new(quiet);
+        queue.track(arrival(&path), start);
+
+        // Файл дописан — ожидание начинается заново
+        std::fs::write(&path, b"abcd").unwrap();
+        assert!(queue.take_settled(start + quiet).is_empty());
+        assert!(queue.take_settled(start + quiet + quiet / 2).is_empty());
+
+        let settled = queue.take_settled(start + quiet * 2);
+        assert_eq!(settled.len(), 1);
+        assert_eq!(settled[0].size_bytes, Some(4));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_vanished_file_is_dropped() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("a.bin");
+        std::fs::write(&path, b"ab").unwrap();
+
+        let start = Instant::now();
+        let mut queue = SettleQueue::new(Duration::from_millis(10));
+        queue.track(arrival(&path), start);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(queue
+            .take_settled(start + Duration::from_secs(1))
+            .is_empty());
+        assert!(queue.is_empty());
+    }
+}
@@ -0,0 +1,159 @@
+//! Состояние watcher'а: переходы сообщаются подписчику, текущее значение
+//! читается через [`WatcherHandle::state`](super::WatcherHandle::state).
+
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use super::WatchMode;
+
+/// Сколько времени без ошибок `notify` нужно, чтобы выйти из [`WatcherState::Degraded`].
+pub const DEGRADED_RECOVERY_PERIOD: Duration = Duration::from_secs(30);
+
+/// Почему watcher перестал работать.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WatcherErrorCode {
+    /// Не удалось создать backend `notify`.
+    BackendInitFailed,
+    /// Backend не смог начать наблюдение за папкой.
+    WatchFailed,
+    /// Backend перестал присылать события (канал закрыт).
+    ChannelDisconnected,
+    /// Backend сообщил о фатальной ошибке.
+    BackendFailed,
+    /// Наблюдаемая папка исчезла (удалена, переименована, диск отключён).
+    WatchDirLost,
+    /// Исчерпан лимит inotify (`fs.inotify.max_user_watches`), а перейти
+    /// на опрос нельзя (лестница деградации выключена).
+    WatchLimitExceeded,
+}
+
+/// Состояние watcher'а.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WatcherState {
+    /// Тред запущен, backend ещё создаётся.
+    Starting,
+    /// События поступают.
+    Running,
+    /// Backend сообщает об ошибках — часть событий может теряться.
+    Degraded { reason: String },
+    /// Нативный backend не работает; изменения получаются способом `mode`
+    /// (см. [`DegradationPolicy`](super::DegradationPolicy)).
+    Fallback { mode: WatchMode, reason: String },
+    /// Backend упал; попытка перезапуска `attempt` через `retry_in`.
+    Restarting {
+        attempt: u32,
+        retry_in: Duration,
+        reason: String,
+    },
+    /// Папка наблюдения пропала; watcher ждёт её возвращения
+    /// (см. [`WatchDirRecovery`](super::WatchDirRecovery)).
+    DirLost { path: PathBuf },
+    /// Остановлен по запросу.
+    Stopped,
+    /// Тред завершился из-за ошибки (перезапуск выключен или не удался);
+    /// событий больше не будет.
+    Error {
+        code: WatcherErrorCode,
+        message: String,
+    },
+}
+
+impl WatcherState {
+    /// Watcher больше не присылает события.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Stopped | Self::Error { .. })
+    }
+}
+
+/// Подписчик на смену состояния. Вызывается на треде watcher'а.
+#[derive(Clone)]
+pub struct StatusListener(Arc<dyn Fn(&WatcherState) + Send + Sync>);
+
+impl StatusListener {
+    pub fn new(on_change: impl Fn(&WatcherState) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(on_change))
+    }
+}
+
+impl fmt::Debug for StatusListener {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("StatusListener")
+    }
+}
+
+/// Текущее состояние, общее для треда watcher'а и handle.
+#[derive(Clone)]
+pub(super) struct StatusCell {
+    state: Arc<Mutex<WatcherState>>,
+    listener: Option<StatusListener>,
+}
+
+impl StatusCell {
+    /// Ячейка в состоянии [`WatcherState::Starting`]; подписчик узнаёт о нём сразу.
+    pub fn new(listener: Option<StatusListener>) -> Self {
+        let cell = Self {
+            state: Arc::new(Mutex::new(WatcherState::Starting)),
+            listener,
+        };
+        cell.notify(&WatcherState::Starting);
+        cell
+    }
+
+    pub fn get(&self) -> WatcherState {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Меняет состояние; подписчик вызывается, только если оно изменилось.
+    pub fn set(&self, state: WatcherState) {
+        {
+            let mut current = self
+                .state
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            if *current == state {
+                return;
+            }
+            current.clone_from(&state);
+        }
+        self.notify(&state);
+    }
+
+    fn notify(&self, state: &WatcherState) {
+        if let Some(listener) = &self.listener {
+            (listener.0)(state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_listener_sees_only_transitions() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        let cell = StatusCell::new(Some(StatusListener::new(move |state| {
+            sink.lock().unwrap().push(state.clone());
+        })));
+        cell.set(WatcherState::Running);
+        cell.set(WatcherState::Running);
+        cell.set(WatcherState::Stopped);
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![
+                WatcherState::Starting,
+                WatcherState::Running,
+                WatcherState::Stopped
+            ]
+        );
+        assert_eq!(cell.get(), WatcherState::Stopped);
+        assert!(cell.get().is_terminal());
+    }
+}
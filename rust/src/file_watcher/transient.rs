@@ -0,0 +1,169 @@
+//! Временные блокировки файлов антивирусом.
+//!
+//! Антивирус (Defender, корпоративные EDR) открывает только что появившийся
+//! файл эксклюзивно на время проверки, а иногда переносит его в карантин.
+//! Чтение метаданных в этот момент падает с sharing violation — без
+//! повтора такое событие терялось бы. Здесь ошибки доступа
+//! классифицируются ([`AccessFailure`]) и временные — повторяются с паузой.
+
+use std::fs::Metadata;
+use std::io;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use log::{debug, warn};
+
+use crate::telemetry::{self, CounterKind};
+
+/// Паузы между повторами при временной блокировке (в сумме < 1 с,
+/// чтобы не задерживать тред watcher'а надолго).
+pub const LOCK_RETRY_DELAYS: [Duration; 4] = [
+    Duration::from_millis(50),
+    Duration::from_millis(100),
+    Duration::from_millis(250),
+    Duration::from_millis(500),
+];
+
+/// Почему не удалось прочитать файл.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccessFailure {
+    /// Файла нет (удалён до чтения).
+    Missing,
+    /// Файл временно занят (sharing/lock violation) — стоит повторить.
+    Locked,
+    /// Антивирус сообщил о заражении или удалил файл.
+    Quarantined,
+    /// Любая другая ошибка.
+    Other,
+}
+
+impl AccessFailure {
+    pub fn classify(error: &io::Error) -> Self {
+        if error.kind() == io::ErrorKind::NotFound {
+            return Self::Missing;
+        }
+        match error.raw_os_error() {
+            // ERROR_ACCESS_DENIED, ERROR_SHARING_VIOLATION, ERROR_LOCK_VIOLATION:
+            // Defender на время проверки открывает новый файл без общего доступа
+            #[cfg(windows)]
+            Some(5 | 32 | 33) => Self::Locked,
+            // ERROR_VIRUS_INFECTED, ERROR_VIRUS_DELETED
+            #[cfg(windows)]
+            Some(225 | 226) => Self::Quarantined,
+            #[cfg(unix)]
+            Some(code) if code == libc::EBUSY || code == libc::ETXTBSY => Self::Locked,
+            _ => Self::Other,
+        }
+    }
+
+    /// Имя для логов и счётчиков телеметрии.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Missing => "file_missing",
+            Self::Locked => "file_locked",
+            Self::Quarantined => "file_quarantined",
+            Self::Other => "file_access_error",
+        }
+    }
+}
+
+/// Выполняет `op`, повторяя после пауз `delays`, пока файл временно занят.
+///
+/// Восстановление после блокировки и окончательные отказы из-за
+/// блокировки или карантина учитываются в телеметрии отдельными счётчиками.
+pub fn retry_locked<T>(
+    path: &Path,
+    delays: &[Duration],
+    mut op: impl FnMut() -> io::Result<T>,
+) -> io::Result<T> {
+    let mut attempt = 0;
+    loop {
+        let error = match op() {
+            Ok(value) => {
+                if attempt > 0 {
+                    debug!("File unlocked after {attempt} retries: {}", path.display());
+                    telemetry::record(CounterKind::Event, "file_lock_recovered");
+                }
+                return Ok(value);
+            }
+            Err(e) => e,
+        };
+        let failure = AccessFailure::classify(&error);
+        match (failure, delays.get(attempt)) {
+            (AccessFailure::Locked, Some(delay)) => {
+                debug!(
+                    "File is locked (likely antivirus scan), retrying in {delay:?}: {}",
+                    path.display()
+                );
+                thread::sleep(*delay);
+                attempt += 1;
+            }
+            (AccessFailure::Locked | AccessFailure::Quarantined, _) => {
+                warn!(
+                    "{} after {attempt} retries: {}: {error}",
+                    failure.as_str(),
+                    path.display()
+                );
+                telemetry::record(CounterKind::Event, failure.as_str());
+                return Err(error);
+            }
+            _ => return Err(error),
+        }
+    }
+}
+
+/// Метаданные файла с повтором при временной блокировке.
+pub fn metadata_with_retry(path: &Path) -> io::Result<Metadata> {
+    retry_locked(path, &LOCK_RETRY_DELAYS, || std::fs::metadata(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    const LOCKED: i32 = libc::EBUSY;
+    #[cfg(windows)]
+    const LOCKED: i32 = 32;
+
+    #[test]
+    fn test_locked_file_is_retried_until_released() {
+        let locked = || io::Error::from_raw_os_error(LOCKED);
+        assert_eq!(AccessFailure::classify(&locked()), AccessFailure::Locked);
+        assert_eq!(
+            AccessFailure::classify(&io::Error::from(io::ErrorKind::NotFound)),
+            AccessFailure::Missing
+        );
+
+        let delays = [Duration::ZERO; 3];
+        let mut calls = 0;
+        let result = retry_locked(Path::new("a.pdf"), &delays, || {
+            calls += 1;
+            if calls < 3 {
+                Err(locked())
+            } else {
+                Ok(calls)
+            }
+        });
+        assert_eq!(result.unwrap(), 3);
+
+        // Попытки кончились — ошибка блокировки возвращается
+        calls = 0;
+        let result: io::Result<()> = retry_locked(Path::new("a.pdf"), &delays, || {
+            calls += 1;
+            Err(locked())
+        });
+        assert!(result.is_err());
+        assert_eq!(calls, 4);
+
+        // Прочие ошибки не повторяются
+        calls = 0;
+        let result: io::Result<()> = retry_locked(Path::new("a.pdf"), &delays, || {
+            calls += 1;
+            Err(io::Error::from(io::ErrorKind::NotFound))
+        });
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+    }
+}
@@ -0,0 +1,91 @@
+//! Статистика активности по подпапкам наблюдаемой директории.
+//!
+//! В рекурсивном режиме показывает, какие подпапки «шумят», чтобы
+//! пользователь мог закрепить или исключить их.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Счётчики одной подпапки.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubdirStats {
+    /// Путь подпапки относительно watch dir (`""` — сама watch dir).
+    pub relative_path: PathBuf,
+    /// Сколько событий ФС пришло из подпапки.
+    pub event_count: u64,
+    /// Время последнего события (Unix timestamp в миллисекундах).
+    pub last_activity_ms: i64,
+}
+
+/// Общая (между тредом watcher'а и API) статистика по подпапкам.
+#[derive(Clone, Debug, Default)]
+pub struct TreeStats {
+    inner: Arc<Mutex<HashMap<PathBuf, SubdirStats>>>,
+}
+
+impl TreeStats {
+    /// Учесть событие для `path` (файл или папка внутри `watch_dir`).
+    ///
+    /// Событие относится к папке, в которой лежит `path`.
+    pub fn record(&self, watch_dir: &Path, path: &Path, now_ms: i64) {
+        let Some(relative) = path
+            .parent()
+            .and_then(|parent| parent.strip_prefix(watch_dir).ok())
+        else {
+            return;
+        };
+
+        let mut stats = self
+            .inner
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let entry = stats
+            .entry(relative.to_path_buf())
+            .or_insert_with(|| SubdirStats {
+                relative_path: relative.to_path_buf(),
+                event_count: 0,
+                last_activity_ms: now_ms,
+            });
+        entry.event_count += 1;
+        entry.last_activity_ms = entry.last_activity_ms.max(now_ms);
+    }
+
+    /// Снимок статистики, отсортированный по пути.
+    pub fn snapshot(&self) -> Vec<SubdirStats> {
+        let mut stats: Vec<SubdirStats> = self
+            .inner
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .values()
+            .cloned()
+            .collect();
+        stats.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_events_are_grouped_by_parent_dir() {
+        let stats = TreeStats::default();
+        let root = Path::new("/w");
+
+        stats.record(root, Path::new("/w/a.txt"), 10);
+        stats.record(root, Path::new("/w/docs/b.txt"), 20);
+        stats.record(root, Path::new("/w/docs/c.txt"), 30);
+        // Вне watch dir — игнорируется
+        stats.record(root, Path::new("/other/d.txt"), 40);
+
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot[0].relative_path, PathBuf::new());
+        assert_eq!(snapshot[0].event_count, 1);
+        assert_eq!(snapshot[1].relative_path, PathBuf::from("docs"));
+        assert_eq!(snapshot[1].event_count, 2);
+        assert_eq!(snapshot[1].last_activity_ms, 30);
+    }
+}
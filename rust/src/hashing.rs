@@ -0,0 +1,209 @@
+//! Хэши содержимого файлов.
+//!
+//! Хэш — устойчивая идентичность файла: переименованный или скопированный
+//! файл сохраняет хэш, поэтому приложение узнаёт в нём дубликат.
+//!
+//! [`HashQueue`] считает хэши новых файлов в фоне, не задерживая события
+//! watcher'а: результат приходит отдельно, когда файл перестанет меняться.
+
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+
+use log::{debug, warn};
+use sha2::{Digest, Sha256};
+
+use crate::error::LateraError;
+use crate::preview;
+
+/// Размер буфера чтения.
+const READ_BUF_SIZE: usize = 64 * 1024;
+
+/// Как часто проверять, что файл перестал меняться.
+const SETTLE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Дольше не ждём: файл, меняющийся дольше, не хэшируется.
+const SETTLE_MAX_WAIT: Duration = Duration::from_secs(30);
+
+/// Алгоритм хэширования.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HashAlgorithm {
+    /// BLAKE3 — в разы быстрее SHA-256 на больших файлах.
+    #[default]
+    Blake3,
+    /// SHA-256 — для сверки с внешними системами.
+    Sha256,
+}
+
+enum Hasher {
+    Blake3(Box<blake3::Hasher>),
+    Sha256(Sha256),
+}
+
+impl Hasher {
+    fn new(algorithm: HashAlgorithm) -> Self {
+        match algorithm {
+            HashAlgorithm::Blake3 => Self::Blake3(Box::new(blake3::Hasher::new())),
+            HashAlgorithm::Sha256 => Self::Sha256(Sha256::new()),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            Self::Blake3(hasher) => {
+                hasher.update(data);
+            }
+            Self::Sha256(hasher) => hasher.update(data),
+        }
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        match self {
+            Self::Blake3(hasher) => hasher.finalize().as_bytes().to_vec(),
+            Self::Sha256(hasher) => hasher.finalize().to_vec(),
+        }
+    }
+}
+
+/// Хэш содержимого файла в hex (нижний регистр).
+pub fn compute_hash(path: &Path, algorithm: HashAlgorithm) -> Result<String, LateraError> {
+    let mut file = File::open(path)?;
+    let mut hasher = Hasher::new(algorithm);
+    let mut buf = vec![0u8; READ_BUF_SIZE];
+    loop {
+        let read = file.read(&mut buf)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buf[..read]);
+    }
+    Ok(hasher
+        .finalize()
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, b| {
+            let _ = write!(hex, "{b:02x}");
+            hex
+        }))
+}
+
+/// Файл, ждущий хэширования.
+#[derive(Clone, Debug)]
+pub struct HashJob {
+    /// Watcher, сообщивший о файле.
+    pub watcher_id: String,
+    pub path: PathBuf,
+}
+
+/// Очередь фонового хэширования.
+pub struct HashQueue {
+    algorithm: HashAlgorithm,
+    job_tx: mpsc::Sender<HashJob>,
+    stop: Arc<AtomicBool>,
+    join: thread::JoinHandle<()>,
+}
+
+impl HashQueue {
+    /// Запускает фоновый тред; `on_hash` получает хэш каждого файла.
+    ///
+    /// Файлы, исчезнувшие или не переставшие меняться, пропускаются.
+    pub fn spawn(
+        algorithm: HashAlgorithm,
+        on_hash: impl Fn(HashJob, String) + Send + 'static,
+    ) -> Result<Self, LateraError> {
+        let (job_tx, job_rx) = mpsc::channel::<HashJob>();
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_for_thread = Arc::clone(&stop);
+        let join = thread::Builder::new()
+            .name("latera-hash".to_string())
+            .spawn(move || {
+                while let Ok(job) = job_rx.recv() {
+                    if stop_for_thread.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    if !preview::wait_until_settled(&job.path, SETTLE_INTERVAL, SETTLE_MAX_WAIT) {
+                        debug!("Hash skipped, file did not settle: {}", job.path.display());
+                        continue;
+                    }
+                    match compute_hash(&job.path, algorithm) {
+                        Ok(hash) => on_hash(job, hash),
+                        Err(e) => debug!("Hash failed for {}: {e}", job.path.display()),
+                    }
+                }
+                debug!("Hash queue stopped");
+            })?;
+        Ok(Self {
+            algorithm,
+            job_tx,
+            stop,
+            join,
+        })
+    }
+
+    pub fn algorithm(&self) -> HashAlgorithm {
+        self.algorithm
+    }
+
+    /// Поставить файл в очередь.
+    pub fn enqueue(&self, job: HashJob) {
+        if self.job_tx.send(job).is_err() {
+            debug!("Hash queue is closed");
+        }
+    }
+
+    /// Останавливает тред; необработанные файлы отбрасываются.
+    pub fn stop(self) {
+        self.stop.store(true, Ordering::Relaxed);
+        drop(self.job_tx);
+        if self.join.join().is_err() {
+            warn!("Hash thread panicked");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_digests() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("abc.txt");
+        std::fs::write(&path, b"abc").unwrap();
+
+        assert_eq!(
+            compute_hash(&path, HashAlgorithm::Sha256).unwrap(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            compute_hash(&path, HashAlgorithm::Blake3).unwrap(),
+            "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+        );
+    }
+
+    #[test]
+    fn test_queue_reports_hash() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("a.bin");
+        std::fs::write(&path, b"abc").unwrap();
+
+        let (tx, rx) = mpsc::channel();
+        let queue = HashQueue::spawn(HashAlgorithm::Sha256, move |job, hash| {
+            let _ = tx.send((job.path, hash));
+        })
+        .unwrap();
+        queue.enqueue(HashJob {
+            watcher_id: "w1".to_string(),
+            path: path.clone(),
+        });
+
+        let (hashed, hash) = rx.recv_timeout(Duration::from_secs(10)).unwrap();
+        assert_eq!(hashed, path);
+        assert!(hash.starts_with("ba7816bf"));
+        queue.stop();
+    }
+}
@@ -0,0 +1,152 @@
+//! Heartbeat ядра.
+//!
+//! Фоновый тред периодически отправляет «пульс» во Flutter. Если пульс
+//! перестал приходить, Dart-слой считает, что Rust Core упал или завис,
+//! и предлагает пользователю перезапустить мониторинг.
+
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use log::{debug, warn};
+
+use crate::error::LateraError;
+use crate::file_watcher::now_ms;
+
+/// Интервал heartbeat по умолчанию.
+pub const DEFAULT_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Минимально допустимый интервал heartbeat.
+pub const MIN_HEARTBEAT_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Один пульс ядра.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Heartbeat {
+    /// Порядковый номер пульса (с 1, в пределах подписки).
+    pub sequence: u64,
+    /// Время отправки (Unix timestamp в миллисекундах).
+    pub emitted_at_ms: i64,
+    /// Сколько работает подписка (монотонное время).
+    pub uptime_ms: u64,
+}
+
+/// Генератор пульсов с монотонной нумерацией.
+#[derive(Debug)]
+pub struct HeartbeatClock {
+    started: Instant,
+    next_sequence: u64,
+}
+
+impl HeartbeatClock {
+    pub fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            next_sequence: 1,
+        }
+    }
+
+    /// Следующий пульс.
+    pub fn beat(&mut self) -> Heartbeat {
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+        Heartbeat {
+            sequence,
+            emitted_at_ms: now_ms(),
+            uptime_ms: u64::try_from(self.started.elapsed().as_millis()).unwrap_or(u64::MAX),
+        }
+    }
+}
+
+impl Default for HeartbeatClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Handle фонового треда heartbeat.
+pub struct HeartbeatHandle {
+    stop_tx: mpsc::Sender<()>,
+    join: thread::JoinHandle<()>,
+}
+
+impl HeartbeatHandle {
+    /// Останавливает тред и дожидается его завершения.
+    pub fn stop(self) {
+        let _ = self.stop_tx.send(());
+        if self.join.join().is_err() {
+            warn!("Heartbeat thread panicked");
+        }
+    }
+}
+
+/// Запускает тред heartbeat.
+///
+/// Первый пульс отправляется сразу. `emit` возвращает `false`, если
+/// подписчик закрыл stream, — тогда тред завершается сам.
+pub fn spawn<F>(interval: Duration, emit: F) -> Result<HeartbeatHandle, LateraError>
+where
+    F: Fn(Heartbeat) -> bool + Send + 'static,
+{
+    if interval < MIN_HEARTBEAT_INTERVAL {
+        return Err(LateraError::InvalidArgument(format!(
+            "heartbeat interval must be at least {} ms",
+            MIN_HEARTBEAT_INTERVAL.as_millis()
+        )));
+    }
+
+    let (stop_tx, stop_rx) = mpsc::channel::<()>();
+    let join = thread::Builder::new()
+        .name("latera-heartbeat".to_string())
+        .spawn(move || {
+            let mut clock = HeartbeatClock::new();
+            loop {
+                if !emit(clock.beat()) {
+                    debug!("Heartbeat stream closed by subscriber");
+                    break;
+                }
+                match stop_rx.recv_timeout(interval) {
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Ok(()) | Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+            debug!("Heartbeat thread stopped");
+        })?;
+    Ok(HeartbeatHandle { stop_tx, join })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_clock_sequence_is_monotonic() {
+        let mut clock = HeartbeatClock::new();
+        let first = clock.beat();
+        let second = clock.beat();
+        assert_eq!(first.sequence, 1);
+        assert_eq!(second.sequence, 2);
+        assert!(second.uptime_ms >= first.uptime_ms);
+    }
+
+    #[test]
+    fn test_spawn_rejects_short_interval() {
+        assert!(spawn(Duration::from_millis(10), |_| true).is_err());
+    }
+
+    #[test]
+    fn test_thread_stops_when_stream_closed() {
+        let beats = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&beats);
+        let handle = spawn(MIN_HEARTBEAT_INTERVAL, move |beat| {
+            let mut beats = sink.lock().unwrap();
+            beats.push(beat.sequence);
+            beats.len() < 2
+        })
+        .unwrap();
+
+        // Тред завершится сам после второго пульса
+        handle.join.join().unwrap();
+        assert_eq!(*beats.lock().unwrap(), vec![1, 2]);
+    }
+}
@@ -0,0 +1,217 @@
+//! Автоматическая индексация файлов наблюдаемых папок.
+//!
+//! События watcher'а превращаются в задания [`IndexJob`] и обрабатываются
+//! фоновым тредом по порядку: появившийся или изменённый файл дожидается
+//! окончания записи, его текст (PDF, DOCX, plain text) попадает в FTS5,
+//! переименование переносит запись, удаление — убирает. Описание
+//! пользователя при обновлении содержимого сохраняется.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+
+use log::{debug, warn};
+use rusqlite::Connection;
+
+use crate::error::LateraError;
+use crate::preview;
+
+use super::{extract_rich_content, ExtractionOptions};
+
+/// Как часто проверять, что файл перестал меняться.
+const SETTLE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Дольше не ждём: файл, меняющийся дольше, индексируется при следующем
+/// событии изменения.
+const SETTLE_MAX_WAIT: Duration = Duration::from_secs(30);
+
+/// Задание автоиндексации.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IndexJob {
+    /// Файл появился или изменился — (пере)индексировать содержимое.
+    Upsert(PathBuf),
+    /// Файл переименован внутри наблюдаемой папки.
+    Rename { from: PathBuf, to: PathBuf },
+    /// Файла больше нет.
+    Remove(PathBuf),
+}
+
+/// Доступ к БД индекса: вызывает переданную функцию с открытым соединением.
+///
+/// Соединение берётся на время одного шага, а не всего задания, чтобы
+/// извлечение текста из большого PDF не блокировало поиск.
+pub trait WithDb:
+    Fn(&mut dyn FnMut(&Connection) -> Result<(), LateraError>) -> Result<(), LateraError>
+{
+}
+
+impl<F> WithDb for F where
+    F: Fn(&mut dyn FnMut(&Connection) -> Result<(), LateraError>) -> Result<(), LateraError>
+{
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+/// Текст файла для индекса; `None` — формат не поддерживается или текста нет.
+fn extract(path: &Path) -> Option<String> {
+    let result = extract_rich_content(path, &ExtractionOptions::default());
+    // too_many_pages — текст первых страниц всё равно извлечён
+    (!result.text.trim().is_empty()).then_some(result.text)
+}
+
+fn upsert<F: WithDb + ?Sized>(path: &Path, with_db: &F) -> Result<(), LateraError> {
+    let text = extract(path);
+    let path_str = path.to_string_lossy();
+    let name = file_name(path);
+    with_db(&mut |conn| super::index_content(conn, &path_str, &name, text.as_deref()))
+}
+
+/// Выполняет задание (без ожидания окончания записи).
+pub fn process<F: WithDb + ?Sized>(job: &IndexJob, with_db: &F) -> Result<(), LateraError> {
+    match job {
+        IndexJob::Upsert(path) => upsert(path, with_db),
+        IndexJob::Rename { from, to } => {
+            let mut renamed = false;
+            with_db(&mut |conn| {
+                renamed = super::rename_file(
+                    conn,
+                    &from.to_string_lossy(),
+                    &to.to_string_lossy(),
+                    &file_name(to),
+                )?;
+                Ok(())
+            })?;
+            if renamed {
+                Ok(())
+            } else {
+                upsert(to, with_db)
+            }
+        }
+        IndexJob::Remove(path) => with_db(&mut |conn| {
+            super::remove_file(conn, &path.to_string_lossy())?;
+            Ok(())
+        }),
+    }
+}
+
+/// Очередь автоиндексации.
+pub struct AutoIndexQueue {
+    job_tx: mpsc::Sender<IndexJob>,
+    stop: Arc<AtomicBool>,
+    join: thread::JoinHandle<()>,
+}
+
+impl AutoIndexQueue {
+    /// Запускает фоновый тред; `with_db` даёт доступ к БД индекса.
+    pub fn spawn(with_db: Box<dyn WithDb + Send>) -> Result<Self, LateraError> {
+        let (job_tx, job_rx) = mpsc::channel::<IndexJob>();
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_for_thread = Arc::clone(&stop);
+        let join = thread::Builder::new()
+            .name("latera-auto-index".to_string())
+            .spawn(move || {
+                while let Ok(job) = job_rx.recv() {
+                    if stop_for_thread.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    if let IndexJob::Upsert(path) = &job {
+                        if !preview::wait_until_settled(path, SETTLE_INTERVAL, SETTLE_MAX_WAIT) {
+                            debug!("Indexing skipped, file did not settle: {}", path.display());
+                            continue;
+                        }
+                    }
+                    if let Err(e) = process(&job, with_db.as_ref()) {
+                        debug!("Auto-indexing failed for {job:?}: {e}");
+                    }
+                }
+                debug!("Auto-index queue stopped");
+            })?;
+        Ok(Self { job_tx, stop, join })
+    }
+
+    /// Поставить задание в очередь.
+    pub fn enqueue(&self, job: IndexJob) {
+        if self.job_tx.send(job).is_err() {
+            debug!("Auto-index queue is closed");
+        }
+    }
+
+    /// Останавливает тред; необработанные задания отбрасываются.
+    pub fn stop(self) {
+        self.stop.store(true, Ordering::Relaxed);
+        drop(self.job_tx);
+        if self.join.join().is_err() {
+            warn!("Auto-index thread panicked");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::indexer;
+
+    #[test]
+    fn test_jobs_keep_index_in_sync_with_files() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let conn = Mutex::new(indexer::init_db(":memory:").unwrap());
+        let with_db =
+            |f: &mut dyn FnMut(&Connection) -> Result<(), LateraError>| f(&conn.lock().unwrap());
+
+        let notes = temp_dir.path().join("notes.txt");
+        std::fs::write(&notes, "квартальный счёт от поставщика").unwrap();
+        process(&IndexJob::Upsert(notes.clone()), &with_db).unwrap();
+        let hits = indexer::search(&conn.lock().unwrap(), "поставщика", 10).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert!(hits[0].snippet.contains("<b>"));
+
+        // Описание пользователя переживает изменение содержимого и переименование
+        let notes_str = notes.to_string_lossy().to_string();
+        indexer::index_file(
+            &conn.lock().unwrap(),
+            &notes_str,
+            "notes.txt",
+            "важное",
+            None,
+        )
+        .unwrap();
+        std::fs::write(&notes, "новый текст").unwrap();
+        process(&IndexJob::Upsert(notes.clone()), &with_db).unwrap();
+        let renamed = temp_dir.path().join("renamed.txt");
+        std::fs::rename(&notes, &renamed).unwrap();
+        process(
+            &IndexJob::Rename {
+                from: notes.clone(),
+                to: renamed.clone(),
+            },
+            &with_db,
+        )
+        .unwrap();
+
+        let conn_guard = conn.lock().unwrap();
+        assert!(indexer::search(&conn_guard, "поставщика", 10)
+            .unwrap()
+            .is_empty());
+        let info = indexer::get_indexed_file(&conn_guard, &renamed.to_string_lossy())
+            .unwrap()
+            .unwrap();
+        assert_eq!(info.description, "важное");
+        assert_eq!(info.file_name, "renamed.txt");
+        assert!(!indexer::is_indexed(&conn_guard, &notes_str).unwrap());
+        drop(conn_guard);
+
+        process(&IndexJob::Remove(renamed), &with_db).unwrap();
+        assert_eq!(
+            indexer::get_indexed_count(&conn.lock().unwrap()).unwrap(),
+            0
+        );
+    }
+}
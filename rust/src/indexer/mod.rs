@@ -5,6 +5,7 @@
 //! - полнотекстовый поиск через FTS5
 //! - CRUD операции индекса
 
+pub mod auto_index;
 pub mod embeddings;
 pub mod llm;
 pub mod llm_engine;
@@ -179,6 +180,57 @@ pub fn index_file(
     Ok(rowid)
 }
 
+/// Индексирует содержимое файла, не трогая описание пользователя.
+///
+/// Используется автоиндексацией: изменение файла обновляет имя и текст,
+/// а описание, заданное через [`index_file`], сохраняется.
+pub fn index_content(
+    conn: &Connection,
+    file_path: &str,
+    file_name: &str,
+    text_content: Option<&str>,
+) -> Result<(), LateraError> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64;
+
+    conn.execute(
+        "INSERT INTO files (file_path, file_name, text_content, indexed_at)
+         VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT(file_path) DO UPDATE SET
+            file_name = excluded.file_name,
+            text_content = excluded.text_content,
+            indexed_at = excluded.indexed_at",
+        params![file_path, file_name, text_content.unwrap_or(""), now],
+    )?;
+    debug!("Indexed content: {file_name} (path={file_path})");
+    Ok(())
+}
+
+/// Переносит запись индекса на новый путь (описание, текст и эмбеддинги
+/// сохраняются). Возвращает `false`, если старого пути нет в индексе.
+pub fn rename_file(
+    conn: &Connection,
+    old_path: &str,
+    new_path: &str,
+    new_name: &str,
+) -> Result<bool, LateraError> {
+    // Запись по новому пути (если была) заменяется переименованным файлом
+    conn.execute(
+        "DELETE FROM files WHERE file_path = ?1 AND ?1 <> ?2",
+        params![new_path, old_path],
+    )?;
+    let rows = conn.execute(
+        "UPDATE files SET file_path = ?1, file_name = ?2 WHERE file_path = ?3",
+        params![new_path, new_name, old_path],
+    )?;
+    if rows > 0 {
+        debug!("Renamed in index: {old_path} -> {new_path}");
+    }
+    Ok(rows > 0)
+}
+
 /// Выполняет полнотекстовый поиск по индексу.
 ///
 /// Ищет по имени файла, описанию и текстовому содержимому.
@@ -281,6 +333,15 @@ pub fn remove_file(conn: &Connection, file_path: &str) -> Result<bool, LateraErr
     }
 }
 
+/// Пути всех проиндексированных файлов.
+pub fn indexed_paths(conn: &Connection) -> Result<Vec<String>, LateraError> {
+    let mut stmt = conn.prepare("SELECT file_path FROM files ORDER BY file_path")?;
+    let paths = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(paths)
+}
+
 /// Возвращает количество проиндексированных файлов.
 pub fn get_indexed_count(conn: &Connection) -> Result<i64, LateraError> {
     let count: i64 = conn.query_row("SELECT COUNT(*) FROM files", [], |row| row.get(0))?;
@@ -64,6 +64,23 @@ pub fn cancel_rag_query() {
     super::llm_engine::cancel_generation();
 }
 
+/// Отменяет текущий RAG-запрос и закрывает канал стриминга.
+///
+/// Используется при shutdown: непрочитанные события отбрасываются,
+/// рабочий поток завершится на ближайшей проверке отмены.
+pub fn shutdown_streaming() {
+    cancel_rag_query();
+    STREAM_SENDER.lock().unwrap().take();
+    let drained = STREAM_RECEIVER
+        .lock()
+        .unwrap()
+        .take()
+        .map_or(0, |rx| rx.try_iter().count());
+    if drained > 0 {
+        debug!("RAG: dropped {} pending stream events on shutdown", drained);
+    }
+}
+
 /// Проверяет, запрошена ли отмена.
 fn is_cancelled() -> bool {
     CANCEL_RAG.load(Ordering::Relaxed)
@@ -0,0 +1,78 @@
+//! Пресет наблюдения за папкой загрузок ОС.
+//!
+//! Браузеры и менеджеры загрузок пишут файл под временным именем
+//! (`*.crdownload`, `*.part`…) и переименовывают его по готовности. Пресет
+//! не сообщает временные файлы и ждёт, пока загрузка «осядет».
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::error::LateraError;
+use crate::file_watcher::{WatchFilter, WatcherOptions};
+
+/// Правило переноса загрузок в журнале аудита.
+pub const RULE_ID: &str = "intake.downloads";
+
+/// Временные файлы незавершённых загрузок (Chrome/Edge, Firefox, Safari,
+/// Opera, менеджеры загрузок, Office).
+pub const TEMP_DOWNLOAD_PATTERNS: &[&str] = &[
+    "*.crdownload",
+    "*.part",
+    "*.partial",
+    "*.download",
+    "*.opdownload",
+    "*.tmp",
+    "~$*",
+    ".com.google.Chrome.*",
+];
+
+/// Сколько загрузка не должна меняться, чтобы считаться завершённой.
+pub const DOWNLOAD_SETTLE_PERIOD: Duration = Duration::from_secs(2);
+
+/// Папка загрузок текущего пользователя.
+pub fn downloads_dir() -> Result<PathBuf, LateraError> {
+    dirs::download_dir().ok_or_else(|| {
+        LateraError::InvalidPath("Downloads directory is not available on this OS/user".to_string())
+    })
+}
+
+/// Настройки watcher'а папки загрузок на основе общих.
+///
+/// Фильтр заменяется исключением временных и скрытых файлов (фильтр
+/// основной папки к загрузкам не относится). Вложенные папки не
+/// наблюдаются; стабилизация — не короче [`DOWNLOAD_SETTLE_PERIOD`].
+pub fn preset_options(base: &WatcherOptions) -> Result<WatcherOptions, LateraError> {
+    let exclude: Vec<String> = TEMP_DOWNLOAD_PATTERNS
+        .iter()
+        .map(|p| (*p).to_string())
+        .collect();
+    Ok(WatcherOptions {
+        recursive: false,
+        settle_quiet_period: Some(
+            base.settle_quiet_period
+                .map_or(DOWNLOAD_SETTLE_PERIOD, |p| p.max(DOWNLOAD_SETTLE_PERIOD)),
+        ),
+        filter: WatchFilter::new(&[], &exclude, &[], false)?,
+        ..base.clone()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn test_preset_filters_temp_files() {
+        let options = preset_options(&WatcherOptions::default()).unwrap();
+        let dir = Path::new("/downloads");
+        assert!(!options
+            .filter
+            .matches(dir, &dir.join("video.mp4.crdownload")));
+        assert!(!options.filter.matches(dir, &dir.join("archive.zip.part")));
+        assert!(options.filter.matches(dir, &dir.join("report.pdf")));
+        assert_eq!(options.settle_quiet_period, Some(DOWNLOAD_SETTLE_PERIOD));
+        assert!(!options.recursive);
+    }
+}
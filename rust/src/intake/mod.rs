@@ -0,0 +1,97 @@
+//! Пресеты приёма файлов из внешних папок ОС.
+//!
+//! Пресет — готовые настройки watcher'а для папки, куда файлы кладёт не
+//! пользователь, а браузер ([`downloads`]) или система ([`screenshots`]).
+//! Появившиеся там файлы можно переносить в папку наблюдения Latera
+//! ([`move_into`]).
+
+pub mod downloads;
+pub mod screenshots;
+
+use std::path::{Path, PathBuf};
+
+use crate::audit_log::{self, AuditAction};
+use crate::error::LateraError;
+use crate::expected_changes;
+use crate::read_only;
+
+/// Переносит файл в `target_dir` по пресету `rule_id` (см. [`audit_log`]);
+/// возвращает новый путь.
+///
+/// Занятое имя не перезаписывается: к нему добавляется ` (1)`, ` (2)`…
+/// Исчезновение файла из исходной папки отмечается как изменение ядра
+/// ([`expected_changes`]); появление в `target_dir` — нет, для папки
+/// наблюдения это новый файл пользователя.
+pub fn move_into(path: &Path, target_dir: &Path, rule_id: &str) -> Result<PathBuf, LateraError> {
+    read_only::ensure_writable("moving files into watch dir")?;
+
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| LateraError::FileNameMissing(path.to_path_buf()))?;
+    std::fs::create_dir_all(target_dir)?;
+    let target = free_path(&target_dir.join(file_name));
+
+    expected_changes::expect(path);
+    let result = std::fs::rename(path, &target).or_else(|_| {
+        // Другой том: копируем и удаляем исходник
+        std::fs::copy(path, &target)?;
+        std::fs::remove_file(path)
+    });
+    if let Err(e) = result {
+        expected_changes::forget(path);
+        return Err(e.into());
+    }
+    audit_log::record(
+        AuditAction::Move,
+        path,
+        Some(&target.to_string_lossy()),
+        Some(rule_id),
+    );
+    Ok(target)
+}
+
+/// `path`, если он свободен, иначе первый свободный `name (N).ext`.
+pub(crate) fn free_path(path: &Path) -> PathBuf {
+    if !path.exists() {
+        return path.to_path_buf();
+    }
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let extension = path
+        .extension()
+        .map(|e| format!(".{}", e.to_string_lossy()))
+        .unwrap_or_default();
+    (1..u32::MAX)
+        .map(|n| path.with_file_name(format!("{stem} ({n}){extension}")))
+        .find(|candidate| !candidate.exists())
+        .unwrap_or_else(|| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_move_into_keeps_existing_files() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let downloads = temp_dir.path().join("Downloads");
+        let target_dir = temp_dir.path().join("Latera");
+        std::fs::create_dir_all(&downloads).unwrap();
+        std::fs::create_dir_all(&target_dir).unwrap();
+        std::fs::write(target_dir.join("report.pdf"), b"old").unwrap();
+        let download = downloads.join("report.pdf");
+        std::fs::write(&download, b"new").unwrap();
+
+        let moved = move_into(&download, &target_dir, downloads::RULE_ID).unwrap();
+        assert_eq!(moved, target_dir.join("report (1).pdf"));
+        assert_eq!(std::fs::read(&moved).unwrap(), b"new");
+        assert_eq!(
+            std::fs::read(target_dir.join("report.pdf")).unwrap(),
+            b"old"
+        );
+        assert!(!download.exists());
+        assert!(expected_changes::is_expected(&download));
+    }
+}
@@ -0,0 +1,105 @@
+//! Пресет приёма скриншотов.
+//!
+//! Скриншоты ОС сохраняет в свою папку: macOS — на Desktop, Windows —
+//! в `Pictures/Screenshots`, GNOME и KDE — туда же. На macOS в папке лежит
+//! и всё остальное, поэтому пресет сообщает только файлы с именами,
+//! которые дают скриншотам системные утилиты.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::error::LateraError;
+use crate::file_watcher::{WatchFilter, WatcherOptions};
+
+/// Имена скриншотов (без учёта регистра):
+/// - macOS: `Screenshot 2024-05-01 at 10.00.00.png`, до 10.14 — `Screen Shot …`,
+///   русская локаль — `Снимок экрана …`;
+/// - Windows: `Screenshot (3).png`, `Screenshot 2024-05-01 100000.png`;
+/// - GNOME: `Screenshot from 2024-05-01 10-00-00.png`;
+/// - KDE Spectacle: `Screenshot_20240501_100000.png`.
+pub const SCREENSHOT_NAME_PATTERNS: &[&str] =
+    &["Screenshot*", "Screen Shot*", "Снимок экрана*", "Скриншот*"];
+
+/// Форматы, в которых системные утилиты сохраняют скриншоты.
+pub const SCREENSHOT_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "heic", "tiff", "webp"];
+
+/// Правило переноса скриншотов в журнале аудита.
+pub const RULE_ID: &str = "intake.screenshots";
+
+/// Скриншот записывается быстро; короткой стабилизации достаточно.
+pub const SCREENSHOT_SETTLE_PERIOD: Duration = Duration::from_secs(1);
+
+/// Папка, куда ОС сохраняет скриншоты.
+///
+/// На macOS — Desktop (нестандартное место из `defaults write
+/// com.apple.screencapture location` не учитывается). На остальных
+/// платформах — `Pictures/Screenshots`.
+pub fn screenshots_dir() -> Result<PathBuf, LateraError> {
+    let dir = if cfg!(target_os = "macos") {
+        dirs::desktop_dir()
+    } else {
+        dirs::picture_dir().map(|d| d.join("Screenshots"))
+    };
+    dir.ok_or_else(|| {
+        LateraError::InvalidPath(
+            "Screenshots directory is not available on this OS/user".to_string(),
+        )
+    })
+}
+
+/// Настройки watcher'а папки скриншотов на основе общих.
+///
+/// Фильтр заменяется шаблонами имён скриншотов; скрытые файлы (macOS
+/// сначала пишет `.Screenshot …`) не сообщаются. Вложенные папки не
+/// наблюдаются; стабилизация — не короче [`SCREENSHOT_SETTLE_PERIOD`].
+pub fn preset_options(base: &WatcherOptions) -> Result<WatcherOptions, LateraError> {
+    let include: Vec<String> = SCREENSHOT_NAME_PATTERNS
+        .iter()
+        .map(|p| (*p).to_string())
+        .collect();
+    let extensions: Vec<String> = SCREENSHOT_EXTENSIONS
+        .iter()
+        .map(|e| (*e).to_string())
+        .collect();
+    Ok(WatcherOptions {
+        recursive: false,
+        settle_quiet_period: Some(
+            base.settle_quiet_period
+                .map_or(SCREENSHOT_SETTLE_PERIOD, |p| {
+                    p.max(SCREENSHOT_SETTLE_PERIOD)
+                }),
+        ),
+        filter: WatchFilter::new(&include, &[], &extensions, false)?,
+        ..base.clone()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn test_preset_matches_platform_screenshot_names() {
+        let options = preset_options(&WatcherOptions::default()).unwrap();
+        let dir = Path::new("/shots");
+        for name in [
+            "Screenshot 2024-05-01 at 10.00.00.png",
+            "Screen Shot 2019-05-01 at 10.00.00.png",
+            "Снимок экрана 2024-05-01 в 10.00.00.png",
+            "Screenshot (3).png",
+            "screenshot from 2024-05-01 10-00-00.PNG",
+            "Screenshot_20240501_100000.jpg",
+        ] {
+            assert!(options.filter.matches(dir, &dir.join(name)), "{name}");
+        }
+        for name in [
+            ".Screenshot 2024-05-01 at 10.00.00.png",
+            "Screenshot notes.txt",
+            "report.png",
+        ] {
+            assert!(!options.filter.matches(dir, &dir.join(name)), "{name}");
+        }
+    }
+}
@@ -0,0 +1,143 @@
+//! Проверка согласованности папки наблюдения.
+//!
+//! Сверяет три источника: поисковый индекс ([`crate::indexer`]), журнал
+//! событий ([`crate::journal`]) и содержимое папки на диске. Расхождения
+//! появляются, когда файлы меняются мимо watcher'а (приложение закрыто,
+//! переполнение очереди событий ОС, ручное редактирование базы).
+
+use std::collections::HashSet;
+use std::hash::BuildHasher;
+use std::path::{Path, PathBuf};
+
+use crate::dir_snapshot::DirSnapshot;
+use crate::error::LateraError;
+use crate::file_watcher::WatchFilter;
+use crate::internal_files;
+
+/// Вид расхождения.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum IssueKind {
+    /// Файл есть в поисковом индексе, но не на диске.
+    IndexedButMissing,
+    /// По журналу файл существует, но на диске его нет.
+    KnownButMissing,
+    /// Файл есть на диске, но журнал о нём не знает.
+    PresentButUnknown,
+}
+
+/// Расхождение для одного файла.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct IntegrityIssue {
+    pub kind: IssueKind,
+    pub path: PathBuf,
+}
+
+/// Папка для проверки.
+pub struct FolderScope<'a> {
+    pub root: &'a Path,
+    pub recursive: bool,
+    /// Фильтр watcher'а: файлы, которые он не сообщает, не проверяются.
+    pub filter: &'a WatchFilter,
+}
+
+impl FolderScope<'_> {
+    fn contains(&self, path: &Path) -> bool {
+        let Ok(relative) = path.strip_prefix(self.root) else {
+            return false;
+        };
+        let depth = relative.components().count();
+        depth > 0
+            && (self.recursive || depth == 1)
+            && !internal_files::is_internal(path)
+            && self.filter.matches(self.root, path)
+    }
+}
+
+/// Расхождения в папке `scope` (по пути). `indexed` и `known` могут
+/// содержать пути вне папки — они не учитываются.
+pub fn find_issues<S: BuildHasher>(
+    scope: &FolderScope<'_>,
+    indexed: &[PathBuf],
+    known: &HashSet<PathBuf, S>,
+) -> Result<Vec<IntegrityIssue>, LateraError> {
+    let is_missing = |path: &Path| std::fs::symlink_metadata(path).is_err();
+    let mut issues: Vec<IntegrityIssue> = indexed
+        .iter()
+        .filter(|path| scope.contains(path) && is_missing(path))
+        .map(|path| IntegrityIssue {
+            kind: IssueKind::IndexedButMissing,
+            path: path.clone(),
+        })
+        .collect();
+    issues.extend(
+        known
+            .iter()
+            .filter(|path| scope.contains(path) && is_missing(path))
+            .map(|path| IntegrityIssue {
+                kind: IssueKind::KnownButMissing,
+                path: path.clone(),
+            }),
+    );
+
+    let present = DirSnapshot::scan(scope.root, scope.recursive, |path| scope.contains(path))?;
+    issues.extend(
+        present
+            .paths()
+            .map(|relative| scope.root.join(relative))
+            .filter(|path| !known.contains(path))
+            .map(|path| IntegrityIssue {
+                kind: IssueKind::PresentButUnknown,
+                path,
+            }),
+    );
+    issues.sort();
+    Ok(issues)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_issues_reports_each_kind() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let root = temp_dir.path();
+        std::fs::write(root.join("known.txt"), b"a").unwrap();
+        std::fs::write(root.join("new.txt"), b"b").unwrap();
+        std::fs::write(root.join("skipped.tmp"), b"c").unwrap();
+        std::fs::create_dir(root.join("sub")).unwrap();
+        std::fs::write(root.join("sub").join("deep.txt"), b"d").unwrap();
+
+        let filter = WatchFilter::new(&[], &["*.tmp".to_string()], &[], false).unwrap();
+        let scope = FolderScope {
+            root,
+            recursive: false,
+            filter: &filter,
+        };
+        let indexed = vec![root.join("known.txt"), root.join("gone.pdf")];
+        let known = HashSet::from([
+            root.join("known.txt"),
+            root.join("lost.txt"),
+            PathBuf::from("/elsewhere/x.txt"),
+        ]);
+
+        let issues = find_issues(&scope, &indexed, &known).unwrap();
+        assert_eq!(
+            issues,
+            vec![
+                IntegrityIssue {
+                    kind: IssueKind::IndexedButMissing,
+                    path: root.join("gone.pdf"),
+                },
+                IntegrityIssue {
+                    kind: IssueKind::KnownButMissing,
+                    path: root.join("lost.txt"),
+                },
+                IntegrityIssue {
+                    kind: IssueKind::PresentButUnknown,
+                    path: root.join("new.txt"),
+                },
+            ]
+        );
+    }
+}
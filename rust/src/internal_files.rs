@@ -0,0 +1,117 @@
+//! Служебные файлы ядра в наблюдаемых папках.
+//!
+//! Всё, что ядро само создаёт в watch dir (probe-файлы проверок здоровья,
+//! `desktop.ini` с иконкой папки), подчиняется соглашению об именах:
+//! имя начинается с зарезервированного префикса (по умолчанию `.latera-`)
+//! или входит в [`RESERVED_FILE_NAMES`]. Watcher отбрасывает события таких
+//! файлов до дедупа и callback'ов — подписчики их никогда не видят.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+
+use crate::error::LateraError;
+use crate::read_only;
+
+/// Префикс служебных файлов по умолчанию.
+pub const DEFAULT_INTERNAL_PREFIX: &str = ".latera-";
+
+/// Служебные файлы с фиксированными именами (без учёта регистра).
+pub const RESERVED_FILE_NAMES: &[&str] = &["desktop.ini"];
+
+static INTERNAL_PREFIX: Lazy<Mutex<String>> =
+    Lazy::new(|| Mutex::new(DEFAULT_INTERNAL_PREFIX.to_string()));
+
+/// Счётчик для уникальных имён probe-файлов.
+static NEXT_PROBE_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Задать префикс служебных файлов.
+///
+/// Префикс не может быть пустым и содержать разделители пути. Файлы,
+/// созданные со старым префиксом, перестают подавляться.
+pub fn set_prefix(prefix: &str) -> Result<(), LateraError> {
+    if prefix.is_empty() || prefix.contains(['/', '\\']) {
+        return Err(LateraError::InvalidArgument(format!(
+            "invalid internal file prefix: {prefix:?}"
+        )));
+    }
+    *INTERNAL_PREFIX
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = prefix.to_string();
+    Ok(())
+}
+
+/// Текущий префикс служебных файлов.
+pub fn prefix() -> String {
+    INTERNAL_PREFIX
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone()
+}
+
+/// Имя служебного файла назначения `purpose` (`.latera-{purpose}`).
+pub fn internal_file_name(purpose: &str) -> String {
+    format!("{}{purpose}", prefix())
+}
+
+/// Является ли имя файла служебным.
+pub fn is_internal_name(name: &str) -> bool {
+    RESERVED_FILE_NAMES
+        .iter()
+        .any(|reserved| name.eq_ignore_ascii_case(reserved))
+        || name.starts_with(
+            INTERNAL_PREFIX
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner)
+                .as_str(),
+        )
+}
+
+/// Является ли файл служебным (по имени).
+pub fn is_internal(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(is_internal_name)
+}
+
+/// Проверка, что в папку можно писать: создаёт и удаляет probe-файл.
+///
+/// События probe-файла watcher подавляет. Возвращает путь использованного
+/// probe-файла (его уже нет на диске).
+pub fn probe_writable(dir: &Path) -> Result<PathBuf, LateraError> {
+    read_only::ensure_writable("write probe")?;
+    let probe = dir.join(internal_file_name(&format!(
+        "probe-{}-{}",
+        std::process::id(),
+        NEXT_PROBE_ID.fetch_add(1, Ordering::Relaxed)
+    )));
+    std::fs::write(&probe, b"probe")?;
+    std::fs::remove_file(&probe)?;
+    Ok(probe)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_internal_names() {
+        assert!(is_internal(Path::new("/w/.latera-probe-1-1")));
+        assert!(is_internal(Path::new("/w/Desktop.ini")));
+        assert!(!is_internal(Path::new("/w/report.pdf")));
+        assert!(!is_internal(Path::new("/w/.hidden")));
+
+        assert!(set_prefix("").is_err());
+        assert!(set_prefix("a/b").is_err());
+    }
+
+    #[test]
+    fn test_probe_leaves_nothing_behind() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let probe = probe_writable(temp_dir.path()).unwrap();
+        assert!(is_internal(&probe));
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+    }
+}
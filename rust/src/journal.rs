@@ -0,0 +1,462 @@
+//! Журнал событий файлов.
+//!
+//! Каждое событие watcher'а записывается в `{data_dir}/journal.db` — после
+//! перезапуска приложение восстанавливает историю из журнала, а не теряет
+//! её вместе с памятью Dart.
+//!
+//! В отличие от [`crate::event_wal`], журнал не про гарантию доставки:
+//! он хранит все события (в том числе изменения и удаления) до явной
+//! очистки.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use rusqlite::{params, Connection};
+
+use crate::error::LateraError;
+use crate::file_watcher::{FileEventKind, InternalFileEvent};
+
+/// Имя файла журнала в папке данных.
+pub const JOURNAL_DB_FILE: &str = "journal.db";
+
+/// Запись журнала.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JournalEntry {
+    /// Порядковый номер записи (растёт монотонно).
+    pub id: i64,
+    pub watcher_id: String,
+    pub kind: FileEventKind,
+    pub path: PathBuf,
+    pub previous_path: Option<PathBuf>,
+    pub occurred_at_ms: i64,
+    pub detected_at_ms: i64,
+    pub size_bytes: Option<u64>,
+    pub mime_type: Option<String>,
+    pub self_generated: bool,
+}
+
+/// Сколько самых частых типов файлов хранит корзина временной шкалы.
+pub const TIMELINE_TOP_TYPES: usize = 3;
+
+/// Наибольшее число корзин в одном запросе временной шкалы.
+pub const MAX_TIMELINE_BUCKETS: i64 = 10_000;
+
+/// Ширина корзины временной шкалы.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimelineGranularity {
+    Hour,
+    Day,
+    Week,
+}
+
+impl TimelineGranularity {
+    pub fn bucket_ms(self) -> i64 {
+        const HOUR_MS: i64 = 60 * 60 * 1000;
+        match self {
+            Self::Hour => HOUR_MS,
+            Self::Day => 24 * HOUR_MS,
+            Self::Week => 7 * 24 * HOUR_MS,
+        }
+    }
+}
+
+/// Активность за один интервал `[start_ms, end_ms)`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TimelineBucket {
+    pub start_ms: i64,
+    pub end_ms: i64,
+    /// Все события интервала.
+    pub event_count: u64,
+    /// Разные файлы, которых касались события.
+    pub file_count: u64,
+    /// Суммарный размер появившихся и изменённых файлов.
+    pub bytes: u64,
+    /// Самые частые MIME-типы (по убыванию числа событий), не больше
+    /// [`TIMELINE_TOP_TYPES`].
+    pub top_types: Vec<(String, u64)>,
+}
+
+fn kind_to_str(kind: FileEventKind) -> &'static str {
+    match kind {
+        FileEventKind::Created => "created",
+        FileEventKind::Modified => "modified",
+        FileEventKind::Removed => "removed",
+        FileEventKind::RenamedFrom => "renamed_from",
+        FileEventKind::RenamedTo => "renamed_to",
+    }
+}
+
+fn parse_kind(value: &str) -> Option<FileEventKind> {
+    match value {
+        "created" => Some(FileEventKind::Created),
+        "modified" => Some(FileEventKind::Modified),
+        "removed" => Some(FileEventKind::Removed),
+        "renamed_from" => Some(FileEventKind::RenamedFrom),
+        "renamed_to" => Some(FileEventKind::RenamedTo),
+        _ => None,
+    }
+}
+
+/// Журнал событий на SQLite.
+pub struct EventJournal {
+    conn: Connection,
+}
+
+impl EventJournal {
+    /// Открывает (или создаёт) журнал.
+    pub fn open(db_path: &Path) -> Result<Self, LateraError> {
+        if let Some(parent) = db_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let conn = Connection::open(db_path)?;
+        conn.execute_batch(
+            "PRAGMA journal_mode=WAL;
+            CREATE TABLE IF NOT EXISTS events (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                watcher_id TEXT NOT NULL,
+                kind TEXT NOT NULL,
+                path TEXT NOT NULL,
+                previous_path TEXT,
+                occurred_at_ms INTEGER NOT NULL,
+                detected_at_ms INTEGER NOT NULL,
+                size_bytes INTEGER,
+                mime_type TEXT,
+                self_generated INTEGER NOT NULL DEFAULT 0
+            );
+            CREATE INDEX IF NOT EXISTS events_detected_at ON events (detected_at_ms);",
+        )?;
+        Ok(Self { conn })
+    }
+
+    /// Записывает событие. Возвращает номер записи.
+    pub fn record(&self, watcher_id: &str, event: &InternalFileEvent) -> Result<i64, LateraError> {
+        self.conn.execute(
+            "INSERT INTO events (watcher_id, kind, path, previous_path, occurred_at_ms,
+                detected_at_ms, size_bytes, mime_type, self_generated)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![
+                watcher_id,
+                kind_to_str(event.kind),
+                event.full_path.to_string_lossy(),
+                event
+                    .previous_path
+                    .as_ref()
+                    .map(|p| p.to_string_lossy().to_string()),
+                event.occurred_at_ms,
+                event.detected_at_ms,
+                event.size_bytes.and_then(|size| i64::try_from(size).ok()),
+                event.mime_type,
+                event.self_generated,
+            ],
+        )?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    /// События, обнаруженные не раньше `since_ms` (по возрастанию), не
+    /// больше `limit`.
+    pub fn query(&self, since_ms: i64, limit: usize) -> Result<Vec<JournalEntry>, LateraError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, watcher_id, kind, path, previous_path, occurred_at_ms, detected_at_ms,
+                size_bytes, mime_type, self_generated
+             FROM events WHERE detected_at_ms >= ?1 ORDER BY id LIMIT ?2",
+        )?;
+        let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+        let rows = stmt.query_map(params![since_ms, limit], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, Option<String>>(4)?,
+                row.get::<_, i64>(5)?,
+                row.get::<_, i64>(6)?,
+                row.get::<_, Option<i64>>(7)?,
+                row.get::<_, Option<String>>(8)?,
+                row.get::<_, bool>(9)?,
+            ))
+        })?;
+        let mut entries = Vec::new();
+        for row in rows {
+            let (
+                id,
+                watcher_id,
+                kind,
+                path,
+                previous_path,
+                occurred_at_ms,
+                detected_at_ms,
+                size_bytes,
+                mime_type,
+                self_generated,
+            ) = row?;
+            // Записи неизвестного вида (от будущей версии) пропускаются
+            let Some(kind) = parse_kind(&kind) else {
+                continue;
+            };
+            entries.push(JournalEntry {
+                id,
+                watcher_id,
+                kind,
+                path: PathBuf::from(path),
+                previous_path: previous_path.map(PathBuf::from),
+                occurred_at_ms,
+                detected_at_ms,
+                size_bytes: size_bytes.and_then(|size| u64::try_from(size).ok()),
+                mime_type,
+                self_generated,
+            });
+        }
+        Ok(entries)
+    }
+
+    /// Активность в `[from_ms, to_ms)` по корзинам `granularity`,
+    /// отсчитанным от `from_ms` (по обнаружению события).
+    ///
+    /// Возвращает все корзины интервала, в том числе пустые. События,
+    /// сделанные самим ядром, не учитываются.
+    pub fn timeline(
+        &self,
+        from_ms: i64,
+        to_ms: i64,
+        granularity: TimelineGranularity,
+    ) -> Result<Vec<TimelineBucket>, LateraError> {
+        if to_ms <= from_ms {
+            return Err(LateraError::InvalidArgument(format!(
+                "timeline end {to_ms} must be after start {from_ms}"
+            )));
+        }
+        let bucket_ms = granularity.bucket_ms();
+        let bucket_count = (to_ms.saturating_sub(from_ms) - 1) / bucket_ms + 1;
+        if bucket_count > MAX_TIMELINE_BUCKETS {
+            return Err(LateraError::InvalidArgument(format!(
+                "timeline has {bucket_count} buckets, at most {MAX_TIMELINE_BUCKETS} allowed"
+            )));
+        }
+        let mut buckets: Vec<TimelineBucket> = (0..bucket_count)
+            .map(|index| {
+                let start_ms = from_ms + index * bucket_ms;
+                TimelineBucket {
+                    start_ms,
+                    end_ms: (start_ms + bucket_ms).min(to_ms),
+                    ..TimelineBucket::default()
+                }
+            })
+            .collect();
+        let bucket_index = |bucket: i64| usize::try_from(bucket).ok();
+
+        let mut stmt = self.conn.prepare(
+            "SELECT (detected_at_ms - ?1) / ?3 AS bucket, COUNT(*), COUNT(DISTINCT path),
+                COALESCE(SUM(CASE WHEN kind IN ('created', 'modified') THEN size_bytes END), 0)
+             FROM events
+             WHERE detected_at_ms >= ?1 AND detected_at_ms < ?2 AND self_generated = 0
+             GROUP BY bucket",
+        )?;
+        let rows = stmt.query_map(params![from_ms, to_ms, bucket_ms], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, i64>(2)?,
+                row.get::<_, i64>(3)?,
+            ))
+        })?;
+        for row in rows {
+            let (bucket, events, files, bytes) = row?;
+            if let Some(entry) = bucket_index(bucket).and_then(|i| buckets.get_mut(i)) {
+                entry.event_count = u64::try_from(events).unwrap_or(0);
+                entry.file_count = u64::try_from(files).unwrap_or(0);
+                entry.bytes = u64::try_from(bytes).unwrap_or(0);
+            }
+        }
+
+        let mut stmt = self.conn.prepare(
+            "SELECT (detected_at_ms - ?1) / ?3 AS bucket, mime_type, COUNT(*) AS n
+             FROM events
+             WHERE detected_at_ms >= ?1 AND detected_at_ms < ?2 AND self_generated = 0
+                AND mime_type IS NOT NULL
+             GROUP BY bucket, mime_type
+             ORDER BY bucket, n DESC, mime_type",
+        )?;
+        let rows = stmt.query_map(params![from_ms, to_ms, bucket_ms], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, i64>(2)?,
+            ))
+        })?;
+        let mut types: HashMap<usize, Vec<(String, u64)>> = HashMap::new();
+        for row in rows {
+            let (bucket, mime_type, count) = row?;
+            let Some(index) = bucket_index(bucket) else {
+                continue;
+            };
+            let top = types.entry(index).or_default();
+            if top.len() < TIMELINE_TOP_TYPES {
+                top.push((mime_type, u64::try_from(count).unwrap_or(0)));
+            }
+        }
+        for (index, top) in types {
+            if let Some(entry) = buckets.get_mut(index) {
+                entry.top_types = top;
+            }
+        }
+        Ok(buckets)
+    }
+
+    /// Файлы, которые по журналу сейчас существуют: события воспроизводятся
+    /// по порядку (появление/изменение — файл есть, удаление или
+    /// переименование из пути — нет).
+    pub fn known_files(&self) -> Result<HashSet<PathBuf>, LateraError> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT kind, path, previous_path FROM events ORDER BY id")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, Option<String>>(2)?,
+            ))
+        })?;
+        let mut known = HashSet::new();
+        for row in rows {
+            let (kind, path, previous_path) = row?;
+            let Some(kind) = parse_kind(&kind) else {
+                continue;
+            };
+            if let Some(previous) = previous_path {
+                known.remove(&PathBuf::from(previous));
+            }
+            let path = PathBuf::from(path);
+            if kind.is_arrival() || kind == FileEventKind::Modified {
+                known.insert(path);
+            } else {
+                known.remove(&path);
+            }
+        }
+        Ok(known)
+    }
+
+    /// Удаляет все записи. Возвращает их число.
+    pub fn clear(&self) -> Result<usize, LateraError> {
+        Ok(self.conn.execute("DELETE FROM events", [])?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(kind: FileEventKind, path: &str, detected_at_ms: i64) -> InternalFileEvent {
+        InternalFileEvent {
+            kind,
+            file_name: path.rsplit('/').next().unwrap_or(path).to_string(),
+            full_path: PathBuf::from(path),
+            previous_path: None,
+            occurred_at_ms: detected_at_ms,
+            detected_at_ms,
+            monotonic_ms: 0,
+            created_at_ms: None,
+            modified_at_ms: None,
+            size_bytes: Some(42),
+            extension: None,
+            mime_type: Some("text/plain".to_string()),
+            access: None,
+            self_generated: false,
+            reconciled: false,
+            claim: None,
+            debug: None,
+        }
+    }
+
+    #[test]
+    fn test_record_and_query_since() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join(JOURNAL_DB_FILE);
+        let journal = EventJournal::open(&db_path).unwrap();
+
+        journal
+            .record("w1", &event(FileEventKind::Created, "/w/a.txt", 100))
+            .unwrap();
+        journal
+            .record("w1", &event(FileEventKind::Modified, "/w/a.txt", 200))
+            .unwrap();
+        journal
+            .record("w2", &event(FileEventKind::Removed, "/w/b.txt", 300))
+            .unwrap();
+
+        let entries = journal.query(200, 10).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].kind, FileEventKind::Modified);
+        assert_eq!(entries[0].size_bytes, Some(42));
+        assert_eq!(entries[1].watcher_id, "w2");
+        assert_eq!(journal.query(0, 1).unwrap().len(), 1);
+
+        // История переживает перезапуск
+        drop(journal);
+        let journal = EventJournal::open(&db_path).unwrap();
+        assert_eq!(journal.query(0, 10).unwrap().len(), 3);
+        let known = journal.known_files().unwrap();
+        assert_eq!(known, HashSet::from([PathBuf::from("/w/a.txt")]));
+        assert_eq!(journal.clear().unwrap(), 3);
+        assert!(journal.query(0, 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_timeline_aggregates_buckets() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let journal = EventJournal::open(&temp_dir.path().join(JOURNAL_DB_FILE)).unwrap();
+        let hour = TimelineGranularity::Hour.bucket_ms();
+        let from = 10 * hour;
+
+        journal
+            .record("w", &event(FileEventKind::Created, "/w/a.txt", from + 1))
+            .unwrap();
+        journal
+            .record("w", &event(FileEventKind::Modified, "/w/a.txt", from + 2))
+            .unwrap();
+        let mut pdf = event(FileEventKind::Created, "/w/b.pdf", from + 3);
+        pdf.mime_type = Some("application/pdf".to_string());
+        journal.record("w", &pdf).unwrap();
+        journal
+            .record(
+                "w",
+                &event(FileEventKind::Removed, "/w/c.txt", from + 2 * hour),
+            )
+            .unwrap();
+        let mut own = event(FileEventKind::Created, "/w/d.txt", from + 4);
+        own.self_generated = true;
+        journal.record("w", &own).unwrap();
+        // Вне интервала
+        journal
+            .record("w", &event(FileEventKind::Created, "/w/e.txt", from - 1))
+            .unwrap();
+
+        let buckets = journal
+            .timeline(from, from + 3 * hour, TimelineGranularity::Hour)
+            .unwrap();
+        assert_eq!(buckets.len(), 3);
+        assert_eq!(buckets[0].event_count, 3);
+        assert_eq!(buckets[0].file_count, 2);
+        assert_eq!(buckets[0].bytes, 126);
+        assert_eq!(
+            buckets[0].top_types,
+            vec![
+                ("text/plain".to_string(), 2),
+                ("application/pdf".to_string(), 1)
+            ]
+        );
+        assert_eq!(
+            buckets[1],
+            TimelineBucket {
+                start_ms: from + hour,
+                end_ms: from + 2 * hour,
+                ..TimelineBucket::default()
+            }
+        );
+        // Удаление не добавляет байт
+        assert_eq!((buckets[2].event_count, buckets[2].bytes), (1, 0));
+
+        assert!(journal
+            .timeline(from, from, TimelineGranularity::Day)
+            .is_err());
+    }
+}
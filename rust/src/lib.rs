@@ -57,17 +57,67 @@
     clippy::filter_map_next
 )]
 
+pub mod adoption;
+pub mod archive;
+pub mod audit_log;
+pub mod claims;
+pub mod codes;
+pub mod composition;
+pub mod config;
+pub mod desktop_access;
+pub mod dir_snapshot;
+pub mod disk_space;
+pub mod dry_run;
+pub mod duplicates;
+pub mod email;
 pub mod error;
+pub mod event_ack;
+pub mod event_chunk;
+pub mod event_wal;
+pub mod expected_changes;
 pub mod ffi_llm;
 pub mod ffi_ocr;
 pub mod ffi_rag;
 pub mod ffi_search;
 pub mod ffi_system;
+pub mod file_lock;
+pub mod file_metadata;
+pub mod file_ops;
+pub mod file_status;
+pub mod file_type;
 pub mod file_watcher;
 pub mod frb_generated;
+pub mod hashing;
+pub mod heartbeat;
 pub mod indexer;
+pub mod intake;
+pub mod integrity;
+pub mod internal_files;
+pub mod journal;
+pub mod lifecycle;
 pub mod logging;
+pub mod metrics;
+pub mod name_conflict;
+pub mod natural_sort;
+pub mod onboarding;
+pub mod path_utils;
+pub mod pdf_layout;
+pub mod policy;
+pub mod portable_config;
+pub mod preview;
+pub mod quota;
+pub mod read_only;
+pub mod recovery;
+pub mod rules;
+pub mod settings;
+pub mod shared_bytes;
 pub mod system_info;
+pub mod tags;
+pub mod telemetry;
+pub mod thumbnails;
+pub mod trash;
+pub mod volume;
+pub mod xattr;
 
 // FRB rust-input по требованию лежит в корне `rust/api.rs`.
 // Подключаем его как модуль, чтобы он участвовал в сборке crate.
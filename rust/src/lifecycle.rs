@@ -0,0 +1,248 @@
+//! Жизненный цикл Rust Core.
+//!
+//! Отвечает за:
+//! - единую инициализацию ядра (`init_core`): логирование, пути, хранилище,
+//!   общий async runtime
+//! - проверку, что инициализация выполнена, перед вызовом API
+//! - восстановление после аварийного завершения прошлого запуска
+//! - сброс состояния при shutdown
+
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+
+use crate::error::LateraError;
+use crate::file_watcher;
+use crate::logging;
+use crate::recovery;
+use crate::system_info;
+
+/// Конфигурация инициализации ядра.
+#[derive(Clone, Debug, Default)]
+pub struct CoreConfig {
+    /// Папка данных приложения. `None` = `{data_local_dir}/Latera`.
+    pub data_dir: Option<PathBuf>,
+}
+
+/// Возможности ядра на текущей машине (результат `init_core`).
+#[derive(Clone, Debug)]
+pub struct CoreCapabilities {
+    /// Версия Rust Core (из `Cargo.toml`).
+    pub core_version: String,
+    /// Целевая ОС (`"windows"`, `"macos"`, `"linux"`).
+    pub platform: String,
+    /// Фактическая папка данных приложения.
+    pub data_dir: PathBuf,
+    /// Доступен ли системный OCR (Windows.Media.Ocr).
+    pub ocr_available: bool,
+    /// Поддерживает ли процессор AVX2.
+    pub has_avx2: bool,
+    /// Доступен ли Vulkan runtime.
+    pub has_vulkan: bool,
+    /// Объём физической памяти в мегабайтах.
+    pub total_ram_mb: u64,
+}
+
+/// Потоков общего runtime: фоновая async-работа ядра лёгкая.
+const RUNTIME_WORKER_THREADS: usize = 2;
+
+/// Состояние инициализированного ядра.
+#[derive(Debug)]
+struct CoreState {
+    capabilities: CoreCapabilities,
+    /// Общий runtime ядра (циклы watcher'ов и другая async-работа).
+    runtime: tokio::runtime::Runtime,
+}
+
+// Глобальное состояние: None = ядро не инициализировано.
+static CORE_STATE: Lazy<Mutex<Option<CoreState>>> = Lazy::new(|| Mutex::new(None));
+
+/// Инициализирует ядро.
+///
+/// Безопасен для повторного вызова — если ядро уже инициализировано,
+/// возвращает текущие возможности (новая конфигурация игнорируется).
+pub fn init_core(config: &CoreConfig) -> Result<CoreCapabilities, LateraError> {
+    logging::init_logging();
+
+    let mut guard = CORE_STATE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    if let Some(state) = guard.as_ref() {
+        if config
+            .data_dir
+            .as_deref()
+            .is_some_and(|d| d != state.capabilities.data_dir)
+        {
+            warn!("init_core called again with a different data_dir; keeping the initial one for process-wide state");
+        }
+        info!("Core already initialized, skipping");
+        return Ok(state.capabilities.clone());
+    }
+
+    let data_dir = match &config.data_dir {
+        Some(dir) => prepare_data_dir(dir)?,
+        None => {
+            let dir = default_data_dir()?;
+            std::fs::create_dir_all(&dir)?;
+            dir
+        }
+    };
+
+    // Артефакты прошлого запуска не должны мешать старту — только логируем.
+    if let Err(e) = recovery::recover(&data_dir) {
+        warn!("Startup recovery failed: {e}");
+    }
+
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(RUNTIME_WORKER_THREADS)
+        .thread_name("latera-core")
+        .enable_time()
+        .build()?;
+
+    let capabilities = CoreCapabilities {
+        core_version: env!("CARGO_PKG_VERSION").to_string(),
+        platform: std::env::consts::OS.to_string(),
+        data_dir,
+        ocr_available: cfg!(target_os = "windows"),
+        has_avx2: system_info::cached_has_avx2(),
+        has_vulkan: system_info::get_has_vulkan(),
+        total_ram_mb: system_info::get_total_ram_mb(),
+    };
+
+    info!(
+        "Core initialized: version={}, platform={}, data_dir={}",
+        capabilities.core_version,
+        capabilities.platform,
+        capabilities.data_dir.display()
+    );
+
+    *guard = Some(CoreState {
+        capabilities: capabilities.clone(),
+        runtime,
+    });
+    Ok(capabilities)
+}
+
+/// Проверяет, что ядро инициализировано через [`init_core`].
+pub fn ensure_initialized() -> Result<(), LateraError> {
+    if is_initialized() {
+        Ok(())
+    } else {
+        Err(LateraError::CoreNotInitialized)
+    }
+}
+
+/// Инициализировано ли ядро.
+pub fn is_initialized() -> bool {
+    CORE_STATE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .is_some()
+}
+
+/// Папка данных приложения, выбранная при инициализации.
+pub fn data_dir() -> Result<PathBuf, LateraError> {
+    CORE_STATE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+        .map(|s| s.capabilities.data_dir.clone())
+        .ok_or(LateraError::CoreNotInitialized)
+}
+
+/// Общий runtime ядра; `None` — ядро не инициализировано.
+pub fn runtime() -> Option<tokio::runtime::Handle> {
+    CORE_STATE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+        .map(|s| s.runtime.handle().clone())
+}
+
+/// Сбрасывает состояние ядра (вызывается из `shutdown_core`).
+///
+/// После сброса API снова требует [`init_core`].
+pub fn reset() {
+    if let Some(state) = CORE_STATE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take()
+    {
+        recovery::release_lock(&state.capabilities.data_dir);
+        // Watcher'ы к этому моменту остановлены; зависшие задачи не ждём
+        state.runtime.shutdown_background();
+        info!("Core state reset");
+    }
+}
+
+/// Дефолтная папка данных: `{data_local_dir}/Latera`.
+fn default_data_dir() -> Result<PathBuf, LateraError> {
+    let local_data = dirs::data_local_dir().ok_or(LateraError::DataLocalDirNotFound)?;
+    Ok(local_data.join(file_watcher::DEFAULT_WATCH_FOLDER_NAME))
+}
+
+/// Проверяет заданную папку данных (абсолютный непустой путь) и создаёт её.
+pub fn prepare_data_dir(dir: &Path) -> Result<PathBuf, LateraError> {
+    let dir = resolve_data_dir(dir)?;
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+fn resolve_data_dir(dir: &Path) -> Result<PathBuf, LateraError> {
+    if dir.as_os_str().is_empty() {
+        return Err(LateraError::InvalidPath("empty data_dir".to_string()));
+    }
+    if !dir.is_absolute() {
+        return Err(LateraError::InvalidPath(format!(
+            "data_dir must be absolute: {}",
+            dir.display()
+        )));
+    }
+    Ok(dir.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_data_dir_rejects_relative() {
+        assert!(resolve_data_dir(Path::new("relative/dir")).is_err());
+        assert!(resolve_data_dir(Path::new("")).is_err());
+    }
+
+    #[test]
+    fn test_init_core_lifecycle() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let data_dir = temp_dir.path().join("data");
+
+        reset();
+        assert!(matches!(
+            ensure_initialized(),
+            Err(LateraError::CoreNotInitialized)
+        ));
+
+        let config = CoreConfig {
+            data_dir: Some(data_dir.clone()),
+        };
+        let caps = init_core(&config).unwrap();
+        assert_eq!(caps.data_dir, data_dir);
+        assert!(data_dir.is_dir());
+        assert!(data_dir.join(recovery::INSTANCE_LOCK_FILE).exists());
+        assert!(ensure_initialized().is_ok());
+        let handle = runtime().unwrap();
+        assert_eq!(handle.block_on(async { 2 + 2 }), 4);
+
+        // Повторный вызов возвращает исходные возможности
+        let again = init_core(&CoreConfig::default()).unwrap();
+        assert_eq!(again.data_dir, data_dir);
+
+        reset();
+        assert!(!is_initialized());
+        assert!(runtime().is_none());
+        assert!(!data_dir.join(recovery::INSTANCE_LOCK_FILE).exists());
+    }
+}
@@ -0,0 +1,185 @@
+//! Слой `tracing`: события и span'ы попадают в те же приёмники, что и `log`.
+//!
+//! Поля span'а вместе с полями его предков сохраняются при создании; при
+//! входе в span они кладутся в стек треда. Записи, сделанные внутри span'а —
+//! через `tracing` или через `log`, — получают поля верхнего span'а стека
+//! (`watcher_id`, `correlation_id`, ...).
+
+use std::cell::RefCell;
+use std::fmt;
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::subscriber::Interest;
+use tracing::{Event, Metadata, Subscriber};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+use super::{EventRecord, FieldValue};
+
+thread_local! {
+    /// Span'ы, в которые вошёл тред, от внешнего к внутреннему.
+    static ENTERED: RefCell<Vec<(Id, SpanScope)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Имена span'а и его предков (от внешнего к внутреннему) и их поля.
+#[derive(Clone, Debug, Default)]
+pub(super) struct SpanScope {
+    pub names: Vec<&'static str>,
+    pub fields: Vec<(String, FieldValue)>,
+}
+
+impl SpanScope {
+    /// Поле с тем же именем заменяется: значение внутреннего span'а важнее.
+    fn set(&mut self, key: String, value: FieldValue) {
+        match self.fields.iter_mut().find(|(k, _)| *k == key) {
+            Some(field) => field.1 = value,
+            None => self.fields.push((key, value)),
+        }
+    }
+}
+
+/// Поля самого внутреннего span'а, в который вошёл текущий тред.
+pub(super) fn current_scope() -> Option<SpanScope> {
+    ENTERED.with(|entered| entered.borrow().last().map(|(_, scope)| scope.clone()))
+}
+
+/// Собирает сообщение и поля события или span'а.
+#[derive(Default)]
+struct FieldVisitor {
+    message: Option<String>,
+    fields: Vec<(String, FieldValue)>,
+}
+
+impl FieldVisitor {
+    fn push(&mut self, field: &Field, value: FieldValue) {
+        self.fields.push((field.name().to_string(), value));
+    }
+}
+
+impl Visit for FieldVisitor {
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.push(field, FieldValue::Bool(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.push(field, FieldValue::I64(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.push(field, FieldValue::U64(value));
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.push(field, FieldValue::F64(value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = Some(value.to_string());
+        } else {
+            self.push(field, FieldValue::Str(value.to_string()));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        // `%value` и `format_args!` приходят сюда же: их Debug — это Display.
+        if field.name() == "message" {
+            self.message = Some(format!("{value:?}"));
+        } else {
+            self.push(field, FieldValue::Str(format!("{value:?}")));
+        }
+    }
+}
+
+/// Рассылает события `tracing` по приёмникам логгера.
+pub(super) struct SinkLayer;
+
+impl<S> Layer<S> for SinkLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn register_callsite(&self, metadata: &'static Metadata<'static>) -> Interest {
+        // Уровни приёмников меняются на лету — события проверяются каждый раз.
+        if metadata.is_span() {
+            Interest::always()
+        } else {
+            Interest::sometimes()
+        }
+    }
+
+    fn enabled(&self, metadata: &Metadata<'_>, _ctx: Context<'_, S>) -> bool {
+        // Span'ы нужны всегда: их поля получают записи любого уровня.
+        metadata.is_span() || super::log_level_of(metadata.level()) <= log::max_level()
+    }
+
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut scope = span
+            .parent()
+            .and_then(|parent| parent.extensions().get::<SpanScope>().cloned())
+            .unwrap_or_default();
+        scope.names.push(attrs.metadata().name());
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        for (key, value) in visitor.fields {
+            scope.set(key, value);
+        }
+        span.extensions_mut().insert(scope);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut visitor = FieldVisitor::default();
+        values.record(&mut visitor);
+        let mut extensions = span.extensions_mut();
+        let Some(scope) = extensions.get_mut::<SpanScope>() else {
+            return;
+        };
+        for (key, value) in visitor.fields {
+            scope.set(key, value);
+        }
+        let updated = scope.clone();
+        ENTERED.with(|entered| {
+            for (entered_id, scope) in entered.borrow_mut().iter_mut() {
+                if entered_id == id {
+                    *scope = updated.clone();
+                }
+            }
+        });
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        let scope = ctx
+            .span(id)
+            .and_then(|span| span.extensions().get::<SpanScope>().cloned())
+            .unwrap_or_default();
+        ENTERED.with(|entered| entered.borrow_mut().push((id.clone(), scope)));
+    }
+
+    fn on_exit(&self, id: &Id, _ctx: Context<'_, S>) {
+        ENTERED.with(|entered| {
+            let mut entered = entered.borrow_mut();
+            if let Some(position) = entered.iter().rposition(|(entered_id, _)| entered_id == id) {
+                entered.remove(position);
+            }
+        });
+    }
+
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        super::dispatch(EventRecord::new(
+            super::log_level_of(metadata.level()),
+            metadata.target().to_string(),
+            visitor.message.unwrap_or_default(),
+            visitor.fields,
+        ));
+    }
+}
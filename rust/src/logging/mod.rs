@@ -0,0 +1,953 @@
+//! Нормализованное логирование для Latera Rust Core.
+//!
+//! ## Уровни логов
+//! - `ERROR`: критические ошибки, требующие внимания
+//! - `WARN`:  предупреждения, некритичные проблемы
+//! - `INFO`:  важные события жизненного цикла (startup, shutdown, key operations)
+//! - `DEBUG`: детальная информация для отладки
+//! - `TRACE`: максимально детальный вывод (включая данные)
+//!
+//! ## Корреляция событий
+//! Каждый лог может содержать `correlation_id` для трассировки запросов
+//! через границы Rust/Flutter.
+//!
+//! ## Span'ы
+//! Записи `log` и `tracing` идут в одни приёмники. Поля активного span'а
+//! `tracing` (и его предков) добавляются к каждой записи, сделанной внутри
+//! него, — так все логи watcher'а получают его `watcher_id` и `correlation_id`:
+//! ```ignore
+//! let _span = tracing::info_span!("start_watcher", watcher_id = %id, correlation_id = %ctx.correlation_id).entered();
+//! log::info!("Starting watcher"); // поля span'а попадут в запись
+//! ```
+//!
+//! ## Использование
+//! ```ignore
+//! use latera_rust::logging::{init_logging, LogContext};
+//!
+//! init_logging(); // вызывается один раз при старте
+//!
+//! log::info!(target: "latera::file_watcher", "Starting watcher");
+//! latera_rust::log_event!(info, target: "latera::file_watcher",
+//!     correlation_id:% = ctx.correlation_id, path:% = path.display(), size, "file added");
+//! ```
+//!
+//! ## Структурированные поля
+//! [`log_event!`](crate::log_event) пишет поля отдельно от сообщения:
+//! - JSON (по умолчанию): одна JSON-строка на запись; `correlation_id` и имена
+//!   span'ов (`spans`) — на верхнем уровне, остальные поля — в объекте `fields`;
+//! - текстовый формат (`LATERA_LOG_FORMAT=text` или [`set_log_format`]):
+//!   `[ts] [I] [target] file added correlation_id=corr_1 path=/a.txt size=10`.
+//!
+//! ## Маршрутизация по target
+//! Каждый watcher пишет под своим target `latera::watcher::{id}`
+//! (см. [`watcher_target`]). Target можно дополнительно направить в отдельный
+//! ротируемый файл через [`route_target_to_file`] — удобно сравнивать
+//! логи разных watcher'ов бок о бок.
+//!
+//! ## Приёмники
+//! Записи рассылаются одновременно в stderr, основной ротируемый файл
+//! (по умолчанию `{local data}/Latera/logs/latera.log`, см. [`set_log_file`]
+//! и [`set_log_file_policy`]), кольцевой буфер в памяти ([`recent_logs`]) и stream
+//! в Dart ([`set_log_stream`]). У каждого приёмника свой уровень
+//! ([`set_sink_level`]). ERROR/WARN можно дублировать в системный журнал ОС
+//! ([`set_system_log_mirroring`]).
+//!
+//! ## Повторяющиеся предупреждения
+//! Массовые предупреждения (например, при превышении rate limit) пишутся
+//! через [`LogThrottle`]: первые несколько — как есть, остальные сводятся
+//! в периодическое «suppressed N similar messages».
+
+mod layer;
+mod rotating;
+mod sinks;
+mod system;
+mod throttle;
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, Once};
+
+use log::kv::{Key, Value, VisitSource};
+use log::{Level, LevelFilter, Log, Metadata};
+use once_cell::sync::{Lazy, OnceCell};
+use std::io::Write;
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::layer::SubscriberExt;
+
+pub use rotating::{RotatingFile, RotationPolicy};
+use sinks::Sinks;
+pub use sinks::{LogEntry, LogSinkKind, RingBuffer, StreamCallback, DEFAULT_RING_BUFFER_CAPACITY};
+pub use system::SYSTEM_LOG_SOURCE;
+pub use throttle::{LogThrottle, DEFAULT_THROTTLE_BURST, DEFAULT_THROTTLE_INTERVAL};
+
+static INIT: Once = Once::new();
+
+/// Писать записи в JSON вместо текстового формата.
+static JSON_FORMAT: AtomicBool = AtomicBool::new(true);
+
+/// Формат вывода логов.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogFormat {
+    /// `[timestamp] [LEVEL] [target] message key=value ...`
+    Text,
+    /// Одна JSON-строка на запись (по умолчанию).
+    Json,
+}
+
+/// Имя основного лог-файла.
+pub const LOG_FILE_NAME: &str = "latera.log";
+
+/// Переопределяет папку основного лог-файла; пустое значение отключает запись в файл.
+pub const LOG_DIR_ENV: &str = "LATERA_LOG_DIR";
+
+/// Фильтр stderr, если `RUST_LOG` не задан.
+pub const DEFAULT_CONSOLE_FILTER: &str = "latera_rust=info,latera=info,notify=warn";
+
+/// Поле корреляции; в JSON выносится на верхний уровень записи.
+pub const CORRELATION_ID_FIELD: &str = "correlation_id";
+
+/// Префикс target'ов watcher'ов.
+pub const WATCHER_TARGET_PREFIX: &str = "latera::watcher::";
+
+/// Файлы, в которые дополнительно пишутся записи отдельных target'ов.
+static TARGET_FILES: Lazy<Mutex<HashMap<String, RotatingFile>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Фильтр stderr (`RUST_LOG`), задаётся в [`init_logging`].
+static CONSOLE_TARGETS: OnceCell<Targets> = OnceCell::new();
+
+/// Приёмники логов и их уровни.
+static SINKS: Lazy<Mutex<Sinks>> = Lazy::new(|| Mutex::new(Sinks::new()));
+
+thread_local! {
+    /// Запись уже рассылается на этом треде — логирование из приёмника
+    /// (например, из Dart-callback'а) пропускается, иначе deadlock на `SINKS`.
+    static IN_DISPATCH: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Логгер `log`: записи рассылаются по приёмникам (см. [`LogSinkKind`]).
+struct LateraLogger;
+
+impl Log for LateraLogger {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        dispatch(EventRecord::from_log(record));
+    }
+
+    fn flush(&self) {
+        let _ = std::io::stderr().flush();
+        if let Some(file) = lock_sinks().file.as_mut() {
+            let _ = file.flush();
+        }
+        for file in TARGET_FILES
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .values_mut()
+        {
+            let _ = file.flush();
+        }
+    }
+}
+
+/// Значение структурированного поля; числа и `bool` сохраняют тип в JSON.
+#[derive(Clone, Debug, PartialEq)]
+enum FieldValue {
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    F64(f64),
+    Str(String),
+}
+
+impl FieldValue {
+    fn from_kv(value: &Value<'_>) -> Self {
+        if let Some(b) = value.to_bool() {
+            Self::Bool(b)
+        } else if let Some(n) = value.to_i64() {
+            Self::I64(n)
+        } else if let Some(n) = value.to_u64() {
+            Self::U64(n)
+        } else if let Some(n) = value.to_f64() {
+            Self::F64(n)
+        } else {
+            Self::Str(value.to_string())
+        }
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::Bool(b) => (*b).into(),
+            Self::I64(n) => (*n).into(),
+            Self::U64(n) => (*n).into(),
+            Self::F64(n) => {
+                serde_json::Number::from_f64(*n).map_or_else(|| n.to_string().into(), Into::into)
+            }
+            Self::Str(s) => s.as_str().into(),
+        }
+    }
+}
+
+impl std::fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool(b) => b.fmt(f),
+            Self::I64(n) => n.fmt(f),
+            Self::U64(n) => n.fmt(f),
+            Self::F64(n) => n.fmt(f),
+            Self::Str(s) => s.fmt(f),
+        }
+    }
+}
+
+/// Запись из `log` или `tracing` до форматирования.
+#[derive(Clone, Debug)]
+struct EventRecord {
+    timestamp: String,
+    level: Level,
+    target: String,
+    message: String,
+    /// Поля записи, затем поля активных span'ов, которых нет среди них.
+    fields: Vec<(String, FieldValue)>,
+    /// Имена активных span'ов, от внешнего к внутреннему.
+    spans: Vec<&'static str>,
+}
+
+impl EventRecord {
+    /// Запись с полями span'а, в котором сейчас находится тред.
+    fn new(
+        level: Level,
+        target: String,
+        message: String,
+        fields: Vec<(String, FieldValue)>,
+    ) -> Self {
+        let mut record = Self {
+            timestamp: chrono_timestamp(),
+            level,
+            target,
+            message,
+            fields,
+            spans: Vec::new(),
+        };
+        if let Some(scope) = layer::current_scope() {
+            for (key, value) in scope.fields {
+                if !record.fields.iter().any(|(k, _)| *k == key) {
+                    record.fields.push((key, value));
+                }
+            }
+            record.spans = scope.names;
+        }
+        record
+    }
+
+    fn from_log(record: &log::Record<'_>) -> Self {
+        Self::new(
+            record.level(),
+            record.target().to_string(),
+            record.args().to_string(),
+            collect_fields(record),
+        )
+    }
+
+    /// Значение поля в текстовом виде.
+    fn field(&self, key: &str) -> Option<String> {
+        self.fields
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.to_string())
+    }
+}
+
+/// Рассылает запись по приёмникам.
+fn dispatch(record: EventRecord) {
+    if IN_DISPATCH.with(Cell::get) {
+        return;
+    }
+    IN_DISPATCH.with(|flag| flag.set(true));
+    write_to_sinks(record);
+    IN_DISPATCH.with(|flag| flag.set(false));
+}
+
+fn write_to_sinks(record: EventRecord) {
+    let level = record.level;
+    let mut sinks = lock_sinks();
+
+    if level <= sinks.stderr_level && (!sinks.use_env_filter || console_enabled(&record)) {
+        let _ = writeln!(std::io::stderr().lock(), "{}", format_line(&record));
+    }
+
+    if level <= sinks.file_level {
+        let mut routed = TARGET_FILES
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let routed = routed.get_mut(&record.target);
+        if sinks.file.is_some() || routed.is_some() {
+            let line = format_line(&record);
+            if let Some(file) = sinks.file.as_mut() {
+                write_to_file(file, &line);
+            }
+            if let Some(file) = routed {
+                write_to_file(file, &line);
+            }
+        }
+    }
+
+    let to_ring = level <= sinks.ring_level;
+    let to_stream = sinks.stream.is_some() && level <= sinks.stream_level;
+    let to_system = level <= sinks.system_level;
+    if !to_ring && !to_stream && !to_system {
+        return;
+    }
+    let entry = log_entry(record);
+    if to_system {
+        system::write(&entry);
+    }
+    if to_stream {
+        let open = sinks.stream.as_ref().is_some_and(|emit| emit(&entry));
+        if !open {
+            // Подписчик закрыл stream — отключаем приёмник.
+            sinks.stream = None;
+            log::set_max_level(sinks.max_level());
+        }
+    }
+    if to_ring {
+        sinks.ring.push(entry);
+    }
+}
+
+/// Пропускает ли фильтр `RUST_LOG` запись в stderr.
+fn console_enabled(record: &EventRecord) -> bool {
+    CONSOLE_TARGETS
+        .get()
+        .is_none_or(|targets| targets.would_enable(&record.target, &tracing_level_of(record.level)))
+}
+
+fn lock_sinks() -> std::sync::MutexGuard<'static, Sinks> {
+    SINKS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+fn write_to_file(file: &mut RotatingFile, line: &str) {
+    // Логировать ошибку логирования нельзя — только stderr.
+    if let Err(e) = file.write_line(line) {
+        eprintln!("Failed to write log file {}: {e}", file.path().display());
+    }
+}
+
+fn log_entry(record: EventRecord) -> LogEntry {
+    LogEntry {
+        timestamp: record.timestamp,
+        level: record.level,
+        target: record.target,
+        message: record.message,
+        fields: record
+            .fields
+            .into_iter()
+            .map(|(key, value)| (key, value.to_string()))
+            .collect(),
+    }
+}
+
+fn log_level_of(level: &tracing::Level) -> Level {
+    match *level {
+        tracing::Level::ERROR => Level::Error,
+        tracing::Level::WARN => Level::Warn,
+        tracing::Level::INFO => Level::Info,
+        tracing::Level::DEBUG => Level::Debug,
+        tracing::Level::TRACE => Level::Trace,
+    }
+}
+
+fn tracing_level_of(level: Level) -> tracing::Level {
+    match level {
+        Level::Error => tracing::Level::ERROR,
+        Level::Warn => tracing::Level::WARN,
+        Level::Info => tracing::Level::INFO,
+        Level::Debug => tracing::Level::DEBUG,
+        Level::Trace => tracing::Level::TRACE,
+    }
+}
+
+/// Фильтр stderr: `RUST_LOG` или, если он не задан, [`DEFAULT_CONSOLE_FILTER`].
+fn console_targets() -> Targets {
+    let default = || DEFAULT_CONSOLE_FILTER.parse().unwrap_or_default();
+    match std::env::var("RUST_LOG") {
+        Ok(directives) if !directives.trim().is_empty() => directives.parse().unwrap_or_else(|e| {
+            eprintln!("Invalid RUST_LOG {directives:?}: {e}");
+            default()
+        }),
+        _ => default(),
+    }
+}
+
+/// Самый подробный уровень, который пропускает фильтр stderr.
+fn console_max_level(targets: &Targets) -> LevelFilter {
+    targets
+        .iter()
+        .map(|(_, level)| level)
+        .chain(targets.default_level())
+        .max()
+        .and_then(tracing_subscriber::filter::LevelFilter::into_level)
+        .map_or(LevelFilter::Off, |level| {
+            log_level_of(&level).to_level_filter()
+        })
+}
+
+/// Инициализировать логирование (idempotent).
+///
+/// Управление уровнем логов: переменная окружения `RUST_LOG`
+/// (по умолчанию [`DEFAULT_CONSOLE_FILTER`]).
+/// Примеры:
+/// - `RUST_LOG=info` — только INFO и выше
+/// - `RUST_LOG=latera_rust=debug` — DEBUG для нашего crate, остальное отключено
+/// - `RUST_LOG=trace` — максимально детальный вывод
+///
+/// `RUST_LOG` ограничивает только stderr; уровни остальных приёмников
+/// задаются через [`set_sink_level`], всех сразу — через [`set_log_level`].
+///
+/// Формат вывода — JSON; `LATERA_LOG_FORMAT=text` возвращает текстовый
+/// (см. [`set_log_format`]).
+///
+/// Устанавливает глобальный subscriber `tracing` со слоем, который пишет
+/// в те же приёмники.
+///
+/// Основной лог-файл открывается в [`default_log_dir`]: в упакованном
+/// приложении stderr никто не видит. Если папка недоступна, логи пишутся
+/// только в stderr.
+pub fn init_logging() {
+    INIT.call_once(|| {
+        match std::env::var("LATERA_LOG_FORMAT") {
+            Ok(v) if v.eq_ignore_ascii_case("text") => set_log_format(LogFormat::Text),
+            Ok(v) if v.eq_ignore_ascii_case("json") => set_log_format(LogFormat::Json),
+            _ => {}
+        }
+        let console = console_targets();
+        let mut sinks = lock_sinks();
+        sinks.console_filter = console_max_level(&console);
+        let _ = CONSOLE_TARGETS.set(console);
+        if let Some(dir) = default_log_dir() {
+            if let Err(e) = sinks.open_file(Some(&dir.join(LOG_FILE_NAME))) {
+                eprintln!("Failed to open log file in {}: {e}", dir.display());
+            }
+        }
+        if log::set_boxed_logger(Box::new(LateraLogger)).is_ok() {
+            log::set_max_level(sinks.max_level());
+        }
+        drop(sinks);
+        // Если приложение уже установило свой subscriber, span'ы и события
+        // `tracing` идут в него; записи `log` по-прежнему приходят сюда.
+        let _ = tracing::subscriber::set_global_default(
+            tracing_subscriber::registry().with(layer::SinkLayer),
+        );
+    });
+}
+
+/// Папка основного лог-файла: `{local data}/Latera/logs`
+/// (`%LOCALAPPDATA%` на Windows, `~/Library/Application Support` на macOS,
+/// `~/.local/share` на Linux) или [`LOG_DIR_ENV`].
+///
+/// `None` — папка неизвестна или запись в файл отключена через [`LOG_DIR_ENV`].
+pub fn default_log_dir() -> Option<PathBuf> {
+    match std::env::var_os(LOG_DIR_ENV) {
+        Some(dir) if dir.is_empty() => None,
+        Some(dir) => Some(PathBuf::from(dir)),
+        None => dirs::data_local_dir().map(|d| d.join("Latera").join("logs")),
+    }
+}
+
+/// Установить уровень приёмника.
+pub fn set_sink_level(kind: LogSinkKind, level: LevelFilter) {
+    let mut sinks = lock_sinks();
+    sinks.set_level(kind, level);
+    log::set_max_level(sinks.max_level());
+}
+
+/// Текущий уровень приёмника.
+pub fn sink_level(kind: LogSinkKind) -> LevelFilter {
+    lock_sinks().level(kind)
+}
+
+/// Установить один уровень всем приёмникам на лету.
+///
+/// Заданный так уровень важнее `RUST_LOG`: фильтр окружения перестаёт
+/// ограничивать stderr до перезапуска.
+pub fn set_log_level(level: LevelFilter) {
+    let mut sinks = lock_sinks();
+    sinks.set_all_levels(level);
+    log::set_max_level(sinks.max_level());
+}
+
+/// Самый подробный уровень, который сейчас куда-либо пишется.
+pub fn log_level() -> LevelFilter {
+    lock_sinks().max_level()
+}
+
+/// Писать логи в основной ротируемый файл `path` (`None` — отключить).
+pub fn set_log_file(path: Option<&Path>) -> std::io::Result<()> {
+    lock_sinks().open_file(path)
+}
+
+/// Политика ротации и хранения основного файла; открытый файл
+/// переоткрывается с новой политикой.
+pub fn set_log_file_policy(policy: RotationPolicy) -> std::io::Result<()> {
+    lock_sinks().set_file_policy(policy)
+}
+
+/// Текущая политика ротации основного файла.
+pub fn log_file_policy() -> RotationPolicy {
+    lock_sinks().file_policy
+}
+
+/// Текущий файл логов (`None` — запись в файл отключена).
+pub fn log_file_path() -> Option<PathBuf> {
+    lock_sinks().file.as_ref().map(|f| f.path().to_path_buf())
+}
+
+/// Последние `limit` записей из кольцевого буфера (от старых к новым).
+pub fn recent_logs(limit: usize) -> Vec<LogEntry> {
+    lock_sinks().ring.recent(limit)
+}
+
+/// Изменить вместимость кольцевого буфера.
+pub fn set_ring_buffer_capacity(capacity: usize) {
+    lock_sinks().ring.set_capacity(capacity);
+}
+
+/// Подключить stream-приёмник (`None` — отключить).
+///
+/// Callback вызывается синхронно на треде, который пишет лог, и не должен
+/// блокироваться; логи изнутри callback'а отбрасываются.
+pub fn set_log_stream(callback: Option<StreamCallback>) {
+    let mut sinks = lock_sinks();
+    sinks.stream = callback;
+    log::set_max_level(sinks.max_level());
+}
+
+/// Дублировать записи не ниже WARN в системный журнал ОС (Windows Event
+/// Log, os_log на macOS) — для режима агента/службы.
+///
+/// Возвращает `false`, если на платформе системного журнала нет (приёмник
+/// остаётся выключенным).
+pub fn set_system_log_mirroring(enabled: bool) -> bool {
+    if enabled && !system::is_supported() {
+        return false;
+    }
+    let level = if enabled {
+        LevelFilter::Warn
+    } else {
+        LevelFilter::Off
+    };
+    set_sink_level(LogSinkKind::System, level);
+    true
+}
+
+/// Установить формат вывода логов (консоль и файлы).
+pub fn set_log_format(format: LogFormat) {
+    JSON_FORMAT.store(format == LogFormat::Json, Ordering::Relaxed);
+}
+
+/// Текущий формат вывода логов.
+pub fn log_format() -> LogFormat {
+    if JSON_FORMAT.load(Ordering::Relaxed) {
+        LogFormat::Json
+    } else {
+        LogFormat::Text
+    }
+}
+
+/// Строка лога в текущем формате (см. [`LogFormat`]).
+fn format_line(record: &EventRecord) -> String {
+    match log_format() {
+        LogFormat::Text => format_text(record),
+        LogFormat::Json => format_json(record),
+    }
+}
+
+/// Текстовый формат: `[timestamp] [LEVEL] [target] message key=value ...`.
+fn format_text(record: &EventRecord) -> String {
+    let level = match record.level {
+        Level::Error => "E",
+        Level::Warn => "W",
+        Level::Info => "I",
+        Level::Debug => "D",
+        Level::Trace => "T",
+    };
+
+    let mut line = format!(
+        "[{}] [{}] [{}] {}",
+        record.timestamp, level, record.target, record.message
+    );
+    for (key, value) in &record.fields {
+        let value = value.to_string();
+        // Значения с пробелами и спецсимволами — в кавычках, чтобы строку можно было разобрать.
+        if value.is_empty() || value.contains(|c: char| c.is_whitespace() || c == '"' || c == '=') {
+            line.push_str(&format!(" {key}={value:?}"));
+        } else {
+            line.push_str(&format!(" {key}={value}"));
+        }
+    }
+    line
+}
+
+/// JSON-формат: `{"ts":..,"level":..,"target":..,"message":..,"correlation_id":..,"spans":[..],"fields":{..}}`.
+///
+/// `correlation_id` вынесен на верхний уровень, чтобы агрегатор логов
+/// индексировал его без разбора `fields`.
+fn format_json(record: &EventRecord) -> String {
+    let mut entry = serde_json::Map::new();
+    entry.insert("ts".into(), record.timestamp.as_str().into());
+    entry.insert("level".into(), record.level.as_str().into());
+    entry.insert("target".into(), record.target.as_str().into());
+    entry.insert("message".into(), record.message.as_str().into());
+    if let Some(correlation_id) = record.field(CORRELATION_ID_FIELD) {
+        entry.insert(CORRELATION_ID_FIELD.into(), correlation_id.into());
+    }
+    if !record.spans.is_empty() {
+        entry.insert("spans".into(), record.spans.clone().into());
+    }
+
+    let fields: serde_json::Map<String, serde_json::Value> = record
+        .fields
+        .iter()
+        .filter(|(key, _)| key != CORRELATION_ID_FIELD)
+        .map(|(key, value)| (key.clone(), value.to_json()))
+        .collect();
+    if !fields.is_empty() {
+        entry.insert("fields".into(), fields.into());
+    }
+    serde_json::Value::Object(entry).to_string()
+}
+
+/// Структурированные поля записи `log` в порядке объявления.
+fn collect_fields(record: &log::Record<'_>) -> Vec<(String, FieldValue)> {
+    struct Collector(Vec<(String, FieldValue)>);
+
+    impl<'kvs> VisitSource<'kvs> for Collector {
+        fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), log::kv::Error> {
+            self.0.push((key.to_string(), FieldValue::from_kv(&value)));
+            Ok(())
+        }
+    }
+
+    let mut collector = Collector(Vec::new());
+    let _ = record.key_values().visit(&mut collector);
+    collector.0
+}
+
+/// Запись лога со структурированными полями.
+///
+/// Поля задаются как в `log` с feature `kv`: `key` (значение из переменной
+/// с тем же именем), `key = expr`, `key:% = expr` (через `Display`),
+/// `key:? = expr` (через `Debug`). Без `:%`/`:?` принимаются числа, `bool`,
+/// `&str` — они сохраняют тип в JSON; `String` и пути — через `:%`.
+/// Сообщение — строковый литерал в конце.
+///
+/// ```ignore
+/// log_event!(info, target: &log_target, correlation_id:%, path:% = path.display(), size, "file added");
+/// log_event!(warn, "folder icon not set");
+/// ```
+#[macro_export]
+macro_rules! log_event {
+    (@level error) => { ::log::Level::Error };
+    (@level warn) => { ::log::Level::Warn };
+    (@level info) => { ::log::Level::Info };
+    (@level debug) => { ::log::Level::Debug };
+    (@level trace) => { ::log::Level::Trace };
+    ($lvl:ident, target: $target:expr, $message:literal $(,)?) => {
+        ::log::log!(target: $target, $crate::log_event!(@level $lvl), $message)
+    };
+    ($lvl:ident, target: $target:expr, $($key:ident $(:$capture:tt)? $(= $value:expr)?,)+ $message:literal $(,)?) => {
+        ::log::log!(
+            target: $target,
+            $crate::log_event!(@level $lvl),
+            $($key $(:$capture)? $(= $value)?),+;
+            $message
+        )
+    };
+    ($lvl:ident, $($rest:tt)+) => {
+        $crate::log_event!($lvl, target: ::std::module_path!(), $($rest)+)
+    };
+}
+
+/// Target логов watcher'а: `latera::watcher::{id}`.
+pub fn watcher_target(watcher_id: &str) -> String {
+    format!("{WATCHER_TARGET_PREFIX}{watcher_id}")
+}
+
+/// Дополнительно писать записи `target` в ротируемый файл `path`.
+///
+/// Повторный вызов для того же target заменяет файл.
+pub fn route_target_to_file(target: &str, path: &Path) -> std::io::Result<()> {
+    let file = RotatingFile::open(path, RotationPolicy::default())?;
+    TARGET_FILES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .insert(target.to_string(), file);
+    Ok(())
+}
+
+/// Перестать писать `target` в отдельный файл. Возвращает путь бывшего файла.
+pub fn unroute_target(target: &str) -> Option<PathBuf> {
+    let mut file = TARGET_FILES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .remove(target)?;
+    let _ = file.flush();
+    Some(file.path().to_path_buf())
+}
+
+/// Сбросить буферы логгера.
+///
+/// Вызывается при shutdown, чтобы последние записи не потерялись при выходе.
+pub fn flush_logging() {
+    log::logger().flush();
+}
+
+/// Генерирует timestamp в ISO 8601 формате с миллисекундами и смещением
+/// локального часового пояса: `2026-01-31T14:05:09.123+03:00`.
+fn chrono_timestamp() -> String {
+    chrono::Local::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, false)
+}
+
+/// Контекст логирования с корреляционным ID.
+///
+/// Используется для трассировки событий через границы слоёв.
+#[derive(Debug, Clone)]
+pub struct LogContext {
+    /// Уникальный идентификатор для корреляции событий.
+    pub correlation_id: String,
+    /// Опциональный контекст операции.
+    pub operation: Option<String>,
+}
+
+impl LogContext {
+    /// Создать новый контекст с уникальным correlation_id.
+    pub fn new() -> Self {
+        Self {
+            correlation_id: generate_correlation_id(),
+            operation: None,
+        }
+    }
+
+    /// Создать контекст с указанным operation name.
+    pub fn with_operation(operation: impl Into<String>) -> Self {
+        Self {
+            correlation_id: generate_correlation_id(),
+            operation: Some(operation.into()),
+        }
+    }
+
+    /// Установить operation name.
+    pub fn operation(mut self, operation: impl Into<String>) -> Self {
+        self.operation = Some(operation.into());
+        self
+    }
+}
+
+impl Default for LogContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Генерирует уникальный correlation ID.
+///
+/// Формат: `corr_<timestamp_ms>_<random_suffix>`
+fn generate_correlation_id() -> String {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+
+    let counter = COUNTER.fetch_add(1, Ordering::Relaxed);
+
+    format!("corr_{}_{}", timestamp, counter % 10000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_context_has_correlation_id() {
+        let ctx = LogContext::new();
+        assert!(ctx.correlation_id.starts_with("corr_"));
+    }
+
+    #[test]
+    fn test_log_context_with_operation() {
+        let ctx = LogContext::with_operation("file_watcher");
+        assert!(ctx.correlation_id.starts_with("corr_"));
+        assert_eq!(ctx.operation, Some("file_watcher".to_string()));
+    }
+
+    #[test]
+    fn test_routed_target_is_written_to_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("watcher-test.log");
+        let target = watcher_target("routing-test");
+
+        init_logging();
+        route_target_to_file(&target, &path).unwrap();
+        log::warn!(target: &target, "routed line");
+        log::warn!(target: &watcher_target("other"), "not routed");
+        assert_eq!(unroute_target(&target), Some(path.clone()));
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let line: serde_json::Value =
+            serde_json::from_str(content.lines().next().unwrap()).unwrap();
+        assert_eq!(line["target"], "latera::watcher::routing-test");
+        assert_eq!(line["message"], "routed line");
+        assert!(!content.contains("not routed"));
+    }
+
+    #[test]
+    fn test_fields_render_in_text_and_json() {
+        let path = std::path::Path::new("/w/my file.txt").display();
+        let fields: [(&str, Value<'_>); 3] = [
+            ("correlation_id", Value::from("corr_7")),
+            ("path", Value::from_display(&path)),
+            ("size", Value::from(42u64)),
+        ];
+        let args = format_args!("file added");
+        let record = EventRecord::from_log(
+            &log::Record::builder()
+                .level(Level::Warn)
+                .target("latera::watcher::fields-test")
+                .args(args)
+                .key_values(&fields)
+                .build(),
+        );
+
+        let text = format_text(&record);
+        assert!(
+            text.ends_with(r#"file added correlation_id=corr_7 path="/w/my file.txt" size=42"#),
+            "{text}"
+        );
+
+        let json: serde_json::Value = serde_json::from_str(&format_json(&record)).unwrap();
+        assert_eq!(json["level"], "WARN");
+        assert_eq!(json["message"], "file added");
+        assert_eq!(json["fields"]["path"], "/w/my file.txt");
+        assert_eq!(json["fields"]["size"], 42);
+        assert_eq!(json["correlation_id"], "corr_7");
+        assert!(json["fields"].get("correlation_id").is_none());
+    }
+
+    #[test]
+    fn test_span_fields_reach_log_and_tracing_records() {
+        let target = "latera::span-test";
+        init_logging();
+        let span = tracing::info_span!(
+            "start_watcher",
+            watcher_id = "w-span",
+            correlation_id = "corr_span"
+        );
+        {
+            let _entered = span.enter();
+            let _event_span = tracing::info_span!("handle_file_event", path = "/w/a.txt").entered();
+            log::warn!(target: target, "from log");
+            tracing::warn!(target: "latera::span-test", size = 3u64, "from tracing");
+        }
+        log::warn!(target: target, "outside span");
+
+        let recent = recent_logs(DEFAULT_RING_BUFFER_CAPACITY);
+        let field = |message: &str, key: &str| {
+            recent
+                .iter()
+                .find(|e| e.target == target && e.message == message)
+                .unwrap()
+                .fields
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.clone())
+        };
+        for message in ["from log", "from tracing"] {
+            assert_eq!(field(message, "watcher_id").as_deref(), Some("w-span"));
+            assert_eq!(
+                field(message, "correlation_id").as_deref(),
+                Some("corr_span")
+            );
+            assert_eq!(field(message, "path").as_deref(), Some("/w/a.txt"));
+        }
+        assert_eq!(field("from tracing", "size").as_deref(), Some("3"));
+        assert_eq!(field("outside span", "watcher_id"), None);
+
+        let _entered = span.enter();
+        let record = EventRecord::new(
+            Level::Info,
+            target.to_string(),
+            "json".to_string(),
+            Vec::new(),
+        );
+        let json: serde_json::Value = serde_json::from_str(&format_json(&record)).unwrap();
+        assert_eq!(json["correlation_id"], "corr_span");
+        assert_eq!(json["spans"], serde_json::json!(["start_watcher"]));
+        assert_eq!(json["fields"]["watcher_id"], "w-span");
+    }
+
+    #[test]
+    fn test_log_event_macro_accepts_field_forms() {
+        let correlation_id = "corr_1".to_string();
+        let size = 10u64;
+        init_logging();
+        crate::log_event!(debug, target: "latera::test", correlation_id:%, size, "file added");
+        crate::log_event!(debug, path:% = std::path::Path::new("/a").display(), "file added");
+        crate::log_event!(trace, "no fields");
+    }
+
+    #[test]
+    fn test_records_fan_out_to_ring_and_stream() {
+        use std::sync::Arc;
+
+        let streamed = Arc::new(Mutex::new(Vec::new()));
+        let streamed_clone = Arc::clone(&streamed);
+
+        init_logging();
+        set_log_stream(Some(Box::new(move |entry: &LogEntry| {
+            if entry.target == "latera::sinks-test" {
+                streamed_clone.lock().unwrap().push(entry.message.clone());
+                // Лог изнутри приёмника не должен приводить к deadlock
+                log::warn!(target: "latera::sinks-test", "nested");
+            }
+            true
+        })));
+        crate::log_event!(warn, target: "latera::sinks-test", size = 3u64, "fan out");
+        set_log_stream(None);
+
+        assert_eq!(*streamed.lock().unwrap(), vec!["fan out".to_string()]);
+        let recent = recent_logs(DEFAULT_RING_BUFFER_CAPACITY);
+        let entry = recent
+            .iter()
+            .rev()
+            .find(|e| e.target == "latera::sinks-test")
+            .unwrap();
+        assert_eq!(entry.message, "fan out");
+        assert_eq!(entry.fields, vec![("size".to_string(), "3".to_string())]);
+    }
+
+    #[test]
+    fn test_timestamp_includes_date_and_offset() {
+        let ts = chrono_timestamp();
+        assert!(chrono::DateTime::parse_from_rfc3339(&ts).is_ok(), "{ts}");
+        // Дата + миллисекунды: `YYYY-MM-DDTHH:MM:SS.mmm`
+        assert_eq!(ts.find('T'), Some(10), "{ts}");
+        assert_eq!(ts.find('.'), Some(19), "{ts}");
+    }
+
+    #[test]
+    fn test_correlation_ids_are_unique() {
+        let ctx1 = LogContext::new();
+        let ctx2 = LogContext::new();
+        assert_ne!(ctx1.correlation_id, ctx2.correlation_id);
+    }
+}
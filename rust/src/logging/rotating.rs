@@ -0,0 +1,247 @@
+//! Лог-файл с ротацией по размеру.
+//!
+//! `app.log` → при превышении лимита сжимается в `app.log.1.gz`,
+//! `app.log.1.gz` → `app.log.2.gz` и т.д.; самый старый архив удаляется.
+//! Суммарный размер логов (текущий файл + архивы) ограничен
+//! [`RotationPolicy::max_total_bytes`] — при превышении удаляются старые архивы.
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+/// Размер файла, после которого выполняется ротация.
+pub const DEFAULT_MAX_FILE_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Сколько ротированных файлов хранить (помимо текущего).
+pub const DEFAULT_MAX_ROTATED_FILES: usize = 3;
+
+/// Жёсткий лимит суммарного размера логов одного файла с архивами.
+pub const DEFAULT_MAX_TOTAL_BYTES: u64 = 20 * 1024 * 1024;
+
+/// Политика ротации.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RotationPolicy {
+    /// Размер файла, после которого выполняется ротация.
+    pub max_file_bytes: u64,
+    /// Сколько ротированных файлов хранить.
+    pub max_rotated_files: usize,
+    /// Сжимать ротированные файлы gzip'ом.
+    pub compress: bool,
+    /// Лимит суммарного размера текущего файла и архивов.
+    pub max_total_bytes: u64,
+}
+
+impl Default for RotationPolicy {
+    fn default() -> Self {
+        Self {
+            max_file_bytes: DEFAULT_MAX_FILE_BYTES,
+            max_rotated_files: DEFAULT_MAX_ROTATED_FILES,
+            compress: true,
+            max_total_bytes: DEFAULT_MAX_TOTAL_BYTES,
+        }
+    }
+}
+
+/// Лог-файл с ротацией по размеру.
+#[derive(Debug)]
+pub struct RotatingFile {
+    path: PathBuf,
+    policy: RotationPolicy,
+    file: File,
+    size: u64,
+}
+
+impl RotatingFile {
+    /// Открывает (или создаёт) лог-файл на дозапись.
+    pub fn open(path: &Path, policy: RotationPolicy) -> io::Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            path: path.to_path_buf(),
+            policy,
+            file,
+            size,
+        })
+    }
+
+    /// Путь к текущему файлу.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Дописывает строку (перевод строки добавляется), при необходимости ротирует.
+    pub fn write_line(&mut self, line: &str) -> io::Result<()> {
+        if self.size > 0 && self.size + line.len() as u64 + 1 > self.policy.max_file_bytes {
+            self.rotate()?;
+        }
+        writeln!(self.file, "{line}")?;
+        self.size += line.len() as u64 + 1;
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+
+    /// Путь к архиву с номером `index` (1 = самый свежий).
+    pub fn archive_path(&self, index: usize) -> PathBuf {
+        let rotated = rotated_path(&self.path, index);
+        if self.policy.compress {
+            let mut raw = rotated.into_os_string();
+            raw.push(".gz");
+            PathBuf::from(raw)
+        } else {
+            rotated
+        }
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        let keep = self.policy.max_rotated_files;
+        if keep == 0 {
+            self.file = File::create(&self.path)?;
+            self.size = 0;
+            return Ok(());
+        }
+
+        let _ = std::fs::remove_file(self.archive_path(keep));
+        for index in (1..keep).rev() {
+            let from = self.archive_path(index);
+            if from.exists() {
+                std::fs::rename(&from, self.archive_path(index + 1))?;
+            }
+        }
+
+        let first = self.archive_path(1);
+        if self.policy.compress {
+            gzip_file(&self.path, &first)?;
+            std::fs::remove_file(&self.path)?;
+        } else {
+            std::fs::rename(&self.path, &first)?;
+        }
+
+        self.file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        self.size = 0;
+        self.enforce_total_cap();
+        Ok(())
+    }
+
+    /// Удаляет самые старые архивы, пока суммарный размер не уложится в лимит.
+    ///
+    /// Для текущего файла резервируется `max_file_bytes` — он дорастёт до
+    /// этого размера до следующей ротации.
+    fn enforce_total_cap(&self) {
+        let mut archives: Vec<(PathBuf, u64)> = (1..=self.policy.max_rotated_files)
+            .map(|index| self.archive_path(index))
+            .filter_map(|p| std::fs::metadata(&p).ok().map(|m| (p, m.len())))
+            .collect();
+        let mut total =
+            self.policy.max_file_bytes + archives.iter().map(|(_, len)| len).sum::<u64>();
+
+        while total > self.policy.max_total_bytes {
+            let Some((oldest, len)) = archives.pop() else {
+                break;
+            };
+            if std::fs::remove_file(&oldest).is_ok() {
+                total -= len;
+            }
+        }
+    }
+}
+
+/// Путь к ротированному файлу: `app.log` → `app.log.{index}`.
+pub fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let mut raw = path.as_os_str().to_os_string();
+    raw.push(format!(".{index}"));
+    PathBuf::from(raw)
+}
+
+/// Сжимает `src` в `dest` (через временный файл — архив не бывает недописанным).
+fn gzip_file(src: &Path, dest: &Path) -> io::Result<()> {
+    let tmp = dest.with_extension("gz.tmp");
+    {
+        let mut input = File::open(src)?;
+        let mut encoder = GzEncoder::new(File::create(&tmp)?, Compression::default());
+        io::copy(&mut input, &mut encoder)?;
+        encoder.finish()?.sync_all()?;
+    }
+    std::fs::rename(&tmp, dest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn policy(compress: bool, max_total_bytes: u64) -> RotationPolicy {
+        RotationPolicy {
+            max_file_bytes: 20,
+            max_rotated_files: 2,
+            compress,
+            max_total_bytes,
+        }
+    }
+
+    #[test]
+    fn test_rotation_keeps_limited_history() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("watcher.log");
+
+        let mut file = RotatingFile::open(&path, policy(false, u64::MAX)).unwrap();
+        for i in 0..5 {
+            file.write_line(&format!("line number {i}")).unwrap();
+        }
+        file.flush().unwrap();
+
+        let current = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(current, "line number 4\n");
+        assert_eq!(
+            std::fs::read_to_string(rotated_path(&path, 1)).unwrap(),
+            "line number 3\n"
+        );
+        assert!(rotated_path(&path, 2).exists());
+        assert!(!rotated_path(&path, 3).exists());
+    }
+
+    #[test]
+    fn test_rotated_segments_are_gzipped() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("watcher.log");
+
+        let mut file = RotatingFile::open(&path, policy(true, u64::MAX)).unwrap();
+        file.write_line("line number 0").unwrap();
+        file.write_line("line number 1").unwrap();
+
+        let archive = file.archive_path(1);
+        assert!(archive.to_string_lossy().ends_with("watcher.log.1.gz"));
+        let mut decoded = String::new();
+        flate2::read::GzDecoder::new(File::open(&archive).unwrap())
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, "line number 0\n");
+    }
+
+    #[test]
+    fn test_total_footprint_is_capped() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("watcher.log");
+
+        // Лимит вмещает только текущий файл (20 байт) и один архив (14 байт)
+        let mut file = RotatingFile::open(&path, policy(false, 40)).unwrap();
+        for i in 0..4 {
+            file.write_line(&format!("line number {i}")).unwrap();
+        }
+
+        assert!(rotated_path(&path, 1).exists());
+        assert!(!rotated_path(&path, 2).exists());
+    }
+}
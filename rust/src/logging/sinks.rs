@@ -0,0 +1,276 @@
+//! Приёмники (sinks) логов.
+//!
+//! Каждая запись рассылается во все активные приёмники, у каждого — свой
+//! уровень:
+//! - stderr (дополнительно ограничен `RUST_LOG`);
+//! - основной ротируемый файл;
+//! - кольцевой буфер последних записей в памяти (для отчётов и debug-экрана);
+//! - stream в Dart (callback, который API-слой связывает со `StreamSink`);
+//! - системный журнал ОС (Windows Event Log, os_log), по умолчанию выключен.
+
+use std::collections::VecDeque;
+use std::path::Path;
+
+use log::{Level, LevelFilter};
+
+use super::rotating::{RotatingFile, RotationPolicy};
+
+/// Вместимость кольцевого буфера по умолчанию.
+pub const DEFAULT_RING_BUFFER_CAPACITY: usize = 1000;
+
+/// Приёмник логов.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LogSinkKind {
+    Stderr,
+    File,
+    RingBuffer,
+    Stream,
+    /// Системный журнал ОС (см. [`super::system`]).
+    System,
+}
+
+/// Запись лога, переданная приёмникам.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogEntry {
+    /// ISO 8601 с миллисекундами и смещением.
+    pub timestamp: String,
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+    /// Структурированные поля (`log_event!`), значения в текстовом виде.
+    pub fields: Vec<(String, String)>,
+}
+
+/// Callback stream-приёмника. Возвращает `false`, если подписчик закрыл stream.
+pub type StreamCallback = Box<dyn Fn(&LogEntry) -> bool + Send>;
+
+/// Кольцевой буфер последних записей.
+#[derive(Debug)]
+pub struct RingBuffer {
+    capacity: usize,
+    entries: VecDeque<LogEntry>,
+}
+
+impl RingBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: VecDeque::with_capacity(capacity.min(DEFAULT_RING_BUFFER_CAPACITY)),
+        }
+    }
+
+    pub fn push(&mut self, entry: LogEntry) {
+        if self.capacity == 0 {
+            return;
+        }
+        while self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    /// Последние `limit` записей (от старых к новым).
+    pub fn recent(&self, limit: usize) -> Vec<LogEntry> {
+        let skip = self.entries.len().saturating_sub(limit);
+        self.entries.iter().skip(skip).cloned().collect()
+    }
+
+    /// Меняет вместимость, отбрасывая самые старые записи при уменьшении.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.entries.len() > capacity {
+            self.entries.pop_front();
+        }
+    }
+}
+
+/// Набор приёмников с их уровнями.
+pub(super) struct Sinks {
+    /// Самый подробный уровень фильтра `RUST_LOG` — верхняя граница для stderr.
+    pub console_filter: LevelFilter,
+    /// Применять фильтр `RUST_LOG` к stderr; снимается [`Sinks::set_all_levels`].
+    pub use_env_filter: bool,
+    pub stderr_level: LevelFilter,
+    pub file_level: LevelFilter,
+    pub file: Option<RotatingFile>,
+    /// Ротация основного файла (применяется при открытии).
+    pub file_policy: RotationPolicy,
+    pub ring_level: LevelFilter,
+    pub ring: RingBuffer,
+    pub stream_level: LevelFilter,
+    pub stream: Option<StreamCallback>,
+    /// Уровень системного журнала; `Off`, пока его явно не включили.
+    pub system_level: LevelFilter,
+}
+
+impl Sinks {
+    pub fn new() -> Self {
+        Self {
+            console_filter: LevelFilter::Info,
+            use_env_filter: true,
+            stderr_level: LevelFilter::Trace,
+            file_level: LevelFilter::Info,
+            file: None,
+            file_policy: RotationPolicy::default(),
+            ring_level: LevelFilter::Info,
+            ring: RingBuffer::new(DEFAULT_RING_BUFFER_CAPACITY),
+            stream_level: LevelFilter::Info,
+            stream: None,
+            system_level: LevelFilter::Off,
+        }
+    }
+
+    pub fn level(&self, kind: LogSinkKind) -> LevelFilter {
+        match kind {
+            LogSinkKind::Stderr => self.stderr_level,
+            LogSinkKind::File => self.file_level,
+            LogSinkKind::RingBuffer => self.ring_level,
+            LogSinkKind::Stream => self.stream_level,
+            LogSinkKind::System => self.system_level,
+        }
+    }
+
+    pub fn set_level(&mut self, kind: LogSinkKind, level: LevelFilter) {
+        match kind {
+            LogSinkKind::Stderr => self.stderr_level = level,
+            LogSinkKind::File => self.file_level = level,
+            LogSinkKind::RingBuffer => self.ring_level = level,
+            LogSinkKind::Stream => self.stream_level = level,
+            LogSinkKind::System => self.system_level = level,
+        }
+    }
+
+    /// Один уровень для всех приёмников, кроме системного журнала (в него
+    /// идут только явно включённые записи); `RUST_LOG` перестаёт ограничивать stderr.
+    pub fn set_all_levels(&mut self, level: LevelFilter) {
+        for kind in [
+            LogSinkKind::Stderr,
+            LogSinkKind::File,
+            LogSinkKind::RingBuffer,
+            LogSinkKind::Stream,
+        ] {
+            self.set_level(kind, level);
+        }
+        self.console_filter = level;
+        self.use_env_filter = false;
+    }
+
+    /// Самый подробный уровень среди активных приёмников — для `log::set_max_level`.
+    ///
+    /// Файловый уровень учитывается всегда: им же пишутся маршрутизированные target'ы.
+    pub fn max_level(&self) -> LevelFilter {
+        let stream = if self.stream.is_some() {
+            self.stream_level
+        } else {
+            LevelFilter::Off
+        };
+        self.console_filter
+            .min(self.stderr_level)
+            .max(self.file_level)
+            .max(self.ring_level)
+            .max(stream)
+            .max(self.system_level)
+    }
+
+    pub fn open_file(&mut self, path: Option<&Path>) -> std::io::Result<()> {
+        if let Some(file) = self.file.as_mut() {
+            let _ = file.flush();
+        }
+        self.file = match path {
+            Some(path) => Some(RotatingFile::open(path, self.file_policy)?),
+            None => None,
+        };
+        Ok(())
+    }
+
+    /// Меняет политику ротации; открытый файл переоткрывается с ней.
+    pub fn set_file_policy(&mut self, policy: RotationPolicy) -> std::io::Result<()> {
+        self.file_policy = policy;
+        let path = self.file.as_ref().map(|f| f.path().to_path_buf());
+        self.open_file(path.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(message: &str) -> LogEntry {
+        LogEntry {
+            timestamp: String::new(),
+            level: Level::Info,
+            target: "latera".to_string(),
+            message: message.to_string(),
+            fields: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_ring_buffer_keeps_latest() {
+        let mut ring = RingBuffer::new(2);
+        ring.push(entry("a"));
+        ring.push(entry("b"));
+        ring.push(entry("c"));
+
+        let messages: Vec<String> = ring.recent(10).into_iter().map(|e| e.message).collect();
+        assert_eq!(messages, vec!["b", "c"]);
+        assert_eq!(ring.recent(1)[0].message, "c");
+
+        ring.set_capacity(1);
+        assert_eq!(ring.recent(10).len(), 1);
+    }
+
+    #[test]
+    fn test_max_level_covers_active_sinks() {
+        let mut sinks = Sinks::new();
+        sinks.set_level(LogSinkKind::Stderr, LevelFilter::Warn);
+        sinks.set_level(LogSinkKind::File, LevelFilter::Warn);
+        sinks.set_level(LogSinkKind::RingBuffer, LevelFilter::Warn);
+        assert_eq!(sinks.max_level(), LevelFilter::Warn);
+
+        // Уровень неподключённого stream не влияет
+        sinks.set_level(LogSinkKind::Stream, LevelFilter::Trace);
+        assert_eq!(sinks.max_level(), LevelFilter::Warn);
+        sinks.stream = Some(Box::new(|_| true));
+        assert_eq!(sinks.max_level(), LevelFilter::Trace);
+    }
+
+    #[test]
+    fn test_all_levels_override_env_filter() {
+        let mut sinks = Sinks::new();
+        sinks.set_all_levels(LevelFilter::Debug);
+        assert!(!sinks.use_env_filter);
+        assert_eq!(sinks.level(LogSinkKind::Stderr), LevelFilter::Debug);
+        assert_eq!(sinks.level(LogSinkKind::File), LevelFilter::Debug);
+        assert_eq!(sinks.max_level(), LevelFilter::Debug);
+        // Системный журнал включается только явно
+        assert_eq!(sinks.level(LogSinkKind::System), LevelFilter::Off);
+
+        sinks.set_all_levels(LevelFilter::Warn);
+        assert_eq!(sinks.level(LogSinkKind::RingBuffer), LevelFilter::Warn);
+        assert_eq!(sinks.max_level(), LevelFilter::Warn);
+    }
+
+    #[test]
+    fn test_file_policy_applies_to_open_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("latera.log");
+        let mut sinks = Sinks::new();
+        sinks.open_file(Some(&path)).unwrap();
+
+        let policy = RotationPolicy {
+            max_file_bytes: 10,
+            max_rotated_files: 1,
+            compress: false,
+            max_total_bytes: u64::MAX,
+        };
+        sinks.set_file_policy(policy).unwrap();
+        let file = sinks.file.as_mut().unwrap();
+        assert_eq!(file.path(), path);
+        file.write_line("first line").unwrap();
+        file.write_line("second line").unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "second line\n");
+        assert!(crate::logging::rotating::rotated_path(&path, 1).exists());
+    }
+}
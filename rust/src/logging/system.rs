@@ -0,0 +1,95 @@
+//! Системный журнал ОС: Windows Event Log и unified logging macOS (os_log).
+//!
+//! В режиме агента/службы администратор смотрит в Event Viewer или
+//! Console.app, а не в файл логов приложения, поэтому ERROR/WARN можно
+//! дублировать туда (приёмник [`LogSinkKind::System`](super::LogSinkKind::System),
+//! по умолчанию выключен). На остальных платформах приёмник недоступен.
+
+#[cfg(any(target_os = "windows", target_os = "macos"))]
+use log::Level;
+
+use super::sinks::LogEntry;
+
+/// Источник в Windows Event Log и subsystem в os_log.
+pub const SYSTEM_LOG_SOURCE: &str = "Latera";
+
+/// Поддерживает ли платформа системный журнал.
+pub fn is_supported() -> bool {
+    cfg!(any(target_os = "windows", target_os = "macos"))
+}
+
+/// Текст записи: target отдельной колонки в системных журналах нет.
+#[cfg(any(target_os = "windows", target_os = "macos"))]
+fn message(entry: &LogEntry) -> String {
+    format!("[{}] {}", entry.target, entry.message)
+}
+
+#[cfg(target_os = "windows")]
+pub(super) fn write(entry: &LogEntry) {
+    use once_cell::sync::OnceCell;
+    use windows::core::{HSTRING, PCWSTR};
+    use windows::Win32::Foundation::HANDLE;
+    use windows::Win32::Security::PSID;
+    use windows::Win32::System::EventLog::{
+        RegisterEventSourceW, ReportEventW, EVENTLOG_ERROR_TYPE, EVENTLOG_INFORMATION_TYPE,
+        EVENTLOG_WARNING_TYPE,
+    };
+
+    /// Handle источника (как число: HANDLE не `Send`). `None` — регистрация
+    /// не удалась, повторять не будем.
+    static SOURCE: OnceCell<Option<isize>> = OnceCell::new();
+
+    let source = SOURCE.get_or_init(|| {
+        // SAFETY: имя источника — валидная строка, живущая до конца вызова
+        unsafe { RegisterEventSourceW(PCWSTR::null(), &HSTRING::from(SYSTEM_LOG_SOURCE)) }
+            .map(|handle| handle.0 as isize)
+            .inspect_err(|e| eprintln!("Failed to register event source: {e}"))
+            .ok()
+    });
+    let Some(source) = *source else {
+        return;
+    };
+    let kind = match entry.level {
+        Level::Error => EVENTLOG_ERROR_TYPE,
+        Level::Warn => EVENTLOG_WARNING_TYPE,
+        _ => EVENTLOG_INFORMATION_TYPE,
+    };
+    let text = HSTRING::from(message(entry));
+    // SAFETY: handle получен от RegisterEventSourceW и не закрывается,
+    // строка живёт до конца вызова
+    let result = unsafe {
+        ReportEventW(
+            HANDLE(source as *mut core::ffi::c_void),
+            kind,
+            0,
+            1,
+            PSID::default(),
+            0,
+            Some(&[PCWSTR(text.as_ptr())]),
+            None,
+        )
+    };
+    if let Err(e) = result {
+        // Логировать ошибку логирования нельзя — только stderr.
+        eprintln!("Failed to write to Windows Event Log: {e}");
+    }
+}
+
+#[cfg(target_os = "macos")]
+pub(super) fn write(entry: &LogEntry) {
+    use once_cell::sync::Lazy;
+    use oslog::OsLog;
+
+    static LOG: Lazy<OsLog> = Lazy::new(|| OsLog::new(SYSTEM_LOG_SOURCE, "core"));
+
+    let level = match entry.level {
+        Level::Error => oslog::Level::Error,
+        Level::Warn => oslog::Level::Default,
+        Level::Info => oslog::Level::Info,
+        Level::Debug | Level::Trace => oslog::Level::Debug,
+    };
+    LOG.with_level(level, &message(entry));
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+pub(super) fn write(_entry: &LogEntry) {}
@@ -0,0 +1,136 @@
+//! Ограничение частоты повторяющихся сообщений.
+//!
+//! Во время массового копирования одно и то же предупреждение (например,
+//! «rate limit exceeded») может писаться тысячи раз в секунду. [`LogThrottle`]
+//! пропускает первые `burst` сообщений каждого вида за интервал, остальные
+//! только считает; раз в интервал вызывающий код пишет сводку
+//! «suppressed N similar messages».
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Интервал ограничения по умолчанию.
+pub const DEFAULT_THROTTLE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Сколько сообщений одного вида пропускать за интервал по умолчанию.
+pub const DEFAULT_THROTTLE_BURST: u32 = 5;
+
+#[derive(Debug)]
+struct ThrottleState {
+    window_started: Instant,
+    emitted: u32,
+    suppressed: u64,
+    /// Когда писать сводку о подавленных сообщениях.
+    summary_at: Instant,
+}
+
+/// Ограничитель повторяющихся сообщений (по ключу вида сообщения).
+#[derive(Debug)]
+pub struct LogThrottle {
+    interval: Duration,
+    burst: u32,
+    states: HashMap<&'static str, ThrottleState>,
+}
+
+impl LogThrottle {
+    pub fn new(interval: Duration, burst: u32) -> Self {
+        Self {
+            interval,
+            burst,
+            states: HashMap::new(),
+        }
+    }
+
+    /// Можно ли сейчас писать сообщение вида `key`.
+    ///
+    /// Если нет — сообщение учитывается как подавленное.
+    pub fn allow(&mut self, key: &'static str, now: Instant) -> bool {
+        let state = self.states.entry(key).or_insert(ThrottleState {
+            window_started: now,
+            emitted: 0,
+            suppressed: 0,
+            summary_at: now,
+        });
+        if now.duration_since(state.window_started) >= self.interval {
+            state.window_started = now;
+            state.emitted = 0;
+        }
+        if state.emitted < self.burst {
+            state.emitted += 1;
+            return true;
+        }
+        if state.suppressed == 0 {
+            state.summary_at = now + self.interval;
+        }
+        state.suppressed += 1;
+        false
+    }
+
+    /// Сводки, которые пора писать: `(key, suppressed)`. Счётчики сбрасываются.
+    pub fn due_summaries(&mut self, now: Instant) -> Vec<(&'static str, u64)> {
+        self.collect_summaries(|state| now >= state.summary_at)
+    }
+
+    /// Все накопленные сводки (при завершении работы).
+    pub fn take_summaries(&mut self) -> Vec<(&'static str, u64)> {
+        self.collect_summaries(|_| true)
+    }
+
+    fn collect_summaries(
+        &mut self,
+        is_due: impl Fn(&ThrottleState) -> bool,
+    ) -> Vec<(&'static str, u64)> {
+        let mut summaries: Vec<(&'static str, u64)> = self
+            .states
+            .iter_mut()
+            .filter(|(_, state)| state.suppressed > 0 && is_due(state))
+            .map(|(key, state)| (*key, std::mem::take(&mut state.suppressed)))
+            .collect();
+        summaries.sort_unstable();
+        summaries
+    }
+}
+
+impl Default for LogThrottle {
+    fn default() -> Self {
+        Self::new(DEFAULT_THROTTLE_INTERVAL, DEFAULT_THROTTLE_BURST)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_burst_then_suppress_per_key() {
+        let mut throttle = LogThrottle::new(Duration::from_secs(1), 2);
+        let now = Instant::now();
+
+        assert!(throttle.allow("rate", now));
+        assert!(throttle.allow("rate", now));
+        assert!(!throttle.allow("rate", now));
+        // Другой вид сообщений считается отдельно
+        assert!(throttle.allow("notify", now));
+
+        // Новый интервал — снова пропускаем
+        assert!(throttle.allow("rate", now + Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_summary_is_periodic() {
+        let mut throttle = LogThrottle::new(Duration::from_secs(1), 1);
+        let now = Instant::now();
+
+        assert!(throttle.allow("rate", now));
+        for _ in 0..10 {
+            assert!(!throttle.allow("rate", now));
+        }
+        assert!(throttle.due_summaries(now).is_empty());
+        assert_eq!(
+            throttle.due_summaries(now + Duration::from_secs(1)),
+            vec![("rate", 10)]
+        );
+        // Счётчик сброшен
+        assert!(throttle.take_summaries().is_empty());
+    }
+}
@@ -0,0 +1,279 @@
+//! Метрики ядра в текстовом формате Prometheus.
+//!
+//! Гистограммы копятся в памяти процесса: задержка события (от времени
+//! события ФС до выдачи подписчику) и длительность callback'а подписчика.
+//! [`export_text`] собирает их вместе со счётчиками watcher'ов в формат
+//! exposition 0.0.4 — его можно отдавать с локального debug-эндпоинта
+//! приложения и читать Prometheus'ом.
+
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+
+/// Границы корзин задержки события, секунды.
+const EVENT_LATENCY_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0,
+];
+
+/// Границы корзин длительности callback'а, секунды.
+const CALLBACK_DURATION_BUCKETS: &[f64] =
+    &[0.000_1, 0.000_5, 0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0];
+
+/// Гистограмма с фиксированными корзинами (как `histogram` в Prometheus).
+#[derive(Clone, Debug)]
+pub struct Histogram {
+    bounds: &'static [f64],
+    /// Попадания в корзину `le = bounds[i]` (не накопленные).
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    pub fn new(bounds: &'static [f64]) -> Self {
+        Self {
+            bounds,
+            counts: vec![0; bounds.len()],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    pub fn observe(&mut self, value: f64) {
+        if let Some(bucket) = self.bounds.iter().position(|&le| value <= le) {
+            self.counts[bucket] += 1;
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+
+    fn write(&self, out: &mut Exposition, name: &str, help: &str) {
+        out.header(name, help, "histogram");
+        let mut cumulative = 0;
+        for (le, count) in self.bounds.iter().zip(&self.counts) {
+            cumulative += count;
+            let _ = writeln!(out.text, "{name}_bucket{{le=\"{le}\"}} {cumulative}");
+        }
+        let _ = writeln!(out.text, "{name}_bucket{{le=\"+Inf\"}} {}", self.count);
+        let _ = writeln!(out.text, "{name}_sum {}", self.sum);
+        let _ = writeln!(out.text, "{name}_count {}", self.count);
+    }
+}
+
+static EVENT_LATENCY: Lazy<Mutex<Histogram>> =
+    Lazy::new(|| Mutex::new(Histogram::new(EVENT_LATENCY_BUCKETS)));
+
+static CALLBACK_DURATION: Lazy<Mutex<Histogram>> =
+    Lazy::new(|| Mutex::new(Histogram::new(CALLBACK_DURATION_BUCKETS)));
+
+/// Как появившиеся файлы переданы подписчику.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferMode {
+    /// Пачкой структур (`FileBatchEvent`).
+    Batch,
+    /// Чанком ([`crate::event_chunk`]).
+    Chunk,
+}
+
+impl TransferMode {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Batch => "batch",
+            Self::Chunk => "chunk",
+        }
+    }
+}
+
+/// Переданное одним способом.
+#[derive(Clone, Copy, Debug, Default)]
+struct TransferCounters {
+    items: u64,
+    events: u64,
+    bytes: u64,
+}
+
+/// Счётчики передачи: `[Batch, Chunk]`.
+static TRANSFER: Lazy<Mutex<[TransferCounters; 2]>> = Lazy::new(Mutex::default);
+
+/// Подписчику отдан элемент stream'а с `events` событиями размером `bytes`
+/// (для пачки — оценка по кодеку SSE).
+pub fn observe_transfer(mode: TransferMode, events: usize, bytes: usize) {
+    let mut transfer = TRANSFER
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let counters = &mut transfer[mode as usize];
+    counters.items += 1;
+    counters.events += events as u64;
+    counters.bytes += bytes as u64;
+}
+
+/// Событие отдано подписчику через `latency_ms` после события ФС.
+/// Отрицательная задержка (часы ФС впереди) считается нулевой.
+pub fn observe_event_latency(latency_ms: i64) {
+    EVENT_LATENCY
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .observe(latency_ms.max(0) as f64 / 1000.0);
+}
+
+/// Callback подписчика (событие или пачка) выполнялся `took`.
+pub fn observe_callback_duration(took: Duration) {
+    CALLBACK_DURATION
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .observe(took.as_secs_f64());
+}
+
+/// Сбрасывает гистограммы (shutdown ядра).
+pub fn reset() {
+    *EVENT_LATENCY
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Histogram::new(EVENT_LATENCY_BUCKETS);
+    *CALLBACK_DURATION
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) =
+        Histogram::new(CALLBACK_DURATION_BUCKETS);
+    *TRANSFER
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Default::default();
+}
+
+/// Текст в формате exposition.
+#[derive(Debug, Default)]
+pub struct Exposition {
+    text: String,
+}
+
+impl Exposition {
+    fn header(&mut self, name: &str, help: &str, kind: &str) {
+        let _ = writeln!(self.text, "# HELP {name} {help}");
+        let _ = writeln!(self.text, "# TYPE {name} {kind}");
+    }
+
+    /// Метрика `kind` (`counter`, `gauge`) со значениями по наборам меток.
+    pub fn metric(
+        &mut self,
+        name: &str,
+        help: &str,
+        kind: &str,
+        samples: &[(Vec<(&str, &str)>, u64)],
+    ) {
+        self.header(name, help, kind);
+        for (labels, value) in samples {
+            let _ = writeln!(self.text, "{name}{} {value}", format_labels(labels));
+        }
+    }
+}
+
+fn format_labels(labels: &[(&str, &str)]) -> String {
+    if labels.is_empty() {
+        return String::new();
+    }
+    let pairs: Vec<String> = labels
+        .iter()
+        .map(|(name, value)| {
+            let value = value
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n");
+            format!("{name}=\"{value}\"")
+        })
+        .collect();
+    format!("{{{}}}", pairs.join(","))
+}
+
+/// Гистограммы ядра и метрики, добавленные `extra`, в формате exposition.
+pub fn export_text(extra: impl FnOnce(&mut Exposition)) -> String {
+    let mut out = Exposition::default();
+    EVENT_LATENCY
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .write(
+            &mut out,
+            "latera_event_latency_seconds",
+            "Time from the file system event to delivery to the subscriber.",
+        );
+    CALLBACK_DURATION
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .write(
+            &mut out,
+            "latera_callback_duration_seconds",
+            "Time spent in the subscriber callback per event or batch.",
+        );
+    let transfer = *TRANSFER
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let per_mode = |value: fn(&TransferCounters) -> u64| {
+        [TransferMode::Batch, TransferMode::Chunk]
+            .into_iter()
+            .map(|mode| {
+                (
+                    vec![("mode", mode.label())],
+                    value(&transfer[mode as usize]),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    out.metric(
+        "latera_transfer_items_total",
+        "Stream items carrying added files (batches or chunks).",
+        "counter",
+        &per_mode(|c| c.items),
+    );
+    out.metric(
+        "latera_transfer_events_total",
+        "Added files delivered in batches or chunks.",
+        "counter",
+        &per_mode(|c| c.events),
+    );
+    out.metric(
+        "latera_transfer_bytes_total",
+        "Bytes of batches (estimated) or chunks handed to the bridge.",
+        "counter",
+        &per_mode(|c| c.bytes),
+    );
+    extra(&mut out);
+    out.text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram_buckets_are_cumulative() {
+        let mut histogram = Histogram::new(&[0.1, 1.0]);
+        histogram.observe(0.05);
+        histogram.observe(0.5);
+        histogram.observe(5.0);
+        let mut out = Exposition::default();
+        histogram.write(&mut out, "latency", "Latency.");
+        assert_eq!(
+            out.text,
+            "# HELP latency Latency.\n\
+             # TYPE latency histogram\n\
+             latency_bucket{le=\"0.1\"} 1\n\
+             latency_bucket{le=\"1\"} 2\n\
+             latency_bucket{le=\"+Inf\"} 3\n\
+             latency_sum 5.55\n\
+             latency_count 3\n"
+        );
+    }
+
+    #[test]
+    fn test_label_values_are_escaped() {
+        let mut out = Exposition::default();
+        out.metric(
+            "events_total",
+            "Events.",
+            "counter",
+            &[(vec![("watcher_id", "w\"1")], 7)],
+        );
+        assert!(out
+            .text
+            .ends_with("events_total{watcher_id=\"w\\\"1\"} 7\n"));
+    }
+}
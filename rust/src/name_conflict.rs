@@ -0,0 +1,154 @@
+//! Конфликты имён: новый файл с тем же именем, что и уже известный, но с
+//! другим содержимым.
+//!
+//! Реестр помнит, под каким путём впервые встретилось каждое имя файла
+//! (без учёта регистра). Когда приходит файл с занятым именем по другому
+//! пути, сравниваются отпечатки (SHA-256) обоих файлов; при различии UI
+//! получает [`NameConflict`] и может предложить «оставить оба» / «заменить».
+//! Отпечатки считаются только при совпадении имён.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use log::debug;
+
+use crate::error::LateraError;
+use crate::hashing::{self, HashAlgorithm};
+
+/// Конфликт имён.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NameConflict {
+    pub file_name: String,
+    pub new_path: PathBuf,
+    pub new_fingerprint: String,
+    pub existing_path: PathBuf,
+    pub existing_fingerprint: String,
+}
+
+/// Отпечаток содержимого файла: SHA-256 в hex.
+pub fn fingerprint(path: &Path) -> Result<String, LateraError> {
+    hashing::compute_hash(path, HashAlgorithm::Sha256)
+}
+
+fn name_key(path: &Path) -> Option<String> {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .map(str::to_lowercase)
+}
+
+/// Реестр известных файлов по имени.
+#[derive(Debug, Default)]
+pub struct KnownFiles {
+    by_name: HashMap<String, PathBuf>,
+}
+
+impl KnownFiles {
+    /// Реестр, заполненный файлами, которые уже лежат в `dir`.
+    pub fn scan(dir: &Path, recursive: bool) -> Self {
+        let mut known = Self::default();
+        let mut pending = vec![dir.to_path_buf()];
+        while let Some(current) = pending.pop() {
+            let Ok(entries) = std::fs::read_dir(&current) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let Ok(file_type) = entry.file_type() else {
+                    continue;
+                };
+                if file_type.is_dir() && recursive {
+                    pending.push(entry.path());
+                } else if file_type.is_file() {
+                    known.remember(entry.path());
+                }
+            }
+        }
+        known
+    }
+
+    fn remember(&mut self, path: PathBuf) {
+        if let Some(key) = name_key(&path) {
+            self.by_name.entry(key).or_insert(path);
+        }
+    }
+
+    /// Учесть появившийся файл. Возвращает конфликт, если файл с таким же
+    /// именем уже известен по другому пути и содержимое отличается.
+    pub fn observe_added(&mut self, path: &Path) -> Option<NameConflict> {
+        let key = name_key(path)?;
+        let existing = match self.by_name.get(&key) {
+            Some(existing) if existing != path && existing.is_file() => existing.clone(),
+            _ => {
+                self.by_name.insert(key, path.to_path_buf());
+                return None;
+            }
+        };
+
+        let (new_fingerprint, existing_fingerprint) =
+            match (fingerprint(path), fingerprint(&existing)) {
+                (Ok(new), Ok(old)) => (new, old),
+                (Err(e), _) | (_, Err(e)) => {
+                    debug!("Cannot fingerprint {}: {e}", path.display());
+                    return None;
+                }
+            };
+        if new_fingerprint == existing_fingerprint {
+            return None;
+        }
+        Some(NameConflict {
+            file_name: path.file_name()?.to_string_lossy().to_string(),
+            new_path: path.to_path_buf(),
+            new_fingerprint,
+            existing_path: existing,
+            existing_fingerprint,
+        })
+    }
+
+    /// Учесть удаление файла.
+    pub fn forget(&mut self, path: &Path) {
+        if let Some(key) = name_key(path) {
+            if self.by_name.get(&key).is_some_and(|known| known == path) {
+                self.by_name.remove(&key);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fingerprint_is_sha256_hex() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("a.txt");
+        std::fs::write(&path, b"abc").unwrap();
+        assert_eq!(
+            fingerprint(&path).unwrap(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_conflict_only_for_different_content() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let sub = temp_dir.path().join("sub");
+        std::fs::create_dir(&sub).unwrap();
+        std::fs::write(temp_dir.path().join("Report.pdf"), b"v1").unwrap();
+        let mut known = KnownFiles::scan(temp_dir.path(), true);
+
+        // То же содержимое — дубликат, не конфликт
+        let same = sub.join("report.pdf");
+        std::fs::write(&same, b"v1").unwrap();
+        assert!(known.observe_added(&same).is_none());
+
+        std::fs::write(&same, b"v2").unwrap();
+        let conflict = known.observe_added(&same).unwrap();
+        assert_eq!(conflict.existing_path, temp_dir.path().join("Report.pdf"));
+        assert_eq!(conflict.new_path, same);
+        assert_ne!(conflict.new_fingerprint, conflict.existing_fingerprint);
+
+        // После удаления известного файла имя освобождается
+        known.forget(&temp_dir.path().join("Report.pdf"));
+        assert!(known.observe_added(&same).is_none());
+    }
+}
@@ -0,0 +1,112 @@
+//! Сортировка имён файлов «как у человека».
+//!
+//! Сравнение — ICU Collator (UCA с поправками CLDR для локали):
+//! - числа сравниваются по значению (`numeric`): `file2` раньше `file10`;
+//! - регистр и диакритика различают имена только при равенстве остального
+//!   (`e` = `é` = `E` на первом уровне);
+//! - локаль задаёт место национальных букв: в шведском `å ä ö` идут после
+//!   `z`, в немецком `ä` сортируется как `a`.
+//!
+//! Dart сортирует через [`crate::api::sort_file_names`], поэтому списки в
+//! ядре и в UI совпадают.
+
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use icu_collator::{Collator, CollatorOptions, Numeric, Strength};
+use icu_locid::Locale;
+
+/// Collator локали `locale` (`"sv-SE"`, `"sv_SE"`); пустая или
+/// нераспознанная локаль — корневые правила CLDR.
+fn collator(locale: &str) -> Collator {
+    let locale = Locale::from_str(&locale.replace('_', "-")).unwrap_or_else(|e| {
+        if !locale.is_empty() {
+            log::debug!("Unknown sort locale {locale:?}, using root collation: {e}");
+        }
+        Locale::UND
+    });
+    let mut options = CollatorOptions::new();
+    options.strength = Some(Strength::Tertiary);
+    options.numeric = Some(Numeric::On);
+    let data_locale = locale.into();
+    Collator::try_new(&data_locale, options)
+        .or_else(|_| Collator::try_new(&Default::default(), options))
+        .expect("root collation data is compiled in")
+}
+
+/// Сравнить по правилам collator'а; равные для него имена — по исходной
+/// строке, чтобы порядок был полностью детерминирован.
+fn compare_with(collator: &Collator, a: &str, b: &str) -> Ordering {
+    collator.compare(a, b).then_with(|| a.cmp(b))
+}
+
+/// Сравнить два имени по правилам локали `locale` (`""` — без локали).
+pub fn compare_names(a: &str, b: &str, locale: &str) -> Ordering {
+    compare_with(&collator(locale), a, b)
+}
+
+/// Отсортировать имена по правилам локали `locale` (`""` — без локали).
+pub fn sort_file_names(names: &mut [String], locale: &str) {
+    let collator = collator(locale);
+    names.sort_by(|a, b| compare_with(&collator, a, b));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(names: &[&str], locale: &str) -> Vec<String> {
+        let mut names: Vec<String> = names.iter().map(|s| (*s).to_string()).collect();
+        sort_file_names(&mut names, locale);
+        names
+    }
+
+    #[test]
+    fn test_numbers_compare_by_value() {
+        assert_eq!(
+            sorted(&["file10.txt", "file2.txt", "File1.txt", "file02.txt"], ""),
+            vec!["File1.txt", "file02.txt", "file2.txt", "file10.txt"]
+        );
+        // Длинные числа не переполняются
+        assert_eq!(
+            compare_names("a99999999999999999999999", "a100000000000000000000000", ""),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn test_case_and_accents_are_secondary() {
+        assert_eq!(
+            sorted(&["Éclair", "ecole", "eclair", "Ecole"], ""),
+            vec!["eclair", "Éclair", "ecole", "Ecole"]
+        );
+    }
+
+    #[test]
+    fn test_locale_tailoring() {
+        assert_eq!(
+            sorted(&["ö.txt", "z.txt", "o.txt"], "sv-SE"),
+            vec!["o.txt", "z.txt", "ö.txt"]
+        );
+        assert_eq!(
+            sorted(&["ö.txt", "z.txt", "o.txt"], "sv_SE"),
+            vec!["o.txt", "z.txt", "ö.txt"]
+        );
+        assert_eq!(
+            sorted(&["ö.txt", "z.txt", "o.txt"], "de"),
+            vec!["o.txt", "ö.txt", "z.txt"]
+        );
+        assert_eq!(
+            sorted(&["ёлка", "жук", "ель", "Яблоко"], "ru"),
+            vec!["ёлка", "ель", "жук", "Яблоко"]
+        );
+    }
+
+    #[test]
+    fn test_unknown_locale_uses_root_rules() {
+        assert_eq!(
+            sorted(&["b", "a10", "a9"], "not a locale"),
+            vec!["a9", "a10", "b"]
+        );
+    }
+}
@@ -0,0 +1,225 @@
+//! Первый запуск: шаги мастера настройки.
+//!
+//! Мастер во Flutter проходит шаги по порядку: выбрать папку наблюдения,
+//! дать доступ к ней (на macOS — к «Рабочему столу»), дождаться первого
+//! события. Шаг завершается, только если ядро видит, что он действительно
+//! выполнен: папка есть, каталог читается, событие прошло через watcher.
+//!
+//! Пройденные шаги и время первого события хранятся в
+//! `{data_dir}/onboarding.json`, так что мастер не повторяется после
+//! перезапуска.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde_json::{json, Value};
+
+use crate::error::LateraError;
+
+/// Имя файла прогресса в папке данных.
+pub const ONBOARDING_FILE: &str = "onboarding.json";
+
+/// Шаг мастера первого запуска.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OnboardingStep {
+    /// Папка наблюдения выбрана и существует.
+    ChooseFolder,
+    /// Приложение может читать папку (на macOS — доступ к «Рабочему столу»).
+    GrantAccess,
+    /// Через watcher прошло первое событие.
+    FirstEvent,
+}
+
+impl OnboardingStep {
+    /// Все шаги по порядку.
+    pub const ALL: [Self; 3] = [Self::ChooseFolder, Self::GrantAccess, Self::FirstEvent];
+
+    fn key(self) -> &'static str {
+        match self {
+            Self::ChooseFolder => "choose_folder",
+            Self::GrantAccess => "grant_access",
+            Self::FirstEvent => "first_event",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|step| step.key() == key)
+    }
+
+    /// Выполнен ли шаг на самом деле.
+    pub fn is_satisfied(self, facts: &OnboardingFacts) -> bool {
+        match self {
+            Self::ChooseFolder => facts.folder_exists,
+            Self::GrantAccess => facts.access_granted,
+            Self::FirstEvent => facts.first_event_at_ms.is_some(),
+        }
+    }
+}
+
+/// Что ядро знает о папке наблюдения и событиях.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OnboardingFacts {
+    pub folder_exists: bool,
+    pub access_granted: bool,
+    /// Когда прошло первое событие (Unix ms).
+    pub first_event_at_ms: Option<i64>,
+}
+
+/// Есть ли папка и читается ли она.
+///
+/// Если папки ещё нет, доступ проверяется по родительской: на macOS именно
+/// чтение «Рабочего стола» требует разрешения.
+pub fn inspect_folder(folder: &Path) -> (bool, bool) {
+    match std::fs::read_dir(folder) {
+        Ok(_) => (true, true),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            let parent_readable = folder
+                .parent()
+                .is_some_and(|parent| std::fs::read_dir(parent).is_ok());
+            (false, parent_readable)
+        }
+        Err(_) => (folder.is_dir(), false),
+    }
+}
+
+/// Прогресс мастера.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OnboardingProgress {
+    /// Завершённые шаги по порядку.
+    pub completed: Vec<OnboardingStep>,
+    /// Когда прошло первое событие (Unix ms).
+    pub first_event_at_ms: Option<i64>,
+}
+
+impl OnboardingProgress {
+    /// Читает прогресс; нет файла — мастер не начат.
+    pub fn load(path: &Path) -> Result<Self, LateraError> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e.into()),
+        };
+        let value: Value = serde_json::from_str(&text).map_err(|e| {
+            LateraError::InvalidArgument(format!("onboarding progress is not valid JSON: {e}"))
+        })?;
+        let completed = value["completed"]
+            .as_array()
+            .map(|steps| {
+                steps
+                    .iter()
+                    .filter_map(|step| step.as_str().and_then(OnboardingStep::from_key))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(Self {
+            completed,
+            first_event_at_ms: value["first_event_at_ms"].as_i64(),
+        })
+    }
+
+    /// Записывает прогресс в `path` (через временный файл).
+    pub fn save(&self, path: &Path) -> Result<(), LateraError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let value = json!({
+            "completed": self.completed.iter().map(|step| step.key()).collect::<Vec<_>>(),
+            "first_event_at_ms": self.first_event_at_ms,
+        });
+        let mut tmp_path = path.as_os_str().to_owned();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+        {
+            let mut file = std::fs::File::create(&tmp_path)?;
+            let text = serde_json::to_string_pretty(&value).map_err(std::io::Error::other)?;
+            file.write_all(text.as_bytes())?;
+            file.sync_all()?;
+        }
+        std::fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    /// Шаг, на котором стоит мастер; `None` — мастер пройден.
+    pub fn current_step(&self) -> Option<OnboardingStep> {
+        OnboardingStep::ALL
+            .into_iter()
+            .find(|step| !self.completed.contains(step))
+    }
+
+    /// Завершает шаг. Завершить можно только текущий шаг и только если он
+    /// выполнен на самом деле; повторное завершение ничего не меняет.
+    /// Возвращает `true`, если прогресс изменился.
+    pub fn complete(
+        &mut self,
+        step: OnboardingStep,
+        facts: &OnboardingFacts,
+    ) -> Result<bool, LateraError> {
+        if self.completed.contains(&step) {
+            return Ok(false);
+        }
+        if self.current_step() != Some(step) {
+            return Err(LateraError::InvalidArgument(format!(
+                "onboarding step {} is not the current step",
+                step.key()
+            )));
+        }
+        if !step.is_satisfied(facts) {
+            return Err(LateraError::InvalidArgument(format!(
+                "onboarding step {} is not done yet",
+                step.key()
+            )));
+        }
+        self.completed.push(step);
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steps_complete_in_order_when_satisfied() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let folder = temp_dir.path().join("Latera");
+        assert_eq!(inspect_folder(&folder), (false, true));
+        std::fs::create_dir(&folder).unwrap();
+        let (folder_exists, access_granted) = inspect_folder(&folder);
+        let mut facts = OnboardingFacts {
+            folder_exists,
+            access_granted,
+            first_event_at_ms: None,
+        };
+
+        let mut progress = OnboardingProgress::default();
+        assert_eq!(progress.current_step(), Some(OnboardingStep::ChooseFolder));
+        // Шаги не перепрыгиваются
+        assert!(progress
+            .complete(OnboardingStep::GrantAccess, &facts)
+            .is_err());
+        assert!(progress
+            .complete(OnboardingStep::ChooseFolder, &facts)
+            .unwrap());
+        assert!(!progress
+            .complete(OnboardingStep::ChooseFolder, &facts)
+            .unwrap());
+        assert!(progress
+            .complete(OnboardingStep::GrantAccess, &facts)
+            .unwrap());
+        // Событий ещё не было
+        assert!(progress
+            .complete(OnboardingStep::FirstEvent, &facts)
+            .is_err());
+
+        facts.first_event_at_ms = Some(1);
+        progress.first_event_at_ms = Some(1);
+        assert!(progress
+            .complete(OnboardingStep::FirstEvent, &facts)
+            .unwrap());
+        assert_eq!(progress.current_step(), None);
+
+        let path = temp_dir.path().join(ONBOARDING_FILE);
+        progress.save(&path).unwrap();
+        assert_eq!(OnboardingProgress::load(&path).unwrap(), progress);
+    }
+}
@@ -0,0 +1,200 @@
+//! Сравнение путей с учётом особенностей файловых систем.
+//!
+//! Побайтовое сравнение `Path` ошибается: `C:\Users\A` и `c:\users\a\`,
+//! симлинк и его цель, `\\?\UNC\srv\share` и `\\srv\share` — один и тот же
+//! каталог. Функции модуля приводят пути к канонической форме:
+//!
+//! - симлинки и `..` раскрываются (для несуществующего хвоста — через
+//!   ближайшего существующего предка);
+//! - verbatim-префиксы Windows (`\\?\`, `\\?\UNC\`) снимаются;
+//! - хвостовые разделители и `.` не влияют на результат;
+//! - на Windows и macOS регистр не различается.
+
+use std::ffi::OsString;
+use std::path::{Component, Path, PathBuf};
+
+/// Различает ли файловая система платформы регистр имён по умолчанию.
+const CASE_INSENSITIVE: bool = cfg!(any(windows, target_os = "macos"));
+
+/// Наибольшая длина пути каталога в Win32 API без `\\?\`: `MAX_PATH`
+/// (260) минус место под имя файла 8.3.
+const MAX_DIR_PATH: usize = 248;
+
+/// Каноническая форма пути для сравнения.
+///
+/// Путь не обязан существовать: раскрывается самый длинный существующий
+/// префикс, остаток дописывается как есть.
+pub fn normalize(path: &Path) -> PathBuf {
+    let mut existing = path.to_path_buf();
+    let mut rest: Vec<OsString> = Vec::new();
+    let resolved = loop {
+        if let Ok(canonical) = std::fs::canonicalize(&existing) {
+            break canonical;
+        }
+        match (existing.file_name(), existing.parent()) {
+            (Some(name), Some(parent)) => {
+                rest.push(name.to_os_string());
+                existing = parent.to_path_buf();
+            }
+            _ => break existing,
+        }
+    };
+
+    let mut result = strip_verbatim(&resolved);
+    for name in rest.iter().rev() {
+        result.push(name);
+    }
+    lexical_clean(&result)
+}
+
+/// Ключ для сравнения и хэш-таблиц: [`normalize`] без учёта регистра
+/// там, где ФС его не различает.
+pub fn comparison_key(path: &Path) -> PathBuf {
+    comparable(&normalize(path))
+}
+
+/// Указывают ли пути на один и тот же каталог или файл.
+pub fn paths_equal(a: &Path, b: &Path) -> bool {
+    comparison_key(a) == comparison_key(b)
+}
+
+/// Лежит ли `path` внутри `dir` (или совпадает с ним).
+pub fn is_within(dir: &Path, path: &Path) -> bool {
+    comparison_key(path).starts_with(comparison_key(dir))
+}
+
+/// Путь в extended-length форме Windows (`\\?\C:\…`, `\\?\UNC\srv\share\…`),
+/// если без неё Win32 API его не примет: длиннее [`MAX_DIR_PATH`].
+///
+/// Для папки наблюдения: backend открывает её в обход std, а пути событий
+/// строятся от неё и получают ту же форму. На других ОС, для коротких и
+/// относительных путей — путь как есть.
+pub fn to_extended_length(path: &Path) -> PathBuf {
+    if !cfg!(windows) || !path.is_absolute() {
+        return path.to_path_buf();
+    }
+    // verbatim-путь не раскрывает `.`, `..` и `/`
+    let clean = lexical_clean(path);
+    match clean.to_str() {
+        Some(raw) if raw.len() > MAX_DIR_PATH => PathBuf::from(verbatim(raw)),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// `C:\x` → `\\?\C:\x`, `\\srv\share` → `\\?\UNC\srv\share`.
+fn verbatim(raw: &str) -> String {
+    if raw.starts_with(r"\\?\") {
+        raw.to_string()
+    } else if let Some(unc) = raw.strip_prefix(r"\\") {
+        format!(r"\\?\UNC\{unc}")
+    } else {
+        format!(r"\\?\{raw}")
+    }
+}
+
+/// `\\?\C:\x` → `C:\x`, `\\?\UNC\srv\share` → `\\srv\share`.
+fn strip_verbatim(path: &Path) -> PathBuf {
+    let raw = path.to_string_lossy();
+    if let Some(unc) = raw.strip_prefix(r"\\?\UNC\") {
+        PathBuf::from(format!(r"\\{unc}"))
+    } else if let Some(local) = raw.strip_prefix(r"\\?\") {
+        PathBuf::from(local)
+    } else {
+        path.to_path_buf()
+    }
+}
+
+/// Убирает `.`, хвостовые разделители и сворачивает `..`.
+fn lexical_clean(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !result.pop() {
+                    result.push(component);
+                }
+            }
+            other => result.push(other),
+        }
+    }
+    result
+}
+
+/// Путь в виде для сравнения: без учёта регистра там, где ФС его не различает.
+fn comparable(path: &Path) -> PathBuf {
+    if CASE_INSENSITIVE {
+        PathBuf::from(path.to_string_lossy().to_lowercase())
+    } else {
+        path.to_path_buf()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trailing_separators_and_dots_ignored() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let dir = temp_dir.path().join("a");
+        std::fs::create_dir(&dir).unwrap();
+
+        let mut trailing = dir.as_os_str().to_os_string();
+        trailing.push(std::path::MAIN_SEPARATOR_STR);
+        assert!(paths_equal(&dir, Path::new(&trailing)));
+        assert!(paths_equal(&dir, &dir.join(".").join("b").join("..")));
+        // Несуществующие пути тоже сравниваются
+        assert!(paths_equal(&dir.join("x/y"), &dir.join("x/./y/")));
+    }
+
+    #[test]
+    fn test_is_within() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let root = temp_dir.path();
+
+        assert!(is_within(root, &root.join("data/logs")));
+        assert!(is_within(root, root));
+        assert!(!is_within(&root.join("data"), root));
+        // Префикс строки — не вложенность
+        assert!(!is_within(&root.join("data"), &root.join("database")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlink_resolved() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let target = temp_dir.path().join("target");
+        let link = temp_dir.path().join("link");
+        std::fs::create_dir(&target).unwrap();
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        assert!(paths_equal(&target, &link));
+        assert!(is_within(&link, &target.join("new.txt")));
+    }
+
+    #[test]
+    fn test_strip_verbatim() {
+        assert_eq!(
+            strip_verbatim(Path::new(r"\\?\UNC\srv\share")),
+            PathBuf::from(r"\\srv\share")
+        );
+        assert_eq!(
+            strip_verbatim(Path::new(r"\\?\C:\Users")),
+            PathBuf::from(r"C:\Users")
+        );
+    }
+
+    #[test]
+    fn test_verbatim_round_trip() {
+        for raw in [r"C:\Users\a", r"\\srv\share\docs"] {
+            let long = verbatim(raw);
+            assert!(long.starts_with(r"\\?\"));
+            assert_eq!(verbatim(&long), long);
+            assert_eq!(strip_verbatim(Path::new(&long)), PathBuf::from(raw));
+        }
+        // Короткие пути и другие ОС не трогаются
+        let short = Path::new("/tmp/inbox");
+        assert_eq!(to_extended_length(short), short);
+    }
+}
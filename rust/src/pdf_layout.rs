@@ -0,0 +1,714 @@
+//! Схемы раскладки страниц PDF (contact sheet).
+//!
+//! Модуль не рендерит страницы: он рисует их раскладку. Страница
+//! разбирается через `lopdf`, а схема рисуется через `tiny-skia`, поэтому
+//! PDFium/Poppler не нужно поставлять под каждую платформу. По схеме видно,
+//! где на странице таблицы, блоки текста и картинки, но прочитать её нельзя:
+//! - векторная графика (пути, заливки, обводки, цвета Gray/RGB/CMYK) — как есть;
+//! - текст — полосы на месте слов («greeking»): шрифты не растеризуются,
+//!   невидимый текст (OCR-слой сканов) не рисуется;
+//! - изображения — светло-серые прямоугольники на своём месте;
+//! - клиппинг, шейдинги, паттерны и прозрачность игнорируются.
+//!
+//! Для настоящего рендера нужен PDFium или аналог; этот модуль его не заменяет.
+
+use std::path::Path;
+
+use lopdf::content::Content;
+use lopdf::{Dictionary, Document, Object, ObjectId};
+use tiny_skia::{Color, FillRule, Paint, PathBuilder, Pixmap, Stroke, Transform};
+
+use crate::error::LateraError;
+
+/// DPI по умолчанию: 1 пиксель на пункт PDF.
+pub const DEFAULT_RENDER_DPI: u32 = 72;
+
+/// Максимальный DPI превью.
+pub const MAX_RENDER_DPI: u32 = 300;
+
+/// Лимит пикселей одной страницы (защита от гигантских MediaBox).
+pub const MAX_PAGE_PIXELS: u64 = 25_000_000;
+
+/// Сколько страниц можно нарисовать за один вызов.
+pub const MAX_PAGES_PER_CALL: usize = 32;
+
+/// Сколько первых страниц рисуется, если номера не указаны.
+pub const DEFAULT_SHEET_PAGES: u32 = 6;
+
+/// Глубина вложенности Form XObject и дерева страниц.
+const MAX_NESTING_DEPTH: u32 = 16;
+
+/// Letter — для страниц без MediaBox.
+const DEFAULT_MEDIA_BOX: [f32; 4] = [0.0, 0.0, 612.0, 792.0];
+
+/// Средняя ширина глифа в долях кегля (для полос текста).
+const AVERAGE_GLYPH_WIDTH: f32 = 0.5;
+
+/// Высота полосы текста в долях кегля (примерно высота строчных букв).
+const TEXT_BAR_HEIGHT: f32 = 0.55;
+
+/// Непрозрачность полос текста: текст светлее сплошной заливки.
+const TEXT_BAR_ALPHA: f32 = 0.45;
+
+/// Серый плейсхолдер изображения.
+const IMAGE_PLACEHOLDER_GRAY: f32 = 0.85;
+
+/// Схема страницы.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenderedPage {
+    /// Номер страницы (с 1).
+    pub page: u32,
+    pub width: u32,
+    pub height: u32,
+    /// PNG (RGBA).
+    pub png: Vec<u8>,
+}
+
+/// Количество страниц документа.
+pub fn page_count(path: &Path) -> Result<u32, LateraError> {
+    let doc = load(path)?;
+    Ok(u32::try_from(doc.get_pages().len()).unwrap_or(u32::MAX))
+}
+
+/// Рендерит страницы `pages` (номера с 1) в PNG с разрешением `dpi`.
+///
+/// Пустой `pages` — первые [`DEFAULT_SHEET_PAGES`] страниц.
+pub fn render_pages(
+    path: &Path,
+    pages: &[u32],
+    dpi: u32,
+) -> Result<Vec<RenderedPage>, LateraError> {
+    if dpi == 0 || dpi > MAX_RENDER_DPI {
+        return Err(LateraError::InvalidArgument(format!(
+            "dpi must be in 1..={MAX_RENDER_DPI}, got {dpi}"
+        )));
+    }
+    if pages.len() > MAX_PAGES_PER_CALL {
+        return Err(LateraError::InvalidArgument(format!(
+            "at most {MAX_PAGES_PER_CALL} pages can be rendered per call, got {}",
+            pages.len()
+        )));
+    }
+
+    let doc = load(path)?;
+    let page_ids = doc.get_pages();
+    let pages: Vec<u32> = if pages.is_empty() {
+        page_ids
+            .keys()
+            .copied()
+            .take(DEFAULT_SHEET_PAGES as usize)
+            .collect()
+    } else {
+        pages.to_vec()
+    };
+    pages
+        .into_iter()
+        .map(|page| {
+            let page_id = page_ids.get(&page).ok_or_else(|| {
+                LateraError::InvalidArgument(format!(
+                    "page {page} is out of range 1..={}",
+                    page_ids.len()
+                ))
+            })?;
+            let pixmap = render_page(&doc, *page_id, dpi)?;
+            let png = pixmap.encode_png().map_err(std::io::Error::other)?;
+            Ok(RenderedPage {
+                page,
+                width: pixmap.width(),
+                height: pixmap.height(),
+                png,
+            })
+        })
+        .collect()
+}
+
+fn load(path: &Path) -> Result<Document, LateraError> {
+    // Отсутствующий файл — обычная ошибка ввода-вывода, а не «не PDF»
+    std::fs::metadata(path)?;
+    Document::load(path).map_err(|e| {
+        LateraError::InvalidArgument(format!("failed to parse PDF {}: {e}", path.display()))
+    })
+}
+
+fn render_page(doc: &Document, page_id: ObjectId, dpi: u32) -> Result<Pixmap, LateraError> {
+    let page = doc
+        .get_dictionary(page_id)
+        .map_err(|e| LateraError::InvalidArgument(format!("invalid PDF page: {e}")))?;
+    let [x0, y0, x1, y1] = inherited(doc, page, b"CropBox")
+        .and_then(rect_of)
+        .or_else(|| inherited(doc, page, b"MediaBox").and_then(rect_of))
+        .unwrap_or(DEFAULT_MEDIA_BOX);
+    let rotate = inherited(doc, page, b"Rotate")
+        .and_then(|o| o.as_i64().ok())
+        .unwrap_or(0)
+        .rem_euclid(360);
+
+    #[allow(clippy::cast_precision_loss)]
+    let s = dpi as f32 / 72.0;
+    let (w, h) = if rotate == 90 || rotate == 270 {
+        (y1 - y0, x1 - x0)
+    } else {
+        (x1 - x0, y1 - y0)
+    };
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (width, height) = (
+        (w * s).ceil().max(1.0) as u32,
+        (h * s).ceil().max(1.0) as u32,
+    );
+    if u64::from(width) * u64::from(height) > MAX_PAGE_PIXELS {
+        return Err(LateraError::InvalidArgument(format!(
+            "page is too large to render at {dpi} dpi: {width}x{height}"
+        )));
+    }
+    let mut pixmap = Pixmap::new(width, height).ok_or_else(|| {
+        LateraError::InvalidArgument(format!("invalid page size {width}x{height}"))
+    })?;
+    pixmap.fill(Color::WHITE);
+
+    // Пространство страницы (y вверх) → пиксели (y вниз), с учётом /Rotate
+    // (поворот по часовой стрелке при показе).
+    let base = match rotate {
+        90 => Transform::from_row(0.0, s, s, 0.0, -y0 * s, -x0 * s),
+        180 => Transform::from_row(-s, 0.0, 0.0, s, x1 * s, -y0 * s),
+        270 => Transform::from_row(0.0, -s, -s, 0.0, y1 * s, x1 * s),
+        _ => Transform::from_row(s, 0.0, 0.0, -s, -x0 * s, y1 * s),
+    };
+
+    let resources = page_resources(doc, page_id);
+    let content = doc.get_page_content(page_id).unwrap_or_default();
+    let mut renderer = Renderer { doc, pixmap };
+    renderer.run(&content, &resources, GraphicsState::new(base), 0);
+    Ok(renderer.pixmap)
+}
+
+/// Атрибут страницы, возможно унаследованный от узлов дерева страниц.
+fn inherited<'a>(doc: &'a Document, page: &'a Dictionary, key: &[u8]) -> Option<&'a Object> {
+    let mut node = page;
+    for _ in 0..MAX_NESTING_DEPTH {
+        if let Ok(value) = node.get(key) {
+            return doc.dereference(value).ok().map(|(_, o)| o);
+        }
+        let parent = node.get(b"Parent").ok()?.as_reference().ok()?;
+        node = doc.get_dictionary(parent).ok()?;
+    }
+    None
+}
+
+fn rect_of(object: &Object) -> Option<[f32; 4]> {
+    let values: Vec<f32> = object
+        .as_array()
+        .ok()?
+        .iter()
+        .filter_map(|o| o.as_float().ok())
+        .collect();
+    let [a, b, c, d] = values[..] else {
+        return None;
+    };
+    let rect = [a.min(c), b.min(d), a.max(c), b.max(d)];
+    (rect[2] > rect[0] && rect[3] > rect[1]).then_some(rect)
+}
+
+/// Словари ресурсов страницы: собственный, затем унаследованные.
+fn page_resources(doc: &Document, page_id: ObjectId) -> Vec<&Dictionary> {
+    let Ok((inline, ids)) = doc.get_page_resources(page_id) else {
+        return Vec::new();
+    };
+    inline
+        .into_iter()
+        .chain(ids.into_iter().filter_map(|id| doc.get_dictionary(id).ok()))
+        .collect()
+}
+
+/// Ресурс `name` категории `category` (`Font`, `XObject`…).
+fn resource<'a>(
+    doc: &'a Document,
+    resources: &[&'a Dictionary],
+    category: &[u8],
+    name: &[u8],
+) -> Option<&'a Object> {
+    resources.iter().find_map(|res| {
+        let (_, group) = doc.dereference(res.get(category).ok()?).ok()?;
+        let (_, object) = doc
+            .dereference(group.as_dict().ok()?.get(name).ok()?)
+            .ok()?;
+        Some(object)
+    })
+}
+
+fn color_of(components: &[f32]) -> Option<Color> {
+    let (r, g, b) = match *components {
+        [gray] => (gray, gray, gray),
+        [r, g, b] => (r, g, b),
+        [c, m, y, k] => (
+            (1.0 - c) * (1.0 - k),
+            (1.0 - m) * (1.0 - k),
+            (1.0 - y) * (1.0 - k),
+        ),
+        _ => return None,
+    };
+    Color::from_rgba(r.clamp(0.0, 1.0), g.clamp(0.0, 1.0), b.clamp(0.0, 1.0), 1.0)
+}
+
+fn paint_of(color: Color) -> Paint<'static> {
+    let mut paint = Paint::default();
+    paint.set_color(color);
+    paint
+}
+
+/// Состояние графики (сохраняется `q`, восстанавливается `Q`).
+#[derive(Clone, Debug)]
+struct GraphicsState {
+    /// Пространство пользователя → пиксели.
+    ctm: Transform,
+    fill: Color,
+    stroke: Color,
+    line_width: f32,
+    font_size: f32,
+    /// Двухбайтовые коды глифов (шрифт Type0).
+    two_byte_font: bool,
+    leading: f32,
+    horizontal_scale: f32,
+    rise: f32,
+    invisible_text: bool,
+}
+
+impl GraphicsState {
+    fn new(ctm: Transform) -> Self {
+        Self {
+            ctm,
+            fill: Color::BLACK,
+            stroke: Color::BLACK,
+            line_width: 1.0,
+            font_size: 0.0,
+            two_byte_font: false,
+            leading: 0.0,
+            horizontal_scale: 1.0,
+            rise: 0.0,
+            invisible_text: false,
+        }
+    }
+}
+
+struct Renderer<'a> {
+    doc: &'a Document,
+    pixmap: Pixmap,
+}
+
+impl<'a> Renderer<'a> {
+    // a..f — компоненты матрицы, как в спецификации PDF
+    #[allow(clippy::too_many_lines, clippy::many_single_char_names)]
+    fn run(&mut self, data: &[u8], resources: &[&'a Dictionary], state: GraphicsState, depth: u32) {
+        let Ok(content) = Content::decode(data) else {
+            return;
+        };
+        let mut gs = state;
+        let mut saved = Vec::new();
+        let mut path = PathBuilder::new();
+        let mut current = (0.0, 0.0);
+        let mut text_matrix = Transform::identity();
+        let mut line_matrix = Transform::identity();
+
+        for op in &content.operations {
+            let n: Vec<f32> = op
+                .operands
+                .iter()
+                .filter_map(|o| o.as_float().ok())
+                .collect();
+            match (op.operator.as_str(), &n[..]) {
+                ("q", _) => saved.push(gs.clone()),
+                ("Q", _) => {
+                    if let Some(previous) = saved.pop() {
+                        gs = previous;
+                    }
+                }
+                ("cm", &[a, b, c, d, e, f]) => {
+                    gs.ctm = gs.ctm.pre_concat(Transform::from_row(a, b, c, d, e, f));
+                }
+                ("w", &[width]) => gs.line_width = width,
+
+                // Построение пути
+                ("m", &[x, y]) => {
+                    path.move_to(x, y);
+                    current = (x, y);
+                }
+                ("l", &[x, y]) => {
+                    path.line_to(x, y);
+                    current = (x, y);
+                }
+                ("c", &[x1, y1, x2, y2, x3, y3]) => {
+                    path.cubic_to(x1, y1, x2, y2, x3, y3);
+                    current = (x3, y3);
+                }
+                ("v", &[x2, y2, x3, y3]) => {
+                    path.cubic_to(current.0, current.1, x2, y2, x3, y3);
+                    current = (x3, y3);
+                }
+                ("y", &[x1, y1, x3, y3]) => {
+                    path.cubic_to(x1, y1, x3, y3, x3, y3);
+                    current = (x3, y3);
+                }
+                ("h", _) => path.close(),
+                ("re", &[x, y, w, h]) => {
+                    path.move_to(x, y);
+                    path.line_to(x + w, y);
+                    path.line_to(x + w, y + h);
+                    path.line_to(x, y + h);
+                    path.close();
+                    current = (x, y);
+                }
+
+                // Отрисовка пути
+                ("f" | "F" | "f*" | "S" | "s" | "B" | "B*" | "b" | "b*" | "n", _) => {
+                    let operator = op.operator.as_str();
+                    if matches!(operator, "s" | "b" | "b*") {
+                        path.close();
+                    }
+                    let fill = match operator {
+                        "f" | "F" | "B" | "b" => Some(FillRule::Winding),
+                        "f*" | "B*" | "b*" => Some(FillRule::EvenOdd),
+                        _ => None,
+                    };
+                    let stroke = matches!(operator, "S" | "s" | "B" | "B*" | "b" | "b*");
+                    let finished = std::mem::take(&mut path);
+                    self.paint_path(finished, &gs, fill, stroke);
+                }
+
+                // Цвета
+                ("g" | "rg" | "k" | "sc" | "scn", components) => {
+                    if let Some(color) = color_of(components) {
+                        gs.fill = color;
+                    }
+                }
+                ("G" | "RG" | "K" | "SC" | "SCN", components) => {
+                    if let Some(color) = color_of(components) {
+                        gs.stroke = color;
+                    }
+                }
+
+                // Текст
+                ("BT", _) => {
+                    text_matrix = Transform::identity();
+                    line_matrix = Transform::identity();
+                }
+                ("Tf", _) => {
+                    if let (Some(name), Some(&size)) = (
+                        op.operands.first().and_then(|o| o.as_name().ok()),
+                        n.first(),
+                    ) {
+                        gs.font_size = size;
+                        gs.two_byte_font = resource(self.doc, resources, b"Font", name)
+                            .and_then(|font| font.as_dict().ok())
+                            .and_then(|font| font.get(b"Subtype").ok())
+                            .and_then(|subtype| subtype.as_name().ok())
+                            == Some(b"Type0".as_slice());
+                    }
+                }
+                ("TL", &[leading]) => gs.leading = leading,
+                ("Tz", &[scale]) => gs.horizontal_scale = scale / 100.0,
+                ("Ts", &[rise]) => gs.rise = rise,
+                // 3 — невидимый текст, 7 — только клиппинг
+                #[allow(clippy::float_cmp)]
+                ("Tr", &[mode]) => gs.invisible_text = mode == 3.0 || mode == 7.0,
+                ("Td" | "TD", &[tx, ty]) => {
+                    if op.operator == "TD" {
+                        gs.leading = -ty;
+                    }
+                    line_matrix = line_matrix.pre_concat(Transform::from_translate(tx, ty));
+                    text_matrix = line_matrix;
+                }
+                ("Tm", &[a, b, c, d, e, f]) => {
+                    line_matrix = Transform::from_row(a, b, c, d, e, f);
+                    text_matrix = line_matrix;
+                }
+                ("T*" | "'" | "\"", _) => {
+                    line_matrix =
+                        line_matrix.pre_concat(Transform::from_translate(0.0, -gs.leading));
+                    text_matrix = line_matrix;
+                    if let Some(Ok(text)) = op.operands.last().map(Object::as_str) {
+                        self.show_text(text, &gs, &mut text_matrix);
+                    }
+                }
+                ("Tj", _) => {
+                    if let Some(Ok(text)) = op.operands.first().map(Object::as_str) {
+                        self.show_text(text, &gs, &mut text_matrix);
+                    }
+                }
+                ("TJ", _) => {
+                    let Some(Ok(items)) = op.operands.first().map(Object::as_array) else {
+                        continue;
+                    };
+                    for item in items {
+                        if let Ok(text) = item.as_str() {
+                            self.show_text(text, &gs, &mut text_matrix);
+                        } else if let Ok(adjust) = item.as_float() {
+                            let tx = -adjust / 1000.0 * gs.font_size * gs.horizontal_scale;
+                            text_matrix =
+                                text_matrix.pre_concat(Transform::from_translate(tx, 0.0));
+                        }
+                    }
+                }
+
+                ("Do", _) => {
+                    if let Some(name) = op.operands.first().and_then(|o| o.as_name().ok()) {
+                        self.draw_xobject(name, resources, &gs, depth);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn paint_path(
+        &mut self,
+        path: PathBuilder,
+        gs: &GraphicsState,
+        fill: Option<FillRule>,
+        stroke: bool,
+    ) {
+        let Some(path) = path.finish() else {
+            return;
+        };
+        if let Some(rule) = fill {
+            self.pixmap
+                .fill_path(&path, &paint_of(gs.fill), rule, gs.ctm, None);
+        }
+        if stroke {
+            let stroke = Stroke {
+                width: gs.line_width.max(0.0),
+                ..Stroke::default()
+            };
+            self.pixmap
+                .stroke_path(&path, &paint_of(gs.stroke), &stroke, gs.ctm, None);
+        }
+    }
+
+    /// Рисует полосу на месте каждого слова и сдвигает матрицу текста.
+    fn show_text(&mut self, text: &[u8], gs: &GraphicsState, text_matrix: &mut Transform) {
+        let advance = AVERAGE_GLYPH_WIDTH * gs.font_size * gs.horizontal_scale;
+        let transform = gs.ctm.pre_concat(*text_matrix);
+        let mut bars = PathBuilder::new();
+        #[allow(clippy::cast_precision_loss)]
+        let mut draw_bar = |start: usize, end: usize| {
+            let (left, right) = (start as f32 * advance, end as f32 * advance);
+            let (bottom, top) = (gs.rise, gs.rise + TEXT_BAR_HEIGHT * gs.font_size);
+            bars.move_to(left, bottom);
+            bars.line_to(right, bottom);
+            bars.line_to(right, top);
+            bars.line_to(left, top);
+            bars.close();
+        };
+
+        let glyphs = if gs.two_byte_font {
+            // Коды CID не сопоставить с пробелами — одна полоса на строку
+            let glyphs = text.len() / 2;
+            draw_bar(0, glyphs);
+            glyphs
+        } else {
+            let mut word_start = None;
+            for (index, byte) in text.iter().enumerate() {
+                match (byte.is_ascii_whitespace(), word_start) {
+                    (true, Some(start)) => {
+                        draw_bar(start, index);
+                        word_start = None;
+                    }
+                    (false, None) => word_start = Some(index),
+                    _ => {}
+                }
+            }
+            if let Some(start) = word_start {
+                draw_bar(start, text.len());
+            }
+            text.len()
+        };
+
+        if !gs.invisible_text && gs.font_size != 0.0 {
+            if let Some(bars) = bars.finish() {
+                let mut color = gs.fill;
+                color.set_alpha(TEXT_BAR_ALPHA);
+                self.pixmap
+                    .fill_path(&bars, &paint_of(color), FillRule::Winding, transform, None);
+            }
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let width = glyphs as f32 * advance;
+        *text_matrix = text_matrix.pre_concat(Transform::from_translate(width, 0.0));
+    }
+
+    fn draw_xobject(
+        &mut self,
+        name: &[u8],
+        resources: &[&'a Dictionary],
+        gs: &GraphicsState,
+        depth: u32,
+    ) {
+        let Some(stream) =
+            resource(self.doc, resources, b"XObject", name).and_then(|o| o.as_stream().ok())
+        else {
+            return;
+        };
+        match stream.dict.get(b"Subtype").and_then(Object::as_name) {
+            Ok(b"Form") if depth < MAX_NESTING_DEPTH => {
+                let mut form_gs = gs.clone();
+                if let Some([a, b, c, d, e, f]) = stream
+                    .dict
+                    .get(b"Matrix")
+                    .and_then(Object::as_array)
+                    .ok()
+                    .and_then(|m| {
+                        m.iter()
+                            .map(|v| v.as_float().ok())
+                            .collect::<Option<Vec<f32>>>()
+                    })
+                    .and_then(|m| <[f32; 6]>::try_from(m).ok())
+                {
+                    form_gs.ctm = gs.ctm.pre_concat(Transform::from_row(a, b, c, d, e, f));
+                }
+                // Форма без своих ресурсов пользуется ресурсами страницы
+                let own = stream
+                    .dict
+                    .get(b"Resources")
+                    .ok()
+                    .and_then(|r| self.doc.dereference(r).ok())
+                    .and_then(|(_, r)| r.as_dict().ok());
+                let form_resources: Vec<&Dictionary> =
+                    own.into_iter().chain(resources.iter().copied()).collect();
+                let content = stream
+                    .decompressed_content()
+                    .unwrap_or_else(|_| stream.content.clone());
+                self.run(&content, &form_resources, form_gs, depth + 1);
+            }
+            Ok(b"Image") => {
+                // Изображение занимает единичный квадрат пространства пользователя
+                let mut unit = PathBuilder::new();
+                unit.move_to(0.0, 0.0);
+                unit.line_to(1.0, 0.0);
+                unit.line_to(1.0, 1.0);
+                unit.line_to(0.0, 1.0);
+                unit.close();
+                if let Some(unit) = unit.finish() {
+                    let gray = IMAGE_PLACEHOLDER_GRAY;
+                    self.pixmap.fill_path(
+                        &unit,
+                        &paint_of(Color::from_rgba(gray, gray, gray, 1.0).unwrap_or(Color::WHITE)),
+                        FillRule::Winding,
+                        gs.ctm,
+                        None,
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use lopdf::content::Operation;
+    use lopdf::{dictionary, Stream};
+
+    use super::*;
+
+    /// PDF из одной страницы 200×100 pt с заданным содержимым.
+    fn write_pdf(path: &Path, operations: Vec<Operation>, rotate: i64) {
+        let mut doc = Document::with_version("1.5");
+        let pages_id = doc.new_object_id();
+        let font_id = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Helvetica",
+        });
+        let content = Content { operations };
+        let content_id = doc.add_object(Stream::new(dictionary! {}, content.encode().unwrap()));
+        let page_id = doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "Contents" => content_id,
+            "Rotate" => rotate,
+        });
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => vec![page_id.into()],
+                "Count" => 1,
+                "MediaBox" => vec![0.into(), 0.into(), 200.into(), 100.into()],
+                "Resources" => dictionary! { "Font" => dictionary! { "F1" => font_id } },
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+        });
+        doc.trailer.set("Root", catalog_id);
+        doc.save(path).unwrap();
+    }
+
+    fn op(operator: &str, operands: Vec<Object>) -> Operation {
+        Operation::new(operator, operands)
+    }
+
+    #[test]
+    fn test_renders_shapes_and_text_bars() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("doc.pdf");
+        write_pdf(
+            &path,
+            vec![
+                // Красный прямоугольник в левом нижнем углу
+                op("rg", vec![1.into(), 0.into(), 0.into()]),
+                op("re", vec![0.into(), 0.into(), 50.into(), 50.into()]),
+                op("f", vec![]),
+                // Строка текста в правой верхней части
+                op("BT", vec![]),
+                op("rg", vec![0.into(), 0.into(), 0.into()]),
+                op("Tf", vec!["F1".into(), 20.into()]),
+                op("Td", vec![100.into(), 70.into()]),
+                op("Tj", vec![Object::string_literal("Hello")]),
+                op("ET", vec![]),
+            ],
+            0,
+        );
+
+        assert_eq!(page_count(&path).unwrap(), 1);
+        let doc = load(&path).unwrap();
+        let page_id = doc.get_pages()[&1];
+        let pixmap = render_page(&doc, page_id, DEFAULT_RENDER_DPI).unwrap();
+        assert_eq!((pixmap.width(), pixmap.height()), (200, 100));
+
+        // y пикселей растёт вниз: нижний левый угол PDF — низ картинки
+        let red = pixmap.pixel(25, 75).unwrap();
+        assert_eq!((red.red(), red.green(), red.blue()), (255, 0, 0));
+        let white = pixmap.pixel(25, 10).unwrap();
+        assert_eq!((white.red(), white.green(), white.blue()), (255, 255, 255));
+        // Полоса текста: базовая линия y=70 pt → 30 px, верх полосы ≈ 19 px
+        let bar = pixmap.pixel(110, 25).unwrap();
+        assert!(bar.red() < 200, "{bar:?}");
+
+        let rendered = render_pages(&path, &[], 144).unwrap();
+        assert_eq!(rendered.len(), 1);
+        assert_eq!((rendered[0].width, rendered[0].height), (400, 200));
+        assert!(rendered[0].png.starts_with(b"\x89PNG"));
+    }
+
+    #[test]
+    fn test_rotation_and_invalid_requests() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("rotated.pdf");
+        write_pdf(&path, Vec::new(), 90);
+
+        let rendered = render_pages(&path, &[1], DEFAULT_RENDER_DPI).unwrap();
+        assert_eq!((rendered[0].width, rendered[0].height), (100, 200));
+
+        assert!(render_pages(&path, &[2], DEFAULT_RENDER_DPI).is_err());
+        assert!(render_pages(&path, &[1], 0).is_err());
+        assert!(render_pages(&path, &[1], MAX_RENDER_DPI + 1).is_err());
+        assert!(matches!(
+            render_pages(
+                &temp_dir.path().join("missing.pdf"),
+                &[1],
+                DEFAULT_RENDER_DPI
+            ),
+            Err(LateraError::Io(_))
+        ));
+    }
+}
@@ -0,0 +1,348 @@
+//! Политика администратора для управляемых установок.
+//!
+//! Машинный JSON-файл, который раскатывается через MDM/GPO и недоступен для
+//! записи пользователю. Заданные в нём значения накладываются поверх
+//! пользовательских настроек и блокируются: API, меняющие их, возвращают
+//! [`LateraError::PolicyLocked`].
+//!
+//! Расположение файла (переопределяется переменной [`POLICY_FILE_ENV`]):
+//! - Windows: `%ProgramData%\Latera\policy.json`
+//! - macOS: `/Library/Application Support/Latera/policy.json`
+//! - Linux: `/etc/latera/policy.json`
+//!
+//! ```json
+//! {
+//!   "forced_watch_path": "D:\\Inbox",
+//!   "disable_uploads": true,
+//!   "max_folder_bytes": 10737418240,
+//!   "max_folder_files": 50000,
+//!   "read_only": false,
+//!   "settings": { "theme": "dark" }
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde_json::Value;
+
+use crate::error::LateraError;
+use crate::path_utils;
+use crate::quota::FolderQuota;
+
+/// Переменная окружения с путём к файлу политики (вместо системного).
+pub const POLICY_FILE_ENV: &str = "LATERA_POLICY_FILE";
+
+/// Имя файла политики в системной папке.
+pub const POLICY_FILE_NAME: &str = "policy.json";
+
+/// Ключи заблокированных параметров (см. [`Policy::locked_options`]).
+pub const WATCH_PATH_KEY: &str = "watch_path";
+pub const UPLOADS_KEY: &str = "uploads";
+pub const MAX_FOLDER_BYTES_KEY: &str = "max_folder_bytes";
+pub const MAX_FOLDER_FILES_KEY: &str = "max_folder_files";
+pub const READ_ONLY_KEY: &str = "read_only";
+/// Префикс ключей настроек приложения: `settings.<key>`.
+pub const SETTINGS_KEY_PREFIX: &str = "settings.";
+
+/// Действующая политика и файл, из которого она прочитана.
+static ACTIVE: Lazy<Mutex<Option<(PathBuf, Policy)>>> = Lazy::new(|| Mutex::new(None));
+
+/// Ограничения администратора.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Policy {
+    /// Единственная папка, которую можно наблюдать.
+    pub forced_watch_path: Option<PathBuf>,
+    /// Запрет отправки данных с машины (телеметрия).
+    pub disable_uploads: bool,
+    /// Верхняя граница квоты папки по объёму.
+    pub max_folder_bytes: Option<u64>,
+    /// Верхняя граница квоты папки по числу файлов.
+    pub max_folder_files: Option<u64>,
+    /// Принудительный режим «только наблюдение» (вкл. или выкл.).
+    pub read_only: Option<bool>,
+    /// Настройки приложения с фиксированными значениями.
+    pub settings: BTreeMap<String, String>,
+}
+
+/// Заблокированный параметр и его значение по политике.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LockedOption {
+    pub key: String,
+    pub value: String,
+}
+
+impl Policy {
+    pub fn from_json(value: &Value) -> Result<Self, LateraError> {
+        let Value::Object(root) = value else {
+            return Err(invalid("policy must be an object".to_string()));
+        };
+        let mut policy = Self::default();
+        for (key, value) in root {
+            if value.is_null() {
+                continue;
+            }
+            match key.as_str() {
+                "forced_watch_path" => {
+                    let path = value
+                        .as_str()
+                        .filter(|p| Path::new(p).is_absolute())
+                        .ok_or_else(|| invalid(format!("{key} must be an absolute path")))?;
+                    policy.forced_watch_path = Some(PathBuf::from(path));
+                }
+                "disable_uploads" => policy.disable_uploads = as_bool(key, value)?,
+                "max_folder_bytes" => policy.max_folder_bytes = Some(as_positive_u64(key, value)?),
+                "max_folder_files" => policy.max_folder_files = Some(as_positive_u64(key, value)?),
+                "read_only" => policy.read_only = Some(as_bool(key, value)?),
+                "settings" => {
+                    let Value::Object(settings) = value else {
+                        return Err(invalid(format!("{key} must be an object")));
+                    };
+                    for (name, value) in settings {
+                        let value = value
+                            .as_str()
+                            .ok_or_else(|| invalid(format!("{key}.{name} must be a string")))?;
+                        policy.settings.insert(name.clone(), value.to_string());
+                    }
+                }
+                // Политика новее ядра: известное применяем, остальное пропускаем.
+                other => log::warn!("Unknown policy option {other:?} ignored"),
+            }
+        }
+        Ok(policy)
+    }
+
+    /// Читает политику из `path`. Отсутствующий файл — политики нет.
+    pub fn load(path: &Path) -> Result<Option<Self>, LateraError> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let value: Value = serde_json::from_str(&text)
+            .map_err(|e| invalid(format!("{} is not valid JSON: {e}", path.display())))?;
+        Self::from_json(&value).map(Some)
+    }
+
+    /// Заблокированные параметры в стабильном порядке.
+    pub fn locked_options(&self) -> Vec<LockedOption> {
+        let mut locked = Vec::new();
+        let mut push = |key: &str, value: String| {
+            locked.push(LockedOption {
+                key: key.to_string(),
+                value,
+            });
+        };
+        if let Some(path) = &self.forced_watch_path {
+            push(WATCH_PATH_KEY, path.display().to_string());
+        }
+        if self.disable_uploads {
+            push(UPLOADS_KEY, "disabled".to_string());
+        }
+        if let Some(bytes) = self.max_folder_bytes {
+            push(MAX_FOLDER_BYTES_KEY, bytes.to_string());
+        }
+        if let Some(files) = self.max_folder_files {
+            push(MAX_FOLDER_FILES_KEY, files.to_string());
+        }
+        if let Some(read_only) = self.read_only {
+            push(READ_ONLY_KEY, read_only.to_string());
+        }
+        for (name, value) in &self.settings {
+            push(&format!("{SETTINGS_KEY_PREFIX}{name}"), value.clone());
+        }
+        locked
+    }
+
+    /// Квота пользователя, урезанная до лимитов политики.
+    pub fn cap_quota(&self, quota: FolderQuota) -> FolderQuota {
+        let cap = |user: Option<u64>, limit: Option<u64>| match (user, limit) {
+            (Some(user), Some(limit)) => Some(user.min(limit)),
+            (user, limit) => user.or(limit),
+        };
+        FolderQuota {
+            max_total_bytes: cap(quota.max_total_bytes, self.max_folder_bytes),
+            max_file_count: cap(quota.max_file_count, self.max_folder_files),
+            cleanup_oldest: quota.cleanup_oldest,
+        }
+    }
+
+    /// Папка для наблюдения с учётом политики: без явного пути —
+    /// принудительная, другая папка — ошибка.
+    pub fn resolve_watch_path(&self, path: Option<String>) -> Result<Option<String>, LateraError> {
+        let Some(forced) = &self.forced_watch_path else {
+            return Ok(path);
+        };
+        match path {
+            None => Ok(Some(forced.to_string_lossy().to_string())),
+            Some(path) if path_utils::paths_equal(Path::new(&path), forced) => Ok(Some(path)),
+            Some(_) => Err(LateraError::PolicyLocked(WATCH_PATH_KEY.to_string())),
+        }
+    }
+}
+
+/// Системный путь к файлу политики (без учёта [`POLICY_FILE_ENV`]).
+pub fn system_policy_path() -> Option<PathBuf> {
+    if cfg!(target_os = "windows") {
+        std::env::var_os("ProgramData")
+            .map(|dir| PathBuf::from(dir).join("Latera").join(POLICY_FILE_NAME))
+    } else if cfg!(target_os = "macos") {
+        Some(PathBuf::from("/Library/Application Support/Latera").join(POLICY_FILE_NAME))
+    } else {
+        Some(PathBuf::from("/etc/latera").join(POLICY_FILE_NAME))
+    }
+}
+
+/// Путь к файлу политики: [`POLICY_FILE_ENV`] или системный.
+pub fn policy_path() -> Option<PathBuf> {
+    std::env::var_os(POLICY_FILE_ENV)
+        .filter(|path| !path.is_empty())
+        .map(PathBuf::from)
+        .or_else(system_policy_path)
+}
+
+/// Перечитывает политику с диска и делает её действующей.
+///
+/// Повреждённый файл — ошибка: молча снимать ограничения администратора нельзя.
+pub fn reload() -> Result<Option<Policy>, LateraError> {
+    let loaded = match policy_path() {
+        Some(path) => Policy::load(&path)?.map(|policy| (path, policy)),
+        None => None,
+    };
+    if let Some((path, policy)) = &loaded {
+        log::info!(
+            "Administrator policy loaded from {}: {} locked options",
+            path.display(),
+            policy.locked_options().len()
+        );
+    }
+    let policy = loaded.as_ref().map(|(_, policy)| policy.clone());
+    *ACTIVE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = loaded;
+    Ok(policy)
+}
+
+/// Снимает действующую политику (при shutdown).
+pub fn clear() {
+    let _dropped = ACTIVE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+}
+
+/// Действующая политика; без файла — пустая.
+pub fn current() -> Policy {
+    ACTIVE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+        .map(|(_, policy)| policy.clone())
+        .unwrap_or_default()
+}
+
+/// Файл действующей политики.
+pub fn current_path() -> Option<PathBuf> {
+    ACTIVE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+        .map(|(path, _)| path.clone())
+}
+
+/// Ошибка [`LateraError::PolicyLocked`], если настройка `key` зафиксирована.
+pub fn ensure_setting_unlocked(key: &str) -> Result<(), LateraError> {
+    if current().settings.contains_key(key) {
+        return Err(LateraError::PolicyLocked(format!(
+            "{SETTINGS_KEY_PREFIX}{key}"
+        )));
+    }
+    Ok(())
+}
+
+fn invalid(message: String) -> LateraError {
+    LateraError::InvalidArgument(format!("policy: {message}"))
+}
+
+fn as_bool(key: &str, value: &Value) -> Result<bool, LateraError> {
+    value
+        .as_bool()
+        .ok_or_else(|| invalid(format!("{key} must be a boolean")))
+}
+
+fn as_positive_u64(key: &str, value: &Value) -> Result<u64, LateraError> {
+    value
+        .as_u64()
+        .filter(|n| *n > 0)
+        .ok_or_else(|| invalid(format!("{key} must be a positive integer")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_parses_and_reports_locked_options() {
+        let root = if cfg!(windows) { "C:\\Inbox" } else { "/inbox" };
+        let policy = Policy::from_json(&serde_json::json!({
+            "forced_watch_path": root,
+            "disable_uploads": true,
+            "max_folder_bytes": 1000,
+            "read_only": null,
+            "settings": { "theme": "dark" },
+            "future_option": 1,
+        }))
+        .unwrap();
+
+        let keys: Vec<_> = policy
+            .locked_options()
+            .into_iter()
+            .map(|option| option.key)
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                "watch_path",
+                "uploads",
+                "max_folder_bytes",
+                "settings.theme"
+            ]
+        );
+
+        assert_eq!(
+            policy.resolve_watch_path(None).unwrap(),
+            Some(root.to_string())
+        );
+        assert!(matches!(
+            policy.resolve_watch_path(Some(format!("{root}2"))),
+            Err(LateraError::PolicyLocked(_))
+        ));
+
+        let capped = policy.cap_quota(FolderQuota {
+            max_total_bytes: Some(5000),
+            max_file_count: Some(10),
+            cleanup_oldest: true,
+        });
+        assert_eq!(capped.max_total_bytes, Some(1000));
+        assert_eq!(capped.max_file_count, Some(10));
+        assert_eq!(
+            policy.cap_quota(FolderQuota::default()).max_total_bytes,
+            Some(1000)
+        );
+    }
+
+    #[test]
+    fn test_invalid_policy_is_rejected() {
+        for bad in [
+            serde_json::json!([]),
+            serde_json::json!({ "forced_watch_path": "relative" }),
+            serde_json::json!({ "disable_uploads": "yes" }),
+            serde_json::json!({ "max_folder_files": 0 }),
+            serde_json::json!({ "settings": { "theme": 1 } }),
+        ] {
+            assert!(Policy::from_json(&bad).is_err(), "{bad}");
+        }
+    }
+}
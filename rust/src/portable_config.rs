@@ -0,0 +1,703 @@
+//! Переносимая конфигурация ядра: один JSON-файл для развёртывания
+//! одинакового поведения Latera на многих машинах.
+//!
+//! В файл попадают запущенные watcher'ы, их фильтры и настройки, настройки
+//! следующих запусков, расписания (архивирование, квота, проверка места) и
+//! настройки приложения из [`crate::settings`]. Пути, зависящие от машины,
+//! не сохраняются: папка по умолчанию и пресеты (загрузки, скриншоты)
+//! определяются заново при импорте.
+//!
+//! ```json
+//! {"format":"latera-config","version":1,
+//!  "watcher_defaults":{"recursive":false,"include_globs":["*.pdf"],...},
+//!  "watchers":[{"kind":"folder","path":null,"settings":{...}},
+//!              {"kind":"downloads","move_to":"/Users/a/Desktop/Latera"}],
+//!  "archive":{"older_than_days":90,"archive_dir":null,"check_interval_minutes":60},
+//!  "folder_quota":{...},"low_disk_space":{...},
+//!  "read_only":false,"dry_run":false,"shared_folder_claims":false,
+//!  "internal_file_prefix":".latera-","settings":{"theme":"dark"}}
+//! ```
+//!
+//! Отсутствующие поля принимают значения по умолчанию — файл, написанный
+//! вручную, может содержать только нужное.
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde_json::{json, Map, Value};
+
+use crate::config;
+use crate::error::LateraError;
+use crate::file_watcher::{TimestampSource, WatchDirRecovery, WatchFilter, WatcherOptions};
+use crate::{archive, disk_space};
+
+/// Значение поля `format`.
+pub const CONFIG_FORMAT: &str = "latera-config";
+
+/// Версия формата; файлы более новых версий не читаются.
+pub const CONFIG_FORMAT_VERSION: u64 = 1;
+
+/// Настройки watcher'а в переносимом виде.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WatcherSettings {
+    pub timestamp_source: TimestampSource,
+    pub recursive: bool,
+    pub include_globs: Vec<String>,
+    pub exclude_globs: Vec<String>,
+    pub extensions: Vec<String>,
+    pub include_hidden: bool,
+    pub max_file_age_minutes: Option<u64>,
+    pub settle_quiet_period_ms: Option<u64>,
+    pub offline_change_detection: bool,
+    pub dir_recovery: WatchDirRecovery,
+    pub dedup_window_ms: u64,
+    pub max_events_per_second: u32,
+}
+
+impl Default for WatcherSettings {
+    fn default() -> Self {
+        Self::from_options(&WatcherOptions::default())
+    }
+}
+
+impl WatcherSettings {
+    pub fn from_options(options: &WatcherOptions) -> Self {
+        let (include_globs, exclude_globs, extensions) = options.filter.patterns();
+        Self {
+            timestamp_source: options.timestamp_source,
+            recursive: options.recursive,
+            include_globs,
+            exclude_globs,
+            extensions,
+            include_hidden: options.filter.include_hidden(),
+            max_file_age_minutes: options.max_file_age.map(|age| age.as_secs() / 60),
+            settle_quiet_period_ms: options
+                .settle_quiet_period
+                .map(|period| period.as_millis() as u64),
+            offline_change_detection: options.snapshot_dir.is_some(),
+            dir_recovery: options.dir_recovery,
+            dedup_window_ms: u64::try_from(options.dedup_window.as_millis()).unwrap_or(u64::MAX),
+            max_events_per_second: options.rate_limit_per_second,
+        }
+    }
+
+    /// Настройки watcher'а; `snapshot_dir` используется, если включена
+    /// сверка изменений при запуске. Некорректный glob — ошибка.
+    pub fn to_options(&self, snapshot_dir: &Path) -> Result<WatcherOptions, LateraError> {
+        Ok(WatcherOptions {
+            timestamp_source: self.timestamp_source,
+            recursive: self.recursive,
+            max_file_age: self
+                .max_file_age_minutes
+                .map(|minutes| Duration::from_secs(minutes * 60)),
+            settle_quiet_period: self.settle_quiet_period_ms.map(Duration::from_millis),
+            filter: WatchFilter::new(
+                &self.include_globs,
+                &self.exclude_globs,
+                &self.extensions,
+                self.include_hidden,
+            )?,
+            snapshot_dir: self
+                .offline_change_detection
+                .then(|| snapshot_dir.to_path_buf()),
+            dir_recovery: self.dir_recovery,
+            dedup_window: Duration::from_millis(self.dedup_window_ms),
+            rate_limit_per_second: self.max_events_per_second,
+            ..WatcherOptions::default()
+        })
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "timestamp_source": match self.timestamp_source {
+                TimestampSource::Detected => "detected",
+                TimestampSource::FileModified => "file_modified",
+            },
+            "recursive": self.recursive,
+            "include_globs": self.include_globs,
+            "exclude_globs": self.exclude_globs,
+            "extensions": self.extensions,
+            "include_hidden": self.include_hidden,
+            "max_file_age_minutes": self.max_file_age_minutes,
+            "settle_quiet_period_ms": self.settle_quiet_period_ms,
+            "offline_change_detection": self.offline_change_detection,
+            "on_dir_lost": match self.dir_recovery {
+                WatchDirRecovery::Stop => "stop",
+                WatchDirRecovery::WaitForReturn => "wait",
+                WatchDirRecovery::Recreate => "recreate",
+            },
+            "dedup_window_ms": self.dedup_window_ms,
+            "max_events_per_second": self.max_events_per_second,
+        })
+    }
+
+    fn from_json(value: &Value, at: &str) -> Result<Self, LateraError> {
+        let object = Object::new(value, at)?;
+        let defaults = Self::default();
+        let timestamp_source = match object.str("timestamp_source")? {
+            None | Some("detected") => TimestampSource::Detected,
+            Some("file_modified") => TimestampSource::FileModified,
+            Some(other) => {
+                return Err(invalid(format!(
+                    "{at}.timestamp_source: unknown value {other:?}"
+                )))
+            }
+        };
+        let dir_recovery = match object.str("on_dir_lost")? {
+            None => defaults.dir_recovery,
+            Some("stop") => WatchDirRecovery::Stop,
+            Some("wait") => WatchDirRecovery::WaitForReturn,
+            Some("recreate") => WatchDirRecovery::Recreate,
+            Some(other) => {
+                return Err(invalid(format!(
+                    "{at}.on_dir_lost: unknown value {other:?}"
+                )))
+            }
+        };
+        let dedup_window_ms = object
+            .u64("dedup_window_ms")?
+            .unwrap_or(defaults.dedup_window_ms);
+        let max_events_per_second = match object.u64("max_events_per_second")? {
+            Some(rate) => u32::try_from(rate)
+                .map_err(|_| object.wrong_type("max_events_per_second", "a 32-bit integer"))?,
+            None => defaults.max_events_per_second,
+        };
+        config::check_event_tuning(
+            Duration::from_millis(dedup_window_ms),
+            max_events_per_second,
+        )
+        .map_err(|e| invalid(format!("{at}: {e}")))?;
+        Ok(Self {
+            timestamp_source,
+            recursive: object.bool("recursive")?.unwrap_or(defaults.recursive),
+            include_globs: object.strings("include_globs")?,
+            exclude_globs: object.strings("exclude_globs")?,
+            extensions: object.strings("extensions")?,
+            include_hidden: object
+                .bool("include_hidden")?
+                .unwrap_or(defaults.include_hidden),
+            max_file_age_minutes: object.positive_u64("max_file_age_minutes")?,
+            settle_quiet_period_ms: object.positive_u64("settle_quiet_period_ms")?,
+            offline_change_detection: object.bool("offline_change_detection")?.unwrap_or(false),
+            dir_recovery,
+            dedup_window_ms,
+            max_events_per_second,
+        })
+    }
+}
+
+/// Запущенный watcher.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WatcherEntry {
+    /// Обычная папка (`path = None` — папка по умолчанию) со своими настройками.
+    Folder {
+        path: Option<String>,
+        settings: WatcherSettings,
+    },
+    /// Пресет папки загрузок.
+    Downloads { move_to: Option<String> },
+    /// Пресет папки скриншотов.
+    Screenshots { move_to: Option<String> },
+}
+
+impl WatcherEntry {
+    fn to_json(&self) -> Value {
+        match self {
+            Self::Folder { path, settings } => json!({
+                "kind": "folder",
+                "path": path,
+                "settings": settings.to_json(),
+            }),
+            Self::Downloads { move_to } => json!({ "kind": "downloads", "move_to": move_to }),
+            Self::Screenshots { move_to } => json!({ "kind": "screenshots", "move_to": move_to }),
+        }
+    }
+
+    fn from_json(value: &Value, at: &str) -> Result<Self, LateraError> {
+        let object = Object::new(value, at)?;
+        let entry = match object.str("kind")? {
+            Some("folder") => Self::Folder {
+                path: object.absolute_path("path")?,
+                settings: match object.get("settings") {
+                    Some(settings) => {
+                        WatcherSettings::from_json(settings, &format!("{at}.settings"))?
+                    }
+                    None => WatcherSettings::default(),
+                },
+            },
+            Some("downloads") => Self::Downloads {
+                move_to: object.absolute_path("move_to")?,
+            },
+            Some("screenshots") => Self::Screenshots {
+                move_to: object.absolute_path("move_to")?,
+            },
+            Some(other) => return Err(invalid(format!("{at}.kind: unknown value {other:?}"))),
+            None => return Err(invalid(format!("{at}.kind is required"))),
+        };
+        Ok(entry)
+    }
+}
+
+/// Расписание архивирования (см. [`crate::archive`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArchiveSchedule {
+    pub older_than_days: u32,
+    pub archive_dir: Option<String>,
+    pub check_interval_minutes: u32,
+}
+
+/// Квота папки (см. [`crate::quota`]).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QuotaSchedule {
+    pub max_total_bytes: Option<u64>,
+    pub max_file_count: Option<u64>,
+    pub cleanup_oldest: bool,
+}
+
+/// Порог нехватки места (см. [`crate::disk_space`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiskSpaceSchedule {
+    pub threshold_bytes: u64,
+    /// Не меньше [`disk_space::MIN_CHECK_INTERVAL`], помещается в `u32`.
+    pub check_interval_ms: u64,
+}
+
+/// Вся переносимая конфигурация.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PortableConfig {
+    /// Настройки следующих запусков watcher'ов.
+    pub watcher_defaults: WatcherSettings,
+    pub watchers: Vec<WatcherEntry>,
+    /// `None` — архивирование выключено.
+    pub archive: Option<ArchiveSchedule>,
+    pub folder_quota: QuotaSchedule,
+    /// `None` — порог по умолчанию.
+    pub low_disk_space: Option<DiskSpaceSchedule>,
+    pub read_only: bool,
+    /// Пробный запуск ([`crate::dry_run`]).
+    pub dry_run: bool,
+    /// Claim-файлы для общей папки ([`crate::claims`]).
+    pub shared_folder_claims: bool,
+    /// `None` — префикс по умолчанию.
+    pub internal_file_prefix: Option<String>,
+    /// Настройки приложения ([`crate::settings::SettingsStore`]).
+    pub settings: BTreeMap<String, String>,
+}
+
+impl PortableConfig {
+    pub fn to_json(&self) -> Value {
+        let mut root = Map::new();
+        root.insert("format".into(), CONFIG_FORMAT.into());
+        root.insert("version".into(), CONFIG_FORMAT_VERSION.into());
+        root.insert("watcher_defaults".into(), self.watcher_defaults.to_json());
+        root.insert(
+            "watchers".into(),
+            self.watchers.iter().map(WatcherEntry::to_json).collect(),
+        );
+        root.insert(
+            "archive".into(),
+            self.archive.as_ref().map_or(Value::Null, |archive| {
+                json!({
+                    "older_than_days": archive.older_than_days,
+                    "archive_dir": archive.archive_dir,
+                    "check_interval_minutes": archive.check_interval_minutes,
+                })
+            }),
+        );
+        root.insert(
+            "folder_quota".into(),
+            json!({
+                "max_total_bytes": self.folder_quota.max_total_bytes,
+                "max_file_count": self.folder_quota.max_file_count,
+                "cleanup_oldest": self.folder_quota.cleanup_oldest,
+            }),
+        );
+        root.insert(
+            "low_disk_space".into(),
+            self.low_disk_space.as_ref().map_or(Value::Null, |disk| {
+                json!({
+                    "threshold_bytes": disk.threshold_bytes,
+                    "check_interval_ms": disk.check_interval_ms,
+                })
+            }),
+        );
+        root.insert("read_only".into(), self.read_only.into());
+        root.insert("dry_run".into(), self.dry_run.into());
+        root.insert(
+            "shared_folder_claims".into(),
+            self.shared_folder_claims.into(),
+        );
+        root.insert(
+            "internal_file_prefix".into(),
+            self.internal_file_prefix.clone().into(),
+        );
+        root.insert("settings".into(), json!(self.settings));
+        Value::Object(root)
+    }
+
+    pub fn from_json(value: &Value) -> Result<Self, LateraError> {
+        let root = Object::new(value, "config")?;
+        if root.str("format")? != Some(CONFIG_FORMAT) {
+            return Err(invalid(format!(
+                "not a Latera config file (format must be {CONFIG_FORMAT:?})"
+            )));
+        }
+        match root.u64("version")? {
+            Some(version) if version > CONFIG_FORMAT_VERSION => {
+                return Err(invalid(format!(
+                    "config version {version} is newer than supported {CONFIG_FORMAT_VERSION}"
+                )))
+            }
+            _ => {}
+        }
+
+        let watcher_defaults = match root.get("watcher_defaults") {
+            Some(value) => WatcherSettings::from_json(value, "watcher_defaults")?,
+            None => WatcherSettings::default(),
+        };
+        let watchers = match root.get("watchers") {
+            Some(Value::Array(items)) => items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| WatcherEntry::from_json(item, &format!("watchers[{i}]")))
+                .collect::<Result<_, _>>()?,
+            Some(_) => return Err(invalid("watchers must be an array".to_string())),
+            None => Vec::new(),
+        };
+        let archive = match root.get("archive") {
+            Some(value) => {
+                let archive = Object::new(value, "archive")?;
+                let schedule = ArchiveSchedule {
+                    older_than_days: archive.required_u32("older_than_days")?,
+                    archive_dir: archive.absolute_path("archive_dir")?,
+                    check_interval_minutes: archive.required_u32("check_interval_minutes")?,
+                };
+                if schedule.older_than_days == 0 {
+                    return Err(archive.wrong_type("older_than_days", "at least 1"));
+                }
+                if Duration::from_secs(u64::from(schedule.check_interval_minutes) * 60)
+                    < archive::MIN_ARCHIVE_CHECK_INTERVAL
+                {
+                    return Err(archive.wrong_type("check_interval_minutes", "at least 1"));
+                }
+                Some(schedule)
+            }
+            None => None,
+        };
+        let folder_quota = match root.get("folder_quota") {
+            Some(value) => {
+                let quota = Object::new(value, "folder_quota")?;
+                QuotaSchedule {
+                    max_total_bytes: quota.u64("max_total_bytes")?,
+                    max_file_count: quota.u64("max_file_count")?,
+                    cleanup_oldest: quota.bool("cleanup_oldest")?.unwrap_or(false),
+                }
+            }
+            None => QuotaSchedule::default(),
+        };
+        let low_disk_space = match root.get("low_disk_space") {
+            Some(value) => {
+                let disk = Object::new(value, "low_disk_space")?;
+                let schedule = DiskSpaceSchedule {
+                    threshold_bytes: disk.required_u64("threshold_bytes")?,
+                    check_interval_ms: disk.required_u64("check_interval_ms")?,
+                };
+                let interval = Duration::from_millis(schedule.check_interval_ms);
+                if interval < disk_space::MIN_CHECK_INTERVAL
+                    || u32::try_from(schedule.check_interval_ms).is_err()
+                {
+                    return Err(disk.wrong_type(
+                        "check_interval_ms",
+                        &format!(
+                            "between {} and {}",
+                            disk_space::MIN_CHECK_INTERVAL.as_millis(),
+                            u32::MAX
+                        ),
+                    ));
+                }
+                Some(schedule)
+            }
+            None => None,
+        };
+        let settings = match root.get("settings") {
+            Some(Value::Object(values)) => values
+                .iter()
+                .map(|(key, value)| match value {
+                    Value::String(s) => Ok((key.clone(), s.clone())),
+                    _ => Err(invalid(format!("settings.{key} must be a string"))),
+                })
+                .collect::<Result<_, _>>()?,
+            Some(_) => return Err(invalid("settings must be an object".to_string())),
+            None => BTreeMap::new(),
+        };
+
+        Ok(Self {
+            watcher_defaults,
+            watchers,
+            archive,
+            folder_quota,
+            low_disk_space,
+            read_only: root.bool("read_only")?.unwrap_or(false),
+            dry_run: root.bool("dry_run")?.unwrap_or(false),
+            shared_folder_claims: root.bool("shared_folder_claims")?.unwrap_or(false),
+            internal_file_prefix: root.str("internal_file_prefix")?.map(str::to_string),
+            settings,
+        })
+    }
+
+    /// Записывает конфигурацию в `path` (через временный файл).
+    pub fn save(&self, path: &Path) -> Result<(), LateraError> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp_path = tmp_path(path);
+        {
+            let mut file = std::fs::File::create(&tmp_path)?;
+            let text =
+                serde_json::to_string_pretty(&self.to_json()).map_err(std::io::Error::other)?;
+            file.write_all(text.as_bytes())?;
+            file.write_all(b"\n")?;
+            file.sync_all()?;
+        }
+        std::fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    /// Читает и проверяет конфигурацию из `path`.
+    pub fn load(path: &Path) -> Result<Self, LateraError> {
+        let text = std::fs::read_to_string(path)?;
+        let value: Value = serde_json::from_str(&text)
+            .map_err(|e| invalid(format!("config is not valid JSON: {e}")))?;
+        Self::from_json(&value)
+    }
+}
+
+pub(crate) fn tmp_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".tmp");
+    path.with_file_name(name)
+}
+
+pub(crate) fn invalid(message: String) -> LateraError {
+    LateraError::InvalidArgument(format!("config: {message}"))
+}
+
+/// JSON-объект с путём для сообщений об ошибках.
+pub(crate) struct Object<'a> {
+    map: &'a Map<String, Value>,
+    at: &'a str,
+}
+
+impl<'a> Object<'a> {
+    pub(crate) fn new(value: &'a Value, at: &'a str) -> Result<Self, LateraError> {
+        match value {
+            Value::Object(map) => Ok(Self { map, at }),
+            _ => Err(invalid(format!("{at} must be an object"))),
+        }
+    }
+
+    /// Поле; `null` равносилен отсутствию.
+    pub(crate) fn get(&self, key: &str) -> Option<&'a Value> {
+        self.map.get(key).filter(|value| !value.is_null())
+    }
+
+    pub(crate) fn wrong_type(&self, key: &str, expected: &str) -> LateraError {
+        invalid(format!("{}.{key} must be {expected}", self.at))
+    }
+
+    pub(crate) fn str(&self, key: &str) -> Result<Option<&'a str>, LateraError> {
+        self.get(key)
+            .map(|value| {
+                value
+                    .as_str()
+                    .ok_or_else(|| self.wrong_type(key, "a string"))
+            })
+            .transpose()
+    }
+
+    pub(crate) fn bool(&self, key: &str) -> Result<Option<bool>, LateraError> {
+        self.get(key)
+            .map(|value| {
+                value
+                    .as_bool()
+                    .ok_or_else(|| self.wrong_type(key, "a boolean"))
+            })
+            .transpose()
+    }
+
+    pub(crate) fn u64(&self, key: &str) -> Result<Option<u64>, LateraError> {
+        self.get(key)
+            .map(|value| {
+                value
+                    .as_u64()
+                    .ok_or_else(|| self.wrong_type(key, "a non-negative integer"))
+            })
+            .transpose()
+    }
+
+    pub(crate) fn positive_u64(&self, key: &str) -> Result<Option<u64>, LateraError> {
+        match self.u64(key)? {
+            Some(0) => Err(self.wrong_type(key, "positive")),
+            other => Ok(other),
+        }
+    }
+
+    pub(crate) fn required_u64(&self, key: &str) -> Result<u64, LateraError> {
+        self.u64(key)?
+            .ok_or_else(|| invalid(format!("{}.{key} is required", self.at)))
+    }
+
+    pub(crate) fn required_u32(&self, key: &str) -> Result<u32, LateraError> {
+        u32::try_from(self.required_u64(key)?).map_err(|_| self.wrong_type(key, "a 32-bit integer"))
+    }
+
+    pub(crate) fn strings(&self, key: &str) -> Result<Vec<String>, LateraError> {
+        match self.get(key) {
+            None => Ok(Vec::new()),
+            Some(Value::Array(items)) => items
+                .iter()
+                .map(|item| {
+                    item.as_str()
+                        .map(str::to_string)
+                        .ok_or_else(|| self.wrong_type(key, "an array of strings"))
+                })
+                .collect(),
+            Some(_) => Err(self.wrong_type(key, "an array of strings")),
+        }
+    }
+
+    /// Необязательный абсолютный путь.
+    pub(crate) fn absolute_path(&self, key: &str) -> Result<Option<String>, LateraError> {
+        let path = self.str(key)?;
+        if path.is_some_and(|p| !Path::new(p).is_absolute()) {
+            return Err(self.wrong_type(key, "an absolute path"));
+        }
+        Ok(path.map(str::to_string))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> PortableConfig {
+        let root = if cfg!(windows) { "C:\\Data" } else { "/data" };
+        PortableConfig {
+            watcher_defaults: WatcherSettings {
+                recursive: true,
+                include_globs: vec!["*.pdf".to_string()],
+                extensions: vec!["pdf".to_string()],
+                settle_quiet_period_ms: Some(1500),
+                ..WatcherSettings::default()
+            },
+            watchers: vec![
+                WatcherEntry::Folder {
+                    path: None,
+                    settings: WatcherSettings::default(),
+                },
+                WatcherEntry::Folder {
+                    path: Some(format!("{root}/inbox")),
+                    settings: WatcherSettings {
+                        timestamp_source: TimestampSource::FileModified,
+                        exclude_globs: vec!["~$*".to_string()],
+                        include_hidden: false,
+                        max_file_age_minutes: Some(60),
+                        offline_change_detection: true,
+                        dir_recovery: WatchDirRecovery::Recreate,
+                        dedup_window_ms: 50,
+                        max_events_per_second: 2000,
+                        ..WatcherSettings::default()
+                    },
+                },
+                WatcherEntry::Downloads {
+                    move_to: Some(format!("{root}/inbox")),
+                },
+                WatcherEntry::Screenshots { move_to: None },
+            ],
+            archive: Some(ArchiveSchedule {
+                older_than_days: 90,
+                archive_dir: None,
+                check_interval_minutes: 60,
+            }),
+            folder_quota: QuotaSchedule {
+                max_total_bytes: Some(1 << 30),
+                max_file_count: None,
+                cleanup_oldest: true,
+            },
+            low_disk_space: Some(DiskSpaceSchedule {
+                threshold_bytes: 500 << 20,
+                check_interval_ms: 30_000,
+            }),
+            read_only: false,
+            dry_run: true,
+            shared_folder_claims: true,
+            internal_file_prefix: Some(".acme-".to_string()),
+            settings: BTreeMap::from([("theme".to_string(), "dark".to_string())]),
+        }
+    }
+
+    #[test]
+    fn test_config_round_trips_through_file() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("nested").join("latera-config.json");
+        let config = sample();
+        config.save(&path).unwrap();
+        assert_eq!(PortableConfig::load(&path).unwrap(), config);
+
+        // Настройки watcher'а переживают преобразование в WatcherOptions
+        let WatcherEntry::Folder { settings, .. } = &config.watchers[1] else {
+            unreachable!()
+        };
+        let options = settings.to_options(temp_dir.path()).unwrap();
+        assert_eq!(options.snapshot_dir.as_deref(), Some(temp_dir.path()));
+        assert_eq!(&WatcherSettings::from_options(&options), settings);
+    }
+
+    #[test]
+    fn test_minimal_config_uses_defaults() {
+        let value = json!({"format": "latera-config", "watchers": [{"kind": "folder"}]});
+        let config = PortableConfig::from_json(&value).unwrap();
+        assert_eq!(
+            config.watchers,
+            vec![WatcherEntry::Folder {
+                path: None,
+                settings: WatcherSettings::default()
+            }]
+        );
+        assert_eq!(config.archive, None);
+        assert!(config.settings.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_configs_are_rejected() {
+        for (value, expected) in [
+            (json!({"format": "other"}), "not a Latera config"),
+            (json!({"format": "latera-config", "version": 99}), "newer"),
+            (
+                json!({"format": "latera-config", "watchers": [{"kind": "ftp"}]}),
+                "watchers[0].kind",
+            ),
+            (
+                json!({"format": "latera-config", "watchers": [{"kind": "folder", "path": "rel/dir"}]}),
+                "absolute path",
+            ),
+            (
+                json!({"format": "latera-config", "watcher_defaults": {"recursive": "yes"}}),
+                "watcher_defaults.recursive",
+            ),
+            (
+                json!({"format": "latera-config", "settings": {"theme": 1}}),
+                "settings.theme",
+            ),
+            (
+                json!({"format": "latera-config", "archive": {"older_than_days": 0, "check_interval_minutes": 60}}),
+                "archive.older_than_days",
+            ),
+        ] {
+            let error = PortableConfig::from_json(&value).unwrap_err().to_string();
+            assert!(error.contains(expected), "{error}");
+        }
+    }
+}
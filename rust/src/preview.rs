@@ -0,0 +1,206 @@
+//! Фоновая пре-генерация превью новых файлов.
+//!
+//! Когда режим включён, каждый новый файл ставится в очередь; фоновый тред
+//! дожидается, пока файл «осядет» (размер и mtime перестанут меняться), и
+//! кладёт текстовое превью в кэш `{data_dir}/previews/{sha256}.txt`. Галерея
+//! во Flutter читает готовое превью, не генерируя его на лету при прокрутке.
+//!
+//! Превью строится через [`indexer::extract_rich_content`] (PDF, DOCX,
+//! текст). Миниатюры изображений строит [`crate::thumbnails`].
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+use log::{debug, warn};
+
+use crate::error::LateraError;
+use crate::indexer::{self, ExtractionOptions};
+use crate::name_conflict;
+
+/// Папка кэша превью внутри папки данных.
+pub const PREVIEW_DIR: &str = "previews";
+
+/// Длина текстового превью по умолчанию (в символах).
+pub const DEFAULT_PREVIEW_CHARS: usize = 500;
+
+/// Интервал между проверками, «осел» ли файл.
+const SETTLE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Сколько ждать, пока файл перестанет меняться (дальше — пропуск).
+const SETTLE_MAX_WAIT: Duration = Duration::from_secs(30);
+
+fn file_state(path: &Path) -> Option<(u64, SystemTime)> {
+    let metadata = std::fs::metadata(path).ok()?;
+    Some((metadata.len(), metadata.modified().ok()?))
+}
+
+/// Ждёт, пока размер и mtime файла не перестанут меняться.
+///
+/// Возвращает `false`, если файл исчез или не успокоился за `max_wait`.
+pub fn wait_until_settled(path: &Path, interval: Duration, max_wait: Duration) -> bool {
+    let started = Instant::now();
+    let Some(mut previous) = file_state(path) else {
+        return false;
+    };
+    loop {
+        thread::sleep(interval);
+        let Some(current) = file_state(path) else {
+            return false;
+        };
+        if current == previous {
+            return true;
+        }
+        if started.elapsed() >= max_wait {
+            return false;
+        }
+        previous = current;
+    }
+}
+
+/// Текстовое превью: первые `max_chars` символов извлечённого текста.
+///
+/// `None` — формат не поддерживается или текста нет.
+pub fn generate_text_preview(path: &Path, max_chars: usize) -> Option<String> {
+    let result = indexer::extract_rich_content(path, &ExtractionOptions::default());
+    if result.error_code.is_some() {
+        return None;
+    }
+    let preview: String = result.text.trim().chars().take(max_chars).collect();
+    (!preview.is_empty()).then_some(preview)
+}
+
+/// Путь к кэшированному превью файла с отпечатком `fingerprint`.
+pub fn preview_path(cache_dir: &Path, fingerprint: &str) -> PathBuf {
+    cache_dir.join(format!("{fingerprint}.txt"))
+}
+
+/// Сгенерировать превью и положить в кэш (если его ещё нет).
+///
+/// Возвращает путь к превью; `None` — превью для файла не строится.
+pub fn pregenerate(cache_dir: &Path, path: &Path) -> Result<Option<PathBuf>, LateraError> {
+    let target = preview_path(cache_dir, &name_conflict::fingerprint(path)?);
+    if target.exists() {
+        return Ok(Some(target));
+    }
+    let Some(preview) = generate_text_preview(path, DEFAULT_PREVIEW_CHARS) else {
+        return Ok(None);
+    };
+    std::fs::create_dir_all(cache_dir)?;
+    // Через временный файл — читатель не увидит недописанное превью.
+    let tmp = target.with_extension("txt.tmp");
+    std::fs::write(&tmp, preview)?;
+    std::fs::rename(&tmp, &target)?;
+    Ok(Some(target))
+}
+
+/// Превью файла из кэша. `None` — ещё не сгенерировано.
+pub fn cached_text_preview(cache_dir: &Path, path: &Path) -> Result<Option<String>, LateraError> {
+    let target = preview_path(cache_dir, &name_conflict::fingerprint(path)?);
+    match std::fs::read_to_string(target) {
+        Ok(preview) => Ok(Some(preview)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Очередь фоновой генерации превью.
+pub struct PreviewQueue {
+    job_tx: mpsc::Sender<PathBuf>,
+    stop: Arc<AtomicBool>,
+    join: thread::JoinHandle<()>,
+}
+
+impl PreviewQueue {
+    /// Запускает фоновый тред генерации в `cache_dir`.
+    pub fn spawn(cache_dir: PathBuf) -> Result<Self, LateraError> {
+        let (job_tx, job_rx) = mpsc::channel::<PathBuf>();
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_for_thread = Arc::clone(&stop);
+        let join = thread::Builder::new()
+            .name("latera-preview".to_string())
+            .spawn(move || {
+                while let Ok(path) = job_rx.recv() {
+                    if stop_for_thread.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    if !wait_until_settled(&path, SETTLE_INTERVAL, SETTLE_MAX_WAIT) {
+                        debug!("Preview skipped, file did not settle: {}", path.display());
+                        continue;
+                    }
+                    match pregenerate(&cache_dir, &path) {
+                        Ok(Some(_)) => debug!("Preview generated: {}", path.display()),
+                        Ok(None) => {}
+                        Err(e) => debug!("Preview failed for {}: {e}", path.display()),
+                    }
+                }
+                debug!("Preview queue stopped");
+            })?;
+        Ok(Self { job_tx, stop, join })
+    }
+
+    /// Поставить файл в очередь.
+    pub fn enqueue(&self, path: PathBuf) {
+        if self.job_tx.send(path).is_err() {
+            debug!("Preview queue is closed");
+        }
+    }
+
+    /// Останавливает тред; необработанные файлы отбрасываются.
+    pub fn stop(self) {
+        self.stop.store(true, Ordering::Relaxed);
+        drop(self.job_tx);
+        if self.join.join().is_err() {
+            warn!("Preview thread panicked");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_preview_is_truncated() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("note.txt");
+        std::fs::write(&path, "  привет, мир  ").unwrap();
+
+        assert_eq!(generate_text_preview(&path, 6), Some("привет".to_string()));
+        let binary = temp_dir.path().join("photo.bin");
+        std::fs::write(&binary, [0u8; 4]).unwrap();
+        assert_eq!(generate_text_preview(&binary, 6), None);
+    }
+
+    #[test]
+    fn test_pregenerate_fills_cache() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cache_dir = temp_dir.path().join(PREVIEW_DIR);
+        let path = temp_dir.path().join("note.txt");
+        std::fs::write(&path, "hello").unwrap();
+
+        assert_eq!(cached_text_preview(&cache_dir, &path).unwrap(), None);
+        assert!(pregenerate(&cache_dir, &path).unwrap().is_some());
+        assert_eq!(
+            cached_text_preview(&cache_dir, &path).unwrap(),
+            Some("hello".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unchanged_file_settles() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("a.txt");
+        std::fs::write(&path, "a").unwrap();
+        let interval = Duration::from_millis(10);
+
+        assert!(wait_until_settled(&path, interval, Duration::from_secs(1)));
+        assert!(!wait_until_settled(
+            &temp_dir.path().join("missing.txt"),
+            interval,
+            Duration::from_secs(1)
+        ));
+    }
+}
@@ -0,0 +1,345 @@
+//! Квота наблюдаемой папки (суммарный размер / число файлов).
+//!
+//! Нужна kiosk-сценариям, где папку нельзя заполнять бесконечно. Фоновый
+//! тред периодически измеряет папку; при превышении квоты вызывается
+//! callback и, если включено, выполняется политика очистки — удаление самых
+//! старых (по mtime) файлов, пока папка не уложится в квоту. В режиме
+//! «только наблюдение» ([`crate::read_only`]) очистка не выполняется.
+//! Удалённые файлы записываются в журнал аудита ([`crate::audit_log`]); при
+//! пробном запуске ([`crate::dry_run`]) удаления только сообщаются.
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use log::{debug, info, warn};
+
+use crate::audit_log::{self, AuditAction};
+use crate::dry_run;
+use crate::error::LateraError;
+use crate::expected_changes;
+use crate::internal_files;
+use crate::read_only;
+
+/// Правило очистки в журнале аудита.
+pub const RULE_ID: &str = "quota.cleanup_oldest";
+
+/// Интервал проверки квоты по умолчанию.
+pub const DEFAULT_QUOTA_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Квота папки. `None` — без ограничения по этому параметру.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FolderQuota {
+    pub max_total_bytes: Option<u64>,
+    pub max_file_count: Option<u64>,
+    /// Удалять самые старые файлы при превышении квоты.
+    pub cleanup_oldest: bool,
+}
+
+impl FolderQuota {
+    /// Задано ли хотя бы одно ограничение.
+    pub fn is_enabled(&self) -> bool {
+        self.max_total_bytes.is_some() || self.max_file_count.is_some()
+    }
+
+    /// Превышена ли квота.
+    pub fn is_exceeded(&self, usage: &FolderUsage) -> bool {
+        self.max_total_bytes
+            .is_some_and(|max| usage.total_bytes > max)
+            || self
+                .max_file_count
+                .is_some_and(|max| usage.file_count > max)
+    }
+}
+
+/// Занятость папки.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FolderUsage {
+    pub total_bytes: u64,
+    pub file_count: u64,
+}
+
+/// Результат очистки.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CleanupReport {
+    pub removed_files: u64,
+    pub freed_bytes: u64,
+}
+
+/// Событие превышения квоты.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QuotaExceeded {
+    /// Занятость в момент обнаружения (до очистки).
+    pub usage: FolderUsage,
+    pub quota: FolderQuota,
+    /// Результат очистки, если она включена.
+    pub cleanup: Option<CleanupReport>,
+}
+
+impl QuotaExceeded {
+    /// Занятость после очистки (без очистки — та же, что при обнаружении).
+    pub fn usage_after_cleanup(&self) -> FolderUsage {
+        let cleanup = self.cleanup.unwrap_or_default();
+        FolderUsage {
+            total_bytes: self.usage.total_bytes - cleanup.freed_bytes,
+            file_count: self.usage.file_count - cleanup.removed_files,
+        }
+    }
+}
+
+struct FileEntry {
+    path: PathBuf,
+    size: u64,
+    modified: SystemTime,
+}
+
+/// Все обычные файлы внутри `dir` (рекурсивно, без перехода по symlink'ам).
+fn list_files(dir: &Path) -> Result<Vec<FileEntry>, LateraError> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(current) = pending.pop() {
+        for entry in std::fs::read_dir(&current)? {
+            let Ok(entry) = entry else { continue };
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_dir() {
+                pending.push(entry.path());
+            } else if file_type.is_file() {
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+                files.push(FileEntry {
+                    path: entry.path(),
+                    size: metadata.len(),
+                    modified: metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+                });
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// Измерить занятость папки (рекурсивно).
+pub fn measure_usage(dir: &Path) -> Result<FolderUsage, LateraError> {
+    let files = list_files(dir)?;
+    Ok(FolderUsage {
+        total_bytes: files.iter().map(|f| f.size).sum(),
+        file_count: files.len() as u64,
+    })
+}
+
+fn is_protected(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|name| name.starts_with('.') || internal_files::is_internal_name(name))
+}
+
+/// Удалить самые старые файлы, пока папка не уложится в квоту.
+///
+/// Служебные файлы (скрытые, [`internal_files`]) не удаляются, но учитываются в занятости.
+/// При пробном запуске удаления только сообщаются ([`dry_run::report`]), а
+/// отчёт пуст.
+pub fn cleanup_oldest(dir: &Path, quota: &FolderQuota) -> Result<CleanupReport, LateraError> {
+    let mut files = list_files(dir)?;
+    let mut usage = FolderUsage {
+        total_bytes: files.iter().map(|f| f.size).sum(),
+        file_count: files.len() as u64,
+    };
+    files.retain(|f| !is_protected(&f.path));
+    files.sort_by_key(|f| f.modified);
+
+    let mut report = CleanupReport::default();
+    for file in files {
+        if !quota.is_exceeded(&usage) {
+            break;
+        }
+        if dry_run::is_enabled() {
+            dry_run::report(AuditAction::Delete, &file.path, None, RULE_ID);
+            usage.total_bytes -= file.size;
+            usage.file_count -= 1;
+            continue;
+        }
+        expected_changes::expect(&file.path);
+        match std::fs::remove_file(&file.path) {
+            Ok(()) => {
+                info!("Quota cleanup removed {}", file.path.display());
+                audit_log::record(AuditAction::Delete, &file.path, None, Some(RULE_ID));
+                usage.total_bytes -= file.size;
+                usage.file_count -= 1;
+                report.removed_files += 1;
+                report.freed_bytes += file.size;
+            }
+            Err(e) => {
+                expected_changes::forget(&file.path);
+                warn!("Quota cleanup failed for {}: {e}", file.path.display());
+            }
+        }
+    }
+    Ok(report)
+}
+
+/// Handle фонового треда проверки квоты.
+pub struct QuotaMonitorHandle {
+    stop_tx: mpsc::Sender<()>,
+    join: thread::JoinHandle<()>,
+}
+
+impl QuotaMonitorHandle {
+    /// Останавливает тред и дожидается его завершения.
+    pub fn stop(self) {
+        let _ = self.stop_tx.send(());
+        if self.join.join().is_err() {
+            warn!("Quota monitor thread panicked");
+        }
+    }
+}
+
+/// Запускает проверку квоты `watch_dir` раз в `interval`.
+///
+/// `on_exceeded` вызывается при переходе в состояние «квота превышена»;
+/// повторно — только после того, как папка снова уложилась в квоту.
+pub fn spawn_monitor<F>(
+    watch_dir: PathBuf,
+    quota: FolderQuota,
+    interval: Duration,
+    on_exceeded: F,
+) -> Result<QuotaMonitorHandle, LateraError>
+where
+    F: Fn(QuotaExceeded) + Send + 'static,
+{
+    if !quota.is_enabled() {
+        return Err(LateraError::InvalidArgument(
+            "folder quota has no limits".to_string(),
+        ));
+    }
+
+    let (stop_tx, stop_rx) = mpsc::channel::<()>();
+    let join = thread::Builder::new()
+        .name("latera-quota".to_string())
+        .spawn(move || {
+            let mut exceeded = false;
+            loop {
+                match check_once(&watch_dir, &quota) {
+                    Ok(Some(event)) if !exceeded => {
+                        warn!(
+                            "Folder quota exceeded: {} bytes, {} files",
+                            event.usage.total_bytes, event.usage.file_count
+                        );
+                        // После успешной очистки папка снова в квоте — следующее
+                        // превышение будет новым событием.
+                        exceeded = quota.is_exceeded(&event.usage_after_cleanup());
+                        on_exceeded(event);
+                    }
+                    Ok(Some(_)) => {}
+                    Ok(None) => exceeded = false,
+                    Err(e) => debug!("Quota check failed: {e}"),
+                }
+                match stop_rx.recv_timeout(interval) {
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Ok(()) | Err(RecvTimeoutError::Disconnected) => break,
+                }
+            }
+            debug!("Quota monitor stopped");
+        })?;
+    Ok(QuotaMonitorHandle { stop_tx, join })
+}
+
+/// Одна проверка: `Some`, если квота превышена (с очисткой, если включена).
+pub fn check_once(
+    watch_dir: &Path,
+    quota: &FolderQuota,
+) -> Result<Option<QuotaExceeded>, LateraError> {
+    let usage = measure_usage(watch_dir)?;
+    if !quota.is_exceeded(&usage) {
+        return Ok(None);
+    }
+    // В режиме «только наблюдение» файлы не удаляются — только событие.
+    let cleanup = if quota.cleanup_oldest && !read_only::is_enabled() {
+        Some(cleanup_oldest(watch_dir, quota)?)
+    } else {
+        None
+    };
+    Ok(Some(QuotaExceeded {
+        usage,
+        quota: *quota,
+        cleanup,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_file(dir: &Path, name: &str, size: usize, age_secs: u64) {
+        let path = dir.join(name);
+        std::fs::write(&path, vec![b'x'; size]).unwrap();
+        let mtime = SystemTime::now() - Duration::from_secs(age_secs);
+        std::fs::File::options()
+            .write(true)
+            .open(&path)
+            .and_then(|f| f.set_modified(mtime))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_measure_usage_is_recursive() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(temp_dir.path().join("sub")).unwrap();
+        write_file(temp_dir.path(), "a.txt", 10, 0);
+        write_file(&temp_dir.path().join("sub"), "b.txt", 5, 0);
+
+        let usage = measure_usage(temp_dir.path()).unwrap();
+        assert_eq!(
+            usage,
+            FolderUsage {
+                total_bytes: 15,
+                file_count: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_check_without_cleanup_only_reports() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        write_file(temp_dir.path(), "a.txt", 10, 0);
+        write_file(temp_dir.path(), "b.txt", 10, 0);
+        let quota = FolderQuota {
+            max_file_count: Some(1),
+            ..FolderQuota::default()
+        };
+
+        let event = check_once(temp_dir.path(), &quota).unwrap().unwrap();
+        assert_eq!(event.usage.file_count, 2);
+        assert!(event.cleanup.is_none());
+        assert!(temp_dir.path().join("a.txt").exists());
+    }
+
+    #[test]
+    fn test_cleanup_removes_oldest_and_keeps_protected() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        write_file(temp_dir.path(), "desktop.ini", 10, 300);
+        write_file(temp_dir.path(), "old.txt", 10, 200);
+        write_file(temp_dir.path(), "mid.txt", 10, 100);
+        write_file(temp_dir.path(), "new.txt", 10, 0);
+        let quota = FolderQuota {
+            max_total_bytes: Some(25),
+            cleanup_oldest: true,
+            ..FolderQuota::default()
+        };
+
+        let report = cleanup_oldest(temp_dir.path(), &quota).unwrap();
+        assert_eq!(
+            report,
+            CleanupReport {
+                removed_files: 2,
+                freed_bytes: 20
+            }
+        );
+        assert!(temp_dir.path().join("desktop.ini").exists());
+        assert!(!temp_dir.path().join("old.txt").exists());
+        assert!(!temp_dir.path().join("mid.txt").exists());
+        assert!(temp_dir.path().join("new.txt").exists());
+    }
+}
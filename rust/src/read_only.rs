@@ -0,0 +1,32 @@
+//! Режим «только наблюдение».
+//!
+//! Для папок, которые нельзя изменять: ядро гарантирует, что ничего не пишет
+//! в watch dir — не создаёт её, не кладёт `desktop.ini`, не удаляет файлы
+//! по квоте и не меняет атрибуты файлов. API, которым нужна запись,
+//! возвращают [`LateraError::ReadOnlyMode`].
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::error::LateraError;
+
+static READ_ONLY: AtomicBool = AtomicBool::new(false);
+
+/// Включить или выключить режим «только наблюдение».
+pub fn set_enabled(enabled: bool) {
+    READ_ONLY.store(enabled, Ordering::Relaxed);
+}
+
+/// Включён ли режим «только наблюдение».
+pub fn is_enabled() -> bool {
+    READ_ONLY.load(Ordering::Relaxed)
+}
+
+/// Ошибка [`LateraError::ReadOnlyMode`], если режим включён.
+///
+/// `operation` — что именно пытались сделать (попадает в текст ошибки).
+pub fn ensure_writable(operation: &str) -> Result<(), LateraError> {
+    if is_enabled() {
+        return Err(LateraError::ReadOnlyMode(operation.to_string()));
+    }
+    Ok(())
+}
@@ -0,0 +1,196 @@
+//! Восстановление после аварийного завершения.
+//!
+//! Если процесс был убит, в папке данных остаются артефакты прошлого
+//! запуска: временные файлы атомарной записи (`*.tmp` — превью, архивы
+//! логов, компакция WAL событий), «осиротевшие» `-wal`/`-shm`/`-journal`
+//! файлы SQLite без основной БД и lock-файл экземпляра ядра.
+//!
+//! [`recover`] вызывается из `init_core`: захватывает lock экземпляра и
+//! убирает артефакты. Если lock держит другой живой процесс, очистка
+//! пропускается — его временные файлы ещё в работе.
+
+use std::path::{Path, PathBuf};
+
+use log::{info, warn};
+use sysinfo::{Pid, ProcessesToUpdate, System};
+
+use crate::error::LateraError;
+use crate::preview;
+
+/// Lock-файл экземпляра ядра (содержит PID владельца).
+pub const INSTANCE_LOCK_FILE: &str = "core.lock";
+
+/// Папка логов внутри папки данных.
+const LOG_DIR: &str = "logs";
+
+/// Суффиксы служебных файлов SQLite рядом с основной БД.
+const SQLITE_SIDECAR_SUFFIXES: [&str; 3] = ["-wal", "-shm", "-journal"];
+
+/// Что было найдено и убрано при восстановлении.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RecoveryReport {
+    /// PID процесса, не снявшего lock (прошлый запуск завершился аварийно).
+    pub stale_lock_pid: Option<u32>,
+    /// Удалённые недописанные временные файлы.
+    pub removed_temp_files: Vec<PathBuf>,
+    /// Удалённые служебные файлы SQLite без основной БД.
+    pub removed_sqlite_remnants: Vec<PathBuf>,
+    /// Очистка пропущена: lock держит другой живой процесс.
+    pub skipped_live_owner: Option<u32>,
+}
+
+impl RecoveryReport {
+    /// Нечего было восстанавливать.
+    pub fn is_clean(&self) -> bool {
+        self.stale_lock_pid.is_none()
+            && self.removed_temp_files.is_empty()
+            && self.removed_sqlite_remnants.is_empty()
+    }
+}
+
+/// Захватывает lock экземпляра и убирает артефакты прошлого запуска.
+pub fn recover(data_dir: &Path) -> Result<RecoveryReport, LateraError> {
+    let mut report = RecoveryReport::default();
+    let own_pid = std::process::id();
+
+    match read_lock_pid(data_dir) {
+        Some(pid) if pid != own_pid && is_process_alive(pid) => {
+            warn!("Core lock is held by live process {pid}; skipping orphan cleanup");
+            report.skipped_live_owner = Some(pid);
+            return Ok(report);
+        }
+        Some(pid) if pid != own_pid => report.stale_lock_pid = Some(pid),
+        _ => {}
+    }
+    std::fs::write(data_dir.join(INSTANCE_LOCK_FILE), own_pid.to_string())?;
+
+    for dir in [
+        data_dir.to_path_buf(),
+        data_dir.join(preview::PREVIEW_DIR),
+        data_dir.join(LOG_DIR),
+    ] {
+        clean_dir(&dir, &mut report);
+    }
+
+    if let Some(pid) = report.stale_lock_pid {
+        warn!("Previous core run (pid {pid}) did not shut down cleanly");
+    }
+    for path in &report.removed_temp_files {
+        info!("Recovered: removed orphaned temp file {}", path.display());
+    }
+    for path in &report.removed_sqlite_remnants {
+        info!("Recovered: removed orphaned SQLite file {}", path.display());
+    }
+    Ok(report)
+}
+
+/// Снимает lock экземпляра, если он принадлежит текущему процессу.
+pub fn release_lock(data_dir: &Path) {
+    if read_lock_pid(data_dir) != Some(std::process::id()) {
+        return;
+    }
+    if let Err(e) = std::fs::remove_file(data_dir.join(INSTANCE_LOCK_FILE)) {
+        warn!("Failed to remove core lock: {e}");
+    }
+}
+
+fn read_lock_pid(data_dir: &Path) -> Option<u32> {
+    std::fs::read_to_string(data_dir.join(INSTANCE_LOCK_FILE))
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+fn is_process_alive(pid: u32) -> bool {
+    let pid = Pid::from_u32(pid);
+    let mut system = System::new();
+    system.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+    system.process(pid).is_some()
+}
+
+fn clean_dir(dir: &Path, report: &mut RecoveryReport) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries.flatten().map(|e| e.path()) {
+        if !path.is_file() {
+            continue;
+        }
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let removed = if path.extension().is_some_and(|ext| ext == "tmp") {
+            &mut report.removed_temp_files
+        } else if is_orphaned_sqlite_sidecar(&path, name) {
+            &mut report.removed_sqlite_remnants
+        } else {
+            continue;
+        };
+        match std::fs::remove_file(&path) {
+            Ok(()) => removed.push(path),
+            Err(e) => warn!("Failed to remove orphaned {}: {e}", path.display()),
+        }
+    }
+}
+
+/// `-wal`/`-shm`/`-journal`, у которого нет основной БД.
+///
+/// Служебные файлы существующей БД не трогаем: SQLite сам восстановит из
+/// них закоммиченные данные при открытии.
+fn is_orphaned_sqlite_sidecar(path: &Path, name: &str) -> bool {
+    SQLITE_SIDECAR_SUFFIXES.iter().any(|suffix| {
+        name.strip_suffix(suffix).is_some_and(|db_name| {
+            Path::new(db_name)
+                .extension()
+                .is_some_and(|ext| ext == "db")
+                && !path.with_file_name(db_name).exists()
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recover_removes_orphans_and_keeps_live_data() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let data_dir = temp_dir.path();
+        let previews = data_dir.join(preview::PREVIEW_DIR);
+        std::fs::create_dir_all(&previews).unwrap();
+
+        std::fs::write(data_dir.join("events.wal.tmp"), "A").unwrap();
+        std::fs::write(previews.join("abc.txt.tmp"), "half").unwrap();
+        std::fs::write(previews.join("abc.txt"), "done").unwrap();
+        std::fs::write(data_dir.join("file_status.db"), "").unwrap();
+        std::fs::write(data_dir.join("file_status.db-wal"), "").unwrap();
+        std::fs::write(data_dir.join("gone.db-shm"), "").unwrap();
+
+        let report = recover(data_dir).unwrap();
+        assert_eq!(report.removed_temp_files.len(), 2);
+        assert_eq!(
+            report.removed_sqlite_remnants,
+            vec![data_dir.join("gone.db-shm")]
+        );
+        assert!(previews.join("abc.txt").exists());
+        assert!(data_dir.join("file_status.db-wal").exists());
+        assert_eq!(read_lock_pid(data_dir), Some(std::process::id()));
+
+        // Повторный вызов тем же процессом — чисто
+        assert!(recover(data_dir).unwrap().is_clean());
+        release_lock(data_dir);
+        assert!(!data_dir.join(INSTANCE_LOCK_FILE).exists());
+    }
+
+    #[test]
+    fn test_stale_lock_is_reported() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        // PID, которого заведомо нет
+        std::fs::write(temp_dir.path().join(INSTANCE_LOCK_FILE), "4000000000").unwrap();
+
+        let report = recover(temp_dir.path()).unwrap();
+        assert_eq!(report.stale_lock_pid, Some(4_000_000_000));
+        assert_eq!(report.skipped_live_owner, None);
+    }
+}
@@ -0,0 +1,418 @@
+//! Правила автоматической организации файлов.
+//!
+//! Приложение регистрирует декларативные правила: условие (glob по имени,
+//! MIME-тип, размер) → действие (перенести в подпапку, переименовать по
+//! шаблону, пометить тегом, игнорировать). Для каждого появившегося файла
+//! выполняется первое подходящее правило — так watcher не только сообщает
+//! о файлах, но и раскладывает их.
+//!
+//! Перенос и переименование записываются в журнал аудита
+//! ([`crate::audit_log`]) с идентификатором `rules.{id}`; при пробном
+//! запуске ([`crate::dry_run`]) только сообщаются, в режиме «только
+//! наблюдение» ([`crate::read_only`]) не выполняются.
+
+use std::path::{Component, Path, PathBuf};
+use std::time::SystemTime;
+
+use chrono::{DateTime, Local};
+use glob::{MatchOptions, Pattern};
+
+use crate::archive;
+use crate::audit_log::{self, AuditAction};
+use crate::dry_run;
+use crate::error::LateraError;
+use crate::file_ops;
+use crate::file_type;
+use crate::intake;
+use crate::read_only;
+
+/// Шаблоны сравниваются с именем файла без учёта регистра.
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: false,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Какие файлы подходят под правило. Пустые поля не ограничивают выбор.
+#[derive(Clone, Debug, Default)]
+pub struct RuleCondition {
+    /// Хотя бы один шаблон должен совпасть с именем файла.
+    pub name_globs: Vec<Pattern>,
+    /// MIME-типы по содержимому: точные (`application/pdf`) или группы
+    /// (`image/*`).
+    pub mime_types: Vec<String>,
+    pub min_size: Option<u64>,
+    pub max_size: Option<u64>,
+}
+
+impl RuleCondition {
+    /// Собирает условие; некорректный glob — ошибка.
+    pub fn new(
+        name_globs: &[String],
+        mime_types: &[String],
+        min_size: Option<u64>,
+        max_size: Option<u64>,
+    ) -> Result<Self, LateraError> {
+        let name_globs = name_globs
+            .iter()
+            .map(|p| {
+                Pattern::new(p).map_err(|e| {
+                    LateraError::InvalidArgument(format!("invalid glob pattern {p:?}: {e}"))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            name_globs,
+            mime_types: mime_types
+                .iter()
+                .map(|m| m.trim().to_lowercase())
+                .filter(|m| !m.is_empty())
+                .collect(),
+            min_size,
+            max_size,
+        })
+    }
+
+    /// Подходит ли файл размером `size`. MIME-тип определяется по
+    /// содержимому, только если он входит в условие.
+    pub fn matches(&self, path: &Path, size: u64) -> bool {
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            return false;
+        };
+        if self.min_size.is_some_and(|min| size < min)
+            || self.max_size.is_some_and(|max| size > max)
+        {
+            return false;
+        }
+        if !self.name_globs.is_empty()
+            && !self
+                .name_globs
+                .iter()
+                .any(|p| p.matches_with(name, MATCH_OPTIONS))
+        {
+            return false;
+        }
+        if self.mime_types.is_empty() {
+            return true;
+        }
+        let Some(mime) = file_type::sniff_mime_type(path) else {
+            return false;
+        };
+        self.mime_types.iter().any(|wanted| {
+            wanted.strip_suffix("/*").map_or(*wanted == mime, |group| {
+                mime.split('/').next() == Some(group)
+            })
+        })
+    }
+}
+
+/// Что сделать с подходящим файлом.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RuleAction {
+    /// Перенести в подпапку папки, где появился файл.
+    MoveToSubfolder(PathBuf),
+    /// Переименовать по шаблону (см. [`render_name`]).
+    Rename(String),
+    /// Пометить тегом ([`crate::tags`]). Файл не меняется.
+    Tag(String),
+    /// Не сообщать о файле и не обрабатывать его.
+    Ignore,
+}
+
+/// Правило: условие и действие.
+#[derive(Clone, Debug)]
+pub struct FileRule {
+    pub id: String,
+    pub condition: RuleCondition,
+    pub action: RuleAction,
+}
+
+impl FileRule {
+    /// Проверяет идентификатор и параметры действия.
+    pub fn validate(&self) -> Result<(), LateraError> {
+        if self.id.trim().is_empty() {
+            return Err(LateraError::InvalidArgument(
+                "rule id must not be empty".to_string(),
+            ));
+        }
+        match &self.action {
+            RuleAction::MoveToSubfolder(subfolder) => {
+                let plain = subfolder.components().next().is_some()
+                    && subfolder
+                        .components()
+                        .all(|c| matches!(c, Component::Normal(_)));
+                if !plain {
+                    return Err(LateraError::InvalidPath(format!(
+                        "subfolder must be a relative path without '..': {}",
+                        subfolder.display()
+                    )));
+                }
+            }
+            RuleAction::Rename(template) => {
+                let sample = render_name(template, Path::new("sample.txt"), SystemTime::now())?;
+                file_ops::renamed_path(Path::new("sample.txt"), &sample)?;
+            }
+            RuleAction::Tag(tag) if tag.trim().is_empty() => {
+                return Err(LateraError::InvalidArgument(
+                    "tag must not be empty".to_string(),
+                ));
+            }
+            RuleAction::Tag(_) | RuleAction::Ignore => {}
+        }
+        Ok(())
+    }
+
+    /// Идентификатор правила в журнале аудита.
+    pub fn audit_rule_id(&self) -> String {
+        format!("rules.{}", self.id)
+    }
+}
+
+/// Упорядоченный набор правил.
+#[derive(Clone, Debug, Default)]
+pub struct RuleSet {
+    rules: Vec<FileRule>,
+}
+
+impl RuleSet {
+    /// Добавляет правило в конец или заменяет правило с тем же `id` на его месте.
+    pub fn upsert(&mut self, rule: FileRule) {
+        match self.rules.iter_mut().find(|r| r.id == rule.id) {
+            Some(existing) => *existing = rule,
+            None => self.rules.push(rule),
+        }
+    }
+
+    /// Удаляет правило; `false` — такого не было.
+    pub fn remove(&mut self, id: &str) -> bool {
+        let before = self.rules.len();
+        self.rules.retain(|r| r.id != id);
+        self.rules.len() != before
+    }
+
+    pub fn rules(&self) -> &[FileRule] {
+        &self.rules
+    }
+
+    /// Первое правило, под которое подходит файл.
+    pub fn first_match(&self, path: &Path, size: u64) -> Option<&FileRule> {
+        self.rules.iter().find(|r| r.condition.matches(path, size))
+    }
+}
+
+/// Имя файла по шаблону.
+///
+/// Подстановки: `{name}` — имя без расширения, `{ext}` — расширение с
+/// точкой (пусто, если его нет), `{date}` — дата изменения файла
+/// `YYYY-MM-DD`, `{year}`, `{month}`, `{day}`.
+pub fn render_name(
+    template: &str,
+    path: &Path,
+    modified: SystemTime,
+) -> Result<String, LateraError> {
+    let date = DateTime::<Local>::from(modified);
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let extension = path
+        .extension()
+        .map(|e| format!(".{}", e.to_string_lossy()))
+        .unwrap_or_default();
+
+    let mut name = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        name.push_str(&rest[..start]);
+        let Some(len) = rest[start..].find('}') else {
+            return Err(LateraError::InvalidArgument(format!(
+                "unclosed placeholder in template {template:?}"
+            )));
+        };
+        let value = match &rest[start + 1..start + len] {
+            "name" => stem.clone(),
+            "ext" => extension.clone(),
+            "date" => date.format("%Y-%m-%d").to_string(),
+            "year" => date.format("%Y").to_string(),
+            "month" => date.format("%m").to_string(),
+            "day" => date.format("%d").to_string(),
+            other => {
+                return Err(LateraError::InvalidArgument(format!(
+                    "unknown placeholder {{{other}}} in template {template:?}"
+                )))
+            }
+        };
+        name.push_str(&value);
+        rest = &rest[start + len + 1..];
+    }
+    name.push_str(rest);
+    Ok(name)
+}
+
+/// Чем закончилось выполнение правила.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RuleOutcome {
+    /// Файл перенесён или переименован.
+    Moved(PathBuf),
+    /// Файл не менялся: действие его не трогает или он уже на месте.
+    Unchanged,
+    /// Пробный запуск: действие только сообщено.
+    Planned,
+}
+
+/// Выполняет действие правила над файлом `path`.
+pub fn apply(rule: &FileRule, path: &Path) -> Result<RuleOutcome, LateraError> {
+    let parent = path
+        .parent()
+        .ok_or_else(|| LateraError::InvalidPath(path.display().to_string()))?;
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| LateraError::FileNameMissing(path.to_path_buf()))?;
+    let (audit_action, target_dir, new_name) = match &rule.action {
+        RuleAction::Tag(_) | RuleAction::Ignore => return Ok(RuleOutcome::Unchanged),
+        RuleAction::MoveToSubfolder(subfolder) => {
+            // Уже в подпапке (рекурсивный watcher увидел результат переноса)
+            if parent.ends_with(subfolder) {
+                return Ok(RuleOutcome::Unchanged);
+            }
+            (
+                AuditAction::Move,
+                parent.join(subfolder),
+                file_name.to_os_string(),
+            )
+        }
+        RuleAction::Rename(template) => {
+            let modified = std::fs::metadata(path)?
+                .modified()
+                .unwrap_or_else(|_| SystemTime::now());
+            let new_name = render_name(template, path, modified)?;
+            if file_name == new_name.as_str() {
+                return Ok(RuleOutcome::Unchanged);
+            }
+            file_ops::renamed_path(path, &new_name)?;
+            (AuditAction::Rename, parent.to_path_buf(), new_name.into())
+        }
+    };
+
+    let rule_id = rule.audit_rule_id();
+    if dry_run::is_enabled() {
+        let target = intake::free_path(&target_dir.join(&new_name));
+        dry_run::report(
+            audit_action,
+            path,
+            Some(&target.to_string_lossy()),
+            &rule_id,
+        );
+        return Ok(RuleOutcome::Planned);
+    }
+    read_only::ensure_writable("applying file rules")?;
+
+    let target = archive::move_file(path, &target_dir, &new_name, true)?;
+    audit_log::record(
+        audit_action,
+        path,
+        Some(&target.to_string_lossy()),
+        Some(&rule_id),
+    );
+    Ok(RuleOutcome::Moved(target))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(id: &str, condition: RuleCondition, action: RuleAction) -> FileRule {
+        FileRule {
+            id: id.to_string(),
+            condition,
+            action,
+        }
+    }
+
+    #[test]
+    fn test_first_matching_rule_wins() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let pdf = temp_dir.path().join("Invoice.PDF");
+        std::fs::write(&pdf, b"%PDF-1.7 test").unwrap();
+        let png = temp_dir.path().join("shot.bin");
+        std::fs::write(&png, b"\x89PNG\r\n\x1a\n....").unwrap();
+
+        let mut rules = RuleSet::default();
+        rules.upsert(rule(
+            "big",
+            RuleCondition::new(&[], &[], Some(1_000), None).unwrap(),
+            RuleAction::Ignore,
+        ));
+        rules.upsert(rule(
+            "pdf",
+            RuleCondition::new(&["*.pdf".to_string()], &[], None, None).unwrap(),
+            RuleAction::MoveToSubfolder(PathBuf::from("Invoices")),
+        ));
+        rules.upsert(rule(
+            "images",
+            RuleCondition::new(&[], &["image/*".to_string()], None, None).unwrap(),
+            RuleAction::Tag("image".to_string()),
+        ));
+
+        assert_eq!(rules.first_match(&pdf, 13).unwrap().id, "pdf");
+        assert_eq!(rules.first_match(&pdf, 5_000).unwrap().id, "big");
+        assert_eq!(rules.first_match(&png, 12).unwrap().id, "images");
+
+        // Замена на месте сохраняет порядок
+        rules.upsert(rule(
+            "big",
+            RuleCondition::new(&[], &[], Some(10), None).unwrap(),
+            RuleAction::Ignore,
+        ));
+        assert_eq!(rules.first_match(&pdf, 13).unwrap().id, "big");
+        assert!(rules.remove("big"));
+        assert!(!rules.remove("big"));
+        assert_eq!(rules.rules().len(), 2);
+    }
+
+    #[test]
+    fn test_move_and_rename_actions() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("scan.pdf");
+        std::fs::write(&path, b"%PDF").unwrap();
+
+        let move_rule = rule(
+            "pdf",
+            RuleCondition::default(),
+            RuleAction::MoveToSubfolder(PathBuf::from("Docs/Scans")),
+        );
+        move_rule.validate().unwrap();
+        let RuleOutcome::Moved(moved) = apply(&move_rule, &path).unwrap() else {
+            panic!("file was not moved");
+        };
+        assert_eq!(moved, temp_dir.path().join("Docs/Scans/scan.pdf"));
+        // Повторное событие по уже перенесённому файлу ничего не делает
+        assert_eq!(apply(&move_rule, &moved).unwrap(), RuleOutcome::Unchanged);
+
+        let rename_rule = rule(
+            "prefix",
+            RuleCondition::default(),
+            RuleAction::Rename("{year}-{name}{ext}".to_string()),
+        );
+        rename_rule.validate().unwrap();
+        let RuleOutcome::Moved(renamed) = apply(&rename_rule, &moved).unwrap() else {
+            panic!("file was not renamed");
+        };
+        let year = Local::now().format("%Y").to_string();
+        assert_eq!(
+            renamed.file_name().unwrap().to_string_lossy(),
+            format!("{year}-scan.pdf")
+        );
+        assert!(!moved.exists());
+
+        for invalid in [
+            RuleAction::MoveToSubfolder(PathBuf::from("../out")),
+            RuleAction::Rename("{nope}".to_string()),
+            RuleAction::Rename("a/{name}".to_string()),
+        ] {
+            assert!(rule("bad", RuleCondition::default(), invalid)
+                .validate()
+                .is_err());
+        }
+    }
+}
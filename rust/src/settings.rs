@@ -0,0 +1,249 @@
+//! Хранилище настроек ядра с защитой от сбоев.
+//!
+//! Настройки — плоский JSON-объект `ключ → строка` в
+//! `{data_dir}/settings.json`. Запись атомарна (временный файл + fsync +
+//! rename), перед каждой записью предыдущая версия сохраняется в
+//! `settings.json.1` … `settings.json.{N}` (`.1` — самая свежая).
+//!
+//! Если основной файл повреждён или обрезан (например, после отключения
+//! питания), настройки восстанавливаются из самой свежей целой резервной
+//! копии, а повреждённый файл откладывается в `settings.json.corrupt`.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use log::{info, warn};
+
+use crate::error::LateraError;
+
+/// Имя файла настроек в папке данных.
+pub const SETTINGS_FILE: &str = "settings.json";
+
+/// Сколько предыдущих версий хранить по умолчанию.
+pub const DEFAULT_BACKUP_COUNT: usize = 3;
+
+/// Результат восстановления повреждённого файла настроек.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SettingsRecovery {
+    /// Номер резервной копии, из которой восстановлено (`1` — самая свежая).
+    /// `None` — целых копий не нашлось, настройки сброшены.
+    pub backup_index: Option<usize>,
+    /// Куда отложен повреждённый файл.
+    pub corrupt_path: PathBuf,
+}
+
+/// Настройки в памяти и их файл на диске.
+pub struct SettingsStore {
+    path: PathBuf,
+    backup_count: usize,
+    values: BTreeMap<String, String>,
+}
+
+impl SettingsStore {
+    /// Открывает настройки; при повреждении восстанавливает из копий.
+    ///
+    /// Отсутствующий файл — не повреждение: настройки пустые.
+    pub fn open(
+        path: &Path,
+        backup_count: usize,
+    ) -> Result<(Self, Option<SettingsRecovery>), LateraError> {
+        let mut store = Self {
+            path: path.to_path_buf(),
+            backup_count,
+            values: BTreeMap::new(),
+        };
+        let bytes = match std::fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok((store, None)),
+            Err(e) => return Err(e.into()),
+        };
+        if let Some(values) = parse(&bytes) {
+            store.values = values;
+            return Ok((store, None));
+        }
+
+        warn!("Settings file is corrupted: {}", path.display());
+        let corrupt_path = sibling(path, "corrupt");
+        std::fs::rename(path, &corrupt_path)?;
+
+        let restored = (1..=backup_count).find_map(|index| {
+            let values = parse(&std::fs::read(store.backup_path(index)).ok()?)?;
+            Some((index, values))
+        });
+        let backup_index = restored.as_ref().map(|(index, _)| *index);
+        if let Some((index, values)) = restored {
+            info!("Settings restored from backup {index}");
+            store.values = values;
+        } else {
+            warn!("No intact settings backup found; settings reset to defaults");
+        }
+        store.write_atomically()?;
+        Ok((
+            store,
+            Some(SettingsRecovery {
+                backup_index,
+                corrupt_path,
+            }),
+        ))
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    /// Сохраняет значение (с резервной копией предыдущей версии файла).
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), LateraError> {
+        if self.get(key) == Some(value) {
+            return Ok(());
+        }
+        self.values.insert(key.to_string(), value.to_string());
+        self.save()
+    }
+
+    /// Все значения (по ключу).
+    pub fn values(&self) -> &BTreeMap<String, String> {
+        &self.values
+    }
+
+    /// Сохраняет несколько значений одной записью файла; остальные не меняются.
+    pub fn set_many(&mut self, values: &BTreeMap<String, String>) -> Result<(), LateraError> {
+        if values
+            .iter()
+            .all(|(key, value)| self.get(key) == Some(value))
+        {
+            return Ok(());
+        }
+        self.values
+            .extend(values.iter().map(|(k, v)| (k.clone(), v.clone())));
+        self.save()
+    }
+
+    /// Удаляет значение. Возвращает `true`, если оно было.
+    pub fn remove(&mut self, key: &str) -> Result<bool, LateraError> {
+        if self.values.remove(key).is_none() {
+            return Ok(false);
+        }
+        self.save()?;
+        Ok(true)
+    }
+
+    /// Путь резервной копии `index` (`1` — самая свежая).
+    pub fn backup_path(&self, index: usize) -> PathBuf {
+        sibling(&self.path, &index.to_string())
+    }
+
+    fn save(&mut self) -> Result<(), LateraError> {
+        self.rotate_backups()?;
+        self.write_atomically()
+    }
+
+    /// Сдвигает копии (`.1` → `.2` …) и копирует текущий файл в `.1`.
+    ///
+    /// Текущий файл копируется, а не переносится: основной файл существует
+    /// в любой момент записи.
+    fn rotate_backups(&self) -> Result<(), LateraError> {
+        if self.backup_count == 0 || !self.path.exists() {
+            return Ok(());
+        }
+        for index in (1..self.backup_count).rev() {
+            let from = self.backup_path(index);
+            if from.exists() {
+                std::fs::rename(&from, self.backup_path(index + 1))?;
+            }
+        }
+        std::fs::copy(&self.path, self.backup_path(1))?;
+        Ok(())
+    }
+
+    fn write_atomically(&self) -> Result<(), LateraError> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_vec_pretty(&self.values)
+            .map_err(|e| LateraError::InvalidArgument(format!("settings serialization: {e}")))?;
+        let tmp = sibling(&self.path, "tmp");
+        {
+            let mut file = File::create(&tmp)?;
+            file.write_all(&json)?;
+            file.sync_all()?;
+        }
+        std::fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+}
+
+/// `settings.json` → `settings.json.{suffix}`.
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut raw = path.as_os_str().to_os_string();
+    raw.push(format!(".{suffix}"));
+    PathBuf::from(raw)
+}
+
+/// Целый файл настроек — JSON-объект строк; иначе `None`.
+fn parse(bytes: &[u8]) -> Option<BTreeMap<String, String>> {
+    serde_json::from_slice(bytes).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_keeps_previous_versions() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join(SETTINGS_FILE);
+        let (mut store, recovery) = SettingsStore::open(&path, 2).unwrap();
+        assert_eq!(recovery, None);
+
+        for value in ["a", "b", "c", "d"] {
+            store.set("theme", value).unwrap();
+        }
+        assert_eq!(store.get("theme"), Some("d"));
+        assert_eq!(
+            parse(&std::fs::read(store.backup_path(1)).unwrap()).unwrap()["theme"],
+            "c"
+        );
+        assert_eq!(
+            parse(&std::fs::read(store.backup_path(2)).unwrap()).unwrap()["theme"],
+            "b"
+        );
+        assert!(!store.backup_path(3).exists());
+
+        let (reopened, _) = SettingsStore::open(&path, 2).unwrap();
+        assert_eq!(reopened.get("theme"), Some("d"));
+    }
+
+    #[test]
+    fn test_truncated_file_is_recovered_from_backup() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join(SETTINGS_FILE);
+        let (mut store, _) = SettingsStore::open(&path, 3).unwrap();
+        store.set("theme", "dark").unwrap();
+        store.set("lang", "ru").unwrap();
+
+        // Обрыв питания посреди записи
+        std::fs::write(&path, b"{\"theme\": \"da").unwrap();
+
+        let (store, recovery) = SettingsStore::open(&path, 3).unwrap();
+        let recovery = recovery.unwrap();
+        assert_eq!(recovery.backup_index, Some(1));
+        assert!(recovery.corrupt_path.exists());
+        assert_eq!(store.get("theme"), Some("dark"));
+        assert_eq!(store.get("lang"), None);
+        // Основной файл снова целый
+        assert!(parse(&std::fs::read(&path).unwrap()).is_some());
+    }
+
+    #[test]
+    fn test_corrupted_without_backups_resets() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join(SETTINGS_FILE);
+        std::fs::write(&path, b"\0\0\0").unwrap();
+
+        let (store, recovery) = SettingsStore::open(&path, 3).unwrap();
+        assert_eq!(recovery.unwrap().backup_index, None);
+        assert_eq!(store.get("theme"), None);
+    }
+}
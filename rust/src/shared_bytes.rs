@@ -0,0 +1,104 @@
+//! Байты изображений, разделяемые между владельцами в памяти ядра.
+//!
+//! `Vec<u8>` через FRB копируется дважды: в буфер сериализации и в
+//! `Uint8List` на стороне Dart. Для галереи с сотнями миниатюр и страниц
+//! PDF это удваивает память. [`SharedBytes`] остаётся в ядре за opaque
+//! handle'ом, которым владеет Dart; адрес памяти наружу не выдаётся, так что
+//! висячего представления после освобождения handle'а быть не может.
+//!
+//! [`SharedBytesCache`] отдаёт один и тот же буфер всем, кто просит один
+//! файл: миниатюра на двух экранах занимает память один раз.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Weak};
+
+use crate::error::LateraError;
+
+/// Неизменяемые байты, разделяемые между владельцами.
+#[derive(Clone, Debug)]
+pub struct SharedBytes(Arc<[u8]>);
+
+impl SharedBytes {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Один ли это буфер (а не равные по содержимому).
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl From<Vec<u8>> for SharedBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes.into())
+    }
+}
+
+/// Буферы прочитанных файлов, пока ими кто-то пользуется.
+///
+/// Ключ — путь, поэтому кэшировать стоит только неизменяемые файлы
+/// (миниатюры адресуются хэшем содержимого). Буфер освобождается вместе
+/// с последним handle'ом; кэш памяти не держит.
+#[derive(Debug, Default)]
+pub struct SharedBytesCache {
+    entries: HashMap<PathBuf, Weak<[u8]>>,
+}
+
+impl SharedBytesCache {
+    /// Буфер файла: уже выданный или прочитанный сейчас.
+    pub fn get_or_read(&mut self, path: &Path) -> Result<SharedBytes, LateraError> {
+        if let Some(bytes) = self.entries.get(path).and_then(Weak::upgrade) {
+            return Ok(SharedBytes(bytes));
+        }
+        self.entries.retain(|_, bytes| bytes.strong_count() > 0);
+        let bytes: Arc<[u8]> = std::fs::read(path)?.into();
+        self.entries
+            .insert(path.to_path_buf(), Arc::downgrade(&bytes));
+        Ok(SharedBytes(bytes))
+    }
+
+    /// Сколько буферов сейчас кем-то используется.
+    pub fn live_count(&self) -> usize {
+        self.entries
+            .values()
+            .filter(|bytes| bytes.strong_count() > 0)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_shares_live_buffers() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("thumb.png");
+        std::fs::write(&path, b"png bytes").unwrap();
+        let mut cache = SharedBytesCache::default();
+
+        let first = cache.get_or_read(&path).unwrap();
+        let second = cache.get_or_read(&path).unwrap();
+        assert!(first.ptr_eq(&second));
+        assert_eq!(first.as_slice(), b"png bytes");
+        assert_eq!(cache.live_count(), 1);
+
+        // Последний handle отпущен — память освобождена, файл читается заново
+        drop((first, second));
+        assert_eq!(cache.live_count(), 0);
+        std::fs::write(&path, b"new").unwrap();
+        assert_eq!(cache.get_or_read(&path).unwrap().as_slice(), b"new");
+
+        assert!(cache.get_or_read(&temp_dir.path().join("missing")).is_err());
+    }
+}
@@ -0,0 +1,249 @@
+//! Теги файлов.
+//!
+//! Теги привязаны к содержимому файла (BLAKE3), а не к пути, и хранятся
+//! локально в `{data_dir}/tags.db`. Для каждого известного пути хранится
+//! хэш, с которым он был помечен: переименование, замеченное watcher'ом,
+//! переносит путь, удаление — забывает его, а теги остаются за
+//! содержимым. Если файл с тем же содержимым появится снова (перенос между
+//! папками выглядит как удаление и создание), теги вернутся к нему.
+
+use std::path::{Path, PathBuf};
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::error::LateraError;
+use crate::hashing::{self, HashAlgorithm};
+
+/// Имя хранилища тегов в папке данных.
+pub const TAGS_DB_FILE: &str = "tags.db";
+
+/// Тег без пробелов по краям; пустой тег — ошибка.
+pub fn normalize_tag(tag: &str) -> Result<&str, LateraError> {
+    let tag = tag.trim();
+    if tag.is_empty() {
+        return Err(LateraError::InvalidArgument(
+            "tag must not be empty".to_string(),
+        ));
+    }
+    Ok(tag)
+}
+
+/// Локальное хранилище тегов.
+pub struct TagStore {
+    conn: Connection,
+}
+
+impl TagStore {
+    /// Открывает (или создаёт) хранилище.
+    pub fn open(db_path: &Path) -> Result<Self, LateraError> {
+        if let Some(parent) = db_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        Self::init(Connection::open(db_path)?)
+    }
+
+    fn init(conn: Connection) -> Result<Self, LateraError> {
+        conn.execute_batch(
+            "PRAGMA journal_mode=WAL;
+            CREATE TABLE IF NOT EXISTS tags (
+                hash TEXT NOT NULL,
+                tag TEXT NOT NULL,
+                added_at_ms INTEGER NOT NULL,
+                PRIMARY KEY (hash, tag)
+            );
+            CREATE INDEX IF NOT EXISTS tags_by_tag ON tags(tag);
+            CREATE TABLE IF NOT EXISTS locations (
+                path TEXT PRIMARY KEY,
+                hash TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS locations_by_hash ON locations(hash);",
+        )?;
+        Ok(Self { conn })
+    }
+
+    /// Хэш, с которым путь известен хранилищу.
+    fn known_hash(&self, path: &Path) -> Result<Option<String>, LateraError> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT hash FROM locations WHERE path = ?1",
+                params![path.to_string_lossy()],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// Хэш файла: известный хранилищу (файл мог измениться после того, как
+    /// его пометили) или посчитанный по содержимому и запомненный.
+    fn hash_of(&self, path: &Path) -> Result<String, LateraError> {
+        if let Some(hash) = self.known_hash(path)? {
+            return Ok(hash);
+        }
+        let hash = hashing::compute_hash(path, HashAlgorithm::Blake3)?;
+        self.conn.execute(
+            "INSERT OR REPLACE INTO locations (path, hash) VALUES (?1, ?2)",
+            params![path.to_string_lossy(), hash],
+        )?;
+        Ok(hash)
+    }
+
+    /// Помечает файл тегом. Возвращает `true`, если тега ещё не было.
+    pub fn add(&self, path: &Path, tag: &str, added_at_ms: i64) -> Result<bool, LateraError> {
+        let tag = normalize_tag(tag)?;
+        let hash = self.hash_of(path)?;
+        let added = self.conn.execute(
+            "INSERT OR IGNORE INTO tags (hash, tag, added_at_ms) VALUES (?1, ?2, ?3)",
+            params![hash, tag, added_at_ms],
+        )?;
+        Ok(added > 0)
+    }
+
+    /// Снимает тег. Возвращает `false`, если тега не было.
+    pub fn remove(&self, path: &Path, tag: &str) -> Result<bool, LateraError> {
+        let Some(hash) = self.known_hash(path)? else {
+            return Ok(false);
+        };
+        let removed = self.conn.execute(
+            "DELETE FROM tags WHERE hash = ?1 AND tag = ?2",
+            params![hash, tag.trim()],
+        )?;
+        Ok(removed > 0)
+    }
+
+    /// Теги файла по алфавиту.
+    pub fn list(&self, path: &Path) -> Result<Vec<String>, LateraError> {
+        let hash = match self.known_hash(path)? {
+            Some(hash) => hash,
+            None if path.is_file() => hashing::compute_hash(path, HashAlgorithm::Blake3)?,
+            None => return Ok(Vec::new()),
+        };
+        let mut stmt = self
+            .conn
+            .prepare("SELECT tag FROM tags WHERE hash = ?1 ORDER BY tag")?;
+        let tags = stmt
+            .query_map(params![hash], |row| row.get(0))?
+            .collect::<Result<Vec<String>, _>>()?;
+        Ok(tags)
+    }
+
+    /// Известные пути файлов с тегом `tag` (по возрастанию).
+    pub fn query(&self, tag: &str) -> Result<Vec<PathBuf>, LateraError> {
+        let mut stmt = self.conn.prepare(
+            "SELECT l.path FROM locations l JOIN tags t ON t.hash = l.hash
+             WHERE t.tag = ?1 ORDER BY l.path",
+        )?;
+        let paths = stmt
+            .query_map(params![tag.trim()], |row| row.get::<_, String>(0))?
+            .map(|p| p.map(PathBuf::from))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(paths)
+    }
+
+    /// Файл переименован или перенесён. Если старый путь неизвестен,
+    /// новый сверяется по содержимому ([`Self::observe`]).
+    pub fn rename(&self, from: &Path, to: &Path) -> Result<bool, LateraError> {
+        self.conn.execute(
+            "DELETE FROM locations WHERE path = ?1",
+            params![to.to_string_lossy()],
+        )?;
+        let moved = self.conn.execute(
+            "UPDATE locations SET path = ?2 WHERE path = ?1",
+            params![from.to_string_lossy(), to.to_string_lossy()],
+        )?;
+        if moved > 0 {
+            return Ok(true);
+        }
+        self.observe(to)
+    }
+
+    /// Файла по пути больше нет; теги остаются за содержимым.
+    pub fn forget(&self, path: &Path) -> Result<bool, LateraError> {
+        let removed = self.conn.execute(
+            "DELETE FROM locations WHERE path = ?1",
+            params![path.to_string_lossy()],
+        )?;
+        Ok(removed > 0)
+    }
+
+    /// Файл появился: если его содержимое помечено, а путь ещё неизвестен,
+    /// теги возвращаются к нему. Файл хэшируется, только если есть теги
+    /// без известного пути.
+    pub fn observe(&self, path: &Path) -> Result<bool, LateraError> {
+        let has_orphans: bool = self.conn.query_row(
+            "SELECT EXISTS (SELECT 1 FROM tags t
+             WHERE NOT EXISTS (SELECT 1 FROM locations l WHERE l.hash = t.hash))",
+            [],
+            |row| row.get(0),
+        )?;
+        if !has_orphans || self.known_hash(path)?.is_some() || !path.is_file() {
+            return Ok(false);
+        }
+        let hash = hashing::compute_hash(path, HashAlgorithm::Blake3)?;
+        let attached = self.conn.execute(
+            "INSERT OR REPLACE INTO locations (path, hash)
+             SELECT ?1, ?2 WHERE EXISTS (SELECT 1 FROM tags WHERE hash = ?2)",
+            params![path.to_string_lossy(), hash],
+        )?;
+        Ok(attached > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store() -> TagStore {
+        TagStore::init(Connection::open_in_memory().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_tags_follow_content() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let store = store();
+        let invoice = temp_dir.path().join("invoice.pdf");
+        std::fs::write(&invoice, b"invoice").unwrap();
+
+        assert!(store.add(&invoice, " finance ", 1).unwrap());
+        assert!(!store.add(&invoice, "finance", 2).unwrap());
+        assert!(store.add(&invoice, "2024", 3).unwrap());
+        assert!(store.add(&invoice, "  ", 4).is_err());
+        assert_eq!(store.list(&invoice).unwrap(), vec!["2024", "finance"]);
+
+        // Копия с тем же содержимым помечена теми же тегами
+        let copy = temp_dir.path().join("copy.pdf");
+        std::fs::write(&copy, b"invoice").unwrap();
+        assert_eq!(store.list(&copy).unwrap(), vec!["2024", "finance"]);
+
+        // Переименование, замеченное watcher'ом
+        let renamed = temp_dir.path().join("renamed.pdf");
+        std::fs::rename(&invoice, &renamed).unwrap();
+        assert!(store.rename(&invoice, &renamed).unwrap());
+        assert_eq!(store.query("finance").unwrap(), vec![renamed.clone()]);
+
+        assert!(store.remove(&renamed, "2024").unwrap());
+        assert!(!store.remove(&renamed, "2024").unwrap());
+        assert_eq!(store.list(&renamed).unwrap(), vec!["finance"]);
+    }
+
+    #[test]
+    fn test_tags_return_when_content_reappears() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let store = store();
+        let original = temp_dir.path().join("a.txt");
+        std::fs::write(&original, b"report").unwrap();
+        store.add(&original, "work", 1).unwrap();
+
+        // Перенос между папками: удаление и создание
+        let moved = temp_dir.path().join("sub.txt");
+        std::fs::rename(&original, &moved).unwrap();
+        assert!(store.forget(&original).unwrap());
+        assert!(store.query("work").unwrap().is_empty());
+        assert!(store.observe(&moved).unwrap());
+        assert_eq!(store.query("work").unwrap(), vec![moved]);
+
+        // Несвязанный файл не привязывается
+        let other = temp_dir.path().join("other.txt");
+        std::fs::write(&other, b"other").unwrap();
+        assert!(!store.observe(&other).unwrap());
+    }
+}
@@ -0,0 +1,606 @@
+//! Анонимная телеметрия использования (строго opt-in).
+//!
+//! Отвечает за:
+//! - агрегацию счётчиков по дням: события файлов, использование функций, коды ошибок
+//! - локальное хранение агрегатов (`{data_dir}/telemetry.db`)
+//! - периодическую отправку агрегатов на заданный endpoint
+//! - предпросмотр ровно того payload, который будет отправлен
+//!
+//! ## Приватность
+//! - По умолчанию выключена; включается только явным вызовом `configure`.
+//! - Хранятся только счётчики с фиксированными именами — никаких путей,
+//!   имён файлов, запросов или идентификаторов устройства.
+//! - При выключении все накопленные данные удаляются.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use chrono::DateTime;
+use log::{debug, info, warn};
+use once_cell::sync::Lazy;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde_json::json;
+
+use crate::error::LateraError;
+use crate::file_watcher::now_ms;
+
+/// Имя файла локального хранилища агрегатов.
+pub const TELEMETRY_DB_FILE: &str = "telemetry.db";
+
+/// Как часто агрегаты из памяти сбрасываются в БД.
+const FLUSH_INTERVAL: Duration = Duration::from_mins(1);
+
+/// Как часто агрегаты отправляются на сервер. Отсчитывается от последней
+/// попытки, сохранённой в хранилище, — перезапуск приложения её не сбрасывает.
+const UPLOAD_INTERVAL: Duration = Duration::from_hours(24);
+
+/// Таймаут HTTP-запроса отправки.
+const UPLOAD_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Категория счётчика.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CounterKind {
+    /// События файловой системы (`file_added`, `file_removed`).
+    Event,
+    /// Использование функций (`search`, `rag_query`, ...).
+    Feature,
+    /// Коды ошибок `LateraError::code()`.
+    Error,
+}
+
+impl CounterKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CounterKind::Event => "event",
+            CounterKind::Feature => "feature",
+            CounterKind::Error => "error",
+        }
+    }
+}
+
+/// Агрегированный счётчик за один день (UTC).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TelemetryCounter {
+    /// День в формате `YYYY-MM-DD` (UTC).
+    pub day: String,
+    /// Категория: `"event"`, `"feature"`, `"error"`.
+    pub kind: String,
+    /// Имя счётчика.
+    pub name: String,
+    /// Значение.
+    pub count: u64,
+}
+
+// ============================================================================
+// Store
+// ============================================================================
+
+/// Локальное хранилище агрегатов телеметрии.
+pub struct TelemetryStore {
+    conn: Connection,
+}
+
+impl TelemetryStore {
+    /// Открывает (или создаёт) хранилище.
+    pub fn open(db_path: &Path) -> Result<Self, LateraError> {
+        if let Some(parent) = db_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let conn = Connection::open(db_path)?;
+        conn.execute_batch(
+            "PRAGMA journal_mode=WAL;
+            CREATE TABLE IF NOT EXISTS counters (
+                day TEXT NOT NULL,
+                kind TEXT NOT NULL,
+                name TEXT NOT NULL,
+                count INTEGER NOT NULL,
+                PRIMARY KEY (day, kind, name)
+            );
+            CREATE TABLE IF NOT EXISTS meta (
+                key TEXT PRIMARY KEY,
+                value INTEGER NOT NULL
+            );",
+        )?;
+        Ok(Self { conn })
+    }
+
+    /// Добавляет приращения счётчиков (одной транзакцией).
+    pub fn add(&mut self, counters: &[TelemetryCounter]) -> Result<(), LateraError> {
+        let tx = self.conn.transaction()?;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT INTO counters (day, kind, name, count) VALUES (?1, ?2, ?3, ?4)
+                 ON CONFLICT(day, kind, name) DO UPDATE SET count = count + excluded.count",
+            )?;
+            for c in counters {
+                stmt.execute(params![c.day, c.kind, c.name, c.count as i64])?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Возвращает все накопленные (ещё не отправленные) счётчики.
+    pub fn pending(&self) -> Result<Vec<TelemetryCounter>, LateraError> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT day, kind, name, count FROM counters ORDER BY day, kind, name")?;
+        let rows = stmt.query_map([], |row| {
+            Ok(TelemetryCounter {
+                day: row.get(0)?,
+                kind: row.get(1)?,
+                name: row.get(2)?,
+                count: row.get::<_, i64>(3)? as u64,
+            })
+        })?;
+        Ok(rows.collect::<Result<Vec<_>, _>>()?)
+    }
+
+    /// Вычитает отправленные значения (счётчики, выросшие во время
+    /// отправки, сохраняют разницу).
+    pub fn subtract(&mut self, counters: &[TelemetryCounter]) -> Result<(), LateraError> {
+        let tx = self.conn.transaction()?;
+        {
+            let mut stmt = tx.prepare(
+                "UPDATE counters SET count = count - ?4
+                 WHERE day = ?1 AND kind = ?2 AND name = ?3",
+            )?;
+            for c in counters {
+                stmt.execute(params![c.day, c.kind, c.name, c.count as i64])?;
+            }
+        }
+        tx.execute("DELETE FROM counters WHERE count <= 0", [])?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Время последней попытки отправки (Unix ms), если она была.
+    pub fn last_upload_ms(&self) -> Result<Option<i64>, LateraError> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT value FROM meta WHERE key = 'last_upload_ms'",
+                [],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// Запоминает время попытки отправки.
+    pub fn set_last_upload_ms(&self, ms: i64) -> Result<(), LateraError> {
+        self.conn.execute(
+            "INSERT INTO meta (key, value) VALUES ('last_upload_ms', ?1)
+             ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+            params![ms],
+        )?;
+        Ok(())
+    }
+
+    /// Удаляет все накопленные данные.
+    pub fn clear(&self) -> Result<(), LateraError> {
+        self.conn
+            .execute_batch("DELETE FROM counters; DELETE FROM meta;")?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Global state
+// ============================================================================
+
+/// Включена ли телеметрия (быстрая проверка на горячем пути).
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Ключ агрегата: (день, категория, имя).
+type CounterKey = (String, CounterKind, String);
+
+/// Агрегаты в памяти: ключ → приращение.
+static PENDING: Lazy<Mutex<HashMap<CounterKey, u64>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Хранилище, endpoint и фоновый поток (None = телеметрия выключена).
+struct TelemetryRuntime {
+    store: TelemetryStore,
+    upload_url: Option<String>,
+    worker_stop: mpsc::Sender<()>,
+    worker: thread::JoinHandle<()>,
+}
+
+static RUNTIME: Lazy<Mutex<Option<TelemetryRuntime>>> = Lazy::new(|| Mutex::new(None));
+
+/// Включает или выключает телеметрию.
+///
+/// - `enabled = true`: открывает хранилище в `data_dir` и запускает фоновый
+///   поток (сброс агрегатов + отправка на `upload_url`, если указан).
+/// - `enabled = false`: останавливает поток и **удаляет** накопленные данные.
+pub fn configure(
+    data_dir: &Path,
+    enabled: bool,
+    upload_url: Option<String>,
+) -> Result<(), LateraError> {
+    if let Some(url) = &upload_url {
+        if !url.starts_with("https://") {
+            return Err(LateraError::InvalidArgument(format!(
+                "telemetry upload_url must use https: {url}"
+            )));
+        }
+    }
+
+    let db_path = data_dir.join(TELEMETRY_DB_FILE);
+
+    if !enabled {
+        ENABLED.store(false, Ordering::Relaxed);
+        stop_worker();
+        PENDING
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clear();
+        if db_path.exists() {
+            TelemetryStore::open(&db_path)?.clear()?;
+        }
+        info!("Telemetry disabled, local data cleared");
+        return Ok(());
+    }
+
+    let mut guard = RUNTIME
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if let Some(rt) = guard.as_mut() {
+        rt.upload_url = upload_url;
+        info!("Telemetry reconfigured");
+        return Ok(());
+    }
+
+    let store = TelemetryStore::open(&db_path)?;
+    // Первая отправка — через полный интервал после включения, а не сразу
+    if store.last_upload_ms()?.is_none() {
+        store.set_last_upload_ms(now_ms())?;
+    }
+    let (worker_stop, stop_rx) = mpsc::channel::<()>();
+    let worker = thread::Builder::new()
+        .name("telemetry".into())
+        .spawn(move || worker_loop(&stop_rx))?;
+
+    *guard = Some(TelemetryRuntime {
+        store,
+        upload_url,
+        worker_stop,
+        worker,
+    });
+    ENABLED.store(true, Ordering::Relaxed);
+    info!("Telemetry enabled (opt-in)");
+    Ok(())
+}
+
+/// Отключает отправку на сервер; агрегаты продолжают копиться локально.
+///
+/// Вызывается, когда политика администратора запрещает отправку уже после
+/// [`configure`] с `upload_url`.
+pub fn disable_uploads() {
+    let mut guard = RUNTIME
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if let Some(rt) = guard.as_mut() {
+        if rt.upload_url.take().is_some() {
+            info!("Telemetry uploads disabled by policy");
+        }
+    }
+}
+
+/// Endpoint отправки; `None` — отправка не настроена или телеметрия выключена.
+pub fn upload_url() -> Option<String> {
+    RUNTIME
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+        .and_then(|rt| rt.upload_url.clone())
+}
+
+/// Включена ли телеметрия.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Увеличивает счётчик. No-op, если телеметрия выключена.
+pub fn record(kind: CounterKind, name: &str) {
+    if !is_enabled() {
+        return;
+    }
+    let key = (utc_day(now_ms()), kind, name.to_string());
+    *PENDING
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .entry(key)
+        .or_insert(0) += 1;
+}
+
+/// Учитывает использование функции и код ошибки (если результат — ошибка).
+///
+/// Возвращает результат без изменений — удобно оборачивать API-вызовы.
+pub fn track<T>(feature: &str, result: Result<T, LateraError>) -> Result<T, LateraError> {
+    record(CounterKind::Feature, feature);
+    if let Err(e) = &result {
+        record(CounterKind::Error, e.code());
+    }
+    result
+}
+
+/// Возвращает накопленные данные, которые будут отправлены.
+pub fn pending() -> Result<Vec<TelemetryCounter>, LateraError> {
+    flush()?;
+    let guard = RUNTIME
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    match guard.as_ref() {
+        Some(rt) => rt.store.pending(),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// Сбрасывает агрегаты из памяти в локальное хранилище.
+pub fn flush() -> Result<(), LateraError> {
+    let mut guard = RUNTIME
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let Some(rt) = guard.as_mut() else {
+        return Ok(());
+    };
+
+    let drained: Vec<TelemetryCounter> = PENDING
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .drain()
+        .map(|((day, kind, name), count)| TelemetryCounter {
+            day,
+            kind: kind.as_str().to_string(),
+            name,
+            count,
+        })
+        .collect();
+
+    if drained.is_empty() {
+        return Ok(());
+    }
+    rt.store.add(&drained)?;
+    debug!("Telemetry: flushed {} counters", drained.len());
+    Ok(())
+}
+
+/// Останавливает фоновый поток и сбрасывает агрегаты на диск.
+///
+/// Вызывается из `shutdown_core`. Настройка (включена/выключена) при этом
+/// не меняется — следующий `configure` продолжит с сохранёнными данными.
+pub fn shutdown() {
+    if let Err(e) = flush() {
+        warn!("Telemetry: flush on shutdown failed: {e}");
+    }
+    ENABLED.store(false, Ordering::Relaxed);
+    stop_worker();
+}
+
+fn stop_worker() {
+    let runtime = RUNTIME
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .take();
+    if let Some(rt) = runtime {
+        let _ = rt.worker_stop.send(());
+        if rt.worker.join().is_err() {
+            warn!("Telemetry worker thread panicked");
+        }
+    }
+}
+
+fn worker_loop(stop_rx: &mpsc::Receiver<()>) {
+    loop {
+        // Проверка и при старте: пропущенная за время простоя отправка
+        // выполняется сразу, а не через сутки работы процесса
+        if let Err(e) = upload_if_due() {
+            warn!("Telemetry: upload failed: {e}");
+        }
+
+        match stop_rx.recv_timeout(FLUSH_INTERVAL) {
+            Ok(()) | Err(mpsc::RecvTimeoutError::Disconnected) => break,
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+        }
+
+        if let Err(e) = flush() {
+            warn!("Telemetry: periodic flush failed: {e}");
+        }
+    }
+    debug!("Telemetry worker finished");
+}
+
+/// Отправляет накопленные агрегаты, если с последней попытки прошёл
+/// [`UPLOAD_INTERVAL`]. При успехе — вычитает отправленное.
+///
+/// Блокировка хранилища на время HTTP-запроса отпускается: `record`,
+/// `flush` и `pending` не ждут таймаута сети.
+fn upload_if_due() -> Result<(), LateraError> {
+    let (url, counters) = {
+        let guard = RUNTIME
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let Some(rt) = guard.as_ref() else {
+            return Ok(());
+        };
+        let Some(url) = rt.upload_url.clone() else {
+            return Ok(());
+        };
+        let now = now_ms();
+        let last = rt.store.last_upload_ms()?.unwrap_or(0);
+        if now.saturating_sub(last) < UPLOAD_INTERVAL.as_millis() as i64 {
+            return Ok(());
+        }
+        // Время попытки фиксируется до отправки: недоступный сервер
+        // не опрашивается каждую минуту
+        rt.store.set_last_upload_ms(now)?;
+        let counters = rt.store.pending()?;
+        if counters.is_empty() {
+            return Ok(());
+        }
+        (url, counters)
+    };
+
+    let payload = build_payload(&counters);
+    ureq::post(&url)
+        .timeout(UPLOAD_TIMEOUT)
+        .set("Content-Type", "application/json")
+        .send_string(&payload)
+        .map_err(|e| LateraError::TelemetryUploadFailed(e.to_string()))?;
+
+    let mut guard = RUNTIME
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    // Выключена во время отправки — данные уже удалены
+    if let Some(rt) = guard.as_mut() {
+        rt.store.subtract(&counters)?;
+    }
+    info!("Telemetry: uploaded {} counters", counters.len());
+    Ok(())
+}
+
+// ============================================================================
+// Payload
+// ============================================================================
+
+/// Формирует JSON payload для отправки (и для предпросмотра в UI).
+///
+/// Формат (ключи по алфавиту):
+/// ```json
+/// {"core_version":"0.1.0","counters":[{"count":42,"day":"2026-01-31","kind":"event","name":"file_added"}],"platform":"windows"}
+/// ```
+pub fn build_payload(counters: &[TelemetryCounter]) -> String {
+    let items: Vec<_> = counters
+        .iter()
+        .map(|c| {
+            json!({
+                "day": c.day,
+                "kind": c.kind,
+                "name": c.name,
+                "count": c.count,
+            })
+        })
+        .collect();
+
+    json!({
+        "core_version": env!("CARGO_PKG_VERSION"),
+        "platform": std::env::consts::OS,
+        "counters": items,
+    })
+    .to_string()
+}
+
+/// Преобразует Unix timestamp (мс) в день `YYYY-MM-DD` (UTC).
+fn utc_day(ms: i64) -> String {
+    DateTime::from_timestamp_millis(ms)
+        .unwrap_or_default()
+        .format("%Y-%m-%d")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counter(day: &str, kind: &str, name: &str, count: u64) -> TelemetryCounter {
+        TelemetryCounter {
+            day: day.to_string(),
+            kind: kind.to_string(),
+            name: name.to_string(),
+            count,
+        }
+    }
+
+    #[test]
+    fn test_utc_day() {
+        assert_eq!(utc_day(0), "1970-01-01");
+        assert_eq!(utc_day(951_782_400_000), "2000-02-29");
+        assert_eq!(utc_day(1_767_225_599_000), "2025-12-31");
+    }
+
+    #[test]
+    fn test_build_payload() {
+        let payload = build_payload(&[counter("2026-01-31", "event", "file\"added", 42)]);
+        let value: serde_json::Value = serde_json::from_str(&payload).unwrap();
+        assert_eq!(value["core_version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(
+            value["counters"],
+            json!([{ "day": "2026-01-31", "kind": "event", "name": "file\"added", "count": 42 }])
+        );
+    }
+
+    #[test]
+    fn test_record_is_noop_when_disabled() {
+        assert!(!is_enabled());
+        record(CounterKind::Feature, "search");
+        assert!(pending().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_store_add_and_subtract() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let mut store = TelemetryStore::open(&temp_dir.path().join(TELEMETRY_DB_FILE)).unwrap();
+
+        store
+            .add(&[
+                counter("2026-01-01", "event", "file_added", 3),
+                counter("2026-01-01", "error", "IO_ERROR", 1),
+            ])
+            .unwrap();
+        store
+            .add(&[counter("2026-01-01", "event", "file_added", 2)])
+            .unwrap();
+
+        let pending = store.pending().unwrap();
+        assert_eq!(pending.len(), 2);
+        assert!(pending.contains(&counter("2026-01-01", "event", "file_added", 5)));
+
+        // Отправлено 4 из 5 → остаётся 1; IO_ERROR уходит полностью
+        store
+            .subtract(&[
+                counter("2026-01-01", "event", "file_added", 4),
+                counter("2026-01-01", "error", "IO_ERROR", 1),
+            ])
+            .unwrap();
+        assert_eq!(
+            store.pending().unwrap(),
+            vec![counter("2026-01-01", "event", "file_added", 1)]
+        );
+
+        store.clear().unwrap();
+        assert!(store.pending().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_last_upload_survives_reopen() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let db_path = temp_dir.path().join(TELEMETRY_DB_FILE);
+
+        let store = TelemetryStore::open(&db_path).unwrap();
+        assert_eq!(store.last_upload_ms().unwrap(), None);
+        store.set_last_upload_ms(1_000).unwrap();
+        store.set_last_upload_ms(2_000).unwrap();
+        drop(store);
+
+        let store = TelemetryStore::open(&db_path).unwrap();
+        assert_eq!(store.last_upload_ms().unwrap(), Some(2_000));
+        store.clear().unwrap();
+        assert_eq!(store.last_upload_ms().unwrap(), None);
+    }
+
+    #[test]
+    fn test_configure_rejects_plain_http() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let result = configure(
+            temp_dir.path(),
+            true,
+            Some("http://example.com/telemetry".to_string()),
+        );
+        assert!(result.is_err());
+        assert!(!is_enabled());
+    }
+}
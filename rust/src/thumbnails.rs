@@ -0,0 +1,289 @@
+//! Миниатюры изображений.
+//!
+//! Уменьшенная копия PNG/JPEG кладётся в кэш приложения
+//! (`{cache_dir}/Latera/thumbnails/{blake3}-{max_edge}.{png|jpg}`): ключ —
+//! хэш содержимого, поэтому переименование файла не сбрасывает кэш, а
+//! изменённый файл получает новую миниатюру. Изображения с прозрачностью
+//! сохраняются в PNG, остальные — в JPEG.
+//!
+//! Для новых изображений из watcher'а миниатюры строятся заранее в фоне
+//! ([`ThumbnailQueue`]), чтобы галерея не декодировала оригиналы при
+//! прокрутке.
+
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::{DynamicImage, ImageFormat};
+use log::{debug, warn};
+
+use crate::error::LateraError;
+use crate::hashing::{self, HashAlgorithm};
+use crate::preview;
+
+/// Папка миниатюр внутри кэша приложения.
+pub const THUMBNAIL_DIR_NAME: &str = "thumbnails";
+
+/// Длинная сторона миниатюры по умолчанию (пиксели).
+pub const DEFAULT_MAX_EDGE: u32 = 256;
+
+/// Наибольшая допустимая длинная сторона миниатюры.
+pub const MAX_EDGE_LIMIT: u32 = 2048;
+
+/// Изображения больше этого числа пикселей не декодируются (память).
+pub const MAX_SOURCE_PIXELS: u64 = 100_000_000;
+
+/// Расширения изображений, для которых строятся миниатюры.
+pub const THUMBNAIL_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg"];
+
+/// Качество JPEG-миниатюр.
+const JPEG_QUALITY: u8 = 85;
+
+/// Как часто проверять, что файл перестал меняться.
+const SETTLE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Дольше не ждём: для файла, меняющегося дольше, миниатюра не строится.
+const SETTLE_MAX_WAIT: Duration = Duration::from_secs(30);
+
+/// Формат файла миниатюры.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThumbnailFormat {
+    Png,
+    Jpeg,
+}
+
+impl ThumbnailFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpg",
+        }
+    }
+
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+        }
+    }
+}
+
+/// Миниатюра в кэше.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Thumbnail {
+    pub path: PathBuf,
+    pub format: ThumbnailFormat,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Изображение поддерживаемого формата (по расширению).
+pub fn is_thumbnailable(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| THUMBNAIL_EXTENSIONS.contains(&e.to_ascii_lowercase().as_str()))
+}
+
+/// Папка миниатюр: `{cache_dir}/Latera/thumbnails`.
+pub fn default_cache_dir() -> Result<PathBuf, LateraError> {
+    dirs::cache_dir()
+        .map(|dir| dir.join("Latera").join(THUMBNAIL_DIR_NAME))
+        .ok_or_else(|| {
+            LateraError::InvalidPath("Cache directory is not available on this OS/user".to_string())
+        })
+}
+
+fn cached_path(cache_dir: &Path, hash: &str, max_edge: u32, format: ThumbnailFormat) -> PathBuf {
+    cache_dir.join(format!("{hash}-{max_edge}.{}", format.extension()))
+}
+
+/// Миниатюра `path` с длинной стороной не больше `max_edge`: из кэша или
+/// построенная и сохранённая в кэш. Маленькие изображения не увеличиваются.
+pub fn get_or_create(
+    cache_dir: &Path,
+    path: &Path,
+    max_edge: u32,
+) -> Result<Thumbnail, LateraError> {
+    if max_edge == 0 || max_edge > MAX_EDGE_LIMIT {
+        return Err(LateraError::InvalidArgument(format!(
+            "max_edge must be between 1 and {MAX_EDGE_LIMIT}, got {max_edge}"
+        )));
+    }
+    let hash = hashing::compute_hash(path, HashAlgorithm::Blake3)?;
+    for format in [ThumbnailFormat::Jpeg, ThumbnailFormat::Png] {
+        let cached = cached_path(cache_dir, &hash, max_edge, format);
+        if let Ok((width, height)) = image::image_dimensions(&cached) {
+            return Ok(Thumbnail {
+                path: cached,
+                format,
+                width,
+                height,
+            });
+        }
+    }
+
+    let invalid = |e: image::ImageError| {
+        LateraError::InvalidArgument(format!("cannot decode image {}: {e}", path.display()))
+    };
+    let (width, height) = image::image_dimensions(path).map_err(invalid)?;
+    if u64::from(width) * u64::from(height) > MAX_SOURCE_PIXELS {
+        return Err(LateraError::InvalidArgument(format!(
+            "image too large for a thumbnail: {width}x{height}"
+        )));
+    }
+    let source = image::ImageReader::open(path)?
+        .with_guessed_format()?
+        .decode()
+        .map_err(invalid)?;
+    let thumbnail = if width.max(height) > max_edge {
+        source.thumbnail(max_edge, max_edge)
+    } else {
+        source
+    };
+    let format = if thumbnail.color().has_alpha() {
+        ThumbnailFormat::Png
+    } else {
+        ThumbnailFormat::Jpeg
+    };
+
+    std::fs::create_dir_all(cache_dir)?;
+    let target = cached_path(cache_dir, &hash, max_edge, format);
+    // Через временный файл — читатель не увидит недописанную миниатюру.
+    let tmp = target.with_extension(format!("{}.tmp", format.extension()));
+    let written = write_image(&thumbnail, &tmp, format);
+    if let Err(e) = written.and_then(|()| std::fs::rename(&tmp, &target).map_err(Into::into)) {
+        let _ = std::fs::remove_file(&tmp);
+        return Err(e);
+    }
+    Ok(Thumbnail {
+        path: target,
+        format,
+        width: thumbnail.width(),
+        height: thumbnail.height(),
+    })
+}
+
+fn write_image(
+    image: &DynamicImage,
+    path: &Path,
+    format: ThumbnailFormat,
+) -> Result<(), LateraError> {
+    let encode_error = |e: image::ImageError| std::io::Error::other(e.to_string());
+    let mut writer = BufWriter::new(std::fs::File::create(path)?);
+    match format {
+        ThumbnailFormat::Png => image
+            .write_to(&mut writer, ImageFormat::Png)
+            .map_err(encode_error)?,
+        ThumbnailFormat::Jpeg => JpegEncoder::new_with_quality(&mut writer, JPEG_QUALITY)
+            .encode_image(&image.to_rgb8())
+            .map_err(encode_error)?,
+    }
+    writer
+        .into_inner()
+        .map_err(|e| e.into_error())?
+        .sync_all()?;
+    Ok(())
+}
+
+/// Очередь фоновой генерации миниатюр новых изображений.
+pub struct ThumbnailQueue {
+    job_tx: mpsc::Sender<PathBuf>,
+    stop: Arc<AtomicBool>,
+    join: thread::JoinHandle<()>,
+}
+
+impl ThumbnailQueue {
+    /// Запускает фоновый тред: миниатюры размера `max_edge` в `cache_dir`.
+    pub fn spawn(cache_dir: PathBuf, max_edge: u32) -> Result<Self, LateraError> {
+        let (job_tx, job_rx) = mpsc::channel::<PathBuf>();
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_for_thread = Arc::clone(&stop);
+        let join = thread::Builder::new()
+            .name("latera-thumbnails".to_string())
+            .spawn(move || {
+                while let Ok(path) = job_rx.recv() {
+                    if stop_for_thread.load(Ordering::Relaxed) {
+                        break;
+                    }
+                    if !preview::wait_until_settled(&path, SETTLE_INTERVAL, SETTLE_MAX_WAIT) {
+                        debug!("Thumbnail skipped, file did not settle: {}", path.display());
+                        continue;
+                    }
+                    match get_or_create(&cache_dir, &path, max_edge) {
+                        Ok(_) => debug!("Thumbnail generated: {}", path.display()),
+                        Err(e) => debug!("Thumbnail failed for {}: {e}", path.display()),
+                    }
+                }
+                debug!("Thumbnail queue stopped");
+            })?;
+        Ok(Self { job_tx, stop, join })
+    }
+
+    /// Поставить файл в очередь; файлы не поддерживаемых форматов игнорируются.
+    pub fn enqueue(&self, path: PathBuf) {
+        if !is_thumbnailable(&path) {
+            return;
+        }
+        if self.job_tx.send(path).is_err() {
+            debug!("Thumbnail queue is closed");
+        }
+    }
+
+    /// Останавливает тред; необработанные файлы отбрасываются.
+    pub fn stop(self) {
+        self.stop.store(true, Ordering::Relaxed);
+        drop(self.job_tx);
+        if self.join.join().is_err() {
+            warn!("Thumbnail thread panicked");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{Rgb, RgbImage, Rgba, RgbaImage};
+
+    use super::*;
+
+    #[test]
+    fn test_thumbnail_is_downscaled_and_cached() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cache_dir = temp_dir.path().join(THUMBNAIL_DIR_NAME);
+        let photo = temp_dir.path().join("photo.jpg");
+        RgbImage::from_pixel(400, 200, Rgb([200, 10, 10]))
+            .save(&photo)
+            .unwrap();
+
+        let thumbnail = get_or_create(&cache_dir, &photo, 100).unwrap();
+        assert_eq!(thumbnail.format, ThumbnailFormat::Jpeg);
+        assert_eq!((thumbnail.width, thumbnail.height), (100, 50));
+        assert!(thumbnail.path.starts_with(&cache_dir));
+
+        // Повторный запрос — из кэша, даже если оригинал переименован
+        let renamed = temp_dir.path().join("renamed.jpg");
+        std::fs::rename(&photo, &renamed).unwrap();
+        assert_eq!(get_or_create(&cache_dir, &renamed, 100).unwrap(), thumbnail);
+        assert_eq!(std::fs::read_dir(&cache_dir).unwrap().count(), 1);
+
+        assert!(get_or_create(&cache_dir, &renamed, 0).is_err());
+    }
+
+    #[test]
+    fn test_small_transparent_image_keeps_size_and_alpha() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let icon = temp_dir.path().join("icon.png");
+        RgbaImage::from_pixel(20, 30, Rgba([0, 0, 0, 0]))
+            .save(&icon)
+            .unwrap();
+
+        let thumbnail = get_or_create(temp_dir.path(), &icon, 256).unwrap();
+        assert_eq!(thumbnail.format, ThumbnailFormat::Png);
+        assert_eq!((thumbnail.width, thumbnail.height), (20, 30));
+        assert!(is_thumbnailable(&icon));
+        assert!(!is_thumbnailable(Path::new("/a/b.pdf")));
+    }
+}
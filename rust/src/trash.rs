@@ -0,0 +1,316 @@
+//! Удаление в корзину системы.
+//!
+//! Безвозвратное удаление из Dart слишком опасно для папки на рабочем
+//! столе, поэтому ядро переносит файл в корзину, откуда его можно вернуть:
+//! - Windows: Shell (`SHFileOperationW` с `FOF_ALLOWUNDO`), путь в корзине
+//!   система не сообщает;
+//! - macOS: `~/.Trash`, для других томов — `{том}/.Trashes/{uid}`;
+//! - Linux и другие Unix: корзина freedesktop.org (`files/` + `info/*.trashinfo`,
+//!   для других томов — `{том}/.Trash-{uid}`), файл можно восстановить из
+//!   файлового менеджера.
+
+use std::path::{Path, PathBuf};
+
+use crate::audit_log::{self, AuditAction};
+use crate::error::LateraError;
+use crate::expected_changes;
+use crate::read_only;
+
+/// Переносит файл или папку в корзину.
+///
+/// Возвращает путь в корзине, если платформа его сообщает.
+pub fn delete_to_trash(path: &Path) -> Result<Option<PathBuf>, LateraError> {
+    if !path.is_absolute() {
+        return Err(LateraError::InvalidPath(format!(
+            "path must be absolute: {}",
+            path.display()
+        )));
+    }
+    if std::fs::symlink_metadata(path).is_err() {
+        return Err(LateraError::InvalidPath(format!(
+            "file not found: {}",
+            path.display()
+        )));
+    }
+    read_only::ensure_writable("moving files to trash")?;
+
+    expected_changes::expect(path);
+    let trashed = platform::trash(path).inspect_err(|_| expected_changes::forget(path))?;
+    audit_log::record(
+        AuditAction::Trash,
+        path,
+        trashed
+            .as_ref()
+            .map(|location| location.to_string_lossy())
+            .as_deref(),
+        None,
+    );
+    Ok(trashed)
+}
+
+/// Корень тома с `path`: самый верхний предок на том же устройстве.
+#[cfg(unix)]
+fn mount_root(path: &Path) -> Result<PathBuf, LateraError> {
+    use std::os::unix::fs::MetadataExt;
+
+    let device = std::fs::symlink_metadata(path)?.dev();
+    let mut root = path.to_path_buf();
+    while let Some(parent) = root.parent() {
+        match std::fs::metadata(parent) {
+            Ok(metadata) if metadata.dev() == device => root = parent.to_path_buf(),
+            _ => break,
+        }
+    }
+    Ok(root)
+}
+
+/// Находится ли `path` на том же устройстве, что и `dir`.
+#[cfg(unix)]
+fn same_device(path: &Path, dir: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    match (std::fs::symlink_metadata(path), std::fs::metadata(dir)) {
+        (Ok(a), Ok(b)) => a.dev() == b.dev(),
+        _ => false,
+    }
+}
+
+#[cfg(unix)]
+fn create_private_dir(dir: &Path) -> Result<(), LateraError> {
+    use std::os::unix::fs::DirBuilderExt;
+
+    std::fs::DirBuilder::new()
+        .recursive(true)
+        .mode(0o700)
+        .create(dir)?;
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use std::path::{Path, PathBuf};
+
+    use crate::error::LateraError;
+    use crate::intake;
+
+    pub fn trash(path: &Path) -> Result<Option<PathBuf>, LateraError> {
+        let home_trash = dirs::home_dir()
+            .ok_or_else(|| LateraError::InvalidPath("home directory is not available".into()))?
+            .join(".Trash");
+        let trash_dir = if super::same_device(path, &home_trash) {
+            home_trash
+        } else {
+            // SAFETY: getuid не имеет предусловий
+            let uid = unsafe { libc::getuid() };
+            let dir = super::mount_root(path)?
+                .join(".Trashes")
+                .join(uid.to_string());
+            super::create_private_dir(&dir)?;
+            dir
+        };
+        let name = path
+            .file_name()
+            .ok_or_else(|| LateraError::FileNameMissing(path.to_path_buf()))?;
+        let target = intake::free_path(&trash_dir.join(name));
+        std::fs::rename(path, &target)?;
+        Ok(Some(target))
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+mod platform {
+    use std::fmt::Write as _;
+    use std::fs::OpenOptions;
+    use std::io::{ErrorKind, Write};
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::{Path, PathBuf};
+
+    use crate::error::LateraError;
+
+    pub fn trash(path: &Path) -> Result<Option<PathBuf>, LateraError> {
+        let home_trash = dirs::data_dir()
+            .ok_or_else(|| LateraError::InvalidPath("data directory is not available".into()))?
+            .join("Trash");
+        super::create_private_dir(&home_trash)?;
+        if super::same_device(path, &home_trash) {
+            return trash_into(path, &home_trash, None).map(Some);
+        }
+        // Другой том: корзина в его корне, пути в .trashinfo — относительно корня
+        let top_dir = super::mount_root(path)?;
+        // SAFETY: getuid не имеет предусловий
+        let uid = unsafe { libc::getuid() };
+        let trash_dir = top_dir.join(format!(".Trash-{uid}"));
+        super::create_private_dir(&trash_dir)?;
+        trash_into(path, &trash_dir, Some(&top_dir)).map(Some)
+    }
+
+    /// Переносит `path` в корзину `trash_dir` по спецификации freedesktop.org.
+    ///
+    /// Имя резервируется созданием `.trashinfo` с `create_new`, поэтому два
+    /// одновременных удаления файлов с одним именем не затрут друг друга.
+    pub(super) fn trash_into(
+        path: &Path,
+        trash_dir: &Path,
+        top_dir: Option<&Path>,
+    ) -> Result<PathBuf, LateraError> {
+        let files_dir = trash_dir.join("files");
+        let info_dir = trash_dir.join("info");
+        super::create_private_dir(&files_dir)?;
+        super::create_private_dir(&info_dir)?;
+
+        let name = path
+            .file_name()
+            .ok_or_else(|| LateraError::FileNameMissing(path.to_path_buf()))?
+            .to_string_lossy()
+            .to_string();
+        let original = top_dir
+            .and_then(|top| path.strip_prefix(top).ok())
+            .unwrap_or(path);
+        let info = format!(
+            "[Trash Info]\nPath={}\nDeletionDate={}\n",
+            percent_encode(original),
+            chrono::Local::now().format("%Y-%m-%dT%H:%M:%S")
+        );
+
+        for n in 1..u32::MAX {
+            let candidate = if n == 1 {
+                name.clone()
+            } else {
+                format!("{name}.{n}")
+            };
+            let info_path = info_dir.join(format!("{candidate}.trashinfo"));
+            let mut info_file = match OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&info_path)
+            {
+                Ok(file) => file,
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e.into()),
+            };
+            let target = files_dir.join(&candidate);
+            if target.exists() {
+                drop(info_file);
+                let _ = std::fs::remove_file(&info_path);
+                continue;
+            }
+            let moved = info_file
+                .write_all(info.as_bytes())
+                .and_then(|()| std::fs::rename(path, &target));
+            if let Err(e) = moved {
+                let _ = std::fs::remove_file(&info_path);
+                return Err(e.into());
+            }
+            return Ok(target);
+        }
+        Err(std::io::Error::new(ErrorKind::AlreadyExists, "no free name in trash").into())
+    }
+
+    /// Путь для `Path=`: всё, кроме безопасных символов и `/`, в `%XX`.
+    fn percent_encode(path: &Path) -> String {
+        let mut encoded = String::new();
+        for &byte in path.as_os_str().as_bytes() {
+            if byte.is_ascii_alphanumeric() || b"/-_.~".contains(&byte) {
+                encoded.push(char::from(byte));
+            } else {
+                let _ = write!(encoded, "%{byte:02X}");
+            }
+        }
+        encoded
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use std::os::windows::ffi::OsStrExt;
+    use std::path::{Path, PathBuf};
+
+    use windows::core::PCWSTR;
+    use windows::Win32::UI::Shell::{
+        SHFileOperationW, FOF_ALLOWUNDO, FOF_NOCONFIRMATION, FOF_NOERRORUI, FOF_SILENT, FO_DELETE,
+        SHFILEOPSTRUCTW,
+    };
+
+    use crate::error::LateraError;
+
+    pub fn trash(path: &Path) -> Result<Option<PathBuf>, LateraError> {
+        // pFrom — список путей, завершённый двумя нулями
+        let mut from: Vec<u16> = path.as_os_str().encode_wide().collect();
+        from.extend([0, 0]);
+        let mut operation = SHFILEOPSTRUCTW {
+            wFunc: FO_DELETE,
+            pFrom: PCWSTR(from.as_ptr()),
+            fFlags: (FOF_ALLOWUNDO | FOF_NOCONFIRMATION | FOF_NOERRORUI | FOF_SILENT).0 as u16,
+            ..Default::default()
+        };
+        // SAFETY: `from` живёт до конца вызова, остальные поля заполнены нулями
+        let rc = unsafe { SHFileOperationW(&mut operation) };
+        if rc != 0 {
+            return Err(std::io::Error::other(format!(
+                "SHFileOperationW failed with code {rc:#x}: {}",
+                path.display()
+            ))
+            .into());
+        }
+        if operation.fAnyOperationsAborted.as_bool() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Interrupted,
+                "moving to recycle bin was cancelled",
+            )
+            .into());
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(not(any(unix, target_os = "windows")))]
+mod platform {
+    use std::path::{Path, PathBuf};
+
+    use crate::error::LateraError;
+
+    pub fn trash(_path: &Path) -> Result<Option<PathBuf>, LateraError> {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "trash is not supported on this platform",
+        )
+        .into())
+    }
+}
+
+#[cfg(all(test, unix, not(target_os = "macos")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trash_into_writes_info_and_avoids_collisions() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let trash_dir = temp_dir.path().join("Trash");
+        let watch_dir = temp_dir.path().join("My Desktop");
+        std::fs::create_dir(&watch_dir).unwrap();
+
+        let mut targets = Vec::new();
+        for content in ["first", "second"] {
+            let path = watch_dir.join("a b.txt");
+            std::fs::write(&path, content).unwrap();
+            targets.push(platform::trash_into(&path, &trash_dir, None).unwrap());
+            assert!(!path.exists());
+        }
+        assert_eq!(targets[0], trash_dir.join("files").join("a b.txt"));
+        assert_eq!(targets[1], trash_dir.join("files").join("a b.txt.2"));
+        assert_eq!(std::fs::read_to_string(&targets[1]).unwrap(), "second");
+
+        let info =
+            std::fs::read_to_string(trash_dir.join("info").join("a b.txt.2.trashinfo")).unwrap();
+        assert!(info.starts_with("[Trash Info]\n"));
+        assert!(info.contains("/My%20Desktop/a%20b.txt\n"));
+        assert!(info.contains("DeletionDate="));
+
+        // Для корзины другого тома путь относительный
+        let path = watch_dir.join("c.txt");
+        std::fs::write(&path, "x").unwrap();
+        platform::trash_into(&path, &trash_dir, Some(temp_dir.path())).unwrap();
+        let info = std::fs::read_to_string(trash_dir.join("info").join("c.txt.trashinfo")).unwrap();
+        assert!(info.contains("\nPath=My%20Desktop/c.txt\n"));
+    }
+}
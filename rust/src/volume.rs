@@ -0,0 +1,247 @@
+//! Тип тома, на котором лежит папка, и настройки наблюдения под него.
+//!
+//! Один и тот же watcher ведёт себя по-разному на внутреннем SSD, флешке,
+//! сетевом диске и в папке облачного клиента: на сети нативные события
+//! приходят не всегда и опрос дорог, облачный клиент дописывает файлы
+//! рывками, а хэширование «файла только в облаке» скачивает его целиком.
+//! [`VolumeKind::strategy`] подбирает умолчания под тип тома.
+
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use sysinfo::{DiskKind, Disks};
+
+use crate::error::LateraError;
+use crate::path_utils;
+
+/// Файловые системы сетевых дисков (`statfs`/`mount`).
+const NETWORK_FILE_SYSTEMS: &[&str] = &[
+    "nfs",
+    "nfs4",
+    "smbfs",
+    "cifs",
+    "smb2",
+    "smb3",
+    "afpfs",
+    "webdav",
+    "davfs",
+    "fuse.sshfs",
+    "9p",
+    "ncpfs",
+];
+
+/// Тип тома.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VolumeKind {
+    /// Встроенный диск.
+    Internal,
+    /// Съёмный диск (флешка, SD-карта, внешний USB-диск).
+    Removable,
+    /// Сетевой диск (SMB, NFS, AFP, WebDAV).
+    Network,
+    /// Папка облачного клиента (OneDrive, iCloud Drive, Dropbox, Google Drive).
+    CloudSynced,
+    /// Тома не удалось определить.
+    Unknown,
+}
+
+/// Накопитель тома.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StorageKind {
+    Ssd,
+    Hdd,
+    Unknown,
+}
+
+/// Том папки.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VolumeInfo {
+    pub kind: VolumeKind,
+    /// Точка монтирования; `None`, если том не найден.
+    pub mount_point: Option<PathBuf>,
+    /// Файловая система (`apfs`, `ntfs`, `smbfs`…); пусто, если неизвестна.
+    pub file_system: String,
+    pub storage: StorageKind,
+    /// Облачный клиент для [`VolumeKind::CloudSynced`].
+    pub cloud_provider: Option<String>,
+}
+
+/// Умолчания наблюдения для типа тома.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VolumeStrategy {
+    /// Период опроса, если watcher перейдёт на опрос.
+    pub poll_interval: Duration,
+    /// Сколько файл должен не меняться, прежде чем о нём сообщат.
+    pub settle_quiet_period: Option<Duration>,
+    /// Хэшировать ли появившиеся файлы (см. `set_event_hashing`).
+    pub hashing: bool,
+}
+
+impl VolumeKind {
+    pub fn strategy(self) -> VolumeStrategy {
+        match self {
+            Self::Internal | Self::Unknown => VolumeStrategy {
+                poll_interval: Duration::from_secs(5),
+                settle_quiet_period: None,
+                hashing: true,
+            },
+            // Запись на флешку медленная; чтение ради хэша — тоже
+            Self::Removable => VolumeStrategy {
+                poll_interval: Duration::from_secs(5),
+                settle_quiet_period: Some(Duration::from_secs(2)),
+                hashing: false,
+            },
+            Self::Network => VolumeStrategy {
+                poll_interval: Duration::from_secs(30),
+                settle_quiet_period: Some(Duration::from_secs(5)),
+                hashing: false,
+            },
+            // Хэш файла «только в облаке» скачал бы его
+            Self::CloudSynced => VolumeStrategy {
+                poll_interval: Duration::from_secs(10),
+                settle_quiet_period: Some(Duration::from_secs(5)),
+                hashing: false,
+            },
+        }
+    }
+}
+
+impl VolumeInfo {
+    pub(crate) fn unknown() -> Self {
+        Self {
+            kind: VolumeKind::Unknown,
+            mount_point: None,
+            file_system: String::new(),
+            storage: StorageKind::Unknown,
+            cloud_provider: None,
+        }
+    }
+}
+
+/// Определяет том папки `path` (она должна существовать).
+pub fn volume_info(path: &Path) -> Result<VolumeInfo, LateraError> {
+    let path = path_utils::normalize(&std::fs::canonicalize(path)?);
+    let mut info = VolumeInfo::unknown();
+
+    let disks = Disks::new_with_refreshed_list();
+    if let Some(disk) = disks
+        .list()
+        .iter()
+        .filter(|disk| path.starts_with(path_utils::normalize(disk.mount_point())))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())
+    {
+        info.mount_point = Some(disk.mount_point().to_path_buf());
+        info.file_system = disk.file_system().to_string_lossy().to_lowercase();
+        info.storage = match disk.kind() {
+            DiskKind::SSD => StorageKind::Ssd,
+            DiskKind::HDD => StorageKind::Hdd,
+            DiskKind::Unknown(_) => StorageKind::Unknown,
+        };
+        info.kind = if is_network_file_system(&info.file_system) {
+            VolumeKind::Network
+        } else if disk.is_removable() {
+            VolumeKind::Removable
+        } else {
+            VolumeKind::Internal
+        };
+    } else if path.to_string_lossy().starts_with(r"\\") {
+        // UNC-путь (`\\srv\share`): сетевые ресурсы среди дисков не перечисляются
+        info.kind = VolumeKind::Network;
+    }
+
+    if let Some(provider) = cloud_provider(&path, &cloud_roots()) {
+        info.kind = VolumeKind::CloudSynced;
+        info.cloud_provider = Some(provider);
+    }
+    Ok(info)
+}
+
+fn is_network_file_system(file_system: &str) -> bool {
+    NETWORK_FILE_SYSTEMS.contains(&file_system)
+}
+
+/// Корни облачных клиентов: (папка, имя клиента; `None` — по имени
+/// вложенной папки, как в `~/Library/CloudStorage/OneDrive-Personal`).
+fn cloud_roots() -> Vec<(PathBuf, Option<String>)> {
+    let mut roots: Vec<(PathBuf, Option<String>)> =
+        ["OneDrive", "OneDriveConsumer", "OneDriveCommercial"]
+            .into_iter()
+            .filter_map(std::env::var_os)
+            .map(|dir| (PathBuf::from(dir), Some("OneDrive".to_string())))
+            .collect();
+    if let Some(home) = dirs::home_dir() {
+        roots.push((home.join("Library").join("CloudStorage"), None));
+        roots.push((
+            home.join("Library").join("Mobile Documents"),
+            Some("iCloud Drive".to_string()),
+        ));
+        roots.push((home.join("iCloudDrive"), Some("iCloud Drive".to_string())));
+        roots.push((home.join("Dropbox"), Some("Dropbox".to_string())));
+        roots.push((home.join("Google Drive"), Some("Google Drive".to_string())));
+    }
+    roots
+}
+
+/// Клиент, в чьей папке лежит `path`.
+fn cloud_provider(path: &Path, roots: &[(PathBuf, Option<String>)]) -> Option<String> {
+    roots.iter().find_map(|(root, provider)| {
+        if !path_utils::is_within(root, path) || path_utils::paths_equal(root, path) {
+            return None;
+        }
+        match provider {
+            Some(provider) => Some(provider.clone()),
+            None => {
+                let normalized = path_utils::normalize(path);
+                let child = normalized
+                    .strip_prefix(path_utils::normalize(root))
+                    .ok()?
+                    .components()
+                    .next()?;
+                provider_from_dir_name(child.as_os_str())
+            }
+        }
+    })
+}
+
+/// `OneDrive-Personal` → `OneDrive`, `GoogleDrive-a@b.c` → `GoogleDrive`.
+fn provider_from_dir_name(name: &OsStr) -> Option<String> {
+    let name = name.to_string_lossy();
+    let provider = name.split('-').next().unwrap_or_default();
+    (!provider.is_empty()).then(|| provider.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cloud_folders_are_recognised() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let storage = temp_dir.path().join("CloudStorage");
+        let onedrive = temp_dir.path().join("OneDrive");
+        let personal = storage.join("OneDrive-Personal").join("Docs");
+        std::fs::create_dir_all(&personal).unwrap();
+        std::fs::create_dir_all(onedrive.join("Work")).unwrap();
+        let roots = vec![
+            (storage.clone(), None),
+            (onedrive.clone(), Some("OneDrive".to_string())),
+        ];
+
+        assert_eq!(
+            cloud_provider(&personal, &roots).as_deref(),
+            Some("OneDrive")
+        );
+        assert_eq!(
+            cloud_provider(&onedrive.join("Work"), &roots).as_deref(),
+            Some("OneDrive")
+        );
+        // Сам корень и папки вне облака — нет
+        assert_eq!(cloud_provider(&storage, &roots), None);
+        assert_eq!(cloud_provider(temp_dir.path(), &roots), None);
+
+        assert!(is_network_file_system("smbfs"));
+        assert!(!VolumeKind::CloudSynced.strategy().hashing);
+        assert!(VolumeKind::Internal.strategy().hashing);
+    }
+}
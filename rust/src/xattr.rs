@@ -0,0 +1,360 @@
+//! Расширенные атрибуты файлов.
+//!
+//! Позволяют хранить пометки Latera (например, «обработан») прямо на файле:
+//! - Linux: xattrs в пространстве `user.` (префикс добавляется/снимается автоматически);
+//! - macOS: xattrs как есть;
+//! - Windows: все атрибуты Latera хранятся в одном NTFS alternate data stream
+//!   `{file}:latera.xattrs` (строки `name=hex(value)`).
+//!
+//! Имена атрибутов: непустые, без `\0`, `=`, `:` и переводов строк.
+
+use std::path::Path;
+
+use crate::error::LateraError;
+use crate::expected_changes;
+
+/// Атрибут файла.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Xattr {
+    pub name: String,
+    pub value: Vec<u8>,
+}
+
+/// Прочитать все атрибуты файла (в порядке имени).
+pub fn get_xattrs(path: &Path) -> Result<Vec<Xattr>, LateraError> {
+    if !path.exists() {
+        return Err(LateraError::InvalidPath(format!(
+            "file not found: {}",
+            path.display()
+        )));
+    }
+    let mut attrs = platform::list(path)?;
+    attrs.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(attrs)
+}
+
+/// Записать атрибут (перезаписывает существующее значение).
+pub fn set_xattr(path: &Path, name: &str, value: &[u8]) -> Result<(), LateraError> {
+    validate_name(name)?;
+    if !path.exists() {
+        return Err(LateraError::InvalidPath(format!(
+            "file not found: {}",
+            path.display()
+        )));
+    }
+    // Запись атрибута (на Windows — ADS) даёт событие Modify в папке
+    // наблюдения; watcher помечает его как self_generated
+    expected_changes::expect(path);
+    let result = platform::set(path, name, value);
+    if result.is_err() {
+        expected_changes::forget(path);
+    }
+    result
+}
+
+fn validate_name(name: &str) -> Result<(), LateraError> {
+    if name.is_empty() || name.contains(['\0', '=', ':', '\n', '\r']) {
+        return Err(LateraError::InvalidArgument(format!(
+            "invalid xattr name: {name:?}"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+mod platform {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::Path;
+
+    use super::Xattr;
+    use crate::error::LateraError;
+
+    /// Пространство имён для пользовательских атрибутов на Linux.
+    #[cfg(target_os = "linux")]
+    const NAMESPACE: &str = "user.";
+    #[cfg(target_os = "macos")]
+    const NAMESPACE: &str = "";
+
+    fn c_string(bytes: &[u8]) -> Result<CString, LateraError> {
+        CString::new(bytes).map_err(|_| LateraError::InvalidArgument("path contains NUL".into()))
+    }
+
+    unsafe fn list_raw(path: *const libc::c_char, buf: *mut libc::c_char, size: usize) -> isize {
+        #[cfg(target_os = "linux")]
+        return libc::listxattr(path, buf, size);
+        #[cfg(target_os = "macos")]
+        return libc::listxattr(path, buf, size, 0);
+    }
+
+    unsafe fn get_raw(
+        path: *const libc::c_char,
+        name: *const libc::c_char,
+        buf: *mut libc::c_void,
+        size: usize,
+    ) -> isize {
+        #[cfg(target_os = "linux")]
+        return libc::getxattr(path, name, buf, size);
+        #[cfg(target_os = "macos")]
+        return libc::getxattr(path, name, buf, size, 0, 0);
+    }
+
+    pub fn list(path: &Path) -> Result<Vec<Xattr>, LateraError> {
+        let c_path = c_string(path.as_os_str().as_bytes())?;
+
+        let size = unsafe { list_raw(c_path.as_ptr(), std::ptr::null_mut(), 0) };
+        if size < 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        let mut names = vec![0u8; size.unsigned_abs()];
+        let size = unsafe { list_raw(c_path.as_ptr(), names.as_mut_ptr().cast(), names.len()) };
+        if size < 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        names.truncate(size.unsigned_abs());
+
+        let mut attrs = Vec::new();
+        for raw_name in names.split(|b| *b == 0).filter(|n| !n.is_empty()) {
+            let Ok(full_name) = std::str::from_utf8(raw_name) else {
+                continue;
+            };
+            let Some(name) = full_name.strip_prefix(NAMESPACE) else {
+                continue;
+            };
+            let c_name = c_string(raw_name)?;
+            attrs.push(Xattr {
+                name: name.to_string(),
+                value: get_value(&c_path, &c_name)?,
+            });
+        }
+        Ok(attrs)
+    }
+
+    fn get_value(c_path: &CString, c_name: &CString) -> Result<Vec<u8>, LateraError> {
+        let size = unsafe { get_raw(c_path.as_ptr(), c_name.as_ptr(), std::ptr::null_mut(), 0) };
+        if size < 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        let mut value = vec![0u8; size.unsigned_abs()];
+        let size = unsafe {
+            get_raw(
+                c_path.as_ptr(),
+                c_name.as_ptr(),
+                value.as_mut_ptr().cast(),
+                value.len(),
+            )
+        };
+        if size < 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        value.truncate(size.unsigned_abs());
+        Ok(value)
+    }
+
+    pub fn set(path: &Path, name: &str, value: &[u8]) -> Result<(), LateraError> {
+        let c_path = c_string(path.as_os_str().as_bytes())?;
+        let c_name = c_string(format!("{NAMESPACE}{name}").as_bytes())?;
+        let value_ptr = value.as_ptr().cast();
+
+        #[cfg(target_os = "linux")]
+        let rc =
+            unsafe { libc::setxattr(c_path.as_ptr(), c_name.as_ptr(), value_ptr, value.len(), 0) };
+        #[cfg(target_os = "macos")]
+        let rc = unsafe {
+            libc::setxattr(
+                c_path.as_ptr(),
+                c_name.as_ptr(),
+                value_ptr,
+                value.len(),
+                0,
+                0,
+            )
+        };
+
+        if rc != 0 {
+            return Err(std::io::Error::last_os_error().into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use std::path::{Path, PathBuf};
+
+    use super::Xattr;
+    use crate::error::LateraError;
+
+    /// Имя alternate data stream с атрибутами Latera.
+    const STREAM_NAME: &str = "latera.xattrs";
+
+    fn stream_path(path: &Path) -> PathBuf {
+        let mut raw = path.as_os_str().to_os_string();
+        raw.push(":");
+        raw.push(STREAM_NAME);
+        PathBuf::from(raw)
+    }
+
+    pub fn list(path: &Path) -> Result<Vec<Xattr>, LateraError> {
+        match std::fs::read_to_string(stream_path(path)) {
+            Ok(content) => Ok(super::parse_stream(&content)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn set(path: &Path, name: &str, value: &[u8]) -> Result<(), LateraError> {
+        let mut attrs = list(path)?;
+        attrs.retain(|a| a.name != name);
+        attrs.push(Xattr {
+            name: name.to_string(),
+            value: value.to_vec(),
+        });
+        // Запись в поток меняет mtime самого файла — возвращаем прежний,
+        // чтобы атрибут не выглядел как правка содержимого
+        let modified = std::fs::metadata(path)?.modified()?;
+        std::fs::write(stream_path(path), super::format_stream(&attrs))?;
+        std::fs::File::options()
+            .write(true)
+            .open(path)?
+            .set_modified(modified)?;
+        Ok(())
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+mod platform {
+    use std::path::Path;
+
+    use super::Xattr;
+    use crate::error::LateraError;
+
+    fn unsupported() -> LateraError {
+        std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "extended attributes are not supported on this platform",
+        )
+        .into()
+    }
+
+    pub fn list(_path: &Path) -> Result<Vec<Xattr>, LateraError> {
+        Err(unsupported())
+    }
+
+    pub fn set(_path: &Path, _name: &str, _value: &[u8]) -> Result<(), LateraError> {
+        Err(unsupported())
+    }
+}
+
+/// Разбор ADS-хранилища: строки `name=hex(value)`. Повреждённые строки пропускаются.
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn parse_stream(content: &str) -> Vec<Xattr> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (name, hex) = line.split_once('=')?;
+            Some(Xattr {
+                name: name.to_string(),
+                value: decode_hex(hex)?,
+            })
+        })
+        .collect()
+}
+
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn format_stream(attrs: &[Xattr]) -> String {
+    use std::fmt::Write;
+
+    let mut out = String::new();
+    for attr in attrs {
+        let _ = write!(out, "{}=", attr.name);
+        for byte in &attr.value {
+            let _ = write!(out, "{byte:02x}");
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg_attr(not(target_os = "windows"), allow(dead_code))]
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_invalid_names() {
+        assert!(validate_name("").is_err());
+        assert!(validate_name("a=b").is_err());
+        assert!(validate_name("a:b").is_err());
+        assert!(validate_name("latera.processed").is_ok());
+    }
+
+    #[test]
+    fn test_stream_format_roundtrip() {
+        let attrs = vec![
+            Xattr {
+                name: "latera.processed".to_string(),
+                value: b"1".to_vec(),
+            },
+            Xattr {
+                name: "latera.binary".to_string(),
+                value: vec![0, 255, 10],
+            },
+        ];
+        assert_eq!(parse_stream(&format_stream(&attrs)), attrs);
+        assert!(parse_stream("broken\nname=zz\n").is_empty());
+    }
+
+    #[test]
+    fn test_set_and_get_roundtrip() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("doc.txt");
+        std::fs::write(&path, b"x").unwrap();
+
+        // Не все ФС (например, tmpfs на старых ядрах) поддерживают xattrs.
+        if let Err(e) = set_xattr(&path, "latera.processed", b"yes") {
+            eprintln!("xattrs not supported here, skipping: {e}");
+            return;
+        }
+        set_xattr(&path, "latera.processed", b"twice").unwrap();
+
+        let attrs = get_xattrs(&path).unwrap();
+        let processed = attrs.iter().find(|a| a.name == "latera.processed").unwrap();
+        assert_eq!(processed.value, b"twice");
+    }
+
+    #[test]
+    fn test_write_is_registered_as_expected_change() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("report.pdf");
+        std::fs::write(&path, b"x").unwrap();
+        let modified = std::fs::metadata(&path).unwrap().modified().unwrap();
+
+        match set_xattr(&path, "latera.processed", b"1") {
+            Ok(()) => assert!(expected_changes::is_expected(&path)),
+            // Неудачная запись событий не даёт — отметка снимается
+            Err(_) => assert!(!expected_changes::is_expected(&path)),
+        }
+        assert_eq!(
+            std::fs::metadata(&path).unwrap().modified().unwrap(),
+            modified
+        );
+    }
+
+    #[test]
+    fn test_missing_file_is_error() {
+        let missing = Path::new("/definitely/missing/file.txt");
+        assert!(get_xattrs(missing).is_err());
+        assert!(set_xattr(missing, "latera.processed", b"1").is_err());
+    }
+}
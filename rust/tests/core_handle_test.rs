@@ -0,0 +1,112 @@
+//! Интеграционные тесты `CoreHandle`.
+//!
+//! `init_core` инициализирует общее состояние процесса, поэтому тесты лежат
+//! в отдельном бинаре.
+
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use tempfile::TempDir;
+
+use latera_rust::api::{self, CoreConfig, CoreHandle, FileProcessingStatus};
+
+fn init_handle(data_dir: &Path) -> CoreHandle {
+    api::init_core(CoreConfig {
+        data_dir: Some(data_dir.to_string_lossy().to_string()),
+        index_db_path: None,
+    })
+    .expect("Failed to init core")
+}
+
+fn wait_for_status(handle: &CoreHandle, path: &Path) -> Option<FileProcessingStatus> {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    loop {
+        let status = handle
+            .get_file_status(path.to_string_lossy().to_string())
+            .expect("Failed to read file status");
+        if status.is_some() || Instant::now() > deadline {
+            return status;
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
+
+#[test]
+fn test_handles_write_to_own_stores() {
+    let first_dir = TempDir::new().expect("Failed to create temp dir");
+    let second_dir = TempDir::new().expect("Failed to create temp dir");
+    let watch_dir = TempDir::new().expect("Failed to create temp dir");
+
+    let first = init_handle(first_dir.path());
+    let second = init_handle(second_dir.path());
+    assert_eq!(
+        second.capabilities().data_dir,
+        second_dir.path().to_string_lossy()
+    );
+
+    first
+        .start_watching(Some(watch_dir.path().to_string_lossy().to_string()), None)
+        .expect("Failed to start watching");
+    // Даём watcher'у время на запуск
+    std::thread::sleep(Duration::from_millis(200));
+    let file = watch_dir.path().join("report.txt");
+    fs::write(&file, b"data").expect("Failed to write file");
+
+    assert_eq!(
+        wait_for_status(&first, &file),
+        Some(FileProcessingStatus::New)
+    );
+    let other = second
+        .get_file_status(file.to_string_lossy().to_string())
+        .expect("Failed to read file status");
+    assert_eq!(other, None, "event leaked into another handle's store");
+
+    first.close().expect("Failed to close handle");
+}
+
+#[test]
+fn test_dropped_handle_releases_watch_dir_at_once() {
+    let data_dir = TempDir::new().expect("Failed to create temp dir");
+    let watch_dir = TempDir::new().expect("Failed to create temp dir");
+    let watch_path = watch_dir.path().to_string_lossy().to_string();
+
+    let first = init_handle(data_dir.path());
+    first
+        .start_watching(Some(watch_path.clone()), None)
+        .expect("Failed to start watching");
+    drop(first);
+
+    // Папка свободна сразу после Drop, хотя watcher ещё останавливается в фоне
+    let second = init_handle(data_dir.path());
+    let started = second
+        .start_watching(Some(watch_path), None)
+        .expect("Watch dir is still held by the dropped handle");
+    assert_eq!(second.list_watchers().unwrap().len(), 1);
+    second
+        .stop_watching(started.watcher_id)
+        .expect("Failed to stop watching");
+}
+
+#[test]
+fn test_same_dir_is_not_watched_twice() {
+    let data_dir = TempDir::new().expect("Failed to create temp dir");
+    let watch_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::create_dir(watch_dir.path().join("sub")).expect("Failed to create subdir");
+    // Тот же каталог, записанный иначе
+    let same_dir = watch_dir.path().join("sub").join("..");
+
+    let handle = init_handle(data_dir.path());
+    let started = handle
+        .start_watching(Some(watch_dir.path().to_string_lossy().to_string()), None)
+        .expect("Failed to start watching");
+    let err = handle
+        .start_watching(Some(same_dir.to_string_lossy().to_string()), None)
+        .expect_err("Watched the same dir twice");
+    assert_eq!(err.code, "WATCHER_ALREADY_RUNNING");
+    assert_eq!(handle.list_watchers().unwrap().len(), 1);
+
+    handle
+        .stop_watching(started.watcher_id)
+        .expect("Failed to stop watching");
+}
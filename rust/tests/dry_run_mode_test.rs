@@ -0,0 +1,84 @@
+//! Интеграционные тесты пробного запуска.
+//!
+//! Режим — глобальный флаг процесса, поэтому тесты лежат в отдельном бинаре
+//! и не влияют на остальные интеграционные тесты.
+
+use std::fs;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use tempfile::TempDir;
+
+use latera_rust::archive::{self, ArchivePolicy};
+use latera_rust::audit_log::AuditAction;
+use latera_rust::dry_run;
+use latera_rust::quota::{self, FolderQuota};
+
+#[test]
+fn test_dry_run_reports_actions_without_touching_files() {
+    let planned = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&planned);
+    dry_run::set_listener(Some(Arc::new(move |action: &dry_run::PlannedAction| {
+        sink.lock().unwrap().push(action.clone());
+    })));
+    dry_run::set_enabled(true);
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let old = SystemTime::now() - Duration::from_hours(100 * 24);
+    for name in ["a.pdf", "b.pdf"] {
+        let path = temp_dir.path().join(name);
+        fs::write(&path, b"data").expect("Failed to write file");
+        fs::File::options()
+            .write(true)
+            .open(&path)
+            .and_then(|f| f.set_modified(old))
+            .expect("Failed to set mtime");
+    }
+
+    let policy = ArchivePolicy {
+        older_than_days: 30,
+        archive_root: None,
+    };
+    let archived = archive::archive_old_files(temp_dir.path(), &policy, SystemTime::now())
+        .expect("Archiving failed");
+    assert!(archived.is_empty());
+
+    let report = quota::cleanup_oldest(
+        temp_dir.path(),
+        &FolderQuota {
+            max_file_count: Some(1),
+            cleanup_oldest: true,
+            ..FolderQuota::default()
+        },
+    )
+    .expect("Cleanup failed");
+    assert_eq!(report.removed_files, 0);
+
+    // Файлы на месте, архив не создан
+    let mut names: Vec<_> = fs::read_dir(temp_dir.path())
+        .expect("Failed to read dir")
+        .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["a.pdf", "b.pdf"]);
+
+    let planned = planned.lock().unwrap();
+    let moves: Vec<_> = planned
+        .iter()
+        .filter(|p| p.action == AuditAction::Move)
+        .collect();
+    assert_eq!(moves.len(), 2);
+    assert!(moves.iter().all(|p| p.rule_id == archive::RULE_ID
+        && p.target
+            .as_deref()
+            .is_some_and(|t| t.contains(archive::DEFAULT_ARCHIVE_DIR_NAME))));
+    let deletes: Vec<_> = planned
+        .iter()
+        .filter(|p| p.action == AuditAction::Delete)
+        .collect();
+    assert_eq!(deletes.len(), 1);
+    assert_eq!(deletes[0].rule_id, quota::RULE_ID);
+
+    dry_run::set_enabled(false);
+    dry_run::set_listener(None);
+}
@@ -0,0 +1,62 @@
+//! Интеграционные тесты `extract_text`: коды ошибок извлечения
+//! превращаются в `LateraError`.
+//!
+//! `init_core` инициализирует общее состояние процесса, поэтому тесты лежат
+//! в отдельном бинаре.
+
+use std::fs::{self, File};
+use std::path::Path;
+
+use tempfile::TempDir;
+
+use latera_rust::api::{self, CoreConfig, CoreHandle};
+use latera_rust::error::LateraError;
+
+fn init_handle(data_dir: &Path) -> CoreHandle {
+    api::init_core(CoreConfig {
+        data_dir: Some(data_dir.to_string_lossy().to_string()),
+        index_db_path: None,
+    })
+    .expect("Failed to init core")
+}
+
+fn extract(path: &Path) -> Result<String, LateraError> {
+    api::extract_text(path.to_string_lossy().to_string())
+}
+
+#[test]
+fn test_extract_text_maps_error_codes() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let _handle = init_handle(temp_dir.path());
+
+    let notes = temp_dir.path().join("notes.txt");
+    fs::write(&notes, "квартальный отчёт").expect("Failed to write file");
+    assert_eq!(extract(&notes).unwrap(), "квартальный отчёт");
+
+    let missing = temp_dir.path().join("missing.txt");
+    assert!(matches!(
+        extract(&missing),
+        Err(LateraError::InvalidPath(_))
+    ));
+
+    let archive = temp_dir.path().join("photos.zip");
+    fs::write(&archive, b"PK\x03\x04").expect("Failed to write file");
+    match extract(&archive) {
+        Err(LateraError::InvalidArgument(message)) => {
+            assert!(message.contains("not supported"), "{message}");
+        }
+        other => panic!("unexpected result for unsupported format: {other:?}"),
+    }
+
+    // Разреженный файл больше лимита по умолчанию (50 МБ)
+    let huge = temp_dir.path().join("huge.txt");
+    File::create(&huge)
+        .and_then(|file| file.set_len(51 * 1024 * 1024))
+        .expect("Failed to create large file");
+    match extract(&huge) {
+        Err(LateraError::InvalidArgument(message)) => {
+            assert!(message.contains("too large"), "{message}");
+        }
+        other => panic!("unexpected result for large file: {other:?}"),
+    }
+}
@@ -11,7 +11,13 @@ use std::time::Duration;
 
 use tempfile::TempDir;
 
-use latera_rust::file_watcher::{start_watcher, InternalFileEvent};
+use latera_rust::expected_changes;
+use latera_rust::file_watcher::{
+    start_watcher, start_watcher_with_events, start_watcher_with_options, FileEventKind,
+    InternalFileEvent, StatusListener, TimestampSource, WatchDirRecovery, WatchFilter,
+    WatcherErrorCode, WatcherOptions, WatcherState,
+};
+use latera_rust::internal_files;
 
 /// Собирает события в потокобезопасную очередь для проверки.
 #[derive(Clone, Default)]
@@ -198,6 +204,13 @@ fn test_watcher_deduplicates_rapid_events() {
         events.len()
     );
 
+    // Счётчики: всё, что дошло до подписчика, пришло от backend'а, без потерь
+    let metrics = handle.metrics();
+    assert!(metrics.events_emitted >= events.len() as u64);
+    assert!(metrics.events_received >= metrics.events_emitted);
+    assert_eq!(metrics.events_dropped, 0);
+    assert_eq!(metrics.queue_depth, 0);
+
     handle.stop().expect("Failed to stop watcher");
 }
 
@@ -345,6 +358,7 @@ fn test_event_contains_correct_metadata() {
     assert_eq!(event.file_name, "metadata_test.txt");
     assert!(event.full_path.is_absolute());
     assert!(event.full_path.ends_with("metadata_test.txt"));
+    assert_eq!(event.extension.as_deref(), Some("txt"));
 
     // Проверяем, что timestamp разумный (в пределах 10 секунд от создания)
     let now = std::time::SystemTime::now()
@@ -402,3 +416,566 @@ fn test_watcher_ignores_directories() {
 
     handle.stop().expect("Failed to stop watcher");
 }
+
+// ============================================================================
+// Тесты семантики времени событий
+// ============================================================================
+
+#[test]
+fn test_watcher_uses_mtime_when_configured() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let staging_dir = TempDir::new().expect("Failed to create staging dir");
+    let collector = EventCollector::new();
+    let collector_clone = collector.clone();
+
+    let handle = start_watcher_with_options(
+        Some(temp_dir.path().to_string_lossy().to_string()),
+        WatcherOptions {
+            timestamp_source: TimestampSource::FileModified,
+            ..WatcherOptions::default()
+        },
+        move |e| {
+            collector_clone.push(e);
+        },
+        |_| {},
+    )
+    .expect("Failed to start watcher");
+
+    thread::sleep(Duration::from_millis(200));
+
+    // Файл с mtime на час в прошлом перемещается в наблюдаемую папку
+    let staged = create_test_file(staging_dir.path(), "old_document.txt");
+    let mtime = std::time::SystemTime::now() - Duration::from_hours(1);
+    File::options()
+        .write(true)
+        .open(&staged)
+        .and_then(|f| f.set_modified(mtime))
+        .expect("Failed to set mtime");
+    fs::rename(&staged, temp_dir.path().join("old_document.txt")).expect("Failed to move file");
+
+    let found = wait_for_events(&collector, 1, Duration::from_secs(5));
+    assert!(
+        found,
+        "Watcher did not detect the moved file within timeout"
+    );
+
+    let event = collector.take_all().remove(0);
+    assert!(
+        event.detected_at_ms - event.occurred_at_ms >= 59 * 60 * 1000,
+        "occurred_at_ms should reflect mtime: {:?}",
+        event
+    );
+    assert_eq!(event.modified_at_ms, Some(event.occurred_at_ms));
+
+    handle.stop().expect("Failed to stop watcher");
+}
+
+#[test]
+fn test_recursive_watcher_reports_subfolder_stats() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let sub_dir = temp_dir.path().join("inbox");
+    fs::create_dir(&sub_dir).expect("Failed to create subdir");
+    let collector = EventCollector::new();
+    let collector_clone = collector.clone();
+
+    let handle = start_watcher_with_options(
+        Some(temp_dir.path().to_string_lossy().to_string()),
+        WatcherOptions {
+            recursive: true,
+            ..WatcherOptions::default()
+        },
+        move |e| {
+            collector_clone.push(e);
+        },
+        |_| {},
+    )
+    .expect("Failed to start watcher");
+
+    thread::sleep(Duration::from_millis(200));
+    create_test_file(&sub_dir, "nested.txt");
+
+    let found = wait_for_events(&collector, 1, Duration::from_secs(5));
+    assert!(found, "Recursive watcher did not detect the nested file");
+
+    let stats = handle.tree_stats();
+    let inbox = stats
+        .iter()
+        .find(|s| s.relative_path == Path::new("inbox"))
+        .expect("No stats for subfolder");
+    assert!(inbox.event_count >= 1);
+    assert!(inbox.last_activity_ms > 0);
+
+    handle.stop().expect("Failed to stop watcher");
+}
+
+#[test]
+fn test_watcher_ignores_files_older_than_max_age() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let staging_dir = TempDir::new().expect("Failed to create staging dir");
+    let collector = EventCollector::new();
+    let collector_clone = collector.clone();
+
+    let handle = start_watcher_with_options(
+        Some(temp_dir.path().to_string_lossy().to_string()),
+        WatcherOptions {
+            max_file_age: Some(Duration::from_mins(10)),
+            ..WatcherOptions::default()
+        },
+        move |e| {
+            collector_clone.push(e);
+        },
+        |_| {},
+    )
+    .expect("Failed to start watcher");
+
+    thread::sleep(Duration::from_millis(200));
+
+    // Файл «из бэкапа» с mtime на час в прошлом
+    let staged = create_test_file(staging_dir.path(), "restored.txt");
+    let mtime = std::time::SystemTime::now() - Duration::from_hours(1);
+    File::options()
+        .write(true)
+        .open(&staged)
+        .and_then(|f| f.set_modified(mtime))
+        .expect("Failed to set mtime");
+    fs::rename(&staged, temp_dir.path().join("restored.txt")).expect("Failed to move file");
+    create_test_file(temp_dir.path(), "fresh.txt");
+
+    let found = wait_for_events(&collector, 1, Duration::from_secs(5));
+    assert!(
+        found,
+        "Watcher did not detect the fresh file within timeout"
+    );
+    thread::sleep(Duration::from_millis(300));
+
+    let names: Vec<String> = collector
+        .take_all()
+        .into_iter()
+        .map(|e| e.file_name)
+        .collect();
+    assert_eq!(names, vec!["fresh.txt".to_string()]);
+
+    handle.stop().expect("Failed to stop watcher");
+}
+
+#[test]
+fn test_watcher_reports_modify_and_remove_events() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let collector = EventCollector::new();
+    let collector_clone = collector.clone();
+
+    let handle = start_watcher_with_events(
+        Some(temp_dir.path().to_string_lossy().to_string()),
+        WatcherOptions::default(),
+        move |e| {
+            collector_clone.push(e);
+        },
+    )
+    .expect("Failed to start watcher");
+
+    thread::sleep(Duration::from_millis(200));
+    let path = create_test_file(temp_dir.path(), "tracked.txt");
+    thread::sleep(Duration::from_millis(400));
+    fs::write(&path, "changed").expect("Failed to modify file");
+    thread::sleep(Duration::from_millis(400));
+    fs::remove_file(&path).expect("Failed to remove file");
+
+    let start = std::time::Instant::now();
+    let mut kinds = Vec::new();
+    while start.elapsed() < Duration::from_secs(5) {
+        kinds.extend(collector.take_all().into_iter().map(|e| e.kind));
+        if kinds.contains(&FileEventKind::Removed) {
+            break;
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+
+    assert_eq!(kinds.first(), Some(&FileEventKind::Created));
+    assert!(kinds.contains(&FileEventKind::Modified), "{kinds:?}");
+    assert_eq!(kinds.last(), Some(&FileEventKind::Removed));
+
+    handle.stop().expect("Failed to stop watcher");
+}
+
+#[test]
+fn test_watcher_tags_core_generated_changes() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let collector = EventCollector::new();
+    let collector_clone = collector.clone();
+
+    let handle = start_watcher_with_events(
+        Some(temp_dir.path().to_string_lossy().to_string()),
+        WatcherOptions::default(),
+        move |e| {
+            collector_clone.push(e);
+        },
+    )
+    .expect("Failed to start watcher");
+
+    thread::sleep(Duration::from_millis(200));
+    let core_path = temp_dir.path().join("organized.txt");
+    expected_changes::expect(&core_path);
+    fs::write(&core_path, "by core").expect("Failed to write core file");
+    create_test_file(temp_dir.path(), "user.txt");
+
+    assert!(
+        wait_for_events(&collector, 2, Duration::from_secs(5)),
+        "Expected both file events"
+    );
+    let events = collector.take_all();
+    for event in &events {
+        assert_eq!(
+            event.self_generated,
+            event.file_name == "organized.txt",
+            "{event:?}"
+        );
+    }
+
+    handle.stop().expect("Failed to stop watcher");
+}
+
+#[test]
+fn test_watcher_suppresses_internal_files() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let collector = EventCollector::new();
+    let collector_clone = collector.clone();
+
+    let handle = start_watcher_with_events(
+        Some(temp_dir.path().to_string_lossy().to_string()),
+        WatcherOptions::default(),
+        move |e| {
+            collector_clone.push(e);
+        },
+    )
+    .expect("Failed to start watcher");
+
+    thread::sleep(Duration::from_millis(200));
+    internal_files::probe_writable(temp_dir.path()).expect("Failed to write probe");
+    thread::sleep(Duration::from_millis(100));
+    create_test_file(temp_dir.path(), "user.txt");
+
+    assert!(
+        wait_for_events(&collector, 1, Duration::from_secs(5)),
+        "Expected user file event"
+    );
+    thread::sleep(Duration::from_millis(300));
+    let events = collector.take_all();
+    assert!(
+        events.iter().all(|e| e.file_name == "user.txt"),
+        "internal file leaked: {events:?}"
+    );
+
+    handle.stop().expect("Failed to stop watcher");
+}
+
+#[test]
+fn test_watcher_reports_file_once_settled() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let collector = EventCollector::new();
+    let collector_clone = collector.clone();
+
+    let handle = start_watcher_with_events(
+        Some(temp_dir.path().to_string_lossy().to_string()),
+        WatcherOptions {
+            settle_quiet_period: Some(Duration::from_millis(500)),
+            ..WatcherOptions::default()
+        },
+        move |e| {
+            collector_clone.push(e);
+        },
+    )
+    .expect("Failed to start watcher");
+
+    thread::sleep(Duration::from_millis(200));
+
+    // «Копирование» частями: файл дописывается, пока не осядет
+    let path = temp_dir.path().join("large.bin");
+    let mut file = File::create(&path).expect("Failed to create test file");
+    for _ in 0..3 {
+        std::io::Write::write_all(&mut file, &[0u8; 1024]).expect("Failed to write chunk");
+        thread::sleep(Duration::from_millis(150));
+        assert_eq!(collector.count(), 0, "event emitted before file settled");
+    }
+    drop(file);
+
+    assert!(
+        wait_for_events(&collector, 1, Duration::from_secs(5)),
+        "Expected settled event"
+    );
+    thread::sleep(Duration::from_millis(300));
+    let events = collector.take_all();
+    assert_eq!(events.len(), 1, "{events:?}");
+    assert_eq!(events[0].kind, FileEventKind::Created);
+    assert_eq!(events[0].size_bytes, Some(3 * 1024));
+
+    handle.stop().expect("Failed to stop watcher");
+}
+
+#[test]
+fn test_watcher_reports_only_filtered_files() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let collector = EventCollector::new();
+    let collector_clone = collector.clone();
+
+    let filter = WatchFilter::new(
+        &[],
+        &["~$*".to_string()],
+        &["pdf".to_string(), "docx".to_string()],
+        true,
+    )
+    .expect("Failed to build filter");
+    let handle = start_watcher_with_events(
+        Some(temp_dir.path().to_string_lossy().to_string()),
+        WatcherOptions {
+            filter,
+            ..WatcherOptions::default()
+        },
+        move |e| {
+            collector_clone.push(e);
+        },
+    )
+    .expect("Failed to start watcher");
+
+    thread::sleep(Duration::from_millis(200));
+    create_test_file(temp_dir.path(), "~$letter.tmp");
+    create_test_file(temp_dir.path(), "~$letter.docx");
+    create_test_file(temp_dir.path(), "notes.txt");
+    thread::sleep(Duration::from_millis(100));
+    create_test_file(temp_dir.path(), "report.pdf");
+
+    assert!(
+        wait_for_events(&collector, 1, Duration::from_secs(5)),
+        "Expected pdf event"
+    );
+    thread::sleep(Duration::from_millis(300));
+    let events = collector.take_all();
+    assert!(
+        events.iter().all(|e| e.file_name == "report.pdf"),
+        "filtered file leaked: {events:?}"
+    );
+
+    handle.stop().expect("Failed to stop watcher");
+}
+
+#[test]
+fn test_watcher_reports_changes_made_while_stopped() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let snapshot_dir = TempDir::new().expect("Failed to create snapshot dir");
+    create_test_file(temp_dir.path(), "kept.txt");
+    create_test_file(temp_dir.path(), "deleted.txt");
+    let options = WatcherOptions {
+        snapshot_dir: Some(snapshot_dir.path().to_path_buf()),
+        ..WatcherOptions::default()
+    };
+    let start = |collector: EventCollector| {
+        start_watcher_with_events(
+            Some(temp_dir.path().to_string_lossy().to_string()),
+            options.clone(),
+            move |e| {
+                collector.push(e);
+            },
+        )
+        .expect("Failed to start watcher")
+    };
+
+    // Первый запуск только запоминает состояние
+    let collector = EventCollector::new();
+    let handle = start(collector.clone());
+    thread::sleep(Duration::from_millis(200));
+    handle.stop().expect("Failed to stop watcher");
+    assert!(collector.take_all().is_empty());
+
+    // Изменения, пока watcher не работает
+    create_test_file(temp_dir.path(), "offline.txt");
+    fs::remove_file(temp_dir.path().join("deleted.txt")).expect("Failed to remove file");
+
+    let collector = EventCollector::new();
+    let handle = start(collector.clone());
+    assert!(
+        wait_for_events(&collector, 2, Duration::from_secs(5)),
+        "Expected reconciled events"
+    );
+    let mut events: Vec<_> = collector
+        .take_all()
+        .into_iter()
+        .map(|e| (e.kind, e.file_name, e.reconciled))
+        .collect();
+    events.sort_by(|a, b| a.1.cmp(&b.1));
+    assert_eq!(
+        events,
+        vec![
+            (FileEventKind::Removed, "deleted.txt".to_string(), true),
+            (FileEventKind::Created, "offline.txt".to_string(), true),
+        ]
+    );
+
+    handle.stop().expect("Failed to stop watcher");
+}
+
+#[test]
+fn test_rescan_does_not_repeat_reported_files() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    create_test_file(temp_dir.path(), "existing.txt");
+    let collector = EventCollector::new();
+    let collector_clone = collector.clone();
+    let handle = start_watcher_with_events(
+        Some(temp_dir.path().to_string_lossy().to_string()),
+        WatcherOptions::default(),
+        move |e| {
+            collector_clone.push(e);
+        },
+    )
+    .expect("Failed to start watcher");
+    thread::sleep(Duration::from_millis(200));
+
+    create_test_file(temp_dir.path(), "new.txt");
+    assert!(
+        wait_for_events(&collector, 1, Duration::from_secs(5)),
+        "Expected event for new file"
+    );
+    collector.take_all();
+
+    // Всё уже сообщено: сверка ничего не находит и событий не повторяет
+    let found = handle.rescan().recv_timeout(Duration::from_secs(5));
+    assert_eq!(found, Ok(Some(0)));
+    thread::sleep(Duration::from_millis(200));
+    assert!(collector.take_all().is_empty());
+
+    handle.stop().expect("Failed to stop watcher");
+}
+
+#[test]
+fn test_watcher_reports_state_transitions() {
+    let states = Arc::new(Mutex::new(Vec::new()));
+    let states_clone = Arc::clone(&states);
+    let options = WatcherOptions {
+        status_listener: Some(StatusListener::new(move |state| {
+            states_clone.lock().unwrap().push(state.clone());
+        })),
+        ..WatcherOptions::default()
+    };
+
+    // Остановка по запросу
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let handle = start_watcher_with_options(
+        Some(temp_dir.path().to_string_lossy().to_string()),
+        options.clone(),
+        |_| {},
+        |_| {},
+    )
+    .expect("Failed to start watcher");
+    let started = std::time::Instant::now();
+    while handle.state() != WatcherState::Running && started.elapsed() < Duration::from_secs(5) {
+        thread::sleep(Duration::from_millis(20));
+    }
+    assert_eq!(handle.state(), WatcherState::Running);
+    handle.stop().expect("Failed to stop watcher");
+    assert_eq!(
+        std::mem::take(&mut *states.lock().unwrap()),
+        vec![
+            WatcherState::Starting,
+            WatcherState::Running,
+            WatcherState::Stopped
+        ]
+    );
+
+    // Папка исчезла — тред завершается с ошибкой, а не молча
+    let parent = TempDir::new().expect("Failed to create temp dir");
+    let watch_dir = parent.path().join("watched");
+    fs::create_dir(&watch_dir).expect("Failed to create watch dir");
+    let handle = start_watcher_with_options(
+        Some(watch_dir.to_string_lossy().to_string()),
+        WatcherOptions {
+            dir_recovery: WatchDirRecovery::Stop,
+            ..options
+        },
+        |_| {},
+        |_| {},
+    )
+    .expect("Failed to start watcher");
+    thread::sleep(Duration::from_millis(200));
+    fs::remove_dir_all(&watch_dir).expect("Failed to remove watch dir");
+
+    let started = std::time::Instant::now();
+    while !handle.state().is_terminal() && started.elapsed() < Duration::from_secs(10) {
+        thread::sleep(Duration::from_millis(50));
+    }
+    assert!(
+        matches!(
+            handle.state(),
+            WatcherState::Error {
+                code: WatcherErrorCode::WatchDirLost,
+                ..
+            }
+        ),
+        "{:?}",
+        handle.state()
+    );
+    handle.stop().expect("Failed to stop watcher");
+}
+
+/// Ждёт, пока состояние watcher'а не станет подходящим.
+fn wait_for_state(
+    handle: &latera_rust::file_watcher::WatcherHandle,
+    expected: impl Fn(&WatcherState) -> bool,
+) -> bool {
+    let started = std::time::Instant::now();
+    while started.elapsed() < Duration::from_secs(10) {
+        if expected(&handle.state()) {
+            return true;
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+    false
+}
+
+#[test]
+fn test_watcher_resumes_when_watch_dir_returns() {
+    for recovery in [WatchDirRecovery::WaitForReturn, WatchDirRecovery::Recreate] {
+        let parent = TempDir::new().expect("Failed to create temp dir");
+        let watch_dir = parent.path().join("watched");
+        fs::create_dir(&watch_dir).expect("Failed to create watch dir");
+
+        let collector = EventCollector::new();
+        let collector_clone = collector.clone();
+        let handle = start_watcher_with_events(
+            Some(watch_dir.to_string_lossy().to_string()),
+            WatcherOptions {
+                dir_recovery: recovery,
+                ..WatcherOptions::default()
+            },
+            move |event| {
+                if event.kind == FileEventKind::Created {
+                    collector_clone.push(event);
+                }
+            },
+        )
+        .expect("Failed to start watcher");
+        assert!(wait_for_state(&handle, |s| *s == WatcherState::Running));
+
+        fs::remove_dir_all(&watch_dir).expect("Failed to remove watch dir");
+        if recovery == WatchDirRecovery::WaitForReturn {
+            assert!(wait_for_state(&handle, |s| matches!(
+                s,
+                WatcherState::DirLost { .. }
+            )));
+            // Диск подключили обратно
+            fs::create_dir(&watch_dir).expect("Failed to recreate watch dir");
+        }
+        assert!(
+            wait_for_state(&handle, |s| *s == WatcherState::Running
+                && watch_dir.is_dir()),
+            "{recovery:?}: {:?}",
+            handle.state()
+        );
+
+        // Наблюдение продолжается
+        thread::sleep(Duration::from_millis(200));
+        create_test_file(&watch_dir, "after_return.txt");
+        assert!(
+            wait_for_events(&collector, 1, Duration::from_secs(5)),
+            "{recovery:?}: no events after the watch dir returned"
+        );
+        handle.stop().expect("Failed to stop watcher");
+    }
+}
@@ -0,0 +1,46 @@
+//! Интеграционные тесты перечитывания политики администратора.
+//!
+//! Политика и телеметрия — общее состояние процесса, а путь к файлу политики
+//! задаётся переменной окружения, поэтому тесты лежат в отдельном бинаре.
+
+use std::fs;
+
+use tempfile::TempDir;
+
+use latera_rust::api::{self, CoreConfig};
+use latera_rust::error::LateraError;
+use latera_rust::policy::POLICY_FILE_ENV;
+use latera_rust::telemetry;
+
+#[test]
+fn test_reload_stops_configured_telemetry_upload() {
+    let data_dir = TempDir::new().expect("Failed to create temp dir");
+    let policy_file = data_dir.path().join("policy.json");
+    std::env::set_var(POLICY_FILE_ENV, &policy_file);
+
+    let _handle = api::init_core(CoreConfig {
+        data_dir: Some(data_dir.path().to_string_lossy().to_string()),
+        index_db_path: None,
+    })
+    .expect("Failed to init core");
+    let url = "https://telemetry.example.com/v1".to_string();
+    api::set_telemetry_enabled(true, Some(url.clone())).expect("Failed to enable telemetry");
+    assert_eq!(telemetry::upload_url(), Some(url.clone()));
+
+    // Администратор запрещает отправку уже после настройки телеметрии
+    fs::write(&policy_file, r#"{ "disable_uploads": true }"#).expect("Failed to write policy");
+    let status = api::reload_policy().expect("Failed to reload policy");
+    assert!(status.locked_options.iter().any(|o| o.key == "uploads"));
+
+    assert_eq!(telemetry::upload_url(), None);
+    assert!(
+        api::is_telemetry_enabled(),
+        "local telemetry must keep working"
+    );
+    assert!(matches!(
+        api::set_telemetry_enabled(true, Some(url)),
+        Err(LateraError::PolicyLocked(_))
+    ));
+
+    api::set_telemetry_enabled(false, None).expect("Failed to disable telemetry");
+}
@@ -0,0 +1,45 @@
+//! Интеграционные тесты режима «только наблюдение».
+//!
+//! Режим — глобальный флаг процесса, поэтому тесты лежат в отдельном бинаре
+//! и не влияют на остальные интеграционные тесты.
+
+use std::fs;
+
+use tempfile::TempDir;
+
+use latera_rust::error::LateraError;
+use latera_rust::file_watcher::start_watcher;
+use latera_rust::read_only;
+
+#[test]
+fn test_read_only_mode_never_writes_into_watch_dir() {
+    read_only::set_enabled(true);
+
+    // Несуществующая папка не создаётся
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let missing = temp_dir.path().join("missing");
+    let result = start_watcher(Some(missing.to_string_lossy().to_string()), |_| {}, |_| {});
+    assert!(matches!(result, Err(LateraError::ReadOnlyMode(_))));
+    assert!(!missing.exists());
+
+    // Существующая папка наблюдается без изменений
+    let handle = start_watcher(
+        Some(temp_dir.path().to_string_lossy().to_string()),
+        |_| {},
+        |_| {},
+    )
+    .expect("Failed to start watcher in read-only mode");
+    handle.stop().expect("Failed to stop watcher");
+
+    let entries: Vec<_> = fs::read_dir(temp_dir.path())
+        .expect("Failed to read dir")
+        .collect();
+    assert!(entries.is_empty(), "watch dir was modified: {entries:?}");
+
+    assert!(matches!(
+        read_only::ensure_writable("test"),
+        Err(LateraError::ReadOnlyMode(_))
+    ));
+    read_only::set_enabled(false);
+    assert!(read_only::ensure_writable("test").is_ok());
+}
@@ -58,6 +58,9 @@ if (-not $SkipRust) {
         $cargoArgs += "vulkan"
         Write-Host "  Vulkan feature: enabled" -ForegroundColor Green
     } else {
+        # LLM без GPU-ускорения (vulkan включает llm сам)
+        $cargoArgs += "--features"
+        $cargoArgs += "llm"
         Write-Host "  Vulkan feature: disabled (SDK not found)" -ForegroundColor Yellow
     }
     
@@ -126,7 +129,7 @@ if ($Msix) {
             Write-Host "Copied Rust DLL to output directory" -ForegroundColor Gray
         } else {
             Write-Host "WARNING: Rust DLL not found at $RustDllSource" -ForegroundColor Yellow
-            Write-Host "Make sure to build Rust first: cargo build --release" -ForegroundColor Gray
+            Write-Host "Make sure to build Rust first: cargo build --release --features llm" -ForegroundColor Gray
         }
         
         # Download and copy onnxruntime.dll (load-dynamic mode: loaded at runtime via LoadLibrary)
@@ -63,6 +63,8 @@ if [ "$SKIP_RUST" = false ]; then
     if [ "$RELEASE_MODE" = true ]; then
         CARGO_ARGS="$CARGO_ARGS --release"
     fi
+    # Генеративная LLM (llama.cpp) — cargo feature, по умолчанию выключена
+    CARGO_ARGS="$CARGO_ARGS --features llm"
     
     cargo $CARGO_ARGS
     if [ $? -ne 0 ]; then